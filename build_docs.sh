@@ -18,7 +18,7 @@ mkdir ./build_docs/plugin
 mkdir ./build_docs/runtime
 
 echo "create instruction reference markdown file"
-(cd doc/insref && cargo update && cargo run -- x64 > ../instructionref_x64.md && cargo run -- aarch64 > ../instructionref_aarch64.md)
+(cd doc/insref && cargo update && cargo run -- x64 > ../instructionref_x64.md && cargo run -- aarch64 > ../instructionref_aarch64.md && cargo run -- rv64 > ../instructionref_riscv.md && cargo run -- arm > ../instructionref_arm.md && cargo run -- thumb > ../instructionref_thumb.md && cargo run -- loongarch64 > ../instructionref_loongarch.md)
 
 echo "build plugin docs"
 for f in ./doc/*.md; do
@@ -0,0 +1,8133 @@
+// This file was generated by tools/aarch64_gen_opmap.py
+
+/// The aarch64 instruction data. Expands to `$ops!(...)`, with for every mnemonic a list of its forms as
+/// `base encoding = [matchers] => [commands] (, features)`.
+#[macro_export]
+macro_rules! aarch64_opmap {
+    ($ops:ident) => { $ops!(
+
+"abs" = [
+    // ABS
+    0b01011110_11100000_10111000_00000000 = [D, D] => [R(0), R(5)];
+    0b00001110_00100000_10111000_00000000 = [V(BYTE), V(BYTE)] => [R(0), R(5), Rwidth(30)];
+    0b00001110_01100000_10111000_00000000 = [V(WORD), V(WORD)] => [R(0), R(5), Rwidth(30)];
+    0b00001110_10100000_10111000_00000000 = [V(DWORD), V(DWORD)] => [R(0), R(5), Rwidth(30)];
+    0b00001110_11100000_10111000_00000000 = [VStatic(QWORD, 2), VStatic(QWORD, 2)] => [R(0), R(5), Rwidth(30)];
+    // ABS (predicated)
+    0b00000100_00010110_10100000_00000000 = [Z(BYTE), PMerge, Z(BYTE)] => [R(0), R3(10), R(5)], SVE;
+    0b00000100_01010110_10100000_00000000 = [Z(WORD), PMerge, Z(WORD)] => [R(0), R3(10), R(5)], SVE;
+    0b00000100_10010110_10100000_00000000 = [Z(DWORD), PMerge, Z(DWORD)] => [R(0), R3(10), R(5)], SVE;
+    0b00000100_11010110_10100000_00000000 = [Z(QWORD), PMerge, Z(QWORD)] => [R(0), R3(10), R(5)], SVE;
+]
+"adc" = [
+    0b00011010_00000000_00000000_00000000 = [W, W, W] => [R(0), R(5), R(16)];
+    0b10011010_00000000_00000000_00000000 = [X, X, X] => [R(0), R(5), R(16)];
+]
+"adclb" = [
+    0b01000101_00000000_11010000_00000000 = [Z(DWORD), Z(DWORD), Z(DWORD)] => [R(0), R(5), R(16)], SVE2;
+    0b01000101_01000000_11010000_00000000 = [Z(QWORD), Z(QWORD), Z(QWORD)] => [R(0), R(5), R(16)], SVE2;
+]
+"adclt" = [
+    0b01000101_00000000_11010100_00000000 = [Z(DWORD), Z(DWORD), Z(DWORD)] => [R(0), R(5), R(16)], SVE2;
+    0b01000101_01000000_11010100_00000000 = [Z(QWORD), Z(QWORD), Z(QWORD)] => [R(0), R(5), R(16)], SVE2;
+]
+"adcs" = [
+    0b00111010_00000000_00000000_00000000 = [W, W, W] => [R(0), R(5), R(16)];
+    0b10111010_00000000_00000000_00000000 = [X, X, X] => [R(0), R(5), R(16)];
+]
+"add" = [
+    // ADD (shifted register)
+    0b00001011_00000000_00000000_00000000 = [W, W, W, End, Mod(SHIFTS)] => [R(0), R(5), R(16), Rotates(22), Ubits(10, 5)];
+    0b10001011_00000000_00000000_00000000 = [X, X, X, End, Mod(SHIFTS)] => [R(0), R(5), R(16), Rotates(22), Ubits(10, 6)];
+    // ADD (extended register)
+    0b00001011_00100000_00000000_00000000 = [WSP, WSP, W, End, Mod(EXTENDS)] => [R(0), R(5), R(16), ExtendsW(13), Urange(10, 0, 4)];
+    0b10001011_00100000_00000000_00000000 = [XSP, XSP, W, End, Mod(EXTENDS_W)] => [R(0), R(5), R(16), ExtendsX(13), Urange(10, 0, 4)];
+    0b10001011_00100000_00000000_00000000 = [XSP, XSP, X, End, Mod(EXTENDS_X)] => [R(0), R(5), R(16), ExtendsX(13), Urange(10, 0, 4)];
+    // ADD (immediate)
+    0b00010001_00000000_00000000_00000000 = [WSP, WSP, Imm, End, LitMod(LSL)] => [R(0), R(5), Ubits(10, 12), Ulist(22, &[0, 12])];
+    0b10010001_00000000_00000000_00000000 = [XSP, XSP, Imm, End, LitMod(LSL)] => [R(0), R(5), Ubits(10, 12), Ulist(22, &[0, 12])];
+    // ADD (vector)
+    0b01011110_11100000_10000100_00000000 = [D, D, D] => [R(0), R(5), R(16)];
+    0b00001110_00100000_10000100_00000000 = [V(BYTE), V(BYTE), V(BYTE)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b00001110_01100000_10000100_00000000 = [V(WORD), V(WORD), V(WORD)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b00001110_10100000_10000100_00000000 = [V(DWORD), V(DWORD), V(DWORD)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b00001110_11100000_10000100_00000000 = [VStatic(QWORD, 2), VStatic(QWORD, 2), VStatic(QWORD, 2)] => [R(0), R(5), R(16), Rwidth(30)];
+    // ADD (vectors, unpredicated)
+    0b00000100_00100000_00000000_00000000 = [Z(BYTE), Z(BYTE), Z(BYTE)] => [R(0), R(5), R(16)], SVE;
+    0b00000100_01100000_00000000_00000000 = [Z(WORD), Z(WORD), Z(WORD)] => [R(0), R(5), R(16)], SVE;
+    0b00000100_10100000_00000000_00000000 = [Z(DWORD), Z(DWORD), Z(DWORD)] => [R(0), R(5), R(16)], SVE;
+    0b00000100_11100000_00000000_00000000 = [Z(QWORD), Z(QWORD), Z(QWORD)] => [R(0), R(5), R(16)], SVE;
+    // ADD (vectors, predicated)
+    0b00000100_00000000_00000000_00000000 = [Z(BYTE), PMerge, Z(BYTE), Z(BYTE)] => [R(0), R3(10), RTied, R(5)], SVE;
+    0b00000100_01000000_00000000_00000000 = [Z(WORD), PMerge, Z(WORD), Z(WORD)] => [R(0), R3(10), RTied, R(5)], SVE;
+    0b00000100_10000000_00000000_00000000 = [Z(DWORD), PMerge, Z(DWORD), Z(DWORD)] => [R(0), R3(10), RTied, R(5)], SVE;
+    0b00000100_11000000_00000000_00000000 = [Z(QWORD), PMerge, Z(QWORD), Z(QWORD)] => [R(0), R3(10), RTied, R(5)], SVE;
+    // ADD (immediate)
+    0b00100101_00100000_11000000_00000000 = [Z(BYTE), Z(BYTE), Imm] => [R(0), RTied, Ubits(5, 8)], SVE;
+    0b00100101_01100000_11000000_00000000 = [Z(WORD), Z(WORD), Imm, End, LitMod(LSL)] => [R(0), RTied, Ubits(5, 8), Ulist(13, &[0, 8])], SVE;
+    0b00100101_10100000_11000000_00000000 = [Z(DWORD), Z(DWORD), Imm, End, LitMod(LSL)] => [R(0), RTied, Ubits(5, 8), Ulist(13, &[0, 8])], SVE;
+    0b00100101_11100000_11000000_00000000 = [Z(QWORD), Z(QWORD), Imm, End, LitMod(LSL)] => [R(0), RTied, Ubits(5, 8), Ulist(13, &[0, 8])], SVE;
+]
+"addg" = [
+    0b10010001_10000000_00000000_00000000 = [XSP, XSP, Imm, Imm] => [R(0), R(5), Uscaled(16, 6, 4), Ubits(10, 4)], MTE;
+]
+"addha" = [
+    0b11000000_10010000_00000000_00000000 = [ZaTile(DWORD), PMerge, PMerge, Z(DWORD)] => [RTile(0, 2), R3(10), R3(13), R(5)], SME;
+    0b11000000_11010000_00000000_00000000 = [ZaTile(QWORD), PMerge, PMerge, Z(QWORD)] => [RTile(0, 3), R3(10), R3(13), R(5)], SME | SME_I16I64;
+]
+"addhn" = [
+    0b00001110_00100000_01000000_00000000 = [VStatic(BYTE, 8), VStatic(WORD, 8), VStatic(WORD, 8)] => [R(0), R(5), R(16)];
+    0b00001110_01100000_01000000_00000000 = [VStatic(WORD, 4), VStatic(DWORD, 4), VStatic(DWORD, 4)] => [R(0), R(5), R(16)];
+    0b00001110_10100000_01000000_00000000 = [VStatic(DWORD, 2), VStatic(QWORD, 2), VStatic(QWORD, 2)] => [R(0), R(5), R(16)];
+]
+"addhn2" = [
+    0b01001110_00100000_01000000_00000000 = [VStatic(BYTE, 16), VStatic(WORD, 8), VStatic(WORD, 8)] => [R(0), R(5), R(16)];
+    0b01001110_01100000_01000000_00000000 = [VStatic(WORD, 8), VStatic(DWORD, 4), VStatic(DWORD, 4)] => [R(0), R(5), R(16)];
+    0b01001110_10100000_01000000_00000000 = [VStatic(DWORD, 4), VStatic(QWORD, 2), VStatic(QWORD, 2)] => [R(0), R(5), R(16)];
+]
+"addhnb" = [
+    0b01000101_01100000_01100000_00000000 = [Z(BYTE), Z(WORD), Z(WORD)] => [R(0), R(5), R(16)], SVE2;
+    0b01000101_10100000_01100000_00000000 = [Z(WORD), Z(DWORD), Z(DWORD)] => [R(0), R(5), R(16)], SVE2;
+    0b01000101_11100000_01100000_00000000 = [Z(DWORD), Z(QWORD), Z(QWORD)] => [R(0), R(5), R(16)], SVE2;
+]
+"addhnt" = [
+    0b01000101_01100000_01100100_00000000 = [Z(BYTE), Z(WORD), Z(WORD)] => [R(0), R(5), R(16)], SVE2;
+    0b01000101_10100000_01100100_00000000 = [Z(WORD), Z(DWORD), Z(DWORD)] => [R(0), R(5), R(16)], SVE2;
+    0b01000101_11100000_01100100_00000000 = [Z(DWORD), Z(QWORD), Z(QWORD)] => [R(0), R(5), R(16)], SVE2;
+]
+"addp" = [
+    // ADDP (scalar)
+    0b01011110_11110001_10111000_00000000 = [D, VStatic(QWORD, 2)] => [R(0), R(5)];
+    // ADDP (vector)
+    0b00001110_00100000_10111100_00000000 = [V(BYTE), V(BYTE), V(BYTE)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b00001110_01100000_10111100_00000000 = [V(WORD), V(WORD), V(WORD)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b00001110_10100000_10111100_00000000 = [V(DWORD), V(DWORD), V(DWORD)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b00001110_11100000_10111100_00000000 = [VStatic(QWORD, 2), VStatic(QWORD, 2), VStatic(QWORD, 2)] => [R(0), R(5), R(16), Rwidth(30)];
+    // ADDP (vectors, predicated)
+    0b01000100_00010001_10100000_00000000 = [Z(BYTE), PMerge, Z(BYTE), Z(BYTE)] => [R(0), R3(10), RTied, R(5)], SVE2;
+    0b01000100_01010001_10100000_00000000 = [Z(WORD), PMerge, Z(WORD), Z(WORD)] => [R(0), R3(10), RTied, R(5)], SVE2;
+    0b01000100_10010001_10100000_00000000 = [Z(DWORD), PMerge, Z(DWORD), Z(DWORD)] => [R(0), R3(10), RTied, R(5)], SVE2;
+    0b01000100_11010001_10100000_00000000 = [Z(QWORD), PMerge, Z(QWORD), Z(QWORD)] => [R(0), R3(10), RTied, R(5)], SVE2;
+]
+"addpl" = [
+    0b00000100_01100000_01010000_00000000 = [XSP, XSP, Imm] => [R(0), R(16), Sbits(5, 6)], SVE;
+]
+"adds" = [
+    // ADDS (shifted register)
+    0b00101011_00000000_00000000_00000000 = [W, W, W, End, Mod(SHIFTS)] => [R(0), R(5), R(16), Rotates(22), Ubits(10, 5)];
+    0b10101011_00000000_00000000_00000000 = [X, X, X, End, Mod(SHIFTS)] => [R(0), R(5), R(16), Rotates(22), Ubits(10, 6)];
+    // ADDS (extended register)
+    0b00101011_00100000_00000000_00000000 = [W, WSP, W, End, Mod(EXTENDS)] => [R(0), R(5), R(16), ExtendsW(13), Urange(10, 0, 4)];
+    0b10101011_00100000_00000000_00000000 = [X, XSP, W, End, Mod(EXTENDS_W)] => [R(0), R(5), R(16), ExtendsX(13), Urange(10, 0, 4)];
+    0b10101011_00100000_00000000_00000000 = [X, XSP, X, End, Mod(EXTENDS_X)] => [R(0), R(5), R(16), ExtendsX(13), Urange(10, 0, 4)];
+    // ADDS (immediate)
+    0b00110001_00000000_00000000_00000000 = [W, WSP, Imm, End, LitMod(LSL)] => [R(0), R(5), Ubits(10, 12), Ulist(22, &[0, 12])];
+    0b10110001_00000000_00000000_00000000 = [X, XSP, Imm, End, LitMod(LSL)] => [R(0), R(5), Ubits(10, 12), Ulist(22, &[0, 12])];
+]
+"addspl" = [
+    0b00000100_01100000_01011000_00000000 = [XSP, XSP, Imm] => [R(0), R(16), Sbits(5, 6)], SME;
+]
+"addsvl" = [
+    0b00000100_00100000_01011000_00000000 = [XSP, XSP, Imm] => [R(0), R(16), Sbits(5, 6)], SME;
+]
+"addv" = [
+    0b00001110_00110001_10111000_00000000 = [B, V(BYTE)] => [R(0), R(5), Rwidth(30)];
+    0b00001110_01110001_10111000_00000000 = [H, V(WORD)] => [R(0), R(5), Rwidth(30)];
+    0b00001110_10110001_10111000_00000000 = [S, VStatic(DWORD, 4)] => [R(0), R(5), Rwidth(30)];
+]
+"addva" = [
+    0b11000000_10010001_00000000_00000000 = [ZaTile(DWORD), PMerge, PMerge, Z(DWORD)] => [RTile(0, 2), R3(10), R3(13), R(5)], SME;
+    0b11000000_11010001_00000000_00000000 = [ZaTile(QWORD), PMerge, PMerge, Z(QWORD)] => [RTile(0, 3), R3(10), R3(13), R(5)], SME | SME_I16I64;
+]
+"addvl" = [
+    0b00000100_00100000_01010000_00000000 = [XSP, XSP, Imm] => [R(0), R(16), Sbits(5, 6)], SVE;
+]
+"adr" = [
+    // ADR
+    0b00010000_00000000_00000000_00000000 = [X, Offset] => [R(0), Offset(ADR)];
+    // ADR (packed offsets)
+    0b00000100_10100000_10100000_00000000 = [Z(DWORD), RefZZ(DWORD)] => [R(0), R(5), R(16), Ubits(10, 2)], SVE;
+    0b00000100_11100000_10100000_00000000 = [Z(QWORD), RefZZ(QWORD)] => [R(0), R(5), R(16), Ubits(10, 2)], SVE;
+    // ADR (unpacked 32-bit signed offsets)
+    0b00000100_00100000_10100000_00000000 = [Z(QWORD), RefZZExtend(SXTW)] => [R(0), R(5), R(16), Ubits(10, 2)], SVE;
+    // ADR (unpacked 32-bit unsigned offsets)
+    0b00000100_01100000_10100000_00000000 = [Z(QWORD), RefZZExtend(UXTW)] => [R(0), R(5), R(16), Ubits(10, 2)], SVE;
+]
+"adrp" = [
+    0b10010000_00000000_00000000_00000000 = [X, Offset] => [R(0), Offset(ADRP)];
+]
+"aesd" = [
+    0b01001110_00101000_01011000_00000000 = [VStatic(BYTE, 16), VStatic(BYTE, 16)] => [R(0), R(5)], AES;
+    0b01000101_00100010_11100100_00000000 = [Z(BYTE), Z(BYTE), Z(BYTE)] => [R(0), RTied, R(5)], SVE2 | AES;
+]
+"aese" = [
+    0b01001110_00101000_01001000_00000000 = [VStatic(BYTE, 16), VStatic(BYTE, 16)] => [R(0), R(5)], AES;
+    0b01000101_00100010_11100000_00000000 = [Z(BYTE), Z(BYTE), Z(BYTE)] => [R(0), RTied, R(5)], SVE2 | AES;
+]
+"aesimc" = [
+    0b01001110_00101000_01111000_00000000 = [VStatic(BYTE, 16), VStatic(BYTE, 16)] => [R(0), R(5)], AES;
+    0b01000101_00100000_11100100_00000000 = [Z(BYTE), Z(BYTE)] => [R(0), RTied], SVE2 | AES;
+]
+"aesmc" = [
+    0b01001110_00101000_01101000_00000000 = [VStatic(BYTE, 16), VStatic(BYTE, 16)] => [R(0), R(5)], AES;
+    0b01000101_00100000_11100000_00000000 = [Z(BYTE), Z(BYTE)] => [R(0), RTied], SVE2 | AES;
+]
+"and" = [
+    // AND (vector)
+    0b00001110_00100000_00011100_00000000 = [V(BYTE), V(BYTE), V(BYTE)] => [R(0), R(5), R(16), Rwidth(30)];
+    // AND (immediate)
+    0b00010010_00000000_00000000_00000000 = [WSP, W, Imm] => [R(0), R(5), Special(10, LOGICAL_IMMEDIATE_W)];
+    0b10010010_00000000_00000000_00000000 = [XSP, X, Imm] => [R(0), R(5), Special(10, LOGICAL_IMMEDIATE_X)];
+    // AND (shifted register)
+    0b00001010_00000000_00000000_00000000 = [W, W, W, End, Mod(ROTATES)] => [R(0), R(5), R(16), Rotates(22), Ubits(10, 5)];
+    0b10001010_00000000_00000000_00000000 = [X, X, X, End, Mod(ROTATES)] => [R(0), R(5), R(16), Rotates(22), Ubits(10, 6)];
+    // AND (predicates)
+    0b00100101_00000000_01000000_00000000 = [P(BYTE), PZero, P(BYTE), P(BYTE)] => [R4(0), R4(10), R4(5), R4(16)], SVE;
+    // AND (vectors, unpredicated)
+    0b00000100_00100000_00110000_00000000 = [Z(QWORD), Z(QWORD), Z(QWORD)] => [R(0), R(5), R(16)], SVE;
+    // AND (vectors, predicated)
+    0b00000100_00011010_00000000_00000000 = [Z(BYTE), PMerge, Z(BYTE), Z(BYTE)] => [R(0), R3(10), RTied, R(5)], SVE;
+    0b00000100_01011010_00000000_00000000 = [Z(WORD), PMerge, Z(WORD), Z(WORD)] => [R(0), R3(10), RTied, R(5)], SVE;
+    0b00000100_10011010_00000000_00000000 = [Z(DWORD), PMerge, Z(DWORD), Z(DWORD)] => [R(0), R3(10), RTied, R(5)], SVE;
+    0b00000100_11011010_00000000_00000000 = [Z(QWORD), PMerge, Z(QWORD), Z(QWORD)] => [R(0), R3(10), RTied, R(5)], SVE;
+    // AND (immediate)
+    0b00000101_10000000_00000000_00000000 = [Z(BYTE), Z(BYTE), Imm] => [R(0), RTied, Special(5, LOGICAL_IMMEDIATE_B)], SVE;
+    0b00000101_10000000_00000000_00000000 = [Z(WORD), Z(WORD), Imm] => [R(0), RTied, Special(5, LOGICAL_IMMEDIATE_H)], SVE;
+    0b00000101_10000000_00000000_00000000 = [Z(DWORD), Z(DWORD), Imm] => [R(0), RTied, Special(5, LOGICAL_IMMEDIATE_W)], SVE;
+    0b00000101_10000000_00000000_00000000 = [Z(QWORD), Z(QWORD), Imm] => [R(0), RTied, Special(5, LOGICAL_IMMEDIATE_X)], SVE;
+]
+"ands" = [
+    // ANDS (immediate)
+    0b01110010_00000000_00000000_00000000 = [W, W, Imm] => [R(0), R(5), Special(10, LOGICAL_IMMEDIATE_W)];
+    0b11110010_00000000_00000000_00000000 = [X, X, Imm] => [R(0), R(5), Special(10, LOGICAL_IMMEDIATE_X)];
+    // ANDS (shifted register)
+    0b01101010_00000000_00000000_00000000 = [W, W, W, End, Mod(ROTATES)] => [R(0), R(5), R(16), Rotates(22), Ubits(10, 5)];
+    0b11101010_00000000_00000000_00000000 = [X, X, X, End, Mod(ROTATES)] => [R(0), R(5), R(16), Rotates(22), Ubits(10, 6)];
+    // ANDS (predicates)
+    0b00100101_01000000_01000000_00000000 = [P(BYTE), PZero, P(BYTE), P(BYTE)] => [R4(0), R4(10), R4(5), R4(16)], SVE;
+]
+"andv" = [
+    0b00000100_00011010_00100000_00000000 = [B, PBare, Z(BYTE)] => [R(0), R3(10), R(5)], SVE;
+    0b00000100_01011010_00100000_00000000 = [H, PBare, Z(WORD)] => [R(0), R3(10), R(5)], SVE;
+    0b00000100_10011010_00100000_00000000 = [S, PBare, Z(DWORD)] => [R(0), R3(10), R(5)], SVE;
+    0b00000100_11011010_00100000_00000000 = [D, PBare, Z(QWORD)] => [R(0), R3(10), R(5)], SVE;
+]
+"asr" = [
+    // ASR (register)
+    0b00011010_11000000_00101000_00000000 = [W, W, W] => [R(0), R(5), R(16)];
+    0b10011010_11000000_00101000_00000000 = [X, X, X] => [R(0), R(5), R(16)];
+    // ASR (immediate)
+    0b00010011_00000000_01111100_00000000 = [W, W, Imm] => [R(0), R(5), Ubits(16, 5)];
+    0b10010011_01000000_11111100_00000000 = [X, X, Imm] => [R(0), R(5), Ubits(16, 6)];
+    // ASR (immediate, unpredicated)
+    0b00000100_00101000_10010000_00000000 = [Z(BYTE), Z(BYTE), Imm] => [R(0), R(5), Usub(16, 3, 8)], SVE;
+    0b00000100_00110000_10010000_00000000 = [Z(WORD), Z(WORD), Imm] => [R(0), R(5), Usub(16, 4, 16)], SVE;
+    0b00000100_01100000_10010000_00000000 = [Z(DWORD), Z(DWORD), Imm] => [R(0), R(5), Usub(16, 5, 32)], SVE;
+    0b00000100_10100000_10010000_00000000 = [Z(QWORD), Z(QWORD), Imm] => [R(0), R(5), BUrange(1, 64), Unegslice(16, 5, 0), Unegslice(22, 1, 5), A], SVE;
+    // ASR (wide elements, unpredicated)
+    0b00000100_00100000_10000000_00000000 = [Z(BYTE), Z(BYTE), Z(QWORD)] => [R(0), R(5), R(16)], SVE;
+    0b00000100_01100000_10000000_00000000 = [Z(WORD), Z(WORD), Z(QWORD)] => [R(0), R(5), R(16)], SVE;
+    0b00000100_10100000_10000000_00000000 = [Z(DWORD), Z(DWORD), Z(QWORD)] => [R(0), R(5), R(16)], SVE;
+    // ASR (immediate, predicated)
+    0b00000100_00000000_10000001_00000000 = [Z(BYTE), PMerge, Z(BYTE), Imm] => [R(0), R3(10), RTied, Usub(5, 3, 8)], SVE;
+    0b00000100_00000000_10000010_00000000 = [Z(WORD), PMerge, Z(WORD), Imm] => [R(0), R3(10), RTied, Usub(5, 4, 16)], SVE;
+    0b00000100_01000000_10000000_00000000 = [Z(DWORD), PMerge, Z(DWORD), Imm] => [R(0), R3(10), RTied, Usub(5, 5, 32)], SVE;
+    0b00000100_10000000_10000000_00000000 = [Z(QWORD), PMerge, Z(QWORD), Imm] => [R(0), R3(10), RTied, BUrange(1, 64), Unegslice(5, 5, 0), Unegslice(22, 1, 5), A], SVE;
+    // ASR (vectors, predicated)
+    0b00000100_00010000_10000000_00000000 = [Z(BYTE), PMerge, Z(BYTE), Z(BYTE)] => [R(0), R3(10), RTied, R(5)], SVE;
+    0b00000100_01010000_10000000_00000000 = [Z(WORD), PMerge, Z(WORD), Z(WORD)] => [R(0), R3(10), RTied, R(5)], SVE;
+    0b00000100_10010000_10000000_00000000 = [Z(DWORD), PMerge, Z(DWORD), Z(DWORD)] => [R(0), R3(10), RTied, R(5)], SVE;
+    0b00000100_11010000_10000000_00000000 = [Z(QWORD), PMerge, Z(QWORD), Z(QWORD)] => [R(0), R3(10), RTied, R(5)], SVE;
+    // ASR (wide elements, predicated)
+    0b00000100_00011000_10000000_00000000 = [Z(BYTE), PMerge, Z(BYTE), Z(QWORD)] => [R(0), R3(10), RTied, R(5)], SVE;
+    0b00000100_01011000_10000000_00000000 = [Z(WORD), PMerge, Z(WORD), Z(QWORD)] => [R(0), R3(10), RTied, R(5)], SVE;
+    0b00000100_10011000_10000000_00000000 = [Z(DWORD), PMerge, Z(DWORD), Z(QWORD)] => [R(0), R3(10), RTied, R(5)], SVE;
+]
+"asrd" = [
+    0b00000100_00000100_10000001_00000000 = [Z(BYTE), PMerge, Z(BYTE), Imm] => [R(0), R3(10), RTied, Usub(5, 3, 8)], SVE;
+    0b00000100_00000100_10000010_00000000 = [Z(WORD), PMerge, Z(WORD), Imm] => [R(0), R3(10), RTied, Usub(5, 4, 16)], SVE;
+    0b00000100_01000100_10000000_00000000 = [Z(DWORD), PMerge, Z(DWORD), Imm] => [R(0), R3(10), RTied, Usub(5, 5, 32)], SVE;
+    0b00000100_10000100_10000000_00000000 = [Z(QWORD), PMerge, Z(QWORD), Imm] => [R(0), R3(10), RTied, BUrange(1, 64), Unegslice(5, 5, 0), Unegslice(22, 1, 5), A], SVE;
+]
+"asrr" = [
+    0b00000100_00010100_10000000_00000000 = [Z(BYTE), PMerge, Z(BYTE), Z(BYTE)] => [R(0), R3(10), RTied, R(5)], SVE;
+    0b00000100_01010100_10000000_00000000 = [Z(WORD), PMerge, Z(WORD), Z(WORD)] => [R(0), R3(10), RTied, R(5)], SVE;
+    0b00000100_10010100_10000000_00000000 = [Z(DWORD), PMerge, Z(DWORD), Z(DWORD)] => [R(0), R3(10), RTied, R(5)], SVE;
+    0b00000100_11010100_10000000_00000000 = [Z(QWORD), PMerge, Z(QWORD), Z(QWORD)] => [R(0), R3(10), RTied, R(5)], SVE;
+]
+"asrv" = [
+    0b00011010_11000000_00101000_00000000 = [W, W, W] => [R(0), R(5), R(16)];
+    0b10011010_11000000_00101000_00000000 = [X, X, X] => [R(0), R(5), R(16)];
+]
+"at" = [
+    0b11010101_00001000_01111000_00000000 = [Ident, X] => [LitList(5, "AT_OPS"), R(0)];
+]
+"autda" = [
+    0b11011010_11000001_00011000_00000000 = [X, XSP] => [R(0), R(5)], PAUTH;
+]
+"autdb" = [
+    0b11011010_11000001_00011100_00000000 = [X, XSP] => [R(0), R(5)], PAUTH;
+]
+"autdza" = [
+    0b11011010_11000001_00111011_11100000 = [X] => [R(0)], PAUTH;
+]
+"autdzb" = [
+    0b11011010_11000001_00111111_11100000 = [X] => [R(0)], PAUTH;
+]
+"autia" = [
+    0b11011010_11000001_00010000_00000000 = [X, XSP] => [R(0), R(5)], PAUTH;
+]
+"autia1716" = [
+    0b11010101_00000011_00100001_10011111 = [] => [], PAUTH;
+]
+"autiasp" = [
+    0b11010101_00000011_00100011_10111111 = [] => [], PAUTH;
+]
+"autiaz" = [
+    0b11010101_00000011_00100011_10011111 = [] => [], PAUTH;
+]
+"autib" = [
+    0b11011010_11000001_00010100_00000000 = [X, XSP] => [R(0), R(5)], PAUTH;
+]
+"autib1716" = [
+    0b11010101_00000011_00100001_11011111 = [] => [], PAUTH;
+]
+"autibsp" = [
+    0b11010101_00000011_00100011_11111111 = [] => [], PAUTH;
+]
+"autibz" = [
+    0b11010101_00000011_00100011_11011111 = [] => [], PAUTH;
+]
+"autiza" = [
+    0b11011010_11000001_00110011_11100000 = [X] => [R(0)], PAUTH;
+]
+"autizb" = [
+    0b11011010_11000001_00110111_11100000 = [X] => [R(0)], PAUTH;
+]
+"b" = [
+    // B.cond
+    0b01010100_00000000_00000000_00000000 = [Dot, Cond, Offset] => [Cond(0), Offset(BCOND)];
+    // B
+    0b00010100_00000000_00000000_00000000 = [Offset] => [Offset(B)];
+]
+"bcax" = [
+    0b11001110_00100000_00000000_00000000 = [VStatic(BYTE, 16), VStatic(BYTE, 16), VStatic(BYTE, 16), VStatic(BYTE, 16)] => [R(0), R(5), R(16), R(10)], SHA3;
+    0b00000100_01100000_00111000_00000000 = [Z(QWORD), Z(QWORD), Z(QWORD), Z(QWORD)] => [R(0), RTied, R(16), R(5)], SVE2;
+]
+"bfc" = [
+    0b00110011_00000000_00000011_11100000 = [W, Imm, Imm] => [R(0), Unegmod(16, 5), BUsum(5), Urange(10, 1, 32)];
+    0b10110011_01000000_00000011_11100000 = [X, Imm, Imm] => [R(0), Unegmod(16, 6), BUsum(6), Urange(10, 1, 64)];
+]
+"bfcvt" = [
+    0b00011110_01100011_01000000_00000000 = [H, S] => [R(0), R(5)], BF16;
+]
+"bfcvtn" = [
+    0b00001110_10100001_01101000_00000000 = [VStatic(WORD, 4), VStatic(DWORD, 4)] => [R(0), R(5)], BF16;
+]
+"bfcvtn2" = [
+    0b01001110_10100001_01101000_00000000 = [VStatic(WORD, 8), VStatic(DWORD, 4)] => [R(0), R(5)], BF16;
+]
+"bfdot" = [
+    // BFDOT (by element)
+    0b00001111_01000000_11110000_00000000 = [VStatic(DWORD, 2), VStatic(WORD, 4), VStaticElement(WORD, 2)] => [R(0), R(5), R(16), Ufields(&[11, 21])], BF16;
+    0b01001111_01000000_11110000_00000000 = [VStatic(DWORD, 4), VStatic(WORD, 8), VStaticElement(WORD, 2)] => [R(0), R(5), R(16), Ufields(&[11, 21])], BF16;
+    // BFDOT (vector)
+    0b00101110_01000000_11111100_00000000 = [VStatic(DWORD, 2), VStatic(WORD, 4), VStatic(WORD, 4)] => [R(0), R(5), R(16)], BF16;
+    0b01101110_01000000_11111100_00000000 = [VStatic(DWORD, 4), VStatic(WORD, 8), VStatic(WORD, 8)] => [R(0), R(5), R(16)], BF16;
+]
+"bfi" = [
+    0b00110011_00000000_00000000_00000000 = [W, W, Imm, Imm] => [R(0), R(5), Unegmod(16, 5), BUsum(5), Urange(10, 1, 32)];
+    0b10110011_01000000_00000000_00000000 = [X, X, Imm, Imm] => [R(0), R(5), Unegmod(16, 6), BUsum(6), Urange(10, 1, 64)];
+]
+"bfm" = [
+    0b00110011_00000000_00000000_00000000 = [W, W, Imm, Imm] => [R(0), R(5), Ubits(16, 5), Ubits(10, 5)];
+    0b10110011_01000000_00000000_00000000 = [X, X, Imm, Imm] => [R(0), R(5), Ubits(16, 6), BUsum(6), Ubits(10, 6)];
+]
+"bfmlalb" = [
+    // BFMLALB (by element)
+    0b00001111_11000000_11110000_00000000 = [VStatic(DWORD, 4), VStatic(WORD, 8), VElement(WORD)] => [R(0), R(5), R4(16), Ufields(&[11, 21, 20])], BF16;
+    // BFMLALB (vector)
+    0b00101110_11000000_11111100_00000000 = [VStatic(DWORD, 4), VStatic(WORD, 8), VStatic(WORD, 8)] => [R(0), R(5), R(16)], BF16;
+]
+"bfmlalt" = [
+    // BFMLALT (by element)
+    0b01001111_11000000_11110000_00000000 = [VStatic(DWORD, 4), VStatic(WORD, 8), VElement(WORD)] => [R(0), R(5), R4(16), Ufields(&[11, 21, 20])], BF16;
+    // BFMLALT (vector)
+    0b01101110_11000000_11111100_00000000 = [VStatic(DWORD, 4), VStatic(WORD, 8), VStatic(WORD, 8)] => [R(0), R(5), R(16)], BF16;
+]
+"bfmmla" = [
+    0b01101110_01000000_11101100_00000000 = [VStatic(DWORD, 4), VStatic(WORD, 8), VStatic(WORD, 8)] => [R(0), R(5), R(16)], BF16;
+]
+"bfmopa" = [
+    0b10000001_10000000_00000000_00000000 = [ZaTile(DWORD), PMerge, PMerge, Z(WORD), Z(WORD)] => [RTile(0, 2), R3(10), R3(13), R(5), R(16)], SME;
+]
+"bfmops" = [
+    0b10000001_10000000_00000000_00010000 = [ZaTile(DWORD), PMerge, PMerge, Z(WORD), Z(WORD)] => [RTile(0, 2), R3(10), R3(13), R(5), R(16)], SME;
+]
+"bfxil" = [
+    0b00110011_00000000_00000000_00000000 = [W, W, Imm, Imm] => [R(0), R(5), Ubits(16, 5), BUsum(5), Usumdec(10, 5)];
+    0b10110011_01000000_00000000_00000000 = [X, X, Imm, Imm] => [R(0), R(5), Ubits(16, 6), BUsum(6), Usumdec(10, 6)];
+]
+"bic" = [
+    // BIC (vector, immediate)
+    0b00101111_00000000_10010100_00000000 = [V(WORD), Imm, End, LitMod(LSL)] => [R(0), BUbits(8), Uslice(5, 5, 0), Uslice(16, 3, 5), A, Ulist(13, &[0, 8]), Rwidth(30)];
+    0b00101111_00000000_00010100_00000000 = [V(DWORD), Imm, End, LitMod(LSL)] => [R(0), BUbits(8), Uslice(5, 5, 0), Uslice(16, 3, 5), A, Ulist(13, &[0, 8, 16, 24]), Rwidth(30)];
+    // BIC (vector, register)
+    0b00001110_01100000_00011100_00000000 = [V(BYTE), V(BYTE), V(BYTE)] => [R(0), R(5), R(16), Rwidth(30)];
+    // BIC (shifted register)
+    0b00001010_00100000_00000000_00000000 = [W, W, W, End, Mod(ROTATES)] => [R(0), R(5), R(16), Rotates(22), Ubits(10, 5)];
+    0b10001010_00100000_00000000_00000000 = [X, X, X, End, Mod(ROTATES)] => [R(0), R(5), R(16), Rotates(22), Ubits(10, 6)];
+    // BIC (predicates)
+    0b00100101_00000000_01000000_00010000 = [P(BYTE), PZero, P(BYTE), P(BYTE)] => [R4(0), R4(10), R4(5), R4(16)], SVE;
+    // BIC (vectors, unpredicated)
+    0b00000100_11100000_00110000_00000000 = [Z(QWORD), Z(QWORD), Z(QWORD)] => [R(0), R(5), R(16)], SVE;
+    // BIC (vectors, predicated)
+    0b00000100_00011011_00000000_00000000 = [Z(BYTE), PMerge, Z(BYTE), Z(BYTE)] => [R(0), R3(10), RTied, R(5)], SVE;
+    0b00000100_01011011_00000000_00000000 = [Z(WORD), PMerge, Z(WORD), Z(WORD)] => [R(0), R3(10), RTied, R(5)], SVE;
+    0b00000100_10011011_00000000_00000000 = [Z(DWORD), PMerge, Z(DWORD), Z(DWORD)] => [R(0), R3(10), RTied, R(5)], SVE;
+    0b00000100_11011011_00000000_00000000 = [Z(QWORD), PMerge, Z(QWORD), Z(QWORD)] => [R(0), R3(10), RTied, R(5)], SVE;
+]
+"bics" = [
+    // BICS
+    0b01101010_00100000_00000000_00000000 = [W, W, W, End, Mod(ROTATES)] => [R(0), R(5), R(16), Rotates(22), Ubits(10, 5)];
+    0b11101010_00100000_00000000_00000000 = [X, X, X, End, Mod(ROTATES)] => [R(0), R(5), R(16), Rotates(22), Ubits(10, 6)];
+    // BICS (predicates)
+    0b00100101_01000000_01000000_00010000 = [P(BYTE), PZero, P(BYTE), P(BYTE)] => [R4(0), R4(10), R4(5), R4(16)], SVE;
+]
+"bif" = [
+    0b00101110_11100000_00011100_00000000 = [V(BYTE), V(BYTE), V(BYTE)] => [R(0), R(5), R(16), Rwidth(30)];
+]
+"bit" = [
+    0b00101110_10100000_00011100_00000000 = [V(BYTE), V(BYTE), V(BYTE)] => [R(0), R(5), R(16), Rwidth(30)];
+]
+"bl" = [
+    0b10010100_00000000_00000000_00000000 = [Offset] => [Offset(B)];
+]
+"blr" = [
+    0b11010110_00111111_00000000_00000000 = [X] => [R(5)];
+]
+"blraa" = [
+    0b11010111_00111111_00001000_00000000 = [X, XSP] => [R(5), R(0)], PAUTH;
+]
+"blraaz" = [
+    0b11010110_00111111_00001000_00011111 = [X] => [R(5)], PAUTH;
+]
+"blrab" = [
+    0b11010111_00111111_00001100_00000000 = [X, XSP] => [R(5), R(0)], PAUTH;
+]
+"blrabz" = [
+    0b11010110_00111111_00001100_00011111 = [X] => [R(5)], PAUTH;
+]
+"br" = [
+    0b11010110_00011111_00000000_00000000 = [X] => [R(5)];
+]
+"braa" = [
+    0b11010111_00011111_00001000_00000000 = [X, XSP] => [R(5), R(0)], PAUTH;
+]
+"braaz" = [
+    0b11010110_00011111_00001000_00011111 = [X] => [R(5)], PAUTH;
+]
+"brab" = [
+    0b11010111_00011111_00001100_00000000 = [X, XSP] => [R(5), R(0)], PAUTH;
+]
+"brabz" = [
+    0b11010110_00011111_00001100_00011111 = [X] => [R(5)], PAUTH;
+]
+"brk" = [
+    0b11010100_00100000_00000000_00000000 = [Imm] => [Ubits(5, 16)];
+]
+"brka" = [
+    0b00100101_00010000_01000000_00000000 = [P(BYTE), PZero, P(BYTE)] => [R4(0), R4(10), R4(5)], SVE;
+    0b00100101_00010000_01000000_00010000 = [P(BYTE), PMerge, P(BYTE)] => [R4(0), R4(10), R4(5)], SVE;
+]
+"brkas" = [
+    0b00100101_01010000_01000000_00000000 = [P(BYTE), PZero, P(BYTE)] => [R4(0), R4(10), R4(5)], SVE;
+]
+"brkb" = [
+    0b00100101_10010000_01000000_00000000 = [P(BYTE), PZero, P(BYTE)] => [R4(0), R4(10), R4(5)], SVE;
+    0b00100101_10010000_01000000_00010000 = [P(BYTE), PMerge, P(BYTE)] => [R4(0), R4(10), R4(5)], SVE;
+]
+"brkbs" = [
+    0b00100101_11010000_01000000_00000000 = [P(BYTE), PZero, P(BYTE)] => [R4(0), R4(10), R4(5)], SVE;
+]
+"brkn" = [
+    0b00100101_00011000_01000000_00000000 = [P(BYTE), PZero, P(BYTE), P(BYTE)] => [R4(0), R4(10), R4(5), RTied], SVE;
+]
+"brkns" = [
+    0b00100101_01011000_01000000_00000000 = [P(BYTE), PZero, P(BYTE), P(BYTE)] => [R4(0), R4(10), R4(5), RTied], SVE;
+]
+"brkpa" = [
+    0b00100101_00000000_11000000_00000000 = [P(BYTE), PZero, P(BYTE), P(BYTE)] => [R4(0), R4(10), R4(5), R4(16)], SVE;
+]
+"brkpas" = [
+    0b00100101_01000000_11000000_00000000 = [P(BYTE), PZero, P(BYTE), P(BYTE)] => [R4(0), R4(10), R4(5), R4(16)], SVE;
+]
+"brkpb" = [
+    0b00100101_00000000_11000000_00010000 = [P(BYTE), PZero, P(BYTE), P(BYTE)] => [R4(0), R4(10), R4(5), R4(16)], SVE;
+]
+"brkpbs" = [
+    0b00100101_01000000_11000000_00010000 = [P(BYTE), PZero, P(BYTE), P(BYTE)] => [R4(0), R4(10), R4(5), R4(16)], SVE;
+]
+"bsl" = [
+    0b00101110_01100000_00011100_00000000 = [V(BYTE), V(BYTE), V(BYTE)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b00000100_00100000_00111100_00000000 = [Z(QWORD), Z(QWORD), Z(QWORD), Z(QWORD)] => [R(0), RTied, R(16), R(5)], SVE2;
+]
+"bsl1n" = [
+    0b00000100_01100000_00111100_00000000 = [Z(QWORD), Z(QWORD), Z(QWORD), Z(QWORD)] => [R(0), RTied, R(16), R(5)], SVE2;
+]
+"bsl2n" = [
+    0b00000100_10100000_00111100_00000000 = [Z(QWORD), Z(QWORD), Z(QWORD), Z(QWORD)] => [R(0), RTied, R(16), R(5)], SVE2;
+]
+"bti" = [
+    0b11010101_00000011_00100100_00011111 = [] => [], BTI;
+    0b11010101_00000011_00100100_01011111 = [Lit("c")] => [], BTI;
+    0b11010101_00000011_00100100_10011111 = [Lit("j")] => [], BTI;
+    0b11010101_00000011_00100100_11011111 = [Lit("jc")] => [], BTI;
+]
+"cadd" = [
+    0b01000101_00000000_11011000_00000000 = [Z(BYTE), Z(BYTE), Z(BYTE), Imm] => [R(0), RTied, R(5), Ulist(10, &[90, 270])], SVE2;
+    0b01000101_01000000_11011000_00000000 = [Z(WORD), Z(WORD), Z(WORD), Imm] => [R(0), RTied, R(5), Ulist(10, &[90, 270])], SVE2;
+    0b01000101_10000000_11011000_00000000 = [Z(DWORD), Z(DWORD), Z(DWORD), Imm] => [R(0), RTied, R(5), Ulist(10, &[90, 270])], SVE2;
+    0b01000101_11000000_11011000_00000000 = [Z(QWORD), Z(QWORD), Z(QWORD), Imm] => [R(0), RTied, R(5), Ulist(10, &[90, 270])], SVE2;
+]
+"cas" = [
+    0b10001000_10100000_01111100_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], LSE;
+    0b11001000_10100000_01111100_00000000 = [X, X, RefBase] => [R(16), R(0), R(5)], LSE;
+]
+"casa" = [
+    0b10001000_11100000_01111100_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], LSE;
+    0b11001000_11100000_01111100_00000000 = [X, X, RefBase] => [R(16), R(0), R(5)], LSE;
+]
+"casab" = [
+    0b00001000_11100000_01111100_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], LSE;
+]
+"casah" = [
+    0b01001000_11100000_01111100_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], LSE;
+]
+"casal" = [
+    0b10001000_11100000_11111100_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], LSE;
+    0b11001000_11100000_11111100_00000000 = [X, X, RefBase] => [R(16), R(0), R(5)], LSE;
+]
+"casalb" = [
+    0b00001000_11100000_11111100_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], LSE;
+]
+"casalh" = [
+    0b01001000_11100000_11111100_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], LSE;
+]
+"casb" = [
+    0b00001000_10100000_01111100_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], LSE;
+]
+"cash" = [
+    0b01001000_10100000_01111100_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], LSE;
+]
+"casl" = [
+    0b10001000_10100000_11111100_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], LSE;
+    0b11001000_10100000_11111100_00000000 = [X, X, RefBase] => [R(16), R(0), R(5)], LSE;
+]
+"caslb" = [
+    0b00001000_10100000_11111100_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], LSE;
+]
+"caslh" = [
+    0b01001000_10100000_11111100_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], LSE;
+]
+"casp" = [
+    0b00001000_00100000_01111100_00000000 = [W, W, W, W, RefBase] => [REven(16), RNext, REven(0), RNext, R(5)], LSE;
+    0b01001000_00100000_01111100_00000000 = [X, X, X, X, RefBase] => [REven(16), RNext, REven(0), RNext, R(5)], LSE;
+]
+"caspa" = [
+    0b00001000_01100000_01111100_00000000 = [W, W, W, W, RefBase] => [REven(16), RNext, REven(0), RNext, R(5)], LSE;
+    0b01001000_01100000_01111100_00000000 = [X, X, X, X, RefBase] => [REven(16), RNext, REven(0), RNext, R(5)], LSE;
+]
+"caspal" = [
+    0b00001000_01100000_11111100_00000000 = [W, W, W, W, RefBase] => [REven(16), RNext, REven(0), RNext, R(5)], LSE;
+    0b01001000_01100000_11111100_00000000 = [X, X, X, X, RefBase] => [REven(16), RNext, REven(0), RNext, R(5)], LSE;
+]
+"caspl" = [
+    0b00001000_00100000_11111100_00000000 = [W, W, W, W, RefBase] => [REven(16), RNext, REven(0), RNext, R(5)], LSE;
+    0b01001000_00100000_11111100_00000000 = [X, X, X, X, RefBase] => [REven(16), RNext, REven(0), RNext, R(5)], LSE;
+]
+"cbnz" = [
+    0b00110101_00000000_00000000_00000000 = [W, Offset] => [R(0), Offset(BCOND)];
+    0b10110101_00000000_00000000_00000000 = [X, Offset] => [R(0), Offset(BCOND)];
+]
+"cbz" = [
+    0b00110100_00000000_00000000_00000000 = [W, Offset] => [R(0), Offset(BCOND)];
+    0b10110100_00000000_00000000_00000000 = [X, Offset] => [R(0), Offset(BCOND)];
+]
+"ccmn" = [
+    // CCMN (immediate)
+    0b00111010_01000000_00001000_00000000 = [W, Imm, Imm, Cond] => [R(5), Ubits(16, 5), Ubits(0, 4), Cond(12)];
+    0b10111010_01000000_00001000_00000000 = [X, Imm, Imm, Cond] => [R(5), Ubits(16, 5), Ubits(0, 4), Cond(12)];
+    // CCMN (register)
+    0b00111010_01000000_00000000_00000000 = [W, W, Imm, Cond] => [R(5), R(16), Ubits(0, 4), Cond(12)];
+    0b10111010_01000000_00000000_00000000 = [X, X, Imm, Cond] => [R(5), R(16), Ubits(0, 4), Cond(12)];
+]
+"ccmp" = [
+    // CCMP (immediate)
+    0b01111010_01000000_00001000_00000000 = [W, Imm, Imm, Cond] => [R(5), Ubits(16, 5), Ubits(0, 4), Cond(12)];
+    0b11111010_01000000_00001000_00000000 = [X, Imm, Imm, Cond] => [R(5), Ubits(16, 5), Ubits(0, 4), Cond(12)];
+    // CCMP (register)
+    0b01111010_01000000_00000000_00000000 = [W, W, Imm, Cond] => [R(5), R(16), Ubits(0, 4), Cond(12)];
+    0b11111010_01000000_00000000_00000000 = [X, X, Imm, Cond] => [R(5), R(16), Ubits(0, 4), Cond(12)];
+]
+"cfinv" = [
+    0b11010101_00000000_01000000_00011111 = [] => [], FLAGM;
+]
+"cfp" = [
+    0b11010101_00001011_01110011_10000000 = [Lit("rctx"), X] => [R(0)], PREDRES;
+]
+"cinc" = [
+    0b00011010_10000000_00000100_00000000 = [W, W, Cond] => [R(0), R(5), C, R(16), CondInv(12)];
+    0b10011010_10000000_00000100_00000000 = [X, X, Cond] => [R(0), R(5), C, R(16), CondInv(12)];
+]
+"cinv" = [
+    0b01011010_10000000_00000000_00000000 = [W, W, Cond] => [R(0), R(5), C, R(16), CondInv(12)];
+    0b11011010_10000000_00000000_00000000 = [X, X, Cond] => [R(0), R(5), C, R(16), CondInv(12)];
+]
+"clasta" = [
+    // CLASTA (scalar)
+    0b00000101_00110000_10100000_00000000 = [W, PBare, W, Z(BYTE)] => [R(0), R3(10), RTied, R(5)], SVE;
+    0b00000101_01110000_10100000_00000000 = [W, PBare, W, Z(WORD)] => [R(0), R3(10), RTied, R(5)], SVE;
+    0b00000101_10110000_10100000_00000000 = [W, PBare, W, Z(DWORD)] => [R(0), R3(10), RTied, R(5)], SVE;
+    0b00000101_11110000_10100000_00000000 = [X, PBare, X, Z(QWORD)] => [R(0), R3(10), RTied, R(5)], SVE;
+    // CLASTA (SIMD&FP scalar)
+    0b00000101_00101010_10000000_00000000 = [B, PBare, B, Z(BYTE)] => [R(0), R3(10), RTied, R(5)], SVE;
+    0b00000101_01101010_10000000_00000000 = [H, PBare, H, Z(WORD)] => [R(0), R3(10), RTied, R(5)], SVE;
+    0b00000101_10101010_10000000_00000000 = [S, PBare, S, Z(DWORD)] => [R(0), R3(10), RTied, R(5)], SVE;
+    0b00000101_11101010_10000000_00000000 = [D, PBare, D, Z(QWORD)] => [R(0), R3(10), RTied, R(5)], SVE;
+    // CLASTA (vectors)
+    0b00000101_00101000_10000000_00000000 = [Z(BYTE), PBare, Z(BYTE), Z(BYTE)] => [R(0), R3(10), RTied, R(5)], SVE;
+    0b00000101_01101000_10000000_00000000 = [Z(WORD), PBare, Z(WORD), Z(WORD)] => [R(0), R3(10), RTied, R(5)], SVE;
+    0b00000101_10101000_10000000_00000000 = [Z(DWORD), PBare, Z(DWORD), Z(DWORD)] => [R(0), R3(10), RTied, R(5)], SVE;
+    0b00000101_11101000_10000000_00000000 = [Z(QWORD), PBare, Z(QWORD), Z(QWORD)] => [R(0), R3(10), RTied, R(5)], SVE;
+]
+"clastb" = [
+    // CLASTB (scalar)
+    0b00000101_00110001_10100000_00000000 = [W, PBare, W, Z(BYTE)] => [R(0), R3(10), RTied, R(5)], SVE;
+    0b00000101_01110001_10100000_00000000 = [W, PBare, W, Z(WORD)] => [R(0), R3(10), RTied, R(5)], SVE;
+    0b00000101_10110001_10100000_00000000 = [W, PBare, W, Z(DWORD)] => [R(0), R3(10), RTied, R(5)], SVE;
+    0b00000101_11110001_10100000_00000000 = [X, PBare, X, Z(QWORD)] => [R(0), R3(10), RTied, R(5)], SVE;
+    // CLASTB (SIMD&FP scalar)
+    0b00000101_00101011_10000000_00000000 = [B, PBare, B, Z(BYTE)] => [R(0), R3(10), RTied, R(5)], SVE;
+    0b00000101_01101011_10000000_00000000 = [H, PBare, H, Z(WORD)] => [R(0), R3(10), RTied, R(5)], SVE;
+    0b00000101_10101011_10000000_00000000 = [S, PBare, S, Z(DWORD)] => [R(0), R3(10), RTied, R(5)], SVE;
+    0b00000101_11101011_10000000_00000000 = [D, PBare, D, Z(QWORD)] => [R(0), R3(10), RTied, R(5)], SVE;
+    // CLASTB (vectors)
+    0b00000101_00101001_10000000_00000000 = [Z(BYTE), PBare, Z(BYTE), Z(BYTE)] => [R(0), R3(10), RTied, R(5)], SVE;
+    0b00000101_01101001_10000000_00000000 = [Z(WORD), PBare, Z(WORD), Z(WORD)] => [R(0), R3(10), RTied, R(5)], SVE;
+    0b00000101_10101001_10000000_00000000 = [Z(DWORD), PBare, Z(DWORD), Z(DWORD)] => [R(0), R3(10), RTied, R(5)], SVE;
+    0b00000101_11101001_10000000_00000000 = [Z(QWORD), PBare, Z(QWORD), Z(QWORD)] => [R(0), R3(10), RTied, R(5)], SVE;
+]
+"clrex" = [
+    0b11010101_00000011_00110000_01011111 = [Imm] => [Ubits(8, 4)];
+    0b11010101_00000011_00111111_01011111 = [] => [];
+]
+"cls" = [
+    // CLS (vector)
+    0b00001110_00100000_01001000_00000000 = [V(BYTE), V(BYTE)] => [R(0), R(5), Rwidth(30)];
+    0b00001110_01100000_01001000_00000000 = [V(WORD), V(WORD)] => [R(0), R(5), Rwidth(30)];
+    0b00001110_10100000_01001000_00000000 = [V(DWORD), V(DWORD)] => [R(0), R(5), Rwidth(30)];
+    // CLS
+    0b01011010_11000000_00010100_00000000 = [W, W] => [R(0), R(5)];
+    0b11011010_11000000_00010100_00000000 = [X, X] => [R(0), R(5)];
+    // CLS (predicated)
+    0b00000100_00011000_10100000_00000000 = [Z(BYTE), PMerge, Z(BYTE)] => [R(0), R3(10), R(5)], SVE;
+    0b00000100_01011000_10100000_00000000 = [Z(WORD), PMerge, Z(WORD)] => [R(0), R3(10), R(5)], SVE;
+    0b00000100_10011000_10100000_00000000 = [Z(DWORD), PMerge, Z(DWORD)] => [R(0), R3(10), R(5)], SVE;
+    0b00000100_11011000_10100000_00000000 = [Z(QWORD), PMerge, Z(QWORD)] => [R(0), R3(10), R(5)], SVE;
+]
+"clz" = [
+    // CLZ (vector)
+    0b00101110_00100000_01001000_00000000 = [V(BYTE), V(BYTE)] => [R(0), R(5), Rwidth(30)];
+    0b00101110_01100000_01001000_00000000 = [V(WORD), V(WORD)] => [R(0), R(5), Rwidth(30)];
+    0b00101110_10100000_01001000_00000000 = [V(DWORD), V(DWORD)] => [R(0), R(5), Rwidth(30)];
+    // CLZ
+    0b01011010_11000000_00010000_00000000 = [W, W] => [R(0), R(5)];
+    0b11011010_11000000_00010000_00000000 = [X, X] => [R(0), R(5)];
+    // CLZ (predicated)
+    0b00000100_00011001_10100000_00000000 = [Z(BYTE), PMerge, Z(BYTE)] => [R(0), R3(10), R(5)], SVE;
+    0b00000100_01011001_10100000_00000000 = [Z(WORD), PMerge, Z(WORD)] => [R(0), R3(10), R(5)], SVE;
+    0b00000100_10011001_10100000_00000000 = [Z(DWORD), PMerge, Z(DWORD)] => [R(0), R3(10), R(5)], SVE;
+    0b00000100_11011001_10100000_00000000 = [Z(QWORD), PMerge, Z(QWORD)] => [R(0), R3(10), R(5)], SVE;
+]
+"cmeq" = [
+    // CMEQ (register)
+    0b01111110_11100000_10001100_00000000 = [D, D, D] => [R(0), R(5), R(16)];
+    0b00101110_00100000_10001100_00000000 = [V(BYTE), V(BYTE), V(BYTE)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b00101110_01100000_10001100_00000000 = [V(WORD), V(WORD), V(WORD)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b00101110_10100000_10001100_00000000 = [V(DWORD), V(DWORD), V(DWORD)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b00101110_11100000_10001100_00000000 = [VStatic(QWORD, 2), VStatic(QWORD, 2), VStatic(QWORD, 2)] => [R(0), R(5), R(16), Rwidth(30)];
+    // CMEQ (zero)
+    0b01011110_11100000_10011000_00000000 = [D, D, LitInt(0)] => [R(0), R(5)];
+    0b00001110_00100000_10011000_00000000 = [V(BYTE), V(BYTE), LitInt(0)] => [R(0), R(5), Rwidth(30)];
+    0b00001110_01100000_10011000_00000000 = [V(WORD), V(WORD), LitInt(0)] => [R(0), R(5), Rwidth(30)];
+    0b00001110_10100000_10011000_00000000 = [V(DWORD), V(DWORD), LitInt(0)] => [R(0), R(5), Rwidth(30)];
+    0b00001110_11100000_10011000_00000000 = [VStatic(QWORD, 2), VStatic(QWORD, 2), LitInt(0)] => [R(0), R(5), Rwidth(30)];
+]
+"cmge" = [
+    // CMGE (register)
+    0b01011110_11100000_00111100_00000000 = [D, D, D] => [R(0), R(5), R(16)];
+    0b00001110_00100000_00111100_00000000 = [V(BYTE), V(BYTE), V(BYTE)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b00001110_01100000_00111100_00000000 = [V(WORD), V(WORD), V(WORD)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b00001110_10100000_00111100_00000000 = [V(DWORD), V(DWORD), V(DWORD)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b00001110_11100000_00111100_00000000 = [VStatic(QWORD, 2), VStatic(QWORD, 2), VStatic(QWORD, 2)] => [R(0), R(5), R(16), Rwidth(30)];
+    // CMGE (zero)
+    0b01111110_11100000_10001000_00000000 = [D, D, LitInt(0)] => [R(0), R(5)];
+    0b00101110_00100000_10001000_00000000 = [V(BYTE), V(BYTE), LitInt(0)] => [R(0), R(5), Rwidth(30)];
+    0b00101110_01100000_10001000_00000000 = [V(WORD), V(WORD), LitInt(0)] => [R(0), R(5), Rwidth(30)];
+    0b00101110_10100000_10001000_00000000 = [V(DWORD), V(DWORD), LitInt(0)] => [R(0), R(5), Rwidth(30)];
+    0b00101110_11100000_10001000_00000000 = [VStatic(QWORD, 2), VStatic(QWORD, 2), LitInt(0)] => [R(0), R(5), Rwidth(30)];
+]
+"cmgt" = [
+    // CMGT (register)
+    0b01011110_11100000_00110100_00000000 = [D, D, D] => [R(0), R(5), R(16)];
+    0b00001110_00100000_00110100_00000000 = [V(BYTE), V(BYTE), V(BYTE)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b00001110_01100000_00110100_00000000 = [V(WORD), V(WORD), V(WORD)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b00001110_10100000_00110100_00000000 = [V(DWORD), V(DWORD), V(DWORD)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b00001110_11100000_00110100_00000000 = [VStatic(QWORD, 2), VStatic(QWORD, 2), VStatic(QWORD, 2)] => [R(0), R(5), R(16), Rwidth(30)];
+    // CMGT (zero)
+    0b01011110_11100000_10001000_00000000 = [D, D, LitInt(0)] => [R(0), R(5)];
+    0b00001110_00100000_10001000_00000000 = [V(BYTE), V(BYTE), LitInt(0)] => [R(0), R(5), Rwidth(30)];
+    0b00001110_01100000_10001000_00000000 = [V(WORD), V(WORD), LitInt(0)] => [R(0), R(5), Rwidth(30)];
+    0b00001110_10100000_10001000_00000000 = [V(DWORD), V(DWORD), LitInt(0)] => [R(0), R(5), Rwidth(30)];
+    0b00001110_11100000_10001000_00000000 = [VStatic(QWORD, 2), VStatic(QWORD, 2), LitInt(0)] => [R(0), R(5), Rwidth(30)];
+]
+"cmhi" = [
+    0b01111110_11100000_00110100_00000000 = [D, D, D] => [R(0), R(5), R(16)];
+    0b00101110_00100000_00110100_00000000 = [V(BYTE), V(BYTE), V(BYTE)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b00101110_01100000_00110100_00000000 = [V(WORD), V(WORD), V(WORD)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b00101110_10100000_00110100_00000000 = [V(DWORD), V(DWORD), V(DWORD)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b00101110_11100000_00110100_00000000 = [VStatic(QWORD, 2), VStatic(QWORD, 2), VStatic(QWORD, 2)] => [R(0), R(5), R(16), Rwidth(30)];
+]
+"cmhs" = [
+    0b01111110_11100000_00111100_00000000 = [D, D, D] => [R(0), R(5), R(16)];
+    0b00101110_00100000_00111100_00000000 = [V(BYTE), V(BYTE), V(BYTE)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b00101110_01100000_00111100_00000000 = [V(WORD), V(WORD), V(WORD)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b00101110_10100000_00111100_00000000 = [V(DWORD), V(DWORD), V(DWORD)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b00101110_11100000_00111100_00000000 = [VStatic(QWORD, 2), VStatic(QWORD, 2), VStatic(QWORD, 2)] => [R(0), R(5), R(16), Rwidth(30)];
+]
+"cmle" = [
+    0b01111110_11100000_10011000_00000000 = [D, D, LitInt(0)] => [R(0), R(5)];
+    0b00101110_00100000_10011000_00000000 = [V(BYTE), V(BYTE), LitInt(0)] => [R(0), R(5), Rwidth(30)];
+    0b00101110_01100000_10011000_00000000 = [V(WORD), V(WORD), LitInt(0)] => [R(0), R(5), Rwidth(30)];
+    0b00101110_10100000_10011000_00000000 = [V(DWORD), V(DWORD), LitInt(0)] => [R(0), R(5), Rwidth(30)];
+    0b00101110_11100000_10011000_00000000 = [VStatic(QWORD, 2), VStatic(QWORD, 2), LitInt(0)] => [R(0), R(5), Rwidth(30)];
+]
+"cmlt" = [
+    0b01011110_11100000_10101000_00000000 = [D, D, LitInt(0)] => [R(0), R(5)];
+    0b00001110_00100000_10101000_00000000 = [V(BYTE), V(BYTE), LitInt(0)] => [R(0), R(5), Rwidth(30)];
+    0b00001110_01100000_10101000_00000000 = [V(WORD), V(WORD), LitInt(0)] => [R(0), R(5), Rwidth(30)];
+    0b00001110_10100000_10101000_00000000 = [V(DWORD), V(DWORD), LitInt(0)] => [R(0), R(5), Rwidth(30)];
+    0b00001110_11100000_10101000_00000000 = [VStatic(QWORD, 2), VStatic(QWORD, 2), LitInt(0)] => [R(0), R(5), Rwidth(30)];
+]
+"cmn" = [
+    // CMN (shifted register)
+    0b00101011_00000000_00000000_00011111 = [W, W, End, Mod(SHIFTS)] => [R(5), R(16), Rotates(22), Ubits(10, 5)];
+    0b10101011_00000000_00000000_00011111 = [X, X, End, Mod(SHIFTS)] => [R(5), R(16), Rotates(22), Ubits(10, 6)];
+    // CMN (extended register)
+    0b00101011_00100000_00000000_00011111 = [WSP, W, End, Mod(EXTENDS)] => [R(5), R(16), ExtendsW(13), Urange(10, 0, 4)];
+    0b10101011_00100000_00000000_00011111 = [XSP, W, Mod(EXTENDS_W)] => [R(5), R(16), ExtendsX(13), Urange(10, 0, 4)];
+    0b10101011_00100000_00000000_00011111 = [XSP, X, End, Mod(EXTENDS_X)] => [R(5), R(16), ExtendsX(13), Urange(10, 0, 4)];
+    // CMN (immediate)
+    0b00110001_00000000_00000000_00011111 = [WSP, Imm, End, LitMod(LSL)] => [R(5), Ubits(10, 12), Ulist(22, &[0, 12])];
+    0b10110001_00000000_00000000_00011111 = [XSP, Imm, End, LitMod(LSL)] => [R(5), Ubits(10, 12), Ulist(22, &[0, 12])];
+]
+"cmp" = [
+    // CMP (shifted register)
+    0b01101011_00000000_00000000_00011111 = [W, W, End, Mod(SHIFTS)] => [R(5), R(16), Rotates(22), Ubits(10, 5)];
+    0b11101011_00000000_00000000_00011111 = [X, X, End, Mod(SHIFTS)] => [R(5), R(16), Rotates(22), Ubits(10, 6)];
+    // CMP (extended register)
+    0b01101011_00100000_00000000_00011111 = [WSP, W, End, Mod(EXTENDS)] => [R(5), R(16), ExtendsW(13), Urange(10, 0, 4)];
+    0b11101011_00100000_00000000_00011111 = [XSP, W, Mod(EXTENDS_W)] => [R(5), R(16), ExtendsX(13), Urange(10, 0, 4)];
+    0b11101011_00100000_00000000_00011111 = [XSP, X, End, Mod(EXTENDS_X)] => [R(5), R(16), ExtendsX(13), Urange(10, 0, 4)];
+    // CMP (immediate)
+    0b01110001_00000000_00000000_00011111 = [WSP, Imm, End, LitMod(LSL)] => [R(5), Ubits(10, 12), Ulist(22, &[0, 12])];
+    0b11110001_00000000_00000000_00011111 = [XSP, Imm, End, LitMod(LSL)] => [R(5), Ubits(10, 12), Ulist(22, &[0, 12])];
+]
+"cmpeq" = [
+    // CMPEQ (immediate)
+    0b00100101_00000000_10000000_00000000 = [P(BYTE), PZero, Z(BYTE), Imm] => [R4(0), R3(10), R(5), Sbits(16, 5)], SVE;
+    0b00100101_01000000_10000000_00000000 = [P(WORD), PZero, Z(WORD), Imm] => [R4(0), R3(10), R(5), Sbits(16, 5)], SVE;
+    0b00100101_10000000_10000000_00000000 = [P(DWORD), PZero, Z(DWORD), Imm] => [R4(0), R3(10), R(5), Sbits(16, 5)], SVE;
+    0b00100101_11000000_10000000_00000000 = [P(QWORD), PZero, Z(QWORD), Imm] => [R4(0), R3(10), R(5), Sbits(16, 5)], SVE;
+    // CMPEQ (vectors)
+    0b00100100_00000000_10100000_00000000 = [P(BYTE), PZero, Z(BYTE), Z(BYTE)] => [R4(0), R3(10), R(5), R(16)], SVE;
+    0b00100100_01000000_10100000_00000000 = [P(WORD), PZero, Z(WORD), Z(WORD)] => [R4(0), R3(10), R(5), R(16)], SVE;
+    0b00100100_10000000_10100000_00000000 = [P(DWORD), PZero, Z(DWORD), Z(DWORD)] => [R4(0), R3(10), R(5), R(16)], SVE;
+    0b00100100_11000000_10100000_00000000 = [P(QWORD), PZero, Z(QWORD), Z(QWORD)] => [R4(0), R3(10), R(5), R(16)], SVE;
+    // CMPEQ (wide elements)
+    0b00100100_00000000_00100000_00000000 = [P(BYTE), PZero, Z(BYTE), Z(QWORD)] => [R4(0), R3(10), R(5), R(16)], SVE;
+    0b00100100_01000000_00100000_00000000 = [P(WORD), PZero, Z(WORD), Z(QWORD)] => [R4(0), R3(10), R(5), R(16)], SVE;
+    0b00100100_10000000_00100000_00000000 = [P(DWORD), PZero, Z(DWORD), Z(QWORD)] => [R4(0), R3(10), R(5), R(16)], SVE;
+]
+"cmpge" = [
+    // CMPGE (immediate)
+    0b00100101_00000000_00000000_00000000 = [P(BYTE), PZero, Z(BYTE), Imm] => [R4(0), R3(10), R(5), Sbits(16, 5)], SVE;
+    0b00100101_01000000_00000000_00000000 = [P(WORD), PZero, Z(WORD), Imm] => [R4(0), R3(10), R(5), Sbits(16, 5)], SVE;
+    0b00100101_10000000_00000000_00000000 = [P(DWORD), PZero, Z(DWORD), Imm] => [R4(0), R3(10), R(5), Sbits(16, 5)], SVE;
+    0b00100101_11000000_00000000_00000000 = [P(QWORD), PZero, Z(QWORD), Imm] => [R4(0), R3(10), R(5), Sbits(16, 5)], SVE;
+    // CMPGE (vectors)
+    0b00100100_00000000_10000000_00000000 = [P(BYTE), PZero, Z(BYTE), Z(BYTE)] => [R4(0), R3(10), R(5), R(16)], SVE;
+    0b00100100_01000000_10000000_00000000 = [P(WORD), PZero, Z(WORD), Z(WORD)] => [R4(0), R3(10), R(5), R(16)], SVE;
+    0b00100100_10000000_10000000_00000000 = [P(DWORD), PZero, Z(DWORD), Z(DWORD)] => [R4(0), R3(10), R(5), R(16)], SVE;
+    0b00100100_11000000_10000000_00000000 = [P(QWORD), PZero, Z(QWORD), Z(QWORD)] => [R4(0), R3(10), R(5), R(16)], SVE;
+    // CMPGE (wide elements)
+    0b00100100_00000000_01000000_00000000 = [P(BYTE), PZero, Z(BYTE), Z(QWORD)] => [R4(0), R3(10), R(5), R(16)], SVE;
+    0b00100100_01000000_01000000_00000000 = [P(WORD), PZero, Z(WORD), Z(QWORD)] => [R4(0), R3(10), R(5), R(16)], SVE;
+    0b00100100_10000000_01000000_00000000 = [P(DWORD), PZero, Z(DWORD), Z(QWORD)] => [R4(0), R3(10), R(5), R(16)], SVE;
+]
+"cmpgt" = [
+    // CMPGT (immediate)
+    0b00100101_00000000_00000000_00010000 = [P(BYTE), PZero, Z(BYTE), Imm] => [R4(0), R3(10), R(5), Sbits(16, 5)], SVE;
+    0b00100101_01000000_00000000_00010000 = [P(WORD), PZero, Z(WORD), Imm] => [R4(0), R3(10), R(5), Sbits(16, 5)], SVE;
+    0b00100101_10000000_00000000_00010000 = [P(DWORD), PZero, Z(DWORD), Imm] => [R4(0), R3(10), R(5), Sbits(16, 5)], SVE;
+    0b00100101_11000000_00000000_00010000 = [P(QWORD), PZero, Z(QWORD), Imm] => [R4(0), R3(10), R(5), Sbits(16, 5)], SVE;
+    // CMPGT (vectors)
+    0b00100100_00000000_10000000_00010000 = [P(BYTE), PZero, Z(BYTE), Z(BYTE)] => [R4(0), R3(10), R(5), R(16)], SVE;
+    0b00100100_01000000_10000000_00010000 = [P(WORD), PZero, Z(WORD), Z(WORD)] => [R4(0), R3(10), R(5), R(16)], SVE;
+    0b00100100_10000000_10000000_00010000 = [P(DWORD), PZero, Z(DWORD), Z(DWORD)] => [R4(0), R3(10), R(5), R(16)], SVE;
+    0b00100100_11000000_10000000_00010000 = [P(QWORD), PZero, Z(QWORD), Z(QWORD)] => [R4(0), R3(10), R(5), R(16)], SVE;
+    // CMPGT (wide elements)
+    0b00100100_00000000_01000000_00010000 = [P(BYTE), PZero, Z(BYTE), Z(QWORD)] => [R4(0), R3(10), R(5), R(16)], SVE;
+    0b00100100_01000000_01000000_00010000 = [P(WORD), PZero, Z(WORD), Z(QWORD)] => [R4(0), R3(10), R(5), R(16)], SVE;
+    0b00100100_10000000_01000000_00010000 = [P(DWORD), PZero, Z(DWORD), Z(QWORD)] => [R4(0), R3(10), R(5), R(16)], SVE;
+]
+"cmphi" = [
+    // CMPHI (immediate)
+    0b00100100_00100000_00000000_00010000 = [P(BYTE), PZero, Z(BYTE), Imm] => [R4(0), R3(10), R(5), Ubits(14, 7)], SVE;
+    0b00100100_01100000_00000000_00010000 = [P(WORD), PZero, Z(WORD), Imm] => [R4(0), R3(10), R(5), Ubits(14, 7)], SVE;
+    0b00100100_10100000_00000000_00010000 = [P(DWORD), PZero, Z(DWORD), Imm] => [R4(0), R3(10), R(5), Ubits(14, 7)], SVE;
+    0b00100100_11100000_00000000_00010000 = [P(QWORD), PZero, Z(QWORD), Imm] => [R4(0), R3(10), R(5), Ubits(14, 7)], SVE;
+    // CMPHI (vectors)
+    0b00100100_00000000_00000000_00010000 = [P(BYTE), PZero, Z(BYTE), Z(BYTE)] => [R4(0), R3(10), R(5), R(16)], SVE;
+    0b00100100_01000000_00000000_00010000 = [P(WORD), PZero, Z(WORD), Z(WORD)] => [R4(0), R3(10), R(5), R(16)], SVE;
+    0b00100100_10000000_00000000_00010000 = [P(DWORD), PZero, Z(DWORD), Z(DWORD)] => [R4(0), R3(10), R(5), R(16)], SVE;
+    0b00100100_11000000_00000000_00010000 = [P(QWORD), PZero, Z(QWORD), Z(QWORD)] => [R4(0), R3(10), R(5), R(16)], SVE;
+    // CMPHI (wide elements)
+    0b00100100_00000000_11000000_00010000 = [P(BYTE), PZero, Z(BYTE), Z(QWORD)] => [R4(0), R3(10), R(5), R(16)], SVE;
+    0b00100100_01000000_11000000_00010000 = [P(WORD), PZero, Z(WORD), Z(QWORD)] => [R4(0), R3(10), R(5), R(16)], SVE;
+    0b00100100_10000000_11000000_00010000 = [P(DWORD), PZero, Z(DWORD), Z(QWORD)] => [R4(0), R3(10), R(5), R(16)], SVE;
+]
+"cmphs" = [
+    // CMPHS (immediate)
+    0b00100100_00100000_00000000_00000000 = [P(BYTE), PZero, Z(BYTE), Imm] => [R4(0), R3(10), R(5), Ubits(14, 7)], SVE;
+    0b00100100_01100000_00000000_00000000 = [P(WORD), PZero, Z(WORD), Imm] => [R4(0), R3(10), R(5), Ubits(14, 7)], SVE;
+    0b00100100_10100000_00000000_00000000 = [P(DWORD), PZero, Z(DWORD), Imm] => [R4(0), R3(10), R(5), Ubits(14, 7)], SVE;
+    0b00100100_11100000_00000000_00000000 = [P(QWORD), PZero, Z(QWORD), Imm] => [R4(0), R3(10), R(5), Ubits(14, 7)], SVE;
+    // CMPHS (vectors)
+    0b00100100_00000000_00000000_00000000 = [P(BYTE), PZero, Z(BYTE), Z(BYTE)] => [R4(0), R3(10), R(5), R(16)], SVE;
+    0b00100100_01000000_00000000_00000000 = [P(WORD), PZero, Z(WORD), Z(WORD)] => [R4(0), R3(10), R(5), R(16)], SVE;
+    0b00100100_10000000_00000000_00000000 = [P(DWORD), PZero, Z(DWORD), Z(DWORD)] => [R4(0), R3(10), R(5), R(16)], SVE;
+    0b00100100_11000000_00000000_00000000 = [P(QWORD), PZero, Z(QWORD), Z(QWORD)] => [R4(0), R3(10), R(5), R(16)], SVE;
+    // CMPHS (wide elements)
+    0b00100100_00000000_11000000_00000000 = [P(BYTE), PZero, Z(BYTE), Z(QWORD)] => [R4(0), R3(10), R(5), R(16)], SVE;
+    0b00100100_01000000_11000000_00000000 = [P(WORD), PZero, Z(WORD), Z(QWORD)] => [R4(0), R3(10), R(5), R(16)], SVE;
+    0b00100100_10000000_11000000_00000000 = [P(DWORD), PZero, Z(DWORD), Z(QWORD)] => [R4(0), R3(10), R(5), R(16)], SVE;
+]
+"cmple" = [
+    // CMPLE (vectors)
+    0b00100100_00000000_10000000_00000000 = [P(BYTE), PZero, Z(BYTE), Z(BYTE)] => [R4(0), R3(10), R(16), R(5)], SVE;
+    0b00100100_01000000_10000000_00000000 = [P(WORD), PZero, Z(WORD), Z(WORD)] => [R4(0), R3(10), R(16), R(5)], SVE;
+    0b00100100_10000000_10000000_00000000 = [P(DWORD), PZero, Z(DWORD), Z(DWORD)] => [R4(0), R3(10), R(16), R(5)], SVE;
+    0b00100100_11000000_10000000_00000000 = [P(QWORD), PZero, Z(QWORD), Z(QWORD)] => [R4(0), R3(10), R(16), R(5)], SVE;
+    // CMPLE (immediate)
+    0b00100101_00000000_00100000_00010000 = [P(BYTE), PZero, Z(BYTE), Imm] => [R4(0), R3(10), R(5), Sbits(16, 5)], SVE;
+    0b00100101_01000000_00100000_00010000 = [P(WORD), PZero, Z(WORD), Imm] => [R4(0), R3(10), R(5), Sbits(16, 5)], SVE;
+    0b00100101_10000000_00100000_00010000 = [P(DWORD), PZero, Z(DWORD), Imm] => [R4(0), R3(10), R(5), Sbits(16, 5)], SVE;
+    0b00100101_11000000_00100000_00010000 = [P(QWORD), PZero, Z(QWORD), Imm] => [R4(0), R3(10), R(5), Sbits(16, 5)], SVE;
+    // CMPLE (wide elements)
+    0b00100100_00000000_01100000_00010000 = [P(BYTE), PZero, Z(BYTE), Z(QWORD)] => [R4(0), R3(10), R(5), R(16)], SVE;
+    0b00100100_01000000_01100000_00010000 = [P(WORD), PZero, Z(WORD), Z(QWORD)] => [R4(0), R3(10), R(5), R(16)], SVE;
+    0b00100100_10000000_01100000_00010000 = [P(DWORD), PZero, Z(DWORD), Z(QWORD)] => [R4(0), R3(10), R(5), R(16)], SVE;
+]
+"cmplo" = [
+    // CMPLO (vectors)
+    0b00100100_00000000_00000000_00010000 = [P(BYTE), PZero, Z(BYTE), Z(BYTE)] => [R4(0), R3(10), R(16), R(5)], SVE;
+    0b00100100_01000000_00000000_00010000 = [P(WORD), PZero, Z(WORD), Z(WORD)] => [R4(0), R3(10), R(16), R(5)], SVE;
+    0b00100100_10000000_00000000_00010000 = [P(DWORD), PZero, Z(DWORD), Z(DWORD)] => [R4(0), R3(10), R(16), R(5)], SVE;
+    0b00100100_11000000_00000000_00010000 = [P(QWORD), PZero, Z(QWORD), Z(QWORD)] => [R4(0), R3(10), R(16), R(5)], SVE;
+    // CMPLO (immediate)
+    0b00100100_00100000_00100000_00000000 = [P(BYTE), PZero, Z(BYTE), Imm] => [R4(0), R3(10), R(5), Ubits(14, 7)], SVE;
+    0b00100100_01100000_00100000_00000000 = [P(WORD), PZero, Z(WORD), Imm] => [R4(0), R3(10), R(5), Ubits(14, 7)], SVE;
+    0b00100100_10100000_00100000_00000000 = [P(DWORD), PZero, Z(DWORD), Imm] => [R4(0), R3(10), R(5), Ubits(14, 7)], SVE;
+    0b00100100_11100000_00100000_00000000 = [P(QWORD), PZero, Z(QWORD), Imm] => [R4(0), R3(10), R(5), Ubits(14, 7)], SVE;
+    // CMPLO (wide elements)
+    0b00100100_00000000_11100000_00000000 = [P(BYTE), PZero, Z(BYTE), Z(QWORD)] => [R4(0), R3(10), R(5), R(16)], SVE;
+    0b00100100_01000000_11100000_00000000 = [P(WORD), PZero, Z(WORD), Z(QWORD)] => [R4(0), R3(10), R(5), R(16)], SVE;
+    0b00100100_10000000_11100000_00000000 = [P(DWORD), PZero, Z(DWORD), Z(QWORD)] => [R4(0), R3(10), R(5), R(16)], SVE;
+]
+"cmpls" = [
+    // CMPLS (vectors)
+    0b00100100_00000000_00000000_00000000 = [P(BYTE), PZero, Z(BYTE), Z(BYTE)] => [R4(0), R3(10), R(16), R(5)], SVE;
+    0b00100100_01000000_00000000_00000000 = [P(WORD), PZero, Z(WORD), Z(WORD)] => [R4(0), R3(10), R(16), R(5)], SVE;
+    0b00100100_10000000_00000000_00000000 = [P(DWORD), PZero, Z(DWORD), Z(DWORD)] => [R4(0), R3(10), R(16), R(5)], SVE;
+    0b00100100_11000000_00000000_00000000 = [P(QWORD), PZero, Z(QWORD), Z(QWORD)] => [R4(0), R3(10), R(16), R(5)], SVE;
+    // CMPLS (immediate)
+    0b00100100_00100000_00100000_00010000 = [P(BYTE), PZero, Z(BYTE), Imm] => [R4(0), R3(10), R(5), Ubits(14, 7)], SVE;
+    0b00100100_01100000_00100000_00010000 = [P(WORD), PZero, Z(WORD), Imm] => [R4(0), R3(10), R(5), Ubits(14, 7)], SVE;
+    0b00100100_10100000_00100000_00010000 = [P(DWORD), PZero, Z(DWORD), Imm] => [R4(0), R3(10), R(5), Ubits(14, 7)], SVE;
+    0b00100100_11100000_00100000_00010000 = [P(QWORD), PZero, Z(QWORD), Imm] => [R4(0), R3(10), R(5), Ubits(14, 7)], SVE;
+    // CMPLS (wide elements)
+    0b00100100_00000000_11100000_00010000 = [P(BYTE), PZero, Z(BYTE), Z(QWORD)] => [R4(0), R3(10), R(5), R(16)], SVE;
+    0b00100100_01000000_11100000_00010000 = [P(WORD), PZero, Z(WORD), Z(QWORD)] => [R4(0), R3(10), R(5), R(16)], SVE;
+    0b00100100_10000000_11100000_00010000 = [P(DWORD), PZero, Z(DWORD), Z(QWORD)] => [R4(0), R3(10), R(5), R(16)], SVE;
+]
+"cmplt" = [
+    // CMPLT (vectors)
+    0b00100100_00000000_10000000_00010000 = [P(BYTE), PZero, Z(BYTE), Z(BYTE)] => [R4(0), R3(10), R(16), R(5)], SVE;
+    0b00100100_01000000_10000000_00010000 = [P(WORD), PZero, Z(WORD), Z(WORD)] => [R4(0), R3(10), R(16), R(5)], SVE;
+    0b00100100_10000000_10000000_00010000 = [P(DWORD), PZero, Z(DWORD), Z(DWORD)] => [R4(0), R3(10), R(16), R(5)], SVE;
+    0b00100100_11000000_10000000_00010000 = [P(QWORD), PZero, Z(QWORD), Z(QWORD)] => [R4(0), R3(10), R(16), R(5)], SVE;
+    // CMPLT (immediate)
+    0b00100101_00000000_00100000_00000000 = [P(BYTE), PZero, Z(BYTE), Imm] => [R4(0), R3(10), R(5), Sbits(16, 5)], SVE;
+    0b00100101_01000000_00100000_00000000 = [P(WORD), PZero, Z(WORD), Imm] => [R4(0), R3(10), R(5), Sbits(16, 5)], SVE;
+    0b00100101_10000000_00100000_00000000 = [P(DWORD), PZero, Z(DWORD), Imm] => [R4(0), R3(10), R(5), Sbits(16, 5)], SVE;
+    0b00100101_11000000_00100000_00000000 = [P(QWORD), PZero, Z(QWORD), Imm] => [R4(0), R3(10), R(5), Sbits(16, 5)], SVE;
+    // CMPLT (wide elements)
+    0b00100100_00000000_01100000_00000000 = [P(BYTE), PZero, Z(BYTE), Z(QWORD)] => [R4(0), R3(10), R(5), R(16)], SVE;
+    0b00100100_01000000_01100000_00000000 = [P(WORD), PZero, Z(WORD), Z(QWORD)] => [R4(0), R3(10), R(5), R(16)], SVE;
+    0b00100100_10000000_01100000_00000000 = [P(DWORD), PZero, Z(DWORD), Z(QWORD)] => [R4(0), R3(10), R(5), R(16)], SVE;
+]
+"cmpne" = [
+    // CMPNE (immediate)
+    0b00100101_00000000_10000000_00010000 = [P(BYTE), PZero, Z(BYTE), Imm] => [R4(0), R3(10), R(5), Sbits(16, 5)], SVE;
+    0b00100101_01000000_10000000_00010000 = [P(WORD), PZero, Z(WORD), Imm] => [R4(0), R3(10), R(5), Sbits(16, 5)], SVE;
+    0b00100101_10000000_10000000_00010000 = [P(DWORD), PZero, Z(DWORD), Imm] => [R4(0), R3(10), R(5), Sbits(16, 5)], SVE;
+    0b00100101_11000000_10000000_00010000 = [P(QWORD), PZero, Z(QWORD), Imm] => [R4(0), R3(10), R(5), Sbits(16, 5)], SVE;
+    // CMPNE (vectors)
+    0b00100100_00000000_10100000_00010000 = [P(BYTE), PZero, Z(BYTE), Z(BYTE)] => [R4(0), R3(10), R(5), R(16)], SVE;
+    0b00100100_01000000_10100000_00010000 = [P(WORD), PZero, Z(WORD), Z(WORD)] => [R4(0), R3(10), R(5), R(16)], SVE;
+    0b00100100_10000000_10100000_00010000 = [P(DWORD), PZero, Z(DWORD), Z(DWORD)] => [R4(0), R3(10), R(5), R(16)], SVE;
+    0b00100100_11000000_10100000_00010000 = [P(QWORD), PZero, Z(QWORD), Z(QWORD)] => [R4(0), R3(10), R(5), R(16)], SVE;
+    // CMPNE (wide elements)
+    0b00100100_00000000_00100000_00010000 = [P(BYTE), PZero, Z(BYTE), Z(QWORD)] => [R4(0), R3(10), R(5), R(16)], SVE;
+    0b00100100_01000000_00100000_00010000 = [P(WORD), PZero, Z(WORD), Z(QWORD)] => [R4(0), R3(10), R(5), R(16)], SVE;
+    0b00100100_10000000_00100000_00010000 = [P(DWORD), PZero, Z(DWORD), Z(QWORD)] => [R4(0), R3(10), R(5), R(16)], SVE;
+]
+"cmpp" = [
+    0b10111010_11000000_00000000_00011111 = [XSP, XSP] => [R(5), R(16)], MTE;
+]
+"cmtst" = [
+    0b01011110_11100000_10001100_00000000 = [D, D, D] => [R(0), R(5), R(16)];
+    0b00001110_00100000_10001100_00000000 = [V(BYTE), V(BYTE), V(BYTE)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b00001110_01100000_10001100_00000000 = [V(WORD), V(WORD), V(WORD)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b00001110_10100000_10001100_00000000 = [V(DWORD), V(DWORD), V(DWORD)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b00001110_11100000_10001100_00000000 = [VStatic(QWORD, 2), VStatic(QWORD, 2), VStatic(QWORD, 2)] => [R(0), R(5), R(16), Rwidth(30)];
+]
+"cneg" = [
+    0b01011010_10000000_00000100_00000000 = [W, W, Cond] => [R(0), R(5), C, R(16), CondInv(12)];
+    0b11011010_10000000_00000100_00000000 = [X, X, Cond] => [R(0), R(5), C, R(16), CondInv(12)];
+]
+"cnot" = [
+    0b00000100_00011011_10100000_00000000 = [Z(BYTE), PMerge, Z(BYTE)] => [R(0), R3(10), R(5)], SVE;
+    0b00000100_01011011_10100000_00000000 = [Z(WORD), PMerge, Z(WORD)] => [R(0), R3(10), R(5)], SVE;
+    0b00000100_10011011_10100000_00000000 = [Z(DWORD), PMerge, Z(DWORD)] => [R(0), R3(10), R(5)], SVE;
+    0b00000100_11011011_10100000_00000000 = [Z(QWORD), PMerge, Z(QWORD)] => [R(0), R3(10), R(5)], SVE;
+]
+"cnt" = [
+    // CNT
+    0b00001110_00100000_01011000_00000000 = [V(BYTE), V(BYTE)] => [R(0), R(5), Rwidth(30)];
+    // CNT (predicated)
+    0b00000100_00011010_10100000_00000000 = [Z(BYTE), PMerge, Z(BYTE)] => [R(0), R3(10), R(5)], SVE;
+    0b00000100_01011010_10100000_00000000 = [Z(WORD), PMerge, Z(WORD)] => [R(0), R3(10), R(5)], SVE;
+    0b00000100_10011010_10100000_00000000 = [Z(DWORD), PMerge, Z(DWORD)] => [R(0), R3(10), R(5)], SVE;
+    0b00000100_11011010_10100000_00000000 = [Z(QWORD), PMerge, Z(QWORD)] => [R(0), R3(10), R(5)], SVE;
+]
+"cntb" = [
+    0b00000100_00100000_11100011_11100000 = [X] => [R(0)], SVE;
+    0b00000100_00100000_11100000_00000000 = [X, Ident, End, LitMod(MUL)] => [R(0), LitList(5, "SVE_PATTERNS"), Urange(16, 1, 16)], SVE;
+]
+"cntd" = [
+    0b00000100_11100000_11100011_11100000 = [X] => [R(0)], SVE;
+    0b00000100_11100000_11100000_00000000 = [X, Ident, End, LitMod(MUL)] => [R(0), LitList(5, "SVE_PATTERNS"), Urange(16, 1, 16)], SVE;
+]
+"cnth" = [
+    0b00000100_01100000_11100011_11100000 = [X] => [R(0)], SVE;
+    0b00000100_01100000_11100000_00000000 = [X, Ident, End, LitMod(MUL)] => [R(0), LitList(5, "SVE_PATTERNS"), Urange(16, 1, 16)], SVE;
+]
+"cntp" = [
+    0b00100101_00100000_10000000_00000000 = [X, PBare, P(BYTE)] => [R(0), R4(10), R4(5)], SVE;
+    0b00100101_01100000_10000000_00000000 = [X, PBare, P(WORD)] => [R(0), R4(10), R4(5)], SVE;
+    0b00100101_10100000_10000000_00000000 = [X, PBare, P(DWORD)] => [R(0), R4(10), R4(5)], SVE;
+    0b00100101_11100000_10000000_00000000 = [X, PBare, P(QWORD)] => [R(0), R4(10), R4(5)], SVE;
+]
+"cntw" = [
+    0b00000100_10100000_11100011_11100000 = [X] => [R(0)], SVE;
+    0b00000100_10100000_11100000_00000000 = [X, Ident, End, LitMod(MUL)] => [R(0), LitList(5, "SVE_PATTERNS"), Urange(16, 1, 16)], SVE;
+]
+"compact" = [
+    0b00000101_10100001_10000000_00000000 = [Z(DWORD), PBare, Z(DWORD)] => [R(0), R3(10), R(5)], SVE;
+    0b00000101_11100001_10000000_00000000 = [Z(QWORD), PBare, Z(QWORD)] => [R(0), R3(10), R(5)], SVE;
+]
+"cpp" = [
+    0b11010101_00001011_01110011_11100000 = [Lit("rctx"), X] => [R(0)], PREDRES;
+]
+"cpy" = [
+    // CPY (immediate)
+    0b00000101_00010000_00000000_00000000 = [Z(BYTE), PZero, Imm] => [R(0), R4(16), Sbits(5, 8)], SVE;
+    0b00000101_01010000_00000000_00000000 = [Z(WORD), PZero, Imm, End, LitMod(LSL)] => [R(0), R4(16), Sbits(5, 8), Ulist(13, &[0, 8])], SVE;
+    0b00000101_10010000_00000000_00000000 = [Z(DWORD), PZero, Imm, End, LitMod(LSL)] => [R(0), R4(16), Sbits(5, 8), Ulist(13, &[0, 8])], SVE;
+    0b00000101_11010000_00000000_00000000 = [Z(QWORD), PZero, Imm, End, LitMod(LSL)] => [R(0), R4(16), Sbits(5, 8), Ulist(13, &[0, 8])], SVE;
+    0b00000101_00010000_01000000_00000000 = [Z(BYTE), PMerge, Imm] => [R(0), R4(16), Sbits(5, 8)], SVE;
+    0b00000101_01010000_01000000_00000000 = [Z(WORD), PMerge, Imm, End, LitMod(LSL)] => [R(0), R4(16), Sbits(5, 8), Ulist(13, &[0, 8])], SVE;
+    0b00000101_10010000_01000000_00000000 = [Z(DWORD), PMerge, Imm, End, LitMod(LSL)] => [R(0), R4(16), Sbits(5, 8), Ulist(13, &[0, 8])], SVE;
+    0b00000101_11010000_01000000_00000000 = [Z(QWORD), PMerge, Imm, End, LitMod(LSL)] => [R(0), R4(16), Sbits(5, 8), Ulist(13, &[0, 8])], SVE;
+    // CPY (scalar)
+    0b00000101_00101000_10100000_00000000 = [Z(BYTE), PMerge, WSP] => [R(0), R3(10), R(5)], SVE;
+    0b00000101_01101000_10100000_00000000 = [Z(WORD), PMerge, WSP] => [R(0), R3(10), R(5)], SVE;
+    0b00000101_10101000_10100000_00000000 = [Z(DWORD), PMerge, WSP] => [R(0), R3(10), R(5)], SVE;
+    0b00000101_11101000_10100000_00000000 = [Z(QWORD), PMerge, XSP] => [R(0), R3(10), R(5)], SVE;
+    // CPY (SIMD&FP scalar)
+    0b00000101_00100000_10000000_00000000 = [Z(BYTE), PMerge, B] => [R(0), R3(10), R(5)], SVE;
+    0b00000101_01100000_10000000_00000000 = [Z(WORD), PMerge, H] => [R(0), R3(10), R(5)], SVE;
+    0b00000101_10100000_10000000_00000000 = [Z(DWORD), PMerge, S] => [R(0), R3(10), R(5)], SVE;
+    0b00000101_11100000_10000000_00000000 = [Z(QWORD), PMerge, D] => [R(0), R3(10), R(5)], SVE;
+]
+"crc32b" = [
+    0b00011010_11000000_01000000_00000000 = [W, W, W] => [R(0), R(5), R(16)], CRC;
+]
+"crc32cb" = [
+    0b00011010_11000000_01010000_00000000 = [W, W, W] => [R(0), R(5), R(16)], CRC;
+]
+"crc32ch" = [
+    0b00011010_11000000_01010100_00000000 = [W, W, W] => [R(0), R(5), R(16)], CRC;
+]
+"crc32cw" = [
+    0b00011010_11000000_01011000_00000000 = [W, W, W] => [R(0), R(5), R(16)], CRC;
+]
+"crc32cx" = [
+    0b10011010_11000000_01011100_00000000 = [W, W, X] => [R(0), R(5), R(16)], CRC;
+]
+"crc32h" = [
+    0b00011010_11000000_01000100_00000000 = [W, W, W] => [R(0), R(5), R(16)], CRC;
+]
+"crc32w" = [
+    0b00011010_11000000_01001000_00000000 = [W, W, W] => [R(0), R(5), R(16)], CRC;
+]
+"crc32x" = [
+    0b10011010_11000000_01001100_00000000 = [W, W, X] => [R(0), R(5), R(16)], CRC;
+]
+"csdb" = [
+    0b11010101_00000011_00100010_10011111 = [] => [];
+]
+"csel" = [
+    0b00011010_10000000_00000000_00000000 = [W, W, W, Cond] => [R(0), R(5), R(16), Cond(12)];
+    0b10011010_10000000_00000000_00000000 = [X, X, X, Cond] => [R(0), R(5), R(16), Cond(12)];
+]
+"cset" = [
+    0b00011010_10011111_00000111_11100000 = [W, Cond] => [R(0), CondInv(12)];
+    0b10011010_10011111_00000111_11100000 = [X, Cond] => [R(0), CondInv(12)];
+]
+"csetm" = [
+    0b01011010_10011111_00000011_11100000 = [W, Cond] => [R(0), CondInv(12)];
+    0b11011010_10011111_00000011_11100000 = [X, Cond] => [R(0), CondInv(12)];
+]
+"csinc" = [
+    0b00011010_10000000_00000100_00000000 = [W, W, W, Cond] => [R(0), R(5), R(16), Cond(12)];
+    0b10011010_10000000_00000100_00000000 = [X, X, X, Cond] => [R(0), R(5), R(16), Cond(12)];
+]
+"csinv" = [
+    0b01011010_10000000_00000000_00000000 = [W, W, W, Cond] => [R(0), R(5), R(16), Cond(12)];
+    0b11011010_10000000_00000000_00000000 = [X, X, X, Cond] => [R(0), R(5), R(16), Cond(12)];
+]
+"csneg" = [
+    0b01011010_10000000_00000100_00000000 = [W, W, W, Cond] => [R(0), R(5), R(16), Cond(12)];
+    0b11011010_10000000_00000100_00000000 = [X, X, X, Cond] => [R(0), R(5), R(16), Cond(12)];
+]
+"ctermeq" = [
+    0b00100101_10100000_00100000_00000000 = [W, W] => [R(5), R(16)], SVE;
+    0b00100101_11100000_00100000_00000000 = [X, X] => [R(5), R(16)], SVE;
+]
+"ctermne" = [
+    0b00100101_10100000_00100000_00010000 = [W, W] => [R(5), R(16)], SVE;
+    0b00100101_11100000_00100000_00010000 = [X, X] => [R(5), R(16)], SVE;
+]
+"dc" = [
+    0b11010101_00001000_01110000_00000000 = [Ident, X] => [LitList(5, "DC_OPS"), R(0)];
+]
+"dcps1" = [
+    0b11010100_10100000_00000000_00000001 = [End, Imm] => [Ubits(5, 16)];
+]
+"dcps2" = [
+    0b11010100_10100000_00000000_00000010 = [End, Imm] => [Ubits(5, 16)];
+]
+"dcps3" = [
+    0b11010100_10100000_00000000_00000011 = [End, Imm] => [Ubits(5, 16)];
+]
+"decb" = [
+    0b00000100_00110000_11100111_11100000 = [X] => [R(0)], SVE;
+    0b00000100_00110000_11100100_00000000 = [X, Ident, End, LitMod(MUL)] => [R(0), LitList(5, "SVE_PATTERNS"), Urange(16, 1, 16)], SVE;
+]
+"decd" = [
+    // DECD (scalar)
+    0b00000100_11110000_11100111_11100000 = [X] => [R(0)], SVE;
+    0b00000100_11110000_11100100_00000000 = [X, Ident, End, LitMod(MUL)] => [R(0), LitList(5, "SVE_PATTERNS"), Urange(16, 1, 16)], SVE;
+    // DECD (vector)
+    0b00000100_11110000_11000111_11100000 = [Z(QWORD)] => [R(0)], SVE;
+    0b00000100_11110000_11000100_00000000 = [Z(QWORD), Ident, End, LitMod(MUL)] => [R(0), LitList(5, "SVE_PATTERNS"), Urange(16, 1, 16)], SVE;
+]
+"dech" = [
+    // DECH (scalar)
+    0b00000100_01110000_11100111_11100000 = [X] => [R(0)], SVE;
+    0b00000100_01110000_11100100_00000000 = [X, Ident, End, LitMod(MUL)] => [R(0), LitList(5, "SVE_PATTERNS"), Urange(16, 1, 16)], SVE;
+    // DECH (vector)
+    0b00000100_01110000_11000111_11100000 = [Z(WORD)] => [R(0)], SVE;
+    0b00000100_01110000_11000100_00000000 = [Z(WORD), Ident, End, LitMod(MUL)] => [R(0), LitList(5, "SVE_PATTERNS"), Urange(16, 1, 16)], SVE;
+]
+"decp" = [
+    // DECP (scalar)
+    0b00100101_00101101_10001000_00000000 = [X, P(BYTE)] => [R(0), R4(5)], SVE;
+    0b00100101_01101101_10001000_00000000 = [X, P(WORD)] => [R(0), R4(5)], SVE;
+    0b00100101_10101101_10001000_00000000 = [X, P(DWORD)] => [R(0), R4(5)], SVE;
+    0b00100101_11101101_10001000_00000000 = [X, P(QWORD)] => [R(0), R4(5)], SVE;
+    // DECP (vector)
+    0b00100101_01101101_10000000_00000000 = [Z(WORD), PBare] => [R(0), R4(5)], SVE;
+    0b00100101_10101101_10000000_00000000 = [Z(DWORD), PBare] => [R(0), R4(5)], SVE;
+    0b00100101_11101101_10000000_00000000 = [Z(QWORD), PBare] => [R(0), R4(5)], SVE;
+]
+"decw" = [
+    // DECW (scalar)
+    0b00000100_10110000_11100111_11100000 = [X] => [R(0)], SVE;
+    0b00000100_10110000_11100100_00000000 = [X, Ident, End, LitMod(MUL)] => [R(0), LitList(5, "SVE_PATTERNS"), Urange(16, 1, 16)], SVE;
+    // DECW (vector)
+    0b00000100_10110000_11000111_11100000 = [Z(DWORD)] => [R(0)], SVE;
+    0b00000100_10110000_11000100_00000000 = [Z(DWORD), Ident, End, LitMod(MUL)] => [R(0), LitList(5, "SVE_PATTERNS"), Urange(16, 1, 16)], SVE;
+]
+"dmb" = [
+    0b11010101_00000011_00110000_10111111 = [Ident] => [LitList(8, "BARRIER_OPS")];
+    0b11010101_00000011_00110000_10111111 = [Imm] => [Ubits(8, 4)];
+]
+"drps" = [
+    0b11010110_10111111_00000011_11100000 = [] => [];
+]
+"dsb" = [
+    0b11010101_00000011_00110000_10011111 = [Ident] => [LitList(8, "BARRIER_OPS")];
+    0b11010101_00000011_00110000_10011111 = [Imm] => [Ubits(8, 4)];
+]
+"dup" = [
+    // DUP (element)
+    0b01011110_00000001_00000100_00000000 = [B, VElement(BYTE)] => [R(0), R(5), Ubits(17, 4)];
+    0b01011110_00000010_00000100_00000000 = [H, VElement(WORD)] => [R(0), R(5), Ubits(18, 3)];
+    0b01011110_00000100_00000100_00000000 = [S, VElement(DWORD)] => [R(0), R(5), Ubits(19, 2)];
+    0b01011110_00001000_00000100_00000000 = [D, VElement(QWORD)] => [R(0), R(5), Ubits(20, 1)];
+    0b00001110_00000001_00000100_00000000 = [V(BYTE), VElement(BYTE)] => [R(0), R(5), Ubits(17, 4), Rwidth(30)];
+    0b00001110_00000010_00000100_00000000 = [V(WORD), VElement(WORD)] => [R(0), R(5), Ubits(18, 3), Rwidth(30)];
+    0b00001110_00000100_00000100_00000000 = [V(DWORD), VElement(DWORD)] => [R(0), R(5), Ubits(19, 2), Rwidth(30)];
+    0b00001110_00001000_00000100_00000000 = [VStatic(QWORD, 2), VElement(QWORD)] => [R(0), R(5), Ubits(20, 1), Rwidth(30)];
+    // DUP (general)
+    0b00001110_00000001_00001100_00000000 = [V(BYTE), W] => [R(0), Rwidth(30), R(5)];
+    0b00001110_00000010_00001100_00000000 = [V(WORD), W] => [R(0), Rwidth(30), R(5)];
+    0b00001110_00000100_00001100_00000000 = [V(DWORD), W] => [R(0), Rwidth(30), R(5)];
+    0b00001110_00001000_00001100_00000000 = [VStatic(QWORD, 2), X] => [R(0), Rwidth(30), R(5)];
+    // DUP (immediate)
+    0b00100101_00111000_11000000_00000000 = [Z(BYTE), Imm] => [R(0), Sbits(5, 8)], SVE;
+    0b00100101_01111000_11000000_00000000 = [Z(WORD), Imm, End, LitMod(LSL)] => [R(0), Sbits(5, 8), Ulist(13, &[0, 8])], SVE;
+    0b00100101_10111000_11000000_00000000 = [Z(DWORD), Imm, End, LitMod(LSL)] => [R(0), Sbits(5, 8), Ulist(13, &[0, 8])], SVE;
+    0b00100101_11111000_11000000_00000000 = [Z(QWORD), Imm, End, LitMod(LSL)] => [R(0), Sbits(5, 8), Ulist(13, &[0, 8])], SVE;
+    // DUP (scalar)
+    0b00000101_00100000_00111000_00000000 = [Z(BYTE), WSP] => [R(0), R(5)], SVE;
+    0b00000101_01100000_00111000_00000000 = [Z(WORD), WSP] => [R(0), R(5)], SVE;
+    0b00000101_10100000_00111000_00000000 = [Z(DWORD), WSP] => [R(0), R(5)], SVE;
+    0b00000101_11100000_00111000_00000000 = [Z(QWORD), XSP] => [R(0), R(5)], SVE;
+    // DUP (indexed)
+    0b00000101_00100001_00100000_00000000 = [Z(BYTE), ZElement(BYTE)] => [R(0), R(5), BUbits(6), Uslice(17, 4, 0), Uslice(22, 2, 4), A], SVE;
+    0b00000101_00100010_00100000_00000000 = [Z(WORD), ZElement(WORD)] => [R(0), R(5), BUbits(5), Uslice(18, 3, 0), Uslice(22, 2, 3), A], SVE;
+    0b00000101_00100100_00100000_00000000 = [Z(DWORD), ZElement(DWORD)] => [R(0), R(5), BUbits(4), Uslice(19, 2, 0), Uslice(22, 2, 2), A], SVE;
+    0b00000101_00101000_00100000_00000000 = [Z(QWORD), ZElement(QWORD)] => [R(0), R(5), BUbits(3), Uslice(20, 1, 0), Uslice(22, 2, 1), A], SVE;
+    0b00000101_00110000_00100000_00000000 = [Z(OWORD), ZElement(OWORD)] => [R(0), R(5), Ubits(22, 2)], SVE;
+]
+"dupm" = [
+    0b00000101_11000000_00000000_00000000 = [Z(BYTE), Imm] => [R(0), Special(5, LOGICAL_IMMEDIATE_B)], SVE;
+    0b00000101_11000000_00000000_00000000 = [Z(WORD), Imm] => [R(0), Special(5, LOGICAL_IMMEDIATE_H)], SVE;
+    0b00000101_11000000_00000000_00000000 = [Z(DWORD), Imm] => [R(0), Special(5, LOGICAL_IMMEDIATE_W)], SVE;
+    0b00000101_11000000_00000000_00000000 = [Z(QWORD), Imm] => [R(0), Special(5, LOGICAL_IMMEDIATE_X)], SVE;
+]
+"dvp" = [
+    0b11010101_00001011_01110011_10100000 = [Lit("rctx"), X] => [R(0)], PREDRES;
+]
+"eon" = [
+    0b01001010_00100000_00000000_00000000 = [W, W, W, End, Mod(ROTATES)] => [R(0), R(5), R(16), Rotates(22), Ubits(10, 5)];
+    0b11001010_00100000_00000000_00000000 = [X, X, X, End, Mod(ROTATES)] => [R(0), R(5), R(16), Rotates(22), Ubits(10, 6)];
+]
+"eor" = [
+    // EOR (vector)
+    0b00101110_00100000_00011100_00000000 = [V(BYTE), V(BYTE), V(BYTE)] => [R(0), R(5), R(16), Rwidth(30)];
+    // EOR (immediate)
+    0b01010010_00000000_00000000_00000000 = [WSP, W, Imm] => [R(0), R(5), Special(10, LOGICAL_IMMEDIATE_W)];
+    0b11010010_00000000_00000000_00000000 = [XSP, X, Imm] => [R(0), R(5), Special(10, LOGICAL_IMMEDIATE_X)];
+    // EOR (shifted register)
+    0b01001010_00000000_00000000_00000000 = [W, W, W, End, Mod(ROTATES)] => [R(0), R(5), R(16), Rotates(22), Ubits(10, 5)];
+    0b11001010_00000000_00000000_00000000 = [X, X, X, End, Mod(ROTATES)] => [R(0), R(5), R(16), Rotates(22), Ubits(10, 6)];
+    // EOR (predicates)
+    0b00100101_00000000_01000010_00000000 = [P(BYTE), PZero, P(BYTE), P(BYTE)] => [R4(0), R4(10), R4(5), R4(16)], SVE;
+    // EOR (vectors, unpredicated)
+    0b00000100_10100000_00110000_00000000 = [Z(QWORD), Z(QWORD), Z(QWORD)] => [R(0), R(5), R(16)], SVE;
+    // EOR (vectors, predicated)
+    0b00000100_00011001_00000000_00000000 = [Z(BYTE), PMerge, Z(BYTE), Z(BYTE)] => [R(0), R3(10), RTied, R(5)], SVE;
+    0b00000100_01011001_00000000_00000000 = [Z(WORD), PMerge, Z(WORD), Z(WORD)] => [R(0), R3(10), RTied, R(5)], SVE;
+    0b00000100_10011001_00000000_00000000 = [Z(DWORD), PMerge, Z(DWORD), Z(DWORD)] => [R(0), R3(10), RTied, R(5)], SVE;
+    0b00000100_11011001_00000000_00000000 = [Z(QWORD), PMerge, Z(QWORD), Z(QWORD)] => [R(0), R3(10), RTied, R(5)], SVE;
+    // EOR (immediate)
+    0b00000101_01000000_00000000_00000000 = [Z(BYTE), Z(BYTE), Imm] => [R(0), RTied, Special(5, LOGICAL_IMMEDIATE_B)], SVE;
+    0b00000101_01000000_00000000_00000000 = [Z(WORD), Z(WORD), Imm] => [R(0), RTied, Special(5, LOGICAL_IMMEDIATE_H)], SVE;
+    0b00000101_01000000_00000000_00000000 = [Z(DWORD), Z(DWORD), Imm] => [R(0), RTied, Special(5, LOGICAL_IMMEDIATE_W)], SVE;
+    0b00000101_01000000_00000000_00000000 = [Z(QWORD), Z(QWORD), Imm] => [R(0), RTied, Special(5, LOGICAL_IMMEDIATE_X)], SVE;
+]
+"eor3" = [
+    0b11001110_00000000_00000000_00000000 = [VStatic(BYTE, 16), VStatic(BYTE, 16), VStatic(BYTE, 16), VStatic(BYTE, 16)] => [R(0), R(5), R(16), R(10)], SHA3;
+    0b00000100_00100000_00111000_00000000 = [Z(QWORD), Z(QWORD), Z(QWORD), Z(QWORD)] => [R(0), RTied, R(16), R(5)], SVE2;
+]
+"eors" = [
+    0b00100101_01000000_01000010_00000000 = [P(BYTE), PZero, P(BYTE), P(BYTE)] => [R4(0), R4(10), R4(5), R4(16)], SVE;
+]
+"eorv" = [
+    0b00000100_00011001_00100000_00000000 = [B, PBare, Z(BYTE)] => [R(0), R3(10), R(5)], SVE;
+    0b00000100_01011001_00100000_00000000 = [H, PBare, Z(WORD)] => [R(0), R3(10), R(5)], SVE;
+    0b00000100_10011001_00100000_00000000 = [S, PBare, Z(DWORD)] => [R(0), R3(10), R(5)], SVE;
+    0b00000100_11011001_00100000_00000000 = [D, PBare, Z(QWORD)] => [R(0), R3(10), R(5)], SVE;
+]
+"eret" = [
+    0b11010110_10011111_00000011_11100000 = [] => [];
+]
+"eretaa" = [
+    0b11010110_10011111_00001011_11111111 = [] => [], PAUTH;
+]
+"eretab" = [
+    0b11010110_10011111_00001111_11111111 = [] => [], PAUTH;
+]
+"esb" = [
+    0b11010101_00000011_00100010_00011111 = [] => [], RAS;
+]
+"ext" = [
+    // EXT
+    0b00101110_00000000_00000000_00000000 = [VStatic(BYTE, 8), VStatic(BYTE, 8), VStatic(BYTE, 8), Imm] => [R(0), R(5), R(16), Ubits(11, 3)];
+    0b01101110_00000000_00000000_00000000 = [VStatic(BYTE, 16), VStatic(BYTE, 16), VStatic(BYTE, 16), Imm] => [R(0), R(5), R(16), Ubits(11, 4)];
+    // EXT (destructive)
+    0b00000101_00100000_00000000_00000000 = [Z(BYTE), Z(BYTE), Z(BYTE), Imm] => [R(0), RTied, R(5), BUbits(8), Uslice(10, 3, 0), Uslice(16, 5, 3), A], SVE;
+    // EXT (constructive)
+    0b00000101_01100000_00000000_00000000 = [Z(BYTE), ZRegList(2, BYTE), Imm] => [R(0), R(5), BUbits(8), Uslice(10, 3, 0), Uslice(16, 5, 3), A], SVE2;
+]
+"extr" = [
+    0b00010011_10000000_00000000_00000000 = [W, W, W, Imm] => [R(0), R(5), R(16), Ubits(10, 5)];
+    0b10010011_11000000_00000000_00000000 = [X, X, X, Imm] => [R(0), R(5), R(16), Ubits(10, 6)];
+]
+"fabd" = [
+    // FABD
+    0b01111110_11000000_00010100_00000000 = [H, H, H] => [R(0), R(5), R(16)], FP16;
+    0b01111110_10100000_11010100_00000000 = [S, S, S] => [R(0), R(5), R(16)];
+    0b01111110_11100000_11010100_00000000 = [D, D, D] => [R(0), R(5), R(16)];
+    0b00101110_11000000_00010100_00000000 = [V(WORD), V(WORD), V(WORD)] => [R(0), R(5), R(16), Rwidth(30)], FP16;
+    0b00101110_10100000_11010100_00000000 = [V(DWORD), V(DWORD), V(DWORD)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b00101110_11100000_11010100_00000000 = [VStatic(QWORD, 2), VStatic(QWORD, 2), VStatic(QWORD, 2)] => [R(0), R(5), R(16), Rwidth(30)];
+    // FABD (vectors, predicated)
+    0b01100101_01001000_10000000_00000000 = [Z(WORD), PMerge, Z(WORD), Z(WORD)] => [R(0), R3(10), RTied, R(5)], SVE;
+    0b01100101_10001000_10000000_00000000 = [Z(DWORD), PMerge, Z(DWORD), Z(DWORD)] => [R(0), R3(10), RTied, R(5)], SVE;
+    0b01100101_11001000_10000000_00000000 = [Z(QWORD), PMerge, Z(QWORD), Z(QWORD)] => [R(0), R3(10), RTied, R(5)], SVE;
+]
+"fabs" = [
+    // FABS (vector)
+    0b00001110_11111000_11111000_00000000 = [V(WORD), V(WORD)] => [R(0), R(5), Rwidth(30)], FP16;
+    0b00001110_10100000_11111000_00000000 = [V(DWORD), V(DWORD)] => [R(0), R(5), Rwidth(30)];
+    0b00001110_11100000_11111000_00000000 = [VStatic(QWORD, 2), VStatic(QWORD, 2)] => [R(0), R(5), Rwidth(30)];
+    // FABS (scalar)
+    0b00011110_11100000_11000000_00000000 = [H, H] => [R(0), R(5)], FP16;
+    0b00011110_00100000_11000000_00000000 = [S, S] => [R(0), R(5)];
+    0b00011110_01100000_11000000_00000000 = [D, D] => [R(0), R(5)];
+    // FABS (predicated)
+    0b00000100_01011100_10100000_00000000 = [Z(WORD), PMerge, Z(WORD)] => [R(0), R3(10), R(5)], SVE;
+    0b00000100_10011100_10100000_00000000 = [Z(DWORD), PMerge, Z(DWORD)] => [R(0), R3(10), R(5)], SVE;
+    0b00000100_11011100_10100000_00000000 = [Z(QWORD), PMerge, Z(QWORD)] => [R(0), R3(10), R(5)], SVE;
+]
+"facge" = [
+    // FACGE
+    0b01111110_01000000_00101100_00000000 = [H, H, H] => [R(0), R(5), R(16)], FP16;
+    0b01111110_00100000_11101100_00000000 = [S, S, S] => [R(0), R(5), R(16)];
+    0b01111110_01100000_11101100_00000000 = [D, D, D] => [R(0), R(5), R(16)];
+    0b00101110_01000000_00101100_00000000 = [V(WORD), V(WORD), V(WORD)] => [R(0), R(5), R(16), Rwidth(30)], FP16;
+    0b00101110_00100000_11101100_00000000 = [V(DWORD), V(DWORD), V(DWORD)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b00101110_01100000_11101100_00000000 = [VStatic(QWORD, 2), VStatic(QWORD, 2), VStatic(QWORD, 2)] => [R(0), R(5), R(16), Rwidth(30)];
+    // FACGE (vectors)
+    0b01100101_01000000_11000000_00010000 = [P(WORD), PZero, Z(WORD), Z(WORD)] => [R4(0), R3(10), R(5), R(16)], SVE;
+    0b01100101_10000000_11000000_00010000 = [P(DWORD), PZero, Z(DWORD), Z(DWORD)] => [R4(0), R3(10), R(5), R(16)], SVE;
+    0b01100101_11000000_11000000_00010000 = [P(QWORD), PZero, Z(QWORD), Z(QWORD)] => [R4(0), R3(10), R(5), R(16)], SVE;
+]
+"facgt" = [
+    // FACGT
+    0b01111110_11000000_00101100_00000000 = [H, H, H] => [R(0), R(5), R(16)], FP16;
+    0b01111110_10100000_11101100_00000000 = [S, S, S] => [R(0), R(5), R(16)];
+    0b01111110_11100000_11101100_00000000 = [D, D, D] => [R(0), R(5), R(16)];
+    0b00101110_11000000_00101100_00000000 = [V(WORD), V(WORD), V(WORD)] => [R(0), R(5), R(16), Rwidth(30)], FP16;
+    0b00101110_10100000_11101100_00000000 = [V(DWORD), V(DWORD), V(DWORD)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b00101110_11100000_11101100_00000000 = [VStatic(QWORD, 2), VStatic(QWORD, 2), VStatic(QWORD, 2)] => [R(0), R(5), R(16), Rwidth(30)];
+    // FACGT (vectors)
+    0b01100101_01000000_11100000_00010000 = [P(WORD), PZero, Z(WORD), Z(WORD)] => [R4(0), R3(10), R(5), R(16)], SVE;
+    0b01100101_10000000_11100000_00010000 = [P(DWORD), PZero, Z(DWORD), Z(DWORD)] => [R4(0), R3(10), R(5), R(16)], SVE;
+    0b01100101_11000000_11100000_00010000 = [P(QWORD), PZero, Z(QWORD), Z(QWORD)] => [R4(0), R3(10), R(5), R(16)], SVE;
+]
+"facle" = [
+    0b01100101_01000000_11000000_00010000 = [P(WORD), PZero, Z(WORD), Z(WORD)] => [R4(0), R3(10), R(16), R(5)], SVE;
+    0b01100101_10000000_11000000_00010000 = [P(DWORD), PZero, Z(DWORD), Z(DWORD)] => [R4(0), R3(10), R(16), R(5)], SVE;
+    0b01100101_11000000_11000000_00010000 = [P(QWORD), PZero, Z(QWORD), Z(QWORD)] => [R4(0), R3(10), R(16), R(5)], SVE;
+]
+"faclt" = [
+    0b01100101_01000000_11100000_00010000 = [P(WORD), PZero, Z(WORD), Z(WORD)] => [R4(0), R3(10), R(16), R(5)], SVE;
+    0b01100101_10000000_11100000_00010000 = [P(DWORD), PZero, Z(DWORD), Z(DWORD)] => [R4(0), R3(10), R(16), R(5)], SVE;
+    0b01100101_11000000_11100000_00010000 = [P(QWORD), PZero, Z(QWORD), Z(QWORD)] => [R4(0), R3(10), R(16), R(5)], SVE;
+]
+"fadd" = [
+    // FADD (vector)
+    0b00001110_01000000_00010100_00000000 = [V(WORD), V(WORD), V(WORD)] => [R(0), R(5), R(16), Rwidth(30)], FP16;
+    0b00001110_00100000_11010100_00000000 = [V(DWORD), V(DWORD), V(DWORD)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b00001110_01100000_11010100_00000000 = [VStatic(QWORD, 2), VStatic(QWORD, 2), VStatic(QWORD, 2)] => [R(0), R(5), R(16), Rwidth(30)];
+    // FADD (scalar)
+    0b00011110_11100000_00101000_00000000 = [H, H, H] => [R(0), R(5), R(16)], FP16;
+    0b00011110_00100000_00101000_00000000 = [S, S, S] => [R(0), R(5), R(16)];
+    0b00011110_01100000_00101000_00000000 = [D, D, D] => [R(0), R(5), R(16)];
+    // FADD (vectors, unpredicated)
+    0b01100101_01000000_00000000_00000000 = [Z(WORD), Z(WORD), Z(WORD)] => [R(0), R(5), R(16)], SVE;
+    0b01100101_10000000_00000000_00000000 = [Z(DWORD), Z(DWORD), Z(DWORD)] => [R(0), R(5), R(16)], SVE;
+    0b01100101_11000000_00000000_00000000 = [Z(QWORD), Z(QWORD), Z(QWORD)] => [R(0), R(5), R(16)], SVE;
+    // FADD (vectors, predicated)
+    0b01100101_01000000_10000000_00000000 = [Z(WORD), PMerge, Z(WORD), Z(WORD)] => [R(0), R3(10), RTied, R(5)], SVE;
+    0b01100101_10000000_10000000_00000000 = [Z(DWORD), PMerge, Z(DWORD), Z(DWORD)] => [R(0), R3(10), RTied, R(5)], SVE;
+    0b01100101_11000000_10000000_00000000 = [Z(QWORD), PMerge, Z(QWORD), Z(QWORD)] => [R(0), R3(10), RTied, R(5)], SVE;
+    // FADD (immediate)
+    0b01100101_01011000_10000000_00000000 = [Z(WORD), PMerge, Z(WORD), LitFloat(0.5)] => [R(0), R3(10), RTied], SVE;
+    0b01100101_10011000_10000000_00000000 = [Z(DWORD), PMerge, Z(DWORD), LitFloat(0.5)] => [R(0), R3(10), RTied], SVE;
+    0b01100101_11011000_10000000_00000000 = [Z(QWORD), PMerge, Z(QWORD), LitFloat(0.5)] => [R(0), R3(10), RTied], SVE;
+    0b01100101_01011000_10000000_00100000 = [Z(WORD), PMerge, Z(WORD), LitFloat(1.0)] => [R(0), R3(10), RTied], SVE;
+    0b01100101_10011000_10000000_00100000 = [Z(DWORD), PMerge, Z(DWORD), LitFloat(1.0)] => [R(0), R3(10), RTied], SVE;
+    0b01100101_11011000_10000000_00100000 = [Z(QWORD), PMerge, Z(QWORD), LitFloat(1.0)] => [R(0), R3(10), RTied], SVE;
+]
+"fadda" = [
+    0b01100101_01011000_00100000_00000000 = [H, PBare, H, Z(WORD)] => [R(0), R3(10), RTied, R(5)], SVE;
+    0b01100101_10011000_00100000_00000000 = [S, PBare, S, Z(DWORD)] => [R(0), R3(10), RTied, R(5)], SVE;
+    0b01100101_11011000_00100000_00000000 = [D, PBare, D, Z(QWORD)] => [R(0), R3(10), RTied, R(5)], SVE;
+]
+"faddp" = [
+    // FADDP (scalar)
+    0b01011110_00110000_11011000_00000000 = [H, VStatic(WORD, 2)] => [R(0), R(5)], FP16;
+    0b01111110_00110000_11011000_00000000 = [S, VStatic(DWORD, 2)] => [R(0), R(5)];
+    0b01111110_01110000_11011000_00000000 = [D, VStatic(QWORD, 2)] => [R(0), R(5)];
+    // FADDP (vector)
+    0b00101110_01000000_00010100_00000000 = [V(WORD), V(WORD), V(WORD)] => [R(0), R(5), R(16), Rwidth(30)], FP16;
+    0b00101110_00100000_11010100_00000000 = [V(DWORD), V(DWORD), V(DWORD)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b00101110_01100000_11010100_00000000 = [VStatic(QWORD, 2), VStatic(QWORD, 2), VStatic(QWORD, 2)] => [R(0), R(5), R(16), Rwidth(30)];
+    // FADDP (vectors, predicated)
+    0b01100100_01010000_10000000_00000000 = [Z(WORD), PMerge, Z(WORD), Z(WORD)] => [R(0), R3(10), RTied, R(5)], SVE2;
+    0b01100100_10010000_10000000_00000000 = [Z(DWORD), PMerge, Z(DWORD), Z(DWORD)] => [R(0), R3(10), RTied, R(5)], SVE2;
+    0b01100100_11010000_10000000_00000000 = [Z(QWORD), PMerge, Z(QWORD), Z(QWORD)] => [R(0), R3(10), RTied, R(5)], SVE2;
+]
+"faddv" = [
+    0b01100101_01000000_00100000_00000000 = [H, PBare, Z(WORD)] => [R(0), R3(10), R(5)], SVE;
+    0b01100101_10000000_00100000_00000000 = [S, PBare, Z(DWORD)] => [R(0), R3(10), R(5)], SVE;
+    0b01100101_11000000_00100000_00000000 = [D, PBare, Z(QWORD)] => [R(0), R3(10), R(5)], SVE;
+]
+"fcadd" = [
+    0b00101110_01000000_11100100_00000000 = [V(WORD), V(WORD), V(WORD), Imm] => [R(0), R(5), R(16), Ulist(12, &[90, 270]), Rwidth(30)], FCMA | FP16;
+    0b00101110_10000000_11100100_00000000 = [V(DWORD), V(DWORD), V(DWORD), Imm] => [R(0), R(5), R(16), Ulist(12, &[90, 270]), Rwidth(30)], FCMA;
+    0b00101110_11000000_11100100_00000000 = [VStatic(QWORD, 2), VStatic(QWORD, 2), VStatic(QWORD, 2), Imm] => [R(0), R(5), R(16), Ulist(12, &[90, 270]), Rwidth(30)], FCMA;
+    0b01100100_01000000_10000000_00000000 = [Z(WORD), PMerge, Z(WORD), Z(WORD), Imm] => [R(0), R3(10), RTied, R(5), Ulist(16, &[90, 270])], SVE;
+    0b01100100_10000000_10000000_00000000 = [Z(DWORD), PMerge, Z(DWORD), Z(DWORD), Imm] => [R(0), R3(10), RTied, R(5), Ulist(16, &[90, 270])], SVE;
+    0b01100100_11000000_10000000_00000000 = [Z(QWORD), PMerge, Z(QWORD), Z(QWORD), Imm] => [R(0), R3(10), RTied, R(5), Ulist(16, &[90, 270])], SVE;
+]
+"fccmp" = [
+    0b00011110_11100000_00000100_00000000 = [H, H, Imm, Cond] => [R(5), R(16), Ubits(0, 4), Cond(12)], FP16;
+    0b00011110_00100000_00000100_00000000 = [S, S, Imm, Cond] => [R(5), R(16), Ubits(0, 4), Cond(12)];
+    0b00011110_01100000_00000100_00000000 = [D, D, Imm, Cond] => [R(5), R(16), Ubits(0, 4), Cond(12)];
+]
+"fccmpe" = [
+    0b00011110_11100000_00000100_00010000 = [H, H, Imm, Cond] => [R(5), R(16), Ubits(0, 4), Cond(12)], FP16;
+    0b00011110_00100000_00000100_00010000 = [S, S, Imm, Cond] => [R(5), R(16), Ubits(0, 4), Cond(12)];
+    0b00011110_01100000_00000100_00010000 = [D, D, Imm, Cond] => [R(5), R(16), Ubits(0, 4), Cond(12)];
+]
+"fcmeq" = [
+    // FCMEQ (register)
+    0b01011110_01000000_00100100_00000000 = [H, H, H] => [R(0), R(5), R(16)], FP16;
+    0b01011110_00100000_11100100_00000000 = [S, S, S] => [R(0), R(5), R(16)];
+    0b01011110_01100000_11100100_00000000 = [D, D, D] => [R(0), R(5), R(16)];
+    0b00001110_01000000_00100100_00000000 = [V(WORD), V(WORD), V(WORD)] => [R(0), R(5), R(16), Rwidth(30)], FP16;
+    0b00001110_00100000_11100100_00000000 = [V(DWORD), V(DWORD), V(DWORD)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b00001110_01100000_11100100_00000000 = [VStatic(QWORD, 2), VStatic(QWORD, 2), VStatic(QWORD, 2)] => [R(0), R(5), R(16), Rwidth(30)];
+    // FCMEQ (zero)
+    0b01011110_11111000_11011000_00000000 = [H, H, LitFloat(0.0)] => [R(0), R(5)], FP16;
+    0b01011110_10100000_11011000_00000000 = [S, S, LitFloat(0.0)] => [R(0), R(5)];
+    0b01011110_11100000_11011000_00000000 = [D, D, LitFloat(0.0)] => [R(0), R(5)];
+    0b00001110_11111000_11011000_00000000 = [V(WORD), V(WORD), LitFloat(0.0)] => [R(0), R(5), Rwidth(30)], FP16;
+    0b00001110_10100000_11011000_00000000 = [V(DWORD), V(DWORD), LitFloat(0.0)] => [R(0), R(5), Rwidth(30)];
+    0b00001110_11100000_11011000_00000000 = [VStatic(QWORD, 2), VStatic(QWORD, 2), LitFloat(0.0)] => [R(0), R(5), Rwidth(30)];
+    // FCMEQ (zero)
+    0b01100101_01010010_00100000_00000000 = [P(WORD), PZero, Z(WORD), LitFloat(0.0)] => [R4(0), R3(10), R(5)], SVE;
+    0b01100101_10010010_00100000_00000000 = [P(DWORD), PZero, Z(DWORD), LitFloat(0.0)] => [R4(0), R3(10), R(5)], SVE;
+    0b01100101_11010010_00100000_00000000 = [P(QWORD), PZero, Z(QWORD), LitFloat(0.0)] => [R4(0), R3(10), R(5)], SVE;
+    // FCMEQ (vectors)
+    0b01100101_01000000_01100000_00000000 = [P(WORD), PZero, Z(WORD), Z(WORD)] => [R4(0), R3(10), R(5), R(16)], SVE;
+    0b01100101_10000000_01100000_00000000 = [P(DWORD), PZero, Z(DWORD), Z(DWORD)] => [R4(0), R3(10), R(5), R(16)], SVE;
+    0b01100101_11000000_01100000_00000000 = [P(QWORD), PZero, Z(QWORD), Z(QWORD)] => [R4(0), R3(10), R(5), R(16)], SVE;
+]
+"fcmge" = [
+    // FCMGE (register)
+    0b01111110_01000000_00100100_00000000 = [H, H, H] => [R(0), R(5), R(16)], FP16;
+    0b01111110_00100000_11100100_00000000 = [S, S, S] => [R(0), R(5), R(16)];
+    0b01111110_01100000_11100100_00000000 = [D, D, D] => [R(0), R(5), R(16)];
+    0b00101110_01000000_00100100_00000000 = [V(WORD), V(WORD), V(WORD)] => [R(0), R(5), R(16), Rwidth(30)], FP16;
+    0b00101110_00100000_11100100_00000000 = [V(DWORD), V(DWORD), V(DWORD)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b00101110_01100000_11100100_00000000 = [VStatic(QWORD, 2), VStatic(QWORD, 2), VStatic(QWORD, 2)] => [R(0), R(5), R(16), Rwidth(30)];
+    // FCMGE (zero)
+    0b01111110_11111000_11001000_00000000 = [H, H, LitFloat(0.0)] => [R(0), R(5)], FP16;
+    0b01111110_10100000_11001000_00000000 = [S, S, LitFloat(0.0)] => [R(0), R(5)];
+    0b01111110_11100000_11001000_00000000 = [D, D, LitFloat(0.0)] => [R(0), R(5)];
+    0b00101110_11111000_11001000_00000000 = [V(WORD), V(WORD), LitFloat(0.0)] => [R(0), R(5), Rwidth(30)], FP16;
+    0b00101110_10100000_11001000_00000000 = [V(DWORD), V(DWORD), LitFloat(0.0)] => [R(0), R(5), Rwidth(30)];
+    0b00101110_11100000_11001000_00000000 = [VStatic(QWORD, 2), VStatic(QWORD, 2), LitFloat(0.0)] => [R(0), R(5), Rwidth(30)];
+    // FCMGE (zero)
+    0b01100101_01010000_00100000_00000000 = [P(WORD), PZero, Z(WORD), LitFloat(0.0)] => [R4(0), R3(10), R(5)], SVE;
+    0b01100101_10010000_00100000_00000000 = [P(DWORD), PZero, Z(DWORD), LitFloat(0.0)] => [R4(0), R3(10), R(5)], SVE;
+    0b01100101_11010000_00100000_00000000 = [P(QWORD), PZero, Z(QWORD), LitFloat(0.0)] => [R4(0), R3(10), R(5)], SVE;
+    // FCMGE (vectors)
+    0b01100101_01000000_01000000_00000000 = [P(WORD), PZero, Z(WORD), Z(WORD)] => [R4(0), R3(10), R(5), R(16)], SVE;
+    0b01100101_10000000_01000000_00000000 = [P(DWORD), PZero, Z(DWORD), Z(DWORD)] => [R4(0), R3(10), R(5), R(16)], SVE;
+    0b01100101_11000000_01000000_00000000 = [P(QWORD), PZero, Z(QWORD), Z(QWORD)] => [R4(0), R3(10), R(5), R(16)], SVE;
+]
+"fcmgt" = [
+    // FCMGT (register)
+    0b01111110_11000000_00100100_00000000 = [H, H, H] => [R(0), R(5), R(16)], FP16;
+    0b01111110_10100000_11100100_00000000 = [S, S, S] => [R(0), R(5), R(16)];
+    0b01111110_11100000_11100100_00000000 = [D, D, D] => [R(0), R(5), R(16)];
+    0b00101110_11000000_00100100_00000000 = [V(WORD), V(WORD), V(WORD)] => [R(0), R(5), R(16), Rwidth(30)], FP16;
+    0b00101110_10100000_11100100_00000000 = [V(DWORD), V(DWORD), V(DWORD)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b00101110_11100000_11100100_00000000 = [VStatic(QWORD, 2), VStatic(QWORD, 2), VStatic(QWORD, 2)] => [R(0), R(5), R(16), Rwidth(30)];
+    // FCMGT (zero)
+    0b01011110_11111000_11001000_00000000 = [H, H, LitFloat(0.0)] => [R(0), R(5)], FP16;
+    0b01011110_10100000_11001000_00000000 = [S, S, LitFloat(0.0)] => [R(0), R(5)];
+    0b01011110_11100000_11001000_00000000 = [D, D, LitFloat(0.0)] => [R(0), R(5)];
+    0b00001110_11111000_11001000_00000000 = [V(WORD), V(WORD), LitFloat(0.0)] => [R(0), R(5), Rwidth(30)], FP16;
+    0b00001110_10100000_11001000_00000000 = [V(DWORD), V(DWORD), LitFloat(0.0)] => [R(0), R(5), Rwidth(30)];
+    0b00001110_11100000_11001000_00000000 = [VStatic(QWORD, 2), VStatic(QWORD, 2), LitFloat(0.0)] => [R(0), R(5), Rwidth(30)];
+    // FCMGT (zero)
+    0b01100101_01010000_00100000_00010000 = [P(WORD), PZero, Z(WORD), LitFloat(0.0)] => [R4(0), R3(10), R(5)], SVE;
+    0b01100101_10010000_00100000_00010000 = [P(DWORD), PZero, Z(DWORD), LitFloat(0.0)] => [R4(0), R3(10), R(5)], SVE;
+    0b01100101_11010000_00100000_00010000 = [P(QWORD), PZero, Z(QWORD), LitFloat(0.0)] => [R4(0), R3(10), R(5)], SVE;
+    // FCMGT (vectors)
+    0b01100101_01000000_01000000_00010000 = [P(WORD), PZero, Z(WORD), Z(WORD)] => [R4(0), R3(10), R(5), R(16)], SVE;
+    0b01100101_10000000_01000000_00010000 = [P(DWORD), PZero, Z(DWORD), Z(DWORD)] => [R4(0), R3(10), R(5), R(16)], SVE;
+    0b01100101_11000000_01000000_00010000 = [P(QWORD), PZero, Z(QWORD), Z(QWORD)] => [R4(0), R3(10), R(5), R(16)], SVE;
+]
+"fcmla" = [
+    // FCMLA (by element)
+    0b00101111_01000000_00010000_00000000 = [VStatic(WORD, 4), VStatic(WORD, 4), VElement(WORD), Imm] => [R(0), R(5), R(16), Ufields(&[21]), Ulist(13, &[0, 90, 180, 270])], FCMA | FP16;
+    0b01101111_01000000_00010000_00000000 = [VStatic(WORD, 8), VStatic(WORD, 8), VElement(WORD), Imm] => [R(0), R(5), R(16), Ufields(&[11, 21]), Ulist(13, &[0, 90, 180, 270])], FCMA | FP16;
+    0b00101111_10000000_00010000_00000000 = [VStatic(DWORD, 4), VStatic(DWORD, 4), VElement(DWORD), Imm] => [R(0), R(5), R(16), Ufields(&[11]), Ulist(13, &[0, 90, 180, 270]), Rwidth(30)], FCMA;
+    // FCMLA
+    0b00101110_01000000_11000100_00000000 = [V(WORD), V(WORD), V(WORD), Imm] => [R(0), R(5), R(16), Ulist(11, &[0, 90, 180, 270]), Rwidth(30)], FCMA | FP16;
+    0b00101110_10000000_11000100_00000000 = [V(DWORD), V(DWORD), V(DWORD), Imm] => [R(0), R(5), R(16), Ulist(11, &[0, 90, 180, 270]), Rwidth(30)], FCMA;
+    0b00101110_11000000_11000100_00000000 = [VStatic(QWORD, 2), VStatic(QWORD, 2), VStatic(QWORD, 2), Imm] => [R(0), R(5), R(16), Ulist(11, &[0, 90, 180, 270]), Rwidth(30)], FCMA;
+    // FCMLA (vectors)
+    0b01100100_01000000_00000000_00000000 = [Z(WORD), PMerge, Z(WORD), Z(WORD), Imm] => [R(0), R3(10), R(5), R(16), Ulist(13, &[0, 90, 180, 270])], SVE;
+    0b01100100_10000000_00000000_00000000 = [Z(DWORD), PMerge, Z(DWORD), Z(DWORD), Imm] => [R(0), R3(10), R(5), R(16), Ulist(13, &[0, 90, 180, 270])], SVE;
+    0b01100100_11000000_00000000_00000000 = [Z(QWORD), PMerge, Z(QWORD), Z(QWORD), Imm] => [R(0), R3(10), R(5), R(16), Ulist(13, &[0, 90, 180, 270])], SVE;
+    // FCMLA (indexed)
+    0b01100100_10100000_00010000_00000000 = [Z(WORD), Z(WORD), ZElement(WORD), Imm] => [R(0), R(5), R3(16), Ubits(19, 2), Ulist(10, &[0, 90, 180, 270])], SVE;
+    0b01100100_11100000_00010000_00000000 = [Z(DWORD), Z(DWORD), ZElement(DWORD), Imm] => [R(0), R(5), R4(16), Ubits(20, 1), Ulist(10, &[0, 90, 180, 270])], SVE;
+]
+"fcmle" = [
+    // FCMLE
+    0b01111110_11111000_11011000_00000000 = [H, H, LitFloat(0.0)] => [R(0), R(5)], FP16;
+    0b01111110_10100000_11011000_00000000 = [S, S, LitFloat(0.0)] => [R(0), R(5)];
+    0b01111110_11100000_11011000_00000000 = [D, D, LitFloat(0.0)] => [R(0), R(5)];
+    0b00101110_11111000_11011000_00000000 = [V(WORD), V(WORD), LitFloat(0.0)] => [R(0), R(5), Rwidth(30)], FP16;
+    0b00101110_10100000_11011000_00000000 = [V(DWORD), V(DWORD), LitFloat(0.0)] => [R(0), R(5), Rwidth(30)];
+    0b00101110_11100000_11011000_00000000 = [VStatic(QWORD, 2), VStatic(QWORD, 2), LitFloat(0.0)] => [R(0), R(5), Rwidth(30)];
+    // FCMLE (vectors)
+    0b01100101_01000000_01000000_00000000 = [P(WORD), PZero, Z(WORD), Z(WORD)] => [R4(0), R3(10), R(16), R(5)], SVE;
+    0b01100101_10000000_01000000_00000000 = [P(DWORD), PZero, Z(DWORD), Z(DWORD)] => [R4(0), R3(10), R(16), R(5)], SVE;
+    0b01100101_11000000_01000000_00000000 = [P(QWORD), PZero, Z(QWORD), Z(QWORD)] => [R4(0), R3(10), R(16), R(5)], SVE;
+    // FCMLE (zero)
+    0b01100101_01010001_00100000_00010000 = [P(WORD), PZero, Z(WORD), LitFloat(0.0)] => [R4(0), R3(10), R(5)], SVE;
+    0b01100101_10010001_00100000_00010000 = [P(DWORD), PZero, Z(DWORD), LitFloat(0.0)] => [R4(0), R3(10), R(5)], SVE;
+    0b01100101_11010001_00100000_00010000 = [P(QWORD), PZero, Z(QWORD), LitFloat(0.0)] => [R4(0), R3(10), R(5)], SVE;
+]
+"fcmlt" = [
+    // FCMLT
+    0b01011110_11111000_11101000_00000000 = [H, H, LitFloat(0.0)] => [R(0), R(5)], FP16;
+    0b01011110_10100000_11101000_00000000 = [S, S, LitFloat(0.0)] => [R(0), R(5)];
+    0b01011110_11100000_11101000_00000000 = [D, D, LitFloat(0.0)] => [R(0), R(5)];
+    0b00001110_11111000_11101000_00000000 = [V(WORD), V(WORD), LitFloat(0.0)] => [R(0), R(5), Rwidth(30)], FP16;
+    0b00001110_10100000_11101000_00000000 = [V(DWORD), V(DWORD), LitFloat(0.0)] => [R(0), R(5), Rwidth(30)];
+    0b00001110_11100000_11101000_00000000 = [VStatic(QWORD, 2), VStatic(QWORD, 2), LitFloat(0.0)] => [R(0), R(5), Rwidth(30)];
+    // FCMLT (vectors)
+    0b01100101_01000000_01000000_00010000 = [P(WORD), PZero, Z(WORD), Z(WORD)] => [R4(0), R3(10), R(16), R(5)], SVE;
+    0b01100101_10000000_01000000_00010000 = [P(DWORD), PZero, Z(DWORD), Z(DWORD)] => [R4(0), R3(10), R(16), R(5)], SVE;
+    0b01100101_11000000_01000000_00010000 = [P(QWORD), PZero, Z(QWORD), Z(QWORD)] => [R4(0), R3(10), R(16), R(5)], SVE;
+    // FCMLT (zero)
+    0b01100101_01010001_00100000_00000000 = [P(WORD), PZero, Z(WORD), LitFloat(0.0)] => [R4(0), R3(10), R(5)], SVE;
+    0b01100101_10010001_00100000_00000000 = [P(DWORD), PZero, Z(DWORD), LitFloat(0.0)] => [R4(0), R3(10), R(5)], SVE;
+    0b01100101_11010001_00100000_00000000 = [P(QWORD), PZero, Z(QWORD), LitFloat(0.0)] => [R4(0), R3(10), R(5)], SVE;
+]
+"fcmne" = [
+    // FCMNE (zero)
+    0b01100101_01010011_00100000_00000000 = [P(WORD), PZero, Z(WORD), LitFloat(0.0)] => [R4(0), R3(10), R(5)], SVE;
+    0b01100101_10010011_00100000_00000000 = [P(DWORD), PZero, Z(DWORD), LitFloat(0.0)] => [R4(0), R3(10), R(5)], SVE;
+    0b01100101_11010011_00100000_00000000 = [P(QWORD), PZero, Z(QWORD), LitFloat(0.0)] => [R4(0), R3(10), R(5)], SVE;
+    // FCMNE (vectors)
+    0b01100101_01000000_01100000_00010000 = [P(WORD), PZero, Z(WORD), Z(WORD)] => [R4(0), R3(10), R(5), R(16)], SVE;
+    0b01100101_10000000_01100000_00010000 = [P(DWORD), PZero, Z(DWORD), Z(DWORD)] => [R4(0), R3(10), R(5), R(16)], SVE;
+    0b01100101_11000000_01100000_00010000 = [P(QWORD), PZero, Z(QWORD), Z(QWORD)] => [R4(0), R3(10), R(5), R(16)], SVE;
+]
+"fcmp" = [
+    0b00011110_11100000_00100000_00000000 = [H, H] => [R(5), R(16)], FP16;
+    0b00011110_11100000_00100000_00001000 = [H, LitFloat(0.0)] => [R(5)], FP16;
+    0b00011110_00100000_00100000_00000000 = [S, S] => [R(5), R(16)];
+    0b00011110_00100000_00100000_00001000 = [S, LitFloat(0.0)] => [R(5)];
+    0b00011110_01100000_00100000_00000000 = [D, D] => [R(5), R(16)];
+    0b00011110_01100000_00100000_00001000 = [D, LitFloat(0.0)] => [R(5)];
+]
+"fcmpe" = [
+    0b00011110_11100000_00100000_00010000 = [H, H] => [R(5), R(16)], FP16;
+    0b00011110_11100000_00100000_00011000 = [H, LitFloat(0.0)] => [R(5)], FP16;
+    0b00011110_00100000_00100000_00010000 = [S, S] => [R(5), R(16)];
+    0b00011110_00100000_00100000_00011000 = [S, LitFloat(0.0)] => [R(5)];
+    0b00011110_01100000_00100000_00010000 = [D, D] => [R(5), R(16)];
+    0b00011110_01100000_00100000_00011000 = [D, LitFloat(0.0)] => [R(5)];
+]
+"fcmuo" = [
+    0b01100101_01000000_11000000_00000000 = [P(WORD), PZero, Z(WORD), Z(WORD)] => [R4(0), R3(10), R(5), R(16)], SVE;
+    0b01100101_10000000_11000000_00000000 = [P(DWORD), PZero, Z(DWORD), Z(DWORD)] => [R4(0), R3(10), R(5), R(16)], SVE;
+    0b01100101_11000000_11000000_00000000 = [P(QWORD), PZero, Z(QWORD), Z(QWORD)] => [R4(0), R3(10), R(5), R(16)], SVE;
+]
+"fcpy" = [
+    0b00000101_01010000_11000000_00000000 = [Z(WORD), PMerge, Imm] => [R(0), R4(16), Special(5, FLOAT_IMMEDIATE)], SVE;
+    0b00000101_10010000_11000000_00000000 = [Z(DWORD), PMerge, Imm] => [R(0), R4(16), Special(5, FLOAT_IMMEDIATE)], SVE;
+    0b00000101_11010000_11000000_00000000 = [Z(QWORD), PMerge, Imm] => [R(0), R4(16), Special(5, FLOAT_IMMEDIATE)], SVE;
+]
+"fcsel" = [
+    0b00011110_11100000_00001100_00000000 = [H, H, H, Cond] => [R(0), R(5), R(16), Cond(12)], FP16;
+    0b00011110_00100000_00001100_00000000 = [S, S, S, Cond] => [R(0), R(5), R(16), Cond(12)];
+    0b00011110_01100000_00001100_00000000 = [D, D, D, Cond] => [R(0), R(5), R(16), Cond(12)];
+]
+"fcvt" = [
+    0b00011110_11100010_01000000_00000000 = [S, H] => [R(0), R(5)];
+    0b00011110_11100010_11000000_00000000 = [D, H] => [R(0), R(5)];
+    0b00011110_00100011_11000000_00000000 = [H, S] => [R(0), R(5)];
+    0b00011110_00100010_11000000_00000000 = [D, S] => [R(0), R(5)];
+    0b00011110_01100011_11000000_00000000 = [H, D] => [R(0), R(5)];
+    0b00011110_01100010_01000000_00000000 = [S, D] => [R(0), R(5)];
+    0b01100101_11001001_10100000_00000000 = [Z(QWORD), PMerge, Z(WORD)] => [R(0), R3(10), R(5)], SVE;
+    0b01100101_11001011_10100000_00000000 = [Z(QWORD), PMerge, Z(DWORD)] => [R(0), R3(10), R(5)], SVE;
+    0b01100101_11001000_10100000_00000000 = [Z(WORD), PMerge, Z(QWORD)] => [R(0), R3(10), R(5)], SVE;
+    0b01100101_10001000_10100000_00000000 = [Z(WORD), PMerge, Z(DWORD)] => [R(0), R3(10), R(5)], SVE;
+    0b01100101_11001010_10100000_00000000 = [Z(DWORD), PMerge, Z(QWORD)] => [R(0), R3(10), R(5)], SVE;
+    0b01100101_10001001_10100000_00000000 = [Z(DWORD), PMerge, Z(WORD)] => [R(0), R3(10), R(5)], SVE;
+]
+"fcvtas" = [
+    // FCVTAS (vector)
+    0b01011110_01111001_11001000_00000000 = [H, H] => [R(0), R(5)], FP16;
+    0b01011110_00100001_11001000_00000000 = [S, S] => [R(0), R(5)];
+    0b01011110_01100001_11001000_00000000 = [D, D] => [R(0), R(5)];
+    0b00001110_01111001_11001000_00000000 = [V(WORD), V(WORD)] => [R(0), R(5), Rwidth(30)], FP16;
+    0b00001110_00100001_11001000_00000000 = [V(DWORD), V(DWORD)] => [R(0), R(5), Rwidth(30)];
+    0b00001110_01100001_11001000_00000000 = [VStatic(QWORD, 2), VStatic(QWORD, 2)] => [R(0), R(5), Rwidth(30)];
+    // FCVTAS (scalar)
+    0b00011110_11100100_00000000_00000000 = [W, H] => [R(0), R(5)], FP16;
+    0b10011110_11100100_00000000_00000000 = [X, H] => [R(0), R(5)], FP16;
+    0b00011110_00100100_00000000_00000000 = [W, S] => [R(0), R(5)];
+    0b10011110_00100100_00000000_00000000 = [X, S] => [R(0), R(5)];
+    0b00011110_01100100_00000000_00000000 = [W, D] => [R(0), R(5)];
+    0b10011110_01100100_00000000_00000000 = [X, D] => [R(0), R(5)];
+]
+"fcvtau" = [
+    // FCVTAU (vector)
+    0b01111110_01111001_11001000_00000000 = [H, H] => [R(0), R(5)], FP16;
+    0b01111110_00100001_11001000_00000000 = [S, S] => [R(0), R(5)];
+    0b01111110_01100001_11001000_00000000 = [D, D] => [R(0), R(5)];
+    0b00101110_01111001_11001000_00000000 = [V(WORD), V(WORD)] => [R(0), R(5), Rwidth(30)], FP16;
+    0b00101110_00100001_11001000_00000000 = [V(DWORD), V(DWORD)] => [R(0), R(5), Rwidth(30)];
+    0b00101110_01100001_11001000_00000000 = [VStatic(QWORD, 2), VStatic(QWORD, 2)] => [R(0), R(5), Rwidth(30)];
+    // FCVTAU (scalar)
+    0b00011110_11100101_00000000_00000000 = [W, H] => [R(0), R(5)], FP16;
+    0b10011110_11100101_00000000_00000000 = [X, H] => [R(0), R(5)], FP16;
+    0b00011110_00100101_00000000_00000000 = [W, S] => [R(0), R(5)];
+    0b10011110_00100101_00000000_00000000 = [X, S] => [R(0), R(5)];
+    0b00011110_01100101_00000000_00000000 = [W, D] => [R(0), R(5)];
+    0b10011110_01100101_00000000_00000000 = [X, D] => [R(0), R(5)];
+]
+"fcvtl" = [
+    0b00001110_00100001_01111000_00000000 = [VStatic(DWORD, 4), VStatic(WORD, 4)] => [R(0), R(5)];
+    0b00001110_01100001_01111000_00000000 = [VStatic(QWORD, 2), VStatic(DWORD, 2)] => [R(0), R(5)];
+]
+"fcvtl2" = [
+    0b01001110_00100001_01111000_00000000 = [VStatic(DWORD, 4), VStatic(WORD, 8)] => [R(0), R(5)];
+    0b01001110_01100001_01111000_00000000 = [VStatic(QWORD, 2), VStatic(DWORD, 4)] => [R(0), R(5)];
+]
+"fcvtms" = [
+    // FCVTMS (vector)
+    0b01011110_01111001_10111000_00000000 = [H, H] => [R(0), R(5)], FP16;
+    0b01011110_00100001_10111000_00000000 = [S, S] => [R(0), R(5)];
+    0b01011110_01100001_10111000_00000000 = [D, D] => [R(0), R(5)];
+    0b00001110_01111001_10111000_00000000 = [V(WORD), V(WORD)] => [R(0), R(5), Rwidth(30)], FP16;
+    0b00001110_00100001_10111000_00000000 = [V(DWORD), V(DWORD)] => [R(0), R(5), Rwidth(30)];
+    0b00001110_01100001_10111000_00000000 = [VStatic(QWORD, 2), VStatic(QWORD, 2)] => [R(0), R(5), Rwidth(30)];
+    // FCVTMS (scalar)
+    0b00011110_11110000_00000000_00000000 = [W, H] => [R(0), R(5)], FP16;
+    0b10011110_11110000_00000000_00000000 = [X, H] => [R(0), R(5)], FP16;
+    0b00011110_00110000_00000000_00000000 = [W, S] => [R(0), R(5)];
+    0b10011110_00110000_00000000_00000000 = [X, S] => [R(0), R(5)];
+    0b00011110_01110000_00000000_00000000 = [W, D] => [R(0), R(5)];
+    0b10011110_01110000_00000000_00000000 = [X, D] => [R(0), R(5)];
+]
+"fcvtmu" = [
+    // FCVTMU (vector)
+    0b01111110_01111001_10111000_00000000 = [H, H] => [R(0), R(5)], FP16;
+    0b01111110_00100001_10111000_00000000 = [S, S] => [R(0), R(5)];
+    0b01111110_01100001_10111000_00000000 = [D, D] => [R(0), R(5)];
+    0b00101110_01111001_10111000_00000000 = [V(WORD), V(WORD)] => [R(0), R(5), Rwidth(30)], FP16;
+    0b00101110_00100001_10111000_00000000 = [V(DWORD), V(DWORD)] => [R(0), R(5), Rwidth(30)];
+    0b00101110_01100001_10111000_00000000 = [VStatic(QWORD, 2), VStatic(QWORD, 2)] => [R(0), R(5), Rwidth(30)];
+    // FCVTMU (scalar)
+    0b00011110_11110001_00000000_00000000 = [W, H] => [R(0), R(5)], FP16;
+    0b10011110_11110001_00000000_00000000 = [X, H] => [R(0), R(5)], FP16;
+    0b00011110_00110001_00000000_00000000 = [W, S] => [R(0), R(5)];
+    0b10011110_00110001_00000000_00000000 = [X, S] => [R(0), R(5)];
+    0b00011110_01110001_00000000_00000000 = [W, D] => [R(0), R(5)];
+    0b10011110_01110001_00000000_00000000 = [X, D] => [R(0), R(5)];
+]
+"fcvtn" = [
+    0b00001110_01100001_01101000_00000000 = [VStatic(DWORD, 2), VStatic(QWORD, 2)] => [R(0), R(5)];
+]
+"fcvtn2" = [
+    0b01001110_01100001_01101000_00000000 = [VStatic(DWORD, 4), VStatic(QWORD, 2)] => [R(0), R(5)];
+]
+"fcvtns" = [
+    // FCVTNS (vector)
+    0b01011110_01111001_10101000_00000000 = [H, H] => [R(0), R(5)], FP16;
+    0b01011110_00100001_10101000_00000000 = [S, S] => [R(0), R(5)];
+    0b01011110_01100001_10101000_00000000 = [D, D] => [R(0), R(5)];
+    0b00001110_01111001_10101000_00000000 = [V(WORD), V(WORD)] => [R(0), R(5), Rwidth(30)], FP16;
+    0b00001110_00100001_10101000_00000000 = [V(DWORD), V(DWORD)] => [R(0), R(5), Rwidth(30)];
+    0b00001110_01100001_10101000_00000000 = [VStatic(QWORD, 2), VStatic(QWORD, 2)] => [R(0), R(5), Rwidth(30)];
+    // FCVTNS (scalar)
+    0b00011110_11100000_00000000_00000000 = [W, H] => [R(0), R(5)], FP16;
+    0b10011110_11100000_00000000_00000000 = [X, H] => [R(0), R(5)], FP16;
+    0b00011110_00100000_00000000_00000000 = [W, S] => [R(0), R(5)];
+    0b10011110_00100000_00000000_00000000 = [X, S] => [R(0), R(5)];
+    0b00011110_01100000_00000000_00000000 = [W, D] => [R(0), R(5)];
+    0b10011110_01100000_00000000_00000000 = [X, D] => [R(0), R(5)];
+]
+"fcvtnu" = [
+    // FCVTNU (vector)
+    0b01111110_01111001_10101000_00000000 = [H, H] => [R(0), R(5)], FP16;
+    0b01111110_00100001_10101000_00000000 = [S, S] => [R(0), R(5)];
+    0b01111110_01100001_10101000_00000000 = [D, D] => [R(0), R(5)];
+    0b00101110_01111001_10101000_00000000 = [V(WORD), V(WORD)] => [R(0), R(5), Rwidth(30)], FP16;
+    0b00101110_00100001_10101000_00000000 = [V(DWORD), V(DWORD)] => [R(0), R(5), Rwidth(30)];
+    0b00101110_01100001_10101000_00000000 = [VStatic(QWORD, 2), VStatic(QWORD, 2)] => [R(0), R(5), Rwidth(30)];
+    // FCVTNU (scalar)
+    0b00011110_11100001_00000000_00000000 = [W, H] => [R(0), R(5)], FP16;
+    0b10011110_11100001_00000000_00000000 = [X, H] => [R(0), R(5)], FP16;
+    0b00011110_00100001_00000000_00000000 = [W, S] => [R(0), R(5)];
+    0b10011110_00100001_00000000_00000000 = [X, S] => [R(0), R(5)];
+    0b00011110_01100001_00000000_00000000 = [W, D] => [R(0), R(5)];
+    0b10011110_01100001_00000000_00000000 = [X, D] => [R(0), R(5)];
+]
+"fcvtps" = [
+    // FCVTPS (vector)
+    0b01011110_11111001_10101000_00000000 = [H, H] => [R(0), R(5)], FP16;
+    0b01011110_10100001_10101000_00000000 = [S, S] => [R(0), R(5)];
+    0b01011110_11100001_10101000_00000000 = [D, D] => [R(0), R(5)];
+    0b00001110_11111001_10101000_00000000 = [V(WORD), V(WORD)] => [R(0), R(5), Rwidth(30)], FP16;
+    0b00001110_10100001_10101000_00000000 = [V(DWORD), V(DWORD)] => [R(0), R(5), Rwidth(30)];
+    0b00001110_11100001_10101000_00000000 = [VStatic(QWORD, 2), VStatic(QWORD, 2)] => [R(0), R(5), Rwidth(30)];
+    // FCVTPS (scalar)
+    0b00011110_11101000_00000000_00000000 = [W, H] => [R(0), R(5)], FP16;
+    0b10011110_11101000_00000000_00000000 = [X, H] => [R(0), R(5)], FP16;
+    0b00011110_00101000_00000000_00000000 = [W, S] => [R(0), R(5)];
+    0b10011110_00101000_00000000_00000000 = [X, S] => [R(0), R(5)];
+    0b00011110_01101000_00000000_00000000 = [W, D] => [R(0), R(5)];
+    0b10011110_01101000_00000000_00000000 = [X, D] => [R(0), R(5)];
+]
+"fcvtpu" = [
+    // FCVTPU (vector)
+    0b01111110_11111001_10101000_00000000 = [H, H] => [R(0), R(5)], FP16;
+    0b01111110_10100001_10101000_00000000 = [S, S] => [R(0), R(5)];
+    0b01111110_11100001_10101000_00000000 = [D, D] => [R(0), R(5)];
+    0b00101110_11111001_10101000_00000000 = [V(WORD), V(WORD)] => [R(0), R(5), Rwidth(30)], FP16;
+    0b00101110_10100001_10101000_00000000 = [V(DWORD), V(DWORD)] => [R(0), R(5), Rwidth(30)];
+    0b00101110_11100001_10101000_00000000 = [VStatic(QWORD, 2), VStatic(QWORD, 2)] => [R(0), R(5), Rwidth(30)];
+    // FCVTPU (scalar)
+    0b00011110_11101001_00000000_00000000 = [W, H] => [R(0), R(5)], FP16;
+    0b10011110_11101001_00000000_00000000 = [X, H] => [R(0), R(5)], FP16;
+    0b00011110_00101001_00000000_00000000 = [W, S] => [R(0), R(5)];
+    0b10011110_00101001_00000000_00000000 = [X, S] => [R(0), R(5)];
+    0b00011110_01101001_00000000_00000000 = [W, D] => [R(0), R(5)];
+    0b10011110_01101001_00000000_00000000 = [X, D] => [R(0), R(5)];
+]
+"fcvtxn" = [
+    0b01111110_01100001_01101000_00000000 = [S, D] => [R(0), R(5)];
+    0b00101110_01100001_01101000_00000000 = [VStatic(DWORD, 2), VStatic(QWORD, 2)] => [R(0), R(5)];
+]
+"fcvtxn2" = [
+    0b01101110_01100001_01101000_00000000 = [VStatic(DWORD, 4), VStatic(QWORD, 2)] => [R(0), R(5)];
+]
+"fcvtzs" = [
+    // FCVTZS (vector, fixed-point)
+    0b01011111_00000000_11111100_00000000 = [H, H, Imm] => [R(0), R(5), BUrange(1, 16), Usub(16, 5, 32)], FP16;
+    0b01011111_00000000_11111100_00000000 = [S, S, Imm] => [R(0), R(5), BUrange(1, 32), Usub(16, 6, 64)];
+    0b01011111_00000000_11111100_00000000 = [D, D, Imm] => [R(0), R(5), BUrange(1, 64), Usub(16, 7, 128)];
+    0b00001111_00010000_11111100_00000000 = [V(WORD), V(WORD), Imm] => [R(0), R(5), Usub(16, 4, 16), Rwidth(30)], FP16;
+    0b00001111_00100000_11111100_00000000 = [V(DWORD), V(DWORD), Imm] => [R(0), R(5), Usub(16, 5, 32), Rwidth(30)];
+    0b00001111_01000000_11111100_00000000 = [VStatic(QWORD, 2), VStatic(QWORD, 2), Imm] => [R(0), R(5), Usub(16, 6, 64), Rwidth(30)];
+    // FCVTZS (vector, integer)
+    0b01011110_11111001_10111000_00000000 = [H, H] => [R(0), R(5)], FP16;
+    0b01011110_10100001_10111000_00000000 = [S, S] => [R(0), R(5)];
+    0b01011110_11100001_10111000_00000000 = [D, D] => [R(0), R(5)];
+    0b00001110_11111001_10111000_00000000 = [V(WORD), V(WORD)] => [R(0), R(5), Rwidth(30)], FP16;
+    0b00001110_10100001_10111000_00000000 = [V(DWORD), V(DWORD)] => [R(0), R(5), Rwidth(30)];
+    0b00001110_11100001_10111000_00000000 = [VStatic(QWORD, 2), VStatic(QWORD, 2)] => [R(0), R(5), Rwidth(30)];
+    // FCVTZS (scalar, fixed-point)
+    0b00011110_11011000_00000000_00000000 = [W, H, Imm] => [R(0), R(5), BUrange(1, 32), Usub(10, 6, 64)], FP16;
+    0b10011110_11011000_00000000_00000000 = [X, H, Imm] => [R(0), R(5), Usub(10, 6, 64)], FP16;
+    0b00011110_00011000_00000000_00000000 = [W, S, Imm] => [R(0), R(5), BUrange(1, 32), Usub(10, 6, 64)];
+    0b10011110_00011000_00000000_00000000 = [X, S, Imm] => [R(0), R(5), Usub(10, 6, 64)];
+    0b00011110_01011000_00000000_00000000 = [W, D, Imm] => [R(0), R(5), BUrange(1, 32), Usub(10, 6, 64)];
+    0b10011110_01011000_00000000_00000000 = [X, D, Imm] => [R(0), R(5), Usub(10, 6, 64)];
+    // FCVTZS (scalar, integer)
+    0b00011110_11111000_00000000_00000000 = [W, H] => [R(0), R(5)], FP16;
+    0b10011110_11111000_00000000_00000000 = [X, H] => [R(0), R(5)], FP16;
+    0b00011110_00111000_00000000_00000000 = [W, S] => [R(0), R(5)];
+    0b10011110_00111000_00000000_00000000 = [X, S] => [R(0), R(5)];
+    0b00011110_01111000_00000000_00000000 = [W, D] => [R(0), R(5)];
+    0b10011110_01111000_00000000_00000000 = [X, D] => [R(0), R(5)];
+    // FCVTZS
+    0b01100101_11011110_10100000_00000000 = [Z(QWORD), PMerge, Z(QWORD)] => [R(0), R3(10), R(5)], SVE;
+    0b01100101_01011110_10100000_00000000 = [Z(QWORD), PMerge, Z(WORD)] => [R(0), R3(10), R(5)], SVE;
+    0b01100101_11011100_10100000_00000000 = [Z(QWORD), PMerge, Z(DWORD)] => [R(0), R3(10), R(5)], SVE;
+    0b01100101_01011010_10100000_00000000 = [Z(WORD), PMerge, Z(WORD)] => [R(0), R3(10), R(5)], SVE;
+    0b01100101_11011000_10100000_00000000 = [Z(DWORD), PMerge, Z(QWORD)] => [R(0), R3(10), R(5)], SVE;
+    0b01100101_01011100_10100000_00000000 = [Z(DWORD), PMerge, Z(WORD)] => [R(0), R3(10), R(5)], SVE;
+    0b01100101_10011100_10100000_00000000 = [Z(DWORD), PMerge, Z(DWORD)] => [R(0), R3(10), R(5)], SVE;
+]
+"fcvtzu" = [
+    // FCVTZU (vector, fixed-point)
+    0b01111111_00000000_11111100_00000000 = [H, H, Imm] => [R(0), R(5), BUrange(1, 16), Usub(16, 5, 32)], FP16;
+    0b01111111_00000000_11111100_00000000 = [S, S, Imm] => [R(0), R(5), BUrange(1, 32), Usub(16, 6, 64)];
+    0b01111111_00000000_11111100_00000000 = [D, D, Imm] => [R(0), R(5), BUrange(1, 64), Usub(16, 7, 128)];
+    0b00101111_00010000_11111100_00000000 = [V(WORD), V(WORD), Imm] => [R(0), R(5), Usub(16, 4, 16), Rwidth(30)], FP16;
+    0b00101111_00100000_11111100_00000000 = [V(DWORD), V(DWORD), Imm] => [R(0), R(5), Usub(16, 5, 32), Rwidth(30)];
+    0b00101111_01000000_11111100_00000000 = [VStatic(QWORD, 2), VStatic(QWORD, 2), Imm] => [R(0), R(5), Usub(16, 6, 64), Rwidth(30)];
+    // FCVTZU (vector, integer)
+    0b01111110_11111001_10111000_00000000 = [H, H] => [R(0), R(5)], FP16;
+    0b01111110_10100001_10111000_00000000 = [S, S] => [R(0), R(5)];
+    0b01111110_11100001_10111000_00000000 = [D, D] => [R(0), R(5)];
+    0b00101110_11111001_10111000_00000000 = [V(WORD), V(WORD)] => [R(0), R(5), Rwidth(30)], FP16;
+    0b00101110_10100001_10111000_00000000 = [V(DWORD), V(DWORD)] => [R(0), R(5), Rwidth(30)];
+    0b00101110_11100001_10111000_00000000 = [VStatic(QWORD, 2), VStatic(QWORD, 2)] => [R(0), R(5), Rwidth(30)];
+    // FCVTZU (scalar, fixed-point)
+    0b00011110_11011001_00000000_00000000 = [W, H, Imm] => [R(0), R(5), BUrange(1, 32), Usub(10, 6, 64)], FP16;
+    0b10011110_11011001_00000000_00000000 = [X, H, Imm] => [R(0), R(5), Usub(10, 6, 64)], FP16;
+    0b00011110_00011001_00000000_00000000 = [W, S, Imm] => [R(0), R(5), BUrange(1, 32), Usub(10, 6, 64)];
+    0b10011110_00011001_00000000_00000000 = [X, S, Imm] => [R(0), R(5), Usub(10, 6, 64)];
+    0b00011110_01011001_00000000_00000000 = [W, D, Imm] => [R(0), R(5), BUrange(1, 32), Usub(10, 6, 64)];
+    0b10011110_01011001_00000000_00000000 = [X, D, Imm] => [R(0), R(5), Usub(10, 6, 64)];
+    // FCVTZU (scalar, integer)
+    0b00011110_11111001_00000000_00000000 = [W, H] => [R(0), R(5)], FP16;
+    0b10011110_11111001_00000000_00000000 = [X, H] => [R(0), R(5)], FP16;
+    0b00011110_00111001_00000000_00000000 = [W, S] => [R(0), R(5)];
+    0b10011110_00111001_00000000_00000000 = [X, S] => [R(0), R(5)];
+    0b00011110_01111001_00000000_00000000 = [W, D] => [R(0), R(5)];
+    0b10011110_01111001_00000000_00000000 = [X, D] => [R(0), R(5)];
+    // FCVTZU
+    0b01100101_11011111_10100000_00000000 = [Z(QWORD), PMerge, Z(QWORD)] => [R(0), R3(10), R(5)], SVE;
+    0b01100101_01011111_10100000_00000000 = [Z(QWORD), PMerge, Z(WORD)] => [R(0), R3(10), R(5)], SVE;
+    0b01100101_11011101_10100000_00000000 = [Z(QWORD), PMerge, Z(DWORD)] => [R(0), R3(10), R(5)], SVE;
+    0b01100101_01011011_10100000_00000000 = [Z(WORD), PMerge, Z(WORD)] => [R(0), R3(10), R(5)], SVE;
+    0b01100101_11011001_10100000_00000000 = [Z(DWORD), PMerge, Z(QWORD)] => [R(0), R3(10), R(5)], SVE;
+    0b01100101_01011101_10100000_00000000 = [Z(DWORD), PMerge, Z(WORD)] => [R(0), R3(10), R(5)], SVE;
+    0b01100101_10011101_10100000_00000000 = [Z(DWORD), PMerge, Z(DWORD)] => [R(0), R3(10), R(5)], SVE;
+]
+"fdiv" = [
+    // FDIV (vector)
+    0b00101110_01000000_00111100_00000000 = [V(WORD), V(WORD), V(WORD)] => [R(0), R(5), R(16), Rwidth(30)], FP16;
+    0b00101110_00100000_11111100_00000000 = [V(DWORD), V(DWORD), V(DWORD)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b00101110_01100000_11111100_00000000 = [VStatic(QWORD, 2), VStatic(QWORD, 2), VStatic(QWORD, 2)] => [R(0), R(5), R(16), Rwidth(30)];
+    // FDIV (scalar)
+    0b00011110_11100000_00011000_00000000 = [H, H, H] => [R(0), R(5), R(16)], FP16;
+    0b00011110_00100000_00011000_00000000 = [S, S, S] => [R(0), R(5), R(16)];
+    0b00011110_01100000_00011000_00000000 = [D, D, D] => [R(0), R(5), R(16)];
+    // FDIV (vectors, predicated)
+    0b01100101_01001101_10000000_00000000 = [Z(WORD), PMerge, Z(WORD), Z(WORD)] => [R(0), R3(10), RTied, R(5)], SVE;
+    0b01100101_10001101_10000000_00000000 = [Z(DWORD), PMerge, Z(DWORD), Z(DWORD)] => [R(0), R3(10), RTied, R(5)], SVE;
+    0b01100101_11001101_10000000_00000000 = [Z(QWORD), PMerge, Z(QWORD), Z(QWORD)] => [R(0), R3(10), RTied, R(5)], SVE;
+]
+"fdivr" = [
+    0b01100101_01001100_10000000_00000000 = [Z(WORD), PMerge, Z(WORD), Z(WORD)] => [R(0), R3(10), RTied, R(5)], SVE;
+    0b01100101_10001100_10000000_00000000 = [Z(DWORD), PMerge, Z(DWORD), Z(DWORD)] => [R(0), R3(10), RTied, R(5)], SVE;
+    0b01100101_11001100_10000000_00000000 = [Z(QWORD), PMerge, Z(QWORD), Z(QWORD)] => [R(0), R3(10), RTied, R(5)], SVE;
+]
+"fdup" = [
+    0b00100101_01111001_11000000_00000000 = [Z(WORD), Imm] => [R(0), Special(5, FLOAT_IMMEDIATE)], SVE;
+    0b00100101_10111001_11000000_00000000 = [Z(DWORD), Imm] => [R(0), Special(5, FLOAT_IMMEDIATE)], SVE;
+    0b00100101_11111001_11000000_00000000 = [Z(QWORD), Imm] => [R(0), Special(5, FLOAT_IMMEDIATE)], SVE;
+]
+"fexpa" = [
+    0b00000100_01100000_10111000_00000000 = [Z(WORD), Z(WORD)] => [R(0), R(5)], SVE;
+    0b00000100_10100000_10111000_00000000 = [Z(DWORD), Z(DWORD)] => [R(0), R(5)], SVE;
+    0b00000100_11100000_10111000_00000000 = [Z(QWORD), Z(QWORD)] => [R(0), R(5)], SVE;
+]
+"fjcvtzs" = [
+    0b00011110_01111110_00000000_00000000 = [W, D] => [R(0), R(5)], JSCONV;
+]
+"flogb" = [
+    0b01100101_00011010_10100000_00000000 = [Z(WORD), PMerge, Z(WORD)] => [R(0), R3(10), R(5)], SVE2;
+    0b01100101_00011100_10100000_00000000 = [Z(DWORD), PMerge, Z(DWORD)] => [R(0), R3(10), R(5)], SVE2;
+    0b01100101_00011110_10100000_00000000 = [Z(QWORD), PMerge, Z(QWORD)] => [R(0), R3(10), R(5)], SVE2;
+]
+"fmad" = [
+    0b01100101_01100000_10000000_00000000 = [Z(WORD), PMerge, Z(WORD), Z(WORD)] => [R(0), R3(10), R(5), R(16)], SVE;
+    0b01100101_10100000_10000000_00000000 = [Z(DWORD), PMerge, Z(DWORD), Z(DWORD)] => [R(0), R3(10), R(5), R(16)], SVE;
+    0b01100101_11100000_10000000_00000000 = [Z(QWORD), PMerge, Z(QWORD), Z(QWORD)] => [R(0), R3(10), R(5), R(16)], SVE;
+]
+"fmadd" = [
+    0b00011111_11000000_00000000_00000000 = [H, H, H, H] => [R(0), R(5), R(16), R(10)], FP16;
+    0b00011111_00000000_00000000_00000000 = [S, S, S, S] => [R(0), R(5), R(16), R(10)];
+    0b00011111_01000000_00000000_00000000 = [D, D, D, D] => [R(0), R(5), R(16), R(10)];
+]
+"fmax" = [
+    // FMAX (vector)
+    0b00001110_01000000_00110100_00000000 = [V(WORD), V(WORD), V(WORD)] => [R(0), R(5), R(16), Rwidth(30)], FP16;
+    0b00001110_00100000_11110100_00000000 = [V(DWORD), V(DWORD), V(DWORD)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b00001110_01100000_11110100_00000000 = [VStatic(QWORD, 2), VStatic(QWORD, 2), VStatic(QWORD, 2)] => [R(0), R(5), R(16), Rwidth(30)];
+    // FMAX (scalar)
+    0b00011110_11100000_01001000_00000000 = [H, H, H] => [R(0), R(5), R(16)], FP16;
+    0b00011110_00100000_01001000_00000000 = [S, S, S] => [R(0), R(5), R(16)];
+    0b00011110_01100000_01001000_00000000 = [D, D, D] => [R(0), R(5), R(16)];
+    // FMAX (vectors, predicated)
+    0b01100101_01000110_10000000_00000000 = [Z(WORD), PMerge, Z(WORD), Z(WORD)] => [R(0), R3(10), RTied, R(5)], SVE;
+    0b01100101_10000110_10000000_00000000 = [Z(DWORD), PMerge, Z(DWORD), Z(DWORD)] => [R(0), R3(10), RTied, R(5)], SVE;
+    0b01100101_11000110_10000000_00000000 = [Z(QWORD), PMerge, Z(QWORD), Z(QWORD)] => [R(0), R3(10), RTied, R(5)], SVE;
+    // FMAX (immediate)
+    0b01100101_01011110_10000000_00000000 = [Z(WORD), PMerge, Z(WORD), LitFloat(0.0)] => [R(0), R3(10), RTied], SVE;
+    0b01100101_10011110_10000000_00000000 = [Z(DWORD), PMerge, Z(DWORD), LitFloat(0.0)] => [R(0), R3(10), RTied], SVE;
+    0b01100101_11011110_10000000_00000000 = [Z(QWORD), PMerge, Z(QWORD), LitFloat(0.0)] => [R(0), R3(10), RTied], SVE;
+    0b01100101_01011110_10000000_00100000 = [Z(WORD), PMerge, Z(WORD), LitFloat(1.0)] => [R(0), R3(10), RTied], SVE;
+    0b01100101_10011110_10000000_00100000 = [Z(DWORD), PMerge, Z(DWORD), LitFloat(1.0)] => [R(0), R3(10), RTied], SVE;
+    0b01100101_11011110_10000000_00100000 = [Z(QWORD), PMerge, Z(QWORD), LitFloat(1.0)] => [R(0), R3(10), RTied], SVE;
+]
+"fmaxnm" = [
+    // FMAXNM (vector)
+    0b00001110_01000000_00000100_00000000 = [V(WORD), V(WORD), V(WORD)] => [R(0), R(5), R(16), Rwidth(30)], FP16;
+    0b00001110_00100000_11000100_00000000 = [V(DWORD), V(DWORD), V(DWORD)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b00001110_01100000_11000100_00000000 = [VStatic(QWORD, 2), VStatic(QWORD, 2), VStatic(QWORD, 2)] => [R(0), R(5), R(16), Rwidth(30)];
+    // FMAXNM (scalar)
+    0b00011110_11100000_01101000_00000000 = [H, H, H] => [R(0), R(5), R(16)], FP16;
+    0b00011110_00100000_01101000_00000000 = [S, S, S] => [R(0), R(5), R(16)];
+    0b00011110_01100000_01101000_00000000 = [D, D, D] => [R(0), R(5), R(16)];
+    // FMAXNM (vectors, predicated)
+    0b01100101_01000100_10000000_00000000 = [Z(WORD), PMerge, Z(WORD), Z(WORD)] => [R(0), R3(10), RTied, R(5)], SVE;
+    0b01100101_10000100_10000000_00000000 = [Z(DWORD), PMerge, Z(DWORD), Z(DWORD)] => [R(0), R3(10), RTied, R(5)], SVE;
+    0b01100101_11000100_10000000_00000000 = [Z(QWORD), PMerge, Z(QWORD), Z(QWORD)] => [R(0), R3(10), RTied, R(5)], SVE;
+    // FMAXNM (immediate)
+    0b01100101_01011100_10000000_00000000 = [Z(WORD), PMerge, Z(WORD), LitFloat(0.0)] => [R(0), R3(10), RTied], SVE;
+    0b01100101_10011100_10000000_00000000 = [Z(DWORD), PMerge, Z(DWORD), LitFloat(0.0)] => [R(0), R3(10), RTied], SVE;
+    0b01100101_11011100_10000000_00000000 = [Z(QWORD), PMerge, Z(QWORD), LitFloat(0.0)] => [R(0), R3(10), RTied], SVE;
+    0b01100101_01011100_10000000_00100000 = [Z(WORD), PMerge, Z(WORD), LitFloat(1.0)] => [R(0), R3(10), RTied], SVE;
+    0b01100101_10011100_10000000_00100000 = [Z(DWORD), PMerge, Z(DWORD), LitFloat(1.0)] => [R(0), R3(10), RTied], SVE;
+    0b01100101_11011100_10000000_00100000 = [Z(QWORD), PMerge, Z(QWORD), LitFloat(1.0)] => [R(0), R3(10), RTied], SVE;
+]
+"fmaxnmp" = [
+    // FMAXNMP (scalar)
+    0b01011110_00110000_11001000_00000000 = [H, VStatic(WORD, 2)] => [R(0), R(5)], FP16;
+    0b01111110_00110000_11001000_00000000 = [S, VStatic(DWORD, 2)] => [R(0), R(5)];
+    0b01111110_01110000_11001000_00000000 = [D, VStatic(QWORD, 2)] => [R(0), R(5)];
+    // FMAXNMP (vector)
+    0b00101110_01000000_00000100_00000000 = [V(WORD), V(WORD), V(WORD)] => [R(0), R(5), R(16), Rwidth(30)], FP16;
+    0b00101110_00100000_11000100_00000000 = [V(DWORD), V(DWORD), V(DWORD)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b00101110_01100000_11000100_00000000 = [VStatic(QWORD, 2), VStatic(QWORD, 2), VStatic(QWORD, 2)] => [R(0), R(5), R(16), Rwidth(30)];
+    // FMAXNMP (vectors, predicated)
+    0b01100100_01010100_10000000_00000000 = [Z(WORD), PMerge, Z(WORD), Z(WORD)] => [R(0), R3(10), RTied, R(5)], SVE2;
+    0b01100100_10010100_10000000_00000000 = [Z(DWORD), PMerge, Z(DWORD), Z(DWORD)] => [R(0), R3(10), RTied, R(5)], SVE2;
+    0b01100100_11010100_10000000_00000000 = [Z(QWORD), PMerge, Z(QWORD), Z(QWORD)] => [R(0), R3(10), RTied, R(5)], SVE2;
+]
+"fmaxnmv" = [
+    // FMAXNMV
+    0b00001110_00110000_11001000_00000000 = [H, V(WORD)] => [R(0), R(5), Rwidth(30)], FP16;
+    0b00101110_00110000_11001000_00000000 = [S, VStatic(DWORD, 4)] => [R(0), R(5), Rwidth(30)];
+    // FMAXNMV (reduction)
+    0b01100101_01000100_00100000_00000000 = [H, PBare, Z(WORD)] => [R(0), R3(10), R(5)], SVE;
+    0b01100101_10000100_00100000_00000000 = [S, PBare, Z(DWORD)] => [R(0), R3(10), R(5)], SVE;
+    0b01100101_11000100_00100000_00000000 = [D, PBare, Z(QWORD)] => [R(0), R3(10), R(5)], SVE;
+]
+"fmaxp" = [
+    // FMAXP (scalar)
+    0b01011110_00110000_11111000_00000000 = [H, VStatic(WORD, 2)] => [R(0), R(5)], FP16;
+    0b01111110_00110000_11111000_00000000 = [S, VStatic(DWORD, 2)] => [R(0), R(5)];
+    0b01111110_01110000_11111000_00000000 = [D, VStatic(QWORD, 2)] => [R(0), R(5)];
+    // FMAXP (vector)
+    0b00101110_01000000_00110100_00000000 = [V(WORD), V(WORD), V(WORD)] => [R(0), R(5), R(16), Rwidth(30)], FP16;
+    0b00101110_00100000_11110100_00000000 = [V(DWORD), V(DWORD), V(DWORD)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b00101110_01100000_11110100_00000000 = [VStatic(QWORD, 2), VStatic(QWORD, 2), VStatic(QWORD, 2)] => [R(0), R(5), R(16), Rwidth(30)];
+    // FMAXP (vectors, predicated)
+    0b01100100_01010110_10000000_00000000 = [Z(WORD), PMerge, Z(WORD), Z(WORD)] => [R(0), R3(10), RTied, R(5)], SVE2;
+    0b01100100_10010110_10000000_00000000 = [Z(DWORD), PMerge, Z(DWORD), Z(DWORD)] => [R(0), R3(10), RTied, R(5)], SVE2;
+    0b01100100_11010110_10000000_00000000 = [Z(QWORD), PMerge, Z(QWORD), Z(QWORD)] => [R(0), R3(10), RTied, R(5)], SVE2;
+]
+"fmaxv" = [
+    // FMAXV
+    0b00001110_00110000_11111000_00000000 = [H, V(WORD)] => [R(0), R(5), Rwidth(30)], FP16;
+    0b00101110_00110000_11111000_00000000 = [S, VStatic(DWORD, 4)] => [R(0), R(5), Rwidth(30)];
+    // FMAXV (reduction)
+    0b01100101_01000110_00100000_00000000 = [H, PBare, Z(WORD)] => [R(0), R3(10), R(5)], SVE;
+    0b01100101_10000110_00100000_00000000 = [S, PBare, Z(DWORD)] => [R(0), R3(10), R(5)], SVE;
+    0b01100101_11000110_00100000_00000000 = [D, PBare, Z(QWORD)] => [R(0), R3(10), R(5)], SVE;
+]
+"fmin" = [
+    // FMIN (vector)
+    0b00001110_11000000_00110100_00000000 = [V(WORD), V(WORD), V(WORD)] => [R(0), R(5), R(16), Rwidth(30)], FP16;
+    0b00001110_10100000_11110100_00000000 = [V(DWORD), V(DWORD), V(DWORD)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b00001110_11100000_11110100_00000000 = [VStatic(QWORD, 2), VStatic(QWORD, 2), VStatic(QWORD, 2)] => [R(0), R(5), R(16), Rwidth(30)];
+    // FMIN (scalar)
+    0b00011110_11100000_01011000_00000000 = [H, H, H] => [R(0), R(5), R(16)], FP16;
+    0b00011110_00100000_01011000_00000000 = [S, S, S] => [R(0), R(5), R(16)];
+    0b00011110_01100000_01011000_00000000 = [D, D, D] => [R(0), R(5), R(16)];
+    // FMIN (vectors, predicated)
+    0b01100101_01000111_10000000_00000000 = [Z(WORD), PMerge, Z(WORD), Z(WORD)] => [R(0), R3(10), RTied, R(5)], SVE;
+    0b01100101_10000111_10000000_00000000 = [Z(DWORD), PMerge, Z(DWORD), Z(DWORD)] => [R(0), R3(10), RTied, R(5)], SVE;
+    0b01100101_11000111_10000000_00000000 = [Z(QWORD), PMerge, Z(QWORD), Z(QWORD)] => [R(0), R3(10), RTied, R(5)], SVE;
+    // FMIN (immediate)
+    0b01100101_01011111_10000000_00000000 = [Z(WORD), PMerge, Z(WORD), LitFloat(0.0)] => [R(0), R3(10), RTied], SVE;
+    0b01100101_10011111_10000000_00000000 = [Z(DWORD), PMerge, Z(DWORD), LitFloat(0.0)] => [R(0), R3(10), RTied], SVE;
+    0b01100101_11011111_10000000_00000000 = [Z(QWORD), PMerge, Z(QWORD), LitFloat(0.0)] => [R(0), R3(10), RTied], SVE;
+    0b01100101_01011111_10000000_00100000 = [Z(WORD), PMerge, Z(WORD), LitFloat(1.0)] => [R(0), R3(10), RTied], SVE;
+    0b01100101_10011111_10000000_00100000 = [Z(DWORD), PMerge, Z(DWORD), LitFloat(1.0)] => [R(0), R3(10), RTied], SVE;
+    0b01100101_11011111_10000000_00100000 = [Z(QWORD), PMerge, Z(QWORD), LitFloat(1.0)] => [R(0), R3(10), RTied], SVE;
+]
+"fminnm" = [
+    // FMINNM (vector)
+    0b00001110_11000000_00000100_00000000 = [V(WORD), V(WORD), V(WORD)] => [R(0), R(5), R(16), Rwidth(30)], FP16;
+    0b00001110_10100000_11000100_00000000 = [V(DWORD), V(DWORD), V(DWORD)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b00001110_11100000_11000100_00000000 = [VStatic(QWORD, 2), VStatic(QWORD, 2), VStatic(QWORD, 2)] => [R(0), R(5), R(16), Rwidth(30)];
+    // FMINNM (scalar)
+    0b00011110_11100000_01111000_00000000 = [H, H, H] => [R(0), R(5), R(16)], FP16;
+    0b00011110_00100000_01111000_00000000 = [S, S, S] => [R(0), R(5), R(16)];
+    0b00011110_01100000_01111000_00000000 = [D, D, D] => [R(0), R(5), R(16)];
+    // FMINNM (vectors, predicated)
+    0b01100101_01000101_10000000_00000000 = [Z(WORD), PMerge, Z(WORD), Z(WORD)] => [R(0), R3(10), RTied, R(5)], SVE;
+    0b01100101_10000101_10000000_00000000 = [Z(DWORD), PMerge, Z(DWORD), Z(DWORD)] => [R(0), R3(10), RTied, R(5)], SVE;
+    0b01100101_11000101_10000000_00000000 = [Z(QWORD), PMerge, Z(QWORD), Z(QWORD)] => [R(0), R3(10), RTied, R(5)], SVE;
+    // FMINNM (immediate)
+    0b01100101_01011101_10000000_00000000 = [Z(WORD), PMerge, Z(WORD), LitFloat(0.0)] => [R(0), R3(10), RTied], SVE;
+    0b01100101_10011101_10000000_00000000 = [Z(DWORD), PMerge, Z(DWORD), LitFloat(0.0)] => [R(0), R3(10), RTied], SVE;
+    0b01100101_11011101_10000000_00000000 = [Z(QWORD), PMerge, Z(QWORD), LitFloat(0.0)] => [R(0), R3(10), RTied], SVE;
+    0b01100101_01011101_10000000_00100000 = [Z(WORD), PMerge, Z(WORD), LitFloat(1.0)] => [R(0), R3(10), RTied], SVE;
+    0b01100101_10011101_10000000_00100000 = [Z(DWORD), PMerge, Z(DWORD), LitFloat(1.0)] => [R(0), R3(10), RTied], SVE;
+    0b01100101_11011101_10000000_00100000 = [Z(QWORD), PMerge, Z(QWORD), LitFloat(1.0)] => [R(0), R3(10), RTied], SVE;
+]
+"fminnmp" = [
+    // FMINNMP (scalar)
+    0b01011110_10110000_11001000_00000000 = [H, VStatic(WORD, 2)] => [R(0), R(5)], FP16;
+    0b01111110_10110000_11001000_00000000 = [S, VStatic(DWORD, 2)] => [R(0), R(5)];
+    0b01111110_11110000_11001000_00000000 = [D, VStatic(QWORD, 2)] => [R(0), R(5)];
+    // FMINNMP (vector)
+    0b00101110_11000000_00000100_00000000 = [V(WORD), V(WORD), V(WORD)] => [R(0), R(5), R(16), Rwidth(30)], FP16;
+    0b00101110_10100000_11000100_00000000 = [V(DWORD), V(DWORD), V(DWORD)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b00101110_11100000_11000100_00000000 = [VStatic(QWORD, 2), VStatic(QWORD, 2), VStatic(QWORD, 2)] => [R(0), R(5), R(16), Rwidth(30)];
+    // FMINNMP (vectors, predicated)
+    0b01100100_01010101_10000000_00000000 = [Z(WORD), PMerge, Z(WORD), Z(WORD)] => [R(0), R3(10), RTied, R(5)], SVE2;
+    0b01100100_10010101_10000000_00000000 = [Z(DWORD), PMerge, Z(DWORD), Z(DWORD)] => [R(0), R3(10), RTied, R(5)], SVE2;
+    0b01100100_11010101_10000000_00000000 = [Z(QWORD), PMerge, Z(QWORD), Z(QWORD)] => [R(0), R3(10), RTied, R(5)], SVE2;
+]
+"fminnmv" = [
+    // FMINNMV
+    0b00001110_10110000_11001000_00000000 = [H, V(WORD)] => [R(0), R(5), Rwidth(30)], FP16;
+    0b00101110_10110000_11001000_00000000 = [S, VStatic(DWORD, 4)] => [R(0), R(5), Rwidth(30)];
+    // FMINNMV (reduction)
+    0b01100101_01000101_00100000_00000000 = [H, PBare, Z(WORD)] => [R(0), R3(10), R(5)], SVE;
+    0b01100101_10000101_00100000_00000000 = [S, PBare, Z(DWORD)] => [R(0), R3(10), R(5)], SVE;
+    0b01100101_11000101_00100000_00000000 = [D, PBare, Z(QWORD)] => [R(0), R3(10), R(5)], SVE;
+]
+"fminp" = [
+    // FMINP (scalar)
+    0b01011110_10110000_11111000_00000000 = [H, VStatic(WORD, 2)] => [R(0), R(5)], FP16;
+    0b01111110_10110000_11111000_00000000 = [S, VStatic(DWORD, 2)] => [R(0), R(5)];
+    0b01111110_11110000_11111000_00000000 = [D, VStatic(QWORD, 2)] => [R(0), R(5)];
+    // FMINP (vector)
+    0b00101110_11000000_00110100_00000000 = [V(WORD), V(WORD), V(WORD)] => [R(0), R(5), R(16), Rwidth(30)], FP16;
+    0b00101110_10100000_11110100_00000000 = [V(DWORD), V(DWORD), V(DWORD)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b00101110_11100000_11110100_00000000 = [VStatic(QWORD, 2), VStatic(QWORD, 2), VStatic(QWORD, 2)] => [R(0), R(5), R(16), Rwidth(30)];
+    // FMINP (vectors, predicated)
+    0b01100100_01010111_10000000_00000000 = [Z(WORD), PMerge, Z(WORD), Z(WORD)] => [R(0), R3(10), RTied, R(5)], SVE2;
+    0b01100100_10010111_10000000_00000000 = [Z(DWORD), PMerge, Z(DWORD), Z(DWORD)] => [R(0), R3(10), RTied, R(5)], SVE2;
+    0b01100100_11010111_10000000_00000000 = [Z(QWORD), PMerge, Z(QWORD), Z(QWORD)] => [R(0), R3(10), RTied, R(5)], SVE2;
+]
+"fminv" = [
+    // FMINV
+    0b00001110_10110000_11111000_00000000 = [H, V(WORD)] => [R(0), R(5), Rwidth(30)], FP16;
+    0b00101110_10110000_11111000_00000000 = [S, VStatic(DWORD, 4)] => [R(0), R(5), Rwidth(30)];
+    // FMINV (reduction)
+    0b01100101_01000111_00100000_00000000 = [H, PBare, Z(WORD)] => [R(0), R3(10), R(5)], SVE;
+    0b01100101_10000111_00100000_00000000 = [S, PBare, Z(DWORD)] => [R(0), R3(10), R(5)], SVE;
+    0b01100101_11000111_00100000_00000000 = [D, PBare, Z(QWORD)] => [R(0), R3(10), R(5)], SVE;
+]
+"fmla" = [
+    // FMLA (by element)
+    0b01011111_00000000_00010000_00000000 = [H, H, VElement(WORD)] => [R(0), R(5), R4(16), Ufields(&[11, 21, 20])], FP16;
+    0b01011111_10000000_00010000_00000000 = [S, S, VElement(DWORD)] => [R(0), R(5), R(16), Ufields(&[11, 21])];
+    0b01011111_11000000_00010000_00000000 = [D, D, VElement(QWORD)] => [R(0), R(5), R(16), Ufields(&[11])];
+    0b00001111_00000000_00010000_00000000 = [V(WORD), V(WORD), VElement(WORD)] => [R(0), R(5), R4(16), Ufields(&[11, 21, 20]), Rwidth(30)], FP16;
+    0b00001111_10000000_00010000_00000000 = [V(DWORD), V(DWORD), VElement(DWORD)] => [R(0), R(5), R(16), Ufields(&[11, 21]), Rwidth(30)];
+    0b00001111_11000000_00010000_00000000 = [VStatic(QWORD, 2), VStatic(QWORD, 2), VElement(QWORD)] => [R(0), R(5), R(16), Ufields(&[11]), Rwidth(30)];
+    // FMLA (vector)
+    0b00001110_01000000_00001100_00000000 = [V(WORD), V(WORD), V(WORD)] => [R(0), R(5), R(16), Rwidth(30)], FP16;
+    0b00001110_00100000_11001100_00000000 = [V(DWORD), V(DWORD), V(DWORD)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b00001110_01100000_11001100_00000000 = [VStatic(QWORD, 2), VStatic(QWORD, 2), VStatic(QWORD, 2)] => [R(0), R(5), R(16), Rwidth(30)];
+    // FMLA (vectors)
+    0b01100101_01100000_00000000_00000000 = [Z(WORD), PMerge, Z(WORD), Z(WORD)] => [R(0), R3(10), R(5), R(16)], SVE;
+    0b01100101_10100000_00000000_00000000 = [Z(DWORD), PMerge, Z(DWORD), Z(DWORD)] => [R(0), R3(10), R(5), R(16)], SVE;
+    0b01100101_11100000_00000000_00000000 = [Z(QWORD), PMerge, Z(QWORD), Z(QWORD)] => [R(0), R3(10), R(5), R(16)], SVE;
+    // FMLA (indexed)
+    0b01100100_11100000_00000000_00000000 = [Z(QWORD), Z(QWORD), ZElement(QWORD)] => [R(0), R(5), R4(16), Ubits(20, 1)], SVE;
+    0b01100100_00100000_00000000_00000000 = [Z(WORD), Z(WORD), ZElement(WORD)] => [R(0), R(5), R3(16), Ufields(&[22, 20, 19])], SVE;
+    0b01100100_10100000_00000000_00000000 = [Z(DWORD), Z(DWORD), ZElement(DWORD)] => [R(0), R(5), R3(16), Ubits(19, 2)], SVE;
+]
+"fmlal" = [
+    // FMLAL, FMLAL2 (by element)
+    0b00001111_10000000_00000000_00000000 = [VStatic(DWORD, 2), VStatic(WORD, 2), VElement(WORD)] => [R(0), R(5), R4(16), Ufields(&[11, 21, 20])], FHM;
+    0b01001111_10000000_00000000_00000000 = [VStatic(DWORD, 4), VStatic(WORD, 4), VElement(WORD)] => [R(0), R(5), R4(16), Ufields(&[11, 21, 20])], FHM;
+    // FMLAL, FMLAL2 (vector)
+    0b00001110_00100000_11101100_00000000 = [VStatic(DWORD, 2), VStatic(WORD, 2), VStatic(WORD, 2)] => [R(0), R(5), R(16)], FHM;
+    0b01001110_00100000_11101100_00000000 = [VStatic(DWORD, 4), VStatic(WORD, 4), VStatic(WORD, 4)] => [R(0), R(5), R(16)], FHM;
+]
+"fmlal2" = [
+    // FMLAL, FMLAL2 (by element)
+    0b00101111_10000000_10000000_00000000 = [VStatic(DWORD, 2), VStatic(WORD, 2), VElement(WORD)] => [R(0), R(5), R4(16), Ufields(&[11, 21, 20])], FHM;
+    0b01101111_10000000_10000000_00000000 = [VStatic(DWORD, 4), VStatic(WORD, 4), VElement(WORD)] => [R(0), R(5), R4(16), Ufields(&[11, 21, 20])], FHM;
+    // FMLAL, FMLAL2 (vector)
+    0b00101110_00100000_11001100_00000000 = [VStatic(DWORD, 2), VStatic(WORD, 2), VStatic(WORD, 2)] => [R(0), R(5), R(16)], FHM;
+    0b01101110_00100000_11001100_00000000 = [VStatic(DWORD, 4), VStatic(WORD, 4), VStatic(WORD, 4)] => [R(0), R(5), R(16)], FHM;
+]
+"fmls" = [
+    // FMLS (by element)
+    0b01011111_00000000_01010000_00000000 = [H, H, VElement(WORD)] => [R(0), R(5), R4(16), Ufields(&[11, 21, 20])], FP16;
+    0b01011111_10000000_01010000_00000000 = [S, S, VElement(DWORD)] => [R(0), R(5), R(16), Ufields(&[11, 21])];
+    0b01011111_11000000_01010000_00000000 = [D, D, VElement(QWORD)] => [R(0), R(5), R(16), Ufields(&[11])];
+    0b00001111_00000000_01010000_00000000 = [V(WORD), V(WORD), VElement(WORD)] => [R(0), R(5), R4(16), Ufields(&[11, 21, 20]), Rwidth(30)], FP16;
+    0b00001111_10000000_01010000_00000000 = [V(DWORD), V(DWORD), VElement(DWORD)] => [R(0), R(5), R(16), Ufields(&[11, 21]), Rwidth(30)];
+    0b00001111_11000000_01010000_00000000 = [VStatic(QWORD, 2), VStatic(QWORD, 2), VElement(QWORD)] => [R(0), R(5), R(16), Ufields(&[11]), Rwidth(30)];
+    // FMLS (vector)
+    0b00001110_11000000_00001100_00000000 = [V(WORD), V(WORD), V(WORD)] => [R(0), R(5), R(16), Rwidth(30)], FP16;
+    0b00001110_10100000_11001100_00000000 = [V(DWORD), V(DWORD), V(DWORD)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b00001110_11100000_11001100_00000000 = [VStatic(QWORD, 2), VStatic(QWORD, 2), VStatic(QWORD, 2)] => [R(0), R(5), R(16), Rwidth(30)];
+    // FMLS (vectors)
+    0b01100101_01100000_00100000_00000000 = [Z(WORD), PMerge, Z(WORD), Z(WORD)] => [R(0), R3(10), R(5), R(16)], SVE;
+    0b01100101_10100000_00100000_00000000 = [Z(DWORD), PMerge, Z(DWORD), Z(DWORD)] => [R(0), R3(10), R(5), R(16)], SVE;
+    0b01100101_11100000_00100000_00000000 = [Z(QWORD), PMerge, Z(QWORD), Z(QWORD)] => [R(0), R3(10), R(5), R(16)], SVE;
+    // FMLS (indexed)
+    0b01100100_11100000_00000100_00000000 = [Z(QWORD), Z(QWORD), ZElement(QWORD)] => [R(0), R(5), R4(16), Ubits(20, 1)], SVE;
+    0b01100100_00100000_00000100_00000000 = [Z(WORD), Z(WORD), ZElement(WORD)] => [R(0), R(5), R3(16), Ufields(&[22, 20, 19])], SVE;
+    0b01100100_10100000_00000100_00000000 = [Z(DWORD), Z(DWORD), ZElement(DWORD)] => [R(0), R(5), R3(16), Ubits(19, 2)], SVE;
+]
+"fmlsl" = [
+    // FMLSL, FMLSL2 (by element)
+    0b00001111_10000000_01000000_00000000 = [VStatic(DWORD, 2), VStatic(WORD, 2), VElement(WORD)] => [R(0), R(5), R4(16), Ufields(&[11, 21, 20])], FHM;
+    0b01001111_10000000_01000000_00000000 = [VStatic(DWORD, 4), VStatic(WORD, 4), VElement(WORD)] => [R(0), R(5), R4(16), Ufields(&[11, 21, 20])], FHM;
+    // FMLSL, FMLSL2 (vector)
+    0b00001110_10100000_11101100_00000000 = [VStatic(DWORD, 2), VStatic(WORD, 2), VStatic(WORD, 2)] => [R(0), R(5), R(16)], FHM;
+    0b01001110_10100000_11101100_00000000 = [VStatic(DWORD, 4), VStatic(WORD, 4), VStatic(WORD, 4)] => [R(0), R(5), R(16)], FHM;
+]
+"fmlsl2" = [
+    // FMLSL, FMLSL2 (by element)
+    0b00101111_10000000_11000000_00000000 = [VStatic(DWORD, 2), VStatic(WORD, 2), VElement(WORD)] => [R(0), R(5), R4(16), Ufields(&[11, 21, 20])], FHM;
+    0b01101111_10000000_11000000_00000000 = [VStatic(DWORD, 4), VStatic(WORD, 4), VElement(WORD)] => [R(0), R(5), R4(16), Ufields(&[11, 21, 20])], FHM;
+    // FMLSL, FMLSL2 (vector)
+    0b00101110_10100000_11001100_00000000 = [VStatic(DWORD, 2), VStatic(WORD, 2), VStatic(WORD, 2)] => [R(0), R(5), R(16)], FHM;
+    0b01101110_10100000_11001100_00000000 = [VStatic(DWORD, 4), VStatic(WORD, 4), VStatic(WORD, 4)] => [R(0), R(5), R(16)], FHM;
+]
+"fmopa" = [
+    // FMOPA (widening)
+    0b10000001_10100000_00000000_00000000 = [ZaTile(DWORD), PMerge, PMerge, Z(WORD), Z(WORD)] => [RTile(0, 2), R3(10), R3(13), R(5), R(16)], SME;
+    // FMOPA (non-widening)
+    0b10000000_10000000_00000000_00000000 = [ZaTile(DWORD), PMerge, PMerge, Z(DWORD), Z(DWORD)] => [RTile(0, 2), R3(10), R3(13), R(5), R(16)], SME;
+    0b10000000_11000000_00000000_00000000 = [ZaTile(QWORD), PMerge, PMerge, Z(QWORD), Z(QWORD)] => [RTile(0, 3), R3(10), R3(13), R(5), R(16)], SME | SME_F64F64;
+]
+"fmops" = [
+    // FMOPS (widening)
+    0b10000001_10100000_00000000_00010000 = [ZaTile(DWORD), PMerge, PMerge, Z(WORD), Z(WORD)] => [RTile(0, 2), R3(10), R3(13), R(5), R(16)], SME;
+    // FMOPS (non-widening)
+    0b10000000_10000000_00000000_00010000 = [ZaTile(DWORD), PMerge, PMerge, Z(DWORD), Z(DWORD)] => [RTile(0, 2), R3(10), R3(13), R(5), R(16)], SME;
+    0b10000000_11000000_00000000_00010000 = [ZaTile(QWORD), PMerge, PMerge, Z(QWORD), Z(QWORD)] => [RTile(0, 3), R3(10), R3(13), R(5), R(16)], SME | SME_F64F64;
+]
+"fmov" = [
+    // FMOV (vector, immediate)
+    0b00001111_00000000_11111100_00000000 = [V(WORD), Imm] => [R(0), Special(5, SPLIT_FLOAT_IMMEDIATE), Rwidth(30)], FP16;
+    0b00001111_00000000_11110100_00000000 = [V(DWORD), Imm] => [R(0), Special(5, SPLIT_FLOAT_IMMEDIATE), Rwidth(30)];
+    0b01101111_00000000_11110100_00000000 = [VStatic(QWORD, 2), Imm] => [R(0), Special(5, SPLIT_FLOAT_IMMEDIATE)];
+    // FMOV (register)
+    0b00011110_11100000_01000000_00000000 = [H, H] => [R(0), R(5)], FP16;
+    0b00011110_00100000_01000000_00000000 = [S, S] => [R(0), R(5)];
+    0b00011110_01100000_01000000_00000000 = [D, D] => [R(0), R(5)];
+    // FMOV (general)
+    0b00011110_11100110_00000000_00000000 = [W, H] => [R(0), R(5)], FP16;
+    0b10011110_11100110_00000000_00000000 = [X, H] => [R(0), R(5)], FP16;
+    0b00011110_11100111_00000000_00000000 = [H, W] => [R(0), R(5)], FP16;
+    0b00011110_00100111_00000000_00000000 = [S, W] => [R(0), R(5)];
+    0b00011110_00100110_00000000_00000000 = [W, S] => [R(0), R(5)];
+    0b10011110_11100111_00000000_00000000 = [H, X] => [R(0), R(5)], FP16;
+    0b10011110_01100111_00000000_00000000 = [D, X] => [R(0), R(5)];
+    0b10011110_10101111_00000000_00000000 = [VElementStatic(QWORD, 1), X] => [R(0), R(5)];
+    0b10011110_01100110_00000000_00000000 = [X, D] => [R(0), R(5)];
+    0b10011110_10101110_00000000_00000000 = [X, VElementStatic(QWORD, 1)] => [R(0), R(5)];
+    // FMOV (scalar, immediate)
+    0b00011110_11100000_00010000_00000000 = [H, Imm] => [R(0), Special(13, FLOAT_IMMEDIATE)], FP16;
+    0b00011110_00100000_00010000_00000000 = [S, Imm] => [R(0), Special(13, FLOAT_IMMEDIATE)];
+    0b00011110_01100000_00010000_00000000 = [D, Imm] => [R(0), Special(13, FLOAT_IMMEDIATE)];
+    // FMOV (zero)
+    0b00100101_01111000_11000000_00000000 = [Z(WORD), LitFloat(0.0)] => [R(0)], SVE;
+    0b00100101_10111000_11000000_00000000 = [Z(DWORD), LitFloat(0.0)] => [R(0)], SVE;
+    0b00100101_11111000_11000000_00000000 = [Z(QWORD), LitFloat(0.0)] => [R(0)], SVE;
+    // FMOV (immediate, unpredicated)
+    0b00100101_01111001_11000000_00000000 = [Z(WORD), Imm] => [R(0), Special(5, FLOAT_IMMEDIATE)], SVE;
+    0b00100101_10111001_11000000_00000000 = [Z(DWORD), Imm] => [R(0), Special(5, FLOAT_IMMEDIATE)], SVE;
+    0b00100101_11111001_11000000_00000000 = [Z(QWORD), Imm] => [R(0), Special(5, FLOAT_IMMEDIATE)], SVE;
+    // FMOV (zero, predicated)
+    0b00000101_01010000_01000000_00000000 = [Z(WORD), PMerge, LitFloat(0.0)] => [R(0), R4(16)], SVE;
+    0b00000101_10010000_01000000_00000000 = [Z(DWORD), PMerge, LitFloat(0.0)] => [R(0), R4(16)], SVE;
+    0b00000101_11010000_01000000_00000000 = [Z(QWORD), PMerge, LitFloat(0.0)] => [R(0), R4(16)], SVE;
+    // FMOV (immediate, predicated)
+    0b00000101_01010000_11000000_00000000 = [Z(WORD), PMerge, Imm] => [R(0), R4(16), Special(5, FLOAT_IMMEDIATE)], SVE;
+    0b00000101_10010000_11000000_00000000 = [Z(DWORD), PMerge, Imm] => [R(0), R4(16), Special(5, FLOAT_IMMEDIATE)], SVE;
+    0b00000101_11010000_11000000_00000000 = [Z(QWORD), PMerge, Imm] => [R(0), R4(16), Special(5, FLOAT_IMMEDIATE)], SVE;
+]
+"fmsb" = [
+    0b01100101_01100000_10100000_00000000 = [Z(WORD), PMerge, Z(WORD), Z(WORD)] => [R(0), R3(10), R(5), R(16)], SVE;
+    0b01100101_10100000_10100000_00000000 = [Z(DWORD), PMerge, Z(DWORD), Z(DWORD)] => [R(0), R3(10), R(5), R(16)], SVE;
+    0b01100101_11100000_10100000_00000000 = [Z(QWORD), PMerge, Z(QWORD), Z(QWORD)] => [R(0), R3(10), R(5), R(16)], SVE;
+]
+"fmsub" = [
+    0b00011111_11000000_10000000_00000000 = [H, H, H, H] => [R(0), R(5), R(16), R(10)], FP16;
+    0b00011111_00000000_10000000_00000000 = [S, S, S, S] => [R(0), R(5), R(16), R(10)];
+    0b00011111_01000000_10000000_00000000 = [D, D, D, D] => [R(0), R(5), R(16), R(10)];
+]
+"fmul" = [
+    // FMUL (by element)
+    0b01011111_00000000_10010000_00000000 = [H, H, VElement(WORD)] => [R(0), R(5), R4(16), Ufields(&[11, 21, 20])], FP16;
+    0b01011111_10000000_10010000_00000000 = [S, S, VElement(DWORD)] => [R(0), R(5), R(16), Ufields(&[11, 21])];
+    0b01011111_11000000_10010000_00000000 = [D, D, VElement(QWORD)] => [R(0), R(5), R(16), Ufields(&[11])];
+    0b00001111_00000000_10010000_00000000 = [V(WORD), V(WORD), VElement(WORD)] => [R(0), R(5), R4(16), Ufields(&[11, 21, 20]), Rwidth(30)], FP16;
+    0b00001111_10000000_10010000_00000000 = [V(DWORD), V(DWORD), VElement(DWORD)] => [R(0), R(5), R(16), Ufields(&[11, 21]), Rwidth(30)];
+    0b00001111_11000000_10010000_00000000 = [VStatic(QWORD, 2), VStatic(QWORD, 2), VElement(QWORD)] => [R(0), R(5), R(16), Ufields(&[11]), Rwidth(30)];
+    // FMUL (vector)
+    0b00101110_01000000_00011100_00000000 = [V(WORD), V(WORD), V(WORD)] => [R(0), R(5), R(16), Rwidth(30)], FP16;
+    0b00101110_00100000_11011100_00000000 = [V(DWORD), V(DWORD), V(DWORD)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b00101110_01100000_11011100_00000000 = [VStatic(QWORD, 2), VStatic(QWORD, 2), VStatic(QWORD, 2)] => [R(0), R(5), R(16), Rwidth(30)];
+    // FMUL (scalar)
+    0b00011110_11100000_00001000_00000000 = [H, H, H] => [R(0), R(5), R(16)], FP16;
+    0b00011110_00100000_00001000_00000000 = [S, S, S] => [R(0), R(5), R(16)];
+    0b00011110_01100000_00001000_00000000 = [D, D, D] => [R(0), R(5), R(16)];
+    // FMUL (vectors, unpredicated)
+    0b01100101_01000000_00001000_00000000 = [Z(WORD), Z(WORD), Z(WORD)] => [R(0), R(5), R(16)], SVE;
+    0b01100101_10000000_00001000_00000000 = [Z(DWORD), Z(DWORD), Z(DWORD)] => [R(0), R(5), R(16)], SVE;
+    0b01100101_11000000_00001000_00000000 = [Z(QWORD), Z(QWORD), Z(QWORD)] => [R(0), R(5), R(16)], SVE;
+    // FMUL (indexed)
+    0b01100100_11100000_00100000_00000000 = [Z(QWORD), Z(QWORD), ZElement(QWORD)] => [R(0), R(5), R4(16), Ubits(20, 1)], SVE;
+    0b01100100_00100000_00100000_00000000 = [Z(WORD), Z(WORD), ZElement(WORD)] => [R(0), R(5), R3(16), Ufields(&[22, 20, 19])], SVE;
+    0b01100100_10100000_00100000_00000000 = [Z(DWORD), Z(DWORD), ZElement(DWORD)] => [R(0), R(5), R3(16), Ubits(19, 2)], SVE;
+    // FMUL (vectors, predicated)
+    0b01100101_01000010_10000000_00000000 = [Z(WORD), PMerge, Z(WORD), Z(WORD)] => [R(0), R3(10), RTied, R(5)], SVE;
+    0b01100101_10000010_10000000_00000000 = [Z(DWORD), PMerge, Z(DWORD), Z(DWORD)] => [R(0), R3(10), RTied, R(5)], SVE;
+    0b01100101_11000010_10000000_00000000 = [Z(QWORD), PMerge, Z(QWORD), Z(QWORD)] => [R(0), R3(10), RTied, R(5)], SVE;
+    // FMUL (immediate)
+    0b01100101_01011010_10000000_00000000 = [Z(WORD), PMerge, Z(WORD), LitFloat(0.5)] => [R(0), R3(10), RTied], SVE;
+    0b01100101_10011010_10000000_00000000 = [Z(DWORD), PMerge, Z(DWORD), LitFloat(0.5)] => [R(0), R3(10), RTied], SVE;
+    0b01100101_11011010_10000000_00000000 = [Z(QWORD), PMerge, Z(QWORD), LitFloat(0.5)] => [R(0), R3(10), RTied], SVE;
+    0b01100101_01011010_10000000_00100000 = [Z(WORD), PMerge, Z(WORD), LitFloat(2.0)] => [R(0), R3(10), RTied], SVE;
+    0b01100101_10011010_10000000_00100000 = [Z(DWORD), PMerge, Z(DWORD), LitFloat(2.0)] => [R(0), R3(10), RTied], SVE;
+    0b01100101_11011010_10000000_00100000 = [Z(QWORD), PMerge, Z(QWORD), LitFloat(2.0)] => [R(0), R3(10), RTied], SVE;
+]
+"fmulx" = [
+    // FMULX (by element)
+    0b01111111_00000000_10010000_00000000 = [H, H, VElement(WORD)] => [R(0), R(5), R4(16), Ufields(&[11, 21, 20])], FP16;
+    0b01111111_10000000_10010000_00000000 = [S, S, VElement(DWORD)] => [R(0), R(5), R(16), Ufields(&[11, 21])];
+    0b01111111_11000000_10010000_00000000 = [D, D, VElement(QWORD)] => [R(0), R(5), R(16), Ufields(&[11])];
+    0b00101111_00000000_10010000_00000000 = [V(WORD), V(WORD), VElement(WORD)] => [R(0), R(5), R4(16), Ufields(&[11, 21, 20]), Rwidth(30)], FP16;
+    0b00101111_10000000_10010000_00000000 = [V(DWORD), V(DWORD), VElement(DWORD)] => [R(0), R(5), R(16), Ufields(&[11, 21]), Rwidth(30)];
+    0b00101111_11000000_10010000_00000000 = [VStatic(QWORD, 2), VStatic(QWORD, 2), VElement(QWORD)] => [R(0), R(5), R(16), Ufields(&[11]), Rwidth(30)];
+    // FMULX
+    0b01011110_01000000_00011100_00000000 = [H, H, H] => [R(0), R(5), R(16)], FP16;
+    0b01011110_00100000_11011100_00000000 = [S, S, S] => [R(0), R(5), R(16)];
+    0b01011110_01100000_11011100_00000000 = [D, D, D] => [R(0), R(5), R(16)];
+    0b00001110_01000000_00011100_00000000 = [V(WORD), V(WORD), V(WORD)] => [R(0), R(5), R(16), Rwidth(30)], FP16;
+    0b00001110_00100000_11011100_00000000 = [V(DWORD), V(DWORD), V(DWORD)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b00001110_01100000_11011100_00000000 = [VStatic(QWORD, 2), VStatic(QWORD, 2), VStatic(QWORD, 2)] => [R(0), R(5), R(16), Rwidth(30)];
+    // FMULX (vectors, predicated)
+    0b01100101_01001010_10000000_00000000 = [Z(WORD), PMerge, Z(WORD), Z(WORD)] => [R(0), R3(10), RTied, R(5)], SVE;
+    0b01100101_10001010_10000000_00000000 = [Z(DWORD), PMerge, Z(DWORD), Z(DWORD)] => [R(0), R3(10), RTied, R(5)], SVE;
+    0b01100101_11001010_10000000_00000000 = [Z(QWORD), PMerge, Z(QWORD), Z(QWORD)] => [R(0), R3(10), RTied, R(5)], SVE;
+]
+"fneg" = [
+    // FNEG (vector)
+    0b00101110_11111000_11111000_00000000 = [V(WORD), V(WORD)] => [R(0), R(5), Rwidth(30)], FP16;
+    0b00101110_10100000_11111000_00000000 = [V(DWORD), V(DWORD)] => [R(0), R(5), Rwidth(30)];
+    0b00101110_11100000_11111000_00000000 = [VStatic(QWORD, 2), VStatic(QWORD, 2)] => [R(0), R(5), Rwidth(30)];
+    // FNEG (scalar)
+    0b00011110_11100001_01000000_00000000 = [H, H] => [R(0), R(5)], FP16;
+    0b00011110_00100001_01000000_00000000 = [S, S] => [R(0), R(5)];
+    0b00011110_01100001_01000000_00000000 = [D, D] => [R(0), R(5)];
+    // FNEG (predicated)
+    0b00000100_01011101_10100000_00000000 = [Z(WORD), PMerge, Z(WORD)] => [R(0), R3(10), R(5)], SVE;
+    0b00000100_10011101_10100000_00000000 = [Z(DWORD), PMerge, Z(DWORD)] => [R(0), R3(10), R(5)], SVE;
+    0b00000100_11011101_10100000_00000000 = [Z(QWORD), PMerge, Z(QWORD)] => [R(0), R3(10), R(5)], SVE;
+]
+"fnmad" = [
+    0b01100101_01100000_11000000_00000000 = [Z(WORD), PMerge, Z(WORD), Z(WORD)] => [R(0), R3(10), R(5), R(16)], SVE;
+    0b01100101_10100000_11000000_00000000 = [Z(DWORD), PMerge, Z(DWORD), Z(DWORD)] => [R(0), R3(10), R(5), R(16)], SVE;
+    0b01100101_11100000_11000000_00000000 = [Z(QWORD), PMerge, Z(QWORD), Z(QWORD)] => [R(0), R3(10), R(5), R(16)], SVE;
+]
+"fnmadd" = [
+    0b00011111_11100000_00000000_00000000 = [H, H, H, H] => [R(0), R(5), R(16), R(10)], FP16;
+    0b00011111_00100000_00000000_00000000 = [S, S, S, S] => [R(0), R(5), R(16), R(10)];
+    0b00011111_01100000_00000000_00000000 = [D, D, D, D] => [R(0), R(5), R(16), R(10)];
+]
+"fnmla" = [
+    0b01100101_01100000_01000000_00000000 = [Z(WORD), PMerge, Z(WORD), Z(WORD)] => [R(0), R3(10), R(5), R(16)], SVE;
+    0b01100101_10100000_01000000_00000000 = [Z(DWORD), PMerge, Z(DWORD), Z(DWORD)] => [R(0), R3(10), R(5), R(16)], SVE;
+    0b01100101_11100000_01000000_00000000 = [Z(QWORD), PMerge, Z(QWORD), Z(QWORD)] => [R(0), R3(10), R(5), R(16)], SVE;
+]
+"fnmls" = [
+    0b01100101_01100000_01100000_00000000 = [Z(WORD), PMerge, Z(WORD), Z(WORD)] => [R(0), R3(10), R(5), R(16)], SVE;
+    0b01100101_10100000_01100000_00000000 = [Z(DWORD), PMerge, Z(DWORD), Z(DWORD)] => [R(0), R3(10), R(5), R(16)], SVE;
+    0b01100101_11100000_01100000_00000000 = [Z(QWORD), PMerge, Z(QWORD), Z(QWORD)] => [R(0), R3(10), R(5), R(16)], SVE;
+]
+"fnmsb" = [
+    0b01100101_01100000_11100000_00000000 = [Z(WORD), PMerge, Z(WORD), Z(WORD)] => [R(0), R3(10), R(5), R(16)], SVE;
+    0b01100101_10100000_11100000_00000000 = [Z(DWORD), PMerge, Z(DWORD), Z(DWORD)] => [R(0), R3(10), R(5), R(16)], SVE;
+    0b01100101_11100000_11100000_00000000 = [Z(QWORD), PMerge, Z(QWORD), Z(QWORD)] => [R(0), R3(10), R(5), R(16)], SVE;
+]
+"fnmsub" = [
+    0b00011111_11100000_10000000_00000000 = [H, H, H, H] => [R(0), R(5), R(16), R(10)], FP16;
+    0b00011111_00100000_10000000_00000000 = [S, S, S, S] => [R(0), R(5), R(16), R(10)];
+    0b00011111_01100000_10000000_00000000 = [D, D, D, D] => [R(0), R(5), R(16), R(10)];
+]
+"fnmul" = [
+    0b00011110_11100000_10001000_00000000 = [H, H, H] => [R(0), R(5), R(16)], FP16;
+    0b00011110_00100000_10001000_00000000 = [S, S, S] => [R(0), R(5), R(16)];
+    0b00011110_01100000_10001000_00000000 = [D, D, D] => [R(0), R(5), R(16)];
+]
+"frecpe" = [
+    // FRECPE
+    0b01011110_11111001_11011000_00000000 = [H, H] => [R(0), R(5)], FP16;
+    0b01011110_10100001_11011000_00000000 = [S, S] => [R(0), R(5)];
+    0b01011110_11100001_11011000_00000000 = [D, D] => [R(0), R(5)];
+    0b00001110_11111001_11011000_00000000 = [V(WORD), V(WORD)] => [R(0), R(5), Rwidth(30)], FP16;
+    0b00001110_10100001_11011000_00000000 = [V(DWORD), V(DWORD)] => [R(0), R(5), Rwidth(30)];
+    0b00001110_11100001_11011000_00000000 = [VStatic(QWORD, 2), VStatic(QWORD, 2)] => [R(0), R(5), Rwidth(30)];
+    // FRECPE (unpredicated)
+    0b01100101_01001110_00110000_00000000 = [Z(WORD), Z(WORD)] => [R(0), R(5)], SVE;
+    0b01100101_10001110_00110000_00000000 = [Z(DWORD), Z(DWORD)] => [R(0), R(5)], SVE;
+    0b01100101_11001110_00110000_00000000 = [Z(QWORD), Z(QWORD)] => [R(0), R(5)], SVE;
+]
+"frecps" = [
+    // FRECPS
+    0b01011110_01000000_00111100_00000000 = [H, H, H] => [R(0), R(5), R(16)], FP16;
+    0b01011110_00100000_11111100_00000000 = [S, S, S] => [R(0), R(5), R(16)];
+    0b01011110_01100000_11111100_00000000 = [D, D, D] => [R(0), R(5), R(16)];
+    0b00001110_01000000_00111100_00000000 = [V(WORD), V(WORD), V(WORD)] => [R(0), R(5), R(16), Rwidth(30)], FP16;
+    0b00001110_00100000_11111100_00000000 = [V(DWORD), V(DWORD), V(DWORD)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b00001110_01100000_11111100_00000000 = [VStatic(QWORD, 2), VStatic(QWORD, 2), VStatic(QWORD, 2)] => [R(0), R(5), R(16), Rwidth(30)];
+    // FRECPS (vectors, unpredicated)
+    0b01100101_01000000_00011000_00000000 = [Z(WORD), Z(WORD), Z(WORD)] => [R(0), R(5), R(16)], SVE;
+    0b01100101_10000000_00011000_00000000 = [Z(DWORD), Z(DWORD), Z(DWORD)] => [R(0), R(5), R(16)], SVE;
+    0b01100101_11000000_00011000_00000000 = [Z(QWORD), Z(QWORD), Z(QWORD)] => [R(0), R(5), R(16)], SVE;
+]
+"frecpx" = [
+    // FRECPX
+    0b01011110_11111001_11111000_00000000 = [H, H] => [R(0), R(5)], FP16;
+    0b01011110_10100001_11111000_00000000 = [S, S] => [R(0), R(5)];
+    0b01011110_11100001_11111000_00000000 = [D, D] => [R(0), R(5)];
+    // FRECPX (predicated)
+    0b01100101_01001100_10100000_00000000 = [Z(WORD), PMerge, Z(WORD)] => [R(0), R3(10), R(5)], SVE;
+    0b01100101_10001100_10100000_00000000 = [Z(DWORD), PMerge, Z(DWORD)] => [R(0), R3(10), R(5)], SVE;
+    0b01100101_11001100_10100000_00000000 = [Z(QWORD), PMerge, Z(QWORD)] => [R(0), R3(10), R(5)], SVE;
+]
+"frinta" = [
+    // FRINTA (vector)
+    0b00101110_01111001_10001000_00000000 = [V(WORD), V(WORD)] => [R(0), R(5), Rwidth(30)], FP16;
+    0b00101110_00100001_10001000_00000000 = [V(DWORD), V(DWORD)] => [R(0), R(5), Rwidth(30)];
+    0b00101110_01100001_10001000_00000000 = [VStatic(QWORD, 2), VStatic(QWORD, 2)] => [R(0), R(5), Rwidth(30)];
+    // FRINTA (scalar)
+    0b00011110_11100110_01000000_00000000 = [H, H] => [R(0), R(5)], FP16;
+    0b00011110_00100110_01000000_00000000 = [S, S] => [R(0), R(5)];
+    0b00011110_01100110_01000000_00000000 = [D, D] => [R(0), R(5)];
+    // FRINTA (predicated)
+    0b01100101_01000100_10100000_00000000 = [Z(WORD), PMerge, Z(WORD)] => [R(0), R3(10), R(5)], SVE;
+    0b01100101_10000100_10100000_00000000 = [Z(DWORD), PMerge, Z(DWORD)] => [R(0), R3(10), R(5)], SVE;
+    0b01100101_11000100_10100000_00000000 = [Z(QWORD), PMerge, Z(QWORD)] => [R(0), R3(10), R(5)], SVE;
+]
+"frinti" = [
+    // FRINTI (vector)
+    0b00101110_11111001_10011000_00000000 = [V(WORD), V(WORD)] => [R(0), R(5), Rwidth(30)], FP16;
+    0b00101110_10100001_10011000_00000000 = [V(DWORD), V(DWORD)] => [R(0), R(5), Rwidth(30)];
+    0b00101110_11100001_10011000_00000000 = [VStatic(QWORD, 2), VStatic(QWORD, 2)] => [R(0), R(5), Rwidth(30)];
+    // FRINTI (scalar)
+    0b00011110_11100111_11000000_00000000 = [H, H] => [R(0), R(5)], FP16;
+    0b00011110_00100111_11000000_00000000 = [S, S] => [R(0), R(5)];
+    0b00011110_01100111_11000000_00000000 = [D, D] => [R(0), R(5)];
+    // FRINTI (predicated)
+    0b01100101_01000111_10100000_00000000 = [Z(WORD), PMerge, Z(WORD)] => [R(0), R3(10), R(5)], SVE;
+    0b01100101_10000111_10100000_00000000 = [Z(DWORD), PMerge, Z(DWORD)] => [R(0), R3(10), R(5)], SVE;
+    0b01100101_11000111_10100000_00000000 = [Z(QWORD), PMerge, Z(QWORD)] => [R(0), R3(10), R(5)], SVE;
+]
+"frintm" = [
+    // FRINTM (vector)
+    0b00001110_01111001_10011000_00000000 = [V(WORD), V(WORD)] => [R(0), R(5), Rwidth(30)], FP16;
+    0b00001110_00100001_10011000_00000000 = [V(DWORD), V(DWORD)] => [R(0), R(5), Rwidth(30)];
+    0b00001110_01100001_10011000_00000000 = [VStatic(QWORD, 2), VStatic(QWORD, 2)] => [R(0), R(5), Rwidth(30)];
+    // FRINTM (scalar)
+    0b00011110_11100101_01000000_00000000 = [H, H] => [R(0), R(5)], FP16;
+    0b00011110_00100101_01000000_00000000 = [S, S] => [R(0), R(5)];
+    0b00011110_01100101_01000000_00000000 = [D, D] => [R(0), R(5)];
+    // FRINTM (predicated)
+    0b01100101_01000010_10100000_00000000 = [Z(WORD), PMerge, Z(WORD)] => [R(0), R3(10), R(5)], SVE;
+    0b01100101_10000010_10100000_00000000 = [Z(DWORD), PMerge, Z(DWORD)] => [R(0), R3(10), R(5)], SVE;
+    0b01100101_11000010_10100000_00000000 = [Z(QWORD), PMerge, Z(QWORD)] => [R(0), R3(10), R(5)], SVE;
+]
+"frintn" = [
+    // FRINTN (vector)
+    0b00001110_01111001_10001000_00000000 = [V(WORD), V(WORD)] => [R(0), R(5), Rwidth(30)], FP16;
+    0b00001110_00100001_10001000_00000000 = [V(DWORD), V(DWORD)] => [R(0), R(5), Rwidth(30)];
+    0b00001110_01100001_10001000_00000000 = [VStatic(QWORD, 2), VStatic(QWORD, 2)] => [R(0), R(5), Rwidth(30)];
+    // FRINTN (scalar)
+    0b00011110_11100100_01000000_00000000 = [H, H] => [R(0), R(5)], FP16;
+    0b00011110_00100100_01000000_00000000 = [S, S] => [R(0), R(5)];
+    0b00011110_01100100_01000000_00000000 = [D, D] => [R(0), R(5)];
+    // FRINTN (predicated)
+    0b01100101_01000000_10100000_00000000 = [Z(WORD), PMerge, Z(WORD)] => [R(0), R3(10), R(5)], SVE;
+    0b01100101_10000000_10100000_00000000 = [Z(DWORD), PMerge, Z(DWORD)] => [R(0), R3(10), R(5)], SVE;
+    0b01100101_11000000_10100000_00000000 = [Z(QWORD), PMerge, Z(QWORD)] => [R(0), R3(10), R(5)], SVE;
+]
+"frintp" = [
+    // FRINTP (vector)
+    0b00001110_11111001_10001000_00000000 = [V(WORD), V(WORD)] => [R(0), R(5), Rwidth(30)], FP16;
+    0b00001110_10100001_10001000_00000000 = [V(DWORD), V(DWORD)] => [R(0), R(5), Rwidth(30)];
+    0b00001110_11100001_10001000_00000000 = [VStatic(QWORD, 2), VStatic(QWORD, 2)] => [R(0), R(5), Rwidth(30)];
+    // FRINTP (scalar)
+    0b00011110_11100100_11000000_00000000 = [H, H] => [R(0), R(5)], FP16;
+    0b00011110_00100100_11000000_00000000 = [S, S] => [R(0), R(5)];
+    0b00011110_01100100_11000000_00000000 = [D, D] => [R(0), R(5)];
+    // FRINTP (predicated)
+    0b01100101_01000001_10100000_00000000 = [Z(WORD), PMerge, Z(WORD)] => [R(0), R3(10), R(5)], SVE;
+    0b01100101_10000001_10100000_00000000 = [Z(DWORD), PMerge, Z(DWORD)] => [R(0), R3(10), R(5)], SVE;
+    0b01100101_11000001_10100000_00000000 = [Z(QWORD), PMerge, Z(QWORD)] => [R(0), R3(10), R(5)], SVE;
+]
+"frintx" = [
+    // FRINTX (vector)
+    0b00101110_01111001_10011000_00000000 = [V(WORD), V(WORD)] => [R(0), R(5), Rwidth(30)], FP16;
+    0b00101110_00100001_10011000_00000000 = [V(DWORD), V(DWORD)] => [R(0), R(5), Rwidth(30)];
+    0b00101110_01100001_10011000_00000000 = [VStatic(QWORD, 2), VStatic(QWORD, 2)] => [R(0), R(5), Rwidth(30)];
+    // FRINTX (scalar)
+    0b00011110_11100111_01000000_00000000 = [H, H] => [R(0), R(5)], FP16;
+    0b00011110_00100111_01000000_00000000 = [S, S] => [R(0), R(5)];
+    0b00011110_01100111_01000000_00000000 = [D, D] => [R(0), R(5)];
+    // FRINTX (predicated)
+    0b01100101_01000110_10100000_00000000 = [Z(WORD), PMerge, Z(WORD)] => [R(0), R3(10), R(5)], SVE;
+    0b01100101_10000110_10100000_00000000 = [Z(DWORD), PMerge, Z(DWORD)] => [R(0), R3(10), R(5)], SVE;
+    0b01100101_11000110_10100000_00000000 = [Z(QWORD), PMerge, Z(QWORD)] => [R(0), R3(10), R(5)], SVE;
+]
+"frintz" = [
+    // FRINTZ (vector)
+    0b00001110_11111001_10011000_00000000 = [V(WORD), V(WORD)] => [R(0), R(5), Rwidth(30)], FP16;
+    0b00001110_10100001_10011000_00000000 = [V(DWORD), V(DWORD)] => [R(0), R(5), Rwidth(30)];
+    0b00001110_11100001_10011000_00000000 = [VStatic(QWORD, 2), VStatic(QWORD, 2)] => [R(0), R(5), Rwidth(30)];
+    // FRINTZ (scalar)
+    0b00011110_11100101_11000000_00000000 = [H, H] => [R(0), R(5)], FP16;
+    0b00011110_00100101_11000000_00000000 = [S, S] => [R(0), R(5)];
+    0b00011110_01100101_11000000_00000000 = [D, D] => [R(0), R(5)];
+    // FRINTZ (predicated)
+    0b01100101_01000011_10100000_00000000 = [Z(WORD), PMerge, Z(WORD)] => [R(0), R3(10), R(5)], SVE;
+    0b01100101_10000011_10100000_00000000 = [Z(DWORD), PMerge, Z(DWORD)] => [R(0), R3(10), R(5)], SVE;
+    0b01100101_11000011_10100000_00000000 = [Z(QWORD), PMerge, Z(QWORD)] => [R(0), R3(10), R(5)], SVE;
+]
+"frsqrte" = [
+    // FRSQRTE
+    0b01111110_11111001_11011000_00000000 = [H, H] => [R(0), R(5)], FP16;
+    0b01111110_10100001_11011000_00000000 = [S, S] => [R(0), R(5)];
+    0b01111110_11100001_11011000_00000000 = [D, D] => [R(0), R(5)];
+    0b00101110_11111001_11011000_00000000 = [V(WORD), V(WORD)] => [R(0), R(5), Rwidth(30)], FP16;
+    0b00101110_10100001_11011000_00000000 = [V(DWORD), V(DWORD)] => [R(0), R(5), Rwidth(30)];
+    0b00101110_11100001_11011000_00000000 = [VStatic(QWORD, 2), VStatic(QWORD, 2)] => [R(0), R(5), Rwidth(30)];
+    // FRSQRTE (unpredicated)
+    0b01100101_01001111_00110000_00000000 = [Z(WORD), Z(WORD)] => [R(0), R(5)], SVE;
+    0b01100101_10001111_00110000_00000000 = [Z(DWORD), Z(DWORD)] => [R(0), R(5)], SVE;
+    0b01100101_11001111_00110000_00000000 = [Z(QWORD), Z(QWORD)] => [R(0), R(5)], SVE;
+]
+"frsqrts" = [
+    // FRSQRTS
+    0b01011110_11000000_00111100_00000000 = [H, H, H] => [R(0), R(5), R(16)], FP16;
+    0b01011110_10100000_11111100_00000000 = [S, S, S] => [R(0), R(5), R(16)];
+    0b01011110_11100000_11111100_00000000 = [D, D, D] => [R(0), R(5), R(16)];
+    0b00001110_11000000_00111100_00000000 = [V(WORD), V(WORD), V(WORD)] => [R(0), R(5), R(16), Rwidth(30)], FP16;
+    0b00001110_10100000_11111100_00000000 = [V(DWORD), V(DWORD), V(DWORD)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b00001110_11100000_11111100_00000000 = [VStatic(QWORD, 2), VStatic(QWORD, 2), VStatic(QWORD, 2)] => [R(0), R(5), R(16), Rwidth(30)];
+    // FRSQRTS (vectors, unpredicated)
+    0b01100101_01000000_00011100_00000000 = [Z(WORD), Z(WORD), Z(WORD)] => [R(0), R(5), R(16)], SVE;
+    0b01100101_10000000_00011100_00000000 = [Z(DWORD), Z(DWORD), Z(DWORD)] => [R(0), R(5), R(16)], SVE;
+    0b01100101_11000000_00011100_00000000 = [Z(QWORD), Z(QWORD), Z(QWORD)] => [R(0), R(5), R(16)], SVE;
+]
+"fscale" = [
+    0b01100101_01001001_10000000_00000000 = [Z(WORD), PMerge, Z(WORD), Z(WORD)] => [R(0), R3(10), RTied, R(5)], SVE;
+    0b01100101_10001001_10000000_00000000 = [Z(DWORD), PMerge, Z(DWORD), Z(DWORD)] => [R(0), R3(10), RTied, R(5)], SVE;
+    0b01100101_11001001_10000000_00000000 = [Z(QWORD), PMerge, Z(QWORD), Z(QWORD)] => [R(0), R3(10), RTied, R(5)], SVE;
+]
+"fsqrt" = [
+    // FSQRT (vector)
+    0b00101110_11111001_11111000_00000000 = [V(WORD), V(WORD)] => [R(0), R(5), Rwidth(30)], FP16;
+    0b00101110_10100001_11111000_00000000 = [V(DWORD), V(DWORD)] => [R(0), R(5), Rwidth(30)];
+    0b00101110_11100001_11111000_00000000 = [VStatic(QWORD, 2), VStatic(QWORD, 2)] => [R(0), R(5), Rwidth(30)];
+    // FSQRT (scalar)
+    0b00011110_11100001_11000000_00000000 = [H, H] => [R(0), R(5)], FP16;
+    0b00011110_00100001_11000000_00000000 = [S, S] => [R(0), R(5)];
+    0b00011110_01100001_11000000_00000000 = [D, D] => [R(0), R(5)];
+    // FSQRT (predicated)
+    0b01100101_01001101_10100000_00000000 = [Z(WORD), PMerge, Z(WORD)] => [R(0), R3(10), R(5)], SVE;
+    0b01100101_10001101_10100000_00000000 = [Z(DWORD), PMerge, Z(DWORD)] => [R(0), R3(10), R(5)], SVE;
+    0b01100101_11001101_10100000_00000000 = [Z(QWORD), PMerge, Z(QWORD)] => [R(0), R3(10), R(5)], SVE;
+]
+"fsub" = [
+    // FSUB (vector)
+    0b00001110_11000000_00010100_00000000 = [V(WORD), V(WORD), V(WORD)] => [R(0), R(5), R(16), Rwidth(30)], FP16;
+    0b00001110_10100000_11010100_00000000 = [V(DWORD), V(DWORD), V(DWORD)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b00001110_11100000_11010100_00000000 = [VStatic(QWORD, 2), VStatic(QWORD, 2), VStatic(QWORD, 2)] => [R(0), R(5), R(16), Rwidth(30)];
+    // FSUB (scalar)
+    0b00011110_11100000_00111000_00000000 = [H, H, H] => [R(0), R(5), R(16)], FP16;
+    0b00011110_00100000_00111000_00000000 = [S, S, S] => [R(0), R(5), R(16)];
+    0b00011110_01100000_00111000_00000000 = [D, D, D] => [R(0), R(5), R(16)];
+    // FSUB (vectors, unpredicated)
+    0b01100101_01000000_00000100_00000000 = [Z(WORD), Z(WORD), Z(WORD)] => [R(0), R(5), R(16)], SVE;
+    0b01100101_10000000_00000100_00000000 = [Z(DWORD), Z(DWORD), Z(DWORD)] => [R(0), R(5), R(16)], SVE;
+    0b01100101_11000000_00000100_00000000 = [Z(QWORD), Z(QWORD), Z(QWORD)] => [R(0), R(5), R(16)], SVE;
+    // FSUB (vectors, predicated)
+    0b01100101_01000001_10000000_00000000 = [Z(WORD), PMerge, Z(WORD), Z(WORD)] => [R(0), R3(10), RTied, R(5)], SVE;
+    0b01100101_10000001_10000000_00000000 = [Z(DWORD), PMerge, Z(DWORD), Z(DWORD)] => [R(0), R3(10), RTied, R(5)], SVE;
+    0b01100101_11000001_10000000_00000000 = [Z(QWORD), PMerge, Z(QWORD), Z(QWORD)] => [R(0), R3(10), RTied, R(5)], SVE;
+    // FSUB (immediate)
+    0b01100101_01011001_10000000_00000000 = [Z(WORD), PMerge, Z(WORD), LitFloat(0.5)] => [R(0), R3(10), RTied], SVE;
+    0b01100101_10011001_10000000_00000000 = [Z(DWORD), PMerge, Z(DWORD), LitFloat(0.5)] => [R(0), R3(10), RTied], SVE;
+    0b01100101_11011001_10000000_00000000 = [Z(QWORD), PMerge, Z(QWORD), LitFloat(0.5)] => [R(0), R3(10), RTied], SVE;
+    0b01100101_01011001_10000000_00100000 = [Z(WORD), PMerge, Z(WORD), LitFloat(1.0)] => [R(0), R3(10), RTied], SVE;
+    0b01100101_10011001_10000000_00100000 = [Z(DWORD), PMerge, Z(DWORD), LitFloat(1.0)] => [R(0), R3(10), RTied], SVE;
+    0b01100101_11011001_10000000_00100000 = [Z(QWORD), PMerge, Z(QWORD), LitFloat(1.0)] => [R(0), R3(10), RTied], SVE;
+]
+"fsubr" = [
+    // FSUBR (vectors, predicated)
+    0b01100101_01000011_10000000_00000000 = [Z(WORD), PMerge, Z(WORD), Z(WORD)] => [R(0), R3(10), RTied, R(5)], SVE;
+    0b01100101_10000011_10000000_00000000 = [Z(DWORD), PMerge, Z(DWORD), Z(DWORD)] => [R(0), R3(10), RTied, R(5)], SVE;
+    0b01100101_11000011_10000000_00000000 = [Z(QWORD), PMerge, Z(QWORD), Z(QWORD)] => [R(0), R3(10), RTied, R(5)], SVE;
+    // FSUBR (immediate)
+    0b01100101_01011011_10000000_00000000 = [Z(WORD), PMerge, Z(WORD), LitFloat(0.5)] => [R(0), R3(10), RTied], SVE;
+    0b01100101_10011011_10000000_00000000 = [Z(DWORD), PMerge, Z(DWORD), LitFloat(0.5)] => [R(0), R3(10), RTied], SVE;
+    0b01100101_11011011_10000000_00000000 = [Z(QWORD), PMerge, Z(QWORD), LitFloat(0.5)] => [R(0), R3(10), RTied], SVE;
+    0b01100101_01011011_10000000_00100000 = [Z(WORD), PMerge, Z(WORD), LitFloat(1.0)] => [R(0), R3(10), RTied], SVE;
+    0b01100101_10011011_10000000_00100000 = [Z(DWORD), PMerge, Z(DWORD), LitFloat(1.0)] => [R(0), R3(10), RTied], SVE;
+    0b01100101_11011011_10000000_00100000 = [Z(QWORD), PMerge, Z(QWORD), LitFloat(1.0)] => [R(0), R3(10), RTied], SVE;
+]
+"ftmad" = [
+    0b01100101_01010000_10000000_00000000 = [Z(WORD), Z(WORD), Z(WORD), Imm] => [R(0), RTied, R(5), Ubits(16, 3)], SVE;
+    0b01100101_10010000_10000000_00000000 = [Z(DWORD), Z(DWORD), Z(DWORD), Imm] => [R(0), RTied, R(5), Ubits(16, 3)], SVE;
+    0b01100101_11010000_10000000_00000000 = [Z(QWORD), Z(QWORD), Z(QWORD), Imm] => [R(0), RTied, R(5), Ubits(16, 3)], SVE;
+]
+"ftsmul" = [
+    0b01100101_01000000_00001100_00000000 = [Z(WORD), Z(WORD), Z(WORD)] => [R(0), R(5), R(16)], SVE;
+    0b01100101_10000000_00001100_00000000 = [Z(DWORD), Z(DWORD), Z(DWORD)] => [R(0), R(5), R(16)], SVE;
+    0b01100101_11000000_00001100_00000000 = [Z(QWORD), Z(QWORD), Z(QWORD)] => [R(0), R(5), R(16)], SVE;
+]
+"ftssel" = [
+    0b00000100_01100000_10110000_00000000 = [Z(WORD), Z(WORD), Z(WORD)] => [R(0), R(5), R(16)], SVE;
+    0b00000100_10100000_10110000_00000000 = [Z(DWORD), Z(DWORD), Z(DWORD)] => [R(0), R(5), R(16)], SVE;
+    0b00000100_11100000_10110000_00000000 = [Z(QWORD), Z(QWORD), Z(QWORD)] => [R(0), R(5), R(16)], SVE;
+]
+"gmi" = [
+    0b10011010_11000000_00010100_00000000 = [X, XSP, X] => [R(0), R(5), R(16)], MTE;
+]
+"hint" = [
+    0b11010101_00000011_00100000_00011111 = [Imm] => [Ubits(5, 7)];
+]
+"histcnt" = [
+    0b01000101_10100000_11000000_00000000 = [Z(DWORD), PZero, Z(DWORD), Z(DWORD)] => [R(0), R3(10), R(5), R(16)], SVE2;
+    0b01000101_11100000_11000000_00000000 = [Z(QWORD), PZero, Z(QWORD), Z(QWORD)] => [R(0), R3(10), R(5), R(16)], SVE2;
+]
+"histseg" = [
+    0b01000101_00100000_10100000_00000000 = [Z(BYTE), Z(BYTE), Z(BYTE)] => [R(0), R(5), R(16)], SVE2;
+]
+"hlt" = [
+    0b11010100_01000000_00000000_00000000 = [Imm] => [Ubits(5, 16)];
+]
+"hvc" = [
+    0b11010100_00000000_00000000_00000010 = [Imm] => [Ubits(5, 16)];
+]
+"ic" = [
+    0b11010101_00001011_01110101_00100000 = [Lit("ivau"), X] => [R(0)];
+    0b11010101_00001000_01110000_00011111 = [Ident] => [LitList(5, "IC_OPS")];
+]
+"incb" = [
+    0b00000100_00110000_11100011_11100000 = [X] => [R(0)], SVE;
+    0b00000100_00110000_11100000_00000000 = [X, Ident, End, LitMod(MUL)] => [R(0), LitList(5, "SVE_PATTERNS"), Urange(16, 1, 16)], SVE;
+]
+"incd" = [
+    // INCD (scalar)
+    0b00000100_11110000_11100011_11100000 = [X] => [R(0)], SVE;
+    0b00000100_11110000_11100000_00000000 = [X, Ident, End, LitMod(MUL)] => [R(0), LitList(5, "SVE_PATTERNS"), Urange(16, 1, 16)], SVE;
+    // INCD (vector)
+    0b00000100_11110000_11000011_11100000 = [Z(QWORD)] => [R(0)], SVE;
+    0b00000100_11110000_11000000_00000000 = [Z(QWORD), Ident, End, LitMod(MUL)] => [R(0), LitList(5, "SVE_PATTERNS"), Urange(16, 1, 16)], SVE;
+]
+"inch" = [
+    // INCH (scalar)
+    0b00000100_01110000_11100011_11100000 = [X] => [R(0)], SVE;
+    0b00000100_01110000_11100000_00000000 = [X, Ident, End, LitMod(MUL)] => [R(0), LitList(5, "SVE_PATTERNS"), Urange(16, 1, 16)], SVE;
+    // INCH (vector)
+    0b00000100_01110000_11000011_11100000 = [Z(WORD)] => [R(0)], SVE;
+    0b00000100_01110000_11000000_00000000 = [Z(WORD), Ident, End, LitMod(MUL)] => [R(0), LitList(5, "SVE_PATTERNS"), Urange(16, 1, 16)], SVE;
+]
+"incp" = [
+    // INCP (scalar)
+    0b00100101_00101100_10001000_00000000 = [X, P(BYTE)] => [R(0), R4(5)], SVE;
+    0b00100101_01101100_10001000_00000000 = [X, P(WORD)] => [R(0), R4(5)], SVE;
+    0b00100101_10101100_10001000_00000000 = [X, P(DWORD)] => [R(0), R4(5)], SVE;
+    0b00100101_11101100_10001000_00000000 = [X, P(QWORD)] => [R(0), R4(5)], SVE;
+    // INCP (vector)
+    0b00100101_01101100_10000000_00000000 = [Z(WORD), PBare] => [R(0), R4(5)], SVE;
+    0b00100101_10101100_10000000_00000000 = [Z(DWORD), PBare] => [R(0), R4(5)], SVE;
+    0b00100101_11101100_10000000_00000000 = [Z(QWORD), PBare] => [R(0), R4(5)], SVE;
+]
+"incw" = [
+    // INCW (scalar)
+    0b00000100_10110000_11100011_11100000 = [X] => [R(0)], SVE;
+    0b00000100_10110000_11100000_00000000 = [X, Ident, End, LitMod(MUL)] => [R(0), LitList(5, "SVE_PATTERNS"), Urange(16, 1, 16)], SVE;
+    // INCW (vector)
+    0b00000100_10110000_11000011_11100000 = [Z(DWORD)] => [R(0)], SVE;
+    0b00000100_10110000_11000000_00000000 = [Z(DWORD), Ident, End, LitMod(MUL)] => [R(0), LitList(5, "SVE_PATTERNS"), Urange(16, 1, 16)], SVE;
+]
+"index" = [
+    // INDEX (immediates)
+    0b00000100_00100000_01000000_00000000 = [Z(BYTE), Imm, Imm] => [R(0), Sbits(5, 5), Sbits(16, 5)], SVE;
+    0b00000100_01100000_01000000_00000000 = [Z(WORD), Imm, Imm] => [R(0), Sbits(5, 5), Sbits(16, 5)], SVE;
+    0b00000100_10100000_01000000_00000000 = [Z(DWORD), Imm, Imm] => [R(0), Sbits(5, 5), Sbits(16, 5)], SVE;
+    0b00000100_11100000_01000000_00000000 = [Z(QWORD), Imm, Imm] => [R(0), Sbits(5, 5), Sbits(16, 5)], SVE;
+    // INDEX (immediate, scalar)
+    0b00000100_00100000_01001000_00000000 = [Z(BYTE), Imm, W] => [R(0), Sbits(5, 5), R(16)], SVE;
+    0b00000100_01100000_01001000_00000000 = [Z(WORD), Imm, W] => [R(0), Sbits(5, 5), R(16)], SVE;
+    0b00000100_10100000_01001000_00000000 = [Z(DWORD), Imm, W] => [R(0), Sbits(5, 5), R(16)], SVE;
+    0b00000100_11100000_01001000_00000000 = [Z(QWORD), Imm, X] => [R(0), Sbits(5, 5), R(16)], SVE;
+    // INDEX (scalar, immediate)
+    0b00000100_00100000_01000100_00000000 = [Z(BYTE), W, Imm] => [R(0), R(5), Sbits(16, 5)], SVE;
+    0b00000100_01100000_01000100_00000000 = [Z(WORD), W, Imm] => [R(0), R(5), Sbits(16, 5)], SVE;
+    0b00000100_10100000_01000100_00000000 = [Z(DWORD), W, Imm] => [R(0), R(5), Sbits(16, 5)], SVE;
+    0b00000100_11100000_01000100_00000000 = [Z(QWORD), X, Imm] => [R(0), R(5), Sbits(16, 5)], SVE;
+    // INDEX (scalars)
+    0b00000100_00100000_01001100_00000000 = [Z(BYTE), W, W] => [R(0), R(5), R(16)], SVE;
+    0b00000100_01100000_01001100_00000000 = [Z(WORD), W, W] => [R(0), R(5), R(16)], SVE;
+    0b00000100_10100000_01001100_00000000 = [Z(DWORD), W, W] => [R(0), R(5), R(16)], SVE;
+    0b00000100_11100000_01001100_00000000 = [Z(QWORD), X, X] => [R(0), R(5), R(16)], SVE;
+]
+"ins" = [
+    // INS (element)
+    0b01101110_00000001_00000100_00000000 = [VElement(BYTE), VElement(BYTE)] => [R(0), Ubits(17, 4), R(5), Ubits(11, 4)];
+    0b01101110_00000010_00000100_00000000 = [VElement(WORD), VElement(WORD)] => [R(0), Ubits(18, 3), R(5), Ubits(12, 3)];
+    0b01101110_00000100_00000100_00000000 = [VElement(DWORD), VElement(DWORD)] => [R(0), Ubits(19, 2), R(5), Ubits(13, 2)];
+    0b01101110_00001000_00000100_00000000 = [VElement(QWORD), VElement(QWORD)] => [R(0), Ubits(20, 1), R(5), Ubits(14, 1)];
+    // INS (general)
+    0b01001110_00000001_00011100_00000000 = [VElement(BYTE), W] => [R(0), Ubits(17, 4), R(5)];
+    0b01001110_00000010_00011100_00000000 = [VElement(WORD), W] => [R(0), Ubits(18, 3), R(5)];
+    0b01001110_00000100_00011100_00000000 = [VElement(DWORD), W] => [R(0), Ubits(19, 2), R(5)];
+    0b01001110_00001000_00011100_00000000 = [VElement(QWORD), X] => [R(0), Ubits(20, 1), R(5)];
+]
+"insr" = [
+    // INSR (scalar)
+    0b00000101_00100100_00111000_00000000 = [Z(BYTE), W] => [R(0), R(5)], SVE;
+    0b00000101_01100100_00111000_00000000 = [Z(WORD), W] => [R(0), R(5)], SVE;
+    0b00000101_10100100_00111000_00000000 = [Z(DWORD), W] => [R(0), R(5)], SVE;
+    0b00000101_11100100_00111000_00000000 = [Z(QWORD), X] => [R(0), R(5)], SVE;
+    // INSR (SIMD&FP scalar)
+    0b00000101_00110100_00111000_00000000 = [Z(BYTE), B] => [R(0), R(5)], SVE;
+    0b00000101_01110100_00111000_00000000 = [Z(WORD), H] => [R(0), R(5)], SVE;
+    0b00000101_10110100_00111000_00000000 = [Z(DWORD), S] => [R(0), R(5)], SVE;
+    0b00000101_11110100_00111000_00000000 = [Z(QWORD), D] => [R(0), R(5)], SVE;
+]
+"irg" = [
+    0b10011010_11000000_00010000_00000000 = [XSP, XSP, X] => [R(0), R(5), R(16)], MTE;
+    0b10011010_11011111_00010000_00000000 = [XSP, XSP] => [R(0), R(5)], MTE;
+]
+"isb" = [
+    0b11010101_00000011_00111111_11011111 = [Lit("sy")] => [];
+    0b11010101_00000011_00110000_11011111 = [Imm] => [Ubits(8, 4)];
+    0b11010101_00000011_00111111_11011111 = [] => [];
+]
+"lasta" = [
+    // LASTA (scalar)
+    0b00000101_00100000_10100000_00000000 = [W, PBare, Z(BYTE)] => [R(0), R3(10), R(5)], SVE;
+    0b00000101_01100000_10100000_00000000 = [W, PBare, Z(WORD)] => [R(0), R3(10), R(5)], SVE;
+    0b00000101_10100000_10100000_00000000 = [W, PBare, Z(DWORD)] => [R(0), R3(10), R(5)], SVE;
+    0b00000101_11100000_10100000_00000000 = [X, PBare, Z(QWORD)] => [R(0), R3(10), R(5)], SVE;
+    // LASTA (SIMD&FP scalar)
+    0b00000101_00100010_10000000_00000000 = [B, PBare, Z(BYTE)] => [R(0), R3(10), R(5)], SVE;
+    0b00000101_01100010_10000000_00000000 = [H, PBare, Z(WORD)] => [R(0), R3(10), R(5)], SVE;
+    0b00000101_10100010_10000000_00000000 = [S, PBare, Z(DWORD)] => [R(0), R3(10), R(5)], SVE;
+    0b00000101_11100010_10000000_00000000 = [D, PBare, Z(QWORD)] => [R(0), R3(10), R(5)], SVE;
+]
+"lastb" = [
+    // LASTB (scalar)
+    0b00000101_00100001_10100000_00000000 = [W, PBare, Z(BYTE)] => [R(0), R3(10), R(5)], SVE;
+    0b00000101_01100001_10100000_00000000 = [W, PBare, Z(WORD)] => [R(0), R3(10), R(5)], SVE;
+    0b00000101_10100001_10100000_00000000 = [W, PBare, Z(DWORD)] => [R(0), R3(10), R(5)], SVE;
+    0b00000101_11100001_10100000_00000000 = [X, PBare, Z(QWORD)] => [R(0), R3(10), R(5)], SVE;
+    // LASTB (SIMD&FP scalar)
+    0b00000101_00100011_10000000_00000000 = [B, PBare, Z(BYTE)] => [R(0), R3(10), R(5)], SVE;
+    0b00000101_01100011_10000000_00000000 = [H, PBare, Z(WORD)] => [R(0), R3(10), R(5)], SVE;
+    0b00000101_10100011_10000000_00000000 = [S, PBare, Z(DWORD)] => [R(0), R3(10), R(5)], SVE;
+    0b00000101_11100011_10000000_00000000 = [D, PBare, Z(QWORD)] => [R(0), R3(10), R(5)], SVE;
+]
+"ld1" = [
+    // LD1 (multiple structures)
+    0b00001100_01000000_01110000_00000000 = [RegList(1, BYTE), RefBase] => [R(0), R(5), Rwidth(30)];
+    0b00001100_01000000_01110100_00000000 = [RegList(1, WORD), RefBase] => [R(0), R(5), Rwidth(30)];
+    0b00001100_01000000_01111000_00000000 = [RegList(1, DWORD), RefBase] => [R(0), R(5), Rwidth(30)];
+    0b00001100_01000000_01111100_00000000 = [RegList(1, QWORD), RefBase] => [R(0), R(5), Rwidth(30)];
+    0b00001100_01000000_10100000_00000000 = [RegList(2, BYTE), RefBase] => [R(0), R(5), Rwidth(30)];
+    0b00001100_01000000_10100100_00000000 = [RegList(2, WORD), RefBase] => [R(0), R(5), Rwidth(30)];
+    0b00001100_01000000_10101000_00000000 = [RegList(2, DWORD), RefBase] => [R(0), R(5), Rwidth(30)];
+    0b00001100_01000000_10101100_00000000 = [RegList(2, QWORD), RefBase] => [R(0), R(5), Rwidth(30)];
+    0b00001100_01000000_01100000_00000000 = [RegList(3, BYTE), RefBase] => [R(0), R(5), Rwidth(30)];
+    0b00001100_01000000_01100100_00000000 = [RegList(3, WORD), RefBase] => [R(0), R(5), Rwidth(30)];
+    0b00001100_01000000_01101000_00000000 = [RegList(3, DWORD), RefBase] => [R(0), R(5), Rwidth(30)];
+    0b00001100_01000000_01101100_00000000 = [RegList(3, QWORD), RefBase] => [R(0), R(5), Rwidth(30)];
+    0b00001100_01000000_00100000_00000000 = [RegList(4, BYTE), RefBase] => [R(0), R(5), Rwidth(30)];
+    0b00001100_01000000_00100100_00000000 = [RegList(4, WORD), RefBase] => [R(0), R(5), Rwidth(30)];
+    0b00001100_01000000_00101000_00000000 = [RegList(4, DWORD), RefBase] => [R(0), R(5), Rwidth(30)];
+    0b00001100_01000000_00101100_00000000 = [RegList(4, QWORD), RefBase] => [R(0), R(5), Rwidth(30)];
+    0b00001100_11011111_01110000_00000000 = [RegListStatic(1, BYTE, 8), RefBase, LitInt(8)] => [R(0), R(5)];
+    0b00001100_11011111_01110100_00000000 = [RegListStatic(1, WORD, 4), RefBase, LitInt(8)] => [R(0), R(5)];
+    0b00001100_11011111_01111000_00000000 = [RegListStatic(1, DWORD, 2), RefBase, LitInt(8)] => [R(0), R(5)];
+    0b00001100_11011111_01111100_00000000 = [RegListStatic(1, QWORD, 1), RefBase, LitInt(8)] => [R(0), R(5)];
+    0b01001100_11011111_01110000_00000000 = [RegListStatic(1, BYTE, 16), RefBase, LitInt(16)] => [R(0), R(5)];
+    0b01001100_11011111_01110100_00000000 = [RegListStatic(1, WORD, 8), RefBase, LitInt(16)] => [R(0), R(5)];
+    0b01001100_11011111_01111000_00000000 = [RegListStatic(1, DWORD, 4), RefBase, LitInt(16)] => [R(0), R(5)];
+    0b01001100_11011111_01111100_00000000 = [RegListStatic(1, QWORD, 2), RefBase, LitInt(16)] => [R(0), R(5)];
+    0b00001100_11000000_01110000_00000000 = [RegList(1, BYTE), RefBase, X] => [R(0), R(5), RNoZr(16), Rwidth(30)];
+    0b00001100_11000000_01110100_00000000 = [RegList(1, WORD), RefBase, X] => [R(0), R(5), RNoZr(16), Rwidth(30)];
+    0b00001100_11000000_01111000_00000000 = [RegList(1, DWORD), RefBase, X] => [R(0), R(5), RNoZr(16), Rwidth(30)];
+    0b00001100_11000000_01111100_00000000 = [RegList(1, QWORD), RefBase, X] => [R(0), R(5), RNoZr(16), Rwidth(30)];
+    0b00001100_11011111_10100000_00000000 = [RegListStatic(2, BYTE, 8), RefBase, LitInt(16)] => [R(0), R(5)];
+    0b00001100_11011111_10100100_00000000 = [RegListStatic(2, WORD, 4), RefBase, LitInt(16)] => [R(0), R(5)];
+    0b00001100_11011111_10101000_00000000 = [RegListStatic(2, DWORD, 2), RefBase, LitInt(16)] => [R(0), R(5)];
+    0b00001100_11011111_10101100_00000000 = [RegListStatic(2, QWORD, 1), RefBase, LitInt(16)] => [R(0), R(5)];
+    0b01001100_11011111_10100000_00000000 = [RegListStatic(2, BYTE, 16), RefBase, LitInt(32)] => [R(0), R(5)];
+    0b01001100_11011111_10100100_00000000 = [RegListStatic(2, WORD, 8), RefBase, LitInt(32)] => [R(0), R(5)];
+    0b01001100_11011111_10101000_00000000 = [RegListStatic(2, DWORD, 4), RefBase, LitInt(32)] => [R(0), R(5)];
+    0b01001100_11011111_10101100_00000000 = [RegListStatic(2, QWORD, 2), RefBase, LitInt(32)] => [R(0), R(5)];
+    0b00001100_11000000_10100000_00000000 = [RegList(2, BYTE), RefBase, X] => [R(0), R(5), RNoZr(16), Rwidth(30)];
+    0b00001100_11000000_10100100_00000000 = [RegList(2, WORD), RefBase, X] => [R(0), R(5), RNoZr(16), Rwidth(30)];
+    0b00001100_11000000_10101000_00000000 = [RegList(2, DWORD), RefBase, X] => [R(0), R(5), RNoZr(16), Rwidth(30)];
+    0b00001100_11000000_10101100_00000000 = [RegList(2, QWORD), RefBase, X] => [R(0), R(5), RNoZr(16), Rwidth(30)];
+    0b00001100_11011111_01100000_00000000 = [RegListStatic(3, BYTE, 8), RefBase, LitInt(24)] => [R(0), R(5)];
+    0b00001100_11011111_01100100_00000000 = [RegListStatic(3, WORD, 4), RefBase, LitInt(24)] => [R(0), R(5)];
+    0b00001100_11011111_01101000_00000000 = [RegListStatic(3, DWORD, 2), RefBase, LitInt(24)] => [R(0), R(5)];
+    0b00001100_11011111_01101100_00000000 = [RegListStatic(3, QWORD, 1), RefBase, LitInt(24)] => [R(0), R(5)];
+    0b01001100_11011111_01100000_00000000 = [RegListStatic(3, BYTE, 16), RefBase, LitInt(48)] => [R(0), R(5)];
+    0b01001100_11011111_01100100_00000000 = [RegListStatic(3, WORD, 8), RefBase, LitInt(48)] => [R(0), R(5)];
+    0b01001100_11011111_01101000_00000000 = [RegListStatic(3, DWORD, 4), RefBase, LitInt(48)] => [R(0), R(5)];
+    0b01001100_11011111_01101100_00000000 = [RegListStatic(3, QWORD, 2), RefBase, LitInt(48)] => [R(0), R(5)];
+    0b00001100_11000000_01100000_00000000 = [RegList(3, BYTE), RefBase, X] => [R(0), R(5), RNoZr(16), Rwidth(30)];
+    0b00001100_11000000_01100100_00000000 = [RegList(3, WORD), RefBase, X] => [R(0), R(5), RNoZr(16), Rwidth(30)];
+    0b00001100_11000000_01101000_00000000 = [RegList(3, DWORD), RefBase, X] => [R(0), R(5), RNoZr(16), Rwidth(30)];
+    0b00001100_11000000_01101100_00000000 = [RegList(3, QWORD), RefBase, X] => [R(0), R(5), RNoZr(16), Rwidth(30)];
+    0b00001100_11011111_00100000_00000000 = [RegListStatic(4, BYTE, 8), RefBase, LitInt(32)] => [R(0), R(5)];
+    0b00001100_11011111_00100100_00000000 = [RegListStatic(4, WORD, 4), RefBase, LitInt(32)] => [R(0), R(5)];
+    0b00001100_11011111_00101000_00000000 = [RegListStatic(4, DWORD, 2), RefBase, LitInt(32)] => [R(0), R(5)];
+    0b00001100_11011111_00101100_00000000 = [RegListStatic(4, QWORD, 1), RefBase, LitInt(32)] => [R(0), R(5)];
+    0b01001100_11011111_00100000_00000000 = [RegListStatic(4, BYTE, 16), RefBase, LitInt(64)] => [R(0), R(5)];
+    0b01001100_11011111_00100100_00000000 = [RegListStatic(4, WORD, 8), RefBase, LitInt(64)] => [R(0), R(5)];
+    0b01001100_11011111_00101000_00000000 = [RegListStatic(4, DWORD, 4), RefBase, LitInt(64)] => [R(0), R(5)];
+    0b01001100_11011111_00101100_00000000 = [RegListStatic(4, QWORD, 2), RefBase, LitInt(64)] => [R(0), R(5)];
+    0b00001100_11000000_00100000_00000000 = [RegList(4, BYTE), RefBase, X] => [R(0), R(5), RNoZr(16), Rwidth(30)];
+    0b00001100_11000000_00100100_00000000 = [RegList(4, WORD), RefBase, X] => [R(0), R(5), RNoZr(16), Rwidth(30)];
+    0b00001100_11000000_00101000_00000000 = [RegList(4, DWORD), RefBase, X] => [R(0), R(5), RNoZr(16), Rwidth(30)];
+    0b00001100_11000000_00101100_00000000 = [RegList(4, QWORD), RefBase, X] => [R(0), R(5), RNoZr(16), Rwidth(30)];
+    // LD1 (single structure)
+    0b00001101_01000000_00000000_00000000 = [RegListElement(1, BYTE), RefBase] => [R(0), Ufields(&[30, 12, 11, 10]), R(5)];
+    0b00001101_01000000_01000000_00000000 = [RegListElement(1, WORD), RefBase] => [R(0), Ufields(&[30, 12, 11]), R(5)];
+    0b00001101_01000000_10000000_00000000 = [RegListElement(1, DWORD), RefBase] => [R(0), Ufields(&[30, 12]), R(5)];
+    0b00001101_01000000_10000100_00000000 = [RegListElement(1, QWORD), RefBase] => [R(0), Ufields(&[30]), R(5)];
+    0b00001101_11011111_00000000_00000000 = [RegListElement(1, BYTE), RefBase, LitInt(1)] => [R(0), Ufields(&[30, 12, 11, 10]), R(5)];
+    0b00001101_11000000_00000000_00000000 = [RegListElement(1, BYTE), RefBase, X] => [R(0), Ufields(&[30, 12, 11, 10]), R(5), RNoZr(16)];
+    0b00001101_11011111_01000000_00000000 = [RegListElement(1, WORD), RefBase, LitInt(2)] => [R(0), Ufields(&[30, 12, 11]), R(5)];
+    0b00001101_11000000_01000000_00000000 = [RegListElement(1, WORD), RefBase, X] => [R(0), Ufields(&[30, 12, 11]), R(5), RNoZr(16)];
+    0b00001101_11011111_10000000_00000000 = [RegListElement(1, DWORD), RefBase, LitInt(4)] => [R(0), Ufields(&[30, 12]), R(5)];
+    0b00001101_11000000_10000000_00000000 = [RegListElement(1, DWORD), RefBase, X] => [R(0), Ufields(&[30, 12]), R(5), RNoZr(16)];
+    0b00001101_11011111_10000100_00000000 = [RegListElement(1, QWORD), RefBase, LitInt(8)] => [R(0), Ufields(&[30]), R(5)];
+    0b00001101_11000000_10000100_00000000 = [RegListElement(1, QWORD), RefBase, X] => [R(0), Ufields(&[30]), R(5), RNoZr(16)];
+]
+"ld1b" = [
+    // LD1B (scalar plus scalar)
+    0b10100100_00000000_01000000_00000000 = [ZRegList(1, BYTE), PZero, RefIndexLsl(0)] => [R(0), R3(10), R(5), RNoZr(16)], SVE;
+    0b10100100_01100000_01000000_00000000 = [ZRegList(1, QWORD), PZero, RefIndexLsl(0)] => [R(0), R3(10), R(5), RNoZr(16)], SVE;
+    0b10100100_00100000_01000000_00000000 = [ZRegList(1, WORD), PZero, RefIndexLsl(0)] => [R(0), R3(10), R(5), RNoZr(16)], SVE;
+    0b10100100_01000000_01000000_00000000 = [ZRegList(1, DWORD), PZero, RefIndexLsl(0)] => [R(0), R3(10), R(5), RNoZr(16)], SVE;
+    // LD1B (scalar plus immediate)
+    0b10100100_00000000_10100000_00000000 = [ZRegList(1, BYTE), PZero, RefMulVl] => [R(0), R3(10), R(5), Sbits(16, 4)], SVE;
+    0b10100100_01100000_10100000_00000000 = [ZRegList(1, QWORD), PZero, RefMulVl] => [R(0), R3(10), R(5), Sbits(16, 4)], SVE;
+    0b10100100_00100000_10100000_00000000 = [ZRegList(1, WORD), PZero, RefMulVl] => [R(0), R3(10), R(5), Sbits(16, 4)], SVE;
+    0b10100100_01000000_10100000_00000000 = [ZRegList(1, DWORD), PZero, RefMulVl] => [R(0), R3(10), R(5), Sbits(16, 4)], SVE;
+    // LD1B (scalar plus vector)
+    0b11000100_00000000_01000000_00000000 = [ZRegList(1, QWORD), PZero, RefZIndexExtend(QWORD, 0)] => [R(0), R3(10), R(5), R(16), ExtendsZ(22)], SVE;
+    0b11000100_01000000_11000000_00000000 = [ZRegList(1, QWORD), PZero, RefZIndexLsl(QWORD, 0)] => [R(0), R3(10), R(5), R(16)], SVE;
+    0b10000100_00000000_01000000_00000000 = [ZRegList(1, DWORD), PZero, RefZIndexExtend(DWORD, 0)] => [R(0), R3(10), R(5), R(16), ExtendsZ(22)], SVE;
+    // LD1B (vector plus immediate)
+    0b11000100_00100000_11000000_00000000 = [ZRegList(1, QWORD), PZero, RefZBase(QWORD)] => [R(0), R3(10), R(5), Ubits(16, 5)], SVE;
+    0b10000100_00100000_11000000_00000000 = [ZRegList(1, DWORD), PZero, RefZBase(DWORD)] => [R(0), R3(10), R(5), Ubits(16, 5)], SVE;
+    // LD1B
+    0b11100000_00011111_00000000_00000000 = [ZaHList(BYTE), PZero, RefBase] => [RTile(4, 0), RSelect(13), Ubits(0, 4), R3(10), R(5)], SME;
+    0b11100000_00000000_00000000_00000000 = [ZaHList(BYTE), PZero, RefIndexLsl(0)] => [RTile(4, 0), RSelect(13), Ubits(0, 4), R3(10), R(5), R(16)], SME;
+    0b11100000_00011111_10000000_00000000 = [ZaVList(BYTE), PZero, RefBase] => [RTile(4, 0), RSelect(13), Ubits(0, 4), R3(10), R(5)], SME;
+    0b11100000_00000000_10000000_00000000 = [ZaVList(BYTE), PZero, RefIndexLsl(0)] => [RTile(4, 0), RSelect(13), Ubits(0, 4), R3(10), R(5), R(16)], SME;
+]
+"ld1d" = [
+    // LD1D (scalar plus scalar)
+    0b10100101_11100000_01000000_00000000 = [ZRegList(1, QWORD), PZero, RefIndexLsl(3)] => [R(0), R3(10), R(5), RNoZr(16)], SVE;
+    // LD1D (scalar plus vector)
+    0b11000101_10100000_01000000_00000000 = [ZRegList(1, QWORD), PZero, RefZIndexExtend(QWORD, 3)] => [R(0), R3(10), R(5), R(16), ExtendsZ(22)], SVE;
+    0b11000101_10000000_01000000_00000000 = [ZRegList(1, QWORD), PZero, RefZIndexExtend(QWORD, 0)] => [R(0), R3(10), R(5), R(16), ExtendsZ(22)], SVE;
+    0b11000101_11100000_11000000_00000000 = [ZRegList(1, QWORD), PZero, RefZIndexLsl(QWORD, 3)] => [R(0), R3(10), R(5), R(16)], SVE;
+    0b11000101_11000000_11000000_00000000 = [ZRegList(1, QWORD), PZero, RefZIndexLsl(QWORD, 0)] => [R(0), R3(10), R(5), R(16)], SVE;
+    // LD1D (scalar plus immediate)
+    0b10100101_11100000_10100000_00000000 = [ZRegList(1, QWORD), PZero, RefMulVl] => [R(0), R3(10), R(5), Sbits(16, 4)], SVE;
+    // LD1D (vector plus immediate)
+    0b11000101_10100000_11000000_00000000 = [ZRegList(1, QWORD), PZero, RefZBase(QWORD)] => [R(0), R3(10), R(5), Uscaled(16, 5, 3)], SVE;
+    // LD1D
+    0b11100000_11011111_00000000_00000000 = [ZaHList(QWORD), PZero, RefBase] => [RTile(1, 3), RSelect(13), Ubits(0, 1), R3(10), R(5)], SME;
+    0b11100000_11000000_00000000_00000000 = [ZaHList(QWORD), PZero, RefIndexLsl(3)] => [RTile(1, 3), RSelect(13), Ubits(0, 1), R3(10), R(5), R(16)], SME;
+    0b11100000_11011111_10000000_00000000 = [ZaVList(QWORD), PZero, RefBase] => [RTile(1, 3), RSelect(13), Ubits(0, 1), R3(10), R(5)], SME;
+    0b11100000_11000000_10000000_00000000 = [ZaVList(QWORD), PZero, RefIndexLsl(3)] => [RTile(1, 3), RSelect(13), Ubits(0, 1), R3(10), R(5), R(16)], SME;
+]
+"ld1h" = [
+    // LD1H (scalar plus scalar)
+    0b10100100_11100000_01000000_00000000 = [ZRegList(1, QWORD), PZero, RefIndexLsl(1)] => [R(0), R3(10), R(5), RNoZr(16)], SVE;
+    0b10100100_10100000_01000000_00000000 = [ZRegList(1, WORD), PZero, RefIndexLsl(1)] => [R(0), R3(10), R(5), RNoZr(16)], SVE;
+    0b10100100_11000000_01000000_00000000 = [ZRegList(1, DWORD), PZero, RefIndexLsl(1)] => [R(0), R3(10), R(5), RNoZr(16)], SVE;
+    // LD1H (scalar plus vector)
+    0b11000100_10100000_01000000_00000000 = [ZRegList(1, QWORD), PZero, RefZIndexExtend(QWORD, 1)] => [R(0), R3(10), R(5), R(16), ExtendsZ(22)], SVE;
+    0b11000100_10000000_01000000_00000000 = [ZRegList(1, QWORD), PZero, RefZIndexExtend(QWORD, 0)] => [R(0), R3(10), R(5), R(16), ExtendsZ(22)], SVE;
+    0b11000100_11100000_11000000_00000000 = [ZRegList(1, QWORD), PZero, RefZIndexLsl(QWORD, 1)] => [R(0), R3(10), R(5), R(16)], SVE;
+    0b11000100_11000000_11000000_00000000 = [ZRegList(1, QWORD), PZero, RefZIndexLsl(QWORD, 0)] => [R(0), R3(10), R(5), R(16)], SVE;
+    0b10000100_10100000_01000000_00000000 = [ZRegList(1, DWORD), PZero, RefZIndexExtend(DWORD, 1)] => [R(0), R3(10), R(5), R(16), ExtendsZ(22)], SVE;
+    0b10000100_10000000_01000000_00000000 = [ZRegList(1, DWORD), PZero, RefZIndexExtend(DWORD, 0)] => [R(0), R3(10), R(5), R(16), ExtendsZ(22)], SVE;
+    // LD1H (scalar plus immediate)
+    0b10100100_11100000_10100000_00000000 = [ZRegList(1, QWORD), PZero, RefMulVl] => [R(0), R3(10), R(5), Sbits(16, 4)], SVE;
+    0b10100100_10100000_10100000_00000000 = [ZRegList(1, WORD), PZero, RefMulVl] => [R(0), R3(10), R(5), Sbits(16, 4)], SVE;
+    0b10100100_11000000_10100000_00000000 = [ZRegList(1, DWORD), PZero, RefMulVl] => [R(0), R3(10), R(5), Sbits(16, 4)], SVE;
+    // LD1H (vector plus immediate)
+    0b11000100_10100000_11000000_00000000 = [ZRegList(1, QWORD), PZero, RefZBase(QWORD)] => [R(0), R3(10), R(5), Uscaled(16, 5, 1)], SVE;
+    0b10000100_10100000_11000000_00000000 = [ZRegList(1, DWORD), PZero, RefZBase(DWORD)] => [R(0), R3(10), R(5), Uscaled(16, 5, 1)], SVE;
+    // LD1H
+    0b11100000_01011111_00000000_00000000 = [ZaHList(WORD), PZero, RefBase] => [RTile(3, 1), RSelect(13), Ubits(0, 3), R3(10), R(5)], SME;
+    0b11100000_01000000_00000000_00000000 = [ZaHList(WORD), PZero, RefIndexLsl(1)] => [RTile(3, 1), RSelect(13), Ubits(0, 3), R3(10), R(5), R(16)], SME;
+    0b11100000_01011111_10000000_00000000 = [ZaVList(WORD), PZero, RefBase] => [RTile(3, 1), RSelect(13), Ubits(0, 3), R3(10), R(5)], SME;
+    0b11100000_01000000_10000000_00000000 = [ZaVList(WORD), PZero, RefIndexLsl(1)] => [RTile(3, 1), RSelect(13), Ubits(0, 3), R3(10), R(5), R(16)], SME;
+]
+"ld1q" = [
+    0b11100001_11011111_00000000_00000000 = [ZaHList(OWORD), PZero, RefBase] => [RTile(0, 4), RSelect(13), Urange(0, 0, 0), R3(10), R(5)], SME;
+    0b11100001_11000000_00000000_00000000 = [ZaHList(OWORD), PZero, RefIndexLsl(4)] => [RTile(0, 4), RSelect(13), Urange(0, 0, 0), R3(10), R(5), R(16)], SME;
+    0b11100001_11011111_10000000_00000000 = [ZaVList(OWORD), PZero, RefBase] => [RTile(0, 4), RSelect(13), Urange(0, 0, 0), R3(10), R(5)], SME;
+    0b11100001_11000000_10000000_00000000 = [ZaVList(OWORD), PZero, RefIndexLsl(4)] => [RTile(0, 4), RSelect(13), Urange(0, 0, 0), R3(10), R(5), R(16)], SME;
+]
+"ld1r" = [
+    0b00001101_01000000_11000000_00000000 = [RegList(1, BYTE), RefBase] => [R(0), R(5), Rwidth(30)];
+    0b00001101_01000000_11000100_00000000 = [RegList(1, WORD), RefBase] => [R(0), R(5), Rwidth(30)];
+    0b00001101_01000000_11001000_00000000 = [RegList(1, DWORD), RefBase] => [R(0), R(5), Rwidth(30)];
+    0b00001101_01000000_11001100_00000000 = [RegList(1, QWORD), RefBase] => [R(0), R(5), Rwidth(30)];
+    0b00001101_11011111_11000000_00000000 = [RegList(1, BYTE), RefBase, LitInt(1)] => [R(0), R(5), Rwidth(30)];
+    0b00001101_11011111_11000100_00000000 = [RegList(1, WORD), RefBase, LitInt(2)] => [R(0), R(5), Rwidth(30)];
+    0b00001101_11011111_11001000_00000000 = [RegList(1, DWORD), RefBase, LitInt(4)] => [R(0), R(5), Rwidth(30)];
+    0b00001101_11011111_11001100_00000000 = [RegList(1, QWORD), RefBase, LitInt(8)] => [R(0), R(5), Rwidth(30)];
+    0b00001101_11000000_11000000_00000000 = [RegList(1, BYTE), RefBase, X] => [R(0), R(5), RNoZr(16), Rwidth(30)];
+    0b00001101_11000000_11000100_00000000 = [RegList(1, WORD), RefBase, X] => [R(0), R(5), RNoZr(16), Rwidth(30)];
+    0b00001101_11000000_11001000_00000000 = [RegList(1, DWORD), RefBase, X] => [R(0), R(5), RNoZr(16), Rwidth(30)];
+    0b00001101_11000000_11001100_00000000 = [RegList(1, QWORD), RefBase, X] => [R(0), R(5), RNoZr(16), Rwidth(30)];
+]
+"ld1rb" = [
+    0b10000100_01000000_10000000_00000000 = [ZRegList(1, BYTE), PZero, RefOffset] => [R(0), R3(10), R(5), Ubits(16, 6)], SVE;
+    0b10000100_01000000_11100000_00000000 = [ZRegList(1, QWORD), PZero, RefOffset] => [R(0), R3(10), R(5), Ubits(16, 6)], SVE;
+    0b10000100_01000000_10100000_00000000 = [ZRegList(1, WORD), PZero, RefOffset] => [R(0), R3(10), R(5), Ubits(16, 6)], SVE;
+    0b10000100_01000000_11000000_00000000 = [ZRegList(1, DWORD), PZero, RefOffset] => [R(0), R3(10), R(5), Ubits(16, 6)], SVE;
+]
+"ld1rd" = [
+    0b10000101_11000000_11100000_00000000 = [ZRegList(1, QWORD), PZero, RefOffset] => [R(0), R3(10), R(5), Uscaled(16, 6, 3)], SVE;
+]
+"ld1rh" = [
+    0b10000100_11000000_11100000_00000000 = [ZRegList(1, QWORD), PZero, RefOffset] => [R(0), R3(10), R(5), Uscaled(16, 6, 1)], SVE;
+    0b10000100_11000000_10100000_00000000 = [ZRegList(1, WORD), PZero, RefOffset] => [R(0), R3(10), R(5), Uscaled(16, 6, 1)], SVE;
+    0b10000100_11000000_11000000_00000000 = [ZRegList(1, DWORD), PZero, RefOffset] => [R(0), R3(10), R(5), Uscaled(16, 6, 1)], SVE;
+]
+"ld1rqb" = [
+    // LD1RQB (scalar plus scalar)
+    0b10100100_00000000_00000000_00000000 = [ZRegList(1, BYTE), PZero, RefIndexLsl(0)] => [R(0), R3(10), R(5), RNoZr(16)], SVE;
+    // LD1RQB (scalar plus immediate)
+    0b10100100_00000000_00100000_00000000 = [ZRegList(1, BYTE), PZero, RefOffset] => [R(0), R3(10), R(5), Sscaled(16, 4, 4)], SVE;
+]
+"ld1rqd" = [
+    // LD1RQD (scalar plus scalar)
+    0b10100101_10000000_00000000_00000000 = [ZRegList(1, QWORD), PZero, RefIndexLsl(3)] => [R(0), R3(10), R(5), RNoZr(16)], SVE;
+    // LD1RQD (scalar plus immediate)
+    0b10100101_10000000_00100000_00000000 = [ZRegList(1, QWORD), PZero, RefOffset] => [R(0), R3(10), R(5), Sscaled(16, 4, 4)], SVE;
+]
+"ld1rqh" = [
+    // LD1RQH (scalar plus scalar)
+    0b10100100_10000000_00000000_00000000 = [ZRegList(1, WORD), PZero, RefIndexLsl(1)] => [R(0), R3(10), R(5), RNoZr(16)], SVE;
+    // LD1RQH (scalar plus immediate)
+    0b10100100_10000000_00100000_00000000 = [ZRegList(1, WORD), PZero, RefOffset] => [R(0), R3(10), R(5), Sscaled(16, 4, 4)], SVE;
+]
+"ld1rqw" = [
+    // LD1RQW (scalar plus scalar)
+    0b10100101_00000000_00000000_00000000 = [ZRegList(1, DWORD), PZero, RefIndexLsl(2)] => [R(0), R3(10), R(5), RNoZr(16)], SVE;
+    // LD1RQW (scalar plus immediate)
+    0b10100101_00000000_00100000_00000000 = [ZRegList(1, DWORD), PZero, RefOffset] => [R(0), R3(10), R(5), Sscaled(16, 4, 4)], SVE;
+]
+"ld1rsb" = [
+    0b10000101_11000000_10000000_00000000 = [ZRegList(1, QWORD), PZero, RefOffset] => [R(0), R3(10), R(5), Ubits(16, 6)], SVE;
+    0b10000101_11000000_11000000_00000000 = [ZRegList(1, WORD), PZero, RefOffset] => [R(0), R3(10), R(5), Ubits(16, 6)], SVE;
+    0b10000101_11000000_10100000_00000000 = [ZRegList(1, DWORD), PZero, RefOffset] => [R(0), R3(10), R(5), Ubits(16, 6)], SVE;
+]
+"ld1rsh" = [
+    0b10000101_01000000_10000000_00000000 = [ZRegList(1, QWORD), PZero, RefOffset] => [R(0), R3(10), R(5), Uscaled(16, 6, 1)], SVE;
+    0b10000101_01000000_10100000_00000000 = [ZRegList(1, DWORD), PZero, RefOffset] => [R(0), R3(10), R(5), Uscaled(16, 6, 1)], SVE;
+]
+"ld1rsw" = [
+    0b10000100_11000000_10000000_00000000 = [ZRegList(1, QWORD), PZero, RefOffset] => [R(0), R3(10), R(5), Uscaled(16, 6, 2)], SVE;
+]
+"ld1rw" = [
+    0b10000101_01000000_11100000_00000000 = [ZRegList(1, QWORD), PZero, RefOffset] => [R(0), R3(10), R(5), Uscaled(16, 6, 2)], SVE;
+    0b10000101_01000000_11000000_00000000 = [ZRegList(1, DWORD), PZero, RefOffset] => [R(0), R3(10), R(5), Uscaled(16, 6, 2)], SVE;
+]
+"ld1sb" = [
+    // LD1SB (scalar plus scalar)
+    0b10100101_10000000_01000000_00000000 = [ZRegList(1, QWORD), PZero, RefIndexLsl(0)] => [R(0), R3(10), R(5), RNoZr(16)], SVE;
+    0b10100101_11000000_01000000_00000000 = [ZRegList(1, WORD), PZero, RefIndexLsl(0)] => [R(0), R3(10), R(5), RNoZr(16)], SVE;
+    0b10100101_10100000_01000000_00000000 = [ZRegList(1, DWORD), PZero, RefIndexLsl(0)] => [R(0), R3(10), R(5), RNoZr(16)], SVE;
+    // LD1SB (scalar plus vector)
+    0b11000100_00000000_00000000_00000000 = [ZRegList(1, QWORD), PZero, RefZIndexExtend(QWORD, 0)] => [R(0), R3(10), R(5), R(16), ExtendsZ(22)], SVE;
+    0b11000100_01000000_10000000_00000000 = [ZRegList(1, QWORD), PZero, RefZIndexLsl(QWORD, 0)] => [R(0), R3(10), R(5), R(16)], SVE;
+    0b10000100_00000000_00000000_00000000 = [ZRegList(1, DWORD), PZero, RefZIndexExtend(DWORD, 0)] => [R(0), R3(10), R(5), R(16), ExtendsZ(22)], SVE;
+    // LD1SB (scalar plus immediate)
+    0b10100101_10000000_10100000_00000000 = [ZRegList(1, QWORD), PZero, RefMulVl] => [R(0), R3(10), R(5), Sbits(16, 4)], SVE;
+    0b10100101_11000000_10100000_00000000 = [ZRegList(1, WORD), PZero, RefMulVl] => [R(0), R3(10), R(5), Sbits(16, 4)], SVE;
+    0b10100101_10100000_10100000_00000000 = [ZRegList(1, DWORD), PZero, RefMulVl] => [R(0), R3(10), R(5), Sbits(16, 4)], SVE;
+    // LD1SB (vector plus immediate)
+    0b11000100_00100000_10000000_00000000 = [ZRegList(1, QWORD), PZero, RefZBase(QWORD)] => [R(0), R3(10), R(5), Ubits(16, 5)], SVE;
+    0b10000100_00100000_10000000_00000000 = [ZRegList(1, DWORD), PZero, RefZBase(DWORD)] => [R(0), R3(10), R(5), Ubits(16, 5)], SVE;
+]
+"ld1sh" = [
+    // LD1SH (scalar plus scalar)
+    0b10100101_00000000_01000000_00000000 = [ZRegList(1, QWORD), PZero, RefIndexLsl(1)] => [R(0), R3(10), R(5), RNoZr(16)], SVE;
+    0b10100101_00100000_01000000_00000000 = [ZRegList(1, DWORD), PZero, RefIndexLsl(1)] => [R(0), R3(10), R(5), RNoZr(16)], SVE;
+    // LD1SH (scalar plus vector)
+    0b11000100_10100000_00000000_00000000 = [ZRegList(1, QWORD), PZero, RefZIndexExtend(QWORD, 1)] => [R(0), R3(10), R(5), R(16), ExtendsZ(22)], SVE;
+    0b11000100_10000000_00000000_00000000 = [ZRegList(1, QWORD), PZero, RefZIndexExtend(QWORD, 0)] => [R(0), R3(10), R(5), R(16), ExtendsZ(22)], SVE;
+    0b11000100_11100000_10000000_00000000 = [ZRegList(1, QWORD), PZero, RefZIndexLsl(QWORD, 1)] => [R(0), R3(10), R(5), R(16)], SVE;
+    0b11000100_11000000_10000000_00000000 = [ZRegList(1, QWORD), PZero, RefZIndexLsl(QWORD, 0)] => [R(0), R3(10), R(5), R(16)], SVE;
+    0b10000100_10100000_00000000_00000000 = [ZRegList(1, DWORD), PZero, RefZIndexExtend(DWORD, 1)] => [R(0), R3(10), R(5), R(16), ExtendsZ(22)], SVE;
+    0b10000100_10000000_00000000_00000000 = [ZRegList(1, DWORD), PZero, RefZIndexExtend(DWORD, 0)] => [R(0), R3(10), R(5), R(16), ExtendsZ(22)], SVE;
+    // LD1SH (scalar plus immediate)
+    0b10100101_00000000_10100000_00000000 = [ZRegList(1, QWORD), PZero, RefMulVl] => [R(0), R3(10), R(5), Sbits(16, 4)], SVE;
+    0b10100101_00100000_10100000_00000000 = [ZRegList(1, DWORD), PZero, RefMulVl] => [R(0), R3(10), R(5), Sbits(16, 4)], SVE;
+    // LD1SH (vector plus immediate)
+    0b11000100_10100000_10000000_00000000 = [ZRegList(1, QWORD), PZero, RefZBase(QWORD)] => [R(0), R3(10), R(5), Uscaled(16, 5, 1)], SVE;
+    0b10000100_10100000_10000000_00000000 = [ZRegList(1, DWORD), PZero, RefZBase(DWORD)] => [R(0), R3(10), R(5), Uscaled(16, 5, 1)], SVE;
+]
+"ld1sw" = [
+    // LD1SW (scalar plus scalar)
+    0b10100100_10000000_01000000_00000000 = [ZRegList(1, QWORD), PZero, RefIndexLsl(2)] => [R(0), R3(10), R(5), RNoZr(16)], SVE;
+    // LD1SW (scalar plus vector)
+    0b11000101_00100000_00000000_00000000 = [ZRegList(1, QWORD), PZero, RefZIndexExtend(QWORD, 2)] => [R(0), R3(10), R(5), R(16), ExtendsZ(22)], SVE;
+    0b11000101_00000000_00000000_00000000 = [ZRegList(1, QWORD), PZero, RefZIndexExtend(QWORD, 0)] => [R(0), R3(10), R(5), R(16), ExtendsZ(22)], SVE;
+    0b11000101_01100000_10000000_00000000 = [ZRegList(1, QWORD), PZero, RefZIndexLsl(QWORD, 2)] => [R(0), R3(10), R(5), R(16)], SVE;
+    0b11000101_01000000_10000000_00000000 = [ZRegList(1, QWORD), PZero, RefZIndexLsl(QWORD, 0)] => [R(0), R3(10), R(5), R(16)], SVE;
+    // LD1SW (scalar plus immediate)
+    0b10100100_10000000_10100000_00000000 = [ZRegList(1, QWORD), PZero, RefMulVl] => [R(0), R3(10), R(5), Sbits(16, 4)], SVE;
+    // LD1SW (vector plus immediate)
+    0b11000101_00100000_10000000_00000000 = [ZRegList(1, QWORD), PZero, RefZBase(QWORD)] => [R(0), R3(10), R(5), Uscaled(16, 5, 2)], SVE;
+]
+"ld1w" = [
+    // LD1W (scalar plus scalar)
+    0b10100101_01100000_01000000_00000000 = [ZRegList(1, QWORD), PZero, RefIndexLsl(2)] => [R(0), R3(10), R(5), RNoZr(16)], SVE;
+    0b10100101_01000000_01000000_00000000 = [ZRegList(1, DWORD), PZero, RefIndexLsl(2)] => [R(0), R3(10), R(5), RNoZr(16)], SVE;
+    // LD1W (scalar plus vector)
+    0b11000101_00100000_01000000_00000000 = [ZRegList(1, QWORD), PZero, RefZIndexExtend(QWORD, 2)] => [R(0), R3(10), R(5), R(16), ExtendsZ(22)], SVE;
+    0b11000101_00000000_01000000_00000000 = [ZRegList(1, QWORD), PZero, RefZIndexExtend(QWORD, 0)] => [R(0), R3(10), R(5), R(16), ExtendsZ(22)], SVE;
+    0b11000101_01100000_11000000_00000000 = [ZRegList(1, QWORD), PZero, RefZIndexLsl(QWORD, 2)] => [R(0), R3(10), R(5), R(16)], SVE;
+    0b11000101_01000000_11000000_00000000 = [ZRegList(1, QWORD), PZero, RefZIndexLsl(QWORD, 0)] => [R(0), R3(10), R(5), R(16)], SVE;
+    0b10000101_00100000_01000000_00000000 = [ZRegList(1, DWORD), PZero, RefZIndexExtend(DWORD, 2)] => [R(0), R3(10), R(5), R(16), ExtendsZ(22)], SVE;
+    0b10000101_00000000_01000000_00000000 = [ZRegList(1, DWORD), PZero, RefZIndexExtend(DWORD, 0)] => [R(0), R3(10), R(5), R(16), ExtendsZ(22)], SVE;
+    // LD1W (scalar plus immediate)
+    0b10100101_01100000_10100000_00000000 = [ZRegList(1, QWORD), PZero, RefMulVl] => [R(0), R3(10), R(5), Sbits(16, 4)], SVE;
+    0b10100101_01000000_10100000_00000000 = [ZRegList(1, DWORD), PZero, RefMulVl] => [R(0), R3(10), R(5), Sbits(16, 4)], SVE;
+    // LD1W (vector plus immediate)
+    0b11000101_00100000_11000000_00000000 = [ZRegList(1, QWORD), PZero, RefZBase(QWORD)] => [R(0), R3(10), R(5), Uscaled(16, 5, 2)], SVE;
+    0b10000101_00100000_11000000_00000000 = [ZRegList(1, DWORD), PZero, RefZBase(DWORD)] => [R(0), R3(10), R(5), Uscaled(16, 5, 2)], SVE;
+    // LD1W
+    0b11100000_10011111_00000000_00000000 = [ZaHList(DWORD), PZero, RefBase] => [RTile(2, 2), RSelect(13), Ubits(0, 2), R3(10), R(5)], SME;
+    0b11100000_10000000_00000000_00000000 = [ZaHList(DWORD), PZero, RefIndexLsl(2)] => [RTile(2, 2), RSelect(13), Ubits(0, 2), R3(10), R(5), R(16)], SME;
+    0b11100000_10011111_10000000_00000000 = [ZaVList(DWORD), PZero, RefBase] => [RTile(2, 2), RSelect(13), Ubits(0, 2), R3(10), R(5)], SME;
+    0b11100000_10000000_10000000_00000000 = [ZaVList(DWORD), PZero, RefIndexLsl(2)] => [RTile(2, 2), RSelect(13), Ubits(0, 2), R3(10), R(5), R(16)], SME;
+]
+"ld2" = [
+    // LD2 (multiple structures)
+    0b00001100_01000000_10000000_00000000 = [RegList(2, BYTE), RefBase] => [R(0), R(5), Rwidth(30)];
+    0b00001100_01000000_10000100_00000000 = [RegList(2, WORD), RefBase] => [R(0), R(5), Rwidth(30)];
+    0b00001100_01000000_10001000_00000000 = [RegList(2, DWORD), RefBase] => [R(0), R(5), Rwidth(30)];
+    0b00001100_01000000_10001100_00000000 = [RegListStatic(2, QWORD, 2), RefBase] => [R(0), R(5), Rwidth(30)];
+    0b00001100_11011111_10000000_00000000 = [RegListStatic(2, BYTE, 8), RefBase, LitInt(16)] => [R(0), R(5)];
+    0b00001100_11011111_10000100_00000000 = [RegListStatic(2, WORD, 4), RefBase, LitInt(16)] => [R(0), R(5)];
+    0b00001100_11011111_10001000_00000000 = [RegListStatic(2, DWORD, 2), RefBase, LitInt(16)] => [R(0), R(5)];
+    0b01001100_11011111_10000000_00000000 = [RegListStatic(2, BYTE, 16), RefBase, LitInt(32)] => [R(0), R(5)];
+    0b01001100_11011111_10000100_00000000 = [RegListStatic(2, WORD, 8), RefBase, LitInt(32)] => [R(0), R(5)];
+    0b01001100_11011111_10001000_00000000 = [RegListStatic(2, DWORD, 4), RefBase, LitInt(32)] => [R(0), R(5)];
+    0b01001100_11011111_10001100_00000000 = [RegListStatic(2, QWORD, 2), RefBase, LitInt(32)] => [R(0), R(5)];
+    0b00001100_11000000_10000000_00000000 = [RegList(2, BYTE), RefBase, X] => [R(0), R(5), RNoZr(16), Rwidth(30)];
+    0b00001100_11000000_10000100_00000000 = [RegList(2, WORD), RefBase, X] => [R(0), R(5), RNoZr(16), Rwidth(30)];
+    0b00001100_11000000_10001000_00000000 = [RegList(2, DWORD), RefBase, X] => [R(0), R(5), RNoZr(16), Rwidth(30)];
+    0b00001100_11000000_10001100_00000000 = [RegListStatic(2, QWORD, 2), RefBase, X] => [R(0), R(5), RNoZr(16), Rwidth(30)];
+    // LD2 (single structure)
+    0b00001101_01100000_00000000_00000000 = [RegListElement(2, BYTE), RefBase] => [R(0), Ufields(&[30, 12, 11, 10]), R(5)];
+    0b00001101_01100000_01000000_00000000 = [RegListElement(2, WORD), RefBase] => [R(0), Ufields(&[30, 12, 11]), R(5)];
+    0b00001101_01100000_10000000_00000000 = [RegListElement(2, DWORD), RefBase] => [R(0), Ufields(&[30, 12]), R(5)];
+    0b00001101_01100000_10000100_00000000 = [RegListElement(2, QWORD), RefBase] => [R(0), Ufields(&[30]), R(5)];
+    0b00001101_11111111_00000000_00000000 = [RegListElement(2, BYTE), RefBase, LitInt(2)] => [R(0), Ufields(&[30, 12, 11, 10]), R(5)];
+    0b00001101_11100000_00000000_00000000 = [RegListElement(2, BYTE), RefBase, X] => [R(0), Ufields(&[30, 12, 11, 10]), R(5), RNoZr(16)];
+    0b00001101_11111111_01000000_00000000 = [RegListElement(2, WORD), RefBase, LitInt(4)] => [R(0), Ufields(&[30, 12, 11]), R(5)];
+    0b00001101_11100000_01000000_00000000 = [RegListElement(2, WORD), RefBase, X] => [R(0), Ufields(&[30, 12, 11]), R(5), RNoZr(16)];
+    0b00001101_11111111_10000000_00000000 = [RegListElement(2, DWORD), RefBase, LitInt(8)] => [R(0), Ufields(&[30, 12]), R(5)];
+    0b00001101_11100000_10000000_00000000 = [RegListElement(2, DWORD), RefBase, X] => [R(0), Ufields(&[30, 12]), R(5), RNoZr(16)];
+    0b00001101_11111111_10000100_00000000 = [RegListElement(2, QWORD), RefBase, LitInt(16)] => [R(0), Ufields(&[30]), R(5)];
+    0b00001101_11100000_10000100_00000000 = [RegListElement(2, QWORD), RefBase, X] => [R(0), Ufields(&[30]), R(5), RNoZr(16)];
+]
+"ld2b" = [
+    // LD2B (scalar plus scalar)
+    0b10100100_00100000_11000000_00000000 = [ZRegList(2, BYTE), PZero, RefIndexLsl(0)] => [R(0), R3(10), R(5), RNoZr(16)], SVE;
+    // LD2B (scalar plus immediate)
+    0b10100100_00100000_11100000_00000000 = [ZRegList(2, BYTE), PZero, RefMulVl] => [R(0), R3(10), R(5), Sscaled(16, 4, 1)], SVE;
+]
+"ld2d" = [
+    // LD2D (scalar plus scalar)
+    0b10100101_10100000_11000000_00000000 = [ZRegList(2, QWORD), PZero, RefIndexLsl(3)] => [R(0), R3(10), R(5), RNoZr(16)], SVE;
+    // LD2D (scalar plus immediate)
+    0b10100101_10100000_11100000_00000000 = [ZRegList(2, QWORD), PZero, RefMulVl] => [R(0), R3(10), R(5), Sscaled(16, 4, 1)], SVE;
+]
+"ld2h" = [
+    // LD2H (scalar plus scalar)
+    0b10100100_10100000_11000000_00000000 = [ZRegList(2, WORD), PZero, RefIndexLsl(1)] => [R(0), R3(10), R(5), RNoZr(16)], SVE;
+    // LD2H (scalar plus immediate)
+    0b10100100_10100000_11100000_00000000 = [ZRegList(2, WORD), PZero, RefMulVl] => [R(0), R3(10), R(5), Sscaled(16, 4, 1)], SVE;
+]
+"ld2r" = [
+    0b00001101_01100000_11000000_00000000 = [RegList(2, BYTE), RefBase] => [R(0), R(5), Rwidth(30)];
+    0b00001101_01100000_11000100_00000000 = [RegList(2, WORD), RefBase] => [R(0), R(5), Rwidth(30)];
+    0b00001101_01100000_11001000_00000000 = [RegList(2, DWORD), RefBase] => [R(0), R(5), Rwidth(30)];
+    0b00001101_01100000_11001100_00000000 = [RegList(2, QWORD), RefBase] => [R(0), R(5), Rwidth(30)];
+    0b00001101_11111111_11000000_00000000 = [RegList(2, BYTE), RefBase, LitInt(2)] => [R(0), R(5), Rwidth(30)];
+    0b00001101_11111111_11000100_00000000 = [RegList(2, WORD), RefBase, LitInt(4)] => [R(0), R(5), Rwidth(30)];
+    0b00001101_11111111_11001000_00000000 = [RegList(2, DWORD), RefBase, LitInt(8)] => [R(0), R(5), Rwidth(30)];
+    0b00001101_11111111_11001100_00000000 = [RegList(2, QWORD), RefBase, LitInt(16)] => [R(0), R(5), Rwidth(30)];
+    0b00001101_11100000_11000000_00000000 = [RegList(2, BYTE), RefBase, X] => [R(0), R(5), RNoZr(16), Rwidth(30)];
+    0b00001101_11100000_11000100_00000000 = [RegList(2, WORD), RefBase, X] => [R(0), R(5), RNoZr(16), Rwidth(30)];
+    0b00001101_11100000_11001000_00000000 = [RegList(2, DWORD), RefBase, X] => [R(0), R(5), RNoZr(16), Rwidth(30)];
+    0b00001101_11100000_11001100_00000000 = [RegList(2, QWORD), RefBase, X] => [R(0), R(5), RNoZr(16), Rwidth(30)];
+]
+"ld2w" = [
+    // LD2W (scalar plus scalar)
+    0b10100101_00100000_11000000_00000000 = [ZRegList(2, DWORD), PZero, RefIndexLsl(2)] => [R(0), R3(10), R(5), RNoZr(16)], SVE;
+    // LD2W (scalar plus immediate)
+    0b10100101_00100000_11100000_00000000 = [ZRegList(2, DWORD), PZero, RefMulVl] => [R(0), R3(10), R(5), Sscaled(16, 4, 1)], SVE;
+]
+"ld3" = [
+    // LD3 (multiple structures)
+    0b00001100_01000000_01000000_00000000 = [RegList(3, BYTE), RefBase] => [R(0), R(5), Rwidth(30)];
+    0b00001100_01000000_01000100_00000000 = [RegList(3, WORD), RefBase] => [R(0), R(5), Rwidth(30)];
+    0b00001100_01000000_01001000_00000000 = [RegList(3, DWORD), RefBase] => [R(0), R(5), Rwidth(30)];
+    0b00001100_01000000_01001100_00000000 = [RegListStatic(3, QWORD, 2), RefBase] => [R(0), R(5), Rwidth(30)];
+    0b00001100_11011111_01000000_00000000 = [RegListStatic(3, BYTE, 8), RefBase, LitInt(24)] => [R(0), R(5)];
+    0b00001100_11011111_01000100_00000000 = [RegListStatic(3, WORD, 4), RefBase, LitInt(24)] => [R(0), R(5)];
+    0b00001100_11011111_01001000_00000000 = [RegListStatic(3, DWORD, 2), RefBase, LitInt(24)] => [R(0), R(5)];
+    0b01001100_11011111_01000000_00000000 = [RegListStatic(3, BYTE, 16), RefBase, LitInt(48)] => [R(0), R(5)];
+    0b01001100_11011111_01000100_00000000 = [RegListStatic(3, WORD, 8), RefBase, LitInt(48)] => [R(0), R(5)];
+    0b01001100_11011111_01001000_00000000 = [RegListStatic(3, DWORD, 4), RefBase, LitInt(48)] => [R(0), R(5)];
+    0b01001100_11011111_01001100_00000000 = [RegListStatic(3, QWORD, 2), RefBase, LitInt(48)] => [R(0), R(5)];
+    0b00001100_11000000_01000000_00000000 = [RegList(3, BYTE), RefBase, X] => [R(0), R(5), RNoZr(16), Rwidth(30)];
+    0b00001100_11000000_01000100_00000000 = [RegList(3, WORD), RefBase, X] => [R(0), R(5), RNoZr(16), Rwidth(30)];
+    0b00001100_11000000_01001000_00000000 = [RegList(3, DWORD), RefBase, X] => [R(0), R(5), RNoZr(16), Rwidth(30)];
+    0b00001100_11000000_01001100_00000000 = [RegListStatic(3, QWORD, 2), RefBase, X] => [R(0), R(5), RNoZr(16), Rwidth(30)];
+    // LD3 (single structure)
+    0b00001101_01000000_00100000_00000000 = [RegListElement(3, BYTE), RefBase] => [R(0), Ufields(&[30, 12, 11, 10]), R(5)];
+    0b00001101_01000000_01100000_00000000 = [RegListElement(3, WORD), RefBase] => [R(0), Ufields(&[30, 12, 11]), R(5)];
+    0b00001101_01000000_10100000_00000000 = [RegListElement(3, DWORD), RefBase] => [R(0), Ufields(&[30, 12]), R(5)];
+    0b00001101_01000000_10100100_00000000 = [RegListElement(3, QWORD), RefBase] => [R(0), Ufields(&[30]), R(5)];
+    0b00001101_11011111_00100000_00000000 = [RegListElement(3, BYTE), RefBase, LitInt(3)] => [R(0), Ufields(&[30, 12, 11, 10]), R(5)];
+    0b00001101_11000000_00100000_00000000 = [RegListElement(3, BYTE), RefBase, X] => [R(0), Ufields(&[30, 12, 11, 10]), R(5), RNoZr(16)];
+    0b00001101_11011111_01100000_00000000 = [RegListElement(3, WORD), RefBase, LitInt(6)] => [R(0), Ufields(&[30, 12, 11]), R(5)];
+    0b00001101_11000000_01100000_00000000 = [RegListElement(3, WORD), RefBase, X] => [R(0), Ufields(&[30, 12, 11]), R(5), RNoZr(16)];
+    0b00001101_11011111_10100000_00000000 = [RegListElement(3, DWORD), RefBase, LitInt(12)] => [R(0), Ufields(&[30, 12]), R(5)];
+    0b00001101_11000000_10100000_00000000 = [RegListElement(3, DWORD), RefBase, X] => [R(0), Ufields(&[30, 12]), R(5), RNoZr(16)];
+    0b00001101_11011111_10100100_00000000 = [RegListElement(3, QWORD), RefBase, LitInt(24)] => [R(0), Ufields(&[30]), R(5)];
+    0b00001101_11000000_10100100_00000000 = [RegListElement(3, QWORD), RefBase, X] => [R(0), Ufields(&[30]), R(5), RNoZr(16)];
+]
+"ld3b" = [
+    // LD3B (scalar plus scalar)
+    0b10100100_01000000_11000000_00000000 = [ZRegList(3, BYTE), PZero, RefIndexLsl(0)] => [R(0), R3(10), R(5), RNoZr(16)], SVE;
+    // LD3B (scalar plus immediate)
+    0b10100100_01000000_11100000_00000000 = [ZRegList(3, BYTE), PZero, RefMulVl] => [R(0), R3(10), R(5), Smultiple(16, 4, 3)], SVE;
+]
+"ld3d" = [
+    // LD3D (scalar plus scalar)
+    0b10100101_11000000_11000000_00000000 = [ZRegList(3, QWORD), PZero, RefIndexLsl(3)] => [R(0), R3(10), R(5), RNoZr(16)], SVE;
+    // LD3D (scalar plus immediate)
+    0b10100101_11000000_11100000_00000000 = [ZRegList(3, QWORD), PZero, RefMulVl] => [R(0), R3(10), R(5), Smultiple(16, 4, 3)], SVE;
+]
+"ld3h" = [
+    // LD3H (scalar plus scalar)
+    0b10100100_11000000_11000000_00000000 = [ZRegList(3, WORD), PZero, RefIndexLsl(1)] => [R(0), R3(10), R(5), RNoZr(16)], SVE;
+    // LD3H (scalar plus immediate)
+    0b10100100_11000000_11100000_00000000 = [ZRegList(3, WORD), PZero, RefMulVl] => [R(0), R3(10), R(5), Smultiple(16, 4, 3)], SVE;
+]
+"ld3r" = [
+    0b00001101_01000000_11100000_00000000 = [RegList(3, BYTE), RefBase] => [R(0), R(5), Rwidth(30)];
+    0b00001101_01000000_11100100_00000000 = [RegList(3, WORD), RefBase] => [R(0), R(5), Rwidth(30)];
+    0b00001101_01000000_11101000_00000000 = [RegList(3, DWORD), RefBase] => [R(0), R(5), Rwidth(30)];
+    0b00001101_01000000_11101100_00000000 = [RegList(3, QWORD), RefBase] => [R(0), R(5), Rwidth(30)];
+    0b00001101_11011111_11100000_00000000 = [RegList(3, BYTE), RefBase, LitInt(3)] => [R(0), R(5), Rwidth(30)];
+    0b00001101_11011111_11100100_00000000 = [RegList(3, WORD), RefBase, LitInt(6)] => [R(0), R(5), Rwidth(30)];
+    0b00001101_11011111_11101000_00000000 = [RegList(3, DWORD), RefBase, LitInt(12)] => [R(0), R(5), Rwidth(30)];
+    0b00001101_11011111_11101100_00000000 = [RegList(3, QWORD), RefBase, LitInt(24)] => [R(0), R(5), Rwidth(30)];
+    0b00001101_11000000_11100000_00000000 = [RegList(3, BYTE), RefBase, X] => [R(0), R(5), RNoZr(16), Rwidth(30)];
+    0b00001101_11000000_11100100_00000000 = [RegList(3, WORD), RefBase, X] => [R(0), R(5), RNoZr(16), Rwidth(30)];
+    0b00001101_11000000_11101000_00000000 = [RegList(3, DWORD), RefBase, X] => [R(0), R(5), RNoZr(16), Rwidth(30)];
+    0b00001101_11000000_11101100_00000000 = [RegList(3, QWORD), RefBase, X] => [R(0), R(5), RNoZr(16), Rwidth(30)];
+]
+"ld3w" = [
+    // LD3W (scalar plus scalar)
+    0b10100101_01000000_11000000_00000000 = [ZRegList(3, DWORD), PZero, RefIndexLsl(2)] => [R(0), R3(10), R(5), RNoZr(16)], SVE;
+    // LD3W (scalar plus immediate)
+    0b10100101_01000000_11100000_00000000 = [ZRegList(3, DWORD), PZero, RefMulVl] => [R(0), R3(10), R(5), Smultiple(16, 4, 3)], SVE;
+]
+"ld4" = [
+    // LD4 (multiple structures)
+    0b00001100_01000000_00000000_00000000 = [RegList(4, BYTE), RefBase] => [R(0), R(5), Rwidth(30)];
+    0b00001100_01000000_00000100_00000000 = [RegList(4, WORD), RefBase] => [R(0), R(5), Rwidth(30)];
+    0b00001100_01000000_00001000_00000000 = [RegList(4, DWORD), RefBase] => [R(0), R(5), Rwidth(30)];
+    0b00001100_01000000_00001100_00000000 = [RegListStatic(4, QWORD, 2), RefBase] => [R(0), R(5), Rwidth(30)];
+    0b00001100_11011111_00000000_00000000 = [RegListStatic(4, BYTE, 8), RefBase, LitInt(32)] => [R(0), R(5)];
+    0b00001100_11011111_00000100_00000000 = [RegListStatic(4, WORD, 4), RefBase, LitInt(32)] => [R(0), R(5)];
+    0b00001100_11011111_00001000_00000000 = [RegListStatic(4, DWORD, 2), RefBase, LitInt(32)] => [R(0), R(5)];
+    0b01001100_11011111_00000000_00000000 = [RegListStatic(4, BYTE, 16), RefBase, LitInt(64)] => [R(0), R(5)];
+    0b01001100_11011111_00000100_00000000 = [RegListStatic(4, WORD, 8), RefBase, LitInt(64)] => [R(0), R(5)];
+    0b01001100_11011111_00001000_00000000 = [RegListStatic(4, DWORD, 4), RefBase, LitInt(64)] => [R(0), R(5)];
+    0b01001100_11011111_00001100_00000000 = [RegListStatic(4, QWORD, 2), RefBase, LitInt(64)] => [R(0), R(5)];
+    0b00001100_11000000_00000000_00000000 = [RegList(4, BYTE), RefBase, X] => [R(0), R(5), RNoZr(16), Rwidth(30)];
+    0b00001100_11000000_00000100_00000000 = [RegList(4, WORD), RefBase, X] => [R(0), R(5), RNoZr(16), Rwidth(30)];
+    0b00001100_11000000_00001000_00000000 = [RegList(4, DWORD), RefBase, X] => [R(0), R(5), RNoZr(16), Rwidth(30)];
+    0b00001100_11000000_00001100_00000000 = [RegListStatic(4, QWORD, 2), RefBase, X] => [R(0), R(5), RNoZr(16), Rwidth(30)];
+    // LD4 (single structure)
+    0b00001101_01100000_00100000_00000000 = [RegListElement(4, BYTE), RefBase] => [R(0), Ufields(&[30, 12, 11, 10]), R(5)];
+    0b00001101_01100000_01100000_00000000 = [RegListElement(4, WORD), RefBase] => [R(0), Ufields(&[30, 12, 11]), R(5)];
+    0b00001101_01100000_10100000_00000000 = [RegListElement(4, DWORD), RefBase] => [R(0), Ufields(&[30, 12]), R(5)];
+    0b00001101_01100000_10100100_00000000 = [RegListElement(4, QWORD), RefBase] => [R(0), Ufields(&[30]), R(5)];
+    0b00001101_11111111_00100000_00000000 = [RegListElement(4, BYTE), RefBase, LitInt(4)] => [R(0), Ufields(&[30, 12, 11, 10]), R(5)];
+    0b00001101_11100000_00100000_00000000 = [RegListElement(4, BYTE), RefBase, X] => [R(0), Ufields(&[30, 12, 11, 10]), R(5), RNoZr(16)];
+    0b00001101_11111111_01100000_00000000 = [RegListElement(4, WORD), RefBase, LitInt(8)] => [R(0), Ufields(&[30, 12, 11]), R(5)];
+    0b00001101_11100000_01100000_00000000 = [RegListElement(4, WORD), RefBase, X] => [R(0), Ufields(&[30, 12, 11]), R(5), RNoZr(16)];
+    0b00001101_11111111_10100000_00000000 = [RegListElement(4, DWORD), RefBase, LitInt(16)] => [R(0), Ufields(&[30, 12]), R(5)];
+    0b00001101_11100000_10100000_00000000 = [RegListElement(4, DWORD), RefBase, X] => [R(0), Ufields(&[30, 12]), R(5), RNoZr(16)];
+    0b00001101_11111111_10100100_00000000 = [RegListElement(4, QWORD), RefBase, LitInt(32)] => [R(0), Ufields(&[30]), R(5)];
+    0b00001101_11100000_10100100_00000000 = [RegListElement(4, QWORD), RefBase, X] => [R(0), Ufields(&[30]), R(5), RNoZr(16)];
+]
+"ld4b" = [
+    // LD4B (scalar plus scalar)
+    0b10100100_01100000_11000000_00000000 = [ZRegList(4, BYTE), PZero, RefIndexLsl(0)] => [R(0), R3(10), R(5), RNoZr(16)], SVE;
+    // LD4B (scalar plus immediate)
+    0b10100100_01100000_11100000_00000000 = [ZRegList(4, BYTE), PZero, RefMulVl] => [R(0), R3(10), R(5), Sscaled(16, 4, 2)], SVE;
+]
+"ld4d" = [
+    // LD4D (scalar plus scalar)
+    0b10100101_11100000_11000000_00000000 = [ZRegList(4, QWORD), PZero, RefIndexLsl(3)] => [R(0), R3(10), R(5), RNoZr(16)], SVE;
+    // LD4D (scalar plus immediate)
+    0b10100101_11100000_11100000_00000000 = [ZRegList(4, QWORD), PZero, RefMulVl] => [R(0), R3(10), R(5), Sscaled(16, 4, 2)], SVE;
+]
+"ld4h" = [
+    // LD4H (scalar plus scalar)
+    0b10100100_11100000_11000000_00000000 = [ZRegList(4, WORD), PZero, RefIndexLsl(1)] => [R(0), R3(10), R(5), RNoZr(16)], SVE;
+    // LD4H (scalar plus immediate)
+    0b10100100_11100000_11100000_00000000 = [ZRegList(4, WORD), PZero, RefMulVl] => [R(0), R3(10), R(5), Sscaled(16, 4, 2)], SVE;
+]
+"ld4r" = [
+    0b00001101_01100000_11100000_00000000 = [RegList(4, BYTE), RefBase] => [R(0), R(5), Rwidth(30)];
+    0b00001101_01100000_11100100_00000000 = [RegList(4, WORD), RefBase] => [R(0), R(5), Rwidth(30)];
+    0b00001101_01100000_11101000_00000000 = [RegList(4, DWORD), RefBase] => [R(0), R(5), Rwidth(30)];
+    0b00001101_01100000_11101100_00000000 = [RegList(4, QWORD), RefBase] => [R(0), R(5), Rwidth(30)];
+    0b00001101_11111111_11100000_00000000 = [RegList(4, BYTE), RefBase, LitInt(4)] => [R(0), R(5), Rwidth(30)];
+    0b00001101_11111111_11100100_00000000 = [RegList(4, WORD), RefBase, LitInt(8)] => [R(0), R(5), Rwidth(30)];
+    0b00001101_11111111_11101000_00000000 = [RegList(4, DWORD), RefBase, LitInt(16)] => [R(0), R(5), Rwidth(30)];
+    0b00001101_11111111_11101100_00000000 = [RegList(4, QWORD), RefBase, LitInt(32)] => [R(0), R(5), Rwidth(30)];
+    0b00001101_11100000_11100000_00000000 = [RegList(4, BYTE), RefBase, X] => [R(0), R(5), RNoZr(16), Rwidth(30)];
+    0b00001101_11100000_11100100_00000000 = [RegList(4, WORD), RefBase, X] => [R(0), R(5), RNoZr(16), Rwidth(30)];
+    0b00001101_11100000_11101000_00000000 = [RegList(4, DWORD), RefBase, X] => [R(0), R(5), RNoZr(16), Rwidth(30)];
+    0b00001101_11100000_11101100_00000000 = [RegList(4, QWORD), RefBase, X] => [R(0), R(5), RNoZr(16), Rwidth(30)];
+]
+"ld4w" = [
+    // LD4W (scalar plus scalar)
+    0b10100101_01100000_11000000_00000000 = [ZRegList(4, DWORD), PZero, RefIndexLsl(2)] => [R(0), R3(10), R(5), RNoZr(16)], SVE;
+    // LD4W (scalar plus immediate)
+    0b10100101_01100000_11100000_00000000 = [ZRegList(4, DWORD), PZero, RefMulVl] => [R(0), R3(10), R(5), Sscaled(16, 4, 2)], SVE;
+]
+"ldadd" = [
+    0b10111000_00100000_00000000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], LSE;
+    0b11111000_00100000_00000000_00000000 = [X, X, RefBase] => [R(16), R(0), R(5)], LSE;
+]
+"ldadda" = [
+    0b10111000_10100000_00000000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], LSE;
+    0b11111000_10100000_00000000_00000000 = [X, X, RefBase] => [R(16), R(0), R(5)], LSE;
+]
+"ldaddab" = [
+    0b00111000_10100000_00000000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], LSE;
+]
+"ldaddah" = [
+    0b01111000_10100000_00000000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], LSE;
+]
+"ldaddal" = [
+    0b10111000_11100000_00000000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], LSE;
+    0b11111000_11100000_00000000_00000000 = [X, X, RefBase] => [R(16), R(0), R(5)], LSE;
+]
+"ldaddalb" = [
+    0b00111000_11100000_00000000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], LSE;
+]
+"ldaddalh" = [
+    0b01111000_11100000_00000000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], LSE;
+]
+"ldaddb" = [
+    0b00111000_00100000_00000000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], LSE;
+]
+"ldaddh" = [
+    0b01111000_00100000_00000000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], LSE;
+]
+"ldaddl" = [
+    0b10111000_01100000_00000000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], LSE;
+    0b11111000_01100000_00000000_00000000 = [X, X, RefBase] => [R(16), R(0), R(5)], LSE;
+]
+"ldaddlb" = [
+    0b00111000_01100000_00000000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], LSE;
+]
+"ldaddlh" = [
+    0b01111000_01100000_00000000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], LSE;
+]
+"ldapr" = [
+    0b10111000_10111111_11000000_00000000 = [W, RefBase] => [R(0), R(5)], RCPC;
+    0b11111000_10111111_11000000_00000000 = [X, RefBase] => [R(0), R(5)], RCPC;
+]
+"ldaprb" = [
+    0b00111000_10111111_11000000_00000000 = [W, RefBase] => [R(0), R(5)], RCPC;
+]
+"ldaprh" = [
+    0b01111000_10111111_11000000_00000000 = [W, RefBase] => [R(0), R(5)], RCPC;
+]
+"ldapur" = [
+    0b10011001_01000000_00000000_00000000 = [W, RefOffset] => [R(0), R(5), Sbits(12, 9)], RCPC2;
+    0b11011001_01000000_00000000_00000000 = [X, RefOffset] => [R(0), R(5), Sbits(12, 9)], RCPC2;
+]
+"ldapurb" = [
+    0b00011001_01000000_00000000_00000000 = [W, RefOffset] => [R(0), R(5), Sbits(12, 9)], RCPC2;
+]
+"ldapurh" = [
+    0b01011001_01000000_00000000_00000000 = [W, RefOffset] => [R(0), R(5), Sbits(12, 9)], RCPC2;
+]
+"ldapursb" = [
+    0b00011001_11000000_00000000_00000000 = [W, RefOffset] => [R(0), R(5), Sbits(12, 9)], RCPC2;
+    0b00011001_10000000_00000000_00000000 = [X, RefOffset] => [R(0), R(5), Sbits(12, 9)], RCPC2;
+]
+"ldapursh" = [
+    0b01011001_11000000_00000000_00000000 = [W, RefOffset] => [R(0), R(5), Sbits(12, 9)], RCPC2;
+    0b01011001_10000000_00000000_00000000 = [X, RefOffset] => [R(0), R(5), Sbits(12, 9)], RCPC2;
+]
+"ldapursw" = [
+    0b10011001_10000000_00000000_00000000 = [X, RefOffset] => [R(0), R(5), Sbits(12, 9)], RCPC2;
+]
+"ldar" = [
+    0b10001000_11011111_11111100_00000000 = [W, RefBase] => [R(0), R(5)];
+    0b11001000_11011111_11111100_00000000 = [X, RefBase] => [R(0), R(5)];
+]
+"ldarb" = [
+    0b00001000_11011111_11111100_00000000 = [W, RefBase] => [R(0), R(5)];
+]
+"ldarh" = [
+    0b01001000_11011111_11111100_00000000 = [W, RefBase] => [R(0), R(5)];
+]
+"ldaxp" = [
+    0b10001000_01111111_10000000_00000000 = [W, W, RefBase] => [R(0), R(10), R(5)];
+    0b11001000_01111111_10000000_00000000 = [X, X, RefBase] => [R(0), R(10), R(5)];
+]
+"ldaxr" = [
+    0b10001000_01011111_11111100_00000000 = [W, RefBase] => [R(0), R(5)];
+    0b11001000_01011111_11111100_00000000 = [X, RefBase] => [R(0), R(5)];
+]
+"ldaxrb" = [
+    0b00001000_01011111_11111100_00000000 = [W, RefBase] => [R(0), R(5)];
+]
+"ldaxrh" = [
+    0b01001000_01011111_11111100_00000000 = [W, RefBase] => [R(0), R(5)];
+]
+"ldclr" = [
+    0b10111000_00100000_00010000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], LSE;
+    0b11111000_00100000_00010000_00000000 = [X, X, RefBase] => [R(16), R(0), R(5)], LSE;
+]
+"ldclra" = [
+    0b10111000_10100000_00010000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], LSE;
+    0b11111000_10100000_00010000_00000000 = [X, X, RefBase] => [R(16), R(0), R(5)], LSE;
+]
+"ldclrab" = [
+    0b00111000_10100000_00010000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], LSE;
+]
+"ldclrah" = [
+    0b01111000_10100000_00010000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], LSE;
+]
+"ldclral" = [
+    0b10111000_11100000_00010000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], LSE;
+    0b11111000_11100000_00010000_00000000 = [X, X, RefBase] => [R(16), R(0), R(5)], LSE;
+]
+"ldclralb" = [
+    0b00111000_11100000_00010000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], LSE;
+]
+"ldclralh" = [
+    0b01111000_11100000_00010000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], LSE;
+]
+"ldclrb" = [
+    0b00111000_00100000_00010000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], LSE;
+]
+"ldclrh" = [
+    0b01111000_00100000_00010000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], LSE;
+]
+"ldclrl" = [
+    0b10111000_01100000_00010000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], LSE;
+    0b11111000_01100000_00010000_00000000 = [X, X, RefBase] => [R(16), R(0), R(5)], LSE;
+]
+"ldclrlb" = [
+    0b00111000_01100000_00010000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], LSE;
+]
+"ldclrlh" = [
+    0b01111000_01100000_00010000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], LSE;
+]
+"ldeor" = [
+    0b10111000_00100000_00100000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], LSE;
+    0b11111000_00100000_00100000_00000000 = [X, X, RefBase] => [R(16), R(0), R(5)], LSE;
+]
+"ldeora" = [
+    0b10111000_10100000_00100000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], LSE;
+    0b11111000_10100000_00100000_00000000 = [X, X, RefBase] => [R(16), R(0), R(5)], LSE;
+]
+"ldeorab" = [
+    0b00111000_10100000_00100000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], LSE;
+]
+"ldeorah" = [
+    0b01111000_10100000_00100000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], LSE;
+]
+"ldeoral" = [
+    0b10111000_11100000_00100000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], LSE;
+    0b11111000_11100000_00100000_00000000 = [X, X, RefBase] => [R(16), R(0), R(5)], LSE;
+]
+"ldeoralb" = [
+    0b00111000_11100000_00100000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], LSE;
+]
+"ldeoralh" = [
+    0b01111000_11100000_00100000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], LSE;
+]
+"ldeorb" = [
+    0b00111000_00100000_00100000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], LSE;
+]
+"ldeorh" = [
+    0b01111000_00100000_00100000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], LSE;
+]
+"ldeorl" = [
+    0b10111000_01100000_00100000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], LSE;
+    0b11111000_01100000_00100000_00000000 = [X, X, RefBase] => [R(16), R(0), R(5)], LSE;
+]
+"ldeorlb" = [
+    0b00111000_01100000_00100000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], LSE;
+]
+"ldeorlh" = [
+    0b01111000_01100000_00100000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], LSE;
+]
+"ldff1b" = [
+    // LDFF1B (scalar plus scalar)
+    0b10100100_00000000_01100000_00000000 = [ZRegList(1, BYTE), PZero, RefIndexLsl(0)] => [R(0), R3(10), R(5), R(16)], SVE;
+    0b10100100_00011111_01100000_00000000 = [ZRegList(1, BYTE), PZero, RefBase] => [R(0), R3(10), R(5)], SVE;
+    0b10100100_01100000_01100000_00000000 = [ZRegList(1, QWORD), PZero, RefIndexLsl(0)] => [R(0), R3(10), R(5), R(16)], SVE;
+    0b10100100_01111111_01100000_00000000 = [ZRegList(1, QWORD), PZero, RefBase] => [R(0), R3(10), R(5)], SVE;
+    0b10100100_00100000_01100000_00000000 = [ZRegList(1, WORD), PZero, RefIndexLsl(0)] => [R(0), R3(10), R(5), R(16)], SVE;
+    0b10100100_00111111_01100000_00000000 = [ZRegList(1, WORD), PZero, RefBase] => [R(0), R3(10), R(5)], SVE;
+    0b10100100_01000000_01100000_00000000 = [ZRegList(1, DWORD), PZero, RefIndexLsl(0)] => [R(0), R3(10), R(5), R(16)], SVE;
+    0b10100100_01011111_01100000_00000000 = [ZRegList(1, DWORD), PZero, RefBase] => [R(0), R3(10), R(5)], SVE;
+    // LDFF1B (scalar plus vector)
+    0b11000100_00000000_01100000_00000000 = [ZRegList(1, QWORD), PZero, RefZIndexExtend(QWORD, 0)] => [R(0), R3(10), R(5), R(16), ExtendsZ(22)], SVE;
+    0b11000100_01000000_11100000_00000000 = [ZRegList(1, QWORD), PZero, RefZIndexLsl(QWORD, 0)] => [R(0), R3(10), R(5), R(16)], SVE;
+    0b10000100_00000000_01100000_00000000 = [ZRegList(1, DWORD), PZero, RefZIndexExtend(DWORD, 0)] => [R(0), R3(10), R(5), R(16), ExtendsZ(22)], SVE;
+    // LDFF1B (vector plus immediate)
+    0b11000100_00100000_11100000_00000000 = [ZRegList(1, QWORD), PZero, RefZBase(QWORD)] => [R(0), R3(10), R(5), Ubits(16, 5)], SVE;
+    0b10000100_00100000_11100000_00000000 = [ZRegList(1, DWORD), PZero, RefZBase(DWORD)] => [R(0), R3(10), R(5), Ubits(16, 5)], SVE;
+]
+"ldff1d" = [
+    // LDFF1D (scalar plus vector)
+    0b11000101_10100000_01100000_00000000 = [ZRegList(1, QWORD), PZero, RefZIndexExtend(QWORD, 3)] => [R(0), R3(10), R(5), R(16), ExtendsZ(22)], SVE;
+    0b11000101_10000000_01100000_00000000 = [ZRegList(1, QWORD), PZero, RefZIndexExtend(QWORD, 0)] => [R(0), R3(10), R(5), R(16), ExtendsZ(22)], SVE;
+    0b11000101_11100000_11100000_00000000 = [ZRegList(1, QWORD), PZero, RefZIndexLsl(QWORD, 3)] => [R(0), R3(10), R(5), R(16)], SVE;
+    0b11000101_11000000_11100000_00000000 = [ZRegList(1, QWORD), PZero, RefZIndexLsl(QWORD, 0)] => [R(0), R3(10), R(5), R(16)], SVE;
+    // LDFF1D (scalar plus scalar)
+    0b10100101_11100000_01100000_00000000 = [ZRegList(1, QWORD), PZero, RefIndexLsl(3)] => [R(0), R3(10), R(5), R(16)], SVE;
+    0b10100101_11111111_01100000_00000000 = [ZRegList(1, QWORD), PZero, RefBase] => [R(0), R3(10), R(5)], SVE;
+    // LDFF1D (vector plus immediate)
+    0b11000101_10100000_11100000_00000000 = [ZRegList(1, QWORD), PZero, RefZBase(QWORD)] => [R(0), R3(10), R(5), Uscaled(16, 5, 3)], SVE;
+]
+"ldff1h" = [
+    // LDFF1H (scalar plus vector)
+    0b11000100_10100000_01100000_00000000 = [ZRegList(1, QWORD), PZero, RefZIndexExtend(QWORD, 1)] => [R(0), R3(10), R(5), R(16), ExtendsZ(22)], SVE;
+    0b11000100_10000000_01100000_00000000 = [ZRegList(1, QWORD), PZero, RefZIndexExtend(QWORD, 0)] => [R(0), R3(10), R(5), R(16), ExtendsZ(22)], SVE;
+    0b11000100_11100000_11100000_00000000 = [ZRegList(1, QWORD), PZero, RefZIndexLsl(QWORD, 1)] => [R(0), R3(10), R(5), R(16)], SVE;
+    0b11000100_11000000_11100000_00000000 = [ZRegList(1, QWORD), PZero, RefZIndexLsl(QWORD, 0)] => [R(0), R3(10), R(5), R(16)], SVE;
+    0b10000100_10100000_01100000_00000000 = [ZRegList(1, DWORD), PZero, RefZIndexExtend(DWORD, 1)] => [R(0), R3(10), R(5), R(16), ExtendsZ(22)], SVE;
+    0b10000100_10000000_01100000_00000000 = [ZRegList(1, DWORD), PZero, RefZIndexExtend(DWORD, 0)] => [R(0), R3(10), R(5), R(16), ExtendsZ(22)], SVE;
+    // LDFF1H (scalar plus scalar)
+    0b10100100_11100000_01100000_00000000 = [ZRegList(1, QWORD), PZero, RefIndexLsl(1)] => [R(0), R3(10), R(5), R(16)], SVE;
+    0b10100100_11111111_01100000_00000000 = [ZRegList(1, QWORD), PZero, RefBase] => [R(0), R3(10), R(5)], SVE;
+    0b10100100_10100000_01100000_00000000 = [ZRegList(1, WORD), PZero, RefIndexLsl(1)] => [R(0), R3(10), R(5), R(16)], SVE;
+    0b10100100_10111111_01100000_00000000 = [ZRegList(1, WORD), PZero, RefBase] => [R(0), R3(10), R(5)], SVE;
+    0b10100100_11000000_01100000_00000000 = [ZRegList(1, DWORD), PZero, RefIndexLsl(1)] => [R(0), R3(10), R(5), R(16)], SVE;
+    0b10100100_11011111_01100000_00000000 = [ZRegList(1, DWORD), PZero, RefBase] => [R(0), R3(10), R(5)], SVE;
+    // LDFF1H (vector plus immediate)
+    0b11000100_10100000_11100000_00000000 = [ZRegList(1, QWORD), PZero, RefZBase(QWORD)] => [R(0), R3(10), R(5), Uscaled(16, 5, 1)], SVE;
+    0b10000100_10100000_11100000_00000000 = [ZRegList(1, DWORD), PZero, RefZBase(DWORD)] => [R(0), R3(10), R(5), Uscaled(16, 5, 1)], SVE;
+]
+"ldff1sb" = [
+    // LDFF1SB (scalar plus vector)
+    0b11000100_00000000_00100000_00000000 = [ZRegList(1, QWORD), PZero, RefZIndexExtend(QWORD, 0)] => [R(0), R3(10), R(5), R(16), ExtendsZ(22)], SVE;
+    0b11000100_01000000_10100000_00000000 = [ZRegList(1, QWORD), PZero, RefZIndexLsl(QWORD, 0)] => [R(0), R3(10), R(5), R(16)], SVE;
+    0b10000100_00000000_00100000_00000000 = [ZRegList(1, DWORD), PZero, RefZIndexExtend(DWORD, 0)] => [R(0), R3(10), R(5), R(16), ExtendsZ(22)], SVE;
+    // LDFF1SB (scalar plus scalar)
+    0b10100101_10000000_01100000_00000000 = [ZRegList(1, QWORD), PZero, RefIndexLsl(0)] => [R(0), R3(10), R(5), R(16)], SVE;
+    0b10100101_10011111_01100000_00000000 = [ZRegList(1, QWORD), PZero, RefBase] => [R(0), R3(10), R(5)], SVE;
+    0b10100101_11000000_01100000_00000000 = [ZRegList(1, WORD), PZero, RefIndexLsl(0)] => [R(0), R3(10), R(5), R(16)], SVE;
+    0b10100101_11011111_01100000_00000000 = [ZRegList(1, WORD), PZero, RefBase] => [R(0), R3(10), R(5)], SVE;
+    0b10100101_10100000_01100000_00000000 = [ZRegList(1, DWORD), PZero, RefIndexLsl(0)] => [R(0), R3(10), R(5), R(16)], SVE;
+    0b10100101_10111111_01100000_00000000 = [ZRegList(1, DWORD), PZero, RefBase] => [R(0), R3(10), R(5)], SVE;
+    // LDFF1SB (vector plus immediate)
+    0b11000100_00100000_10100000_00000000 = [ZRegList(1, QWORD), PZero, RefZBase(QWORD)] => [R(0), R3(10), R(5), Ubits(16, 5)], SVE;
+    0b10000100_00100000_10100000_00000000 = [ZRegList(1, DWORD), PZero, RefZBase(DWORD)] => [R(0), R3(10), R(5), Ubits(16, 5)], SVE;
+]
+"ldff1sh" = [
+    // LDFF1SH (scalar plus vector)
+    0b11000100_10100000_00100000_00000000 = [ZRegList(1, QWORD), PZero, RefZIndexExtend(QWORD, 1)] => [R(0), R3(10), R(5), R(16), ExtendsZ(22)], SVE;
+    0b11000100_10000000_00100000_00000000 = [ZRegList(1, QWORD), PZero, RefZIndexExtend(QWORD, 0)] => [R(0), R3(10), R(5), R(16), ExtendsZ(22)], SVE;
+    0b11000100_11100000_10100000_00000000 = [ZRegList(1, QWORD), PZero, RefZIndexLsl(QWORD, 1)] => [R(0), R3(10), R(5), R(16)], SVE;
+    0b11000100_11000000_10100000_00000000 = [ZRegList(1, QWORD), PZero, RefZIndexLsl(QWORD, 0)] => [R(0), R3(10), R(5), R(16)], SVE;
+    0b10000100_10100000_00100000_00000000 = [ZRegList(1, DWORD), PZero, RefZIndexExtend(DWORD, 1)] => [R(0), R3(10), R(5), R(16), ExtendsZ(22)], SVE;
+    0b10000100_10000000_00100000_00000000 = [ZRegList(1, DWORD), PZero, RefZIndexExtend(DWORD, 0)] => [R(0), R3(10), R(5), R(16), ExtendsZ(22)], SVE;
+    // LDFF1SH (scalar plus scalar)
+    0b10100101_00000000_01100000_00000000 = [ZRegList(1, QWORD), PZero, RefIndexLsl(1)] => [R(0), R3(10), R(5), R(16)], SVE;
+    0b10100101_00011111_01100000_00000000 = [ZRegList(1, QWORD), PZero, RefBase] => [R(0), R3(10), R(5)], SVE;
+    0b10100101_00100000_01100000_00000000 = [ZRegList(1, DWORD), PZero, RefIndexLsl(1)] => [R(0), R3(10), R(5), R(16)], SVE;
+    0b10100101_00111111_01100000_00000000 = [ZRegList(1, DWORD), PZero, RefBase] => [R(0), R3(10), R(5)], SVE;
+    // LDFF1SH (vector plus immediate)
+    0b11000100_10100000_10100000_00000000 = [ZRegList(1, QWORD), PZero, RefZBase(QWORD)] => [R(0), R3(10), R(5), Uscaled(16, 5, 1)], SVE;
+    0b10000100_10100000_10100000_00000000 = [ZRegList(1, DWORD), PZero, RefZBase(DWORD)] => [R(0), R3(10), R(5), Uscaled(16, 5, 1)], SVE;
+]
+"ldff1sw" = [
+    // LDFF1SW (scalar plus vector)
+    0b11000101_00100000_00100000_00000000 = [ZRegList(1, QWORD), PZero, RefZIndexExtend(QWORD, 2)] => [R(0), R3(10), R(5), R(16), ExtendsZ(22)], SVE;
+    0b11000101_00000000_00100000_00000000 = [ZRegList(1, QWORD), PZero, RefZIndexExtend(QWORD, 0)] => [R(0), R3(10), R(5), R(16), ExtendsZ(22)], SVE;
+    0b11000101_01100000_10100000_00000000 = [ZRegList(1, QWORD), PZero, RefZIndexLsl(QWORD, 2)] => [R(0), R3(10), R(5), R(16)], SVE;
+    0b11000101_01000000_10100000_00000000 = [ZRegList(1, QWORD), PZero, RefZIndexLsl(QWORD, 0)] => [R(0), R3(10), R(5), R(16)], SVE;
+    // LDFF1SW (scalar plus scalar)
+    0b10100100_10000000_01100000_00000000 = [ZRegList(1, QWORD), PZero, RefIndexLsl(2)] => [R(0), R3(10), R(5), R(16)], SVE;
+    0b10100100_10011111_01100000_00000000 = [ZRegList(1, QWORD), PZero, RefBase] => [R(0), R3(10), R(5)], SVE;
+    // LDFF1SW (vector plus immediate)
+    0b11000101_00100000_10100000_00000000 = [ZRegList(1, QWORD), PZero, RefZBase(QWORD)] => [R(0), R3(10), R(5), Uscaled(16, 5, 2)], SVE;
+]
+"ldff1w" = [
+    // LDFF1W (scalar plus vector)
+    0b11000101_00100000_01100000_00000000 = [ZRegList(1, QWORD), PZero, RefZIndexExtend(QWORD, 2)] => [R(0), R3(10), R(5), R(16), ExtendsZ(22)], SVE;
+    0b11000101_00000000_01100000_00000000 = [ZRegList(1, QWORD), PZero, RefZIndexExtend(QWORD, 0)] => [R(0), R3(10), R(5), R(16), ExtendsZ(22)], SVE;
+    0b11000101_01100000_11100000_00000000 = [ZRegList(1, QWORD), PZero, RefZIndexLsl(QWORD, 2)] => [R(0), R3(10), R(5), R(16)], SVE;
+    0b11000101_01000000_11100000_00000000 = [ZRegList(1, QWORD), PZero, RefZIndexLsl(QWORD, 0)] => [R(0), R3(10), R(5), R(16)], SVE;
+    0b10000101_00100000_01100000_00000000 = [ZRegList(1, DWORD), PZero, RefZIndexExtend(DWORD, 2)] => [R(0), R3(10), R(5), R(16), ExtendsZ(22)], SVE;
+    0b10000101_00000000_01100000_00000000 = [ZRegList(1, DWORD), PZero, RefZIndexExtend(DWORD, 0)] => [R(0), R3(10), R(5), R(16), ExtendsZ(22)], SVE;
+    // LDFF1W (scalar plus scalar)
+    0b10100101_01100000_01100000_00000000 = [ZRegList(1, QWORD), PZero, RefIndexLsl(2)] => [R(0), R3(10), R(5), R(16)], SVE;
+    0b10100101_01111111_01100000_00000000 = [ZRegList(1, QWORD), PZero, RefBase] => [R(0), R3(10), R(5)], SVE;
+    0b10100101_01000000_01100000_00000000 = [ZRegList(1, DWORD), PZero, RefIndexLsl(2)] => [R(0), R3(10), R(5), R(16)], SVE;
+    0b10100101_01011111_01100000_00000000 = [ZRegList(1, DWORD), PZero, RefBase] => [R(0), R3(10), R(5)], SVE;
+    // LDFF1W (vector plus immediate)
+    0b11000101_00100000_11100000_00000000 = [ZRegList(1, QWORD), PZero, RefZBase(QWORD)] => [R(0), R3(10), R(5), Uscaled(16, 5, 2)], SVE;
+    0b10000101_00100000_11100000_00000000 = [ZRegList(1, DWORD), PZero, RefZBase(DWORD)] => [R(0), R3(10), R(5), Uscaled(16, 5, 2)], SVE;
+]
+"ldg" = [
+    0b11011001_01100000_00000000_00000000 = [X, RefOffset] => [R(0), R(5), Sscaled(12, 9, 4)], MTE;
+]
+"ldgm" = [
+    0b11011001_11100000_00000000_00000000 = [X, RefBase] => [R(0), R(5)], MTE;
+]
+"ldlar" = [
+    0b10001000_11011111_01111100_00000000 = [W, RefBase] => [R(0), R(5)], LOR;
+    0b11001000_11011111_01111100_00000000 = [X, RefBase] => [R(0), R(5)], LOR;
+]
+"ldlarb" = [
+    0b00001000_11011111_01111100_00000000 = [W, RefBase] => [R(0), R(5)], LOR;
+]
+"ldlarh" = [
+    0b01001000_11011111_01111100_00000000 = [W, RefBase] => [R(0), R(5)], LOR;
+]
+"ldnf1b" = [
+    0b10100100_00010000_10100000_00000000 = [ZRegList(1, BYTE), PZero, RefMulVl] => [R(0), R3(10), R(5), Sbits(16, 4)], SVE;
+    0b10100100_01110000_10100000_00000000 = [ZRegList(1, QWORD), PZero, RefMulVl] => [R(0), R3(10), R(5), Sbits(16, 4)], SVE;
+    0b10100100_00110000_10100000_00000000 = [ZRegList(1, WORD), PZero, RefMulVl] => [R(0), R3(10), R(5), Sbits(16, 4)], SVE;
+    0b10100100_01010000_10100000_00000000 = [ZRegList(1, DWORD), PZero, RefMulVl] => [R(0), R3(10), R(5), Sbits(16, 4)], SVE;
+]
+"ldnf1d" = [
+    0b10100101_11110000_10100000_00000000 = [ZRegList(1, QWORD), PZero, RefMulVl] => [R(0), R3(10), R(5), Sbits(16, 4)], SVE;
+]
+"ldnf1h" = [
+    0b10100100_11110000_10100000_00000000 = [ZRegList(1, QWORD), PZero, RefMulVl] => [R(0), R3(10), R(5), Sbits(16, 4)], SVE;
+    0b10100100_10110000_10100000_00000000 = [ZRegList(1, WORD), PZero, RefMulVl] => [R(0), R3(10), R(5), Sbits(16, 4)], SVE;
+    0b10100100_11010000_10100000_00000000 = [ZRegList(1, DWORD), PZero, RefMulVl] => [R(0), R3(10), R(5), Sbits(16, 4)], SVE;
+]
+"ldnf1sb" = [
+    0b10100101_10010000_10100000_00000000 = [ZRegList(1, QWORD), PZero, RefMulVl] => [R(0), R3(10), R(5), Sbits(16, 4)], SVE;
+    0b10100101_11010000_10100000_00000000 = [ZRegList(1, WORD), PZero, RefMulVl] => [R(0), R3(10), R(5), Sbits(16, 4)], SVE;
+    0b10100101_10110000_10100000_00000000 = [ZRegList(1, DWORD), PZero, RefMulVl] => [R(0), R3(10), R(5), Sbits(16, 4)], SVE;
+]
+"ldnf1sh" = [
+    0b10100101_00010000_10100000_00000000 = [ZRegList(1, QWORD), PZero, RefMulVl] => [R(0), R3(10), R(5), Sbits(16, 4)], SVE;
+    0b10100101_00110000_10100000_00000000 = [ZRegList(1, DWORD), PZero, RefMulVl] => [R(0), R3(10), R(5), Sbits(16, 4)], SVE;
+]
+"ldnf1sw" = [
+    0b10100100_10010000_10100000_00000000 = [ZRegList(1, QWORD), PZero, RefMulVl] => [R(0), R3(10), R(5), Sbits(16, 4)], SVE;
+]
+"ldnf1w" = [
+    0b10100101_01110000_10100000_00000000 = [ZRegList(1, QWORD), PZero, RefMulVl] => [R(0), R3(10), R(5), Sbits(16, 4)], SVE;
+    0b10100101_01010000_10100000_00000000 = [ZRegList(1, DWORD), PZero, RefMulVl] => [R(0), R3(10), R(5), Sbits(16, 4)], SVE;
+]
+"ldnp" = [
+    // LDNP (SIMD&FP)
+    0b00101100_01000000_00000000_00000000 = [S, S, RefOffset] => [R(0), R(10), R(5), Sscaled(15, 7, 2)];
+    0b01101100_01000000_00000000_00000000 = [D, D, RefOffset] => [R(0), R(10), R(5), Sscaled(15, 7, 3)];
+    0b10101100_01000000_00000000_00000000 = [Q, Q, RefOffset] => [R(0), R(10), R(5), Sscaled(15, 7, 4)];
+    // LDNP
+    0b00101000_01000000_00000000_00000000 = [W, W, RefOffset] => [R(0), R(10), R(5), Sscaled(15, 7, 2)];
+    0b10101000_01000000_00000000_00000000 = [X, X, RefOffset] => [R(0), R(10), R(5), Sscaled(15, 7, 3)];
+]
+"ldnt1b" = [
+    // LDNT1B (scalar plus scalar)
+    0b10100100_00000000_11000000_00000000 = [ZRegList(1, BYTE), PZero, RefIndexLsl(0)] => [R(0), R3(10), R(5), RNoZr(16)], SVE;
+    // LDNT1B (scalar plus immediate)
+    0b10100100_00000000_11100000_00000000 = [ZRegList(1, BYTE), PZero, RefMulVl] => [R(0), R3(10), R(5), Sbits(16, 4)], SVE;
+]
+"ldnt1d" = [
+    // LDNT1D (scalar plus scalar)
+    0b10100101_10000000_11000000_00000000 = [ZRegList(1, QWORD), PZero, RefIndexLsl(3)] => [R(0), R3(10), R(5), RNoZr(16)], SVE;
+    // LDNT1D (scalar plus immediate)
+    0b10100101_10000000_11100000_00000000 = [ZRegList(1, QWORD), PZero, RefMulVl] => [R(0), R3(10), R(5), Sbits(16, 4)], SVE;
+]
+"ldnt1h" = [
+    // LDNT1H (scalar plus scalar)
+    0b10100100_10000000_11000000_00000000 = [ZRegList(1, WORD), PZero, RefIndexLsl(1)] => [R(0), R3(10), R(5), RNoZr(16)], SVE;
+    // LDNT1H (scalar plus immediate)
+    0b10100100_10000000_11100000_00000000 = [ZRegList(1, WORD), PZero, RefMulVl] => [R(0), R3(10), R(5), Sbits(16, 4)], SVE;
+]
+"ldnt1w" = [
+    // LDNT1W (scalar plus scalar)
+    0b10100101_00000000_11000000_00000000 = [ZRegList(1, DWORD), PZero, RefIndexLsl(2)] => [R(0), R3(10), R(5), RNoZr(16)], SVE;
+    // LDNT1W (scalar plus immediate)
+    0b10100101_00000000_11100000_00000000 = [ZRegList(1, DWORD), PZero, RefMulVl] => [R(0), R3(10), R(5), Sbits(16, 4)], SVE;
+]
+"ldp" = [
+    // LDP (SIMD&FP)
+    0b00101100_11000000_00000000_00000000 = [S, S, RefBase, Imm] => [R(0), R(10), R(5), Sscaled(15, 7, 2)];
+    0b01101100_11000000_00000000_00000000 = [D, D, RefBase, Imm] => [R(0), R(10), R(5), Sscaled(15, 7, 3)];
+    0b10101100_11000000_00000000_00000000 = [Q, Q, RefBase, Imm] => [R(0), R(10), R(5), Sscaled(15, 7, 4)];
+    0b00101101_11000000_00000000_00000000 = [S, S, RefPre] => [R(0), R(10), R(5), Sscaled(15, 7, 2)];
+    0b01101101_11000000_00000000_00000000 = [D, D, RefPre] => [R(0), R(10), R(5), Sscaled(15, 7, 3)];
+    0b10101101_11000000_00000000_00000000 = [Q, Q, RefPre] => [R(0), R(10), R(5), Sscaled(15, 7, 4)];
+    0b00101101_01000000_00000000_00000000 = [S, S, RefOffset] => [R(0), R(10), R(5), Sscaled(15, 7, 2)];
+    0b01101101_01000000_00000000_00000000 = [D, D, RefOffset] => [R(0), R(10), R(5), Sscaled(15, 7, 3)];
+    0b10101101_01000000_00000000_00000000 = [Q, Q, RefOffset] => [R(0), R(10), R(5), Sscaled(15, 7, 4)];
+    // LDP
+    0b00101000_11000000_00000000_00000000 = [W, W, RefBase, Imm] => [R(0), R(10), R(5), Sscaled(15, 7, 2)];
+    0b10101000_11000000_00000000_00000000 = [X, X, RefBase, Imm] => [R(0), R(10), R(5), Sscaled(15, 7, 3)];
+    0b00101001_11000000_00000000_00000000 = [W, W, RefPre] => [R(0), R(10), R(5), Sscaled(15, 7, 2)];
+    0b10101001_11000000_00000000_00000000 = [X, X, RefPre] => [R(0), R(10), R(5), Sscaled(15, 7, 3)];
+    0b00101001_01000000_00000000_00000000 = [W, W, RefOffset] => [R(0), R(10), R(5), Sscaled(15, 7, 2)];
+    0b10101001_01000000_00000000_00000000 = [X, X, RefOffset] => [R(0), R(10), R(5), Sscaled(15, 7, 3)];
+]
+"ldpsw" = [
+    0b01101000_11000000_00000000_00000000 = [X, X, RefBase, Imm] => [R(0), R(10), R(5), Sscaled(15, 7, 2)];
+    0b01101001_11000000_00000000_00000000 = [X, X, RefPre] => [R(0), R(10), R(5), Sscaled(15, 7, 2)];
+    0b01101001_01000000_00000000_00000000 = [X, X, RefOffset] => [R(0), R(10), R(5), Sscaled(15, 7, 2)];
+]
+"ldr" = [
+    // LDR (immediate, SIMD&FP)
+    0b00111100_01000000_00000100_00000000 = [B, RefBase, Imm] => [R(0), R(5), Sbits(12, 9)];
+    0b01111100_01000000_00000100_00000000 = [H, RefBase, Imm] => [R(0), R(5), Sbits(12, 9)];
+    0b10111100_01000000_00000100_00000000 = [S, RefBase, Imm] => [R(0), R(5), Sbits(12, 9)];
+    0b11111100_01000000_00000100_00000000 = [D, RefBase, Imm] => [R(0), R(5), Sbits(12, 9)];
+    0b00111100_11000000_00000100_00000000 = [Q, RefBase, Imm] => [R(0), R(5), Sbits(12, 9)];
+    0b00111100_01000000_00001100_00000000 = [B, RefPre] => [R(0), R(5), Sbits(12, 9)];
+    0b01111100_01000000_00001100_00000000 = [H, RefPre] => [R(0), R(5), Sbits(12, 9)];
+    0b10111100_01000000_00001100_00000000 = [S, RefPre] => [R(0), R(5), Sbits(12, 9)];
+    0b11111100_01000000_00001100_00000000 = [D, RefPre] => [R(0), R(5), Sbits(12, 9)];
+    0b00111100_11000000_00001100_00000000 = [Q, RefPre] => [R(0), R(5), Sbits(12, 9)];
+    0b00111101_01000000_00000000_00000000 = [B, RefOffset] => [R(0), R(5), Ubits(10, 12)];
+    0b01111101_01000000_00000000_00000000 = [H, RefOffset] => [R(0), R(5), Uscaled(10, 12, 1)];
+    0b10111101_01000000_00000000_00000000 = [S, RefOffset] => [R(0), R(5), Uscaled(10, 12, 2)];
+    0b11111101_01000000_00000000_00000000 = [D, RefOffset] => [R(0), R(5), Uscaled(10, 12, 3)];
+    0b00111101_11000000_00000000_00000000 = [Q, RefOffset] => [R(0), R(5), Uscaled(10, 12, 4)];
+    // LDR (immediate)
+    0b10111000_01000000_00000100_00000000 = [W, RefBase, Imm] => [R(0), R(5), Sbits(12, 9)];
+    0b11111000_01000000_00000100_00000000 = [X, RefBase, Imm] => [R(0), R(5), Sbits(12, 9)];
+    0b10111000_01000000_00001100_00000000 = [W, RefPre] => [R(0), R(5), Sbits(12, 9)];
+    0b11111000_01000000_00001100_00000000 = [X, RefPre] => [R(0), R(5), Sbits(12, 9)];
+    0b10111001_01000000_00000000_00000000 = [W, RefOffset] => [R(0), R(5), Uscaled(10, 12, 2)];
+    0b11111001_01000000_00000000_00000000 = [X, RefOffset] => [R(0), R(5), Uscaled(10, 12, 3)];
+    // LDR (literal, SIMD&FP)
+    0b00011100_00000000_00000000_00000000 = [S, Offset] => [R(0), Offset(BCOND)];
+    0b01011100_00000000_00000000_00000000 = [D, Offset] => [R(0), Offset(BCOND)];
+    0b10011100_00000000_00000000_00000000 = [Q, Offset] => [R(0), Offset(BCOND)];
+    // LDR (literal)
+    0b00011000_00000000_00000000_00000000 = [W, Offset] => [R(0), Offset(BCOND)];
+    0b01011000_00000000_00000000_00000000 = [X, Offset] => [R(0), Offset(BCOND)];
+    // LDR (register, SIMD&FP)
+    0b00111100_01100000_00001000_00000000 = [B, RefIndex] => [R(0), R(5), R(16), ExtendsX(13), Ulist(12, &[0, 0])];
+    0b01111100_01100000_00001000_00000000 = [H, RefIndex] => [R(0), R(5), R(16), ExtendsX(13), Ulist(12, &[0, 1])];
+    0b10111100_01100000_00001000_00000000 = [S, RefIndex] => [R(0), R(5), R(16), ExtendsX(13), Ulist(12, &[0, 2])];
+    0b11111100_01100000_00001000_00000000 = [D, RefIndex] => [R(0), R(5), R(16), ExtendsX(13), Ulist(12, &[0, 3])];
+    0b00111100_11100000_00001000_00000000 = [Q, RefIndex] => [R(0), R(5), R(16), ExtendsX(13), Ulist(12, &[0, 4])];
+    // LDR (register)
+    0b10111000_01100000_00001000_00000000 = [W, RefIndex] => [R(0), R(5), R(16), ExtendsX(13), Ulist(12, &[0, 2])];
+    0b11111000_01100000_00001000_00000000 = [X, RefIndex] => [R(0), R(5), R(16), ExtendsX(13), Ulist(12, &[0, 3])];
+    // LDR (predicate)
+    0b10000101_10000000_00000000_00000000 = [PBare, RefMulVl] => [R4(0), R(5), BSscaled(9, 0), Sslice(10, 3, 0), Sslice(16, 6, 3), A], SVE;
+    // LDR (vector)
+    0b10000101_10000000_01000000_00000000 = [ZBare, RefMulVl] => [R(0), R(5), BSscaled(9, 0), Sslice(10, 3, 0), Sslice(16, 6, 3), A], SVE;
+    // LDR (array vector)
+    0b11100001_00000000_00000000_00000000 = [ZaArray, RefMulVl] => [RSelect(13), Ubits(0, 4), R(5), Usame(2)], SME;
+]
+"ldraa" = [
+    0b11111000_00100000_00000100_00000000 = [X, RefOffset] => [R(0), R(5), BSscaled(10, 3), Sslice(12, 9, 3), Sslice(22, 1, 12), A], PAUTH;
+    0b11111000_00100000_00001100_00000000 = [X, RefPre] => [R(0), R(5), BSscaled(10, 3), Sslice(12, 9, 3), Sslice(22, 1, 12), A], PAUTH;
+]
+"ldrab" = [
+    0b11111000_10100000_00000100_00000000 = [X, RefOffset] => [R(0), R(5), BSscaled(10, 3), Sslice(12, 9, 3), Sslice(22, 1, 12), A], PAUTH;
+    0b11111000_10100000_00001100_00000000 = [X, RefPre] => [R(0), R(5), BSscaled(10, 3), Sslice(12, 9, 3), Sslice(22, 1, 12), A], PAUTH;
+]
+"ldrb" = [
+    // LDRB (immediate)
+    0b00111000_01000000_00000100_00000000 = [W, RefBase, Imm] => [R(0), R(5), Sbits(12, 9)];
+    0b00111000_01000000_00001100_00000000 = [W, RefPre] => [R(0), R(5), Sbits(12, 9)];
+    0b00111001_01000000_00000000_00000000 = [W, RefOffset] => [R(0), R(5), Ubits(10, 12)];
+    // LDRB (register)
+    0b00111000_01100000_00001000_00000000 = [W, RefIndex] => [R(0), R(5), R(16), ExtendsX(13), Ulist(12, &[0, 0])];
+]
+"ldrh" = [
+    // LDRH (immediate)
+    0b01111000_01000000_00000100_00000000 = [W, RefBase, Imm] => [R(0), R(5), Sbits(12, 9)];
+    0b01111000_01000000_00001100_00000000 = [W, RefPre] => [R(0), R(5), Sbits(12, 9)];
+    0b01111001_01000000_00000000_00000000 = [W, RefOffset] => [R(0), R(5), Uscaled(10, 12, 1)];
+    // LDRH (register)
+    0b01111000_01100000_00001000_00000000 = [W, RefIndex] => [R(0), R(5), R(16), ExtendsX(13), Ulist(12, &[0, 1])];
+]
+"ldrsb" = [
+    // LDRSB (immediate)
+    0b00111000_11000000_00000100_00000000 = [W, RefBase, Imm] => [R(0), R(5), Sbits(12, 9)];
+    0b00111000_10000000_00000100_00000000 = [X, RefBase, Imm] => [R(0), R(5), Sbits(12, 9)];
+    0b00111000_11000000_00001100_00000000 = [W, RefPre] => [R(0), R(5), Sbits(12, 9)];
+    0b00111000_10000000_00001100_00000000 = [X, RefPre] => [R(0), R(5), Sbits(12, 9)];
+    0b00111001_11000000_00000000_00000000 = [W, RefOffset] => [R(0), R(5), Ubits(10, 12)];
+    0b00111001_10000000_00000000_00000000 = [X, RefOffset] => [R(0), R(5), Ubits(10, 12)];
+    // LDRSB (register)
+    0b00111000_11100000_00001000_00000000 = [W, RefIndex] => [R(0), R(5), R(16), ExtendsX(13), Ulist(12, &[0, 0])];
+    0b00111000_10100000_00001000_00000000 = [X, RefIndex] => [R(0), R(5), R(16), ExtendsX(13), Ulist(12, &[0, 0])];
+]
+"ldrsh" = [
+    // LDRSH (immediate)
+    0b01111000_11000000_00000100_00000000 = [W, RefBase, Imm] => [R(0), R(5), Sbits(12, 9)];
+    0b01111000_10000000_00000100_00000000 = [X, RefBase, Imm] => [R(0), R(5), Sbits(12, 9)];
+    0b01111000_11000000_00001100_00000000 = [W, RefPre] => [R(0), R(5), Sbits(12, 9)];
+    0b01111000_10000000_00001100_00000000 = [X, RefPre] => [R(0), R(5), Sbits(12, 9)];
+    0b01111001_11000000_00000000_00000000 = [W, RefOffset] => [R(0), R(5), Uscaled(10, 12, 1)];
+    0b01111001_10000000_00000000_00000000 = [X, RefOffset] => [R(0), R(5), Uscaled(10, 12, 1)];
+    // LDRSH (register)
+    0b01111000_11100000_00001000_00000000 = [W, RefIndex] => [R(0), R(5), R(16), ExtendsX(13), Ulist(12, &[0, 1])];
+    0b01111000_10100000_00001000_00000000 = [X, RefIndex] => [R(0), R(5), R(16), ExtendsX(13), Ulist(12, &[0, 1])];
+]
+"ldrsw" = [
+    // LDRSW (immediate)
+    0b10111000_10000000_00000100_00000000 = [X, RefBase, Imm] => [R(0), R(5), Sbits(12, 9)];
+    0b10111000_10000000_00001100_00000000 = [X, RefPre] => [R(0), R(5), Sbits(12, 9)];
+    0b10111001_10000000_00000000_00000000 = [X, RefOffset] => [R(0), R(5), Uscaled(10, 12, 2)];
+    // LDRSW (literal)
+    0b10011000_00000000_00000000_00000000 = [X, Offset] => [R(0), Offset(BCOND)];
+    // LDRSW (register)
+    0b10111000_10100000_00001000_00000000 = [X, RefIndex] => [R(0), R(5), R(16), ExtendsX(13), Ulist(12, &[0, 2])];
+]
+"ldset" = [
+    0b10111000_00100000_00110000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], LSE;
+    0b11111000_00100000_00110000_00000000 = [X, X, RefBase] => [R(16), R(0), R(5)], LSE;
+]
+"ldseta" = [
+    0b10111000_10100000_00110000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], LSE;
+    0b11111000_10100000_00110000_00000000 = [X, X, RefBase] => [R(16), R(0), R(5)], LSE;
+]
+"ldsetab" = [
+    0b00111000_10100000_00110000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], LSE;
+]
+"ldsetah" = [
+    0b01111000_10100000_00110000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], LSE;
+]
+"ldsetal" = [
+    0b10111000_11100000_00110000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], LSE;
+    0b11111000_11100000_00110000_00000000 = [X, X, RefBase] => [R(16), R(0), R(5)], LSE;
+]
+"ldsetalb" = [
+    0b00111000_11100000_00110000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], LSE;
+]
+"ldsetalh" = [
+    0b01111000_11100000_00110000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], LSE;
+]
+"ldsetb" = [
+    0b00111000_00100000_00110000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], LSE;
+]
+"ldseth" = [
+    0b01111000_00100000_00110000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], LSE;
+]
+"ldsetl" = [
+    0b10111000_01100000_00110000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], LSE;
+    0b11111000_01100000_00110000_00000000 = [X, X, RefBase] => [R(16), R(0), R(5)], LSE;
+]
+"ldsetlb" = [
+    0b00111000_01100000_00110000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], LSE;
+]
+"ldsetlh" = [
+    0b01111000_01100000_00110000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], LSE;
+]
+"ldsmax" = [
+    0b10111000_00100000_01000000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], LSE;
+    0b11111000_00100000_01000000_00000000 = [X, X, RefBase] => [R(16), R(0), R(5)], LSE;
+]
+"ldsmaxa" = [
+    0b10111000_10100000_01000000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], LSE;
+    0b11111000_10100000_01000000_00000000 = [X, X, RefBase] => [R(16), R(0), R(5)], LSE;
+]
+"ldsmaxab" = [
+    0b00111000_10100000_01000000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], LSE;
+]
+"ldsmaxah" = [
+    0b01111000_10100000_01000000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], LSE;
+]
+"ldsmaxal" = [
+    0b10111000_11100000_01000000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], LSE;
+    0b11111000_11100000_01000000_00000000 = [X, X, RefBase] => [R(16), R(0), R(5)], LSE;
+]
+"ldsmaxalb" = [
+    0b00111000_11100000_01000000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], LSE;
+]
+"ldsmaxalh" = [
+    0b01111000_11100000_01000000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], LSE;
+]
+"ldsmaxb" = [
+    0b00111000_00100000_01000000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], LSE;
+]
+"ldsmaxh" = [
+    0b01111000_00100000_01000000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], LSE;
+]
+"ldsmaxl" = [
+    0b10111000_01100000_01000000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], LSE;
+    0b11111000_01100000_01000000_00000000 = [X, X, RefBase] => [R(16), R(0), R(5)], LSE;
+]
+"ldsmaxlb" = [
+    0b00111000_01100000_01000000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], LSE;
+]
+"ldsmaxlh" = [
+    0b01111000_01100000_01000000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], LSE;
+]
+"ldsmin" = [
+    0b10111000_00100000_01010000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], LSE;
+    0b11111000_00100000_01010000_00000000 = [X, X, RefBase] => [R(16), R(0), R(5)], LSE;
+]
+"ldsmina" = [
+    0b10111000_10100000_01010000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], LSE;
+    0b11111000_10100000_01010000_00000000 = [X, X, RefBase] => [R(16), R(0), R(5)], LSE;
+]
+"ldsminab" = [
+    0b00111000_10100000_01010000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], LSE;
+]
+"ldsminah" = [
+    0b01111000_10100000_01010000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], LSE;
+]
+"ldsminal" = [
+    0b10111000_11100000_01010000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], LSE;
+    0b11111000_11100000_01010000_00000000 = [X, X, RefBase] => [R(16), R(0), R(5)], LSE;
+]
+"ldsminalb" = [
+    0b00111000_11100000_01010000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], LSE;
+]
+"ldsminalh" = [
+    0b01111000_11100000_01010000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], LSE;
+]
+"ldsminb" = [
+    0b00111000_00100000_01010000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], LSE;
+]
+"ldsminh" = [
+    0b01111000_00100000_01010000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], LSE;
+]
+"ldsminl" = [
+    0b10111000_01100000_01010000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], LSE;
+    0b11111000_01100000_01010000_00000000 = [X, X, RefBase] => [R(16), R(0), R(5)], LSE;
+]
+"ldsminlb" = [
+    0b00111000_01100000_01010000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], LSE;
+]
+"ldsminlh" = [
+    0b01111000_01100000_01010000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], LSE;
+]
+"ldtr" = [
+    0b10111000_01000000_00001000_00000000 = [W, RefOffset] => [R(0), R(5), Sbits(12, 9)];
+    0b11111000_01000000_00001000_00000000 = [X, RefOffset] => [R(0), R(5), Sbits(12, 9)];
+]
+"ldtrb" = [
+    0b00111000_01000000_00001000_00000000 = [W, RefOffset] => [R(0), R(5), Sbits(12, 9)];
+]
+"ldtrh" = [
+    0b01111000_01000000_00001000_00000000 = [W, RefOffset] => [R(0), R(5), Sbits(12, 9)];
+]
+"ldtrsb" = [
+    0b00111000_11000000_00001000_00000000 = [W, RefOffset] => [R(0), R(5), Sbits(12, 9)];
+    0b00111000_10000000_00001000_00000000 = [X, RefOffset] => [R(0), R(5), Sbits(12, 9)];
+]
+"ldtrsh" = [
+    0b01111000_11000000_00001000_00000000 = [W, RefOffset] => [R(0), R(5), Sbits(12, 9)];
+    0b01111000_10000000_00001000_00000000 = [X, RefOffset] => [R(0), R(5), Sbits(12, 9)];
+]
+"ldtrsw" = [
+    0b10111000_10000000_00001000_00000000 = [X, RefOffset] => [R(0), R(5), Sbits(12, 9)];
+]
+"ldumax" = [
+    0b10111000_00100000_01100000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], LSE;
+    0b11111000_00100000_01100000_00000000 = [X, X, RefBase] => [R(16), R(0), R(5)], LSE;
+]
+"ldumaxa" = [
+    0b10111000_10100000_01100000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], LSE;
+    0b11111000_10100000_01100000_00000000 = [X, X, RefBase] => [R(16), R(0), R(5)], LSE;
+]
+"ldumaxab" = [
+    0b00111000_10100000_01100000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], LSE;
+]
+"ldumaxah" = [
+    0b01111000_10100000_01100000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], LSE;
+]
+"ldumaxal" = [
+    0b10111000_11100000_01100000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], LSE;
+    0b11111000_11100000_01100000_00000000 = [X, X, RefBase] => [R(16), R(0), R(5)], LSE;
+]
+"ldumaxalb" = [
+    0b00111000_11100000_01100000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], LSE;
+]
+"ldumaxalh" = [
+    0b01111000_11100000_01100000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], LSE;
+]
+"ldumaxb" = [
+    0b00111000_00100000_01100000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], LSE;
+]
+"ldumaxh" = [
+    0b01111000_00100000_01100000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], LSE;
+]
+"ldumaxl" = [
+    0b10111000_01100000_01100000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], LSE;
+    0b11111000_01100000_01100000_00000000 = [X, X, RefBase] => [R(16), R(0), R(5)], LSE;
+]
+"ldumaxlb" = [
+    0b00111000_01100000_01100000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], LSE;
+]
+"ldumaxlh" = [
+    0b01111000_01100000_01100000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], LSE;
+]
+"ldumin" = [
+    0b10111000_00100000_01110000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], LSE;
+    0b11111000_00100000_01110000_00000000 = [X, X, RefBase] => [R(16), R(0), R(5)], LSE;
+]
+"ldumina" = [
+    0b10111000_10100000_01110000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], LSE;
+    0b11111000_10100000_01110000_00000000 = [X, X, RefBase] => [R(16), R(0), R(5)], LSE;
+]
+"lduminab" = [
+    0b00111000_10100000_01110000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], LSE;
+]
+"lduminah" = [
+    0b01111000_10100000_01110000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], LSE;
+]
+"lduminal" = [
+    0b10111000_11100000_01110000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], LSE;
+    0b11111000_11100000_01110000_00000000 = [X, X, RefBase] => [R(16), R(0), R(5)], LSE;
+]
+"lduminalb" = [
+    0b00111000_11100000_01110000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], LSE;
+]
+"lduminalh" = [
+    0b01111000_11100000_01110000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], LSE;
+]
+"lduminb" = [
+    0b00111000_00100000_01110000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], LSE;
+]
+"lduminh" = [
+    0b01111000_00100000_01110000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], LSE;
+]
+"lduminl" = [
+    0b10111000_01100000_01110000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], LSE;
+    0b11111000_01100000_01110000_00000000 = [X, X, RefBase] => [R(16), R(0), R(5)], LSE;
+]
+"lduminlb" = [
+    0b00111000_01100000_01110000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], LSE;
+]
+"lduminlh" = [
+    0b01111000_01100000_01110000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], LSE;
+]
+"ldur" = [
+    // LDUR (SIMD&FP)
+    0b00111100_01000000_00000000_00000000 = [B, RefOffset] => [R(0), R(5), Sbits(12, 9)];
+    0b01111100_01000000_00000000_00000000 = [H, RefOffset] => [R(0), R(5), Sbits(12, 9)];
+    0b10111100_01000000_00000000_00000000 = [S, RefOffset] => [R(0), R(5), Sbits(12, 9)];
+    0b11111100_01000000_00000000_00000000 = [D, RefOffset] => [R(0), R(5), Sbits(12, 9)];
+    0b00111100_11000000_00000000_00000000 = [Q, RefOffset] => [R(0), R(5), Sbits(12, 9)];
+    // LDUR
+    0b10111000_01000000_00000000_00000000 = [W, RefOffset] => [R(0), R(5), Sbits(12, 9)];
+    0b11111000_01000000_00000000_00000000 = [X, RefOffset] => [R(0), R(5), Sbits(12, 9)];
+]
+"ldurb" = [
+    0b00111000_01000000_00000000_00000000 = [W, RefOffset] => [R(0), R(5), Sbits(12, 9)];
+]
+"ldurh" = [
+    0b01111000_01000000_00000000_00000000 = [W, RefOffset] => [R(0), R(5), Sbits(12, 9)];
+]
+"ldursb" = [
+    0b00111000_11000000_00000000_00000000 = [W, RefOffset] => [R(0), R(5), Sbits(12, 9)];
+    0b00111000_10000000_00000000_00000000 = [X, RefOffset] => [R(0), R(5), Sbits(12, 9)];
+]
+"ldursh" = [
+    0b01111000_11000000_00000000_00000000 = [W, RefOffset] => [R(0), R(5), Sbits(12, 9)];
+    0b01111000_10000000_00000000_00000000 = [X, RefOffset] => [R(0), R(5), Sbits(12, 9)];
+]
+"ldursw" = [
+    0b10111000_10000000_00000000_00000000 = [X, RefOffset] => [R(0), R(5), Sbits(12, 9)];
+]
+"ldxp" = [
+    0b10001000_01111111_00000000_00000000 = [W, W, RefBase] => [R(0), R(10), R(5)];
+    0b11001000_01111111_00000000_00000000 = [X, X, RefBase] => [R(0), R(10), R(5)];
+]
+"ldxr" = [
+    0b10001000_01011111_01111100_00000000 = [W, RefBase] => [R(0), R(5)];
+    0b11001000_01011111_01111100_00000000 = [X, RefBase] => [R(0), R(5)];
+]
+"ldxrb" = [
+    0b00001000_01011111_01111100_00000000 = [W, RefBase] => [R(0), R(5)];
+]
+"ldxrh" = [
+    0b01001000_01011111_01111100_00000000 = [W, RefBase] => [R(0), R(5)];
+]
+"lsl" = [
+    // LSL (register)
+    0b00011010_11000000_00100000_00000000 = [W, W, W] => [R(0), R(5), R(16)];
+    0b10011010_11000000_00100000_00000000 = [X, X, X] => [R(0), R(5), R(16)];
+    // LSL (immediate)
+    0b01010011_00000000_00000000_00000000 = [W, W, Imm] => [R(0), R(5), Unegmod(16, 5), C, Usub(10, 5, 31)];
+    0b11010011_01000000_00000000_00000000 = [X, X, Imm] => [R(0), R(5), Unegmod(16, 6), C, Usub(10, 6, 63)];
+    // LSL (immediate, unpredicated)
+    0b00000100_00101000_10011100_00000000 = [Z(BYTE), Z(BYTE), Imm] => [R(0), R(5), Ubits(16, 3)], SVE;
+    0b00000100_00110000_10011100_00000000 = [Z(WORD), Z(WORD), Imm] => [R(0), R(5), Ubits(16, 4)], SVE;
+    0b00000100_01100000_10011100_00000000 = [Z(DWORD), Z(DWORD), Imm] => [R(0), R(5), Ubits(16, 5)], SVE;
+    0b00000100_10100000_10011100_00000000 = [Z(QWORD), Z(QWORD), Imm] => [R(0), R(5), BUbits(6), Uslice(16, 5, 0), Uslice(22, 1, 5), A], SVE;
+    // LSL (wide elements, unpredicated)
+    0b00000100_00100000_10001100_00000000 = [Z(BYTE), Z(BYTE), Z(QWORD)] => [R(0), R(5), R(16)], SVE;
+    0b00000100_01100000_10001100_00000000 = [Z(WORD), Z(WORD), Z(QWORD)] => [R(0), R(5), R(16)], SVE;
+    0b00000100_10100000_10001100_00000000 = [Z(DWORD), Z(DWORD), Z(QWORD)] => [R(0), R(5), R(16)], SVE;
+    // LSL (immediate, predicated)
+    0b00000100_00000011_10000001_00000000 = [Z(BYTE), PMerge, Z(BYTE), Imm] => [R(0), R3(10), RTied, Ubits(5, 3)], SVE;
+    0b00000100_00000011_10000010_00000000 = [Z(WORD), PMerge, Z(WORD), Imm] => [R(0), R3(10), RTied, Ubits(5, 4)], SVE;
+    0b00000100_01000011_10000000_00000000 = [Z(DWORD), PMerge, Z(DWORD), Imm] => [R(0), R3(10), RTied, Ubits(5, 5)], SVE;
+    0b00000100_10000011_10000000_00000000 = [Z(QWORD), PMerge, Z(QWORD), Imm] => [R(0), R3(10), RTied, BUbits(6), Uslice(5, 5, 0), Uslice(22, 1, 5), A], SVE;
+    // LSL (vectors, predicated)
+    0b00000100_00010011_10000000_00000000 = [Z(BYTE), PMerge, Z(BYTE), Z(BYTE)] => [R(0), R3(10), RTied, R(5)], SVE;
+    0b00000100_01010011_10000000_00000000 = [Z(WORD), PMerge, Z(WORD), Z(WORD)] => [R(0), R3(10), RTied, R(5)], SVE;
+    0b00000100_10010011_10000000_00000000 = [Z(DWORD), PMerge, Z(DWORD), Z(DWORD)] => [R(0), R3(10), RTied, R(5)], SVE;
+    0b00000100_11010011_10000000_00000000 = [Z(QWORD), PMerge, Z(QWORD), Z(QWORD)] => [R(0), R3(10), RTied, R(5)], SVE;
+    // LSL (wide elements, predicated)
+    0b00000100_00011011_10000000_00000000 = [Z(BYTE), PMerge, Z(BYTE), Z(QWORD)] => [R(0), R3(10), RTied, R(5)], SVE;
+    0b00000100_01011011_10000000_00000000 = [Z(WORD), PMerge, Z(WORD), Z(QWORD)] => [R(0), R3(10), RTied, R(5)], SVE;
+    0b00000100_10011011_10000000_00000000 = [Z(DWORD), PMerge, Z(DWORD), Z(QWORD)] => [R(0), R3(10), RTied, R(5)], SVE;
+]
+"lslr" = [
+    0b00000100_00010111_10000000_00000000 = [Z(BYTE), PMerge, Z(BYTE), Z(BYTE)] => [R(0), R3(10), RTied, R(5)], SVE;
+    0b00000100_01010111_10000000_00000000 = [Z(WORD), PMerge, Z(WORD), Z(WORD)] => [R(0), R3(10), RTied, R(5)], SVE;
+    0b00000100_10010111_10000000_00000000 = [Z(DWORD), PMerge, Z(DWORD), Z(DWORD)] => [R(0), R3(10), RTied, R(5)], SVE;
+    0b00000100_11010111_10000000_00000000 = [Z(QWORD), PMerge, Z(QWORD), Z(QWORD)] => [R(0), R3(10), RTied, R(5)], SVE;
+]
+"lslv" = [
+    0b00011010_11000000_00100000_00000000 = [W, W, W] => [R(0), R(5), R(16)];
+    0b10011010_11000000_00100000_00000000 = [X, X, X] => [R(0), R(5), R(16)];
+]
+"lsr" = [
+    // LSR (register)
+    0b00011010_11000000_00100100_00000000 = [W, W, W] => [R(0), R(5), R(16)];
+    0b10011010_11000000_00100100_00000000 = [X, X, X] => [R(0), R(5), R(16)];
+    // LSR (immediate)
+    0b01010011_00000000_01111100_00000000 = [W, W, Imm] => [R(0), R(5), Ubits(16, 5)];
+    0b11010011_01000000_11111100_00000000 = [X, X, Imm] => [R(0), R(5), Ubits(16, 6)];
+    // LSR (immediate, unpredicated)
+    0b00000100_00101000_10010100_00000000 = [Z(BYTE), Z(BYTE), Imm] => [R(0), R(5), Usub(16, 3, 8)], SVE;
+    0b00000100_00110000_10010100_00000000 = [Z(WORD), Z(WORD), Imm] => [R(0), R(5), Usub(16, 4, 16)], SVE;
+    0b00000100_01100000_10010100_00000000 = [Z(DWORD), Z(DWORD), Imm] => [R(0), R(5), Usub(16, 5, 32)], SVE;
+    0b00000100_10100000_10010100_00000000 = [Z(QWORD), Z(QWORD), Imm] => [R(0), R(5), BUrange(1, 64), Unegslice(16, 5, 0), Unegslice(22, 1, 5), A], SVE;
+    // LSR (wide elements, unpredicated)
+    0b00000100_00100000_10000100_00000000 = [Z(BYTE), Z(BYTE), Z(QWORD)] => [R(0), R(5), R(16)], SVE;
+    0b00000100_01100000_10000100_00000000 = [Z(WORD), Z(WORD), Z(QWORD)] => [R(0), R(5), R(16)], SVE;
+    0b00000100_10100000_10000100_00000000 = [Z(DWORD), Z(DWORD), Z(QWORD)] => [R(0), R(5), R(16)], SVE;
+    // LSR (immediate, predicated)
+    0b00000100_00000001_10000001_00000000 = [Z(BYTE), PMerge, Z(BYTE), Imm] => [R(0), R3(10), RTied, Usub(5, 3, 8)], SVE;
+    0b00000100_00000001_10000010_00000000 = [Z(WORD), PMerge, Z(WORD), Imm] => [R(0), R3(10), RTied, Usub(5, 4, 16)], SVE;
+    0b00000100_01000001_10000000_00000000 = [Z(DWORD), PMerge, Z(DWORD), Imm] => [R(0), R3(10), RTied, Usub(5, 5, 32)], SVE;
+    0b00000100_10000001_10000000_00000000 = [Z(QWORD), PMerge, Z(QWORD), Imm] => [R(0), R3(10), RTied, BUrange(1, 64), Unegslice(5, 5, 0), Unegslice(22, 1, 5), A], SVE;
+    // LSR (vectors, predicated)
+    0b00000100_00010001_10000000_00000000 = [Z(BYTE), PMerge, Z(BYTE), Z(BYTE)] => [R(0), R3(10), RTied, R(5)], SVE;
+    0b00000100_01010001_10000000_00000000 = [Z(WORD), PMerge, Z(WORD), Z(WORD)] => [R(0), R3(10), RTied, R(5)], SVE;
+    0b00000100_10010001_10000000_00000000 = [Z(DWORD), PMerge, Z(DWORD), Z(DWORD)] => [R(0), R3(10), RTied, R(5)], SVE;
+    0b00000100_11010001_10000000_00000000 = [Z(QWORD), PMerge, Z(QWORD), Z(QWORD)] => [R(0), R3(10), RTied, R(5)], SVE;
+    // LSR (wide elements, predicated)
+    0b00000100_00011001_10000000_00000000 = [Z(BYTE), PMerge, Z(BYTE), Z(QWORD)] => [R(0), R3(10), RTied, R(5)], SVE;
+    0b00000100_01011001_10000000_00000000 = [Z(WORD), PMerge, Z(WORD), Z(QWORD)] => [R(0), R3(10), RTied, R(5)], SVE;
+    0b00000100_10011001_10000000_00000000 = [Z(DWORD), PMerge, Z(DWORD), Z(QWORD)] => [R(0), R3(10), RTied, R(5)], SVE;
+]
+"lsrr" = [
+    0b00000100_00010101_10000000_00000000 = [Z(BYTE), PMerge, Z(BYTE), Z(BYTE)] => [R(0), R3(10), RTied, R(5)], SVE;
+    0b00000100_01010101_10000000_00000000 = [Z(WORD), PMerge, Z(WORD), Z(WORD)] => [R(0), R3(10), RTied, R(5)], SVE;
+    0b00000100_10010101_10000000_00000000 = [Z(DWORD), PMerge, Z(DWORD), Z(DWORD)] => [R(0), R3(10), RTied, R(5)], SVE;
+    0b00000100_11010101_10000000_00000000 = [Z(QWORD), PMerge, Z(QWORD), Z(QWORD)] => [R(0), R3(10), RTied, R(5)], SVE;
+]
+"lsrv" = [
+    0b00011010_11000000_00100100_00000000 = [W, W, W] => [R(0), R(5), R(16)];
+    0b10011010_11000000_00100100_00000000 = [X, X, X] => [R(0), R(5), R(16)];
+]
+"mad" = [
+    0b00000100_00000000_11000000_00000000 = [Z(BYTE), PMerge, Z(BYTE), Z(BYTE)] => [R(0), R3(10), R(16), R(5)], SVE;
+    0b00000100_01000000_11000000_00000000 = [Z(WORD), PMerge, Z(WORD), Z(WORD)] => [R(0), R3(10), R(16), R(5)], SVE;
+    0b00000100_10000000_11000000_00000000 = [Z(DWORD), PMerge, Z(DWORD), Z(DWORD)] => [R(0), R3(10), R(16), R(5)], SVE;
+    0b00000100_11000000_11000000_00000000 = [Z(QWORD), PMerge, Z(QWORD), Z(QWORD)] => [R(0), R3(10), R(16), R(5)], SVE;
+]
+"madd" = [
+    0b00011011_00000000_00000000_00000000 = [W, W, W, W] => [R(0), R(5), R(16), R(10)];
+    0b10011011_00000000_00000000_00000000 = [X, X, X, X] => [R(0), R(5), R(16), R(10)];
+]
+"match" = [
+    0b01000101_00100000_10000000_00000000 = [P(BYTE), PZero, Z(BYTE), Z(BYTE)] => [R4(0), R3(10), R(5), R(16)], SVE2;
+    0b01000101_01100000_10000000_00000000 = [P(WORD), PZero, Z(WORD), Z(WORD)] => [R4(0), R3(10), R(5), R(16)], SVE2;
+]
+"mla" = [
+    // MLA (by element)
+    0b00101111_01000000_00000000_00000000 = [V(WORD), V(WORD), VElement(WORD)] => [R(0), R(5), R4(16), Ufields(&[11, 21, 20]), Rwidth(30)];
+    0b00101111_10000000_00000000_00000000 = [V(DWORD), V(DWORD), VElement(DWORD)] => [R(0), R(5), R(16), Ufields(&[11, 21]), Rwidth(30)];
+    // MLA (vector)
+    0b00001110_00100000_10010100_00000000 = [V(BYTE), V(BYTE), V(BYTE)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b00001110_01100000_10010100_00000000 = [V(WORD), V(WORD), V(WORD)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b00001110_10100000_10010100_00000000 = [V(DWORD), V(DWORD), V(DWORD)] => [R(0), R(5), R(16), Rwidth(30)];
+    // MLA
+    0b00000100_00000000_01000000_00000000 = [Z(BYTE), PMerge, Z(BYTE), Z(BYTE)] => [R(0), R3(10), R(5), R(16)], SVE;
+    0b00000100_01000000_01000000_00000000 = [Z(WORD), PMerge, Z(WORD), Z(WORD)] => [R(0), R3(10), R(5), R(16)], SVE;
+    0b00000100_10000000_01000000_00000000 = [Z(DWORD), PMerge, Z(DWORD), Z(DWORD)] => [R(0), R3(10), R(5), R(16)], SVE;
+    0b00000100_11000000_01000000_00000000 = [Z(QWORD), PMerge, Z(QWORD), Z(QWORD)] => [R(0), R3(10), R(5), R(16)], SVE;
+]
+"mls" = [
+    // MLS (by element)
+    0b00101111_01000000_01000000_00000000 = [V(WORD), V(WORD), VElement(WORD)] => [R(0), R(5), R4(16), Ufields(&[11, 21, 20]), Rwidth(30)];
+    0b00101111_10000000_01000000_00000000 = [V(DWORD), V(DWORD), VElement(DWORD)] => [R(0), R(5), R(16), Ufields(&[11, 21]), Rwidth(30)];
+    // MLS (vector)
+    0b00101110_00100000_10010100_00000000 = [V(BYTE), V(BYTE), V(BYTE)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b00101110_01100000_10010100_00000000 = [V(WORD), V(WORD), V(WORD)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b00101110_10100000_10010100_00000000 = [V(DWORD), V(DWORD), V(DWORD)] => [R(0), R(5), R(16), Rwidth(30)];
+    // MLS
+    0b00000100_00000000_01100000_00000000 = [Z(BYTE), PMerge, Z(BYTE), Z(BYTE)] => [R(0), R3(10), R(5), R(16)], SVE;
+    0b00000100_01000000_01100000_00000000 = [Z(WORD), PMerge, Z(WORD), Z(WORD)] => [R(0), R3(10), R(5), R(16)], SVE;
+    0b00000100_10000000_01100000_00000000 = [Z(DWORD), PMerge, Z(DWORD), Z(DWORD)] => [R(0), R3(10), R(5), R(16)], SVE;
+    0b00000100_11000000_01100000_00000000 = [Z(QWORD), PMerge, Z(QWORD), Z(QWORD)] => [R(0), R3(10), R(5), R(16)], SVE;
+]
+"mneg" = [
+    0b00011011_00000000_11111100_00000000 = [W, W, W] => [R(0), R(5), R(16)];
+    0b10011011_00000000_11111100_00000000 = [X, X, X] => [R(0), R(5), R(16)];
+]
+"mov" = [
+    // MOV (register)
+    0b00101010_00000000_00000011_11100000 = [W, W] => [R(0), R(16)];
+    0b10101010_00000000_00000011_11100000 = [X, X] => [R(0), R(16)];
+    // MOV (to/from SP)
+    0b00010001_00000000_00000000_00000000 = [WSP, WSP] => [R(0), R(5)];
+    0b10010001_00000000_00000000_00000000 = [XSP, XSP] => [R(0), R(5)];
+    // MOV (scalar)
+    0b01011110_00000001_00000100_00000000 = [B, VElement(BYTE)] => [R(0), R(5), Ubits(17, 4)];
+    0b01011110_00000010_00000100_00000000 = [H, VElement(WORD)] => [R(0), R(5), Ubits(18, 3)];
+    0b01011110_00000100_00000100_00000000 = [S, VElement(DWORD)] => [R(0), R(5), Ubits(19, 2)];
+    0b01011110_00001000_00000100_00000000 = [D, VElement(QWORD)] => [R(0), R(5), Ubits(20, 1)];
+    // MOV (element)
+    0b01101110_00000001_00000100_00000000 = [VElement(BYTE), VElement(BYTE)] => [R(0), Ubits(17, 4), R(5), Ubits(11, 4)];
+    0b01101110_00000010_00000100_00000000 = [VElement(WORD), VElement(WORD)] => [R(0), Ubits(18, 3), R(5), Ubits(12, 3)];
+    0b01101110_00000100_00000100_00000000 = [VElement(DWORD), VElement(DWORD)] => [R(0), Ubits(19, 2), R(5), Ubits(13, 2)];
+    0b01101110_00001000_00000100_00000000 = [VElement(QWORD), VElement(QWORD)] => [R(0), Ubits(20, 1), R(5), Ubits(14, 1)];
+    // MOV (from general)
+    0b01001110_00000001_00011100_00000000 = [VElement(BYTE), W] => [R(0), Ubits(17, 4), R(5)];
+    0b01001110_00000010_00011100_00000000 = [VElement(WORD), W] => [R(0), Ubits(18, 3), R(5)];
+    0b01001110_00000100_00011100_00000000 = [VElement(DWORD), W] => [R(0), Ubits(19, 2), R(5)];
+    0b01001110_00001000_00011100_00000000 = [VElement(QWORD), X] => [R(0), Ubits(20, 1), R(5)];
+    // MOV (inverted wide immediate)
+    0b00010010_10000000_00000000_00000000 = [Dot, Lit("inverted"), W, Imm] => [R(0), Special(5, INVERTED_WIDE_IMMEDIATE_W)];
+    0b10010010_10000000_00000000_00000000 = [Dot, Lit("inverted"), X, Imm] => [R(0), Special(5, INVERTED_WIDE_IMMEDIATE_X)];
+    // MOV (wide immediate)
+    0b01010010_10000000_00000000_00000000 = [W, Imm] => [R(0), Special(5, WIDE_IMMEDIATE_W)];
+    0b11010010_10000000_00000000_00000000 = [X, Imm] => [R(0), Special(5, WIDE_IMMEDIATE_X)];
+    // MOV (vector)
+    0b00001110_10100000_00011100_00000000 = [V(BYTE), V(BYTE)] => [R(0), R(5), C, R(16), Rwidth(30)];
+    // MOV (bitmask immediate)
+    0b00110010_00000000_00000011_11100000 = [Dot, Lit("logical"), WSP, Imm] => [R(0), Special(10, LOGICAL_IMMEDIATE_W)];
+    0b10110010_00000000_00000011_11100000 = [Dot, Lit("logical"), XSP, Imm] => [R(0), Special(10, LOGICAL_IMMEDIATE_X)];
+    // MOV (to general)
+    0b00001110_00000100_00111100_00000000 = [W, VElement(DWORD)] => [R(0), R(5), Ubits(19, 2)];
+    0b01001110_00001000_00111100_00000000 = [X, VElement(QWORD)] => [R(0), R(5), Ubits(20, 1)];
+    // MOV (predicate, predicated, merging)
+    0b00100101_00000000_01000010_00010000 = [P(BYTE), PMerge, P(BYTE)] => [R4(0), C, R4(16), R4(10), R4(5)], SVE;
+    // MOV (predicate, predicated, zeroing)
+    0b00100101_00000000_01000000_00000000 = [P(BYTE), PZero, P(BYTE)] => [R4(0), R4(10), R4(5), C, R4(16)], SVE;
+    // MOV (predicate, unpredicated)
+    0b00100101_10000000_01000000_00000000 = [P(BYTE), P(BYTE)] => [R4(0), R4(5), C, R4(10), C, R4(16)], SVE;
+    // MOV (immediate, unpredicated)
+    0b00100101_00111000_11000000_00000000 = [Z(BYTE), Imm] => [R(0), Sbits(5, 8)], SVE;
+    0b00100101_01111000_11000000_00000000 = [Z(WORD), Imm, End, LitMod(LSL)] => [R(0), Sbits(5, 8), Ulist(13, &[0, 8])], SVE;
+    0b00100101_10111000_11000000_00000000 = [Z(DWORD), Imm, End, LitMod(LSL)] => [R(0), Sbits(5, 8), Ulist(13, &[0, 8])], SVE;
+    0b00100101_11111000_11000000_00000000 = [Z(QWORD), Imm, End, LitMod(LSL)] => [R(0), Sbits(5, 8), Ulist(13, &[0, 8])], SVE;
+    // MOV (immediate, predicated)
+    0b00000101_00010000_00000000_00000000 = [Z(BYTE), PZero, Imm] => [R(0), R4(16), Sbits(5, 8)], SVE;
+    0b00000101_01010000_00000000_00000000 = [Z(WORD), PZero, Imm, End, LitMod(LSL)] => [R(0), R4(16), Sbits(5, 8), Ulist(13, &[0, 8])], SVE;
+    0b00000101_10010000_00000000_00000000 = [Z(DWORD), PZero, Imm, End, LitMod(LSL)] => [R(0), R4(16), Sbits(5, 8), Ulist(13, &[0, 8])], SVE;
+    0b00000101_11010000_00000000_00000000 = [Z(QWORD), PZero, Imm, End, LitMod(LSL)] => [R(0), R4(16), Sbits(5, 8), Ulist(13, &[0, 8])], SVE;
+    0b00000101_00010000_01000000_00000000 = [Z(BYTE), PMerge, Imm] => [R(0), R4(16), Sbits(5, 8)], SVE;
+    0b00000101_01010000_01000000_00000000 = [Z(WORD), PMerge, Imm, End, LitMod(LSL)] => [R(0), R4(16), Sbits(5, 8), Ulist(13, &[0, 8])], SVE;
+    0b00000101_10010000_01000000_00000000 = [Z(DWORD), PMerge, Imm, End, LitMod(LSL)] => [R(0), R4(16), Sbits(5, 8), Ulist(13, &[0, 8])], SVE;
+    0b00000101_11010000_01000000_00000000 = [Z(QWORD), PMerge, Imm, End, LitMod(LSL)] => [R(0), R4(16), Sbits(5, 8), Ulist(13, &[0, 8])], SVE;
+    // MOV (scalar, predicated)
+    0b00000101_00101000_10100000_00000000 = [Z(BYTE), PMerge, WSP] => [R(0), R3(10), R(5)], SVE;
+    0b00000101_01101000_10100000_00000000 = [Z(WORD), PMerge, WSP] => [R(0), R3(10), R(5)], SVE;
+    0b00000101_10101000_10100000_00000000 = [Z(DWORD), PMerge, WSP] => [R(0), R3(10), R(5)], SVE;
+    0b00000101_11101000_10100000_00000000 = [Z(QWORD), PMerge, XSP] => [R(0), R3(10), R(5)], SVE;
+    // MOV (SIMD&FP scalar, predicated)
+    0b00000101_00100000_10000000_00000000 = [Z(BYTE), PMerge, B] => [R(0), R3(10), R(5)], SVE;
+    0b00000101_01100000_10000000_00000000 = [Z(WORD), PMerge, H] => [R(0), R3(10), R(5)], SVE;
+    0b00000101_10100000_10000000_00000000 = [Z(DWORD), PMerge, S] => [R(0), R3(10), R(5)], SVE;
+    0b00000101_11100000_10000000_00000000 = [Z(QWORD), PMerge, D] => [R(0), R3(10), R(5)], SVE;
+    // MOV (vector, predicated)
+    0b00000101_00100000_11000000_00000000 = [Z(BYTE), PMerge, Z(BYTE)] => [R(0), C, R(16), R4(10), R(5)], SVE;
+    0b00000101_01100000_11000000_00000000 = [Z(WORD), PMerge, Z(WORD)] => [R(0), C, R(16), R4(10), R(5)], SVE;
+    0b00000101_10100000_11000000_00000000 = [Z(DWORD), PMerge, Z(DWORD)] => [R(0), C, R(16), R4(10), R(5)], SVE;
+    0b00000101_11100000_11000000_00000000 = [Z(QWORD), PMerge, Z(QWORD)] => [R(0), C, R(16), R4(10), R(5)], SVE;
+    // MOV (scalar, unpredicated)
+    0b00000101_00100000_00111000_00000000 = [Z(BYTE), WSP] => [R(0), R(5)], SVE;
+    0b00000101_01100000_00111000_00000000 = [Z(WORD), WSP] => [R(0), R(5)], SVE;
+    0b00000101_10100000_00111000_00000000 = [Z(DWORD), WSP] => [R(0), R(5)], SVE;
+    0b00000101_11100000_00111000_00000000 = [Z(QWORD), XSP] => [R(0), R(5)], SVE;
+    // MOV (SIMD&FP scalar, unpredicated)
+    0b00000101_00100001_00100000_00000000 = [Z(BYTE), B] => [R(0), R(5)], SVE;
+    0b00000101_00100010_00100000_00000000 = [Z(WORD), H] => [R(0), R(5)], SVE;
+    0b00000101_00100100_00100000_00000000 = [Z(DWORD), S] => [R(0), R(5)], SVE;
+    0b00000101_00101000_00100000_00000000 = [Z(QWORD), D] => [R(0), R(5)], SVE;
+    0b00000101_00110000_00100000_00000000 = [Z(OWORD), Q] => [R(0), R(5)], SVE;
+    // MOV (indexed)
+    0b00000101_00100001_00100000_00000000 = [Z(BYTE), ZElement(BYTE)] => [R(0), R(5), BUbits(6), Uslice(17, 4, 0), Uslice(22, 2, 4), A], SVE;
+    0b00000101_00100010_00100000_00000000 = [Z(WORD), ZElement(WORD)] => [R(0), R(5), BUbits(5), Uslice(18, 3, 0), Uslice(22, 2, 3), A], SVE;
+    0b00000101_00100100_00100000_00000000 = [Z(DWORD), ZElement(DWORD)] => [R(0), R(5), BUbits(4), Uslice(19, 2, 0), Uslice(22, 2, 2), A], SVE;
+    0b00000101_00101000_00100000_00000000 = [Z(QWORD), ZElement(QWORD)] => [R(0), R(5), BUbits(3), Uslice(20, 1, 0), Uslice(22, 2, 1), A], SVE;
+    0b00000101_00110000_00100000_00000000 = [Z(OWORD), ZElement(OWORD)] => [R(0), R(5), Ubits(22, 2)], SVE;
+    // MOV (vector, unpredicated)
+    0b00000100_01100000_00110000_00000000 = [Z(QWORD), Z(QWORD)] => [R(0), R(5), C, R(16)], SVE;
+    // MOV (tile to vector)
+    0b11000000_00000010_00000000_00000000 = [Z(BYTE), PMerge, ZaH(BYTE)] => [R(0), R3(10), RTile(9, 0), RSelect(13), Ubits(5, 4)], SME;
+    0b11000000_01000010_00000000_00000000 = [Z(WORD), PMerge, ZaH(WORD)] => [R(0), R3(10), RTile(8, 1), RSelect(13), Ubits(5, 3)], SME;
+    0b11000000_10000010_00000000_00000000 = [Z(DWORD), PMerge, ZaH(DWORD)] => [R(0), R3(10), RTile(7, 2), RSelect(13), Ubits(5, 2)], SME;
+    0b11000000_11000010_00000000_00000000 = [Z(QWORD), PMerge, ZaH(QWORD)] => [R(0), R3(10), RTile(6, 3), RSelect(13), Ubits(5, 1)], SME;
+    0b11000000_11000011_00000000_00000000 = [Z(OWORD), PMerge, ZaH(OWORD)] => [R(0), R3(10), RTile(5, 4), RSelect(13), Urange(5, 0, 0)], SME;
+    0b11000000_00000010_10000000_00000000 = [Z(BYTE), PMerge, ZaV(BYTE)] => [R(0), R3(10), RTile(9, 0), RSelect(13), Ubits(5, 4)], SME;
+    0b11000000_01000010_10000000_00000000 = [Z(WORD), PMerge, ZaV(WORD)] => [R(0), R3(10), RTile(8, 1), RSelect(13), Ubits(5, 3)], SME;
+    0b11000000_10000010_10000000_00000000 = [Z(DWORD), PMerge, ZaV(DWORD)] => [R(0), R3(10), RTile(7, 2), RSelect(13), Ubits(5, 2)], SME;
+    0b11000000_11000010_10000000_00000000 = [Z(QWORD), PMerge, ZaV(QWORD)] => [R(0), R3(10), RTile(6, 3), RSelect(13), Ubits(5, 1)], SME;
+    0b11000000_11000011_10000000_00000000 = [Z(OWORD), PMerge, ZaV(OWORD)] => [R(0), R3(10), RTile(5, 4), RSelect(13), Urange(5, 0, 0)], SME;
+    // MOV (vector to tile)
+    0b11000000_00000000_00000000_00000000 = [ZaH(BYTE), PMerge, Z(BYTE)] => [RTile(4, 0), RSelect(13), Ubits(0, 4), R3(10), R(5)], SME;
+    0b11000000_01000000_00000000_00000000 = [ZaH(WORD), PMerge, Z(WORD)] => [RTile(3, 1), RSelect(13), Ubits(0, 3), R3(10), R(5)], SME;
+    0b11000000_10000000_00000000_00000000 = [ZaH(DWORD), PMerge, Z(DWORD)] => [RTile(2, 2), RSelect(13), Ubits(0, 2), R3(10), R(5)], SME;
+    0b11000000_11000000_00000000_00000000 = [ZaH(QWORD), PMerge, Z(QWORD)] => [RTile(1, 3), RSelect(13), Ubits(0, 1), R3(10), R(5)], SME;
+    0b11000000_11000001_00000000_00000000 = [ZaH(OWORD), PMerge, Z(OWORD)] => [RTile(0, 4), RSelect(13), Urange(0, 0, 0), R3(10), R(5)], SME;
+    0b11000000_00000000_10000000_00000000 = [ZaV(BYTE), PMerge, Z(BYTE)] => [RTile(4, 0), RSelect(13), Ubits(0, 4), R3(10), R(5)], SME;
+    0b11000000_01000000_10000000_00000000 = [ZaV(WORD), PMerge, Z(WORD)] => [RTile(3, 1), RSelect(13), Ubits(0, 3), R3(10), R(5)], SME;
+    0b11000000_10000000_10000000_00000000 = [ZaV(DWORD), PMerge, Z(DWORD)] => [RTile(2, 2), RSelect(13), Ubits(0, 2), R3(10), R(5)], SME;
+    0b11000000_11000000_10000000_00000000 = [ZaV(QWORD), PMerge, Z(QWORD)] => [RTile(1, 3), RSelect(13), Ubits(0, 1), R3(10), R(5)], SME;
+    0b11000000_11000001_10000000_00000000 = [ZaV(OWORD), PMerge, Z(OWORD)] => [RTile(0, 4), RSelect(13), Urange(0, 0, 0), R3(10), R(5)], SME;
+]
+"mova" = [
+    // MOVA (tile to vector)
+    0b11000000_00000010_00000000_00000000 = [Z(BYTE), PMerge, ZaH(BYTE)] => [R(0), R3(10), RTile(9, 0), RSelect(13), Ubits(5, 4)], SME;
+    0b11000000_01000010_00000000_00000000 = [Z(WORD), PMerge, ZaH(WORD)] => [R(0), R3(10), RTile(8, 1), RSelect(13), Ubits(5, 3)], SME;
+    0b11000000_10000010_00000000_00000000 = [Z(DWORD), PMerge, ZaH(DWORD)] => [R(0), R3(10), RTile(7, 2), RSelect(13), Ubits(5, 2)], SME;
+    0b11000000_11000010_00000000_00000000 = [Z(QWORD), PMerge, ZaH(QWORD)] => [R(0), R3(10), RTile(6, 3), RSelect(13), Ubits(5, 1)], SME;
+    0b11000000_11000011_00000000_00000000 = [Z(OWORD), PMerge, ZaH(OWORD)] => [R(0), R3(10), RTile(5, 4), RSelect(13), Urange(5, 0, 0)], SME;
+    0b11000000_00000010_10000000_00000000 = [Z(BYTE), PMerge, ZaV(BYTE)] => [R(0), R3(10), RTile(9, 0), RSelect(13), Ubits(5, 4)], SME;
+    0b11000000_01000010_10000000_00000000 = [Z(WORD), PMerge, ZaV(WORD)] => [R(0), R3(10), RTile(8, 1), RSelect(13), Ubits(5, 3)], SME;
+    0b11000000_10000010_10000000_00000000 = [Z(DWORD), PMerge, ZaV(DWORD)] => [R(0), R3(10), RTile(7, 2), RSelect(13), Ubits(5, 2)], SME;
+    0b11000000_11000010_10000000_00000000 = [Z(QWORD), PMerge, ZaV(QWORD)] => [R(0), R3(10), RTile(6, 3), RSelect(13), Ubits(5, 1)], SME;
+    0b11000000_11000011_10000000_00000000 = [Z(OWORD), PMerge, ZaV(OWORD)] => [R(0), R3(10), RTile(5, 4), RSelect(13), Urange(5, 0, 0)], SME;
+    // MOVA (vector to tile)
+    0b11000000_00000000_00000000_00000000 = [ZaH(BYTE), PMerge, Z(BYTE)] => [RTile(4, 0), RSelect(13), Ubits(0, 4), R3(10), R(5)], SME;
+    0b11000000_01000000_00000000_00000000 = [ZaH(WORD), PMerge, Z(WORD)] => [RTile(3, 1), RSelect(13), Ubits(0, 3), R3(10), R(5)], SME;
+    0b11000000_10000000_00000000_00000000 = [ZaH(DWORD), PMerge, Z(DWORD)] => [RTile(2, 2), RSelect(13), Ubits(0, 2), R3(10), R(5)], SME;
+    0b11000000_11000000_00000000_00000000 = [ZaH(QWORD), PMerge, Z(QWORD)] => [RTile(1, 3), RSelect(13), Ubits(0, 1), R3(10), R(5)], SME;
+    0b11000000_11000001_00000000_00000000 = [ZaH(OWORD), PMerge, Z(OWORD)] => [RTile(0, 4), RSelect(13), Urange(0, 0, 0), R3(10), R(5)], SME;
+    0b11000000_00000000_10000000_00000000 = [ZaV(BYTE), PMerge, Z(BYTE)] => [RTile(4, 0), RSelect(13), Ubits(0, 4), R3(10), R(5)], SME;
+    0b11000000_01000000_10000000_00000000 = [ZaV(WORD), PMerge, Z(WORD)] => [RTile(3, 1), RSelect(13), Ubits(0, 3), R3(10), R(5)], SME;
+    0b11000000_10000000_10000000_00000000 = [ZaV(DWORD), PMerge, Z(DWORD)] => [RTile(2, 2), RSelect(13), Ubits(0, 2), R3(10), R(5)], SME;
+    0b11000000_11000000_10000000_00000000 = [ZaV(QWORD), PMerge, Z(QWORD)] => [RTile(1, 3), RSelect(13), Ubits(0, 1), R3(10), R(5)], SME;
+    0b11000000_11000001_10000000_00000000 = [ZaV(OWORD), PMerge, Z(OWORD)] => [RTile(0, 4), RSelect(13), Urange(0, 0, 0), R3(10), R(5)], SME;
+]
+"movi" = [
+    0b00001111_00000000_11100100_00000000 = [V(BYTE), Imm, End, LitMod(LSL)] => [R(0), BUbits(8), Uslice(5, 5, 0), Uslice(16, 3, 5), A, BUbits(0), A, Rwidth(30)];
+    0b00001111_00000000_10000100_00000000 = [V(WORD), Imm, End, LitMod(LSL)] => [R(0), BUbits(8), Uslice(5, 5, 0), Uslice(16, 3, 5), A, Ulist(13, &[0, 8]), Rwidth(30)];
+    0b00001111_00000000_00000100_00000000 = [V(DWORD), Imm, End, LitMod(LSL)] => [R(0), BUbits(8), Uslice(5, 5, 0), Uslice(16, 3, 5), A, Ulist(13, &[0, 8, 16, 24]), Rwidth(30)];
+    0b00001111_00000000_11000100_00000000 = [V(DWORD), Imm, LitMod(MSL)] => [R(0), BUbits(8), Uslice(5, 5, 0), Uslice(16, 3, 5), A, Ulist(12, &[8, 16]), Rwidth(30)];
+    0b00101111_00000000_11100100_00000000 = [D, Imm] => [R(0), Special(5, STRETCHED_IMMEDIATE)];
+    0b01101111_00000000_11100100_00000000 = [VStatic(QWORD, 2), Imm] => [R(0), Special(5, STRETCHED_IMMEDIATE)];
+]
+"movk" = [
+    0b01110010_10000000_00000000_00000000 = [W, Imm, End, LitMod(LSL)] => [R(0), Ubits(5, 16), Ulist(21, &[0, 16])];
+    0b11110010_10000000_00000000_00000000 = [X, Imm, End, LitMod(LSL)] => [R(0), Ubits(5, 16), Ulist(21, &[0, 16, 32, 48])];
+]
+"movn" = [
+    0b00010010_10000000_00000000_00000000 = [W, Imm, End, LitMod(LSL)] => [R(0), Ubits(5, 16), Ulist(21, &[0, 16])];
+    0b10010010_10000000_00000000_00000000 = [X, Imm, End, LitMod(LSL)] => [R(0), Ubits(5, 16), Ulist(21, &[0, 16, 32, 48])];
+]
+"movprfx" = [
+    // MOVPRFX (unpredicated)
+    0b00000100_00100000_10111100_00000000 = [ZBare, ZBare] => [R(0), R(5)], SVE;
+    // MOVPRFX (predicated)
+    0b00000100_00010000_00100000_00000000 = [Z(BYTE), PZero, Z(BYTE)] => [R(0), R3(10), R(5)], SVE;
+    0b00000100_01010000_00100000_00000000 = [Z(WORD), PZero, Z(WORD)] => [R(0), R3(10), R(5)], SVE;
+    0b00000100_10010000_00100000_00000000 = [Z(DWORD), PZero, Z(DWORD)] => [R(0), R3(10), R(5)], SVE;
+    0b00000100_11010000_00100000_00000000 = [Z(QWORD), PZero, Z(QWORD)] => [R(0), R3(10), R(5)], SVE;
+    0b00000100_00010001_00100000_00000000 = [Z(BYTE), PMerge, Z(BYTE)] => [R(0), R3(10), R(5)], SVE;
+    0b00000100_01010001_00100000_00000000 = [Z(WORD), PMerge, Z(WORD)] => [R(0), R3(10), R(5)], SVE;
+    0b00000100_10010001_00100000_00000000 = [Z(DWORD), PMerge, Z(DWORD)] => [R(0), R3(10), R(5)], SVE;
+    0b00000100_11010001_00100000_00000000 = [Z(QWORD), PMerge, Z(QWORD)] => [R(0), R3(10), R(5)], SVE;
+]
+"movs" = [
+    // MOVS (predicate, predicated, zeroing)
+    0b00100101_01000000_01000000_00000000 = [P(BYTE), PZero, P(BYTE)] => [R4(0), R4(10), R4(5), C, R4(16)], SVE;
+    // MOVS (predicate, unpredicated)
+    0b00100101_11000000_01000000_00000000 = [P(BYTE), P(BYTE)] => [R4(0), R4(5), C, R4(10), C, R4(16)], SVE;
+]
+"movz" = [
+    0b01010010_10000000_00000000_00000000 = [W, Imm, End, LitMod(LSL)] => [R(0), Ubits(5, 16), Ulist(21, &[0, 16])];
+    0b11010010_10000000_00000000_00000000 = [X, Imm, End, LitMod(LSL)] => [R(0), Ubits(5, 16), Ulist(21, &[0, 16, 32, 48])];
+]
+"mrs" = [
+    0b11010101_00110000_00000000_00000000 = [X, Ident] => [R(0), SysRegRead(5)];
+    0b11010101_00110000_00000000_00000000 = [X, Imm] => [R(0), Ubits(5, 15)];
+]
+"msb" = [
+    0b00000100_00000000_11100000_00000000 = [Z(BYTE), PMerge, Z(BYTE), Z(BYTE)] => [R(0), R3(10), R(16), R(5)], SVE;
+    0b00000100_01000000_11100000_00000000 = [Z(WORD), PMerge, Z(WORD), Z(WORD)] => [R(0), R3(10), R(16), R(5)], SVE;
+    0b00000100_10000000_11100000_00000000 = [Z(DWORD), PMerge, Z(DWORD), Z(DWORD)] => [R(0), R3(10), R(16), R(5)], SVE;
+    0b00000100_11000000_11100000_00000000 = [Z(QWORD), PMerge, Z(QWORD), Z(QWORD)] => [R(0), R3(10), R(16), R(5)], SVE;
+]
+"msr" = [
+    // MSR (immediate)
+    0b11010101_00000000_01000000_00011111 = [Ident, Imm] => [LitList(5, "MSR_IMM_OPS"), Ubits(8, 4)];
+    // MSR (register)
+    0b11010101_00010000_00000000_00000000 = [Ident, X] => [SysRegWrite(5), R(0)];
+    0b11010101_00010000_00000000_00000000 = [Imm, X] => [Ubits(5, 15), R(0)];
+]
+"msub" = [
+    0b00011011_00000000_10000000_00000000 = [W, W, W, W] => [R(0), R(5), R(16), R(10)];
+    0b10011011_00000000_10000000_00000000 = [X, X, X, X] => [R(0), R(5), R(16), R(10)];
+]
+"mul" = [
+    // MUL (by element)
+    0b00001111_01000000_10000000_00000000 = [V(WORD), V(WORD), VElement(WORD)] => [R(0), R(5), R4(16), Ufields(&[11, 21, 20]), Rwidth(30)];
+    0b00001111_10000000_10000000_00000000 = [V(DWORD), V(DWORD), VElement(DWORD)] => [R(0), R(5), R(16), Ufields(&[11, 21]), Rwidth(30)];
+    // MUL (vector)
+    0b00001110_00100000_10011100_00000000 = [V(BYTE), V(BYTE), V(BYTE)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b00001110_01100000_10011100_00000000 = [V(WORD), V(WORD), V(WORD)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b00001110_10100000_10011100_00000000 = [V(DWORD), V(DWORD), V(DWORD)] => [R(0), R(5), R(16), Rwidth(30)];
+    // MUL
+    0b00011011_00000000_01111100_00000000 = [W, W, W] => [R(0), R(5), R(16)];
+    0b10011011_00000000_01111100_00000000 = [X, X, X] => [R(0), R(5), R(16)];
+    // MUL (vectors, predicated)
+    0b00000100_00010000_00000000_00000000 = [Z(BYTE), PMerge, Z(BYTE), Z(BYTE)] => [R(0), R3(10), RTied, R(5)], SVE;
+    0b00000100_01010000_00000000_00000000 = [Z(WORD), PMerge, Z(WORD), Z(WORD)] => [R(0), R3(10), RTied, R(5)], SVE;
+    0b00000100_10010000_00000000_00000000 = [Z(DWORD), PMerge, Z(DWORD), Z(DWORD)] => [R(0), R3(10), RTied, R(5)], SVE;
+    0b00000100_11010000_00000000_00000000 = [Z(QWORD), PMerge, Z(QWORD), Z(QWORD)] => [R(0), R3(10), RTied, R(5)], SVE;
+    // MUL (immediate)
+    0b00100101_00110000_11000000_00000000 = [Z(BYTE), Z(BYTE), Imm] => [R(0), RTied, Sbits(5, 8)], SVE;
+    0b00100101_01110000_11000000_00000000 = [Z(WORD), Z(WORD), Imm] => [R(0), RTied, Sbits(5, 8)], SVE;
+    0b00100101_10110000_11000000_00000000 = [Z(DWORD), Z(DWORD), Imm] => [R(0), RTied, Sbits(5, 8)], SVE;
+    0b00100101_11110000_11000000_00000000 = [Z(QWORD), Z(QWORD), Imm] => [R(0), RTied, Sbits(5, 8)], SVE;
+    // MUL (vectors, unpredicated)
+    0b00000100_00100000_01100000_00000000 = [Z(BYTE), Z(BYTE), Z(BYTE)] => [R(0), R(5), R(16)], SVE2;
+    0b00000100_01100000_01100000_00000000 = [Z(WORD), Z(WORD), Z(WORD)] => [R(0), R(5), R(16)], SVE2;
+    0b00000100_10100000_01100000_00000000 = [Z(DWORD), Z(DWORD), Z(DWORD)] => [R(0), R(5), R(16)], SVE2;
+    0b00000100_11100000_01100000_00000000 = [Z(QWORD), Z(QWORD), Z(QWORD)] => [R(0), R(5), R(16)], SVE2;
+]
+"mvn" = [
+    0b00101010_00100000_00000011_11100000 = [W, W, End, Mod(SHIFTS)] => [R(0), R(16), Rotates(22), Ubits(10, 5)];
+    0b10101010_00100000_00000011_11100000 = [X, X, End, Mod(SHIFTS)] => [R(0), R(16), Rotates(22), Ubits(10, 6)];
+    0b00101110_00100000_01011000_00000000 = [V(BYTE), V(BYTE)] => [R(0), R(5), Rwidth(30)];
+]
+"mvni" = [
+    0b00101111_00000000_10000100_00000000 = [V(WORD), Imm, End, LitMod(LSL)] => [R(0), BUbits(8), Uslice(5, 5, 0), Uslice(16, 3, 5), A, Ulist(13, &[0, 8]), Rwidth(30)];
+    0b00101111_00000000_00000100_00000000 = [V(DWORD), Imm, End, LitMod(LSL)] => [R(0), BUbits(8), Uslice(5, 5, 0), Uslice(16, 3, 5), A, Ulist(13, &[0, 8, 16, 24]), Rwidth(30)];
+    0b00101111_00000000_11000100_00000000 = [V(DWORD), Imm, LitMod(MSL)] => [R(0), BUbits(8), Uslice(5, 5, 0), Uslice(16, 3, 5), A, Ulist(12, &[8, 16]), Rwidth(30)];
+]
+"nand" = [
+    0b00100101_10000000_01000010_00010000 = [P(BYTE), PZero, P(BYTE), P(BYTE)] => [R4(0), R4(10), R4(5), R4(16)], SVE;
+]
+"nands" = [
+    0b00100101_11000000_01000010_00010000 = [P(BYTE), PZero, P(BYTE), P(BYTE)] => [R4(0), R4(10), R4(5), R4(16)], SVE;
+]
+"nbsl" = [
+    0b00000100_11100000_00111100_00000000 = [Z(QWORD), Z(QWORD), Z(QWORD), Z(QWORD)] => [R(0), RTied, R(16), R(5)], SVE2;
+]
+"neg" = [
+    // NEG (shifted register)
+    0b01001011_00000000_00000011_11100000 = [W, W, End, Mod(SHIFTS)] => [R(0), R(16), Rotates(22), Ubits(10, 5)];
+    0b11001011_00000000_00000011_11100000 = [X, X, End, Mod(SHIFTS)] => [R(0), R(16), Rotates(22), Ubits(10, 6)];
+    // NEG (vector)
+    0b01111110_11100000_10111000_00000000 = [D, D] => [R(0), R(5)];
+    0b00101110_00100000_10111000_00000000 = [V(BYTE), V(BYTE)] => [R(0), R(5), Rwidth(30)];
+    0b00101110_01100000_10111000_00000000 = [V(WORD), V(WORD)] => [R(0), R(5), Rwidth(30)];
+    0b00101110_10100000_10111000_00000000 = [V(DWORD), V(DWORD)] => [R(0), R(5), Rwidth(30)];
+    0b00101110_11100000_10111000_00000000 = [VStatic(QWORD, 2), VStatic(QWORD, 2)] => [R(0), R(5), Rwidth(30)];
+    // NEG (predicated)
+    0b00000100_00010111_10100000_00000000 = [Z(BYTE), PMerge, Z(BYTE)] => [R(0), R3(10), R(5)], SVE;
+    0b00000100_01010111_10100000_00000000 = [Z(WORD), PMerge, Z(WORD)] => [R(0), R3(10), R(5)], SVE;
+    0b00000100_10010111_10100000_00000000 = [Z(DWORD), PMerge, Z(DWORD)] => [R(0), R3(10), R(5)], SVE;
+    0b00000100_11010111_10100000_00000000 = [Z(QWORD), PMerge, Z(QWORD)] => [R(0), R3(10), R(5)], SVE;
+]
+"negs" = [
+    0b01101011_00000000_00000011_11100000 = [W, W, End, Mod(SHIFTS)] => [R(0), R(16), Rotates(22), Ubits(10, 5)];
+    0b11101011_00000000_00000011_11100000 = [X, X, End, Mod(SHIFTS)] => [R(0), R(16), Rotates(22), Ubits(10, 6)];
+]
+"ngc" = [
+    0b01011010_00000000_00000011_11100000 = [W, W] => [R(0), R(16)];
+    0b11011010_00000000_00000011_11100000 = [X, X] => [R(0), R(16)];
+]
+"ngcs" = [
+    0b01111010_00000000_00000011_11100000 = [W, W] => [R(0), R(16)];
+    0b11111010_00000000_00000011_11100000 = [X, X] => [R(0), R(16)];
+]
+"nmatch" = [
+    0b01000101_00100000_10000000_00010000 = [P(BYTE), PZero, Z(BYTE), Z(BYTE)] => [R4(0), R3(10), R(5), R(16)], SVE2;
+    0b01000101_01100000_10000000_00010000 = [P(WORD), PZero, Z(WORD), Z(WORD)] => [R4(0), R3(10), R(5), R(16)], SVE2;
+]
+"nop" = [
+    0b11010101_00000011_00100000_00011111 = [] => [];
+]
+"nor" = [
+    0b00100101_10000000_01000010_00000000 = [P(BYTE), PZero, P(BYTE), P(BYTE)] => [R4(0), R4(10), R4(5), R4(16)], SVE;
+]
+"nors" = [
+    0b00100101_11000000_01000010_00000000 = [P(BYTE), PZero, P(BYTE), P(BYTE)] => [R4(0), R4(10), R4(5), R4(16)], SVE;
+]
+"not" = [
+    // NOT
+    0b00101110_00100000_01011000_00000000 = [V(BYTE), V(BYTE)] => [R(0), R(5), Rwidth(30)];
+    // NOT (predicate)
+    0b00100101_00000000_01000010_00000000 = [P(BYTE), PZero, P(BYTE)] => [R4(0), R4(10), C, R4(16), R4(5)], SVE;
+    // NOT (predicated)
+    0b00000100_00011110_10100000_00000000 = [Z(BYTE), PMerge, Z(BYTE)] => [R(0), R3(10), R(5)], SVE;
+    0b00000100_01011110_10100000_00000000 = [Z(WORD), PMerge, Z(WORD)] => [R(0), R3(10), R(5)], SVE;
+    0b00000100_10011110_10100000_00000000 = [Z(DWORD), PMerge, Z(DWORD)] => [R(0), R3(10), R(5)], SVE;
+    0b00000100_11011110_10100000_00000000 = [Z(QWORD), PMerge, Z(QWORD)] => [R(0), R3(10), R(5)], SVE;
+]
+"nots" = [
+    0b00100101_01000000_01000010_00000000 = [P(BYTE), PZero, P(BYTE)] => [R4(0), R4(10), C, R4(16), R4(5)], SVE;
+]
+"orn" = [
+    // ORN (vector)
+    0b00001110_11100000_00011100_00000000 = [V(BYTE), V(BYTE), V(BYTE)] => [R(0), R(5), R(16), Rwidth(30)];
+    // ORN (shifted register)
+    0b00101010_00100000_00000000_00000000 = [W, W, W, End, Mod(ROTATES)] => [R(0), R(5), R(16), Rotates(22), Ubits(10, 5)];
+    0b10101010_00100000_00000000_00000000 = [X, X, X, End, Mod(ROTATES)] => [R(0), R(5), R(16), Rotates(22), Ubits(10, 6)];
+    // ORN (predicates)
+    0b00100101_10000000_01000000_00010000 = [P(BYTE), PZero, P(BYTE), P(BYTE)] => [R4(0), R4(10), R4(5), R4(16)], SVE;
+]
+"orns" = [
+    0b00100101_11000000_01000000_00010000 = [P(BYTE), PZero, P(BYTE), P(BYTE)] => [R4(0), R4(10), R4(5), R4(16)], SVE;
+]
+"orr" = [
+    // ORR (vector, immediate)
+    0b00001111_00000000_10010100_00000000 = [V(WORD), Imm, End, LitMod(LSL)] => [R(0), BUbits(8), Uslice(5, 5, 0), Uslice(16, 3, 5), A, Ulist(13, &[0, 8]), Rwidth(30)];
+    0b00001111_00000000_00010100_00000000 = [V(DWORD), Imm, End, LitMod(LSL)] => [R(0), BUbits(8), Uslice(5, 5, 0), Uslice(16, 3, 5), A, Ulist(13, &[0, 8, 16, 24]), Rwidth(30)];
+    // ORR (vector, register)
+    0b00001110_10100000_00011100_00000000 = [V(BYTE), V(BYTE), V(BYTE)] => [R(0), R(5), R(16), Rwidth(30)];
+    // ORR (immediate)
+    0b00110010_00000000_00000000_00000000 = [WSP, W, Imm] => [R(0), R(5), Special(10, LOGICAL_IMMEDIATE_W)];
+    0b10110010_00000000_00000000_00000000 = [XSP, X, Imm] => [R(0), R(5), Special(10, LOGICAL_IMMEDIATE_X)];
+    // ORR (shifted register)
+    0b00101010_00000000_00000000_00000000 = [W, W, W, End, Mod(ROTATES)] => [R(0), R(5), R(16), Rotates(22), Ubits(10, 5)];
+    0b10101010_00000000_00000000_00000000 = [X, X, X, End, Mod(ROTATES)] => [R(0), R(5), R(16), Rotates(22), Ubits(10, 6)];
+    // ORR (predicates)
+    0b00100101_10000000_01000000_00000000 = [P(BYTE), PZero, P(BYTE), P(BYTE)] => [R4(0), R4(10), R4(5), R4(16)], SVE;
+    // ORR (vectors, unpredicated)
+    0b00000100_01100000_00110000_00000000 = [Z(QWORD), Z(QWORD), Z(QWORD)] => [R(0), R(5), R(16)], SVE;
+    // ORR (vectors, predicated)
+    0b00000100_00011000_00000000_00000000 = [Z(BYTE), PMerge, Z(BYTE), Z(BYTE)] => [R(0), R3(10), RTied, R(5)], SVE;
+    0b00000100_01011000_00000000_00000000 = [Z(WORD), PMerge, Z(WORD), Z(WORD)] => [R(0), R3(10), RTied, R(5)], SVE;
+    0b00000100_10011000_00000000_00000000 = [Z(DWORD), PMerge, Z(DWORD), Z(DWORD)] => [R(0), R3(10), RTied, R(5)], SVE;
+    0b00000100_11011000_00000000_00000000 = [Z(QWORD), PMerge, Z(QWORD), Z(QWORD)] => [R(0), R3(10), RTied, R(5)], SVE;
+    // ORR (immediate)
+    0b00000101_00000000_00000000_00000000 = [Z(BYTE), Z(BYTE), Imm] => [R(0), RTied, Special(5, LOGICAL_IMMEDIATE_B)], SVE;
+    0b00000101_00000000_00000000_00000000 = [Z(WORD), Z(WORD), Imm] => [R(0), RTied, Special(5, LOGICAL_IMMEDIATE_H)], SVE;
+    0b00000101_00000000_00000000_00000000 = [Z(DWORD), Z(DWORD), Imm] => [R(0), RTied, Special(5, LOGICAL_IMMEDIATE_W)], SVE;
+    0b00000101_00000000_00000000_00000000 = [Z(QWORD), Z(QWORD), Imm] => [R(0), RTied, Special(5, LOGICAL_IMMEDIATE_X)], SVE;
+]
+"orrs" = [
+    0b00100101_11000000_01000000_00000000 = [P(BYTE), PZero, P(BYTE), P(BYTE)] => [R4(0), R4(10), R4(5), R4(16)], SVE;
+]
+"orv" = [
+    0b00000100_00011000_00100000_00000000 = [B, PBare, Z(BYTE)] => [R(0), R3(10), R(5)], SVE;
+    0b00000100_01011000_00100000_00000000 = [H, PBare, Z(WORD)] => [R(0), R3(10), R(5)], SVE;
+    0b00000100_10011000_00100000_00000000 = [S, PBare, Z(DWORD)] => [R(0), R3(10), R(5)], SVE;
+    0b00000100_11011000_00100000_00000000 = [D, PBare, Z(QWORD)] => [R(0), R3(10), R(5)], SVE;
+]
+"pacda" = [
+    0b11011010_11000001_00001000_00000000 = [X, XSP] => [R(0), R(5)], PAUTH;
+]
+"pacdb" = [
+    0b11011010_11000001_00001100_00000000 = [X, XSP] => [R(0), R(5)], PAUTH;
+]
+"pacdza" = [
+    0b11011010_11000001_00101011_11100000 = [X] => [R(0)], PAUTH;
+]
+"pacdzb" = [
+    0b11011010_11000001_00101111_11100000 = [X] => [R(0)], PAUTH;
+]
+"pacga" = [
+    0b10011010_11000000_00110000_00000000 = [X, X, XSP] => [R(0), R(5), R(16)], PAUTH;
+]
+"pacia" = [
+    0b11011010_11000001_00000000_00000000 = [X, XSP] => [R(0), R(5)], PAUTH;
+]
+"pacia1716" = [
+    0b11010101_00000011_00100001_00011111 = [] => [], PAUTH;
+]
+"paciasp" = [
+    0b11010101_00000011_00100011_00111111 = [] => [], PAUTH;
+]
+"paciaz" = [
+    0b11010101_00000011_00100011_00011111 = [] => [], PAUTH;
+]
+"pacib" = [
+    0b11011010_11000001_00000100_00000000 = [X, XSP] => [R(0), R(5)], PAUTH;
+]
+"pacib1716" = [
+    0b11010101_00000011_00100001_01011111 = [] => [], PAUTH;
+]
+"pacibsp" = [
+    0b11010101_00000011_00100011_01111111 = [] => [], PAUTH;
+]
+"pacibz" = [
+    0b11010101_00000011_00100011_01011111 = [] => [], PAUTH;
+]
+"paciza" = [
+    0b11011010_11000001_00100011_11100000 = [X] => [R(0)], PAUTH;
+]
+"pacizb" = [
+    0b11011010_11000001_00100111_11100000 = [X] => [R(0)], PAUTH;
+]
+"pfalse" = [
+    0b00100101_00011000_11100100_00000000 = [P(BYTE)] => [R4(0)], SVE;
+]
+"pfirst" = [
+    0b00100101_01011000_11000000_00000000 = [P(BYTE), PBare, P(BYTE)] => [R4(0), R4(5), RTied], SVE;
+]
+"pmul" = [
+    0b00101110_00100000_10011100_00000000 = [V(BYTE), V(BYTE), V(BYTE)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b00000100_00100000_01100100_00000000 = [Z(BYTE), Z(BYTE), Z(BYTE)] => [R(0), R(5), R(16)], SVE2;
+]
+"pmull" = [
+    0b00001110_00100000_11100000_00000000 = [VStatic(WORD, 8), VStatic(BYTE, 8), VStatic(BYTE, 8)] => [R(0), R(5), R(16)];
+    0b00001110_11100000_11100000_00000000 = [VStatic(OWORD, 1), VStatic(QWORD, 1), VStatic(QWORD, 1)] => [R(0), R(5), R(16)], AES;
+]
+"pmull2" = [
+    0b01001110_00100000_11100000_00000000 = [VStatic(WORD, 8), VStatic(BYTE, 16), VStatic(BYTE, 16)] => [R(0), R(5), R(16)];
+    0b01001110_11100000_11100000_00000000 = [VStatic(OWORD, 1), VStatic(QWORD, 2), VStatic(QWORD, 2)] => [R(0), R(5), R(16)], AES;
+]
+"pnext" = [
+    0b00100101_00011001_11000100_00000000 = [P(BYTE), PBare, P(BYTE)] => [R4(0), R4(5), RTied], SVE;
+    0b00100101_01011001_11000100_00000000 = [P(WORD), PBare, P(WORD)] => [R4(0), R4(5), RTied], SVE;
+    0b00100101_10011001_11000100_00000000 = [P(DWORD), PBare, P(DWORD)] => [R4(0), R4(5), RTied], SVE;
+    0b00100101_11011001_11000100_00000000 = [P(QWORD), PBare, P(QWORD)] => [R4(0), R4(5), RTied], SVE;
+]
+"prfb" = [
+    // PRFB (scalar plus scalar)
+    0b10000100_00000000_11000000_00000000 = [Imm, PBare, RefIndexLsl(0)] => [Ubits(0, 4), R3(10), R(5), RNoZr(16)], SVE;
+    // PRFB (scalar plus vector)
+    0b11000100_00100000_00000000_00000000 = [Imm, PBare, RefZIndexExtend(QWORD, 0)] => [Ubits(0, 4), R3(10), R(5), R(16), ExtendsZ(22)], SVE;
+    0b11000100_01100000_10000000_00000000 = [Imm, PBare, RefZIndexLsl(QWORD, 0)] => [Ubits(0, 4), R3(10), R(5), R(16)], SVE;
+    0b10000100_00100000_00000000_00000000 = [Imm, PBare, RefZIndexExtend(DWORD, 0)] => [Ubits(0, 4), R3(10), R(5), R(16), ExtendsZ(22)], SVE;
+    // PRFB (scalar plus immediate)
+    0b10000101_11000000_00000000_00000000 = [Imm, PBare, RefMulVl] => [Ubits(0, 4), R3(10), R(5), Sbits(16, 6)], SVE;
+    // PRFB (vector plus immediate)
+    0b11000100_00000000_11100000_00000000 = [Imm, PBare, RefZBase(QWORD)] => [Ubits(0, 4), R3(10), R(5), Ubits(16, 5)], SVE;
+    0b10000100_00000000_11100000_00000000 = [Imm, PBare, RefZBase(DWORD)] => [Ubits(0, 4), R3(10), R(5), Ubits(16, 5)], SVE;
+]
+"prfd" = [
+    // PRFD (scalar plus scalar)
+    0b10000101_10000000_11000000_00000000 = [Imm, PBare, RefIndexLsl(3)] => [Ubits(0, 4), R3(10), R(5), RNoZr(16)], SVE;
+    // PRFD (scalar plus vector)
+    0b11000100_00100000_01100000_00000000 = [Imm, PBare, RefZIndexExtend(QWORD, 3)] => [Ubits(0, 4), R3(10), R(5), R(16), ExtendsZ(22)], SVE;
+    0b11000100_01100000_11100000_00000000 = [Imm, PBare, RefZIndexLsl(QWORD, 3)] => [Ubits(0, 4), R3(10), R(5), R(16)], SVE;
+    0b10000100_00100000_01100000_00000000 = [Imm, PBare, RefZIndexExtend(DWORD, 3)] => [Ubits(0, 4), R3(10), R(5), R(16), ExtendsZ(22)], SVE;
+    // PRFD (scalar plus immediate)
+    0b10000101_11000000_01100000_00000000 = [Imm, PBare, RefMulVl] => [Ubits(0, 4), R3(10), R(5), Sbits(16, 6)], SVE;
+    // PRFD (vector plus immediate)
+    0b11000101_10000000_11100000_00000000 = [Imm, PBare, RefZBase(QWORD)] => [Ubits(0, 4), R3(10), R(5), Uscaled(16, 5, 3)], SVE;
+    0b10000101_10000000_11100000_00000000 = [Imm, PBare, RefZBase(DWORD)] => [Ubits(0, 4), R3(10), R(5), Uscaled(16, 5, 3)], SVE;
+]
+"prfh" = [
+    // PRFH (scalar plus scalar)
+    0b10000100_10000000_11000000_00000000 = [Imm, PBare, RefIndexLsl(1)] => [Ubits(0, 4), R3(10), R(5), RNoZr(16)], SVE;
+    // PRFH (scalar plus vector)
+    0b11000100_00100000_00100000_00000000 = [Imm, PBare, RefZIndexExtend(QWORD, 1)] => [Ubits(0, 4), R3(10), R(5), R(16), ExtendsZ(22)], SVE;
+    0b11000100_01100000_10100000_00000000 = [Imm, PBare, RefZIndexLsl(QWORD, 1)] => [Ubits(0, 4), R3(10), R(5), R(16)], SVE;
+    0b10000100_00100000_00100000_00000000 = [Imm, PBare, RefZIndexExtend(DWORD, 1)] => [Ubits(0, 4), R3(10), R(5), R(16), ExtendsZ(22)], SVE;
+    // PRFH (scalar plus immediate)
+    0b10000101_11000000_00100000_00000000 = [Imm, PBare, RefMulVl] => [Ubits(0, 4), R3(10), R(5), Sbits(16, 6)], SVE;
+    // PRFH (vector plus immediate)
+    0b11000100_10000000_11100000_00000000 = [Imm, PBare, RefZBase(QWORD)] => [Ubits(0, 4), R3(10), R(5), Uscaled(16, 5, 1)], SVE;
+    0b10000100_10000000_11100000_00000000 = [Imm, PBare, RefZBase(DWORD)] => [Ubits(0, 4), R3(10), R(5), Uscaled(16, 5, 1)], SVE;
+]
+"prfm" = [
+    // PRFM (literal)
+    0b11011000_00000000_00000000_00000000 = [Imm, Offset] => [Ubits(0, 5), Offset(BCOND)];
+    // PRFM (register)
+    0b11111000_10100000_00001000_00000000 = [Imm, RefIndex] => [Ubits(0, 5), R(5), R(16), ExtendsX(13), Ulist(12, &[0, 3])];
+]
+"prfum" = [
+    0b11111000_10000000_00000000_00000000 = [Imm, RefOffset] => [Ubits(0, 5), R(5), Sbits(12, 9)];
+]
+"prfw" = [
+    // PRFW (scalar plus scalar)
+    0b10000101_00000000_11000000_00000000 = [Imm, PBare, RefIndexLsl(2)] => [Ubits(0, 4), R3(10), R(5), RNoZr(16)], SVE;
+    // PRFW (scalar plus vector)
+    0b11000100_00100000_01000000_00000000 = [Imm, PBare, RefZIndexExtend(QWORD, 2)] => [Ubits(0, 4), R3(10), R(5), R(16), ExtendsZ(22)], SVE;
+    0b11000100_01100000_11000000_00000000 = [Imm, PBare, RefZIndexLsl(QWORD, 2)] => [Ubits(0, 4), R3(10), R(5), R(16)], SVE;
+    0b10000100_00100000_01000000_00000000 = [Imm, PBare, RefZIndexExtend(DWORD, 2)] => [Ubits(0, 4), R3(10), R(5), R(16), ExtendsZ(22)], SVE;
+    // PRFW (scalar plus immediate)
+    0b10000101_11000000_01000000_00000000 = [Imm, PBare, RefMulVl] => [Ubits(0, 4), R3(10), R(5), Sbits(16, 6)], SVE;
+    // PRFW (vector plus immediate)
+    0b11000101_00000000_11100000_00000000 = [Imm, PBare, RefZBase(QWORD)] => [Ubits(0, 4), R3(10), R(5), Uscaled(16, 5, 2)], SVE;
+    0b10000101_00000000_11100000_00000000 = [Imm, PBare, RefZBase(DWORD)] => [Ubits(0, 4), R3(10), R(5), Uscaled(16, 5, 2)], SVE;
+]
+"psb" = [
+    0b11010101_00000011_00100010_00111111 = [Lit("csync")] => [], SPE;
+]
+"pssbb" = [
+    0b11010101_00000011_00110100_10011111 = [] => [];
+]
+"ptest" = [
+    0b00100101_01010000_11000000_00000000 = [PBare, P(BYTE)] => [R4(10), R4(5)], SVE;
+]
+"ptrue" = [
+    0b00100101_00011000_11100011_11100000 = [P(BYTE)] => [R4(0)], SVE;
+    0b00100101_01011000_11100011_11100000 = [P(WORD)] => [R4(0)], SVE;
+    0b00100101_10011000_11100011_11100000 = [P(DWORD)] => [R4(0)], SVE;
+    0b00100101_11011000_11100011_11100000 = [P(QWORD)] => [R4(0)], SVE;
+    0b00100101_00011000_11100000_00000000 = [P(BYTE), Ident] => [R4(0), LitList(5, "SVE_PATTERNS")], SVE;
+    0b00100101_01011000_11100000_00000000 = [P(WORD), Ident] => [R4(0), LitList(5, "SVE_PATTERNS")], SVE;
+    0b00100101_10011000_11100000_00000000 = [P(DWORD), Ident] => [R4(0), LitList(5, "SVE_PATTERNS")], SVE;
+    0b00100101_11011000_11100000_00000000 = [P(QWORD), Ident] => [R4(0), LitList(5, "SVE_PATTERNS")], SVE;
+]
+"ptrues" = [
+    0b00100101_00011001_11100011_11100000 = [P(BYTE)] => [R4(0)], SVE;
+    0b00100101_01011001_11100011_11100000 = [P(WORD)] => [R4(0)], SVE;
+    0b00100101_10011001_11100011_11100000 = [P(DWORD)] => [R4(0)], SVE;
+    0b00100101_11011001_11100011_11100000 = [P(QWORD)] => [R4(0)], SVE;
+    0b00100101_00011001_11100000_00000000 = [P(BYTE), Ident] => [R4(0), LitList(5, "SVE_PATTERNS")], SVE;
+    0b00100101_01011001_11100000_00000000 = [P(WORD), Ident] => [R4(0), LitList(5, "SVE_PATTERNS")], SVE;
+    0b00100101_10011001_11100000_00000000 = [P(DWORD), Ident] => [R4(0), LitList(5, "SVE_PATTERNS")], SVE;
+    0b00100101_11011001_11100000_00000000 = [P(QWORD), Ident] => [R4(0), LitList(5, "SVE_PATTERNS")], SVE;
+]
+"punpkhi" = [
+    0b00000101_00110001_01000000_00000000 = [P(WORD), P(BYTE)] => [R4(0), R4(5)], SVE;
+]
+"punpklo" = [
+    0b00000101_00110000_01000000_00000000 = [P(WORD), P(BYTE)] => [R4(0), R4(5)], SVE;
+]
+"raddhn" = [
+    0b00101110_00100000_01000000_00000000 = [VStatic(BYTE, 8), VStatic(WORD, 8), VStatic(WORD, 8)] => [R(0), R(5), R(16)];
+    0b00101110_01100000_01000000_00000000 = [VStatic(WORD, 4), VStatic(DWORD, 4), VStatic(DWORD, 4)] => [R(0), R(5), R(16)];
+    0b00101110_10100000_01000000_00000000 = [VStatic(DWORD, 2), VStatic(QWORD, 2), VStatic(QWORD, 2)] => [R(0), R(5), R(16)];
+]
+"raddhn2" = [
+    0b01101110_00100000_01000000_00000000 = [VStatic(BYTE, 16), VStatic(WORD, 8), VStatic(WORD, 8)] => [R(0), R(5), R(16)];
+    0b01101110_01100000_01000000_00000000 = [VStatic(WORD, 8), VStatic(DWORD, 4), VStatic(DWORD, 4)] => [R(0), R(5), R(16)];
+    0b01101110_10100000_01000000_00000000 = [VStatic(DWORD, 4), VStatic(QWORD, 2), VStatic(QWORD, 2)] => [R(0), R(5), R(16)];
+]
+"raddhnb" = [
+    0b01000101_01100000_01101000_00000000 = [Z(BYTE), Z(WORD), Z(WORD)] => [R(0), R(5), R(16)], SVE2;
+    0b01000101_10100000_01101000_00000000 = [Z(WORD), Z(DWORD), Z(DWORD)] => [R(0), R(5), R(16)], SVE2;
+    0b01000101_11100000_01101000_00000000 = [Z(DWORD), Z(QWORD), Z(QWORD)] => [R(0), R(5), R(16)], SVE2;
+]
+"raddhnt" = [
+    0b01000101_01100000_01101100_00000000 = [Z(BYTE), Z(WORD), Z(WORD)] => [R(0), R(5), R(16)], SVE2;
+    0b01000101_10100000_01101100_00000000 = [Z(WORD), Z(DWORD), Z(DWORD)] => [R(0), R(5), R(16)], SVE2;
+    0b01000101_11100000_01101100_00000000 = [Z(DWORD), Z(QWORD), Z(QWORD)] => [R(0), R(5), R(16)], SVE2;
+]
+"rax1" = [
+    0b11001110_01100000_10001100_00000000 = [VStatic(QWORD, 2), VStatic(QWORD, 2), VStatic(QWORD, 2)] => [R(0), R(5), R(16)], SHA3;
+    0b01000101_00100000_11110100_00000000 = [Z(QWORD), Z(QWORD), Z(QWORD)] => [R(0), R(5), R(16)], SVE2 | SHA3;
+]
+"rbit" = [
+    // RBIT (vector)
+    0b00101110_01100000_01011000_00000000 = [V(BYTE), V(BYTE)] => [R(0), R(5), Rwidth(30)];
+    // RBIT
+    0b01011010_11000000_00000000_00000000 = [W, W] => [R(0), R(5)];
+    0b11011010_11000000_00000000_00000000 = [X, X] => [R(0), R(5)];
+    // RBIT (predicated)
+    0b00000101_00100111_10000000_00000000 = [Z(BYTE), PMerge, Z(BYTE)] => [R(0), R3(10), R(5)], SVE;
+    0b00000101_01100111_10000000_00000000 = [Z(WORD), PMerge, Z(WORD)] => [R(0), R3(10), R(5)], SVE;
+    0b00000101_10100111_10000000_00000000 = [Z(DWORD), PMerge, Z(DWORD)] => [R(0), R3(10), R(5)], SVE;
+    0b00000101_11100111_10000000_00000000 = [Z(QWORD), PMerge, Z(QWORD)] => [R(0), R3(10), R(5)], SVE;
+]
+"rdffr" = [
+    // RDFFR (unpredicated)
+    0b00100101_00011001_11110000_00000000 = [P(BYTE)] => [R4(0)], SVE;
+    // RDFFR (predicated)
+    0b00100101_00011000_11110000_00000000 = [P(BYTE), PZero] => [R4(0), R4(5)], SVE;
+]
+"rdffrs" = [
+    0b00100101_01011000_11110000_00000000 = [P(BYTE), PZero] => [R4(0), R4(5)], SVE;
+]
+"rdsvl" = [
+    0b00000100_10111111_01011000_00000000 = [X, Imm] => [R(0), Sbits(5, 6)], SME;
+]
+"rdvl" = [
+    0b00000100_10111111_01010000_00000000 = [X, Imm] => [R(0), Sbits(5, 6)], SVE;
+]
+"ret" = [
+    0b11010110_01011111_00000000_00000000 = [X] => [R(5)];
+    0b11010110_01011111_00000011_11000000 = [] => [];
+]
+"retaa" = [
+    0b11010110_01011111_00001011_11111111 = [] => [], PAUTH;
+]
+"retab" = [
+    0b11010110_01011111_00001111_11111111 = [] => [], PAUTH;
+]
+"rev" = [
+    // REV
+    0b01011010_11000000_00001000_00000000 = [W, W] => [R(0), R(5)];
+    0b11011010_11000000_00001100_00000000 = [X, X] => [R(0), R(5)];
+    // REV (predicate)
+    0b00000101_00110100_01000000_00000000 = [P(BYTE), P(BYTE)] => [R4(0), R4(5)], SVE;
+    0b00000101_01110100_01000000_00000000 = [P(WORD), P(WORD)] => [R4(0), R4(5)], SVE;
+    0b00000101_10110100_01000000_00000000 = [P(DWORD), P(DWORD)] => [R4(0), R4(5)], SVE;
+    0b00000101_11110100_01000000_00000000 = [P(QWORD), P(QWORD)] => [R4(0), R4(5)], SVE;
+    // REV (vector)
+    0b00000101_00111000_00111000_00000000 = [Z(BYTE), Z(BYTE)] => [R(0), R(5)], SVE;
+    0b00000101_01111000_00111000_00000000 = [Z(WORD), Z(WORD)] => [R(0), R(5)], SVE;
+    0b00000101_10111000_00111000_00000000 = [Z(DWORD), Z(DWORD)] => [R(0), R(5)], SVE;
+    0b00000101_11111000_00111000_00000000 = [Z(QWORD), Z(QWORD)] => [R(0), R(5)], SVE;
+]
+"rev16" = [
+    // REV16 (vector)
+    0b00001110_00100000_00011000_00000000 = [V(BYTE), V(BYTE)] => [R(0), R(5), Rwidth(30)];
+    // REV16
+    0b01011010_11000000_00000100_00000000 = [W, W] => [R(0), R(5)];
+    0b11011010_11000000_00000100_00000000 = [X, X] => [R(0), R(5)];
+]
+"rev32" = [
+    // REV32 (vector)
+    0b00101110_00100000_00001000_00000000 = [V(BYTE), V(BYTE)] => [R(0), R(5), Rwidth(30)];
+    0b00101110_01100000_00001000_00000000 = [V(WORD), V(WORD)] => [R(0), R(5), Rwidth(30)];
+    // REV32
+    0b11011010_11000000_00001000_00000000 = [X, X] => [R(0), R(5)];
+]
+"rev64" = [
+    0b00001110_00100000_00001000_00000000 = [V(BYTE), V(BYTE)] => [R(0), R(5), Rwidth(30)];
+    0b00001110_01100000_00001000_00000000 = [V(WORD), V(WORD)] => [R(0), R(5), Rwidth(30)];
+    0b00001110_10100000_00001000_00000000 = [V(DWORD), V(DWORD)] => [R(0), R(5), Rwidth(30)];
+    0b11011010_11000000_00001100_00000000 = [X, X] => [R(0), R(5)];
+]
+"revb" = [
+    0b00000101_01100100_10000000_00000000 = [Z(WORD), PMerge, Z(WORD)] => [R(0), R3(10), R(5)], SVE;
+    0b00000101_10100100_10000000_00000000 = [Z(DWORD), PMerge, Z(DWORD)] => [R(0), R3(10), R(5)], SVE;
+    0b00000101_11100100_10000000_00000000 = [Z(QWORD), PMerge, Z(QWORD)] => [R(0), R3(10), R(5)], SVE;
+]
+"revd" = [
+    0b00000101_00101110_10000000_00000000 = [Z(OWORD), PMerge, Z(OWORD)] => [R(0), R3(10), R(5)], SME;
+]
+"revh" = [
+    0b00000101_10100101_10000000_00000000 = [Z(DWORD), PMerge, Z(DWORD)] => [R(0), R3(10), R(5)], SVE;
+    0b00000101_11100101_10000000_00000000 = [Z(QWORD), PMerge, Z(QWORD)] => [R(0), R3(10), R(5)], SVE;
+]
+"revw" = [
+    0b00000101_11100110_10000000_00000000 = [Z(QWORD), PMerge, Z(QWORD)] => [R(0), R3(10), R(5)], SVE;
+]
+"rmif" = [
+    0b10111010_00000000_00000100_00000000 = [X, Imm, Imm] => [R(5), Ubits(15, 6), Ubits(0, 4)], FLAGM;
+]
+"ror" = [
+    // ROR (immediate)
+    0b00010011_10000000_00000000_00000000 = [W, W, Imm] => [R(0), R(5), C, R(16), Ubits(10, 5)];
+    0b10010011_11000000_00000000_00000000 = [X, X, Imm] => [R(0), R(5), C, R(16), Ubits(10, 6)];
+    // ROR (register)
+    0b00011010_11000000_00101100_00000000 = [W, W, W] => [R(0), R(5), R(16)];
+    0b10011010_11000000_00101100_00000000 = [X, X, X] => [R(0), R(5), R(16)];
+]
+"rorv" = [
+    0b00011010_11000000_00101100_00000000 = [W, W, W] => [R(0), R(5), R(16)];
+    0b10011010_11000000_00101100_00000000 = [X, X, X] => [R(0), R(5), R(16)];
+]
+"rshrn" = [
+    0b00001111_00001000_10001100_00000000 = [VStatic(BYTE, 8), VStatic(WORD, 8), Imm] => [R(0), R(5), Usub(16, 3, 8)];
+    0b00001111_00010000_10001100_00000000 = [VStatic(WORD, 4), VStatic(DWORD, 4), Imm] => [R(0), R(5), Usub(16, 4, 16)];
+    0b00001111_00100000_10001100_00000000 = [VStatic(DWORD, 2), VStatic(QWORD, 2), Imm] => [R(0), R(5), Usub(16, 5, 32)];
+]
+"rshrn2" = [
+    0b01001111_00001000_10001100_00000000 = [VStatic(BYTE, 16), VStatic(WORD, 8), Imm] => [R(0), R(5), Usub(16, 3, 8)];
+    0b01001111_00010000_10001100_00000000 = [VStatic(WORD, 8), VStatic(DWORD, 4), Imm] => [R(0), R(5), Usub(16, 4, 16)];
+    0b01001111_00100000_10001100_00000000 = [VStatic(DWORD, 4), VStatic(QWORD, 2), Imm] => [R(0), R(5), Usub(16, 5, 32)];
+]
+"rshrnb" = [
+    0b01000101_00101000_00011000_00000000 = [Z(BYTE), Z(WORD), Imm] => [R(0), R(5), Usub(16, 3, 8)], SVE2;
+    0b01000101_00110000_00011000_00000000 = [Z(WORD), Z(DWORD), Imm] => [R(0), R(5), Usub(16, 4, 16)], SVE2;
+    0b01000101_01100000_00011000_00000000 = [Z(DWORD), Z(QWORD), Imm] => [R(0), R(5), Usub(16, 5, 32)], SVE2;
+]
+"rshrnt" = [
+    0b01000101_00101000_00011100_00000000 = [Z(BYTE), Z(WORD), Imm] => [R(0), R(5), Usub(16, 3, 8)], SVE2;
+    0b01000101_00110000_00011100_00000000 = [Z(WORD), Z(DWORD), Imm] => [R(0), R(5), Usub(16, 4, 16)], SVE2;
+    0b01000101_01100000_00011100_00000000 = [Z(DWORD), Z(QWORD), Imm] => [R(0), R(5), Usub(16, 5, 32)], SVE2;
+]
+"rsubhn" = [
+    0b00101110_00100000_01100000_00000000 = [VStatic(BYTE, 8), VStatic(WORD, 8), VStatic(WORD, 8)] => [R(0), R(5), R(16)];
+    0b00101110_01100000_01100000_00000000 = [VStatic(WORD, 4), VStatic(DWORD, 4), VStatic(DWORD, 4)] => [R(0), R(5), R(16)];
+    0b00101110_10100000_01100000_00000000 = [VStatic(DWORD, 2), VStatic(QWORD, 2), VStatic(QWORD, 2)] => [R(0), R(5), R(16)];
+]
+"rsubhn2" = [
+    0b01101110_00100000_01100000_00000000 = [VStatic(BYTE, 16), VStatic(WORD, 8), VStatic(WORD, 8)] => [R(0), R(5), R(16)];
+    0b01101110_01100000_01100000_00000000 = [VStatic(WORD, 8), VStatic(DWORD, 4), VStatic(DWORD, 4)] => [R(0), R(5), R(16)];
+    0b01101110_10100000_01100000_00000000 = [VStatic(DWORD, 4), VStatic(QWORD, 2), VStatic(QWORD, 2)] => [R(0), R(5), R(16)];
+]
+"rsubhnb" = [
+    0b01000101_01100000_01111000_00000000 = [Z(BYTE), Z(WORD), Z(WORD)] => [R(0), R(5), R(16)], SVE2;
+    0b01000101_10100000_01111000_00000000 = [Z(WORD), Z(DWORD), Z(DWORD)] => [R(0), R(5), R(16)], SVE2;
+    0b01000101_11100000_01111000_00000000 = [Z(DWORD), Z(QWORD), Z(QWORD)] => [R(0), R(5), R(16)], SVE2;
+]
+"rsubhnt" = [
+    0b01000101_01100000_01111100_00000000 = [Z(BYTE), Z(WORD), Z(WORD)] => [R(0), R(5), R(16)], SVE2;
+    0b01000101_10100000_01111100_00000000 = [Z(WORD), Z(DWORD), Z(DWORD)] => [R(0), R(5), R(16)], SVE2;
+    0b01000101_11100000_01111100_00000000 = [Z(DWORD), Z(QWORD), Z(QWORD)] => [R(0), R(5), R(16)], SVE2;
+]
+"saba" = [
+    0b00001110_00100000_01111100_00000000 = [V(BYTE), V(BYTE), V(BYTE)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b00001110_01100000_01111100_00000000 = [V(WORD), V(WORD), V(WORD)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b00001110_10100000_01111100_00000000 = [V(DWORD), V(DWORD), V(DWORD)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b01000101_00000000_11111000_00000000 = [Z(BYTE), Z(BYTE), Z(BYTE)] => [R(0), R(5), R(16)], SVE2;
+    0b01000101_01000000_11111000_00000000 = [Z(WORD), Z(WORD), Z(WORD)] => [R(0), R(5), R(16)], SVE2;
+    0b01000101_10000000_11111000_00000000 = [Z(DWORD), Z(DWORD), Z(DWORD)] => [R(0), R(5), R(16)], SVE2;
+    0b01000101_11000000_11111000_00000000 = [Z(QWORD), Z(QWORD), Z(QWORD)] => [R(0), R(5), R(16)], SVE2;
+]
+"sabal" = [
+    0b00001110_00100000_01010000_00000000 = [VStatic(WORD, 8), VStatic(BYTE, 8), VStatic(BYTE, 8)] => [R(0), R(5), R(16)];
+    0b00001110_01100000_01010000_00000000 = [VStatic(DWORD, 4), VStatic(WORD, 4), VStatic(WORD, 4)] => [R(0), R(5), R(16)];
+    0b00001110_10100000_01010000_00000000 = [VStatic(QWORD, 2), VStatic(DWORD, 2), VStatic(DWORD, 2)] => [R(0), R(5), R(16)];
+]
+"sabal2" = [
+    0b01001110_00100000_01010000_00000000 = [VStatic(WORD, 8), VStatic(BYTE, 16), VStatic(BYTE, 16)] => [R(0), R(5), R(16)];
+    0b01001110_01100000_01010000_00000000 = [VStatic(DWORD, 4), VStatic(WORD, 8), VStatic(WORD, 8)] => [R(0), R(5), R(16)];
+    0b01001110_10100000_01010000_00000000 = [VStatic(QWORD, 2), VStatic(DWORD, 4), VStatic(DWORD, 4)] => [R(0), R(5), R(16)];
+]
+"sabalb" = [
+    0b01000101_01000000_11000000_00000000 = [Z(WORD), Z(BYTE), Z(BYTE)] => [R(0), R(5), R(16)], SVE2;
+    0b01000101_10000000_11000000_00000000 = [Z(DWORD), Z(WORD), Z(WORD)] => [R(0), R(5), R(16)], SVE2;
+    0b01000101_11000000_11000000_00000000 = [Z(QWORD), Z(DWORD), Z(DWORD)] => [R(0), R(5), R(16)], SVE2;
+]
+"sabalt" = [
+    0b01000101_01000000_11000100_00000000 = [Z(WORD), Z(BYTE), Z(BYTE)] => [R(0), R(5), R(16)], SVE2;
+    0b01000101_10000000_11000100_00000000 = [Z(DWORD), Z(WORD), Z(WORD)] => [R(0), R(5), R(16)], SVE2;
+    0b01000101_11000000_11000100_00000000 = [Z(QWORD), Z(DWORD), Z(DWORD)] => [R(0), R(5), R(16)], SVE2;
+]
+"sabd" = [
+    // SABD
+    0b00001110_00100000_01110100_00000000 = [V(BYTE), V(BYTE), V(BYTE)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b00001110_01100000_01110100_00000000 = [V(WORD), V(WORD), V(WORD)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b00001110_10100000_01110100_00000000 = [V(DWORD), V(DWORD), V(DWORD)] => [R(0), R(5), R(16), Rwidth(30)];
+    // SABD (vectors, predicated)
+    0b00000100_00001100_00000000_00000000 = [Z(BYTE), PMerge, Z(BYTE), Z(BYTE)] => [R(0), R3(10), RTied, R(5)], SVE;
+    0b00000100_01001100_00000000_00000000 = [Z(WORD), PMerge, Z(WORD), Z(WORD)] => [R(0), R3(10), RTied, R(5)], SVE;
+    0b00000100_10001100_00000000_00000000 = [Z(DWORD), PMerge, Z(DWORD), Z(DWORD)] => [R(0), R3(10), RTied, R(5)], SVE;
+    0b00000100_11001100_00000000_00000000 = [Z(QWORD), PMerge, Z(QWORD), Z(QWORD)] => [R(0), R3(10), RTied, R(5)], SVE;
+]
+"sabdl" = [
+    0b00001110_00100000_01110000_00000000 = [VStatic(WORD, 8), VStatic(BYTE, 8), VStatic(BYTE, 8)] => [R(0), R(5), R(16)];
+    0b00001110_01100000_01110000_00000000 = [VStatic(DWORD, 4), VStatic(WORD, 4), VStatic(WORD, 4)] => [R(0), R(5), R(16)];
+    0b00001110_10100000_01110000_00000000 = [VStatic(QWORD, 2), VStatic(DWORD, 2), VStatic(DWORD, 2)] => [R(0), R(5), R(16)];
+]
+"sabdl2" = [
+    0b01001110_00100000_01110000_00000000 = [VStatic(WORD, 8), VStatic(BYTE, 16), VStatic(BYTE, 16)] => [R(0), R(5), R(16)];
+    0b01001110_01100000_01110000_00000000 = [VStatic(DWORD, 4), VStatic(WORD, 8), VStatic(WORD, 8)] => [R(0), R(5), R(16)];
+    0b01001110_10100000_01110000_00000000 = [VStatic(QWORD, 2), VStatic(DWORD, 4), VStatic(DWORD, 4)] => [R(0), R(5), R(16)];
+]
+"sabdlb" = [
+    0b01000101_01000000_00110000_00000000 = [Z(WORD), Z(BYTE), Z(BYTE)] => [R(0), R(5), R(16)], SVE2;
+    0b01000101_10000000_00110000_00000000 = [Z(DWORD), Z(WORD), Z(WORD)] => [R(0), R(5), R(16)], SVE2;
+    0b01000101_11000000_00110000_00000000 = [Z(QWORD), Z(DWORD), Z(DWORD)] => [R(0), R(5), R(16)], SVE2;
+]
+"sabdlt" = [
+    0b01000101_01000000_00110100_00000000 = [Z(WORD), Z(BYTE), Z(BYTE)] => [R(0), R(5), R(16)], SVE2;
+    0b01000101_10000000_00110100_00000000 = [Z(DWORD), Z(WORD), Z(WORD)] => [R(0), R(5), R(16)], SVE2;
+    0b01000101_11000000_00110100_00000000 = [Z(QWORD), Z(DWORD), Z(DWORD)] => [R(0), R(5), R(16)], SVE2;
+]
+"sadalp" = [
+    0b00001110_00100000_01101000_00000000 = [V(WORD), V(BYTE)] => [R(0), R(5), Rwidth(30)];
+    0b00001110_01100000_01101000_00000000 = [V(DWORD), V(WORD)] => [R(0), R(5), Rwidth(30)];
+    0b00001110_10100000_01101000_00000000 = [V(QWORD), V(DWORD)] => [R(0), R(5), Rwidth(30)];
+    0b01000100_01000100_10100000_00000000 = [Z(WORD), PMerge, Z(BYTE)] => [R(0), R3(10), R(5)], SVE2;
+    0b01000100_10000100_10100000_00000000 = [Z(DWORD), PMerge, Z(WORD)] => [R(0), R3(10), R(5)], SVE2;
+    0b01000100_11000100_10100000_00000000 = [Z(QWORD), PMerge, Z(DWORD)] => [R(0), R3(10), R(5)], SVE2;
+]
+"saddl" = [
+    0b00001110_00100000_00000000_00000000 = [VStatic(WORD, 8), VStatic(BYTE, 8), VStatic(BYTE, 8)] => [R(0), R(5), R(16)];
+    0b00001110_01100000_00000000_00000000 = [VStatic(DWORD, 4), VStatic(WORD, 4), VStatic(WORD, 4)] => [R(0), R(5), R(16)];
+    0b00001110_10100000_00000000_00000000 = [VStatic(QWORD, 2), VStatic(DWORD, 2), VStatic(DWORD, 2)] => [R(0), R(5), R(16)];
+]
+"saddl2" = [
+    0b01001110_00100000_00000000_00000000 = [VStatic(WORD, 8), VStatic(BYTE, 16), VStatic(BYTE, 16)] => [R(0), R(5), R(16)];
+    0b01001110_01100000_00000000_00000000 = [VStatic(DWORD, 4), VStatic(WORD, 8), VStatic(WORD, 8)] => [R(0), R(5), R(16)];
+    0b01001110_10100000_00000000_00000000 = [VStatic(QWORD, 2), VStatic(DWORD, 4), VStatic(DWORD, 4)] => [R(0), R(5), R(16)];
+]
+"saddlb" = [
+    0b01000101_01000000_00000000_00000000 = [Z(WORD), Z(BYTE), Z(BYTE)] => [R(0), R(5), R(16)], SVE2;
+    0b01000101_10000000_00000000_00000000 = [Z(DWORD), Z(WORD), Z(WORD)] => [R(0), R(5), R(16)], SVE2;
+    0b01000101_11000000_00000000_00000000 = [Z(QWORD), Z(DWORD), Z(DWORD)] => [R(0), R(5), R(16)], SVE2;
+]
+"saddlp" = [
+    0b00001110_00100000_00101000_00000000 = [V(WORD), V(BYTE)] => [R(0), R(5), Rwidth(30)];
+    0b00001110_01100000_00101000_00000000 = [V(DWORD), V(WORD)] => [R(0), R(5), Rwidth(30)];
+    0b00001110_10100000_00101000_00000000 = [V(QWORD), V(DWORD)] => [R(0), R(5), Rwidth(30)];
+]
+"saddlt" = [
+    0b01000101_01000000_00000100_00000000 = [Z(WORD), Z(BYTE), Z(BYTE)] => [R(0), R(5), R(16)], SVE2;
+    0b01000101_10000000_00000100_00000000 = [Z(DWORD), Z(WORD), Z(WORD)] => [R(0), R(5), R(16)], SVE2;
+    0b01000101_11000000_00000100_00000000 = [Z(QWORD), Z(DWORD), Z(DWORD)] => [R(0), R(5), R(16)], SVE2;
+]
+"saddlv" = [
+    0b00001110_00110000_00111000_00000000 = [H, V(BYTE)] => [R(0), R(5), Rwidth(30)];
+    0b00001110_01110000_00111000_00000000 = [S, V(WORD)] => [R(0), R(5), Rwidth(30)];
+    0b00001110_10110000_00111000_00000000 = [D, VStatic(DWORD, 4)] => [R(0), R(5), Rwidth(30)];
+]
+"saddv" = [
+    0b00000100_00000000_00100000_00000000 = [D, PBare, Z(BYTE)] => [R(0), R3(10), R(5)], SVE;
+    0b00000100_01000000_00100000_00000000 = [D, PBare, Z(WORD)] => [R(0), R3(10), R(5)], SVE;
+    0b00000100_10000000_00100000_00000000 = [D, PBare, Z(DWORD)] => [R(0), R3(10), R(5)], SVE;
+]
+"saddw" = [
+    0b00001110_00100000_00010000_00000000 = [VStatic(WORD, 8), VStatic(WORD, 8), VStatic(BYTE, 8)] => [R(0), R(5), R(16)];
+    0b00001110_01100000_00010000_00000000 = [VStatic(DWORD, 4), VStatic(DWORD, 4), VStatic(WORD, 4)] => [R(0), R(5), R(16)];
+    0b00001110_10100000_00010000_00000000 = [VStatic(QWORD, 2), VStatic(QWORD, 2), VStatic(DWORD, 2)] => [R(0), R(5), R(16)];
+]
+"saddw2" = [
+    0b01001110_00100000_00010000_00000000 = [VStatic(WORD, 8), VStatic(WORD, 8), VStatic(BYTE, 16)] => [R(0), R(5), R(16)];
+    0b01001110_01100000_00010000_00000000 = [VStatic(DWORD, 4), VStatic(DWORD, 4), VStatic(WORD, 8)] => [R(0), R(5), R(16)];
+    0b01001110_10100000_00010000_00000000 = [VStatic(QWORD, 2), VStatic(QWORD, 2), VStatic(DWORD, 4)] => [R(0), R(5), R(16)];
+]
+"saddwb" = [
+    0b01000101_01000000_01000000_00000000 = [Z(WORD), Z(WORD), Z(BYTE)] => [R(0), R(5), R(16)], SVE2;
+    0b01000101_10000000_01000000_00000000 = [Z(DWORD), Z(DWORD), Z(WORD)] => [R(0), R(5), R(16)], SVE2;
+    0b01000101_11000000_01000000_00000000 = [Z(QWORD), Z(QWORD), Z(DWORD)] => [R(0), R(5), R(16)], SVE2;
+]
+"saddwt" = [
+    0b01000101_01000000_01000100_00000000 = [Z(WORD), Z(WORD), Z(BYTE)] => [R(0), R(5), R(16)], SVE2;
+    0b01000101_10000000_01000100_00000000 = [Z(DWORD), Z(DWORD), Z(WORD)] => [R(0), R(5), R(16)], SVE2;
+    0b01000101_11000000_01000100_00000000 = [Z(QWORD), Z(QWORD), Z(DWORD)] => [R(0), R(5), R(16)], SVE2;
+]
+"sb" = [
+    0b11010101_00000011_00110000_11111111 = [] => [], SB;
+]
+"sbc" = [
+    0b01011010_00000000_00000000_00000000 = [W, W, W] => [R(0), R(5), R(16)];
+    0b11011010_00000000_00000000_00000000 = [X, X, X] => [R(0), R(5), R(16)];
+]
+"sbclb" = [
+    0b01000101_10000000_11010000_00000000 = [Z(DWORD), Z(DWORD), Z(DWORD)] => [R(0), R(5), R(16)], SVE2;
+    0b01000101_11000000_11010000_00000000 = [Z(QWORD), Z(QWORD), Z(QWORD)] => [R(0), R(5), R(16)], SVE2;
+]
+"sbclt" = [
+    0b01000101_10000000_11010100_00000000 = [Z(DWORD), Z(DWORD), Z(DWORD)] => [R(0), R(5), R(16)], SVE2;
+    0b01000101_11000000_11010100_00000000 = [Z(QWORD), Z(QWORD), Z(QWORD)] => [R(0), R(5), R(16)], SVE2;
+]
+"sbcs" = [
+    0b01111010_00000000_00000000_00000000 = [W, W, W] => [R(0), R(5), R(16)];
+    0b11111010_00000000_00000000_00000000 = [X, X, X] => [R(0), R(5), R(16)];
+]
+"sbfiz" = [
+    0b00010011_00000000_00000000_00000000 = [W, W, Imm, Imm] => [R(0), R(5), Unegmod(16, 5), BUsum(5), Urange(10, 1, 32)];
+    0b10010011_01000000_00000000_00000000 = [X, X, Imm, Imm] => [R(0), R(5), Unegmod(16, 6), BUsum(6), Urange(10, 1, 64)];
+]
+"sbfm" = [
+    0b00010011_00000000_00000000_00000000 = [W, W, Imm, Imm] => [R(0), R(5), Ubits(16, 5), Ubits(10, 5)];
+    0b10010011_01000000_00000000_00000000 = [X, X, Imm, Imm] => [R(0), R(5), Ubits(16, 6), BUsum(6), Ubits(10, 6)];
+]
+"sbfx" = [
+    0b00010011_00000000_00000000_00000000 = [W, W, Imm, Imm] => [R(0), R(5), Ubits(16, 5), BUsum(5), Usumdec(10, 5)];
+    0b10010011_01000000_00000000_00000000 = [X, X, Imm, Imm] => [R(0), R(5), Ubits(16, 6), BUsum(6), Usumdec(10, 6)];
+]
+"sclamp" = [
+    0b01000100_00000000_11000000_00000000 = [Z(BYTE), Z(BYTE), Z(BYTE)] => [R(0), R(5), R(16)], SME;
+    0b01000100_01000000_11000000_00000000 = [Z(WORD), Z(WORD), Z(WORD)] => [R(0), R(5), R(16)], SME;
+    0b01000100_10000000_11000000_00000000 = [Z(DWORD), Z(DWORD), Z(DWORD)] => [R(0), R(5), R(16)], SME;
+    0b01000100_11000000_11000000_00000000 = [Z(QWORD), Z(QWORD), Z(QWORD)] => [R(0), R(5), R(16)], SME;
+]
+"scvtf" = [
+    // SCVTF (vector, fixed-point)
+    0b01011111_00000000_11100100_00000000 = [H, H, Imm] => [R(0), R(5), BUrange(1, 16), Usub(16, 5, 32)], FP16;
+    0b01011111_00000000_11100100_00000000 = [S, S, Imm] => [R(0), R(5), BUrange(1, 32), Usub(16, 6, 64)];
+    0b01011111_00000000_11100100_00000000 = [D, D, Imm] => [R(0), R(5), BUrange(1, 64), Usub(16, 7, 128)];
+    0b00001111_00010000_11100100_00000000 = [V(WORD), V(WORD), Imm] => [R(0), R(5), Usub(16, 4, 16), Rwidth(30)], FP16;
+    0b00001111_00100000_11100100_00000000 = [V(DWORD), V(DWORD), Imm] => [R(0), R(5), Usub(16, 5, 32), Rwidth(30)];
+    0b00001111_01000000_11100100_00000000 = [VStatic(QWORD, 2), VStatic(QWORD, 2), Imm] => [R(0), R(5), Usub(16, 6, 64), Rwidth(30)];
+    // SCVTF (vector, integer)
+    0b01011110_01111001_11011000_00000000 = [H, H] => [R(0), R(5)], FP16;
+    0b01011110_00100001_11011000_00000000 = [S, S] => [R(0), R(5)];
+    0b01011110_01100001_11011000_00000000 = [D, D] => [R(0), R(5)];
+    0b00001110_01111001_11011000_00000000 = [V(WORD), V(WORD)] => [R(0), R(5), Rwidth(30)], FP16;
+    0b00001110_00100001_11011000_00000000 = [V(DWORD), V(DWORD)] => [R(0), R(5), Rwidth(30)];
+    0b00001110_01100001_11011000_00000000 = [VStatic(QWORD, 2), VStatic(QWORD, 2)] => [R(0), R(5), Rwidth(30)];
+    // SCVTF (scalar, fixed-point)
+    0b00011110_11000010_00000000_00000000 = [H, W, Imm] => [R(0), R(5), BUrange(1, 32), Usub(10, 6, 64)], FP16;
+    0b00011110_00000010_00000000_00000000 = [S, W, Imm] => [R(0), R(5), BUrange(1, 32), Usub(10, 6, 64)];
+    0b00011110_01000010_00000000_00000000 = [D, W, Imm] => [R(0), R(5), BUrange(1, 32), Usub(10, 6, 64)];
+    0b10011110_11000010_00000000_00000000 = [H, X, Imm] => [R(0), R(5), Usub(10, 6, 64)], FP16;
+    0b10011110_00000010_00000000_00000000 = [S, X, Imm] => [R(0), R(5), Usub(10, 6, 64)];
+    0b10011110_01000010_00000000_00000000 = [D, X, Imm] => [R(0), R(5), Usub(10, 6, 64)];
+    // SCVTF (scalar, integer)
+    0b00011110_11100010_00000000_00000000 = [H, W] => [R(0), R(5)], FP16;
+    0b00011110_00100010_00000000_00000000 = [S, W] => [R(0), R(5)];
+    0b00011110_01100010_00000000_00000000 = [D, W] => [R(0), R(5)];
+    0b10011110_11100010_00000000_00000000 = [H, X] => [R(0), R(5)], FP16;
+    0b10011110_00100010_00000000_00000000 = [S, X] => [R(0), R(5)];
+    0b10011110_01100010_00000000_00000000 = [D, X] => [R(0), R(5)];
+    // SCVTF
+    0b01100101_11010110_10100000_00000000 = [Z(QWORD), PMerge, Z(QWORD)] => [R(0), R3(10), R(5)], SVE;
+    0b01100101_11010000_10100000_00000000 = [Z(QWORD), PMerge, Z(DWORD)] => [R(0), R3(10), R(5)], SVE;
+    0b01100101_01010110_10100000_00000000 = [Z(WORD), PMerge, Z(QWORD)] => [R(0), R3(10), R(5)], SVE;
+    0b01100101_01010010_10100000_00000000 = [Z(WORD), PMerge, Z(WORD)] => [R(0), R3(10), R(5)], SVE;
+    0b01100101_01010100_10100000_00000000 = [Z(WORD), PMerge, Z(DWORD)] => [R(0), R3(10), R(5)], SVE;
+    0b01100101_11010100_10100000_00000000 = [Z(DWORD), PMerge, Z(QWORD)] => [R(0), R3(10), R(5)], SVE;
+    0b01100101_10010100_10100000_00000000 = [Z(DWORD), PMerge, Z(DWORD)] => [R(0), R3(10), R(5)], SVE;
+]
+"sdiv" = [
+    0b00011010_11000000_00001100_00000000 = [W, W, W] => [R(0), R(5), R(16)];
+    0b10011010_11000000_00001100_00000000 = [X, X, X] => [R(0), R(5), R(16)];
+    0b00000100_10010100_00000000_00000000 = [Z(DWORD), PMerge, Z(DWORD), Z(DWORD)] => [R(0), R3(10), RTied, R(5)], SVE;
+    0b00000100_11010100_00000000_00000000 = [Z(QWORD), PMerge, Z(QWORD), Z(QWORD)] => [R(0), R3(10), RTied, R(5)], SVE;
+]
+"sdivr" = [
+    0b00000100_10010110_00000000_00000000 = [Z(DWORD), PMerge, Z(DWORD), Z(DWORD)] => [R(0), R3(10), RTied, R(5)], SVE;
+    0b00000100_11010110_00000000_00000000 = [Z(QWORD), PMerge, Z(QWORD), Z(QWORD)] => [R(0), R3(10), RTied, R(5)], SVE;
+]
+"sdot" = [
+    // SDOT (by element)
+    0b00001111_10000000_11100000_00000000 = [VStatic(DWORD, 2), VStatic(BYTE, 8), VStaticElement(BYTE, 4)] => [R(0), R(5), R(16), Ufields(&[11, 21])], DOTPROD;
+    0b01001111_10000000_11100000_00000000 = [VStatic(DWORD, 4), VStatic(BYTE, 16), VStaticElement(BYTE, 4)] => [R(0), R(5), R(16), Ufields(&[11, 21])], DOTPROD;
+    // SDOT (vector)
+    0b00001110_10000000_10010100_00000000 = [VStatic(DWORD, 2), VStatic(BYTE, 8), VStatic(BYTE, 8)] => [R(0), R(5), R(16)], DOTPROD;
+    0b01001110_10000000_10010100_00000000 = [VStatic(DWORD, 4), VStatic(BYTE, 16), VStatic(BYTE, 16)] => [R(0), R(5), R(16)], DOTPROD;
+    // SDOT (vectors)
+    0b01000100_10000000_00000000_00000000 = [Z(DWORD), Z(BYTE), Z(BYTE)] => [R(0), R(5), R(16)], SVE;
+    0b01000100_11000000_00000000_00000000 = [Z(QWORD), Z(WORD), Z(WORD)] => [R(0), R(5), R(16)], SVE;
+    // SDOT (indexed)
+    0b01000100_11100000_00000000_00000000 = [Z(QWORD), Z(WORD), ZElement(WORD)] => [R(0), R(5), R4(16), Ubits(20, 1)], SVE;
+    0b01000100_10100000_00000000_00000000 = [Z(DWORD), Z(BYTE), ZElement(BYTE)] => [R(0), R(5), R3(16), Ubits(19, 2)], SVE;
+]
+"sel" = [
+    // SEL (predicates)
+    0b00100101_00000000_01000010_00010000 = [P(BYTE), PBare, P(BYTE), P(BYTE)] => [R4(0), R4(10), R4(5), R4(16)], SVE;
+    // SEL (vectors)
+    0b00000101_00100000_11000000_00000000 = [Z(BYTE), PBare, Z(BYTE), Z(BYTE)] => [R(0), R4(10), R(5), R(16)], SVE;
+    0b00000101_01100000_11000000_00000000 = [Z(WORD), PBare, Z(WORD), Z(WORD)] => [R(0), R4(10), R(5), R(16)], SVE;
+    0b00000101_10100000_11000000_00000000 = [Z(DWORD), PBare, Z(DWORD), Z(DWORD)] => [R(0), R4(10), R(5), R(16)], SVE;
+    0b00000101_11100000_11000000_00000000 = [Z(QWORD), PBare, Z(QWORD), Z(QWORD)] => [R(0), R4(10), R(5), R(16)], SVE;
+]
+"setf16" = [
+    0b00111010_00000000_01001000_00001101 = [W] => [R(5)], FLAGM;
+]
+"setf8" = [
+    0b00111010_00000000_00001000_00001101 = [W] => [R(5)], FLAGM;
+]
+"setffr" = [
+    0b00100101_00101100_10010000_00000000 = [] => [], SVE;
+]
+"sev" = [
+    0b11010101_00000011_00100000_10011111 = [] => [];
+]
+"sevl" = [
+    0b11010101_00000011_00100000_10111111 = [] => [];
+]
+"sha1c" = [
+    0b01011110_00000000_00000000_00000000 = [Q, S, VStatic(DWORD, 4)] => [R(0), R(5), R(16)], SHA2;
+]
+"sha1h" = [
+    0b01011110_00101000_00001000_00000000 = [S, S] => [R(0), R(5)], SHA2;
+]
+"sha1m" = [
+    0b01011110_00000000_00100000_00000000 = [Q, S, VStatic(DWORD, 4)] => [R(0), R(5), R(16)], SHA2;
+]
+"sha1p" = [
+    0b01011110_00000000_00010000_00000000 = [Q, S, VStatic(DWORD, 4)] => [R(0), R(5), R(16)], SHA2;
+]
+"sha1su0" = [
+    0b01011110_00000000_00110000_00000000 = [VStatic(DWORD, 4), VStatic(DWORD, 4), VStatic(DWORD, 4)] => [R(0), R(5), R(16)], SHA2;
+]
+"sha1su1" = [
+    0b01011110_00101000_00011000_00000000 = [VStatic(DWORD, 4), VStatic(DWORD, 4)] => [R(0), R(5)], SHA2;
+]
+"sha256h" = [
+    0b01011110_00000000_01000000_00000000 = [Q, Q, VStatic(DWORD, 4)] => [R(0), R(5), R(16)], SHA2;
+]
+"sha256h2" = [
+    0b01011110_00000000_01010000_00000000 = [Q, Q, VStatic(DWORD, 4)] => [R(0), R(5), R(16)], SHA2;
+]
+"sha256su0" = [
+    0b01011110_00101000_00101000_00000000 = [VStatic(DWORD, 4), VStatic(DWORD, 4)] => [R(0), R(5)], SHA2;
+]
+"sha256su1" = [
+    0b01011110_00000000_01100000_00000000 = [VStatic(DWORD, 4), VStatic(DWORD, 4), VStatic(DWORD, 4)] => [R(0), R(5), R(16)], SHA2;
+]
+"sha512h" = [
+    0b11001110_01100000_10000000_00000000 = [Q, Q, VStatic(QWORD, 2)] => [R(0), R(5), R(16)], SHA3;
+]
+"sha512h2" = [
+    0b11001110_01100000_10000100_00000000 = [Q, Q, VStatic(QWORD, 2)] => [R(0), R(5), R(16)], SHA3;
+]
+"sha512su0" = [
+    0b11001110_11000000_10000000_00000000 = [VStatic(QWORD, 2), VStatic(QWORD, 2)] => [R(0), R(5)], SHA3;
+]
+"sha512su1" = [
+    0b11001110_01100000_10001000_00000000 = [VStatic(QWORD, 2), VStatic(QWORD, 2), VStatic(QWORD, 2)] => [R(0), R(5), R(16)], SHA3;
+]
+"shadd" = [
+    // SHADD
+    0b00001110_00100000_00000100_00000000 = [V(BYTE), V(BYTE), V(BYTE)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b00001110_01100000_00000100_00000000 = [V(WORD), V(WORD), V(WORD)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b00001110_10100000_00000100_00000000 = [V(DWORD), V(DWORD), V(DWORD)] => [R(0), R(5), R(16), Rwidth(30)];
+    // SHADD (vectors, predicated)
+    0b01000100_00010000_10000000_00000000 = [Z(BYTE), PMerge, Z(BYTE), Z(BYTE)] => [R(0), R3(10), RTied, R(5)], SVE2;
+    0b01000100_01010000_10000000_00000000 = [Z(WORD), PMerge, Z(WORD), Z(WORD)] => [R(0), R3(10), RTied, R(5)], SVE2;
+    0b01000100_10010000_10000000_00000000 = [Z(DWORD), PMerge, Z(DWORD), Z(DWORD)] => [R(0), R3(10), RTied, R(5)], SVE2;
+    0b01000100_11010000_10000000_00000000 = [Z(QWORD), PMerge, Z(QWORD), Z(QWORD)] => [R(0), R3(10), RTied, R(5)], SVE2;
+]
+"shl" = [
+    0b01011111_01000000_01010100_00000000 = [D, D, Imm] => [R(0), R(5), Ubits(16, 6)];
+    0b00001111_00001000_01010100_00000000 = [V(BYTE), V(BYTE), Imm] => [R(0), R(5), Ubits(16, 3), Rwidth(30)];
+    0b00001111_00010000_01010100_00000000 = [V(WORD), V(WORD), Imm] => [R(0), R(5), Ubits(16, 4), Rwidth(30)];
+    0b00001111_00100000_01010100_00000000 = [V(DWORD), V(DWORD), Imm] => [R(0), R(5), Ubits(16, 5), Rwidth(30)];
+    0b00001111_01000000_01010100_00000000 = [VStatic(QWORD, 2), VStatic(QWORD, 2), Imm] => [R(0), R(5), Ubits(16, 6), Rwidth(30)];
+]
+"shll" = [
+    0b00101110_00100001_00111000_00000000 = [VStatic(WORD, 8), VStatic(BYTE, 8), LitInt(8)] => [R(0), R(5)];
+    0b00101110_01100001_00111000_00000000 = [VStatic(DWORD, 4), VStatic(WORD, 4), LitInt(16)] => [R(0), R(5)];
+    0b00101110_10100001_00111000_00000000 = [VStatic(QWORD, 2), VStatic(DWORD, 2), LitInt(32)] => [R(0), R(5)];
+]
+"shll2" = [
+    0b01101110_00100001_00111000_00000000 = [VStatic(WORD, 8), VStatic(BYTE, 16), LitInt(8)] => [R(0), R(5)];
+    0b01101110_01100001_00111000_00000000 = [VStatic(DWORD, 4), VStatic(WORD, 8), LitInt(16)] => [R(0), R(5)];
+    0b01101110_10100001_00111000_00000000 = [VStatic(QWORD, 2), VStatic(DWORD, 4), LitInt(32)] => [R(0), R(5)];
+]
+"shrn" = [
+    0b00001111_00001000_10000100_00000000 = [VStatic(BYTE, 8), VStatic(WORD, 8), Imm] => [R(0), R(5), Usub(16, 3, 8)];
+    0b00001111_00010000_10000100_00000000 = [VStatic(WORD, 4), VStatic(DWORD, 4), Imm] => [R(0), R(5), Usub(16, 4, 16)];
+    0b00001111_00100000_10000100_00000000 = [VStatic(DWORD, 2), VStatic(QWORD, 2), Imm] => [R(0), R(5), Usub(16, 5, 32)];
+]
+"shrn2" = [
+    0b01001111_00001000_10000100_00000000 = [VStatic(BYTE, 16), VStatic(WORD, 8), Imm] => [R(0), R(5), Usub(16, 3, 8)];
+    0b01001111_00010000_10000100_00000000 = [VStatic(WORD, 8), VStatic(DWORD, 4), Imm] => [R(0), R(5), Usub(16, 4, 16)];
+    0b01001111_00100000_10000100_00000000 = [VStatic(DWORD, 4), VStatic(QWORD, 2), Imm] => [R(0), R(5), Usub(16, 5, 32)];
+]
+"shrnb" = [
+    0b01000101_00101000_00010000_00000000 = [Z(BYTE), Z(WORD), Imm] => [R(0), R(5), Usub(16, 3, 8)], SVE2;
+    0b01000101_00110000_00010000_00000000 = [Z(WORD), Z(DWORD), Imm] => [R(0), R(5), Usub(16, 4, 16)], SVE2;
+    0b01000101_01100000_00010000_00000000 = [Z(DWORD), Z(QWORD), Imm] => [R(0), R(5), Usub(16, 5, 32)], SVE2;
+]
+"shrnt" = [
+    0b01000101_00101000_00010100_00000000 = [Z(BYTE), Z(WORD), Imm] => [R(0), R(5), Usub(16, 3, 8)], SVE2;
+    0b01000101_00110000_00010100_00000000 = [Z(WORD), Z(DWORD), Imm] => [R(0), R(5), Usub(16, 4, 16)], SVE2;
+    0b01000101_01100000_00010100_00000000 = [Z(DWORD), Z(QWORD), Imm] => [R(0), R(5), Usub(16, 5, 32)], SVE2;
+]
+"shsub" = [
+    // SHSUB
+    0b00001110_00100000_00100100_00000000 = [V(BYTE), V(BYTE), V(BYTE)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b00001110_01100000_00100100_00000000 = [V(WORD), V(WORD), V(WORD)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b00001110_10100000_00100100_00000000 = [V(DWORD), V(DWORD), V(DWORD)] => [R(0), R(5), R(16), Rwidth(30)];
+    // SHSUB (vectors, predicated)
+    0b01000100_00010010_10000000_00000000 = [Z(BYTE), PMerge, Z(BYTE), Z(BYTE)] => [R(0), R3(10), RTied, R(5)], SVE2;
+    0b01000100_01010010_10000000_00000000 = [Z(WORD), PMerge, Z(WORD), Z(WORD)] => [R(0), R3(10), RTied, R(5)], SVE2;
+    0b01000100_10010010_10000000_00000000 = [Z(DWORD), PMerge, Z(DWORD), Z(DWORD)] => [R(0), R3(10), RTied, R(5)], SVE2;
+    0b01000100_11010010_10000000_00000000 = [Z(QWORD), PMerge, Z(QWORD), Z(QWORD)] => [R(0), R3(10), RTied, R(5)], SVE2;
+]
+"shsubr" = [
+    0b01000100_00010110_10000000_00000000 = [Z(BYTE), PMerge, Z(BYTE), Z(BYTE)] => [R(0), R3(10), RTied, R(5)], SVE2;
+    0b01000100_01010110_10000000_00000000 = [Z(WORD), PMerge, Z(WORD), Z(WORD)] => [R(0), R3(10), RTied, R(5)], SVE2;
+    0b01000100_10010110_10000000_00000000 = [Z(DWORD), PMerge, Z(DWORD), Z(DWORD)] => [R(0), R3(10), RTied, R(5)], SVE2;
+    0b01000100_11010110_10000000_00000000 = [Z(QWORD), PMerge, Z(QWORD), Z(QWORD)] => [R(0), R3(10), RTied, R(5)], SVE2;
+]
+"sli" = [
+    0b01111111_01000000_01010100_00000000 = [D, D, Imm] => [R(0), R(5), Ubits(16, 6)];
+    0b00101111_00001000_01010100_00000000 = [V(BYTE), V(BYTE), Imm] => [R(0), R(5), Ubits(16, 3), Rwidth(30)];
+    0b00101111_00010000_01010100_00000000 = [V(WORD), V(WORD), Imm] => [R(0), R(5), Ubits(16, 4), Rwidth(30)];
+    0b00101111_00100000_01010100_00000000 = [V(DWORD), V(DWORD), Imm] => [R(0), R(5), Ubits(16, 5), Rwidth(30)];
+    0b00101111_01000000_01010100_00000000 = [VStatic(QWORD, 2), VStatic(QWORD, 2), Imm] => [R(0), R(5), Ubits(16, 6), Rwidth(30)];
+    0b01000101_00001000_11110100_00000000 = [Z(BYTE), Z(BYTE), Imm] => [R(0), R(5), Ubits(16, 3)], SVE2;
+    0b01000101_00010000_11110100_00000000 = [Z(WORD), Z(WORD), Imm] => [R(0), R(5), Ubits(16, 4)], SVE2;
+    0b01000101_01000000_11110100_00000000 = [Z(DWORD), Z(DWORD), Imm] => [R(0), R(5), Ubits(16, 5)], SVE2;
+    0b01000101_10000000_11110100_00000000 = [Z(QWORD), Z(QWORD), Imm] => [R(0), R(5), BUbits(6), Uslice(16, 5, 0), Uslice(22, 1, 5), A], SVE2;
+]
+"sm3partw1" = [
+    0b11001110_01100000_11000000_00000000 = [VStatic(DWORD, 4), VStatic(DWORD, 4), VStatic(DWORD, 4)] => [R(0), R(5), R(16)], SM4;
+]
+"sm3partw2" = [
+    0b11001110_01100000_11000100_00000000 = [VStatic(DWORD, 4), VStatic(DWORD, 4), VStatic(DWORD, 4)] => [R(0), R(5), R(16)], SM4;
+]
+"sm3ss1" = [
+    0b11001110_01000000_00000000_00000000 = [VStatic(DWORD, 4), VStatic(DWORD, 4), VStatic(DWORD, 4), VStatic(DWORD, 4)] => [R(0), R(5), R(16), R(10)], SM4;
+]
+"sm3tt1a" = [
+    0b11001110_01000000_10000000_00000000 = [VStatic(DWORD, 4), VStatic(DWORD, 4), VElement(DWORD)] => [R(0), R(5), R(16), Ubits(12, 2)], SM4;
+]
+"sm3tt1b" = [
+    0b11001110_01000000_10000100_00000000 = [VStatic(DWORD, 4), VStatic(DWORD, 4), VElement(DWORD)] => [R(0), R(5), R(16), Ubits(12, 2)], SM4;
+]
+"sm3tt2a" = [
+    0b11001110_01000000_10001000_00000000 = [VStatic(DWORD, 4), VStatic(DWORD, 4), VElement(DWORD)] => [R(0), R(5), R(16), Ubits(12, 2)], SM4;
+]
+"sm3tt2b" = [
+    0b11001110_01000000_10001100_00000000 = [VStatic(DWORD, 4), VStatic(DWORD, 4), VElement(DWORD)] => [R(0), R(5), R(16), Ubits(12, 2)], SM4;
+]
+"sm4e" = [
+    0b11001110_11000000_10000100_00000000 = [VStatic(DWORD, 4), VStatic(DWORD, 4)] => [R(0), R(5)], SM4;
+    0b01000101_00100011_11100000_00000000 = [Z(DWORD), Z(DWORD), Z(DWORD)] => [R(0), RTied, R(5)], SVE2 | SM4;
+]
+"sm4ekey" = [
+    0b11001110_01100000_11001000_00000000 = [VStatic(DWORD, 4), VStatic(DWORD, 4), VStatic(DWORD, 4)] => [R(0), R(5), R(16)], SM4;
+    0b01000101_00100000_11110000_00000000 = [Z(DWORD), Z(DWORD), Z(DWORD)] => [R(0), R(5), R(16)], SVE2 | SM4;
+]
+"smaddl" = [
+    0b10011011_00100000_00000000_00000000 = [X, W, W, X] => [R(0), R(5), R(16), R(10)];
+]
+"smax" = [
+    // SMAX
+    0b00001110_00100000_01100100_00000000 = [V(BYTE), V(BYTE), V(BYTE)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b00001110_01100000_01100100_00000000 = [V(WORD), V(WORD), V(WORD)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b00001110_10100000_01100100_00000000 = [V(DWORD), V(DWORD), V(DWORD)] => [R(0), R(5), R(16), Rwidth(30)];
+    // SMAX (vectors, predicated)
+    0b00000100_00001000_00000000_00000000 = [Z(BYTE), PMerge, Z(BYTE), Z(BYTE)] => [R(0), R3(10), RTied, R(5)], SVE;
+    0b00000100_01001000_00000000_00000000 = [Z(WORD), PMerge, Z(WORD), Z(WORD)] => [R(0), R3(10), RTied, R(5)], SVE;
+    0b00000100_10001000_00000000_00000000 = [Z(DWORD), PMerge, Z(DWORD), Z(DWORD)] => [R(0), R3(10), RTied, R(5)], SVE;
+    0b00000100_11001000_00000000_00000000 = [Z(QWORD), PMerge, Z(QWORD), Z(QWORD)] => [R(0), R3(10), RTied, R(5)], SVE;
+    // SMAX (immediate)
+    0b00100101_00101000_11000000_00000000 = [Z(BYTE), Z(BYTE), Imm] => [R(0), RTied, Sbits(5, 8)], SVE;
+    0b00100101_01101000_11000000_00000000 = [Z(WORD), Z(WORD), Imm] => [R(0), RTied, Sbits(5, 8)], SVE;
+    0b00100101_10101000_11000000_00000000 = [Z(DWORD), Z(DWORD), Imm] => [R(0), RTied, Sbits(5, 8)], SVE;
+    0b00100101_11101000_11000000_00000000 = [Z(QWORD), Z(QWORD), Imm] => [R(0), RTied, Sbits(5, 8)], SVE;
+]
+"smaxp" = [
+    // SMAXP
+    0b00001110_00100000_10100100_00000000 = [V(BYTE), V(BYTE), V(BYTE)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b00001110_01100000_10100100_00000000 = [V(WORD), V(WORD), V(WORD)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b00001110_10100000_10100100_00000000 = [V(DWORD), V(DWORD), V(DWORD)] => [R(0), R(5), R(16), Rwidth(30)];
+    // SMAXP (vectors, predicated)
+    0b01000100_00010100_10100000_00000000 = [Z(BYTE), PMerge, Z(BYTE), Z(BYTE)] => [R(0), R3(10), RTied, R(5)], SVE2;
+    0b01000100_01010100_10100000_00000000 = [Z(WORD), PMerge, Z(WORD), Z(WORD)] => [R(0), R3(10), RTied, R(5)], SVE2;
+    0b01000100_10010100_10100000_00000000 = [Z(DWORD), PMerge, Z(DWORD), Z(DWORD)] => [R(0), R3(10), RTied, R(5)], SVE2;
+    0b01000100_11010100_10100000_00000000 = [Z(QWORD), PMerge, Z(QWORD), Z(QWORD)] => [R(0), R3(10), RTied, R(5)], SVE2;
+]
+"smaxv" = [
+    // SMAXV
+    0b00001110_00110000_10101000_00000000 = [B, V(BYTE)] => [R(0), R(5), Rwidth(30)];
+    0b00001110_01110000_10101000_00000000 = [H, V(WORD)] => [R(0), R(5), Rwidth(30)];
+    0b00001110_10110000_10101000_00000000 = [S, VStatic(DWORD, 4)] => [R(0), R(5), Rwidth(30)];
+    // SMAXV (reduction)
+    0b00000100_00001000_00100000_00000000 = [B, PBare, Z(BYTE)] => [R(0), R3(10), R(5)], SVE;
+    0b00000100_01001000_00100000_00000000 = [H, PBare, Z(WORD)] => [R(0), R3(10), R(5)], SVE;
+    0b00000100_10001000_00100000_00000000 = [S, PBare, Z(DWORD)] => [R(0), R3(10), R(5)], SVE;
+    0b00000100_11001000_00100000_00000000 = [D, PBare, Z(QWORD)] => [R(0), R3(10), R(5)], SVE;
+]
+"smc" = [
+    0b11010100_00000000_00000000_00000011 = [Imm] => [Ubits(5, 16)];
+]
+"smin" = [
+    // SMIN
+    0b00001110_00100000_01101100_00000000 = [V(BYTE), V(BYTE), V(BYTE)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b00001110_01100000_01101100_00000000 = [V(WORD), V(WORD), V(WORD)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b00001110_10100000_01101100_00000000 = [V(DWORD), V(DWORD), V(DWORD)] => [R(0), R(5), R(16), Rwidth(30)];
+    // SMIN (vectors, predicated)
+    0b00000100_00001010_00000000_00000000 = [Z(BYTE), PMerge, Z(BYTE), Z(BYTE)] => [R(0), R3(10), RTied, R(5)], SVE;
+    0b00000100_01001010_00000000_00000000 = [Z(WORD), PMerge, Z(WORD), Z(WORD)] => [R(0), R3(10), RTied, R(5)], SVE;
+    0b00000100_10001010_00000000_00000000 = [Z(DWORD), PMerge, Z(DWORD), Z(DWORD)] => [R(0), R3(10), RTied, R(5)], SVE;
+    0b00000100_11001010_00000000_00000000 = [Z(QWORD), PMerge, Z(QWORD), Z(QWORD)] => [R(0), R3(10), RTied, R(5)], SVE;
+    // SMIN (immediate)
+    0b00100101_00101010_11000000_00000000 = [Z(BYTE), Z(BYTE), Imm] => [R(0), RTied, Sbits(5, 8)], SVE;
+    0b00100101_01101010_11000000_00000000 = [Z(WORD), Z(WORD), Imm] => [R(0), RTied, Sbits(5, 8)], SVE;
+    0b00100101_10101010_11000000_00000000 = [Z(DWORD), Z(DWORD), Imm] => [R(0), RTied, Sbits(5, 8)], SVE;
+    0b00100101_11101010_11000000_00000000 = [Z(QWORD), Z(QWORD), Imm] => [R(0), RTied, Sbits(5, 8)], SVE;
+]
+"sminp" = [
+    // SMINP
+    0b00001110_00100000_10101100_00000000 = [V(BYTE), V(BYTE), V(BYTE)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b00001110_01100000_10101100_00000000 = [V(WORD), V(WORD), V(WORD)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b00001110_10100000_10101100_00000000 = [V(DWORD), V(DWORD), V(DWORD)] => [R(0), R(5), R(16), Rwidth(30)];
+    // SMINP (vectors, predicated)
+    0b01000100_00010110_10100000_00000000 = [Z(BYTE), PMerge, Z(BYTE), Z(BYTE)] => [R(0), R3(10), RTied, R(5)], SVE2;
+    0b01000100_01010110_10100000_00000000 = [Z(WORD), PMerge, Z(WORD), Z(WORD)] => [R(0), R3(10), RTied, R(5)], SVE2;
+    0b01000100_10010110_10100000_00000000 = [Z(DWORD), PMerge, Z(DWORD), Z(DWORD)] => [R(0), R3(10), RTied, R(5)], SVE2;
+    0b01000100_11010110_10100000_00000000 = [Z(QWORD), PMerge, Z(QWORD), Z(QWORD)] => [R(0), R3(10), RTied, R(5)], SVE2;
+]
+"sminv" = [
+    // SMINV
+    0b00001110_00110001_10101000_00000000 = [B, V(BYTE)] => [R(0), R(5), Rwidth(30)];
+    0b00001110_01110001_10101000_00000000 = [H, V(WORD)] => [R(0), R(5), Rwidth(30)];
+    0b00001110_10110001_10101000_00000000 = [S, VStatic(DWORD, 4)] => [R(0), R(5), Rwidth(30)];
+    // SMINV (reduction)
+    0b00000100_00001010_00100000_00000000 = [B, PBare, Z(BYTE)] => [R(0), R3(10), R(5)], SVE;
+    0b00000100_01001010_00100000_00000000 = [H, PBare, Z(WORD)] => [R(0), R3(10), R(5)], SVE;
+    0b00000100_10001010_00100000_00000000 = [S, PBare, Z(DWORD)] => [R(0), R3(10), R(5)], SVE;
+    0b00000100_11001010_00100000_00000000 = [D, PBare, Z(QWORD)] => [R(0), R3(10), R(5)], SVE;
+]
+"smlal" = [
+    // SMLAL, SMLAL2 (by element)
+    0b00001111_01000000_00100000_00000000 = [VStatic(DWORD, 4), VStatic(WORD, 4), VElement(WORD)] => [R(0), R(5), R4(16), Ufields(&[11, 21, 20])];
+    0b00001111_10000000_00100000_00000000 = [VStatic(QWORD, 2), VStatic(DWORD, 2), VElement(DWORD)] => [R(0), R(5), R(16), Ufields(&[11, 21])];
+    // SMLAL, SMLAL2 (vector)
+    0b00001110_00100000_10000000_00000000 = [VStatic(WORD, 8), VStatic(BYTE, 8), VStatic(BYTE, 8)] => [R(0), R(5), R(16)];
+    0b00001110_01100000_10000000_00000000 = [VStatic(DWORD, 4), VStatic(WORD, 4), VStatic(WORD, 4)] => [R(0), R(5), R(16)];
+    0b00001110_10100000_10000000_00000000 = [VStatic(QWORD, 2), VStatic(DWORD, 2), VStatic(DWORD, 2)] => [R(0), R(5), R(16)];
+]
+"smlal2" = [
+    // SMLAL, SMLAL2 (by element)
+    0b01001111_01000000_00100000_00000000 = [VStatic(DWORD, 4), VStatic(WORD, 8), VElement(WORD)] => [R(0), R(5), R4(16), Ufields(&[11, 21, 20])];
+    0b01001111_10000000_00100000_00000000 = [VStatic(QWORD, 2), VStatic(DWORD, 4), VElement(DWORD)] => [R(0), R(5), R(16), Ufields(&[11, 21])];
+    // SMLAL, SMLAL2 (vector)
+    0b01001110_00100000_10000000_00000000 = [VStatic(WORD, 8), VStatic(BYTE, 16), VStatic(BYTE, 16)] => [R(0), R(5), R(16)];
+    0b01001110_01100000_10000000_00000000 = [VStatic(DWORD, 4), VStatic(WORD, 8), VStatic(WORD, 8)] => [R(0), R(5), R(16)];
+    0b01001110_10100000_10000000_00000000 = [VStatic(QWORD, 2), VStatic(DWORD, 4), VStatic(DWORD, 4)] => [R(0), R(5), R(16)];
+]
+"smlalb" = [
+    0b01000100_01000000_01000000_00000000 = [Z(WORD), Z(BYTE), Z(BYTE)] => [R(0), R(5), R(16)], SVE2;
+    0b01000100_10000000_01000000_00000000 = [Z(DWORD), Z(WORD), Z(WORD)] => [R(0), R(5), R(16)], SVE2;
+    0b01000100_11000000_01000000_00000000 = [Z(QWORD), Z(DWORD), Z(DWORD)] => [R(0), R(5), R(16)], SVE2;
+]
+"smlalt" = [
+    0b01000100_01000000_01000100_00000000 = [Z(WORD), Z(BYTE), Z(BYTE)] => [R(0), R(5), R(16)], SVE2;
+    0b01000100_10000000_01000100_00000000 = [Z(DWORD), Z(WORD), Z(WORD)] => [R(0), R(5), R(16)], SVE2;
+    0b01000100_11000000_01000100_00000000 = [Z(QWORD), Z(DWORD), Z(DWORD)] => [R(0), R(5), R(16)], SVE2;
+]
+"smlsl" = [
+    // SMLSL, SMLSL2 (by element)
+    0b00001111_01000000_01100000_00000000 = [VStatic(DWORD, 4), VStatic(WORD, 4), VElement(WORD)] => [R(0), R(5), R4(16), Ufields(&[11, 21, 20])];
+    0b00001111_10000000_01100000_00000000 = [VStatic(QWORD, 2), VStatic(DWORD, 2), VElement(DWORD)] => [R(0), R(5), R(16), Ufields(&[11, 21])];
+    // SMLSL, SMLSL2 (vector)
+    0b00001110_00100000_10100000_00000000 = [VStatic(WORD, 8), VStatic(BYTE, 8), VStatic(BYTE, 8)] => [R(0), R(5), R(16)];
+    0b00001110_01100000_10100000_00000000 = [VStatic(DWORD, 4), VStatic(WORD, 4), VStatic(WORD, 4)] => [R(0), R(5), R(16)];
+    0b00001110_10100000_10100000_00000000 = [VStatic(QWORD, 2), VStatic(DWORD, 2), VStatic(DWORD, 2)] => [R(0), R(5), R(16)];
+]
+"smlsl2" = [
+    // SMLSL, SMLSL2 (by element)
+    0b01001111_01000000_01100000_00000000 = [VStatic(DWORD, 4), VStatic(WORD, 8), VElement(WORD)] => [R(0), R(5), R4(16), Ufields(&[11, 21, 20])];
+    0b01001111_10000000_01100000_00000000 = [VStatic(QWORD, 2), VStatic(DWORD, 4), VElement(DWORD)] => [R(0), R(5), R(16), Ufields(&[11, 21])];
+    // SMLSL, SMLSL2 (vector)
+    0b01001110_00100000_10100000_00000000 = [VStatic(WORD, 8), VStatic(BYTE, 16), VStatic(BYTE, 16)] => [R(0), R(5), R(16)];
+    0b01001110_01100000_10100000_00000000 = [VStatic(DWORD, 4), VStatic(WORD, 8), VStatic(WORD, 8)] => [R(0), R(5), R(16)];
+    0b01001110_10100000_10100000_00000000 = [VStatic(QWORD, 2), VStatic(DWORD, 4), VStatic(DWORD, 4)] => [R(0), R(5), R(16)];
+]
+"smlslb" = [
+    0b01000100_01000000_01010000_00000000 = [Z(WORD), Z(BYTE), Z(BYTE)] => [R(0), R(5), R(16)], SVE2;
+    0b01000100_10000000_01010000_00000000 = [Z(DWORD), Z(WORD), Z(WORD)] => [R(0), R(5), R(16)], SVE2;
+    0b01000100_11000000_01010000_00000000 = [Z(QWORD), Z(DWORD), Z(DWORD)] => [R(0), R(5), R(16)], SVE2;
+]
+"smlslt" = [
+    0b01000100_01000000_01010100_00000000 = [Z(WORD), Z(BYTE), Z(BYTE)] => [R(0), R(5), R(16)], SVE2;
+    0b01000100_10000000_01010100_00000000 = [Z(DWORD), Z(WORD), Z(WORD)] => [R(0), R(5), R(16)], SVE2;
+    0b01000100_11000000_01010100_00000000 = [Z(QWORD), Z(DWORD), Z(DWORD)] => [R(0), R(5), R(16)], SVE2;
+]
+"smmla" = [
+    0b01001110_10000000_10100100_00000000 = [VStatic(DWORD, 4), VStatic(BYTE, 16), VStatic(BYTE, 16)] => [R(0), R(5), R(16)], I8MM;
+]
+"smnegl" = [
+    0b10011011_00100000_11111100_00000000 = [X, W, W] => [R(0), R(5), R(16)];
+]
+"smopa" = [
+    0b10100000_10000000_00000000_00000000 = [ZaTile(DWORD), PMerge, PMerge, Z(BYTE), Z(BYTE)] => [RTile(0, 2), R3(10), R3(13), R(5), R(16)], SME;
+    0b10100000_11000000_00000000_00000000 = [ZaTile(QWORD), PMerge, PMerge, Z(WORD), Z(WORD)] => [RTile(0, 3), R3(10), R3(13), R(5), R(16)], SME | SME_I16I64;
+]
+"smops" = [
+    0b10100000_10000000_00000000_00010000 = [ZaTile(DWORD), PMerge, PMerge, Z(BYTE), Z(BYTE)] => [RTile(0, 2), R3(10), R3(13), R(5), R(16)], SME;
+    0b10100000_11000000_00000000_00010000 = [ZaTile(QWORD), PMerge, PMerge, Z(WORD), Z(WORD)] => [RTile(0, 3), R3(10), R3(13), R(5), R(16)], SME | SME_I16I64;
+]
+"smov" = [
+    0b00001110_00000001_00101100_00000000 = [W, VElement(BYTE)] => [R(0), R(5), Ubits(17, 4)];
+    0b00001110_00000010_00101100_00000000 = [W, VElement(WORD)] => [R(0), R(5), Ubits(18, 3)];
+    0b01001110_00000001_00101100_00000000 = [X, VElement(BYTE)] => [R(0), R(5), Ubits(17, 4)];
+    0b01001110_00000010_00101100_00000000 = [X, VElement(WORD)] => [R(0), R(5), Ubits(18, 3)];
+    0b01001110_00000100_00101100_00000000 = [X, VElement(DWORD)] => [R(0), R(5), Ubits(19, 2)];
+]
+"smstart" = [
+    0b11010101_00000011_01000111_01111111 = [] => [], SME;
+    0b11010101_00000011_01000011_01111111 = [Lit("sm")] => [], SME;
+    0b11010101_00000011_01000101_01111111 = [Lit("za")] => [], SME;
+]
+"smstop" = [
+    0b11010101_00000011_01000110_01111111 = [] => [], SME;
+    0b11010101_00000011_01000010_01111111 = [Lit("sm")] => [], SME;
+    0b11010101_00000011_01000100_01111111 = [Lit("za")] => [], SME;
+]
+"smsubl" = [
+    0b10011011_00100000_10000000_00000000 = [X, W, W, X] => [R(0), R(5), R(16), R(10)];
+]
+"smulh" = [
+    // SMULH
+    0b10011011_01000000_01111100_00000000 = [X, X, X] => [R(0), R(5), R(16)];
+    // SMULH (vectors, predicated)
+    0b00000100_00010010_00000000_00000000 = [Z(BYTE), PMerge, Z(BYTE), Z(BYTE)] => [R(0), R3(10), RTied, R(5)], SVE;
+    0b00000100_01010010_00000000_00000000 = [Z(WORD), PMerge, Z(WORD), Z(WORD)] => [R(0), R3(10), RTied, R(5)], SVE;
+    0b00000100_10010010_00000000_00000000 = [Z(DWORD), PMerge, Z(DWORD), Z(DWORD)] => [R(0), R3(10), RTied, R(5)], SVE;
+    0b00000100_11010010_00000000_00000000 = [Z(QWORD), PMerge, Z(QWORD), Z(QWORD)] => [R(0), R3(10), RTied, R(5)], SVE;
+    // SMULH (vectors, unpredicated)
+    0b00000100_00100000_01101000_00000000 = [Z(BYTE), Z(BYTE), Z(BYTE)] => [R(0), R(5), R(16)], SVE2;
+    0b00000100_01100000_01101000_00000000 = [Z(WORD), Z(WORD), Z(WORD)] => [R(0), R(5), R(16)], SVE2;
+    0b00000100_10100000_01101000_00000000 = [Z(DWORD), Z(DWORD), Z(DWORD)] => [R(0), R(5), R(16)], SVE2;
+    0b00000100_11100000_01101000_00000000 = [Z(QWORD), Z(QWORD), Z(QWORD)] => [R(0), R(5), R(16)], SVE2;
+]
+"smull" = [
+    // SMULL, SMULL2 (by element)
+    0b00001111_01000000_10100000_00000000 = [VStatic(DWORD, 4), VStatic(WORD, 4), VElement(WORD)] => [R(0), R(5), R4(16), Ufields(&[11, 21, 20])];
+    0b00001111_10000000_10100000_00000000 = [VStatic(QWORD, 2), VStatic(DWORD, 2), VElement(DWORD)] => [R(0), R(5), R(16), Ufields(&[11, 21])];
+    // SMULL, SMULL2 (vector)
+    0b00001110_00100000_11000000_00000000 = [VStatic(WORD, 8), VStatic(BYTE, 8), VStatic(BYTE, 8)] => [R(0), R(5), R(16)];
+    0b00001110_01100000_11000000_00000000 = [VStatic(DWORD, 4), VStatic(WORD, 4), VStatic(WORD, 4)] => [R(0), R(5), R(16)];
+    0b00001110_10100000_11000000_00000000 = [VStatic(QWORD, 2), VStatic(DWORD, 2), VStatic(DWORD, 2)] => [R(0), R(5), R(16)];
+    // SMULL
+    0b10011011_00100000_01111100_00000000 = [X, W, W] => [R(0), R(5), R(16)];
+]
+"smull2" = [
+    // SMULL, SMULL2 (by element)
+    0b01001111_01000000_10100000_00000000 = [VStatic(DWORD, 4), VStatic(WORD, 8), VElement(WORD)] => [R(0), R(5), R4(16), Ufields(&[11, 21, 20])];
+    0b01001111_10000000_10100000_00000000 = [VStatic(QWORD, 2), VStatic(DWORD, 4), VElement(DWORD)] => [R(0), R(5), R(16), Ufields(&[11, 21])];
+    // SMULL, SMULL2 (vector)
+    0b01001110_00100000_11000000_00000000 = [VStatic(WORD, 8), VStatic(BYTE, 16), VStatic(BYTE, 16)] => [R(0), R(5), R(16)];
+    0b01001110_01100000_11000000_00000000 = [VStatic(DWORD, 4), VStatic(WORD, 8), VStatic(WORD, 8)] => [R(0), R(5), R(16)];
+    0b01001110_10100000_11000000_00000000 = [VStatic(QWORD, 2), VStatic(DWORD, 4), VStatic(DWORD, 4)] => [R(0), R(5), R(16)];
+]
+"smullb" = [
+    0b01000101_01000000_01110000_00000000 = [Z(WORD), Z(BYTE), Z(BYTE)] => [R(0), R(5), R(16)], SVE2;
+    0b01000101_10000000_01110000_00000000 = [Z(DWORD), Z(WORD), Z(WORD)] => [R(0), R(5), R(16)], SVE2;
+    0b01000101_11000000_01110000_00000000 = [Z(QWORD), Z(DWORD), Z(DWORD)] => [R(0), R(5), R(16)], SVE2;
+]
+"smullt" = [
+    0b01000101_01000000_01110100_00000000 = [Z(WORD), Z(BYTE), Z(BYTE)] => [R(0), R(5), R(16)], SVE2;
+    0b01000101_10000000_01110100_00000000 = [Z(DWORD), Z(WORD), Z(WORD)] => [R(0), R(5), R(16)], SVE2;
+    0b01000101_11000000_01110100_00000000 = [Z(QWORD), Z(DWORD), Z(DWORD)] => [R(0), R(5), R(16)], SVE2;
+]
+"splice" = [
+    // SPLICE (destructive)
+    0b00000101_00101100_10000000_00000000 = [Z(BYTE), PBare, Z(BYTE), Z(BYTE)] => [R(0), R3(10), RTied, R(5)], SVE;
+    0b00000101_01101100_10000000_00000000 = [Z(WORD), PBare, Z(WORD), Z(WORD)] => [R(0), R3(10), RTied, R(5)], SVE;
+    0b00000101_10101100_10000000_00000000 = [Z(DWORD), PBare, Z(DWORD), Z(DWORD)] => [R(0), R3(10), RTied, R(5)], SVE;
+    0b00000101_11101100_10000000_00000000 = [Z(QWORD), PBare, Z(QWORD), Z(QWORD)] => [R(0), R3(10), RTied, R(5)], SVE;
+    // SPLICE (constructive)
+    0b00000101_00101101_10000000_00000000 = [Z(BYTE), PBare, ZRegList(2, BYTE)] => [R(0), R3(10), R(5)], SVE2;
+    0b00000101_01101101_10000000_00000000 = [Z(WORD), PBare, ZRegList(2, WORD)] => [R(0), R3(10), R(5)], SVE2;
+    0b00000101_10101101_10000000_00000000 = [Z(DWORD), PBare, ZRegList(2, DWORD)] => [R(0), R3(10), R(5)], SVE2;
+    0b00000101_11101101_10000000_00000000 = [Z(QWORD), PBare, ZRegList(2, QWORD)] => [R(0), R3(10), R(5)], SVE2;
+]
+"sqabs" = [
+    0b01011110_00100000_01111000_00000000 = [B, B] => [R(0), R(5)];
+    0b01011110_01100000_01111000_00000000 = [H, H] => [R(0), R(5)];
+    0b01011110_10100000_01111000_00000000 = [S, S] => [R(0), R(5)];
+    0b01011110_11100000_01111000_00000000 = [D, D] => [R(0), R(5)];
+    0b00001110_00100000_01111000_00000000 = [V(BYTE), V(BYTE)] => [R(0), R(5), Rwidth(30)];
+    0b00001110_01100000_01111000_00000000 = [V(WORD), V(WORD)] => [R(0), R(5), Rwidth(30)];
+    0b00001110_10100000_01111000_00000000 = [V(DWORD), V(DWORD)] => [R(0), R(5), Rwidth(30)];
+    0b00001110_11100000_01111000_00000000 = [VStatic(QWORD, 2), VStatic(QWORD, 2)] => [R(0), R(5), Rwidth(30)];
+    0b01000100_00001000_10100000_00000000 = [Z(BYTE), PMerge, Z(BYTE)] => [R(0), R3(10), R(5)], SVE2;
+    0b01000100_01001000_10100000_00000000 = [Z(WORD), PMerge, Z(WORD)] => [R(0), R3(10), R(5)], SVE2;
+    0b01000100_10001000_10100000_00000000 = [Z(DWORD), PMerge, Z(DWORD)] => [R(0), R3(10), R(5)], SVE2;
+    0b01000100_11001000_10100000_00000000 = [Z(QWORD), PMerge, Z(QWORD)] => [R(0), R3(10), R(5)], SVE2;
+]
+"sqadd" = [
+    // SQADD
+    0b01011110_00100000_00001100_00000000 = [B, B, B] => [R(0), R(5), R(16)];
+    0b01011110_01100000_00001100_00000000 = [H, H, H] => [R(0), R(5), R(16)];
+    0b01011110_10100000_00001100_00000000 = [S, S, S] => [R(0), R(5), R(16)];
+    0b01011110_11100000_00001100_00000000 = [D, D, D] => [R(0), R(5), R(16)];
+    0b00001110_00100000_00001100_00000000 = [V(BYTE), V(BYTE), V(BYTE)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b00001110_01100000_00001100_00000000 = [V(WORD), V(WORD), V(WORD)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b00001110_10100000_00001100_00000000 = [V(DWORD), V(DWORD), V(DWORD)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b00001110_11100000_00001100_00000000 = [VStatic(QWORD, 2), VStatic(QWORD, 2), VStatic(QWORD, 2)] => [R(0), R(5), R(16), Rwidth(30)];
+    // SQADD (vectors, unpredicated)
+    0b00000100_00100000_00010000_00000000 = [Z(BYTE), Z(BYTE), Z(BYTE)] => [R(0), R(5), R(16)], SVE;
+    0b00000100_01100000_00010000_00000000 = [Z(WORD), Z(WORD), Z(WORD)] => [R(0), R(5), R(16)], SVE;
+    0b00000100_10100000_00010000_00000000 = [Z(DWORD), Z(DWORD), Z(DWORD)] => [R(0), R(5), R(16)], SVE;
+    0b00000100_11100000_00010000_00000000 = [Z(QWORD), Z(QWORD), Z(QWORD)] => [R(0), R(5), R(16)], SVE;
+    // SQADD (immediate)
+    0b00100101_00100100_11000000_00000000 = [Z(BYTE), Z(BYTE), Imm] => [R(0), RTied, Ubits(5, 8)], SVE;
+    0b00100101_01100100_11000000_00000000 = [Z(WORD), Z(WORD), Imm, End, LitMod(LSL)] => [R(0), RTied, Ubits(5, 8), Ulist(13, &[0, 8])], SVE;
+    0b00100101_10100100_11000000_00000000 = [Z(DWORD), Z(DWORD), Imm, End, LitMod(LSL)] => [R(0), RTied, Ubits(5, 8), Ulist(13, &[0, 8])], SVE;
+    0b00100101_11100100_11000000_00000000 = [Z(QWORD), Z(QWORD), Imm, End, LitMod(LSL)] => [R(0), RTied, Ubits(5, 8), Ulist(13, &[0, 8])], SVE;
+    // SQADD (vectors, predicated)
+    0b01000100_00011000_10000000_00000000 = [Z(BYTE), PMerge, Z(BYTE), Z(BYTE)] => [R(0), R3(10), RTied, R(5)], SVE2;
+    0b01000100_01011000_10000000_00000000 = [Z(WORD), PMerge, Z(WORD), Z(WORD)] => [R(0), R3(10), RTied, R(5)], SVE2;
+    0b01000100_10011000_10000000_00000000 = [Z(DWORD), PMerge, Z(DWORD), Z(DWORD)] => [R(0), R3(10), RTied, R(5)], SVE2;
+    0b01000100_11011000_10000000_00000000 = [Z(QWORD), PMerge, Z(QWORD), Z(QWORD)] => [R(0), R3(10), RTied, R(5)], SVE2;
+]
+"sqcadd" = [
+    0b01000101_00000001_11011000_00000000 = [Z(BYTE), Z(BYTE), Z(BYTE), Imm] => [R(0), RTied, R(5), Ulist(10, &[90, 270])], SVE2;
+    0b01000101_01000001_11011000_00000000 = [Z(WORD), Z(WORD), Z(WORD), Imm] => [R(0), RTied, R(5), Ulist(10, &[90, 270])], SVE2;
+    0b01000101_10000001_11011000_00000000 = [Z(DWORD), Z(DWORD), Z(DWORD), Imm] => [R(0), RTied, R(5), Ulist(10, &[90, 270])], SVE2;
+    0b01000101_11000001_11011000_00000000 = [Z(QWORD), Z(QWORD), Z(QWORD), Imm] => [R(0), RTied, R(5), Ulist(10, &[90, 270])], SVE2;
+]
+"sqdecb" = [
+    // SQDECB (scalar, 32-bit)
+    0b00000100_00100000_11111011_11100000 = [X, W] => [R(0), RTied], SVE;
+    0b00000100_00100000_11111000_00000000 = [X, W, Ident, End, LitMod(MUL)] => [R(0), RTied, LitList(5, "SVE_PATTERNS"), Urange(16, 1, 16)], SVE;
+    // SQDECB (scalar)
+    0b00000100_00110000_11111011_11100000 = [X] => [R(0)], SVE;
+    0b00000100_00110000_11111000_00000000 = [X, Ident, End, LitMod(MUL)] => [R(0), LitList(5, "SVE_PATTERNS"), Urange(16, 1, 16)], SVE;
+]
+"sqdecd" = [
+    // SQDECD (scalar, 32-bit)
+    0b00000100_11100000_11111011_11100000 = [X, W] => [R(0), RTied], SVE;
+    0b00000100_11100000_11111000_00000000 = [X, W, Ident, End, LitMod(MUL)] => [R(0), RTied, LitList(5, "SVE_PATTERNS"), Urange(16, 1, 16)], SVE;
+    // SQDECD (scalar)
+    0b00000100_11110000_11111011_11100000 = [X] => [R(0)], SVE;
+    0b00000100_11110000_11111000_00000000 = [X, Ident, End, LitMod(MUL)] => [R(0), LitList(5, "SVE_PATTERNS"), Urange(16, 1, 16)], SVE;
+    // SQDECD (vector)
+    0b00000100_11100000_11001011_11100000 = [Z(QWORD)] => [R(0)], SVE;
+    0b00000100_11100000_11001000_00000000 = [Z(QWORD), Ident, End, LitMod(MUL)] => [R(0), LitList(5, "SVE_PATTERNS"), Urange(16, 1, 16)], SVE;
+]
+"sqdech" = [
+    // SQDECH (scalar, 32-bit)
+    0b00000100_01100000_11111011_11100000 = [X, W] => [R(0), RTied], SVE;
+    0b00000100_01100000_11111000_00000000 = [X, W, Ident, End, LitMod(MUL)] => [R(0), RTied, LitList(5, "SVE_PATTERNS"), Urange(16, 1, 16)], SVE;
+    // SQDECH (scalar)
+    0b00000100_01110000_11111011_11100000 = [X] => [R(0)], SVE;
+    0b00000100_01110000_11111000_00000000 = [X, Ident, End, LitMod(MUL)] => [R(0), LitList(5, "SVE_PATTERNS"), Urange(16, 1, 16)], SVE;
+    // SQDECH (vector)
+    0b00000100_01100000_11001011_11100000 = [Z(WORD)] => [R(0)], SVE;
+    0b00000100_01100000_11001000_00000000 = [Z(WORD), Ident, End, LitMod(MUL)] => [R(0), LitList(5, "SVE_PATTERNS"), Urange(16, 1, 16)], SVE;
+]
+"sqdecp" = [
+    // SQDECP (scalar)
+    0b00100101_00101010_10001100_00000000 = [X, P(BYTE)] => [R(0), R4(5)], SVE;
+    0b00100101_01101010_10001100_00000000 = [X, P(WORD)] => [R(0), R4(5)], SVE;
+    0b00100101_10101010_10001100_00000000 = [X, P(DWORD)] => [R(0), R4(5)], SVE;
+    0b00100101_11101010_10001100_00000000 = [X, P(QWORD)] => [R(0), R4(5)], SVE;
+    // SQDECP (scalar, 32-bit)
+    0b00100101_00101010_10001000_00000000 = [X, P(BYTE), W] => [R(0), R4(5), RTied], SVE;
+    0b00100101_01101010_10001000_00000000 = [X, P(WORD), W] => [R(0), R4(5), RTied], SVE;
+    0b00100101_10101010_10001000_00000000 = [X, P(DWORD), W] => [R(0), R4(5), RTied], SVE;
+    0b00100101_11101010_10001000_00000000 = [X, P(QWORD), W] => [R(0), R4(5), RTied], SVE;
+    // SQDECP (vector)
+    0b00100101_01101010_10000000_00000000 = [Z(WORD), PBare] => [R(0), R4(5)], SVE;
+    0b00100101_10101010_10000000_00000000 = [Z(DWORD), PBare] => [R(0), R4(5)], SVE;
+    0b00100101_11101010_10000000_00000000 = [Z(QWORD), PBare] => [R(0), R4(5)], SVE;
+]
+"sqdecw" = [
+    // SQDECW (scalar, 32-bit)
+    0b00000100_10100000_11111011_11100000 = [X, W] => [R(0), RTied], SVE;
+    0b00000100_10100000_11111000_00000000 = [X, W, Ident, End, LitMod(MUL)] => [R(0), RTied, LitList(5, "SVE_PATTERNS"), Urange(16, 1, 16)], SVE;
+    // SQDECW (scalar)
+    0b00000100_10110000_11111011_11100000 = [X] => [R(0)], SVE;
+    0b00000100_10110000_11111000_00000000 = [X, Ident, End, LitMod(MUL)] => [R(0), LitList(5, "SVE_PATTERNS"), Urange(16, 1, 16)], SVE;
+    // SQDECW (vector)
+    0b00000100_10100000_11001011_11100000 = [Z(DWORD)] => [R(0)], SVE;
+    0b00000100_10100000_11001000_00000000 = [Z(DWORD), Ident, End, LitMod(MUL)] => [R(0), LitList(5, "SVE_PATTERNS"), Urange(16, 1, 16)], SVE;
+]
+"sqdmlal" = [
+    // SQDMLAL, SQDMLAL2 (by element)
+    0b01011111_01000000_00110000_00000000 = [S, H, VElement(WORD)] => [R(0), R(5), R4(16), Ufields(&[11, 21, 20])];
+    0b01011111_10000000_00110000_00000000 = [D, S, VElement(DWORD)] => [R(0), R(5), R(16), Ufields(&[11, 21])];
+    0b00001111_01000000_00110000_00000000 = [VStatic(DWORD, 4), VStatic(WORD, 4), VElement(WORD)] => [R(0), R(5), R4(16), Ufields(&[11, 21, 20])];
+    0b00001111_10000000_00110000_00000000 = [VStatic(QWORD, 2), VStatic(DWORD, 2), VElement(DWORD)] => [R(0), R(5), R(16), Ufields(&[11, 21])];
+    // SQDMLAL, SQDMLAL2 (vector)
+    0b01011110_01100000_10010000_00000000 = [S, H, H] => [R(0), R(5), R(16)];
+    0b01011110_10100000_10010000_00000000 = [D, S, S] => [R(0), R(5), R(16)];
+    0b00001110_01100000_10010000_00000000 = [VStatic(DWORD, 4), VStatic(WORD, 4), VStatic(WORD, 4)] => [R(0), R(5), R(16)];
+    0b00001110_10100000_10010000_00000000 = [VStatic(QWORD, 2), VStatic(DWORD, 2), VStatic(DWORD, 2)] => [R(0), R(5), R(16)];
+]
+"sqdmlal2" = [
+    // SQDMLAL, SQDMLAL2 (by element)
+    0b01001111_01000000_00110000_00000000 = [VStatic(DWORD, 4), VStatic(WORD, 8), VElement(WORD)] => [R(0), R(5), R4(16), Ufields(&[11, 21, 20])];
+    0b01001111_10000000_00110000_00000000 = [VStatic(QWORD, 2), VStatic(DWORD, 4), VElement(DWORD)] => [R(0), R(5), R(16), Ufields(&[11, 21])];
+    // SQDMLAL, SQDMLAL2 (vector)
+    0b01001110_01100000_10010000_00000000 = [VStatic(DWORD, 4), VStatic(WORD, 8), VStatic(WORD, 8)] => [R(0), R(5), R(16)];
+    0b01001110_10100000_10010000_00000000 = [VStatic(QWORD, 2), VStatic(DWORD, 4), VStatic(DWORD, 4)] => [R(0), R(5), R(16)];
+]
+"sqdmlalb" = [
+    0b01000100_01000000_01100000_00000000 = [Z(WORD), Z(BYTE), Z(BYTE)] => [R(0), R(5), R(16)], SVE2;
+    0b01000100_10000000_01100000_00000000 = [Z(DWORD), Z(WORD), Z(WORD)] => [R(0), R(5), R(16)], SVE2;
+    0b01000100_11000000_01100000_00000000 = [Z(QWORD), Z(DWORD), Z(DWORD)] => [R(0), R(5), R(16)], SVE2;
+]
+"sqdmlalt" = [
+    0b01000100_01000000_01100100_00000000 = [Z(WORD), Z(BYTE), Z(BYTE)] => [R(0), R(5), R(16)], SVE2;
+    0b01000100_10000000_01100100_00000000 = [Z(DWORD), Z(WORD), Z(WORD)] => [R(0), R(5), R(16)], SVE2;
+    0b01000100_11000000_01100100_00000000 = [Z(QWORD), Z(DWORD), Z(DWORD)] => [R(0), R(5), R(16)], SVE2;
+]
+"sqdmlsl" = [
+    // SQDMLSL, SQDMLSL2 (by element)
+    0b01011111_01000000_01110000_00000000 = [S, H, VElement(WORD)] => [R(0), R(5), R4(16), Ufields(&[11, 21, 20])];
+    0b01011111_10000000_01110000_00000000 = [D, S, VElement(DWORD)] => [R(0), R(5), R(16), Ufields(&[11, 21])];
+    0b00001111_01000000_01110000_00000000 = [VStatic(DWORD, 4), VStatic(WORD, 4), VElement(WORD)] => [R(0), R(5), R4(16), Ufields(&[11, 21, 20])];
+    0b00001111_10000000_01110000_00000000 = [VStatic(QWORD, 2), VStatic(DWORD, 2), VElement(DWORD)] => [R(0), R(5), R(16), Ufields(&[11, 21])];
+    // SQDMLSL, SQDMLSL2 (vector)
+    0b01011110_01100000_10110000_00000000 = [S, H, H] => [R(0), R(5), R(16)];
+    0b01011110_10100000_10110000_00000000 = [D, S, S] => [R(0), R(5), R(16)];
+    0b00001110_01100000_10110000_00000000 = [VStatic(DWORD, 4), VStatic(WORD, 4), VStatic(WORD, 4)] => [R(0), R(5), R(16)];
+    0b00001110_10100000_10110000_00000000 = [VStatic(QWORD, 2), VStatic(DWORD, 2), VStatic(DWORD, 2)] => [R(0), R(5), R(16)];
+]
+"sqdmlsl2" = [
+    // SQDMLSL, SQDMLSL2 (by element)
+    0b01001111_01000000_01110000_00000000 = [VStatic(DWORD, 4), VStatic(WORD, 8), VElement(WORD)] => [R(0), R(5), R4(16), Ufields(&[11, 21, 20])];
+    0b01001111_10000000_01110000_00000000 = [VStatic(QWORD, 2), VStatic(DWORD, 4), VElement(DWORD)] => [R(0), R(5), R(16), Ufields(&[11, 21])];
+    // SQDMLSL, SQDMLSL2 (vector)
+    0b01001110_01100000_10110000_00000000 = [VStatic(DWORD, 4), VStatic(WORD, 8), VStatic(WORD, 8)] => [R(0), R(5), R(16)];
+    0b01001110_10100000_10110000_00000000 = [VStatic(QWORD, 2), VStatic(DWORD, 4), VStatic(DWORD, 4)] => [R(0), R(5), R(16)];
+]
+"sqdmlslb" = [
+    0b01000100_01000000_01101000_00000000 = [Z(WORD), Z(BYTE), Z(BYTE)] => [R(0), R(5), R(16)], SVE2;
+    0b01000100_10000000_01101000_00000000 = [Z(DWORD), Z(WORD), Z(WORD)] => [R(0), R(5), R(16)], SVE2;
+    0b01000100_11000000_01101000_00000000 = [Z(QWORD), Z(DWORD), Z(DWORD)] => [R(0), R(5), R(16)], SVE2;
+]
+"sqdmlslt" = [
+    0b01000100_01000000_01101100_00000000 = [Z(WORD), Z(BYTE), Z(BYTE)] => [R(0), R(5), R(16)], SVE2;
+    0b01000100_10000000_01101100_00000000 = [Z(DWORD), Z(WORD), Z(WORD)] => [R(0), R(5), R(16)], SVE2;
+    0b01000100_11000000_01101100_00000000 = [Z(QWORD), Z(DWORD), Z(DWORD)] => [R(0), R(5), R(16)], SVE2;
+]
+"sqdmulh" = [
+    // SQDMULH (by element)
+    0b01011111_01000000_11000000_00000000 = [H, H, VElement(WORD)] => [R(0), R(5), R4(16), Ufields(&[11, 21, 20])];
+    0b01011111_10000000_11000000_00000000 = [S, S, VElement(DWORD)] => [R(0), R(5), R(16), Ufields(&[11, 21])];
+    0b00001111_01000000_11000000_00000000 = [V(WORD), V(WORD), VElement(WORD)] => [R(0), R(5), R4(16), Ufields(&[11, 21, 20]), Rwidth(30)];
+    0b00001111_10000000_11000000_00000000 = [V(DWORD), V(DWORD), VElement(DWORD)] => [R(0), R(5), R(16), Ufields(&[11, 21]), Rwidth(30)];
+    // SQDMULH (vector)
+    0b01011110_01100000_10110100_00000000 = [H, H, H] => [R(0), R(5), R(16)];
+    0b01011110_10100000_10110100_00000000 = [S, S, S] => [R(0), R(5), R(16)];
+    0b00001110_01100000_10110100_00000000 = [V(WORD), V(WORD), V(WORD)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b00001110_10100000_10110100_00000000 = [V(DWORD), V(DWORD), V(DWORD)] => [R(0), R(5), R(16), Rwidth(30)];
+    // SQDMULH (vectors, unpredicated)
+    0b00000100_00100000_01110000_00000000 = [Z(BYTE), Z(BYTE), Z(BYTE)] => [R(0), R(5), R(16)], SVE2;
+    0b00000100_01100000_01110000_00000000 = [Z(WORD), Z(WORD), Z(WORD)] => [R(0), R(5), R(16)], SVE2;
+    0b00000100_10100000_01110000_00000000 = [Z(DWORD), Z(DWORD), Z(DWORD)] => [R(0), R(5), R(16)], SVE2;
+    0b00000100_11100000_01110000_00000000 = [Z(QWORD), Z(QWORD), Z(QWORD)] => [R(0), R(5), R(16)], SVE2;
+]
+"sqdmull" = [
+    // SQDMULL, SQDMULL2 (by element)
+    0b01011111_01000000_10110000_00000000 = [S, H, VElement(WORD)] => [R(0), R(5), R4(16), Ufields(&[11, 21, 20])];
+    0b01011111_10000000_10110000_00000000 = [D, S, VElement(DWORD)] => [R(0), R(5), R(16), Ufields(&[11, 21])];
+    0b00001111_01000000_10110000_00000000 = [VStatic(DWORD, 4), VStatic(WORD, 4), VElement(WORD)] => [R(0), R(5), R4(16), Ufields(&[11, 21, 20])];
+    0b00001111_10000000_10110000_00000000 = [VStatic(QWORD, 2), VStatic(DWORD, 2), VElement(DWORD)] => [R(0), R(5), R(16), Ufields(&[11, 21])];
+    // SQDMULL, SQDMULL2 (vector)
+    0b01011110_01100000_11010000_00000000 = [S, H, H] => [R(0), R(5), R(16)];
+    0b01011110_10100000_11010000_00000000 = [D, S, S] => [R(0), R(5), R(16)];
+    0b00001110_01100000_11010000_00000000 = [VStatic(DWORD, 4), VStatic(WORD, 4), VStatic(WORD, 4)] => [R(0), R(5), R(16)];
+    0b00001110_10100000_11010000_00000000 = [VStatic(QWORD, 2), VStatic(DWORD, 2), VStatic(DWORD, 2)] => [R(0), R(5), R(16)];
+]
+"sqdmull2" = [
+    // SQDMULL, SQDMULL2 (by element)
+    0b01001111_01000000_10110000_00000000 = [VStatic(DWORD, 4), VStatic(WORD, 8), VElement(WORD)] => [R(0), R(5), R4(16), Ufields(&[11, 21, 20])];
+    0b01001111_10000000_10110000_00000000 = [VStatic(QWORD, 2), VStatic(DWORD, 4), VElement(DWORD)] => [R(0), R(5), R(16), Ufields(&[11, 21])];
+    // SQDMULL, SQDMULL2 (vector)
+    0b01001110_01100000_11010000_00000000 = [VStatic(DWORD, 4), VStatic(WORD, 8), VStatic(WORD, 8)] => [R(0), R(5), R(16)];
+    0b01001110_10100000_11010000_00000000 = [VStatic(QWORD, 2), VStatic(DWORD, 4), VStatic(DWORD, 4)] => [R(0), R(5), R(16)];
+]
+"sqdmullb" = [
+    0b01000101_01000000_01100000_00000000 = [Z(WORD), Z(BYTE), Z(BYTE)] => [R(0), R(5), R(16)], SVE2;
+    0b01000101_10000000_01100000_00000000 = [Z(DWORD), Z(WORD), Z(WORD)] => [R(0), R(5), R(16)], SVE2;
+    0b01000101_11000000_01100000_00000000 = [Z(QWORD), Z(DWORD), Z(DWORD)] => [R(0), R(5), R(16)], SVE2;
+]
+"sqdmullt" = [
+    0b01000101_01000000_01100100_00000000 = [Z(WORD), Z(BYTE), Z(BYTE)] => [R(0), R(5), R(16)], SVE2;
+    0b01000101_10000000_01100100_00000000 = [Z(DWORD), Z(WORD), Z(WORD)] => [R(0), R(5), R(16)], SVE2;
+    0b01000101_11000000_01100100_00000000 = [Z(QWORD), Z(DWORD), Z(DWORD)] => [R(0), R(5), R(16)], SVE2;
+]
+"sqincb" = [
+    // SQINCB (scalar, 32-bit)
+    0b00000100_00100000_11110011_11100000 = [X, W] => [R(0), RTied], SVE;
+    0b00000100_00100000_11110000_00000000 = [X, W, Ident, End, LitMod(MUL)] => [R(0), RTied, LitList(5, "SVE_PATTERNS"), Urange(16, 1, 16)], SVE;
+    // SQINCB (scalar)
+    0b00000100_00110000_11110011_11100000 = [X] => [R(0)], SVE;
+    0b00000100_00110000_11110000_00000000 = [X, Ident, End, LitMod(MUL)] => [R(0), LitList(5, "SVE_PATTERNS"), Urange(16, 1, 16)], SVE;
+]
+"sqincd" = [
+    // SQINCD (scalar, 32-bit)
+    0b00000100_11100000_11110011_11100000 = [X, W] => [R(0), RTied], SVE;
+    0b00000100_11100000_11110000_00000000 = [X, W, Ident, End, LitMod(MUL)] => [R(0), RTied, LitList(5, "SVE_PATTERNS"), Urange(16, 1, 16)], SVE;
+    // SQINCD (scalar)
+    0b00000100_11110000_11110011_11100000 = [X] => [R(0)], SVE;
+    0b00000100_11110000_11110000_00000000 = [X, Ident, End, LitMod(MUL)] => [R(0), LitList(5, "SVE_PATTERNS"), Urange(16, 1, 16)], SVE;
+    // SQINCD (vector)
+    0b00000100_11100000_11000011_11100000 = [Z(QWORD)] => [R(0)], SVE;
+    0b00000100_11100000_11000000_00000000 = [Z(QWORD), Ident, End, LitMod(MUL)] => [R(0), LitList(5, "SVE_PATTERNS"), Urange(16, 1, 16)], SVE;
+]
+"sqinch" = [
+    // SQINCH (scalar, 32-bit)
+    0b00000100_01100000_11110011_11100000 = [X, W] => [R(0), RTied], SVE;
+    0b00000100_01100000_11110000_00000000 = [X, W, Ident, End, LitMod(MUL)] => [R(0), RTied, LitList(5, "SVE_PATTERNS"), Urange(16, 1, 16)], SVE;
+    // SQINCH (scalar)
+    0b00000100_01110000_11110011_11100000 = [X] => [R(0)], SVE;
+    0b00000100_01110000_11110000_00000000 = [X, Ident, End, LitMod(MUL)] => [R(0), LitList(5, "SVE_PATTERNS"), Urange(16, 1, 16)], SVE;
+    // SQINCH (vector)
+    0b00000100_01100000_11000011_11100000 = [Z(WORD)] => [R(0)], SVE;
+    0b00000100_01100000_11000000_00000000 = [Z(WORD), Ident, End, LitMod(MUL)] => [R(0), LitList(5, "SVE_PATTERNS"), Urange(16, 1, 16)], SVE;
+]
+"sqincp" = [
+    // SQINCP (scalar)
+    0b00100101_00101000_10001100_00000000 = [X, P(BYTE)] => [R(0), R4(5)], SVE;
+    0b00100101_01101000_10001100_00000000 = [X, P(WORD)] => [R(0), R4(5)], SVE;
+    0b00100101_10101000_10001100_00000000 = [X, P(DWORD)] => [R(0), R4(5)], SVE;
+    0b00100101_11101000_10001100_00000000 = [X, P(QWORD)] => [R(0), R4(5)], SVE;
+    // SQINCP (scalar, 32-bit)
+    0b00100101_00101000_10001000_00000000 = [X, P(BYTE), W] => [R(0), R4(5), RTied], SVE;
+    0b00100101_01101000_10001000_00000000 = [X, P(WORD), W] => [R(0), R4(5), RTied], SVE;
+    0b00100101_10101000_10001000_00000000 = [X, P(DWORD), W] => [R(0), R4(5), RTied], SVE;
+    0b00100101_11101000_10001000_00000000 = [X, P(QWORD), W] => [R(0), R4(5), RTied], SVE;
+    // SQINCP (vector)
+    0b00100101_01101000_10000000_00000000 = [Z(WORD), PBare] => [R(0), R4(5)], SVE;
+    0b00100101_10101000_10000000_00000000 = [Z(DWORD), PBare] => [R(0), R4(5)], SVE;
+    0b00100101_11101000_10000000_00000000 = [Z(QWORD), PBare] => [R(0), R4(5)], SVE;
+]
+"sqincw" = [
+    // SQINCW (scalar, 32-bit)
+    0b00000100_10100000_11110011_11100000 = [X, W] => [R(0), RTied], SVE;
+    0b00000100_10100000_11110000_00000000 = [X, W, Ident, End, LitMod(MUL)] => [R(0), RTied, LitList(5, "SVE_PATTERNS"), Urange(16, 1, 16)], SVE;
+    // SQINCW (scalar)
+    0b00000100_10110000_11110011_11100000 = [X] => [R(0)], SVE;
+    0b00000100_10110000_11110000_00000000 = [X, Ident, End, LitMod(MUL)] => [R(0), LitList(5, "SVE_PATTERNS"), Urange(16, 1, 16)], SVE;
+    // SQINCW (vector)
+    0b00000100_10100000_11000011_11100000 = [Z(DWORD)] => [R(0)], SVE;
+    0b00000100_10100000_11000000_00000000 = [Z(DWORD), Ident, End, LitMod(MUL)] => [R(0), LitList(5, "SVE_PATTERNS"), Urange(16, 1, 16)], SVE;
+]
+"sqneg" = [
+    0b01111110_00100000_01111000_00000000 = [B, B] => [R(0), R(5)];
+    0b01111110_01100000_01111000_00000000 = [H, H] => [R(0), R(5)];
+    0b01111110_10100000_01111000_00000000 = [S, S] => [R(0), R(5)];
+    0b01111110_11100000_01111000_00000000 = [D, D] => [R(0), R(5)];
+    0b00101110_00100000_01111000_00000000 = [V(BYTE), V(BYTE)] => [R(0), R(5), Rwidth(30)];
+    0b00101110_01100000_01111000_00000000 = [V(WORD), V(WORD)] => [R(0), R(5), Rwidth(30)];
+    0b00101110_10100000_01111000_00000000 = [V(DWORD), V(DWORD)] => [R(0), R(5), Rwidth(30)];
+    0b00101110_11100000_01111000_00000000 = [VStatic(QWORD, 2), VStatic(QWORD, 2)] => [R(0), R(5), Rwidth(30)];
+    0b01000100_00001001_10100000_00000000 = [Z(BYTE), PMerge, Z(BYTE)] => [R(0), R3(10), R(5)], SVE2;
+    0b01000100_01001001_10100000_00000000 = [Z(WORD), PMerge, Z(WORD)] => [R(0), R3(10), R(5)], SVE2;
+    0b01000100_10001001_10100000_00000000 = [Z(DWORD), PMerge, Z(DWORD)] => [R(0), R3(10), R(5)], SVE2;
+    0b01000100_11001001_10100000_00000000 = [Z(QWORD), PMerge, Z(QWORD)] => [R(0), R3(10), R(5)], SVE2;
+]
+"sqrdmlah" = [
+    // SQRDMLAH (by element)
+    0b01111111_01000000_11010000_00000000 = [H, H, VElement(WORD)] => [R(0), R(5), R4(16), Ufields(&[11, 21, 20])], RDM;
+    0b01111111_10000000_11010000_00000000 = [S, S, VElement(DWORD)] => [R(0), R(5), R(16), Ufields(&[11, 21])], RDM;
+    0b00101111_01000000_11010000_00000000 = [V(WORD), V(WORD), VElement(WORD)] => [R(0), R(5), R4(16), Ufields(&[11, 21, 20]), Rwidth(30)], RDM;
+    0b00101111_10000000_11010000_00000000 = [V(DWORD), V(DWORD), VElement(DWORD)] => [R(0), R(5), R(16), Ufields(&[11, 21]), Rwidth(30)], RDM;
+    // SQRDMLAH (vector)
+    0b01111110_01000000_10000100_00000000 = [H, H, H] => [R(0), R(5), R(16)], RDM;
+    0b01111110_10000000_10000100_00000000 = [S, S, S] => [R(0), R(5), R(16)], RDM;
+    0b00101110_01000000_10000100_00000000 = [V(WORD), V(WORD), V(WORD)] => [R(0), R(5), R(16), Rwidth(30)], RDM;
+    0b00101110_10000000_10000100_00000000 = [V(DWORD), V(DWORD), V(DWORD)] => [R(0), R(5), R(16), Rwidth(30)], RDM;
+]
+"sqrdmlsh" = [
+    // SQRDMLSH (by element)
+    0b01111111_01000000_11110000_00000000 = [H, H, VElement(WORD)] => [R(0), R(5), R4(16), Ufields(&[11, 21, 20])], RDM;
+    0b01111111_10000000_11110000_00000000 = [S, S, VElement(DWORD)] => [R(0), R(5), R(16), Ufields(&[11, 21])], RDM;
+    0b00101111_01000000_11110000_00000000 = [V(WORD), V(WORD), VElement(WORD)] => [R(0), R(5), R4(16), Ufields(&[11, 21, 20]), Rwidth(30)], RDM;
+    0b00101111_10000000_11110000_00000000 = [V(DWORD), V(DWORD), VElement(DWORD)] => [R(0), R(5), R(16), Ufields(&[11, 21]), Rwidth(30)], RDM;
+    // SQRDMLSH (vector)
+    0b01111110_01000000_10001100_00000000 = [H, H, H] => [R(0), R(5), R(16)], RDM;
+    0b01111110_10000000_10001100_00000000 = [S, S, S] => [R(0), R(5), R(16)], RDM;
+    0b00101110_01000000_10001100_00000000 = [V(WORD), V(WORD), V(WORD)] => [R(0), R(5), R(16), Rwidth(30)], RDM;
+    0b00101110_10000000_10001100_00000000 = [V(DWORD), V(DWORD), V(DWORD)] => [R(0), R(5), R(16), Rwidth(30)], RDM;
+]
+"sqrdmulh" = [
+    // SQRDMULH (by element)
+    0b01011111_01000000_11010000_00000000 = [H, H, VElement(WORD)] => [R(0), R(5), R4(16), Ufields(&[11, 21, 20])];
+    0b01011111_10000000_11010000_00000000 = [S, S, VElement(DWORD)] => [R(0), R(5), R(16), Ufields(&[11, 21])];
+    0b00001111_01000000_11010000_00000000 = [V(WORD), V(WORD), VElement(WORD)] => [R(0), R(5), R4(16), Ufields(&[11, 21, 20]), Rwidth(30)];
+    0b00001111_10000000_11010000_00000000 = [V(DWORD), V(DWORD), VElement(DWORD)] => [R(0), R(5), R(16), Ufields(&[11, 21]), Rwidth(30)];
+    // SQRDMULH (vector)
+    0b01111110_01100000_10110100_00000000 = [H, H, H] => [R(0), R(5), R(16)];
+    0b01111110_10100000_10110100_00000000 = [S, S, S] => [R(0), R(5), R(16)];
+    0b00101110_01100000_10110100_00000000 = [V(WORD), V(WORD), V(WORD)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b00101110_10100000_10110100_00000000 = [V(DWORD), V(DWORD), V(DWORD)] => [R(0), R(5), R(16), Rwidth(30)];
+    // SQRDMULH (vectors, unpredicated)
+    0b00000100_00100000_01110100_00000000 = [Z(BYTE), Z(BYTE), Z(BYTE)] => [R(0), R(5), R(16)], SVE2;
+    0b00000100_01100000_01110100_00000000 = [Z(WORD), Z(WORD), Z(WORD)] => [R(0), R(5), R(16)], SVE2;
+    0b00000100_10100000_01110100_00000000 = [Z(DWORD), Z(DWORD), Z(DWORD)] => [R(0), R(5), R(16)], SVE2;
+    0b00000100_11100000_01110100_00000000 = [Z(QWORD), Z(QWORD), Z(QWORD)] => [R(0), R(5), R(16)], SVE2;
+]
+"sqrshl" = [
+    // SQRSHL
+    0b01011110_00100000_01011100_00000000 = [B, B, B] => [R(0), R(5), R(16)];
+    0b01011110_01100000_01011100_00000000 = [H, H, H] => [R(0), R(5), R(16)];
+    0b01011110_10100000_01011100_00000000 = [S, S, S] => [R(0), R(5), R(16)];
+    0b01011110_11100000_01011100_00000000 = [D, D, D] => [R(0), R(5), R(16)];
+    0b00001110_00100000_01011100_00000000 = [V(BYTE), V(BYTE), V(BYTE)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b00001110_01100000_01011100_00000000 = [V(WORD), V(WORD), V(WORD)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b00001110_10100000_01011100_00000000 = [V(DWORD), V(DWORD), V(DWORD)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b00001110_11100000_01011100_00000000 = [VStatic(QWORD, 2), VStatic(QWORD, 2), VStatic(QWORD, 2)] => [R(0), R(5), R(16), Rwidth(30)];
+    // SQRSHL (vectors, predicated)
+    0b01000100_00001010_10000000_00000000 = [Z(BYTE), PMerge, Z(BYTE), Z(BYTE)] => [R(0), R3(10), RTied, R(5)], SVE2;
+    0b01000100_01001010_10000000_00000000 = [Z(WORD), PMerge, Z(WORD), Z(WORD)] => [R(0), R3(10), RTied, R(5)], SVE2;
+    0b01000100_10001010_10000000_00000000 = [Z(DWORD), PMerge, Z(DWORD), Z(DWORD)] => [R(0), R3(10), RTied, R(5)], SVE2;
+    0b01000100_11001010_10000000_00000000 = [Z(QWORD), PMerge, Z(QWORD), Z(QWORD)] => [R(0), R3(10), RTied, R(5)], SVE2;
+]
+"sqrshlr" = [
+    0b01000100_00001110_10000000_00000000 = [Z(BYTE), PMerge, Z(BYTE), Z(BYTE)] => [R(0), R3(10), RTied, R(5)], SVE2;
+    0b01000100_01001110_10000000_00000000 = [Z(WORD), PMerge, Z(WORD), Z(WORD)] => [R(0), R3(10), RTied, R(5)], SVE2;
+    0b01000100_10001110_10000000_00000000 = [Z(DWORD), PMerge, Z(DWORD), Z(DWORD)] => [R(0), R3(10), RTied, R(5)], SVE2;
+    0b01000100_11001110_10000000_00000000 = [Z(QWORD), PMerge, Z(QWORD), Z(QWORD)] => [R(0), R3(10), RTied, R(5)], SVE2;
+]
+"sqrshrn" = [
+    0b01011111_00000000_10011100_00000000 = [B, H, Imm] => [R(0), R(5), BUrange(1, 8), Usub(16, 4, 16)];
+    0b01011111_00000000_10011100_00000000 = [H, S, Imm] => [R(0), R(5), BUrange(1, 16), Usub(16, 5, 32)];
+    0b01011111_00000000_10011100_00000000 = [S, D, Imm] => [R(0), R(5), BUrange(1, 32), Usub(16, 6, 64)];
+    0b00001111_00001000_10011100_00000000 = [VStatic(BYTE, 8), VStatic(WORD, 8), Imm] => [R(0), R(5), Usub(16, 3, 8)];
+    0b00001111_00010000_10011100_00000000 = [VStatic(WORD, 4), VStatic(DWORD, 4), Imm] => [R(0), R(5), Usub(16, 4, 16)];
+    0b00001111_00100000_10011100_00000000 = [VStatic(DWORD, 2), VStatic(QWORD, 2), Imm] => [R(0), R(5), Usub(16, 5, 32)];
+]
+"sqrshrn2" = [
+    0b01001111_00001000_10011100_00000000 = [VStatic(BYTE, 16), VStatic(WORD, 8), Imm] => [R(0), R(5), Usub(16, 3, 8)];
+    0b01001111_00010000_10011100_00000000 = [VStatic(WORD, 8), VStatic(DWORD, 4), Imm] => [R(0), R(5), Usub(16, 4, 16)];
+    0b01001111_00100000_10011100_00000000 = [VStatic(DWORD, 4), VStatic(QWORD, 2), Imm] => [R(0), R(5), Usub(16, 5, 32)];
+]
+"sqrshrnb" = [
+    0b01000101_00101000_00101000_00000000 = [Z(BYTE), Z(WORD), Imm] => [R(0), R(5), Usub(16, 3, 8)], SVE2;
+    0b01000101_00110000_00101000_00000000 = [Z(WORD), Z(DWORD), Imm] => [R(0), R(5), Usub(16, 4, 16)], SVE2;
+    0b01000101_01100000_00101000_00000000 = [Z(DWORD), Z(QWORD), Imm] => [R(0), R(5), Usub(16, 5, 32)], SVE2;
+]
+"sqrshrnt" = [
+    0b01000101_00101000_00101100_00000000 = [Z(BYTE), Z(WORD), Imm] => [R(0), R(5), Usub(16, 3, 8)], SVE2;
+    0b01000101_00110000_00101100_00000000 = [Z(WORD), Z(DWORD), Imm] => [R(0), R(5), Usub(16, 4, 16)], SVE2;
+    0b01000101_01100000_00101100_00000000 = [Z(DWORD), Z(QWORD), Imm] => [R(0), R(5), Usub(16, 5, 32)], SVE2;
+]
+"sqrshrun" = [
+    0b01111111_00000000_10001100_00000000 = [B, H, Imm] => [R(0), R(5), BUrange(1, 8), Usub(16, 4, 16)];
+    0b01111111_00000000_10001100_00000000 = [H, S, Imm] => [R(0), R(5), BUrange(1, 16), Usub(16, 5, 32)];
+    0b01111111_00000000_10001100_00000000 = [S, D, Imm] => [R(0), R(5), BUrange(1, 32), Usub(16, 6, 64)];
+    0b00101111_00001000_10001100_00000000 = [VStatic(BYTE, 8), VStatic(WORD, 8), Imm] => [R(0), R(5), Usub(16, 3, 8)];
+    0b00101111_00010000_10001100_00000000 = [VStatic(WORD, 4), VStatic(DWORD, 4), Imm] => [R(0), R(5), Usub(16, 4, 16)];
+    0b00101111_00100000_10001100_00000000 = [VStatic(DWORD, 2), VStatic(QWORD, 2), Imm] => [R(0), R(5), Usub(16, 5, 32)];
+]
+"sqrshrun2" = [
+    0b01101111_00001000_10001100_00000000 = [VStatic(BYTE, 16), VStatic(WORD, 8), Imm] => [R(0), R(5), Usub(16, 3, 8)];
+    0b01101111_00010000_10001100_00000000 = [VStatic(WORD, 8), VStatic(DWORD, 4), Imm] => [R(0), R(5), Usub(16, 4, 16)];
+    0b01101111_00100000_10001100_00000000 = [VStatic(DWORD, 4), VStatic(QWORD, 2), Imm] => [R(0), R(5), Usub(16, 5, 32)];
+]
+"sqrshrunb" = [
+    0b01000101_00101000_00001000_00000000 = [Z(BYTE), Z(WORD), Imm] => [R(0), R(5), Usub(16, 3, 8)], SVE2;
+    0b01000101_00110000_00001000_00000000 = [Z(WORD), Z(DWORD), Imm] => [R(0), R(5), Usub(16, 4, 16)], SVE2;
+    0b01000101_01100000_00001000_00000000 = [Z(DWORD), Z(QWORD), Imm] => [R(0), R(5), Usub(16, 5, 32)], SVE2;
+]
+"sqrshrunt" = [
+    0b01000101_00101000_00001100_00000000 = [Z(BYTE), Z(WORD), Imm] => [R(0), R(5), Usub(16, 3, 8)], SVE2;
+    0b01000101_00110000_00001100_00000000 = [Z(WORD), Z(DWORD), Imm] => [R(0), R(5), Usub(16, 4, 16)], SVE2;
+    0b01000101_01100000_00001100_00000000 = [Z(DWORD), Z(QWORD), Imm] => [R(0), R(5), Usub(16, 5, 32)], SVE2;
+]
+"sqshl" = [
+    // SQSHL (immediate)
+    0b01011111_00001000_01110100_00000000 = [B, B, Imm] => [R(0), R(5), Ubits(16, 3)];
+    0b01011111_00010000_01110100_00000000 = [H, H, Imm] => [R(0), R(5), Ubits(16, 4)];
+    0b01011111_00100000_01110100_00000000 = [S, S, Imm] => [R(0), R(5), Ubits(16, 5)];
+    0b01011111_01000000_01110100_00000000 = [D, D, Imm] => [R(0), R(5), Ubits(16, 6)];
+    0b00001111_00001000_01110100_00000000 = [V(BYTE), V(BYTE), Imm] => [R(0), R(5), Ubits(16, 3), Rwidth(30)];
+    0b00001111_00010000_01110100_00000000 = [V(WORD), V(WORD), Imm] => [R(0), R(5), Ubits(16, 4), Rwidth(30)];
+    0b00001111_00100000_01110100_00000000 = [V(DWORD), V(DWORD), Imm] => [R(0), R(5), Ubits(16, 5), Rwidth(30)];
+    0b00001111_01000000_01110100_00000000 = [VStatic(QWORD, 2), VStatic(QWORD, 2), Imm] => [R(0), R(5), Ubits(16, 6), Rwidth(30)];
+    // SQSHL (register)
+    0b01011110_00100000_01001100_00000000 = [B, B, B] => [R(0), R(5), R(16)];
+    0b01011110_01100000_01001100_00000000 = [H, H, H] => [R(0), R(5), R(16)];
+    0b01011110_10100000_01001100_00000000 = [S, S, S] => [R(0), R(5), R(16)];
+    0b01011110_11100000_01001100_00000000 = [D, D, D] => [R(0), R(5), R(16)];
+    0b00001110_00100000_01001100_00000000 = [V(BYTE), V(BYTE), V(BYTE)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b00001110_01100000_01001100_00000000 = [V(WORD), V(WORD), V(WORD)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b00001110_10100000_01001100_00000000 = [V(DWORD), V(DWORD), V(DWORD)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b00001110_11100000_01001100_00000000 = [VStatic(QWORD, 2), VStatic(QWORD, 2), VStatic(QWORD, 2)] => [R(0), R(5), R(16), Rwidth(30)];
+    // SQSHL (vectors, predicated)
+    0b01000100_00001000_10000000_00000000 = [Z(BYTE), PMerge, Z(BYTE), Z(BYTE)] => [R(0), R3(10), RTied, R(5)], SVE2;
+    0b01000100_01001000_10000000_00000000 = [Z(WORD), PMerge, Z(WORD), Z(WORD)] => [R(0), R3(10), RTied, R(5)], SVE2;
+    0b01000100_10001000_10000000_00000000 = [Z(DWORD), PMerge, Z(DWORD), Z(DWORD)] => [R(0), R3(10), RTied, R(5)], SVE2;
+    0b01000100_11001000_10000000_00000000 = [Z(QWORD), PMerge, Z(QWORD), Z(QWORD)] => [R(0), R3(10), RTied, R(5)], SVE2;
+]
+"sqshlr" = [
+    0b01000100_00001100_10000000_00000000 = [Z(BYTE), PMerge, Z(BYTE), Z(BYTE)] => [R(0), R3(10), RTied, R(5)], SVE2;
+    0b01000100_01001100_10000000_00000000 = [Z(WORD), PMerge, Z(WORD), Z(WORD)] => [R(0), R3(10), RTied, R(5)], SVE2;
+    0b01000100_10001100_10000000_00000000 = [Z(DWORD), PMerge, Z(DWORD), Z(DWORD)] => [R(0), R3(10), RTied, R(5)], SVE2;
+    0b01000100_11001100_10000000_00000000 = [Z(QWORD), PMerge, Z(QWORD), Z(QWORD)] => [R(0), R3(10), RTied, R(5)], SVE2;
+]
+"sqshlu" = [
+    0b01111111_00001000_01100100_00000000 = [B, B, Imm] => [R(0), R(5), Ubits(16, 3)];
+    0b01111111_00010000_01100100_00000000 = [H, H, Imm] => [R(0), R(5), Ubits(16, 4)];
+    0b01111111_00100000_01100100_00000000 = [S, S, Imm] => [R(0), R(5), Ubits(16, 5)];
+    0b01111111_01000000_01100100_00000000 = [D, D, Imm] => [R(0), R(5), Ubits(16, 6)];
+    0b00101111_00001000_01100100_00000000 = [V(BYTE), V(BYTE), Imm] => [R(0), R(5), Ubits(16, 3), Rwidth(30)];
+    0b00101111_00010000_01100100_00000000 = [V(WORD), V(WORD), Imm] => [R(0), R(5), Ubits(16, 4), Rwidth(30)];
+    0b00101111_00100000_01100100_00000000 = [V(DWORD), V(DWORD), Imm] => [R(0), R(5), Ubits(16, 5), Rwidth(30)];
+    0b00101111_01000000_01100100_00000000 = [VStatic(QWORD, 2), VStatic(QWORD, 2), Imm] => [R(0), R(5), Ubits(16, 6), Rwidth(30)];
+]
+"sqshrn" = [
+    0b01011111_00000000_10010100_00000000 = [B, H, Imm] => [R(0), R(5), BUrange(1, 8), Usub(16, 4, 16)];
+    0b01011111_00000000_10010100_00000000 = [H, S, Imm] => [R(0), R(5), BUrange(1, 16), Usub(16, 5, 32)];
+    0b01011111_00000000_10010100_00000000 = [S, D, Imm] => [R(0), R(5), BUrange(1, 32), Usub(16, 6, 64)];
+    0b00001111_00001000_10010100_00000000 = [VStatic(BYTE, 8), VStatic(WORD, 8), Imm] => [R(0), R(5), Usub(16, 3, 8)];
+    0b00001111_00010000_10010100_00000000 = [VStatic(WORD, 4), VStatic(DWORD, 4), Imm] => [R(0), R(5), Usub(16, 4, 16)];
+    0b00001111_00100000_10010100_00000000 = [VStatic(DWORD, 2), VStatic(QWORD, 2), Imm] => [R(0), R(5), Usub(16, 5, 32)];
+]
+"sqshrn2" = [
+    0b01001111_00001000_10010100_00000000 = [VStatic(BYTE, 16), VStatic(WORD, 8), Imm] => [R(0), R(5), Usub(16, 3, 8)];
+    0b01001111_00010000_10010100_00000000 = [VStatic(WORD, 8), VStatic(DWORD, 4), Imm] => [R(0), R(5), Usub(16, 4, 16)];
+    0b01001111_00100000_10010100_00000000 = [VStatic(DWORD, 4), VStatic(QWORD, 2), Imm] => [R(0), R(5), Usub(16, 5, 32)];
+]
+"sqshrnb" = [
+    0b01000101_00101000_00100000_00000000 = [Z(BYTE), Z(WORD), Imm] => [R(0), R(5), Usub(16, 3, 8)], SVE2;
+    0b01000101_00110000_00100000_00000000 = [Z(WORD), Z(DWORD), Imm] => [R(0), R(5), Usub(16, 4, 16)], SVE2;
+    0b01000101_01100000_00100000_00000000 = [Z(DWORD), Z(QWORD), Imm] => [R(0), R(5), Usub(16, 5, 32)], SVE2;
+]
+"sqshrnt" = [
+    0b01000101_00101000_00100100_00000000 = [Z(BYTE), Z(WORD), Imm] => [R(0), R(5), Usub(16, 3, 8)], SVE2;
+    0b01000101_00110000_00100100_00000000 = [Z(WORD), Z(DWORD), Imm] => [R(0), R(5), Usub(16, 4, 16)], SVE2;
+    0b01000101_01100000_00100100_00000000 = [Z(DWORD), Z(QWORD), Imm] => [R(0), R(5), Usub(16, 5, 32)], SVE2;
+]
+"sqshrun" = [
+    0b01111111_00000000_10000100_00000000 = [B, H, Imm] => [R(0), R(5), BUrange(1, 8), Usub(16, 4, 16)];
+    0b01111111_00000000_10000100_00000000 = [H, S, Imm] => [R(0), R(5), BUrange(1, 16), Usub(16, 5, 32)];
+    0b01111111_00000000_10000100_00000000 = [S, D, Imm] => [R(0), R(5), BUrange(1, 32), Usub(16, 6, 64)];
+    0b00101111_00001000_10000100_00000000 = [VStatic(BYTE, 8), VStatic(WORD, 8), Imm] => [R(0), R(5), Usub(16, 3, 8)];
+    0b00101111_00010000_10000100_00000000 = [VStatic(WORD, 4), VStatic(DWORD, 4), Imm] => [R(0), R(5), Usub(16, 4, 16)];
+    0b00101111_00100000_10000100_00000000 = [VStatic(DWORD, 2), VStatic(QWORD, 2), Imm] => [R(0), R(5), Usub(16, 5, 32)];
+]
+"sqshrun2" = [
+    0b01101111_00001000_10000100_00000000 = [VStatic(BYTE, 16), VStatic(WORD, 8), Imm] => [R(0), R(5), Usub(16, 3, 8)];
+    0b01101111_00010000_10000100_00000000 = [VStatic(WORD, 8), VStatic(DWORD, 4), Imm] => [R(0), R(5), Usub(16, 4, 16)];
+    0b01101111_00100000_10000100_00000000 = [VStatic(DWORD, 4), VStatic(QWORD, 2), Imm] => [R(0), R(5), Usub(16, 5, 32)];
+]
+"sqshrunb" = [
+    0b01000101_00101000_00000000_00000000 = [Z(BYTE), Z(WORD), Imm] => [R(0), R(5), Usub(16, 3, 8)], SVE2;
+    0b01000101_00110000_00000000_00000000 = [Z(WORD), Z(DWORD), Imm] => [R(0), R(5), Usub(16, 4, 16)], SVE2;
+    0b01000101_01100000_00000000_00000000 = [Z(DWORD), Z(QWORD), Imm] => [R(0), R(5), Usub(16, 5, 32)], SVE2;
+]
+"sqshrunt" = [
+    0b01000101_00101000_00000100_00000000 = [Z(BYTE), Z(WORD), Imm] => [R(0), R(5), Usub(16, 3, 8)], SVE2;
+    0b01000101_00110000_00000100_00000000 = [Z(WORD), Z(DWORD), Imm] => [R(0), R(5), Usub(16, 4, 16)], SVE2;
+    0b01000101_01100000_00000100_00000000 = [Z(DWORD), Z(QWORD), Imm] => [R(0), R(5), Usub(16, 5, 32)], SVE2;
+]
+"sqsub" = [
+    // SQSUB
+    0b01011110_00100000_00101100_00000000 = [B, B, B] => [R(0), R(5), R(16)];
+    0b01011110_01100000_00101100_00000000 = [H, H, H] => [R(0), R(5), R(16)];
+    0b01011110_10100000_00101100_00000000 = [S, S, S] => [R(0), R(5), R(16)];
+    0b01011110_11100000_00101100_00000000 = [D, D, D] => [R(0), R(5), R(16)];
+    0b00001110_00100000_00101100_00000000 = [V(BYTE), V(BYTE), V(BYTE)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b00001110_01100000_00101100_00000000 = [V(WORD), V(WORD), V(WORD)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b00001110_10100000_00101100_00000000 = [V(DWORD), V(DWORD), V(DWORD)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b00001110_11100000_00101100_00000000 = [VStatic(QWORD, 2), VStatic(QWORD, 2), VStatic(QWORD, 2)] => [R(0), R(5), R(16), Rwidth(30)];
+    // SQSUB (vectors, unpredicated)
+    0b00000100_00100000_00011000_00000000 = [Z(BYTE), Z(BYTE), Z(BYTE)] => [R(0), R(5), R(16)], SVE;
+    0b00000100_01100000_00011000_00000000 = [Z(WORD), Z(WORD), Z(WORD)] => [R(0), R(5), R(16)], SVE;
+    0b00000100_10100000_00011000_00000000 = [Z(DWORD), Z(DWORD), Z(DWORD)] => [R(0), R(5), R(16)], SVE;
+    0b00000100_11100000_00011000_00000000 = [Z(QWORD), Z(QWORD), Z(QWORD)] => [R(0), R(5), R(16)], SVE;
+    // SQSUB (immediate)
+    0b00100101_00100110_11000000_00000000 = [Z(BYTE), Z(BYTE), Imm] => [R(0), RTied, Ubits(5, 8)], SVE;
+    0b00100101_01100110_11000000_00000000 = [Z(WORD), Z(WORD), Imm, End, LitMod(LSL)] => [R(0), RTied, Ubits(5, 8), Ulist(13, &[0, 8])], SVE;
+    0b00100101_10100110_11000000_00000000 = [Z(DWORD), Z(DWORD), Imm, End, LitMod(LSL)] => [R(0), RTied, Ubits(5, 8), Ulist(13, &[0, 8])], SVE;
+    0b00100101_11100110_11000000_00000000 = [Z(QWORD), Z(QWORD), Imm, End, LitMod(LSL)] => [R(0), RTied, Ubits(5, 8), Ulist(13, &[0, 8])], SVE;
+    // SQSUB (vectors, predicated)
+    0b01000100_00011010_10000000_00000000 = [Z(BYTE), PMerge, Z(BYTE), Z(BYTE)] => [R(0), R3(10), RTied, R(5)], SVE2;
+    0b01000100_01011010_10000000_00000000 = [Z(WORD), PMerge, Z(WORD), Z(WORD)] => [R(0), R3(10), RTied, R(5)], SVE2;
+    0b01000100_10011010_10000000_00000000 = [Z(DWORD), PMerge, Z(DWORD), Z(DWORD)] => [R(0), R3(10), RTied, R(5)], SVE2;
+    0b01000100_11011010_10000000_00000000 = [Z(QWORD), PMerge, Z(QWORD), Z(QWORD)] => [R(0), R3(10), RTied, R(5)], SVE2;
+]
+"sqsubr" = [
+    0b01000100_00011110_10000000_00000000 = [Z(BYTE), PMerge, Z(BYTE), Z(BYTE)] => [R(0), R3(10), RTied, R(5)], SVE2;
+    0b01000100_01011110_10000000_00000000 = [Z(WORD), PMerge, Z(WORD), Z(WORD)] => [R(0), R3(10), RTied, R(5)], SVE2;
+    0b01000100_10011110_10000000_00000000 = [Z(DWORD), PMerge, Z(DWORD), Z(DWORD)] => [R(0), R3(10), RTied, R(5)], SVE2;
+    0b01000100_11011110_10000000_00000000 = [Z(QWORD), PMerge, Z(QWORD), Z(QWORD)] => [R(0), R3(10), RTied, R(5)], SVE2;
+]
+"sqxtn" = [
+    0b01011110_00100001_01001000_00000000 = [B, H] => [R(0), R(5)];
+    0b01011110_01100001_01001000_00000000 = [H, S] => [R(0), R(5)];
+    0b01011110_10100001_01001000_00000000 = [S, D] => [R(0), R(5)];
+    0b00001110_00100001_01001000_00000000 = [VStatic(BYTE, 8), VStatic(WORD, 8)] => [R(0), R(5)];
+    0b00001110_01100001_01001000_00000000 = [VStatic(WORD, 4), VStatic(DWORD, 4)] => [R(0), R(5)];
+    0b00001110_10100001_01001000_00000000 = [VStatic(DWORD, 2), VStatic(QWORD, 2)] => [R(0), R(5)];
+]
+"sqxtn2" = [
+    0b01001110_00100001_01001000_00000000 = [VStatic(BYTE, 16), VStatic(WORD, 8)] => [R(0), R(5)];
+    0b01001110_01100001_01001000_00000000 = [VStatic(WORD, 8), VStatic(DWORD, 4)] => [R(0), R(5)];
+    0b01001110_10100001_01001000_00000000 = [VStatic(DWORD, 4), VStatic(QWORD, 2)] => [R(0), R(5)];
+]
+"sqxtnb" = [
+    0b01000101_00101000_01000000_00000000 = [Z(BYTE), Z(WORD)] => [R(0), R(5)], SVE2;
+    0b01000101_00110000_01000000_00000000 = [Z(WORD), Z(DWORD)] => [R(0), R(5)], SVE2;
+    0b01000101_01100000_01000000_00000000 = [Z(DWORD), Z(QWORD)] => [R(0), R(5)], SVE2;
+]
+"sqxtnt" = [
+    0b01000101_00101000_01000100_00000000 = [Z(BYTE), Z(WORD)] => [R(0), R(5)], SVE2;
+    0b01000101_00110000_01000100_00000000 = [Z(WORD), Z(DWORD)] => [R(0), R(5)], SVE2;
+    0b01000101_01100000_01000100_00000000 = [Z(DWORD), Z(QWORD)] => [R(0), R(5)], SVE2;
+]
+"sqxtun" = [
+    0b01111110_00100001_00101000_00000000 = [B, H] => [R(0), R(5)];
+    0b01111110_01100001_00101000_00000000 = [H, S] => [R(0), R(5)];
+    0b01111110_10100001_00101000_00000000 = [S, D] => [R(0), R(5)];
+    0b00101110_00100001_00101000_00000000 = [VStatic(BYTE, 8), VStatic(WORD, 8)] => [R(0), R(5)];
+    0b00101110_01100001_00101000_00000000 = [VStatic(WORD, 4), VStatic(DWORD, 4)] => [R(0), R(5)];
+    0b00101110_10100001_00101000_00000000 = [VStatic(DWORD, 2), VStatic(QWORD, 2)] => [R(0), R(5)];
+]
+"sqxtun2" = [
+    0b01101110_00100001_00101000_00000000 = [VStatic(BYTE, 16), VStatic(WORD, 8)] => [R(0), R(5)];
+    0b01101110_01100001_00101000_00000000 = [VStatic(WORD, 8), VStatic(DWORD, 4)] => [R(0), R(5)];
+    0b01101110_10100001_00101000_00000000 = [VStatic(DWORD, 4), VStatic(QWORD, 2)] => [R(0), R(5)];
+]
+"sqxtunb" = [
+    0b01000101_00101000_01010000_00000000 = [Z(BYTE), Z(WORD)] => [R(0), R(5)], SVE2;
+    0b01000101_00110000_01010000_00000000 = [Z(WORD), Z(DWORD)] => [R(0), R(5)], SVE2;
+    0b01000101_01100000_01010000_00000000 = [Z(DWORD), Z(QWORD)] => [R(0), R(5)], SVE2;
+]
+"sqxtunt" = [
+    0b01000101_00101000_01010100_00000000 = [Z(BYTE), Z(WORD)] => [R(0), R(5)], SVE2;
+    0b01000101_00110000_01010100_00000000 = [Z(WORD), Z(DWORD)] => [R(0), R(5)], SVE2;
+    0b01000101_01100000_01010100_00000000 = [Z(DWORD), Z(QWORD)] => [R(0), R(5)], SVE2;
+]
+"srhadd" = [
+    // SRHADD
+    0b00001110_00100000_00010100_00000000 = [V(BYTE), V(BYTE), V(BYTE)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b00001110_01100000_00010100_00000000 = [V(WORD), V(WORD), V(WORD)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b00001110_10100000_00010100_00000000 = [V(DWORD), V(DWORD), V(DWORD)] => [R(0), R(5), R(16), Rwidth(30)];
+    // SRHADD (vectors, predicated)
+    0b01000100_00010100_10000000_00000000 = [Z(BYTE), PMerge, Z(BYTE), Z(BYTE)] => [R(0), R3(10), RTied, R(5)], SVE2;
+    0b01000100_01010100_10000000_00000000 = [Z(WORD), PMerge, Z(WORD), Z(WORD)] => [R(0), R3(10), RTied, R(5)], SVE2;
+    0b01000100_10010100_10000000_00000000 = [Z(DWORD), PMerge, Z(DWORD), Z(DWORD)] => [R(0), R3(10), RTied, R(5)], SVE2;
+    0b01000100_11010100_10000000_00000000 = [Z(QWORD), PMerge, Z(QWORD), Z(QWORD)] => [R(0), R3(10), RTied, R(5)], SVE2;
+]
+"sri" = [
+    0b01111111_00000000_01000100_00000000 = [D, D, Imm] => [R(0), R(5), BUrange(1, 64), Usub(16, 7, 128)];
+    0b00101111_00001000_01000100_00000000 = [V(BYTE), V(BYTE), Imm] => [R(0), R(5), Usub(16, 3, 8), Rwidth(30)];
+    0b00101111_00010000_01000100_00000000 = [V(WORD), V(WORD), Imm] => [R(0), R(5), Usub(16, 4, 16), Rwidth(30)];
+    0b00101111_00100000_01000100_00000000 = [V(DWORD), V(DWORD), Imm] => [R(0), R(5), Usub(16, 5, 32), Rwidth(30)];
+    0b00101111_01000000_01000100_00000000 = [VStatic(QWORD, 2), VStatic(QWORD, 2), Imm] => [R(0), R(5), Usub(16, 6, 64), Rwidth(30)];
+    0b01000101_00001000_11110000_00000000 = [Z(BYTE), Z(BYTE), Imm] => [R(0), R(5), Usub(16, 3, 8)], SVE2;
+    0b01000101_00010000_11110000_00000000 = [Z(WORD), Z(WORD), Imm] => [R(0), R(5), Usub(16, 4, 16)], SVE2;
+    0b01000101_01000000_11110000_00000000 = [Z(DWORD), Z(DWORD), Imm] => [R(0), R(5), Usub(16, 5, 32)], SVE2;
+    0b01000101_10000000_11110000_00000000 = [Z(QWORD), Z(QWORD), Imm] => [R(0), R(5), BUrange(1, 64), Unegslice(16, 5, 0), Unegslice(22, 1, 5), A], SVE2;
+]
+"srshl" = [
+    // SRSHL
+    0b01011110_11100000_01010100_00000000 = [D, D, D] => [R(0), R(5), R(16)];
+    0b00001110_00100000_01010100_00000000 = [V(BYTE), V(BYTE), V(BYTE)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b00001110_01100000_01010100_00000000 = [V(WORD), V(WORD), V(WORD)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b00001110_10100000_01010100_00000000 = [V(DWORD), V(DWORD), V(DWORD)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b00001110_11100000_01010100_00000000 = [VStatic(QWORD, 2), VStatic(QWORD, 2), VStatic(QWORD, 2)] => [R(0), R(5), R(16), Rwidth(30)];
+    // SRSHL (vectors, predicated)
+    0b01000100_00000010_10000000_00000000 = [Z(BYTE), PMerge, Z(BYTE), Z(BYTE)] => [R(0), R3(10), RTied, R(5)], SVE2;
+    0b01000100_01000010_10000000_00000000 = [Z(WORD), PMerge, Z(WORD), Z(WORD)] => [R(0), R3(10), RTied, R(5)], SVE2;
+    0b01000100_10000010_10000000_00000000 = [Z(DWORD), PMerge, Z(DWORD), Z(DWORD)] => [R(0), R3(10), RTied, R(5)], SVE2;
+    0b01000100_11000010_10000000_00000000 = [Z(QWORD), PMerge, Z(QWORD), Z(QWORD)] => [R(0), R3(10), RTied, R(5)], SVE2;
+]
+"srshlr" = [
+    0b01000100_00000110_10000000_00000000 = [Z(BYTE), PMerge, Z(BYTE), Z(BYTE)] => [R(0), R3(10), RTied, R(5)], SVE2;
+    0b01000100_01000110_10000000_00000000 = [Z(WORD), PMerge, Z(WORD), Z(WORD)] => [R(0), R3(10), RTied, R(5)], SVE2;
+    0b01000100_10000110_10000000_00000000 = [Z(DWORD), PMerge, Z(DWORD), Z(DWORD)] => [R(0), R3(10), RTied, R(5)], SVE2;
+    0b01000100_11000110_10000000_00000000 = [Z(QWORD), PMerge, Z(QWORD), Z(QWORD)] => [R(0), R3(10), RTied, R(5)], SVE2;
+]
+"srshr" = [
+    0b01011111_00000000_00100100_00000000 = [D, D, Imm] => [R(0), R(5), BUrange(1, 64), Usub(16, 7, 128)];
+    0b00001111_00001000_00100100_00000000 = [V(BYTE), V(BYTE), Imm] => [R(0), R(5), Usub(16, 3, 8), Rwidth(30)];
+    0b00001111_00010000_00100100_00000000 = [V(WORD), V(WORD), Imm] => [R(0), R(5), Usub(16, 4, 16), Rwidth(30)];
+    0b00001111_00100000_00100100_00000000 = [V(DWORD), V(DWORD), Imm] => [R(0), R(5), Usub(16, 5, 32), Rwidth(30)];
+    0b00001111_01000000_00100100_00000000 = [VStatic(QWORD, 2), VStatic(QWORD, 2), Imm] => [R(0), R(5), Usub(16, 6, 64), Rwidth(30)];
+]
+"srsra" = [
+    0b01011111_00000000_00110100_00000000 = [D, D, Imm] => [R(0), R(5), BUrange(1, 64), Usub(16, 7, 128)];
+    0b00001111_00001000_00110100_00000000 = [V(BYTE), V(BYTE), Imm] => [R(0), R(5), Usub(16, 3, 8), Rwidth(30)];
+    0b00001111_00010000_00110100_00000000 = [V(WORD), V(WORD), Imm] => [R(0), R(5), Usub(16, 4, 16), Rwidth(30)];
+    0b00001111_00100000_00110100_00000000 = [V(DWORD), V(DWORD), Imm] => [R(0), R(5), Usub(16, 5, 32), Rwidth(30)];
+    0b00001111_01000000_00110100_00000000 = [VStatic(QWORD, 2), VStatic(QWORD, 2), Imm] => [R(0), R(5), Usub(16, 6, 64), Rwidth(30)];
+    0b01000101_00001000_11101000_00000000 = [Z(BYTE), Z(BYTE), Imm] => [R(0), R(5), Usub(16, 3, 8)], SVE2;
+    0b01000101_00010000_11101000_00000000 = [Z(WORD), Z(WORD), Imm] => [R(0), R(5), Usub(16, 4, 16)], SVE2;
+    0b01000101_01000000_11101000_00000000 = [Z(DWORD), Z(DWORD), Imm] => [R(0), R(5), Usub(16, 5, 32)], SVE2;
+    0b01000101_10000000_11101000_00000000 = [Z(QWORD), Z(QWORD), Imm] => [R(0), R(5), BUrange(1, 64), Unegslice(16, 5, 0), Unegslice(22, 1, 5), A], SVE2;
+]
+"ssbb" = [
+    0b11010101_00000011_00110000_10011111 = [] => [];
+]
+"sshl" = [
+    0b01011110_11100000_01000100_00000000 = [D, D, D] => [R(0), R(5), R(16)];
+    0b00001110_00100000_01000100_00000000 = [V(BYTE), V(BYTE), V(BYTE)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b00001110_01100000_01000100_00000000 = [V(WORD), V(WORD), V(WORD)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b00001110_10100000_01000100_00000000 = [V(DWORD), V(DWORD), V(DWORD)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b00001110_11100000_01000100_00000000 = [VStatic(QWORD, 2), VStatic(QWORD, 2), VStatic(QWORD, 2)] => [R(0), R(5), R(16), Rwidth(30)];
+]
+"sshll" = [
+    0b00001111_00001000_10100100_00000000 = [VStatic(WORD, 8), VStatic(BYTE, 8), Imm] => [R(0), R(5), Ubits(16, 3)];
+    0b00001111_00010000_10100100_00000000 = [VStatic(DWORD, 4), VStatic(WORD, 4), Imm] => [R(0), R(5), Ubits(16, 4)];
+    0b00001111_00100000_10100100_00000000 = [VStatic(QWORD, 2), VStatic(DWORD, 2), Imm] => [R(0), R(5), Ubits(16, 5)];
+]
+"sshll2" = [
+    0b01001111_00001000_10100100_00000000 = [VStatic(WORD, 8), VStatic(BYTE, 16), Imm] => [R(0), R(5), Ubits(16, 3)];
+    0b01001111_00010000_10100100_00000000 = [VStatic(DWORD, 4), VStatic(WORD, 8), Imm] => [R(0), R(5), Ubits(16, 4)];
+    0b01001111_00100000_10100100_00000000 = [VStatic(QWORD, 2), VStatic(DWORD, 4), Imm] => [R(0), R(5), Ubits(16, 5)];
+]
+"sshllb" = [
+    0b01000101_00001000_10100000_00000000 = [Z(WORD), Z(BYTE), Imm] => [R(0), R(5), Ubits(16, 3)], SVE2;
+    0b01000101_00010000_10100000_00000000 = [Z(DWORD), Z(WORD), Imm] => [R(0), R(5), Ubits(16, 4)], SVE2;
+    0b01000101_01000000_10100000_00000000 = [Z(QWORD), Z(DWORD), Imm] => [R(0), R(5), Ubits(16, 5)], SVE2;
+]
+"sshllt" = [
+    0b01000101_00001000_10100100_00000000 = [Z(WORD), Z(BYTE), Imm] => [R(0), R(5), Ubits(16, 3)], SVE2;
+    0b01000101_00010000_10100100_00000000 = [Z(DWORD), Z(WORD), Imm] => [R(0), R(5), Ubits(16, 4)], SVE2;
+    0b01000101_01000000_10100100_00000000 = [Z(QWORD), Z(DWORD), Imm] => [R(0), R(5), Ubits(16, 5)], SVE2;
+]
+"sshr" = [
+    0b01011111_00000000_00000100_00000000 = [D, D, Imm] => [R(0), R(5), BUrange(1, 64), Usub(16, 7, 128)];
+    0b00001111_00001000_00000100_00000000 = [V(BYTE), V(BYTE), Imm] => [R(0), R(5), Usub(16, 3, 8), Rwidth(30)];
+    0b00001111_00010000_00000100_00000000 = [V(WORD), V(WORD), Imm] => [R(0), R(5), Usub(16, 4, 16), Rwidth(30)];
+    0b00001111_00100000_00000100_00000000 = [V(DWORD), V(DWORD), Imm] => [R(0), R(5), Usub(16, 5, 32), Rwidth(30)];
+    0b00001111_01000000_00000100_00000000 = [VStatic(QWORD, 2), VStatic(QWORD, 2), Imm] => [R(0), R(5), Usub(16, 6, 64), Rwidth(30)];
+]
+"ssra" = [
+    0b01011111_00000000_00010100_00000000 = [D, D, Imm] => [R(0), R(5), BUrange(1, 64), Usub(16, 7, 128)];
+    0b00001111_00001000_00010100_00000000 = [V(BYTE), V(BYTE), Imm] => [R(0), R(5), Usub(16, 3, 8), Rwidth(30)];
+    0b00001111_00010000_00010100_00000000 = [V(WORD), V(WORD), Imm] => [R(0), R(5), Usub(16, 4, 16), Rwidth(30)];
+    0b00001111_00100000_00010100_00000000 = [V(DWORD), V(DWORD), Imm] => [R(0), R(5), Usub(16, 5, 32), Rwidth(30)];
+    0b00001111_01000000_00010100_00000000 = [VStatic(QWORD, 2), VStatic(QWORD, 2), Imm] => [R(0), R(5), Usub(16, 6, 64), Rwidth(30)];
+    0b01000101_00001000_11100000_00000000 = [Z(BYTE), Z(BYTE), Imm] => [R(0), R(5), Usub(16, 3, 8)], SVE2;
+    0b01000101_00010000_11100000_00000000 = [Z(WORD), Z(WORD), Imm] => [R(0), R(5), Usub(16, 4, 16)], SVE2;
+    0b01000101_01000000_11100000_00000000 = [Z(DWORD), Z(DWORD), Imm] => [R(0), R(5), Usub(16, 5, 32)], SVE2;
+    0b01000101_10000000_11100000_00000000 = [Z(QWORD), Z(QWORD), Imm] => [R(0), R(5), BUrange(1, 64), Unegslice(16, 5, 0), Unegslice(22, 1, 5), A], SVE2;
+]
+"ssubl" = [
+    0b00001110_00100000_00100000_00000000 = [VStatic(WORD, 8), VStatic(BYTE, 8), VStatic(BYTE, 8)] => [R(0), R(5), R(16)];
+    0b00001110_01100000_00100000_00000000 = [VStatic(DWORD, 4), VStatic(WORD, 4), VStatic(WORD, 4)] => [R(0), R(5), R(16)];
+    0b00001110_10100000_00100000_00000000 = [VStatic(QWORD, 2), VStatic(DWORD, 2), VStatic(DWORD, 2)] => [R(0), R(5), R(16)];
+]
+"ssubl2" = [
+    0b01001110_00100000_00100000_00000000 = [VStatic(WORD, 8), VStatic(BYTE, 16), VStatic(BYTE, 16)] => [R(0), R(5), R(16)];
+    0b01001110_01100000_00100000_00000000 = [VStatic(DWORD, 4), VStatic(WORD, 8), VStatic(WORD, 8)] => [R(0), R(5), R(16)];
+    0b01001110_10100000_00100000_00000000 = [VStatic(QWORD, 2), VStatic(DWORD, 4), VStatic(DWORD, 4)] => [R(0), R(5), R(16)];
+]
+"ssublb" = [
+    0b01000101_01000000_00010000_00000000 = [Z(WORD), Z(BYTE), Z(BYTE)] => [R(0), R(5), R(16)], SVE2;
+    0b01000101_10000000_00010000_00000000 = [Z(DWORD), Z(WORD), Z(WORD)] => [R(0), R(5), R(16)], SVE2;
+    0b01000101_11000000_00010000_00000000 = [Z(QWORD), Z(DWORD), Z(DWORD)] => [R(0), R(5), R(16)], SVE2;
+]
+"ssublt" = [
+    0b01000101_01000000_00010100_00000000 = [Z(WORD), Z(BYTE), Z(BYTE)] => [R(0), R(5), R(16)], SVE2;
+    0b01000101_10000000_00010100_00000000 = [Z(DWORD), Z(WORD), Z(WORD)] => [R(0), R(5), R(16)], SVE2;
+    0b01000101_11000000_00010100_00000000 = [Z(QWORD), Z(DWORD), Z(DWORD)] => [R(0), R(5), R(16)], SVE2;
+]
+"ssubw" = [
+    0b00001110_00100000_00110000_00000000 = [VStatic(WORD, 8), VStatic(WORD, 8), VStatic(BYTE, 8)] => [R(0), R(5), R(16)];
+    0b00001110_01100000_00110000_00000000 = [VStatic(DWORD, 4), VStatic(DWORD, 4), VStatic(WORD, 4)] => [R(0), R(5), R(16)];
+    0b00001110_10100000_00110000_00000000 = [VStatic(QWORD, 2), VStatic(QWORD, 2), VStatic(DWORD, 2)] => [R(0), R(5), R(16)];
+]
+"ssubw2" = [
+    0b01001110_00100000_00110000_00000000 = [VStatic(WORD, 8), VStatic(WORD, 8), VStatic(BYTE, 16)] => [R(0), R(5), R(16)];
+    0b01001110_01100000_00110000_00000000 = [VStatic(DWORD, 4), VStatic(DWORD, 4), VStatic(WORD, 8)] => [R(0), R(5), R(16)];
+    0b01001110_10100000_00110000_00000000 = [VStatic(QWORD, 2), VStatic(QWORD, 2), VStatic(DWORD, 4)] => [R(0), R(5), R(16)];
+]
+"ssubwb" = [
+    0b01000101_01000000_01010000_00000000 = [Z(WORD), Z(WORD), Z(BYTE)] => [R(0), R(5), R(16)], SVE2;
+    0b01000101_10000000_01010000_00000000 = [Z(DWORD), Z(DWORD), Z(WORD)] => [R(0), R(5), R(16)], SVE2;
+    0b01000101_11000000_01010000_00000000 = [Z(QWORD), Z(QWORD), Z(DWORD)] => [R(0), R(5), R(16)], SVE2;
+]
+"ssubwt" = [
+    0b01000101_01000000_01010100_00000000 = [Z(WORD), Z(WORD), Z(BYTE)] => [R(0), R(5), R(16)], SVE2;
+    0b01000101_10000000_01010100_00000000 = [Z(DWORD), Z(DWORD), Z(WORD)] => [R(0), R(5), R(16)], SVE2;
+    0b01000101_11000000_01010100_00000000 = [Z(QWORD), Z(QWORD), Z(DWORD)] => [R(0), R(5), R(16)], SVE2;
+]
+"st1" = [
+    // ST1 (multiple structures)
+    0b00001100_00000000_01110000_00000000 = [RegList(1, BYTE), RefBase] => [R(0), R(5), Rwidth(30)];
+    0b00001100_00000000_01110100_00000000 = [RegList(1, WORD), RefBase] => [R(0), R(5), Rwidth(30)];
+    0b00001100_00000000_01111000_00000000 = [RegList(1, DWORD), RefBase] => [R(0), R(5), Rwidth(30)];
+    0b00001100_00000000_01111100_00000000 = [RegList(1, QWORD), RefBase] => [R(0), R(5), Rwidth(30)];
+    0b00001100_00000000_10100000_00000000 = [RegList(2, BYTE), RefBase] => [R(0), R(5), Rwidth(30)];
+    0b00001100_00000000_10100100_00000000 = [RegList(2, WORD), RefBase] => [R(0), R(5), Rwidth(30)];
+    0b00001100_00000000_10101000_00000000 = [RegList(2, DWORD), RefBase] => [R(0), R(5), Rwidth(30)];
+    0b00001100_00000000_10101100_00000000 = [RegList(2, QWORD), RefBase] => [R(0), R(5), Rwidth(30)];
+    0b00001100_00000000_01100000_00000000 = [RegList(3, BYTE), RefBase] => [R(0), R(5), Rwidth(30)];
+    0b00001100_00000000_01100100_00000000 = [RegList(3, WORD), RefBase] => [R(0), R(5), Rwidth(30)];
+    0b00001100_00000000_01101000_00000000 = [RegList(3, DWORD), RefBase] => [R(0), R(5), Rwidth(30)];
+    0b00001100_00000000_01101100_00000000 = [RegList(3, QWORD), RefBase] => [R(0), R(5), Rwidth(30)];
+    0b00001100_00000000_00100000_00000000 = [RegList(4, BYTE), RefBase] => [R(0), R(5), Rwidth(30)];
+    0b00001100_00000000_00100100_00000000 = [RegList(4, WORD), RefBase] => [R(0), R(5), Rwidth(30)];
+    0b00001100_00000000_00101000_00000000 = [RegList(4, DWORD), RefBase] => [R(0), R(5), Rwidth(30)];
+    0b00001100_00000000_00101100_00000000 = [RegList(4, QWORD), RefBase] => [R(0), R(5), Rwidth(30)];
+    0b00001100_10011111_01110000_00000000 = [RegListStatic(1, BYTE, 8), RefBase, LitInt(8)] => [R(0), R(5)];
+    0b00001100_10011111_01110100_00000000 = [RegListStatic(1, WORD, 4), RefBase, LitInt(8)] => [R(0), R(5)];
+    0b00001100_10011111_01111000_00000000 = [RegListStatic(1, DWORD, 2), RefBase, LitInt(8)] => [R(0), R(5)];
+    0b00001100_10011111_01111100_00000000 = [RegListStatic(1, QWORD, 1), RefBase, LitInt(8)] => [R(0), R(5)];
+    0b01001100_10011111_01110000_00000000 = [RegListStatic(1, BYTE, 16), RefBase, LitInt(16)] => [R(0), R(5)];
+    0b01001100_10011111_01110100_00000000 = [RegListStatic(1, WORD, 8), RefBase, LitInt(16)] => [R(0), R(5)];
+    0b01001100_10011111_01111000_00000000 = [RegListStatic(1, DWORD, 4), RefBase, LitInt(16)] => [R(0), R(5)];
+    0b01001100_10011111_01111100_00000000 = [RegListStatic(1, QWORD, 2), RefBase, LitInt(16)] => [R(0), R(5)];
+    0b00001100_10000000_01110000_00000000 = [RegList(1, BYTE), RefBase, X] => [R(0), R(5), RNoZr(16), Rwidth(30)];
+    0b00001100_10000000_01110100_00000000 = [RegList(1, WORD), RefBase, X] => [R(0), R(5), RNoZr(16), Rwidth(30)];
+    0b00001100_10000000_01111000_00000000 = [RegList(1, DWORD), RefBase, X] => [R(0), R(5), RNoZr(16), Rwidth(30)];
+    0b00001100_10000000_01111100_00000000 = [RegList(1, QWORD), RefBase, X] => [R(0), R(5), RNoZr(16), Rwidth(30)];
+    0b00001100_10011111_10100000_00000000 = [RegListStatic(2, BYTE, 8), RefBase, LitInt(16)] => [R(0), R(5)];
+    0b00001100_10011111_10100100_00000000 = [RegListStatic(2, WORD, 4), RefBase, LitInt(16)] => [R(0), R(5)];
+    0b00001100_10011111_10101000_00000000 = [RegListStatic(2, DWORD, 2), RefBase, LitInt(16)] => [R(0), R(5)];
+    0b00001100_10011111_10101100_00000000 = [RegListStatic(2, QWORD, 1), RefBase, LitInt(16)] => [R(0), R(5)];
+    0b01001100_10011111_10100000_00000000 = [RegListStatic(2, BYTE, 16), RefBase, LitInt(32)] => [R(0), R(5)];
+    0b01001100_10011111_10100100_00000000 = [RegListStatic(2, WORD, 8), RefBase, LitInt(32)] => [R(0), R(5)];
+    0b01001100_10011111_10101000_00000000 = [RegListStatic(2, DWORD, 4), RefBase, LitInt(32)] => [R(0), R(5)];
+    0b01001100_10011111_10101100_00000000 = [RegListStatic(2, QWORD, 2), RefBase, LitInt(32)] => [R(0), R(5)];
+    0b00001100_10000000_10100000_00000000 = [RegList(2, BYTE), RefBase, X] => [R(0), R(5), RNoZr(16), Rwidth(30)];
+    0b00001100_10000000_10100100_00000000 = [RegList(2, WORD), RefBase, X] => [R(0), R(5), RNoZr(16), Rwidth(30)];
+    0b00001100_10000000_10101000_00000000 = [RegList(2, DWORD), RefBase, X] => [R(0), R(5), RNoZr(16), Rwidth(30)];
+    0b00001100_10000000_10101100_00000000 = [RegList(2, QWORD), RefBase, X] => [R(0), R(5), RNoZr(16), Rwidth(30)];
+    0b00001100_10011111_01100000_00000000 = [RegListStatic(3, BYTE, 8), RefBase, LitInt(24)] => [R(0), R(5)];
+    0b00001100_10011111_01100100_00000000 = [RegListStatic(3, WORD, 4), RefBase, LitInt(24)] => [R(0), R(5)];
+    0b00001100_10011111_01101000_00000000 = [RegListStatic(3, DWORD, 2), RefBase, LitInt(24)] => [R(0), R(5)];
+    0b00001100_10011111_01101100_00000000 = [RegListStatic(3, QWORD, 1), RefBase, LitInt(24)] => [R(0), R(5)];
+    0b01001100_10011111_01100000_00000000 = [RegListStatic(3, BYTE, 16), RefBase, LitInt(48)] => [R(0), R(5)];
+    0b01001100_10011111_01100100_00000000 = [RegListStatic(3, WORD, 8), RefBase, LitInt(48)] => [R(0), R(5)];
+    0b01001100_10011111_01101000_00000000 = [RegListStatic(3, DWORD, 4), RefBase, LitInt(48)] => [R(0), R(5)];
+    0b01001100_10011111_01101100_00000000 = [RegListStatic(3, QWORD, 2), RefBase, LitInt(48)] => [R(0), R(5)];
+    0b00001100_10000000_01100000_00000000 = [RegList(3, BYTE), RefBase, X] => [R(0), R(5), RNoZr(16), Rwidth(30)];
+    0b00001100_10000000_01100100_00000000 = [RegList(3, WORD), RefBase, X] => [R(0), R(5), RNoZr(16), Rwidth(30)];
+    0b00001100_10000000_01101000_00000000 = [RegList(3, DWORD), RefBase, X] => [R(0), R(5), RNoZr(16), Rwidth(30)];
+    0b00001100_10000000_01101100_00000000 = [RegList(3, QWORD), RefBase, X] => [R(0), R(5), RNoZr(16), Rwidth(30)];
+    0b00001100_10011111_00100000_00000000 = [RegListStatic(4, BYTE, 8), RefBase, LitInt(32)] => [R(0), R(5)];
+    0b00001100_10011111_00100100_00000000 = [RegListStatic(4, WORD, 4), RefBase, LitInt(32)] => [R(0), R(5)];
+    0b00001100_10011111_00101000_00000000 = [RegListStatic(4, DWORD, 2), RefBase, LitInt(32)] => [R(0), R(5)];
+    0b00001100_10011111_00101100_00000000 = [RegListStatic(4, QWORD, 1), RefBase, LitInt(32)] => [R(0), R(5)];
+    0b01001100_10011111_00100000_00000000 = [RegListStatic(4, BYTE, 16), RefBase, LitInt(64)] => [R(0), R(5)];
+    0b01001100_10011111_00100100_00000000 = [RegListStatic(4, WORD, 8), RefBase, LitInt(64)] => [R(0), R(5)];
+    0b01001100_10011111_00101000_00000000 = [RegListStatic(4, DWORD, 4), RefBase, LitInt(64)] => [R(0), R(5)];
+    0b01001100_10011111_00101100_00000000 = [RegListStatic(4, QWORD, 2), RefBase, LitInt(64)] => [R(0), R(5)];
+    0b00001100_10000000_00100000_00000000 = [RegList(4, BYTE), RefBase, X] => [R(0), R(5), RNoZr(16), Rwidth(30)];
+    0b00001100_10000000_00100100_00000000 = [RegList(4, WORD), RefBase, X] => [R(0), R(5), RNoZr(16), Rwidth(30)];
+    0b00001100_10000000_00101000_00000000 = [RegList(4, DWORD), RefBase, X] => [R(0), R(5), RNoZr(16), Rwidth(30)];
+    0b00001100_10000000_00101100_00000000 = [RegList(4, QWORD), RefBase, X] => [R(0), R(5), RNoZr(16), Rwidth(30)];
+    // ST1 (single structure)
+    0b00001101_00000000_00000000_00000000 = [RegListElement(1, BYTE), RefBase] => [R(0), Ufields(&[30, 12, 11, 10]), R(5)];
+    0b00001101_00000000_01000000_00000000 = [RegListElement(1, WORD), RefBase] => [R(0), Ufields(&[30, 12, 11]), R(5)];
+    0b00001101_00000000_10000000_00000000 = [RegListElement(1, DWORD), RefBase] => [R(0), Ufields(&[30, 12]), R(5)];
+    0b00001101_00000000_10000100_00000000 = [RegListElement(1, QWORD), RefBase] => [R(0), Ufields(&[30]), R(5)];
+    0b00001101_10011111_00000000_00000000 = [RegListElement(1, BYTE), RefBase, LitInt(1)] => [R(0), Ufields(&[30, 12, 11, 10]), R(5)];
+    0b00001101_10000000_00000000_00000000 = [RegListElement(1, BYTE), RefBase, X] => [R(0), Ufields(&[30, 12, 11, 10]), R(5), RNoZr(16)];
+    0b00001101_10011111_01000000_00000000 = [RegListElement(1, WORD), RefBase, LitInt(2)] => [R(0), Ufields(&[30, 12, 11]), R(5)];
+    0b00001101_10000000_01000000_00000000 = [RegListElement(1, WORD), RefBase, X] => [R(0), Ufields(&[30, 12, 11]), R(5), RNoZr(16)];
+    0b00001101_10011111_10000000_00000000 = [RegListElement(1, DWORD), RefBase, LitInt(4)] => [R(0), Ufields(&[30, 12]), R(5)];
+    0b00001101_10000000_10000000_00000000 = [RegListElement(1, DWORD), RefBase, X] => [R(0), Ufields(&[30, 12]), R(5), RNoZr(16)];
+    0b00001101_10011111_10000100_00000000 = [RegListElement(1, QWORD), RefBase, LitInt(8)] => [R(0), Ufields(&[30]), R(5)];
+    0b00001101_10000000_10000100_00000000 = [RegListElement(1, QWORD), RefBase, X] => [R(0), Ufields(&[30]), R(5), RNoZr(16)];
+]
+"st1b" = [
+    // ST1B (scalar plus scalar)
+    0b11100100_00000000_01000000_00000000 = [ZRegList(1, BYTE), PBare, RefIndexLsl(0)] => [R(0), R3(10), R(5), RNoZr(16)], SVE;
+    0b11100100_00100000_01000000_00000000 = [ZRegList(1, WORD), PBare, RefIndexLsl(0)] => [R(0), R3(10), R(5), RNoZr(16)], SVE;
+    0b11100100_01000000_01000000_00000000 = [ZRegList(1, DWORD), PBare, RefIndexLsl(0)] => [R(0), R3(10), R(5), RNoZr(16)], SVE;
+    0b11100100_01100000_01000000_00000000 = [ZRegList(1, QWORD), PBare, RefIndexLsl(0)] => [R(0), R3(10), R(5), RNoZr(16)], SVE;
+    // ST1B (scalar plus immediate)
+    0b11100100_00000000_11100000_00000000 = [ZRegList(1, BYTE), PBare, RefMulVl] => [R(0), R3(10), R(5), Sbits(16, 4)], SVE;
+    0b11100100_00100000_11100000_00000000 = [ZRegList(1, WORD), PBare, RefMulVl] => [R(0), R3(10), R(5), Sbits(16, 4)], SVE;
+    0b11100100_01000000_11100000_00000000 = [ZRegList(1, DWORD), PBare, RefMulVl] => [R(0), R3(10), R(5), Sbits(16, 4)], SVE;
+    0b11100100_01100000_11100000_00000000 = [ZRegList(1, QWORD), PBare, RefMulVl] => [R(0), R3(10), R(5), Sbits(16, 4)], SVE;
+    // ST1B (scalar plus vector)
+    0b11100100_00000000_10000000_00000000 = [ZRegList(1, QWORD), PBare, RefZIndexExtend(QWORD, 0)] => [R(0), R3(10), R(5), R(16), ExtendsZ(14)], SVE;
+    0b11100100_00000000_10100000_00000000 = [ZRegList(1, QWORD), PBare, RefZIndexLsl(QWORD, 0)] => [R(0), R3(10), R(5), R(16)], SVE;
+    0b11100100_01000000_10000000_00000000 = [ZRegList(1, DWORD), PBare, RefZIndexExtend(DWORD, 0)] => [R(0), R3(10), R(5), R(16), ExtendsZ(14)], SVE;
+    // ST1B (vector plus immediate)
+    0b11100100_01000000_10100000_00000000 = [ZRegList(1, QWORD), PBare, RefZBase(QWORD)] => [R(0), R3(10), R(5), Ubits(16, 5)], SVE;
+    0b11100100_01100000_10100000_00000000 = [ZRegList(1, DWORD), PBare, RefZBase(DWORD)] => [R(0), R3(10), R(5), Ubits(16, 5)], SVE;
+    // ST1B
+    0b11100000_00111111_00000000_00000000 = [ZaHList(BYTE), PBare, RefBase] => [RTile(4, 0), RSelect(13), Ubits(0, 4), R3(10), R(5)], SME;
+    0b11100000_00100000_00000000_00000000 = [ZaHList(BYTE), PBare, RefIndexLsl(0)] => [RTile(4, 0), RSelect(13), Ubits(0, 4), R3(10), R(5), R(16)], SME;
+    0b11100000_00111111_10000000_00000000 = [ZaVList(BYTE), PBare, RefBase] => [RTile(4, 0), RSelect(13), Ubits(0, 4), R3(10), R(5)], SME;
+    0b11100000_00100000_10000000_00000000 = [ZaVList(BYTE), PBare, RefIndexLsl(0)] => [RTile(4, 0), RSelect(13), Ubits(0, 4), R3(10), R(5), R(16)], SME;
+]
+"st1d" = [
+    // ST1D (scalar plus scalar)
+    0b11100101_11100000_01000000_00000000 = [ZRegList(1, QWORD), PBare, RefIndexLsl(3)] => [R(0), R3(10), R(5), RNoZr(16)], SVE;
+    // ST1D (scalar plus vector)
+    0b11100101_10100000_10000000_00000000 = [ZRegList(1, QWORD), PBare, RefZIndexExtend(QWORD, 3)] => [R(0), R3(10), R(5), R(16), ExtendsZ(14)], SVE;
+    0b11100101_10000000_10000000_00000000 = [ZRegList(1, QWORD), PBare, RefZIndexExtend(QWORD, 0)] => [R(0), R3(10), R(5), R(16), ExtendsZ(14)], SVE;
+    0b11100101_10100000_10100000_00000000 = [ZRegList(1, QWORD), PBare, RefZIndexLsl(QWORD, 3)] => [R(0), R3(10), R(5), R(16)], SVE;
+    0b11100101_10000000_10100000_00000000 = [ZRegList(1, QWORD), PBare, RefZIndexLsl(QWORD, 0)] => [R(0), R3(10), R(5), R(16)], SVE;
+    // ST1D (scalar plus immediate)
+    0b11100101_11100000_11100000_00000000 = [ZRegList(1, QWORD), PBare, RefMulVl] => [R(0), R3(10), R(5), Sbits(16, 4)], SVE;
+    // ST1D (vector plus immediate)
+    0b11100101_11000000_10100000_00000000 = [ZRegList(1, QWORD), PBare, RefZBase(QWORD)] => [R(0), R3(10), R(5), Uscaled(16, 5, 3)], SVE;
+    // ST1D
+    0b11100000_11111111_00000000_00000000 = [ZaHList(QWORD), PBare, RefBase] => [RTile(1, 3), RSelect(13), Ubits(0, 1), R3(10), R(5)], SME;
+    0b11100000_11100000_00000000_00000000 = [ZaHList(QWORD), PBare, RefIndexLsl(3)] => [RTile(1, 3), RSelect(13), Ubits(0, 1), R3(10), R(5), R(16)], SME;
+    0b11100000_11111111_10000000_00000000 = [ZaVList(QWORD), PBare, RefBase] => [RTile(1, 3), RSelect(13), Ubits(0, 1), R3(10), R(5)], SME;
+    0b11100000_11100000_10000000_00000000 = [ZaVList(QWORD), PBare, RefIndexLsl(3)] => [RTile(1, 3), RSelect(13), Ubits(0, 1), R3(10), R(5), R(16)], SME;
+]
+"st1h" = [
+    // ST1H (scalar plus scalar)
+    0b11100100_10100000_01000000_00000000 = [ZRegList(1, WORD), PBare, RefIndexLsl(1)] => [R(0), R3(10), R(5), RNoZr(16)], SVE;
+    0b11100100_11000000_01000000_00000000 = [ZRegList(1, DWORD), PBare, RefIndexLsl(1)] => [R(0), R3(10), R(5), RNoZr(16)], SVE;
+    0b11100100_11100000_01000000_00000000 = [ZRegList(1, QWORD), PBare, RefIndexLsl(1)] => [R(0), R3(10), R(5), RNoZr(16)], SVE;
+    // ST1H (scalar plus immediate)
+    0b11100100_10100000_11100000_00000000 = [ZRegList(1, WORD), PBare, RefMulVl] => [R(0), R3(10), R(5), Sbits(16, 4)], SVE;
+    0b11100100_11000000_11100000_00000000 = [ZRegList(1, DWORD), PBare, RefMulVl] => [R(0), R3(10), R(5), Sbits(16, 4)], SVE;
+    0b11100100_11100000_11100000_00000000 = [ZRegList(1, QWORD), PBare, RefMulVl] => [R(0), R3(10), R(5), Sbits(16, 4)], SVE;
+    // ST1H (scalar plus vector)
+    0b11100100_10100000_10000000_00000000 = [ZRegList(1, QWORD), PBare, RefZIndexExtend(QWORD, 1)] => [R(0), R3(10), R(5), R(16), ExtendsZ(14)], SVE;
+    0b11100100_10000000_10000000_00000000 = [ZRegList(1, QWORD), PBare, RefZIndexExtend(QWORD, 0)] => [R(0), R3(10), R(5), R(16), ExtendsZ(14)], SVE;
+    0b11100100_10100000_10100000_00000000 = [ZRegList(1, QWORD), PBare, RefZIndexLsl(QWORD, 1)] => [R(0), R3(10), R(5), R(16)], SVE;
+    0b11100100_10000000_10100000_00000000 = [ZRegList(1, QWORD), PBare, RefZIndexLsl(QWORD, 0)] => [R(0), R3(10), R(5), R(16)], SVE;
+    0b11100100_11100000_10000000_00000000 = [ZRegList(1, DWORD), PBare, RefZIndexExtend(DWORD, 1)] => [R(0), R3(10), R(5), R(16), ExtendsZ(14)], SVE;
+    0b11100100_11000000_10000000_00000000 = [ZRegList(1, DWORD), PBare, RefZIndexExtend(DWORD, 0)] => [R(0), R3(10), R(5), R(16), ExtendsZ(14)], SVE;
+    // ST1H (vector plus immediate)
+    0b11100100_11000000_10100000_00000000 = [ZRegList(1, QWORD), PBare, RefZBase(QWORD)] => [R(0), R3(10), R(5), Uscaled(16, 5, 1)], SVE;
+    0b11100100_11100000_10100000_00000000 = [ZRegList(1, DWORD), PBare, RefZBase(DWORD)] => [R(0), R3(10), R(5), Uscaled(16, 5, 1)], SVE;
+    // ST1H
+    0b11100000_01111111_00000000_00000000 = [ZaHList(WORD), PBare, RefBase] => [RTile(3, 1), RSelect(13), Ubits(0, 3), R3(10), R(5)], SME;
+    0b11100000_01100000_00000000_00000000 = [ZaHList(WORD), PBare, RefIndexLsl(1)] => [RTile(3, 1), RSelect(13), Ubits(0, 3), R3(10), R(5), R(16)], SME;
+    0b11100000_01111111_10000000_00000000 = [ZaVList(WORD), PBare, RefBase] => [RTile(3, 1), RSelect(13), Ubits(0, 3), R3(10), R(5)], SME;
+    0b11100000_01100000_10000000_00000000 = [ZaVList(WORD), PBare, RefIndexLsl(1)] => [RTile(3, 1), RSelect(13), Ubits(0, 3), R3(10), R(5), R(16)], SME;
+]
+"st1q" = [
+    0b11100001_11111111_00000000_00000000 = [ZaHList(OWORD), PBare, RefBase] => [RTile(0, 4), RSelect(13), Urange(0, 0, 0), R3(10), R(5)], SME;
+    0b11100001_11100000_00000000_00000000 = [ZaHList(OWORD), PBare, RefIndexLsl(4)] => [RTile(0, 4), RSelect(13), Urange(0, 0, 0), R3(10), R(5), R(16)], SME;
+    0b11100001_11111111_10000000_00000000 = [ZaVList(OWORD), PBare, RefBase] => [RTile(0, 4), RSelect(13), Urange(0, 0, 0), R3(10), R(5)], SME;
+    0b11100001_11100000_10000000_00000000 = [ZaVList(OWORD), PBare, RefIndexLsl(4)] => [RTile(0, 4), RSelect(13), Urange(0, 0, 0), R3(10), R(5), R(16)], SME;
+]
+"st1w" = [
+    // ST1W (scalar plus scalar)
+    0b11100101_01000000_01000000_00000000 = [ZRegList(1, DWORD), PBare, RefIndexLsl(2)] => [R(0), R3(10), R(5), RNoZr(16)], SVE;
+    0b11100101_01100000_01000000_00000000 = [ZRegList(1, QWORD), PBare, RefIndexLsl(2)] => [R(0), R3(10), R(5), RNoZr(16)], SVE;
+    // ST1W (scalar plus immediate)
+    0b11100101_01000000_11100000_00000000 = [ZRegList(1, DWORD), PBare, RefMulVl] => [R(0), R3(10), R(5), Sbits(16, 4)], SVE;
+    0b11100101_01100000_11100000_00000000 = [ZRegList(1, QWORD), PBare, RefMulVl] => [R(0), R3(10), R(5), Sbits(16, 4)], SVE;
+    // ST1W (scalar plus vector)
+    0b11100101_00100000_10000000_00000000 = [ZRegList(1, QWORD), PBare, RefZIndexExtend(QWORD, 2)] => [R(0), R3(10), R(5), R(16), ExtendsZ(14)], SVE;
+    0b11100101_00000000_10000000_00000000 = [ZRegList(1, QWORD), PBare, RefZIndexExtend(QWORD, 0)] => [R(0), R3(10), R(5), R(16), ExtendsZ(14)], SVE;
+    0b11100101_00100000_10100000_00000000 = [ZRegList(1, QWORD), PBare, RefZIndexLsl(QWORD, 2)] => [R(0), R3(10), R(5), R(16)], SVE;
+    0b11100101_00000000_10100000_00000000 = [ZRegList(1, QWORD), PBare, RefZIndexLsl(QWORD, 0)] => [R(0), R3(10), R(5), R(16)], SVE;
+    0b11100101_01100000_10000000_00000000 = [ZRegList(1, DWORD), PBare, RefZIndexExtend(DWORD, 2)] => [R(0), R3(10), R(5), R(16), ExtendsZ(14)], SVE;
+    0b11100101_01000000_10000000_00000000 = [ZRegList(1, DWORD), PBare, RefZIndexExtend(DWORD, 0)] => [R(0), R3(10), R(5), R(16), ExtendsZ(14)], SVE;
+    // ST1W (vector plus immediate)
+    0b11100101_01000000_10100000_00000000 = [ZRegList(1, QWORD), PBare, RefZBase(QWORD)] => [R(0), R3(10), R(5), Uscaled(16, 5, 2)], SVE;
+    0b11100101_01100000_10100000_00000000 = [ZRegList(1, DWORD), PBare, RefZBase(DWORD)] => [R(0), R3(10), R(5), Uscaled(16, 5, 2)], SVE;
+    // ST1W
+    0b11100000_10111111_00000000_00000000 = [ZaHList(DWORD), PBare, RefBase] => [RTile(2, 2), RSelect(13), Ubits(0, 2), R3(10), R(5)], SME;
+    0b11100000_10100000_00000000_00000000 = [ZaHList(DWORD), PBare, RefIndexLsl(2)] => [RTile(2, 2), RSelect(13), Ubits(0, 2), R3(10), R(5), R(16)], SME;
+    0b11100000_10111111_10000000_00000000 = [ZaVList(DWORD), PBare, RefBase] => [RTile(2, 2), RSelect(13), Ubits(0, 2), R3(10), R(5)], SME;
+    0b11100000_10100000_10000000_00000000 = [ZaVList(DWORD), PBare, RefIndexLsl(2)] => [RTile(2, 2), RSelect(13), Ubits(0, 2), R3(10), R(5), R(16)], SME;
+]
+"st2" = [
+    // ST2 (multiple structures)
+    0b00001100_00000000_10000000_00000000 = [RegList(2, BYTE), RefBase] => [R(0), R(5), Rwidth(30)];
+    0b00001100_00000000_10000100_00000000 = [RegList(2, WORD), RefBase] => [R(0), R(5), Rwidth(30)];
+    0b00001100_00000000_10001000_00000000 = [RegList(2, DWORD), RefBase] => [R(0), R(5), Rwidth(30)];
+    0b00001100_00000000_10001100_00000000 = [RegListStatic(2, QWORD, 2), RefBase] => [R(0), R(5), Rwidth(30)];
+    0b00001100_10011111_10000000_00000000 = [RegListStatic(2, BYTE, 8), RefBase, LitInt(16)] => [R(0), R(5)];
+    0b00001100_10011111_10000100_00000000 = [RegListStatic(2, WORD, 4), RefBase, LitInt(16)] => [R(0), R(5)];
+    0b00001100_10011111_10001000_00000000 = [RegListStatic(2, DWORD, 2), RefBase, LitInt(16)] => [R(0), R(5)];
+    0b01001100_10011111_10000000_00000000 = [RegListStatic(2, BYTE, 16), RefBase, LitInt(32)] => [R(0), R(5)];
+    0b01001100_10011111_10000100_00000000 = [RegListStatic(2, WORD, 8), RefBase, LitInt(32)] => [R(0), R(5)];
+    0b01001100_10011111_10001000_00000000 = [RegListStatic(2, DWORD, 4), RefBase, LitInt(32)] => [R(0), R(5)];
+    0b01001100_10011111_10001100_00000000 = [RegListStatic(2, QWORD, 2), RefBase, LitInt(32)] => [R(0), R(5)];
+    0b00001100_10000000_10000000_00000000 = [RegList(2, BYTE), RefBase, X] => [R(0), R(5), RNoZr(16), Rwidth(30)];
+    0b00001100_10000000_10000100_00000000 = [RegList(2, WORD), RefBase, X] => [R(0), R(5), RNoZr(16), Rwidth(30)];
+    0b00001100_10000000_10001000_00000000 = [RegList(2, DWORD), RefBase, X] => [R(0), R(5), RNoZr(16), Rwidth(30)];
+    0b00001100_10000000_10001100_00000000 = [RegListStatic(2, QWORD, 2), RefBase, X] => [R(0), R(5), RNoZr(16), Rwidth(30)];
+    // ST2 (single structure)
+    0b00001101_00100000_00000000_00000000 = [RegListElement(2, BYTE), RefBase] => [R(0), Ufields(&[30, 12, 11, 10]), R(5)];
+    0b00001101_00100000_01000000_00000000 = [RegListElement(2, WORD), RefBase] => [R(0), Ufields(&[30, 12, 11]), R(5)];
+    0b00001101_00100000_10000000_00000000 = [RegListElement(2, DWORD), RefBase] => [R(0), Ufields(&[30, 12]), R(5)];
+    0b00001101_00100000_10000100_00000000 = [RegListElement(2, QWORD), RefBase] => [R(0), Ufields(&[30]), R(5)];
+    0b00001101_10111111_00000000_00000000 = [RegListElement(2, BYTE), RefBase, LitInt(2)] => [R(0), Ufields(&[30, 12, 11, 10]), R(5)];
+    0b00001101_10100000_00000000_00000000 = [RegListElement(2, BYTE), RefBase, X] => [R(0), Ufields(&[30, 12, 11, 10]), R(5), RNoZr(16)];
+    0b00001101_10111111_01000000_00000000 = [RegListElement(2, WORD), RefBase, LitInt(4)] => [R(0), Ufields(&[30, 12, 11]), R(5)];
+    0b00001101_10100000_01000000_00000000 = [RegListElement(2, WORD), RefBase, X] => [R(0), Ufields(&[30, 12, 11]), R(5), RNoZr(16)];
+    0b00001101_10111111_10000000_00000000 = [RegListElement(2, DWORD), RefBase, LitInt(8)] => [R(0), Ufields(&[30, 12]), R(5)];
+    0b00001101_10100000_10000000_00000000 = [RegListElement(2, DWORD), RefBase, X] => [R(0), Ufields(&[30, 12]), R(5), RNoZr(16)];
+    0b00001101_10111111_10000100_00000000 = [RegListElement(2, QWORD), RefBase, LitInt(16)] => [R(0), Ufields(&[30]), R(5)];
+    0b00001101_10100000_10000100_00000000 = [RegListElement(2, QWORD), RefBase, X] => [R(0), Ufields(&[30]), R(5), RNoZr(16)];
+]
+"st2b" = [
+    // ST2B (scalar plus scalar)
+    0b11100100_00100000_01100000_00000000 = [ZRegList(2, BYTE), PBare, RefIndexLsl(0)] => [R(0), R3(10), R(5), RNoZr(16)], SVE;
+    // ST2B (scalar plus immediate)
+    0b11100100_00110000_11100000_00000000 = [ZRegList(2, BYTE), PBare, RefMulVl] => [R(0), R3(10), R(5), Sscaled(16, 4, 1)], SVE;
+]
+"st2d" = [
+    // ST2D (scalar plus scalar)
+    0b11100101_10100000_01100000_00000000 = [ZRegList(2, QWORD), PBare, RefIndexLsl(3)] => [R(0), R3(10), R(5), RNoZr(16)], SVE;
+    // ST2D (scalar plus immediate)
+    0b11100101_10110000_11100000_00000000 = [ZRegList(2, QWORD), PBare, RefMulVl] => [R(0), R3(10), R(5), Sscaled(16, 4, 1)], SVE;
+]
+"st2g" = [
+    0b11011001_10100000_00001000_00000000 = [XSP, RefOffset] => [R(0), R(5), Sscaled(12, 9, 4)], MTE;
+    0b11011001_10100000_00001100_00000000 = [XSP, RefPre] => [R(0), R(5), Sscaled(12, 9, 4)], MTE;
+    0b11011001_10100000_00000100_00000000 = [XSP, RefBase, Imm] => [R(0), R(5), Sscaled(12, 9, 4)], MTE;
+]
+"st2h" = [
+    // ST2H (scalar plus scalar)
+    0b11100100_10100000_01100000_00000000 = [ZRegList(2, WORD), PBare, RefIndexLsl(1)] => [R(0), R3(10), R(5), RNoZr(16)], SVE;
+    // ST2H (scalar plus immediate)
+    0b11100100_10110000_11100000_00000000 = [ZRegList(2, WORD), PBare, RefMulVl] => [R(0), R3(10), R(5), Sscaled(16, 4, 1)], SVE;
+]
+"st2w" = [
+    // ST2W (scalar plus scalar)
+    0b11100101_00100000_01100000_00000000 = [ZRegList(2, DWORD), PBare, RefIndexLsl(2)] => [R(0), R3(10), R(5), RNoZr(16)], SVE;
+    // ST2W (scalar plus immediate)
+    0b11100101_00110000_11100000_00000000 = [ZRegList(2, DWORD), PBare, RefMulVl] => [R(0), R3(10), R(5), Sscaled(16, 4, 1)], SVE;
+]
+"st3" = [
+    // ST3 (multiple structures)
+    0b00001100_00000000_01000000_00000000 = [RegList(3, BYTE), RefBase] => [R(0), R(5), Rwidth(30)];
+    0b00001100_00000000_01000100_00000000 = [RegList(3, WORD), RefBase] => [R(0), R(5), Rwidth(30)];
+    0b00001100_00000000_01001000_00000000 = [RegList(3, DWORD), RefBase] => [R(0), R(5), Rwidth(30)];
+    0b00001100_00000000_01001100_00000000 = [RegListStatic(3, QWORD, 2), RefBase] => [R(0), R(5), Rwidth(30)];
+    0b00001100_10011111_01000000_00000000 = [RegListStatic(3, BYTE, 8), RefBase, LitInt(24)] => [R(0), R(5)];
+    0b00001100_10011111_01000100_00000000 = [RegListStatic(3, WORD, 4), RefBase, LitInt(24)] => [R(0), R(5)];
+    0b00001100_10011111_01001000_00000000 = [RegListStatic(3, DWORD, 2), RefBase, LitInt(24)] => [R(0), R(5)];
+    0b01001100_10011111_01000000_00000000 = [RegListStatic(3, BYTE, 16), RefBase, LitInt(48)] => [R(0), R(5)];
+    0b01001100_10011111_01000100_00000000 = [RegListStatic(3, WORD, 8), RefBase, LitInt(48)] => [R(0), R(5)];
+    0b01001100_10011111_01001000_00000000 = [RegListStatic(3, DWORD, 4), RefBase, LitInt(48)] => [R(0), R(5)];
+    0b01001100_10011111_01001100_00000000 = [RegListStatic(3, QWORD, 2), RefBase, LitInt(48)] => [R(0), R(5)];
+    0b00001100_10000000_01000000_00000000 = [RegList(3, BYTE), RefBase, X] => [R(0), R(5), RNoZr(16), Rwidth(30)];
+    0b00001100_10000000_01000100_00000000 = [RegList(3, WORD), RefBase, X] => [R(0), R(5), RNoZr(16), Rwidth(30)];
+    0b00001100_10000000_01001000_00000000 = [RegList(3, DWORD), RefBase, X] => [R(0), R(5), RNoZr(16), Rwidth(30)];
+    0b00001100_10000000_01001100_00000000 = [RegListStatic(3, QWORD, 2), RefBase, X] => [R(0), R(5), RNoZr(16), Rwidth(30)];
+    // ST3 (single structure)
+    0b00001101_00000000_00100000_00000000 = [RegListElement(3, BYTE), RefBase] => [R(0), Ufields(&[30, 12, 11, 10]), R(5)];
+    0b00001101_00000000_01100000_00000000 = [RegListElement(3, WORD), RefBase] => [R(0), Ufields(&[30, 12, 11]), R(5)];
+    0b00001101_00000000_10100000_00000000 = [RegListElement(3, DWORD), RefBase] => [R(0), Ufields(&[30, 12]), R(5)];
+    0b00001101_00000000_10100100_00000000 = [RegListElement(3, QWORD), RefBase] => [R(0), Ufields(&[30]), R(5)];
+    0b00001101_10011111_00100000_00000000 = [RegListElement(3, BYTE), RefBase, LitInt(3)] => [R(0), Ufields(&[30, 12, 11, 10]), R(5)];
+    0b00001101_10000000_00100000_00000000 = [RegListElement(3, BYTE), RefBase, X] => [R(0), Ufields(&[30, 12, 11, 10]), R(5), RNoZr(16)];
+    0b00001101_10011111_01100000_00000000 = [RegListElement(3, WORD), RefBase, LitInt(6)] => [R(0), Ufields(&[30, 12, 11]), R(5)];
+    0b00001101_10000000_01100000_00000000 = [RegListElement(3, WORD), RefBase, X] => [R(0), Ufields(&[30, 12, 11]), R(5), RNoZr(16)];
+    0b00001101_10011111_10100000_00000000 = [RegListElement(3, DWORD), RefBase, LitInt(12)] => [R(0), Ufields(&[30, 12]), R(5)];
+    0b00001101_10000000_10100000_00000000 = [RegListElement(3, DWORD), RefBase, X] => [R(0), Ufields(&[30, 12]), R(5), RNoZr(16)];
+    0b00001101_10011111_10100100_00000000 = [RegListElement(3, QWORD), RefBase, LitInt(24)] => [R(0), Ufields(&[30]), R(5)];
+    0b00001101_10000000_10100100_00000000 = [RegListElement(3, QWORD), RefBase, X] => [R(0), Ufields(&[30]), R(5), RNoZr(16)];
+]
+"st3b" = [
+    // ST3B (scalar plus scalar)
+    0b11100100_01000000_01100000_00000000 = [ZRegList(3, BYTE), PBare, RefIndexLsl(0)] => [R(0), R3(10), R(5), RNoZr(16)], SVE;
+    // ST3B (scalar plus immediate)
+    0b11100100_01010000_11100000_00000000 = [ZRegList(3, BYTE), PBare, RefMulVl] => [R(0), R3(10), R(5), Smultiple(16, 4, 3)], SVE;
+]
+"st3d" = [
+    // ST3D (scalar plus scalar)
+    0b11100101_11000000_01100000_00000000 = [ZRegList(3, QWORD), PBare, RefIndexLsl(3)] => [R(0), R3(10), R(5), RNoZr(16)], SVE;
+    // ST3D (scalar plus immediate)
+    0b11100101_11010000_11100000_00000000 = [ZRegList(3, QWORD), PBare, RefMulVl] => [R(0), R3(10), R(5), Smultiple(16, 4, 3)], SVE;
+]
+"st3h" = [
+    // ST3H (scalar plus scalar)
+    0b11100100_11000000_01100000_00000000 = [ZRegList(3, WORD), PBare, RefIndexLsl(1)] => [R(0), R3(10), R(5), RNoZr(16)], SVE;
+    // ST3H (scalar plus immediate)
+    0b11100100_11010000_11100000_00000000 = [ZRegList(3, WORD), PBare, RefMulVl] => [R(0), R3(10), R(5), Smultiple(16, 4, 3)], SVE;
+]
+"st3w" = [
+    // ST3W (scalar plus scalar)
+    0b11100101_01000000_01100000_00000000 = [ZRegList(3, DWORD), PBare, RefIndexLsl(2)] => [R(0), R3(10), R(5), RNoZr(16)], SVE;
+    // ST3W (scalar plus immediate)
+    0b11100101_01010000_11100000_00000000 = [ZRegList(3, DWORD), PBare, RefMulVl] => [R(0), R3(10), R(5), Smultiple(16, 4, 3)], SVE;
+]
+"st4" = [
+    // ST4 (multiple structures)
+    0b00001100_00000000_00000000_00000000 = [RegList(4, BYTE), RefBase] => [R(0), R(5), Rwidth(30)];
+    0b00001100_00000000_00000100_00000000 = [RegList(4, WORD), RefBase] => [R(0), R(5), Rwidth(30)];
+    0b00001100_00000000_00001000_00000000 = [RegList(4, DWORD), RefBase] => [R(0), R(5), Rwidth(30)];
+    0b00001100_00000000_00001100_00000000 = [RegListStatic(4, QWORD, 2), RefBase] => [R(0), R(5), Rwidth(30)];
+    0b00001100_10011111_00000000_00000000 = [RegListStatic(4, BYTE, 8), RefBase, LitInt(32)] => [R(0), R(5)];
+    0b00001100_10011111_00000100_00000000 = [RegListStatic(4, WORD, 4), RefBase, LitInt(32)] => [R(0), R(5)];
+    0b00001100_10011111_00001000_00000000 = [RegListStatic(4, DWORD, 2), RefBase, LitInt(32)] => [R(0), R(5)];
+    0b01001100_10011111_00000000_00000000 = [RegListStatic(4, BYTE, 16), RefBase, LitInt(64)] => [R(0), R(5)];
+    0b01001100_10011111_00000100_00000000 = [RegListStatic(4, WORD, 8), RefBase, LitInt(64)] => [R(0), R(5)];
+    0b01001100_10011111_00001000_00000000 = [RegListStatic(4, DWORD, 4), RefBase, LitInt(64)] => [R(0), R(5)];
+    0b01001100_10011111_00001100_00000000 = [RegListStatic(4, QWORD, 2), RefBase, LitInt(64)] => [R(0), R(5)];
+    0b00001100_10000000_00000000_00000000 = [RegList(4, BYTE), RefBase, X] => [R(0), R(5), RNoZr(16), Rwidth(30)];
+    0b00001100_10000000_00000100_00000000 = [RegList(4, WORD), RefBase, X] => [R(0), R(5), RNoZr(16), Rwidth(30)];
+    0b00001100_10000000_00001000_00000000 = [RegList(4, DWORD), RefBase, X] => [R(0), R(5), RNoZr(16), Rwidth(30)];
+    0b00001100_10000000_00001100_00000000 = [RegListStatic(4, QWORD, 2), RefBase, X] => [R(0), R(5), RNoZr(16), Rwidth(30)];
+    // ST4 (single structure)
+    0b00001101_00100000_00100000_00000000 = [RegListElement(4, BYTE), RefBase] => [R(0), Ufields(&[30, 12, 11, 10]), R(5)];
+    0b00001101_00100000_01100000_00000000 = [RegListElement(4, WORD), RefBase] => [R(0), Ufields(&[30, 12, 11]), R(5)];
+    0b00001101_00100000_10100000_00000000 = [RegListElement(4, DWORD), RefBase] => [R(0), Ufields(&[30, 12]), R(5)];
+    0b00001101_00100000_10100100_00000000 = [RegListElement(4, QWORD), RefBase] => [R(0), Ufields(&[30]), R(5)];
+    0b00001101_10111111_00100000_00000000 = [RegListElement(4, BYTE), RefBase, LitInt(4)] => [R(0), Ufields(&[30, 12, 11, 10]), R(5)];
+    0b00001101_10100000_00100000_00000000 = [RegListElement(4, BYTE), RefBase, X] => [R(0), Ufields(&[30, 12, 11, 10]), R(5), RNoZr(16)];
+    0b00001101_10111111_01100000_00000000 = [RegListElement(4, WORD), RefBase, LitInt(8)] => [R(0), Ufields(&[30, 12, 11]), R(5)];
+    0b00001101_10100000_01100000_00000000 = [RegListElement(4, WORD), RefBase, X] => [R(0), Ufields(&[30, 12, 11]), R(5), RNoZr(16)];
+    0b00001101_10111111_10100000_00000000 = [RegListElement(4, DWORD), RefBase, LitInt(16)] => [R(0), Ufields(&[30, 12]), R(5)];
+    0b00001101_10100000_10100000_00000000 = [RegListElement(4, DWORD), RefBase, X] => [R(0), Ufields(&[30, 12]), R(5), RNoZr(16)];
+    0b00001101_10111111_10100100_00000000 = [RegListElement(4, QWORD), RefBase, LitInt(32)] => [R(0), Ufields(&[30]), R(5)];
+    0b00001101_10100000_10100100_00000000 = [RegListElement(4, QWORD), RefBase, X] => [R(0), Ufields(&[30]), R(5), RNoZr(16)];
+]
+"st4b" = [
+    // ST4B (scalar plus scalar)
+    0b11100100_01100000_01100000_00000000 = [ZRegList(4, BYTE), PBare, RefIndexLsl(0)] => [R(0), R3(10), R(5), RNoZr(16)], SVE;
+    // ST4B (scalar plus immediate)
+    0b11100100_01110000_11100000_00000000 = [ZRegList(4, BYTE), PBare, RefMulVl] => [R(0), R3(10), R(5), Sscaled(16, 4, 2)], SVE;
+]
+"st4d" = [
+    // ST4D (scalar plus scalar)
+    0b11100101_11100000_01100000_00000000 = [ZRegList(4, QWORD), PBare, RefIndexLsl(3)] => [R(0), R3(10), R(5), RNoZr(16)], SVE;
+    // ST4D (scalar plus immediate)
+    0b11100101_11110000_11100000_00000000 = [ZRegList(4, QWORD), PBare, RefMulVl] => [R(0), R3(10), R(5), Sscaled(16, 4, 2)], SVE;
+]
+"st4h" = [
+    // ST4H (scalar plus scalar)
+    0b11100100_11100000_01100000_00000000 = [ZRegList(4, WORD), PBare, RefIndexLsl(1)] => [R(0), R3(10), R(5), RNoZr(16)], SVE;
+    // ST4H (scalar plus immediate)
+    0b11100100_11110000_11100000_00000000 = [ZRegList(4, WORD), PBare, RefMulVl] => [R(0), R3(10), R(5), Sscaled(16, 4, 2)], SVE;
+]
+"st4w" = [
+    // ST4W (scalar plus scalar)
+    0b11100101_01100000_01100000_00000000 = [ZRegList(4, DWORD), PBare, RefIndexLsl(2)] => [R(0), R3(10), R(5), RNoZr(16)], SVE;
+    // ST4W (scalar plus immediate)
+    0b11100101_01110000_11100000_00000000 = [ZRegList(4, DWORD), PBare, RefMulVl] => [R(0), R3(10), R(5), Sscaled(16, 4, 2)], SVE;
+]
+"stadd" = [
+    0b10111000_00100000_00000000_00011111 = [W, RefBase] => [R(16), R(5)], LSE;
+    0b11111000_00100000_00000000_00011111 = [X, RefBase] => [R(16), R(5)], LSE;
+]
+"staddb" = [
+    0b00111000_00100000_00000000_00011111 = [W, RefBase] => [R(16), R(5)], LSE;
+]
+"staddh" = [
+    0b01111000_00100000_00000000_00011111 = [W, RefBase] => [R(16), R(5)], LSE;
+]
+"staddl" = [
+    0b10111000_01100000_00000000_00011111 = [W, RefBase] => [R(16), R(5)], LSE;
+    0b11111000_01100000_00000000_00011111 = [X, RefBase] => [R(16), R(5)], LSE;
+]
+"staddlb" = [
+    0b00111000_01100000_00000000_00011111 = [W, RefBase] => [R(16), R(5)], LSE;
+]
+"staddlh" = [
+    0b01111000_01100000_00000000_00011111 = [W, RefBase] => [R(16), R(5)], LSE;
+]
+"stclr" = [
+    0b10111000_00100000_00010000_00011111 = [W, RefBase] => [R(16), R(5)], LSE;
+    0b11111000_00100000_00010000_00011111 = [X, RefBase] => [R(16), R(5)], LSE;
+]
+"stclrb" = [
+    0b00111000_00100000_00010000_00011111 = [W, RefBase] => [R(16), R(5)], LSE;
+]
+"stclrh" = [
+    0b01111000_00100000_00010000_00011111 = [W, RefBase] => [R(16), R(5)], LSE;
+]
+"stclrl" = [
+    0b10111000_01100000_00010000_00011111 = [W, RefBase] => [R(16), R(5)], LSE;
+    0b11111000_01100000_00010000_00011111 = [X, RefBase] => [R(16), R(5)], LSE;
+]
+"stclrlb" = [
+    0b00111000_01100000_00010000_00011111 = [W, RefBase] => [R(16), R(5)], LSE;
+]
+"stclrlh" = [
+    0b01111000_01100000_00010000_00011111 = [W, RefBase] => [R(16), R(5)], LSE;
+]
+"steor" = [
+    0b10111000_00100000_00100000_00011111 = [W, RefBase] => [R(16), R(5)], LSE;
+    0b11111000_00100000_00100000_00011111 = [X, RefBase] => [R(16), R(5)], LSE;
+]
+"steorb" = [
+    0b00111000_00100000_00100000_00011111 = [W, RefBase] => [R(16), R(5)], LSE;
+]
+"steorh" = [
+    0b01111000_00100000_00100000_00011111 = [W, RefBase] => [R(16), R(5)], LSE;
+]
+"steorl" = [
+    0b10111000_01100000_00100000_00011111 = [W, RefBase] => [R(16), R(5)], LSE;
+    0b11111000_01100000_00100000_00011111 = [X, RefBase] => [R(16), R(5)], LSE;
+]
+"steorlb" = [
+    0b00111000_01100000_00100000_00011111 = [W, RefBase] => [R(16), R(5)], LSE;
+]
+"steorlh" = [
+    0b01111000_01100000_00100000_00011111 = [W, RefBase] => [R(16), R(5)], LSE;
+]
+"stg" = [
+    0b11011001_00100000_00001000_00000000 = [XSP, RefOffset] => [R(0), R(5), Sscaled(12, 9, 4)], MTE;
+    0b11011001_00100000_00001100_00000000 = [XSP, RefPre] => [R(0), R(5), Sscaled(12, 9, 4)], MTE;
+    0b11011001_00100000_00000100_00000000 = [XSP, RefBase, Imm] => [R(0), R(5), Sscaled(12, 9, 4)], MTE;
+]
+"stgm" = [
+    0b11011001_10100000_00000000_00000000 = [X, RefBase] => [R(0), R(5)], MTE;
+]
+"stgp" = [
+    0b01101001_00000000_00000000_00000000 = [X, X, RefOffset] => [R(0), R(10), R(5), Sscaled(15, 7, 4)], MTE;
+    0b01101001_10000000_00000000_00000000 = [X, X, RefPre] => [R(0), R(10), R(5), Sscaled(15, 7, 4)], MTE;
+    0b01101000_10000000_00000000_00000000 = [X, X, RefBase, Imm] => [R(0), R(10), R(5), Sscaled(15, 7, 4)], MTE;
+]
+"stllr" = [
+    0b10001000_10011111_01111100_00000000 = [W, RefBase] => [R(0), R(5)], LOR;
+    0b11001000_10011111_01111100_00000000 = [X, RefBase] => [R(0), R(5)], LOR;
+]
+"stllrb" = [
+    0b00001000_10011111_01111100_00000000 = [W, RefBase] => [R(0), R(5)], LOR;
+]
+"stllrh" = [
+    0b01001000_10011111_01111100_00000000 = [W, RefBase] => [R(0), R(5)], LOR;
+]
+"stlr" = [
+    0b10001000_10011111_11111100_00000000 = [W, RefBase] => [R(0), R(5)];
+    0b11001000_10011111_11111100_00000000 = [X, RefBase] => [R(0), R(5)];
+]
+"stlrb" = [
+    0b00001000_10011111_11111100_00000000 = [W, RefBase] => [R(0), R(5)];
+]
+"stlrh" = [
+    0b01001000_10011111_11111100_00000000 = [W, RefBase] => [R(0), R(5)];
+]
+"stlur" = [
+    0b10011001_00000000_00000000_00000000 = [W, RefOffset] => [R(0), R(5), Sbits(12, 9)], RCPC2;
+    0b11011001_00000000_00000000_00000000 = [X, RefOffset] => [R(0), R(5), Sbits(12, 9)], RCPC2;
+]
+"stlurb" = [
+    0b00011001_00000000_00000000_00000000 = [W, RefOffset] => [R(0), R(5), Sbits(12, 9)], RCPC2;
+]
+"stlurh" = [
+    0b01011001_00000000_00000000_00000000 = [W, RefOffset] => [R(0), R(5), Sbits(12, 9)], RCPC2;
+]
+"stlxp" = [
+    0b10001000_00100000_10000000_00000000 = [W, W, W, RefBase] => [R(16), R(0), R(10), R(5)];
+    0b11001000_00100000_10000000_00000000 = [W, X, X, RefBase] => [R(16), R(0), R(10), R(5)];
+]
+"stlxr" = [
+    0b10001000_00000000_11111100_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)];
+    0b11001000_00000000_11111100_00000000 = [W, X, RefBase] => [R(16), R(0), R(5)];
+]
+"stlxrb" = [
+    0b00001000_00000000_11111100_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)];
+]
+"stlxrh" = [
+    0b01001000_00000000_11111100_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)];
+]
+"stnp" = [
+    // STNP (SIMD&FP)
+    0b00101100_00000000_00000000_00000000 = [S, S, RefOffset] => [R(0), R(10), R(5), Sscaled(15, 7, 2)];
+    0b01101100_00000000_00000000_00000000 = [D, D, RefOffset] => [R(0), R(10), R(5), Sscaled(15, 7, 3)];
+    0b10101100_00000000_00000000_00000000 = [Q, Q, RefOffset] => [R(0), R(10), R(5), Sscaled(15, 7, 4)];
+    // STNP
+    0b00101000_00000000_00000000_00000000 = [W, W, RefOffset] => [R(0), R(10), R(5), Sscaled(15, 7, 2)];
+    0b10101000_00000000_00000000_00000000 = [X, X, RefOffset] => [R(0), R(10), R(5), Sscaled(15, 7, 3)];
+]
+"stnt1b" = [
+    // STNT1B (scalar plus scalar)
+    0b11100100_00000000_01100000_00000000 = [ZRegList(1, BYTE), PBare, RefIndexLsl(0)] => [R(0), R3(10), R(5), RNoZr(16)], SVE;
+    // STNT1B (scalar plus immediate)
+    0b11100100_00010000_11100000_00000000 = [ZRegList(1, BYTE), PBare, RefMulVl] => [R(0), R3(10), R(5), Sbits(16, 4)], SVE;
+]
+"stnt1d" = [
+    // STNT1D (scalar plus scalar)
+    0b11100101_10000000_01100000_00000000 = [ZRegList(1, QWORD), PBare, RefIndexLsl(3)] => [R(0), R3(10), R(5), RNoZr(16)], SVE;
+    // STNT1D (scalar plus immediate)
+    0b11100101_10010000_11100000_00000000 = [ZRegList(1, QWORD), PBare, RefMulVl] => [R(0), R3(10), R(5), Sbits(16, 4)], SVE;
+]
+"stnt1h" = [
+    // STNT1H (scalar plus scalar)
+    0b11100100_10000000_01100000_00000000 = [ZRegList(1, WORD), PBare, RefIndexLsl(1)] => [R(0), R3(10), R(5), RNoZr(16)], SVE;
+    // STNT1H (scalar plus immediate)
+    0b11100100_10010000_11100000_00000000 = [ZRegList(1, WORD), PBare, RefMulVl] => [R(0), R3(10), R(5), Sbits(16, 4)], SVE;
+]
+"stnt1w" = [
+    // STNT1W (scalar plus scalar)
+    0b11100101_00000000_01100000_00000000 = [ZRegList(1, DWORD), PBare, RefIndexLsl(2)] => [R(0), R3(10), R(5), RNoZr(16)], SVE;
+    // STNT1W (scalar plus immediate)
+    0b11100101_00010000_11100000_00000000 = [ZRegList(1, DWORD), PBare, RefMulVl] => [R(0), R3(10), R(5), Sbits(16, 4)], SVE;
+]
+"stp" = [
+    // STP (SIMD&FP)
+    0b00101100_10000000_00000000_00000000 = [S, S, RefBase, Imm] => [R(0), R(10), R(5), Sscaled(15, 7, 2)];
+    0b01101100_10000000_00000000_00000000 = [D, D, RefBase, Imm] => [R(0), R(10), R(5), Sscaled(15, 7, 3)];
+    0b10101100_10000000_00000000_00000000 = [Q, Q, RefBase, Imm] => [R(0), R(10), R(5), Sscaled(15, 7, 4)];
+    0b00101101_10000000_00000000_00000000 = [S, S, RefPre] => [R(0), R(10), R(5), Sscaled(15, 7, 2)];
+    0b01101101_10000000_00000000_00000000 = [D, D, RefPre] => [R(0), R(10), R(5), Sscaled(15, 7, 3)];
+    0b10101101_10000000_00000000_00000000 = [Q, Q, RefPre] => [R(0), R(10), R(5), Sscaled(15, 7, 4)];
+    0b00101101_00000000_00000000_00000000 = [S, S, RefOffset] => [R(0), R(10), R(5), Sscaled(15, 7, 2)];
+    0b01101101_00000000_00000000_00000000 = [D, D, RefOffset] => [R(0), R(10), R(5), Sscaled(15, 7, 3)];
+    0b10101101_00000000_00000000_00000000 = [Q, Q, RefOffset] => [R(0), R(10), R(5), Sscaled(15, 7, 4)];
+    // STP
+    0b00101000_10000000_00000000_00000000 = [W, W, RefBase, Imm] => [R(0), R(10), R(5), Sscaled(15, 7, 2)];
+    0b10101000_10000000_00000000_00000000 = [X, X, RefBase, Imm] => [R(0), R(10), R(5), Sscaled(15, 7, 3)];
+    0b00101001_10000000_00000000_00000000 = [W, W, RefPre] => [R(0), R(10), R(5), Sscaled(15, 7, 2)];
+    0b10101001_10000000_00000000_00000000 = [X, X, RefPre] => [R(0), R(10), R(5), Sscaled(15, 7, 3)];
+    0b00101001_00000000_00000000_00000000 = [W, W, RefOffset] => [R(0), R(10), R(5), Sscaled(15, 7, 2)];
+    0b10101001_00000000_00000000_00000000 = [X, X, RefOffset] => [R(0), R(10), R(5), Sscaled(15, 7, 3)];
+]
+"str" = [
+    // STR (immediate, SIMD&FP)
+    0b00111100_00000000_00000100_00000000 = [B, RefBase, Imm] => [R(0), R(5), Sbits(12, 9)];
+    0b01111100_00000000_00000100_00000000 = [H, RefBase, Imm] => [R(0), R(5), Sbits(12, 9)];
+    0b10111100_00000000_00000100_00000000 = [S, RefBase, Imm] => [R(0), R(5), Sbits(12, 9)];
+    0b11111100_00000000_00000100_00000000 = [D, RefBase, Imm] => [R(0), R(5), Sbits(12, 9)];
+    0b00111100_10000000_00000100_00000000 = [Q, RefBase, Imm] => [R(0), R(5), Sbits(12, 9)];
+    0b00111100_00000000_00001100_00000000 = [B, RefPre] => [R(0), R(5), Sbits(12, 9)];
+    0b01111100_00000000_00001100_00000000 = [H, RefPre] => [R(0), R(5), Sbits(12, 9)];
+    0b10111100_00000000_00001100_00000000 = [S, RefPre] => [R(0), R(5), Sbits(12, 9)];
+    0b11111100_00000000_00001100_00000000 = [D, RefPre] => [R(0), R(5), Sbits(12, 9)];
+    0b00111100_10000000_00001100_00000000 = [Q, RefPre] => [R(0), R(5), Sbits(12, 9)];
+    0b00111101_00000000_00000000_00000000 = [B, RefOffset] => [R(0), R(5), Ubits(10, 12)];
+    0b01111101_00000000_00000000_00000000 = [H, RefOffset] => [R(0), R(5), Uscaled(10, 12, 1)];
+    0b10111101_00000000_00000000_00000000 = [S, RefOffset] => [R(0), R(5), Uscaled(10, 12, 2)];
+    0b11111101_00000000_00000000_00000000 = [D, RefOffset] => [R(0), R(5), Uscaled(10, 12, 3)];
+    0b00111101_10000000_00000000_00000000 = [Q, RefOffset] => [R(0), R(5), Uscaled(10, 12, 4)];
+    // STR (immediate)
+    0b10111000_00000000_00000100_00000000 = [W, RefBase, Imm] => [R(0), R(5), Sbits(12, 9)];
+    0b11111000_00000000_00000100_00000000 = [X, RefBase, Imm] => [R(0), R(5), Sbits(12, 9)];
+    0b10111000_00000000_00001100_00000000 = [W, RefPre] => [R(0), R(5), Sbits(12, 9)];
+    0b11111000_00000000_00001100_00000000 = [X, RefPre] => [R(0), R(5), Sbits(12, 9)];
+    0b10111001_00000000_00000000_00000000 = [W, RefOffset] => [R(0), R(5), Uscaled(10, 12, 2)];
+    0b11111001_00000000_00000000_00000000 = [X, RefOffset] => [R(0), R(5), Uscaled(10, 12, 3)];
+    // STR (register, SIMD&FP)
+    0b00111100_00100000_00001000_00000000 = [B, RefIndex] => [R(0), R(5), R(16), ExtendsX(13), Ulist(12, &[0, 0])];
+    0b01111100_00100000_00001000_00000000 = [H, RefIndex] => [R(0), R(5), R(16), ExtendsX(13), Ulist(12, &[0, 1])];
+    0b10111100_00100000_00001000_00000000 = [S, RefIndex] => [R(0), R(5), R(16), ExtendsX(13), Ulist(12, &[0, 2])];
+    0b11111100_00100000_00001000_00000000 = [D, RefIndex] => [R(0), R(5), R(16), ExtendsX(13), Ulist(12, &[0, 3])];
+    0b00111100_10100000_00001000_00000000 = [Q, RefIndex] => [R(0), R(5), R(16), ExtendsX(13), Ulist(12, &[0, 4])];
+    // STR (register)
+    0b10111000_00100000_00001000_00000000 = [W, RefIndex] => [R(0), R(5), R(16), ExtendsX(13), Ulist(12, &[0, 2])];
+    0b11111000_00100000_00001000_00000000 = [X, RefIndex] => [R(0), R(5), R(16), ExtendsX(13), Ulist(12, &[0, 3])];
+    // STR (predicate)
+    0b11100101_10000000_00000000_00000000 = [PBare, RefMulVl] => [R4(0), R(5), BSscaled(9, 0), Sslice(10, 3, 0), Sslice(16, 6, 3), A], SVE;
+    // STR (vector)
+    0b11100101_10000000_01000000_00000000 = [ZBare, RefMulVl] => [R(0), R(5), BSscaled(9, 0), Sslice(10, 3, 0), Sslice(16, 6, 3), A], SVE;
+    // STR (array vector)
+    0b11100001_00100000_00000000_00000000 = [ZaArray, RefMulVl] => [RSelect(13), Ubits(0, 4), R(5), Usame(2)], SME;
+]
+"strb" = [
+    // STRB (immediate)
+    0b00111000_00000000_00000100_00000000 = [W, RefBase, Imm] => [R(0), R(5), Sbits(12, 9)];
+    0b00111000_00000000_00001100_00000000 = [W, RefPre] => [R(0), R(5), Sbits(12, 9)];
+    0b00111001_00000000_00000000_00000000 = [W, RefOffset] => [R(0), R(5), Ubits(10, 12)];
+    // STRB (register)
+    0b00111000_00100000_00001000_00000000 = [W, RefIndex] => [R(0), R(5), R(16), ExtendsX(13), Ulist(12, &[0, 0])];
+]
+"strh" = [
+    // STRH (immediate)
+    0b01111000_00000000_00000100_00000000 = [W, RefBase, Imm] => [R(0), R(5), Sbits(12, 9)];
+    0b01111000_00000000_00001100_00000000 = [W, RefPre] => [R(0), R(5), Sbits(12, 9)];
+    0b01111001_00000000_00000000_00000000 = [W, RefOffset] => [R(0), R(5), Uscaled(10, 12, 1)];
+    // STRH (register)
+    0b01111000_00100000_00001000_00000000 = [W, RefIndex] => [R(0), R(5), R(16), ExtendsX(13), Ulist(12, &[0, 1])];
+]
+"stset" = [
+    0b10111000_00100000_00110000_00011111 = [W, RefBase] => [R(16), R(5)], LSE;
+    0b11111000_00100000_00110000_00011111 = [X, RefBase] => [R(16), R(5)], LSE;
+]
+"stsetb" = [
+    0b00111000_00100000_00110000_00011111 = [W, RefBase] => [R(16), R(5)], LSE;
+]
+"stseth" = [
+    0b01111000_00100000_00110000_00011111 = [W, RefBase] => [R(16), R(5)], LSE;
+]
+"stsetl" = [
+    0b10111000_01100000_00110000_00011111 = [W, RefBase] => [R(16), R(5)], LSE;
+    0b11111000_01100000_00110000_00011111 = [X, RefBase] => [R(16), R(5)], LSE;
+]
+"stsetlb" = [
+    0b00111000_01100000_00110000_00011111 = [W, RefBase] => [R(16), R(5)], LSE;
+]
+"stsetlh" = [
+    0b01111000_01100000_00110000_00011111 = [W, RefBase] => [R(16), R(5)], LSE;
+]
+"stsmax" = [
+    0b10111000_00100000_01000000_00011111 = [W, RefBase] => [R(16), R(5)], LSE;
+    0b11111000_00100000_01000000_00011111 = [X, RefBase] => [R(16), R(5)], LSE;
+]
+"stsmaxb" = [
+    0b00111000_00100000_01000000_00011111 = [W, RefBase] => [R(16), R(5)], LSE;
+]
+"stsmaxh" = [
+    0b01111000_00100000_01000000_00011111 = [W, RefBase] => [R(16), R(5)], LSE;
+]
+"stsmaxl" = [
+    0b10111000_01100000_01000000_00011111 = [W, RefBase] => [R(16), R(5)], LSE;
+    0b11111000_01100000_01000000_00011111 = [X, RefBase] => [R(16), R(5)], LSE;
+]
+"stsmaxlb" = [
+    0b00111000_01100000_01000000_00011111 = [W, RefBase] => [R(16), R(5)], LSE;
+]
+"stsmaxlh" = [
+    0b01111000_01100000_01000000_00011111 = [W, RefBase] => [R(16), R(5)], LSE;
+]
+"stsmin" = [
+    0b10111000_00100000_01010000_00011111 = [W, RefBase] => [R(16), R(5)], LSE;
+    0b11111000_00100000_01010000_00011111 = [X, RefBase] => [R(16), R(5)], LSE;
+]
+"stsminb" = [
+    0b00111000_00100000_01010000_00011111 = [W, RefBase] => [R(16), R(5)], LSE;
+]
+"stsminh" = [
+    0b01111000_00100000_01010000_00011111 = [W, RefBase] => [R(16), R(5)], LSE;
+]
+"stsminl" = [
+    0b10111000_01100000_01010000_00011111 = [W, RefBase] => [R(16), R(5)], LSE;
+    0b11111000_01100000_01010000_00011111 = [X, RefBase] => [R(16), R(5)], LSE;
+]
+"stsminlb" = [
+    0b00111000_01100000_01010000_00011111 = [W, RefBase] => [R(16), R(5)], LSE;
+]
+"stsminlh" = [
+    0b01111000_01100000_01010000_00011111 = [W, RefBase] => [R(16), R(5)], LSE;
+]
+"sttr" = [
+    0b10111000_00000000_00001000_00000000 = [W, RefOffset] => [R(0), R(5), Sbits(12, 9)];
+    0b11111000_00000000_00001000_00000000 = [X, RefOffset] => [R(0), R(5), Sbits(12, 9)];
+]
+"sttrb" = [
+    0b00111000_00000000_00001000_00000000 = [W, RefOffset] => [R(0), R(5), Sbits(12, 9)];
+]
+"sttrh" = [
+    0b01111000_00000000_00001000_00000000 = [W, RefOffset] => [R(0), R(5), Sbits(12, 9)];
+]
+"stumax" = [
+    0b10111000_00100000_01100000_00011111 = [W, RefBase] => [R(16), R(5)], LSE;
+    0b11111000_00100000_01100000_00011111 = [X, RefBase] => [R(16), R(5)], LSE;
+]
+"stumaxb" = [
+    0b00111000_00100000_01100000_00011111 = [W, RefBase] => [R(16), R(5)], LSE;
+]
+"stumaxh" = [
+    0b01111000_00100000_01100000_00011111 = [W, RefBase] => [R(16), R(5)], LSE;
+]
+"stumaxl" = [
+    0b10111000_01100000_01100000_00011111 = [W, RefBase] => [R(16), R(5)], LSE;
+    0b11111000_01100000_01100000_00011111 = [X, RefBase] => [R(16), R(5)], LSE;
+]
+"stumaxlb" = [
+    0b00111000_01100000_01100000_00011111 = [W, RefBase] => [R(16), R(5)], LSE;
+]
+"stumaxlh" = [
+    0b01111000_01100000_01100000_00011111 = [W, RefBase] => [R(16), R(5)], LSE;
+]
+"stumin" = [
+    0b10111000_00100000_01110000_00011111 = [W, RefBase] => [R(16), R(5)], LSE;
+    0b11111000_00100000_01110000_00011111 = [X, RefBase] => [R(16), R(5)], LSE;
+]
+"stuminb" = [
+    0b00111000_00100000_01110000_00011111 = [W, RefBase] => [R(16), R(5)], LSE;
+]
+"stuminh" = [
+    0b01111000_00100000_01110000_00011111 = [W, RefBase] => [R(16), R(5)], LSE;
+]
+"stuminl" = [
+    0b10111000_01100000_01110000_00011111 = [W, RefBase] => [R(16), R(5)], LSE;
+    0b11111000_01100000_01110000_00011111 = [X, RefBase] => [R(16), R(5)], LSE;
+]
+"stuminlb" = [
+    0b00111000_01100000_01110000_00011111 = [W, RefBase] => [R(16), R(5)], LSE;
+]
+"stuminlh" = [
+    0b01111000_01100000_01110000_00011111 = [W, RefBase] => [R(16), R(5)], LSE;
+]
+"stur" = [
+    // STUR (SIMD&FP)
+    0b00111100_00000000_00000000_00000000 = [B, RefOffset] => [R(0), R(5), Sbits(12, 9)];
+    0b01111100_00000000_00000000_00000000 = [H, RefOffset] => [R(0), R(5), Sbits(12, 9)];
+    0b10111100_00000000_00000000_00000000 = [S, RefOffset] => [R(0), R(5), Sbits(12, 9)];
+    0b11111100_00000000_00000000_00000000 = [D, RefOffset] => [R(0), R(5), Sbits(12, 9)];
+    0b00111100_10000000_00000000_00000000 = [Q, RefOffset] => [R(0), R(5), Sbits(12, 9)];
+    // STUR
+    0b10111000_00000000_00000000_00000000 = [W, RefOffset] => [R(0), R(5), Sbits(12, 9)];
+    0b11111000_00000000_00000000_00000000 = [X, RefOffset] => [R(0), R(5), Sbits(12, 9)];
+]
+"sturb" = [
+    0b00111000_00000000_00000000_00000000 = [W, RefOffset] => [R(0), R(5), Sbits(12, 9)];
+]
+"sturh" = [
+    0b01111000_00000000_00000000_00000000 = [W, RefOffset] => [R(0), R(5), Sbits(12, 9)];
+]
+"stxp" = [
+    0b10001000_00100000_00000000_00000000 = [W, W, W, RefBase] => [R(16), R(0), R(10), R(5)];
+    0b11001000_00100000_00000000_00000000 = [W, X, X, RefBase] => [R(16), R(0), R(10), R(5)];
+]
+"stxr" = [
+    0b10001000_00000000_01111100_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)];
+    0b11001000_00000000_01111100_00000000 = [W, X, RefBase] => [R(16), R(0), R(5)];
+]
+"stxrb" = [
+    0b00001000_00000000_01111100_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)];
+]
+"stxrh" = [
+    0b01001000_00000000_01111100_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)];
+]
+"stz2g" = [
+    0b11011001_11100000_00001000_00000000 = [XSP, RefOffset] => [R(0), R(5), Sscaled(12, 9, 4)], MTE;
+    0b11011001_11100000_00001100_00000000 = [XSP, RefPre] => [R(0), R(5), Sscaled(12, 9, 4)], MTE;
+    0b11011001_11100000_00000100_00000000 = [XSP, RefBase, Imm] => [R(0), R(5), Sscaled(12, 9, 4)], MTE;
+]
+"stzg" = [
+    0b11011001_01100000_00001000_00000000 = [XSP, RefOffset] => [R(0), R(5), Sscaled(12, 9, 4)], MTE;
+    0b11011001_01100000_00001100_00000000 = [XSP, RefPre] => [R(0), R(5), Sscaled(12, 9, 4)], MTE;
+    0b11011001_01100000_00000100_00000000 = [XSP, RefBase, Imm] => [R(0), R(5), Sscaled(12, 9, 4)], MTE;
+]
+"stzgm" = [
+    0b11011001_00100000_00000000_00000000 = [X, RefBase] => [R(0), R(5)], MTE;
+]
+"sub" = [
+    // SUB (shifted register)
+    0b01001011_00000000_00000000_00000000 = [W, W, W, End, Mod(SHIFTS)] => [R(0), R(5), R(16), Rotates(22), Ubits(10, 5)];
+    0b11001011_00000000_00000000_00000000 = [X, X, X, End, Mod(SHIFTS)] => [R(0), R(5), R(16), Rotates(22), Ubits(10, 6)];
+    // SUB (extended register)
+    0b01001011_00100000_00000000_00000000 = [WSP, WSP, W, End, Mod(EXTENDS)] => [R(0), R(5), R(16), ExtendsW(13), Urange(10, 0, 4)];
+    0b11001011_00100000_00000000_00000000 = [XSP, XSP, W, End, Mod(EXTENDS_W)] => [R(0), R(5), R(16), ExtendsX(13), Urange(10, 0, 4)];
+    0b11001011_00100000_00000000_00000000 = [XSP, XSP, X, End, Mod(EXTENDS_X)] => [R(0), R(5), R(16), ExtendsX(13), Urange(10, 0, 4)];
+    // SUB (immediate)
+    0b01010001_00000000_00000000_00000000 = [WSP, WSP, Imm, End, LitMod(LSL)] => [R(0), R(5), Ubits(10, 12), Ulist(22, &[0, 12])];
+    0b11010001_00000000_00000000_00000000 = [XSP, XSP, Imm, End, LitMod(LSL)] => [R(0), R(5), Ubits(10, 12), Ulist(22, &[0, 12])];
+    // SUB (vector)
+    0b01111110_11100000_10000100_00000000 = [D, D, D] => [R(0), R(5), R(16)];
+    0b00101110_00100000_10000100_00000000 = [V(BYTE), V(BYTE), V(BYTE)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b00101110_01100000_10000100_00000000 = [V(WORD), V(WORD), V(WORD)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b00101110_10100000_10000100_00000000 = [V(DWORD), V(DWORD), V(DWORD)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b00101110_11100000_10000100_00000000 = [VStatic(QWORD, 2), VStatic(QWORD, 2), VStatic(QWORD, 2)] => [R(0), R(5), R(16), Rwidth(30)];
+    // SUB (vectors, unpredicated)
+    0b00000100_00100000_00000100_00000000 = [Z(BYTE), Z(BYTE), Z(BYTE)] => [R(0), R(5), R(16)], SVE;
+    0b00000100_01100000_00000100_00000000 = [Z(WORD), Z(WORD), Z(WORD)] => [R(0), R(5), R(16)], SVE;
+    0b00000100_10100000_00000100_00000000 = [Z(DWORD), Z(DWORD), Z(DWORD)] => [R(0), R(5), R(16)], SVE;
+    0b00000100_11100000_00000100_00000000 = [Z(QWORD), Z(QWORD), Z(QWORD)] => [R(0), R(5), R(16)], SVE;
+    // SUB (vectors, predicated)
+    0b00000100_00000001_00000000_00000000 = [Z(BYTE), PMerge, Z(BYTE), Z(BYTE)] => [R(0), R3(10), RTied, R(5)], SVE;
+    0b00000100_01000001_00000000_00000000 = [Z(WORD), PMerge, Z(WORD), Z(WORD)] => [R(0), R3(10), RTied, R(5)], SVE;
+    0b00000100_10000001_00000000_00000000 = [Z(DWORD), PMerge, Z(DWORD), Z(DWORD)] => [R(0), R3(10), RTied, R(5)], SVE;
+    0b00000100_11000001_00000000_00000000 = [Z(QWORD), PMerge, Z(QWORD), Z(QWORD)] => [R(0), R3(10), RTied, R(5)], SVE;
+    // SUB (immediate)
+    0b00100101_00100001_11000000_00000000 = [Z(BYTE), Z(BYTE), Imm] => [R(0), RTied, Ubits(5, 8)], SVE;
+    0b00100101_01100001_11000000_00000000 = [Z(WORD), Z(WORD), Imm, End, LitMod(LSL)] => [R(0), RTied, Ubits(5, 8), Ulist(13, &[0, 8])], SVE;
+    0b00100101_10100001_11000000_00000000 = [Z(DWORD), Z(DWORD), Imm, End, LitMod(LSL)] => [R(0), RTied, Ubits(5, 8), Ulist(13, &[0, 8])], SVE;
+    0b00100101_11100001_11000000_00000000 = [Z(QWORD), Z(QWORD), Imm, End, LitMod(LSL)] => [R(0), RTied, Ubits(5, 8), Ulist(13, &[0, 8])], SVE;
+]
+"subg" = [
+    0b11010001_10000000_00000000_00000000 = [XSP, XSP, Imm, Imm] => [R(0), R(5), Uscaled(16, 6, 4), Ubits(10, 4)], MTE;
+]
+"subhn" = [
+    0b00001110_00100000_01100000_00000000 = [VStatic(BYTE, 8), VStatic(WORD, 8), VStatic(WORD, 8)] => [R(0), R(5), R(16)];
+    0b00001110_01100000_01100000_00000000 = [VStatic(WORD, 4), VStatic(DWORD, 4), VStatic(DWORD, 4)] => [R(0), R(5), R(16)];
+    0b00001110_10100000_01100000_00000000 = [VStatic(DWORD, 2), VStatic(QWORD, 2), VStatic(QWORD, 2)] => [R(0), R(5), R(16)];
+]
+"subhn2" = [
+    0b01001110_00100000_01100000_00000000 = [VStatic(BYTE, 16), VStatic(WORD, 8), VStatic(WORD, 8)] => [R(0), R(5), R(16)];
+    0b01001110_01100000_01100000_00000000 = [VStatic(WORD, 8), VStatic(DWORD, 4), VStatic(DWORD, 4)] => [R(0), R(5), R(16)];
+    0b01001110_10100000_01100000_00000000 = [VStatic(DWORD, 4), VStatic(QWORD, 2), VStatic(QWORD, 2)] => [R(0), R(5), R(16)];
+]
+"subhnb" = [
+    0b01000101_01100000_01110000_00000000 = [Z(BYTE), Z(WORD), Z(WORD)] => [R(0), R(5), R(16)], SVE2;
+    0b01000101_10100000_01110000_00000000 = [Z(WORD), Z(DWORD), Z(DWORD)] => [R(0), R(5), R(16)], SVE2;
+    0b01000101_11100000_01110000_00000000 = [Z(DWORD), Z(QWORD), Z(QWORD)] => [R(0), R(5), R(16)], SVE2;
+]
+"subhnt" = [
+    0b01000101_01100000_01110100_00000000 = [Z(BYTE), Z(WORD), Z(WORD)] => [R(0), R(5), R(16)], SVE2;
+    0b01000101_10100000_01110100_00000000 = [Z(WORD), Z(DWORD), Z(DWORD)] => [R(0), R(5), R(16)], SVE2;
+    0b01000101_11100000_01110100_00000000 = [Z(DWORD), Z(QWORD), Z(QWORD)] => [R(0), R(5), R(16)], SVE2;
+]
+"subp" = [
+    0b10011010_11000000_00000000_00000000 = [X, XSP, XSP] => [R(0), R(5), R(16)], MTE;
+]
+"subps" = [
+    0b10111010_11000000_00000000_00000000 = [X, XSP, XSP] => [R(0), R(5), R(16)], MTE;
+]
+"subr" = [
+    // SUBR (vectors, predicated)
+    0b00000100_00000011_00000000_00000000 = [Z(BYTE), PMerge, Z(BYTE), Z(BYTE)] => [R(0), R3(10), RTied, R(5)], SVE;
+    0b00000100_01000011_00000000_00000000 = [Z(WORD), PMerge, Z(WORD), Z(WORD)] => [R(0), R3(10), RTied, R(5)], SVE;
+    0b00000100_10000011_00000000_00000000 = [Z(DWORD), PMerge, Z(DWORD), Z(DWORD)] => [R(0), R3(10), RTied, R(5)], SVE;
+    0b00000100_11000011_00000000_00000000 = [Z(QWORD), PMerge, Z(QWORD), Z(QWORD)] => [R(0), R3(10), RTied, R(5)], SVE;
+    // SUBR (immediate)
+    0b00100101_00100011_11000000_00000000 = [Z(BYTE), Z(BYTE), Imm] => [R(0), RTied, Ubits(5, 8)], SVE;
+    0b00100101_01100011_11000000_00000000 = [Z(WORD), Z(WORD), Imm, End, LitMod(LSL)] => [R(0), RTied, Ubits(5, 8), Ulist(13, &[0, 8])], SVE;
+    0b00100101_10100011_11000000_00000000 = [Z(DWORD), Z(DWORD), Imm, End, LitMod(LSL)] => [R(0), RTied, Ubits(5, 8), Ulist(13, &[0, 8])], SVE;
+    0b00100101_11100011_11000000_00000000 = [Z(QWORD), Z(QWORD), Imm, End, LitMod(LSL)] => [R(0), RTied, Ubits(5, 8), Ulist(13, &[0, 8])], SVE;
+]
+"subs" = [
+    // SUBS (shifted register)
+    0b01101011_00000000_00000000_00000000 = [W, W, W, End, Mod(SHIFTS)] => [R(0), R(5), R(16), Rotates(22), Ubits(10, 5)];
+    0b11101011_00000000_00000000_00000000 = [X, X, X, End, Mod(SHIFTS)] => [R(0), R(5), R(16), Rotates(22), Ubits(10, 6)];
+    // SUBS (extended register)
+    0b01101011_00100000_00000000_00000000 = [W, WSP, W, End, Mod(EXTENDS)] => [R(0), R(5), R(16), ExtendsW(13), Urange(10, 0, 4)];
+    0b11101011_00100000_00000000_00000000 = [X, XSP, W, End, Mod(EXTENDS_W)] => [R(0), R(5), R(16), ExtendsX(13), Urange(10, 0, 4)];
+    0b11101011_00100000_00000000_00000000 = [X, XSP, X, End, Mod(EXTENDS_X)] => [R(0), R(5), R(16), ExtendsX(13), Urange(10, 0, 4)];
+    // SUBS (immediate)
+    0b01110001_00000000_00000000_00000000 = [W, WSP, Imm, End, LitMod(LSL)] => [R(0), R(5), Ubits(10, 12), Ulist(22, &[0, 12])];
+    0b11110001_00000000_00000000_00000000 = [X, XSP, Imm, End, LitMod(LSL)] => [R(0), R(5), Ubits(10, 12), Ulist(22, &[0, 12])];
+]
+"sudot" = [
+    0b00001111_00000000_11110000_00000000 = [VStatic(DWORD, 2), VStatic(BYTE, 8), VStaticElement(BYTE, 4)] => [R(0), R(5), R(16), Ufields(&[11, 21])], I8MM;
+    0b01001111_00000000_11110000_00000000 = [VStatic(DWORD, 4), VStatic(BYTE, 16), VStaticElement(BYTE, 4)] => [R(0), R(5), R(16), Ufields(&[11, 21])], I8MM;
+]
+"sumopa" = [
+    0b10100000_10100000_00000000_00000000 = [ZaTile(DWORD), PMerge, PMerge, Z(BYTE), Z(BYTE)] => [RTile(0, 2), R3(10), R3(13), R(5), R(16)], SME;
+    0b10100000_11100000_00000000_00000000 = [ZaTile(QWORD), PMerge, PMerge, Z(WORD), Z(WORD)] => [RTile(0, 3), R3(10), R3(13), R(5), R(16)], SME | SME_I16I64;
+]
+"sumops" = [
+    0b10100000_10100000_00000000_00010000 = [ZaTile(DWORD), PMerge, PMerge, Z(BYTE), Z(BYTE)] => [RTile(0, 2), R3(10), R3(13), R(5), R(16)], SME;
+    0b10100000_11100000_00000000_00010000 = [ZaTile(QWORD), PMerge, PMerge, Z(WORD), Z(WORD)] => [RTile(0, 3), R3(10), R3(13), R(5), R(16)], SME | SME_I16I64;
+]
+"sunpkhi" = [
+    0b00000101_01110001_00111000_00000000 = [Z(WORD), Z(BYTE)] => [R(0), R(5)], SVE;
+    0b00000101_10110001_00111000_00000000 = [Z(DWORD), Z(WORD)] => [R(0), R(5)], SVE;
+    0b00000101_11110001_00111000_00000000 = [Z(QWORD), Z(DWORD)] => [R(0), R(5)], SVE;
+]
+"sunpklo" = [
+    0b00000101_01110000_00111000_00000000 = [Z(WORD), Z(BYTE)] => [R(0), R(5)], SVE;
+    0b00000101_10110000_00111000_00000000 = [Z(DWORD), Z(WORD)] => [R(0), R(5)], SVE;
+    0b00000101_11110000_00111000_00000000 = [Z(QWORD), Z(DWORD)] => [R(0), R(5)], SVE;
+]
+"suqadd" = [
+    // SUQADD
+    0b01011110_00100000_00111000_00000000 = [B, B] => [R(0), R(5)];
+    0b01011110_01100000_00111000_00000000 = [H, H] => [R(0), R(5)];
+    0b01011110_10100000_00111000_00000000 = [S, S] => [R(0), R(5)];
+    0b01011110_11100000_00111000_00000000 = [D, D] => [R(0), R(5)];
+    0b00001110_00100000_00111000_00000000 = [V(BYTE), V(BYTE)] => [R(0), R(5), Rwidth(30)];
+    0b00001110_01100000_00111000_00000000 = [V(WORD), V(WORD)] => [R(0), R(5), Rwidth(30)];
+    0b00001110_10100000_00111000_00000000 = [V(DWORD), V(DWORD)] => [R(0), R(5), Rwidth(30)];
+    0b00001110_11100000_00111000_00000000 = [VStatic(QWORD, 2), VStatic(QWORD, 2)] => [R(0), R(5), Rwidth(30)];
+    // SUQADD (vectors, predicated)
+    0b01000100_00011100_10000000_00000000 = [Z(BYTE), PMerge, Z(BYTE), Z(BYTE)] => [R(0), R3(10), RTied, R(5)], SVE2;
+    0b01000100_01011100_10000000_00000000 = [Z(WORD), PMerge, Z(WORD), Z(WORD)] => [R(0), R3(10), RTied, R(5)], SVE2;
+    0b01000100_10011100_10000000_00000000 = [Z(DWORD), PMerge, Z(DWORD), Z(DWORD)] => [R(0), R3(10), RTied, R(5)], SVE2;
+    0b01000100_11011100_10000000_00000000 = [Z(QWORD), PMerge, Z(QWORD), Z(QWORD)] => [R(0), R3(10), RTied, R(5)], SVE2;
+]
+"svc" = [
+    0b11010100_00000000_00000000_00000001 = [Imm] => [Ubits(5, 16)];
+]
+"swp" = [
+    0b10111000_00100000_10000000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], LSE;
+    0b11111000_00100000_10000000_00000000 = [X, X, RefBase] => [R(16), R(0), R(5)], LSE;
+]
+"swpa" = [
+    0b10111000_10100000_10000000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], LSE;
+    0b11111000_10100000_10000000_00000000 = [X, X, RefBase] => [R(16), R(0), R(5)], LSE;
+]
+"swpab" = [
+    0b00111000_10100000_10000000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], LSE;
+]
+"swpah" = [
+    0b01111000_10100000_10000000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], LSE;
+]
+"swpal" = [
+    0b10111000_11100000_10000000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], LSE;
+    0b11111000_11100000_10000000_00000000 = [X, X, RefBase] => [R(16), R(0), R(5)], LSE;
+]
+"swpalb" = [
+    0b00111000_11100000_10000000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], LSE;
+]
+"swpalh" = [
+    0b01111000_11100000_10000000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], LSE;
+]
+"swpb" = [
+    0b00111000_00100000_10000000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], LSE;
+]
+"swph" = [
+    0b01111000_00100000_10000000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], LSE;
+]
+"swpl" = [
+    0b10111000_01100000_10000000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], LSE;
+    0b11111000_01100000_10000000_00000000 = [X, X, RefBase] => [R(16), R(0), R(5)], LSE;
+]
+"swplb" = [
+    0b00111000_01100000_10000000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], LSE;
+]
+"swplh" = [
+    0b01111000_01100000_10000000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], LSE;
+]
+"sxtb" = [
+    // SXTB
+    0b00010011_00000000_00011100_00000000 = [W, W] => [R(0), R(5)];
+    0b10010011_01000000_00011100_00000000 = [X, W] => [R(0), R(5)];
+    // SXTB (predicated)
+    0b00000100_01010000_10100000_00000000 = [Z(WORD), PMerge, Z(WORD)] => [R(0), R3(10), R(5)], SVE;
+    0b00000100_10010000_10100000_00000000 = [Z(DWORD), PMerge, Z(DWORD)] => [R(0), R3(10), R(5)], SVE;
+    0b00000100_11010000_10100000_00000000 = [Z(QWORD), PMerge, Z(QWORD)] => [R(0), R3(10), R(5)], SVE;
+]
+"sxth" = [
+    // SXTH
+    0b00010011_00000000_00111100_00000000 = [W, W] => [R(0), R(5)];
+    0b10010011_01000000_00111100_00000000 = [X, W] => [R(0), R(5)];
+    // SXTH (predicated)
+    0b00000100_10010010_10100000_00000000 = [Z(DWORD), PMerge, Z(DWORD)] => [R(0), R3(10), R(5)], SVE;
+    0b00000100_11010010_10100000_00000000 = [Z(QWORD), PMerge, Z(QWORD)] => [R(0), R3(10), R(5)], SVE;
+]
+"sxtl" = [
+    0b00001111_00001000_10100100_00000000 = [VStatic(WORD, 8), VStatic(BYTE, 8)] => [R(0), R(5)];
+    0b00001111_00010000_10100100_00000000 = [VStatic(DWORD, 4), VStatic(WORD, 4)] => [R(0), R(5)];
+    0b00001111_00100000_10100100_00000000 = [VStatic(QWORD, 2), VStatic(DWORD, 2)] => [R(0), R(5)];
+]
+"sxtl2" = [
+    0b01001111_00001000_10100100_00000000 = [VStatic(WORD, 8), VStatic(BYTE, 16)] => [R(0), R(5)];
+    0b01001111_00010000_10100100_00000000 = [VStatic(DWORD, 4), VStatic(WORD, 8)] => [R(0), R(5)];
+    0b01001111_00100000_10100100_00000000 = [VStatic(QWORD, 2), VStatic(DWORD, 4)] => [R(0), R(5)];
+]
+"sxtw" = [
+    // SXTW
+    0b10010011_01000000_01111100_00000000 = [X, W] => [R(0), R(5)];
+    // SXTW (predicated)
+    0b00000100_11010100_10100000_00000000 = [Z(QWORD), PMerge, Z(QWORD)] => [R(0), R3(10), R(5)], SVE;
+]
+"sys" = [
+    0b11010101_00001000_00000000_00000000 = [Imm, Ident, Ident, Imm, End, X] => [Ubits(16, 3), LitList(12, "CONTROL_REGS"), LitList(8, "CONTROL_REGS"), Ubits(5, 3), R(0)];
+]
+"sysl" = [
+    0b11010101_00101000_00000000_00000000 = [X, Imm, Ident, Ident, Imm] => [R(0), Ubits(16, 3), LitList(12, "CONTROL_REGS"), LitList(8, "CONTROL_REGS"), Ubits(5, 3)];
+]
+"tbl" = [
+    // TBL
+    0b00001110_00000000_00100000_00000000 = [V(BYTE), RegListStatic(2, BYTE, 16), V(BYTE)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b00001110_00000000_01000000_00000000 = [V(BYTE), RegListStatic(3, BYTE, 16), V(BYTE)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b00001110_00000000_01100000_00000000 = [V(BYTE), RegListStatic(4, BYTE, 16), V(BYTE)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b00001110_00000000_00000000_00000000 = [V(BYTE), RegListStatic(1, BYTE, 16), V(BYTE)] => [R(0), R(5), R(16), Rwidth(30)];
+    // TBL
+    0b00000101_00100000_00110000_00000000 = [Z(BYTE), ZRegList(1, BYTE), Z(BYTE)] => [R(0), R(5), R(16)], SVE;
+    0b00000101_01100000_00110000_00000000 = [Z(WORD), ZRegList(1, WORD), Z(WORD)] => [R(0), R(5), R(16)], SVE;
+    0b00000101_10100000_00110000_00000000 = [Z(DWORD), ZRegList(1, DWORD), Z(DWORD)] => [R(0), R(5), R(16)], SVE;
+    0b00000101_11100000_00110000_00000000 = [Z(QWORD), ZRegList(1, QWORD), Z(QWORD)] => [R(0), R(5), R(16)], SVE;
+    // TBL (two registers)
+    0b00000101_00100000_00101000_00000000 = [Z(BYTE), ZRegList(2, BYTE), Z(BYTE)] => [R(0), R(5), R(16)], SVE2;
+    0b00000101_01100000_00101000_00000000 = [Z(WORD), ZRegList(2, WORD), Z(WORD)] => [R(0), R(5), R(16)], SVE2;
+    0b00000101_10100000_00101000_00000000 = [Z(DWORD), ZRegList(2, DWORD), Z(DWORD)] => [R(0), R(5), R(16)], SVE2;
+    0b00000101_11100000_00101000_00000000 = [Z(QWORD), ZRegList(2, QWORD), Z(QWORD)] => [R(0), R(5), R(16)], SVE2;
+]
+"tbnz" = [
+    0b00110111_00000000_00000000_00000000 = [W, Imm, Offset] => [R(0), Ubits(19, 5), Offset(TBZ)];
+    0b00110111_00000000_00000000_00000000 = [X, Imm, Offset] => [R(0), BUbits(6), Uslice(19, 5, 0), Uslice(31, 1, 5), A, Offset(TBZ)];
+]
+"tbx" = [
+    0b00001110_00000000_00110000_00000000 = [V(BYTE), RegListStatic(2, BYTE, 16), V(BYTE)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b00001110_00000000_01010000_00000000 = [V(BYTE), RegListStatic(3, BYTE, 16), V(BYTE)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b00001110_00000000_01110000_00000000 = [V(BYTE), RegListStatic(4, BYTE, 16), V(BYTE)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b00001110_00000000_00010000_00000000 = [V(BYTE), RegListStatic(1, BYTE, 16), V(BYTE)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b00000101_00100000_00101100_00000000 = [Z(BYTE), Z(BYTE), Z(BYTE)] => [R(0), R(5), R(16)], SVE2;
+    0b00000101_01100000_00101100_00000000 = [Z(WORD), Z(WORD), Z(WORD)] => [R(0), R(5), R(16)], SVE2;
+    0b00000101_10100000_00101100_00000000 = [Z(DWORD), Z(DWORD), Z(DWORD)] => [R(0), R(5), R(16)], SVE2;
+    0b00000101_11100000_00101100_00000000 = [Z(QWORD), Z(QWORD), Z(QWORD)] => [R(0), R(5), R(16)], SVE2;
+]
+"tbz" = [
+    0b00110110_00000000_00000000_00000000 = [W, Imm, Offset] => [R(0), Ubits(19, 5), Offset(TBZ)];
+    0b00110110_00000000_00000000_00000000 = [X, Imm, Offset] => [R(0), BUbits(6), Uslice(19, 5, 0), Uslice(31, 1, 5), A, Offset(TBZ)];
+]
+"tlbi" = [
+    0b11010101_00001000_10000000_00000000 = [Ident, End, X] => [LitList(5, "TLBI_OPS"), R(0)];
+]
+"trn1" = [
+    // TRN1
+    0b00001110_00000000_00101000_00000000 = [V(BYTE), V(BYTE), V(BYTE)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b00001110_01000000_00101000_00000000 = [V(WORD), V(WORD), V(WORD)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b00001110_10000000_00101000_00000000 = [V(DWORD), V(DWORD), V(DWORD)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b00001110_11000000_00101000_00000000 = [VStatic(QWORD, 2), VStatic(QWORD, 2), VStatic(QWORD, 2)] => [R(0), R(5), R(16), Rwidth(30)];
+    // TRN1 (predicates)
+    0b00000101_00100000_01010000_00000000 = [P(BYTE), P(BYTE), P(BYTE)] => [R4(0), R4(5), R4(16)], SVE;
+    0b00000101_01100000_01010000_00000000 = [P(WORD), P(WORD), P(WORD)] => [R4(0), R4(5), R4(16)], SVE;
+    0b00000101_10100000_01010000_00000000 = [P(DWORD), P(DWORD), P(DWORD)] => [R4(0), R4(5), R4(16)], SVE;
+    0b00000101_11100000_01010000_00000000 = [P(QWORD), P(QWORD), P(QWORD)] => [R4(0), R4(5), R4(16)], SVE;
+    // TRN1 (vectors, unpredicated)
+    0b00000101_00100000_01110000_00000000 = [Z(BYTE), Z(BYTE), Z(BYTE)] => [R(0), R(5), R(16)], SVE;
+    0b00000101_01100000_01110000_00000000 = [Z(WORD), Z(WORD), Z(WORD)] => [R(0), R(5), R(16)], SVE;
+    0b00000101_10100000_01110000_00000000 = [Z(DWORD), Z(DWORD), Z(DWORD)] => [R(0), R(5), R(16)], SVE;
+    0b00000101_11100000_01110000_00000000 = [Z(QWORD), Z(QWORD), Z(QWORD)] => [R(0), R(5), R(16)], SVE;
+]
+"trn2" = [
+    // TRN2
+    0b00001110_00000000_01101000_00000000 = [V(BYTE), V(BYTE), V(BYTE)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b00001110_01000000_01101000_00000000 = [V(WORD), V(WORD), V(WORD)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b00001110_10000000_01101000_00000000 = [V(DWORD), V(DWORD), V(DWORD)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b00001110_11000000_01101000_00000000 = [VStatic(QWORD, 2), VStatic(QWORD, 2), VStatic(QWORD, 2)] => [R(0), R(5), R(16), Rwidth(30)];
+    // TRN2 (predicates)
+    0b00000101_00100000_01010100_00000000 = [P(BYTE), P(BYTE), P(BYTE)] => [R4(0), R4(5), R4(16)], SVE;
+    0b00000101_01100000_01010100_00000000 = [P(WORD), P(WORD), P(WORD)] => [R4(0), R4(5), R4(16)], SVE;
+    0b00000101_10100000_01010100_00000000 = [P(DWORD), P(DWORD), P(DWORD)] => [R4(0), R4(5), R4(16)], SVE;
+    0b00000101_11100000_01010100_00000000 = [P(QWORD), P(QWORD), P(QWORD)] => [R4(0), R4(5), R4(16)], SVE;
+    // TRN2 (vectors, unpredicated)
+    0b00000101_00100000_01110100_00000000 = [Z(BYTE), Z(BYTE), Z(BYTE)] => [R(0), R(5), R(16)], SVE;
+    0b00000101_01100000_01110100_00000000 = [Z(WORD), Z(WORD), Z(WORD)] => [R(0), R(5), R(16)], SVE;
+    0b00000101_10100000_01110100_00000000 = [Z(DWORD), Z(DWORD), Z(DWORD)] => [R(0), R(5), R(16)], SVE;
+    0b00000101_11100000_01110100_00000000 = [Z(QWORD), Z(QWORD), Z(QWORD)] => [R(0), R(5), R(16)], SVE;
+]
+"tsb" = [
+    0b11010101_00000011_00100010_01011111 = [Lit("csync")] => [], TRF;
+]
+"tst" = [
+    // TST (immediate)
+    0b01110010_00000000_00000000_00011111 = [W, Imm] => [R(5), Special(10, LOGICAL_IMMEDIATE_W)];
+    0b11110010_00000000_00000000_00011111 = [X, Imm] => [R(5), Special(10, LOGICAL_IMMEDIATE_X)];
+    // TST (shifted register)
+    0b01101010_00000000_00000000_00011111 = [W, W, End, Mod(ROTATES)] => [R(5), R(16), Rotates(22), Ubits(10, 5)];
+    0b11101010_00000000_00000000_00011111 = [X, X, End, Mod(ROTATES)] => [R(5), R(16), Rotates(22), Ubits(10, 6)];
+]
+"uaba" = [
+    0b00101110_00100000_01111100_00000000 = [V(BYTE), V(BYTE), V(BYTE)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b00101110_01100000_01111100_00000000 = [V(WORD), V(WORD), V(WORD)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b00101110_10100000_01111100_00000000 = [V(DWORD), V(DWORD), V(DWORD)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b01000101_00000000_11111100_00000000 = [Z(BYTE), Z(BYTE), Z(BYTE)] => [R(0), R(5), R(16)], SVE2;
+    0b01000101_01000000_11111100_00000000 = [Z(WORD), Z(WORD), Z(WORD)] => [R(0), R(5), R(16)], SVE2;
+    0b01000101_10000000_11111100_00000000 = [Z(DWORD), Z(DWORD), Z(DWORD)] => [R(0), R(5), R(16)], SVE2;
+    0b01000101_11000000_11111100_00000000 = [Z(QWORD), Z(QWORD), Z(QWORD)] => [R(0), R(5), R(16)], SVE2;
+]
+"uabal" = [
+    0b00101110_00100000_01010000_00000000 = [VStatic(WORD, 8), VStatic(BYTE, 8), VStatic(BYTE, 8)] => [R(0), R(5), R(16)];
+    0b00101110_01100000_01010000_00000000 = [VStatic(DWORD, 4), VStatic(WORD, 4), VStatic(WORD, 4)] => [R(0), R(5), R(16)];
+    0b00101110_10100000_01010000_00000000 = [VStatic(QWORD, 2), VStatic(DWORD, 2), VStatic(DWORD, 2)] => [R(0), R(5), R(16)];
+]
+"uabal2" = [
+    0b01101110_00100000_01010000_00000000 = [VStatic(WORD, 8), VStatic(BYTE, 16), VStatic(BYTE, 16)] => [R(0), R(5), R(16)];
+    0b01101110_01100000_01010000_00000000 = [VStatic(DWORD, 4), VStatic(WORD, 8), VStatic(WORD, 8)] => [R(0), R(5), R(16)];
+    0b01101110_10100000_01010000_00000000 = [VStatic(QWORD, 2), VStatic(DWORD, 4), VStatic(DWORD, 4)] => [R(0), R(5), R(16)];
+]
+"uabalb" = [
+    0b01000101_01000000_11001000_00000000 = [Z(WORD), Z(BYTE), Z(BYTE)] => [R(0), R(5), R(16)], SVE2;
+    0b01000101_10000000_11001000_00000000 = [Z(DWORD), Z(WORD), Z(WORD)] => [R(0), R(5), R(16)], SVE2;
+    0b01000101_11000000_11001000_00000000 = [Z(QWORD), Z(DWORD), Z(DWORD)] => [R(0), R(5), R(16)], SVE2;
+]
+"uabalt" = [
+    0b01000101_01000000_11001100_00000000 = [Z(WORD), Z(BYTE), Z(BYTE)] => [R(0), R(5), R(16)], SVE2;
+    0b01000101_10000000_11001100_00000000 = [Z(DWORD), Z(WORD), Z(WORD)] => [R(0), R(5), R(16)], SVE2;
+    0b01000101_11000000_11001100_00000000 = [Z(QWORD), Z(DWORD), Z(DWORD)] => [R(0), R(5), R(16)], SVE2;
+]
+"uabd" = [
+    // UABD
+    0b00101110_00100000_01110100_00000000 = [V(BYTE), V(BYTE), V(BYTE)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b00101110_01100000_01110100_00000000 = [V(WORD), V(WORD), V(WORD)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b00101110_10100000_01110100_00000000 = [V(DWORD), V(DWORD), V(DWORD)] => [R(0), R(5), R(16), Rwidth(30)];
+    // UABD (vectors, predicated)
+    0b00000100_00001101_00000000_00000000 = [Z(BYTE), PMerge, Z(BYTE), Z(BYTE)] => [R(0), R3(10), RTied, R(5)], SVE;
+    0b00000100_01001101_00000000_00000000 = [Z(WORD), PMerge, Z(WORD), Z(WORD)] => [R(0), R3(10), RTied, R(5)], SVE;
+    0b00000100_10001101_00000000_00000000 = [Z(DWORD), PMerge, Z(DWORD), Z(DWORD)] => [R(0), R3(10), RTied, R(5)], SVE;
+    0b00000100_11001101_00000000_00000000 = [Z(QWORD), PMerge, Z(QWORD), Z(QWORD)] => [R(0), R3(10), RTied, R(5)], SVE;
+]
+"uabdl" = [
+    0b00101110_00100000_01110000_00000000 = [VStatic(WORD, 8), VStatic(BYTE, 8), VStatic(BYTE, 8)] => [R(0), R(5), R(16)];
+    0b00101110_01100000_01110000_00000000 = [VStatic(DWORD, 4), VStatic(WORD, 4), VStatic(WORD, 4)] => [R(0), R(5), R(16)];
+    0b00101110_10100000_01110000_00000000 = [VStatic(QWORD, 2), VStatic(DWORD, 2), VStatic(DWORD, 2)] => [R(0), R(5), R(16)];
+]
+"uabdl2" = [
+    0b01101110_00100000_01110000_00000000 = [VStatic(WORD, 8), VStatic(BYTE, 16), VStatic(BYTE, 16)] => [R(0), R(5), R(16)];
+    0b01101110_01100000_01110000_00000000 = [VStatic(DWORD, 4), VStatic(WORD, 8), VStatic(WORD, 8)] => [R(0), R(5), R(16)];
+    0b01101110_10100000_01110000_00000000 = [VStatic(QWORD, 2), VStatic(DWORD, 4), VStatic(DWORD, 4)] => [R(0), R(5), R(16)];
+]
+"uabdlb" = [
+    0b01000101_01000000_00111000_00000000 = [Z(WORD), Z(BYTE), Z(BYTE)] => [R(0), R(5), R(16)], SVE2;
+    0b01000101_10000000_00111000_00000000 = [Z(DWORD), Z(WORD), Z(WORD)] => [R(0), R(5), R(16)], SVE2;
+    0b01000101_11000000_00111000_00000000 = [Z(QWORD), Z(DWORD), Z(DWORD)] => [R(0), R(5), R(16)], SVE2;
+]
+"uabdlt" = [
+    0b01000101_01000000_00111100_00000000 = [Z(WORD), Z(BYTE), Z(BYTE)] => [R(0), R(5), R(16)], SVE2;
+    0b01000101_10000000_00111100_00000000 = [Z(DWORD), Z(WORD), Z(WORD)] => [R(0), R(5), R(16)], SVE2;
+    0b01000101_11000000_00111100_00000000 = [Z(QWORD), Z(DWORD), Z(DWORD)] => [R(0), R(5), R(16)], SVE2;
+]
+"uadalp" = [
+    0b00101110_00100000_01101000_00000000 = [V(WORD), V(BYTE)] => [R(0), R(5), Rwidth(30)];
+    0b00101110_01100000_01101000_00000000 = [V(DWORD), V(WORD)] => [R(0), R(5), Rwidth(30)];
+    0b00101110_10100000_01101000_00000000 = [V(QWORD), V(DWORD)] => [R(0), R(5), Rwidth(30)];
+    0b01000100_01000101_10100000_00000000 = [Z(WORD), PMerge, Z(BYTE)] => [R(0), R3(10), R(5)], SVE2;
+    0b01000100_10000101_10100000_00000000 = [Z(DWORD), PMerge, Z(WORD)] => [R(0), R3(10), R(5)], SVE2;
+    0b01000100_11000101_10100000_00000000 = [Z(QWORD), PMerge, Z(DWORD)] => [R(0), R3(10), R(5)], SVE2;
+]
+"uaddl" = [
+    0b00101110_00100000_00000000_00000000 = [VStatic(WORD, 8), VStatic(BYTE, 8), VStatic(BYTE, 8)] => [R(0), R(5), R(16)];
+    0b00101110_01100000_00000000_00000000 = [VStatic(DWORD, 4), VStatic(WORD, 4), VStatic(WORD, 4)] => [R(0), R(5), R(16)];
+    0b00101110_10100000_00000000_00000000 = [VStatic(QWORD, 2), VStatic(DWORD, 2), VStatic(DWORD, 2)] => [R(0), R(5), R(16)];
+]
+"uaddl2" = [
+    0b01101110_00100000_00000000_00000000 = [VStatic(WORD, 8), VStatic(BYTE, 16), VStatic(BYTE, 16)] => [R(0), R(5), R(16)];
+    0b01101110_01100000_00000000_00000000 = [VStatic(DWORD, 4), VStatic(WORD, 8), VStatic(WORD, 8)] => [R(0), R(5), R(16)];
+    0b01101110_10100000_00000000_00000000 = [VStatic(QWORD, 2), VStatic(DWORD, 4), VStatic(DWORD, 4)] => [R(0), R(5), R(16)];
+]
+"uaddlb" = [
+    0b01000101_01000000_00001000_00000000 = [Z(WORD), Z(BYTE), Z(BYTE)] => [R(0), R(5), R(16)], SVE2;
+    0b01000101_10000000_00001000_00000000 = [Z(DWORD), Z(WORD), Z(WORD)] => [R(0), R(5), R(16)], SVE2;
+    0b01000101_11000000_00001000_00000000 = [Z(QWORD), Z(DWORD), Z(DWORD)] => [R(0), R(5), R(16)], SVE2;
+]
+"uaddlp" = [
+    0b00101110_00100000_00101000_00000000 = [V(WORD), V(BYTE)] => [R(0), R(5), Rwidth(30)];
+    0b00101110_01100000_00101000_00000000 = [V(DWORD), V(WORD)] => [R(0), R(5), Rwidth(30)];
+    0b00101110_10100000_00101000_00000000 = [V(QWORD), V(DWORD)] => [R(0), R(5), Rwidth(30)];
+]
+"uaddlt" = [
+    0b01000101_01000000_00001100_00000000 = [Z(WORD), Z(BYTE), Z(BYTE)] => [R(0), R(5), R(16)], SVE2;
+    0b01000101_10000000_00001100_00000000 = [Z(DWORD), Z(WORD), Z(WORD)] => [R(0), R(5), R(16)], SVE2;
+    0b01000101_11000000_00001100_00000000 = [Z(QWORD), Z(DWORD), Z(DWORD)] => [R(0), R(5), R(16)], SVE2;
+]
+"uaddlv" = [
+    0b00101110_00110000_00111000_00000000 = [H, V(BYTE)] => [R(0), R(5), Rwidth(30)];
+    0b00101110_01110000_00111000_00000000 = [S, V(WORD)] => [R(0), R(5), Rwidth(30)];
+    0b00101110_10110000_00111000_00000000 = [D, VStatic(DWORD, 4)] => [R(0), R(5), Rwidth(30)];
+]
+"uaddv" = [
+    0b00000100_00000001_00100000_00000000 = [D, PBare, Z(BYTE)] => [R(0), R3(10), R(5)], SVE;
+    0b00000100_01000001_00100000_00000000 = [D, PBare, Z(WORD)] => [R(0), R3(10), R(5)], SVE;
+    0b00000100_10000001_00100000_00000000 = [D, PBare, Z(DWORD)] => [R(0), R3(10), R(5)], SVE;
+    0b00000100_11000001_00100000_00000000 = [D, PBare, Z(QWORD)] => [R(0), R3(10), R(5)], SVE;
+]
+"uaddw" = [
+    0b00101110_00100000_00010000_00000000 = [VStatic(WORD, 8), VStatic(WORD, 8), VStatic(BYTE, 8)] => [R(0), R(5), R(16)];
+    0b00101110_01100000_00010000_00000000 = [VStatic(DWORD, 4), VStatic(DWORD, 4), VStatic(WORD, 4)] => [R(0), R(5), R(16)];
+    0b00101110_10100000_00010000_00000000 = [VStatic(QWORD, 2), VStatic(QWORD, 2), VStatic(DWORD, 2)] => [R(0), R(5), R(16)];
+]
+"uaddw2" = [
+    0b01101110_00100000_00010000_00000000 = [VStatic(WORD, 8), VStatic(WORD, 8), VStatic(BYTE, 16)] => [R(0), R(5), R(16)];
+    0b01101110_01100000_00010000_00000000 = [VStatic(DWORD, 4), VStatic(DWORD, 4), VStatic(WORD, 8)] => [R(0), R(5), R(16)];
+    0b01101110_10100000_00010000_00000000 = [VStatic(QWORD, 2), VStatic(QWORD, 2), VStatic(DWORD, 4)] => [R(0), R(5), R(16)];
+]
+"uaddwb" = [
+    0b01000101_01000000_01001000_00000000 = [Z(WORD), Z(WORD), Z(BYTE)] => [R(0), R(5), R(16)], SVE2;
+    0b01000101_10000000_01001000_00000000 = [Z(DWORD), Z(DWORD), Z(WORD)] => [R(0), R(5), R(16)], SVE2;
+    0b01000101_11000000_01001000_00000000 = [Z(QWORD), Z(QWORD), Z(DWORD)] => [R(0), R(5), R(16)], SVE2;
+]
+"uaddwt" = [
+    0b01000101_01000000_01001100_00000000 = [Z(WORD), Z(WORD), Z(BYTE)] => [R(0), R(5), R(16)], SVE2;
+    0b01000101_10000000_01001100_00000000 = [Z(DWORD), Z(DWORD), Z(WORD)] => [R(0), R(5), R(16)], SVE2;
+    0b01000101_11000000_01001100_00000000 = [Z(QWORD), Z(QWORD), Z(DWORD)] => [R(0), R(5), R(16)], SVE2;
+]
+"ubfiz" = [
+    0b01010011_00000000_00000000_00000000 = [W, W, Imm, Imm] => [R(0), R(5), Unegmod(16, 5), BUsum(5), Urange(10, 1, 32)];
+    0b11010011_01000000_00000000_00000000 = [X, X, Imm, Imm] => [R(0), R(5), Unegmod(16, 6), BUsum(6), Urange(10, 1, 64)];
+]
+"ubfm" = [
+    0b01010011_00000000_00000000_00000000 = [W, W, Imm, Imm] => [R(0), R(5), Ubits(16, 5), Ubits(10, 5)];
+    0b11010011_01000000_00000000_00000000 = [X, X, Imm, Imm] => [R(0), R(5), Ubits(16, 6), BUsum(6), Ubits(10, 6)];
+]
+"ubfx" = [
+    0b01010011_00000000_00000000_00000000 = [W, W, Imm, Imm] => [R(0), R(5), Ubits(16, 5), BUsum(5), Usumdec(10, 5)];
+    0b11010011_01000000_00000000_00000000 = [X, X, Imm, Imm] => [R(0), R(5), Ubits(16, 6), BUsum(6), Usumdec(10, 6)];
+]
+"uclamp" = [
+    0b01000100_00000000_11000100_00000000 = [Z(BYTE), Z(BYTE), Z(BYTE)] => [R(0), R(5), R(16)], SME;
+    0b01000100_01000000_11000100_00000000 = [Z(WORD), Z(WORD), Z(WORD)] => [R(0), R(5), R(16)], SME;
+    0b01000100_10000000_11000100_00000000 = [Z(DWORD), Z(DWORD), Z(DWORD)] => [R(0), R(5), R(16)], SME;
+    0b01000100_11000000_11000100_00000000 = [Z(QWORD), Z(QWORD), Z(QWORD)] => [R(0), R(5), R(16)], SME;
+]
+"ucvtf" = [
+    // UCVTF (vector, fixed-point)
+    0b01111111_00000000_11100100_00000000 = [H, H, Imm] => [R(0), R(5), BUrange(1, 16), Usub(16, 5, 32)], FP16;
+    0b01111111_00000000_11100100_00000000 = [S, S, Imm] => [R(0), R(5), BUrange(1, 32), Usub(16, 6, 64)];
+    0b01111111_00000000_11100100_00000000 = [D, D, Imm] => [R(0), R(5), BUrange(1, 64), Usub(16, 7, 128)];
+    0b00101111_00010000_11100100_00000000 = [V(WORD), V(WORD), Imm] => [R(0), R(5), Usub(16, 4, 16), Rwidth(30)], FP16;
+    0b00101111_00100000_11100100_00000000 = [V(DWORD), V(DWORD), Imm] => [R(0), R(5), Usub(16, 5, 32), Rwidth(30)];
+    0b00101111_01000000_11100100_00000000 = [VStatic(QWORD, 2), VStatic(QWORD, 2), Imm] => [R(0), R(5), Usub(16, 6, 64), Rwidth(30)];
+    // UCVTF (vector, integer)
+    0b01111110_01111001_11011000_00000000 = [H, H] => [R(0), R(5)], FP16;
+    0b01111110_00100001_11011000_00000000 = [S, S] => [R(0), R(5)];
+    0b01111110_01100001_11011000_00000000 = [D, D] => [R(0), R(5)];
+    0b00101110_01111001_11011000_00000000 = [V(WORD), V(WORD)] => [R(0), R(5), Rwidth(30)], FP16;
+    0b00101110_00100001_11011000_00000000 = [V(DWORD), V(DWORD)] => [R(0), R(5), Rwidth(30)];
+    0b00101110_01100001_11011000_00000000 = [VStatic(QWORD, 2), VStatic(QWORD, 2)] => [R(0), R(5), Rwidth(30)];
+    // UCVTF (scalar, fixed-point)
+    0b00011110_11000011_00000000_00000000 = [H, W, Imm] => [R(0), R(5), BUrange(1, 32), Usub(10, 6, 64)], FP16;
+    0b00011110_00000011_00000000_00000000 = [S, W, Imm] => [R(0), R(5), BUrange(1, 32), Usub(10, 6, 64)];
+    0b00011110_01000011_00000000_00000000 = [D, W, Imm] => [R(0), R(5), BUrange(1, 32), Usub(10, 6, 64)];
+    0b10011110_11000011_00000000_00000000 = [H, X, Imm] => [R(0), R(5), Usub(10, 6, 64)], FP16;
+    0b10011110_00000011_00000000_00000000 = [S, X, Imm] => [R(0), R(5), Usub(10, 6, 64)];
+    0b10011110_01000011_00000000_00000000 = [D, X, Imm] => [R(0), R(5), Usub(10, 6, 64)];
+    // UCVTF (scalar, integer)
+    0b00011110_11100011_00000000_00000000 = [H, W] => [R(0), R(5)], FP16;
+    0b00011110_00100011_00000000_00000000 = [S, W] => [R(0), R(5)];
+    0b00011110_01100011_00000000_00000000 = [D, W] => [R(0), R(5)];
+    0b10011110_11100011_00000000_00000000 = [H, X] => [R(0), R(5)], FP16;
+    0b10011110_00100011_00000000_00000000 = [S, X] => [R(0), R(5)];
+    0b10011110_01100011_00000000_00000000 = [D, X] => [R(0), R(5)];
+    // UCVTF
+    0b01100101_11010111_10100000_00000000 = [Z(QWORD), PMerge, Z(QWORD)] => [R(0), R3(10), R(5)], SVE;
+    0b01100101_11010001_10100000_00000000 = [Z(QWORD), PMerge, Z(DWORD)] => [R(0), R3(10), R(5)], SVE;
+    0b01100101_01010111_10100000_00000000 = [Z(WORD), PMerge, Z(QWORD)] => [R(0), R3(10), R(5)], SVE;
+    0b01100101_01010011_10100000_00000000 = [Z(WORD), PMerge, Z(WORD)] => [R(0), R3(10), R(5)], SVE;
+    0b01100101_01010101_10100000_00000000 = [Z(WORD), PMerge, Z(DWORD)] => [R(0), R3(10), R(5)], SVE;
+    0b01100101_11010101_10100000_00000000 = [Z(DWORD), PMerge, Z(QWORD)] => [R(0), R3(10), R(5)], SVE;
+    0b01100101_10010101_10100000_00000000 = [Z(DWORD), PMerge, Z(DWORD)] => [R(0), R3(10), R(5)], SVE;
+]
+"udf" = [
+    0b00000000_00000000_00000000_00000000 = [Imm] => [Ubits(0, 16)];
+]
+"udiv" = [
+    0b00011010_11000000_00001000_00000000 = [W, W, W] => [R(0), R(5), R(16)];
+    0b10011010_11000000_00001000_00000000 = [X, X, X] => [R(0), R(5), R(16)];
+    0b00000100_10010101_00000000_00000000 = [Z(DWORD), PMerge, Z(DWORD), Z(DWORD)] => [R(0), R3(10), RTied, R(5)], SVE;
+    0b00000100_11010101_00000000_00000000 = [Z(QWORD), PMerge, Z(QWORD), Z(QWORD)] => [R(0), R3(10), RTied, R(5)], SVE;
+]
+"udivr" = [
+    0b00000100_10010111_00000000_00000000 = [Z(DWORD), PMerge, Z(DWORD), Z(DWORD)] => [R(0), R3(10), RTied, R(5)], SVE;
+    0b00000100_11010111_00000000_00000000 = [Z(QWORD), PMerge, Z(QWORD), Z(QWORD)] => [R(0), R3(10), RTied, R(5)], SVE;
+]
+"udot" = [
+    // UDOT (by element)
+    0b00101111_10000000_11100000_00000000 = [VStatic(DWORD, 2), VStatic(BYTE, 8), VStaticElement(BYTE, 4)] => [R(0), R(5), R(16), Ufields(&[11, 21])], DOTPROD;
+    0b01101111_10000000_11100000_00000000 = [VStatic(DWORD, 4), VStatic(BYTE, 16), VStaticElement(BYTE, 4)] => [R(0), R(5), R(16), Ufields(&[11, 21])], DOTPROD;
+    // UDOT (vector)
+    0b00101110_10000000_10010100_00000000 = [VStatic(DWORD, 2), VStatic(BYTE, 8), VStatic(BYTE, 8)] => [R(0), R(5), R(16)], DOTPROD;
+    0b01101110_10000000_10010100_00000000 = [VStatic(DWORD, 4), VStatic(BYTE, 16), VStatic(BYTE, 16)] => [R(0), R(5), R(16)], DOTPROD;
+    // UDOT (vectors)
+    0b01000100_10000000_00000100_00000000 = [Z(DWORD), Z(BYTE), Z(BYTE)] => [R(0), R(5), R(16)], SVE;
+    0b01000100_11000000_00000100_00000000 = [Z(QWORD), Z(WORD), Z(WORD)] => [R(0), R(5), R(16)], SVE;
+    // UDOT (indexed)
+    0b01000100_11100000_00000100_00000000 = [Z(QWORD), Z(WORD), ZElement(WORD)] => [R(0), R(5), R4(16), Ubits(20, 1)], SVE;
+    0b01000100_10100000_00000100_00000000 = [Z(DWORD), Z(BYTE), ZElement(BYTE)] => [R(0), R(5), R3(16), Ubits(19, 2)], SVE;
+]
+"uhadd" = [
+    // UHADD
+    0b00101110_00100000_00000100_00000000 = [V(BYTE), V(BYTE), V(BYTE)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b00101110_01100000_00000100_00000000 = [V(WORD), V(WORD), V(WORD)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b00101110_10100000_00000100_00000000 = [V(DWORD), V(DWORD), V(DWORD)] => [R(0), R(5), R(16), Rwidth(30)];
+    // UHADD (vectors, predicated)
+    0b01000100_00010001_10000000_00000000 = [Z(BYTE), PMerge, Z(BYTE), Z(BYTE)] => [R(0), R3(10), RTied, R(5)], SVE2;
+    0b01000100_01010001_10000000_00000000 = [Z(WORD), PMerge, Z(WORD), Z(WORD)] => [R(0), R3(10), RTied, R(5)], SVE2;
+    0b01000100_10010001_10000000_00000000 = [Z(DWORD), PMerge, Z(DWORD), Z(DWORD)] => [R(0), R3(10), RTied, R(5)], SVE2;
+    0b01000100_11010001_10000000_00000000 = [Z(QWORD), PMerge, Z(QWORD), Z(QWORD)] => [R(0), R3(10), RTied, R(5)], SVE2;
+]
+"uhsub" = [
+    // UHSUB
+    0b00101110_00100000_00100100_00000000 = [V(BYTE), V(BYTE), V(BYTE)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b00101110_01100000_00100100_00000000 = [V(WORD), V(WORD), V(WORD)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b00101110_10100000_00100100_00000000 = [V(DWORD), V(DWORD), V(DWORD)] => [R(0), R(5), R(16), Rwidth(30)];
+    // UHSUB (vectors, predicated)
+    0b01000100_00010011_10000000_00000000 = [Z(BYTE), PMerge, Z(BYTE), Z(BYTE)] => [R(0), R3(10), RTied, R(5)], SVE2;
+    0b01000100_01010011_10000000_00000000 = [Z(WORD), PMerge, Z(WORD), Z(WORD)] => [R(0), R3(10), RTied, R(5)], SVE2;
+    0b01000100_10010011_10000000_00000000 = [Z(DWORD), PMerge, Z(DWORD), Z(DWORD)] => [R(0), R3(10), RTied, R(5)], SVE2;
+    0b01000100_11010011_10000000_00000000 = [Z(QWORD), PMerge, Z(QWORD), Z(QWORD)] => [R(0), R3(10), RTied, R(5)], SVE2;
+]
+"uhsubr" = [
+    0b01000100_00010111_10000000_00000000 = [Z(BYTE), PMerge, Z(BYTE), Z(BYTE)] => [R(0), R3(10), RTied, R(5)], SVE2;
+    0b01000100_01010111_10000000_00000000 = [Z(WORD), PMerge, Z(WORD), Z(WORD)] => [R(0), R3(10), RTied, R(5)], SVE2;
+    0b01000100_10010111_10000000_00000000 = [Z(DWORD), PMerge, Z(DWORD), Z(DWORD)] => [R(0), R3(10), RTied, R(5)], SVE2;
+    0b01000100_11010111_10000000_00000000 = [Z(QWORD), PMerge, Z(QWORD), Z(QWORD)] => [R(0), R3(10), RTied, R(5)], SVE2;
+]
+"umaddl" = [
+    0b10011011_10100000_00000000_00000000 = [X, W, W, X] => [R(0), R(5), R(16), R(10)];
+]
+"umax" = [
+    // UMAX
+    0b00101110_00100000_01100100_00000000 = [V(BYTE), V(BYTE), V(BYTE)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b00101110_01100000_01100100_00000000 = [V(WORD), V(WORD), V(WORD)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b00101110_10100000_01100100_00000000 = [V(DWORD), V(DWORD), V(DWORD)] => [R(0), R(5), R(16), Rwidth(30)];
+    // UMAX (vectors, predicated)
+    0b00000100_00001001_00000000_00000000 = [Z(BYTE), PMerge, Z(BYTE), Z(BYTE)] => [R(0), R3(10), RTied, R(5)], SVE;
+    0b00000100_01001001_00000000_00000000 = [Z(WORD), PMerge, Z(WORD), Z(WORD)] => [R(0), R3(10), RTied, R(5)], SVE;
+    0b00000100_10001001_00000000_00000000 = [Z(DWORD), PMerge, Z(DWORD), Z(DWORD)] => [R(0), R3(10), RTied, R(5)], SVE;
+    0b00000100_11001001_00000000_00000000 = [Z(QWORD), PMerge, Z(QWORD), Z(QWORD)] => [R(0), R3(10), RTied, R(5)], SVE;
+    // UMAX (immediate)
+    0b00100101_00101001_11000000_00000000 = [Z(BYTE), Z(BYTE), Imm] => [R(0), RTied, Ubits(5, 8)], SVE;
+    0b00100101_01101001_11000000_00000000 = [Z(WORD), Z(WORD), Imm] => [R(0), RTied, Ubits(5, 8)], SVE;
+    0b00100101_10101001_11000000_00000000 = [Z(DWORD), Z(DWORD), Imm] => [R(0), RTied, Ubits(5, 8)], SVE;
+    0b00100101_11101001_11000000_00000000 = [Z(QWORD), Z(QWORD), Imm] => [R(0), RTied, Ubits(5, 8)], SVE;
+]
+"umaxp" = [
+    // UMAXP
+    0b00101110_00100000_10100100_00000000 = [V(BYTE), V(BYTE), V(BYTE)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b00101110_01100000_10100100_00000000 = [V(WORD), V(WORD), V(WORD)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b00101110_10100000_10100100_00000000 = [V(DWORD), V(DWORD), V(DWORD)] => [R(0), R(5), R(16), Rwidth(30)];
+    // UMAXP (vectors, predicated)
+    0b01000100_00010101_10100000_00000000 = [Z(BYTE), PMerge, Z(BYTE), Z(BYTE)] => [R(0), R3(10), RTied, R(5)], SVE2;
+    0b01000100_01010101_10100000_00000000 = [Z(WORD), PMerge, Z(WORD), Z(WORD)] => [R(0), R3(10), RTied, R(5)], SVE2;
+    0b01000100_10010101_10100000_00000000 = [Z(DWORD), PMerge, Z(DWORD), Z(DWORD)] => [R(0), R3(10), RTied, R(5)], SVE2;
+    0b01000100_11010101_10100000_00000000 = [Z(QWORD), PMerge, Z(QWORD), Z(QWORD)] => [R(0), R3(10), RTied, R(5)], SVE2;
+]
+"umaxv" = [
+    // UMAXV
+    0b00101110_00110000_10101000_00000000 = [B, V(BYTE)] => [R(0), R(5), Rwidth(30)];
+    0b00101110_01110000_10101000_00000000 = [H, V(WORD)] => [R(0), R(5), Rwidth(30)];
+    0b00101110_10110000_10101000_00000000 = [S, VStatic(DWORD, 4)] => [R(0), R(5), Rwidth(30)];
+    // UMAXV (reduction)
+    0b00000100_00001001_00100000_00000000 = [B, PBare, Z(BYTE)] => [R(0), R3(10), R(5)], SVE;
+    0b00000100_01001001_00100000_00000000 = [H, PBare, Z(WORD)] => [R(0), R3(10), R(5)], SVE;
+    0b00000100_10001001_00100000_00000000 = [S, PBare, Z(DWORD)] => [R(0), R3(10), R(5)], SVE;
+    0b00000100_11001001_00100000_00000000 = [D, PBare, Z(QWORD)] => [R(0), R3(10), R(5)], SVE;
+]
+"umin" = [
+    // UMIN
+    0b00101110_00100000_01101100_00000000 = [V(BYTE), V(BYTE), V(BYTE)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b00101110_01100000_01101100_00000000 = [V(WORD), V(WORD), V(WORD)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b00101110_10100000_01101100_00000000 = [V(DWORD), V(DWORD), V(DWORD)] => [R(0), R(5), R(16), Rwidth(30)];
+    // UMIN (vectors, predicated)
+    0b00000100_00001011_00000000_00000000 = [Z(BYTE), PMerge, Z(BYTE), Z(BYTE)] => [R(0), R3(10), RTied, R(5)], SVE;
+    0b00000100_01001011_00000000_00000000 = [Z(WORD), PMerge, Z(WORD), Z(WORD)] => [R(0), R3(10), RTied, R(5)], SVE;
+    0b00000100_10001011_00000000_00000000 = [Z(DWORD), PMerge, Z(DWORD), Z(DWORD)] => [R(0), R3(10), RTied, R(5)], SVE;
+    0b00000100_11001011_00000000_00000000 = [Z(QWORD), PMerge, Z(QWORD), Z(QWORD)] => [R(0), R3(10), RTied, R(5)], SVE;
+    // UMIN (immediate)
+    0b00100101_00101011_11000000_00000000 = [Z(BYTE), Z(BYTE), Imm] => [R(0), RTied, Ubits(5, 8)], SVE;
+    0b00100101_01101011_11000000_00000000 = [Z(WORD), Z(WORD), Imm] => [R(0), RTied, Ubits(5, 8)], SVE;
+    0b00100101_10101011_11000000_00000000 = [Z(DWORD), Z(DWORD), Imm] => [R(0), RTied, Ubits(5, 8)], SVE;
+    0b00100101_11101011_11000000_00000000 = [Z(QWORD), Z(QWORD), Imm] => [R(0), RTied, Ubits(5, 8)], SVE;
+]
+"uminp" = [
+    // UMINP
+    0b00101110_00100000_10101100_00000000 = [V(BYTE), V(BYTE), V(BYTE)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b00101110_01100000_10101100_00000000 = [V(WORD), V(WORD), V(WORD)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b00101110_10100000_10101100_00000000 = [V(DWORD), V(DWORD), V(DWORD)] => [R(0), R(5), R(16), Rwidth(30)];
+    // UMINP (vectors, predicated)
+    0b01000100_00010111_10100000_00000000 = [Z(BYTE), PMerge, Z(BYTE), Z(BYTE)] => [R(0), R3(10), RTied, R(5)], SVE2;
+    0b01000100_01010111_10100000_00000000 = [Z(WORD), PMerge, Z(WORD), Z(WORD)] => [R(0), R3(10), RTied, R(5)], SVE2;
+    0b01000100_10010111_10100000_00000000 = [Z(DWORD), PMerge, Z(DWORD), Z(DWORD)] => [R(0), R3(10), RTied, R(5)], SVE2;
+    0b01000100_11010111_10100000_00000000 = [Z(QWORD), PMerge, Z(QWORD), Z(QWORD)] => [R(0), R3(10), RTied, R(5)], SVE2;
+]
+"uminv" = [
+    // UMINV
+    0b00101110_00110001_10101000_00000000 = [B, V(BYTE)] => [R(0), R(5), Rwidth(30)];
+    0b00101110_01110001_10101000_00000000 = [H, V(WORD)] => [R(0), R(5), Rwidth(30)];
+    0b00101110_10110001_10101000_00000000 = [S, VStatic(DWORD, 4)] => [R(0), R(5), Rwidth(30)];
+    // UMINV (reduction)
+    0b00000100_00001011_00100000_00000000 = [B, PBare, Z(BYTE)] => [R(0), R3(10), R(5)], SVE;
+    0b00000100_01001011_00100000_00000000 = [H, PBare, Z(WORD)] => [R(0), R3(10), R(5)], SVE;
+    0b00000100_10001011_00100000_00000000 = [S, PBare, Z(DWORD)] => [R(0), R3(10), R(5)], SVE;
+    0b00000100_11001011_00100000_00000000 = [D, PBare, Z(QWORD)] => [R(0), R3(10), R(5)], SVE;
+]
+"umlal" = [
+    // UMLAL, UMLAL2 (by element)
+    0b00101111_01000000_00100000_00000000 = [VStatic(DWORD, 4), VStatic(WORD, 4), VElement(WORD)] => [R(0), R(5), R4(16), Ufields(&[11, 21, 20])];
+    0b00101111_10000000_00100000_00000000 = [VStatic(QWORD, 2), VStatic(DWORD, 2), VElement(DWORD)] => [R(0), R(5), R(16), Ufields(&[11, 21])];
+    // UMLAL, UMLAL2 (vector)
+    0b00101110_00100000_10000000_00000000 = [VStatic(WORD, 8), VStatic(BYTE, 8), VStatic(BYTE, 8)] => [R(0), R(5), R(16)];
+    0b00101110_01100000_10000000_00000000 = [VStatic(DWORD, 4), VStatic(WORD, 4), VStatic(WORD, 4)] => [R(0), R(5), R(16)];
+    0b00101110_10100000_10000000_00000000 = [VStatic(QWORD, 2), VStatic(DWORD, 2), VStatic(DWORD, 2)] => [R(0), R(5), R(16)];
+]
+"umlal2" = [
+    // UMLAL, UMLAL2 (by element)
+    0b01101111_01000000_00100000_00000000 = [VStatic(DWORD, 4), VStatic(WORD, 8), VElement(WORD)] => [R(0), R(5), R4(16), Ufields(&[11, 21, 20])];
+    0b01101111_10000000_00100000_00000000 = [VStatic(QWORD, 2), VStatic(DWORD, 4), VElement(DWORD)] => [R(0), R(5), R(16), Ufields(&[11, 21])];
+    // UMLAL, UMLAL2 (vector)
+    0b01101110_00100000_10000000_00000000 = [VStatic(WORD, 8), VStatic(BYTE, 16), VStatic(BYTE, 16)] => [R(0), R(5), R(16)];
+    0b01101110_01100000_10000000_00000000 = [VStatic(DWORD, 4), VStatic(WORD, 8), VStatic(WORD, 8)] => [R(0), R(5), R(16)];
+    0b01101110_10100000_10000000_00000000 = [VStatic(QWORD, 2), VStatic(DWORD, 4), VStatic(DWORD, 4)] => [R(0), R(5), R(16)];
+]
+"umlalb" = [
+    0b01000100_01000000_01001000_00000000 = [Z(WORD), Z(BYTE), Z(BYTE)] => [R(0), R(5), R(16)], SVE2;
+    0b01000100_10000000_01001000_00000000 = [Z(DWORD), Z(WORD), Z(WORD)] => [R(0), R(5), R(16)], SVE2;
+    0b01000100_11000000_01001000_00000000 = [Z(QWORD), Z(DWORD), Z(DWORD)] => [R(0), R(5), R(16)], SVE2;
+]
+"umlalt" = [
+    0b01000100_01000000_01001100_00000000 = [Z(WORD), Z(BYTE), Z(BYTE)] => [R(0), R(5), R(16)], SVE2;
+    0b01000100_10000000_01001100_00000000 = [Z(DWORD), Z(WORD), Z(WORD)] => [R(0), R(5), R(16)], SVE2;
+    0b01000100_11000000_01001100_00000000 = [Z(QWORD), Z(DWORD), Z(DWORD)] => [R(0), R(5), R(16)], SVE2;
+]
+"umlsl" = [
+    // UMLSL, UMLSL2 (by element)
+    0b00101111_01000000_01100000_00000000 = [VStatic(DWORD, 4), VStatic(WORD, 4), VElement(WORD)] => [R(0), R(5), R4(16), Ufields(&[11, 21, 20])];
+    0b00101111_10000000_01100000_00000000 = [VStatic(QWORD, 2), VStatic(DWORD, 2), VElement(DWORD)] => [R(0), R(5), R(16), Ufields(&[11, 21])];
+    // UMLSL, UMLSL2 (vector)
+    0b00101110_00100000_10100000_00000000 = [VStatic(WORD, 8), VStatic(BYTE, 8), VStatic(BYTE, 8)] => [R(0), R(5), R(16)];
+    0b00101110_01100000_10100000_00000000 = [VStatic(DWORD, 4), VStatic(WORD, 4), VStatic(WORD, 4)] => [R(0), R(5), R(16)];
+    0b00101110_10100000_10100000_00000000 = [VStatic(QWORD, 2), VStatic(DWORD, 2), VStatic(DWORD, 2)] => [R(0), R(5), R(16)];
+]
+"umlsl2" = [
+    // UMLSL, UMLSL2 (by element)
+    0b01101111_01000000_01100000_00000000 = [VStatic(DWORD, 4), VStatic(WORD, 8), VElement(WORD)] => [R(0), R(5), R4(16), Ufields(&[11, 21, 20])];
+    0b01101111_10000000_01100000_00000000 = [VStatic(QWORD, 2), VStatic(DWORD, 4), VElement(DWORD)] => [R(0), R(5), R(16), Ufields(&[11, 21])];
+    // UMLSL, UMLSL2 (vector)
+    0b01101110_00100000_10100000_00000000 = [VStatic(WORD, 8), VStatic(BYTE, 16), VStatic(BYTE, 16)] => [R(0), R(5), R(16)];
+    0b01101110_01100000_10100000_00000000 = [VStatic(DWORD, 4), VStatic(WORD, 8), VStatic(WORD, 8)] => [R(0), R(5), R(16)];
+    0b01101110_10100000_10100000_00000000 = [VStatic(QWORD, 2), VStatic(DWORD, 4), VStatic(DWORD, 4)] => [R(0), R(5), R(16)];
+]
+"umlslb" = [
+    0b01000100_01000000_01011000_00000000 = [Z(WORD), Z(BYTE), Z(BYTE)] => [R(0), R(5), R(16)], SVE2;
+    0b01000100_10000000_01011000_00000000 = [Z(DWORD), Z(WORD), Z(WORD)] => [R(0), R(5), R(16)], SVE2;
+    0b01000100_11000000_01011000_00000000 = [Z(QWORD), Z(DWORD), Z(DWORD)] => [R(0), R(5), R(16)], SVE2;
+]
+"umlslt" = [
+    0b01000100_01000000_01011100_00000000 = [Z(WORD), Z(BYTE), Z(BYTE)] => [R(0), R(5), R(16)], SVE2;
+    0b01000100_10000000_01011100_00000000 = [Z(DWORD), Z(WORD), Z(WORD)] => [R(0), R(5), R(16)], SVE2;
+    0b01000100_11000000_01011100_00000000 = [Z(QWORD), Z(DWORD), Z(DWORD)] => [R(0), R(5), R(16)], SVE2;
+]
+"ummla" = [
+    0b01101110_10000000_10100100_00000000 = [VStatic(DWORD, 4), VStatic(BYTE, 16), VStatic(BYTE, 16)] => [R(0), R(5), R(16)], I8MM;
+]
+"umnegl" = [
+    0b10011011_10100000_11111100_00000000 = [X, W, W] => [R(0), R(5), R(16)];
+]
+"umopa" = [
+    0b10100001_10100000_00000000_00000000 = [ZaTile(DWORD), PMerge, PMerge, Z(BYTE), Z(BYTE)] => [RTile(0, 2), R3(10), R3(13), R(5), R(16)], SME;
+    0b10100001_11100000_00000000_00000000 = [ZaTile(QWORD), PMerge, PMerge, Z(WORD), Z(WORD)] => [RTile(0, 3), R3(10), R3(13), R(5), R(16)], SME | SME_I16I64;
+]
+"umops" = [
+    0b10100001_10100000_00000000_00010000 = [ZaTile(DWORD), PMerge, PMerge, Z(BYTE), Z(BYTE)] => [RTile(0, 2), R3(10), R3(13), R(5), R(16)], SME;
+    0b10100001_11100000_00000000_00010000 = [ZaTile(QWORD), PMerge, PMerge, Z(WORD), Z(WORD)] => [RTile(0, 3), R3(10), R3(13), R(5), R(16)], SME | SME_I16I64;
+]
+"umov" = [
+    0b00001110_00000001_00111100_00000000 = [W, VElement(BYTE)] => [R(0), R(5), Ubits(17, 4)];
+    0b00001110_00000010_00111100_00000000 = [W, VElement(WORD)] => [R(0), R(5), Ubits(18, 3)];
+    0b00001110_00000100_00111100_00000000 = [W, VElement(DWORD)] => [R(0), R(5), Ubits(19, 2)];
+    0b01001110_00001000_00111100_00000000 = [X, VElement(QWORD)] => [R(0), R(5), Ubits(20, 1)];
+]
+"umsubl" = [
+    0b10011011_10100000_10000000_00000000 = [X, W, W, X] => [R(0), R(5), R(16), R(10)];
+]
+"umulh" = [
+    // UMULH
+    0b10011011_11000000_01111100_00000000 = [X, X, X] => [R(0), R(5), R(16)];
+    // UMULH (vectors, predicated)
+    0b00000100_00010011_00000000_00000000 = [Z(BYTE), PMerge, Z(BYTE), Z(BYTE)] => [R(0), R3(10), RTied, R(5)], SVE;
+    0b00000100_01010011_00000000_00000000 = [Z(WORD), PMerge, Z(WORD), Z(WORD)] => [R(0), R3(10), RTied, R(5)], SVE;
+    0b00000100_10010011_00000000_00000000 = [Z(DWORD), PMerge, Z(DWORD), Z(DWORD)] => [R(0), R3(10), RTied, R(5)], SVE;
+    0b00000100_11010011_00000000_00000000 = [Z(QWORD), PMerge, Z(QWORD), Z(QWORD)] => [R(0), R3(10), RTied, R(5)], SVE;
+    // UMULH (vectors, unpredicated)
+    0b00000100_00100000_01101100_00000000 = [Z(BYTE), Z(BYTE), Z(BYTE)] => [R(0), R(5), R(16)], SVE2;
+    0b00000100_01100000_01101100_00000000 = [Z(WORD), Z(WORD), Z(WORD)] => [R(0), R(5), R(16)], SVE2;
+    0b00000100_10100000_01101100_00000000 = [Z(DWORD), Z(DWORD), Z(DWORD)] => [R(0), R(5), R(16)], SVE2;
+    0b00000100_11100000_01101100_00000000 = [Z(QWORD), Z(QWORD), Z(QWORD)] => [R(0), R(5), R(16)], SVE2;
+]
+"umull" = [
+    // UMULL, UMULL2 (by element)
+    0b00101111_01000000_10100000_00000000 = [VStatic(DWORD, 4), VStatic(WORD, 4), VElement(WORD)] => [R(0), R(5), R4(16), Ufields(&[11, 21, 20])];
+    0b00101111_10000000_10100000_00000000 = [VStatic(QWORD, 2), VStatic(DWORD, 2), VElement(DWORD)] => [R(0), R(5), R(16), Ufields(&[11, 21])];
+    // UMULL, UMULL2 (vector)
+    0b00101110_00100000_11000000_00000000 = [VStatic(WORD, 8), VStatic(BYTE, 8), VStatic(BYTE, 8)] => [R(0), R(5), R(16)];
+    0b00101110_01100000_11000000_00000000 = [VStatic(DWORD, 4), VStatic(WORD, 4), VStatic(WORD, 4)] => [R(0), R(5), R(16)];
+    0b00101110_10100000_11000000_00000000 = [VStatic(QWORD, 2), VStatic(DWORD, 2), VStatic(DWORD, 2)] => [R(0), R(5), R(16)];
+    // UMULL
+    0b10011011_10100000_01111100_00000000 = [X, W, W] => [R(0), R(5), R(16)];
+]
+"umull2" = [
+    // UMULL, UMULL2 (by element)
+    0b01101111_01000000_10100000_00000000 = [VStatic(DWORD, 4), VStatic(WORD, 8), VElement(WORD)] => [R(0), R(5), R4(16), Ufields(&[11, 21, 20])];
+    0b01101111_10000000_10100000_00000000 = [VStatic(QWORD, 2), VStatic(DWORD, 4), VElement(DWORD)] => [R(0), R(5), R(16), Ufields(&[11, 21])];
+    // UMULL, UMULL2 (vector)
+    0b01101110_00100000_11000000_00000000 = [VStatic(WORD, 8), VStatic(BYTE, 16), VStatic(BYTE, 16)] => [R(0), R(5), R(16)];
+    0b01101110_01100000_11000000_00000000 = [VStatic(DWORD, 4), VStatic(WORD, 8), VStatic(WORD, 8)] => [R(0), R(5), R(16)];
+    0b01101110_10100000_11000000_00000000 = [VStatic(QWORD, 2), VStatic(DWORD, 4), VStatic(DWORD, 4)] => [R(0), R(5), R(16)];
+]
+"umullb" = [
+    0b01000101_01000000_01111000_00000000 = [Z(WORD), Z(BYTE), Z(BYTE)] => [R(0), R(5), R(16)], SVE2;
+    0b01000101_10000000_01111000_00000000 = [Z(DWORD), Z(WORD), Z(WORD)] => [R(0), R(5), R(16)], SVE2;
+    0b01000101_11000000_01111000_00000000 = [Z(QWORD), Z(DWORD), Z(DWORD)] => [R(0), R(5), R(16)], SVE2;
+]
+"umullt" = [
+    0b01000101_01000000_01111100_00000000 = [Z(WORD), Z(BYTE), Z(BYTE)] => [R(0), R(5), R(16)], SVE2;
+    0b01000101_10000000_01111100_00000000 = [Z(DWORD), Z(WORD), Z(WORD)] => [R(0), R(5), R(16)], SVE2;
+    0b01000101_11000000_01111100_00000000 = [Z(QWORD), Z(DWORD), Z(DWORD)] => [R(0), R(5), R(16)], SVE2;
+]
+"uqadd" = [
+    // UQADD
+    0b01111110_00100000_00001100_00000000 = [B, B, B] => [R(0), R(5), R(16)];
+    0b01111110_01100000_00001100_00000000 = [H, H, H] => [R(0), R(5), R(16)];
+    0b01111110_10100000_00001100_00000000 = [S, S, S] => [R(0), R(5), R(16)];
+    0b01111110_11100000_00001100_00000000 = [D, D, D] => [R(0), R(5), R(16)];
+    0b00101110_00100000_00001100_00000000 = [V(BYTE), V(BYTE), V(BYTE)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b00101110_01100000_00001100_00000000 = [V(WORD), V(WORD), V(WORD)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b00101110_10100000_00001100_00000000 = [V(DWORD), V(DWORD), V(DWORD)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b00101110_11100000_00001100_00000000 = [VStatic(QWORD, 2), VStatic(QWORD, 2), VStatic(QWORD, 2)] => [R(0), R(5), R(16), Rwidth(30)];
+    // UQADD (vectors, unpredicated)
+    0b00000100_00100000_00010100_00000000 = [Z(BYTE), Z(BYTE), Z(BYTE)] => [R(0), R(5), R(16)], SVE;
+    0b00000100_01100000_00010100_00000000 = [Z(WORD), Z(WORD), Z(WORD)] => [R(0), R(5), R(16)], SVE;
+    0b00000100_10100000_00010100_00000000 = [Z(DWORD), Z(DWORD), Z(DWORD)] => [R(0), R(5), R(16)], SVE;
+    0b00000100_11100000_00010100_00000000 = [Z(QWORD), Z(QWORD), Z(QWORD)] => [R(0), R(5), R(16)], SVE;
+    // UQADD (immediate)
+    0b00100101_00100101_11000000_00000000 = [Z(BYTE), Z(BYTE), Imm] => [R(0), RTied, Ubits(5, 8)], SVE;
+    0b00100101_01100101_11000000_00000000 = [Z(WORD), Z(WORD), Imm, End, LitMod(LSL)] => [R(0), RTied, Ubits(5, 8), Ulist(13, &[0, 8])], SVE;
+    0b00100101_10100101_11000000_00000000 = [Z(DWORD), Z(DWORD), Imm, End, LitMod(LSL)] => [R(0), RTied, Ubits(5, 8), Ulist(13, &[0, 8])], SVE;
+    0b00100101_11100101_11000000_00000000 = [Z(QWORD), Z(QWORD), Imm, End, LitMod(LSL)] => [R(0), RTied, Ubits(5, 8), Ulist(13, &[0, 8])], SVE;
+    // UQADD (vectors, predicated)
+    0b01000100_00011001_10000000_00000000 = [Z(BYTE), PMerge, Z(BYTE), Z(BYTE)] => [R(0), R3(10), RTied, R(5)], SVE2;
+    0b01000100_01011001_10000000_00000000 = [Z(WORD), PMerge, Z(WORD), Z(WORD)] => [R(0), R3(10), RTied, R(5)], SVE2;
+    0b01000100_10011001_10000000_00000000 = [Z(DWORD), PMerge, Z(DWORD), Z(DWORD)] => [R(0), R3(10), RTied, R(5)], SVE2;
+    0b01000100_11011001_10000000_00000000 = [Z(QWORD), PMerge, Z(QWORD), Z(QWORD)] => [R(0), R3(10), RTied, R(5)], SVE2;
+]
+"uqdecb" = [
+    // UQDECB (scalar, 32-bit)
+    0b00000100_00100000_11111111_11100000 = [W] => [R(0)], SVE;
+    0b00000100_00100000_11111100_00000000 = [W, Ident, End, LitMod(MUL)] => [R(0), LitList(5, "SVE_PATTERNS"), Urange(16, 1, 16)], SVE;
+    // UQDECB (scalar)
+    0b00000100_00110000_11111111_11100000 = [X] => [R(0)], SVE;
+    0b00000100_00110000_11111100_00000000 = [X, Ident, End, LitMod(MUL)] => [R(0), LitList(5, "SVE_PATTERNS"), Urange(16, 1, 16)], SVE;
+]
+"uqdecd" = [
+    // UQDECD (scalar, 32-bit)
+    0b00000100_11100000_11111111_11100000 = [W] => [R(0)], SVE;
+    0b00000100_11100000_11111100_00000000 = [W, Ident, End, LitMod(MUL)] => [R(0), LitList(5, "SVE_PATTERNS"), Urange(16, 1, 16)], SVE;
+    // UQDECD (scalar)
+    0b00000100_11110000_11111111_11100000 = [X] => [R(0)], SVE;
+    0b00000100_11110000_11111100_00000000 = [X, Ident, End, LitMod(MUL)] => [R(0), LitList(5, "SVE_PATTERNS"), Urange(16, 1, 16)], SVE;
+    // UQDECD (vector)
+    0b00000100_11100000_11001111_11100000 = [Z(QWORD)] => [R(0)], SVE;
+    0b00000100_11100000_11001100_00000000 = [Z(QWORD), Ident, End, LitMod(MUL)] => [R(0), LitList(5, "SVE_PATTERNS"), Urange(16, 1, 16)], SVE;
+]
+"uqdech" = [
+    // UQDECH (scalar, 32-bit)
+    0b00000100_01100000_11111111_11100000 = [W] => [R(0)], SVE;
+    0b00000100_01100000_11111100_00000000 = [W, Ident, End, LitMod(MUL)] => [R(0), LitList(5, "SVE_PATTERNS"), Urange(16, 1, 16)], SVE;
+    // UQDECH (scalar)
+    0b00000100_01110000_11111111_11100000 = [X] => [R(0)], SVE;
+    0b00000100_01110000_11111100_00000000 = [X, Ident, End, LitMod(MUL)] => [R(0), LitList(5, "SVE_PATTERNS"), Urange(16, 1, 16)], SVE;
+    // UQDECH (vector)
+    0b00000100_01100000_11001111_11100000 = [Z(WORD)] => [R(0)], SVE;
+    0b00000100_01100000_11001100_00000000 = [Z(WORD), Ident, End, LitMod(MUL)] => [R(0), LitList(5, "SVE_PATTERNS"), Urange(16, 1, 16)], SVE;
+]
+"uqdecp" = [
+    // UQDECP (scalar, 32-bit)
+    0b00100101_00101011_10001000_00000000 = [W, P(BYTE)] => [R(0), R4(5)], SVE;
+    0b00100101_01101011_10001000_00000000 = [W, P(WORD)] => [R(0), R4(5)], SVE;
+    0b00100101_10101011_10001000_00000000 = [W, P(DWORD)] => [R(0), R4(5)], SVE;
+    0b00100101_11101011_10001000_00000000 = [W, P(QWORD)] => [R(0), R4(5)], SVE;
+    // UQDECP (scalar)
+    0b00100101_00101011_10001100_00000000 = [X, P(BYTE)] => [R(0), R4(5)], SVE;
+    0b00100101_01101011_10001100_00000000 = [X, P(WORD)] => [R(0), R4(5)], SVE;
+    0b00100101_10101011_10001100_00000000 = [X, P(DWORD)] => [R(0), R4(5)], SVE;
+    0b00100101_11101011_10001100_00000000 = [X, P(QWORD)] => [R(0), R4(5)], SVE;
+    // UQDECP (vector)
+    0b00100101_01101011_10000000_00000000 = [Z(WORD), PBare] => [R(0), R4(5)], SVE;
+    0b00100101_10101011_10000000_00000000 = [Z(DWORD), PBare] => [R(0), R4(5)], SVE;
+    0b00100101_11101011_10000000_00000000 = [Z(QWORD), PBare] => [R(0), R4(5)], SVE;
+]
+"uqdecw" = [
+    // UQDECW (scalar, 32-bit)
+    0b00000100_10100000_11111111_11100000 = [W] => [R(0)], SVE;
+    0b00000100_10100000_11111100_00000000 = [W, Ident, End, LitMod(MUL)] => [R(0), LitList(5, "SVE_PATTERNS"), Urange(16, 1, 16)], SVE;
+    // UQDECW (scalar)
+    0b00000100_10110000_11111111_11100000 = [X] => [R(0)], SVE;
+    0b00000100_10110000_11111100_00000000 = [X, Ident, End, LitMod(MUL)] => [R(0), LitList(5, "SVE_PATTERNS"), Urange(16, 1, 16)], SVE;
+    // UQDECW (vector)
+    0b00000100_10100000_11001111_11100000 = [Z(DWORD)] => [R(0)], SVE;
+    0b00000100_10100000_11001100_00000000 = [Z(DWORD), Ident, End, LitMod(MUL)] => [R(0), LitList(5, "SVE_PATTERNS"), Urange(16, 1, 16)], SVE;
+]
+"uqincb" = [
+    // UQINCB (scalar, 32-bit)
+    0b00000100_00100000_11110111_11100000 = [W] => [R(0)], SVE;
+    0b00000100_00100000_11110100_00000000 = [W, Ident, End, LitMod(MUL)] => [R(0), LitList(5, "SVE_PATTERNS"), Urange(16, 1, 16)], SVE;
+    // UQINCB (scalar)
+    0b00000100_00110000_11110111_11100000 = [X] => [R(0)], SVE;
+    0b00000100_00110000_11110100_00000000 = [X, Ident, End, LitMod(MUL)] => [R(0), LitList(5, "SVE_PATTERNS"), Urange(16, 1, 16)], SVE;
+]
+"uqincd" = [
+    // UQINCD (scalar, 32-bit)
+    0b00000100_11100000_11110111_11100000 = [W] => [R(0)], SVE;
+    0b00000100_11100000_11110100_00000000 = [W, Ident, End, LitMod(MUL)] => [R(0), LitList(5, "SVE_PATTERNS"), Urange(16, 1, 16)], SVE;
+    // UQINCD (scalar)
+    0b00000100_11110000_11110111_11100000 = [X] => [R(0)], SVE;
+    0b00000100_11110000_11110100_00000000 = [X, Ident, End, LitMod(MUL)] => [R(0), LitList(5, "SVE_PATTERNS"), Urange(16, 1, 16)], SVE;
+    // UQINCD (vector)
+    0b00000100_11100000_11000111_11100000 = [Z(QWORD)] => [R(0)], SVE;
+    0b00000100_11100000_11000100_00000000 = [Z(QWORD), Ident, End, LitMod(MUL)] => [R(0), LitList(5, "SVE_PATTERNS"), Urange(16, 1, 16)], SVE;
+]
+"uqinch" = [
+    // UQINCH (scalar, 32-bit)
+    0b00000100_01100000_11110111_11100000 = [W] => [R(0)], SVE;
+    0b00000100_01100000_11110100_00000000 = [W, Ident, End, LitMod(MUL)] => [R(0), LitList(5, "SVE_PATTERNS"), Urange(16, 1, 16)], SVE;
+    // UQINCH (scalar)
+    0b00000100_01110000_11110111_11100000 = [X] => [R(0)], SVE;
+    0b00000100_01110000_11110100_00000000 = [X, Ident, End, LitMod(MUL)] => [R(0), LitList(5, "SVE_PATTERNS"), Urange(16, 1, 16)], SVE;
+    // UQINCH (vector)
+    0b00000100_01100000_11000111_11100000 = [Z(WORD)] => [R(0)], SVE;
+    0b00000100_01100000_11000100_00000000 = [Z(WORD), Ident, End, LitMod(MUL)] => [R(0), LitList(5, "SVE_PATTERNS"), Urange(16, 1, 16)], SVE;
+]
+"uqincp" = [
+    // UQINCP (scalar, 32-bit)
+    0b00100101_00101001_10001000_00000000 = [W, P(BYTE)] => [R(0), R4(5)], SVE;
+    0b00100101_01101001_10001000_00000000 = [W, P(WORD)] => [R(0), R4(5)], SVE;
+    0b00100101_10101001_10001000_00000000 = [W, P(DWORD)] => [R(0), R4(5)], SVE;
+    0b00100101_11101001_10001000_00000000 = [W, P(QWORD)] => [R(0), R4(5)], SVE;
+    // UQINCP (scalar)
+    0b00100101_00101001_10001100_00000000 = [X, P(BYTE)] => [R(0), R4(5)], SVE;
+    0b00100101_01101001_10001100_00000000 = [X, P(WORD)] => [R(0), R4(5)], SVE;
+    0b00100101_10101001_10001100_00000000 = [X, P(DWORD)] => [R(0), R4(5)], SVE;
+    0b00100101_11101001_10001100_00000000 = [X, P(QWORD)] => [R(0), R4(5)], SVE;
+    // UQINCP (vector)
+    0b00100101_01101001_10000000_00000000 = [Z(WORD), PBare] => [R(0), R4(5)], SVE;
+    0b00100101_10101001_10000000_00000000 = [Z(DWORD), PBare] => [R(0), R4(5)], SVE;
+    0b00100101_11101001_10000000_00000000 = [Z(QWORD), PBare] => [R(0), R4(5)], SVE;
+]
+"uqincw" = [
+    // UQINCW (scalar, 32-bit)
+    0b00000100_10100000_11110111_11100000 = [W] => [R(0)], SVE;
+    0b00000100_10100000_11110100_00000000 = [W, Ident, End, LitMod(MUL)] => [R(0), LitList(5, "SVE_PATTERNS"), Urange(16, 1, 16)], SVE;
+    // UQINCW (scalar)
+    0b00000100_10110000_11110111_11100000 = [X] => [R(0)], SVE;
+    0b00000100_10110000_11110100_00000000 = [X, Ident, End, LitMod(MUL)] => [R(0), LitList(5, "SVE_PATTERNS"), Urange(16, 1, 16)], SVE;
+    // UQINCW (vector)
+    0b00000100_10100000_11000111_11100000 = [Z(DWORD)] => [R(0)], SVE;
+    0b00000100_10100000_11000100_00000000 = [Z(DWORD), Ident, End, LitMod(MUL)] => [R(0), LitList(5, "SVE_PATTERNS"), Urange(16, 1, 16)], SVE;
+]
+"uqrshl" = [
+    // UQRSHL
+    0b01111110_00100000_01011100_00000000 = [B, B, B] => [R(0), R(5), R(16)];
+    0b01111110_01100000_01011100_00000000 = [H, H, H] => [R(0), R(5), R(16)];
+    0b01111110_10100000_01011100_00000000 = [S, S, S] => [R(0), R(5), R(16)];
+    0b01111110_11100000_01011100_00000000 = [D, D, D] => [R(0), R(5), R(16)];
+    0b00101110_00100000_01011100_00000000 = [V(BYTE), V(BYTE), V(BYTE)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b00101110_01100000_01011100_00000000 = [V(WORD), V(WORD), V(WORD)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b00101110_10100000_01011100_00000000 = [V(DWORD), V(DWORD), V(DWORD)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b00101110_11100000_01011100_00000000 = [VStatic(QWORD, 2), VStatic(QWORD, 2), VStatic(QWORD, 2)] => [R(0), R(5), R(16), Rwidth(30)];
+    // UQRSHL (vectors, predicated)
+    0b01000100_00001011_10000000_00000000 = [Z(BYTE), PMerge, Z(BYTE), Z(BYTE)] => [R(0), R3(10), RTied, R(5)], SVE2;
+    0b01000100_01001011_10000000_00000000 = [Z(WORD), PMerge, Z(WORD), Z(WORD)] => [R(0), R3(10), RTied, R(5)], SVE2;
+    0b01000100_10001011_10000000_00000000 = [Z(DWORD), PMerge, Z(DWORD), Z(DWORD)] => [R(0), R3(10), RTied, R(5)], SVE2;
+    0b01000100_11001011_10000000_00000000 = [Z(QWORD), PMerge, Z(QWORD), Z(QWORD)] => [R(0), R3(10), RTied, R(5)], SVE2;
+]
+"uqrshlr" = [
+    0b01000100_00001111_10000000_00000000 = [Z(BYTE), PMerge, Z(BYTE), Z(BYTE)] => [R(0), R3(10), RTied, R(5)], SVE2;
+    0b01000100_01001111_10000000_00000000 = [Z(WORD), PMerge, Z(WORD), Z(WORD)] => [R(0), R3(10), RTied, R(5)], SVE2;
+    0b01000100_10001111_10000000_00000000 = [Z(DWORD), PMerge, Z(DWORD), Z(DWORD)] => [R(0), R3(10), RTied, R(5)], SVE2;
+    0b01000100_11001111_10000000_00000000 = [Z(QWORD), PMerge, Z(QWORD), Z(QWORD)] => [R(0), R3(10), RTied, R(5)], SVE2;
+]
+"uqrshrn" = [
+    0b01111111_00000000_10011100_00000000 = [B, H, Imm] => [R(0), R(5), BUrange(1, 8), Usub(16, 4, 16)];
+    0b01111111_00000000_10011100_00000000 = [H, S, Imm] => [R(0), R(5), BUrange(1, 16), Usub(16, 5, 32)];
+    0b01111111_00000000_10011100_00000000 = [S, D, Imm] => [R(0), R(5), BUrange(1, 32), Usub(16, 6, 64)];
+    0b00101111_00001000_10011100_00000000 = [VStatic(BYTE, 8), VStatic(WORD, 8), Imm] => [R(0), R(5), Usub(16, 3, 8)];
+    0b00101111_00010000_10011100_00000000 = [VStatic(WORD, 4), VStatic(DWORD, 4), Imm] => [R(0), R(5), Usub(16, 4, 16)];
+    0b00101111_00100000_10011100_00000000 = [VStatic(DWORD, 2), VStatic(QWORD, 2), Imm] => [R(0), R(5), Usub(16, 5, 32)];
+]
+"uqrshrn2" = [
+    0b01101111_00001000_10011100_00000000 = [VStatic(BYTE, 16), VStatic(WORD, 8), Imm] => [R(0), R(5), Usub(16, 3, 8)];
+    0b01101111_00010000_10011100_00000000 = [VStatic(WORD, 8), VStatic(DWORD, 4), Imm] => [R(0), R(5), Usub(16, 4, 16)];
+    0b01101111_00100000_10011100_00000000 = [VStatic(DWORD, 4), VStatic(QWORD, 2), Imm] => [R(0), R(5), Usub(16, 5, 32)];
+]
+"uqrshrnb" = [
+    0b01000101_00101000_00111000_00000000 = [Z(BYTE), Z(WORD), Imm] => [R(0), R(5), Usub(16, 3, 8)], SVE2;
+    0b01000101_00110000_00111000_00000000 = [Z(WORD), Z(DWORD), Imm] => [R(0), R(5), Usub(16, 4, 16)], SVE2;
+    0b01000101_01100000_00111000_00000000 = [Z(DWORD), Z(QWORD), Imm] => [R(0), R(5), Usub(16, 5, 32)], SVE2;
+]
+"uqrshrnt" = [
+    0b01000101_00101000_00111100_00000000 = [Z(BYTE), Z(WORD), Imm] => [R(0), R(5), Usub(16, 3, 8)], SVE2;
+    0b01000101_00110000_00111100_00000000 = [Z(WORD), Z(DWORD), Imm] => [R(0), R(5), Usub(16, 4, 16)], SVE2;
+    0b01000101_01100000_00111100_00000000 = [Z(DWORD), Z(QWORD), Imm] => [R(0), R(5), Usub(16, 5, 32)], SVE2;
+]
+"uqshl" = [
+    // UQSHL (immediate)
+    0b01111111_00001000_01110100_00000000 = [B, B, Imm] => [R(0), R(5), Ubits(16, 3)];
+    0b01111111_00010000_01110100_00000000 = [H, H, Imm] => [R(0), R(5), Ubits(16, 4)];
+    0b01111111_00100000_01110100_00000000 = [S, S, Imm] => [R(0), R(5), Ubits(16, 5)];
+    0b01111111_01000000_01110100_00000000 = [D, D, Imm] => [R(0), R(5), Ubits(16, 6)];
+    0b00101111_00001000_01110100_00000000 = [V(BYTE), V(BYTE), Imm] => [R(0), R(5), Ubits(16, 3), Rwidth(30)];
+    0b00101111_00010000_01110100_00000000 = [V(WORD), V(WORD), Imm] => [R(0), R(5), Ubits(16, 4), Rwidth(30)];
+    0b00101111_00100000_01110100_00000000 = [V(DWORD), V(DWORD), Imm] => [R(0), R(5), Ubits(16, 5), Rwidth(30)];
+    0b00101111_01000000_01110100_00000000 = [VStatic(QWORD, 2), VStatic(QWORD, 2), Imm] => [R(0), R(5), Ubits(16, 6), Rwidth(30)];
+    // UQSHL (register)
+    0b01111110_00100000_01001100_00000000 = [B, B, B] => [R(0), R(5), R(16)];
+    0b01111110_01100000_01001100_00000000 = [H, H, H] => [R(0), R(5), R(16)];
+    0b01111110_10100000_01001100_00000000 = [S, S, S] => [R(0), R(5), R(16)];
+    0b01111110_11100000_01001100_00000000 = [D, D, D] => [R(0), R(5), R(16)];
+    0b00101110_00100000_01001100_00000000 = [V(BYTE), V(BYTE), V(BYTE)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b00101110_01100000_01001100_00000000 = [V(WORD), V(WORD), V(WORD)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b00101110_10100000_01001100_00000000 = [V(DWORD), V(DWORD), V(DWORD)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b00101110_11100000_01001100_00000000 = [VStatic(QWORD, 2), VStatic(QWORD, 2), VStatic(QWORD, 2)] => [R(0), R(5), R(16), Rwidth(30)];
+    // UQSHL (vectors, predicated)
+    0b01000100_00001001_10000000_00000000 = [Z(BYTE), PMerge, Z(BYTE), Z(BYTE)] => [R(0), R3(10), RTied, R(5)], SVE2;
+    0b01000100_01001001_10000000_00000000 = [Z(WORD), PMerge, Z(WORD), Z(WORD)] => [R(0), R3(10), RTied, R(5)], SVE2;
+    0b01000100_10001001_10000000_00000000 = [Z(DWORD), PMerge, Z(DWORD), Z(DWORD)] => [R(0), R3(10), RTied, R(5)], SVE2;
+    0b01000100_11001001_10000000_00000000 = [Z(QWORD), PMerge, Z(QWORD), Z(QWORD)] => [R(0), R3(10), RTied, R(5)], SVE2;
+]
+"uqshlr" = [
+    0b01000100_00001101_10000000_00000000 = [Z(BYTE), PMerge, Z(BYTE), Z(BYTE)] => [R(0), R3(10), RTied, R(5)], SVE2;
+    0b01000100_01001101_10000000_00000000 = [Z(WORD), PMerge, Z(WORD), Z(WORD)] => [R(0), R3(10), RTied, R(5)], SVE2;
+    0b01000100_10001101_10000000_00000000 = [Z(DWORD), PMerge, Z(DWORD), Z(DWORD)] => [R(0), R3(10), RTied, R(5)], SVE2;
+    0b01000100_11001101_10000000_00000000 = [Z(QWORD), PMerge, Z(QWORD), Z(QWORD)] => [R(0), R3(10), RTied, R(5)], SVE2;
+]
+"uqshrn" = [
+    0b01111111_00000000_10010100_00000000 = [B, H, Imm] => [R(0), R(5), BUrange(1, 8), Usub(16, 4, 16)];
+    0b01111111_00000000_10010100_00000000 = [H, S, Imm] => [R(0), R(5), BUrange(1, 16), Usub(16, 5, 32)];
+    0b01111111_00000000_10010100_00000000 = [S, D, Imm] => [R(0), R(5), BUrange(1, 32), Usub(16, 6, 64)];
+    0b00101111_00001000_10010100_00000000 = [VStatic(BYTE, 8), VStatic(WORD, 8), Imm] => [R(0), R(5), Usub(16, 3, 8)];
+    0b00101111_00010000_10010100_00000000 = [VStatic(WORD, 4), VStatic(DWORD, 4), Imm] => [R(0), R(5), Usub(16, 4, 16)];
+    0b00101111_00100000_10010100_00000000 = [VStatic(DWORD, 2), VStatic(QWORD, 2), Imm] => [R(0), R(5), Usub(16, 5, 32)];
+]
+"uqshrn2" = [
+    0b01101111_00001000_10010100_00000000 = [VStatic(BYTE, 16), VStatic(WORD, 8), Imm] => [R(0), R(5), Usub(16, 3, 8)];
+    0b01101111_00010000_10010100_00000000 = [VStatic(WORD, 8), VStatic(DWORD, 4), Imm] => [R(0), R(5), Usub(16, 4, 16)];
+    0b01101111_00100000_10010100_00000000 = [VStatic(DWORD, 4), VStatic(QWORD, 2), Imm] => [R(0), R(5), Usub(16, 5, 32)];
+]
+"uqshrnb" = [
+    0b01000101_00101000_00110000_00000000 = [Z(BYTE), Z(WORD), Imm] => [R(0), R(5), Usub(16, 3, 8)], SVE2;
+    0b01000101_00110000_00110000_00000000 = [Z(WORD), Z(DWORD), Imm] => [R(0), R(5), Usub(16, 4, 16)], SVE2;
+    0b01000101_01100000_00110000_00000000 = [Z(DWORD), Z(QWORD), Imm] => [R(0), R(5), Usub(16, 5, 32)], SVE2;
+]
+"uqshrnt" = [
+    0b01000101_00101000_00110100_00000000 = [Z(BYTE), Z(WORD), Imm] => [R(0), R(5), Usub(16, 3, 8)], SVE2;
+    0b01000101_00110000_00110100_00000000 = [Z(WORD), Z(DWORD), Imm] => [R(0), R(5), Usub(16, 4, 16)], SVE2;
+    0b01000101_01100000_00110100_00000000 = [Z(DWORD), Z(QWORD), Imm] => [R(0), R(5), Usub(16, 5, 32)], SVE2;
+]
+"uqsub" = [
+    // UQSUB
+    0b01111110_00100000_00101100_00000000 = [B, B, B] => [R(0), R(5), R(16)];
+    0b01111110_01100000_00101100_00000000 = [H, H, H] => [R(0), R(5), R(16)];
+    0b01111110_10100000_00101100_00000000 = [S, S, S] => [R(0), R(5), R(16)];
+    0b01111110_11100000_00101100_00000000 = [D, D, D] => [R(0), R(5), R(16)];
+    0b00101110_00100000_00101100_00000000 = [V(BYTE), V(BYTE), V(BYTE)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b00101110_01100000_00101100_00000000 = [V(WORD), V(WORD), V(WORD)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b00101110_10100000_00101100_00000000 = [V(DWORD), V(DWORD), V(DWORD)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b00101110_11100000_00101100_00000000 = [VStatic(QWORD, 2), VStatic(QWORD, 2), VStatic(QWORD, 2)] => [R(0), R(5), R(16), Rwidth(30)];
+    // UQSUB (vectors, unpredicated)
+    0b00000100_00100000_00011100_00000000 = [Z(BYTE), Z(BYTE), Z(BYTE)] => [R(0), R(5), R(16)], SVE;
+    0b00000100_01100000_00011100_00000000 = [Z(WORD), Z(WORD), Z(WORD)] => [R(0), R(5), R(16)], SVE;
+    0b00000100_10100000_00011100_00000000 = [Z(DWORD), Z(DWORD), Z(DWORD)] => [R(0), R(5), R(16)], SVE;
+    0b00000100_11100000_00011100_00000000 = [Z(QWORD), Z(QWORD), Z(QWORD)] => [R(0), R(5), R(16)], SVE;
+    // UQSUB (immediate)
+    0b00100101_00100111_11000000_00000000 = [Z(BYTE), Z(BYTE), Imm] => [R(0), RTied, Ubits(5, 8)], SVE;
+    0b00100101_01100111_11000000_00000000 = [Z(WORD), Z(WORD), Imm, End, LitMod(LSL)] => [R(0), RTied, Ubits(5, 8), Ulist(13, &[0, 8])], SVE;
+    0b00100101_10100111_11000000_00000000 = [Z(DWORD), Z(DWORD), Imm, End, LitMod(LSL)] => [R(0), RTied, Ubits(5, 8), Ulist(13, &[0, 8])], SVE;
+    0b00100101_11100111_11000000_00000000 = [Z(QWORD), Z(QWORD), Imm, End, LitMod(LSL)] => [R(0), RTied, Ubits(5, 8), Ulist(13, &[0, 8])], SVE;
+    // UQSUB (vectors, predicated)
+    0b01000100_00011011_10000000_00000000 = [Z(BYTE), PMerge, Z(BYTE), Z(BYTE)] => [R(0), R3(10), RTied, R(5)], SVE2;
+    0b01000100_01011011_10000000_00000000 = [Z(WORD), PMerge, Z(WORD), Z(WORD)] => [R(0), R3(10), RTied, R(5)], SVE2;
+    0b01000100_10011011_10000000_00000000 = [Z(DWORD), PMerge, Z(DWORD), Z(DWORD)] => [R(0), R3(10), RTied, R(5)], SVE2;
+    0b01000100_11011011_10000000_00000000 = [Z(QWORD), PMerge, Z(QWORD), Z(QWORD)] => [R(0), R3(10), RTied, R(5)], SVE2;
+]
+"uqsubr" = [
+    0b01000100_00011111_10000000_00000000 = [Z(BYTE), PMerge, Z(BYTE), Z(BYTE)] => [R(0), R3(10), RTied, R(5)], SVE2;
+    0b01000100_01011111_10000000_00000000 = [Z(WORD), PMerge, Z(WORD), Z(WORD)] => [R(0), R3(10), RTied, R(5)], SVE2;
+    0b01000100_10011111_10000000_00000000 = [Z(DWORD), PMerge, Z(DWORD), Z(DWORD)] => [R(0), R3(10), RTied, R(5)], SVE2;
+    0b01000100_11011111_10000000_00000000 = [Z(QWORD), PMerge, Z(QWORD), Z(QWORD)] => [R(0), R3(10), RTied, R(5)], SVE2;
+]
+"uqxtn" = [
+    0b01111110_00100001_01001000_00000000 = [B, H] => [R(0), R(5)];
+    0b01111110_01100001_01001000_00000000 = [H, S] => [R(0), R(5)];
+    0b01111110_10100001_01001000_00000000 = [S, D] => [R(0), R(5)];
+    0b00101110_00100001_01001000_00000000 = [VStatic(BYTE, 8), VStatic(WORD, 8)] => [R(0), R(5)];
+    0b00101110_01100001_01001000_00000000 = [VStatic(WORD, 4), VStatic(DWORD, 4)] => [R(0), R(5)];
+    0b00101110_10100001_01001000_00000000 = [VStatic(DWORD, 2), VStatic(QWORD, 2)] => [R(0), R(5)];
+]
+"uqxtn2" = [
+    0b01101110_00100001_01001000_00000000 = [VStatic(BYTE, 16), VStatic(WORD, 8)] => [R(0), R(5)];
+    0b01101110_01100001_01001000_00000000 = [VStatic(WORD, 8), VStatic(DWORD, 4)] => [R(0), R(5)];
+    0b01101110_10100001_01001000_00000000 = [VStatic(DWORD, 4), VStatic(QWORD, 2)] => [R(0), R(5)];
+]
+"uqxtnb" = [
+    0b01000101_00101000_01001000_00000000 = [Z(BYTE), Z(WORD)] => [R(0), R(5)], SVE2;
+    0b01000101_00110000_01001000_00000000 = [Z(WORD), Z(DWORD)] => [R(0), R(5)], SVE2;
+    0b01000101_01100000_01001000_00000000 = [Z(DWORD), Z(QWORD)] => [R(0), R(5)], SVE2;
+]
+"uqxtnt" = [
+    0b01000101_00101000_01001100_00000000 = [Z(BYTE), Z(WORD)] => [R(0), R(5)], SVE2;
+    0b01000101_00110000_01001100_00000000 = [Z(WORD), Z(DWORD)] => [R(0), R(5)], SVE2;
+    0b01000101_01100000_01001100_00000000 = [Z(DWORD), Z(QWORD)] => [R(0), R(5)], SVE2;
+]
+"urecpe" = [
+    0b00001110_10100001_11001000_00000000 = [V(DWORD), V(DWORD)] => [R(0), R(5), Rwidth(30)];
+    0b01000100_10000000_10100000_00000000 = [Z(DWORD), PMerge, Z(DWORD)] => [R(0), R3(10), R(5)], SVE2;
+]
+"urhadd" = [
+    // URHADD
+    0b00101110_00100000_00010100_00000000 = [V(BYTE), V(BYTE), V(BYTE)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b00101110_01100000_00010100_00000000 = [V(WORD), V(WORD), V(WORD)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b00101110_10100000_00010100_00000000 = [V(DWORD), V(DWORD), V(DWORD)] => [R(0), R(5), R(16), Rwidth(30)];
+    // URHADD (vectors, predicated)
+    0b01000100_00010101_10000000_00000000 = [Z(BYTE), PMerge, Z(BYTE), Z(BYTE)] => [R(0), R3(10), RTied, R(5)], SVE2;
+    0b01000100_01010101_10000000_00000000 = [Z(WORD), PMerge, Z(WORD), Z(WORD)] => [R(0), R3(10), RTied, R(5)], SVE2;
+    0b01000100_10010101_10000000_00000000 = [Z(DWORD), PMerge, Z(DWORD), Z(DWORD)] => [R(0), R3(10), RTied, R(5)], SVE2;
+    0b01000100_11010101_10000000_00000000 = [Z(QWORD), PMerge, Z(QWORD), Z(QWORD)] => [R(0), R3(10), RTied, R(5)], SVE2;
+]
+"urshl" = [
+    // URSHL
+    0b01111110_11100000_01010100_00000000 = [D, D, D] => [R(0), R(5), R(16)];
+    0b00101110_00100000_01010100_00000000 = [V(BYTE), V(BYTE), V(BYTE)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b00101110_01100000_01010100_00000000 = [V(WORD), V(WORD), V(WORD)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b00101110_10100000_01010100_00000000 = [V(DWORD), V(DWORD), V(DWORD)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b00101110_11100000_01010100_00000000 = [VStatic(QWORD, 2), VStatic(QWORD, 2), VStatic(QWORD, 2)] => [R(0), R(5), R(16), Rwidth(30)];
+    // URSHL (vectors, predicated)
+    0b01000100_00000011_10000000_00000000 = [Z(BYTE), PMerge, Z(BYTE), Z(BYTE)] => [R(0), R3(10), RTied, R(5)], SVE2;
+    0b01000100_01000011_10000000_00000000 = [Z(WORD), PMerge, Z(WORD), Z(WORD)] => [R(0), R3(10), RTied, R(5)], SVE2;
+    0b01000100_10000011_10000000_00000000 = [Z(DWORD), PMerge, Z(DWORD), Z(DWORD)] => [R(0), R3(10), RTied, R(5)], SVE2;
+    0b01000100_11000011_10000000_00000000 = [Z(QWORD), PMerge, Z(QWORD), Z(QWORD)] => [R(0), R3(10), RTied, R(5)], SVE2;
+]
+"urshlr" = [
+    0b01000100_00000111_10000000_00000000 = [Z(BYTE), PMerge, Z(BYTE), Z(BYTE)] => [R(0), R3(10), RTied, R(5)], SVE2;
+    0b01000100_01000111_10000000_00000000 = [Z(WORD), PMerge, Z(WORD), Z(WORD)] => [R(0), R3(10), RTied, R(5)], SVE2;
+    0b01000100_10000111_10000000_00000000 = [Z(DWORD), PMerge, Z(DWORD), Z(DWORD)] => [R(0), R3(10), RTied, R(5)], SVE2;
+    0b01000100_11000111_10000000_00000000 = [Z(QWORD), PMerge, Z(QWORD), Z(QWORD)] => [R(0), R3(10), RTied, R(5)], SVE2;
+]
+"urshr" = [
+    0b01111111_00000000_00100100_00000000 = [D, D, Imm] => [R(0), R(5), BUrange(1, 64), Usub(16, 7, 128)];
+    0b00101111_00001000_00100100_00000000 = [V(BYTE), V(BYTE), Imm] => [R(0), R(5), Usub(16, 3, 8), Rwidth(30)];
+    0b00101111_00010000_00100100_00000000 = [V(WORD), V(WORD), Imm] => [R(0), R(5), Usub(16, 4, 16), Rwidth(30)];
+    0b00101111_00100000_00100100_00000000 = [V(DWORD), V(DWORD), Imm] => [R(0), R(5), Usub(16, 5, 32), Rwidth(30)];
+    0b00101111_01000000_00100100_00000000 = [VStatic(QWORD, 2), VStatic(QWORD, 2), Imm] => [R(0), R(5), Usub(16, 6, 64), Rwidth(30)];
+]
+"ursqrte" = [
+    0b00101110_10100001_11001000_00000000 = [V(DWORD), V(DWORD)] => [R(0), R(5), Rwidth(30)];
+    0b01000100_10000001_10100000_00000000 = [Z(DWORD), PMerge, Z(DWORD)] => [R(0), R3(10), R(5)], SVE2;
+]
+"ursra" = [
+    0b01111111_00000000_00110100_00000000 = [D, D, Imm] => [R(0), R(5), BUrange(1, 64), Usub(16, 7, 128)];
+    0b00101111_00001000_00110100_00000000 = [V(BYTE), V(BYTE), Imm] => [R(0), R(5), Usub(16, 3, 8), Rwidth(30)];
+    0b00101111_00010000_00110100_00000000 = [V(WORD), V(WORD), Imm] => [R(0), R(5), Usub(16, 4, 16), Rwidth(30)];
+    0b00101111_00100000_00110100_00000000 = [V(DWORD), V(DWORD), Imm] => [R(0), R(5), Usub(16, 5, 32), Rwidth(30)];
+    0b00101111_01000000_00110100_00000000 = [VStatic(QWORD, 2), VStatic(QWORD, 2), Imm] => [R(0), R(5), Usub(16, 6, 64), Rwidth(30)];
+    0b01000101_00001000_11101100_00000000 = [Z(BYTE), Z(BYTE), Imm] => [R(0), R(5), Usub(16, 3, 8)], SVE2;
+    0b01000101_00010000_11101100_00000000 = [Z(WORD), Z(WORD), Imm] => [R(0), R(5), Usub(16, 4, 16)], SVE2;
+    0b01000101_01000000_11101100_00000000 = [Z(DWORD), Z(DWORD), Imm] => [R(0), R(5), Usub(16, 5, 32)], SVE2;
+    0b01000101_10000000_11101100_00000000 = [Z(QWORD), Z(QWORD), Imm] => [R(0), R(5), BUrange(1, 64), Unegslice(16, 5, 0), Unegslice(22, 1, 5), A], SVE2;
+]
+"usdot" = [
+    // USDOT (by element)
+    0b00001111_10000000_11110000_00000000 = [VStatic(DWORD, 2), VStatic(BYTE, 8), VStaticElement(BYTE, 4)] => [R(0), R(5), R(16), Ufields(&[11, 21])], I8MM;
+    0b01001111_10000000_11110000_00000000 = [VStatic(DWORD, 4), VStatic(BYTE, 16), VStaticElement(BYTE, 4)] => [R(0), R(5), R(16), Ufields(&[11, 21])], I8MM;
+    // USDOT (vector)
+    0b00001110_10000000_10011100_00000000 = [VStatic(DWORD, 2), VStatic(BYTE, 8), VStatic(BYTE, 8)] => [R(0), R(5), R(16)], I8MM;
+    0b01001110_10000000_10011100_00000000 = [VStatic(DWORD, 4), VStatic(BYTE, 16), VStatic(BYTE, 16)] => [R(0), R(5), R(16)], I8MM;
+]
+"ushl" = [
+    0b01111110_11100000_01000100_00000000 = [D, D, D] => [R(0), R(5), R(16)];
+    0b00101110_00100000_01000100_00000000 = [V(BYTE), V(BYTE), V(BYTE)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b00101110_01100000_01000100_00000000 = [V(WORD), V(WORD), V(WORD)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b00101110_10100000_01000100_00000000 = [V(DWORD), V(DWORD), V(DWORD)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b00101110_11100000_01000100_00000000 = [VStatic(QWORD, 2), VStatic(QWORD, 2), VStatic(QWORD, 2)] => [R(0), R(5), R(16), Rwidth(30)];
+]
+"ushll" = [
+    0b00101111_00001000_10100100_00000000 = [VStatic(WORD, 8), VStatic(BYTE, 8), Imm] => [R(0), R(5), Ubits(16, 3)];
+    0b00101111_00010000_10100100_00000000 = [VStatic(DWORD, 4), VStatic(WORD, 4), Imm] => [R(0), R(5), Ubits(16, 4)];
+    0b00101111_00100000_10100100_00000000 = [VStatic(QWORD, 2), VStatic(DWORD, 2), Imm] => [R(0), R(5), Ubits(16, 5)];
+]
+"ushll2" = [
+    0b01101111_00001000_10100100_00000000 = [VStatic(WORD, 8), VStatic(BYTE, 16), Imm] => [R(0), R(5), Ubits(16, 3)];
+    0b01101111_00010000_10100100_00000000 = [VStatic(DWORD, 4), VStatic(WORD, 8), Imm] => [R(0), R(5), Ubits(16, 4)];
+    0b01101111_00100000_10100100_00000000 = [VStatic(QWORD, 2), VStatic(DWORD, 4), Imm] => [R(0), R(5), Ubits(16, 5)];
+]
+"ushllb" = [
+    0b01000101_00001000_10101000_00000000 = [Z(WORD), Z(BYTE), Imm] => [R(0), R(5), Ubits(16, 3)], SVE2;
+    0b01000101_00010000_10101000_00000000 = [Z(DWORD), Z(WORD), Imm] => [R(0), R(5), Ubits(16, 4)], SVE2;
+    0b01000101_01000000_10101000_00000000 = [Z(QWORD), Z(DWORD), Imm] => [R(0), R(5), Ubits(16, 5)], SVE2;
+]
+"ushllt" = [
+    0b01000101_00001000_10101100_00000000 = [Z(WORD), Z(BYTE), Imm] => [R(0), R(5), Ubits(16, 3)], SVE2;
+    0b01000101_00010000_10101100_00000000 = [Z(DWORD), Z(WORD), Imm] => [R(0), R(5), Ubits(16, 4)], SVE2;
+    0b01000101_01000000_10101100_00000000 = [Z(QWORD), Z(DWORD), Imm] => [R(0), R(5), Ubits(16, 5)], SVE2;
+]
+"ushr" = [
+    0b01111111_00000000_00000100_00000000 = [D, D, Imm] => [R(0), R(5), BUrange(1, 64), Usub(16, 7, 128)];
+    0b00101111_00001000_00000100_00000000 = [V(BYTE), V(BYTE), Imm] => [R(0), R(5), Usub(16, 3, 8), Rwidth(30)];
+    0b00101111_00010000_00000100_00000000 = [V(WORD), V(WORD), Imm] => [R(0), R(5), Usub(16, 4, 16), Rwidth(30)];
+    0b00101111_00100000_00000100_00000000 = [V(DWORD), V(DWORD), Imm] => [R(0), R(5), Usub(16, 5, 32), Rwidth(30)];
+    0b00101111_01000000_00000100_00000000 = [VStatic(QWORD, 2), VStatic(QWORD, 2), Imm] => [R(0), R(5), Usub(16, 6, 64), Rwidth(30)];
+]
+"usmmla" = [
+    0b01001110_10000000_10101100_00000000 = [VStatic(DWORD, 4), VStatic(BYTE, 16), VStatic(BYTE, 16)] => [R(0), R(5), R(16)], I8MM;
+]
+"usmopa" = [
+    0b10100001_10000000_00000000_00000000 = [ZaTile(DWORD), PMerge, PMerge, Z(BYTE), Z(BYTE)] => [RTile(0, 2), R3(10), R3(13), R(5), R(16)], SME;
+    0b10100001_11000000_00000000_00000000 = [ZaTile(QWORD), PMerge, PMerge, Z(WORD), Z(WORD)] => [RTile(0, 3), R3(10), R3(13), R(5), R(16)], SME | SME_I16I64;
+]
+"usmops" = [
+    0b10100001_10000000_00000000_00010000 = [ZaTile(DWORD), PMerge, PMerge, Z(BYTE), Z(BYTE)] => [RTile(0, 2), R3(10), R3(13), R(5), R(16)], SME;
+    0b10100001_11000000_00000000_00010000 = [ZaTile(QWORD), PMerge, PMerge, Z(WORD), Z(WORD)] => [RTile(0, 3), R3(10), R3(13), R(5), R(16)], SME | SME_I16I64;
+]
+"usqadd" = [
+    // USQADD
+    0b01111110_00100000_00111000_00000000 = [B, B] => [R(0), R(5)];
+    0b01111110_01100000_00111000_00000000 = [H, H] => [R(0), R(5)];
+    0b01111110_10100000_00111000_00000000 = [S, S] => [R(0), R(5)];
+    0b01111110_11100000_00111000_00000000 = [D, D] => [R(0), R(5)];
+    0b00101110_00100000_00111000_00000000 = [V(BYTE), V(BYTE)] => [R(0), R(5), Rwidth(30)];
+    0b00101110_01100000_00111000_00000000 = [V(WORD), V(WORD)] => [R(0), R(5), Rwidth(30)];
+    0b00101110_10100000_00111000_00000000 = [V(DWORD), V(DWORD)] => [R(0), R(5), Rwidth(30)];
+    0b00101110_11100000_00111000_00000000 = [VStatic(QWORD, 2), VStatic(QWORD, 2)] => [R(0), R(5), Rwidth(30)];
+    // USQADD (vectors, predicated)
+    0b01000100_00011101_10000000_00000000 = [Z(BYTE), PMerge, Z(BYTE), Z(BYTE)] => [R(0), R3(10), RTied, R(5)], SVE2;
+    0b01000100_01011101_10000000_00000000 = [Z(WORD), PMerge, Z(WORD), Z(WORD)] => [R(0), R3(10), RTied, R(5)], SVE2;
+    0b01000100_10011101_10000000_00000000 = [Z(DWORD), PMerge, Z(DWORD), Z(DWORD)] => [R(0), R3(10), RTied, R(5)], SVE2;
+    0b01000100_11011101_10000000_00000000 = [Z(QWORD), PMerge, Z(QWORD), Z(QWORD)] => [R(0), R3(10), RTied, R(5)], SVE2;
+]
+"usra" = [
+    0b01111111_00000000_00010100_00000000 = [D, D, Imm] => [R(0), R(5), BUrange(1, 64), Usub(16, 7, 128)];
+    0b00101111_00001000_00010100_00000000 = [V(BYTE), V(BYTE), Imm] => [R(0), R(5), Usub(16, 3, 8), Rwidth(30)];
+    0b00101111_00010000_00010100_00000000 = [V(WORD), V(WORD), Imm] => [R(0), R(5), Usub(16, 4, 16), Rwidth(30)];
+    0b00101111_00100000_00010100_00000000 = [V(DWORD), V(DWORD), Imm] => [R(0), R(5), Usub(16, 5, 32), Rwidth(30)];
+    0b00101111_01000000_00010100_00000000 = [VStatic(QWORD, 2), VStatic(QWORD, 2), Imm] => [R(0), R(5), Usub(16, 6, 64), Rwidth(30)];
+    0b01000101_00001000_11100100_00000000 = [Z(BYTE), Z(BYTE), Imm] => [R(0), R(5), Usub(16, 3, 8)], SVE2;
+    0b01000101_00010000_11100100_00000000 = [Z(WORD), Z(WORD), Imm] => [R(0), R(5), Usub(16, 4, 16)], SVE2;
+    0b01000101_01000000_11100100_00000000 = [Z(DWORD), Z(DWORD), Imm] => [R(0), R(5), Usub(16, 5, 32)], SVE2;
+    0b01000101_10000000_11100100_00000000 = [Z(QWORD), Z(QWORD), Imm] => [R(0), R(5), BUrange(1, 64), Unegslice(16, 5, 0), Unegslice(22, 1, 5), A], SVE2;
+]
+"usubl" = [
+    0b00101110_00100000_00100000_00000000 = [VStatic(WORD, 8), VStatic(BYTE, 8), VStatic(BYTE, 8)] => [R(0), R(5), R(16)];
+    0b00101110_01100000_00100000_00000000 = [VStatic(DWORD, 4), VStatic(WORD, 4), VStatic(WORD, 4)] => [R(0), R(5), R(16)];
+    0b00101110_10100000_00100000_00000000 = [VStatic(QWORD, 2), VStatic(DWORD, 2), VStatic(DWORD, 2)] => [R(0), R(5), R(16)];
+]
+"usubl2" = [
+    0b01101110_00100000_00100000_00000000 = [VStatic(WORD, 8), VStatic(BYTE, 16), VStatic(BYTE, 16)] => [R(0), R(5), R(16)];
+    0b01101110_01100000_00100000_00000000 = [VStatic(DWORD, 4), VStatic(WORD, 8), VStatic(WORD, 8)] => [R(0), R(5), R(16)];
+    0b01101110_10100000_00100000_00000000 = [VStatic(QWORD, 2), VStatic(DWORD, 4), VStatic(DWORD, 4)] => [R(0), R(5), R(16)];
+]
+"usublb" = [
+    0b01000101_01000000_00011000_00000000 = [Z(WORD), Z(BYTE), Z(BYTE)] => [R(0), R(5), R(16)], SVE2;
+    0b01000101_10000000_00011000_00000000 = [Z(DWORD), Z(WORD), Z(WORD)] => [R(0), R(5), R(16)], SVE2;
+    0b01000101_11000000_00011000_00000000 = [Z(QWORD), Z(DWORD), Z(DWORD)] => [R(0), R(5), R(16)], SVE2;
+]
+"usublt" = [
+    0b01000101_01000000_00011100_00000000 = [Z(WORD), Z(BYTE), Z(BYTE)] => [R(0), R(5), R(16)], SVE2;
+    0b01000101_10000000_00011100_00000000 = [Z(DWORD), Z(WORD), Z(WORD)] => [R(0), R(5), R(16)], SVE2;
+    0b01000101_11000000_00011100_00000000 = [Z(QWORD), Z(DWORD), Z(DWORD)] => [R(0), R(5), R(16)], SVE2;
+]
+"usubw" = [
+    0b00101110_00100000_00110000_00000000 = [VStatic(WORD, 8), VStatic(WORD, 8), VStatic(BYTE, 8)] => [R(0), R(5), R(16)];
+    0b00101110_01100000_00110000_00000000 = [VStatic(DWORD, 4), VStatic(DWORD, 4), VStatic(WORD, 4)] => [R(0), R(5), R(16)];
+    0b00101110_10100000_00110000_00000000 = [VStatic(QWORD, 2), VStatic(QWORD, 2), VStatic(DWORD, 2)] => [R(0), R(5), R(16)];
+]
+"usubw2" = [
+    0b01101110_00100000_00110000_00000000 = [VStatic(WORD, 8), VStatic(WORD, 8), VStatic(BYTE, 16)] => [R(0), R(5), R(16)];
+    0b01101110_01100000_00110000_00000000 = [VStatic(DWORD, 4), VStatic(DWORD, 4), VStatic(WORD, 8)] => [R(0), R(5), R(16)];
+    0b01101110_10100000_00110000_00000000 = [VStatic(QWORD, 2), VStatic(QWORD, 2), VStatic(DWORD, 4)] => [R(0), R(5), R(16)];
+]
+"usubwb" = [
+    0b01000101_01000000_01011000_00000000 = [Z(WORD), Z(WORD), Z(BYTE)] => [R(0), R(5), R(16)], SVE2;
+    0b01000101_10000000_01011000_00000000 = [Z(DWORD), Z(DWORD), Z(WORD)] => [R(0), R(5), R(16)], SVE2;
+    0b01000101_11000000_01011000_00000000 = [Z(QWORD), Z(QWORD), Z(DWORD)] => [R(0), R(5), R(16)], SVE2;
+]
+"usubwt" = [
+    0b01000101_01000000_01011100_00000000 = [Z(WORD), Z(WORD), Z(BYTE)] => [R(0), R(5), R(16)], SVE2;
+    0b01000101_10000000_01011100_00000000 = [Z(DWORD), Z(DWORD), Z(WORD)] => [R(0), R(5), R(16)], SVE2;
+    0b01000101_11000000_01011100_00000000 = [Z(QWORD), Z(QWORD), Z(DWORD)] => [R(0), R(5), R(16)], SVE2;
+]
+"uunpkhi" = [
+    0b00000101_01110011_00111000_00000000 = [Z(WORD), Z(BYTE)] => [R(0), R(5)], SVE;
+    0b00000101_10110011_00111000_00000000 = [Z(DWORD), Z(WORD)] => [R(0), R(5)], SVE;
+    0b00000101_11110011_00111000_00000000 = [Z(QWORD), Z(DWORD)] => [R(0), R(5)], SVE;
+]
+"uunpklo" = [
+    0b00000101_01110010_00111000_00000000 = [Z(WORD), Z(BYTE)] => [R(0), R(5)], SVE;
+    0b00000101_10110010_00111000_00000000 = [Z(DWORD), Z(WORD)] => [R(0), R(5)], SVE;
+    0b00000101_11110010_00111000_00000000 = [Z(QWORD), Z(DWORD)] => [R(0), R(5)], SVE;
+]
+"uxtb" = [
+    // UXTB
+    0b01010011_00000000_00011100_00000000 = [W, W] => [R(0), R(5)];
+    // UXTB (predicated)
+    0b00000100_01010001_10100000_00000000 = [Z(WORD), PMerge, Z(WORD)] => [R(0), R3(10), R(5)], SVE;
+    0b00000100_10010001_10100000_00000000 = [Z(DWORD), PMerge, Z(DWORD)] => [R(0), R3(10), R(5)], SVE;
+    0b00000100_11010001_10100000_00000000 = [Z(QWORD), PMerge, Z(QWORD)] => [R(0), R3(10), R(5)], SVE;
+]
+"uxth" = [
+    // UXTH
+    0b01010011_00000000_00111100_00000000 = [W, W] => [R(0), R(5)];
+    // UXTH (predicated)
+    0b00000100_10010011_10100000_00000000 = [Z(DWORD), PMerge, Z(DWORD)] => [R(0), R3(10), R(5)], SVE;
+    0b00000100_11010011_10100000_00000000 = [Z(QWORD), PMerge, Z(QWORD)] => [R(0), R3(10), R(5)], SVE;
+]
+"uxtl" = [
+    0b00101111_00001000_10100100_00000000 = [VStatic(WORD, 8), VStatic(BYTE, 8)] => [R(0), R(5)];
+    0b00101111_00010000_10100100_00000000 = [VStatic(DWORD, 4), VStatic(WORD, 4)] => [R(0), R(5)];
+    0b00101111_00100000_10100100_00000000 = [VStatic(QWORD, 2), VStatic(DWORD, 2)] => [R(0), R(5)];
+]
+"uxtl2" = [
+    0b01101111_00001000_10100100_00000000 = [VStatic(WORD, 8), VStatic(BYTE, 16)] => [R(0), R(5)];
+    0b01101111_00010000_10100100_00000000 = [VStatic(DWORD, 4), VStatic(WORD, 8)] => [R(0), R(5)];
+    0b01101111_00100000_10100100_00000000 = [VStatic(QWORD, 2), VStatic(DWORD, 4)] => [R(0), R(5)];
+]
+"uxtw" = [
+    0b00000100_11010101_10100000_00000000 = [Z(QWORD), PMerge, Z(QWORD)] => [R(0), R3(10), R(5)], SVE;
+]
+"uzp1" = [
+    // UZP1
+    0b00001110_00000000_00011000_00000000 = [V(BYTE), V(BYTE), V(BYTE)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b00001110_01000000_00011000_00000000 = [V(WORD), V(WORD), V(WORD)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b00001110_10000000_00011000_00000000 = [V(DWORD), V(DWORD), V(DWORD)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b00001110_11000000_00011000_00000000 = [VStatic(QWORD, 2), VStatic(QWORD, 2), VStatic(QWORD, 2)] => [R(0), R(5), R(16), Rwidth(30)];
+    // UZP1 (predicates)
+    0b00000101_00100000_01001000_00000000 = [P(BYTE), P(BYTE), P(BYTE)] => [R4(0), R4(5), R4(16)], SVE;
+    0b00000101_01100000_01001000_00000000 = [P(WORD), P(WORD), P(WORD)] => [R4(0), R4(5), R4(16)], SVE;
+    0b00000101_10100000_01001000_00000000 = [P(DWORD), P(DWORD), P(DWORD)] => [R4(0), R4(5), R4(16)], SVE;
+    0b00000101_11100000_01001000_00000000 = [P(QWORD), P(QWORD), P(QWORD)] => [R4(0), R4(5), R4(16)], SVE;
+    // UZP1 (vectors, unpredicated)
+    0b00000101_00100000_01101000_00000000 = [Z(BYTE), Z(BYTE), Z(BYTE)] => [R(0), R(5), R(16)], SVE;
+    0b00000101_01100000_01101000_00000000 = [Z(WORD), Z(WORD), Z(WORD)] => [R(0), R(5), R(16)], SVE;
+    0b00000101_10100000_01101000_00000000 = [Z(DWORD), Z(DWORD), Z(DWORD)] => [R(0), R(5), R(16)], SVE;
+    0b00000101_11100000_01101000_00000000 = [Z(QWORD), Z(QWORD), Z(QWORD)] => [R(0), R(5), R(16)], SVE;
+]
+"uzp2" = [
+    // UZP2
+    0b00001110_00000000_01011000_00000000 = [V(BYTE), V(BYTE), V(BYTE)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b00001110_01000000_01011000_00000000 = [V(WORD), V(WORD), V(WORD)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b00001110_10000000_01011000_00000000 = [V(DWORD), V(DWORD), V(DWORD)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b00001110_11000000_01011000_00000000 = [VStatic(QWORD, 2), VStatic(QWORD, 2), VStatic(QWORD, 2)] => [R(0), R(5), R(16), Rwidth(30)];
+    // UZP2 (predicates)
+    0b00000101_00100000_01001100_00000000 = [P(BYTE), P(BYTE), P(BYTE)] => [R4(0), R4(5), R4(16)], SVE;
+    0b00000101_01100000_01001100_00000000 = [P(WORD), P(WORD), P(WORD)] => [R4(0), R4(5), R4(16)], SVE;
+    0b00000101_10100000_01001100_00000000 = [P(DWORD), P(DWORD), P(DWORD)] => [R4(0), R4(5), R4(16)], SVE;
+    0b00000101_11100000_01001100_00000000 = [P(QWORD), P(QWORD), P(QWORD)] => [R4(0), R4(5), R4(16)], SVE;
+    // UZP2 (vectors, unpredicated)
+    0b00000101_00100000_01101100_00000000 = [Z(BYTE), Z(BYTE), Z(BYTE)] => [R(0), R(5), R(16)], SVE;
+    0b00000101_01100000_01101100_00000000 = [Z(WORD), Z(WORD), Z(WORD)] => [R(0), R(5), R(16)], SVE;
+    0b00000101_10100000_01101100_00000000 = [Z(DWORD), Z(DWORD), Z(DWORD)] => [R(0), R(5), R(16)], SVE;
+    0b00000101_11100000_01101100_00000000 = [Z(QWORD), Z(QWORD), Z(QWORD)] => [R(0), R(5), R(16)], SVE;
+]
+"wfe" = [
+    0b11010101_00000011_00100000_01011111 = [] => [];
+]
+"wfi" = [
+    0b11010101_00000011_00100000_01111111 = [] => [];
+]
+"whilege" = [
+    0b00100101_00100000_00000000_00000000 = [P(BYTE), W, W] => [R4(0), R(5), R(16)], SVE2;
+    0b00100101_01100000_00000000_00000000 = [P(WORD), W, W] => [R4(0), R(5), R(16)], SVE2;
+    0b00100101_10100000_00000000_00000000 = [P(DWORD), W, W] => [R4(0), R(5), R(16)], SVE2;
+    0b00100101_11100000_00000000_00000000 = [P(QWORD), W, W] => [R4(0), R(5), R(16)], SVE2;
+    0b00100101_00100000_00010000_00000000 = [P(BYTE), X, X] => [R4(0), R(5), R(16)], SVE2;
+    0b00100101_01100000_00010000_00000000 = [P(WORD), X, X] => [R4(0), R(5), R(16)], SVE2;
+    0b00100101_10100000_00010000_00000000 = [P(DWORD), X, X] => [R4(0), R(5), R(16)], SVE2;
+    0b00100101_11100000_00010000_00000000 = [P(QWORD), X, X] => [R4(0), R(5), R(16)], SVE2;
+]
+"whilegt" = [
+    0b00100101_00100000_00000000_00010000 = [P(BYTE), W, W] => [R4(0), R(5), R(16)], SVE2;
+    0b00100101_01100000_00000000_00010000 = [P(WORD), W, W] => [R4(0), R(5), R(16)], SVE2;
+    0b00100101_10100000_00000000_00010000 = [P(DWORD), W, W] => [R4(0), R(5), R(16)], SVE2;
+    0b00100101_11100000_00000000_00010000 = [P(QWORD), W, W] => [R4(0), R(5), R(16)], SVE2;
+    0b00100101_00100000_00010000_00010000 = [P(BYTE), X, X] => [R4(0), R(5), R(16)], SVE2;
+    0b00100101_01100000_00010000_00010000 = [P(WORD), X, X] => [R4(0), R(5), R(16)], SVE2;
+    0b00100101_10100000_00010000_00010000 = [P(DWORD), X, X] => [R4(0), R(5), R(16)], SVE2;
+    0b00100101_11100000_00010000_00010000 = [P(QWORD), X, X] => [R4(0), R(5), R(16)], SVE2;
+]
+"whilehi" = [
+    0b00100101_00100000_00001000_00010000 = [P(BYTE), W, W] => [R4(0), R(5), R(16)], SVE2;
+    0b00100101_01100000_00001000_00010000 = [P(WORD), W, W] => [R4(0), R(5), R(16)], SVE2;
+    0b00100101_10100000_00001000_00010000 = [P(DWORD), W, W] => [R4(0), R(5), R(16)], SVE2;
+    0b00100101_11100000_00001000_00010000 = [P(QWORD), W, W] => [R4(0), R(5), R(16)], SVE2;
+    0b00100101_00100000_00011000_00010000 = [P(BYTE), X, X] => [R4(0), R(5), R(16)], SVE2;
+    0b00100101_01100000_00011000_00010000 = [P(WORD), X, X] => [R4(0), R(5), R(16)], SVE2;
+    0b00100101_10100000_00011000_00010000 = [P(DWORD), X, X] => [R4(0), R(5), R(16)], SVE2;
+    0b00100101_11100000_00011000_00010000 = [P(QWORD), X, X] => [R4(0), R(5), R(16)], SVE2;
+]
+"whilehs" = [
+    0b00100101_00100000_00001000_00000000 = [P(BYTE), W, W] => [R4(0), R(5), R(16)], SVE2;
+    0b00100101_01100000_00001000_00000000 = [P(WORD), W, W] => [R4(0), R(5), R(16)], SVE2;
+    0b00100101_10100000_00001000_00000000 = [P(DWORD), W, W] => [R4(0), R(5), R(16)], SVE2;
+    0b00100101_11100000_00001000_00000000 = [P(QWORD), W, W] => [R4(0), R(5), R(16)], SVE2;
+    0b00100101_00100000_00011000_00000000 = [P(BYTE), X, X] => [R4(0), R(5), R(16)], SVE2;
+    0b00100101_01100000_00011000_00000000 = [P(WORD), X, X] => [R4(0), R(5), R(16)], SVE2;
+    0b00100101_10100000_00011000_00000000 = [P(DWORD), X, X] => [R4(0), R(5), R(16)], SVE2;
+    0b00100101_11100000_00011000_00000000 = [P(QWORD), X, X] => [R4(0), R(5), R(16)], SVE2;
+]
+"whilele" = [
+    0b00100101_00100000_00000100_00010000 = [P(BYTE), W, W] => [R4(0), R(5), R(16)], SVE;
+    0b00100101_01100000_00000100_00010000 = [P(WORD), W, W] => [R4(0), R(5), R(16)], SVE;
+    0b00100101_10100000_00000100_00010000 = [P(DWORD), W, W] => [R4(0), R(5), R(16)], SVE;
+    0b00100101_11100000_00000100_00010000 = [P(QWORD), W, W] => [R4(0), R(5), R(16)], SVE;
+    0b00100101_00100000_00010100_00010000 = [P(BYTE), X, X] => [R4(0), R(5), R(16)], SVE;
+    0b00100101_01100000_00010100_00010000 = [P(WORD), X, X] => [R4(0), R(5), R(16)], SVE;
+    0b00100101_10100000_00010100_00010000 = [P(DWORD), X, X] => [R4(0), R(5), R(16)], SVE;
+    0b00100101_11100000_00010100_00010000 = [P(QWORD), X, X] => [R4(0), R(5), R(16)], SVE;
+]
+"whilelo" = [
+    0b00100101_00100000_00001100_00000000 = [P(BYTE), W, W] => [R4(0), R(5), R(16)], SVE;
+    0b00100101_01100000_00001100_00000000 = [P(WORD), W, W] => [R4(0), R(5), R(16)], SVE;
+    0b00100101_10100000_00001100_00000000 = [P(DWORD), W, W] => [R4(0), R(5), R(16)], SVE;
+    0b00100101_11100000_00001100_00000000 = [P(QWORD), W, W] => [R4(0), R(5), R(16)], SVE;
+    0b00100101_00100000_00011100_00000000 = [P(BYTE), X, X] => [R4(0), R(5), R(16)], SVE;
+    0b00100101_01100000_00011100_00000000 = [P(WORD), X, X] => [R4(0), R(5), R(16)], SVE;
+    0b00100101_10100000_00011100_00000000 = [P(DWORD), X, X] => [R4(0), R(5), R(16)], SVE;
+    0b00100101_11100000_00011100_00000000 = [P(QWORD), X, X] => [R4(0), R(5), R(16)], SVE;
+]
+"whilels" = [
+    0b00100101_00100000_00001100_00010000 = [P(BYTE), W, W] => [R4(0), R(5), R(16)], SVE;
+    0b00100101_01100000_00001100_00010000 = [P(WORD), W, W] => [R4(0), R(5), R(16)], SVE;
+    0b00100101_10100000_00001100_00010000 = [P(DWORD), W, W] => [R4(0), R(5), R(16)], SVE;
+    0b00100101_11100000_00001100_00010000 = [P(QWORD), W, W] => [R4(0), R(5), R(16)], SVE;
+    0b00100101_00100000_00011100_00010000 = [P(BYTE), X, X] => [R4(0), R(5), R(16)], SVE;
+    0b00100101_01100000_00011100_00010000 = [P(WORD), X, X] => [R4(0), R(5), R(16)], SVE;
+    0b00100101_10100000_00011100_00010000 = [P(DWORD), X, X] => [R4(0), R(5), R(16)], SVE;
+    0b00100101_11100000_00011100_00010000 = [P(QWORD), X, X] => [R4(0), R(5), R(16)], SVE;
+]
+"whilelt" = [
+    0b00100101_00100000_00000100_00000000 = [P(BYTE), W, W] => [R4(0), R(5), R(16)], SVE;
+    0b00100101_01100000_00000100_00000000 = [P(WORD), W, W] => [R4(0), R(5), R(16)], SVE;
+    0b00100101_10100000_00000100_00000000 = [P(DWORD), W, W] => [R4(0), R(5), R(16)], SVE;
+    0b00100101_11100000_00000100_00000000 = [P(QWORD), W, W] => [R4(0), R(5), R(16)], SVE;
+    0b00100101_00100000_00010100_00000000 = [P(BYTE), X, X] => [R4(0), R(5), R(16)], SVE;
+    0b00100101_01100000_00010100_00000000 = [P(WORD), X, X] => [R4(0), R(5), R(16)], SVE;
+    0b00100101_10100000_00010100_00000000 = [P(DWORD), X, X] => [R4(0), R(5), R(16)], SVE;
+    0b00100101_11100000_00010100_00000000 = [P(QWORD), X, X] => [R4(0), R(5), R(16)], SVE;
+]
+"whilerw" = [
+    0b00100101_00100000_00110000_00010000 = [P(BYTE), X, X] => [R4(0), R(5), R(16)], SVE2;
+    0b00100101_01100000_00110000_00010000 = [P(WORD), X, X] => [R4(0), R(5), R(16)], SVE2;
+    0b00100101_10100000_00110000_00010000 = [P(DWORD), X, X] => [R4(0), R(5), R(16)], SVE2;
+    0b00100101_11100000_00110000_00010000 = [P(QWORD), X, X] => [R4(0), R(5), R(16)], SVE2;
+]
+"whilewr" = [
+    0b00100101_00100000_00110000_00000000 = [P(BYTE), X, X] => [R4(0), R(5), R(16)], SVE2;
+    0b00100101_01100000_00110000_00000000 = [P(WORD), X, X] => [R4(0), R(5), R(16)], SVE2;
+    0b00100101_10100000_00110000_00000000 = [P(DWORD), X, X] => [R4(0), R(5), R(16)], SVE2;
+    0b00100101_11100000_00110000_00000000 = [P(QWORD), X, X] => [R4(0), R(5), R(16)], SVE2;
+]
+"wrffr" = [
+    0b00100101_00101000_10010000_00000000 = [P(BYTE)] => [R4(5)], SVE;
+]
+"xar" = [
+    0b11001110_10000000_00000000_00000000 = [VStatic(QWORD, 2), VStatic(QWORD, 2), VStatic(QWORD, 2), Imm] => [R(0), R(5), R(16), Ubits(10, 6)], SHA3;
+    0b00000100_00101000_00110100_00000000 = [Z(BYTE), Z(BYTE), Z(BYTE), Imm] => [R(0), RTied, R(5), Usub(16, 3, 8)], SVE2;
+    0b00000100_00110000_00110100_00000000 = [Z(WORD), Z(WORD), Z(WORD), Imm] => [R(0), RTied, R(5), Usub(16, 4, 16)], SVE2;
+    0b00000100_01100000_00110100_00000000 = [Z(DWORD), Z(DWORD), Z(DWORD), Imm] => [R(0), RTied, R(5), Usub(16, 5, 32)], SVE2;
+    0b00000100_10100000_00110100_00000000 = [Z(QWORD), Z(QWORD), Z(QWORD), Imm] => [R(0), RTied, R(5), BUrange(1, 64), Unegslice(16, 5, 0), Unegslice(22, 1, 5), A], SVE2;
+]
+"xpacd" = [
+    0b11011010_11000001_01000111_11100000 = [X] => [R(0)], PAUTH;
+]
+"xpaci" = [
+    0b11011010_11000001_01000011_11100000 = [X] => [R(0)], PAUTH;
+]
+"xpaclri" = [
+    0b11010101_00000011_00100000_11111111 = [] => [], PAUTH;
+]
+"xtn" = [
+    0b00001110_00100001_00101000_00000000 = [VStatic(BYTE, 8), VStatic(WORD, 8)] => [R(0), R(5)];
+    0b00001110_01100001_00101000_00000000 = [VStatic(WORD, 4), VStatic(DWORD, 4)] => [R(0), R(5)];
+    0b00001110_10100001_00101000_00000000 = [VStatic(DWORD, 2), VStatic(QWORD, 2)] => [R(0), R(5)];
+]
+"xtn2" = [
+    0b01001110_00100001_00101000_00000000 = [VStatic(BYTE, 16), VStatic(WORD, 8)] => [R(0), R(5)];
+    0b01001110_01100001_00101000_00000000 = [VStatic(WORD, 8), VStatic(DWORD, 4)] => [R(0), R(5)];
+    0b01001110_10100001_00101000_00000000 = [VStatic(DWORD, 4), VStatic(QWORD, 2)] => [R(0), R(5)];
+]
+"yield" = [
+    0b11010101_00000011_00100000_00111111 = [] => [];
+]
+"zero" = [
+    0b11000000_00001000_00000000_00000000 = [ZaTileList] => [Ubits(0, 8)], SME;
+]
+"zip1" = [
+    // ZIP1
+    0b00001110_00000000_00111000_00000000 = [V(BYTE), V(BYTE), V(BYTE)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b00001110_01000000_00111000_00000000 = [V(WORD), V(WORD), V(WORD)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b00001110_10000000_00111000_00000000 = [V(DWORD), V(DWORD), V(DWORD)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b00001110_11000000_00111000_00000000 = [VStatic(QWORD, 2), VStatic(QWORD, 2), VStatic(QWORD, 2)] => [R(0), R(5), R(16), Rwidth(30)];
+    // ZIP1 (predicates)
+    0b00000101_00100000_01000000_00000000 = [P(BYTE), P(BYTE), P(BYTE)] => [R4(0), R4(5), R4(16)], SVE;
+    0b00000101_01100000_01000000_00000000 = [P(WORD), P(WORD), P(WORD)] => [R4(0), R4(5), R4(16)], SVE;
+    0b00000101_10100000_01000000_00000000 = [P(DWORD), P(DWORD), P(DWORD)] => [R4(0), R4(5), R4(16)], SVE;
+    0b00000101_11100000_01000000_00000000 = [P(QWORD), P(QWORD), P(QWORD)] => [R4(0), R4(5), R4(16)], SVE;
+    // ZIP1 (vectors, unpredicated)
+    0b00000101_00100000_01100000_00000000 = [Z(BYTE), Z(BYTE), Z(BYTE)] => [R(0), R(5), R(16)], SVE;
+    0b00000101_01100000_01100000_00000000 = [Z(WORD), Z(WORD), Z(WORD)] => [R(0), R(5), R(16)], SVE;
+    0b00000101_10100000_01100000_00000000 = [Z(DWORD), Z(DWORD), Z(DWORD)] => [R(0), R(5), R(16)], SVE;
+    0b00000101_11100000_01100000_00000000 = [Z(QWORD), Z(QWORD), Z(QWORD)] => [R(0), R(5), R(16)], SVE;
+]
+"zip2" = [
+    // ZIP2
+    0b00001110_00000000_01111000_00000000 = [V(BYTE), V(BYTE), V(BYTE)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b00001110_01000000_01111000_00000000 = [V(WORD), V(WORD), V(WORD)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b00001110_10000000_01111000_00000000 = [V(DWORD), V(DWORD), V(DWORD)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b00001110_11000000_01111000_00000000 = [VStatic(QWORD, 2), VStatic(QWORD, 2), VStatic(QWORD, 2)] => [R(0), R(5), R(16), Rwidth(30)];
+    // ZIP2 (predicates)
+    0b00000101_00100000_01000100_00000000 = [P(BYTE), P(BYTE), P(BYTE)] => [R4(0), R4(5), R4(16)], SVE;
+    0b00000101_01100000_01000100_00000000 = [P(WORD), P(WORD), P(WORD)] => [R4(0), R4(5), R4(16)], SVE;
+    0b00000101_10100000_01000100_00000000 = [P(DWORD), P(DWORD), P(DWORD)] => [R4(0), R4(5), R4(16)], SVE;
+    0b00000101_11100000_01000100_00000000 = [P(QWORD), P(QWORD), P(QWORD)] => [R4(0), R4(5), R4(16)], SVE;
+    // ZIP2 (vectors, unpredicated)
+    0b00000101_00100000_01100100_00000000 = [Z(BYTE), Z(BYTE), Z(BYTE)] => [R(0), R(5), R(16)], SVE;
+    0b00000101_01100000_01100100_00000000 = [Z(WORD), Z(WORD), Z(WORD)] => [R(0), R(5), R(16)], SVE;
+    0b00000101_10100000_01100100_00000000 = [Z(DWORD), Z(DWORD), Z(DWORD)] => [R(0), R(5), R(16)], SVE;
+    0b00000101_11100000_01100100_00000000 = [Z(QWORD), Z(QWORD), Z(QWORD)] => [R(0), R(5), R(16)], SVE;
+]
+
+    ) }
+}
@@ -0,0 +1,178 @@
+/// Special identifier lists, as an array of `(list name, &[(identifier, encoding)])`.
+#[macro_export]
+macro_rules! aarch64_special_idents {
+    () => {
+[
+    ("AT_OPS", &[
+        ("s1e1r",  0b00_0011_1100_0000),
+        ("s1e1w",  0b00_0011_1100_0001),
+        ("s1e0r",  0b00_0011_1100_0010),
+        ("s1e0w",  0b00_0011_1100_0011),
+        ("s1e2r",  0b10_0011_1100_0000),
+        ("s1e2w",  0b10_0011_1100_0001),
+        ("s12e1r", 0b10_0011_1100_0100),
+        ("s12e1w", 0b10_0011_1100_0101),
+        ("s12e0r", 0b10_0011_1100_0110),
+        ("s12e0w", 0b10_0011_1100_0111),
+        ("s1e3r",  0b11_0011_1100_0000),
+        ("s1e3w",  0b11_0011_1100_0001),
+        ("s1e1rp", 0b00_0011_1100_1000),
+        ("s1e1wp", 0b00_0011_1100_1001),
+    ]),
+    ("IC_OPS", &[
+        ("ialluis", 0b00_0011_1000_1000),
+        ("iallu",   0b00_0011_1010_1000),
+    ]),
+    ("DC_OPS", &[
+        ("ivac",  0b00_0011_1011_0001),
+        ("isw",   0b00_0011_1011_0010),
+        ("csw",   0b00_0011_1101_0010),
+        ("cisw",  0b00_0011_1111_0010),
+        ("zva",   0b01_1011_1010_0001),
+        ("cvac",  0b01_1011_1101_0001),
+        ("cvau",  0b01_1011_1101_1001),
+        ("civac", 0b01_1011_1111_0001),
+        ("cvap",  0b01_1011_1110_0001),
+    ]),
+    ("BARRIER_OPS", &[
+        ("sy",    0b1111),
+        ("st",    0b1110),
+        ("ld",    0b1101),
+        ("ish",   0b1011),
+        ("ishst", 0b1010),
+        ("ishld", 0b1001),
+        ("nsh",   0b0111),
+        ("nshst", 0b0110),
+        ("nshld", 0b0101),
+        ("osh",   0b0011),
+        ("oshst", 0b0010),
+        ("oshld", 0b0001),
+    ]),
+    ("MSR_IMM_OPS", &[
+        ("spsel",   0b00_0010_0000_0101),
+        ("daifset", 0b01_1010_0000_0110),
+        ("daifclr", 0b01_1010_0000_0111),
+        ("uao",     0b00_0010_0000_0011),
+        ("pan",     0b00_0010_0000_0100),
+        ("dit",     0b01_1010_0000_0010),
+    ]),
+    ("CONTROL_REGS", &[
+        ("c0",  0),
+        ("c1",  1),
+        ("c2",  2),
+        ("c3",  3),
+        ("c4",  4),
+        ("c5",  5),
+        ("c6",  6),
+        ("c7",  7),
+        ("c8",  8),
+        ("c9",  9),
+        ("c10", 10),
+        ("c11", 11),
+        ("c12", 12),
+        ("c13", 13),
+        ("c14", 14),
+        ("c15", 15),
+    ]),
+    ("SVE_PATTERNS", &[
+        ("pow2",  0b00000),
+        ("vl1",   0b00001),
+        ("vl2",   0b00010),
+        ("vl3",   0b00011),
+        ("vl4",   0b00100),
+        ("vl5",   0b00101),
+        ("vl6",   0b00110),
+        ("vl7",   0b00111),
+        ("vl8",   0b01000),
+        ("vl16",  0b01001),
+        ("vl32",  0b01010),
+        ("vl64",  0b01011),
+        ("vl128", 0b01100),
+        ("vl256", 0b01101),
+        ("mul4",  0b11101),
+        ("mul3",  0b11110),
+        ("all",   0b11111),
+    ]),
+    ("TLBI_OPS", &[
+        ("vmalle1is",    0b00_0100_0001_1000),
+        ("vae1is",       0b00_0100_0001_1001),
+        ("aside1is",     0b00_0100_0001_1010),
+        ("vaae1is",      0b00_0100_0001_1011),
+        ("vale1is",      0b00_0100_0001_1101),
+        ("vaale1is",     0b00_0100_0001_1111),
+        ("vmalle1",      0b00_0100_0011_1000),
+        ("vae1",         0b00_0100_0011_1001),
+        ("aside1",       0b00_0100_0011_1010),
+        ("vaae1",        0b00_0100_0011_1011),
+        ("vale1",        0b00_0100_0011_1101),
+        ("vaale1",       0b00_0100_0011_1111),
+        ("ipas2e1is",    0b10_0100_0000_0001),
+        ("ipas2le1is",   0b10_0100_0000_0101),
+        ("alle2is",      0b10_0100_0001_1000),
+        ("vae2is",       0b10_0100_0001_1001),
+        ("alle1is",      0b10_0100_0001_1100),
+        ("vale2is",      0b10_0100_0001_1101),
+        ("vmalls12e1is", 0b10_0100_0001_1110),
+        ("ipas2e1",      0b10_0100_0010_0001),
+        ("ipas2le1",     0b10_0100_0010_0101),
+        ("alle2",        0b10_0100_0011_1000),
+        ("vae2",         0b10_0100_0011_1001),
+        ("alle1",        0b10_0100_0011_1100),
+        ("vale2",        0b10_0100_0011_1101),
+        ("vmalls12e1",   0b10_0100_0011_1110),
+        ("alle3is",      0b11_0100_0001_1000),
+        ("vae3is",       0b11_0100_0001_1001),
+        ("vale3is",      0b11_0100_0001_1101),
+        ("alle3",        0b11_0100_0011_1000),
+        ("vae3",         0b11_0100_0011_1001),
+        ("vale3",        0b11_0100_0011_1101),
+        ("vmalle1os",    0b00_0100_0000_1000),
+        ("vae1os",       0b00_0100_0000_1001),
+        ("aside1os",     0b00_0100_0000_1010),
+        ("vaae1os",      0b00_0100_0000_1011),
+        ("vale1os",      0b00_0100_0000_1101),
+        ("vaale1os",     0b00_0100_0000_1111),
+        ("rvae1is",      0b00_0100_0001_0001),
+        ("rvaae1is",     0b00_0100_0001_0011),
+        ("rvale1is",     0b00_0100_0001_0101),
+        ("rvaale1is",    0b00_0100_0001_0111),
+        ("rvae1os",      0b00_0100_0010_1001),
+        ("rvaae1os",     0b00_0100_0010_1011),
+        ("rvale1os",     0b00_0100_0010_1101),
+        ("rvaale1os",    0b00_0100_0010_1111),
+        ("rvae1",        0b00_0100_0011_0001),
+        ("rvaae1",       0b00_0100_0011_0011),
+        ("rvale1",       0b00_0100_0011_0101),
+        ("rvaale1",      0b00_0100_0011_0111),
+        ("ripas2e1is",   0b10_0100_0000_0010),
+        ("ripas2le1is",  0b10_0100_0000_0110),
+        ("alle2os",      0b10_0100_0000_1000),
+        ("vae2os",       0b10_0100_0000_1001),
+        ("alle1os",      0b10_0100_0000_1100),
+        ("vale2os",      0b10_0100_0000_1101),
+        ("vmalls12e1os", 0b10_0100_0000_1110),
+        ("rvae2is",      0b10_0100_0001_0001),
+        ("rvale2is",     0b10_0100_0001_0101),
+        ("ipas2e1os",    0b10_0100_0010_0000),
+        ("ripas2e1",     0b10_0100_0010_0010),
+        ("ripas2e1os",   0b10_0100_0010_0011),
+        ("ipas2le1os",   0b10_0100_0010_0100),
+        ("ripas2le1",    0b10_0100_0010_0110),
+        ("ripas2le1os",  0b10_0100_0010_0111),
+        ("rvae2os",      0b10_0100_0010_1001),
+        ("rvale2os",     0b10_0100_0010_1101),
+        ("rvae2",        0b10_0100_0011_0001),
+        ("rvale2",       0b10_0100_0011_0101),
+        ("alle3os",      0b11_0100_0000_1000),
+        ("vae3os",       0b11_0100_0000_1001),
+        ("vale3os",      0b11_0100_0000_1101),
+        ("rvae3is",      0b11_0100_0001_0001),
+        ("rvale3is",     0b11_0100_0001_0101),
+        ("rvae3os",      0b11_0100_0010_1001),
+        ("rvale3os",     0b11_0100_0010_1101),
+        ("rvae3",        0b11_0100_0011_0001),
+        ("rvale3",       0b11_0100_0011_0101),
+    ]),
+]
+    }
+}
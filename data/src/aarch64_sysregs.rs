@@ -0,0 +1,1007 @@
+// This file was generated by tools/aarch64_gen_sysregs.py
+
+/// The named aarch64 system registers, as an array of `(name, o0:op1:CRn:CRm:op2 encoding, access)`.
+#[macro_export]
+macro_rules! aarch64_sysregs {
+    () => {
+[
+    ("accdata_el1",           0b100_0110_1000_0101, ReadWrite),
+    ("actlr_el1",             0b100_0000_1000_0001, ReadWrite),
+    ("actlr_el2",             0b110_0000_1000_0001, ReadWrite),
+    ("actlr_el3",             0b111_0000_1000_0001, ReadWrite),
+    ("afsr0_el1",             0b100_0010_1000_1000, ReadWrite),
+    ("afsr0_el12",            0b110_1010_1000_1000, ReadWrite),
+    ("afsr0_el2",             0b110_0010_1000_1000, ReadWrite),
+    ("afsr0_el3",             0b111_0010_1000_1000, ReadWrite),
+    ("afsr1_el1",             0b100_0010_1000_1001, ReadWrite),
+    ("afsr1_el12",            0b110_1010_1000_1001, ReadWrite),
+    ("afsr1_el2",             0b110_0010_1000_1001, ReadWrite),
+    ("afsr1_el3",             0b111_0010_1000_1001, ReadWrite),
+    ("aidr_el1",              0b100_1000_0000_0111, Read),
+    ("amair_el1",             0b100_0101_0001_1000, ReadWrite),
+    ("amair_el12",            0b110_1101_0001_1000, ReadWrite),
+    ("amair_el2",             0b110_0101_0001_1000, ReadWrite),
+    ("amair_el3",             0b111_0101_0001_1000, ReadWrite),
+    ("amcfgr_el0",            0b101_1110_1001_0001, Read),
+    ("amcgcr_el0",            0b101_1110_1001_0010, Read),
+    ("amcntenclr0_el0",       0b101_1110_1001_0100, ReadWrite),
+    ("amcntenclr1_el0",       0b101_1110_1001_1000, ReadWrite),
+    ("amcntenset0_el0",       0b101_1110_1001_0101, ReadWrite),
+    ("amcntenset1_el0",       0b101_1110_1001_1001, ReadWrite),
+    ("amcr_el0",              0b101_1110_1001_0000, ReadWrite),
+    ("amevcntr00_el0",        0b101_1110_1010_0000, ReadWrite),
+    ("amevcntr01_el0",        0b101_1110_1010_0001, ReadWrite),
+    ("amevcntr02_el0",        0b101_1110_1010_0010, ReadWrite),
+    ("amevcntr03_el0",        0b101_1110_1010_0011, ReadWrite),
+    ("amevcntr10_el0",        0b101_1110_1110_0000, ReadWrite),
+    ("amevcntr110_el0",       0b101_1110_1110_1010, ReadWrite),
+    ("amevcntr111_el0",       0b101_1110_1110_1011, ReadWrite),
+    ("amevcntr112_el0",       0b101_1110_1110_1100, ReadWrite),
+    ("amevcntr113_el0",       0b101_1110_1110_1101, ReadWrite),
+    ("amevcntr114_el0",       0b101_1110_1110_1110, ReadWrite),
+    ("amevcntr115_el0",       0b101_1110_1110_1111, ReadWrite),
+    ("amevcntr11_el0",        0b101_1110_1110_0001, ReadWrite),
+    ("amevcntr12_el0",        0b101_1110_1110_0010, ReadWrite),
+    ("amevcntr13_el0",        0b101_1110_1110_0011, ReadWrite),
+    ("amevcntr14_el0",        0b101_1110_1110_0100, ReadWrite),
+    ("amevcntr15_el0",        0b101_1110_1110_0101, ReadWrite),
+    ("amevcntr16_el0",        0b101_1110_1110_0110, ReadWrite),
+    ("amevcntr17_el0",        0b101_1110_1110_0111, ReadWrite),
+    ("amevcntr18_el0",        0b101_1110_1110_1000, ReadWrite),
+    ("amevcntr19_el0",        0b101_1110_1110_1001, ReadWrite),
+    ("amevcntvoff00_el2",     0b110_0110_1100_0000, ReadWrite),
+    ("amevcntvoff010_el2",    0b110_0110_1100_1010, ReadWrite),
+    ("amevcntvoff011_el2",    0b110_0110_1100_1011, ReadWrite),
+    ("amevcntvoff012_el2",    0b110_0110_1100_1100, ReadWrite),
+    ("amevcntvoff013_el2",    0b110_0110_1100_1101, ReadWrite),
+    ("amevcntvoff014_el2",    0b110_0110_1100_1110, ReadWrite),
+    ("amevcntvoff015_el2",    0b110_0110_1100_1111, ReadWrite),
+    ("amevcntvoff01_el2",     0b110_0110_1100_0001, ReadWrite),
+    ("amevcntvoff02_el2",     0b110_0110_1100_0010, ReadWrite),
+    ("amevcntvoff03_el2",     0b110_0110_1100_0011, ReadWrite),
+    ("amevcntvoff04_el2",     0b110_0110_1100_0100, ReadWrite),
+    ("amevcntvoff05_el2",     0b110_0110_1100_0101, ReadWrite),
+    ("amevcntvoff06_el2",     0b110_0110_1100_0110, ReadWrite),
+    ("amevcntvoff07_el2",     0b110_0110_1100_0111, ReadWrite),
+    ("amevcntvoff08_el2",     0b110_0110_1100_1000, ReadWrite),
+    ("amevcntvoff09_el2",     0b110_0110_1100_1001, ReadWrite),
+    ("amevcntvoff10_el2",     0b110_0110_1101_0000, ReadWrite),
+    ("amevcntvoff110_el2",    0b110_0110_1101_1010, ReadWrite),
+    ("amevcntvoff111_el2",    0b110_0110_1101_1011, ReadWrite),
+    ("amevcntvoff112_el2",    0b110_0110_1101_1100, ReadWrite),
+    ("amevcntvoff113_el2",    0b110_0110_1101_1101, ReadWrite),
+    ("amevcntvoff114_el2",    0b110_0110_1101_1110, ReadWrite),
+    ("amevcntvoff115_el2",    0b110_0110_1101_1111, ReadWrite),
+    ("amevcntvoff11_el2",     0b110_0110_1101_0001, ReadWrite),
+    ("amevcntvoff12_el2",     0b110_0110_1101_0010, ReadWrite),
+    ("amevcntvoff13_el2",     0b110_0110_1101_0011, ReadWrite),
+    ("amevcntvoff14_el2",     0b110_0110_1101_0100, ReadWrite),
+    ("amevcntvoff15_el2",     0b110_0110_1101_0101, ReadWrite),
+    ("amevcntvoff16_el2",     0b110_0110_1101_0110, ReadWrite),
+    ("amevcntvoff17_el2",     0b110_0110_1101_0111, ReadWrite),
+    ("amevcntvoff18_el2",     0b110_0110_1101_1000, ReadWrite),
+    ("amevcntvoff19_el2",     0b110_0110_1101_1001, ReadWrite),
+    ("amevtyper00_el0",       0b101_1110_1011_0000, Read),
+    ("amevtyper01_el0",       0b101_1110_1011_0001, Read),
+    ("amevtyper02_el0",       0b101_1110_1011_0010, Read),
+    ("amevtyper03_el0",       0b101_1110_1011_0011, Read),
+    ("amevtyper10_el0",       0b101_1110_1111_0000, ReadWrite),
+    ("amevtyper110_el0",      0b101_1110_1111_1010, ReadWrite),
+    ("amevtyper111_el0",      0b101_1110_1111_1011, ReadWrite),
+    ("amevtyper112_el0",      0b101_1110_1111_1100, ReadWrite),
+    ("amevtyper113_el0",      0b101_1110_1111_1101, ReadWrite),
+    ("amevtyper114_el0",      0b101_1110_1111_1110, ReadWrite),
+    ("amevtyper115_el0",      0b101_1110_1111_1111, ReadWrite),
+    ("amevtyper11_el0",       0b101_1110_1111_0001, ReadWrite),
+    ("amevtyper12_el0",       0b101_1110_1111_0010, ReadWrite),
+    ("amevtyper13_el0",       0b101_1110_1111_0011, ReadWrite),
+    ("amevtyper14_el0",       0b101_1110_1111_0100, ReadWrite),
+    ("amevtyper15_el0",       0b101_1110_1111_0101, ReadWrite),
+    ("amevtyper16_el0",       0b101_1110_1111_0110, ReadWrite),
+    ("amevtyper17_el0",       0b101_1110_1111_0111, ReadWrite),
+    ("amevtyper18_el0",       0b101_1110_1111_1000, ReadWrite),
+    ("amevtyper19_el0",       0b101_1110_1111_1001, ReadWrite),
+    ("amuserenr_el0",         0b101_1110_1001_0011, ReadWrite),
+    ("apdakeyhi_el1",         0b100_0001_0001_0001, ReadWrite),
+    ("apdakeylo_el1",         0b100_0001_0001_0000, ReadWrite),
+    ("apdbkeyhi_el1",         0b100_0001_0001_0011, ReadWrite),
+    ("apdbkeylo_el1",         0b100_0001_0001_0010, ReadWrite),
+    ("apgakeyhi_el1",         0b100_0001_0001_1001, ReadWrite),
+    ("apgakeylo_el1",         0b100_0001_0001_1000, ReadWrite),
+    ("apiakeyhi_el1",         0b100_0001_0000_1001, ReadWrite),
+    ("apiakeylo_el1",         0b100_0001_0000_1000, ReadWrite),
+    ("apibkeyhi_el1",         0b100_0001_0000_1011, ReadWrite),
+    ("apibkeylo_el1",         0b100_0001_0000_1010, ReadWrite),
+    ("brbcr_el1",             0b000_1100_1000_0000, ReadWrite),
+    ("brbcr_el12",            0b010_1100_1000_0000, ReadWrite),
+    ("brbcr_el2",             0b010_0100_1000_0000, ReadWrite),
+    ("brbfcr_el1",            0b000_1100_1000_0001, ReadWrite),
+    ("brbidr0_el1",           0b000_1100_1001_0000, Read),
+    ("brbinf0_el1",           0b000_1100_0000_0000, Read),
+    ("brbinf10_el1",          0b000_1100_0101_0000, Read),
+    ("brbinf11_el1",          0b000_1100_0101_1000, Read),
+    ("brbinf12_el1",          0b000_1100_0110_0000, Read),
+    ("brbinf13_el1",          0b000_1100_0110_1000, Read),
+    ("brbinf14_el1",          0b000_1100_0111_0000, Read),
+    ("brbinf15_el1",          0b000_1100_0111_1000, Read),
+    ("brbinf16_el1",          0b000_1100_0000_0100, Read),
+    ("brbinf17_el1",          0b000_1100_0000_1100, Read),
+    ("brbinf18_el1",          0b000_1100_0001_0100, Read),
+    ("brbinf19_el1",          0b000_1100_0001_1100, Read),
+    ("brbinf1_el1",           0b000_1100_0000_1000, Read),
+    ("brbinf20_el1",          0b000_1100_0010_0100, Read),
+    ("brbinf21_el1",          0b000_1100_0010_1100, Read),
+    ("brbinf22_el1",          0b000_1100_0011_0100, Read),
+    ("brbinf23_el1",          0b000_1100_0011_1100, Read),
+    ("brbinf24_el1",          0b000_1100_0100_0100, Read),
+    ("brbinf25_el1",          0b000_1100_0100_1100, Read),
+    ("brbinf26_el1",          0b000_1100_0101_0100, Read),
+    ("brbinf27_el1",          0b000_1100_0101_1100, Read),
+    ("brbinf28_el1",          0b000_1100_0110_0100, Read),
+    ("brbinf29_el1",          0b000_1100_0110_1100, Read),
+    ("brbinf2_el1",           0b000_1100_0001_0000, Read),
+    ("brbinf30_el1",          0b000_1100_0111_0100, Read),
+    ("brbinf31_el1",          0b000_1100_0111_1100, Read),
+    ("brbinf3_el1",           0b000_1100_0001_1000, Read),
+    ("brbinf4_el1",           0b000_1100_0010_0000, Read),
+    ("brbinf5_el1",           0b000_1100_0010_1000, Read),
+    ("brbinf6_el1",           0b000_1100_0011_0000, Read),
+    ("brbinf7_el1",           0b000_1100_0011_1000, Read),
+    ("brbinf8_el1",           0b000_1100_0100_0000, Read),
+    ("brbinf9_el1",           0b000_1100_0100_1000, Read),
+    ("brbinfinj_el1",         0b000_1100_1000_1000, ReadWrite),
+    ("brbsrc0_el1",           0b000_1100_0000_0001, Read),
+    ("brbsrc10_el1",          0b000_1100_0101_0001, Read),
+    ("brbsrc11_el1",          0b000_1100_0101_1001, Read),
+    ("brbsrc12_el1",          0b000_1100_0110_0001, Read),
+    ("brbsrc13_el1",          0b000_1100_0110_1001, Read),
+    ("brbsrc14_el1",          0b000_1100_0111_0001, Read),
+    ("brbsrc15_el1",          0b000_1100_0111_1001, Read),
+    ("brbsrc16_el1",          0b000_1100_0000_0101, Read),
+    ("brbsrc17_el1",          0b000_1100_0000_1101, Read),
+    ("brbsrc18_el1",          0b000_1100_0001_0101, Read),
+    ("brbsrc19_el1",          0b000_1100_0001_1101, Read),
+    ("brbsrc1_el1",           0b000_1100_0000_1001, Read),
+    ("brbsrc20_el1",          0b000_1100_0010_0101, Read),
+    ("brbsrc21_el1",          0b000_1100_0010_1101, Read),
+    ("brbsrc22_el1",          0b000_1100_0011_0101, Read),
+    ("brbsrc23_el1",          0b000_1100_0011_1101, Read),
+    ("brbsrc24_el1",          0b000_1100_0100_0101, Read),
+    ("brbsrc25_el1",          0b000_1100_0100_1101, Read),
+    ("brbsrc26_el1",          0b000_1100_0101_0101, Read),
+    ("brbsrc27_el1",          0b000_1100_0101_1101, Read),
+    ("brbsrc28_el1",          0b000_1100_0110_0101, Read),
+    ("brbsrc29_el1",          0b000_1100_0110_1101, Read),
+    ("brbsrc2_el1",           0b000_1100_0001_0001, Read),
+    ("brbsrc30_el1",          0b000_1100_0111_0101, Read),
+    ("brbsrc31_el1",          0b000_1100_0111_1101, Read),
+    ("brbsrc3_el1",           0b000_1100_0001_1001, Read),
+    ("brbsrc4_el1",           0b000_1100_0010_0001, Read),
+    ("brbsrc5_el1",           0b000_1100_0010_1001, Read),
+    ("brbsrc6_el1",           0b000_1100_0011_0001, Read),
+    ("brbsrc7_el1",           0b000_1100_0011_1001, Read),
+    ("brbsrc8_el1",           0b000_1100_0100_0001, Read),
+    ("brbsrc9_el1",           0b000_1100_0100_1001, Read),
+    ("brbsrcinj_el1",         0b000_1100_1000_1001, ReadWrite),
+    ("brbtgt0_el1",           0b000_1100_0000_0010, Read),
+    ("brbtgt10_el1",          0b000_1100_0101_0010, Read),
+    ("brbtgt11_el1",          0b000_1100_0101_1010, Read),
+    ("brbtgt12_el1",          0b000_1100_0110_0010, Read),
+    ("brbtgt13_el1",          0b000_1100_0110_1010, Read),
+    ("brbtgt14_el1",          0b000_1100_0111_0010, Read),
+    ("brbtgt15_el1",          0b000_1100_0111_1010, Read),
+    ("brbtgt16_el1",          0b000_1100_0000_0110, Read),
+    ("brbtgt17_el1",          0b000_1100_0000_1110, Read),
+    ("brbtgt18_el1",          0b000_1100_0001_0110, Read),
+    ("brbtgt19_el1",          0b000_1100_0001_1110, Read),
+    ("brbtgt1_el1",           0b000_1100_0000_1010, Read),
+    ("brbtgt20_el1",          0b000_1100_0010_0110, Read),
+    ("brbtgt21_el1",          0b000_1100_0010_1110, Read),
+    ("brbtgt22_el1",          0b000_1100_0011_0110, Read),
+    ("brbtgt23_el1",          0b000_1100_0011_1110, Read),
+    ("brbtgt24_el1",          0b000_1100_0100_0110, Read),
+    ("brbtgt25_el1",          0b000_1100_0100_1110, Read),
+    ("brbtgt26_el1",          0b000_1100_0101_0110, Read),
+    ("brbtgt27_el1",          0b000_1100_0101_1110, Read),
+    ("brbtgt28_el1",          0b000_1100_0110_0110, Read),
+    ("brbtgt29_el1",          0b000_1100_0110_1110, Read),
+    ("brbtgt2_el1",           0b000_1100_0001_0010, Read),
+    ("brbtgt30_el1",          0b000_1100_0111_0110, Read),
+    ("brbtgt31_el1",          0b000_1100_0111_1110, Read),
+    ("brbtgt3_el1",           0b000_1100_0001_1010, Read),
+    ("brbtgt4_el1",           0b000_1100_0010_0010, Read),
+    ("brbtgt5_el1",           0b000_1100_0010_1010, Read),
+    ("brbtgt6_el1",           0b000_1100_0011_0010, Read),
+    ("brbtgt7_el1",           0b000_1100_0011_1010, Read),
+    ("brbtgt8_el1",           0b000_1100_0100_0010, Read),
+    ("brbtgt9_el1",           0b000_1100_0100_1010, Read),
+    ("brbtgtinj_el1",         0b000_1100_1000_1010, ReadWrite),
+    ("brbts_el1",             0b000_1100_1000_0010, ReadWrite),
+    ("ccsidr2_el1",           0b100_1000_0000_0010, Read),
+    ("ccsidr_el1",            0b100_1000_0000_0000, Read),
+    ("clidr_el1",             0b100_1000_0000_0001, Read),
+    ("cntfrq_el0",            0b101_1111_0000_0000, ReadWrite),
+    ("cnthctl_el2",           0b110_0111_0000_1000, ReadWrite),
+    ("cnthp_ctl_el2",         0b110_0111_0001_0001, ReadWrite),
+    ("cnthp_cval_el2",        0b110_0111_0001_0010, ReadWrite),
+    ("cnthp_tval_el2",        0b110_0111_0001_0000, ReadWrite),
+    ("cnthps_ctl_el2",        0b110_0111_0010_1001, ReadWrite),
+    ("cnthps_cval_el2",       0b110_0111_0010_1010, ReadWrite),
+    ("cnthps_tval_el2",       0b110_0111_0010_1000, ReadWrite),
+    ("cnthv_ctl_el2",         0b110_0111_0001_1001, ReadWrite),
+    ("cnthv_cval_el2",        0b110_0111_0001_1010, ReadWrite),
+    ("cnthv_tval_el2",        0b110_0111_0001_1000, ReadWrite),
+    ("cnthvs_ctl_el2",        0b110_0111_0010_0001, ReadWrite),
+    ("cnthvs_cval_el2",       0b110_0111_0010_0010, ReadWrite),
+    ("cnthvs_tval_el2",       0b110_0111_0010_0000, ReadWrite),
+    ("cntiscale_el2",         0b110_0111_0000_0101, ReadWrite),
+    ("cntkctl_el1",           0b100_0111_0000_1000, ReadWrite),
+    ("cntkctl_el12",          0b110_1111_0000_1000, ReadWrite),
+    ("cntp_ctl_el0",          0b101_1111_0001_0001, ReadWrite),
+    ("cntp_ctl_el02",         0b110_1111_0001_0001, ReadWrite),
+    ("cntp_cval_el0",         0b101_1111_0001_0010, ReadWrite),
+    ("cntp_cval_el02",        0b110_1111_0001_0010, ReadWrite),
+    ("cntp_tval_el0",         0b101_1111_0001_0000, ReadWrite),
+    ("cntp_tval_el02",        0b110_1111_0001_0000, ReadWrite),
+    ("cntpct_el0",            0b101_1111_0000_0001, Read),
+    ("cntpctss_el0",          0b101_1111_0000_0101, Read),
+    ("cntpoff_el2",           0b110_0111_0000_0110, ReadWrite),
+    ("cntps_ctl_el1",         0b111_1111_0001_0001, ReadWrite),
+    ("cntps_cval_el1",        0b111_1111_0001_0010, ReadWrite),
+    ("cntps_tval_el1",        0b111_1111_0001_0000, ReadWrite),
+    ("cntscale_el2",          0b110_0111_0000_0100, ReadWrite),
+    ("cntv_ctl_el0",          0b101_1111_0001_1001, ReadWrite),
+    ("cntv_ctl_el02",         0b110_1111_0001_1001, ReadWrite),
+    ("cntv_cval_el0",         0b101_1111_0001_1010, ReadWrite),
+    ("cntv_cval_el02",        0b110_1111_0001_1010, ReadWrite),
+    ("cntv_tval_el0",         0b101_1111_0001_1000, ReadWrite),
+    ("cntv_tval_el02",        0b110_1111_0001_1000, ReadWrite),
+    ("cntvct_el0",            0b101_1111_0000_0010, Read),
+    ("cntvctss_el0",          0b101_1111_0000_0110, Read),
+    ("cntvfrq_el2",           0b110_0111_0000_0111, ReadWrite),
+    ("cntvoff_el2",           0b110_0111_0000_0011, ReadWrite),
+    ("contextidr_el1",        0b100_0110_1000_0001, ReadWrite),
+    ("contextidr_el12",       0b110_1110_1000_0001, ReadWrite),
+    ("contextidr_el2",        0b110_0110_1000_0001, ReadWrite),
+    ("cpacr_el1",             0b100_0000_1000_0010, ReadWrite),
+    ("cpacr_el12",            0b110_1000_1000_0010, ReadWrite),
+    ("cptr_el2",              0b110_0000_1000_1010, ReadWrite),
+    ("cptr_el3",              0b111_0000_1000_1010, ReadWrite),
+    ("csselr_el1",            0b101_0000_0000_0000, ReadWrite),
+    ("ctr_el0",               0b101_1000_0000_0001, Read),
+    ("currentel",             0b100_0010_0001_0010, Read),
+    ("dacr32_el2",            0b110_0001_1000_0000, ReadWrite),
+    ("daif",                  0b101_1010_0001_0001, ReadWrite),
+    ("dbgauthstatus_el1",     0b000_0011_1111_0110, Read),
+    ("dbgbcr0_el1",           0b000_0000_0000_0101, ReadWrite),
+    ("dbgbcr10_el1",          0b000_0000_0101_0101, ReadWrite),
+    ("dbgbcr11_el1",          0b000_0000_0101_1101, ReadWrite),
+    ("dbgbcr12_el1",          0b000_0000_0110_0101, ReadWrite),
+    ("dbgbcr13_el1",          0b000_0000_0110_1101, ReadWrite),
+    ("dbgbcr14_el1",          0b000_0000_0111_0101, ReadWrite),
+    ("dbgbcr15_el1",          0b000_0000_0111_1101, ReadWrite),
+    ("dbgbcr1_el1",           0b000_0000_0000_1101, ReadWrite),
+    ("dbgbcr2_el1",           0b000_0000_0001_0101, ReadWrite),
+    ("dbgbcr3_el1",           0b000_0000_0001_1101, ReadWrite),
+    ("dbgbcr4_el1",           0b000_0000_0010_0101, ReadWrite),
+    ("dbgbcr5_el1",           0b000_0000_0010_1101, ReadWrite),
+    ("dbgbcr6_el1",           0b000_0000_0011_0101, ReadWrite),
+    ("dbgbcr7_el1",           0b000_0000_0011_1101, ReadWrite),
+    ("dbgbcr8_el1",           0b000_0000_0100_0101, ReadWrite),
+    ("dbgbcr9_el1",           0b000_0000_0100_1101, ReadWrite),
+    ("dbgbvr0_el1",           0b000_0000_0000_0100, ReadWrite),
+    ("dbgbvr10_el1",          0b000_0000_0101_0100, ReadWrite),
+    ("dbgbvr11_el1",          0b000_0000_0101_1100, ReadWrite),
+    ("dbgbvr12_el1",          0b000_0000_0110_0100, ReadWrite),
+    ("dbgbvr13_el1",          0b000_0000_0110_1100, ReadWrite),
+    ("dbgbvr14_el1",          0b000_0000_0111_0100, ReadWrite),
+    ("dbgbvr15_el1",          0b000_0000_0111_1100, ReadWrite),
+    ("dbgbvr1_el1",           0b000_0000_0000_1100, ReadWrite),
+    ("dbgbvr2_el1",           0b000_0000_0001_0100, ReadWrite),
+    ("dbgbvr3_el1",           0b000_0000_0001_1100, ReadWrite),
+    ("dbgbvr4_el1",           0b000_0000_0010_0100, ReadWrite),
+    ("dbgbvr5_el1",           0b000_0000_0010_1100, ReadWrite),
+    ("dbgbvr6_el1",           0b000_0000_0011_0100, ReadWrite),
+    ("dbgbvr7_el1",           0b000_0000_0011_1100, ReadWrite),
+    ("dbgbvr8_el1",           0b000_0000_0100_0100, ReadWrite),
+    ("dbgbvr9_el1",           0b000_0000_0100_1100, ReadWrite),
+    ("dbgclaimclr_el1",       0b000_0011_1100_1110, ReadWrite),
+    ("dbgclaimset_el1",       0b000_0011_1100_0110, ReadWrite),
+    ("dbgdtr_el0",            0b001_1000_0010_0000, ReadWrite),
+    ("dbgdtrrx_el0",          0b001_1000_0010_1000, Read),
+    ("dbgdtrtx_el0",          0b001_1000_0010_1000, Write),
+    ("dbgprcr_el1",           0b000_0000_1010_0100, ReadWrite),
+    ("dbgvcr32_el2",          0b010_0000_0011_1000, ReadWrite),
+    ("dbgwcr0_el1",           0b000_0000_0000_0111, ReadWrite),
+    ("dbgwcr10_el1",          0b000_0000_0101_0111, ReadWrite),
+    ("dbgwcr11_el1",          0b000_0000_0101_1111, ReadWrite),
+    ("dbgwcr12_el1",          0b000_0000_0110_0111, ReadWrite),
+    ("dbgwcr13_el1",          0b000_0000_0110_1111, ReadWrite),
+    ("dbgwcr14_el1",          0b000_0000_0111_0111, ReadWrite),
+    ("dbgwcr15_el1",          0b000_0000_0111_1111, ReadWrite),
+    ("dbgwcr1_el1",           0b000_0000_0000_1111, ReadWrite),
+    ("dbgwcr2_el1",           0b000_0000_0001_0111, ReadWrite),
+    ("dbgwcr3_el1",           0b000_0000_0001_1111, ReadWrite),
+    ("dbgwcr4_el1",           0b000_0000_0010_0111, ReadWrite),
+    ("dbgwcr5_el1",           0b000_0000_0010_1111, ReadWrite),
+    ("dbgwcr6_el1",           0b000_0000_0011_0111, ReadWrite),
+    ("dbgwcr7_el1",           0b000_0000_0011_1111, ReadWrite),
+    ("dbgwcr8_el1",           0b000_0000_0100_0111, ReadWrite),
+    ("dbgwcr9_el1",           0b000_0000_0100_1111, ReadWrite),
+    ("dbgwvr0_el1",           0b000_0000_0000_0110, ReadWrite),
+    ("dbgwvr10_el1",          0b000_0000_0101_0110, ReadWrite),
+    ("dbgwvr11_el1",          0b000_0000_0101_1110, ReadWrite),
+    ("dbgwvr12_el1",          0b000_0000_0110_0110, ReadWrite),
+    ("dbgwvr13_el1",          0b000_0000_0110_1110, ReadWrite),
+    ("dbgwvr14_el1",          0b000_0000_0111_0110, ReadWrite),
+    ("dbgwvr15_el1",          0b000_0000_0111_1110, ReadWrite),
+    ("dbgwvr1_el1",           0b000_0000_0000_1110, ReadWrite),
+    ("dbgwvr2_el1",           0b000_0000_0001_0110, ReadWrite),
+    ("dbgwvr3_el1",           0b000_0000_0001_1110, ReadWrite),
+    ("dbgwvr4_el1",           0b000_0000_0010_0110, ReadWrite),
+    ("dbgwvr5_el1",           0b000_0000_0010_1110, ReadWrite),
+    ("dbgwvr6_el1",           0b000_0000_0011_0110, ReadWrite),
+    ("dbgwvr7_el1",           0b000_0000_0011_1110, ReadWrite),
+    ("dbgwvr8_el1",           0b000_0000_0100_0110, ReadWrite),
+    ("dbgwvr9_el1",           0b000_0000_0100_1110, ReadWrite),
+    ("dczid_el0",             0b101_1000_0000_0111, Read),
+    ("disr_el1",              0b100_0110_0000_1001, ReadWrite),
+    ("dit",                   0b101_1010_0001_0101, ReadWrite),
+    ("dlr_el0",               0b101_1010_0010_1001, ReadWrite),
+    ("dspsr_el0",             0b101_1010_0010_1000, ReadWrite),
+    ("elr_el1",               0b100_0010_0000_0001, ReadWrite),
+    ("elr_el12",              0b110_1010_0000_0001, ReadWrite),
+    ("elr_el2",               0b110_0010_0000_0001, ReadWrite),
+    ("elr_el3",               0b111_0010_0000_0001, ReadWrite),
+    ("erridr_el1",            0b100_0010_1001_1000, Read),
+    ("errselr_el1",           0b100_0010_1001_1001, ReadWrite),
+    ("erxaddr_el1",           0b100_0010_1010_0011, ReadWrite),
+    ("erxctlr_el1",           0b100_0010_1010_0001, ReadWrite),
+    ("erxfr_el1",             0b100_0010_1010_0000, Read),
+    ("erxmisc0_el1",          0b100_0010_1010_1000, ReadWrite),
+    ("erxmisc1_el1",          0b100_0010_1010_1001, ReadWrite),
+    ("erxmisc2_el1",          0b100_0010_1010_1010, ReadWrite),
+    ("erxmisc3_el1",          0b100_0010_1010_1011, ReadWrite),
+    ("erxpfgcdn_el1",         0b100_0010_1010_0110, ReadWrite),
+    ("erxpfgctl_el1",         0b100_0010_1010_0101, ReadWrite),
+    ("erxpfgf_el1",           0b100_0010_1010_0100, Read),
+    ("erxstatus_el1",         0b100_0010_1010_0010, ReadWrite),
+    ("esr_el1",               0b100_0010_1001_0000, ReadWrite),
+    ("esr_el12",              0b110_1010_1001_0000, ReadWrite),
+    ("esr_el2",               0b110_0010_1001_0000, ReadWrite),
+    ("esr_el3",               0b111_0010_1001_0000, ReadWrite),
+    ("far_el1",               0b100_0011_0000_0000, ReadWrite),
+    ("far_el12",              0b110_1011_0000_0000, ReadWrite),
+    ("far_el2",               0b110_0011_0000_0000, ReadWrite),
+    ("far_el3",               0b111_0011_0000_0000, ReadWrite),
+    ("fpcr",                  0b101_1010_0010_0000, ReadWrite),
+    ("fpexc32_el2",           0b110_0010_1001_1000, ReadWrite),
+    ("fpsr",                  0b101_1010_0010_0001, ReadWrite),
+    ("gcr_el1",               0b100_0000_1000_0110, ReadWrite),
+    ("gmid_el1",              0b100_1000_0000_0100, Read),
+    ("hacr_el2",              0b110_0000_1000_1111, ReadWrite),
+    ("hcr_el2",               0b110_0000_1000_1000, ReadWrite),
+    ("hcrx_el2",              0b110_0000_1001_0010, ReadWrite),
+    ("hdfgrtr_el2",           0b110_0001_1000_1100, ReadWrite),
+    ("hdfgwtr_el2",           0b110_0001_1000_1101, ReadWrite),
+    ("hfgitr_el2",            0b110_0000_1000_1110, ReadWrite),
+    ("hfgrtr_el2",            0b110_0000_1000_1100, ReadWrite),
+    ("hfgwtr_el2",            0b110_0000_1000_1101, ReadWrite),
+    ("hpfar_el2",             0b110_0011_0000_0100, ReadWrite),
+    ("hstr_el2",              0b110_0000_1000_1011, ReadWrite),
+    ("icc_ap0r0_el1",         0b100_0110_0100_0100, ReadWrite),
+    ("icc_ap0r1_el1",         0b100_0110_0100_0101, ReadWrite),
+    ("icc_ap0r2_el1",         0b100_0110_0100_0110, ReadWrite),
+    ("icc_ap0r3_el1",         0b100_0110_0100_0111, ReadWrite),
+    ("icc_ap1r0_el1",         0b100_0110_0100_1000, ReadWrite),
+    ("icc_ap1r1_el1",         0b100_0110_0100_1001, ReadWrite),
+    ("icc_ap1r2_el1",         0b100_0110_0100_1010, ReadWrite),
+    ("icc_ap1r3_el1",         0b100_0110_0100_1011, ReadWrite),
+    ("icc_asgi1r_el1",        0b100_0110_0101_1110, Write),
+    ("icc_bpr0_el1",          0b100_0110_0100_0011, ReadWrite),
+    ("icc_bpr1_el1",          0b100_0110_0110_0011, ReadWrite),
+    ("icc_ctlr_el1",          0b100_0110_0110_0100, ReadWrite),
+    ("icc_ctlr_el3",          0b111_0110_0110_0100, ReadWrite),
+    ("icc_dir_el1",           0b100_0110_0101_1001, Write),
+    ("icc_eoir0_el1",         0b100_0110_0100_0001, Write),
+    ("icc_eoir1_el1",         0b100_0110_0110_0001, Write),
+    ("icc_hppir0_el1",        0b100_0110_0100_0010, Read),
+    ("icc_hppir1_el1",        0b100_0110_0110_0010, Read),
+    ("icc_iar0_el1",          0b100_0110_0100_0000, Read),
+    ("icc_iar1_el1",          0b100_0110_0110_0000, Read),
+    ("icc_igrpen0_el1",       0b100_0110_0110_0110, ReadWrite),
+    ("icc_igrpen1_el1",       0b100_0110_0110_0111, ReadWrite),
+    ("icc_igrpen1_el3",       0b111_0110_0110_0111, ReadWrite),
+    ("icc_pmr_el1",           0b100_0010_0011_0000, ReadWrite),
+    ("icc_rpr_el1",           0b100_0110_0101_1011, Read),
+    ("icc_sgi0r_el1",         0b100_0110_0101_1111, Write),
+    ("icc_sgi1r_el1",         0b100_0110_0101_1101, Write),
+    ("icc_sre_el1",           0b100_0110_0110_0101, ReadWrite),
+    ("icc_sre_el2",           0b110_0110_0100_1101, ReadWrite),
+    ("icc_sre_el3",           0b111_0110_0110_0101, ReadWrite),
+    ("ich_ap0r0_el2",         0b110_0110_0100_0000, ReadWrite),
+    ("ich_ap0r1_el2",         0b110_0110_0100_0001, ReadWrite),
+    ("ich_ap0r2_el2",         0b110_0110_0100_0010, ReadWrite),
+    ("ich_ap0r3_el2",         0b110_0110_0100_0011, ReadWrite),
+    ("ich_ap1r0_el2",         0b110_0110_0100_1000, ReadWrite),
+    ("ich_ap1r1_el2",         0b110_0110_0100_1001, ReadWrite),
+    ("ich_ap1r2_el2",         0b110_0110_0100_1010, ReadWrite),
+    ("ich_ap1r3_el2",         0b110_0110_0100_1011, ReadWrite),
+    ("ich_eisr_el2",          0b110_0110_0101_1011, Read),
+    ("ich_elrsr_el2",         0b110_0110_0101_1101, Read),
+    ("ich_hcr_el2",           0b110_0110_0101_1000, ReadWrite),
+    ("ich_lr0_el2",           0b110_0110_0110_0000, ReadWrite),
+    ("ich_lr10_el2",          0b110_0110_0110_1010, ReadWrite),
+    ("ich_lr11_el2",          0b110_0110_0110_1011, ReadWrite),
+    ("ich_lr12_el2",          0b110_0110_0110_1100, ReadWrite),
+    ("ich_lr13_el2",          0b110_0110_0110_1101, ReadWrite),
+    ("ich_lr14_el2",          0b110_0110_0110_1110, ReadWrite),
+    ("ich_lr15_el2",          0b110_0110_0110_1111, ReadWrite),
+    ("ich_lr1_el2",           0b110_0110_0110_0001, ReadWrite),
+    ("ich_lr2_el2",           0b110_0110_0110_0010, ReadWrite),
+    ("ich_lr3_el2",           0b110_0110_0110_0011, ReadWrite),
+    ("ich_lr4_el2",           0b110_0110_0110_0100, ReadWrite),
+    ("ich_lr5_el2",           0b110_0110_0110_0101, ReadWrite),
+    ("ich_lr6_el2",           0b110_0110_0110_0110, ReadWrite),
+    ("ich_lr7_el2",           0b110_0110_0110_0111, ReadWrite),
+    ("ich_lr8_el2",           0b110_0110_0110_1000, ReadWrite),
+    ("ich_lr9_el2",           0b110_0110_0110_1001, ReadWrite),
+    ("ich_misr_el2",          0b110_0110_0101_1010, Read),
+    ("ich_vmcr_el2",          0b110_0110_0101_1111, ReadWrite),
+    ("ich_vtr_el2",           0b110_0110_0101_1001, Read),
+    ("id_aa64afr0_el1",       0b100_0000_0010_1100, Read),
+    ("id_aa64afr1_el1",       0b100_0000_0010_1101, Read),
+    ("id_aa64dfr0_el1",       0b100_0000_0010_1000, Read),
+    ("id_aa64dfr1_el1",       0b100_0000_0010_1001, Read),
+    ("id_aa64isar0_el1",      0b100_0000_0011_0000, Read),
+    ("id_aa64isar1_el1",      0b100_0000_0011_0001, Read),
+    ("id_aa64isar2_el1",      0b100_0000_0011_0010, Read),
+    ("id_aa64mmfr0_el1",      0b100_0000_0011_1000, Read),
+    ("id_aa64mmfr1_el1",      0b100_0000_0011_1001, Read),
+    ("id_aa64mmfr2_el1",      0b100_0000_0011_1010, Read),
+    ("id_aa64pfr0_el1",       0b100_0000_0010_0000, Read),
+    ("id_aa64pfr1_el1",       0b100_0000_0010_0001, Read),
+    ("id_aa64smfr0_el1",      0b100_0000_0010_0101, Read),
+    ("id_aa64zfr0_el1",       0b100_0000_0010_0100, Read),
+    ("id_afr0_el1",           0b100_0000_0000_1011, Read),
+    ("id_dfr0_el1",           0b100_0000_0000_1010, Read),
+    ("id_isar0_el1",          0b100_0000_0001_0000, Read),
+    ("id_isar1_el1",          0b100_0000_0001_0001, Read),
+    ("id_isar2_el1",          0b100_0000_0001_0010, Read),
+    ("id_isar3_el1",          0b100_0000_0001_0011, Read),
+    ("id_isar4_el1",          0b100_0000_0001_0100, Read),
+    ("id_isar5_el1",          0b100_0000_0001_0101, Read),
+    ("id_isar6_el1",          0b100_0000_0001_0111, Read),
+    ("id_mmfr0_el1",          0b100_0000_0000_1100, Read),
+    ("id_mmfr1_el1",          0b100_0000_0000_1101, Read),
+    ("id_mmfr2_el1",          0b100_0000_0000_1110, Read),
+    ("id_mmfr3_el1",          0b100_0000_0000_1111, Read),
+    ("id_mmfr4_el1",          0b100_0000_0001_0110, Read),
+    ("id_mmfr5_el1",          0b100_0000_0001_1110, Read),
+    ("id_pfr0_el1",           0b100_0000_0000_1000, Read),
+    ("id_pfr1_el1",           0b100_0000_0000_1001, Read),
+    ("id_pfr2_el1",           0b100_0000_0001_1100, Read),
+    ("ifsr32_el2",            0b110_0010_1000_0001, ReadWrite),
+    ("isr_el1",               0b100_0110_0000_1000, Read),
+    ("lorc_el1",              0b100_0101_0010_0011, ReadWrite),
+    ("lorea_el1",             0b100_0101_0010_0001, ReadWrite),
+    ("lorid_el1",             0b100_0101_0010_0111, Read),
+    ("lorn_el1",              0b100_0101_0010_0010, ReadWrite),
+    ("lorsa_el1",             0b100_0101_0010_0000, ReadWrite),
+    ("mair_el1",              0b100_0101_0001_0000, ReadWrite),
+    ("mair_el12",             0b110_1101_0001_0000, ReadWrite),
+    ("mair_el2",              0b110_0101_0001_0000, ReadWrite),
+    ("mair_el3",              0b111_0101_0001_0000, ReadWrite),
+    ("mdccint_el1",           0b000_0000_0001_0000, ReadWrite),
+    ("mdccsr_el0",            0b001_1000_0000_1000, Read),
+    ("mdcr_el2",              0b110_0000_1000_1001, ReadWrite),
+    ("mdcr_el3",              0b111_0000_1001_1001, ReadWrite),
+    ("mdrar_el1",             0b000_0000_1000_0000, Read),
+    ("mdscr_el1",             0b000_0000_0001_0010, ReadWrite),
+    ("midr_el1",              0b100_0000_0000_0000, Read),
+    ("mpam0_el1",             0b100_0101_0010_1001, ReadWrite),
+    ("mpam1_el1",             0b100_0101_0010_1000, ReadWrite),
+    ("mpam1_el12",            0b110_1101_0010_1000, ReadWrite),
+    ("mpam2_el2",             0b110_0101_0010_1000, ReadWrite),
+    ("mpam3_el3",             0b111_0101_0010_1000, ReadWrite),
+    ("mpamhcr_el2",           0b110_0101_0010_0000, ReadWrite),
+    ("mpamidr_el1",           0b100_0101_0010_0100, Read),
+    ("mpamsm_el1",            0b100_0101_0010_1011, ReadWrite),
+    ("mpamvpm0_el2",          0b110_0101_0011_0000, ReadWrite),
+    ("mpamvpm1_el2",          0b110_0101_0011_0001, ReadWrite),
+    ("mpamvpm2_el2",          0b110_0101_0011_0010, ReadWrite),
+    ("mpamvpm3_el2",          0b110_0101_0011_0011, ReadWrite),
+    ("mpamvpm4_el2",          0b110_0101_0011_0100, ReadWrite),
+    ("mpamvpm5_el2",          0b110_0101_0011_0101, ReadWrite),
+    ("mpamvpm6_el2",          0b110_0101_0011_0110, ReadWrite),
+    ("mpamvpm7_el2",          0b110_0101_0011_0111, ReadWrite),
+    ("mpamvpmv_el2",          0b110_0101_0010_0001, ReadWrite),
+    ("mpidr_el1",             0b100_0000_0000_0101, Read),
+    ("mpuir_el1",             0b100_0000_0000_0100, ReadWrite),
+    ("mpuir_el2",             0b110_0000_0000_0100, ReadWrite),
+    ("mvfr0_el1",             0b100_0000_0001_1000, Read),
+    ("mvfr1_el1",             0b100_0000_0001_1001, Read),
+    ("mvfr2_el1",             0b100_0000_0001_1010, Read),
+    ("nzcv",                  0b101_1010_0001_0000, ReadWrite),
+    ("osdlr_el1",             0b000_0000_1001_1100, ReadWrite),
+    ("osdtrrx_el1",           0b000_0000_0000_0010, ReadWrite),
+    ("osdtrtx_el1",           0b000_0000_0001_1010, ReadWrite),
+    ("oseccr_el1",            0b000_0000_0011_0010, ReadWrite),
+    ("oslar_el1",             0b000_0000_1000_0100, Write),
+    ("oslsr_el1",             0b000_0000_1000_1100, Read),
+    ("pan",                   0b100_0010_0001_0011, ReadWrite),
+    ("par_el1",               0b100_0011_1010_0000, ReadWrite),
+    ("pmbidr_el1",            0b100_0100_1101_0111, Read),
+    ("pmblimitr_el1",         0b100_0100_1101_0000, ReadWrite),
+    ("pmbptr_el1",            0b100_0100_1101_0001, ReadWrite),
+    ("pmbsr_el1",             0b100_0100_1101_0011, ReadWrite),
+    ("pmccfiltr_el0",         0b101_1111_0111_1111, ReadWrite),
+    ("pmccntr_el0",           0b101_1100_1110_1000, ReadWrite),
+    ("pmceid0_el0",           0b101_1100_1110_0110, Read),
+    ("pmceid1_el0",           0b101_1100_1110_0111, Read),
+    ("pmcntenclr_el0",        0b101_1100_1110_0010, ReadWrite),
+    ("pmcntenset_el0",        0b101_1100_1110_0001, ReadWrite),
+    ("pmcr_el0",              0b101_1100_1110_0000, ReadWrite),
+    ("pmevcntr0_el0",         0b101_1111_0100_0000, ReadWrite),
+    ("pmevcntr10_el0",        0b101_1111_0100_1010, ReadWrite),
+    ("pmevcntr11_el0",        0b101_1111_0100_1011, ReadWrite),
+    ("pmevcntr12_el0",        0b101_1111_0100_1100, ReadWrite),
+    ("pmevcntr13_el0",        0b101_1111_0100_1101, ReadWrite),
+    ("pmevcntr14_el0",        0b101_1111_0100_1110, ReadWrite),
+    ("pmevcntr15_el0",        0b101_1111_0100_1111, ReadWrite),
+    ("pmevcntr16_el0",        0b101_1111_0101_0000, ReadWrite),
+    ("pmevcntr17_el0",        0b101_1111_0101_0001, ReadWrite),
+    ("pmevcntr18_el0",        0b101_1111_0101_0010, ReadWrite),
+    ("pmevcntr19_el0",        0b101_1111_0101_0011, ReadWrite),
+    ("pmevcntr1_el0",         0b101_1111_0100_0001, ReadWrite),
+    ("pmevcntr20_el0",        0b101_1111_0101_0100, ReadWrite),
+    ("pmevcntr21_el0",        0b101_1111_0101_0101, ReadWrite),
+    ("pmevcntr22_el0",        0b101_1111_0101_0110, ReadWrite),
+    ("pmevcntr23_el0",        0b101_1111_0101_0111, ReadWrite),
+    ("pmevcntr24_el0",        0b101_1111_0101_1000, ReadWrite),
+    ("pmevcntr25_el0",        0b101_1111_0101_1001, ReadWrite),
+    ("pmevcntr26_el0",        0b101_1111_0101_1010, ReadWrite),
+    ("pmevcntr27_el0",        0b101_1111_0101_1011, ReadWrite),
+    ("pmevcntr28_el0",        0b101_1111_0101_1100, ReadWrite),
+    ("pmevcntr29_el0",        0b101_1111_0101_1101, ReadWrite),
+    ("pmevcntr2_el0",         0b101_1111_0100_0010, ReadWrite),
+    ("pmevcntr30_el0",        0b101_1111_0101_1110, ReadWrite),
+    ("pmevcntr3_el0",         0b101_1111_0100_0011, ReadWrite),
+    ("pmevcntr4_el0",         0b101_1111_0100_0100, ReadWrite),
+    ("pmevcntr5_el0",         0b101_1111_0100_0101, ReadWrite),
+    ("pmevcntr6_el0",         0b101_1111_0100_0110, ReadWrite),
+    ("pmevcntr7_el0",         0b101_1111_0100_0111, ReadWrite),
+    ("pmevcntr8_el0",         0b101_1111_0100_1000, ReadWrite),
+    ("pmevcntr9_el0",         0b101_1111_0100_1001, ReadWrite),
+    ("pmevtyper0_el0",        0b101_1111_0110_0000, ReadWrite),
+    ("pmevtyper10_el0",       0b101_1111_0110_1010, ReadWrite),
+    ("pmevtyper11_el0",       0b101_1111_0110_1011, ReadWrite),
+    ("pmevtyper12_el0",       0b101_1111_0110_1100, ReadWrite),
+    ("pmevtyper13_el0",       0b101_1111_0110_1101, ReadWrite),
+    ("pmevtyper14_el0",       0b101_1111_0110_1110, ReadWrite),
+    ("pmevtyper15_el0",       0b101_1111_0110_1111, ReadWrite),
+    ("pmevtyper16_el0",       0b101_1111_0111_0000, ReadWrite),
+    ("pmevtyper17_el0",       0b101_1111_0111_0001, ReadWrite),
+    ("pmevtyper18_el0",       0b101_1111_0111_0010, ReadWrite),
+    ("pmevtyper19_el0",       0b101_1111_0111_0011, ReadWrite),
+    ("pmevtyper1_el0",        0b101_1111_0110_0001, ReadWrite),
+    ("pmevtyper20_el0",       0b101_1111_0111_0100, ReadWrite),
+    ("pmevtyper21_el0",       0b101_1111_0111_0101, ReadWrite),
+    ("pmevtyper22_el0",       0b101_1111_0111_0110, ReadWrite),
+    ("pmevtyper23_el0",       0b101_1111_0111_0111, ReadWrite),
+    ("pmevtyper24_el0",       0b101_1111_0111_1000, ReadWrite),
+    ("pmevtyper25_el0",       0b101_1111_0111_1001, ReadWrite),
+    ("pmevtyper26_el0",       0b101_1111_0111_1010, ReadWrite),
+    ("pmevtyper27_el0",       0b101_1111_0111_1011, ReadWrite),
+    ("pmevtyper28_el0",       0b101_1111_0111_1100, ReadWrite),
+    ("pmevtyper29_el0",       0b101_1111_0111_1101, ReadWrite),
+    ("pmevtyper2_el0",        0b101_1111_0110_0010, ReadWrite),
+    ("pmevtyper30_el0",       0b101_1111_0111_1110, ReadWrite),
+    ("pmevtyper3_el0",        0b101_1111_0110_0011, ReadWrite),
+    ("pmevtyper4_el0",        0b101_1111_0110_0100, ReadWrite),
+    ("pmevtyper5_el0",        0b101_1111_0110_0101, ReadWrite),
+    ("pmevtyper6_el0",        0b101_1111_0110_0110, ReadWrite),
+    ("pmevtyper7_el0",        0b101_1111_0110_0111, ReadWrite),
+    ("pmevtyper8_el0",        0b101_1111_0110_1000, ReadWrite),
+    ("pmevtyper9_el0",        0b101_1111_0110_1001, ReadWrite),
+    ("pmintenclr_el1",        0b100_0100_1111_0010, ReadWrite),
+    ("pmintenset_el1",        0b100_0100_1111_0001, ReadWrite),
+    ("pmmir_el1",             0b100_0100_1111_0110, ReadWrite),
+    ("pmovsclr_el0",          0b101_1100_1110_0011, ReadWrite),
+    ("pmovsset_el0",          0b101_1100_1111_0011, ReadWrite),
+    ("pmscr_el1",             0b100_0100_1100_1000, ReadWrite),
+    ("pmscr_el12",            0b110_1100_1100_1000, ReadWrite),
+    ("pmscr_el2",             0b110_0100_1100_1000, ReadWrite),
+    ("pmselr_el0",            0b101_1100_1110_0101, ReadWrite),
+    ("pmsevfr_el1",           0b100_0100_1100_1101, ReadWrite),
+    ("pmsfcr_el1",            0b100_0100_1100_1100, ReadWrite),
+    ("pmsicr_el1",            0b100_0100_1100_1010, ReadWrite),
+    ("pmsidr_el1",            0b100_0100_1100_1111, Read),
+    ("pmsirr_el1",            0b100_0100_1100_1011, ReadWrite),
+    ("pmslatfr_el1",          0b100_0100_1100_1110, ReadWrite),
+    ("pmsnevfr_el1",          0b100_0100_1100_1001, ReadWrite),
+    ("pmswinc_el0",           0b101_1100_1110_0100, Write),
+    ("pmuserenr_el0",         0b101_1100_1111_0000, ReadWrite),
+    ("pmxevcntr_el0",         0b101_1100_1110_1010, ReadWrite),
+    ("pmxevtyper_el0",        0b101_1100_1110_1001, ReadWrite),
+    ("prbar10_el1",           0b100_0011_0110_1000, ReadWrite),
+    ("prbar10_el2",           0b110_0011_0110_1000, ReadWrite),
+    ("prbar11_el1",           0b100_0011_0110_1100, ReadWrite),
+    ("prbar11_el2",           0b110_0011_0110_1100, ReadWrite),
+    ("prbar12_el1",           0b100_0011_0111_0000, ReadWrite),
+    ("prbar12_el2",           0b110_0011_0111_0000, ReadWrite),
+    ("prbar13_el1",           0b100_0011_0111_0100, ReadWrite),
+    ("prbar13_el2",           0b110_0011_0111_0100, ReadWrite),
+    ("prbar14_el1",           0b100_0011_0111_1000, ReadWrite),
+    ("prbar14_el2",           0b110_0011_0111_1000, ReadWrite),
+    ("prbar15_el1",           0b100_0011_0111_1100, ReadWrite),
+    ("prbar15_el2",           0b110_0011_0111_1100, ReadWrite),
+    ("prbar1_el1",            0b100_0011_0100_0100, ReadWrite),
+    ("prbar1_el2",            0b110_0011_0100_0100, ReadWrite),
+    ("prbar2_el1",            0b100_0011_0100_1000, ReadWrite),
+    ("prbar2_el2",            0b110_0011_0100_1000, ReadWrite),
+    ("prbar3_el1",            0b100_0011_0100_1100, ReadWrite),
+    ("prbar3_el2",            0b110_0011_0100_1100, ReadWrite),
+    ("prbar4_el1",            0b100_0011_0101_0000, ReadWrite),
+    ("prbar4_el2",            0b110_0011_0101_0000, ReadWrite),
+    ("prbar5_el1",            0b100_0011_0101_0100, ReadWrite),
+    ("prbar5_el2",            0b110_0011_0101_0100, ReadWrite),
+    ("prbar6_el1",            0b100_0011_0101_1000, ReadWrite),
+    ("prbar6_el2",            0b110_0011_0101_1000, ReadWrite),
+    ("prbar7_el1",            0b100_0011_0101_1100, ReadWrite),
+    ("prbar7_el2",            0b110_0011_0101_1100, ReadWrite),
+    ("prbar8_el1",            0b100_0011_0110_0000, ReadWrite),
+    ("prbar8_el2",            0b110_0011_0110_0000, ReadWrite),
+    ("prbar9_el1",            0b100_0011_0110_0100, ReadWrite),
+    ("prbar9_el2",            0b110_0011_0110_0100, ReadWrite),
+    ("prbar_el1",             0b100_0011_0100_0000, ReadWrite),
+    ("prbar_el2",             0b110_0011_0100_0000, ReadWrite),
+    ("prenr_el1",             0b100_0011_0000_1001, ReadWrite),
+    ("prenr_el2",             0b110_0011_0000_1001, ReadWrite),
+    ("prlar10_el1",           0b100_0011_0110_1001, ReadWrite),
+    ("prlar10_el2",           0b110_0011_0110_1001, ReadWrite),
+    ("prlar11_el1",           0b100_0011_0110_1101, ReadWrite),
+    ("prlar11_el2",           0b110_0011_0110_1101, ReadWrite),
+    ("prlar12_el1",           0b100_0011_0111_0001, ReadWrite),
+    ("prlar12_el2",           0b110_0011_0111_0001, ReadWrite),
+    ("prlar13_el1",           0b100_0011_0111_0101, ReadWrite),
+    ("prlar13_el2",           0b110_0011_0111_0101, ReadWrite),
+    ("prlar14_el1",           0b100_0011_0111_1001, ReadWrite),
+    ("prlar14_el2",           0b110_0011_0111_1001, ReadWrite),
+    ("prlar15_el1",           0b100_0011_0111_1101, ReadWrite),
+    ("prlar15_el2",           0b110_0011_0111_1101, ReadWrite),
+    ("prlar1_el1",            0b100_0011_0100_0101, ReadWrite),
+    ("prlar1_el2",            0b110_0011_0100_0101, ReadWrite),
+    ("prlar2_el1",            0b100_0011_0100_1001, ReadWrite),
+    ("prlar2_el2",            0b110_0011_0100_1001, ReadWrite),
+    ("prlar3_el1",            0b100_0011_0100_1101, ReadWrite),
+    ("prlar3_el2",            0b110_0011_0100_1101, ReadWrite),
+    ("prlar4_el1",            0b100_0011_0101_0001, ReadWrite),
+    ("prlar4_el2",            0b110_0011_0101_0001, ReadWrite),
+    ("prlar5_el1",            0b100_0011_0101_0101, ReadWrite),
+    ("prlar5_el2",            0b110_0011_0101_0101, ReadWrite),
+    ("prlar6_el1",            0b100_0011_0101_1001, ReadWrite),
+    ("prlar6_el2",            0b110_0011_0101_1001, ReadWrite),
+    ("prlar7_el1",            0b100_0011_0101_1101, ReadWrite),
+    ("prlar7_el2",            0b110_0011_0101_1101, ReadWrite),
+    ("prlar8_el1",            0b100_0011_0110_0001, ReadWrite),
+    ("prlar8_el2",            0b110_0011_0110_0001, ReadWrite),
+    ("prlar9_el1",            0b100_0011_0110_0101, ReadWrite),
+    ("prlar9_el2",            0b110_0011_0110_0101, ReadWrite),
+    ("prlar_el1",             0b100_0011_0100_0001, ReadWrite),
+    ("prlar_el2",             0b110_0011_0100_0001, ReadWrite),
+    ("prselr_el1",            0b100_0011_0001_0001, ReadWrite),
+    ("prselr_el2",            0b110_0011_0001_0001, ReadWrite),
+    ("revidr_el1",            0b100_0000_0000_0110, Read),
+    ("rgsr_el1",              0b100_0000_1000_0101, ReadWrite),
+    ("rmr_el1",               0b100_0110_0000_0010, ReadWrite),
+    ("rmr_el2",               0b110_0110_0000_0010, ReadWrite),
+    ("rmr_el3",               0b111_0110_0000_0010, ReadWrite),
+    ("rndr",                  0b101_1001_0010_0000, Read),
+    ("rndrrs",                0b101_1001_0010_0001, Read),
+    ("rvbar_el1",             0b100_0110_0000_0001, Read),
+    ("rvbar_el2",             0b110_0110_0000_0001, Read),
+    ("rvbar_el3",             0b111_0110_0000_0001, Read),
+    ("scr_el3",               0b111_0000_1000_1000, ReadWrite),
+    ("sctlr_el1",             0b100_0000_1000_0000, ReadWrite),
+    ("sctlr_el12",            0b110_1000_1000_0000, ReadWrite),
+    ("sctlr_el2",             0b110_0000_1000_0000, ReadWrite),
+    ("sctlr_el3",             0b111_0000_1000_0000, ReadWrite),
+    ("scxtnum_el0",           0b101_1110_1000_0111, ReadWrite),
+    ("scxtnum_el1",           0b100_0110_1000_0111, ReadWrite),
+    ("scxtnum_el12",          0b110_1110_1000_0111, ReadWrite),
+    ("scxtnum_el2",           0b110_0110_1000_0111, ReadWrite),
+    ("scxtnum_el3",           0b111_0110_1000_0111, ReadWrite),
+    ("sder32_el2",            0b110_0000_1001_1001, ReadWrite),
+    ("sder32_el3",            0b111_0000_1000_1001, ReadWrite),
+    ("smcr_el1",              0b100_0000_1001_0110, ReadWrite),
+    ("smcr_el12",             0b110_1000_1001_0110, ReadWrite),
+    ("smcr_el2",              0b110_0000_1001_0110, ReadWrite),
+    ("smcr_el3",              0b111_0000_1001_0110, ReadWrite),
+    ("smidr_el1",             0b100_1000_0000_0110, Read),
+    ("smpri_el1",             0b100_0000_1001_0100, ReadWrite),
+    ("smprimap_el2",          0b110_0000_1001_0101, ReadWrite),
+    ("sp_el0",                0b100_0010_0000_1000, ReadWrite),
+    ("sp_el1",                0b110_0010_0000_1000, ReadWrite),
+    ("sp_el2",                0b111_0010_0000_1000, ReadWrite),
+    ("spsel",                 0b100_0010_0001_0000, ReadWrite),
+    ("spsr_abt",              0b110_0010_0001_1001, ReadWrite),
+    ("spsr_el1",              0b100_0010_0000_0000, ReadWrite),
+    ("spsr_el12",             0b110_1010_0000_0000, ReadWrite),
+    ("spsr_el2",              0b110_0010_0000_0000, ReadWrite),
+    ("spsr_el3",              0b111_0010_0000_0000, ReadWrite),
+    ("spsr_fiq",              0b110_0010_0001_1011, ReadWrite),
+    ("spsr_irq",              0b110_0010_0001_1000, ReadWrite),
+    ("spsr_und",              0b110_0010_0001_1010, ReadWrite),
+    ("ssbs",                  0b101_1010_0001_0110, ReadWrite),
+    ("svcr",                  0b101_1010_0001_0010, ReadWrite),
+    ("tco",                   0b101_1010_0001_0111, ReadWrite),
+    ("tcr_el1",               0b100_0001_0000_0010, ReadWrite),
+    ("tcr_el12",              0b110_1001_0000_0010, ReadWrite),
+    ("tcr_el2",               0b110_0001_0000_0010, ReadWrite),
+    ("tcr_el3",               0b111_0001_0000_0010, ReadWrite),
+    ("teecr32_el1",           0b001_0000_0000_0000, ReadWrite),
+    ("teehbr32_el1",          0b001_0000_1000_0000, ReadWrite),
+    ("tfsr_el1",              0b100_0010_1011_0000, ReadWrite),
+    ("tfsr_el12",             0b110_1010_1011_0000, ReadWrite),
+    ("tfsr_el2",              0b110_0010_1011_0000, ReadWrite),
+    ("tfsr_el3",              0b111_0010_1011_0000, ReadWrite),
+    ("tfsre0_el1",            0b100_0010_1011_0001, ReadWrite),
+    ("tpidr2_el0",            0b101_1110_1000_0101, ReadWrite),
+    ("tpidr_el0",             0b101_1110_1000_0010, ReadWrite),
+    ("tpidr_el1",             0b100_0110_1000_0100, ReadWrite),
+    ("tpidr_el2",             0b110_0110_1000_0010, ReadWrite),
+    ("tpidr_el3",             0b111_0110_1000_0010, ReadWrite),
+    ("tpidrro_el0",           0b101_1110_1000_0011, ReadWrite),
+    ("trbbaser_el1",          0b100_0100_1101_1010, ReadWrite),
+    ("trbidr_el1",            0b100_0100_1101_1111, Read),
+    ("trblimitr_el1",         0b100_0100_1101_1000, ReadWrite),
+    ("trbmar_el1",            0b100_0100_1101_1100, ReadWrite),
+    ("trbptr_el1",            0b100_0100_1101_1001, ReadWrite),
+    ("trbsr_el1",             0b100_0100_1101_1011, ReadWrite),
+    ("trbtrg_el1",            0b100_0100_1101_1110, ReadWrite),
+    ("trcacatr0",             0b000_1001_0000_0010, ReadWrite),
+    ("trcacatr1",             0b000_1001_0001_0010, ReadWrite),
+    ("trcacatr10",            0b000_1001_0010_0011, ReadWrite),
+    ("trcacatr11",            0b000_1001_0011_0011, ReadWrite),
+    ("trcacatr12",            0b000_1001_0100_0011, ReadWrite),
+    ("trcacatr13",            0b000_1001_0101_0011, ReadWrite),
+    ("trcacatr14",            0b000_1001_0110_0011, ReadWrite),
+    ("trcacatr15",            0b000_1001_0111_0011, ReadWrite),
+    ("trcacatr2",             0b000_1001_0010_0010, ReadWrite),
+    ("trcacatr3",             0b000_1001_0011_0010, ReadWrite),
+    ("trcacatr4",             0b000_1001_0100_0010, ReadWrite),
+    ("trcacatr5",             0b000_1001_0101_0010, ReadWrite),
+    ("trcacatr6",             0b000_1001_0110_0010, ReadWrite),
+    ("trcacatr7",             0b000_1001_0111_0010, ReadWrite),
+    ("trcacatr8",             0b000_1001_0000_0011, ReadWrite),
+    ("trcacatr9",             0b000_1001_0001_0011, ReadWrite),
+    ("trcacvr0",              0b000_1001_0000_0000, ReadWrite),
+    ("trcacvr1",              0b000_1001_0001_0000, ReadWrite),
+    ("trcacvr10",             0b000_1001_0010_0001, ReadWrite),
+    ("trcacvr11",             0b000_1001_0011_0001, ReadWrite),
+    ("trcacvr12",             0b000_1001_0100_0001, ReadWrite),
+    ("trcacvr13",             0b000_1001_0101_0001, ReadWrite),
+    ("trcacvr14",             0b000_1001_0110_0001, ReadWrite),
+    ("trcacvr15",             0b000_1001_0111_0001, ReadWrite),
+    ("trcacvr2",              0b000_1001_0010_0000, ReadWrite),
+    ("trcacvr3",              0b000_1001_0011_0000, ReadWrite),
+    ("trcacvr4",              0b000_1001_0100_0000, ReadWrite),
+    ("trcacvr5",              0b000_1001_0101_0000, ReadWrite),
+    ("trcacvr6",              0b000_1001_0110_0000, ReadWrite),
+    ("trcacvr7",              0b000_1001_0111_0000, ReadWrite),
+    ("trcacvr8",              0b000_1001_0000_0001, ReadWrite),
+    ("trcacvr9",              0b000_1001_0001_0001, ReadWrite),
+    ("trcauthstatus",         0b000_1011_1111_0110, Read),
+    ("trcauxctlr",            0b000_1000_0011_0000, ReadWrite),
+    ("trcbbctlr",             0b000_1000_0111_1000, ReadWrite),
+    ("trcccctlr",             0b000_1000_0111_0000, ReadWrite),
+    ("trccidcctlr0",          0b000_1001_1000_0010, ReadWrite),
+    ("trccidcctlr1",          0b000_1001_1000_1010, ReadWrite),
+    ("trccidcvr0",            0b000_1001_1000_0000, ReadWrite),
+    ("trccidcvr1",            0b000_1001_1001_0000, ReadWrite),
+    ("trccidcvr2",            0b000_1001_1010_0000, ReadWrite),
+    ("trccidcvr3",            0b000_1001_1011_0000, ReadWrite),
+    ("trccidcvr4",            0b000_1001_1100_0000, ReadWrite),
+    ("trccidcvr5",            0b000_1001_1101_0000, ReadWrite),
+    ("trccidcvr6",            0b000_1001_1110_0000, ReadWrite),
+    ("trccidcvr7",            0b000_1001_1111_0000, ReadWrite),
+    ("trccidr0",              0b000_1011_1110_0111, Read),
+    ("trccidr1",              0b000_1011_1110_1111, Read),
+    ("trccidr2",              0b000_1011_1111_0111, Read),
+    ("trccidr3",              0b000_1011_1111_1111, Read),
+    ("trcclaimclr",           0b000_1011_1100_1110, ReadWrite),
+    ("trcclaimset",           0b000_1011_1100_0110, ReadWrite),
+    ("trccntctlr0",           0b000_1000_0010_0101, ReadWrite),
+    ("trccntctlr1",           0b000_1000_0010_1101, ReadWrite),
+    ("trccntctlr2",           0b000_1000_0011_0101, ReadWrite),
+    ("trccntctlr3",           0b000_1000_0011_1101, ReadWrite),
+    ("trccntrldvr0",          0b000_1000_0000_0101, ReadWrite),
+    ("trccntrldvr1",          0b000_1000_0000_1101, ReadWrite),
+    ("trccntrldvr2",          0b000_1000_0001_0101, ReadWrite),
+    ("trccntrldvr3",          0b000_1000_0001_1101, ReadWrite),
+    ("trccntvr0",             0b000_1000_0100_0101, ReadWrite),
+    ("trccntvr1",             0b000_1000_0100_1101, ReadWrite),
+    ("trccntvr2",             0b000_1000_0101_0101, ReadWrite),
+    ("trccntvr3",             0b000_1000_0101_1101, ReadWrite),
+    ("trcconfigr",            0b000_1000_0010_0000, ReadWrite),
+    ("trcdevaff0",            0b000_1011_1101_0110, Read),
+    ("trcdevaff1",            0b000_1011_1101_1110, Read),
+    ("trcdevarch",            0b000_1011_1111_1110, Read),
+    ("trcdevid",              0b000_1011_1001_0111, Read),
+    ("trcdevtype",            0b000_1011_1001_1111, Read),
+    ("trcdvcmr0",             0b000_1001_0000_0110, ReadWrite),
+    ("trcdvcmr1",             0b000_1001_0010_0110, ReadWrite),
+    ("trcdvcmr2",             0b000_1001_0100_0110, ReadWrite),
+    ("trcdvcmr3",             0b000_1001_0110_0110, ReadWrite),
+    ("trcdvcmr4",             0b000_1001_0000_0111, ReadWrite),
+    ("trcdvcmr5",             0b000_1001_0010_0111, ReadWrite),
+    ("trcdvcmr6",             0b000_1001_0100_0111, ReadWrite),
+    ("trcdvcmr7",             0b000_1001_0110_0111, ReadWrite),
+    ("trcdvcvr0",             0b000_1001_0000_0100, ReadWrite),
+    ("trcdvcvr1",             0b000_1001_0010_0100, ReadWrite),
+    ("trcdvcvr2",             0b000_1001_0100_0100, ReadWrite),
+    ("trcdvcvr3",             0b000_1001_0110_0100, ReadWrite),
+    ("trcdvcvr4",             0b000_1001_0000_0101, ReadWrite),
+    ("trcdvcvr5",             0b000_1001_0010_0101, ReadWrite),
+    ("trcdvcvr6",             0b000_1001_0100_0101, ReadWrite),
+    ("trcdvcvr7",             0b000_1001_0110_0101, ReadWrite),
+    ("trceventctl0r",         0b000_1000_0100_0000, ReadWrite),
+    ("trceventctl1r",         0b000_1000_0100_1000, ReadWrite),
+    ("trcextinselr",          0b000_1000_0100_0100, ReadWrite),
+    ("trcextinselr1",         0b000_1000_0100_1100, ReadWrite),
+    ("trcextinselr2",         0b000_1000_0101_0100, ReadWrite),
+    ("trcextinselr3",         0b000_1000_0101_1100, ReadWrite),
+    ("trcidr0",               0b000_1000_0100_0111, Read),
+    ("trcidr1",               0b000_1000_0100_1111, Read),
+    ("trcidr10",              0b000_1000_0001_0110, Read),
+    ("trcidr11",              0b000_1000_0001_1110, Read),
+    ("trcidr12",              0b000_1000_0010_0110, Read),
+    ("trcidr13",              0b000_1000_0010_1110, Read),
+    ("trcidr2",               0b000_1000_0101_0111, Read),
+    ("trcidr3",               0b000_1000_0101_1111, Read),
+    ("trcidr4",               0b000_1000_0110_0111, Read),
+    ("trcidr5",               0b000_1000_0110_1111, Read),
+    ("trcidr6",               0b000_1000_0111_0111, Read),
+    ("trcidr7",               0b000_1000_0111_1111, Read),
+    ("trcidr8",               0b000_1000_0000_0110, Read),
+    ("trcidr9",               0b000_1000_0000_1110, Read),
+    ("trcimspec0",            0b000_1000_0000_0111, ReadWrite),
+    ("trcimspec1",            0b000_1000_0000_1111, ReadWrite),
+    ("trcimspec2",            0b000_1000_0001_0111, ReadWrite),
+    ("trcimspec3",            0b000_1000_0001_1111, ReadWrite),
+    ("trcimspec4",            0b000_1000_0010_0111, ReadWrite),
+    ("trcimspec5",            0b000_1000_0010_1111, ReadWrite),
+    ("trcimspec6",            0b000_1000_0011_0111, ReadWrite),
+    ("trcimspec7",            0b000_1000_0011_1111, ReadWrite),
+    ("trcitctrl",             0b000_1011_1000_0100, ReadWrite),
+    ("trclar",                0b000_1011_1110_0110, Write),
+    ("trclsr",                0b000_1011_1110_1110, Read),
+    ("trcoslar",              0b000_1000_1000_0100, Write),
+    ("trcoslsr",              0b000_1000_1000_1100, Read),
+    ("trcpdcr",               0b000_1000_1010_0100, ReadWrite),
+    ("trcpdsr",               0b000_1000_1010_1100, Read),
+    ("trcpidr0",              0b000_1011_1100_0111, Read),
+    ("trcpidr1",              0b000_1011_1100_1111, Read),
+    ("trcpidr2",              0b000_1011_1101_0111, Read),
+    ("trcpidr3",              0b000_1011_1101_1111, Read),
+    ("trcpidr4",              0b000_1011_1010_0111, Read),
+    ("trcpidr5",              0b000_1011_1010_1111, Read),
+    ("trcpidr6",              0b000_1011_1011_0111, Read),
+    ("trcpidr7",              0b000_1011_1011_1111, Read),
+    ("trcprgctlr",            0b000_1000_0000_1000, ReadWrite),
+    ("trcprocselr",           0b000_1000_0001_0000, ReadWrite),
+    ("trcqctlr",              0b000_1000_0000_1001, ReadWrite),
+    ("trcrsctlr10",           0b000_1000_1101_0000, ReadWrite),
+    ("trcrsctlr11",           0b000_1000_1101_1000, ReadWrite),
+    ("trcrsctlr12",           0b000_1000_1110_0000, ReadWrite),
+    ("trcrsctlr13",           0b000_1000_1110_1000, ReadWrite),
+    ("trcrsctlr14",           0b000_1000_1111_0000, ReadWrite),
+    ("trcrsctlr15",           0b000_1000_1111_1000, ReadWrite),
+    ("trcrsctlr16",           0b000_1000_1000_0001, ReadWrite),
+    ("trcrsctlr17",           0b000_1000_1000_1001, ReadWrite),
+    ("trcrsctlr18",           0b000_1000_1001_0001, ReadWrite),
+    ("trcrsctlr19",           0b000_1000_1001_1001, ReadWrite),
+    ("trcrsctlr2",            0b000_1000_1001_0000, ReadWrite),
+    ("trcrsctlr20",           0b000_1000_1010_0001, ReadWrite),
+    ("trcrsctlr21",           0b000_1000_1010_1001, ReadWrite),
+    ("trcrsctlr22",           0b000_1000_1011_0001, ReadWrite),
+    ("trcrsctlr23",           0b000_1000_1011_1001, ReadWrite),
+    ("trcrsctlr24",           0b000_1000_1100_0001, ReadWrite),
+    ("trcrsctlr25",           0b000_1000_1100_1001, ReadWrite),
+    ("trcrsctlr26",           0b000_1000_1101_0001, ReadWrite),
+    ("trcrsctlr27",           0b000_1000_1101_1001, ReadWrite),
+    ("trcrsctlr28",           0b000_1000_1110_0001, ReadWrite),
+    ("trcrsctlr29",           0b000_1000_1110_1001, ReadWrite),
+    ("trcrsctlr3",            0b000_1000_1001_1000, ReadWrite),
+    ("trcrsctlr30",           0b000_1000_1111_0001, ReadWrite),
+    ("trcrsctlr31",           0b000_1000_1111_1001, ReadWrite),
+    ("trcrsctlr4",            0b000_1000_1010_0000, ReadWrite),
+    ("trcrsctlr5",            0b000_1000_1010_1000, ReadWrite),
+    ("trcrsctlr6",            0b000_1000_1011_0000, ReadWrite),
+    ("trcrsctlr7",            0b000_1000_1011_1000, ReadWrite),
+    ("trcrsctlr8",            0b000_1000_1100_0000, ReadWrite),
+    ("trcrsctlr9",            0b000_1000_1100_1000, ReadWrite),
+    ("trcrsr",                0b000_1000_0101_0000, ReadWrite),
+    ("trcseqevr0",            0b000_1000_0000_0100, ReadWrite),
+    ("trcseqevr1",            0b000_1000_0000_1100, ReadWrite),
+    ("trcseqevr2",            0b000_1000_0001_0100, ReadWrite),
+    ("trcseqrstevr",          0b000_1000_0011_0100, ReadWrite),
+    ("trcseqstr",             0b000_1000_0011_1100, ReadWrite),
+    ("trcssccr0",             0b000_1000_1000_0010, ReadWrite),
+    ("trcssccr1",             0b000_1000_1000_1010, ReadWrite),
+    ("trcssccr2",             0b000_1000_1001_0010, ReadWrite),
+    ("trcssccr3",             0b000_1000_1001_1010, ReadWrite),
+    ("trcssccr4",             0b000_1000_1010_0010, ReadWrite),
+    ("trcssccr5",             0b000_1000_1010_1010, ReadWrite),
+    ("trcssccr6",             0b000_1000_1011_0010, ReadWrite),
+    ("trcssccr7",             0b000_1000_1011_1010, ReadWrite),
+    ("trcsscsr0",             0b000_1000_1100_0010, ReadWrite),
+    ("trcsscsr1",             0b000_1000_1100_1010, ReadWrite),
+    ("trcsscsr2",             0b000_1000_1101_0010, ReadWrite),
+    ("trcsscsr3",             0b000_1000_1101_1010, ReadWrite),
+    ("trcsscsr4",             0b000_1000_1110_0010, ReadWrite),
+    ("trcsscsr5",             0b000_1000_1110_1010, ReadWrite),
+    ("trcsscsr6",             0b000_1000_1111_0010, ReadWrite),
+    ("trcsscsr7",             0b000_1000_1111_1010, ReadWrite),
+    ("trcsspcicr0",           0b000_1000_1000_0011, ReadWrite),
+    ("trcsspcicr1",           0b000_1000_1000_1011, ReadWrite),
+    ("trcsspcicr2",           0b000_1000_1001_0011, ReadWrite),
+    ("trcsspcicr3",           0b000_1000_1001_1011, ReadWrite),
+    ("trcsspcicr4",           0b000_1000_1010_0011, ReadWrite),
+    ("trcsspcicr5",           0b000_1000_1010_1011, ReadWrite),
+    ("trcsspcicr6",           0b000_1000_1011_0011, ReadWrite),
+    ("trcsspcicr7",           0b000_1000_1011_1011, ReadWrite),
+    ("trcstallctlr",          0b000_1000_0101_1000, ReadWrite),
+    ("trcstatr",              0b000_1000_0001_1000, Read),
+    ("trcsyncpr",             0b000_1000_0110_1000, ReadWrite),
+    ("trctraceidr",           0b000_1000_0000_0001, ReadWrite),
+    ("trctsctlr",             0b000_1000_0110_0000, ReadWrite),
+    ("trcvdarcctlr",          0b000_1000_0101_0010, ReadWrite),
+    ("trcvdctlr",             0b000_1000_0100_0010, ReadWrite),
+    ("trcvdsacctlr",          0b000_1000_0100_1010, ReadWrite),
+    ("trcvictlr",             0b000_1000_0000_0010, ReadWrite),
+    ("trcviiectlr",           0b000_1000_0000_1010, ReadWrite),
+    ("trcvipcssctlr",         0b000_1000_0001_1010, ReadWrite),
+    ("trcvissctlr",           0b000_1000_0001_0010, ReadWrite),
+    ("trcvmidcctlr0",         0b000_1001_1001_0010, ReadWrite),
+    ("trcvmidcctlr1",         0b000_1001_1001_1010, ReadWrite),
+    ("trcvmidcvr0",           0b000_1001_1000_0001, ReadWrite),
+    ("trcvmidcvr1",           0b000_1001_1001_0001, ReadWrite),
+    ("trcvmidcvr2",           0b000_1001_1010_0001, ReadWrite),
+    ("trcvmidcvr3",           0b000_1001_1011_0001, ReadWrite),
+    ("trcvmidcvr4",           0b000_1001_1100_0001, ReadWrite),
+    ("trcvmidcvr5",           0b000_1001_1101_0001, ReadWrite),
+    ("trcvmidcvr6",           0b000_1001_1110_0001, ReadWrite),
+    ("trcvmidcvr7",           0b000_1001_1111_0001, ReadWrite),
+    ("trfcr_el1",             0b100_0000_1001_0001, ReadWrite),
+    ("trfcr_el12",            0b110_1000_1001_0001, ReadWrite),
+    ("trfcr_el2",             0b110_0000_1001_0001, ReadWrite),
+    ("ttbr0_el1",             0b100_0001_0000_0000, ReadWrite),
+    ("ttbr0_el12",            0b110_1001_0000_0000, ReadWrite),
+    ("ttbr0_el2",             0b110_0001_0000_0000, ReadWrite),
+    ("ttbr0_el3",             0b111_0001_0000_0000, ReadWrite),
+    ("ttbr1_el1",             0b100_0001_0000_0001, ReadWrite),
+    ("ttbr1_el12",            0b110_1001_0000_0001, ReadWrite),
+    ("ttbr1_el2",             0b110_0001_0000_0001, ReadWrite),
+    ("uao",                   0b100_0010_0001_0100, ReadWrite),
+    ("vbar_el1",              0b100_0110_0000_0000, ReadWrite),
+    ("vbar_el12",             0b110_1110_0000_0000, ReadWrite),
+    ("vbar_el2",              0b110_0110_0000_0000, ReadWrite),
+    ("vbar_el3",              0b111_0110_0000_0000, ReadWrite),
+    ("vdisr_el2",             0b110_0110_0000_1001, ReadWrite),
+    ("vmpidr_el2",            0b110_0000_0000_0101, ReadWrite),
+    ("vncr_el2",              0b110_0001_0001_0000, ReadWrite),
+    ("vpidr_el2",             0b110_0000_0000_0000, ReadWrite),
+    ("vsesr_el2",             0b110_0010_1001_0011, ReadWrite),
+    ("vstcr_el2",             0b110_0001_0011_0010, ReadWrite),
+    ("vsttbr_el2",            0b110_0001_0011_0000, ReadWrite),
+    ("vtcr_el2",              0b110_0001_0000_1010, ReadWrite),
+    ("vttbr_el2",             0b110_0001_0000_1000, ReadWrite),
+    ("zcr_el1",               0b100_0000_1001_0000, ReadWrite),
+    ("zcr_el12",              0b110_1000_1001_0000, ReadWrite),
+    ("zcr_el2",               0b110_0000_1001_0000, ReadWrite),
+    ("zcr_el3",               0b111_0000_1001_0000, ReadWrite),
+]
+    }
+}
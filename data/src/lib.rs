@@ -0,0 +1,13 @@
+//! Instruction data shared by the `dynasm` plugin and the runtime encoders of `dynasmrt`.
+//!
+//! The instruction tables are exported as macros expanding to the table expressions, so every crate
+//! can interpret their entries with its own types. Identifiers used in the tables, like flags,
+//! matchers and commands, resolve where the macro is invoked. The opmap macros take the name of the
+//! macro the instruction list should be passed to.
+
+pub mod x64;
+
+mod x64_opmap;
+mod aarch64_opmap;
+mod aarch64_sysregs;
+mod aarch64_special_idents;
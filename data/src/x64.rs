@@ -0,0 +1,111 @@
+//! Flags and features of the x64 instruction data.
+
+/// The flags of an instruction form, describing how it is encoded.
+pub mod flags {
+    pub const DEFAULT     : u64 = 0x0000_0000; // this instruction has default encoding
+    pub const VEX_OP      : u64 = 0x0000_0001; // this instruction requires a VEX prefix to be encoded
+    pub const XOP_OP      : u64 = 0x0000_0002; // this instruction requires a XOP prefix to be encoded
+    pub const IMM_OP      : u64 = 0x0000_0004; // this instruction encodes the final opcode byte in the immediate position, like 3DNow! ops.
+
+    // note: the first 4 in this block are mutually exclusive
+    pub const AUTO_SIZE   : u64 = 0x0000_0008; // 16 bit -> OPSIZE , 32-bit -> None     , 64-bit -> REX.W/VEX.W/XOP.W
+    pub const AUTO_NO32   : u64 = 0x0000_0010; // 16 bit -> OPSIZE , 32-bit -> None(x86), 64-bit -> None(x64)
+    pub const AUTO_REXW   : u64 = 0x0000_0020; // 16 bit -> illegal, 32-bit -> None     , 64-bit -> REX.W/VEX.W/XOP.W
+    pub const AUTO_VEXL   : u64 = 0x0000_0040; // 128bit -> None   , 256bit -> VEX.L
+    pub const WORD_SIZE   : u64 = 0x0000_0080; // implies a 16-bit operand size
+    pub const WITH_REXW   : u64 = 0x0000_0100; // implies REX.W/VEX.W/XOP.W
+    pub const WITH_VEXL   : u64 = 0x0000_0200; // implies VEX.L/XOP.L
+    pub const EXACT_SIZE  : u64 = 0x0000_0400; // operands with unknown sizes cannot be assumed to match
+
+    pub const PREF_66     : u64 = 0x0001_0000_0000; // mandatory prefix
+    pub const PREF_67     : u64 = 0x0000_0800; // mandatory prefix (same as SMALL_ADDRESS)
+    pub const PREF_F0     : u64 = 0x0000_1000; // mandatory prefix (same as LOCK)
+    pub const PREF_F2     : u64 = 0x0000_2000; // mandatory prefix (REPNE)
+    pub const PREF_F3     : u64 = 0x0000_4000; // mandatory prefix (REP)
+
+    pub const LOCK        : u64 = 0x0000_8000; // user lock prefix is valid with this instruction
+    pub const REP         : u64 = 0x0001_0000; // user rep prefix is valid with this instruction
+    pub const REPE        : u64 = 0x0002_0000;
+
+    pub const SHORT_ARG   : u64 = 0x0004_0000; // a register argument is encoded in the last byte of the opcode
+    pub const ENC_MR      : u64 = 0x0008_0000; // select alternate arg encoding
+    pub const ENC_VM      : u64 = 0x0010_0000; // select alternate arg encoding
+    pub const ENC_MIB     : u64 = 0x0020_0000; // A special encoding using the SIB to specify an immediate and two registers
+    pub const X86_ONLY    : u64 = 0x0040_0000; // instructions available in protected mode, but not long mode
+
+    pub const EVEX_OP     : u64 = 0x0080_0000; // this instruction requires an EVEX prefix to be encoded
+    pub const OPMASK      : u64 = 0x0100_0000; // an opmask can be applied to the destination operand
+    pub const ZEROING     : u64 = 0x0200_0000; // zeroing-masking can be used instead of merging-masking
+    pub const BCST        : u64 = 0x0400_0000; // the memory operand can be an embedded broadcast
+    pub const ROUNDING    : u64 = 0x0800_0000; // embedded rounding control can be used
+    pub const SAE         : u64 = 0x1000_0000; // suppress-all-exceptions can be used
+    pub const WITH_EVEXL  : u64 = 0x2000_0000; // implies EVEX.L' (512-bit vector length)
+    pub const NDD         : u64 = 0x4000_0000; // APX new data destination form: the first operand is encoded in EVEX.vvvv and EVEX.ND is set
+    pub const SIBMEM      : u64 = 0x8000_0000; // the memory operand is always encoded using a SIB byte
+    pub const DWORD_SIZE  : u64 = 0x0002_0000_0000; // implies a 32-bit operand size
+}
+
+/// The architectural features an instruction form requires.
+pub mod features {
+    pub const X64_IMPLICIT: u64 = 0x0000_0000;
+    pub const FPU         : u64 = 0x0000_0001;
+    pub const MMX         : u64 = 0x0000_0002;
+    pub const TDNOW       : u64 = 0x0000_0004;
+    pub const SSE         : u64 = 0x0000_0008;
+    pub const SSE2        : u64 = 0x0000_0010;
+    pub const SSE3        : u64 = 0x0000_0020;
+    pub const VMX         : u64 = 0x0000_0040;
+    pub const SSSE3       : u64 = 0x0000_0080;
+    pub const SSE4A       : u64 = 0x0000_0100;
+    pub const SSE41       : u64 = 0x0000_0200;
+    pub const SSE42       : u64 = 0x0000_0400;
+    pub const SSE5        : u64 = 0x0000_0800;
+    pub const AVX         : u64 = 0x0000_1000;
+    pub const AVX2        : u64 = 0x0000_2000;
+    pub const FMA         : u64 = 0x0000_4000;
+    pub const BMI1        : u64 = 0x0000_8000;
+    pub const BMI2        : u64 = 0x0001_0000;
+    pub const TBM         : u64 = 0x0002_0000;
+    pub const RTM         : u64 = 0x0004_0000;
+    pub const INVPCID     : u64 = 0x0008_0000;
+    pub const MPX         : u64 = 0x0010_0000;
+    pub const SHA         : u64 = 0x0020_0000;
+    pub const PREFETCHWT1 : u64 = 0x0040_0000;
+    pub const CYRIX       : u64 = 0x0080_0000;
+    pub const AMD         : u64 = 0x0100_0000;
+    pub const AVX512F     : u64 = 0x0200_0000;
+    pub const AVX512CD    : u64 = 0x0400_0000;
+    pub const AVX512ER    : u64 = 0x0800_0000;
+    pub const AVX512PF    : u64 = 0x1000_0000;
+    pub const AVX512BW    : u64 = 0x2000_0000;
+    pub const AVX512DQ    : u64 = 0x4000_0000;
+    pub const AVX512VL    : u64 = 0x8000_0000;
+    pub const AVX512IFMA  : u64 = 0x0001_0000_0000;
+    pub const AVX512VBMI  : u64 = 0x0002_0000_0000;
+    pub const APX         : u64 = 0x0004_0000_0000;
+    pub const AMX_TILE    : u64 = 0x0008_0000_0000;
+    pub const AMX_INT8    : u64 = 0x0010_0000_0000;
+    pub const AMX_BF16    : u64 = 0x0020_0000_0000;
+    pub const AES         : u64 = 0x0040_0000_0000;
+    pub const PCLMULQDQ   : u64 = 0x0080_0000_0000;
+    pub const ADX         : u64 = 0x0100_0000_0000;
+    pub const POPCNT      : u64 = 0x0200_0000_0000;
+    pub const LZCNT       : u64 = 0x0400_0000_0000;
+    pub const RDRAND      : u64 = 0x0800_0000_0000;
+    pub const RDSEED      : u64 = 0x1000_0000_0000;
+    pub const MOVBE       : u64 = 0x2000_0000_0000;
+    pub const XSAVE       : u64 = 0x4000_0000_0000;
+    pub const XSAVEOPT    : u64 = 0x8000_0000_0000;
+    pub const XSAVEC      : u64 = 0x0001_0000_0000_0000;
+    pub const XSAVES      : u64 = 0x0002_0000_0000_0000;
+    pub const F16C        : u64 = 0x0004_0000_0000_0000;
+    pub const CLFLUSHOPT  : u64 = 0x0008_0000_0000_0000;
+    pub const CLWB        : u64 = 0x0010_0000_0000_0000;
+    pub const GFNI        : u64 = 0x0020_0000_0000_0000;
+    pub const VAES        : u64 = 0x0040_0000_0000_0000;
+    pub const VPCLMULQDQ  : u64 = 0x0080_0000_0000_0000;
+    pub const AVXVNNI     : u64 = 0x0100_0000_0000_0000;
+    pub const SERIALIZE   : u64 = 0x0200_0000_0000_0000;
+    pub const FSGSBASE    : u64 = 0x0400_0000_0000_0000;
+    pub const PRFCHW      : u64 = 0x0800_0000_0000_0000;
+}
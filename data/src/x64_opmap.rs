@@ -1,4 +1,8 @@
-Ops!(
+/// The x64 instruction data. Expands to `$ops!(...)`, with for every mnemonic a list of its forms as
+/// `format string, opcode bytes, reg field, flags (, features)`. See the `x64` module for the flags and features.
+#[macro_export]
+macro_rules! x64_opmap {
+    ($ops:ident) => { $ops!(
 
 "aaa" = [
     b""           , [0x37              ], X, X86_ONLY;
@@ -27,9 +31,16 @@ Ops!(
     b"r*i*"       , [0x81              ], 2, AUTO_SIZE ;
     b"r*r*"       , [0x11              ], X, AUTO_SIZE | ENC_MR;
     b"r*v*"       , [0x13              ], X, AUTO_SIZE;
+    b"rbvbib"     , [0x04, 0x80        ], 2, EVEX_OP | NDD, APX;
+    b"rbvbrb"     , [0x04, 0x10        ], X, EVEX_OP | NDD, APX;
+    b"rbrbvb"     , [0x04, 0x12        ], X, EVEX_OP | NDD, APX;
+    b"r*v*ib"     , [0x04, 0x83        ], 2, EVEX_OP | NDD | AUTO_SIZE | EXACT_SIZE, APX;
+    b"r*v*i*"     , [0x04, 0x81        ], 2, EVEX_OP | NDD | AUTO_SIZE, APX;
+    b"r*v*r*"     , [0x04, 0x11        ], X, EVEX_OP | NDD | AUTO_SIZE, APX;
+    b"r*r*v*"     , [0x04, 0x13        ], X, EVEX_OP | NDD | AUTO_SIZE, APX;
 ]
 "adcx" = [
-    b"rqvq"       , [0x0F, 0x38, 0xF6  ], X, WITH_REXW | PREF_66;
+    b"rqvq"       , [0x0F, 0x38, 0xF6  ], X, WITH_REXW | PREF_66, ADX;
 ]
 "add" = [
     b"Abib"       , [0x04              ], X;
@@ -46,6 +57,13 @@ Ops!(
     b"r*i*"       , [0x81              ], 0, AUTO_SIZE ;
     b"r*r*"       , [0x01              ], X, AUTO_SIZE | ENC_MR;
     b"r*v*"       , [0x03              ], X, AUTO_SIZE;
+    b"rbvbib"     , [0x04, 0x80        ], 0, EVEX_OP | NDD, APX;
+    b"rbvbrb"     , [0x04, 0x00        ], X, EVEX_OP | NDD, APX;
+    b"rbrbvb"     , [0x04, 0x02        ], X, EVEX_OP | NDD, APX;
+    b"r*v*ib"     , [0x04, 0x83        ], 0, EVEX_OP | NDD | AUTO_SIZE | EXACT_SIZE, APX;
+    b"r*v*i*"     , [0x04, 0x81        ], 0, EVEX_OP | NDD | AUTO_SIZE, APX;
+    b"r*v*r*"     , [0x04, 0x01        ], X, EVEX_OP | NDD | AUTO_SIZE, APX;
+    b"r*r*v*"     , [0x04, 0x03        ], X, EVEX_OP | NDD | AUTO_SIZE, APX;
 ]
 "addpd" = [
     b"yowo"       , [0x0F, 0x58        ], X, PREF_66, SSE2;
@@ -68,25 +86,25 @@ Ops!(
     b"yowo"       , [0x0F, 0xD0        ], X, PREF_F2, SSE3;
 ]
 "adox" = [
-    b"rqvq"       , [0x0F, 0x38, 0xF6  ], X, WITH_REXW | PREF_F3;
+    b"rqvq"       , [0x0F, 0x38, 0xF6  ], X, WITH_REXW | PREF_F3, ADX;
 ]
 "aesdec" = [
-    b"yowo"       , [0x0F, 0x38, 0xDE  ], X, PREF_66, SSE;
+    b"yowo"       , [0x0F, 0x38, 0xDE  ], X, PREF_66, AES;
 ]
 "aesdeclast" = [
-    b"yowo"       , [0x0F, 0x38, 0xDF  ], X, PREF_66, SSE;
+    b"yowo"       , [0x0F, 0x38, 0xDF  ], X, PREF_66, AES;
 ]
 "aesenc" = [
-    b"yowo"       , [0x0F, 0x38, 0xDC  ], X, PREF_66, SSE;
+    b"yowo"       , [0x0F, 0x38, 0xDC  ], X, PREF_66, AES;
 ]
 "aesenclast" = [
-    b"yowo"       , [0x0F, 0x38, 0xDD  ], X, PREF_66, SSE;
+    b"yowo"       , [0x0F, 0x38, 0xDD  ], X, PREF_66, AES;
 ]
 "aesimc" = [
-    b"yowo"       , [0x0F, 0x38, 0xDB  ], X, PREF_66, SSE;
+    b"yowo"       , [0x0F, 0x38, 0xDB  ], X, PREF_66, AES;
 ]
 "aeskeygenassist" = [
-    b"yowoib"     , [0x0F, 0x3A, 0xDF  ], X, PREF_66, SSE;
+    b"yowoib"     , [0x0F, 0x3A, 0xDF  ], X, PREF_66, AES;
 ]
 "and" = [
     b"Abib"       , [0x24              ], X;
@@ -103,6 +121,13 @@ Ops!(
     b"r*i*"       , [0x81              ], 4, AUTO_SIZE ;
     b"r*r*"       , [0x21              ], X, AUTO_SIZE | ENC_MR;
     b"r*v*"       , [0x23              ], X, AUTO_SIZE;
+    b"rbvbib"     , [0x04, 0x80        ], 4, EVEX_OP | NDD, APX;
+    b"rbvbrb"     , [0x04, 0x20        ], X, EVEX_OP | NDD, APX;
+    b"rbrbvb"     , [0x04, 0x22        ], X, EVEX_OP | NDD, APX;
+    b"r*v*ib"     , [0x04, 0x83        ], 4, EVEX_OP | NDD | AUTO_SIZE | EXACT_SIZE, APX;
+    b"r*v*i*"     , [0x04, 0x81        ], 4, EVEX_OP | NDD | AUTO_SIZE, APX;
+    b"r*v*r*"     , [0x04, 0x21        ], X, EVEX_OP | NDD | AUTO_SIZE, APX;
+    b"r*r*v*"     , [0x04, 0x23        ], X, EVEX_OP | NDD | AUTO_SIZE, APX;
 ]
 "andn" = [
     b"r*r*v*"     , [0x02, 0xF2        ], X, VEX_OP | AUTO_REXW, BMI1;
@@ -240,7 +265,7 @@ Ops!(
     b""           , [0x98              ], X, WORD_SIZE;
 ]
 "cdq" = [
-    b""           , [0x99              ], X;
+    b""           , [0x99              ], X, DWORD_SIZE;
 ]
 "cdqe" = [
     b""           , [0x98              ], X, WITH_REXW;
@@ -257,6 +282,9 @@ Ops!(
 "clflush" = [
     b"mb"         , [0x0F, 0xAE        ], 7, DEFAULT, SSE2;
 ]
+"clflushopt" = [
+    b"mb"         , [0x0F, 0xAE        ], 7, PREF_66, CLFLUSHOPT;
+]
 "clgi" = [
     b""           , [0x0F, 0x01, 0xDD  ], X, DEFAULT, VMX | AMD;
 ]
@@ -266,6 +294,9 @@ Ops!(
 "clts" = [
     b""           , [0x0F, 0x06        ], X;
 ]
+"clwb" = [
+    b"mb"         , [0x0F, 0xAE        ], 6, PREF_66, CLWB;
+]
 "clzero" = [
     b""           , [0x0F, 0x01, 0xFC  ], X, DEFAULT, AMD;
 ]
@@ -392,7 +423,7 @@ Ops!(
     b""           , [0xA6              ], X, REPE;
 ]
 "cmpsd" = [
-    b""           , [0xA7              ], X, REPE;
+    b""           , [0xA7              ], X, REPE | DWORD_SIZE;
     b"yowoib"     , [0x0F, 0xC2        ], X, PREF_F2, SSE2;
 ]
 "cmpsq" = [
@@ -541,7 +572,7 @@ Ops!(
     b""           , [0x99              ], X, WORD_SIZE;
 ]
 "cwde" = [
-    b""           , [0x98              ], X;
+    b""           , [0x98              ], X, DWORD_SIZE;
 ]
 "daa" = [
     b""           , [0x27              ], X, X86_ONLY;
@@ -555,6 +586,8 @@ Ops!(
     b"m*"         , [0xFF              ], 1, AUTO_SIZE | LOCK;
     b"r*"         , [0x48              ], 0, X86_ONLY | SHORT_ARG;
     b"r*"         , [0xFF              ], 1, AUTO_SIZE ;
+    b"rbvb"       , [0x04, 0xFE        ], 1, EVEX_OP | NDD, APX;
+    b"r*v*"       , [0x04, 0xFF        ], 1, EVEX_OP | NDD | AUTO_SIZE, APX;
 ]
 "div" = [
     b"vb"         , [0xF6              ], 6;
@@ -1027,6 +1060,15 @@ Ops!(
 "getsec" = [
     b""           , [0x0F, 0x37        ], X;
 ]
+"gf2p8affineinvqb" = [
+    b"yowoib"     , [0x0F, 0x3A, 0xCF  ], X, PREF_66, GFNI;
+]
+"gf2p8affineqb" = [
+    b"yowoib"     , [0x0F, 0x3A, 0xCE  ], X, PREF_66, GFNI;
+]
+"gf2p8mulb" = [
+    b"yowo"       , [0x0F, 0x38, 0xCF  ], X, PREF_66, GFNI;
+]
 "haddpd" = [
     b"yowo"       , [0x0F, 0x7C        ], X, PREF_66, SSE3;
 ]
@@ -1055,12 +1097,14 @@ Ops!(
     b"m*"         , [0xFF              ], 0, AUTO_SIZE | LOCK;
     b"r*"         , [0x40              ], 0, X86_ONLY | SHORT_ARG;
     b"r*"         , [0xFF              ], 0, AUTO_SIZE ;
+    b"rbvb"       , [0x04, 0xFE        ], 0, EVEX_OP | NDD, APX;
+    b"r*v*"       , [0x04, 0xFF        ], 0, EVEX_OP | NDD | AUTO_SIZE, APX;
 ]
 "insb" = [
     b""           , [0x6C              ], X, REP;
 ]
 "insd" = [
-    b""           , [0x6D              ], X, REP;
+    b""           , [0x6D              ], X, REP | DWORD_SIZE;
 ]
 "insertps" = [
     b"yomdib"     , [0x0F, 0x3A, 0x21  ], X, PREF_66, SSE41;
@@ -1114,7 +1158,7 @@ Ops!(
     b""           , [0xCF              ], X;
 ]
 "iretd" = [
-    b""           , [0xCF              ], X;
+    b""           , [0xCF              ], X, DWORD_SIZE;
 ]
 "iretq" = [
     b""           , [0xCF              ], X, WITH_REXW;
@@ -1128,6 +1172,163 @@ Ops!(
 "jrcxz" = [
     b"ob"         , [0xE3              ], X;
 ]
+"kandb" = [
+    b"jqjqjq"     , [0x01, 0x41        ], X, VEX_OP | WITH_VEXL | PREF_66, AVX512DQ;
+]
+"kandd" = [
+    b"jqjqjq"     , [0x01, 0x41        ], X, VEX_OP | WITH_VEXL | WITH_REXW | PREF_66, AVX512BW;
+]
+"kandnb" = [
+    b"jqjqjq"     , [0x01, 0x42        ], X, VEX_OP | WITH_VEXL | PREF_66, AVX512DQ;
+]
+"kandnd" = [
+    b"jqjqjq"     , [0x01, 0x42        ], X, VEX_OP | WITH_VEXL | WITH_REXW | PREF_66, AVX512BW;
+]
+"kandnq" = [
+    b"jqjqjq"     , [0x01, 0x42        ], X, VEX_OP | WITH_VEXL | WITH_REXW, AVX512BW;
+]
+"kandnw" = [
+    b"jqjqjq"     , [0x01, 0x42        ], X, VEX_OP | WITH_VEXL, AVX512F;
+]
+"kandq" = [
+    b"jqjqjq"     , [0x01, 0x41        ], X, VEX_OP | WITH_VEXL | WITH_REXW, AVX512BW;
+]
+"kandw" = [
+    b"jqjqjq"     , [0x01, 0x41        ], X, VEX_OP | WITH_VEXL, AVX512F;
+]
+"kmovb" = [
+    b"jqjq"       , [0x01, 0x90        ], X, VEX_OP | PREF_66, AVX512DQ;
+    b"jqmb"       , [0x01, 0x90        ], X, VEX_OP | PREF_66, AVX512DQ;
+    b"mbjq"       , [0x01, 0x91        ], X, VEX_OP | ENC_MR | PREF_66, AVX512DQ;
+    b"jqrd"       , [0x01, 0x92        ], X, VEX_OP | PREF_66, AVX512DQ;
+    b"rdjq"       , [0x01, 0x93        ], X, VEX_OP | PREF_66, AVX512DQ;
+]
+"kmovd" = [
+    b"jqjq"       , [0x01, 0x90        ], X, VEX_OP | WITH_REXW | PREF_66, AVX512BW;
+    b"jqmd"       , [0x01, 0x90        ], X, VEX_OP | WITH_REXW | PREF_66, AVX512BW;
+    b"mdjq"       , [0x01, 0x91        ], X, VEX_OP | ENC_MR | WITH_REXW | PREF_66, AVX512BW;
+    b"jqrd"       , [0x01, 0x92        ], X, VEX_OP | PREF_F2, AVX512BW;
+    b"rdjq"       , [0x01, 0x93        ], X, VEX_OP | PREF_F2, AVX512BW;
+]
+"kmovq" = [
+    b"jqjq"       , [0x01, 0x90        ], X, VEX_OP | WITH_REXW, AVX512BW;
+    b"jqmq"       , [0x01, 0x90        ], X, VEX_OP | WITH_REXW, AVX512BW;
+    b"mqjq"       , [0x01, 0x91        ], X, VEX_OP | ENC_MR | WITH_REXW, AVX512BW;
+    b"jqrq"       , [0x01, 0x92        ], X, VEX_OP | WITH_REXW | PREF_F2, AVX512BW;
+    b"rqjq"       , [0x01, 0x93        ], X, VEX_OP | WITH_REXW | PREF_F2, AVX512BW;
+]
+"kmovw" = [
+    b"jqjq"       , [0x01, 0x90        ], X, VEX_OP, AVX512F;
+    b"jqmw"       , [0x01, 0x90        ], X, VEX_OP, AVX512F;
+    b"mwjq"       , [0x01, 0x91        ], X, VEX_OP | ENC_MR, AVX512F;
+    b"jqrd"       , [0x01, 0x92        ], X, VEX_OP, AVX512F;
+    b"rdjq"       , [0x01, 0x93        ], X, VEX_OP, AVX512F;
+]
+"knotb" = [
+    b"jqjq"       , [0x01, 0x44        ], X, VEX_OP | PREF_66, AVX512DQ;
+]
+"knotd" = [
+    b"jqjq"       , [0x01, 0x44        ], X, VEX_OP | WITH_REXW | PREF_66, AVX512BW;
+]
+"knotq" = [
+    b"jqjq"       , [0x01, 0x44        ], X, VEX_OP | WITH_REXW, AVX512BW;
+]
+"knotw" = [
+    b"jqjq"       , [0x01, 0x44        ], X, VEX_OP, AVX512F;
+]
+"korb" = [
+    b"jqjqjq"     , [0x01, 0x45        ], X, VEX_OP | WITH_VEXL | PREF_66, AVX512DQ;
+]
+"kord" = [
+    b"jqjqjq"     , [0x01, 0x45        ], X, VEX_OP | WITH_VEXL | WITH_REXW | PREF_66, AVX512BW;
+]
+"korq" = [
+    b"jqjqjq"     , [0x01, 0x45        ], X, VEX_OP | WITH_VEXL | WITH_REXW, AVX512BW;
+]
+"kortestb" = [
+    b"jqjq"       , [0x01, 0x98        ], X, VEX_OP | PREF_66, AVX512DQ;
+]
+"kortestd" = [
+    b"jqjq"       , [0x01, 0x98        ], X, VEX_OP | WITH_REXW | PREF_66, AVX512BW;
+]
+"kortestq" = [
+    b"jqjq"       , [0x01, 0x98        ], X, VEX_OP | WITH_REXW, AVX512BW;
+]
+"kortestw" = [
+    b"jqjq"       , [0x01, 0x98        ], X, VEX_OP, AVX512F;
+]
+"korw" = [
+    b"jqjqjq"     , [0x01, 0x45        ], X, VEX_OP | WITH_VEXL, AVX512F;
+]
+"kshiftlb" = [
+    b"jqjqib"     , [0x03, 0x32        ], X, VEX_OP | PREF_66, AVX512DQ;
+]
+"kshiftld" = [
+    b"jqjqib"     , [0x03, 0x33        ], X, VEX_OP | PREF_66, AVX512BW;
+]
+"kshiftlq" = [
+    b"jqjqib"     , [0x03, 0x33        ], X, VEX_OP | WITH_REXW | PREF_66, AVX512BW;
+]
+"kshiftlw" = [
+    b"jqjqib"     , [0x03, 0x32        ], X, VEX_OP | WITH_REXW | PREF_66, AVX512F;
+]
+"kshiftrb" = [
+    b"jqjqib"     , [0x03, 0x30        ], X, VEX_OP | PREF_66, AVX512DQ;
+]
+"kshiftrd" = [
+    b"jqjqib"     , [0x03, 0x31        ], X, VEX_OP | PREF_66, AVX512BW;
+]
+"kshiftrq" = [
+    b"jqjqib"     , [0x03, 0x31        ], X, VEX_OP | WITH_REXW | PREF_66, AVX512BW;
+]
+"kshiftrw" = [
+    b"jqjqib"     , [0x03, 0x30        ], X, VEX_OP | WITH_REXW | PREF_66, AVX512F;
+]
+"ktestb" = [
+    b"jqjq"       , [0x01, 0x99        ], X, VEX_OP | PREF_66, AVX512DQ;
+]
+"ktestd" = [
+    b"jqjq"       , [0x01, 0x99        ], X, VEX_OP | WITH_REXW | PREF_66, AVX512BW;
+]
+"ktestq" = [
+    b"jqjq"       , [0x01, 0x99        ], X, VEX_OP | WITH_REXW, AVX512BW;
+]
+"ktestw" = [
+    b"jqjq"       , [0x01, 0x99        ], X, VEX_OP, AVX512DQ;
+]
+"kunpckbw" = [
+    b"jqjqjq"     , [0x01, 0x4B        ], X, VEX_OP | WITH_VEXL | PREF_66, AVX512F;
+]
+"kunpckdq" = [
+    b"jqjqjq"     , [0x01, 0x4B        ], X, VEX_OP | WITH_VEXL | WITH_REXW, AVX512BW;
+]
+"kunpckwd" = [
+    b"jqjqjq"     , [0x01, 0x4B        ], X, VEX_OP | WITH_VEXL, AVX512BW;
+]
+"kxnorb" = [
+    b"jqjqjq"     , [0x01, 0x46        ], X, VEX_OP | WITH_VEXL | PREF_66, AVX512DQ;
+]
+"kxnord" = [
+    b"jqjqjq"     , [0x01, 0x46        ], X, VEX_OP | WITH_VEXL | WITH_REXW | PREF_66, AVX512BW;
+]
+"kxnorq" = [
+    b"jqjqjq"     , [0x01, 0x46        ], X, VEX_OP | WITH_VEXL | WITH_REXW, AVX512BW;
+]
+"kxnorw" = [
+    b"jqjqjq"     , [0x01, 0x46        ], X, VEX_OP | WITH_VEXL, AVX512F;
+]
+"kxorb" = [
+    b"jqjqjq"     , [0x01, 0x47        ], X, VEX_OP | WITH_VEXL | PREF_66, AVX512DQ;
+]
+"kxord" = [
+    b"jqjqjq"     , [0x01, 0x47        ], X, VEX_OP | WITH_VEXL | WITH_REXW | PREF_66, AVX512BW;
+]
+"kxorq" = [
+    b"jqjqjq"     , [0x01, 0x47        ], X, VEX_OP | WITH_VEXL | WITH_REXW, AVX512BW;
+]
+"kxorw" = [
+    b"jqjqjq"     , [0x01, 0x47        ], X, VEX_OP | WITH_VEXL, AVX512F;
+]
 "lahf" = [
     b""           , [0x9F              ], X;
 ]
@@ -1144,6 +1345,9 @@ Ops!(
 "lds" = [
     b"r*m!"       , [0xC5              ], X, AUTO_SIZE | X86_ONLY;
 ]
+"ldtilecfg" = [
+    b"m!"         , [0x02, 0x49        ], 0, VEX_OP, AMX_TILE;
+]
 "lea" = [
     b"r*m!"       , [0x8D              ], X, AUTO_SIZE;
 ]
@@ -1183,7 +1387,7 @@ Ops!(
     b""           , [0xAC              ], X, REP;
 ]
 "lodsd" = [
-    b""           , [0xAD              ], X, REP;
+    b""           , [0xAD              ], X, REP | DWORD_SIZE;
 ]
 "lodsq" = [
     b""           , [0xAD              ], X, WITH_REXW | REP;
@@ -1224,7 +1428,7 @@ Ops!(
     b"r*v*id"     , [0x10, 0x12        ], 1, XOP_OP | AUTO_REXW | ENC_VM, AMD;
 ]
 "lzcnt" = [
-    b"r*v*"       , [0x0F, 0xBD        ], X, AUTO_SIZE | PREF_F3, AMD;
+    b"r*v*"       , [0x0F, 0xBD        ], X, AUTO_SIZE | PREF_F3, LZCNT;
 ]
 "maskmovdqu" = [
     b"yoyo"       , [0x0F, 0xF7        ], X, PREF_66, SSE2;
@@ -1285,8 +1489,8 @@ Ops!(
     b"woyo"       , [0x0F, 0x29        ], X, ENC_MR, SSE;
 ]
 "movbe" = [
-    b"m*r*"       , [0x0F, 0x38, 0xF1  ], X, AUTO_SIZE | ENC_MR;
-    b"r*m*"       , [0x0F, 0x38, 0xF0  ], X, AUTO_SIZE;
+    b"m*r*"       , [0x0F, 0x38, 0xF1  ], X, AUTO_SIZE | ENC_MR, MOVBE;
+    b"r*m*"       , [0x0F, 0x38, 0xF0  ], X, AUTO_SIZE, MOVBE;
 ]
 "movd" = [
     b"mdyo"       , [0x0F, 0x7E        ], X, ENC_MR | PREF_66, SSE2;
@@ -1391,7 +1595,7 @@ Ops!(
     b""           , [0xA4              ], X, REP;
 ]
 "movsd" = [
-    b""           , [0xA5              ], X, REP;
+    b""           , [0xA5              ], X, REP | DWORD_SIZE;
     b"mqyo"       , [0x0F, 0x11        ], X, ENC_MR | PREF_F2, SSE2;
     b"yomq"       , [0x0F, 0x10        ], X, PREF_F2, SSE2;
     b"yoyo"       , [0x0F, 0x10        ], X, PREF_F2, SSE2;
@@ -1478,6 +1682,8 @@ Ops!(
     b"rb"         , [0xF6              ], 3;
     b"m*"         , [0xF7              ], 3, AUTO_SIZE | LOCK;
     b"r*"         , [0xF7              ], 3, AUTO_SIZE ;
+    b"rbvb"       , [0x04, 0xF6        ], 3, EVEX_OP | NDD, APX;
+    b"r*v*"       , [0x04, 0xF7        ], 3, EVEX_OP | NDD | AUTO_SIZE, APX;
 ]
 "nop" = [
     b""           , [0x90              ], X;
@@ -1488,6 +1694,8 @@ Ops!(
     b"rb"         , [0xF6              ], 2;
     b"m*"         , [0xF7              ], 2, AUTO_SIZE | LOCK;
     b"r*"         , [0xF7              ], 2, AUTO_SIZE ;
+    b"rbvb"       , [0x04, 0xF6        ], 2, EVEX_OP | NDD, APX;
+    b"r*v*"       , [0x04, 0xF7        ], 2, EVEX_OP | NDD | AUTO_SIZE, APX;
 ]
 "or" = [
     b"Abib"       , [0x0C              ], X;
@@ -1504,6 +1712,13 @@ Ops!(
     b"r*i*"       , [0x81              ], 1, AUTO_SIZE ;
     b"r*r*"       , [0x09              ], X, AUTO_SIZE | ENC_MR;
     b"r*v*"       , [0x0B              ], X, AUTO_SIZE;
+    b"rbvbib"     , [0x04, 0x80        ], 1, EVEX_OP | NDD, APX;
+    b"rbvbrb"     , [0x04, 0x08        ], X, EVEX_OP | NDD, APX;
+    b"rbrbvb"     , [0x04, 0x0A        ], X, EVEX_OP | NDD, APX;
+    b"r*v*ib"     , [0x04, 0x83        ], 1, EVEX_OP | NDD | AUTO_SIZE | EXACT_SIZE, APX;
+    b"r*v*i*"     , [0x04, 0x81        ], 1, EVEX_OP | NDD | AUTO_SIZE, APX;
+    b"r*v*r*"     , [0x04, 0x09        ], X, EVEX_OP | NDD | AUTO_SIZE, APX;
+    b"r*r*v*"     , [0x04, 0x0B        ], X, EVEX_OP | NDD | AUTO_SIZE, APX;
 ]
 "orpd" = [
     b"yowo"       , [0x0F, 0x56        ], X, PREF_66, SSE2;
@@ -1515,7 +1730,7 @@ Ops!(
     b""           , [0x6E              ], X, REP;
 ]
 "outsd" = [
-    b""           , [0x6F              ], X, REP;
+    b""           , [0x6F              ], X, REP | DWORD_SIZE;
 ]
 "outsw" = [
     b""           , [0x6F              ], X, WORD_SIZE | REP;
@@ -1625,19 +1840,19 @@ Ops!(
     b"yoyoib"     , [0x0F, 0x3A, 0x0E  ], X, PREF_66, SSE41;
 ]
 "pclmulhqhqdq" = [
-    b"yowo"       , [0x0F, 0x3A, 0x44, 0x11], X, IMM_OP | PREF_66, SSE;
+    b"yowo"       , [0x0F, 0x3A, 0x44, 0x11], X, IMM_OP | PREF_66, PCLMULQDQ;
 ]
 "pclmulhqlqdq" = [
-    b"yowo"       , [0x0F, 0x3A, 0x44, 0x01], X, PREF_66 | IMM_OP, SSE;
+    b"yowo"       , [0x0F, 0x3A, 0x44, 0x01], X, PREF_66 | IMM_OP, PCLMULQDQ;
 ]
 "pclmullqhqdq" = [
-    b"yowo"       , [0x0F, 0x3A, 0x44, 0x10], X, PREF_66 | IMM_OP, SSE;
+    b"yowo"       , [0x0F, 0x3A, 0x44, 0x10], X, PREF_66 | IMM_OP, PCLMULQDQ;
 ]
 "pclmullqlqdq" = [
-    b"yowo"       , [0x0F, 0x3A, 0x44, 0x00], X, PREF_66 | IMM_OP, SSE;
+    b"yowo"       , [0x0F, 0x3A, 0x44, 0x00], X, PREF_66 | IMM_OP, PCLMULQDQ;
 ]
 "pclmulqdq" = [
-    b"yowoib"     , [0x0F, 0x3A, 0x44  ], X, PREF_66, SSE;
+    b"yowoib"     , [0x0F, 0x3A, 0x44  ], X, PREF_66, PCLMULQDQ;
 ]
 "pcmpeqb" = [
     b"xquq"       , [0x0F, 0x74        ], X, DEFAULT, MMX;
@@ -2017,14 +2232,17 @@ Ops!(
     b""           , [0x61              ], X, X86_ONLY | WORD_SIZE;
 ]
 "popad" = [
-    b""           , [0x61              ], X, X86_ONLY;
+    b""           , [0x61              ], X, X86_ONLY | DWORD_SIZE;
 ]
 "popcnt" = [
-    b"r*v*"       , [0x0F, 0xB8        ], X, AUTO_SIZE | PREF_F3;
+    b"r*v*"       , [0x0F, 0xB8        ], X, AUTO_SIZE | PREF_F3, POPCNT;
 ]
 "popf" = [
     b""           , [0x9D              ], X;
 ]
+"popfd" = [
+    b""           , [0x9D              ], X, X86_ONLY | DWORD_SIZE;
+]
 "popfq" = [
     b""           , [0x9D              ], X;
 ]
@@ -2051,7 +2269,7 @@ Ops!(
     b"mb"         , [0x0F, 0x18        ], 3;
 ]
 "prefetchw" = [
-    b"mq"         , [0x0F, 0x0D        ], 1, DEFAULT, TDNOW;
+    b"mq"         , [0x0F, 0x0D        ], 1, DEFAULT, PRFCHW;
 ]
 "prefetchwt1" = [
     b"mb"         , [0x0F, 0x0D        ], 2, DEFAULT, PREFETCHWT1;
@@ -2227,7 +2445,7 @@ Ops!(
     b"Vw"         , [0x0F, 0xA8        ], X;
     b"ib"         , [0x6A              ], X, EXACT_SIZE;
     b"iw"         , [0x68              ], X, EXACT_SIZE | WORD_SIZE;
-    b"id"         , [0x68              ], X;
+    b"id"         , [0x68              ], X, DWORD_SIZE;
     b"r*"         , [0x50              ], X, AUTO_NO32 | SHORT_ARG;
     b"v*"         , [0xFF              ], 6, AUTO_NO32;
 ]
@@ -2235,11 +2453,14 @@ Ops!(
     b""           , [0x60              ], X, X86_ONLY | WORD_SIZE;
 ]
 "pushad" = [
-    b""           , [0x60              ], X, X86_ONLY;
+    b""           , [0x60              ], X, X86_ONLY | DWORD_SIZE;
 ]
 "pushf" = [
     b""           , [0x9C              ], X;
 ]
+"pushfd" = [
+    b""           , [0x9C              ], X, X86_ONLY | DWORD_SIZE;
+]
 "pushfq" = [
     b""           , [0x9C              ], X;
 ]
@@ -2270,12 +2491,12 @@ Ops!(
     b"v*ib"       , [0xC1              ], 3, AUTO_SIZE;
 ]
 "rdfsbase" = [
-    b"rd"         , [0x0F, 0xAE        ], 0, PREF_F3;
-    b"rq"         , [0x0F, 0xAE        ], 0, WITH_REXW | PREF_F3;
+    b"rd"         , [0x0F, 0xAE        ], 0, PREF_F3, FSGSBASE;
+    b"rq"         , [0x0F, 0xAE        ], 0, WITH_REXW | PREF_F3, FSGSBASE;
 ]
 "rdgsbase" = [
-    b"rd"         , [0x0F, 0xAE        ], 1, PREF_F3;
-    b"rq"         , [0x0F, 0xAE        ], 1, WITH_REXW | PREF_F3;
+    b"rd"         , [0x0F, 0xAE        ], 1, PREF_F3, FSGSBASE;
+    b"rq"         , [0x0F, 0xAE        ], 1, WITH_REXW | PREF_F3, FSGSBASE;
 ]
 "rdm" = [
     b""           , [0x0F, 0x3A        ], X, DEFAULT, CYRIX;
@@ -2293,10 +2514,10 @@ Ops!(
     b""           , [0x0F, 0x33        ], X;
 ]
 "rdrand" = [
-    b"rq"         , [0x0F, 0xC7        ], 6, WITH_REXW;
+    b"rq"         , [0x0F, 0xC7        ], 6, WITH_REXW, RDRAND;
 ]
 "rdseed" = [
-    b"rq"         , [0x0F, 0xC7        ], 7, WITH_REXW;
+    b"rq"         , [0x0F, 0xC7        ], 7, WITH_REXW, RDSEED;
 ]
 "rdshr" = [
     b"vd"         , [0x0F, 0x36        ], 0, DEFAULT, CYRIX;
@@ -2402,12 +2623,19 @@ Ops!(
     b"r*i*"       , [0x81              ], 3, AUTO_SIZE ;
     b"r*r*"       , [0x19              ], X, AUTO_SIZE | ENC_MR;
     b"r*v*"       , [0x1B              ], X, AUTO_SIZE;
+    b"rbvbib"     , [0x04, 0x80        ], 3, EVEX_OP | NDD, APX;
+    b"rbvbrb"     , [0x04, 0x18        ], X, EVEX_OP | NDD, APX;
+    b"rbrbvb"     , [0x04, 0x1A        ], X, EVEX_OP | NDD, APX;
+    b"r*v*ib"     , [0x04, 0x83        ], 3, EVEX_OP | NDD | AUTO_SIZE | EXACT_SIZE, APX;
+    b"r*v*i*"     , [0x04, 0x81        ], 3, EVEX_OP | NDD | AUTO_SIZE, APX;
+    b"r*v*r*"     , [0x04, 0x19        ], X, EVEX_OP | NDD | AUTO_SIZE, APX;
+    b"r*r*v*"     , [0x04, 0x1B        ], X, EVEX_OP | NDD | AUTO_SIZE, APX;
 ]
 "scasb" = [
     b""           , [0xAE              ], X, REPE;
 ]
 "scasd" = [
-    b""           , [0xAF              ], X, REPE;
+    b""           , [0xAF              ], X, REPE | DWORD_SIZE;
 ]
 "scasq" = [
     b""           , [0xAF              ], X, REPE | WITH_REXW;
@@ -2415,6 +2643,9 @@ Ops!(
 "scasw" = [
     b""           , [0xAF              ], X, REPE | WORD_SIZE;
 ]
+"serialize" = [
+    b""           , [0x0F, 0x01, 0xE8  ], X, DEFAULT, SERIALIZE;
+]
 "sfence" = [
     b""           , [0x0F, 0xAE, 0xF8  ], X, DEFAULT, AMD;
 ]
@@ -2530,7 +2761,7 @@ Ops!(
     b""           , [0xAA              ], X, REP;
 ]
 "stosd" = [
-    b""           , [0xAB              ], X, REP;
+    b""           , [0xAB              ], X, REP | DWORD_SIZE;
 ]
 "stosq" = [
     b""           , [0xAB              ], X, WITH_REXW | REP;
@@ -2542,6 +2773,9 @@ Ops!(
     b"m!"         , [0x0F, 0x00        ], 1;
     b"r*"         , [0x0F, 0x00        ], 1, AUTO_SIZE;
 ]
+"sttilecfg" = [
+    b"m!"         , [0x02, 0x49        ], 0, VEX_OP | PREF_66, AMX_TILE;
+]
 "sub" = [
     b"Abib"       , [0x2C              ], X;
     b"mbib"       , [0x80              ], 5, LOCK;
@@ -2557,6 +2791,13 @@ Ops!(
     b"r*i*"       , [0x81              ], 5, AUTO_SIZE ;
     b"r*r*"       , [0x29              ], X, AUTO_SIZE | ENC_MR;
     b"r*v*"       , [0x2B              ], X, AUTO_SIZE;
+    b"rbvbib"     , [0x04, 0x80        ], 5, EVEX_OP | NDD, APX;
+    b"rbvbrb"     , [0x04, 0x28        ], X, EVEX_OP | NDD, APX;
+    b"rbrbvb"     , [0x04, 0x2A        ], X, EVEX_OP | NDD, APX;
+    b"r*v*ib"     , [0x04, 0x83        ], 5, EVEX_OP | NDD | AUTO_SIZE | EXACT_SIZE, APX;
+    b"r*v*i*"     , [0x04, 0x81        ], 5, EVEX_OP | NDD | AUTO_SIZE, APX;
+    b"r*v*r*"     , [0x04, 0x29        ], X, EVEX_OP | NDD | AUTO_SIZE, APX;
+    b"r*r*v*"     , [0x04, 0x2B        ], X, EVEX_OP | NDD | AUTO_SIZE, APX;
 ]
 "subpd" = [
     b"yowo"       , [0x0F, 0x5C        ], X, PREF_66, SSE2;
@@ -2599,6 +2840,12 @@ Ops!(
 "t1mskc" = [
     b"r*v*"       , [0x09, 0x01        ], 7, XOP_OP | AUTO_REXW | ENC_VM, TBM;
 ]
+"tdpbf16ps" = [
+    b"tztztz"     , [0x02, 0x5C        ], X, VEX_OP | ENC_MR | PREF_F3, AMX_BF16;
+]
+"tdpbssd" = [
+    b"tztztz"     , [0x02, 0x5E        ], X, VEX_OP | ENC_MR | PREF_F2, AMX_INT8;
+]
 "test" = [
     b"Abib"       , [0xA8              ], X;
     b"rbmb"       , [0x84              ], X;
@@ -2609,6 +2856,18 @@ Ops!(
     b"v*i*"       , [0xF7              ], 0, AUTO_SIZE;
     b"v*r*"       , [0x85              ], X, AUTO_SIZE | ENC_MR;
 ]
+"tileloadd" = [
+    b"tzm!"       , [0x02, 0x4B        ], X, VEX_OP | SIBMEM | PREF_F2, AMX_TILE;
+]
+"tilerelease" = [
+    b""           , [0x02, 0x49, 0xC0  ], X, VEX_OP, AMX_TILE;
+]
+"tilestored" = [
+    b"m!tz"       , [0x02, 0x4B        ], X, VEX_OP | SIBMEM | PREF_F3, AMX_TILE;
+]
+"tilezero" = [
+    b"tz"         , [0x02, 0x49        ], X, VEX_OP | ENC_MR | PREF_F2, AMX_TILE;
+]
 "tzcnt" = [
     b"r*v*"       , [0x0F, 0xBC        ], X, AUTO_SIZE | PREF_F3, BMI1;
 ]
@@ -2643,17 +2902,23 @@ Ops!(
 ]
 "vaddpd" = [
     b"y*y*w*"     , [0x01, 0x58        ], X, VEX_OP | AUTO_VEXL | PREF_66, AVX;
+    b"y*y*w*"     , [0x01, 0x58        ], X, EVEX_OP | AUTO_VEXL | WITH_REXW | OPMASK | ZEROING | BCST | ROUNDING | PREF_66, AVX512F;
 ]
 "vaddps" = [
     b"y*y*w*"     , [0x01, 0x58        ], X, VEX_OP | AUTO_VEXL, AVX;
+    b"y*y*w*"     , [0x01, 0x58        ], X, EVEX_OP | AUTO_VEXL | OPMASK | ZEROING | BCST | ROUNDING, AVX512F;
 ]
 "vaddsd" = [
     b"yoyomq"     , [0x01, 0x58        ], X, VEX_OP | PREF_F2, AVX;
     b"yoyoyo"     , [0x01, 0x58        ], X, VEX_OP | PREF_F2, AVX;
+    b"yoyomq"     , [0x01, 0x58        ], X, EVEX_OP | WITH_REXW | OPMASK | ZEROING | PREF_F2, AVX512F;
+    b"yoyoyo"     , [0x01, 0x58        ], X, EVEX_OP | WITH_REXW | OPMASK | ZEROING | ROUNDING | PREF_F2, AVX512F;
 ]
 "vaddss" = [
     b"yoyomd"     , [0x01, 0x58        ], X, VEX_OP | PREF_F3, AVX;
     b"yoyoyo"     , [0x01, 0x58        ], X, VEX_OP | PREF_F3, AVX;
+    b"yoyomd"     , [0x01, 0x58        ], X, EVEX_OP | OPMASK | ZEROING | PREF_F3, AVX512F;
+    b"yoyoyo"     , [0x01, 0x58        ], X, EVEX_OP | OPMASK | ZEROING | ROUNDING | PREF_F3, AVX512F;
 ]
 "vaddsubpd" = [
     b"y*y*w*"     , [0x01, 0xD0        ], X, VEX_OP | AUTO_VEXL | PREF_66, AVX;
@@ -2662,34 +2927,46 @@ Ops!(
     b"y*y*w*"     , [0x01, 0xD0        ], X, VEX_OP | AUTO_VEXL | PREF_F2, AVX;
 ]
 "vaesdec" = [
-    b"yoyowo"     , [0x02, 0xDE        ], X, VEX_OP | PREF_66, AVX;
+    b"yoyowo"     , [0x02, 0xDE        ], X, VEX_OP | PREF_66, AES | AVX;
+    b"yhyhwh"     , [0x02, 0xDE        ], X, VEX_OP | WITH_VEXL | PREF_66, VAES;
+    b"y*y*w*"     , [0x02, 0xDE        ], X, EVEX_OP | AUTO_VEXL | PREF_66, VAES | AVX512F;
 ]
 "vaesdeclast" = [
-    b"yoyowo"     , [0x02, 0xDF        ], X, VEX_OP | PREF_66, AVX;
+    b"yoyowo"     , [0x02, 0xDF        ], X, VEX_OP | PREF_66, AES | AVX;
+    b"yhyhwh"     , [0x02, 0xDF        ], X, VEX_OP | WITH_VEXL | PREF_66, VAES;
+    b"y*y*w*"     , [0x02, 0xDF        ], X, EVEX_OP | AUTO_VEXL | PREF_66, VAES | AVX512F;
 ]
 "vaesenc" = [
-    b"yoyowo"     , [0x02, 0xDC        ], X, VEX_OP | PREF_66, AVX;
+    b"yoyowo"     , [0x02, 0xDC        ], X, VEX_OP | PREF_66, AES | AVX;
+    b"yhyhwh"     , [0x02, 0xDC        ], X, VEX_OP | WITH_VEXL | PREF_66, VAES;
+    b"y*y*w*"     , [0x02, 0xDC        ], X, EVEX_OP | AUTO_VEXL | PREF_66, VAES | AVX512F;
 ]
 "vaesenclast" = [
-    b"yoyowo"     , [0x02, 0xDD        ], X, VEX_OP | PREF_66, AVX;
+    b"yoyowo"     , [0x02, 0xDD        ], X, VEX_OP | PREF_66, AES | AVX;
+    b"yhyhwh"     , [0x02, 0xDD        ], X, VEX_OP | WITH_VEXL | PREF_66, VAES;
+    b"y*y*w*"     , [0x02, 0xDD        ], X, EVEX_OP | AUTO_VEXL | PREF_66, VAES | AVX512F;
 ]
 "vaesimc" = [
-    b"yowo"       , [0x02, 0xDB        ], X, VEX_OP | PREF_66, AVX;
+    b"yowo"       , [0x02, 0xDB        ], X, VEX_OP | PREF_66, AES | AVX;
 ]
 "vaeskeygenassist" = [
-    b"yowoib"     , [0x03, 0xDF        ], X, VEX_OP | PREF_66, AVX;
+    b"yowoib"     , [0x03, 0xDF        ], X, VEX_OP | PREF_66, AES | AVX;
 ]
 "vandnpd" = [
     b"y*y*w*"     , [0x01, 0x55        ], X, VEX_OP | AUTO_VEXL | PREF_66, AVX;
+    b"y*y*w*"     , [0x01, 0x55        ], X, EVEX_OP | AUTO_VEXL | WITH_REXW | OPMASK | ZEROING | BCST | PREF_66, AVX512DQ;
 ]
 "vandnps" = [
     b"y*y*w*"     , [0x01, 0x55        ], X, VEX_OP | AUTO_VEXL, AVX;
+    b"y*y*w*"     , [0x01, 0x55        ], X, EVEX_OP | AUTO_VEXL | OPMASK | ZEROING | BCST, AVX512DQ;
 ]
 "vandpd" = [
     b"y*y*w*"     , [0x01, 0x54        ], X, VEX_OP | AUTO_VEXL | PREF_66, AVX;
+    b"y*y*w*"     , [0x01, 0x54        ], X, EVEX_OP | AUTO_VEXL | WITH_REXW | OPMASK | ZEROING | BCST | PREF_66, AVX512DQ;
 ]
 "vandps" = [
     b"y*y*w*"     , [0x01, 0x54        ], X, VEX_OP | AUTO_VEXL, AVX;
+    b"y*y*w*"     , [0x01, 0x54        ], X, EVEX_OP | AUTO_VEXL | OPMASK | ZEROING | BCST, AVX512DQ;
 ]
 "vblendpd" = [
     b"y*y*w*ib"   , [0x03, 0x0D        ], X, VEX_OP | AUTO_VEXL | ENC_MR | PREF_66, AVX;
@@ -2706,9 +2983,17 @@ Ops!(
 "vbroadcastf128" = [
     b"yhmo"       , [0x02, 0x1A        ], X, WITH_VEXL | VEX_OP | PREF_66, AVX;
 ]
+"vbroadcastf32x4" = [
+    b"yhmo"       , [0x02, 0x1A        ], X, EVEX_OP | WITH_VEXL | OPMASK | ZEROING | PREF_66, AVX512F | AVX512VL;
+    b"yzmo"       , [0x02, 0x1A        ], X, EVEX_OP | WITH_EVEXL | OPMASK | ZEROING | PREF_66, AVX512F;
+]
 "vbroadcasti128" = [
     b"yhmo"       , [0x02, 0x5A        ], X, WITH_VEXL | VEX_OP | PREF_66, AVX2;
 ]
+"vbroadcasti32x4" = [
+    b"yhmo"       , [0x02, 0x5A        ], X, EVEX_OP | WITH_VEXL | OPMASK | ZEROING | PREF_66, AVX512F | AVX512VL;
+    b"yzmo"       , [0x02, 0x5A        ], X, EVEX_OP | WITH_EVEXL | OPMASK | ZEROING | PREF_66, AVX512F;
+]
 "vbroadcastsd" = [
     b"yhmq"       , [0x02, 0x19        ], X, VEX_OP | WITH_VEXL | PREF_66, AVX;
     b"yhyo"       , [0x02, 0x19        ], X, VEX_OP | WITH_VEXL | PREF_66, AVX;
@@ -2716,6 +3001,8 @@ Ops!(
 "vbroadcastss" = [
     b"y*md"       , [0x02, 0x18        ], X, VEX_OP | PREF_66, AVX;
     b"y*yo"       , [0x02, 0x18        ], X, VEX_OP | AUTO_VEXL | PREF_66, AVX;
+    b"y*md"       , [0x02, 0x18        ], X, EVEX_OP | AUTO_VEXL | OPMASK | ZEROING | PREF_66, AVX512F;
+    b"y*yo"       , [0x02, 0x18        ], X, EVEX_OP | AUTO_VEXL | OPMASK | ZEROING | PREF_66, AVX512F;
 ]
 "vcmpeq_ospd" = [
     b"y*y*w*"     , [0x01, 0xC2, 0x10  ], X, VEX_OP | PREF_66 | IMM_OP, AVX;
@@ -3286,9 +3573,11 @@ Ops!(
 ]
 "vcmppd" = [
     b"y*y*w*ib"   , [0x01, 0xC2        ], X, VEX_OP | AUTO_VEXL | ENC_MR | PREF_66, AVX;
+    b"jqy*w*ib"   , [0x01, 0xC2        ], X, EVEX_OP | AUTO_VEXL | WITH_REXW | OPMASK | BCST | SAE | PREF_66, AVX512F;
 ]
 "vcmpps" = [
     b"y*y*w*ib"   , [0x01, 0xC2        ], X, VEX_OP | AUTO_VEXL | ENC_MR, AVX;
+    b"jqy*w*ib"   , [0x01, 0xC2        ], X, EVEX_OP | AUTO_VEXL | OPMASK | BCST | SAE, AVX512F;
 ]
 "vcmpsd" = [
     b"yoyomqib"   , [0x01, 0xC2        ], X, VEX_OP | PREF_F2, AVX;
@@ -3398,6 +3687,7 @@ Ops!(
 ]
 "vcvtdq2ps" = [
     b"y*w*"       , [0x01, 0x5B        ], X, VEX_OP | AUTO_VEXL, AVX;
+    b"y*w*"       , [0x01, 0x5B        ], X, EVEX_OP | AUTO_VEXL | OPMASK | ZEROING | BCST | ROUNDING, AVX512F;
 ]
 "vcvtpd2dq" = [
     b"yom*"       , [0x01, 0xE6        ], X, VEX_OP | AUTO_VEXL | PREF_F2, AVX;
@@ -3406,21 +3696,30 @@ Ops!(
 "vcvtpd2ps" = [
     b"yom*"       , [0x01, 0x5A        ], X, VEX_OP | AUTO_VEXL | PREF_66, AVX;
     b"yoy*"       , [0x01, 0x5A        ], X, VEX_OP | AUTO_VEXL | PREF_66, AVX;
+    b"yhwz"       , [0x01, 0x5A        ], X, EVEX_OP | WITH_EVEXL | WITH_REXW | OPMASK | ZEROING | BCST | ROUNDING | PREF_66, AVX512F;
 ]
 "vcvtph2ps" = [
-    b"yomq"       , [0x02, 0x13        ], X, VEX_OP | PREF_66, AVX;
-    b"y*wo"       , [0x02, 0x13        ], X, VEX_OP | AUTO_VEXL | PREF_66, AVX;
+    b"yomq"       , [0x02, 0x13        ], X, VEX_OP | PREF_66, F16C;
+    b"y*wo"       , [0x02, 0x13        ], X, VEX_OP | AUTO_VEXL | PREF_66, F16C;
 ]
 "vcvtps2dq" = [
     b"y*w*"       , [0x01, 0x5B        ], X, VEX_OP | AUTO_VEXL | PREF_66, AVX;
+    b"y*w*"       , [0x01, 0x5B        ], X, EVEX_OP | AUTO_VEXL | OPMASK | ZEROING | BCST | ROUNDING | PREF_66, AVX512F;
 ]
 "vcvtps2pd" = [
     b"yomq"       , [0x01, 0x5A        ], X, VEX_OP, AVX;
     b"y*wo"       , [0x01, 0x5A        ], X, VEX_OP | AUTO_VEXL, AVX;
+    b"yzwh"       , [0x01, 0x5A        ], X, EVEX_OP | WITH_EVEXL | OPMASK | ZEROING | BCST | SAE, AVX512F;
 ]
 "vcvtps2ph" = [
-    b"mqyoib"     , [0x03, 0x1D        ], X, VEX_OP | ENC_MR | PREF_66, AVX;
-    b"woy*ib"     , [0x03, 0x1D        ], X, VEX_OP | AUTO_VEXL | ENC_MR | PREF_66, AVX;
+    b"mqyoib"     , [0x03, 0x1D        ], X, VEX_OP | ENC_MR | PREF_66, F16C;
+    b"woy*ib"     , [0x03, 0x1D        ], X, VEX_OP | AUTO_VEXL | ENC_MR | PREF_66, F16C;
+]
+"vcvtps2udq" = [
+    b"y*w*"       , [0x01, 0x79        ], X, EVEX_OP | AUTO_VEXL | OPMASK | ZEROING | BCST | ROUNDING, AVX512F;
+]
+"vcvtqq2pd" = [
+    b"y*w*"       , [0x01, 0xE6        ], X, EVEX_OP | AUTO_VEXL | WITH_REXW | OPMASK | ZEROING | BCST | ROUNDING | PREF_F3, AVX512DQ;
 ]
 "vcvtsd2si" = [
     b"r*mq"       , [0x01, 0x2D        ], X, VEX_OP | AUTO_REXW | PREF_F2, AVX;
@@ -3450,6 +3749,7 @@ Ops!(
 ]
 "vcvttps2dq" = [
     b"y*w*"       , [0x01, 0x5B        ], X, VEX_OP | AUTO_VEXL | PREF_F3, AVX;
+    b"y*w*"       , [0x01, 0x5B        ], X, EVEX_OP | AUTO_VEXL | OPMASK | ZEROING | BCST | SAE | PREF_F3, AVX512F;
 ]
 "vcvttsd2si" = [
     b"r*mq"       , [0x01, 0x2C        ], X, VEX_OP | AUTO_REXW | PREF_F2, AVX;
@@ -3459,19 +3759,28 @@ Ops!(
     b"r*md"       , [0x01, 0x2C        ], X, VEX_OP | AUTO_REXW | PREF_F3, AVX;
     b"r*yo"       , [0x01, 0x2C        ], X, VEX_OP | AUTO_REXW | PREF_F3, AVX;
 ]
+"vcvtudq2ps" = [
+    b"y*w*"       , [0x01, 0x7A        ], X, EVEX_OP | AUTO_VEXL | OPMASK | ZEROING | BCST | ROUNDING | PREF_F2, AVX512F;
+]
 "vdivpd" = [
     b"y*y*w*"     , [0x01, 0x5E        ], X, VEX_OP | AUTO_VEXL | PREF_66, AVX;
+    b"y*y*w*"     , [0x01, 0x5E        ], X, EVEX_OP | AUTO_VEXL | WITH_REXW | OPMASK | ZEROING | BCST | ROUNDING | PREF_66, AVX512F;
 ]
 "vdivps" = [
     b"y*y*w*"     , [0x01, 0x5E        ], X, VEX_OP | AUTO_VEXL, AVX;
+    b"y*y*w*"     , [0x01, 0x5E        ], X, EVEX_OP | AUTO_VEXL | OPMASK | ZEROING | BCST | ROUNDING, AVX512F;
 ]
 "vdivsd" = [
     b"yoyomq"     , [0x01, 0x5E        ], X, VEX_OP | PREF_F2, AVX;
     b"yoyoyo"     , [0x01, 0x5E        ], X, VEX_OP | PREF_F2, AVX;
+    b"yoyomq"     , [0x01, 0x5E        ], X, EVEX_OP | WITH_REXW | OPMASK | ZEROING | PREF_F2, AVX512F;
+    b"yoyoyo"     , [0x01, 0x5E        ], X, EVEX_OP | WITH_REXW | OPMASK | ZEROING | ROUNDING | PREF_F2, AVX512F;
 ]
 "vdivss" = [
     b"yoyomd"     , [0x01, 0x5E        ], X, VEX_OP | PREF_F3, AVX;
     b"yoyoyo"     , [0x01, 0x5E        ], X, VEX_OP | PREF_F3, AVX;
+    b"yoyomd"     , [0x01, 0x5E        ], X, EVEX_OP | OPMASK | ZEROING | PREF_F3, AVX512F;
+    b"yoyoyo"     , [0x01, 0x5E        ], X, EVEX_OP | OPMASK | ZEROING | ROUNDING | PREF_F3, AVX512F;
 ]
 "vdppd" = [
     b"yoyowoib"   , [0x03, 0x41        ], X, VEX_OP | PREF_66, AVX;
@@ -3487,12 +3796,35 @@ Ops!(
     b"m!"         , [0x0F, 0x00        ], 5;
     b"rw"         , [0x0F, 0x00        ], 5;
 ]
+"vexp2pd" = [
+    b"yzwz"       , [0x02, 0xC8        ], X, EVEX_OP | WITH_EVEXL | WITH_REXW | OPMASK | ZEROING | BCST | SAE | PREF_66, AVX512ER;
+]
+"vexp2ps" = [
+    b"yzwz"       , [0x02, 0xC8        ], X, EVEX_OP | WITH_EVEXL | OPMASK | ZEROING | BCST | SAE | PREF_66, AVX512ER;
+]
 "vextractf128" = [
     b"woyhib"     , [0x03, 0x19        ], X, WITH_VEXL | VEX_OP | ENC_MR | PREF_66, AVX;
 ]
+"vextractf32x4" = [
+    b"woyhib"     , [0x03, 0x19        ], X, EVEX_OP | WITH_VEXL | ENC_MR | OPMASK | ZEROING | PREF_66, AVX512F | AVX512VL;
+    b"woyzib"     , [0x03, 0x19        ], X, EVEX_OP | WITH_EVEXL | ENC_MR | OPMASK | ZEROING | PREF_66, AVX512F;
+]
+"vextractf32x8" = [
+    b"whyzib"     , [0x03, 0x1B        ], X, EVEX_OP | WITH_EVEXL | ENC_MR | OPMASK | ZEROING | PREF_66, AVX512DQ;
+]
+"vextractf64x4" = [
+    b"whyzib"     , [0x03, 0x1B        ], X, EVEX_OP | WITH_EVEXL | WITH_REXW | ENC_MR | OPMASK | ZEROING | PREF_66, AVX512F;
+]
 "vextracti128" = [
     b"woyhib"     , [0x03, 0x39        ], X, WITH_VEXL | VEX_OP | ENC_MR | PREF_66, AVX2;
 ]
+"vextracti32x4" = [
+    b"woyhib"     , [0x03, 0x39        ], X, EVEX_OP | WITH_VEXL | ENC_MR | OPMASK | ZEROING | PREF_66, AVX512F | AVX512VL;
+    b"woyzib"     , [0x03, 0x39        ], X, EVEX_OP | WITH_EVEXL | ENC_MR | OPMASK | ZEROING | PREF_66, AVX512F;
+]
+"vextracti64x4" = [
+    b"whyzib"     , [0x03, 0x3B        ], X, EVEX_OP | WITH_EVEXL | WITH_REXW | ENC_MR | OPMASK | ZEROING | PREF_66, AVX512F;
+]
 "vextractps" = [
     b"vdyoib"     , [0x03, 0x17        ], X, VEX_OP | ENC_MR | PREF_66, AVX;
 ]
@@ -3512,9 +3844,11 @@ Ops!(
 ]
 "vfmadd132pd" = [
     b"y*y*w*"     , [0x02, 0x98        ], X, VEX_OP | AUTO_VEXL | PREF_66, FMA;
+    b"y*y*w*"     , [0x02, 0x98        ], X, EVEX_OP | AUTO_VEXL | WITH_REXW | OPMASK | ZEROING | BCST | ROUNDING | PREF_66, AVX512F;
 ]
 "vfmadd132ps" = [
     b"y*y*w*"     , [0x02, 0x98        ], X, VEX_OP | AUTO_VEXL | PREF_66, FMA;
+    b"y*y*w*"     , [0x02, 0x98        ], X, EVEX_OP | AUTO_VEXL | OPMASK | ZEROING | BCST | ROUNDING | PREF_66, AVX512F;
 ]
 "vfmadd132sd" = [
     b"yoyomq"     , [0x02, 0x99        ], X, VEX_OP | WITH_REXW | PREF_66, FMA;
@@ -3526,9 +3860,11 @@ Ops!(
 ]
 "vfmadd213pd" = [
     b"y*y*w*"     , [0x02, 0xA8        ], X, VEX_OP | AUTO_VEXL | PREF_66, FMA;
+    b"y*y*w*"     , [0x02, 0xA8        ], X, EVEX_OP | AUTO_VEXL | WITH_REXW | OPMASK | ZEROING | BCST | ROUNDING | PREF_66, AVX512F;
 ]
 "vfmadd213ps" = [
     b"y*y*w*"     , [0x02, 0xA8        ], X, VEX_OP | AUTO_VEXL | PREF_66, FMA;
+    b"y*y*w*"     , [0x02, 0xA8        ], X, EVEX_OP | AUTO_VEXL | OPMASK | ZEROING | BCST | ROUNDING | PREF_66, AVX512F;
 ]
 "vfmadd213sd" = [
     b"yoyomq"     , [0x02, 0xA9        ], X, VEX_OP | WITH_REXW | PREF_66, FMA;
@@ -3540,9 +3876,11 @@ Ops!(
 ]
 "vfmadd231pd" = [
     b"y*y*w*"     , [0x02, 0xB8        ], X, VEX_OP | AUTO_VEXL | PREF_66, FMA;
+    b"y*y*w*"     , [0x02, 0xB8        ], X, EVEX_OP | AUTO_VEXL | WITH_REXW | OPMASK | ZEROING | BCST | ROUNDING | PREF_66, AVX512F;
 ]
 "vfmadd231ps" = [
     b"y*y*w*"     , [0x02, 0xB8        ], X, VEX_OP | AUTO_VEXL | PREF_66, FMA;
+    b"y*y*w*"     , [0x02, 0xB8        ], X, EVEX_OP | AUTO_VEXL | OPMASK | ZEROING | BCST | ROUNDING | PREF_66, AVX512F;
 ]
 "vfmadd231sd" = [
     b"yoyomq"     , [0x02, 0xB9        ], X, VEX_OP | WITH_REXW | PREF_66, FMA;
@@ -4008,9 +4346,37 @@ Ops!(
 ]
 "vgatherdpd" = [
     b"y*loy*"     , [0x02, 0x92        ], X, VEX_OP | AUTO_VEXL | ENC_MR | PREF_66, AVX2;
+    b"yolo"       , [0x02, 0x92        ], X, EVEX_OP | WITH_REXW | OPMASK | PREF_66, AVX512F | AVX512VL;
+    b"yhlo"       , [0x02, 0x92        ], X, EVEX_OP | WITH_VEXL | WITH_REXW | OPMASK | PREF_66, AVX512F | AVX512VL;
+    b"yzlh"       , [0x02, 0x92        ], X, EVEX_OP | WITH_EVEXL | WITH_REXW | OPMASK | PREF_66, AVX512F;
 ]
 "vgatherdps" = [
     b"y*k*y*"     , [0x02, 0x92        ], X, VEX_OP | AUTO_VEXL | ENC_MR | PREF_66, AVX2;
+    b"y*k*"       , [0x02, 0x92        ], X, EVEX_OP | AUTO_VEXL | OPMASK | PREF_66, AVX512F;
+]
+"vgatherpf0dpd" = [
+    b"lh"         , [0x02, 0xC6        ], 1, EVEX_OP | WITH_EVEXL | WITH_REXW | OPMASK | PREF_66, AVX512PF;
+]
+"vgatherpf0dps" = [
+    b"kz"         , [0x02, 0xC6        ], 1, EVEX_OP | WITH_EVEXL | OPMASK | PREF_66, AVX512PF;
+]
+"vgatherpf0qpd" = [
+    b"lz"         , [0x02, 0xC7        ], 1, EVEX_OP | WITH_EVEXL | WITH_REXW | OPMASK | PREF_66, AVX512PF;
+]
+"vgatherpf0qps" = [
+    b"kz"         , [0x02, 0xC7        ], 1, EVEX_OP | WITH_EVEXL | OPMASK | PREF_66, AVX512PF;
+]
+"vgatherpf1dpd" = [
+    b"lh"         , [0x02, 0xC6        ], 2, EVEX_OP | WITH_EVEXL | WITH_REXW | OPMASK | PREF_66, AVX512PF;
+]
+"vgatherpf1dps" = [
+    b"kz"         , [0x02, 0xC6        ], 2, EVEX_OP | WITH_EVEXL | OPMASK | PREF_66, AVX512PF;
+]
+"vgatherpf1qpd" = [
+    b"lz"         , [0x02, 0xC7        ], 2, EVEX_OP | WITH_EVEXL | WITH_REXW | OPMASK | PREF_66, AVX512PF;
+]
+"vgatherpf1qps" = [
+    b"kz"         , [0x02, 0xC7        ], 2, EVEX_OP | WITH_EVEXL | OPMASK | PREF_66, AVX512PF;
 ]
 "vgatherqpd" = [
     b"y*l*y*"     , [0x02, 0x93        ], X, VEX_OP | AUTO_VEXL | ENC_MR | PREF_66, AVX2;
@@ -4018,6 +4384,18 @@ Ops!(
 "vgatherqps" = [
     b"yok*yo"     , [0x02, 0x93        ], X, VEX_OP | AUTO_VEXL | ENC_MR | PREF_66, AVX2;
 ]
+"vgf2p8affineinvqb" = [
+    b"y*y*w*ib"   , [0x03, 0xCF        ], X, VEX_OP | AUTO_VEXL | WITH_REXW | PREF_66, GFNI | AVX;
+    b"y*y*w*ib"   , [0x03, 0xCF        ], X, EVEX_OP | AUTO_VEXL | WITH_REXW | OPMASK | ZEROING | BCST | PREF_66, GFNI | AVX512F;
+]
+"vgf2p8affineqb" = [
+    b"y*y*w*ib"   , [0x03, 0xCE        ], X, VEX_OP | AUTO_VEXL | WITH_REXW | PREF_66, GFNI | AVX;
+    b"y*y*w*ib"   , [0x03, 0xCE        ], X, EVEX_OP | AUTO_VEXL | WITH_REXW | OPMASK | ZEROING | BCST | PREF_66, GFNI | AVX512F;
+]
+"vgf2p8mulb" = [
+    b"y*y*w*"     , [0x02, 0xCF        ], X, VEX_OP | AUTO_VEXL | PREF_66, GFNI | AVX;
+    b"y*y*w*"     , [0x02, 0xCF        ], X, EVEX_OP | AUTO_VEXL | OPMASK | ZEROING | PREF_66, GFNI | AVX512F;
+]
 "vhaddpd" = [
     b"y*y*w*"     , [0x01, 0x7C        ], X, VEX_OP | AUTO_VEXL | PREF_66, AVX;
 ]
@@ -4033,9 +4411,26 @@ Ops!(
 "vinsertf128" = [
     b"yhyhwoib"   , [0x03, 0x18        ], X, WITH_VEXL | VEX_OP | PREF_66, AVX;
 ]
+"vinsertf32x4" = [
+    b"yhyhwoib"   , [0x03, 0x18        ], X, EVEX_OP | WITH_VEXL | OPMASK | ZEROING | PREF_66, AVX512F | AVX512VL;
+    b"yzyzwoib"   , [0x03, 0x18        ], X, EVEX_OP | WITH_EVEXL | OPMASK | ZEROING | PREF_66, AVX512F;
+]
+"vinsertf32x8" = [
+    b"yzyzwhib"   , [0x03, 0x1A        ], X, EVEX_OP | WITH_EVEXL | OPMASK | ZEROING | PREF_66, AVX512DQ;
+]
+"vinsertf64x4" = [
+    b"yzyzwhib"   , [0x03, 0x1A        ], X, EVEX_OP | WITH_EVEXL | WITH_REXW | OPMASK | ZEROING | PREF_66, AVX512F;
+]
 "vinserti128" = [
     b"yhyhwoib"   , [0x03, 0x38        ], X, WITH_VEXL | VEX_OP | PREF_66, AVX2;
 ]
+"vinserti32x4" = [
+    b"yhyhwoib"   , [0x03, 0x38        ], X, EVEX_OP | WITH_VEXL | OPMASK | ZEROING | PREF_66, AVX512F | AVX512VL;
+    b"yzyzwoib"   , [0x03, 0x38        ], X, EVEX_OP | WITH_EVEXL | OPMASK | ZEROING | PREF_66, AVX512F;
+]
+"vinserti64x4" = [
+    b"yzyzwhib"   , [0x03, 0x3A        ], X, EVEX_OP | WITH_EVEXL | WITH_REXW | OPMASK | ZEROING | PREF_66, AVX512F;
+]
 "vinsertps" = [
     b"yoyomdib"   , [0x03, 0x21        ], X, VEX_OP | PREF_66, AVX;
     b"yoyoyoib"   , [0x03, 0x21        ], X, VEX_OP | PREF_66, AVX;
@@ -4062,9 +4457,11 @@ Ops!(
 ]
 "vmaxpd" = [
     b"y*y*w*"     , [0x01, 0x5F        ], X, VEX_OP | AUTO_VEXL | PREF_66, AVX;
+    b"y*y*w*"     , [0x01, 0x5F        ], X, EVEX_OP | AUTO_VEXL | WITH_REXW | OPMASK | ZEROING | BCST | SAE | PREF_66, AVX512F;
 ]
 "vmaxps" = [
     b"y*y*w*"     , [0x01, 0x5F        ], X, VEX_OP | AUTO_VEXL, AVX;
+    b"y*y*w*"     , [0x01, 0x5F        ], X, EVEX_OP | AUTO_VEXL | OPMASK | ZEROING | BCST | SAE, AVX512F;
 ]
 "vmaxsd" = [
     b"yoyomq"     , [0x01, 0x5F        ], X, VEX_OP | PREF_F2, AVX;
@@ -4085,9 +4482,11 @@ Ops!(
 ]
 "vminpd" = [
     b"y*y*w*"     , [0x01, 0x5D        ], X, VEX_OP | AUTO_VEXL | PREF_66, AVX;
+    b"y*y*w*"     , [0x01, 0x5D        ], X, EVEX_OP | AUTO_VEXL | WITH_REXW | OPMASK | ZEROING | BCST | SAE | PREF_66, AVX512F;
 ]
 "vminps" = [
     b"y*y*w*"     , [0x01, 0x5D        ], X, VEX_OP | AUTO_VEXL, AVX;
+    b"y*y*w*"     , [0x01, 0x5D        ], X, EVEX_OP | AUTO_VEXL | OPMASK | ZEROING | BCST | SAE, AVX512F;
 ]
 "vminsd" = [
     b"yoyomq"     , [0x01, 0x5D        ], X, VEX_OP | PREF_F2, AVX;
@@ -4110,11 +4509,15 @@ Ops!(
     b"y*w*"       , [0x01, 0x28        ], X, VEX_OP | AUTO_VEXL | PREF_66, AVX;
     b"whyh"       , [0x01, 0x29        ], X, VEX_OP | WITH_VEXL | ENC_MR | PREF_66, AVX;
     b"woyo"       , [0x01, 0x29        ], X, VEX_OP | ENC_MR | PREF_66, AVX;
+    b"y*w*"       , [0x01, 0x28        ], X, EVEX_OP | AUTO_VEXL | WITH_REXW | OPMASK | ZEROING | PREF_66, AVX512F;
+    b"m*y*"       , [0x01, 0x29        ], X, EVEX_OP | AUTO_VEXL | WITH_REXW | ENC_MR | OPMASK | PREF_66, AVX512F;
 ]
 "vmovaps" = [
     b"y*w*"       , [0x01, 0x28        ], X, VEX_OP | AUTO_VEXL, AVX;
     b"whyh"       , [0x01, 0x29        ], X, VEX_OP | WITH_VEXL | ENC_MR, AVX;
     b"woyo"       , [0x01, 0x29        ], X, VEX_OP | ENC_MR, AVX;
+    b"y*w*"       , [0x01, 0x28        ], X, EVEX_OP | AUTO_VEXL | OPMASK | ZEROING, AVX512F;
+    b"m*y*"       , [0x01, 0x29        ], X, EVEX_OP | AUTO_VEXL | ENC_MR | OPMASK, AVX512F;
 ]
 "vmovd" = [
     b"yovd"       , [0x01, 0x6E        ], X, VEX_OP | PREF_66, AVX;
@@ -4129,11 +4532,35 @@ Ops!(
     b"whyh"       , [0x01, 0x7F        ], X, VEX_OP | WITH_VEXL | ENC_MR | PREF_66, AVX;
     b"woyo"       , [0x01, 0x7F        ], X, VEX_OP | ENC_MR | PREF_66, AVX;
 ]
+"vmovdqa32" = [
+    b"y*w*"       , [0x01, 0x6F        ], X, EVEX_OP | AUTO_VEXL | OPMASK | ZEROING | PREF_66, AVX512F;
+    b"m*y*"       , [0x01, 0x7F        ], X, EVEX_OP | AUTO_VEXL | ENC_MR | OPMASK | PREF_66, AVX512F;
+]
+"vmovdqa64" = [
+    b"y*w*"       , [0x01, 0x6F        ], X, EVEX_OP | AUTO_VEXL | WITH_REXW | OPMASK | ZEROING | PREF_66, AVX512F;
+    b"m*y*"       , [0x01, 0x7F        ], X, EVEX_OP | AUTO_VEXL | WITH_REXW | ENC_MR | OPMASK | PREF_66, AVX512F;
+]
 "vmovdqu" = [
     b"y*w*"       , [0x01, 0x6F        ], X, VEX_OP | AUTO_VEXL | PREF_F3, AVX;
     b"whyh"       , [0x01, 0x7F        ], X, VEX_OP | WITH_VEXL | ENC_MR | PREF_F3, AVX;
     b"woyo"       , [0x01, 0x7F        ], X, VEX_OP | ENC_MR | PREF_F3, AVX;
 ]
+"vmovdqu16" = [
+    b"y*w*"       , [0x01, 0x6F        ], X, EVEX_OP | AUTO_VEXL | WITH_REXW | OPMASK | ZEROING | PREF_F2, AVX512BW;
+    b"m*y*"       , [0x01, 0x7F        ], X, EVEX_OP | AUTO_VEXL | WITH_REXW | ENC_MR | OPMASK | PREF_F2, AVX512BW;
+]
+"vmovdqu32" = [
+    b"y*w*"       , [0x01, 0x6F        ], X, EVEX_OP | AUTO_VEXL | OPMASK | ZEROING | PREF_F3, AVX512F;
+    b"m*y*"       , [0x01, 0x7F        ], X, EVEX_OP | AUTO_VEXL | ENC_MR | OPMASK | PREF_F3, AVX512F;
+]
+"vmovdqu64" = [
+    b"y*w*"       , [0x01, 0x6F        ], X, EVEX_OP | AUTO_VEXL | WITH_REXW | OPMASK | ZEROING | PREF_F3, AVX512F;
+    b"m*y*"       , [0x01, 0x7F        ], X, EVEX_OP | AUTO_VEXL | WITH_REXW | ENC_MR | OPMASK | PREF_F3, AVX512F;
+]
+"vmovdqu8" = [
+    b"y*w*"       , [0x01, 0x6F        ], X, EVEX_OP | AUTO_VEXL | OPMASK | ZEROING | PREF_F2, AVX512BW;
+    b"m*y*"       , [0x01, 0x7F        ], X, EVEX_OP | AUTO_VEXL | ENC_MR | OPMASK | PREF_F2, AVX512BW;
+]
 "vmovhlps" = [
     b"yoyoyo"     , [0x01, 0x12        ], X, VEX_OP, AVX;
 ]
@@ -4157,10 +4584,12 @@ Ops!(
     b"yoyomq"     , [0x01, 0x12        ], X, VEX_OP, AVX;
 ]
 "vmovmskpd" = [
-    b"r*y*"       , [0x01, 0x50        ], X, VEX_OP | PREF_66, AVX;
+    b"rdyo"       , [0x01, 0x50        ], X, VEX_OP | PREF_66, AVX;
+    b"rdyh"       , [0x01, 0x50        ], X, VEX_OP | WITH_VEXL | PREF_66, AVX;
 ]
 "vmovmskps" = [
-    b"r*y*"       , [0x01, 0x50        ], X, VEX_OP, AVX;
+    b"rdyo"       , [0x01, 0x50        ], X, VEX_OP, AVX;
+    b"rdyh"       , [0x01, 0x50        ], X, VEX_OP | WITH_VEXL, AVX;
 ]
 "vmovntdq" = [
     b"m*y*"       , [0x01, 0xE7        ], X, VEX_OP | AUTO_VEXL | ENC_MR | PREF_66, AVX;
@@ -4198,6 +4627,9 @@ Ops!(
     b"yomq"       , [0x01, 0x10        ], X, VEX_OP | PREF_F2, AVX;
     b"yoyoyo"     , [0x01, 0x10        ], X, VEX_OP | PREF_F2, AVX;
     b"yoyoyo"     , [0x01, 0x11        ], X, VEX_OP | ENC_VM | PREF_F2, AVX;
+    b"mqyo"       , [0x01, 0x11        ], X, EVEX_OP | WITH_REXW | ENC_MR | OPMASK | PREF_F2, AVX512F;
+    b"yomq"       , [0x01, 0x10        ], X, EVEX_OP | WITH_REXW | OPMASK | ZEROING | PREF_F2, AVX512F;
+    b"yoyoyo"     , [0x01, 0x10        ], X, EVEX_OP | WITH_REXW | OPMASK | ZEROING | PREF_F2, AVX512F;
 ]
 "vmovshdup" = [
     b"y*w*"       , [0x01, 0x16        ], X, VEX_OP | AUTO_VEXL | PREF_F3, AVX;
@@ -4210,16 +4642,23 @@ Ops!(
     b"yomd"       , [0x01, 0x10        ], X, VEX_OP | PREF_F3, AVX;
     b"yoyoyo"     , [0x01, 0x10        ], X, VEX_OP | PREF_F3, AVX;
     b"yoyoyo"     , [0x01, 0x11        ], X, VEX_OP | ENC_VM | PREF_F3, AVX;
+    b"mdyo"       , [0x01, 0x11        ], X, EVEX_OP | ENC_MR | OPMASK | PREF_F3, AVX512F;
+    b"yomd"       , [0x01, 0x10        ], X, EVEX_OP | OPMASK | ZEROING | PREF_F3, AVX512F;
+    b"yoyoyo"     , [0x01, 0x10        ], X, EVEX_OP | OPMASK | ZEROING | PREF_F3, AVX512F;
 ]
 "vmovupd" = [
     b"y*w*"       , [0x01, 0x10        ], X, VEX_OP | AUTO_VEXL | PREF_66, AVX;
     b"whyh"       , [0x01, 0x11        ], X, VEX_OP | WITH_VEXL | ENC_MR | PREF_66, AVX;
     b"woyo"       , [0x01, 0x11        ], X, VEX_OP | ENC_MR | PREF_66, AVX;
+    b"y*w*"       , [0x01, 0x10        ], X, EVEX_OP | AUTO_VEXL | WITH_REXW | OPMASK | ZEROING | PREF_66, AVX512F;
+    b"m*y*"       , [0x01, 0x11        ], X, EVEX_OP | AUTO_VEXL | WITH_REXW | ENC_MR | OPMASK | PREF_66, AVX512F;
 ]
 "vmovups" = [
     b"y*w*"       , [0x01, 0x10        ], X, VEX_OP | AUTO_VEXL, AVX;
     b"whyh"       , [0x01, 0x11        ], X, VEX_OP | WITH_VEXL | ENC_MR, AVX;
     b"woyo"       , [0x01, 0x11        ], X, VEX_OP | ENC_MR, AVX;
+    b"y*w*"       , [0x01, 0x10        ], X, EVEX_OP | AUTO_VEXL | OPMASK | ZEROING, AVX512F;
+    b"m*y*"       , [0x01, 0x11        ], X, EVEX_OP | AUTO_VEXL | ENC_MR | OPMASK, AVX512F;
 ]
 "vmpsadbw" = [
     b"y*y*w*ib"   , [0x03, 0x42        ], X, VEX_OP | AUTO_VEXL | ENC_MR | PREF_66, AVX;
@@ -4244,17 +4683,23 @@ Ops!(
 ]
 "vmulpd" = [
     b"y*y*w*"     , [0x01, 0x59        ], X, VEX_OP | AUTO_VEXL | PREF_66, AVX;
+    b"y*y*w*"     , [0x01, 0x59        ], X, EVEX_OP | AUTO_VEXL | WITH_REXW | OPMASK | ZEROING | BCST | ROUNDING | PREF_66, AVX512F;
 ]
 "vmulps" = [
     b"y*y*w*"     , [0x01, 0x59        ], X, VEX_OP | AUTO_VEXL, AVX;
+    b"y*y*w*"     , [0x01, 0x59        ], X, EVEX_OP | AUTO_VEXL | OPMASK | ZEROING | BCST | ROUNDING, AVX512F;
 ]
 "vmulsd" = [
     b"yoyomq"     , [0x01, 0x59        ], X, VEX_OP | PREF_F2, AVX;
     b"yoyoyo"     , [0x01, 0x59        ], X, VEX_OP | PREF_F2, AVX;
+    b"yoyomq"     , [0x01, 0x59        ], X, EVEX_OP | WITH_REXW | OPMASK | ZEROING | PREF_F2, AVX512F;
+    b"yoyoyo"     , [0x01, 0x59        ], X, EVEX_OP | WITH_REXW | OPMASK | ZEROING | ROUNDING | PREF_F2, AVX512F;
 ]
 "vmulss" = [
     b"yoyomd"     , [0x01, 0x59        ], X, VEX_OP | PREF_F3, AVX;
     b"yoyoyo"     , [0x01, 0x59        ], X, VEX_OP | PREF_F3, AVX;
+    b"yoyomd"     , [0x01, 0x59        ], X, EVEX_OP | OPMASK | ZEROING | PREF_F3, AVX512F;
+    b"yoyoyo"     , [0x01, 0x59        ], X, EVEX_OP | OPMASK | ZEROING | ROUNDING | PREF_F3, AVX512F;
 ]
 "vmwrite" = [
     b"rqvq"       , [0x0F, 0x79        ], X, DEFAULT, VMX;
@@ -4267,9 +4712,11 @@ Ops!(
 ]
 "vorpd" = [
     b"y*y*w*"     , [0x01, 0x56        ], X, VEX_OP | AUTO_VEXL | PREF_66, AVX;
+    b"y*y*w*"     , [0x01, 0x56        ], X, EVEX_OP | AUTO_VEXL | WITH_REXW | OPMASK | ZEROING | BCST | PREF_66, AVX512DQ;
 ]
 "vorps" = [
     b"y*y*w*"     , [0x01, 0x56        ], X, VEX_OP | AUTO_VEXL, AVX;
+    b"y*y*w*"     , [0x01, 0x56        ], X, EVEX_OP | AUTO_VEXL | OPMASK | ZEROING | BCST, AVX512DQ;
 ]
 "vpabsb" = [
     b"y*w*"       , [0x02, 0x1C        ], X, VEX_OP | AUTO_VEXL | PREF_66, AVX;
@@ -4294,12 +4741,15 @@ Ops!(
 ]
 "vpaddb" = [
     b"y*y*w*"     , [0x01, 0xFC        ], X, VEX_OP | AUTO_VEXL | PREF_66, AVX;
+    b"y*y*w*"     , [0x01, 0xFC        ], X, EVEX_OP | AUTO_VEXL | OPMASK | ZEROING | PREF_66, AVX512BW;
 ]
 "vpaddd" = [
     b"y*y*w*"     , [0x01, 0xFE        ], X, VEX_OP | AUTO_VEXL | PREF_66, AVX;
+    b"y*y*w*"     , [0x01, 0xFE        ], X, EVEX_OP | AUTO_VEXL | OPMASK | ZEROING | BCST | PREF_66, AVX512F;
 ]
 "vpaddq" = [
     b"y*y*w*"     , [0x01, 0xD4        ], X, VEX_OP | AUTO_VEXL | PREF_66, AVX;
+    b"y*y*w*"     , [0x01, 0xD4        ], X, EVEX_OP | AUTO_VEXL | WITH_REXW | OPMASK | ZEROING | BCST | PREF_66, AVX512F;
 ]
 "vpaddsb" = [
     b"y*y*w*"     , [0x01, 0xEC        ], X, VEX_OP | AUTO_VEXL | PREF_66, AVX;
@@ -4315,6 +4765,7 @@ Ops!(
 ]
 "vpaddw" = [
     b"y*y*w*"     , [0x01, 0xFD        ], X, VEX_OP | AUTO_VEXL | PREF_66, AVX;
+    b"y*y*w*"     , [0x01, 0xFD        ], X, EVEX_OP | AUTO_VEXL | OPMASK | ZEROING | PREF_66, AVX512BW;
 ]
 "vpalignr" = [
     b"y*y*w*ib"   , [0x03, 0x0F        ], X, VEX_OP | AUTO_VEXL | ENC_MR | PREF_66, AVX;
@@ -4322,9 +4773,21 @@ Ops!(
 "vpand" = [
     b"y*y*w*"     , [0x01, 0xDB        ], X, VEX_OP | AUTO_VEXL | PREF_66, AVX;
 ]
+"vpandd" = [
+    b"y*y*w*"     , [0x01, 0xDB        ], X, EVEX_OP | AUTO_VEXL | OPMASK | ZEROING | BCST | PREF_66, AVX512F;
+]
 "vpandn" = [
     b"y*y*w*"     , [0x01, 0xDF        ], X, VEX_OP | AUTO_VEXL | PREF_66, AVX;
 ]
+"vpandnd" = [
+    b"y*y*w*"     , [0x01, 0xDF        ], X, EVEX_OP | AUTO_VEXL | OPMASK | ZEROING | BCST | PREF_66, AVX512F;
+]
+"vpandnq" = [
+    b"y*y*w*"     , [0x01, 0xDF        ], X, EVEX_OP | AUTO_VEXL | WITH_REXW | OPMASK | ZEROING | BCST | PREF_66, AVX512F;
+]
+"vpandq" = [
+    b"y*y*w*"     , [0x01, 0xDB        ], X, EVEX_OP | AUTO_VEXL | WITH_REXW | OPMASK | ZEROING | BCST | PREF_66, AVX512F;
+]
 "vpavgb" = [
     b"y*y*w*"     , [0x01, 0xE0        ], X, VEX_OP | AUTO_VEXL | PREF_66, AVX;
 ]
@@ -4347,40 +4810,59 @@ Ops!(
 "vpbroadcastd" = [
     b"y*md"       , [0x02, 0x58        ], X, VEX_OP | AUTO_VEXL | PREF_66, AVX2;
     b"y*yo"       , [0x02, 0x58        ], X, VEX_OP | AUTO_VEXL | PREF_66, AVX2;
+    b"y*md"       , [0x02, 0x58        ], X, EVEX_OP | AUTO_VEXL | OPMASK | ZEROING | PREF_66, AVX512F;
+    b"y*yo"       , [0x02, 0x58        ], X, EVEX_OP | AUTO_VEXL | OPMASK | ZEROING | PREF_66, AVX512F;
+    b"y*rd"       , [0x02, 0x7C        ], X, EVEX_OP | AUTO_VEXL | OPMASK | ZEROING | PREF_66, AVX512F;
+]
+"vpbroadcastmb2q" = [
+    b"y*jq"       , [0x02, 0x2A        ], X, EVEX_OP | AUTO_VEXL | WITH_REXW | PREF_F3, AVX512CD;
+]
+"vpbroadcastmw2d" = [
+    b"y*jq"       , [0x02, 0x3A        ], X, EVEX_OP | AUTO_VEXL | PREF_F3, AVX512CD;
 ]
 "vpbroadcastq" = [
     b"yhmq"       , [0x02, 0x59        ], X, VEX_OP | WITH_VEXL | PREF_66, AVX2;
     b"yomq"       , [0x02, 0x59        ], X, VEX_OP | PREF_66, AVX2;
     b"y*yo"       , [0x02, 0x59        ], X, VEX_OP | AUTO_VEXL | PREF_66, AVX2;
+    b"y*mq"       , [0x02, 0x59        ], X, EVEX_OP | AUTO_VEXL | WITH_REXW | OPMASK | ZEROING | PREF_66, AVX512F;
+    b"y*yo"       , [0x02, 0x59        ], X, EVEX_OP | AUTO_VEXL | WITH_REXW | OPMASK | ZEROING | PREF_66, AVX512F;
+    b"y*rq"       , [0x02, 0x7C        ], X, EVEX_OP | AUTO_VEXL | WITH_REXW | OPMASK | ZEROING | PREF_66, AVX512F;
 ]
 "vpbroadcastw" = [
     b"y*mw"       , [0x02, 0x79        ], X, VEX_OP | AUTO_VEXL | PREF_66, AVX2;
     b"y*yo"       , [0x02, 0x79        ], X, VEX_OP | AUTO_VEXL | PREF_66, AVX2;
 ]
 "vpclmulhqhqdq" = [
-    b"yoyowo"     , [0x03, 0x44, 0x11  ], X, VEX_OP | PREF_66 | IMM_OP, AVX;
+    b"yoyowo"     , [0x03, 0x44, 0x11  ], X, VEX_OP | PREF_66 | IMM_OP, PCLMULQDQ | AVX;
 ]
 "vpclmulhqlqdq" = [
-    b"yoyowo"     , [0x03, 0x44, 0x01  ], X, VEX_OP | IMM_OP | PREF_66, AVX;
+    b"yoyowo"     , [0x03, 0x44, 0x01  ], X, VEX_OP | IMM_OP | PREF_66, PCLMULQDQ | AVX;
 ]
 "vpclmullqhqdq" = [
-    b"yoyowo"     , [0x03, 0x44, 0x10  ], X, VEX_OP | IMM_OP | PREF_66, AVX;
+    b"yoyowo"     , [0x03, 0x44, 0x10  ], X, VEX_OP | IMM_OP | PREF_66, PCLMULQDQ | AVX;
 ]
 "vpclmullqlqdq" = [
-    b"yoyowo"     , [0x03, 0x44, 0x00  ], X, VEX_OP | IMM_OP | PREF_66, AVX;
+    b"yoyowo"     , [0x03, 0x44, 0x00  ], X, VEX_OP | IMM_OP | PREF_66, PCLMULQDQ | AVX;
 ]
 "vpclmulqdq" = [
-    b"yoyowoib"   , [0x03, 0x44        ], X, VEX_OP | PREF_66, AVX;
+    b"yoyowoib"   , [0x03, 0x44        ], X, VEX_OP | PREF_66, PCLMULQDQ | AVX;
+    b"yhyhwhib"   , [0x03, 0x44        ], X, VEX_OP | WITH_VEXL | PREF_66, VPCLMULQDQ;
+    b"y*y*w*ib"   , [0x03, 0x44        ], X, EVEX_OP | AUTO_VEXL | PREF_66, VPCLMULQDQ | AVX512F;
 ]
 "vpcmov" = [
     b"y*y*w*y*"   , [0x08, 0xA2        ], X, XOP_OP | AUTO_VEXL, SSE5 | AMD;
     b"y*y*y*w*"   , [0x08, 0xA2        ], X, XOP_OP | AUTO_VEXL, AMD | SSE5;
 ]
+"vpcmpd" = [
+    b"jqy*w*ib"   , [0x03, 0x1F        ], X, EVEX_OP | AUTO_VEXL | OPMASK | BCST | PREF_66, AVX512F;
+]
 "vpcmpeqb" = [
     b"y*y*w*"     , [0x01, 0x74        ], X, VEX_OP | AUTO_VEXL | PREF_66, AVX;
+    b"jqy*w*"     , [0x01, 0x74        ], X, EVEX_OP | AUTO_VEXL | OPMASK | PREF_66, AVX512BW;
 ]
 "vpcmpeqd" = [
     b"y*y*w*"     , [0x01, 0x76        ], X, VEX_OP | AUTO_VEXL | PREF_66, AVX;
+    b"jqy*w*"     , [0x01, 0x76        ], X, EVEX_OP | AUTO_VEXL | OPMASK | BCST | PREF_66, AVX512F;
 ]
 "vpcmpeqq" = [
     b"y*y*w*"     , [0x02, 0x29        ], X, VEX_OP | AUTO_VEXL | PREF_66, AVX;
@@ -4399,6 +4881,7 @@ Ops!(
 ]
 "vpcmpgtd" = [
     b"y*y*w*"     , [0x01, 0x66        ], X, VEX_OP | AUTO_VEXL | PREF_66, AVX;
+    b"jqy*w*"     , [0x01, 0x66        ], X, EVEX_OP | AUTO_VEXL | OPMASK | BCST | PREF_66, AVX512F;
 ]
 "vpcmpgtq" = [
     b"y*y*w*"     , [0x02, 0x37        ], X, VEX_OP | AUTO_VEXL | PREF_66, AVX;
@@ -4412,6 +4895,9 @@ Ops!(
 "vpcmpistrm" = [
     b"yowoib"     , [0x03, 0x62        ], X, VEX_OP | PREF_66, AVX;
 ]
+"vpcmpud" = [
+    b"jqy*w*ib"   , [0x03, 0x1E        ], X, EVEX_OP | AUTO_VEXL | OPMASK | BCST | PREF_66, AVX512F;
+]
 "vpcomb" = [
     b"yoyowoib"   , [0x08, 0xCC        ], X, XOP_OP, AMD | SSE5;
 ]
@@ -4436,15 +4922,42 @@ Ops!(
 "vpcomw" = [
     b"yoyowoib"   , [0x08, 0xCD        ], X, XOP_OP, AMD | SSE5;
 ]
+"vpconflictd" = [
+    b"y*w*"       , [0x02, 0xC4        ], X, EVEX_OP | AUTO_VEXL | OPMASK | ZEROING | BCST | PREF_66, AVX512CD;
+]
+"vpconflictq" = [
+    b"y*w*"       , [0x02, 0xC4        ], X, EVEX_OP | AUTO_VEXL | WITH_REXW | OPMASK | ZEROING | BCST | PREF_66, AVX512CD;
+]
+"vpdpbusd" = [
+    b"y*y*w*"     , [0x02, 0x50        ], X, VEX_OP | AUTO_VEXL | PREF_66, AVXVNNI;
+]
+"vpdpbusds" = [
+    b"y*y*w*"     , [0x02, 0x51        ], X, VEX_OP | AUTO_VEXL | PREF_66, AVXVNNI;
+]
+"vpdpwssd" = [
+    b"y*y*w*"     , [0x02, 0x52        ], X, VEX_OP | AUTO_VEXL | PREF_66, AVXVNNI;
+]
+"vpdpwssds" = [
+    b"y*y*w*"     , [0x02, 0x53        ], X, VEX_OP | AUTO_VEXL | PREF_66, AVXVNNI;
+]
 "vperm2f128" = [
     b"yhyhwhib"   , [0x03, 0x06        ], X, WITH_VEXL | VEX_OP | PREF_66, AVX;
 ]
 "vperm2i128" = [
     b"yhyhwhib"   , [0x03, 0x46        ], X, WITH_VEXL | VEX_OP | PREF_66, AVX2;
 ]
+"vpermb" = [
+    b"y*y*w*"     , [0x02, 0x8D        ], X, EVEX_OP | AUTO_VEXL | OPMASK | ZEROING | PREF_66, AVX512VBMI;
+]
 "vpermd" = [
     b"yhyhwh"     , [0x02, 0x36        ], X, WITH_VEXL | VEX_OP | PREF_66, AVX2;
 ]
+"vpermi2b" = [
+    b"y*y*w*"     , [0x02, 0x75        ], X, EVEX_OP | AUTO_VEXL | OPMASK | ZEROING | PREF_66, AVX512VBMI;
+]
+"vpermi2d" = [
+    b"y*y*w*"     , [0x02, 0x76        ], X, EVEX_OP | AUTO_VEXL | OPMASK | ZEROING | BCST | PREF_66, AVX512F;
+]
 "vpermilpd" = [
     b"y*y*w*"     , [0x02, 0x0D        ], X, VEX_OP | AUTO_VEXL | PREF_66, AVX;
     b"y*w*ib"     , [0x03, 0x05        ], X, VEX_OP | AUTO_VEXL | PREF_66, AVX;
@@ -4452,6 +4965,8 @@ Ops!(
 "vpermilps" = [
     b"y*y*w*"     , [0x02, 0x0C        ], X, VEX_OP | AUTO_VEXL | PREF_66, AVX;
     b"y*w*ib"     , [0x03, 0x04        ], X, VEX_OP | AUTO_VEXL | PREF_66, AVX;
+    b"y*y*w*"     , [0x02, 0x0C        ], X, EVEX_OP | AUTO_VEXL | OPMASK | ZEROING | BCST | PREF_66, AVX512F;
+    b"y*w*ib"     , [0x03, 0x04        ], X, EVEX_OP | AUTO_VEXL | OPMASK | ZEROING | BCST | PREF_66, AVX512F;
 ]
 "vpermpd" = [
     b"yhwhib"     , [0x03, 0x01        ], X, WITH_VEXL | WITH_REXW | VEX_OP | PREF_66, AVX2;
@@ -4462,6 +4977,15 @@ Ops!(
 "vpermq" = [
     b"yhwhib"     , [0x03, 0x00        ], X, WITH_VEXL | WITH_REXW | VEX_OP | PREF_66, AVX2;
 ]
+"vpermt2b" = [
+    b"y*y*w*"     , [0x02, 0x7D        ], X, EVEX_OP | AUTO_VEXL | OPMASK | ZEROING | PREF_66, AVX512VBMI;
+]
+"vpermt2d" = [
+    b"y*y*w*"     , [0x02, 0x7E        ], X, EVEX_OP | AUTO_VEXL | OPMASK | ZEROING | BCST | PREF_66, AVX512F;
+]
+"vpermw" = [
+    b"y*y*w*"     , [0x02, 0x8D        ], X, EVEX_OP | AUTO_VEXL | WITH_REXW | OPMASK | ZEROING | PREF_66, AVX512BW;
+]
 "vpextrb" = [
     b"mbyoib"     , [0x03, 0x14        ], X, VEX_OP | ENC_MR | PREF_66, AVX;
     b"rdyoib"     , [0x03, 0x14        ], X, VEX_OP | ENC_MR | PREF_66, AVX;
@@ -4483,6 +5007,7 @@ Ops!(
 ]
 "vpgatherdd" = [
     b"y*k*y*"     , [0x02, 0x90        ], X, VEX_OP | AUTO_VEXL | ENC_MR | PREF_66, AVX2;
+    b"y*k*"       , [0x02, 0x90        ], X, EVEX_OP | AUTO_VEXL | OPMASK | PREF_66, AVX512F;
 ]
 "vpgatherdq" = [
     b"y*loy*"     , [0x02, 0x90        ], X, VEX_OP | AUTO_VEXL | ENC_MR | PREF_66, AVX2;
@@ -4492,6 +5017,7 @@ Ops!(
 ]
 "vpgatherqq" = [
     b"y*l*y*"     , [0x02, 0x91        ], X, VEX_OP | AUTO_VEXL | ENC_MR | PREF_66, AVX2;
+    b"y*l*"       , [0x02, 0x91        ], X, EVEX_OP | AUTO_VEXL | WITH_REXW | OPMASK | PREF_66, AVX512F;
 ]
 "vphaddbd" = [
     b"yowo"       , [0x09, 0xC2        ], X, XOP_OP, SSE5 | AMD;
@@ -4573,6 +5099,12 @@ Ops!(
     b"yoyordib"   , [0x01, 0xC4        ], X, VEX_OP | PREF_66, AVX;
     b"yoyovwib"   , [0x01, 0xC4        ], X, VEX_OP | PREF_66, AVX;
 ]
+"vplzcntd" = [
+    b"y*w*"       , [0x02, 0x44        ], X, EVEX_OP | AUTO_VEXL | OPMASK | ZEROING | BCST | PREF_66, AVX512CD;
+]
+"vplzcntq" = [
+    b"y*w*"       , [0x02, 0x44        ], X, EVEX_OP | AUTO_VEXL | WITH_REXW | OPMASK | ZEROING | BCST | PREF_66, AVX512CD;
+]
 "vpmacsdd" = [
     b"yoyowoyo"   , [0x08, 0x9E        ], X, XOP_OP, AMD | SSE5;
 ]
@@ -4609,6 +5141,12 @@ Ops!(
 "vpmadcswd" = [
     b"yoyowoyo"   , [0x08, 0xB6        ], X, XOP_OP, AMD | SSE5;
 ]
+"vpmadd52huq" = [
+    b"y*y*w*"     , [0x02, 0xB5        ], X, EVEX_OP | AUTO_VEXL | WITH_REXW | OPMASK | ZEROING | BCST | PREF_66, AVX512IFMA;
+]
+"vpmadd52luq" = [
+    b"y*y*w*"     , [0x02, 0xB4        ], X, EVEX_OP | AUTO_VEXL | WITH_REXW | OPMASK | ZEROING | BCST | PREF_66, AVX512IFMA;
+]
 "vpmaddubsw" = [
     b"y*y*w*"     , [0x02, 0x04        ], X, VEX_OP | AUTO_VEXL | PREF_66, AVX;
 ]
@@ -4628,6 +5166,7 @@ Ops!(
 ]
 "vpmaxsd" = [
     b"y*y*w*"     , [0x02, 0x3D        ], X, VEX_OP | AUTO_VEXL | PREF_66, AVX;
+    b"y*y*w*"     , [0x02, 0x3D        ], X, EVEX_OP | AUTO_VEXL | OPMASK | ZEROING | BCST | PREF_66, AVX512F;
 ]
 "vpmaxsw" = [
     b"y*y*w*"     , [0x01, 0xEE        ], X, VEX_OP | AUTO_VEXL | PREF_66, AVX;
@@ -4646,6 +5185,7 @@ Ops!(
 ]
 "vpminsd" = [
     b"y*y*w*"     , [0x02, 0x39        ], X, VEX_OP | AUTO_VEXL | PREF_66, AVX;
+    b"y*y*w*"     , [0x02, 0x39        ], X, EVEX_OP | AUTO_VEXL | OPMASK | ZEROING | BCST | PREF_66, AVX512F;
 ]
 "vpminsw" = [
     b"y*y*w*"     , [0x01, 0xEA        ], X, VEX_OP | AUTO_VEXL | PREF_66, AVX;
@@ -4659,8 +5199,27 @@ Ops!(
 "vpminuw" = [
     b"y*y*w*"     , [0x02, 0x3A        ], X, VEX_OP | AUTO_VEXL | PREF_66, AVX;
 ]
+"vpmovb2m" = [
+    b"jqy*"       , [0x02, 0x29        ], X, EVEX_OP | AUTO_VEXL | PREF_F3, AVX512BW;
+]
+"vpmovd2m" = [
+    b"jqy*"       , [0x02, 0x39        ], X, EVEX_OP | AUTO_VEXL | PREF_F3, AVX512DQ;
+]
+"vpmovdb" = [
+    b"woyz"       , [0x02, 0x31        ], X, EVEX_OP | WITH_EVEXL | ENC_MR | OPMASK | ZEROING | PREF_F3, AVX512F;
+]
+"vpmovdw" = [
+    b"whyz"       , [0x02, 0x33        ], X, EVEX_OP | WITH_EVEXL | ENC_MR | OPMASK | ZEROING | PREF_F3, AVX512F;
+]
+"vpmovm2b" = [
+    b"y*jq"       , [0x02, 0x28        ], X, EVEX_OP | AUTO_VEXL | PREF_F3, AVX512BW;
+]
+"vpmovm2d" = [
+    b"y*jq"       , [0x02, 0x38        ], X, EVEX_OP | AUTO_VEXL | PREF_F3, AVX512DQ;
+]
 "vpmovmskb" = [
-    b"r*y*"       , [0x01, 0xD7        ], X, VEX_OP | PREF_66, AVX;
+    b"rdyo"       , [0x01, 0xD7        ], X, VEX_OP | PREF_66, AVX;
+    b"rdyh"       , [0x01, 0xD7        ], X, VEX_OP | WITH_VEXL | PREF_66, AVX2;
 ]
 "vpmovsxbd" = [
     b"yomd"       , [0x02, 0x21        ], X, VEX_OP | PREF_66, AVX;
@@ -4689,6 +5248,11 @@ Ops!(
 "vpmovzxbd" = [
     b"yomd"       , [0x02, 0x31        ], X, VEX_OP | PREF_66, AVX;
     b"y*yo"       , [0x02, 0x31        ], X, VEX_OP | AUTO_VEXL | PREF_66, AVX;
+    b"yomd"       , [0x02, 0x31        ], X, EVEX_OP | OPMASK | ZEROING | PREF_66, AVX512F | AVX512VL;
+    b"yoyo"       , [0x02, 0x31        ], X, EVEX_OP | OPMASK | ZEROING | PREF_66, AVX512F | AVX512VL;
+    b"yhmq"       , [0x02, 0x31        ], X, EVEX_OP | WITH_VEXL | OPMASK | ZEROING | PREF_66, AVX512F | AVX512VL;
+    b"yhyo"       , [0x02, 0x31        ], X, EVEX_OP | WITH_VEXL | OPMASK | ZEROING | PREF_66, AVX512F | AVX512VL;
+    b"yzwo"       , [0x02, 0x31        ], X, EVEX_OP | WITH_EVEXL | OPMASK | ZEROING | PREF_66, AVX512F;
 ]
 "vpmovzxbq" = [
     b"y*mw"       , [0x02, 0x32        ], X, VEX_OP | AUTO_VEXL | PREF_66, AVX;
@@ -4724,16 +5288,29 @@ Ops!(
 ]
 "vpmulld" = [
     b"y*y*w*"     , [0x02, 0x40        ], X, VEX_OP | AUTO_VEXL | PREF_66, AVX;
+    b"y*y*w*"     , [0x02, 0x40        ], X, EVEX_OP | AUTO_VEXL | OPMASK | ZEROING | BCST | PREF_66, AVX512F;
+]
+"vpmullq" = [
+    b"y*y*w*"     , [0x02, 0x40        ], X, EVEX_OP | AUTO_VEXL | WITH_REXW | OPMASK | ZEROING | BCST | PREF_66, AVX512DQ;
 ]
 "vpmullw" = [
     b"y*y*w*"     , [0x01, 0xD5        ], X, VEX_OP | AUTO_VEXL | PREF_66, AVX;
 ]
+"vpmultishiftqb" = [
+    b"y*y*w*"     , [0x02, 0x83        ], X, EVEX_OP | AUTO_VEXL | WITH_REXW | OPMASK | ZEROING | BCST | PREF_66, AVX512VBMI;
+]
 "vpmuludq" = [
     b"y*y*w*"     , [0x01, 0xF4        ], X, VEX_OP | AUTO_VEXL | PREF_66, AVX;
 ]
 "vpor" = [
     b"y*y*w*"     , [0x01, 0xEB        ], X, VEX_OP | AUTO_VEXL | PREF_66, AVX;
 ]
+"vpord" = [
+    b"y*y*w*"     , [0x01, 0xEB        ], X, EVEX_OP | AUTO_VEXL | OPMASK | ZEROING | BCST | PREF_66, AVX512F;
+]
+"vporq" = [
+    b"y*y*w*"     , [0x01, 0xEB        ], X, EVEX_OP | AUTO_VEXL | WITH_REXW | OPMASK | ZEROING | BCST | PREF_66, AVX512F;
+]
 "vpperm" = [
     b"yoyowoyo"   , [0x08, 0xA3        ], X, XOP_OP, AMD | SSE5;
     b"yoyoyowo"   , [0x08, 0xA3        ], X, WITH_REXW | XOP_OP, SSE5 | AMD;
@@ -4761,6 +5338,9 @@ Ops!(
 "vpsadbw" = [
     b"y*y*w*"     , [0x01, 0xF6        ], X, VEX_OP | AUTO_VEXL | PREF_66, AVX;
 ]
+"vpscatterdd" = [
+    b"k*y*"       , [0x02, 0xA0        ], X, EVEX_OP | AUTO_VEXL | ENC_MR | OPMASK | PREF_66, AVX512F;
+]
 "vpshab" = [
     b"yowoyo"     , [0x09, 0x98        ], X, XOP_OP | ENC_MR, AMD | SSE5;
     b"yoyowo"     , [0x09, 0x98        ], X, WITH_REXW | XOP_OP, SSE5 | AMD;
@@ -4795,9 +5375,11 @@ Ops!(
 ]
 "vpshufb" = [
     b"y*y*w*"     , [0x02, 0x00        ], X, VEX_OP | AUTO_VEXL | PREF_66, AVX;
+    b"y*y*w*"     , [0x02, 0x00        ], X, EVEX_OP | AUTO_VEXL | OPMASK | ZEROING | PREF_66, AVX512BW;
 ]
 "vpshufd" = [
     b"y*w*ib"     , [0x01, 0x70        ], X, VEX_OP | AUTO_VEXL | PREF_66, AVX;
+    b"y*w*ib"     , [0x01, 0x70        ], X, EVEX_OP | AUTO_VEXL | OPMASK | ZEROING | BCST | PREF_66, AVX512F;
 ]
 "vpshufhw" = [
     b"y*w*ib"     , [0x01, 0x70        ], X, VEX_OP | AUTO_VEXL | PREF_F3, AVX;
@@ -4869,12 +5451,15 @@ Ops!(
 ]
 "vpsubb" = [
     b"y*y*w*"     , [0x01, 0xF8        ], X, VEX_OP | AUTO_VEXL | PREF_66, AVX;
+    b"y*y*w*"     , [0x01, 0xF8        ], X, EVEX_OP | AUTO_VEXL | OPMASK | ZEROING | PREF_66, AVX512BW;
 ]
 "vpsubd" = [
     b"y*y*w*"     , [0x01, 0xFA        ], X, VEX_OP | AUTO_VEXL | PREF_66, AVX;
+    b"y*y*w*"     , [0x01, 0xFA        ], X, EVEX_OP | AUTO_VEXL | OPMASK | ZEROING | BCST | PREF_66, AVX512F;
 ]
 "vpsubq" = [
     b"y*y*w*"     , [0x01, 0xFB        ], X, VEX_OP | AUTO_VEXL | PREF_66, AVX;
+    b"y*y*w*"     , [0x01, 0xFB        ], X, EVEX_OP | AUTO_VEXL | WITH_REXW | OPMASK | ZEROING | BCST | PREF_66, AVX512F;
 ]
 "vpsubsb" = [
     b"y*y*w*"     , [0x01, 0xE8        ], X, VEX_OP | AUTO_VEXL | PREF_66, AVX;
@@ -4890,10 +5475,20 @@ Ops!(
 ]
 "vpsubw" = [
     b"y*y*w*"     , [0x01, 0xF9        ], X, VEX_OP | AUTO_VEXL | PREF_66, AVX;
+    b"y*y*w*"     , [0x01, 0xF9        ], X, EVEX_OP | AUTO_VEXL | OPMASK | ZEROING | PREF_66, AVX512BW;
+]
+"vpternlogd" = [
+    b"y*y*w*ib"   , [0x03, 0x25        ], X, EVEX_OP | AUTO_VEXL | OPMASK | ZEROING | BCST | PREF_66, AVX512F;
+]
+"vpternlogq" = [
+    b"y*y*w*ib"   , [0x03, 0x25        ], X, EVEX_OP | AUTO_VEXL | WITH_REXW | OPMASK | ZEROING | BCST | PREF_66, AVX512F;
 ]
 "vptest" = [
     b"y*w*"       , [0x02, 0x17        ], X, VEX_OP | AUTO_VEXL | PREF_66, AVX;
 ]
+"vptestmd" = [
+    b"jqy*w*"     , [0x02, 0x27        ], X, EVEX_OP | AUTO_VEXL | OPMASK | BCST | PREF_66, AVX512F;
+]
 "vpunpckhbw" = [
     b"y*y*w*"     , [0x01, 0x68        ], X, VEX_OP | AUTO_VEXL | PREF_66, AVX;
 ]
@@ -4921,6 +5516,26 @@ Ops!(
 "vpxor" = [
     b"y*y*w*"     , [0x01, 0xEF        ], X, VEX_OP | AUTO_VEXL | PREF_66, AVX;
 ]
+"vpxord" = [
+    b"y*y*w*"     , [0x01, 0xEF        ], X, EVEX_OP | AUTO_VEXL | OPMASK | ZEROING | BCST | PREF_66, AVX512F;
+]
+"vpxorq" = [
+    b"y*y*w*"     , [0x01, 0xEF        ], X, EVEX_OP | AUTO_VEXL | WITH_REXW | OPMASK | ZEROING | BCST | PREF_66, AVX512F;
+]
+"vrcp28pd" = [
+    b"yzwz"       , [0x02, 0xCA        ], X, EVEX_OP | WITH_EVEXL | WITH_REXW | OPMASK | ZEROING | BCST | SAE | PREF_66, AVX512ER;
+]
+"vrcp28ps" = [
+    b"yzwz"       , [0x02, 0xCA        ], X, EVEX_OP | WITH_EVEXL | OPMASK | ZEROING | BCST | SAE | PREF_66, AVX512ER;
+]
+"vrcp28sd" = [
+    b"yoyomq"     , [0x02, 0xCB        ], X, EVEX_OP | WITH_REXW | OPMASK | ZEROING | PREF_66, AVX512ER;
+    b"yoyoyo"     , [0x02, 0xCB        ], X, EVEX_OP | WITH_REXW | OPMASK | ZEROING | SAE | PREF_66, AVX512ER;
+]
+"vrcp28ss" = [
+    b"yoyomd"     , [0x02, 0xCB        ], X, EVEX_OP | OPMASK | ZEROING | PREF_66, AVX512ER;
+    b"yoyoyo"     , [0x02, 0xCB        ], X, EVEX_OP | OPMASK | ZEROING | SAE | PREF_66, AVX512ER;
+]
 "vrcpps" = [
     b"y*w*"       , [0x01, 0x53        ], X, VEX_OP | AUTO_VEXL, AVX;
 ]
@@ -4942,6 +5557,20 @@ Ops!(
     b"yoyomdib"   , [0x03, 0x0A        ], X, VEX_OP | PREF_66, AVX;
     b"yoyoyoib"   , [0x03, 0x0A        ], X, VEX_OP | PREF_66, AVX;
 ]
+"vrsqrt28pd" = [
+    b"yzwz"       , [0x02, 0xCC        ], X, EVEX_OP | WITH_EVEXL | WITH_REXW | OPMASK | ZEROING | BCST | SAE | PREF_66, AVX512ER;
+]
+"vrsqrt28ps" = [
+    b"yzwz"       , [0x02, 0xCC        ], X, EVEX_OP | WITH_EVEXL | OPMASK | ZEROING | BCST | SAE | PREF_66, AVX512ER;
+]
+"vrsqrt28sd" = [
+    b"yoyomq"     , [0x02, 0xCD        ], X, EVEX_OP | WITH_REXW | OPMASK | ZEROING | PREF_66, AVX512ER;
+    b"yoyoyo"     , [0x02, 0xCD        ], X, EVEX_OP | WITH_REXW | OPMASK | ZEROING | SAE | PREF_66, AVX512ER;
+]
+"vrsqrt28ss" = [
+    b"yoyomd"     , [0x02, 0xCD        ], X, EVEX_OP | OPMASK | ZEROING | PREF_66, AVX512ER;
+    b"yoyoyo"     , [0x02, 0xCD        ], X, EVEX_OP | OPMASK | ZEROING | SAE | PREF_66, AVX512ER;
+]
 "vrsqrtps" = [
     b"y*w*"       , [0x01, 0x52        ], X, VEX_OP | AUTO_VEXL, AVX;
 ]
@@ -4949,17 +5578,47 @@ Ops!(
     b"yoyomd"     , [0x01, 0x52        ], X, VEX_OP | PREF_F3, AVX;
     b"yoyoyo"     , [0x01, 0x52        ], X, VEX_OP | PREF_F3, AVX;
 ]
+"vscatterdps" = [
+    b"k*y*"       , [0x02, 0xA2        ], X, EVEX_OP | AUTO_VEXL | ENC_MR | OPMASK | PREF_66, AVX512F;
+]
+"vscatterpf0dpd" = [
+    b"lh"         , [0x02, 0xC6        ], 5, EVEX_OP | WITH_EVEXL | WITH_REXW | OPMASK | PREF_66, AVX512PF;
+]
+"vscatterpf0dps" = [
+    b"kz"         , [0x02, 0xC6        ], 5, EVEX_OP | WITH_EVEXL | OPMASK | PREF_66, AVX512PF;
+]
+"vscatterpf0qpd" = [
+    b"lz"         , [0x02, 0xC7        ], 5, EVEX_OP | WITH_EVEXL | WITH_REXW | OPMASK | PREF_66, AVX512PF;
+]
+"vscatterpf0qps" = [
+    b"kz"         , [0x02, 0xC7        ], 5, EVEX_OP | WITH_EVEXL | OPMASK | PREF_66, AVX512PF;
+]
+"vscatterpf1dpd" = [
+    b"lh"         , [0x02, 0xC6        ], 6, EVEX_OP | WITH_EVEXL | WITH_REXW | OPMASK | PREF_66, AVX512PF;
+]
+"vscatterpf1dps" = [
+    b"kz"         , [0x02, 0xC6        ], 6, EVEX_OP | WITH_EVEXL | OPMASK | PREF_66, AVX512PF;
+]
+"vscatterpf1qpd" = [
+    b"lz"         , [0x02, 0xC7        ], 6, EVEX_OP | WITH_EVEXL | WITH_REXW | OPMASK | PREF_66, AVX512PF;
+]
+"vscatterpf1qps" = [
+    b"kz"         , [0x02, 0xC7        ], 6, EVEX_OP | WITH_EVEXL | OPMASK | PREF_66, AVX512PF;
+]
 "vshufpd" = [
     b"y*y*w*ib"   , [0x01, 0xC6        ], X, VEX_OP | AUTO_VEXL | ENC_MR | PREF_66, AVX;
 ]
 "vshufps" = [
     b"y*y*w*ib"   , [0x01, 0xC6        ], X, VEX_OP | AUTO_VEXL | ENC_MR, AVX;
+    b"y*y*w*ib"   , [0x01, 0xC6        ], X, EVEX_OP | AUTO_VEXL | OPMASK | ZEROING | BCST, AVX512F;
 ]
 "vsqrtpd" = [
     b"y*w*"       , [0x01, 0x51        ], X, VEX_OP | AUTO_VEXL | PREF_66, AVX;
+    b"y*w*"       , [0x01, 0x51        ], X, EVEX_OP | AUTO_VEXL | WITH_REXW | OPMASK | ZEROING | BCST | ROUNDING | PREF_66, AVX512F;
 ]
 "vsqrtps" = [
     b"y*w*"       , [0x01, 0x51        ], X, VEX_OP | AUTO_VEXL, AVX;
+    b"y*w*"       , [0x01, 0x51        ], X, EVEX_OP | AUTO_VEXL | OPMASK | ZEROING | BCST | ROUNDING, AVX512F;
 ]
 "vsqrtsd" = [
     b"yoyomq"     , [0x01, 0x51        ], X, VEX_OP | PREF_F2, AVX;
@@ -4974,17 +5633,23 @@ Ops!(
 ]
 "vsubpd" = [
     b"y*y*w*"     , [0x01, 0x5C        ], X, VEX_OP | AUTO_VEXL | PREF_66, AVX;
+    b"y*y*w*"     , [0x01, 0x5C        ], X, EVEX_OP | AUTO_VEXL | WITH_REXW | OPMASK | ZEROING | BCST | ROUNDING | PREF_66, AVX512F;
 ]
 "vsubps" = [
     b"y*y*w*"     , [0x01, 0x5C        ], X, VEX_OP | AUTO_VEXL, AVX;
+    b"y*y*w*"     , [0x01, 0x5C        ], X, EVEX_OP | AUTO_VEXL | OPMASK | ZEROING | BCST | ROUNDING, AVX512F;
 ]
 "vsubsd" = [
     b"yoyomq"     , [0x01, 0x5C        ], X, VEX_OP | PREF_F2, AVX;
     b"yoyoyo"     , [0x01, 0x5C        ], X, VEX_OP | PREF_F2, AVX;
+    b"yoyomq"     , [0x01, 0x5C        ], X, EVEX_OP | WITH_REXW | OPMASK | ZEROING | PREF_F2, AVX512F;
+    b"yoyoyo"     , [0x01, 0x5C        ], X, EVEX_OP | WITH_REXW | OPMASK | ZEROING | ROUNDING | PREF_F2, AVX512F;
 ]
 "vsubss" = [
     b"yoyomd"     , [0x01, 0x5C        ], X, VEX_OP | PREF_F3, AVX;
     b"yoyoyo"     , [0x01, 0x5C        ], X, VEX_OP | PREF_F3, AVX;
+    b"yoyomd"     , [0x01, 0x5C        ], X, EVEX_OP | OPMASK | ZEROING | PREF_F3, AVX512F;
+    b"yoyoyo"     , [0x01, 0x5C        ], X, EVEX_OP | OPMASK | ZEROING | ROUNDING | PREF_F3, AVX512F;
 ]
 "vtestpd" = [
     b"y*w*"       , [0x02, 0x0F        ], X, VEX_OP | AUTO_VEXL | PREF_66, AVX;
@@ -5011,12 +5676,15 @@ Ops!(
 ]
 "vunpcklps" = [
     b"y*y*w*"     , [0x01, 0x14        ], X, VEX_OP | AUTO_VEXL, AVX;
+    b"y*y*w*"     , [0x01, 0x14        ], X, EVEX_OP | AUTO_VEXL | OPMASK | ZEROING | BCST, AVX512F;
 ]
 "vxorpd" = [
     b"y*y*w*"     , [0x01, 0x57        ], X, VEX_OP | AUTO_VEXL | PREF_66, AVX;
+    b"y*y*w*"     , [0x01, 0x57        ], X, EVEX_OP | AUTO_VEXL | WITH_REXW | OPMASK | ZEROING | BCST | PREF_66, AVX512DQ;
 ]
 "vxorps" = [
     b"y*y*w*"     , [0x01, 0x57        ], X, VEX_OP | AUTO_VEXL, AVX;
+    b"y*y*w*"     , [0x01, 0x57        ], X, EVEX_OP | AUTO_VEXL | OPMASK | ZEROING | BCST, AVX512DQ;
 ]
 "vzeroall" = [
     b""           , [0x01, 0x77        ], X, WITH_VEXL | VEX_OP, AVX;
@@ -5028,12 +5696,12 @@ Ops!(
     b""           , [0x0F, 0x09        ], X;
 ]
 "wrfsbase" = [
-    b"rd"         , [0x0F, 0xAE        ], 2, PREF_F3;
-    b"rq"         , [0x0F, 0xAE        ], 2, WITH_REXW | PREF_F3;
+    b"rd"         , [0x0F, 0xAE        ], 2, PREF_F3, FSGSBASE;
+    b"rq"         , [0x0F, 0xAE        ], 2, WITH_REXW | PREF_F3, FSGSBASE;
 ]
 "wrgsbase" = [
-    b"rd"         , [0x0F, 0xAE        ], 3, PREF_F3;
-    b"rq"         , [0x0F, 0xAE        ], 3, WITH_REXW | PREF_F3;
+    b"rd"         , [0x0F, 0xAE        ], 3, PREF_F3, FSGSBASE;
+    b"rq"         , [0x0F, 0xAE        ], 3, WITH_REXW | PREF_F3, FSGSBASE;
 ]
 "wrmsr" = [
     b""           , [0x0F, 0x30        ], X;
@@ -5087,7 +5755,7 @@ Ops!(
     b""           , [0x0F, 0x01, 0xD5  ], X, DEFAULT, RTM;
 ]
 "xgetbv" = [
-    b""           , [0x0F, 0x01, 0xD0  ], X;
+    b""           , [0x0F, 0x01, 0xD0  ], X, DEFAULT, XSAVE;
 ]
 "xlat" = [
     b""           , [0xD7              ], X;
@@ -5110,6 +5778,13 @@ Ops!(
     b"r*i*"       , [0x81              ], 6, AUTO_SIZE ;
     b"r*r*"       , [0x31              ], X, AUTO_SIZE | ENC_MR;
     b"r*v*"       , [0x33              ], X, AUTO_SIZE;
+    b"rbvbib"     , [0x04, 0x80        ], 6, EVEX_OP | NDD, APX;
+    b"rbvbrb"     , [0x04, 0x30        ], X, EVEX_OP | NDD, APX;
+    b"rbrbvb"     , [0x04, 0x32        ], X, EVEX_OP | NDD, APX;
+    b"r*v*ib"     , [0x04, 0x83        ], 6, EVEX_OP | NDD | AUTO_SIZE | EXACT_SIZE, APX;
+    b"r*v*i*"     , [0x04, 0x81        ], 6, EVEX_OP | NDD | AUTO_SIZE, APX;
+    b"r*v*r*"     , [0x04, 0x31        ], X, EVEX_OP | NDD | AUTO_SIZE, APX;
+    b"r*r*v*"     , [0x04, 0x33        ], X, EVEX_OP | NDD | AUTO_SIZE, APX;
 ]
 "xorpd" = [
     b"yowo"       , [0x0F, 0x57        ], X, PREF_66, SSE2;
@@ -5118,31 +5793,43 @@ Ops!(
     b"yowo"       , [0x0F, 0x57        ], X, DEFAULT, SSE;
 ]
 "xrstor" = [
-    b"m!"         , [0x0F, 0xAE        ], 5;
+    b"m!"         , [0x0F, 0xAE        ], 5, DEFAULT, XSAVE;
 ]
 "xrstor64" = [
-    b"m!"         , [0x0F, 0xAE        ], 5, WITH_REXW;
+    b"m!"         , [0x0F, 0xAE        ], 5, WITH_REXW, XSAVE;
+]
+"xrstors" = [
+    b"m!"         , [0x0F, 0xC7        ], 3, DEFAULT, XSAVES;
 ]
 "xrstors64" = [
-    b"m!"         , [0x0F, 0xC7        ], 3, WITH_REXW;
+    b"m!"         , [0x0F, 0xC7        ], 3, WITH_REXW, XSAVES;
 ]
 "xsave" = [
-    b"m!"         , [0x0F, 0xAE        ], 4;
+    b"m!"         , [0x0F, 0xAE        ], 4, DEFAULT, XSAVE;
 ]
 "xsave64" = [
-    b"m!"         , [0x0F, 0xAE        ], 4, WITH_REXW;
+    b"m!"         , [0x0F, 0xAE        ], 4, WITH_REXW, XSAVE;
+]
+"xsavec" = [
+    b"m!"         , [0x0F, 0xC7        ], 4, DEFAULT, XSAVEC;
 ]
 "xsavec64" = [
-    b"m!"         , [0x0F, 0xC7        ], 4, WITH_REXW;
+    b"m!"         , [0x0F, 0xC7        ], 4, WITH_REXW, XSAVEC;
+]
+"xsaveopt" = [
+    b"m!"         , [0x0F, 0xAE        ], 6, DEFAULT, XSAVEOPT;
 ]
 "xsaveopt64" = [
-    b"m!"         , [0x0F, 0xAE        ], 6, WITH_REXW;
+    b"m!"         , [0x0F, 0xAE        ], 6, WITH_REXW, XSAVEOPT;
+]
+"xsaves" = [
+    b"m!"         , [0x0F, 0xC7        ], 5, DEFAULT, XSAVES;
 ]
 "xsaves64" = [
-    b"m!"         , [0x0F, 0xC7        ], 5, WITH_REXW;
+    b"m!"         , [0x0F, 0xC7        ], 5, WITH_REXW, XSAVES;
 ]
 "xsetbv" = [
-    b""           , [0x0F, 0x01, 0xD1  ], X;
+    b""           , [0x0F, 0x01, 0xD1  ], X, DEFAULT, XSAVE;
 ]
 "xsha1" = [
     b""           , [0x0F, 0xA6, 0xC8  ], X, PREF_F3, CYRIX;
@@ -5159,30 +5846,30 @@ Ops!(
 
 "call"  = [
     b"iwiw"       , [0x9A              ], X, X86_ONLY | WORD_SIZE | EXACT_SIZE;
-    b"idiw"       , [0x9A              ], X, X86_ONLY;
-    b"mf"         , [0xFF              ], 3, X86_ONLY | EXACT_SIZE;
+    b"idiw"       , [0x9A              ], X, X86_ONLY | DWORD_SIZE;
+    b"mf"         , [0xFF              ], 3, X86_ONLY | EXACT_SIZE | DWORD_SIZE;
     b"od"         , [0xE8              ], X;
     b"v*"         , [0xFF              ], 2, AUTO_NO32;
 ]
 "callf" = [
     b"iwiw"       , [0x9A              ], X, X86_ONLY | WORD_SIZE | EXACT_SIZE;
-    b"idiw"       , [0x9A              ], X, X86_ONLY;
+    b"idiw"       , [0x9A              ], X, X86_ONLY | DWORD_SIZE;
     b"md"         , [0xFF              ], 3, X86_ONLY | WORD_SIZE | EXACT_SIZE;
-    b"mf"         , [0xFF              ], 3, X86_ONLY;
+    b"mf"         , [0xFF              ], 3, X86_ONLY | DWORD_SIZE;
 ]
 "jmp"   = [
-    b"iwiw"       , [0x9A              ], X, X86_ONLY | WORD_SIZE | EXACT_SIZE;
-    b"idiw"       , [0xEA              ], X, X86_ONLY;
-    b"mf"         , [0xFF              ], 5, X86_ONLY | EXACT_SIZE;
+    b"iwiw"       , [0xEA              ], X, X86_ONLY | WORD_SIZE | EXACT_SIZE;
+    b"idiw"       , [0xEA              ], X, X86_ONLY | DWORD_SIZE;
+    b"mf"         , [0xFF              ], 5, X86_ONLY | EXACT_SIZE | DWORD_SIZE;
     b"ob"         , [0xEB              ], X, EXACT_SIZE;
     b"od"         , [0xE9              ], X;
     b"v*"         , [0xFF              ], 4, AUTO_NO32 ;
 ]
 "jmpf" = [
-    b"iwiw"       , [0x9A              ], X, X86_ONLY | WORD_SIZE | EXACT_SIZE;
-    b"idiw"       , [0xEA              ], X, X86_ONLY;
+    b"iwiw"       , [0xEA              ], X, X86_ONLY | WORD_SIZE | EXACT_SIZE;
+    b"idiw"       , [0xEA              ], X, X86_ONLY | DWORD_SIZE;
     b"md"         , [0xFF              ], 5, X86_ONLY | WORD_SIZE | EXACT_SIZE;
-    b"mf"         , [0xFF              ], 5, X86_ONLY;
+    b"mf"         , [0xFF              ], 5, X86_ONLY | DWORD_SIZE;
 ]
 "mov"   = [
     b"v*r*"       , [0x89              ], X, AUTO_SIZE;
@@ -5195,21 +5882,21 @@ Ops!(
     b"swrw"       , [0x8C              ], X;
     b"rbib"       , [0xB0              ], X,             SHORT_ARG;
     b"rwiw"       , [0xB8              ], X, WORD_SIZE | SHORT_ARG;
-    b"rdid"       , [0xB8              ], X,             SHORT_ARG;
+    b"rdid"       , [0xB8              ], X, DWORD_SIZE | SHORT_ARG;
     b"v*i*"       , [0xC7              ], 0, AUTO_SIZE;
     b"vbib"       , [0xC6              ], 0;
     b"rqiq"       , [0xB8              ], X, WITH_REXW | SHORT_ARG;
-    b"cdrd"       , [0x0F, 0x22        ], X; // can only match in 32 bit mode due to "cd"
+    b"cdrd"       , [0x0F, 0x22        ], X, X86_ONLY; // can only match in 32 bit mode due to "cd"
     b"cqrq"       , [0x0F, 0x22        ], X; // doesn't need a prefix to be encoded, as it's 64 bit natural in 64 bit mode
-    b"rdcd"       , [0x0F, 0x20        ], X;
+    b"rdcd"       , [0x0F, 0x20        ], X, X86_ONLY;
     b"rqcq"       , [0x0F, 0x20        ], X;
-    b"Wdrd"       , [0x0F, 0x22        ], 0, PREF_F0; // note: technically CR8 should actually be encoded, but the encoding is 0.
+    b"Wdrd"       , [0x0F, 0x22        ], 0, PREF_F0 | X86_ONLY; // note: technically CR8 should actually be encoded, but the encoding is 0.
     b"Wqrq"       , [0x0F, 0x22        ], 0, PREF_F0;
-    b"rdWd"       , [0x0F, 0x22        ], 0, PREF_F0;
+    b"rdWd"       , [0x0F, 0x22        ], 0, PREF_F0 | X86_ONLY;
     b"rqWq"       , [0x0F, 0x22        ], 0, PREF_F0;
-    b"ddrd"       , [0x0F, 0x23        ], X; // 32 bit mode only
+    b"ddrd"       , [0x0F, 0x23        ], X, X86_ONLY; // 32 bit mode only
     b"dqrq"       , [0x0F, 0x23        ], X;
-    b"rddd"       , [0x0F, 0x21        ], X;
+    b"rddd"       , [0x0F, 0x21        ], X, X86_ONLY;
     b"rqdq"       , [0x0F, 0x21        ], X;
 ]
 "movabs"  = [
@@ -5529,25 +6216,25 @@ Ops!(
 "in"    = [
     b"Abib"       , [0xE4            ], X;
     b"Awib"       , [0xE5            ], X, WORD_SIZE;
-    b"Adib"       , [0xE5            ], X;
+    b"Adib"       , [0xE5            ], X, DWORD_SIZE;
     b"AbCw"       , [0xEC            ], X;
     b"AwCw"       , [0xED            ], X, WORD_SIZE;
-    b"AdCw"       , [0xED            ], X;
+    b"AdCw"       , [0xED            ], X, DWORD_SIZE;
 ]
 
 "out"   = [
     b"ibAb"       , [0xE6            ], X;
-    b"ibAw"       , [0xE7            ], X;
-    b"ibAd"       , [0xE7            ], X;
+    b"ibAw"       , [0xE7            ], X, WORD_SIZE;
+    b"ibAd"       , [0xE7            ], X, DWORD_SIZE;
     b"CwAb"       , [0xEE            ], X;
     b"CwAw"       , [0xEF            ], X, WORD_SIZE;
-    b"CwAd"       , [0xEF            ], X;
+    b"CwAd"       , [0xEF            ], X, DWORD_SIZE;
 ]
 
 "crc32"  = [
-    b"r*vb"       , [0x0F, 0x38, 0xF0], X, AUTO_REXW | PREF_F2 | EXACT_SIZE; // unique size encoding scheme
-    b"rdvw"       , [0x0F, 0x38, 0xF1], X, WORD_SIZE | PREF_F2 | EXACT_SIZE;
-    b"r*v*"       , [0x0F, 0x38, 0xF1], X, AUTO_REXW | PREF_F2 | EXACT_SIZE;
+    b"r*vb"       , [0x0F, 0x38, 0xF0], X, AUTO_REXW | PREF_F2 | EXACT_SIZE, SSE42; // unique size encoding scheme
+    b"rdvw"       , [0x0F, 0x38, 0xF1], X, WORD_SIZE | PREF_F2 | EXACT_SIZE, SSE42;
+    b"r*v*"       , [0x0F, 0x38, 0xF1], X, AUTO_REXW | PREF_F2 | EXACT_SIZE, SSE42;
 ]
 
 "imul"   = [
@@ -5558,4 +6245,5 @@ Ops!(
     b"r*v*i*"     , [0x69            ], X, AUTO_SIZE;
 ]
 
-)
+    ) }
+}
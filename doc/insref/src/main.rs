@@ -9,6 +9,10 @@ fn main() {
     let opmap = match args.next().expect("Architecture name").as_str() {
         "x64" => dynasm::dynasm_opmap!(x64),
         "aarch64" => dynasm::dynasm_opmap!(aarch64),
+        "rv64" => dynasm::dynasm_opmap!(rv64),
+        "arm" => dynasm::dynasm_opmap!(arm),
+        "thumb" => dynasm::dynasm_opmap!(thumb),
+        "loongarch64" => dynasm::dynasm_opmap!(loongarch64),
         x => panic!("Unknown opmap format '{}'", x)
     };
 
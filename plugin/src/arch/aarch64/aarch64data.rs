@@ -2,7 +2,9 @@ use crate::common::Size;
 use super::ast::Modifier;
 
 use lazy_static::lazy_static;
+use bitflags::bitflags;
 use std::collections::{HashMap, hash_map};
+use std::fmt::{self, Display};
 
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum Matcher {
@@ -61,6 +63,42 @@ pub enum Matcher {
     // register list with element specifier. It has .0 items with a size of .1
     RegListElement(u8, Size),
 
+    // scalable vector regs
+    /// scalable vector register with elements of the specified size
+    Z(Size),
+    /// scalable vector register without an element size
+    ZBare,
+    /// scalable vector register with element specifier, with the element of the specified size
+    ZElement(Size),
+    // register list of scalable vector registers with .0 items, with the elements of size .1
+    ZRegList(u8, Size),
+
+    // scalable predicate regs
+    /// predicate register with elements of the specified size
+    P(Size),
+    /// predicate register without an element size or qualifier
+    PBare,
+    /// governing predicate register with the /M qualifier
+    PMerge,
+    /// governing predicate register with the /Z qualifier
+    PZero,
+
+    // SME ZA operands
+    /// ZA tile with elements of the specified size
+    ZaTile(Size),
+    /// horizontal slice of a ZA tile with elements of the specified size
+    ZaH(Size),
+    /// vertical slice of a ZA tile with elements of the specified size
+    ZaV(Size),
+    /// register list containing only a horizontal slice of a ZA tile with elements of the specified size
+    ZaHList(Size),
+    /// register list containing only a vertical slice of a ZA tile with elements of the specified size
+    ZaVList(Size),
+    /// vector of the ZA array
+    ZaArray,
+    /// register list of ZA tiles
+    ZaTileList,
+
     // jump offsets
     Offset,
 
@@ -70,6 +108,22 @@ pub enum Matcher {
     RefPre,
     RefIndex,
 
+    // scalable vector references
+    /// [Xn|SP {, #imm, MUL VL}]
+    RefMulVl,
+    /// [Xn|SP, Xm {, LSL #.0}], where the shift has to be exactly .0 and is omitted if it is 0
+    RefIndexLsl(u8),
+    /// [Xn|SP, Zm.T {, LSL #.1}], with elements of size .0. The shift has to be exactly .1 and is omitted if it is 0
+    RefZIndexLsl(Size, u8),
+    /// [Xn|SP, Zm.T, UXTW|SXTW {#.1}], with elements of size .0. The shift has to be exactly .1 and is omitted if it is 0
+    RefZIndexExtend(Size, u8),
+    /// [Zn.T {, #imm}], with elements of size .0
+    RefZBase(Size),
+    /// [Zn.T, Zm.T {, LSL #imm}], with elements of size .0
+    RefZZ(Size),
+    /// [Zn.D, Zm.D, .0 {#imm}]
+    RefZZExtend(Modifier),
+
     // a single modifier
     LitMod(Modifier),
 
@@ -87,7 +141,11 @@ pub enum Command {
     REven(u8), // same as R, but requires that the register is even.
     RNoZr(u8), // same as R, but does not allow register 31.
     R4(u8), // encode a register in the range 0-15 into a 4-bit bitfield
+    R3(u8), // encode a register in the range 0-7 into a 3-bit bitfield
     RNext, // encode that this register should be the previous register, plus one
+    RTied, // encode that this register should be the same register as the first argument
+    RTile(u8, u8), // encode a ZA tile into a .1 bits long bitfield at .0, checking that the tile exists
+    RSelect(u8), // encode a vector select register in the range W12-W15 into a 2-bit bitfield
 
     // unsigned immediate encodings
     Ubits(u8, u8), // encodes an unsigned immediate starting at bit .0, .1 bits long
@@ -97,11 +155,13 @@ pub enum Command {
     Usub(u8, u8, u8), // encodes at .0, .1 bits long, .2 - value. Checks if the value is in the range 1 ..= value
     Unegmod(u8, u8), // encodes at .0, .1 bits long, -value % (1 << .1). Checks if the value is in the range 0 .. value
     Usumdec(u8, u8), // encodes at .0, .1 bits long, the value of the previous arg + the value of the current arg - 1
+    Usame(u8), // checks that the value is the same as the immediate .0 args before it. Nothing is encoded
     Ufields(&'static [u8]), // an immediate, encoded bitwise with the highest bit going into field 0, up to the lowest going into the last bitfield.
 
     // signed immediate encodings
     Sbits(u8, u8), // encodes a signed immediate starting at bit .0, .1 bits long
     Sscaled(u8, u8, u8), // encodes a signed immediate, starting at bit .0, .1 bits long, shifted .2 bits to the right before encoding
+    Smultiple(u8, u8, u8), // encodes a signed immediate that has to be a multiple of .2, starting at bit .0, .1 bits long, divided by .2 before encoding
 
     // bit slice encodings. These don't advance the current argument. Only the slice argument actually encodes anything
     BUbits(u8), // checks if the pointed value fits in the given amount of bits
@@ -110,6 +170,7 @@ pub enum Command {
     BUrange(u8, u8), // check if the pointed value is between min/max
     Uslice(u8, u8, u8), // encodes at .0, .1 bits long, the bitslice starting at .2 from the current arg
     Sslice(u8, u8, u8), // encodes at .0, .1 bits long, the bitslice starting at .2 from the current arg
+    Unegslice(u8, u8, u8), // encodes at .0, .1 bits long, the bitslice starting at .2 from the negated current arg
 
     // special immediate encodings
     Special(u8, SpecialComm),
@@ -121,6 +182,7 @@ pub enum Command {
     Rotates(u8), // 2-bits field encoding [LSL, LSR, ASR, ROR]
     ExtendsW(u8), // 3-bits field encoding [UXTB, UXTH, UXTW, UXTX, SXTB, SXTH, SXTW, SXTX]. Additionally, LSL is interpreted as UXTW
     ExtendsX(u8), // 3-bits field encoding [UXTB, UXTH, UXTW, UXTX, SXTB, SXTH, SXTW, SXTX]. Additionally, LSL is interpreted as UXTX
+    ExtendsZ(u8), // 1-bit field encoding [UXTW, SXTW]
 
     // Condition encodings.
     /// Normal condition code 4-bit encoding
@@ -131,6 +193,11 @@ pub enum Command {
     // Mapping of literal -> bitvalue
     LitList(u8, &'static str),
 
+    // System register encodings. These accept the names in SYSREG_MAP as well as the generic
+    // s<op0>_<op1>_c<n>_c<m>_<op2> notation, and encode them into a 15-bit field at .0
+    SysRegRead(u8), // the system register has to be readable
+    SysRegWrite(u8), // the system register has to be writable
+
     // Offsets
     Offset(Relocation),
 
@@ -147,6 +214,8 @@ pub enum SpecialComm {
     WIDE_IMMEDIATE_W,
     WIDE_IMMEDIATE_X,
     STRETCHED_IMMEDIATE,
+    LOGICAL_IMMEDIATE_B,
+    LOGICAL_IMMEDIATE_H,
     LOGICAL_IMMEDIATE_W,
     LOGICAL_IMMEDIATE_X,
     FLOAT_IMMEDIATE,
@@ -181,6 +250,182 @@ impl Relocation {
     }
 }
 
+/// The ways a named system register can be accessed
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SysRegAccess {
+    Read,
+    Write,
+    ReadWrite,
+}
+
+impl SysRegAccess {
+    pub fn readable(self) -> bool {
+        self != SysRegAccess::Write
+    }
+
+    pub fn writable(self) -> bool {
+        self != SysRegAccess::Read
+    }
+}
+
+
+bitflags! {
+    pub struct Features: u64 {
+        const BASELINE   = 0x0000_0000;
+        const LSE        = 0x0000_0001;
+        const CRC        = 0x0000_0002;
+        const AES        = 0x0000_0004;
+        const SHA2       = 0x0000_0008;
+        const SHA3       = 0x0000_0010;
+        const SM4        = 0x0000_0020;
+        const FP16       = 0x0000_0040;
+        const FHM        = 0x0000_0080;
+        const RDM        = 0x0000_0100;
+        const DOTPROD    = 0x0000_0200;
+        const RCPC       = 0x0000_0400;
+        const RCPC2      = 0x0000_0800;
+        const FCMA       = 0x0000_1000;
+        const JSCONV     = 0x0000_2000;
+        const FLAGM      = 0x0000_4000;
+        const SB         = 0x0000_8000;
+        const LOR        = 0x0001_0000;
+        const RAS        = 0x0002_0000;
+        const SPE        = 0x0004_0000;
+        const TRF        = 0x0008_0000;
+        const PREDRES    = 0x0010_0000;
+        const BF16       = 0x0020_0000;
+        const I8MM       = 0x0040_0000;
+        const MTE        = 0x0080_0000;
+        const PAUTH      = 0x0100_0000;
+        const BTI        = 0x0200_0000;
+        const SVE        = 0x0400_0000;
+        const SVE2       = 0x0800_0000;
+        const SME        = 0x1000_0000;
+        const SME_I16I64 = 0x2000_0000;
+        const SME_F64F64 = 0x4000_0000;
+    }
+}
+
+impl Features {
+    const fn make(bits: u64) -> Features {
+        Features { bits }
+    }
+
+    pub fn from_str(name: &str) -> Option<Features> {
+        match name {
+            "lse"       => Some(Features::LSE),
+            "crc"       => Some(Features::CRC),
+            "aes"       => Some(Features::AES),
+            "sha2"      => Some(Features::SHA2),
+            "sha3"      => Some(Features::SHA3),
+            "sm4"       => Some(Features::SM4),
+            "fp16"      => Some(Features::FP16),
+            "fhm"       => Some(Features::FHM),
+            "rdm"       => Some(Features::RDM),
+            "dotprod"   => Some(Features::DOTPROD),
+            "rcpc"      => Some(Features::RCPC),
+            "rcpc2"     => Some(Features::RCPC2),
+            "fcma"      => Some(Features::FCMA),
+            "jsconv"    => Some(Features::JSCONV),
+            "flagm"     => Some(Features::FLAGM),
+            "sb"        => Some(Features::SB),
+            "lor"       => Some(Features::LOR),
+            "ras"       => Some(Features::RAS),
+            "spe"       => Some(Features::SPE),
+            "trf"       => Some(Features::TRF),
+            "predres"   => Some(Features::PREDRES),
+            "bf16"      => Some(Features::BF16),
+            "i8mm"      => Some(Features::I8MM),
+            "mte"       => Some(Features::MTE),
+            "pauth"     => Some(Features::PAUTH),
+            "bti"       => Some(Features::BTI),
+            "sve"       => Some(Features::SVE),
+            "sve2"      => Some(Features::SVE2),
+            "sme"       => Some(Features::SME),
+            "smei16i64" => Some(Features::SME_I16I64),
+            "smef64f64" => Some(Features::SME_F64F64),
+            "crypto"    => Some(Features::AES | Features::SHA2 | Features::SHA3 | Features::SM4),
+            _ => None
+        }
+    }
+}
+
+impl Display for Features {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut keys = Vec::new();
+        if self.contains(Features::LSE)        { keys.push("lse"); }
+        if self.contains(Features::CRC)        { keys.push("crc"); }
+        if self.contains(Features::AES)        { keys.push("aes"); }
+        if self.contains(Features::SHA2)       { keys.push("sha2"); }
+        if self.contains(Features::SHA3)       { keys.push("sha3"); }
+        if self.contains(Features::SM4)        { keys.push("sm4"); }
+        if self.contains(Features::FP16)       { keys.push("fp16"); }
+        if self.contains(Features::FHM)        { keys.push("fhm"); }
+        if self.contains(Features::RDM)        { keys.push("rdm"); }
+        if self.contains(Features::DOTPROD)    { keys.push("dotprod"); }
+        if self.contains(Features::RCPC)       { keys.push("rcpc"); }
+        if self.contains(Features::RCPC2)      { keys.push("rcpc2"); }
+        if self.contains(Features::FCMA)       { keys.push("fcma"); }
+        if self.contains(Features::JSCONV)     { keys.push("jsconv"); }
+        if self.contains(Features::FLAGM)      { keys.push("flagm"); }
+        if self.contains(Features::SB)         { keys.push("sb"); }
+        if self.contains(Features::LOR)        { keys.push("lor"); }
+        if self.contains(Features::RAS)        { keys.push("ras"); }
+        if self.contains(Features::SPE)        { keys.push("spe"); }
+        if self.contains(Features::TRF)        { keys.push("trf"); }
+        if self.contains(Features::PREDRES)    { keys.push("predres"); }
+        if self.contains(Features::BF16)       { keys.push("bf16"); }
+        if self.contains(Features::I8MM)       { keys.push("i8mm"); }
+        if self.contains(Features::MTE)        { keys.push("mte"); }
+        if self.contains(Features::PAUTH)      { keys.push("pauth"); }
+        if self.contains(Features::BTI)        { keys.push("bti"); }
+        if self.contains(Features::SVE)        { keys.push("sve"); }
+        if self.contains(Features::SVE2)       { keys.push("sve2"); }
+        if self.contains(Features::SME)        { keys.push("sme"); }
+        if self.contains(Features::SME_I16I64) { keys.push("smei16i64"); }
+        if self.contains(Features::SME_F64F64) { keys.push("smef64f64"); }
+        for (i, k) in keys.into_iter().enumerate() {
+            if i != 0 {
+                f.write_str(", ")?;
+            }
+            f.write_str(k)?;
+        }
+        Ok(())
+    }
+}
+
+// workaround until bitflags can be used in const
+const LSE        : u64 = Features::LSE.bits;
+const CRC        : u64 = Features::CRC.bits;
+const AES        : u64 = Features::AES.bits;
+const SHA2       : u64 = Features::SHA2.bits;
+const SHA3       : u64 = Features::SHA3.bits;
+const SM4        : u64 = Features::SM4.bits;
+const FP16       : u64 = Features::FP16.bits;
+const FHM        : u64 = Features::FHM.bits;
+const RDM        : u64 = Features::RDM.bits;
+const DOTPROD    : u64 = Features::DOTPROD.bits;
+const RCPC       : u64 = Features::RCPC.bits;
+const RCPC2      : u64 = Features::RCPC2.bits;
+const FCMA       : u64 = Features::FCMA.bits;
+const JSCONV     : u64 = Features::JSCONV.bits;
+const FLAGM      : u64 = Features::FLAGM.bits;
+const SB         : u64 = Features::SB.bits;
+const LOR        : u64 = Features::LOR.bits;
+const RAS        : u64 = Features::RAS.bits;
+const SPE        : u64 = Features::SPE.bits;
+const TRF        : u64 = Features::TRF.bits;
+const PREDRES    : u64 = Features::PREDRES.bits;
+const BF16       : u64 = Features::BF16.bits;
+const I8MM       : u64 = Features::I8MM.bits;
+const MTE        : u64 = Features::MTE.bits;
+const PAUTH      : u64 = Features::PAUTH.bits;
+const BTI        : u64 = Features::BTI.bits;
+const SVE        : u64 = Features::SVE.bits;
+const SVE2       : u64 = Features::SVE2.bits;
+const SME        : u64 = Features::SME.bits;
+const SME_I16I64 : u64 = Features::SME_I16I64.bits;
+const SME_F64F64 : u64 = Features::SME_F64F64.bits;
 
 #[derive(Debug, Clone, Copy)]
 pub struct Opdata {
@@ -189,11 +434,13 @@ pub struct Opdata {
     /// A set of matchers capable of matching the instruction encoding that this instruction represents.
     pub matchers: &'static [Matcher],
     /// A sequence of encoder commands that check the matched instruction on validity and whose output gets orred together with the original template at runtime.
-    pub commands: &'static [Command]
+    pub commands: &'static [Command],
+    /// The architecture extensions that have to be available for this instruction to be used.
+    pub features: Features
 }
 
 macro_rules! SingleOp {
-    ( $base:expr, [ $( $matcher:expr ),* ], [ $( $command:expr ),* ] ) => {
+    ( $base:expr, [ $( $matcher:expr ),* ], [ $( $command:expr ),* ] $( , $features:expr )? ) => {
         {
             const MATCHERS: &'static [Matcher] = {
                 #[allow(unused_imports)]
@@ -213,18 +460,19 @@ macro_rules! SingleOp {
                 base: $base,
                 matchers: MATCHERS,
                 commands: COMMANDS,
+                features: Features::make(0 $( | $features )?),
             }
         }
     }
 }
 
 macro_rules! Ops {
-    ( $( $name:tt = [ $( $base:tt = [ $( $matcher:expr ),* ] => [ $( $command:expr ),* ] ; )+ ] )* ) => {
+    ( $( $name:tt = [ $( $base:tt = [ $( $matcher:expr ),* ] => [ $( $command:expr ),* ] $( , $features:expr )? ; )+ ] )* ) => {
         [ $(
             (
                 $name,
                 &[ $(
-                    SingleOp!( $base, [ $( $matcher ),* ], [ $( $command ),* ] )
+                    SingleOp!( $base, [ $( $matcher ),* ], [ $( $command ),* ] $( , $features )? )
                 ),+ ] as &[_]
             )
         ),* ]
@@ -253,7 +501,7 @@ lazy_static! {
         const SHIFTS: &[super::ast::Modifier] = &[LSL, LSR, ASR];
         const ROTATES: &[super::ast::Modifier] = &[LSL, LSR, ASR, ROR];
 
-        static MAP: &[(&str, &[Opdata])] = &include!("opmap.rs");
+        static MAP: &[(&str, &[Opdata])] = &dynasm_data::aarch64_opmap!(Ops);
         MAP.iter().cloned().collect()
     };
 
@@ -282,181 +530,17 @@ lazy_static! {
         MAP.iter().cloned().collect()
     };
 
-    // special ident maps
+    /// A map of named system registers to their o0:op1:CRn:CRm:op2 encoding and how they can be accessed
+    pub static ref SYSREG_MAP: HashMap<&'static str, (u32, SysRegAccess)> = {
+        use self::SysRegAccess::*;
+
+        static MAP: &[(&str, u32, SysRegAccess)] = &dynasm_data::aarch64_sysregs!();
+        MAP.iter().map(|&(name, bits, access)| (name, (bits, access))).collect()
+    };
+
+    /// Lists of special identifiers and their encodings, keyed by list name
     pub static ref SPECIAL_IDENT_MAP: HashMap<&'static str, HashMap<&'static str, u32>> = {
-        let mut mapmap = HashMap::new();
-        mapmap.insert("AT_OPS", {
-            static MAP: &[(&str, u32)] = &[
-                ("s1e1r",  0b00_0011_1100_0000),
-                ("s1e1w",  0b00_0011_1100_0001),
-                ("s1e0r",  0b00_0011_1100_0010),
-                ("s1e0w",  0b00_0011_1100_0011),
-                ("s1e2r",  0b10_0011_1100_0000),
-                ("s1e2w",  0b10_0011_1100_0001),
-                ("s12e1r", 0b10_0011_1100_0100),
-                ("s12e1w", 0b10_0011_1100_0101),
-                ("s12e0r", 0b10_0011_1100_0110),
-                ("s12e0w", 0b10_0011_1100_0111),
-                ("s1e3r",  0b11_0011_1100_0000),
-                ("s1e3w",  0b11_0011_1100_0001),
-                ("s1e1rp", 0b00_0011_1100_1000),
-                ("s1e1wp", 0b00_0011_1100_1001),
-            ];
-            MAP.iter().cloned().collect()
-        });
-        mapmap.insert("IC_OPS", {
-            static MAP: &[(&str, u32)] = &[
-                ("ialluis", 0b00_0011_1000_1000),
-                ("iallu",   0b00_0011_1010_1000),
-            ];
-            MAP.iter().cloned().collect()
-        });
-        mapmap.insert("DC_OPS", {
-            static MAP: &[(&str, u32)] = &[
-                ("ivac",  0b00_0011_1011_0001),
-                ("isw",   0b00_0011_1011_0010),
-                ("csw",   0b00_0011_1101_0010),
-                ("cisw",  0b00_0011_1111_0010),
-                ("zva",   0b01_1011_1010_0001),
-                ("cvac",  0b01_1011_1101_0001),
-                ("cvau",  0b01_1011_1101_1001),
-                ("civac", 0b01_1011_1111_0001),
-                ("cvap",  0b01_1011_1110_0001),
-            ];
-            MAP.iter().cloned().collect()
-        });
-        mapmap.insert("BARRIER_OPS", {
-            static MAP: &[(&str, u32)] = &[
-                ("sy",    0b1111),
-                ("st",    0b1110),
-                ("ld",    0b1101),
-                ("ish",   0b1011),
-                ("ishst", 0b1010),
-                ("ishld", 0b1001),
-                ("nsh",   0b0111),
-                ("nshst", 0b0110),
-                ("nshld", 0b0101),
-                ("osh",   0b0011),
-                ("oshst", 0b0010),
-                ("oshld", 0b0001),
-            ];
-            MAP.iter().cloned().collect()
-        });
-        mapmap.insert("MSR_IMM_OPS", {
-            static MAP: &[(&str, u32)] = &[
-                ("spsel",   0b00_0010_0000_0101),
-                ("daifset", 0b01_1010_0000_0110),
-                ("daifclr", 0b01_1010_0000_0111),
-                ("uao",     0b00_0010_0000_0011),
-                ("pan",     0b00_0010_0000_0100),
-                ("dit",     0b01_1010_0000_0010),
-            ];
-            MAP.iter().cloned().collect()
-        });
-        mapmap.insert("CONTROL_REGS", {
-            static MAP: &[(&str, u32)] = &[
-                ("c0",  0),
-                ("c1",  1),
-                ("c2",  2),
-                ("c3",  3),
-                ("c4",  4),
-                ("c5",  5),
-                ("c6",  6),
-                ("c7",  7),
-                ("c8",  8),
-                ("c9",  9),
-                ("c10", 10),
-                ("c11", 11),
-                ("c12", 12),
-                ("c13", 13),
-                ("c14", 14),
-                ("c15", 15),
-            ];
-            MAP.iter().cloned().collect()
-        });
-        mapmap.insert("TLBI_OPS", {
-            static MAP: &[(&str, u32)] = &[
-                ("vmalle1is",    0b00_0100_0001_1000),
-                ("vae1is",       0b00_0100_0001_1001),
-                ("aside1is",     0b00_0100_0001_1010),
-                ("vaae1is",      0b00_0100_0001_1011),
-                ("vale1is",      0b00_0100_0001_1101),
-                ("vaale1is",     0b00_0100_0001_1111),
-                ("vmalle1",      0b00_0100_0011_1000),
-                ("vae1",         0b00_0100_0011_1001),
-                ("aside1",       0b00_0100_0011_1010),
-                ("vaae1",        0b00_0100_0011_1011),
-                ("vale1",        0b00_0100_0011_1101),
-                ("vaale1",       0b00_0100_0011_1111),
-                ("ipas2e1is",    0b10_0100_0000_0001),
-                ("ipas2le1is",   0b10_0100_0000_0101),
-                ("alle2is",      0b10_0100_0001_1000),
-                ("vae2is",       0b10_0100_0001_1001),
-                ("alle1is",      0b10_0100_0001_1100),
-                ("vale2is",      0b10_0100_0001_1101),
-                ("vmalls12e1is", 0b10_0100_0001_1110),
-                ("ipas2e1",      0b10_0100_0010_0001),
-                ("ipas2le1",     0b10_0100_0010_0101),
-                ("alle2",        0b10_0100_0011_1000),
-                ("vae2",         0b10_0100_0011_1001),
-                ("alle1",        0b10_0100_0011_1100),
-                ("vale2",        0b10_0100_0011_1101),
-                ("vmalls12e1",   0b10_0100_0011_1110),
-                ("alle3is",      0b11_0100_0001_1000),
-                ("vae3is",       0b11_0100_0001_1001),
-                ("vale3is",      0b11_0100_0001_1101),
-                ("alle3",        0b11_0100_0011_1000),
-                ("vae3",         0b11_0100_0011_1001),
-                ("vale3",        0b11_0100_0011_1101),
-                ("vmalle1os",    0b00_0100_0000_1000),
-                ("vae1os",       0b00_0100_0000_1001),
-                ("aside1os",     0b00_0100_0000_1010),
-                ("vaae1os",      0b00_0100_0000_1011),
-                ("vale1os",      0b00_0100_0000_1101),
-                ("vaale1os",     0b00_0100_0000_1111),
-                ("rvae1is",      0b00_0100_0001_0001),
-                ("rvaae1is",     0b00_0100_0001_0011),
-                ("rvale1is",     0b00_0100_0001_0101),
-                ("rvaale1is",    0b00_0100_0001_0111),
-                ("rvae1os",      0b00_0100_0010_1001),
-                ("rvaae1os",     0b00_0100_0010_1011),
-                ("rvale1os",     0b00_0100_0010_1101),
-                ("rvaale1os",    0b00_0100_0010_1111),
-                ("rvae1",        0b00_0100_0011_0001),
-                ("rvaae1",       0b00_0100_0011_0011),
-                ("rvale1",       0b00_0100_0011_0101),
-                ("rvaale1",      0b00_0100_0011_0111),
-                ("ripas2e1is",   0b10_0100_0000_0010),
-                ("ripas2le1is",  0b10_0100_0000_0110),
-                ("alle2os",      0b10_0100_0000_1000),
-                ("vae2os",       0b10_0100_0000_1001),
-                ("alle1os",      0b10_0100_0000_1100),
-                ("vale2os",      0b10_0100_0000_1101),
-                ("vmalls12e1os", 0b10_0100_0000_1110),
-                ("rvae2is",      0b10_0100_0001_0001),
-                ("rvale2is",     0b10_0100_0001_0101),
-                ("ipas2e1os",    0b10_0100_0010_0000),
-                ("ripas2e1",     0b10_0100_0010_0010),
-                ("ripas2e1os",   0b10_0100_0010_0011),
-                ("ipas2le1os",   0b10_0100_0010_0100),
-                ("ripas2le1",    0b10_0100_0010_0110),
-                ("ripas2le1os",  0b10_0100_0010_0111),
-                ("rvae2os",      0b10_0100_0010_1001),
-                ("rvale2os",     0b10_0100_0010_1101),
-                ("rvae2",        0b10_0100_0011_0001),
-                ("rvale2",       0b10_0100_0011_0101),
-                ("alle3os",      0b11_0100_0000_1000),
-                ("vae3os",       0b11_0100_0000_1001),
-                ("vale3os",      0b11_0100_0000_1101),
-                ("rvae3is",      0b11_0100_0001_0001),
-                ("rvale3is",     0b11_0100_0001_0101),
-                ("rvae3os",      0b11_0100_0010_1001),
-                ("rvale3os",     0b11_0100_0010_1101),
-                ("rvae3",        0b11_0100_0011_0001),
-                ("rvale3",       0b11_0100_0011_0101),
-            ];
-            MAP.iter().cloned().collect()
-        });
-        mapmap
+        static MAP: &[(&str, &[(&str, u32)])] = &dynasm_data::aarch64_special_idents!();
+        MAP.iter().map(|&(name, idents)| (name, idents.iter().cloned().collect())).collect()
     };
 }
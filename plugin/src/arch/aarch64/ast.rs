@@ -8,7 +8,9 @@ use crate::common::{Size, Jump};
 #[derive(Debug, Clone)]
 pub enum Register {
     Scalar(RegScalar),
-    Vector(RegVector)
+    Vector(RegVector),
+    Scalable(RegScalable),
+    Za(RegZa)
 }
 
 /// A vcalar register. Can be either of the integer or simd families. 
@@ -27,6 +29,48 @@ pub struct RegVector {
     pub element: Option<syn::Expr>
 }
 
+/// A scalable vector or predicate register. Can only be of the scalable or predicate families.
+/// The element size is optional, as some instructions use the bare register.
+#[derive(Debug, Clone)]
+pub struct RegScalable {
+    pub kind: RegKind,
+    pub element_size: Option<Size>,
+    pub element: Option<syn::Expr>,
+    pub qualifier: Option<PredicateQualifier>
+}
+
+/// An SME ZA array operand. This is either the whole array, one of its tiles, or a horizontal or
+/// vertical slice of a tile. Slices and vectors of the whole array are indexed by a vector select
+/// register and an offset.
+#[derive(Debug, Clone)]
+pub struct RegZa {
+    pub kind: RegKind,
+    pub element_size: Option<Size>,
+    pub orientation: Option<ZaOrientation>,
+    pub index: Option<ZaIndex>
+}
+
+/// The direction of a ZA tile slice
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ZaOrientation {
+    Horizontal,
+    Vertical
+}
+
+/// The [Wv, offset] index of a ZA tile slice or array vector
+#[derive(Debug, Clone)]
+pub struct ZaIndex {
+    pub select: RegScalar,
+    pub offset: syn::Expr
+}
+
+/// The /Z or /M qualifier on a governing predicate register
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PredicateQualifier {
+    Zeroing,
+    Merging
+}
+
 // Register id without indication of its usage. Either a static Regid or a family identifier + expression to choose the register
 #[derive(Debug, Clone)]
 pub enum RegKind {
@@ -62,15 +106,46 @@ pub enum RegId {
     V16= 0x50, V17= 0x51, V18= 0x52, V19= 0x53,
     V20= 0x54, V21= 0x55, V22= 0x56, V23= 0x57,
     V24= 0x58, V25= 0x59, V26= 0x5A, V27= 0x5B,
-    V28= 0x5C, V29= 0x5D, V30= 0x5E, V31= 0x5F
+    V28= 0x5C, V29= 0x5D, V30= 0x5E, V31= 0x5F,
+
+    // scalable vector registers (SVE). These overlap the SIMD registers architecturally, but are a
+    // separate family as the instructions using them are completely separate.
+    Z0 = 0x60, Z1 = 0x61, Z2 = 0x62, Z3 = 0x63,
+    Z4 = 0x64, Z5 = 0x65, Z6 = 0x66, Z7 = 0x67,
+    Z8 = 0x68, Z9 = 0x69, Z10= 0x6A, Z11= 0x6B,
+    Z12= 0x6C, Z13= 0x6D, Z14= 0x6E, Z15= 0x6F,
+    Z16= 0x70, Z17= 0x71, Z18= 0x72, Z19= 0x73,
+    Z20= 0x74, Z21= 0x75, Z22= 0x76, Z23= 0x77,
+    Z24= 0x78, Z25= 0x79, Z26= 0x7A, Z27= 0x7B,
+    Z28= 0x7C, Z29= 0x7D, Z30= 0x7E, Z31= 0x7F,
+
+    // scalable predicate registers (SVE).
+    P0 = 0x80, P1 = 0x81, P2 = 0x82, P3 = 0x83,
+    P4 = 0x84, P5 = 0x85, P6 = 0x86, P7 = 0x87,
+    P8 = 0x88, P9 = 0x89, P10= 0x8A, P11= 0x8B,
+    P12= 0x8C, P13= 0x8D, P14= 0x8E, P15= 0x8F,
+
+    // SME ZA array tiles. How many of these exist depends on the element size they're used with.
+    ZA0 = 0xA0, ZA1 = 0xA1, ZA2 = 0xA2, ZA3 = 0xA3,
+    ZA4 = 0xA4, ZA5 = 0xA5, ZA6 = 0xA6, ZA7 = 0xA7,
+    ZA8 = 0xA8, ZA9 = 0xA9, ZA10= 0xAA, ZA11= 0xAB,
+    ZA12= 0xAC, ZA13= 0xAD, ZA14= 0xAE, ZA15= 0xAF,
+
+    // the SME ZA array as a whole
+    ZA = 0xC0,
 }
 
 // register family. INTEGER = Xn/Wn including XZR/WZR. INTEGERSP is just SP or XSP. SIMD = Bn/Hn/Sn/Dn/Qn
+// SCALABLE = Zn, PREDICATE = Pn, TILE = ZAn, ARRAY = ZA
 #[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
 pub enum RegFamily {
     INTEGER   = 0,
     INTEGERSP = 1,
     SIMD      = 2,
+    SCALABLE  = 3,
+    PREDICATE = 4,
+    TILE      = 5,
+    ARRAY     = 6,
 }
 
 impl RegId {
@@ -85,6 +160,10 @@ impl RegId {
             0 => RegFamily::INTEGER,
             1 => RegFamily::INTEGERSP,
             2 => RegFamily::SIMD,
+            3 => RegFamily::SCALABLE,
+            4 => RegFamily::PREDICATE,
+            5 => RegFamily::TILE,
+            6 => RegFamily::ARRAY,
             _ => unreachable!()
         }
     }
@@ -165,24 +244,32 @@ impl RegVector {
 }
 
 impl Register {
-    pub fn size(&self) -> Size {
+    /// Returns the size of this register, or the size of its elements for vector registers.
+    /// Returns None for scalable registers and ZA operands without an element size.
+    pub fn size(&self) -> Option<Size> {
         match self {
-            Register::Scalar(s) => s.size(),
-            Register::Vector(v) => v.element_size()
+            Register::Scalar(s) => Some(s.size()),
+            Register::Vector(v) => Some(v.element_size()),
+            Register::Scalable(s) => s.element_size,
+            Register::Za(z) => z.element_size
         }
     }
 
     pub fn kind(&self) -> &RegKind {
         match self {
             Register::Scalar(s) => &s.kind,
-            Register::Vector(v) => &v.kind
+            Register::Vector(v) => &v.kind,
+            Register::Scalable(s) => &s.kind,
+            Register::Za(z) => &z.kind
         }
     }
 
     pub fn kind_owned(self) -> RegKind {
         match self {
             Register::Scalar(s) => s.kind,
-            Register::Vector(v) => v.kind
+            Register::Vector(v) => v.kind,
+            Register::Scalable(s) => s.kind,
+            Register::Za(z) => z.kind
         }
     }
 
@@ -190,13 +277,15 @@ impl Register {
         match self {
             Register::Scalar(s) => s.kind.family(),
             Register::Vector(_) => RegFamily::SIMD,
+            Register::Scalable(s) => s.kind.family(),
+            Register::Za(z) => z.kind.family(),
         }
     }
 
     pub fn assume_vector(&self) -> &RegVector {
         match self {
-            Register::Scalar(_) => panic!("That wasn't a vector register"),
-            Register::Vector(v) => v
+            Register::Vector(v) => v,
+            _ => panic!("That wasn't a vector register")
         }
     }
 }
@@ -220,6 +309,7 @@ pub enum Modifier {
     UXTH,
     UXTB,
     MSL,
+    MUL,
 }
 
 impl Modifier {
@@ -238,6 +328,7 @@ impl Modifier {
             Modifier::UXTH => "UXTH",
             Modifier::UXTB => "UXTB",
             Modifier::MSL => "MSL",
+            Modifier::MUL => "MUL",
         }
     }
 
@@ -247,7 +338,8 @@ impl Modifier {
             | Modifier::LSR
             | Modifier::ASR
             | Modifier::ROR
-            | Modifier::MSL => true,
+            | Modifier::MSL
+            | Modifier::MUL => true,
             Modifier::SXTX
             | Modifier::SXTW
             | Modifier::SXTH
@@ -291,6 +383,10 @@ pub enum RefItem {
     Modifier {
         span: Span,
         modifier: ModifyExpr
+    },
+    // the MUL VL suffix of SVE vector-length scaled offsets
+    MulVl {
+        span: Span
     }
 }
 
@@ -348,6 +444,11 @@ pub enum RawArg {
     // an ident, not intended to be parsed as an expression
     Lit {
         ident: syn::Ident
+    },
+    // a dynamically chosen condition code, i.e. Cond(expr)
+    Cond {
+        span: Span,
+        value: syn::Expr
     }
 }
 
@@ -364,6 +465,7 @@ pub enum RefKind {
     Offset(syn::Expr),
     Indexed(Register, Option<ModifyExpr>),
     PreIndexed(syn::Expr),
+    MulVl(syn::Expr),
 }
 
 // sanitized parse results
@@ -380,6 +482,11 @@ pub enum CleanArg {
         amount: u8,
         element: Option<syn::Expr>
     },
+    // a list of ZA tiles, stored as the mask of the 64-bit tiles it covers
+    TileList {
+        span: Span,
+        mask: u8
+    },
     Direct {
         span: Span,
         reg: Register
@@ -400,6 +507,10 @@ pub enum CleanArg {
     },
     Lit {
         ident: syn::Ident
+    },
+    Cond {
+        span: Span,
+        value: syn::Expr
     }
 }
 
@@ -423,5 +534,9 @@ pub enum FlatArg {
     Lit {
         ident: syn::Ident
     },
+    Cond {
+        span: Span,
+        value: syn::Expr
+    },
     Default
 }
@@ -1,5 +1,5 @@
 use super::matching::MatchData;
-use super::aarch64data::{Command, COND_MAP, SPECIAL_IDENT_MAP, SpecialComm, Relocation};
+use super::aarch64data::{Command, COND_MAP, SPECIAL_IDENT_MAP, SYSREG_MAP, SpecialComm, Relocation};
 use super::Context;
 use super::ast::{FlatArg, RegKind, RegId, Modifier};
 use super::encoding_helpers;
@@ -12,6 +12,14 @@ use quote::{quote, quote_spanned};
 use proc_macro2::TokenStream;
 
 pub(super) fn compile_instruction(ctx: &mut Context, data: MatchData) -> Result<(), Option<String>> {
+    // determine if the features required for this op are fulfilled
+    if !ctx.features.contains(data.data.features) {
+        return Err(Some(format!(
+            "This instruction uses features that are not indicated to be available: {}",
+            data.data.features - ctx.features
+        )));
+    }
+
     let mut cursor = 0usize;
 
     // All static bitfields (compile-time constant) will be encoded into this map of (offset, bitfield)
@@ -68,6 +76,44 @@ pub(super) fn compile_instruction(ctx: &mut Context, data: MatchData) -> Result<
                     }
                     statics.push((offset, u32::from(id.code())));
                 },
+                Command::R3(offset) => {
+                    if id.code() >= 8 {
+                        emit_error_at(span, "Field only supports register numbers 0-7".into());
+                        return Err(None);
+                    }
+                    statics.push((offset, u32::from(id.code())));
+                },
+                Command::RTile(offset, bitlen) => {
+                    let mask = bitmask(bitlen);
+                    if u32::from(id.code()) > mask {
+                        emit_error_at(span, format!("Field only supports ZA tiles 0-{}", mask));
+                        return Err(None);
+                    }
+                    statics.push((offset, u32::from(id.code())));
+                },
+                Command::RSelect(offset) => {
+                    if id.code() < 12 || id.code() > 15 {
+                        emit_error_at(span, "Field only supports the registers W12-W15".into());
+                        return Err(None);
+                    }
+                    statics.push((offset, u32::from(id.code() - 12)));
+                },
+                Command::RTied => {
+                    if let Some(FlatArg::Direct { span: _firstspan, reg: ref firstreg } ) = data.args.get(0) {
+                        match firstreg {
+                            RegKind::Static(firstid) => if id.code() != firstid.code() {
+                                emit_error_at(span, "Invalid register. This register has to be the same register as the first argument.".into());
+                                return Err(None);
+                            },
+                            RegKind::Dynamic(_, _) => {
+                                emit_error_at(span, "This register has to be the same register as the first argument, which is dynamic. Please use the same dynamic register here.".into());
+                                return Err(None);
+                            }
+                        }
+                    } else {
+                        panic!("RTied command without the first argument being a register");
+                    }
+                },
                 Command::RNext => {
                     if let Some(FlatArg::Direct { span: _prevspan, reg: ref prevreg } ) = data.args.get(cursor - 1) {
                         match prevreg {
@@ -103,19 +149,45 @@ pub(super) fn compile_instruction(ctx: &mut Context, data: MatchData) -> Result<
                         #expr & 0xF
                     }));
                 },
+                Command::R3(offset) => {
+                    dynamics.push((offset, quote_spanned!{ span=>
+                        #expr & 0x7
+                    }));
+                },
+                Command::RTile(offset, bitlen) => if bitlen != 0 {
+                    let mask = bitmask(bitlen);
+                    dynamics.push((offset, quote_spanned!{ span=>
+                        #expr & #mask
+                    }));
+                },
+                Command::RSelect(offset) => {
+                    dynamics.push((offset, quote_spanned!{ span=>
+                        #expr & 0x3
+                    }));
+                },
+                Command::RTied => {
+                    // there's no way to check this at compile time, so we trust the user to pass the same register
+                    if let Some(FlatArg::Direct { reg: RegKind::Static(_), .. } ) = data.args.get(0) {
+                        emit_error_at(span, "This register has to be the same register as the first argument, which is static. Please use the same static register here.".into());
+                        return Err(None);
+                    }
+                },
                 Command::RNext => {
                     emit_error_at(span, "This register is constrained to be the register after the previous argument's register. As such, it does not support dynamic registers. Please substitute it with XZR to indicate this".into());
                     return Err(None);
                 },
                 _ => panic!("Invalid argument processor")
             },
-            FlatArg::Modifier { modifier, .. } => match *command {
+            FlatArg::Modifier { span, modifier } => match *command {
                 Command::Rotates(offset) => match modifier {
                     Modifier::LSL => statics.push((offset, 0b00)),
                     Modifier::LSR => statics.push((offset, 0b01)),
                     Modifier::ASR => statics.push((offset, 0b10)),
                     Modifier::ROR => statics.push((offset, 0b11)),
-                    _ => panic!("Unexpected modifier for argument processor")
+                    _ => {
+                        emit_error_at(span, format!("The {} modifier cannot be used here", modifier.as_str()));
+                        return Err(None);
+                    }
                 },
                 Command::ExtendsW(offset) => match modifier {
                     Modifier::UXTB => statics.push((offset, 0b000)),
@@ -127,7 +199,10 @@ pub(super) fn compile_instruction(ctx: &mut Context, data: MatchData) -> Result<
                     Modifier::SXTW => statics.push((offset, 0b110)),
                     Modifier::SXTX => statics.push((offset, 0b111)),
                     Modifier::LSL  => statics.push((offset, 0b010)),
-                    _ => panic!("Unexpected modifier for argument processor")
+                    _ => {
+                        emit_error_at(span, format!("The {} modifier cannot be used here", modifier.as_str()));
+                        return Err(None);
+                    }
                 },
                 Command::ExtendsX(offset) => match modifier {
                     Modifier::UXTB => statics.push((offset, 0b000)),
@@ -139,7 +214,18 @@ pub(super) fn compile_instruction(ctx: &mut Context, data: MatchData) -> Result<
                     Modifier::SXTW => statics.push((offset, 0b110)),
                     Modifier::SXTX => statics.push((offset, 0b111)),
                     Modifier::LSL  => statics.push((offset, 0b011)),
-                    _ => panic!("Unexpected modifier for argument processor")
+                    _ => {
+                        emit_error_at(span, format!("The {} modifier cannot be used here", modifier.as_str()));
+                        return Err(None);
+                    }
+                },
+                Command::ExtendsZ(offset) => match modifier {
+                    Modifier::UXTW => statics.push((offset, 0b0)),
+                    Modifier::SXTW => statics.push((offset, 0b1)),
+                    _ => {
+                        emit_error_at(span, format!("The {} modifier cannot be used here", modifier.as_str()));
+                        return Err(None);
+                    }
                 },
                 _ => panic!("Invalid argument processor")
             },
@@ -166,6 +252,14 @@ pub(super) fn compile_instruction(ctx: &mut Context, data: MatchData) -> Result<
                         return Err(None);
                     }
                 },
+                Command::SysRegRead(offset) => {
+                    let ident = as_ident(value).expect("bad command data");
+                    statics.push((offset, encode_sysreg(ident, false)?));
+                },
+                Command::SysRegWrite(offset) => {
+                    let ident = as_ident(value).expect("bad command data");
+                    statics.push((offset, encode_sysreg(ident, true)?));
+                },
 
                 // unsigned integer encodings
                 Command::Ubits(offset, bitlen) => {
@@ -198,6 +292,16 @@ pub(super) fn compile_instruction(ctx: &mut Context, data: MatchData) -> Result<
                         }));
                     }
                 },
+                Command::Unegslice(offset, bitlen, shift) => {
+                    let mask = bitmask(bitlen);
+                    if let Some(value) = as_number(value) {
+                        statics.push((offset, ((value as u32).wrapping_neg() >> shift) & mask));
+                    } else {
+                        dynamics.push((offset, quote_spanned!{ value.span()=>
+                            ((#value as u32).wrapping_neg() >> #shift) & #mask
+                        }));
+                    }
+                },
                 Command::Ulist(offset, options) => {
                     if let Some(number) = as_number(value) {
                         if let Some(i) = options.iter().rposition(|&n| u64::from(n) == number) {
@@ -257,6 +361,19 @@ pub(super) fn compile_instruction(ctx: &mut Context, data: MatchData) -> Result<
                         panic!("Bad encoding data, previous argument was not an immediate");
                     }
                 },
+                Command::Usame(back) => {
+                    if let Some(FlatArg::Immediate { value: other } ) = data.args.get(cursor - usize::from(back)) {
+                        // this can only be checked if both values are known at compile time
+                        if let (Some(number), Some(other)) = (as_number(value), as_number(other)) {
+                            if number != other {
+                                emit_error_at(value.span(), "This value has to be the same as the earlier offset".into());
+                                return Err(None);
+                            }
+                        }
+                    } else {
+                        panic!("Bad encoding data, the tied argument was not an immediate");
+                    }
+                },
                 Command::Ufields(bitfields) => {
                     let mask = bitmask(bitfields.len() as u8);
                     if let Some(value) = unsigned_rangecheck(value, 0, mask, 0) {
@@ -296,6 +413,29 @@ pub(super) fn compile_instruction(ctx: &mut Context, data: MatchData) -> Result<
                         }));
                     }
                 },
+                Command::Smultiple(offset, bitlen, factor) => {
+                    let mask = bitmask(bitlen);
+                    let half = -1i32 << (bitlen - 1);
+                    if let Some(number) = as_signed_number(value) {
+                        let factor = i64::from(factor);
+                        if number % factor != 0 {
+                            emit_error_at(value.span(), "Unrepresentable value".into());
+                            return Err(None);
+                        } else if number / factor > i64::from(mask as i32 + half) {
+                            emit_error_at(value.span(), "Value too large".into());
+                            return Err(None);
+                        } else if number / factor < i64::from(half) {
+                            emit_error_at(value.span(), "Value too small".into());
+                            return Err(None);
+                        }
+                        statics.push((offset, ((number / factor) as u32) & mask));
+                    } else {
+                        let factor = i32::from(factor);
+                        dynamics.push((offset, quote_spanned!{ value.span()=>
+                            ((#value / #factor) as u32) & #mask
+                        }));
+                    }
+                },
                 Command::Sslice(offset, bitlen, shift) => {
                     let mask = bitmask(bitlen);
                     if let Some(value) = as_signed_number(value) {
@@ -453,16 +593,19 @@ pub(super) fn compile_instruction(ctx: &mut Context, data: MatchData) -> Result<
                 Command::Ubits(_, _) |
                 Command::Uscaled(_, _, _) |
                 Command::Uslice(_, _, _) |
+                Command::Unegslice(_, _, _) |
                 Command::Urange(_, _, _) |
                 Command::Ulist(_, _) |
                 Command::Ufields(_) |
                 Command::Sbits(_, _) |
                 Command::Sscaled(_, _, _) |
+                Command::Smultiple(_, _, _) |
                 Command::Sslice(_, _, _) => (),
 
                 // integer checks don't have anything to check
                 Command::BUbits(_) |
-                Command::BSscaled(_, _) => (),
+                Command::BSscaled(_, _) |
+                Command::Usame(_) => (),
 
                 _ => panic!("Invalid argument processor")
             },
@@ -501,6 +644,23 @@ pub(super) fn compile_instruction(ctx: &mut Context, data: MatchData) -> Result<
                         return Err(None);
                     }
                 },
+                Command::SysRegRead(offset) => statics.push((offset, encode_sysreg(ident, false)?)),
+                Command::SysRegWrite(offset) => statics.push((offset, encode_sysreg(ident, true)?)),
+                _ => panic!("Invalid argument processor")
+            },
+            FlatArg::Cond { span, ref value } => match *command {
+
+                // Condition codes determined at runtime
+                Command::Cond(offset) => {
+                    dynamics.push((offset, quote_spanned!{ span=>
+                        (#value) & 0xF
+                    }));
+                },
+                Command::CondInv(offset) => {
+                    dynamics.push((offset, quote_spanned!{ span=>
+                        ((#value) & 0xF) ^ 1
+                    }));
+                },
                 _ => panic!("Invalid argument processor")
             }
         }
@@ -508,7 +668,8 @@ pub(super) fn compile_instruction(ctx: &mut Context, data: MatchData) -> Result<
         // figure out how far the cursor has to be advanced.
         match *command {
             Command::Uslice(_, _, _) |
-            Command::Sslice(_, _, _) => (),
+            Command::Sslice(_, _, _) |
+            Command::Unegslice(_, _, _) => (),
             Command::BUbits(_) |
             Command::BUsum(_) |
             Command::BSscaled(_, _) |
@@ -635,6 +796,38 @@ fn handle_special_immediates(offset: u8, special: SpecialComm, imm: &syn::Expr,
             }));
             return Ok(());
         },
+        SpecialComm::LOGICAL_IMMEDIATE_B => if let Some(number) = as_number(imm) {
+            if number <= u64::from(std::u8::MAX) {
+                if let Some(encoded) = encoding_helpers::encode_logical_immediate_32bit(number as u32 * 0x0101_0101) {
+                    statics.push((offset, u32::from(encoded)));
+                    return Ok(());
+                }
+            }
+        } else {
+            dynamics.push((offset, quote_spanned!{ imm.span()=>
+                {
+                    let value: u8 = #imm;
+                    dynasmrt::aarch64::encode_logical_immediate_32bit(u32::from(value) * 0x0101_0101).expect("Impossible logical immediate") as u32
+                }
+            }));
+            return Ok(());
+        },
+        SpecialComm::LOGICAL_IMMEDIATE_H => if let Some(number) = as_number(imm) {
+            if number <= u64::from(std::u16::MAX) {
+                if let Some(encoded) = encoding_helpers::encode_logical_immediate_32bit(number as u32 * 0x0001_0001) {
+                    statics.push((offset, u32::from(encoded)));
+                    return Ok(());
+                }
+            }
+        } else {
+            dynamics.push((offset, quote_spanned!{ imm.span()=>
+                {
+                    let value: u16 = #imm;
+                    dynasmrt::aarch64::encode_logical_immediate_32bit(u32::from(value) * 0x0001_0001).expect("Impossible logical immediate") as u32
+                }
+            }));
+            return Ok(());
+        },
         SpecialComm::LOGICAL_IMMEDIATE_W => if let Some(number) = as_number(imm) {
             if number <= u64::from(std::u32::MAX) {
                 if let Some(encoded) = encoding_helpers::encode_logical_immediate_32bit(number as u32) {
@@ -696,6 +889,57 @@ fn handle_special_immediates(offset: u8, special: SpecialComm, imm: &syn::Expr,
     Err(None)
 }
 
+/// Looks up the o0:op1:CRn:CRm:op2 encoding of a named system register, or of a system register
+/// written as s<op0>_<op1>_c<n>_c<m>_<op2>, and checks that it can be read or written.
+fn encode_sysreg(ident: &syn::Ident, write: bool) -> Result<u32, Option<String>> {
+    let name = ident.to_string();
+
+    if let Some(&(bits, access)) = SYSREG_MAP.get(&&*name) {
+        if write && !access.writable() {
+            emit_error_at(ident.span(), format!("System register '{}' is read-only", name));
+            return Err(None);
+        } else if !write && !access.readable() {
+            emit_error_at(ident.span(), format!("System register '{}' is write-only", name));
+            return Err(None);
+        }
+        return Ok(bits);
+    }
+
+    if let Some(bits) = parse_generic_sysreg(&name) {
+        return Ok(bits);
+    }
+
+    if write && SPECIAL_IDENT_MAP["MSR_IMM_OPS"].contains_key(&&*name) {
+        emit_error_at(ident.span(), format!("PSTATE field '{}' can only be written with an immediate", name));
+    } else {
+        emit_error_at(ident.span(), format!("Unknown system register '{}'", name));
+    }
+    Err(None)
+}
+
+/// Parses the s<op0>_<op1>_c<n>_c<m>_<op2> notation for system registers.
+fn parse_generic_sysreg(name: &str) -> Option<u32> {
+    let mut fields = name.strip_prefix('s')?.split('_');
+    let mut field = |prefix: &str, max: u32| -> Option<u32> {
+        let digits = fields.next()?.strip_prefix(prefix)?;
+        if digits.is_empty() || !digits.bytes().all(|b| b.is_ascii_digit()) {
+            return None;
+        }
+        digits.parse().ok().filter(|&v| v <= max)
+    };
+
+    let op0 = field("", 3)?;
+    let op1 = field("", 7)?;
+    let crn = field("c", 15)?;
+    let crm = field("c", 15)?;
+    let op2 = field("", 7)?;
+    if op0 < 2 || fields.next().is_some() {
+        return None;
+    }
+
+    Some(((op0 - 2) << 14) | (op1 << 11) | (crn << 7) | (crm << 3) | op2)
+}
+
 fn unsigned_rangecheck(expr: &syn::Expr, min: u32, max: u32, scale: u8) -> Option<Result<u32, Option<String>>> {
     let value = as_number(expr)?;
     let scaled = value >> scale;
@@ -139,11 +139,36 @@ pub fn format_opdata(name: &str, data: &Opdata) -> Vec<String> {
                 },
                 Matcher::RegListStatic(a, s, c) => write!(buf, "{{V{}.{}{} * {}}}", arg_names[0], size_to_string(*s), c, a).unwrap(),
                 Matcher::RegListElement(a, s) =>   write!(buf, "{{V{}.{} * {}}}[{}]", arg_names[0], size_to_string(*s), a, arg_names[1]).unwrap(),
+                Matcher::Z(s) => write!(buf, "Z{}.{}", arg_names[0], size_to_string(*s)).unwrap(),
+                Matcher::ZBare => write!(buf, "Z{}", arg_names[0]).unwrap(),
+                Matcher::ZElement(s) => write!(buf, "Z{}.{}[{}]", arg_names[0], size_to_string(*s), arg_names[1]).unwrap(),
+                Matcher::ZRegList(a, s) => write!(buf, "{{Z{}.{} * {}}}", arg_names[0], size_to_string(*s), a).unwrap(),
+                Matcher::P(s) => write!(buf, "P{}.{}", arg_names[0], size_to_string(*s)).unwrap(),
+                Matcher::PBare => write!(buf, "P{}", arg_names[0]).unwrap(),
+                Matcher::PMerge => write!(buf, "P{}/M", arg_names[0]).unwrap(),
+                Matcher::PZero => write!(buf, "P{}/Z", arg_names[0]).unwrap(),
+                Matcher::ZaTile(s) => write!(buf, "ZA{}.{}", arg_names[0], size_to_string(*s)).unwrap(),
+                Matcher::ZaH(s) => write!(buf, "ZA{}H.{}[W{}, #{}]", arg_names[0], size_to_string(*s), arg_names[1], arg_names[2]).unwrap(),
+                Matcher::ZaV(s) => write!(buf, "ZA{}V.{}[W{}, #{}]", arg_names[0], size_to_string(*s), arg_names[1], arg_names[2]).unwrap(),
+                Matcher::ZaHList(s) => write!(buf, "{{ZA{}H.{}[W{}, #{}]}}", arg_names[0], size_to_string(*s), arg_names[1], arg_names[2]).unwrap(),
+                Matcher::ZaVList(s) => write!(buf, "{{ZA{}V.{}[W{}, #{}]}}", arg_names[0], size_to_string(*s), arg_names[1], arg_names[2]).unwrap(),
+                Matcher::ZaArray => write!(buf, "ZA[W{}, #{}]", arg_names[0], arg_names[1]).unwrap(),
+                Matcher::ZaTileList => write!(buf, "{{<tiles>}}").unwrap(),
                 Matcher::Offset => buf.push_str(&arg_names[0]),
                 Matcher::RefBase =>   write!(buf, "[X{}|SP]", arg_names[0]).unwrap(),
                 Matcher::RefOffset => write!(buf, "[X{}|SP {{, #{} }} ]", arg_names[0], arg_names[1]).unwrap(),
                 Matcher::RefPre =>    write!(buf, "[X{}|SP, #{}]!", arg_names[0], arg_names[1]).unwrap(),
                 Matcher::RefIndex =>  write!(buf, "[X{}|SP, W{}|X{} {{ , UXTW|LSL|SXTW|SXTX {{ #{} }} }} ]", arg_names[0], arg_names[1], arg_names[1], arg_names[3]).unwrap(),
+                Matcher::RefMulVl =>  write!(buf, "[X{}|SP {{, #{}, MUL VL }} ]", arg_names[0], arg_names[1]).unwrap(),
+                Matcher::RefIndexLsl(0) => write!(buf, "[X{}|SP, X{}]", arg_names[0], arg_names[1]).unwrap(),
+                Matcher::RefIndexLsl(shift) => write!(buf, "[X{}|SP, X{}, LSL #{}]", arg_names[0], arg_names[1], shift).unwrap(),
+                Matcher::RefZIndexLsl(s, 0) => write!(buf, "[X{}|SP, Z{}.{}]", arg_names[0], arg_names[1], size_to_string(*s)).unwrap(),
+                Matcher::RefZIndexLsl(s, shift) => write!(buf, "[X{}|SP, Z{}.{}, LSL #{}]", arg_names[0], arg_names[1], size_to_string(*s), shift).unwrap(),
+                Matcher::RefZIndexExtend(s, 0) => write!(buf, "[X{}|SP, Z{}.{}, UXTW|SXTW]", arg_names[0], arg_names[1], size_to_string(*s)).unwrap(),
+                Matcher::RefZIndexExtend(s, shift) => write!(buf, "[X{}|SP, Z{}.{}, UXTW|SXTW #{}]", arg_names[0], arg_names[1], size_to_string(*s), shift).unwrap(),
+                Matcher::RefZBase(s) => write!(buf, "[Z{}.{} {{, #{} }} ]", arg_names[0], size_to_string(*s), arg_names[1]).unwrap(),
+                Matcher::RefZZ(s) => write!(buf, "[Z{}.{}, Z{}.{} {{, LSL #{} }} ]", arg_names[0], size_to_string(*s), arg_names[1], size_to_string(*s), arg_names[2]).unwrap(),
+                Matcher::RefZZExtend(m) => write!(buf, "[Z{}.D, Z{}.D, {} {{ #{} }} ]", arg_names[0], arg_names[1], m.as_str(), arg_names[2]).unwrap(),
                 Matcher::LitMod(m) => {
                     buf.push_str(m.as_str());
                     if !m.expr_required() {
@@ -160,7 +185,7 @@ pub fn format_opdata(name: &str, data: &Opdata) -> Vec<String> {
                     for m in *mods {
                         required = required || m.expr_required();
                         match m {
-                            Modifier::LSL | Modifier::LSR | Modifier::ASR | Modifier::ROR | Modifier::MSL => rest.push(m.as_str()),
+                            Modifier::LSL | Modifier::LSR | Modifier::ASR | Modifier::ROR | Modifier::MSL | Modifier::MUL => rest.push(m.as_str()),
                             Modifier::SXTX | Modifier::SXTW | Modifier::SXTH | Modifier::SXTB => signed_extends.push(m.as_str().chars().nth(3).unwrap()),
                             Modifier::UXTX | Modifier::UXTW | Modifier::UXTH | Modifier::UXTB => unsigned_extends.push(m.as_str().chars().nth(3).unwrap()),
                         }
@@ -207,6 +232,10 @@ pub fn format_opdata(name: &str, data: &Opdata) -> Vec<String> {
             buf.push_str(&c);
         }
 
+        if !data.features.is_empty() {
+            write!(buf, " ({})", data.features).unwrap();
+        }
+
         forms.push(buf);
     }
 
@@ -303,9 +332,31 @@ fn flatten_matchers(matchers: &[Matcher]) -> Vec<(FlatArgTy, bool)> {
             | Matcher::VElementStatic(_, _)
             | Matcher::RegList(_, _)
             | Matcher::RegListStatic(_, _, _) => args.push((FlatArgTy::Direct, default)),
+            Matcher::Z(_)
+            | Matcher::ZBare
+            | Matcher::ZRegList(_, _)
+            | Matcher::P(_)
+            | Matcher::PBare
+            | Matcher::PMerge
+            | Matcher::PZero => args.push((FlatArgTy::Direct, default)),
+            Matcher::ZaTile(_) => args.push((FlatArgTy::Direct, default)),
+            Matcher::ZaH(_)
+            | Matcher::ZaV(_)
+            | Matcher::ZaHList(_)
+            | Matcher::ZaVList(_) => {
+                args.push((FlatArgTy::Direct, default));
+                args.push((FlatArgTy::Direct, default));
+                args.push((FlatArgTy::Immediate, default));
+            },
+            Matcher::ZaArray => {
+                args.push((FlatArgTy::Direct, default));
+                args.push((FlatArgTy::Immediate, default));
+            },
+            Matcher::ZaTileList => args.push((FlatArgTy::Immediate, default)),
             Matcher::VElement(_)
             | Matcher::VStaticElement(_, _)
-            | Matcher::RegListElement(_, _) => {
+            | Matcher::RegListElement(_, _)
+            | Matcher::ZElement(_) => {
                 args.push((FlatArgTy::Direct, default));
                 args.push((FlatArgTy::Immediate, default));
             },
@@ -325,6 +376,27 @@ fn flatten_matchers(matchers: &[Matcher]) -> Vec<(FlatArgTy, bool)> {
                 args.push((FlatArgTy::Modifier, true));
                 args.push((FlatArgTy::Immediate, true));
             },
+            Matcher::RefMulVl
+            | Matcher::RefZBase(_) => {
+                args.push((FlatArgTy::Direct, default));
+                args.push((FlatArgTy::Immediate, true));
+            },
+            Matcher::RefIndexLsl(_)
+            | Matcher::RefZIndexLsl(_, _) => {
+                args.push((FlatArgTy::Direct, default));
+                args.push((FlatArgTy::Direct, default));
+            },
+            Matcher::RefZIndexExtend(_, _) => {
+                args.push((FlatArgTy::Direct, default));
+                args.push((FlatArgTy::Direct, default));
+                args.push((FlatArgTy::Modifier, default));
+            },
+            Matcher::RefZZ(_)
+            | Matcher::RefZZExtend(_) => {
+                args.push((FlatArgTy::Direct, default));
+                args.push((FlatArgTy::Direct, default));
+                args.push((FlatArgTy::Immediate, true));
+            },
             Matcher::LitMod(_) => {
                 args.push((FlatArgTy::Immediate, true));
             },
@@ -364,25 +436,34 @@ fn group_commands(commands: &[Command]) -> (usize, Vec<(Command, usize)>) {
             Command::R(_)
             | Command::REven(_)
             | Command::R4(_)
+            | Command::R3(_)
             | Command::RNoZr(_)
             | Command::RNext
+            | Command::RTied
+            | Command::RTile(_, _)
+            | Command::RSelect(_)
             | Command::Ubits(_, _)
             | Command::Uscaled(_, _, _)
             | Command::Ulist(_, _)
             | Command::Urange(_, _, _)
             | Command::Usub(_, _, _)
+            | Command::Usame(_)
             | Command::Unegmod(_, _)
             | Command::Usumdec(_, _)
             | Command::Ufields(_)
             | Command::Sbits(_, _)
             | Command::Sscaled(_, _,_)
+            | Command::Smultiple(_, _, _)
             | Command::Special(_, _)
             | Command::Rotates(_)
             | Command::ExtendsW(_)
             | Command::ExtendsX(_)
+            | Command::ExtendsZ(_)
             | Command::Cond(_)
             | Command::CondInv(_)
             | Command::LitList(_, _)
+            | Command::SysRegRead(_)
+            | Command::SysRegWrite(_)
             | Command::Offset(_) => cursor += 1,
             _ => ()
         }
@@ -403,8 +484,12 @@ fn check_command_sanity(args: &[ArgWithCommands]) -> Result<(), &'static str> {
                 Command::R(_)
                 | Command::REven(_)
                 | Command::R4(_)
+                | Command::R3(_)
                 | Command::RNoZr(_)
-                | Command::RNext => arg.arg == FlatArgTy::Direct,
+                | Command::RNext
+                | Command::RTied
+                | Command::RTile(_, _)
+                | Command::RSelect(_) => arg.arg == FlatArgTy::Direct,
                 Command::Ubits(_, _)
                 | Command::Uscaled(_, _, _)
                 | Command::Ulist(_, _)
@@ -413,22 +498,28 @@ fn check_command_sanity(args: &[ArgWithCommands]) -> Result<(), &'static str> {
                 | Command::Unegmod(_, _)
                 | Command::Usumdec(_, _)
                 | Command::Ufields(_)
+                | Command::Usame(_)
                 | Command::Sbits(_, _)
                 | Command::Sscaled(_, _,_)
+                | Command::Smultiple(_, _, _)
                 | Command::BUbits(_)
                 | Command::BUsum(_)
                 | Command::BSscaled(_, _)
                 | Command::BUrange(_, _)
                 | Command::Uslice(_, _, _)
                 | Command::Sslice(_, _, _)
+                | Command::Unegslice(_, _, _)
                 | Command::Special(_, _) => arg.arg == FlatArgTy::Immediate,
                 Command::Cond(_)
                 | Command::CondInv(_)
-                | Command::LitList(_, _) => arg.arg == FlatArgTy::Lit || arg.arg == FlatArgTy::Immediate,
+                | Command::LitList(_, _)
+                | Command::SysRegRead(_)
+                | Command::SysRegWrite(_) => arg.arg == FlatArgTy::Lit || arg.arg == FlatArgTy::Immediate,
                 Command::Offset(_) => arg.arg == FlatArgTy::JumpTarget,
                 Command::Rotates(_)
                 | Command::ExtendsW(_)
-                | Command::ExtendsX(_) => arg.arg == FlatArgTy::Modifier,
+                | Command::ExtendsX(_)
+                | Command::ExtendsZ(_) => arg.arg == FlatArgTy::Modifier,
                 Command::A
                 | Command::C
                 | Command::Rwidth(_) => unreachable!()
@@ -448,7 +539,10 @@ fn check_command_sanity(args: &[ArgWithCommands]) -> Result<(), &'static str> {
                 | Command::Ufields(_)
                 | Command::Sbits(_, _)
                 | Command::Sscaled(_, _, _)
+                | Command::Smultiple(_, _, _)
                 | Command::Sslice(_, _, _)
+                | Command::Unegslice(_, _, _)
+                | Command::Usame(_)
                 | Command::BUbits(_)
                 | Command::BUsum(_)
                 | Command::BSscaled(_, _)
@@ -456,9 +550,14 @@ fn check_command_sanity(args: &[ArgWithCommands]) -> Result<(), &'static str> {
                 | Command::ExtendsW(_)
                 | Command::ExtendsX(_) => true,
                 Command::R4(_)
+                | Command::R3(_)
                 | Command::RNoZr(_)
                 | Command::REven(_)
                 | Command::RNext
+                | Command::RTied
+                | Command::RTile(_, _)
+                | Command::RSelect(_)
+                | Command::ExtendsZ(_)
                 | Command::Usub(_, _, _)
                 | Command::Unegmod(_, _)
                 | Command::Usumdec(_, _)
@@ -467,6 +566,8 @@ fn check_command_sanity(args: &[ArgWithCommands]) -> Result<(), &'static str> {
                 | Command::Cond(_)
                 | Command::CondInv(_)
                 | Command::LitList(_, _)
+                | Command::SysRegRead(_)
+                | Command::SysRegWrite(_)
                 | Command::Offset(_) => !arg.can_be_default,
                 Command::A
                 | Command::C
@@ -497,13 +598,20 @@ fn name_args(args: &mut [ArgWithCommands]) {
                     Command::R(_)
                     | Command::REven(_)
                     | Command::RNoZr(_)
-                    | Command::R4(_) => {
+                    | Command::R4(_)
+                    | Command::R3(_) => {
                         arg.name = Some(reg_name_list[reg_name_idx].to_string());
                         reg_name_idx += 1;
                     },
                     Command::RNext => {
                         arg.name = Some(format!("{}+1", reg_name_list[reg_name_idx - 1]));
                     },
+                    Command::RTied => {
+                        // tied operands share the name of the first operand
+                        arg.name = Some(reg_name_list[0].to_string());
+                    },
+                    Command::RTile(_, _) => arg.name = Some("t".to_string()),
+                    Command::RSelect(_) => arg.name = Some("v".to_string()),
                     _ => unreachable!()
                 }
             },
@@ -512,6 +620,8 @@ fn name_args(args: &mut [ArgWithCommands]) {
                     Command::Cond(_)
                     | Command::CondInv(_) => arg.name = None,
                     Command::LitList(_, name) => arg.name = Some(name.trim_end_matches('S').to_lowercase()),
+                    Command::SysRegRead(_)
+                    | Command::SysRegWrite(_) => arg.name = Some("sysreg".into()),
                     Command::Ubits(_, _)
                     | Command::Uscaled(_, _, _)
                     | Command::Ulist(_, _)
@@ -523,12 +633,14 @@ fn name_args(args: &mut [ArgWithCommands]) {
                     | Command::BUbits(_)
                     | Command::BUsum(_)
                     | Command::BUrange(_, _)
-                    | Command::Uslice(_, _, _) => {
+                    | Command::Uslice(_, _, _)
+                    | Command::Unegslice(_, _, _) => {
                         arg.name = Some(format!("uimm{}", imm_name_list[imm_name_idx]));
                         imm_name_idx += 1;
                     },
                     Command::Sbits(_, _)
                     | Command::Sscaled(_, _,_)
+                    | Command::Smultiple(_, _, _)
                     | Command::BSscaled(_, _)
                     | Command::Sslice(_, _, _) => {
                         arg.name = Some(format!("simm{}", imm_name_list[imm_name_idx]));
@@ -538,6 +650,10 @@ fn name_args(args: &mut [ArgWithCommands]) {
                         arg.name = Some(format!("imm{}", imm_name_list[imm_name_idx]));
                         imm_name_idx += 1;
                     },
+                    Command::Usame(_) => {
+                        // immediates that have to repeat an earlier immediate share its name
+                        arg.name = Some(format!("uimm{}", imm_name_list[imm_name_idx - 1]));
+                    },
                     _ => unreachable!()
                 }
             },
@@ -550,6 +666,8 @@ fn name_args(args: &mut [ArgWithCommands]) {
                 Command::Cond(_)
                 | Command::CondInv(_) => arg.name = None,
                 Command::LitList(_, name) => arg.name = Some(name.trim_end_matches('S').to_lowercase()),
+                Command::SysRegRead(_)
+                | Command::SysRegWrite(_) => arg.name = Some("sysreg".into()),
                 _ => unreachable!()
             }
         }
@@ -579,7 +697,10 @@ fn emit_constraints(name: &str, prevname: &str, commands: &[Command], buf: &mut
     for command in commands {
         match command {
             Command::R4(_) => write!(buf, "{} is 0-15", name),
+            Command::R3(_) => write!(buf, "{} is 0-7", name),
             Command::RNoZr(_) => write!(buf, "{} is 0-30", name),
+            Command::RTile(_, bits) => write!(buf, "{} is 0-{}", name, (1u32 << bits) - 1),
+            Command::RSelect(_) => write!(buf, "{} is 12-15", name),
             Command::REven(_) => write!(buf, "{} is even", name),
             Command::Ubits(_, bits)
             | Command::BUbits(bits) => write!(buf, "#{} < {}", name, 1u32 << bits),
@@ -598,11 +719,14 @@ fn emit_constraints(name: &str, prevname: &str, commands: &[Command], buf: &mut
             Command::Sbits(_, bits) => write!(buf, "-{} <= #{} < {}", 1u32 << (bits - 1), name, 1u32 << (bits - 1)),
             Command::Sscaled(_, bits, scale)
             | Command::BSscaled(bits, scale) => write!(buf, "-{} <= #{} < {}, #{} = {} * N", 1u32 << (bits + scale - 1), name, 1u32 << (bits + scale - 1), name, 1u32 << scale),
+            Command::Smultiple(_, bits, factor) => write!(buf, "-{} <= #{} < {}, #{} = {} * N", (1u32 << (bits - 1)) * u32::from(*factor), name, (1u32 << (bits - 1)) * u32::from(*factor), name, factor),
             Command::Special(_, SpecialComm::WIDE_IMMEDIATE_W)
             | Command::Special(_, SpecialComm::WIDE_IMMEDIATE_X)
             | Command::Special(_, SpecialComm::INVERTED_WIDE_IMMEDIATE_W)
             | Command::Special(_, SpecialComm::INVERTED_WIDE_IMMEDIATE_X) => write!(buf, "#{} is a wide immediate", name),
-            Command::Special(_, SpecialComm::LOGICAL_IMMEDIATE_W)
+            Command::Special(_, SpecialComm::LOGICAL_IMMEDIATE_B)
+            | Command::Special(_, SpecialComm::LOGICAL_IMMEDIATE_H)
+            | Command::Special(_, SpecialComm::LOGICAL_IMMEDIATE_W)
             | Command::Special(_, SpecialComm::LOGICAL_IMMEDIATE_X) => write!(buf, "#{} is a logical immediate", name),
             Command::Special(_, SpecialComm::FLOAT_IMMEDIATE)
             | Command::Special(_, SpecialComm::SPLIT_FLOAT_IMMEDIATE) => write!(buf, "#{} is a floating point immediate", name),
@@ -696,6 +820,25 @@ pub fn extract_opdata(name: &str, data: &Opdata) -> Vec<String> {
                 },
                 Matcher::RegListStatic(a, s, c) => write!(buf, "{{<V,{}>.{}{} * {}}}", arg_idx, size_to_string(*s), c, a).unwrap(),
                 Matcher::RegListElement(a, s) =>   write!(buf, "{{<V,{}>.{} * {}}}[<Imm,{}>]", arg_idx, size_to_string(*s), a, arg_idx + 1).unwrap(),
+                Matcher::Z(s) => write!(buf, "<Z,{}>.{}", arg_idx, size_to_string(*s)).unwrap(),
+                Matcher::ZBare => write!(buf, "<Z,{}>", arg_idx).unwrap(),
+                Matcher::ZElement(s) => write!(buf, "<Z,{}>.{}[<Imm,{}>]", arg_idx, size_to_string(*s), arg_idx + 1).unwrap(),
+                Matcher::ZRegList(a, s) => write!(buf, "{{<Z,{}>.{} * {}}}", arg_idx, size_to_string(*s), a).unwrap(),
+                Matcher::P(s) => write!(buf, "<P,{}>.{}", arg_idx, size_to_string(*s)).unwrap(),
+                Matcher::PBare => write!(buf, "<P,{}>", arg_idx).unwrap(),
+                Matcher::PMerge => write!(buf, "<P,{}>/m", arg_idx).unwrap(),
+                Matcher::PZero => write!(buf, "<P,{}>/z", arg_idx).unwrap(),
+                Matcher::ZaTile(s) => write!(buf, "<ZA,{}>.{}", arg_idx, size_to_string(*s)).unwrap(),
+                Matcher::ZaH(s) => write!(buf, "<ZAH,{}>.{}[<W,{}>, <Imm,{}>]", arg_idx, size_to_string(*s), arg_idx + 1, arg_idx + 2).unwrap(),
+                Matcher::ZaV(s) => write!(buf, "<ZAV,{}>.{}[<W,{}>, <Imm,{}>]", arg_idx, size_to_string(*s), arg_idx + 1, arg_idx + 2).unwrap(),
+                Matcher::ZaHList(s) => write!(buf, "{{<ZAH,{}>.{}[<W,{}>, <Imm,{}>]}}", arg_idx, size_to_string(*s), arg_idx + 1, arg_idx + 2).unwrap(),
+                Matcher::ZaVList(s) => write!(buf, "{{<ZAV,{}>.{}[<W,{}>, <Imm,{}>]}}", arg_idx, size_to_string(*s), arg_idx + 1, arg_idx + 2).unwrap(),
+                Matcher::ZaArray => write!(buf, "za[<W,{}>, <Imm,{}>]", arg_idx, arg_idx + 1).unwrap(),
+                Matcher::ZaTileList => {
+                    // an empty tile list cannot be written down
+                    constraints.push(format!("{}: Range(1, 256, 1)", arg_idx));
+                    write!(buf, "{{<ZAList,{}>}}", arg_idx).unwrap();
+                },
                 Matcher::Offset => write!(buf, "<Off,{}>", arg_idx).unwrap(),
                 Matcher::RefBase =>   write!(buf, "[<XSP,{}>]", arg_idx).unwrap(),
                 Matcher::RefOffset => write!(buf, "[<XSP,{}> <, <Imm,{}> > ]", arg_idx, arg_idx + 1).unwrap(),
@@ -704,6 +847,22 @@ pub fn extract_opdata(name: &str, data: &Opdata) -> Vec<String> {
                     constraints.push(format!("{}: ModWX()", arg_idx + 2));
                     write!(buf, "[<XSP,{}>, <WX,{}> < , <Mod,{}> < <Imm,{}> > > ]", arg_idx, arg_idx + 1, arg_idx + 2, arg_idx + 3).unwrap();
                 },
+                Matcher::RefMulVl => write!(buf, "[<XSP,{}> <, <Imm,{}>, mul vl > ]", arg_idx, arg_idx + 1).unwrap(),
+                Matcher::RefIndexLsl(0) => write!(buf, "[<XSP,{}>, <X,{}>]", arg_idx, arg_idx + 1).unwrap(),
+                Matcher::RefIndexLsl(shift) => write!(buf, "[<XSP,{}>, <X,{}>, LSL {}]", arg_idx, arg_idx + 1, shift).unwrap(),
+                Matcher::RefZIndexLsl(s, 0) => write!(buf, "[<XSP,{}>, <Z,{}>.{}]", arg_idx, arg_idx + 1, size_to_string(*s)).unwrap(),
+                Matcher::RefZIndexLsl(s, shift) => write!(buf, "[<XSP,{}>, <Z,{}>.{}, LSL {}]", arg_idx, arg_idx + 1, size_to_string(*s), shift).unwrap(),
+                Matcher::RefZIndexExtend(s, shift) => {
+                    constraints.push(format!("{}: List(\"UXTW\", \"SXTW\")", arg_idx + 2));
+                    if *shift == 0 {
+                        write!(buf, "[<XSP,{}>, <Z,{}>.{}, <Mod,{}>]", arg_idx, arg_idx + 1, size_to_string(*s), arg_idx + 2).unwrap();
+                    } else {
+                        write!(buf, "[<XSP,{}>, <Z,{}>.{}, <Mod,{}> {}]", arg_idx, arg_idx + 1, size_to_string(*s), arg_idx + 2, shift).unwrap();
+                    }
+                },
+                Matcher::RefZBase(s) => write!(buf, "[<Z,{}>.{} <, <Imm,{}> > ]", arg_idx, size_to_string(*s), arg_idx + 1).unwrap(),
+                Matcher::RefZZ(s) => write!(buf, "[<Z,{}>.{}, <Z,{}>.{} <, LSL <Imm,{}> > ]", arg_idx, size_to_string(*s), arg_idx + 1, size_to_string(*s), arg_idx + 2).unwrap(),
+                Matcher::RefZZExtend(m) => write!(buf, "[<Z,{}>.D, <Z,{}>.D, {} < <Imm,{}> > ]", arg_idx, arg_idx + 1, m.as_str(), arg_idx + 2).unwrap(),
                 Matcher::LitMod(m) => {
                     buf.push_str(m.as_str());
                     if !m.expr_required() {
@@ -748,7 +907,7 @@ pub fn extract_opdata(name: &str, data: &Opdata) -> Vec<String> {
 
 #[cfg(feature = "dynasm_extract")]
 fn extract_constraints(args: &[ArgWithCommands]) -> Vec<String> {
-    use super::aarch64data::{COND_MAP, SPECIAL_IDENT_MAP};
+    use super::aarch64data::{COND_MAP, SPECIAL_IDENT_MAP, SYSREG_MAP};
 
     let mut constraints = Vec::new();
     for (i, arg) in args.iter().enumerate() {
@@ -758,7 +917,12 @@ fn extract_constraints(args: &[ArgWithCommands]) -> Vec<String> {
                 Command::REven(_) => format!("R(32, 2)"),
                 Command::RNoZr(_) => format!("R(31)"),
                 Command::R4(_) => format!("R(16)"),
+                Command::R3(_) => format!("R(8)"),
                 Command::RNext => format!("RNext()"),
+                Command::RTied => format!("RTied()"),
+                Command::RTile(_, bits) => format!("R({})", 1u32 << bits),
+                Command::RSelect(_) => format!("Range(12, 16, 1)"),
+                Command::Usame(back) => format!("Same({})", back),
                 Command::Ubits(_, bits)
                 | Command::BUbits(bits) => format!("Range(0, {}, 1)", 1u32 << bits),
                 Command::Uscaled(_, bits, scale) => format!("Range(0, {}, {})", 1u32 << (bits + scale), 1u32 << scale),
@@ -776,10 +940,13 @@ fn extract_constraints(args: &[ArgWithCommands]) -> Vec<String> {
                 Command::Sbits(_, bits) => format!("Range(-{}, {}, 1)", 1u32 << (bits - 1), 1u32 << (bits - 1)),
                 Command::Sscaled(_, bits, scale)
                 | Command::BSscaled(bits, scale) => format!("Range(-{}, {}, {})", 1u32 << (bits + scale - 1), 1u32 << (bits + scale - 1), 1u32 << scale),
+                Command::Smultiple(_, bits, factor) => format!("Range(-{}, {}, {})", (1u32 << (bits - 1)) * u32::from(*factor), (1u32 << (bits - 1)) * u32::from(*factor), factor),
                 Command::Special(_, SpecialComm::WIDE_IMMEDIATE_W) => format!("Special('wide_w')"),
                 | Command::Special(_, SpecialComm::WIDE_IMMEDIATE_X) => format!("Special('wide_x')"),
                 | Command::Special(_, SpecialComm::INVERTED_WIDE_IMMEDIATE_W) => format!("Special('inverted_w')"),
                 | Command::Special(_, SpecialComm::INVERTED_WIDE_IMMEDIATE_X) => format!("Special('inverted_x')"),
+                Command::Special(_, SpecialComm::LOGICAL_IMMEDIATE_B) => format!("Special('logical_b')"),
+                | Command::Special(_, SpecialComm::LOGICAL_IMMEDIATE_H) => format!("Special('logical_h')"),
                 Command::Special(_, SpecialComm::LOGICAL_IMMEDIATE_W) => format!("Special('logical_w')"),
                 | Command::Special(_, SpecialComm::LOGICAL_IMMEDIATE_X) => format!("Special('logical_x')"),
                 Command::Special(_, SpecialComm::FLOAT_IMMEDIATE)
@@ -803,6 +970,14 @@ fn extract_constraints(args: &[ArgWithCommands]) -> Vec<String> {
                 Command::LitList(_, name) => {
                     let keys: Vec<_> = SPECIAL_IDENT_MAP[name].keys().map(|k| format!("\"{}\"", k)).collect();
                     format!("List({})", keys.join(", "))
+                },
+                Command::SysRegRead(_) => {
+                    let keys: Vec<_> = SYSREG_MAP.iter().filter(|(_, v)| v.1.readable()).map(|(k, _)| format!("\"{}\"", k)).collect();
+                    format!("List({})", keys.join(", "))
+                },
+                Command::SysRegWrite(_) => {
+                    let keys: Vec<_> = SYSREG_MAP.iter().filter(|(_, v)| v.1.writable()).map(|(k, _)| format!("\"{}\"", k)).collect();
+                    format!("List({})", keys.join(", "))
                 }
                 _ => continue
             };
@@ -2,7 +2,7 @@
 use proc_macro2::Span;
 
 use super::Context;
-use super::ast::{Instruction, RawArg, CleanArg, FlatArg, RefItem, Register, RegFamily, RefKind, Modifier};
+use super::ast::{Instruction, RawArg, CleanArg, FlatArg, RefItem, Register, RegScalable, RegZa, RegFamily, RegKind, RegId, RefKind, Modifier, ModifyExpr, PredicateQualifier, ZaOrientation};
 use super::aarch64data::{Opdata, Matcher, COND_MAP, get_mnemonic_data};
 use super::debug::format_opdata_list;
 
@@ -82,6 +82,10 @@ fn sanitize_args(args: Vec<RawArg>) -> Result<Vec<CleanArg>, Option<String>> {
             RawArg::Immediate { value, prefixed } => {
                 res.push(CleanArg::Immediate { value, prefixed })
             },
+            // dynamic condition code: pass through
+            RawArg::Cond { span, value } => {
+                res.push(CleanArg::Cond { span, value })
+            },
             // reference: first, assert the used indexing mode (base, offset, pre-indexed, or register-indexed)
             // then, verify that the base register is always an XSP register
             // for the register-indexed mode, additionally verify that the index register is either an W or an X register
@@ -113,6 +117,10 @@ fn sanitize_args(args: Vec<RawArg>) -> Result<Vec<CleanArg>, Option<String>> {
                         emit_error_at(span, "Cannot have a modifier without index register or offset".into());
                         return Err(None);
                     },
+                    Some(RefItem::MulVl { span }) => {
+                        emit_error_at(span, "Cannot have MUL VL without an offset".into());
+                        return Err(None);
+                    },
                     None => hit_end = true
                 }
 
@@ -130,6 +138,22 @@ fn sanitize_args(args: Vec<RawArg>) -> Result<Vec<CleanArg>, Option<String>> {
                     }
                 }
 
+                // if the second item was an offset, it could be scaled by the vector length
+                if let RefKind::Offset(_) = kind {
+                    match items.next() {
+                        Some(RefItem::MulVl { .. }) => {
+                            if let RefKind::Offset(offset) = kind {
+                                kind = RefKind::MulVl(offset);
+                            }
+                        },
+                        Some(_) => {
+                            emit_error_at(span, "Too many items in reference list".into());
+                            return Err(None);
+                        },
+                        None => hit_end = true
+                    }
+                }
+
                 // there should not be any more items in the reference
                 if !hit_end && items.next().is_some() {
                     emit_error_at(span, "Too many items in reference list".into());
@@ -147,27 +171,50 @@ fn sanitize_args(args: Vec<RawArg>) -> Result<Vec<CleanArg>, Option<String>> {
                 }
 
                 // sanitizaiton
-                // base can only be a Xn|SP reg
-                if !(base.size() == Size::QWORD && (base.family() == RegFamily::INTEGERSP || (base.family() == RegFamily::INTEGER && !base.kind().is_zero_reg()))) {
+                // base can only be a Xn|SP reg, or a scalable vector register for vector-plus-immediate and vector-plus-vector addressing
+                if let Register::Scalable(ref s) = base {
+                    if s.kind.family() != RegFamily::SCALABLE || s.element_size.is_none() || s.element.is_some() || s.qualifier.is_some() {
+                        emit_error_at(span, "Base register can only be a Xn|SP register or a Zn.S|Zn.D register".into());
+                        return Err(None);
+                    }
+                    if let RefKind::PreIndexed(_) | RefKind::MulVl(_) = kind {
+                        emit_error_at(span, "Cannot use this addressing mode with a vector base register".into());
+                        return Err(None);
+                    }
+                } else if !(base.size() == Some(Size::QWORD) && (base.family() == RegFamily::INTEGERSP || (base.family() == RegFamily::INTEGER && !base.kind().is_zero_reg()))) {
                     emit_error_at(span, "Base register can only be a Xn|SP register".into());
                     return Err(None);
                 }
 
-                // index can only be a Xn or Wn reg
+                // index can only be a Xn or Wn reg, or a scalable vector register
                 if let RefKind::Indexed(ref index, ref modifier) = kind {
-                    if index.family() != RegFamily::INTEGER {
+                    if let Register::Scalable(ref s) = index {
+                        if s.kind.family() != RegFamily::SCALABLE || s.element_size.is_none() || s.element.is_some() || s.qualifier.is_some() {
+                            emit_error_at(span, "Index register can only be a Xn, Wn or Zn.S|Zn.D register".into());
+                            return Err(None);
+                        }
+
+                        // limited set of allowed modifiers.
+                        if let Some(ref m) = modifier {
+                            if m.op != Modifier::LSL && m.op != Modifier::SXTW && m.op != Modifier::UXTW {
+                                emit_error_at(span, "Invalid modifier for the selected index register type".into());
+                                return Err(None);
+                            }
+                        }
+                    } else if index.family() != RegFamily::INTEGER {
                         emit_error_at(span, "Index register can only be a Xn or Wn register".into());
                         return Err(None);
-                    }
 
                     // limited set of allowed modifiers.
-                    if let Some(ref m) = modifier {
-                        if if index.size() == Size::QWORD {m.op != Modifier::LSL && m.op != Modifier::SXTX} else {m.op != Modifier::SXTW && m.op != Modifier::UXTW} {
+                    } else if let Some(ref m) = modifier {
+                        if if index.size() == Some(Size::QWORD) {m.op != Modifier::LSL && m.op != Modifier::SXTX} else {m.op != Modifier::SXTW && m.op != Modifier::UXTW} {
                             emit_error_at(span, "Invalid modifier for the selected base register type".into());
                             return Err(None);
                         }
+                    }
 
-                        // LSL requires a stated immediate
+                    // LSL requires a stated immediate
+                    if let Some(ref m) = modifier {
                         if m.op.expr_required() && m.expr.is_none() {
                             emit_error_at(span, "LSL reference modifier requires an immediate".into());
                             return Err(None);
@@ -207,6 +254,12 @@ fn sanitize_args(args: Vec<RawArg>) -> Result<Vec<CleanArg>, Option<String>> {
             // registerlist in comma notation: verify that all used registers have the same element size / lane count.
             // then, canonicalize it to first register / count and confirm it is a valid bare vector register
             RawArg::CommaList { span, items, element } => {
+                // lists of ZA operands are handled separately, as they are not register ranges
+                if let Some(Register::Za(_)) = items.first() {
+                    res.push(sanitize_za_list(span, items, element)?);
+                    continue;
+                }
+
                 if items.len() > 32 {
                     emit_error_at(span, "Too many registers in register list.".into());
                     return Err(None);
@@ -246,6 +299,8 @@ fn sanitize_args(args: Vec<RawArg>) -> Result<Vec<CleanArg>, Option<String>> {
                         emit_error_at(span, "Cannot use element specifiers inside of register lists.".into());
                         return Err(None);
                     }
+                } else if let Register::Scalable(s) = &first {
+                    sanitize_scalable_list_item(span, s)?;
                 } else {
                     emit_error_at(span, "Can only use vector registers in register lists.".into());
                     return Err(None);
@@ -278,21 +333,47 @@ fn sanitize_args(args: Vec<RawArg>) -> Result<Vec<CleanArg>, Option<String>> {
 
 struct ListSanitizer {
     pub element_size: Option<Size>,
-    pub lanes: Option<Option<u8>>
+    pub lanes: Option<Option<u8>>,
+    pub scalable: Option<bool>
 }
 
 impl ListSanitizer {
     fn new() -> ListSanitizer {
         ListSanitizer {
             element_size: None,
-            lanes: None
+            lanes: None,
+            scalable: None
         }
     }
 
     // check if this register spec is valid in a register list
     fn sanitize(&mut self, span: Span, register: &Register) -> Result<(), Option<String>> {
         sanitize_register(span, register)?;
-        if let Register::Vector(v) = register {
+
+        let scalable = if let Register::Scalable(_) = register { true } else { false };
+        if let Some(s) = self.scalable {
+            if s != scalable {
+                emit_error_at(span, "Cannot mix vector and scalable vector registers in a register list.".into());
+                return Err(None);
+            }
+        } else {
+            self.scalable = Some(scalable);
+        }
+
+        if let Register::Scalable(s) = register {
+            sanitize_scalable_list_item(span, s)?;
+
+            if s.kind.is_dynamic() {
+                emit_error_at(span, "Cannot use dynamic registers inside of a comma/dash register list.".into());
+                return Err(None);
+            }
+
+            if self.element_size.is_some() && self.element_size != s.element_size {
+                emit_error_at(span, "Inconsistent element sizes.".into());
+                return Err(None);
+            }
+            self.element_size = s.element_size;
+        } else if let Register::Vector(v) = register {
             if v.element.is_some() {
                 emit_error_at(span, "Cannot use element specifiers inside of register lists.".into());
                 return Err(None);
@@ -328,6 +409,85 @@ impl ListSanitizer {
     }
 }
 
+// check that this scalable register can be used in a register list
+fn sanitize_scalable_list_item(span: Span, register: &RegScalable) -> Result<(), Option<String>> {
+    if register.kind.family() != RegFamily::SCALABLE {
+        emit_error_at(span, "Can only use vector registers in register lists.".into());
+        return Err(None);
+    }
+
+    if register.element.is_some() {
+        emit_error_at(span, "Cannot use element specifiers inside of register lists.".into());
+        return Err(None);
+    }
+
+    if register.element_size.is_none() {
+        emit_error_at(span, "Scalable vector registers in register lists require an element size.".into());
+        return Err(None);
+    }
+    Ok(())
+}
+
+// check that a list of ZA operands is either a single tile slice, or a set of static tiles.
+// The latter gets canonicalized to the mask of 64-bit tiles covered by the listed tiles.
+fn sanitize_za_list(span: Span, mut items: Vec<Register>, element: Option<syn::Expr>) -> Result<CleanArg, Option<String>> {
+    if element.is_some() {
+        emit_error_at(span, "Cannot use element specifiers on lists of ZA operands.".into());
+        return Err(None);
+    }
+
+    if let [Register::Za(RegZa { orientation: Some(_), .. })] = &items[..] {
+        sanitize_register(span, &items[0])?;
+
+        return Ok(CleanArg::RegList {
+            span,
+            first: items.pop().unwrap(),
+            amount: 1,
+            element: None
+        });
+    }
+
+    let mut mask = 0u8;
+    for item in &items {
+        sanitize_register(span, item)?;
+
+        let za = match item {
+            Register::Za(za) if za.orientation.is_none() && za.index.is_none() => za,
+            _ => {
+                emit_error_at(span, "Can only use ZA tiles in lists of ZA tiles.".into());
+                return Err(None);
+            }
+        };
+
+        let id = match za.kind {
+            RegKind::Static(id) => id,
+            RegKind::Dynamic(_, _) => {
+                emit_error_at(span, "Cannot use dynamic registers inside of a list of ZA tiles.".into());
+                return Err(None);
+            }
+        };
+
+        // every tile of a certain element size interleaves with the other tiles of that size
+        let tile = id.code();
+        mask |= match za.element_size {
+            None if id == RegId::ZA => 0xFF,
+            Some(Size::BYTE) if tile < 1 => 0xFF,
+            Some(Size::WORD) if tile < 2 => 0x55 << tile,
+            Some(Size::DWORD) if tile < 4 => 0x11 << tile,
+            Some(Size::QWORD) if tile < 8 => 0x01 << tile,
+            _ => {
+                emit_error_at(span, "This ZA tile cannot be used in a list of ZA tiles.".into());
+                return Err(None);
+            }
+        };
+    }
+
+    Ok(CleanArg::TileList {
+        span,
+        mask
+    })
+}
+
 // check that the register spec is possible
 fn sanitize_register(span: Span, register: &Register) -> Result<(), Option<String>> {
     if let Register::Vector(v) = register {
@@ -337,6 +497,26 @@ fn sanitize_register(span: Span, register: &Register) -> Result<(), Option<Strin
                 return Err(None)
             }
         }
+    } else if let Register::Za(za) = register {
+        if za.kind.family() == RegFamily::ARRAY {
+            if za.element_size.is_some() {
+                emit_error_at(span, "The ZA array cannot have an element size.".into());
+                return Err(None);
+            }
+        } else if za.element_size.is_none() {
+            emit_error_at(span, "ZA tiles require an element size.".into());
+            return Err(None);
+        } else if za.orientation.is_some() != za.index.is_some() {
+            emit_error_at(span, "ZA tile slices require both an orientation and a slice index.".into());
+            return Err(None);
+        }
+
+        if let Some(ref index) = za.index {
+            if index.select.kind.family() != RegFamily::INTEGER || index.select.size() != Size::DWORD {
+                emit_error_at(span, "The vector select register has to be a W register.".into());
+                return Err(None);
+            }
+        }
     }
     Ok(())
 }
@@ -365,15 +545,67 @@ impl Matcher {
     /// Returns if this matcher matches the given argument
     pub fn matches(&self, arg: &CleanArg, ctx: &mut MatchData) -> bool {
         match arg {
-            CleanArg::Reference { kind, .. } => {
-                match kind {
-                    RefKind::Base => *self == Matcher::RefBase || *self == Matcher::RefOffset,
-                    RefKind::Offset(_) => *self == Matcher::RefOffset,
-                    RefKind::PreIndexed(_) => *self == Matcher::RefPre,
-                    RefKind::Indexed(_, _) => *self == Matcher::RefIndex,
+            CleanArg::Reference { base, kind, .. } => {
+                // references using a vector base register can only match the vector-plus-immediate/vector matchers
+                if let Register::Scalable(ref b) = base {
+                    return match (self, kind) {
+                        (Matcher::RefZBase(size), RefKind::Base)
+                        | (Matcher::RefZBase(size), RefKind::Offset(_)) => b.element_size == Some(*size),
+                        (Matcher::RefZZ(size), RefKind::Indexed(index, modifier)) =>
+                            b.element_size == Some(*size)
+                            && index.family() == RegFamily::SCALABLE
+                            && index.size() == Some(*size)
+                            && modifier.as_ref().map_or(true, |m| m.op == Modifier::LSL),
+                        (Matcher::RefZZExtend(op), RefKind::Indexed(index, Some(modifier))) =>
+                            b.element_size == Some(Size::QWORD)
+                            && index.family() == RegFamily::SCALABLE
+                            && index.size() == Some(Size::QWORD)
+                            && modifier.op == *op,
+                        _ => false
+                    };
+                }
+
+                match (self, kind) {
+                    (Matcher::RefBase, RefKind::Base)
+                    | (Matcher::RefOffset, RefKind::Base)
+                    | (Matcher::RefOffset, RefKind::Offset(_))
+                    | (Matcher::RefPre, RefKind::PreIndexed(_))
+                    | (Matcher::RefMulVl, RefKind::Base)
+                    | (Matcher::RefMulVl, RefKind::MulVl(_)) => true,
+                    (Matcher::RefIndex, RefKind::Indexed(index, _)) => index.family() == RegFamily::INTEGER,
+                    (Matcher::RefIndexLsl(shift), RefKind::Indexed(index, modifier)) =>
+                        index.family() == RegFamily::INTEGER
+                        && index.size() == Some(Size::QWORD)
+                        && fixed_shift_matches(modifier.as_ref(), &[Modifier::LSL], *shift),
+                    (Matcher::RefZIndexLsl(size, shift), RefKind::Indexed(index, modifier)) =>
+                        index.family() == RegFamily::SCALABLE
+                        && index.size() == Some(*size)
+                        && fixed_shift_matches(modifier.as_ref(), &[Modifier::LSL], *shift),
+                    (Matcher::RefZIndexExtend(size, shift), RefKind::Indexed(index, Some(modifier))) =>
+                        index.family() == RegFamily::SCALABLE
+                        && index.size() == Some(*size)
+                        && fixed_shift_matches(Some(modifier), &[Modifier::UXTW, Modifier::SXTW], *shift),
+                    _ => false
                 }
             },
+            CleanArg::TileList { .. } => *self == Matcher::ZaTileList,
             CleanArg::RegList { amount, element, first, .. } => {
+                if let Register::Za(ref za) = first {
+                    return match self {
+                        Matcher::ZaHList(size) => za.element_size == Some(*size) && za.orientation == Some(ZaOrientation::Horizontal),
+                        Matcher::ZaVList(size) => za.element_size == Some(*size) && za.orientation == Some(ZaOrientation::Vertical),
+                        _ => false
+                    };
+                }
+
+                if let Register::Scalable(ref s) = first {
+                    return match self {
+                        Matcher::ZRegList(m_amount, element_size) =>
+                            m_amount == amount && s.element_size == Some(*element_size) && element.is_none(),
+                        _ => false
+                    };
+                }
+
                 let first = first.assume_vector();
                 match self {
                     Matcher::RegList(m_amount, element_size) => {
@@ -439,6 +671,37 @@ impl Matcher {
                             *size == v.element_size && v.element.is_some() && v.lanes == Some(*lanes),
                         _ => false
                     },
+                    Register::Scalable(ref s) => match self {
+                        Matcher::Z(size) =>
+                            s.kind.family() == RegFamily::SCALABLE && s.element_size == Some(*size) && s.element.is_none(),
+                        Matcher::ZBare =>
+                            s.kind.family() == RegFamily::SCALABLE && s.element_size.is_none() && s.element.is_none(),
+                        Matcher::ZElement(size) =>
+                            s.kind.family() == RegFamily::SCALABLE && s.element_size == Some(*size) && s.element.is_some(),
+                        Matcher::P(size) =>
+                            s.kind.family() == RegFamily::PREDICATE && s.element_size == Some(*size) && s.qualifier.is_none(),
+                        Matcher::PBare =>
+                            s.kind.family() == RegFamily::PREDICATE && s.element_size.is_none() && s.qualifier.is_none(),
+                        Matcher::PMerge =>
+                            s.kind.family() == RegFamily::PREDICATE && s.element_size.is_none() && s.qualifier == Some(PredicateQualifier::Merging),
+                        Matcher::PZero =>
+                            s.kind.family() == RegFamily::PREDICATE && s.element_size.is_none() && s.qualifier == Some(PredicateQualifier::Zeroing),
+                        _ => false
+                    },
+                    Register::Za(ref za) => match self {
+                        Matcher::ZaTile(size) =>
+                            za.kind.family() == RegFamily::TILE && za.element_size == Some(*size) && za.orientation.is_none(),
+                        Matcher::ZaH(size) =>
+                            za.element_size == Some(*size) && za.orientation == Some(ZaOrientation::Horizontal),
+                        Matcher::ZaV(size) =>
+                            za.element_size == Some(*size) && za.orientation == Some(ZaOrientation::Vertical),
+                        Matcher::ZaArray =>
+                            za.kind.family() == RegFamily::ARRAY && za.index.is_some(),
+                        // smstart/smstop name the ZA storage as a literal
+                        Matcher::Lit(s) =>
+                            *s == "za" && za.kind == RegKind::Static(RegId::ZA) && za.index.is_none(),
+                        _ => false
+                    },
                     Register::Scalar(ref s) => match self {
                         Matcher::W => s.size() == Size::DWORD && s.kind.family() == RegFamily::INTEGER,
                         Matcher::X => s.size() == Size::QWORD && s.kind.family() == RegFamily::INTEGER,
@@ -494,7 +757,8 @@ impl Matcher {
                 Matcher::Cond => COND_MAP.contains_key(&&*ident.to_string()),
                 Matcher::Lit(s) => ident == s,
                 _ => false
-            }
+            },
+            CleanArg::Cond { .. } => *self == Matcher::Cond
         }
     }
 
@@ -524,11 +788,33 @@ impl Matcher {
             Matcher::RegList(_, _) |
             Matcher::RegListStatic(_, _, _) => 1,
             Matcher::RegListElement(_, _) => 2,
+            Matcher::Z(_) |
+            Matcher::ZBare => 1,
+            Matcher::ZElement(_) => 2,
+            Matcher::ZRegList(_, _) => 1,
+            Matcher::P(_) |
+            Matcher::PBare |
+            Matcher::PMerge |
+            Matcher::PZero => 1,
+            Matcher::ZaTile(_) => 1,
+            Matcher::ZaH(_) |
+            Matcher::ZaV(_) |
+            Matcher::ZaHList(_) |
+            Matcher::ZaVList(_) => 3,
+            Matcher::ZaArray => 2,
+            Matcher::ZaTileList => 1,
             Matcher::Offset => 1,
             Matcher::RefBase => 1,
             Matcher::RefOffset => 2,
             Matcher::RefPre => 2,
             Matcher::RefIndex => 4,
+            Matcher::RefMulVl => 2,
+            Matcher::RefIndexLsl(_) => 2,
+            Matcher::RefZIndexLsl(_, _) => 2,
+            Matcher::RefZIndexExtend(_, _) => 3,
+            Matcher::RefZBase(_) => 2,
+            Matcher::RefZZ(_) => 3,
+            Matcher::RefZZExtend(_) => 3,
             Matcher::Mod(_) => 2,
             Matcher::LitMod(_) => 1,
 
@@ -538,6 +824,22 @@ impl Matcher {
     }
 }
 
+/// Check if a reference modifier is one of the allowed modifiers, with exactly the given shift amount.
+/// A shift of 0 can be written by omitting the shift, or the complete modifier if it is LSL.
+fn fixed_shift_matches(modifier: Option<&ModifyExpr>, allowed: &[Modifier], shift: u8) -> bool {
+    if let Some(modifier) = modifier {
+        if !allowed.contains(&modifier.op) {
+            return false;
+        }
+        match modifier.expr {
+            Some(ref expr) => as_number(expr) == Some(u64::from(shift)),
+            None => shift == 0
+        }
+    } else {
+        shift == 0 && allowed.contains(&Modifier::LSL)
+    }
+}
+
 /// Check if the args string matches the data matching template
 pub fn match_args(args: &[CleanArg], data: &'static Opdata) -> Option<MatchData> {
     let mut ctx = MatchData::new(data);
@@ -589,23 +891,50 @@ fn flatten_args(args: Vec<CleanArg>, data: &Opdata, ctx: &mut MatchData) {
                             new_args.push(FlatArg::Immediate { value } ),
                         RefKind::PreIndexed(value) =>
                             new_args.push(FlatArg::Immediate { value } ),
+                        RefKind::MulVl(value) =>
+                            new_args.push(FlatArg::Immediate { value } ),
                         RefKind::Indexed(index, modifier) => {
                             new_args.push(FlatArg::Direct { span, reg: index.kind_owned() } );
                             if let Some(modifier) = modifier {
-                                new_args.push(FlatArg::Modifier { span, modifier: modifier.op } );
-                                if let Some(expr) = modifier.expr {
-                                    new_args.push(FlatArg::Immediate { value: expr } );
+                                match matcher {
+                                    // the shift amount of these is fixed, and has already been checked while matching
+                                    Matcher::RefIndexLsl(_)
+                                    | Matcher::RefZIndexLsl(_, _) => (),
+                                    Matcher::RefZIndexExtend(_, _) => {
+                                        new_args.push(FlatArg::Modifier { span, modifier: modifier.op } );
+                                    },
+                                    // the modifier of these is fixed, only the shift amount gets encoded
+                                    Matcher::RefZZ(_)
+                                    | Matcher::RefZZExtend(_) => if let Some(expr) = modifier.expr {
+                                        new_args.push(FlatArg::Immediate { value: expr } );
+                                    },
+                                    _ => {
+                                        new_args.push(FlatArg::Modifier { span, modifier: modifier.op } );
+                                        if let Some(expr) = modifier.expr {
+                                            new_args.push(FlatArg::Immediate { value: expr } );
+                                        }
+                                    }
                                 }
                             }
                         }
                     }
                 },
+                CleanArg::RegList { span, first: Register::Za(za), .. } => {
+                    flatten_za(span, za, &mut new_args);
+                },
                 CleanArg::RegList { span, first, element, .. } => {
                     new_args.push(FlatArg::Direct { span, reg: first.kind_owned() } );
                     if let Some(element) = element {
                         new_args.push(FlatArg::Immediate { value: element } );
                     }
                 },
+                CleanArg::TileList { span, mask } => {
+                    let value = syn::Expr::Lit(syn::ExprLit {
+                        attrs: Vec::new(),
+                        lit: syn::LitInt::new(&mask.to_string(), span).into()
+                    });
+                    new_args.push(FlatArg::Immediate { value } );
+                },
                 CleanArg::Direct { span, reg } => {
                     match reg {
                         Register::Scalar(s) => {
@@ -616,7 +945,14 @@ fn flatten_args(args: Vec<CleanArg>, data: &Opdata, ctx: &mut MatchData) {
                             if let Some(element) = v.element {
                                 new_args.push(FlatArg::Immediate { value: element });
                             }
-                        }
+                        },
+                        Register::Scalable(s) => {
+                            new_args.push(FlatArg::Direct { span, reg: s.kind });
+                            if let Some(element) = s.element {
+                                new_args.push(FlatArg::Immediate { value: element });
+                            }
+                        },
+                        Register::Za(za) => flatten_za(span, za, &mut new_args)
                     }
                 },
                 CleanArg::JumpTarget { jump } => {
@@ -636,6 +972,9 @@ fn flatten_args(args: Vec<CleanArg>, data: &Opdata, ctx: &mut MatchData) {
                 CleanArg::Dot { .. } => (),
                 CleanArg::Lit { ident } => {
                     new_args.push(FlatArg::Lit { ident });
+                },
+                CleanArg::Cond { span, value } => {
+                    new_args.push(FlatArg::Cond { span, value });
                 }
             }
         }
@@ -645,3 +984,15 @@ fn flatten_args(args: Vec<CleanArg>, data: &Opdata, ctx: &mut MatchData) {
         ctx.args.extend(new_args.drain(..))
     }
 }
+
+/// flatten a ZA operand into its tile, and the select register and offset of its index.
+/// The whole array carries no information of its own, so only its index is emitted.
+fn flatten_za(span: Span, za: RegZa, new_args: &mut Vec<FlatArg>) {
+    if za.kind.family() != RegFamily::ARRAY {
+        new_args.push(FlatArg::Direct { span, reg: za.kind });
+    }
+    if let Some(index) = za.index {
+        new_args.push(FlatArg::Direct { span, reg: index.select.kind });
+        new_args.push(FlatArg::Immediate { value: index.offset });
+    }
+}
@@ -5,13 +5,13 @@ mod parser;
 mod matching;
 mod compiler;
 mod aarch64data;
-mod encoding_helpers;
+pub(super) mod encoding_helpers;
 mod debug;
 
 use crate::State;
 use crate::common::{Size, Stmt, Jump, emit_error_at};
 use crate::arch::Arch;
-use self::aarch64data::Relocation;
+use self::aarch64data::{Relocation, Features};
 
 #[cfg(feature = "dynasm_opmap")]
 pub use debug::create_opmap;
@@ -19,29 +19,50 @@ pub use debug::create_opmap;
 pub use debug::extract_opmap;
 
 struct Context<'a, 'b: 'a> {
-    pub state: &'a mut State<'b>
+    pub state: &'a mut State<'b>,
+    pub features: Features
 }
 
 #[derive(Clone, Debug)]
 pub struct ArchAarch64 {
-
+    features: Features,
+    big_endian: bool
 }
 
 impl Default for ArchAarch64 {
     fn default() -> ArchAarch64 {
-        ArchAarch64 { }
+        ArchAarch64 { features: Features::all(), big_endian: false }
+    }
+}
+
+impl ArchAarch64 {
+    /// aarch64_be. Instructions are still stored little-endian, but data is stored big-endian.
+    pub fn big_endian() -> ArchAarch64 {
+        ArchAarch64 { features: Features::all(), big_endian: true }
     }
 }
 
 impl Arch for ArchAarch64 {
     fn name(&self) -> &str {
-        "aarch64"
+        if self.big_endian {
+            "aarch64_be"
+        } else {
+            "aarch64"
+        }
     }
 
     fn set_features(&mut self, features: &[syn::Ident]) {
-        if let Some(feature) = features.first() {
-            emit_error_at(feature.span(), "Arch aarch64 has no known features".into());
+        let mut new_features = Features::empty();
+        for ident in features {
+            new_features |= match Features::from_str(&ident.to_string()) {
+                Some(feature) => feature,
+                None => {
+                    emit_error_at(ident.span(), format!("Architecture {} does not support feature '{}'", self.name(), ident.to_string()));
+                    continue;
+                }
+            }
         }
+        self.features = new_features;
     }
 
     fn handle_static_reloc(&self, stmts: &mut Vec<Stmt>, reloc: Jump, size: Size) {
@@ -63,13 +84,18 @@ impl Arch for ArchAarch64 {
         stmts.push(reloc.encode(&data));
     }
 
-    fn default_align(&self) -> u8 {
-        0
+    fn default_align(&self) -> &'static [u8] {
+        &[0]
+    }
+
+    fn big_endian(&self) -> bool {
+        self.big_endian
     }
 
     fn compile_instruction(&self, state: &mut State, input: parse::ParseStream) -> parse::Result<()> {
         let mut ctx = Context {
-            state
+            state,
+            features: self.features
         };
 
         let (instruction, args) = parser::parse_instruction(&mut ctx, input)?;
@@ -2,11 +2,11 @@ use syn::{parse, Token};
 
 use lazy_static::lazy_static;
 
-use crate::parse_helpers::{parse_ident_or_rust_keyword, ParseOpt, ParseOptExt};
+use crate::parse_helpers::{parse_ident_or_rust_keyword, parse_dynamic_cond, ParseOpt, ParseOptExt};
 use crate::common::Size;
 
 use super::Context;
-use super::ast::{Instruction, RawArg, Register, RegId, RegKind, RegScalar, RegVector, RegFamily, RefItem, Modifier, ModifyExpr};
+use super::ast::{Instruction, RawArg, Register, RegId, RegKind, RegScalar, RegVector, RegScalable, RegZa, ZaIndex, ZaOrientation, RegFamily, RefItem, Modifier, ModifyExpr, PredicateQualifier};
 
 use std::collections::HashMap;
 
@@ -24,10 +24,15 @@ pub(super) fn parse_instruction(ctx: &mut Context, input: parse::ParseStream) ->
     while input.peek(Token![.]) {
         let span = input.cursor().span();
         let _: Token![.] = input.parse()?;
-        let arg: syn::Ident = input.parse()?;
-
         args.push(RawArg::Dot { span } );
-        args.push(RawArg::Lit { ident: arg });
+
+        let span = input.cursor().span();
+        if let Some(value) = parse_dynamic_cond(input)? {
+            args.push(RawArg::Cond { span, value });
+        } else {
+            let arg: syn::Ident = input.parse()?;
+            args.push(RawArg::Lit { ident: arg });
+        }
     }
 
     // parse 0 or more comma-separated args
@@ -192,6 +197,14 @@ fn parse_arg(ctx: &mut Context, input: parse::ParseStream) -> parse::Result<RawA
         })
     }
 
+    // dynamic condition code
+    if let Some(value) = parse_dynamic_cond(input)? {
+        return Ok(RawArg::Cond {
+            span: _start,
+            value
+        });
+    }
+
     // immediate (relaxed notation)
     let arg: syn::Expr = input.parse()?;
     Ok(RawArg::Immediate {
@@ -203,6 +216,13 @@ fn parse_arg(ctx: &mut Context, input: parse::ParseStream) -> parse::Result<RawA
 fn parse_refitem(ctx: &mut Context, input: parse::ParseStream) -> parse::Result<RefItem> {
     let _start = input.cursor().span(); // FIXME can't join spans yet
 
+    // mul vl. This has to be checked before modifiers as MUL is a modifier as well
+    if parse_mul_vl(input)? {
+        return Ok(RefItem::MulVl {
+            span: _start
+        });
+    }
+
     // modifier
     if let Some(modifier) = input.parse_opt()? {
         return Ok(RefItem::Modifier {
@@ -235,6 +255,22 @@ fn parse_refitem(ctx: &mut Context, input: parse::ParseStream) -> parse::Result<
     })
 }
 
+/// Parses the `MUL VL` suffix of an SVE reference, returning true if it was present.
+fn parse_mul_vl(input: parse::ParseStream) -> parse::Result<bool> {
+    Ok(input.step(|cursor| {
+        if let Some((mul, rest)) = cursor.ident() {
+            if let Some((vl, rest)) = rest.ident() {
+                let mul = mul.to_string();
+                let vl = vl.to_string();
+                if (mul == "mul" || mul == "MUL") && (vl == "vl" || vl == "VL") {
+                    return Ok(((), rest));
+                }
+            }
+        }
+        Err(cursor.error("Expected MUL VL"))
+    }).is_ok())
+}
+
 fn parse_reg(ctx: &mut Context, input: parse::ParseStream) -> parse::Result<Option<Register>> {
     let name = match input.step(|cursor| {
         if let Some((ident, rest)) = cursor.ident() {
@@ -248,7 +284,7 @@ fn parse_reg(ctx: &mut Context, input: parse::ParseStream) -> parse::Result<Opti
                 ident = repl.clone();
             }
 
-            if AARCH64_REGISTERS.contains_key(&*ident) {
+            if AARCH64_REGISTERS.contains_key(&*ident) || split_za_slice(&ident).is_some() {
                 return Ok((ident, rest));
             }
         }
@@ -258,6 +294,12 @@ fn parse_reg(ctx: &mut Context, input: parse::ParseStream) -> parse::Result<Opti
         Err(_) => return Ok(None)
     };
 
+    // slices of ZA tiles are named after the tile they slice
+    let (name, orientation) = match split_za_slice(&name) {
+        Some((tile, orientation)) => (tile.to_string(), Some(orientation)),
+        None => (name, None)
+    };
+
     let kind;
     let size;
 
@@ -285,6 +327,94 @@ fn parse_reg(ctx: &mut Context, input: parse::ParseStream) -> parse::Result<Opti
             kind,
             size
         })))
+    } else if kind.family() == RegFamily::TILE || kind.family() == RegFamily::ARRAY {
+        // parse possible ZA trailers
+
+        // parse the element size specifier ("." [BHSDQ])
+        let element_size = if input.peek(Token![.]) {
+            let _: Token![.] = input.parse()?;
+            Some(parse_element_size(input)?)
+        } else {
+            None
+        };
+
+        // parse the slice index ("[" Wv "," offset "]")
+        let index = if input.peek(syn::token::Bracket) {
+            let inner;
+            let _ = syn::bracketed!(inner in input);
+            let inner = &inner;
+
+            let select = match parse_reg(ctx, inner)? {
+                Some(Register::Scalar(select)) => select,
+                _ => return Err(inner.error("Expected a vector select register"))
+            };
+            let _: Token![,] = inner.parse()?;
+            if inner.peek(Token![#]) {
+                let _: Token![#] = inner.parse()?;
+            }
+            let offset: syn::Expr = inner.parse()?;
+
+            Some(ZaIndex {
+                select,
+                offset
+            })
+        } else {
+            None
+        };
+
+        Ok(Some(Register::Za(RegZa {
+            kind,
+            element_size,
+            orientation,
+            index
+        })))
+    } else if kind.family() == RegFamily::SCALABLE || kind.family() == RegFamily::PREDICATE {
+        // parse possible scalable register trailers
+
+        // parse the element size specifier ("." [BHSDQ]). Scalable registers have no lane count.
+        let element_size = if input.peek(Token![.]) {
+            let _: Token![.] = input.parse()?;
+            Some(parse_element_size(input)?)
+        } else {
+            None
+        };
+
+        // parse the element specifier for vectors
+        let element = if kind.family() == RegFamily::SCALABLE && input.peek(syn::token::Bracket) {
+            let inner;
+            let _ = syn::bracketed!(inner in input);
+            let inner = &inner;
+
+            let expr: syn::Expr = inner.parse()?;
+            Some(expr)
+        } else {
+            None
+        };
+
+        // parse the zeroing / merging qualifier for predicates ("/" [ZM])
+        let qualifier = if kind.family() == RegFamily::PREDICATE && input.peek(Token![/]) {
+            let _: Token![/] = input.parse()?;
+            Some(input.step(|cursor| {
+                if let Some((ident, rest)) = cursor.ident() {
+                    let qualifier = match &*ident.to_string() {
+                        "Z" | "z" => PredicateQualifier::Zeroing,
+                        "M" | "m" => PredicateQualifier::Merging,
+                        _ => return Err(cursor.error("Invalid predicate qualifier"))
+                    };
+                    return Ok((qualifier, rest));
+                }
+                Err(cursor.error("Expected a predicate qualifier"))
+            })?)
+        } else {
+            None
+        };
+
+        Ok(Some(Register::Scalable(RegScalable {
+            kind,
+            element_size,
+            element,
+            qualifier
+        })))
     } else {
         // parse possible vector trailers
 
@@ -349,6 +479,45 @@ fn parse_reg(ctx: &mut Context, input: parse::ParseStream) -> parse::Result<Opti
     }
 }
 
+/// Parses an element size specifier without a lane count, as used by scalable vectors and ZA tiles.
+fn parse_element_size(input: parse::ParseStream) -> parse::Result<Size> {
+    input.step(|cursor| {
+        if let Some((ident, rest)) = cursor.ident() {
+            let element_size = match &*ident.to_string() {
+                "B" | "b" => Size::BYTE,
+                "H" | "h" => Size::WORD,
+                "S" | "s" => Size::DWORD,
+                "D" | "d" => Size::QWORD,
+                "Q" | "q" => Size::OWORD,
+                _ => return Err(cursor.error("Invalid element size specifier"))
+            };
+            return Ok((element_size, rest));
+        }
+        Err(cursor.error("Expected an element size specifier"))
+    })
+}
+
+/// Splits a ZA tile slice name like `za1h` or `ZAV` into the tile name and the slice orientation.
+fn split_za_slice(name: &str) -> Option<(&str, ZaOrientation)> {
+    let orientation = match name.chars().last() {
+        Some('h') | Some('H') => ZaOrientation::Horizontal,
+        Some('v') | Some('V') => ZaOrientation::Vertical,
+        _ => return None
+    };
+
+    let tile = &name[.. name.len() - 1];
+    let is_tile = match AARCH64_REGISTERS.get(tile) {
+        Some(&(id, _)) => id.family() == RegFamily::TILE,
+        None => AARCH64_FAMILIES.get(tile).map_or(false, |&(family, _)| family == RegFamily::TILE)
+    };
+
+    if is_tile {
+        Some((tile, orientation))
+    } else {
+        None
+    }
+}
+
 impl ParseOpt for ModifyExpr {
     fn parse(input: parse::ParseStream) -> parse::Result<Option<Self>> {
         let modifier: Modifier = match input.parse() {
@@ -389,6 +558,7 @@ impl parse::Parse for Modifier {
                     "UXTH" | "uxth" => Modifier::UXTH,
                     "UXTB" | "uxtb" => Modifier::UXTB,
                     "MSL"  | "msl"  => Modifier::MSL,
+                    "MUL"  | "mul"  => Modifier::MUL,
                     _ => return Err(cursor.error("Unknown modifier"))
                 };
 
@@ -673,6 +843,75 @@ lazy_static!{
             ("v29", (V29, None)),
             ("v30", (V30, None)),
             ("v31", (V31, None)),
+
+            ("z0" , (Z0 , None)),
+            ("z1" , (Z1 , None)),
+            ("z2" , (Z2 , None)),
+            ("z3" , (Z3 , None)),
+            ("z4" , (Z4 , None)),
+            ("z5" , (Z5 , None)),
+            ("z6" , (Z6 , None)),
+            ("z7" , (Z7 , None)),
+            ("z8" , (Z8 , None)),
+            ("z9" , (Z9 , None)),
+            ("z10", (Z10, None)),
+            ("z11", (Z11, None)),
+            ("z12", (Z12, None)),
+            ("z13", (Z13, None)),
+            ("z14", (Z14, None)),
+            ("z15", (Z15, None)),
+            ("z16", (Z16, None)),
+            ("z17", (Z17, None)),
+            ("z18", (Z18, None)),
+            ("z19", (Z19, None)),
+            ("z20", (Z20, None)),
+            ("z21", (Z21, None)),
+            ("z22", (Z22, None)),
+            ("z23", (Z23, None)),
+            ("z24", (Z24, None)),
+            ("z25", (Z25, None)),
+            ("z26", (Z26, None)),
+            ("z27", (Z27, None)),
+            ("z28", (Z28, None)),
+            ("z29", (Z29, None)),
+            ("z30", (Z30, None)),
+            ("z31", (Z31, None)),
+
+            ("p0" , (P0 , None)),
+            ("p1" , (P1 , None)),
+            ("p2" , (P2 , None)),
+            ("p3" , (P3 , None)),
+            ("p4" , (P4 , None)),
+            ("p5" , (P5 , None)),
+            ("p6" , (P6 , None)),
+            ("p7" , (P7 , None)),
+            ("p8" , (P8 , None)),
+            ("p9" , (P9 , None)),
+            ("p10", (P10, None)),
+            ("p11", (P11, None)),
+            ("p12", (P12, None)),
+            ("p13", (P13, None)),
+            ("p14", (P14, None)),
+            ("p15", (P15, None)),
+
+            ("za0" , (ZA0 , None)),
+            ("za1" , (ZA1 , None)),
+            ("za2" , (ZA2 , None)),
+            ("za3" , (ZA3 , None)),
+            ("za4" , (ZA4 , None)),
+            ("za5" , (ZA5 , None)),
+            ("za6" , (ZA6 , None)),
+            ("za7" , (ZA7 , None)),
+            ("za8" , (ZA8 , None)),
+            ("za9" , (ZA9 , None)),
+            ("za10", (ZA10, None)),
+            ("za11", (ZA11, None)),
+            ("za12", (ZA12, None)),
+            ("za13", (ZA13, None)),
+            ("za14", (ZA14, None)),
+            ("za15", (ZA15, None)),
+
+            ("za", (ZA, None)),
         ];
         MAP.iter().cloned().collect()
     };
@@ -691,6 +930,11 @@ lazy_static!{
             ("Q", (RegFamily::SIMD, Some(Size::OWORD))),
 
             ("V", (RegFamily::SIMD, None)),
+
+            ("Z", (RegFamily::SCALABLE, None)),
+            ("P", (RegFamily::PREDICATE, None)),
+
+            ("ZA", (RegFamily::TILE, None)),
         ];
         MAP.iter().cloned().collect()
     };
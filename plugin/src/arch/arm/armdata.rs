@@ -0,0 +1,377 @@
+use crate::common::Size;
+
+use lazy_static::lazy_static;
+use bitflags::bitflags;
+use std::collections::{HashMap, hash_map};
+use std::fmt::{self, Display};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Matcher {
+    // a random ident, like a condition, barrier option or special register name
+    Ident,
+
+    // immediate
+    Imm,
+
+    // core registers
+    R,
+    // core register with writeback, "r0!"
+    W,
+    // core register that may be subtracted, "-r0", used as post-indexed offset
+    Ri,
+    // the stack pointer, used by 16-bit thumb instructions that only accept sp
+    Sp,
+    // single precision registers
+    S,
+    // double precision registers
+    D,
+
+    // register lists
+    /// {r0, r4-r7, lr}
+    List,
+    /// {d0-d3}
+    DList,
+    /// {s0-s3}
+    SList,
+
+    // shifts
+    /// lsl #imm, lsr #imm, asr #imm, ror #imm or rrx
+    Shift,
+    /// lsl Rs, lsr Rs, asr Rs or ror Rs
+    ShiftReg,
+
+    // jump offsets and pc-relative addresses. Only matches labels
+    Offset,
+
+    // references
+    /// [Rn]
+    RefBase,
+    /// [Rn] or [Rn, #imm]
+    RefImm,
+    /// [Rn, #imm]!
+    RefPre,
+    /// [sp] or [sp, #imm]
+    RefSp,
+    /// [Rn, +/-Rm {, shift #imm}]
+    RefReg,
+    /// [Rn, +/-Rm {, shift #imm}]!
+    RefRegPre,
+    /// [Rn, +/-Rm]
+    RefIdx,
+    /// [Rn, +/-Rm]!
+    RefIdxPre,
+
+    // possible op mnemnonic end (everything after this point uses the default encoding)
+    End,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Command {
+    // commands that advance the argument pointer
+    R(u8), // encode a core register, or reference base, into a 4-bit bitfield.
+    RLo(u8), // encode a core register in the range r0-r7 into a 3-bit bitfield, as used by 16-bit thumb instructions.
+    RSplit(u8, u8), // encode a core register as a 3-bit bitfield at .0, with the upper bit at .1.
+    RIs(u8), // checks that the register is the given register. Nothing is encoded
+    RNext, // checks that the register is the register following the previous register. Nothing is encoded
+    Ridx(u8), // encode an index register into a 4-bit bitfield, and whether it is added into the U bit (bit 23).
+    Sreg(u8, u8), // encode a single precision register, bits 4-1 at .0 and bit 0 at .1
+    Dreg(u8, u8), // encode a double precision register, bits 3-0 at .0 and bit 4 at .1
+
+    // immediate encodings
+    Ubits(u8, u8), // encodes an unsigned immediate starting at bit .0, .1 bits long
+    Urange(u8, u8, u8), // encodes an unsigned immediate in the range [.1, .2] at bit .0, minus .1
+    Zero, // checks that the immediate is zero. Nothing is encoded
+    ModImm, // 12-bit A32 modified immediate: an 8-bit value rotated right by twice a 4-bit value
+    ModImmT, // 12-bit T32 modified immediate, split over the i, imm3 and imm8 fields
+    ImmT12, // T32 load/store offset. Either a positive 12-bit offset, or a negative 8-bit offset
+    FpImm, // 8-bit VFP floating point immediate, split over bits 19-16 and 3-0
+    Msb(u8), // 5-bit bitfield msb, calculated from the lsb in the previous argument and the width in this argument
+
+    // shifts
+    Shift, // A32 immediate shift: shift type at bit 5, 5-bit amount at bit 7. rrx is encoded as ror #0
+    ShiftT, // T32 immediate shift: shift type at bit 4, the amount split over imm3 and imm2
+    ShiftReg, // A32 register shift: shift type at bit 5, register at bit 8
+    LslT(u8), // T32 register offset shift, only lsl #0-3 into a 2-bit bitfield
+    Rotate(u8), // extend rotation, only ror #0, #8, #16 or #24 into a 2-bit bitfield
+    Amount(u8, u8), // shift amount for the shift type .0 into the 5-bit bitfield at .1
+    AmountT(u8), // T32 shift amount for the shift type .0, split over imm3 and imm2
+
+    // register lists
+    List(u16, u8), // core register list at bit 0, only allowing the registers in mask .0 and at least .1 registers
+    ListN(u8), // 16-bit thumb push/pop list. r0-r7 at bit 0, and register .0 at bit 8
+    DList, // consecutive double precision registers, first register at 22/15-12 and twice the count at bit 0
+    SList, // consecutive single precision registers, first register at 15-12/22 and the count at bit 0
+
+    // named operands
+    It(u8, u8), // it block first condition at bit 4, with a mask made from the then/else pattern .0 for .1 more instructions
+    Barrier(u8), // 4-bit barrier option. Defaults to sy.
+    PsrMask(u8), // 2-bit msr write mask, from apsr_nzcvq, apsr_g or apsr_nzcvqg
+    Is(&'static str), // checks that the ident is the given name. Nothing is encoded
+
+    // bit slice encodings. These don't advance the current argument. Only the slice commands actually encode anything
+    BUbits(u8), // checks that the pointed value fits in .0 bits
+    BUscaled(u8, u8), // checks that the pointed value fits in .0 bits after being shifted .1 bits to the right, without losing bits
+    Slice(u8, u8, u8), // encodes at .0, .1 bits long, the bitslice starting at .2 from the current arg
+    SignMag(u8, u8, u8), // checks that the magnitude of the pointed value fits in .1 bits after being shifted .2 bits to the right, and encodes its sign at .0 (1 if positive)
+    MagSlice(u8, u8, u8), // encodes at .0, .1 bits long, the bitslice starting at .2 from the magnitude of the current arg
+
+    // Offsets
+    Offset(Relocation),
+
+    // special commands
+    Cond(u8), // encodes the condition code of the instruction at .0. Defaults to al. Doesn't use an argument
+    CondReq(u8), // encodes the condition code of the instruction at .0, which has to be given. Doesn't use an argument
+    A, // advances the argument pointer, only needed to skip over an argument.
+    C, // moves the argument pointer back.
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[allow(non_camel_case_types)]
+pub enum Relocation {
+    // A32 b, bl: 24 bits, word aligned
+    B = 0,
+    // A32 blx to thumb code: 24 bits and the H bit, halfword aligned
+    BLX = 1,
+    // A32 ldr, ldrb: 12 bits and the U bit, byte aligned
+    LDR = 2,
+    // A32 ldrh, ldrsb, ldrsh, ldrd: split 8 bits and the U bit, byte aligned
+    LDRH = 3,
+    // A32 vldr: 8 bits and the U bit, word aligned
+    VLDR = 4,
+    // A32 adr: modified immediate, add or subtract
+    ADR = 5,
+    // T32 b.w, bl: split 24 bits, halfword aligned
+    T_B = 6,
+    // T32 blx to arm code: split 24 bits, word aligned, relative to the aligned PC
+    T_BLX = 7,
+    // T32 conditional b.w: split 20 bits, halfword aligned
+    T_BCOND = 8,
+    // 16-bit thumb b: 11 bits, halfword aligned
+    T_B16 = 9,
+    // 16-bit thumb conditional b: 8 bits, halfword aligned
+    T_BCOND16 = 10,
+    // cbz, cbnz: split 6 bits, halfword aligned, forward only
+    T_CBZ = 11,
+    // T32 ldr, ldrb, ldrh, ldrsb, ldrsh: 12 bits and the U bit, relative to the aligned PC
+    T_LDR = 12,
+    // 16-bit thumb ldr: 8 bits, word aligned, forward only, relative to the aligned PC
+    T_LDR16 = 13,
+    // T32 vldr, ldrd: 8 bits and the U bit, word aligned, relative to the aligned PC
+    T_VLDR = 14,
+    // T32 adr: split 12 bits, add or subtract, relative to the aligned PC
+    T_ADR = 15,
+    // 16-bit thumb adr: 8 bits, word aligned, forward only, relative to the aligned PC
+    T_ADR16 = 16,
+    // 8-bit literal
+    LITERAL8 = 18,
+    // 16-bit literal
+    LITERAL16 = 19,
+    // 32-bit literal
+    LITERAL32 = 21,
+    // 64-bit literal
+    LITERAL64 = 25,
+}
+
+impl Relocation {
+    pub fn to_id(self) -> u8 {
+        self as u8
+    }
+}
+
+
+bitflags! {
+    pub struct Features: u64 {
+        const BASELINE   = 0x0000_0000;
+        const IDIV       = 0x0000_0001;
+        const VFP        = 0x0000_0002;
+    }
+}
+
+impl Features {
+    const fn make(bits: u64) -> Features {
+        Features { bits }
+    }
+
+    pub fn from_str(name: &str) -> Option<Features> {
+        match name {
+            "idiv" => Some(Features::IDIV),
+            "vfp"  => Some(Features::VFP),
+            _ => None
+        }
+    }
+}
+
+impl Display for Features {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut keys = Vec::new();
+        if self.contains(Features::IDIV) { keys.push("idiv"); }
+        if self.contains(Features::VFP)  { keys.push("vfp"); }
+        for (i, k) in keys.into_iter().enumerate() {
+            if i != 0 {
+                f.write_str(", ")?;
+            }
+            f.write_str(k)?;
+        }
+        Ok(())
+    }
+}
+
+// workaround until bitflags can be used in const
+const IDIV       : u64 = Features::IDIV.bits;
+const VFP        : u64 = Features::VFP.bits;
+
+#[derive(Debug, Clone, Copy)]
+pub struct Opdata {
+    /// The base template for the encoding. 32-bit thumb instructions have their first halfword in the upper 16 bits.
+    pub base: u32,
+    /// A set of matchers capable of matching the instruction encoding that this instruction represents.
+    pub matchers: &'static [Matcher],
+    /// A sequence of encoder commands that check the matched instruction on validity and whose output gets orred together with the original template at runtime.
+    pub commands: &'static [Command],
+    /// The architecture extensions that have to be available for this instruction to be used.
+    pub features: Features
+}
+
+impl Opdata {
+    /// The size of the encoded instruction. A32 instructions are always 4 bytes long,
+    /// and 16-bit thumb instructions are the only ones that fit in the lower 16 bits.
+    pub fn size(&self, thumb: bool) -> Size {
+        if !thumb || self.base > 0xFFFF {
+            Size::DWORD
+        } else {
+            Size::WORD
+        }
+    }
+
+    /// Whether the condition of this instruction is encoded in the instruction
+    pub fn conditional(&self) -> bool {
+        self.commands.iter().any(|c| matches!(c, Command::Cond(_) | Command::CondReq(_)))
+    }
+
+    /// Whether this instruction requires a condition
+    pub fn requires_condition(&self) -> bool {
+        self.commands.iter().any(|c| matches!(c, Command::CondReq(_)))
+    }
+}
+
+macro_rules! SingleOp {
+    ( $base:expr, [ $( $matcher:expr ),* ], [ $( $command:expr ),* ] $( , $features:expr )? ) => {
+        {
+            const MATCHERS: &'static [Matcher] = {
+                #[allow(unused_imports)]
+                use self::Matcher::*;
+                &[ $(
+                    $matcher
+                ),* ]
+            };
+            const COMMANDS: &'static [Command] = {
+                #[allow(unused_imports)]
+                use self::Command::*;
+                &[ $(
+                    $command
+                ),* ]
+            };
+            Opdata {
+                base: $base,
+                matchers: MATCHERS,
+                commands: COMMANDS,
+                features: Features::make(0 $( | $features )?),
+            }
+        }
+    }
+}
+
+macro_rules! Ops {
+    ( $( $name:tt = [ $( $base:tt = [ $( $matcher:expr ),* ] => [ $( $command:expr ),* ] $( , $features:expr )? ; )+ ] )* ) => {
+        [ $(
+            (
+                $name,
+                &[ $(
+                    SingleOp!( $base, [ $( $matcher ),* ], [ $( $command ),* ] $( , $features )? )
+                ),+ ] as &[_]
+            )
+        ),* ]
+    }
+}
+
+pub fn get_mnemonic_data(name: &str, thumb: bool) -> Option<&'static [Opdata]> {
+    if thumb {
+        THUMB_OPMAP.get(&name).cloned()
+    } else {
+        ARM_OPMAP.get(&name).cloned()
+    }
+}
+
+#[allow(dead_code)]
+pub fn mnemnonics(thumb: bool) -> hash_map::Keys<'static, &'static str, &'static [Opdata]> {
+    if thumb {
+        THUMB_OPMAP.keys()
+    } else {
+        ARM_OPMAP.keys()
+    }
+}
+
+lazy_static! {
+    static ref ARM_OPMAP: HashMap<&'static str, &'static [Opdata]> = {
+        use self::Relocation::*;
+
+        static MAP: &[(&str, &[Opdata])] = &include!("opmap_arm.rs");
+        MAP.iter().cloned().collect()
+    };
+
+    static ref THUMB_OPMAP: HashMap<&'static str, &'static [Opdata]> = {
+        use self::Relocation::*;
+
+        static MAP: &[(&str, &[Opdata])] = &include!("opmap_thumb.rs");
+        MAP.iter().cloned().collect()
+    };
+
+    /// A map of condition code suffixes and their encoding
+    pub static ref CONDITIONS: HashMap<&'static str, u8> = {
+        static MAP: &[(&str, u8)] = &[
+            ("eq", 0x0),
+            ("ne", 0x1),
+            ("cs", 0x2),
+            ("hs", 0x2),
+            ("cc", 0x3),
+            ("lo", 0x3),
+            ("mi", 0x4),
+            ("pl", 0x5),
+            ("vs", 0x6),
+            ("vc", 0x7),
+            ("hi", 0x8),
+            ("ls", 0x9),
+            ("ge", 0xA),
+            ("lt", 0xB),
+            ("gt", 0xC),
+            ("le", 0xD),
+            ("al", 0xE),
+        ];
+        MAP.iter().cloned().collect()
+    };
+
+    /// A map of barrier options and their encoding
+    pub static ref BARRIER_OPTIONS: HashMap<&'static str, u32> = {
+        static MAP: &[(&str, u32)] = &[
+            ("sy", 0b1111),
+            ("st", 0b1110),
+            ("ish", 0b1011),
+            ("ishst", 0b1010),
+            ("nsh", 0b0111),
+            ("nshst", 0b0110),
+            ("osh", 0b0011),
+            ("oshst", 0b0010),
+        ];
+        MAP.iter().cloned().collect()
+    };
+
+    /// A map of msr destinations and their write mask
+    pub static ref PSR_MASKS: HashMap<&'static str, u32> = {
+        static MAP: &[(&str, u32)] = &[
+            ("apsr_nzcvq", 0b10),
+            ("apsr_g", 0b01),
+            ("apsr_nzcvqg", 0b11),
+        ];
+        MAP.iter().cloned().collect()
+    };
+}
@@ -0,0 +1,194 @@
+use syn;
+use proc_macro2::Span;
+
+use crate::common::Jump;
+
+
+// Register id without indication of its usage. Either a static Regid or a family identifier + expression to choose the register
+#[derive(Debug, Clone)]
+pub enum RegKind {
+    Static(RegId),
+    Dynamic(RegFamily, syn::Expr)
+}
+
+// a register identifier. This identifies an architecturally completely separate register.
+// Note that the single precision registers alias the lower half of the double precision registers.
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+pub enum RegId {
+    // core registers. r13 is the stack pointer, r14 the link register and r15 the program counter.
+    R0 = 0x00, R1 = 0x01, R2 = 0x02, R3 = 0x03,
+    R4 = 0x04, R5 = 0x05, R6 = 0x06, R7 = 0x07,
+    R8 = 0x08, R9 = 0x09, R10= 0x0A, R11= 0x0B,
+    R12= 0x0C, R13= 0x0D, R14= 0x0E, R15= 0x0F,
+
+    // single precision floating point registers
+    S0 = 0x20, S1 = 0x21, S2 = 0x22, S3 = 0x23,
+    S4 = 0x24, S5 = 0x25, S6 = 0x26, S7 = 0x27,
+    S8 = 0x28, S9 = 0x29, S10= 0x2A, S11= 0x2B,
+    S12= 0x2C, S13= 0x2D, S14= 0x2E, S15= 0x2F,
+    S16= 0x30, S17= 0x31, S18= 0x32, S19= 0x33,
+    S20= 0x34, S21= 0x35, S22= 0x36, S23= 0x37,
+    S24= 0x38, S25= 0x39, S26= 0x3A, S27= 0x3B,
+    S28= 0x3C, S29= 0x3D, S30= 0x3E, S31= 0x3F,
+
+    // double precision floating point registers
+    D0 = 0x40, D1 = 0x41, D2 = 0x42, D3 = 0x43,
+    D4 = 0x44, D5 = 0x45, D6 = 0x46, D7 = 0x47,
+    D8 = 0x48, D9 = 0x49, D10= 0x4A, D11= 0x4B,
+    D12= 0x4C, D13= 0x4D, D14= 0x4E, D15= 0x4F,
+    D16= 0x50, D17= 0x51, D18= 0x52, D19= 0x53,
+    D20= 0x54, D21= 0x55, D22= 0x56, D23= 0x57,
+    D24= 0x58, D25= 0x59, D26= 0x5A, D27= 0x5B,
+    D28= 0x5C, D29= 0x5D, D30= 0x5E, D31= 0x5F,
+}
+
+// register family. CORE = rN, SINGLE = sN, DOUBLE = dN
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+pub enum RegFamily {
+    CORE   = 0,
+    SINGLE = 1,
+    DOUBLE = 2,
+}
+
+impl RegId {
+    /// Encode this RegId in a 5-bit value
+    pub fn code(self) -> u8 {
+        self as u8 & 0x1F
+    }
+
+    /// Returns what family this Regid is from
+    pub fn family(self) -> RegFamily {
+        match self as u8 >> 5 {
+            0 => RegFamily::CORE,
+            1 => RegFamily::SINGLE,
+            2 => RegFamily::DOUBLE,
+            _ => unreachable!()
+        }
+    }
+}
+
+impl RegKind {
+    /// Returns the family that this regkind is of
+    pub fn family(&self) -> RegFamily {
+        match *self {
+            RegKind::Static(code) => code.family(),
+            RegKind::Dynamic(family, _) => family
+        }
+    }
+}
+
+// shift types
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+pub enum ShiftKind {
+    LSL = 0,
+    LSR = 1,
+    ASR = 2,
+    ROR = 3,
+    RRX = 4,
+}
+
+impl ShiftKind {
+    /// The encoding of the shift type. rrx is encoded as ror
+    pub fn code(self) -> u8 {
+        match self {
+            ShiftKind::RRX => ShiftKind::ROR as u8,
+            kind => kind as u8
+        }
+    }
+}
+
+// the index of a memory reference
+#[derive(Debug)]
+pub enum RefIndex {
+    None,
+    Imm(syn::Expr),
+    Reg {
+        reg: RegKind,
+        subtract: bool,
+        shift: Option<(ShiftKind, Option<syn::Expr>)>
+    }
+}
+
+// basic parse results, before we start doing any kind of checking
+#[derive(Debug)]
+pub enum RawArg {
+    // A memory reference, [base], [base, #imm] or [base, +/-index {, shift}], optionally followed by "!"
+    Reference {
+        span: Span,
+        base: RegKind,
+        index: RefIndex,
+        writeback: bool
+    },
+    // direct register reference, possibly subtracted or with writeback
+    Direct {
+        span: Span,
+        reg: RegKind,
+        subtract: bool,
+        writeback: bool
+    },
+    // a static list of registers, like {r0, r4-r7, lr}
+    List {
+        span: Span,
+        regs: Vec<RegId>
+    },
+    // a shift, like lsl #2, lsr r3 or rrx
+    Shift {
+        span: Span,
+        kind: ShiftKind,
+        amount: ShiftAmount
+    },
+    // jump target. Also used by PC-relative address calculations
+    JumpTarget {
+        jump: Jump
+    },
+    // just an arbitrary expression
+    Immediate {
+        value: syn::Expr
+    }
+}
+
+// the amount a value is shifted by
+#[derive(Debug)]
+pub enum ShiftAmount {
+    None,
+    Imm(syn::Expr),
+    Reg(RegKind)
+}
+
+// Contains the actual instruction mnemnonic.
+#[derive(Debug)]
+pub struct Instruction {
+    pub span: Span,
+    pub name: String
+}
+
+// flat arg list after matching, for encoding
+#[derive(Debug)]
+pub enum FlatArg {
+    Direct {
+        span: Span,
+        reg: RegKind,
+        subtract: bool
+    },
+    Immediate {
+        value: syn::Expr,
+    },
+    List {
+        span: Span,
+        regs: Vec<RegId>
+    },
+    Shift {
+        span: Span,
+        kind: ShiftKind,
+        amount: Option<syn::Expr>
+    },
+    ShiftReg {
+        span: Span,
+        kind: ShiftKind,
+        reg: RegKind
+    },
+    JumpTarget {
+        jump: Jump
+    },
+    Default
+}
@@ -0,0 +1,702 @@
+use super::matching::MatchData;
+use super::armdata::{Command, CONDITIONS, BARRIER_OPTIONS, PSR_MASKS};
+use super::encoding_helpers::{encode_modified_immediate, encode_thumb_modified_immediate, scatter_thumb_immediate};
+use super::Context;
+use super::ast::{FlatArg, RegKind, RegId, ShiftKind};
+use crate::arch::aarch64::encoding_helpers::encode_floating_point_immediate;
+
+use crate::common::{Stmt, Size, delimited, emit_error_at, bitmask};
+use crate::parse_helpers::{as_ident, as_number, as_float, as_signed_number};
+
+use syn::spanned::Spanned;
+use quote::{quote, quote_spanned};
+
+pub(super) fn compile_instruction(ctx: &mut Context, data: MatchData) -> Result<(), Option<String>> {
+    // determine if the features required for this op are fulfilled
+    if !ctx.features.contains(data.data.features) {
+        return Err(Some(format!(
+            "This instruction uses features that are not indicated to be available: {}",
+            data.data.features - ctx.features
+        )));
+    }
+
+    let mut cursor = 0usize;
+
+    // All static bitfields (compile-time constant) will be encoded into this map of (offset, bitfield)
+    let mut statics = Vec::new();
+    // All dynamic bitfields (run-time determined) will be encoded into this map of (offset, TokenStream)
+    let mut dynamics = Vec::new();
+    // Any relocations will be encoded into this list
+    let mut relocations = Vec::new();
+
+    for command in data.data.commands.iter() {
+        match *command {
+            // special commands that don't check the current arg
+            Command::A => {
+                cursor += 1;
+                continue
+            },
+            Command::C => {
+                cursor -= 1;
+                continue
+            },
+            Command::Cond(offset) => {
+                statics.push((offset, u32::from(data.cond.unwrap_or(0xE))));
+                continue
+            },
+            Command::CondReq(offset) => {
+                statics.push((offset, u32::from(data.cond.expect("Conditional instruction matched without a condition"))));
+                continue
+            },
+            _ => ()
+        }
+
+        let arg = data.args.get(cursor).expect("Invalid encoding data, tried to process more arguments than given");
+
+        match *arg {
+            FlatArg::Direct { span, ref reg, subtract } => match *command {
+                Command::Ridx(offset) => {
+                    match *reg {
+                        RegKind::Static(id) => statics.push((offset, u32::from(id.code()))),
+                        RegKind::Dynamic(_, ref expr) => dynamics.push((offset, quote_spanned!{ span=>
+                            #expr & 0xF
+                        }))
+                    }
+                    statics.push((23, u32::from(!subtract)));
+                },
+                _ if subtract => {
+                    emit_error_at(span, "This register cannot be subtracted".into());
+                    return Err(None);
+                },
+                _ => match *reg {
+                    RegKind::Static(id) => compile_static_register(&mut statics, &data, cursor, *command, span, id)?,
+                    RegKind::Dynamic(_, ref expr) => match *command {
+                        Command::R(offset) => {
+                            dynamics.push((offset, quote_spanned!{ span=>
+                                #expr & 0xF
+                            }));
+                        },
+                        Command::RLo(offset) => {
+                            dynamics.push((offset, quote_spanned!{ span=>
+                                #expr & 0x7
+                            }));
+                        },
+                        Command::RSplit(offset, high) => {
+                            dynamics.push((offset, quote_spanned!{ span=>
+                                #expr & 0x7
+                            }));
+                            dynamics.push((high, quote_spanned!{ span=>
+                                (#expr >> 3) & 0x1
+                            }));
+                        },
+                        Command::RIs(code) => {
+                            emit_error_at(span, format!("Field only supports register r{}, which cannot be chosen dynamically", code));
+                            return Err(None);
+                        },
+                        // the register pair cannot be checked
+                        Command::RNext => (),
+                        Command::Sreg(offset, low) => {
+                            dynamics.push((offset, quote_spanned!{ span=>
+                                (#expr >> 1) & 0xF
+                            }));
+                            dynamics.push((low, quote_spanned!{ span=>
+                                #expr & 0x1
+                            }));
+                        },
+                        Command::Dreg(offset, high) => {
+                            dynamics.push((offset, quote_spanned!{ span=>
+                                #expr & 0xF
+                            }));
+                            dynamics.push((high, quote_spanned!{ span=>
+                                (#expr >> 4) & 0x1
+                            }));
+                        },
+                        _ => panic!("Invalid argument processor")
+                    }
+                }
+            },
+            FlatArg::Immediate { ref value } => match *command {
+
+                // unsigned integer encodings
+                Command::Ubits(offset, bitlen) => {
+                    let mask = bitmask(bitlen);
+                    if let Some(value) = unsigned_rangecheck(value, 0, mask, 0) {
+                        statics.push((offset, value?));
+                    } else {
+                        dynamics.push((offset, quote_spanned!{ value.span()=>
+                            #value & #mask
+                        }));
+                    }
+                },
+                Command::Urange(offset, min, max) => {
+                    let mask = bitmask(8 - (max - min).leading_zeros() as u8);
+                    let min = u32::from(min);
+                    if let Some(value) = unsigned_rangecheck(value, min, u32::from(max), 0) {
+                        statics.push((offset, value? - min));
+                    } else {
+                        dynamics.push((offset, quote_spanned!{ value.span()=>
+                            (#value - #min) & #mask
+                        }));
+                    }
+                },
+                Command::Zero => {
+                    if as_number(value) != Some(0) && as_float(value) != Some(0.0) {
+                        emit_error_at(value.span(), "Value has to be zero".into());
+                        return Err(None);
+                    }
+                },
+                Command::Msb(offset) => {
+                    // the previous argument is the lsb, this argument the width of the bitfield
+                    let lsb = match data.args[cursor - 1] {
+                        FlatArg::Immediate { value: ref lsb } => lsb,
+                        _ => panic!("bad command data")
+                    };
+                    match (as_number(lsb), unsigned_rangecheck(value, 1, 32, 0)) {
+                        (Some(lsb), Some(width)) => {
+                            let width = width?;
+                            if lsb + u64::from(width) > 32 {
+                                emit_error_at(value.span(), "Bitfield extends past the end of the register".into());
+                                return Err(None);
+                            }
+                            statics.push((offset, lsb as u32 + width - 1));
+                        },
+                        (_, Some(Err(e))) => return Err(e),
+                        _ => dynamics.push((offset, quote_spanned!{ value.span()=>
+                            (#lsb + #value - 1) & 0x1F
+                        }))
+                    }
+                },
+
+                // modified immediates
+                Command::ModImm => {
+                    if let Some(number) = as_modimm_value(value)? {
+                        if let Some(encoded) = encode_modified_immediate(number) {
+                            statics.push((0, u32::from(encoded)));
+                        } else {
+                            emit_error_at(value.span(), "Impossible to encode immediate".into());
+                            return Err(None);
+                        }
+                    } else {
+                        dynamics.push((0, quote_spanned!{ value.span()=>
+                            dynasmrt::arm::encode_modified_immediate(#value as u32).expect("Impossible modified immediate") as u32
+                        }));
+                    }
+                },
+                Command::ModImmT => {
+                    if let Some(number) = as_modimm_value(value)? {
+                        if let Some(encoded) = encode_thumb_modified_immediate(number) {
+                            statics.push((0, scatter_thumb_immediate(encoded)));
+                        } else {
+                            emit_error_at(value.span(), "Impossible to encode immediate".into());
+                            return Err(None);
+                        }
+                    } else {
+                        dynamics.push((0, quote_spanned!{ value.span()=>
+                            {
+                                let imm = u32::from(dynasmrt::arm::encode_thumb_modified_immediate(#value as u32).expect("Impossible modified immediate"));
+                                ((imm & 0x800) << 15) | ((imm & 0x700) << 4) | (imm & 0xFF)
+                            }
+                        }));
+                    }
+                },
+                Command::ImmT12 => {
+                    if let Some(number) = as_signed_number(value) {
+                        if (0 ..= 0xFFF).contains(&number) {
+                            statics.push((0, (1 << 23) | number as u32));
+                        } else if (-0xFF ..= -1).contains(&number) {
+                            statics.push((0, 0xC00 | (-number) as u32));
+                        } else {
+                            emit_error_at(value.span(), "Value out of range".into());
+                            return Err(None);
+                        }
+                    } else {
+                        dynamics.push((0, quote_spanned!{ value.span()=>
+                            {
+                                let offset = #value as i32;
+                                if offset >= 0 {
+                                    0x80_0000 | (offset as u32 & 0xFFF)
+                                } else {
+                                    0xC00 | (offset.unsigned_abs() & 0xFF)
+                                }
+                            }
+                        }));
+                    }
+                },
+                Command::FpImm => {
+                    let number = as_float(value).or_else(|| as_signed_number(value).map(|v| v as f64));
+                    if let Some(number) = number {
+                        if let Some(encoded) = encode_floating_point_immediate(number as f32) {
+                            let encoded = u32::from(encoded);
+                            statics.push((16, encoded >> 4));
+                            statics.push((0, encoded & 0xF));
+                        } else {
+                            emit_error_at(value.span(), "Impossible to encode immediate".into());
+                            return Err(None);
+                        }
+                    } else {
+                        dynamics.push((0, quote_spanned!{ value.span()=>
+                            {
+                                let value: f32 = #value;
+                                let bits = value.to_bits();
+                                let imm = ((bits >> 24) & 0x80) | ((bits >> 19) & 0x7F);
+                                ((imm & 0xF0) << 12) | (imm & 0xF)
+                            }
+                        }));
+                    }
+                },
+
+                // shift amounts
+                Command::Amount(kind, offset) => {
+                    let (min, max) = shift_range(shift_kind(kind));
+                    if let Some(amount) = unsigned_rangecheck(value, min, max, 0) {
+                        statics.push((offset, amount? & 0x1F));
+                    } else {
+                        dynamics.push((offset, quote_spanned!{ value.span()=>
+                            #value & 0x1F
+                        }));
+                    }
+                },
+                Command::AmountT(kind) => {
+                    let (min, max) = shift_range(shift_kind(kind));
+                    if let Some(amount) = unsigned_rangecheck(value, min, max, 0) {
+                        let amount = amount? & 0x1F;
+                        statics.push((12, amount >> 2));
+                        statics.push((6, amount & 3));
+                    } else {
+                        dynamics.push((12, quote_spanned!{ value.span()=>
+                            (#value >> 2) & 0x7
+                        }));
+                        dynamics.push((6, quote_spanned!{ value.span()=>
+                            #value & 0x3
+                        }));
+                    }
+                },
+
+                // nonconsuming integer checks
+                Command::BUbits(bitlen) => {
+                    let mask = bitmask(bitlen);
+                    if let Some(value) = unsigned_rangecheck(value, 0, mask, 0) {
+                        value?;
+                    }
+                },
+                Command::BUscaled(bitlen, shift) => {
+                    let mask = bitmask(bitlen);
+                    if let Some(value) = unsigned_rangecheck(value, 0, mask, shift) {
+                        value?;
+                    }
+                },
+                Command::Slice(offset, bitlen, shift) => {
+                    let mask = bitmask(bitlen);
+                    if let Some(value) = as_signed_number(value) {
+                        statics.push((offset, ((value >> shift) as u32) & mask));
+                    } else {
+                        dynamics.push((offset, quote_spanned!{ value.span()=>
+                            ((#value >> #shift) as u32) & #mask
+                        }));
+                    }
+                },
+                Command::SignMag(offset, bitlen, shift) => {
+                    if let Some(number) = as_signed_number(value) {
+                        let mask = i64::from(bitmask(bitlen));
+                        let magnitude = number.abs();
+                        if (magnitude >> shift) << shift != magnitude {
+                            emit_error_at(value.span(), "Unrepresentable value".into());
+                            return Err(None);
+                        } else if (magnitude >> shift) > mask {
+                            emit_error_at(value.span(), "Value out of range".into());
+                            return Err(None);
+                        }
+                        statics.push((offset, u32::from(number >= 0)));
+                    } else {
+                        dynamics.push((offset, quote_spanned!{ value.span()=>
+                            ((#value as i32) >= 0) as u32
+                        }));
+                    }
+                },
+                Command::MagSlice(offset, bitlen, shift) => {
+                    let mask = bitmask(bitlen);
+                    if let Some(value) = as_signed_number(value) {
+                        statics.push((offset, ((value.abs() >> shift) as u32) & mask));
+                    } else {
+                        dynamics.push((offset, quote_spanned!{ value.span()=>
+                            ((#value as i32).unsigned_abs() >> #shift) & #mask
+                        }));
+                    }
+                },
+
+                // named operands
+                Command::It(pattern, count) => {
+                    let name = as_ident(value).expect("bad command data").to_string().to_lowercase();
+                    let cond = if let Some(&cond) = CONDITIONS.get(&&*name) {
+                        u32::from(cond)
+                    } else {
+                        emit_error_at(value.span(), format!("Unknown condition '{}'", name));
+                        return Err(None);
+                    };
+                    let then_mask = bitmask(count);
+                    if cond == 0xE && u32::from(pattern) != then_mask {
+                        emit_error_at(value.span(), "An it block with condition al cannot have else instructions".into());
+                        return Err(None);
+                    }
+
+                    // each following instruction uses the first condition or its inverse
+                    let mut mask = 1 << (3 - count);
+                    for i in 0 .. count {
+                        let bit = if pattern & (1 << i) != 0 { cond & 1 } else { !cond & 1 };
+                        mask |= bit << (3 - i);
+                    }
+                    statics.push((4, cond));
+                    statics.push((0, mask));
+                },
+                Command::Barrier(offset) => {
+                    let name = as_ident(value).expect("bad command data").to_string().to_lowercase();
+                    if let Some(&bits) = BARRIER_OPTIONS.get(&&*name) {
+                        statics.push((offset, bits));
+                    } else {
+                        emit_error_at(value.span(), format!("Unknown barrier option '{}'", name));
+                        return Err(None);
+                    }
+                },
+                Command::PsrMask(offset) => {
+                    let name = as_ident(value).expect("bad command data").to_string().to_lowercase();
+                    if let Some(&bits) = PSR_MASKS.get(&&*name) {
+                        statics.push((offset, bits));
+                    } else {
+                        emit_error_at(value.span(), format!("Unknown special register '{}'. Expected apsr_nzcvq, apsr_g or apsr_nzcvqg", name));
+                        return Err(None);
+                    }
+                },
+                Command::Is(expected) => {
+                    let name = as_ident(value).expect("bad command data").to_string();
+                    if !name.eq_ignore_ascii_case(expected) {
+                        emit_error_at(value.span(), format!("Expected '{}'", expected));
+                        return Err(None);
+                    }
+                },
+
+                _ => panic!("Invalid argument processor")
+            },
+            FlatArg::List { span, ref regs } => match *command {
+                Command::List(allowed, min) => {
+                    let mut bits = 0u32;
+                    for reg in regs {
+                        bits |= 1 << reg.code();
+                    }
+                    if bits & !u32::from(allowed) != 0 {
+                        emit_error_at(span, "Register list contains registers that cannot be used here".into());
+                        return Err(None);
+                    } else if regs.len() < usize::from(min) {
+                        emit_error_at(span, format!("Register list has to contain at least {} registers", min));
+                        return Err(None);
+                    }
+                    statics.push((0, bits));
+                },
+                Command::ListN(extra) => {
+                    let mut bits = 0u32;
+                    for reg in regs {
+                        if reg.code() < 8 {
+                            bits |= 1 << reg.code();
+                        } else if reg.code() == extra {
+                            bits |= 1 << 8;
+                        } else {
+                            emit_error_at(span, format!("Register list can only contain registers r0-r7 and r{}", extra));
+                            return Err(None);
+                        }
+                    }
+                    statics.push((0, bits));
+                },
+                Command::DList
+                | Command::SList => {
+                    let (limit, scale) = if *command == Command::DList { (16, 2) } else { (32, 1) };
+                    for pair in regs.windows(2) {
+                        if pair[1].code() != pair[0].code() + 1 {
+                            emit_error_at(span, "Register list has to contain consecutive registers".into());
+                            return Err(None);
+                        }
+                    }
+                    if regs.len() > limit {
+                        emit_error_at(span, format!("Register list can contain at most {} registers", limit));
+                        return Err(None);
+                    }
+
+                    let first = u32::from(regs[0].code());
+                    if *command == Command::DList {
+                        statics.push((12, first & 0xF));
+                        statics.push((22, first >> 4));
+                    } else {
+                        statics.push((12, first >> 1));
+                        statics.push((22, first & 1));
+                    }
+                    statics.push((0, (regs.len() * scale) as u32));
+                },
+                _ => panic!("Invalid argument processor")
+            },
+            FlatArg::Shift { span, kind, ref amount } => match *command {
+                Command::Shift
+                | Command::ShiftT => {
+                    let (kind_offset, amount_slices): (u8, &[(u8, u8, u8)]) = if *command == Command::Shift {
+                        (5, &[(7, 5, 0)])
+                    } else {
+                        (4, &[(12, 3, 2), (6, 2, 0)])
+                    };
+                    statics.push((kind_offset, u32::from(kind.code())));
+
+                    // rrx is encoded as ror #0
+                    if let Some(amount) = amount {
+                        let (min, max) = shift_range(kind);
+                        if let Some(number) = unsigned_rangecheck(amount, min, max, 0) {
+                            let number = number? & 0x1F;
+                            for &(offset, bitlen, shift) in amount_slices {
+                                statics.push((offset, (number >> shift) & bitmask(bitlen)));
+                            }
+                        } else {
+                            for &(offset, bitlen, shift) in amount_slices {
+                                let mask = bitmask(bitlen);
+                                dynamics.push((offset, quote_spanned!{ amount.span()=>
+                                    (#amount >> #shift) & #mask
+                                }));
+                            }
+                        }
+                    }
+                },
+                Command::LslT(offset) => {
+                    let amount = amount.as_ref().expect("bad shift data");
+                    if kind != ShiftKind::LSL {
+                        emit_error_at(span, "Only lsl is supported here".into());
+                        return Err(None);
+                    }
+                    if let Some(number) = unsigned_rangecheck(amount, 0, 3, 0) {
+                        statics.push((offset, number?));
+                    } else {
+                        dynamics.push((offset, quote_spanned!{ amount.span()=>
+                            #amount & 0x3
+                        }));
+                    }
+                },
+                Command::Rotate(offset) => {
+                    if kind != ShiftKind::ROR {
+                        emit_error_at(span, "Only ror is supported here".into());
+                        return Err(None);
+                    }
+                    let amount = amount.as_ref().expect("bad shift data");
+                    if let Some(number) = as_number(amount) {
+                        if number % 8 != 0 || number > 24 {
+                            emit_error_at(amount.span(), "Rotation has to be 0, 8, 16 or 24".into());
+                            return Err(None);
+                        }
+                        statics.push((offset, (number / 8) as u32));
+                    } else {
+                        dynamics.push((offset, quote_spanned!{ amount.span()=>
+                            (#amount >> 3) & 0x3
+                        }));
+                    }
+                },
+                _ => panic!("Invalid argument processor")
+            },
+            FlatArg::ShiftReg { span, kind, ref reg } => match *command {
+                Command::ShiftReg => {
+                    statics.push((5, u32::from(kind.code())));
+                    match *reg {
+                        RegKind::Static(id) => statics.push((8, u32::from(id.code()))),
+                        RegKind::Dynamic(_, ref expr) => dynamics.push((8, quote_spanned!{ span=>
+                            #expr & 0xF
+                        }))
+                    }
+                },
+                _ => panic!("Invalid argument processor")
+            },
+            FlatArg::Default => match *command {
+                // the barrier option defaults to sy
+                Command::Barrier(offset) => {
+                    statics.push((offset, 0b1111));
+                },
+                // omitted offsets default to +0
+                Command::SignMag(offset, _, _) => {
+                    statics.push((offset, 1));
+                },
+                Command::ImmT12 => {
+                    statics.push((23, 1));
+                },
+                // the only allowed value is already part of the encoding
+                Command::Is(_) => (),
+
+                // omitted shifts default to lsl #0, and omitted offsets to 0
+                Command::Shift
+                | Command::ShiftT
+                | Command::LslT(_)
+                | Command::Rotate(_)
+                | Command::MagSlice(_, _, _)
+                | Command::Slice(_, _, _) => (),
+
+                // integer checks don't have anything to check
+                Command::BUbits(_)
+                | Command::BUscaled(_, _) => (),
+
+                _ => panic!("Invalid argument processor")
+            },
+            FlatArg::JumpTarget { ref jump } => match *command {
+                Command::Offset(relocation) => {
+                    // what kind of relocation is it
+                    let data = [relocation.to_id()];
+
+                    // encode the complete relocation
+                    let stmt = jump.clone().encode(&data);
+
+                    relocations.push(stmt);
+                },
+                _ => panic!("Invalid argument processor")
+            },
+        }
+
+        // figure out how far the cursor has to be advanced.
+        match *command {
+            Command::BUbits(_) |
+            Command::BUscaled(_, _) |
+            Command::Slice(_, _, _) |
+            Command::SignMag(_, _, _) |
+            Command::MagSlice(_, _, _) => (),
+            _ => cursor += 1
+        }
+    }
+
+    // sanity
+    if cursor != data.args.len() {
+        panic!("Not enough command processors");
+    }
+
+    // apply all statics to bits
+    let mut bits = data.data.base;
+    for (offset, value) in statics {
+        bits |= value << offset;
+    }
+
+    let size = data.data.size(ctx.thumb);
+
+    // 32-bit thumb instructions are stored as two little-endian halfwords, the first one being the most significant
+    let halfwords = ctx.thumb && size == Size::DWORD;
+
+    // generate code to be emitted for dynamics
+    if !dynamics.is_empty() {
+        let (mut res, ty) = match size {
+            Size::WORD => (quote!{ #bits as u16 }, quote!(u16)),
+            Size::DWORD => (quote!{ #bits }, quote!(u32)),
+            _ => unreachable!()
+        };
+        for (offset, expr) in dynamics {
+            res = quote!{
+                #res | (((#expr) as #ty) << #offset)
+            };
+        }
+        if halfwords {
+            res = quote!{ (#res).rotate_left(16) };
+        }
+        ctx.state.stmts.push(Stmt::ExprUnsigned(delimited(res), size));
+    } else {
+        let bits = if halfwords { bits.rotate_left(16) } else { bits };
+        ctx.state.stmts.push(Stmt::Const(u64::from(bits), size));
+    }
+
+    // generate code to be emitted for relocations
+    ctx.state.stmts.extend(relocations);
+
+    Ok(())
+}
+
+/// Encodes a statically known register for the register command `command`.
+fn compile_static_register(statics: &mut Vec<(u8, u32)>, data: &MatchData, cursor: usize, command: Command, span: proc_macro2::Span, id: RegId) -> Result<(), Option<String>> {
+    let code = u32::from(id.code());
+
+    match command {
+        Command::R(offset) => {
+            statics.push((offset, code));
+        },
+        Command::RLo(offset) => {
+            if code >= 8 {
+                emit_error_at(span, "Field only supports registers r0-r7".into());
+                return Err(None);
+            }
+            statics.push((offset, code));
+        },
+        Command::RSplit(offset, high) => {
+            statics.push((offset, code & 0x7));
+            statics.push((high, code >> 3));
+        },
+        Command::RIs(expected) => {
+            if id.code() != expected {
+                emit_error_at(span, format!("Field only supports register r{}", expected));
+                return Err(None);
+            }
+        },
+        Command::RNext => {
+            // the second register of a pair has to follow the first, which has to be even
+            if let FlatArg::Direct { reg: RegKind::Static(first), .. } = data.args[cursor - 1] {
+                if first.code() & 1 != 0 || first.code() + 1 != id.code() {
+                    emit_error_at(span, "Register pairs have to be an even register and the register following it".into());
+                    return Err(None);
+                }
+            }
+        },
+        Command::Sreg(offset, low) => {
+            statics.push((offset, code >> 1));
+            statics.push((low, code & 1));
+        },
+        Command::Dreg(offset, high) => {
+            statics.push((offset, code & 0xF));
+            statics.push((high, code >> 4));
+        },
+        _ => panic!("Invalid argument processor")
+    }
+
+    Ok(())
+}
+
+/// The valid range of immediate shift amounts for each shift type.
+fn shift_range(kind: ShiftKind) -> (u32, u32) {
+    match kind {
+        ShiftKind::LSL => (0, 31),
+        ShiftKind::LSR
+        | ShiftKind::ASR => (1, 32),
+        ShiftKind::ROR => (1, 31),
+        ShiftKind::RRX => (0, 0),
+    }
+}
+
+fn shift_kind(code: u8) -> ShiftKind {
+    match code {
+        0 => ShiftKind::LSL,
+        1 => ShiftKind::LSR,
+        2 => ShiftKind::ASR,
+        3 => ShiftKind::ROR,
+        _ => panic!("bad command data")
+    }
+}
+
+/// Modified immediates are 32-bit patterns, so both signed and unsigned values are accepted.
+fn as_modimm_value(expr: &syn::Expr) -> Result<Option<u32>, Option<String>> {
+    match as_signed_number(expr) {
+        Some(value) if value >= i64::from(i32::MIN) && value <= i64::from(u32::MAX) => Ok(Some(value as u32)),
+        Some(_) => {
+            emit_error_at(expr.span(), "Value out of range".into());
+            Err(None)
+        },
+        None => Ok(None)
+    }
+}
+
+fn unsigned_rangecheck(expr: &syn::Expr, min: u32, max: u32, scale: u8) -> Option<Result<u32, Option<String>>> {
+    let value = as_number(expr)?;
+    let scaled = value >> scale;
+
+    Some(if (scaled << scale) != value {
+        emit_error_at(expr.span(), "Unrepresentable value".into());
+        Err(None)
+    } else if scaled > u64::from(max) {
+        emit_error_at(expr.span(), "Value too large".into());
+        Err(None)
+    } else if scaled < u64::from(min) {
+        emit_error_at(expr.span(), "Value too small".into());
+        Err(None)
+    } else {
+        Ok(scaled as u32)
+    })
+}
@@ -0,0 +1,233 @@
+use super::armdata::{Opdata, Matcher, Command};
+
+use crate::common::Size;
+
+use std::fmt::Write;
+
+
+#[cfg(feature = "dynasm_opmap")]
+pub fn create_opmap(thumb: bool) -> String {
+    let mut s = String::new();
+
+    let mut mnemnonics: Vec<_> = super::armdata::mnemnonics(thumb).cloned().collect();
+    mnemnonics.sort();
+
+    for mnemnonic in mnemnonics {
+        // get the data for this mnemnonic
+        let data = super::armdata::get_mnemonic_data(mnemnonic, thumb).unwrap();
+        // format the data for the opmap docs
+        let formats = data.iter()
+            .map(|x| format_opdata(&display_name(mnemnonic, x, thumb), x).replace(">>> ", ""))
+            .collect::<Vec<_>>();
+
+        // push mnemnonic name as title
+        write!(s, "### {}\n```insref\n{}\n```\n", mnemnonic, formats.join("\n")).unwrap();
+    }
+    s
+}
+
+/// In thumb mode, 16-bit encodings are listed with a `.n` qualifier
+#[cfg(feature = "dynasm_opmap")]
+fn display_name(name: &str, data: &Opdata, thumb: bool) -> String {
+    if thumb && data.size(thumb) == Size::WORD {
+        format!("{}.n", name)
+    } else {
+        name.to_string()
+    }
+}
+
+/// Formats the forms of an instruction, only showing those of the requested size.
+pub fn format_opdata_list(name: &str, data: &[Opdata], width: Option<Size>) -> String {
+    data.iter()
+        .filter(|data| width.map_or(true, |w| data.size(true) == w))
+        .map(|data| format_opdata(name, data))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+pub fn format_opdata(name: &str, data: &Opdata) -> String {
+    let mut buf = format!(">>> {}", name);
+
+    let args = group_commands(data);
+    let arg_names = name_args(&args);
+    let mut names = &arg_names[..];
+
+    let mut first = true;
+    let mut end_count = 0;
+
+    for matcher in data.matchers {
+        if let Matcher::End = matcher {
+            end_count += 1;
+            buf.push_str(" {");
+            continue;
+        }
+
+        if first {
+            buf.push(' ');
+            first = false;
+        } else {
+            buf.push_str(", ");
+        }
+
+        let (arg_names, rest) = names.split_at(matcher.flatarg_count());
+        names = rest;
+
+        match matcher {
+            Matcher::Ident
+            | Matcher::Offset => buf.push_str(&arg_names[0]),
+            Matcher::Imm => write!(buf, "#{}", arg_names[0]).unwrap(),
+            Matcher::R => write!(buf, "R{}", arg_names[0]).unwrap(),
+            Matcher::W => write!(buf, "R{}!", arg_names[0]).unwrap(),
+            Matcher::Ri => write!(buf, "+/-R{}", arg_names[0]).unwrap(),
+            Matcher::Sp => buf.push_str("SP"),
+            Matcher::S => write!(buf, "S{}", arg_names[0]).unwrap(),
+            Matcher::D => write!(buf, "D{}", arg_names[0]).unwrap(),
+            Matcher::List => buf.push_str("{reglist}"),
+            Matcher::DList => buf.push_str("{D reglist}"),
+            Matcher::SList => buf.push_str("{S reglist}"),
+            Matcher::Shift => write!(buf, "{} #amount", arg_names[0]).unwrap(),
+            Matcher::ShiftReg => write!(buf, "{} Rs", arg_names[0]).unwrap(),
+            Matcher::RefBase => write!(buf, "[R{}]", arg_names[0]).unwrap(),
+            Matcher::RefImm => write!(buf, "[R{} {{, #{} }} ]", arg_names[0], arg_names[1]).unwrap(),
+            Matcher::RefSp => write!(buf, "[SP {{, #{} }} ]", arg_names[1]).unwrap(),
+            Matcher::RefPre => write!(buf, "[R{}, #{}]!", arg_names[0], arg_names[1]).unwrap(),
+            Matcher::RefReg => write!(buf, "[R{}, +/-R{} {{, {} #amount }} ]", arg_names[0], arg_names[1], arg_names[2]).unwrap(),
+            Matcher::RefRegPre => write!(buf, "[R{}, +/-R{} {{, {} #amount }} ]!", arg_names[0], arg_names[1], arg_names[2]).unwrap(),
+            Matcher::RefIdx => write!(buf, "[R{}, +/-R{}]", arg_names[0], arg_names[1]).unwrap(),
+            Matcher::RefIdxPre => write!(buf, "[R{}, +/-R{}]!", arg_names[0], arg_names[1]).unwrap(),
+            Matcher::End => ()
+        }
+    }
+
+    for _ in 0 .. end_count {
+        buf.push_str(" }");
+    }
+
+    let constraints = format_constraints(&args, &arg_names);
+    if !constraints.is_empty() {
+        let mut len = constraints.len() + buf.len();
+        while len < 100 {
+            buf.push(' ');
+            len += 1;
+        }
+        buf.push_str(&constraints);
+    }
+
+    if !data.features.is_empty() {
+        write!(buf, " ({})", data.features).unwrap();
+    }
+
+    buf
+}
+
+/// Groups the commands of an op by the flat argument they operate on.
+fn group_commands(data: &Opdata) -> Vec<Vec<Command>> {
+    let count = data.matchers.iter().map(|m| m.flatarg_count()).sum();
+    let mut args = vec![Vec::new(); count];
+    let mut cursor = 0usize;
+
+    for &command in data.commands {
+        match command {
+            Command::A => cursor += 1,
+            Command::C => cursor -= 1,
+            Command::Cond(_)
+            | Command::CondReq(_) => (),
+            Command::BUbits(_)
+            | Command::BUscaled(_, _)
+            | Command::Slice(_, _, _)
+            | Command::SignMag(_, _, _)
+            | Command::MagSlice(_, _, _) => args[cursor].push(command),
+            _ => {
+                args[cursor].push(command);
+                cursor += 1;
+            }
+        }
+    }
+
+    args
+}
+
+/// Names arguments after what they encode. Registers get a letter, other arguments a descriptive name.
+fn name_args(args: &[Vec<Command>]) -> Vec<String> {
+    let mut letters = "abcdefgh".chars();
+    let mut names: Vec<String> = Vec::new();
+
+    for commands in args {
+        let name = match commands.first() {
+            Some(Command::R(_))
+            | Some(Command::RLo(_))
+            | Some(Command::RSplit(_, _))
+            | Some(Command::RIs(_))
+            | Some(Command::RNext)
+            | Some(Command::Ridx(_))
+            | Some(Command::Sreg(_, _))
+            | Some(Command::Dreg(_, _)) => letters.next().unwrap().to_string(),
+            Some(Command::Shift)
+            | Some(Command::ShiftT)
+            | Some(Command::ShiftReg)
+            | Some(Command::LslT(_))
+            | Some(Command::Rotate(_)) => "shift".into(),
+            Some(Command::It(_, _)) => "cond".into(),
+            Some(Command::Barrier(_)) => "option".into(),
+            Some(Command::PsrMask(_)) => "spec_reg".into(),
+            Some(&Command::Is(name)) => name.into(),
+            Some(Command::Offset(_)) => "offset".into(),
+            Some(Command::FpImm) => "float".into(),
+            _ => "imm".into()
+        };
+
+        // disambiguate arguments with the same name
+        let name = if names.contains(&name) {
+            let mut i = 2;
+            while names.contains(&format!("{}{}", name, i)) {
+                i += 1;
+            }
+            format!("{}{}", name, i)
+        } else {
+            name
+        };
+        names.push(name);
+    }
+
+    names
+}
+
+fn format_constraints(args: &[Vec<Command>], names: &[String]) -> String {
+    let mut constraints = Vec::new();
+
+    for (name, commands) in names.iter().zip(args) {
+        // bitslices are encoded as a whole, so only the checks matter
+        let mut range = None;
+
+        for &command in commands {
+            match command {
+                Command::RLo(_) => constraints.push(format!("R{} = [r0, r7]", name)),
+                Command::RIs(code) => constraints.push(format!("R{} = r{}", name, code)),
+                Command::RNext => constraints.push(format!("R{} = next register", name)),
+                Command::Ubits(_, bits) => range = Some((0, (1i64 << bits) - 1, 0)),
+                Command::Urange(_, min, max) => range = Some((i64::from(min), i64::from(max), 0)),
+                Command::BUbits(bits) => range = Some((0, (1i64 << bits) - 1, 0)),
+                Command::BUscaled(bits, shift) => range = Some((0, ((1i64 << bits) - 1) << shift, shift)),
+                Command::SignMag(_, bits, shift) => range = Some((-((1i64 << bits) - 1) << shift, ((1i64 << bits) - 1) << shift, shift)),
+                Command::ImmT12 => range = Some((-255, 4095, 0)),
+                Command::Zero => constraints.push(format!("{} = 0", name)),
+                Command::ModImm
+                | Command::ModImmT => constraints.push(format!("{} is a modified immediate", name)),
+                Command::Msb(_) => constraints.push(format!("{} = [1, 32 - imm]", name)),
+                Command::LslT(_) => constraints.push(format!("{} = lsl #0-3", name)),
+                Command::Rotate(_) => constraints.push(format!("{} = ror #0, 8, 16 or 24", name)),
+                _ => ()
+            }
+        }
+
+        if let Some((min, max, shift)) = range {
+            let mut c = format!("{} = [{}, {}]", name, min, max);
+            if shift != 0 {
+                write!(c, " #{}", 1 << shift).unwrap();
+            }
+            constraints.push(c);
+        }
+    }
+
+    constraints.join(", ")
+}
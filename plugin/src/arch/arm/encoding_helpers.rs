@@ -0,0 +1,45 @@
+/// Encodes a value as an A32 modified immediate: an 8-bit value rotated right by twice a 4-bit value.
+/// Returns the 12-bit rot:imm8 field. The smallest possible rotation is used.
+pub fn encode_modified_immediate(value: u32) -> Option<u16> {
+    for rot in 0 .. 16 {
+        let imm8 = value.rotate_left(rot * 2);
+        if imm8 <= 0xFF {
+            return Some(((rot as u16) << 8) | (imm8 as u16));
+        }
+    }
+    None
+}
+
+/// Encodes a value as a T32 modified immediate. Returns the 12-bit i:imm3:imm8 field.
+pub fn encode_thumb_modified_immediate(value: u32) -> Option<u16> {
+    let byte = value & 0xFF;
+    if value <= 0xFF {
+        return Some(value as u16);
+    }
+    if value == byte * 0x0001_0001 {
+        return Some(0x100 | byte as u16);
+    }
+    let byte = (value >> 8) & 0xFF;
+    if value == byte * 0x0100_0100 {
+        return Some(0x200 | byte as u16);
+    }
+    let byte = value & 0xFF;
+    if value == byte * 0x0101_0101 {
+        return Some(0x300 | byte as u16);
+    }
+
+    // an 8-bit value with its top bit set, rotated right by 8-31 bits
+    for rot in 8 .. 32 {
+        let imm8 = value.rotate_left(rot);
+        if (0x80 ..= 0xFF).contains(&imm8) {
+            return Some(((rot as u16) << 7) | (imm8 as u16 & 0x7F));
+        }
+    }
+    None
+}
+
+/// Spreads a 12-bit i:imm3:imm8 field over its position in a T32 instruction.
+pub fn scatter_thumb_immediate(field: u16) -> u32 {
+    let field = u32::from(field);
+    ((field & 0x800) << 15) | ((field & 0x700) << 4) | (field & 0xFF)
+}
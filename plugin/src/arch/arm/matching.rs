@@ -0,0 +1,279 @@
+use super::Context;
+use super::ast::{Instruction, RawArg, FlatArg, RegKind, RegId, RegFamily, RefIndex, ShiftAmount};
+use super::armdata::{Opdata, Matcher, get_mnemonic_data, CONDITIONS};
+use super::debug::format_opdata_list;
+
+use crate::common::{JumpKind, Size, emit_error_at};
+use crate::parse_helpers::as_ident;
+
+/// Try finding an appropriate definition that matches the given instruction / arguments.
+pub(super) fn match_instruction(ctx: &mut Context, instruction: &Instruction, args: Vec<RawArg>) -> Result<MatchData, Option<String>> {
+    // sanitize our arg list to remove any structures that cannot be matched on
+    let args = sanitize_args(ctx, args)?;
+
+    // split off the width qualifier and the condition code, and get the possible matchers
+    let (name, cond, width) = split_mnemonic(ctx, &instruction.name)?;
+    let opdata = get_mnemonic_data(&name, ctx.thumb).expect("split_mnemonic returned an unknown mnemonic");
+
+    // matching loop
+    let mut cond_mismatch = false;
+    for data in opdata {
+        if width.map_or(false, |w| w != data.size(ctx.thumb)) {
+            continue;
+        }
+
+        // thumb branches with an encoded condition are only used when a condition is given
+        if data.requires_condition() && cond.is_none() {
+            continue;
+        }
+
+        if let Some(mut ctx_data) = match_args(&args, data) {
+
+            // a32 instructions have to be able to encode the condition
+            if cond.is_some() && !ctx.thumb && !data.conditional() {
+                cond_mismatch = true;
+                continue;
+            }
+
+            ctx_data.cond = cond;
+
+            // flatten the arg list for the encoding vm
+            flatten_args(args, data, &mut ctx_data);
+
+            return Ok(ctx_data);
+        }
+    }
+
+    if cond_mismatch {
+        return Err(Some(format!("'{}': instruction cannot be executed conditionally", &name)));
+    }
+
+    let full_name = match width {
+        Some(Size::WORD) => format!("{}.n", name),
+        Some(_) => format!("{}.w", name),
+        None => name
+    };
+
+    Err(Some(
+        format!("'{}': instruction format mismatch, expected one of the following forms:\n{}", &full_name, format_opdata_list(&full_name, opdata, width))
+    ))
+}
+
+/// Splits a mnemonic in the name of its opmap entry, the condition code and the required instruction size.
+/// The condition code is written as a suffix of the first part of the name, as in `addseq` or `vaddne.f32`.
+/// In thumb mode, the name can end with a `.n` or `.w` qualifier to force a 16-bit or 32-bit encoding.
+fn split_mnemonic(ctx: &Context, full_name: &str) -> Result<(String, Option<u8>, Option<Size>), Option<String>> {
+    let mut parts: Vec<&str> = full_name.split('.').collect();
+
+    let width = if ctx.thumb && parts.len() > 1 {
+        match parts[parts.len() - 1] {
+            "n" => Some(Size::WORD),
+            "w" => Some(Size::DWORD),
+            _ => None
+        }
+    } else {
+        None
+    };
+    if width.is_some() {
+        parts.pop();
+    }
+
+    let name = parts.join(".");
+    if get_mnemonic_data(&name, ctx.thumb).is_some() {
+        return Ok((name, None, width));
+    }
+
+    // try again with a condition code split off
+    let first = parts[0];
+    if first.len() > 2 && first.is_char_boundary(first.len() - 2) {
+        let (stem, suffix) = first.split_at(first.len() - 2);
+        if let Some(&cond) = CONDITIONS.get(suffix) {
+            parts[0] = stem;
+            let name = parts.join(".");
+            if get_mnemonic_data(&name, ctx.thumb).is_some() {
+                return Ok((name, Some(cond), width));
+            }
+        }
+    }
+
+    Err(Some(format!("Unknown instruction mnemonic '{}'", full_name)))
+}
+
+/// Sanitizes arguments, ensuring that only relative jumps are used.
+fn sanitize_args(ctx: &Context, args: Vec<RawArg>) -> Result<Vec<RawArg>, Option<String>> {
+    let arch = if ctx.thumb { "thumb" } else { "arm" };
+
+    for arg in &args {
+        if let RawArg::JumpTarget { jump } = arg {
+            if let JumpKind::Bare(_) = jump.kind {
+                emit_error_at(jump.span(), format!("Extern relocations are not allowed in {}", arch));
+                return Err(None);
+            }
+        }
+    }
+
+    Ok(args)
+}
+
+#[derive(Debug)]
+pub struct MatchData {
+    pub data: &'static Opdata,
+    pub args: Vec<FlatArg>,
+    pub cond: Option<u8>
+}
+
+impl MatchData {
+    fn new(data: &'static Opdata) -> MatchData {
+        MatchData {
+            data,
+            args: Vec::new(),
+            cond: None
+        }
+    }
+}
+
+impl Matcher {
+    /// Returns if this matcher matches the given argument
+    pub fn matches(&self, arg: &RawArg) -> bool {
+        match arg {
+            RawArg::Direct { reg, subtract, writeback, .. } => match self {
+                Matcher::R => reg.family() == RegFamily::CORE && !subtract && !writeback,
+                Matcher::W => reg.family() == RegFamily::CORE && !subtract && *writeback,
+                Matcher::Ri => reg.family() == RegFamily::CORE && !writeback,
+                Matcher::Sp => matches!(reg, RegKind::Static(RegId::R13)) && !subtract && !writeback,
+                Matcher::S => reg.family() == RegFamily::SINGLE && !subtract && !writeback,
+                Matcher::D => reg.family() == RegFamily::DOUBLE && !subtract && !writeback,
+                _ => false
+            },
+            RawArg::Reference { base, index, writeback, .. } => base.family() == RegFamily::CORE && match (self, index) {
+                (Matcher::RefBase, RefIndex::None) => !writeback,
+                (Matcher::RefImm, RefIndex::None)
+                | (Matcher::RefImm, RefIndex::Imm(_)) => !writeback,
+                (Matcher::RefPre, RefIndex::Imm(_)) => *writeback,
+                (Matcher::RefSp, RefIndex::None)
+                | (Matcher::RefSp, RefIndex::Imm(_)) => matches!(base, RegKind::Static(RegId::R13)) && !writeback,
+                (Matcher::RefReg, RefIndex::Reg { reg, .. }) => reg.family() == RegFamily::CORE && !writeback,
+                (Matcher::RefRegPre, RefIndex::Reg { reg, .. }) => reg.family() == RegFamily::CORE && *writeback,
+                (Matcher::RefIdx, RefIndex::Reg { reg, shift: None, .. }) => reg.family() == RegFamily::CORE && !writeback,
+                (Matcher::RefIdxPre, RefIndex::Reg { reg, shift: None, .. }) => reg.family() == RegFamily::CORE && *writeback,
+                _ => false
+            },
+            RawArg::List { regs, .. } => match self {
+                Matcher::List => regs[0].family() == RegFamily::CORE,
+                Matcher::DList => regs[0].family() == RegFamily::DOUBLE,
+                Matcher::SList => regs[0].family() == RegFamily::SINGLE,
+                _ => false
+            },
+            RawArg::Shift { amount, .. } => match self {
+                Matcher::Shift => !matches!(amount, ShiftAmount::Reg(_)),
+                Matcher::ShiftReg => matches!(amount, ShiftAmount::Reg(ref reg) if reg.family() == RegFamily::CORE),
+                _ => false
+            },
+            RawArg::JumpTarget { .. } => *self == Matcher::Offset,
+            RawArg::Immediate { value } => match self {
+                Matcher::Ident => as_ident(value).is_some(),
+                Matcher::Imm => true,
+                _ => false
+            },
+        }
+    }
+
+    /// Returns the amount of arguments this matcher flattens to
+    pub fn flatarg_count(&self) -> usize {
+        match self {
+            Matcher::RefImm
+            | Matcher::RefPre
+            | Matcher::RefSp
+            | Matcher::RefIdx
+            | Matcher::RefIdxPre => 2,
+            Matcher::RefReg
+            | Matcher::RefRegPre => 3,
+            Matcher::End => 0,
+            _ => 1
+        }
+    }
+}
+
+pub fn match_args(args: &[RawArg], data: &'static Opdata) -> Option<MatchData> {
+    let mut args = args.iter();
+    let mut optional = false;
+
+    for matcher in data.matchers {
+        if let Matcher::End = matcher {
+            // the remaining arguments can be omitted
+            optional = true;
+            continue;
+        }
+
+        if let Some(arg) = args.next() {
+            if !matcher.matches(arg) {
+                return None;
+            }
+        } else if optional {
+            break;
+        } else {
+            return None;
+        }
+    }
+
+    // too many arguments
+    if args.next().is_some() {
+        return None;
+    }
+
+    Some(MatchData::new(data))
+}
+
+fn flatten_args(args: Vec<RawArg>, data: &Opdata, ctx: &mut MatchData) {
+    let mut source_args = args.into_iter();
+    let mut new_args = Vec::new();
+
+    for matcher in data.matchers {
+        if let Matcher::End = matcher {
+            continue;
+        }
+
+        let arg = match source_args.next() {
+            Some(arg) => arg,
+            None => {
+                // omitted optional arguments use the default encoding
+                for _ in 0 .. matcher.flatarg_count() {
+                    new_args.push(FlatArg::Default);
+                }
+                continue;
+            }
+        };
+
+        match arg {
+            RawArg::Direct { span, reg, subtract, .. } => new_args.push(FlatArg::Direct { span, reg, subtract }),
+            RawArg::Reference { span, base, index, .. } => {
+                new_args.push(FlatArg::Direct { span, reg: base, subtract: false });
+                match index {
+                    RefIndex::None => if let Matcher::RefImm | Matcher::RefSp = matcher {
+                        new_args.push(FlatArg::Default);
+                    },
+                    RefIndex::Imm(value) => new_args.push(FlatArg::Immediate { value }),
+                    RefIndex::Reg { reg, subtract, shift } => {
+                        new_args.push(FlatArg::Direct { span, reg, subtract });
+                        if let Matcher::RefReg | Matcher::RefRegPre = matcher {
+                            new_args.push(match shift {
+                                Some((kind, amount)) => FlatArg::Shift { span, kind, amount },
+                                None => FlatArg::Default
+                            });
+                        }
+                    }
+                }
+            },
+            RawArg::List { span, regs } => new_args.push(FlatArg::List { span, regs }),
+            RawArg::Shift { span, kind, amount } => new_args.push(match amount {
+                ShiftAmount::None => FlatArg::Shift { span, kind, amount: None },
+                ShiftAmount::Imm(value) => FlatArg::Shift { span, kind, amount: Some(value) },
+                ShiftAmount::Reg(reg) => FlatArg::ShiftReg { span, kind, reg },
+            }),
+            RawArg::JumpTarget { jump } => new_args.push(FlatArg::JumpTarget { jump }),
+            RawArg::Immediate { value } => new_args.push(FlatArg::Immediate { value }),
+        }
+    }
+
+    ctx.args = new_args;
+}
@@ -0,0 +1,125 @@
+use syn::parse;
+
+mod ast;
+mod parser;
+mod matching;
+mod compiler;
+mod armdata;
+mod encoding_helpers;
+mod debug;
+
+use crate::State;
+use crate::common::{Size, Stmt, Jump, emit_error_at};
+use crate::arch::Arch;
+use self::armdata::{Relocation, Features};
+
+#[cfg(feature = "dynasm_opmap")]
+pub use debug::create_opmap;
+
+struct Context<'a, 'b: 'a> {
+    pub state: &'a mut State<'b>,
+    pub features: Features,
+    pub thumb: bool
+}
+
+/// The 32-bit ARM architecture. Depending on `thumb`, either A32 or Thumb-2 (T32) instructions are assembled.
+#[derive(Clone, Debug)]
+pub struct ArchArm {
+    features: Features,
+    thumb: bool
+}
+
+impl Default for ArchArm {
+    fn default() -> ArchArm {
+        ArchArm { features: Features::all(), thumb: false }
+    }
+}
+
+impl ArchArm {
+    pub fn thumb() -> ArchArm {
+        ArchArm { features: Features::all(), thumb: true }
+    }
+}
+
+impl Arch for ArchArm {
+    fn name(&self) -> &str {
+        if self.thumb {
+            "thumb"
+        } else {
+            "arm"
+        }
+    }
+
+    fn set_features(&mut self, features: &[syn::Ident]) {
+        let mut new_features = Features::empty();
+        for ident in features {
+            new_features |= match Features::from_str(&ident.to_string()) {
+                Some(feature) => feature,
+                None => {
+                    emit_error_at(ident.span(), format!("Architecture {} does not support feature '{}'", self.name(), ident.to_string()));
+                    continue;
+                }
+            }
+        }
+        self.features = new_features;
+    }
+
+    fn handle_static_reloc(&self, stmts: &mut Vec<Stmt>, reloc: Jump, size: Size) {
+        let span = reloc.span();
+
+        let relocation = match size {
+            Size::BYTE => Relocation::LITERAL8,
+            Size::WORD => Relocation::LITERAL16,
+            Size::DWORD => Relocation::LITERAL32,
+            Size::QWORD => Relocation::LITERAL64,
+            _ => {
+                emit_error_at(span, "Relocation of unsupported size for the current target architecture".into());
+                return;
+            }
+        };
+        let data = [relocation.to_id()];
+
+        stmts.push(Stmt::Const(0, size));
+        stmts.push(reloc.encode(&data));
+    }
+
+    fn default_align(&self) -> &'static [u8] {
+        // pad with nops, as zeroes would decode as valid instructions in both instruction sets
+        if self.thumb {
+            &[0x00, 0xBF]
+        } else {
+            &[0x00, 0xF0, 0x20, 0xE3]
+        }
+    }
+
+    fn compile_instruction(&self, state: &mut State, input: parse::ParseStream) -> parse::Result<()> {
+        let mut ctx = Context {
+            state,
+            features: self.features,
+            thumb: self.thumb
+        };
+
+        let (instruction, args) = parser::parse_instruction(&mut ctx, input)?;
+        let span = instruction.span;
+
+        let match_data = match matching::match_instruction(&mut ctx, &instruction, args) {
+            Err(None) => return Ok(()),
+            Err(Some(e)) => {
+                emit_error_at(span, e);
+                return Ok(())
+            }
+            Ok(m) => m
+        };
+
+        match compiler::compile_instruction(&mut ctx, match_data) {
+            Err(None) => return Ok(()),
+            Err(Some(e)) => {
+                emit_error_at(span, e);
+                return Ok(())
+            }
+            Ok(()) => ()
+        }
+
+        Ok(())
+    }
+}
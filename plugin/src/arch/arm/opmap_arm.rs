@@ -0,0 +1,706 @@
+// ARMv7-A A32 instructions, including the VFPv3 instructions
+Ops!(
+"adc" = [
+    0x02A0_0000 = [R, R, Imm] => [Cond(28), R(12), R(16), ModImm];
+    0x00A0_0000 = [R, R, R, End, Shift] => [Cond(28), R(12), R(16), R(0), Shift];
+    0x00A0_0010 = [R, R, R, ShiftReg] => [Cond(28), R(12), R(16), R(0), ShiftReg];
+]
+"adcs" = [
+    0x02B0_0000 = [R, R, Imm] => [Cond(28), R(12), R(16), ModImm];
+    0x00B0_0000 = [R, R, R, End, Shift] => [Cond(28), R(12), R(16), R(0), Shift];
+    0x00B0_0010 = [R, R, R, ShiftReg] => [Cond(28), R(12), R(16), R(0), ShiftReg];
+]
+"add" = [
+    0x0280_0000 = [R, R, Imm] => [Cond(28), R(12), R(16), ModImm];
+    0x0080_0000 = [R, R, R, End, Shift] => [Cond(28), R(12), R(16), R(0), Shift];
+    0x0080_0010 = [R, R, R, ShiftReg] => [Cond(28), R(12), R(16), R(0), ShiftReg];
+]
+"adds" = [
+    0x0290_0000 = [R, R, Imm] => [Cond(28), R(12), R(16), ModImm];
+    0x0090_0000 = [R, R, R, End, Shift] => [Cond(28), R(12), R(16), R(0), Shift];
+    0x0090_0010 = [R, R, R, ShiftReg] => [Cond(28), R(12), R(16), R(0), ShiftReg];
+]
+"adr" = [
+    0x028F_0000 = [R, Offset] => [Cond(28), R(12), Offset(ADR)];
+]
+"and" = [
+    0x0200_0000 = [R, R, Imm] => [Cond(28), R(12), R(16), ModImm];
+    0x0000 = [R, R, R, End, Shift] => [Cond(28), R(12), R(16), R(0), Shift];
+    0x0010 = [R, R, R, ShiftReg] => [Cond(28), R(12), R(16), R(0), ShiftReg];
+]
+"ands" = [
+    0x0210_0000 = [R, R, Imm] => [Cond(28), R(12), R(16), ModImm];
+    0x0010_0000 = [R, R, R, End, Shift] => [Cond(28), R(12), R(16), R(0), Shift];
+    0x0010_0010 = [R, R, R, ShiftReg] => [Cond(28), R(12), R(16), R(0), ShiftReg];
+]
+"asr" = [
+    0x01A0_0040 = [R, R, Imm] => [Cond(28), R(12), R(0), Amount(2, 7)];
+    0x01A0_0050 = [R, R, R] => [Cond(28), R(12), R(0), R(8)];
+]
+"asrs" = [
+    0x01B0_0040 = [R, R, Imm] => [Cond(28), R(12), R(0), Amount(2, 7)];
+    0x01B0_0050 = [R, R, R] => [Cond(28), R(12), R(0), R(8)];
+]
+"b" = [
+    0x0A00_0000 = [Offset] => [Cond(28), Offset(B)];
+]
+"bfc" = [
+    0x07C0_001F = [R, Imm, Imm] => [Cond(28), R(12), Ubits(7, 5), Msb(16)];
+]
+"bfi" = [
+    0x07C0_0010 = [R, R, Imm, Imm] => [Cond(28), R(12), R(0), Ubits(7, 5), Msb(16)];
+]
+"bic" = [
+    0x03C0_0000 = [R, R, Imm] => [Cond(28), R(12), R(16), ModImm];
+    0x01C0_0000 = [R, R, R, End, Shift] => [Cond(28), R(12), R(16), R(0), Shift];
+    0x01C0_0010 = [R, R, R, ShiftReg] => [Cond(28), R(12), R(16), R(0), ShiftReg];
+]
+"bics" = [
+    0x03D0_0000 = [R, R, Imm] => [Cond(28), R(12), R(16), ModImm];
+    0x01D0_0000 = [R, R, R, End, Shift] => [Cond(28), R(12), R(16), R(0), Shift];
+    0x01D0_0010 = [R, R, R, ShiftReg] => [Cond(28), R(12), R(16), R(0), ShiftReg];
+]
+"bkpt" = [
+    0xE120_0070 = [Imm] => [BUbits(16), Slice(8, 12, 4), Slice(0, 4, 0), A];
+]
+"bl" = [
+    0x0B00_0000 = [Offset] => [Cond(28), Offset(B)];
+]
+"blx" = [
+    0xFA00_0000 = [Offset] => [Offset(BLX)];
+    0x012F_FF30 = [R] => [Cond(28), R(0)];
+]
+"bx" = [
+    0x012F_FF10 = [R] => [Cond(28), R(0)];
+]
+"clrex" = [
+    0xF57F_F01F = [] => [];
+]
+"clz" = [
+    0x016F_0F10 = [R, R] => [Cond(28), R(12), R(0)];
+]
+"cmn" = [
+    0x0370_0000 = [R, Imm] => [Cond(28), R(16), ModImm];
+    0x0170_0000 = [R, R, End, Shift] => [Cond(28), R(16), R(0), Shift];
+    0x0170_0010 = [R, R, ShiftReg] => [Cond(28), R(16), R(0), ShiftReg];
+]
+"cmp" = [
+    0x0350_0000 = [R, Imm] => [Cond(28), R(16), ModImm];
+    0x0150_0000 = [R, R, End, Shift] => [Cond(28), R(16), R(0), Shift];
+    0x0150_0010 = [R, R, ShiftReg] => [Cond(28), R(16), R(0), ShiftReg];
+]
+"dmb" = [
+    0xF57F_F050 = [End, Ident] => [Barrier(0)];
+]
+"dsb" = [
+    0xF57F_F040 = [End, Ident] => [Barrier(0)];
+]
+"eor" = [
+    0x0220_0000 = [R, R, Imm] => [Cond(28), R(12), R(16), ModImm];
+    0x0020_0000 = [R, R, R, End, Shift] => [Cond(28), R(12), R(16), R(0), Shift];
+    0x0020_0010 = [R, R, R, ShiftReg] => [Cond(28), R(12), R(16), R(0), ShiftReg];
+]
+"eors" = [
+    0x0230_0000 = [R, R, Imm] => [Cond(28), R(12), R(16), ModImm];
+    0x0030_0000 = [R, R, R, End, Shift] => [Cond(28), R(12), R(16), R(0), Shift];
+    0x0030_0010 = [R, R, R, ShiftReg] => [Cond(28), R(12), R(16), R(0), ShiftReg];
+]
+"isb" = [
+    0xF57F_F06F = [End, Ident] => [Is("sy")];
+]
+"ldm" = [
+    0x0890_0000 = [R, List] => [Cond(28), R(16), List(0xFFFF, 1)];
+    0x08B0_0000 = [W, List] => [Cond(28), R(16), List(0xFFFF, 1)];
+]
+"ldmda" = [
+    0x0810_0000 = [R, List] => [Cond(28), R(16), List(0xFFFF, 1)];
+    0x0830_0000 = [W, List] => [Cond(28), R(16), List(0xFFFF, 1)];
+]
+"ldmdb" = [
+    0x0910_0000 = [R, List] => [Cond(28), R(16), List(0xFFFF, 1)];
+    0x0930_0000 = [W, List] => [Cond(28), R(16), List(0xFFFF, 1)];
+]
+"ldmea" = [
+    0x0910_0000 = [R, List] => [Cond(28), R(16), List(0xFFFF, 1)];
+    0x0930_0000 = [W, List] => [Cond(28), R(16), List(0xFFFF, 1)];
+]
+"ldmed" = [
+    0x0990_0000 = [R, List] => [Cond(28), R(16), List(0xFFFF, 1)];
+    0x09B0_0000 = [W, List] => [Cond(28), R(16), List(0xFFFF, 1)];
+]
+"ldmfa" = [
+    0x0810_0000 = [R, List] => [Cond(28), R(16), List(0xFFFF, 1)];
+    0x0830_0000 = [W, List] => [Cond(28), R(16), List(0xFFFF, 1)];
+]
+"ldmfd" = [
+    0x0890_0000 = [R, List] => [Cond(28), R(16), List(0xFFFF, 1)];
+    0x08B0_0000 = [W, List] => [Cond(28), R(16), List(0xFFFF, 1)];
+]
+"ldmia" = [
+    0x0890_0000 = [R, List] => [Cond(28), R(16), List(0xFFFF, 1)];
+    0x08B0_0000 = [W, List] => [Cond(28), R(16), List(0xFFFF, 1)];
+]
+"ldmib" = [
+    0x0990_0000 = [R, List] => [Cond(28), R(16), List(0xFFFF, 1)];
+    0x09B0_0000 = [W, List] => [Cond(28), R(16), List(0xFFFF, 1)];
+]
+"ldr" = [
+    0x0510_0000 = [R, RefImm] => [Cond(28), R(12), R(16), SignMag(23, 12, 0), MagSlice(0, 12, 0), A];
+    0x0530_0000 = [R, RefPre] => [Cond(28), R(12), R(16), SignMag(23, 12, 0), MagSlice(0, 12, 0), A];
+    0x0410_0000 = [R, RefBase, Imm] => [Cond(28), R(12), R(16), SignMag(23, 12, 0), MagSlice(0, 12, 0), A];
+    0x0710_0000 = [R, RefReg] => [Cond(28), R(12), R(16), Ridx(0), Shift];
+    0x0730_0000 = [R, RefRegPre] => [Cond(28), R(12), R(16), Ridx(0), Shift];
+    0x0610_0000 = [R, RefBase, Ri, End, Shift] => [Cond(28), R(12), R(16), Ridx(0), Shift];
+    0x051F_0000 = [R, Offset] => [Cond(28), R(12), Offset(LDR)];
+]
+"ldrb" = [
+    0x0550_0000 = [R, RefImm] => [Cond(28), R(12), R(16), SignMag(23, 12, 0), MagSlice(0, 12, 0), A];
+    0x0570_0000 = [R, RefPre] => [Cond(28), R(12), R(16), SignMag(23, 12, 0), MagSlice(0, 12, 0), A];
+    0x0450_0000 = [R, RefBase, Imm] => [Cond(28), R(12), R(16), SignMag(23, 12, 0), MagSlice(0, 12, 0), A];
+    0x0750_0000 = [R, RefReg] => [Cond(28), R(12), R(16), Ridx(0), Shift];
+    0x0770_0000 = [R, RefRegPre] => [Cond(28), R(12), R(16), Ridx(0), Shift];
+    0x0650_0000 = [R, RefBase, Ri, End, Shift] => [Cond(28), R(12), R(16), Ridx(0), Shift];
+    0x055F_0000 = [R, Offset] => [Cond(28), R(12), Offset(LDR)];
+]
+"ldrd" = [
+    0x0140_00D0 = [R, R, RefImm] => [Cond(28), R(12), RNext, R(16), SignMag(23, 8, 0), MagSlice(8, 4, 4), MagSlice(0, 4, 0), A];
+    0x0160_00D0 = [R, R, RefPre] => [Cond(28), R(12), RNext, R(16), SignMag(23, 8, 0), MagSlice(8, 4, 4), MagSlice(0, 4, 0), A];
+    0x0040_00D0 = [R, R, RefBase, Imm] => [Cond(28), R(12), RNext, R(16), SignMag(23, 8, 0), MagSlice(8, 4, 4), MagSlice(0, 4, 0), A];
+    0x0100_00D0 = [R, R, RefIdx] => [Cond(28), R(12), RNext, R(16), Ridx(0)];
+    0x0120_00D0 = [R, R, RefIdxPre] => [Cond(28), R(12), RNext, R(16), Ridx(0)];
+    0x014F_00D0 = [R, R, Offset] => [Cond(28), R(12), RNext, Offset(LDRH)];
+    0x00D0 = [R, R, RefBase, Ri] => [Cond(28), R(12), RNext, R(16), Ridx(0)];
+]
+"ldrex" = [
+    0x0190_0F9F = [R, RefBase] => [Cond(28), R(12), R(16)];
+]
+"ldrexb" = [
+    0x01D0_0F9F = [R, RefBase] => [Cond(28), R(12), R(16)];
+]
+"ldrexd" = [
+    0x01B0_0F9F = [R, R, RefBase] => [Cond(28), R(12), RNext, R(16)];
+]
+"ldrexh" = [
+    0x01F0_0F9F = [R, RefBase] => [Cond(28), R(12), R(16)];
+]
+"ldrh" = [
+    0x0150_00B0 = [R, RefImm] => [Cond(28), R(12), R(16), SignMag(23, 8, 0), MagSlice(8, 4, 4), MagSlice(0, 4, 0), A];
+    0x0170_00B0 = [R, RefPre] => [Cond(28), R(12), R(16), SignMag(23, 8, 0), MagSlice(8, 4, 4), MagSlice(0, 4, 0), A];
+    0x0050_00B0 = [R, RefBase, Imm] => [Cond(28), R(12), R(16), SignMag(23, 8, 0), MagSlice(8, 4, 4), MagSlice(0, 4, 0), A];
+    0x0110_00B0 = [R, RefIdx] => [Cond(28), R(12), R(16), Ridx(0)];
+    0x0130_00B0 = [R, RefIdxPre] => [Cond(28), R(12), R(16), Ridx(0)];
+    0x0010_00B0 = [R, RefBase, Ri] => [Cond(28), R(12), R(16), Ridx(0)];
+    0x015F_00B0 = [R, Offset] => [Cond(28), R(12), Offset(LDRH)];
+]
+"ldrsb" = [
+    0x0150_00D0 = [R, RefImm] => [Cond(28), R(12), R(16), SignMag(23, 8, 0), MagSlice(8, 4, 4), MagSlice(0, 4, 0), A];
+    0x0170_00D0 = [R, RefPre] => [Cond(28), R(12), R(16), SignMag(23, 8, 0), MagSlice(8, 4, 4), MagSlice(0, 4, 0), A];
+    0x0050_00D0 = [R, RefBase, Imm] => [Cond(28), R(12), R(16), SignMag(23, 8, 0), MagSlice(8, 4, 4), MagSlice(0, 4, 0), A];
+    0x0110_00D0 = [R, RefIdx] => [Cond(28), R(12), R(16), Ridx(0)];
+    0x0130_00D0 = [R, RefIdxPre] => [Cond(28), R(12), R(16), Ridx(0)];
+    0x0010_00D0 = [R, RefBase, Ri] => [Cond(28), R(12), R(16), Ridx(0)];
+    0x015F_00D0 = [R, Offset] => [Cond(28), R(12), Offset(LDRH)];
+]
+"ldrsh" = [
+    0x0150_00F0 = [R, RefImm] => [Cond(28), R(12), R(16), SignMag(23, 8, 0), MagSlice(8, 4, 4), MagSlice(0, 4, 0), A];
+    0x0170_00F0 = [R, RefPre] => [Cond(28), R(12), R(16), SignMag(23, 8, 0), MagSlice(8, 4, 4), MagSlice(0, 4, 0), A];
+    0x0050_00F0 = [R, RefBase, Imm] => [Cond(28), R(12), R(16), SignMag(23, 8, 0), MagSlice(8, 4, 4), MagSlice(0, 4, 0), A];
+    0x0110_00F0 = [R, RefIdx] => [Cond(28), R(12), R(16), Ridx(0)];
+    0x0130_00F0 = [R, RefIdxPre] => [Cond(28), R(12), R(16), Ridx(0)];
+    0x0010_00F0 = [R, RefBase, Ri] => [Cond(28), R(12), R(16), Ridx(0)];
+    0x015F_00F0 = [R, Offset] => [Cond(28), R(12), Offset(LDRH)];
+]
+"lsl" = [
+    0x01A0_0000 = [R, R, Imm] => [Cond(28), R(12), R(0), Amount(0, 7)];
+    0x01A0_0010 = [R, R, R] => [Cond(28), R(12), R(0), R(8)];
+]
+"lsls" = [
+    0x01B0_0000 = [R, R, Imm] => [Cond(28), R(12), R(0), Amount(0, 7)];
+    0x01B0_0010 = [R, R, R] => [Cond(28), R(12), R(0), R(8)];
+]
+"lsr" = [
+    0x01A0_0020 = [R, R, Imm] => [Cond(28), R(12), R(0), Amount(1, 7)];
+    0x01A0_0030 = [R, R, R] => [Cond(28), R(12), R(0), R(8)];
+]
+"lsrs" = [
+    0x01B0_0020 = [R, R, Imm] => [Cond(28), R(12), R(0), Amount(1, 7)];
+    0x01B0_0030 = [R, R, R] => [Cond(28), R(12), R(0), R(8)];
+]
+"mla" = [
+    0x0020_0090 = [R, R, R, R] => [Cond(28), R(16), R(0), R(8), R(12)];
+]
+"mlas" = [
+    0x0030_0090 = [R, R, R, R] => [Cond(28), R(16), R(0), R(8), R(12)];
+]
+"mls" = [
+    0x0060_0090 = [R, R, R, R] => [Cond(28), R(16), R(0), R(8), R(12)];
+]
+"mov" = [
+    0x03A0_0000 = [R, Imm] => [Cond(28), R(12), ModImm];
+    0x01A0_0000 = [R, R, End, Shift] => [Cond(28), R(12), R(0), Shift];
+    0x01A0_0010 = [R, R, ShiftReg] => [Cond(28), R(12), R(0), ShiftReg];
+]
+"movs" = [
+    0x03B0_0000 = [R, Imm] => [Cond(28), R(12), ModImm];
+    0x01B0_0000 = [R, R, End, Shift] => [Cond(28), R(12), R(0), Shift];
+    0x01B0_0010 = [R, R, ShiftReg] => [Cond(28), R(12), R(0), ShiftReg];
+]
+"movt" = [
+    0x0340_0000 = [R, Imm] => [Cond(28), R(12), BUbits(16), Slice(16, 4, 12), Slice(0, 12, 0), A];
+]
+"movw" = [
+    0x0300_0000 = [R, Imm] => [Cond(28), R(12), BUbits(16), Slice(16, 4, 12), Slice(0, 12, 0), A];
+]
+"mrs" = [
+    0x010F_0000 = [R, Ident] => [Cond(28), R(12), Is("apsr")];
+]
+"msr" = [
+    0x0120_F000 = [Ident, R] => [Cond(28), PsrMask(18), R(0)];
+]
+"mul" = [
+    0x0090 = [R, R, R] => [Cond(28), R(16), R(0), R(8)];
+]
+"muls" = [
+    0x0010_0090 = [R, R, R] => [Cond(28), R(16), R(0), R(8)];
+]
+"mvn" = [
+    0x03E0_0000 = [R, Imm] => [Cond(28), R(12), ModImm];
+    0x01E0_0000 = [R, R, End, Shift] => [Cond(28), R(12), R(0), Shift];
+    0x01E0_0010 = [R, R, ShiftReg] => [Cond(28), R(12), R(0), ShiftReg];
+]
+"mvns" = [
+    0x03F0_0000 = [R, Imm] => [Cond(28), R(12), ModImm];
+    0x01F0_0000 = [R, R, End, Shift] => [Cond(28), R(12), R(0), Shift];
+    0x01F0_0010 = [R, R, ShiftReg] => [Cond(28), R(12), R(0), ShiftReg];
+]
+"nop" = [
+    0x0320_F000 = [] => [Cond(28)];
+]
+"orr" = [
+    0x0380_0000 = [R, R, Imm] => [Cond(28), R(12), R(16), ModImm];
+    0x0180_0000 = [R, R, R, End, Shift] => [Cond(28), R(12), R(16), R(0), Shift];
+    0x0180_0010 = [R, R, R, ShiftReg] => [Cond(28), R(12), R(16), R(0), ShiftReg];
+]
+"orrs" = [
+    0x0390_0000 = [R, R, Imm] => [Cond(28), R(12), R(16), ModImm];
+    0x0190_0000 = [R, R, R, End, Shift] => [Cond(28), R(12), R(16), R(0), Shift];
+    0x0190_0010 = [R, R, R, ShiftReg] => [Cond(28), R(12), R(16), R(0), ShiftReg];
+]
+"pop" = [
+    0x08BD_0000 = [List] => [Cond(28), List(0xFFFF, 1)];
+]
+"push" = [
+    0x092D_0000 = [List] => [Cond(28), List(0xFFFF, 1)];
+]
+"rbit" = [
+    0x06FF_0F30 = [R, R] => [Cond(28), R(12), R(0)];
+]
+"rev" = [
+    0x06BF_0F30 = [R, R] => [Cond(28), R(12), R(0)];
+]
+"rev16" = [
+    0x06BF_0FB0 = [R, R] => [Cond(28), R(12), R(0)];
+]
+"revsh" = [
+    0x06FF_0FB0 = [R, R] => [Cond(28), R(12), R(0)];
+]
+"ror" = [
+    0x01A0_0060 = [R, R, Imm] => [Cond(28), R(12), R(0), Amount(3, 7)];
+    0x01A0_0070 = [R, R, R] => [Cond(28), R(12), R(0), R(8)];
+]
+"rors" = [
+    0x01B0_0060 = [R, R, Imm] => [Cond(28), R(12), R(0), Amount(3, 7)];
+    0x01B0_0070 = [R, R, R] => [Cond(28), R(12), R(0), R(8)];
+]
+"rrx" = [
+    0x01A0_0060 = [R, R] => [Cond(28), R(12), R(0)];
+]
+"rrxs" = [
+    0x01B0_0060 = [R, R] => [Cond(28), R(12), R(0)];
+]
+"rsb" = [
+    0x0260_0000 = [R, R, Imm] => [Cond(28), R(12), R(16), ModImm];
+    0x0060_0000 = [R, R, R, End, Shift] => [Cond(28), R(12), R(16), R(0), Shift];
+    0x0060_0010 = [R, R, R, ShiftReg] => [Cond(28), R(12), R(16), R(0), ShiftReg];
+]
+"rsbs" = [
+    0x0270_0000 = [R, R, Imm] => [Cond(28), R(12), R(16), ModImm];
+    0x0070_0000 = [R, R, R, End, Shift] => [Cond(28), R(12), R(16), R(0), Shift];
+    0x0070_0010 = [R, R, R, ShiftReg] => [Cond(28), R(12), R(16), R(0), ShiftReg];
+]
+"rsc" = [
+    0x02E0_0000 = [R, R, Imm] => [Cond(28), R(12), R(16), ModImm];
+    0x00E0_0000 = [R, R, R, End, Shift] => [Cond(28), R(12), R(16), R(0), Shift];
+    0x00E0_0010 = [R, R, R, ShiftReg] => [Cond(28), R(12), R(16), R(0), ShiftReg];
+]
+"rscs" = [
+    0x02F0_0000 = [R, R, Imm] => [Cond(28), R(12), R(16), ModImm];
+    0x00F0_0000 = [R, R, R, End, Shift] => [Cond(28), R(12), R(16), R(0), Shift];
+    0x00F0_0010 = [R, R, R, ShiftReg] => [Cond(28), R(12), R(16), R(0), ShiftReg];
+]
+"sbc" = [
+    0x02C0_0000 = [R, R, Imm] => [Cond(28), R(12), R(16), ModImm];
+    0x00C0_0000 = [R, R, R, End, Shift] => [Cond(28), R(12), R(16), R(0), Shift];
+    0x00C0_0010 = [R, R, R, ShiftReg] => [Cond(28), R(12), R(16), R(0), ShiftReg];
+]
+"sbcs" = [
+    0x02D0_0000 = [R, R, Imm] => [Cond(28), R(12), R(16), ModImm];
+    0x00D0_0000 = [R, R, R, End, Shift] => [Cond(28), R(12), R(16), R(0), Shift];
+    0x00D0_0010 = [R, R, R, ShiftReg] => [Cond(28), R(12), R(16), R(0), ShiftReg];
+]
+"sbfx" = [
+    0x07A0_0050 = [R, R, Imm, Imm] => [Cond(28), R(12), R(0), Ubits(7, 5), Urange(16, 1, 32)];
+]
+"sdiv" = [
+    0x0710_F010 = [R, R, R] => [Cond(28), R(16), R(0), R(8)], IDIV;
+]
+"sev" = [
+    0x0320_F004 = [] => [Cond(28)];
+]
+"smlal" = [
+    0x00E0_0090 = [R, R, R, R] => [Cond(28), R(12), R(16), R(0), R(8)];
+]
+"smlals" = [
+    0x00F0_0090 = [R, R, R, R] => [Cond(28), R(12), R(16), R(0), R(8)];
+]
+"smull" = [
+    0x00C0_0090 = [R, R, R, R] => [Cond(28), R(12), R(16), R(0), R(8)];
+]
+"smulls" = [
+    0x00D0_0090 = [R, R, R, R] => [Cond(28), R(12), R(16), R(0), R(8)];
+]
+"stm" = [
+    0x0880_0000 = [R, List] => [Cond(28), R(16), List(0xFFFF, 1)];
+    0x08A0_0000 = [W, List] => [Cond(28), R(16), List(0xFFFF, 1)];
+]
+"stmda" = [
+    0x0800_0000 = [R, List] => [Cond(28), R(16), List(0xFFFF, 1)];
+    0x0820_0000 = [W, List] => [Cond(28), R(16), List(0xFFFF, 1)];
+]
+"stmdb" = [
+    0x0900_0000 = [R, List] => [Cond(28), R(16), List(0xFFFF, 1)];
+    0x0920_0000 = [W, List] => [Cond(28), R(16), List(0xFFFF, 1)];
+]
+"stmea" = [
+    0x0880_0000 = [R, List] => [Cond(28), R(16), List(0xFFFF, 1)];
+    0x08A0_0000 = [W, List] => [Cond(28), R(16), List(0xFFFF, 1)];
+]
+"stmed" = [
+    0x0800_0000 = [R, List] => [Cond(28), R(16), List(0xFFFF, 1)];
+    0x0820_0000 = [W, List] => [Cond(28), R(16), List(0xFFFF, 1)];
+]
+"stmfa" = [
+    0x0980_0000 = [R, List] => [Cond(28), R(16), List(0xFFFF, 1)];
+    0x09A0_0000 = [W, List] => [Cond(28), R(16), List(0xFFFF, 1)];
+]
+"stmfd" = [
+    0x0900_0000 = [R, List] => [Cond(28), R(16), List(0xFFFF, 1)];
+    0x0920_0000 = [W, List] => [Cond(28), R(16), List(0xFFFF, 1)];
+]
+"stmia" = [
+    0x0880_0000 = [R, List] => [Cond(28), R(16), List(0xFFFF, 1)];
+    0x08A0_0000 = [W, List] => [Cond(28), R(16), List(0xFFFF, 1)];
+]
+"stmib" = [
+    0x0980_0000 = [R, List] => [Cond(28), R(16), List(0xFFFF, 1)];
+    0x09A0_0000 = [W, List] => [Cond(28), R(16), List(0xFFFF, 1)];
+]
+"str" = [
+    0x0500_0000 = [R, RefImm] => [Cond(28), R(12), R(16), SignMag(23, 12, 0), MagSlice(0, 12, 0), A];
+    0x0520_0000 = [R, RefPre] => [Cond(28), R(12), R(16), SignMag(23, 12, 0), MagSlice(0, 12, 0), A];
+    0x0400_0000 = [R, RefBase, Imm] => [Cond(28), R(12), R(16), SignMag(23, 12, 0), MagSlice(0, 12, 0), A];
+    0x0700_0000 = [R, RefReg] => [Cond(28), R(12), R(16), Ridx(0), Shift];
+    0x0720_0000 = [R, RefRegPre] => [Cond(28), R(12), R(16), Ridx(0), Shift];
+    0x0600_0000 = [R, RefBase, Ri, End, Shift] => [Cond(28), R(12), R(16), Ridx(0), Shift];
+]
+"strb" = [
+    0x0540_0000 = [R, RefImm] => [Cond(28), R(12), R(16), SignMag(23, 12, 0), MagSlice(0, 12, 0), A];
+    0x0560_0000 = [R, RefPre] => [Cond(28), R(12), R(16), SignMag(23, 12, 0), MagSlice(0, 12, 0), A];
+    0x0440_0000 = [R, RefBase, Imm] => [Cond(28), R(12), R(16), SignMag(23, 12, 0), MagSlice(0, 12, 0), A];
+    0x0740_0000 = [R, RefReg] => [Cond(28), R(12), R(16), Ridx(0), Shift];
+    0x0760_0000 = [R, RefRegPre] => [Cond(28), R(12), R(16), Ridx(0), Shift];
+    0x0640_0000 = [R, RefBase, Ri, End, Shift] => [Cond(28), R(12), R(16), Ridx(0), Shift];
+]
+"strd" = [
+    0x0140_00F0 = [R, R, RefImm] => [Cond(28), R(12), RNext, R(16), SignMag(23, 8, 0), MagSlice(8, 4, 4), MagSlice(0, 4, 0), A];
+    0x0160_00F0 = [R, R, RefPre] => [Cond(28), R(12), RNext, R(16), SignMag(23, 8, 0), MagSlice(8, 4, 4), MagSlice(0, 4, 0), A];
+    0x0040_00F0 = [R, R, RefBase, Imm] => [Cond(28), R(12), RNext, R(16), SignMag(23, 8, 0), MagSlice(8, 4, 4), MagSlice(0, 4, 0), A];
+    0x0100_00F0 = [R, R, RefIdx] => [Cond(28), R(12), RNext, R(16), Ridx(0)];
+    0x0120_00F0 = [R, R, RefIdxPre] => [Cond(28), R(12), RNext, R(16), Ridx(0)];
+    0x00F0 = [R, R, RefBase, Ri] => [Cond(28), R(12), RNext, R(16), Ridx(0)];
+]
+"strex" = [
+    0x0180_0F90 = [R, R, RefBase] => [Cond(28), R(12), R(0), R(16)];
+]
+"strexb" = [
+    0x01C0_0F90 = [R, R, RefBase] => [Cond(28), R(12), R(0), R(16)];
+]
+"strexd" = [
+    0x01A0_0F90 = [R, R, R, RefBase] => [Cond(28), R(12), R(0), RNext, R(16)];
+]
+"strexh" = [
+    0x01E0_0F90 = [R, R, RefBase] => [Cond(28), R(12), R(0), R(16)];
+]
+"strh" = [
+    0x0140_00B0 = [R, RefImm] => [Cond(28), R(12), R(16), SignMag(23, 8, 0), MagSlice(8, 4, 4), MagSlice(0, 4, 0), A];
+    0x0160_00B0 = [R, RefPre] => [Cond(28), R(12), R(16), SignMag(23, 8, 0), MagSlice(8, 4, 4), MagSlice(0, 4, 0), A];
+    0x0040_00B0 = [R, RefBase, Imm] => [Cond(28), R(12), R(16), SignMag(23, 8, 0), MagSlice(8, 4, 4), MagSlice(0, 4, 0), A];
+    0x0100_00B0 = [R, RefIdx] => [Cond(28), R(12), R(16), Ridx(0)];
+    0x0120_00B0 = [R, RefIdxPre] => [Cond(28), R(12), R(16), Ridx(0)];
+    0x00B0 = [R, RefBase, Ri] => [Cond(28), R(12), R(16), Ridx(0)];
+]
+"sub" = [
+    0x0240_0000 = [R, R, Imm] => [Cond(28), R(12), R(16), ModImm];
+    0x0040_0000 = [R, R, R, End, Shift] => [Cond(28), R(12), R(16), R(0), Shift];
+    0x0040_0010 = [R, R, R, ShiftReg] => [Cond(28), R(12), R(16), R(0), ShiftReg];
+]
+"subs" = [
+    0x0250_0000 = [R, R, Imm] => [Cond(28), R(12), R(16), ModImm];
+    0x0050_0000 = [R, R, R, End, Shift] => [Cond(28), R(12), R(16), R(0), Shift];
+    0x0050_0010 = [R, R, R, ShiftReg] => [Cond(28), R(12), R(16), R(0), ShiftReg];
+]
+"svc" = [
+    0x0F00_0000 = [Imm] => [Cond(28), Ubits(0, 24)];
+]
+"sxtb" = [
+    0x06AF_0070 = [R, R, End, Shift] => [Cond(28), R(12), R(0), Rotate(10)];
+]
+"sxth" = [
+    0x06BF_0070 = [R, R, End, Shift] => [Cond(28), R(12), R(0), Rotate(10)];
+]
+"teq" = [
+    0x0330_0000 = [R, Imm] => [Cond(28), R(16), ModImm];
+    0x0130_0000 = [R, R, End, Shift] => [Cond(28), R(16), R(0), Shift];
+    0x0130_0010 = [R, R, ShiftReg] => [Cond(28), R(16), R(0), ShiftReg];
+]
+"tst" = [
+    0x0310_0000 = [R, Imm] => [Cond(28), R(16), ModImm];
+    0x0110_0000 = [R, R, End, Shift] => [Cond(28), R(16), R(0), Shift];
+    0x0110_0010 = [R, R, ShiftReg] => [Cond(28), R(16), R(0), ShiftReg];
+]
+"ubfx" = [
+    0x07E0_0050 = [R, R, Imm, Imm] => [Cond(28), R(12), R(0), Ubits(7, 5), Urange(16, 1, 32)];
+]
+"udf" = [
+    0xE7F0_00F0 = [Imm] => [BUbits(16), Slice(8, 12, 4), Slice(0, 4, 0), A];
+]
+"udiv" = [
+    0x0730_F010 = [R, R, R] => [Cond(28), R(16), R(0), R(8)], IDIV;
+]
+"umlal" = [
+    0x00A0_0090 = [R, R, R, R] => [Cond(28), R(12), R(16), R(0), R(8)];
+]
+"umlals" = [
+    0x00B0_0090 = [R, R, R, R] => [Cond(28), R(12), R(16), R(0), R(8)];
+]
+"umull" = [
+    0x0080_0090 = [R, R, R, R] => [Cond(28), R(12), R(16), R(0), R(8)];
+]
+"umulls" = [
+    0x0090_0090 = [R, R, R, R] => [Cond(28), R(12), R(16), R(0), R(8)];
+]
+"uxtb" = [
+    0x06EF_0070 = [R, R, End, Shift] => [Cond(28), R(12), R(0), Rotate(10)];
+]
+"uxth" = [
+    0x06FF_0070 = [R, R, End, Shift] => [Cond(28), R(12), R(0), Rotate(10)];
+]
+"vabs.f32" = [
+    0x0EB0_0AC0 = [S, S] => [Cond(28), Sreg(12, 22), Sreg(0, 5)], VFP;
+]
+"vabs.f64" = [
+    0x0EB0_0BC0 = [D, D] => [Cond(28), Dreg(12, 22), Dreg(0, 5)], VFP;
+]
+"vadd.f32" = [
+    0x0E30_0A00 = [S, S, S] => [Cond(28), Sreg(12, 22), Sreg(16, 7), Sreg(0, 5)], VFP;
+]
+"vadd.f64" = [
+    0x0E30_0B00 = [D, D, D] => [Cond(28), Dreg(12, 22), Dreg(16, 7), Dreg(0, 5)], VFP;
+]
+"vcmp.f32" = [
+    0x0EB4_0A40 = [S, S] => [Cond(28), Sreg(12, 22), Sreg(0, 5)], VFP;
+    0x0EB5_0A40 = [S, Imm] => [Cond(28), Sreg(12, 22), Zero], VFP;
+]
+"vcmp.f64" = [
+    0x0EB4_0B40 = [D, D] => [Cond(28), Dreg(12, 22), Dreg(0, 5)], VFP;
+    0x0EB5_0B40 = [D, Imm] => [Cond(28), Dreg(12, 22), Zero], VFP;
+]
+"vcmpe.f32" = [
+    0x0EB4_0AC0 = [S, S] => [Cond(28), Sreg(12, 22), Sreg(0, 5)], VFP;
+    0x0EB5_0AC0 = [S, Imm] => [Cond(28), Sreg(12, 22), Zero], VFP;
+]
+"vcmpe.f64" = [
+    0x0EB4_0BC0 = [D, D] => [Cond(28), Dreg(12, 22), Dreg(0, 5)], VFP;
+    0x0EB5_0BC0 = [D, Imm] => [Cond(28), Dreg(12, 22), Zero], VFP;
+]
+"vcvt.f32.f64" = [
+    0x0EB7_0BC0 = [S, D] => [Cond(28), Sreg(12, 22), Dreg(0, 5)], VFP;
+]
+"vcvt.f32.s32" = [
+    0x0EB8_0AC0 = [S, S] => [Cond(28), Sreg(12, 22), Sreg(0, 5)], VFP;
+]
+"vcvt.f32.u32" = [
+    0x0EB8_0A40 = [S, S] => [Cond(28), Sreg(12, 22), Sreg(0, 5)], VFP;
+]
+"vcvt.f64.f32" = [
+    0x0EB7_0AC0 = [D, S] => [Cond(28), Dreg(12, 22), Sreg(0, 5)], VFP;
+]
+"vcvt.f64.s32" = [
+    0x0EB8_0BC0 = [D, S] => [Cond(28), Dreg(12, 22), Sreg(0, 5)], VFP;
+]
+"vcvt.f64.u32" = [
+    0x0EB8_0B40 = [D, S] => [Cond(28), Dreg(12, 22), Sreg(0, 5)], VFP;
+]
+"vcvt.s32.f32" = [
+    0x0EBD_0AC0 = [S, S] => [Cond(28), Sreg(12, 22), Sreg(0, 5)], VFP;
+]
+"vcvt.s32.f64" = [
+    0x0EBD_0BC0 = [S, D] => [Cond(28), Sreg(12, 22), Dreg(0, 5)], VFP;
+]
+"vcvt.u32.f32" = [
+    0x0EBC_0AC0 = [S, S] => [Cond(28), Sreg(12, 22), Sreg(0, 5)], VFP;
+]
+"vcvt.u32.f64" = [
+    0x0EBC_0BC0 = [S, D] => [Cond(28), Sreg(12, 22), Dreg(0, 5)], VFP;
+]
+"vdiv.f32" = [
+    0x0E80_0A00 = [S, S, S] => [Cond(28), Sreg(12, 22), Sreg(16, 7), Sreg(0, 5)], VFP;
+]
+"vdiv.f64" = [
+    0x0E80_0B00 = [D, D, D] => [Cond(28), Dreg(12, 22), Dreg(16, 7), Dreg(0, 5)], VFP;
+]
+"vldm" = [
+    0x0C90_0B00 = [R, DList] => [Cond(28), R(16), DList], VFP;
+    0x0CB0_0B00 = [W, DList] => [Cond(28), R(16), DList], VFP;
+    0x0C90_0A00 = [R, SList] => [Cond(28), R(16), SList], VFP;
+    0x0CB0_0A00 = [W, SList] => [Cond(28), R(16), SList], VFP;
+]
+"vldmdb" = [
+    0x0D30_0B00 = [W, DList] => [Cond(28), R(16), DList], VFP;
+    0x0D30_0A00 = [W, SList] => [Cond(28), R(16), SList], VFP;
+]
+"vldmia" = [
+    0x0C90_0B00 = [R, DList] => [Cond(28), R(16), DList], VFP;
+    0x0CB0_0B00 = [W, DList] => [Cond(28), R(16), DList], VFP;
+    0x0C90_0A00 = [R, SList] => [Cond(28), R(16), SList], VFP;
+    0x0CB0_0A00 = [W, SList] => [Cond(28), R(16), SList], VFP;
+]
+"vldr" = [
+    0x0D10_0A00 = [S, RefImm] => [Cond(28), Sreg(12, 22), R(16), SignMag(23, 8, 2), MagSlice(0, 8, 2), A], VFP;
+    0x0D10_0B00 = [D, RefImm] => [Cond(28), Dreg(12, 22), R(16), SignMag(23, 8, 2), MagSlice(0, 8, 2), A], VFP;
+    0x0D1F_0A00 = [S, Offset] => [Cond(28), Sreg(12, 22), Offset(VLDR)], VFP;
+    0x0D1F_0B00 = [D, Offset] => [Cond(28), Dreg(12, 22), Offset(VLDR)], VFP;
+]
+"vmla.f32" = [
+    0x0E00_0A00 = [S, S, S] => [Cond(28), Sreg(12, 22), Sreg(16, 7), Sreg(0, 5)], VFP;
+]
+"vmla.f64" = [
+    0x0E00_0B00 = [D, D, D] => [Cond(28), Dreg(12, 22), Dreg(16, 7), Dreg(0, 5)], VFP;
+]
+"vmls.f32" = [
+    0x0E00_0A40 = [S, S, S] => [Cond(28), Sreg(12, 22), Sreg(16, 7), Sreg(0, 5)], VFP;
+]
+"vmls.f64" = [
+    0x0E00_0B40 = [D, D, D] => [Cond(28), Dreg(12, 22), Dreg(16, 7), Dreg(0, 5)], VFP;
+]
+"vmov" = [
+    0x0E00_0A10 = [S, R] => [Cond(28), Sreg(16, 7), R(12)], VFP;
+    0x0E10_0A10 = [R, S] => [Cond(28), R(12), Sreg(16, 7)], VFP;
+    0x0C40_0B10 = [D, R, R] => [Cond(28), Dreg(0, 5), R(12), R(16)], VFP;
+    0x0C50_0B10 = [R, R, D] => [Cond(28), R(12), R(16), Dreg(0, 5)], VFP;
+]
+"vmov.f32" = [
+    0x0EB0_0A40 = [S, S] => [Cond(28), Sreg(12, 22), Sreg(0, 5)], VFP;
+    0x0EB0_0A00 = [S, Imm] => [Cond(28), Sreg(12, 22), FpImm], VFP;
+]
+"vmov.f64" = [
+    0x0EB0_0B40 = [D, D] => [Cond(28), Dreg(12, 22), Dreg(0, 5)], VFP;
+    0x0EB0_0B00 = [D, Imm] => [Cond(28), Dreg(12, 22), FpImm], VFP;
+]
+"vmrs" = [
+    0x0EF1_0A10 = [R, Ident] => [Cond(28), R(12), Is("fpscr")], VFP;
+    0x0EF1_FA10 = [Ident, Ident] => [Cond(28), Is("apsr_nzcv"), Is("fpscr")], VFP;
+]
+"vmsr" = [
+    0x0EE1_0A10 = [Ident, R] => [Cond(28), Is("fpscr"), R(12)], VFP;
+]
+"vmul.f32" = [
+    0x0E20_0A00 = [S, S, S] => [Cond(28), Sreg(12, 22), Sreg(16, 7), Sreg(0, 5)], VFP;
+]
+"vmul.f64" = [
+    0x0E20_0B00 = [D, D, D] => [Cond(28), Dreg(12, 22), Dreg(16, 7), Dreg(0, 5)], VFP;
+]
+"vneg.f32" = [
+    0x0EB1_0A40 = [S, S] => [Cond(28), Sreg(12, 22), Sreg(0, 5)], VFP;
+]
+"vneg.f64" = [
+    0x0EB1_0B40 = [D, D] => [Cond(28), Dreg(12, 22), Dreg(0, 5)], VFP;
+]
+"vnmla.f32" = [
+    0x0E10_0A40 = [S, S, S] => [Cond(28), Sreg(12, 22), Sreg(16, 7), Sreg(0, 5)], VFP;
+]
+"vnmla.f64" = [
+    0x0E10_0B40 = [D, D, D] => [Cond(28), Dreg(12, 22), Dreg(16, 7), Dreg(0, 5)], VFP;
+]
+"vnmls.f32" = [
+    0x0E10_0A00 = [S, S, S] => [Cond(28), Sreg(12, 22), Sreg(16, 7), Sreg(0, 5)], VFP;
+]
+"vnmls.f64" = [
+    0x0E10_0B00 = [D, D, D] => [Cond(28), Dreg(12, 22), Dreg(16, 7), Dreg(0, 5)], VFP;
+]
+"vnmul.f32" = [
+    0x0E20_0A40 = [S, S, S] => [Cond(28), Sreg(12, 22), Sreg(16, 7), Sreg(0, 5)], VFP;
+]
+"vnmul.f64" = [
+    0x0E20_0B40 = [D, D, D] => [Cond(28), Dreg(12, 22), Dreg(16, 7), Dreg(0, 5)], VFP;
+]
+"vpop" = [
+    0x0CBD_0B00 = [DList] => [Cond(28), DList], VFP;
+    0x0CBD_0A00 = [SList] => [Cond(28), SList], VFP;
+]
+"vpush" = [
+    0x0D2D_0B00 = [DList] => [Cond(28), DList], VFP;
+    0x0D2D_0A00 = [SList] => [Cond(28), SList], VFP;
+]
+"vsqrt.f32" = [
+    0x0EB1_0AC0 = [S, S] => [Cond(28), Sreg(12, 22), Sreg(0, 5)], VFP;
+]
+"vsqrt.f64" = [
+    0x0EB1_0BC0 = [D, D] => [Cond(28), Dreg(12, 22), Dreg(0, 5)], VFP;
+]
+"vstm" = [
+    0x0C80_0B00 = [R, DList] => [Cond(28), R(16), DList], VFP;
+    0x0CA0_0B00 = [W, DList] => [Cond(28), R(16), DList], VFP;
+    0x0C80_0A00 = [R, SList] => [Cond(28), R(16), SList], VFP;
+    0x0CA0_0A00 = [W, SList] => [Cond(28), R(16), SList], VFP;
+]
+"vstmdb" = [
+    0x0D20_0B00 = [W, DList] => [Cond(28), R(16), DList], VFP;
+    0x0D20_0A00 = [W, SList] => [Cond(28), R(16), SList], VFP;
+]
+"vstmia" = [
+    0x0C80_0B00 = [R, DList] => [Cond(28), R(16), DList], VFP;
+    0x0CA0_0B00 = [W, DList] => [Cond(28), R(16), DList], VFP;
+    0x0C80_0A00 = [R, SList] => [Cond(28), R(16), SList], VFP;
+    0x0CA0_0A00 = [W, SList] => [Cond(28), R(16), SList], VFP;
+]
+"vstr" = [
+    0x0D00_0A00 = [S, RefImm] => [Cond(28), Sreg(12, 22), R(16), SignMag(23, 8, 2), MagSlice(0, 8, 2), A], VFP;
+    0x0D00_0B00 = [D, RefImm] => [Cond(28), Dreg(12, 22), R(16), SignMag(23, 8, 2), MagSlice(0, 8, 2), A], VFP;
+]
+"vsub.f32" = [
+    0x0E30_0A40 = [S, S, S] => [Cond(28), Sreg(12, 22), Sreg(16, 7), Sreg(0, 5)], VFP;
+]
+"vsub.f64" = [
+    0x0E30_0B40 = [D, D, D] => [Cond(28), Dreg(12, 22), Dreg(16, 7), Dreg(0, 5)], VFP;
+]
+"wfe" = [
+    0x0320_F002 = [] => [Cond(28)];
+]
+"wfi" = [
+    0x0320_F003 = [] => [Cond(28)];
+]
+"yield" = [
+    0x0320_F001 = [] => [Cond(28)];
+]
+)
@@ -0,0 +1,740 @@
+// ARMv7 Thumb-2 instructions, including the VFPv3 instructions
+Ops!(
+"adc" = [
+    0xF140_0000 = [R, R, Imm] => [R(8), R(16), ModImmT];
+    0xEB40_0000 = [R, R, R, End, Shift] => [R(8), R(16), R(0), ShiftT];
+]
+"adcs" = [
+    0xF150_0000 = [R, R, Imm] => [R(8), R(16), ModImmT];
+    0xEB50_0000 = [R, R, R, End, Shift] => [R(8), R(16), R(0), ShiftT];
+    0x4140 = [R, R] => [RLo(0), RLo(3)];
+]
+"add" = [
+    0xF100_0000 = [R, R, Imm] => [R(8), R(16), ModImmT];
+    0xEB00_0000 = [R, R, R, End, Shift] => [R(8), R(16), R(0), ShiftT];
+    0x4400 = [R, R] => [RSplit(0, 7), R(3)];
+    0xB000 = [Sp, Sp, Imm] => [RIs(13), RIs(13), BUscaled(7, 2), Slice(0, 7, 2), A];
+    0xA800 = [R, Sp, Imm] => [RLo(8), RIs(13), BUscaled(8, 2), Slice(0, 8, 2), A];
+]
+"adds" = [
+    0xF110_0000 = [R, R, Imm] => [R(8), R(16), ModImmT];
+    0xEB10_0000 = [R, R, R, End, Shift] => [R(8), R(16), R(0), ShiftT];
+    0x1800 = [R, R, R] => [RLo(0), RLo(3), RLo(6)];
+    0x1C00 = [R, R, Imm] => [RLo(0), RLo(3), Ubits(6, 3)];
+    0x3000 = [R, Imm] => [RLo(8), Ubits(0, 8)];
+]
+"addw" = [
+    0xF200_0000 = [R, R, Imm] => [R(8), R(16), BUbits(12), Slice(26, 1, 11), Slice(12, 3, 8), Slice(0, 8, 0), A];
+]
+"adr" = [
+    0xF20F_0000 = [R, Offset] => [R(8), Offset(T_ADR)];
+    0xA000 = [R, Offset] => [RLo(8), Offset(T_ADR16)];
+]
+"and" = [
+    0xF000_0000 = [R, R, Imm] => [R(8), R(16), ModImmT];
+    0xEA00_0000 = [R, R, R, End, Shift] => [R(8), R(16), R(0), ShiftT];
+]
+"ands" = [
+    0xF010_0000 = [R, R, Imm] => [R(8), R(16), ModImmT];
+    0xEA10_0000 = [R, R, R, End, Shift] => [R(8), R(16), R(0), ShiftT];
+    0x4000 = [R, R] => [RLo(0), RLo(3)];
+]
+"asr" = [
+    0xEA4F_0020 = [R, R, Imm] => [R(8), R(0), AmountT(2)];
+    0xFA40_F000 = [R, R, R] => [R(8), R(16), R(0)];
+]
+"asrs" = [
+    0xEA5F_0020 = [R, R, Imm] => [R(8), R(0), AmountT(2)];
+    0xFA50_F000 = [R, R, R] => [R(8), R(16), R(0)];
+    0x1000 = [R, R, Imm] => [RLo(0), RLo(3), Amount(2, 6)];
+    0x4100 = [R, R] => [RLo(0), RLo(3)];
+]
+"b" = [
+    0xF000_8000 = [Offset] => [CondReq(22), Offset(T_BCOND)];
+    0xF000_9000 = [Offset] => [Offset(T_B)];
+    0xD000 = [Offset] => [CondReq(8), Offset(T_BCOND16)];
+    0xE000 = [Offset] => [Offset(T_B16)];
+]
+"bfc" = [
+    0xF36F_0000 = [R, Imm, Imm] => [R(8), BUbits(5), Slice(12, 3, 2), Slice(6, 2, 0), A, Msb(0)];
+]
+"bfi" = [
+    0xF360_0000 = [R, R, Imm, Imm] => [R(8), R(16), BUbits(5), Slice(12, 3, 2), Slice(6, 2, 0), A, Msb(0)];
+]
+"bic" = [
+    0xF020_0000 = [R, R, Imm] => [R(8), R(16), ModImmT];
+    0xEA20_0000 = [R, R, R, End, Shift] => [R(8), R(16), R(0), ShiftT];
+]
+"bics" = [
+    0xF030_0000 = [R, R, Imm] => [R(8), R(16), ModImmT];
+    0xEA30_0000 = [R, R, R, End, Shift] => [R(8), R(16), R(0), ShiftT];
+    0x4380 = [R, R] => [RLo(0), RLo(3)];
+]
+"bkpt" = [
+    0xBE00 = [Imm] => [Ubits(0, 8)];
+]
+"bl" = [
+    0xF000_D000 = [Offset] => [Offset(T_B)];
+]
+"blx" = [
+    0xF000_C000 = [Offset] => [Offset(T_BLX)];
+    0x4780 = [R] => [R(3)];
+]
+"bx" = [
+    0x4700 = [R] => [R(3)];
+]
+"cbnz" = [
+    0xB900 = [R, Offset] => [RLo(0), Offset(T_CBZ)];
+]
+"cbz" = [
+    0xB100 = [R, Offset] => [RLo(0), Offset(T_CBZ)];
+]
+"clrex" = [
+    0xF3BF_8F2F = [] => [];
+]
+"clz" = [
+    0xFAB0_F080 = [R, R] => [R(8), R(16), C, R(0)];
+]
+"cmn" = [
+    0xF110_0F00 = [R, Imm] => [R(16), ModImmT];
+    0xEB10_0F00 = [R, R, End, Shift] => [R(16), R(0), ShiftT];
+    0x42C0 = [R, R] => [RLo(0), RLo(3)];
+]
+"cmp" = [
+    0xF1B0_0F00 = [R, Imm] => [R(16), ModImmT];
+    0xEBB0_0F00 = [R, R, End, Shift] => [R(16), R(0), ShiftT];
+    0x2800 = [R, Imm] => [RLo(8), Ubits(0, 8)];
+    0x4280 = [R, R] => [RLo(0), RLo(3)];
+]
+"dmb" = [
+    0xF3BF_8F50 = [End, Ident] => [Barrier(0)];
+]
+"dsb" = [
+    0xF3BF_8F40 = [End, Ident] => [Barrier(0)];
+]
+"eor" = [
+    0xF080_0000 = [R, R, Imm] => [R(8), R(16), ModImmT];
+    0xEA80_0000 = [R, R, R, End, Shift] => [R(8), R(16), R(0), ShiftT];
+]
+"eors" = [
+    0xF090_0000 = [R, R, Imm] => [R(8), R(16), ModImmT];
+    0xEA90_0000 = [R, R, R, End, Shift] => [R(8), R(16), R(0), ShiftT];
+    0x4040 = [R, R] => [RLo(0), RLo(3)];
+]
+"isb" = [
+    0xF3BF_8F6F = [End, Ident] => [Is("sy")];
+]
+"it" = [
+    0xBF00 = [Ident] => [It(0, 0)];
+]
+"ite" = [
+    0xBF00 = [Ident] => [It(0, 1)];
+]
+"itee" = [
+    0xBF00 = [Ident] => [It(0, 2)];
+]
+"iteee" = [
+    0xBF00 = [Ident] => [It(0, 3)];
+]
+"iteet" = [
+    0xBF00 = [Ident] => [It(4, 3)];
+]
+"itet" = [
+    0xBF00 = [Ident] => [It(2, 2)];
+]
+"itete" = [
+    0xBF00 = [Ident] => [It(2, 3)];
+]
+"itett" = [
+    0xBF00 = [Ident] => [It(6, 3)];
+]
+"itt" = [
+    0xBF00 = [Ident] => [It(1, 1)];
+]
+"itte" = [
+    0xBF00 = [Ident] => [It(1, 2)];
+]
+"ittee" = [
+    0xBF00 = [Ident] => [It(1, 3)];
+]
+"ittet" = [
+    0xBF00 = [Ident] => [It(5, 3)];
+]
+"ittt" = [
+    0xBF00 = [Ident] => [It(3, 2)];
+]
+"ittte" = [
+    0xBF00 = [Ident] => [It(3, 3)];
+]
+"itttt" = [
+    0xBF00 = [Ident] => [It(7, 3)];
+]
+"ldm" = [
+    0xE890_0000 = [R, List] => [R(16), List(0xDFFF, 2)];
+    0xE8B0_0000 = [W, List] => [R(16), List(0xDFFF, 2)];
+    0xC800 = [W, List] => [RLo(8), List(0x00FF, 1)];
+]
+"ldmdb" = [
+    0xE910_0000 = [R, List] => [R(16), List(0xDFFF, 2)];
+    0xE930_0000 = [W, List] => [R(16), List(0xDFFF, 2)];
+]
+"ldmea" = [
+    0xE910_0000 = [R, List] => [R(16), List(0xDFFF, 2)];
+    0xE930_0000 = [W, List] => [R(16), List(0xDFFF, 2)];
+]
+"ldmfd" = [
+    0xE890_0000 = [R, List] => [R(16), List(0xDFFF, 2)];
+    0xE8B0_0000 = [W, List] => [R(16), List(0xDFFF, 2)];
+    0xC800 = [W, List] => [RLo(8), List(0x00FF, 1)];
+]
+"ldmia" = [
+    0xE890_0000 = [R, List] => [R(16), List(0xDFFF, 2)];
+    0xE8B0_0000 = [W, List] => [R(16), List(0xDFFF, 2)];
+    0xC800 = [W, List] => [RLo(8), List(0x00FF, 1)];
+]
+"ldr" = [
+    0xF850_0000 = [R, RefImm] => [R(12), R(16), ImmT12];
+    0xF850_0D00 = [R, RefPre] => [R(12), R(16), SignMag(9, 8, 0), MagSlice(0, 8, 0), A];
+    0xF850_0900 = [R, RefBase, Imm] => [R(12), R(16), SignMag(9, 8, 0), MagSlice(0, 8, 0), A];
+    0xF850_0000 = [R, RefReg] => [R(12), R(16), R(0), LslT(4)];
+    0xF85F_0000 = [R, Offset] => [R(12), Offset(T_LDR)];
+    0x4800 = [R, Offset] => [RLo(8), Offset(T_LDR16)];
+    0x5800 = [R, RefIdx] => [RLo(0), RLo(3), RLo(6)];
+    0x9800 = [R, RefSp] => [RLo(8), RIs(13), BUscaled(8, 2), Slice(0, 8, 2), A];
+    0x6800 = [R, RefImm] => [RLo(0), RLo(3), BUscaled(5, 2), Slice(6, 5, 2), A];
+]
+"ldrb" = [
+    0xF810_0000 = [R, RefImm] => [R(12), R(16), ImmT12];
+    0xF810_0D00 = [R, RefPre] => [R(12), R(16), SignMag(9, 8, 0), MagSlice(0, 8, 0), A];
+    0xF810_0900 = [R, RefBase, Imm] => [R(12), R(16), SignMag(9, 8, 0), MagSlice(0, 8, 0), A];
+    0xF810_0000 = [R, RefReg] => [R(12), R(16), R(0), LslT(4)];
+    0xF81F_0000 = [R, Offset] => [R(12), Offset(T_LDR)];
+    0x5C00 = [R, RefIdx] => [RLo(0), RLo(3), RLo(6)];
+    0x7800 = [R, RefImm] => [RLo(0), RLo(3), BUscaled(5, 0), Slice(6, 5, 0), A];
+]
+"ldrd" = [
+    0xE950_0000 = [R, R, RefImm] => [R(12), R(8), R(16), SignMag(23, 8, 2), MagSlice(0, 8, 2), A];
+    0xE970_0000 = [R, R, RefPre] => [R(12), R(8), R(16), SignMag(23, 8, 2), MagSlice(0, 8, 2), A];
+    0xE870_0000 = [R, R, RefBase, Imm] => [R(12), R(8), R(16), SignMag(23, 8, 2), MagSlice(0, 8, 2), A];
+    0xE95F_0000 = [R, R, Offset] => [R(12), R(8), Offset(T_VLDR)];
+]
+"ldrex" = [
+    0xE850_0F00 = [R, RefImm] => [R(12), R(16), BUbits(10), BUscaled(8, 2), Slice(0, 8, 2), A];
+]
+"ldrexb" = [
+    0xE8D0_0F4F = [R, RefBase] => [R(12), R(16)];
+]
+"ldrexd" = [
+    0xE8D0_007F = [R, R, RefBase] => [R(12), R(8), R(16)];
+]
+"ldrexh" = [
+    0xE8D0_0F5F = [R, RefBase] => [R(12), R(16)];
+]
+"ldrh" = [
+    0xF830_0000 = [R, RefImm] => [R(12), R(16), ImmT12];
+    0xF830_0D00 = [R, RefPre] => [R(12), R(16), SignMag(9, 8, 0), MagSlice(0, 8, 0), A];
+    0xF830_0900 = [R, RefBase, Imm] => [R(12), R(16), SignMag(9, 8, 0), MagSlice(0, 8, 0), A];
+    0xF830_0000 = [R, RefReg] => [R(12), R(16), R(0), LslT(4)];
+    0xF83F_0000 = [R, Offset] => [R(12), Offset(T_LDR)];
+    0x5A00 = [R, RefIdx] => [RLo(0), RLo(3), RLo(6)];
+    0x8800 = [R, RefImm] => [RLo(0), RLo(3), BUscaled(5, 1), Slice(6, 5, 1), A];
+]
+"ldrsb" = [
+    0xF910_0000 = [R, RefImm] => [R(12), R(16), ImmT12];
+    0xF910_0D00 = [R, RefPre] => [R(12), R(16), SignMag(9, 8, 0), MagSlice(0, 8, 0), A];
+    0xF910_0900 = [R, RefBase, Imm] => [R(12), R(16), SignMag(9, 8, 0), MagSlice(0, 8, 0), A];
+    0xF910_0000 = [R, RefReg] => [R(12), R(16), R(0), LslT(4)];
+    0xF91F_0000 = [R, Offset] => [R(12), Offset(T_LDR)];
+    0x5600 = [R, RefIdx] => [RLo(0), RLo(3), RLo(6)];
+]
+"ldrsh" = [
+    0xF930_0000 = [R, RefImm] => [R(12), R(16), ImmT12];
+    0xF930_0D00 = [R, RefPre] => [R(12), R(16), SignMag(9, 8, 0), MagSlice(0, 8, 0), A];
+    0xF930_0900 = [R, RefBase, Imm] => [R(12), R(16), SignMag(9, 8, 0), MagSlice(0, 8, 0), A];
+    0xF930_0000 = [R, RefReg] => [R(12), R(16), R(0), LslT(4)];
+    0xF93F_0000 = [R, Offset] => [R(12), Offset(T_LDR)];
+    0x5E00 = [R, RefIdx] => [RLo(0), RLo(3), RLo(6)];
+]
+"lsl" = [
+    0xEA4F_0000 = [R, R, Imm] => [R(8), R(0), AmountT(0)];
+    0xFA00_F000 = [R, R, R] => [R(8), R(16), R(0)];
+]
+"lsls" = [
+    0xEA5F_0000 = [R, R, Imm] => [R(8), R(0), AmountT(0)];
+    0xFA10_F000 = [R, R, R] => [R(8), R(16), R(0)];
+    0x0000 = [R, R, Imm] => [RLo(0), RLo(3), Amount(0, 6)];
+    0x4080 = [R, R] => [RLo(0), RLo(3)];
+]
+"lsr" = [
+    0xEA4F_0010 = [R, R, Imm] => [R(8), R(0), AmountT(1)];
+    0xFA20_F000 = [R, R, R] => [R(8), R(16), R(0)];
+]
+"lsrs" = [
+    0xEA5F_0010 = [R, R, Imm] => [R(8), R(0), AmountT(1)];
+    0xFA30_F000 = [R, R, R] => [R(8), R(16), R(0)];
+    0x0800 = [R, R, Imm] => [RLo(0), RLo(3), Amount(1, 6)];
+    0x40C0 = [R, R] => [RLo(0), RLo(3)];
+]
+"mla" = [
+    0xFB00_0000 = [R, R, R, R] => [R(8), R(16), R(0), R(12)];
+]
+"mls" = [
+    0xFB00_0010 = [R, R, R, R] => [R(8), R(16), R(0), R(12)];
+]
+"mov" = [
+    0xF04F_0000 = [R, Imm] => [R(8), ModImmT];
+    0xEA4F_0000 = [R, R, End, Shift] => [R(8), R(0), ShiftT];
+    0x4600 = [R, R] => [RSplit(0, 7), R(3)];
+]
+"movs" = [
+    0xF05F_0000 = [R, Imm] => [R(8), ModImmT];
+    0xEA5F_0000 = [R, R, End, Shift] => [R(8), R(0), ShiftT];
+    0x2000 = [R, Imm] => [RLo(8), Ubits(0, 8)];
+    0x0000 = [R, R] => [RLo(0), RLo(3)];
+]
+"movt" = [
+    0xF2C0_0000 = [R, Imm] => [R(8), BUbits(16), Slice(16, 4, 12), Slice(26, 1, 11), Slice(12, 3, 8), Slice(0, 8, 0), A];
+]
+"movw" = [
+    0xF240_0000 = [R, Imm] => [R(8), BUbits(16), Slice(16, 4, 12), Slice(26, 1, 11), Slice(12, 3, 8), Slice(0, 8, 0), A];
+]
+"mrs" = [
+    0xF3EF_8000 = [R, Ident] => [R(8), Is("apsr")];
+]
+"msr" = [
+    0xF380_8000 = [Ident, R] => [PsrMask(10), R(16)];
+]
+"mul" = [
+    0xFB00_F000 = [R, R, R] => [R(8), R(16), R(0)];
+]
+"muls" = [
+    0x4340 = [R, R] => [RLo(0), RLo(3)];
+]
+"mvn" = [
+    0xF06F_0000 = [R, Imm] => [R(8), ModImmT];
+    0xEA6F_0000 = [R, R, End, Shift] => [R(8), R(0), ShiftT];
+]
+"mvns" = [
+    0xF07F_0000 = [R, Imm] => [R(8), ModImmT];
+    0xEA7F_0000 = [R, R, End, Shift] => [R(8), R(0), ShiftT];
+    0x43C0 = [R, R] => [RLo(0), RLo(3)];
+]
+"nop" = [
+    0xF3AF_8000 = [] => [];
+    0xBF00 = [] => [];
+]
+"orn" = [
+    0xF060_0000 = [R, R, Imm] => [R(8), R(16), ModImmT];
+    0xEA60_0000 = [R, R, R, End, Shift] => [R(8), R(16), R(0), ShiftT];
+]
+"orns" = [
+    0xF070_0000 = [R, R, Imm] => [R(8), R(16), ModImmT];
+    0xEA70_0000 = [R, R, R, End, Shift] => [R(8), R(16), R(0), ShiftT];
+]
+"orr" = [
+    0xF040_0000 = [R, R, Imm] => [R(8), R(16), ModImmT];
+    0xEA40_0000 = [R, R, R, End, Shift] => [R(8), R(16), R(0), ShiftT];
+]
+"orrs" = [
+    0xF050_0000 = [R, R, Imm] => [R(8), R(16), ModImmT];
+    0xEA50_0000 = [R, R, R, End, Shift] => [R(8), R(16), R(0), ShiftT];
+    0x4300 = [R, R] => [RLo(0), RLo(3)];
+]
+"pop" = [
+    0xE8BD_0000 = [List] => [List(0xDFFF, 2)];
+    0xBC00 = [List] => [ListN(15)];
+]
+"push" = [
+    0xE92D_0000 = [List] => [List(0x5FFF, 2)];
+    0xB400 = [List] => [ListN(14)];
+]
+"rbit" = [
+    0xFA90_F0A0 = [R, R] => [R(8), R(16), C, R(0)];
+]
+"rev" = [
+    0xFA90_F080 = [R, R] => [R(8), R(16), C, R(0)];
+    0xBA00 = [R, R] => [RLo(0), RLo(3)];
+]
+"rev16" = [
+    0xFA90_F090 = [R, R] => [R(8), R(16), C, R(0)];
+    0xBA40 = [R, R] => [RLo(0), RLo(3)];
+]
+"revsh" = [
+    0xFA90_F0B0 = [R, R] => [R(8), R(16), C, R(0)];
+    0xBAC0 = [R, R] => [RLo(0), RLo(3)];
+]
+"ror" = [
+    0xEA4F_0030 = [R, R, Imm] => [R(8), R(0), AmountT(3)];
+    0xFA60_F000 = [R, R, R] => [R(8), R(16), R(0)];
+]
+"rors" = [
+    0xEA5F_0030 = [R, R, Imm] => [R(8), R(0), AmountT(3)];
+    0xFA70_F000 = [R, R, R] => [R(8), R(16), R(0)];
+    0x41C0 = [R, R] => [RLo(0), RLo(3)];
+]
+"rrx" = [
+    0xEA4F_0030 = [R, R] => [R(8), R(0)];
+]
+"rrxs" = [
+    0xEA5F_0030 = [R, R] => [R(8), R(0)];
+]
+"rsb" = [
+    0xF1C0_0000 = [R, R, Imm] => [R(8), R(16), ModImmT];
+    0xEBC0_0000 = [R, R, R, End, Shift] => [R(8), R(16), R(0), ShiftT];
+]
+"rsbs" = [
+    0xF1D0_0000 = [R, R, Imm] => [R(8), R(16), ModImmT];
+    0xEBD0_0000 = [R, R, R, End, Shift] => [R(8), R(16), R(0), ShiftT];
+    0x4240 = [R, R, Imm] => [RLo(0), RLo(3), Zero];
+]
+"sbc" = [
+    0xF160_0000 = [R, R, Imm] => [R(8), R(16), ModImmT];
+    0xEB60_0000 = [R, R, R, End, Shift] => [R(8), R(16), R(0), ShiftT];
+]
+"sbcs" = [
+    0xF170_0000 = [R, R, Imm] => [R(8), R(16), ModImmT];
+    0xEB70_0000 = [R, R, R, End, Shift] => [R(8), R(16), R(0), ShiftT];
+    0x4180 = [R, R] => [RLo(0), RLo(3)];
+]
+"sbfx" = [
+    0xF340_0000 = [R, R, Imm, Imm] => [R(8), R(16), BUbits(5), Slice(12, 3, 2), Slice(6, 2, 0), A, Urange(0, 1, 32)];
+]
+"sdiv" = [
+    0xFB90_F0F0 = [R, R, R] => [R(8), R(16), R(0)], IDIV;
+]
+"sev" = [
+    0xF3AF_8004 = [] => [];
+    0xBF40 = [] => [];
+]
+"smlal" = [
+    0xFBC0_0000 = [R, R, R, R] => [R(12), R(8), R(16), R(0)];
+]
+"smull" = [
+    0xFB80_0000 = [R, R, R, R] => [R(12), R(8), R(16), R(0)];
+]
+"stm" = [
+    0xE880_0000 = [R, List] => [R(16), List(0x5FFF, 2)];
+    0xE8A0_0000 = [W, List] => [R(16), List(0x5FFF, 2)];
+    0xC000 = [W, List] => [RLo(8), List(0x00FF, 1)];
+]
+"stmdb" = [
+    0xE900_0000 = [R, List] => [R(16), List(0x5FFF, 2)];
+    0xE920_0000 = [W, List] => [R(16), List(0x5FFF, 2)];
+]
+"stmea" = [
+    0xE880_0000 = [R, List] => [R(16), List(0x5FFF, 2)];
+    0xE8A0_0000 = [W, List] => [R(16), List(0x5FFF, 2)];
+    0xC000 = [W, List] => [RLo(8), List(0x00FF, 1)];
+]
+"stmfd" = [
+    0xE900_0000 = [R, List] => [R(16), List(0x5FFF, 2)];
+    0xE920_0000 = [W, List] => [R(16), List(0x5FFF, 2)];
+]
+"stmia" = [
+    0xE880_0000 = [R, List] => [R(16), List(0x5FFF, 2)];
+    0xE8A0_0000 = [W, List] => [R(16), List(0x5FFF, 2)];
+    0xC000 = [W, List] => [RLo(8), List(0x00FF, 1)];
+]
+"str" = [
+    0xF840_0000 = [R, RefImm] => [R(12), R(16), ImmT12];
+    0xF840_0D00 = [R, RefPre] => [R(12), R(16), SignMag(9, 8, 0), MagSlice(0, 8, 0), A];
+    0xF840_0900 = [R, RefBase, Imm] => [R(12), R(16), SignMag(9, 8, 0), MagSlice(0, 8, 0), A];
+    0xF840_0000 = [R, RefReg] => [R(12), R(16), R(0), LslT(4)];
+    0x5000 = [R, RefIdx] => [RLo(0), RLo(3), RLo(6)];
+    0x9000 = [R, RefSp] => [RLo(8), RIs(13), BUscaled(8, 2), Slice(0, 8, 2), A];
+    0x6000 = [R, RefImm] => [RLo(0), RLo(3), BUscaled(5, 2), Slice(6, 5, 2), A];
+]
+"strb" = [
+    0xF800_0000 = [R, RefImm] => [R(12), R(16), ImmT12];
+    0xF800_0D00 = [R, RefPre] => [R(12), R(16), SignMag(9, 8, 0), MagSlice(0, 8, 0), A];
+    0xF800_0900 = [R, RefBase, Imm] => [R(12), R(16), SignMag(9, 8, 0), MagSlice(0, 8, 0), A];
+    0xF800_0000 = [R, RefReg] => [R(12), R(16), R(0), LslT(4)];
+    0x5400 = [R, RefIdx] => [RLo(0), RLo(3), RLo(6)];
+    0x7000 = [R, RefImm] => [RLo(0), RLo(3), BUscaled(5, 0), Slice(6, 5, 0), A];
+]
+"strd" = [
+    0xE940_0000 = [R, R, RefImm] => [R(12), R(8), R(16), SignMag(23, 8, 2), MagSlice(0, 8, 2), A];
+    0xE960_0000 = [R, R, RefPre] => [R(12), R(8), R(16), SignMag(23, 8, 2), MagSlice(0, 8, 2), A];
+    0xE860_0000 = [R, R, RefBase, Imm] => [R(12), R(8), R(16), SignMag(23, 8, 2), MagSlice(0, 8, 2), A];
+]
+"strex" = [
+    0xE840_0000 = [R, R, RefImm] => [R(8), R(12), R(16), BUbits(10), BUscaled(8, 2), Slice(0, 8, 2), A];
+]
+"strexb" = [
+    0xE8C0_0F40 = [R, R, RefBase] => [R(0), R(12), R(16)];
+]
+"strexd" = [
+    0xE8C0_0070 = [R, R, R, RefBase] => [R(0), R(12), R(8), R(16)];
+]
+"strexh" = [
+    0xE8C0_0F50 = [R, R, RefBase] => [R(0), R(12), R(16)];
+]
+"strh" = [
+    0xF820_0000 = [R, RefImm] => [R(12), R(16), ImmT12];
+    0xF820_0D00 = [R, RefPre] => [R(12), R(16), SignMag(9, 8, 0), MagSlice(0, 8, 0), A];
+    0xF820_0900 = [R, RefBase, Imm] => [R(12), R(16), SignMag(9, 8, 0), MagSlice(0, 8, 0), A];
+    0xF820_0000 = [R, RefReg] => [R(12), R(16), R(0), LslT(4)];
+    0x5200 = [R, RefIdx] => [RLo(0), RLo(3), RLo(6)];
+    0x8000 = [R, RefImm] => [RLo(0), RLo(3), BUscaled(5, 1), Slice(6, 5, 1), A];
+]
+"sub" = [
+    0xF1A0_0000 = [R, R, Imm] => [R(8), R(16), ModImmT];
+    0xEBA0_0000 = [R, R, R, End, Shift] => [R(8), R(16), R(0), ShiftT];
+    0xB080 = [Sp, Sp, Imm] => [RIs(13), RIs(13), BUscaled(7, 2), Slice(0, 7, 2), A];
+]
+"subs" = [
+    0xF1B0_0000 = [R, R, Imm] => [R(8), R(16), ModImmT];
+    0xEBB0_0000 = [R, R, R, End, Shift] => [R(8), R(16), R(0), ShiftT];
+    0x1A00 = [R, R, R] => [RLo(0), RLo(3), RLo(6)];
+    0x1E00 = [R, R, Imm] => [RLo(0), RLo(3), Ubits(6, 3)];
+    0x3800 = [R, Imm] => [RLo(8), Ubits(0, 8)];
+]
+"subw" = [
+    0xF2A0_0000 = [R, R, Imm] => [R(8), R(16), BUbits(12), Slice(26, 1, 11), Slice(12, 3, 8), Slice(0, 8, 0), A];
+]
+"svc" = [
+    0xDF00 = [Imm] => [Ubits(0, 8)];
+]
+"sxtb" = [
+    0xFA4F_F080 = [R, R, End, Shift] => [R(8), R(0), Rotate(4)];
+    0xB240 = [R, R] => [RLo(0), RLo(3)];
+]
+"sxth" = [
+    0xFA0F_F080 = [R, R, End, Shift] => [R(8), R(0), Rotate(4)];
+    0xB200 = [R, R] => [RLo(0), RLo(3)];
+]
+"teq" = [
+    0xF090_0F00 = [R, Imm] => [R(16), ModImmT];
+    0xEA90_0F00 = [R, R, End, Shift] => [R(16), R(0), ShiftT];
+]
+"tst" = [
+    0xF010_0F00 = [R, Imm] => [R(16), ModImmT];
+    0xEA10_0F00 = [R, R, End, Shift] => [R(16), R(0), ShiftT];
+    0x4200 = [R, R] => [RLo(0), RLo(3)];
+]
+"ubfx" = [
+    0xF3C0_0000 = [R, R, Imm, Imm] => [R(8), R(16), BUbits(5), Slice(12, 3, 2), Slice(6, 2, 0), A, Urange(0, 1, 32)];
+]
+"udf" = [
+    0xF7F0_A000 = [Imm] => [BUbits(16), Slice(16, 4, 12), Slice(0, 12, 0), A];
+    0xDE00 = [Imm] => [Ubits(0, 8)];
+]
+"udiv" = [
+    0xFBB0_F0F0 = [R, R, R] => [R(8), R(16), R(0)], IDIV;
+]
+"umlal" = [
+    0xFBE0_0000 = [R, R, R, R] => [R(12), R(8), R(16), R(0)];
+]
+"umull" = [
+    0xFBA0_0000 = [R, R, R, R] => [R(12), R(8), R(16), R(0)];
+]
+"uxtb" = [
+    0xFA5F_F080 = [R, R, End, Shift] => [R(8), R(0), Rotate(4)];
+    0xB2C0 = [R, R] => [RLo(0), RLo(3)];
+]
+"uxth" = [
+    0xFA1F_F080 = [R, R, End, Shift] => [R(8), R(0), Rotate(4)];
+    0xB280 = [R, R] => [RLo(0), RLo(3)];
+]
+"vabs.f32" = [
+    0xEEB0_0AC0 = [S, S] => [Sreg(12, 22), Sreg(0, 5)], VFP;
+]
+"vabs.f64" = [
+    0xEEB0_0BC0 = [D, D] => [Dreg(12, 22), Dreg(0, 5)], VFP;
+]
+"vadd.f32" = [
+    0xEE30_0A00 = [S, S, S] => [Sreg(12, 22), Sreg(16, 7), Sreg(0, 5)], VFP;
+]
+"vadd.f64" = [
+    0xEE30_0B00 = [D, D, D] => [Dreg(12, 22), Dreg(16, 7), Dreg(0, 5)], VFP;
+]
+"vcmp.f32" = [
+    0xEEB4_0A40 = [S, S] => [Sreg(12, 22), Sreg(0, 5)], VFP;
+    0xEEB5_0A40 = [S, Imm] => [Sreg(12, 22), Zero], VFP;
+]
+"vcmp.f64" = [
+    0xEEB4_0B40 = [D, D] => [Dreg(12, 22), Dreg(0, 5)], VFP;
+    0xEEB5_0B40 = [D, Imm] => [Dreg(12, 22), Zero], VFP;
+]
+"vcmpe.f32" = [
+    0xEEB4_0AC0 = [S, S] => [Sreg(12, 22), Sreg(0, 5)], VFP;
+    0xEEB5_0AC0 = [S, Imm] => [Sreg(12, 22), Zero], VFP;
+]
+"vcmpe.f64" = [
+    0xEEB4_0BC0 = [D, D] => [Dreg(12, 22), Dreg(0, 5)], VFP;
+    0xEEB5_0BC0 = [D, Imm] => [Dreg(12, 22), Zero], VFP;
+]
+"vcvt.f32.f64" = [
+    0xEEB7_0BC0 = [S, D] => [Sreg(12, 22), Dreg(0, 5)], VFP;
+]
+"vcvt.f32.s32" = [
+    0xEEB8_0AC0 = [S, S] => [Sreg(12, 22), Sreg(0, 5)], VFP;
+]
+"vcvt.f32.u32" = [
+    0xEEB8_0A40 = [S, S] => [Sreg(12, 22), Sreg(0, 5)], VFP;
+]
+"vcvt.f64.f32" = [
+    0xEEB7_0AC0 = [D, S] => [Dreg(12, 22), Sreg(0, 5)], VFP;
+]
+"vcvt.f64.s32" = [
+    0xEEB8_0BC0 = [D, S] => [Dreg(12, 22), Sreg(0, 5)], VFP;
+]
+"vcvt.f64.u32" = [
+    0xEEB8_0B40 = [D, S] => [Dreg(12, 22), Sreg(0, 5)], VFP;
+]
+"vcvt.s32.f32" = [
+    0xEEBD_0AC0 = [S, S] => [Sreg(12, 22), Sreg(0, 5)], VFP;
+]
+"vcvt.s32.f64" = [
+    0xEEBD_0BC0 = [S, D] => [Sreg(12, 22), Dreg(0, 5)], VFP;
+]
+"vcvt.u32.f32" = [
+    0xEEBC_0AC0 = [S, S] => [Sreg(12, 22), Sreg(0, 5)], VFP;
+]
+"vcvt.u32.f64" = [
+    0xEEBC_0BC0 = [S, D] => [Sreg(12, 22), Dreg(0, 5)], VFP;
+]
+"vdiv.f32" = [
+    0xEE80_0A00 = [S, S, S] => [Sreg(12, 22), Sreg(16, 7), Sreg(0, 5)], VFP;
+]
+"vdiv.f64" = [
+    0xEE80_0B00 = [D, D, D] => [Dreg(12, 22), Dreg(16, 7), Dreg(0, 5)], VFP;
+]
+"vldm" = [
+    0xEC90_0B00 = [R, DList] => [R(16), DList], VFP;
+    0xECB0_0B00 = [W, DList] => [R(16), DList], VFP;
+    0xEC90_0A00 = [R, SList] => [R(16), SList], VFP;
+    0xECB0_0A00 = [W, SList] => [R(16), SList], VFP;
+]
+"vldmdb" = [
+    0xED30_0B00 = [W, DList] => [R(16), DList], VFP;
+    0xED30_0A00 = [W, SList] => [R(16), SList], VFP;
+]
+"vldmia" = [
+    0xEC90_0B00 = [R, DList] => [R(16), DList], VFP;
+    0xECB0_0B00 = [W, DList] => [R(16), DList], VFP;
+    0xEC90_0A00 = [R, SList] => [R(16), SList], VFP;
+    0xECB0_0A00 = [W, SList] => [R(16), SList], VFP;
+]
+"vldr" = [
+    0xED10_0A00 = [S, RefImm] => [Sreg(12, 22), R(16), SignMag(23, 8, 2), MagSlice(0, 8, 2), A], VFP;
+    0xED10_0B00 = [D, RefImm] => [Dreg(12, 22), R(16), SignMag(23, 8, 2), MagSlice(0, 8, 2), A], VFP;
+    0xED1F_0A00 = [S, Offset] => [Sreg(12, 22), Offset(T_VLDR)], VFP;
+    0xED1F_0B00 = [D, Offset] => [Dreg(12, 22), Offset(T_VLDR)], VFP;
+]
+"vmla.f32" = [
+    0xEE00_0A00 = [S, S, S] => [Sreg(12, 22), Sreg(16, 7), Sreg(0, 5)], VFP;
+]
+"vmla.f64" = [
+    0xEE00_0B00 = [D, D, D] => [Dreg(12, 22), Dreg(16, 7), Dreg(0, 5)], VFP;
+]
+"vmls.f32" = [
+    0xEE00_0A40 = [S, S, S] => [Sreg(12, 22), Sreg(16, 7), Sreg(0, 5)], VFP;
+]
+"vmls.f64" = [
+    0xEE00_0B40 = [D, D, D] => [Dreg(12, 22), Dreg(16, 7), Dreg(0, 5)], VFP;
+]
+"vmov" = [
+    0xEE00_0A10 = [S, R] => [Sreg(16, 7), R(12)], VFP;
+    0xEE10_0A10 = [R, S] => [R(12), Sreg(16, 7)], VFP;
+    0xEC40_0B10 = [D, R, R] => [Dreg(0, 5), R(12), R(16)], VFP;
+    0xEC50_0B10 = [R, R, D] => [R(12), R(16), Dreg(0, 5)], VFP;
+]
+"vmov.f32" = [
+    0xEEB0_0A40 = [S, S] => [Sreg(12, 22), Sreg(0, 5)], VFP;
+    0xEEB0_0A00 = [S, Imm] => [Sreg(12, 22), FpImm], VFP;
+]
+"vmov.f64" = [
+    0xEEB0_0B40 = [D, D] => [Dreg(12, 22), Dreg(0, 5)], VFP;
+    0xEEB0_0B00 = [D, Imm] => [Dreg(12, 22), FpImm], VFP;
+]
+"vmrs" = [
+    0xEEF1_0A10 = [R, Ident] => [R(12), Is("fpscr")], VFP;
+    0xEEF1_FA10 = [Ident, Ident] => [Is("apsr_nzcv"), Is("fpscr")], VFP;
+]
+"vmsr" = [
+    0xEEE1_0A10 = [Ident, R] => [Is("fpscr"), R(12)], VFP;
+]
+"vmul.f32" = [
+    0xEE20_0A00 = [S, S, S] => [Sreg(12, 22), Sreg(16, 7), Sreg(0, 5)], VFP;
+]
+"vmul.f64" = [
+    0xEE20_0B00 = [D, D, D] => [Dreg(12, 22), Dreg(16, 7), Dreg(0, 5)], VFP;
+]
+"vneg.f32" = [
+    0xEEB1_0A40 = [S, S] => [Sreg(12, 22), Sreg(0, 5)], VFP;
+]
+"vneg.f64" = [
+    0xEEB1_0B40 = [D, D] => [Dreg(12, 22), Dreg(0, 5)], VFP;
+]
+"vnmla.f32" = [
+    0xEE10_0A40 = [S, S, S] => [Sreg(12, 22), Sreg(16, 7), Sreg(0, 5)], VFP;
+]
+"vnmla.f64" = [
+    0xEE10_0B40 = [D, D, D] => [Dreg(12, 22), Dreg(16, 7), Dreg(0, 5)], VFP;
+]
+"vnmls.f32" = [
+    0xEE10_0A00 = [S, S, S] => [Sreg(12, 22), Sreg(16, 7), Sreg(0, 5)], VFP;
+]
+"vnmls.f64" = [
+    0xEE10_0B00 = [D, D, D] => [Dreg(12, 22), Dreg(16, 7), Dreg(0, 5)], VFP;
+]
+"vnmul.f32" = [
+    0xEE20_0A40 = [S, S, S] => [Sreg(12, 22), Sreg(16, 7), Sreg(0, 5)], VFP;
+]
+"vnmul.f64" = [
+    0xEE20_0B40 = [D, D, D] => [Dreg(12, 22), Dreg(16, 7), Dreg(0, 5)], VFP;
+]
+"vpop" = [
+    0xECBD_0B00 = [DList] => [DList], VFP;
+    0xECBD_0A00 = [SList] => [SList], VFP;
+]
+"vpush" = [
+    0xED2D_0B00 = [DList] => [DList], VFP;
+    0xED2D_0A00 = [SList] => [SList], VFP;
+]
+"vsqrt.f32" = [
+    0xEEB1_0AC0 = [S, S] => [Sreg(12, 22), Sreg(0, 5)], VFP;
+]
+"vsqrt.f64" = [
+    0xEEB1_0BC0 = [D, D] => [Dreg(12, 22), Dreg(0, 5)], VFP;
+]
+"vstm" = [
+    0xEC80_0B00 = [R, DList] => [R(16), DList], VFP;
+    0xECA0_0B00 = [W, DList] => [R(16), DList], VFP;
+    0xEC80_0A00 = [R, SList] => [R(16), SList], VFP;
+    0xECA0_0A00 = [W, SList] => [R(16), SList], VFP;
+]
+"vstmdb" = [
+    0xED20_0B00 = [W, DList] => [R(16), DList], VFP;
+    0xED20_0A00 = [W, SList] => [R(16), SList], VFP;
+]
+"vstmia" = [
+    0xEC80_0B00 = [R, DList] => [R(16), DList], VFP;
+    0xECA0_0B00 = [W, DList] => [R(16), DList], VFP;
+    0xEC80_0A00 = [R, SList] => [R(16), SList], VFP;
+    0xECA0_0A00 = [W, SList] => [R(16), SList], VFP;
+]
+"vstr" = [
+    0xED00_0A00 = [S, RefImm] => [Sreg(12, 22), R(16), SignMag(23, 8, 2), MagSlice(0, 8, 2), A], VFP;
+    0xED00_0B00 = [D, RefImm] => [Dreg(12, 22), R(16), SignMag(23, 8, 2), MagSlice(0, 8, 2), A], VFP;
+]
+"vsub.f32" = [
+    0xEE30_0A40 = [S, S, S] => [Sreg(12, 22), Sreg(16, 7), Sreg(0, 5)], VFP;
+]
+"vsub.f64" = [
+    0xEE30_0B40 = [D, D, D] => [Dreg(12, 22), Dreg(16, 7), Dreg(0, 5)], VFP;
+]
+"wfe" = [
+    0xF3AF_8002 = [] => [];
+    0xBF20 = [] => [];
+]
+"wfi" = [
+    0xF3AF_8003 = [] => [];
+    0xBF30 = [] => [];
+]
+"yield" = [
+    0xF3AF_8001 = [] => [];
+    0xBF10 = [] => [];
+]
+)
@@ -0,0 +1,476 @@
+use syn::{parse, Token};
+
+use lazy_static::lazy_static;
+
+use crate::parse_helpers::{parse_ident_or_rust_keyword, ParseOptExt};
+
+use super::Context;
+use super::ast::{Instruction, RawArg, RegId, RegKind, RegFamily, RefIndex, ShiftKind, ShiftAmount};
+
+use std::collections::HashMap;
+
+// parses a full instruction
+// syntax for a single op: ident ("." ident)* (arg ("," arg)*)? ";"
+pub(super) fn parse_instruction(ctx: &mut Context, input: parse::ParseStream) -> parse::Result<(Instruction, Vec<RawArg>)> {
+    let span = input.cursor().span();
+
+    // read the full dot-separated op. Condition codes and width qualifiers are split off during matching.
+    let mut name = parse_ident_or_rust_keyword(input)?.to_string();
+
+    while input.peek(Token![.]) {
+        let _: Token![.] = input.parse()?;
+        let part = parse_ident_or_rust_keyword(input)?;
+        name.push('.');
+        name.push_str(&part.to_string());
+    }
+
+    let mut args = Vec::new();
+
+    // parse 0 or more comma-separated args
+    if !(input.is_empty() || input.peek(Token![;])) {
+        args.push(parse_arg(ctx, input)?);
+
+        while input.peek(Token![,]) {
+            let _: Token![,] = input.parse()?;
+
+            args.push(parse_arg(ctx, input)?);
+        }
+    }
+
+    Ok((
+        Instruction {
+            span,
+            name
+        },
+        args
+    ))
+}
+
+/// tries to parse a full arg definition
+fn parse_arg(ctx: &mut Context, input: parse::ParseStream) -> parse::Result<RawArg> {
+    let _start = input.cursor().span(); // FIXME can't join spans yet
+
+    // a label
+    if let Some(jump) = input.parse_opt()? {
+        return Ok(RawArg::JumpTarget {
+            jump
+        });
+    }
+
+    // reference
+    if input.peek(syn::token::Bracket) {
+        let span = input.cursor().span();
+        let inner;
+        let _ = syn::bracketed!(inner in input);
+        let inner = &inner;
+
+        let base = parse_reg(ctx, inner)?.ok_or_else(|| inner.error("Expected a base register"))?;
+
+        let index = if inner.peek(Token![,]) {
+            let _: Token![,] = inner.parse()?;
+
+            if let Some((reg, subtract)) = parse_signed_reg(ctx, inner)? {
+                let shift = if inner.peek(Token![,]) {
+                    let _: Token![,] = inner.parse()?;
+                    let (kind, amount) = parse_shift(inner)?.ok_or_else(|| inner.error("Expected a shift"))?;
+                    Some((kind, amount))
+                } else {
+                    None
+                };
+
+                RefIndex::Reg {
+                    reg,
+                    subtract,
+                    shift
+                }
+            } else {
+                if inner.peek(Token![#]) {
+                    let _: Token![#] = inner.parse()?;
+                }
+                RefIndex::Imm(inner.parse()?)
+            }
+        } else {
+            RefIndex::None
+        };
+
+        if !inner.is_empty() {
+            return Err(inner.error("Expected the end of the reference"));
+        }
+
+        let writeback = eat_writeback(input)?;
+
+        return Ok(RawArg::Reference {
+            span,
+            base,
+            index,
+            writeback
+        });
+    }
+
+    // register list
+    if input.peek(syn::token::Brace) {
+        let span = input.cursor().span();
+        let inner;
+        let _ = syn::braced!(inner in input);
+        let inner = &inner;
+
+        let regs = parse_reg_list(ctx, inner)?;
+
+        return Ok(RawArg::List {
+            span,
+            regs
+        });
+    }
+
+    // shift
+    if let Some((kind, amount)) = parse_shift_modifier(ctx, input)? {
+        return Ok(RawArg::Shift {
+            span: _start,
+            kind,
+            amount
+        });
+    }
+
+    // immediate (arm notation)
+    if input.peek(Token![#]) {
+        let _: Token![#] = input.parse()?;
+        let arg: syn::Expr = input.parse()?;
+        return Ok(RawArg::Immediate {
+            value: arg
+        });
+    }
+
+    // register
+    if let Some((reg, subtract)) = parse_signed_reg(ctx, input)? {
+        let writeback = eat_writeback(input)?;
+        return Ok(RawArg::Direct {
+            span: _start,
+            reg,
+            subtract,
+            writeback
+        })
+    }
+
+    // immediate
+    let arg: syn::Expr = input.parse()?;
+    Ok(RawArg::Immediate {
+        value: arg
+    })
+}
+
+/// parses an optional "!" writeback marker
+fn eat_writeback(input: parse::ParseStream) -> parse::Result<bool> {
+    if input.peek(Token![!]) {
+        let _: Token![!] = input.parse()?;
+        Ok(true)
+    } else {
+        Ok(false)
+    }
+}
+
+/// parses a register that is optionally preceded by a + or - sign.
+fn parse_signed_reg(ctx: &mut Context, input: parse::ParseStream) -> parse::Result<Option<(RegKind, bool)>> {
+    let subtract = if input.peek(Token![-]) || input.peek(Token![+]) {
+        // only a sign if it's followed by a register
+        let fork = input.fork();
+        let subtract = fork.peek(Token![-]);
+        if subtract {
+            let _: Token![-] = fork.parse()?;
+        } else {
+            let _: Token![+] = fork.parse()?;
+        }
+        if parse_reg(ctx, &fork)?.is_none() {
+            return Ok(None);
+        }
+
+        if subtract {
+            let _: Token![-] = input.parse()?;
+        } else {
+            let _: Token![+] = input.parse()?;
+        }
+        subtract
+    } else {
+        false
+    };
+
+    Ok(parse_reg(ctx, input)?.map(|reg| (reg, subtract)))
+}
+
+/// parses a shift applied to a register: lsl/lsr/asr/ror followed by either an immediate or a register, or rrx.
+fn parse_shift_modifier(ctx: &mut Context, input: parse::ParseStream) -> parse::Result<Option<(ShiftKind, ShiftAmount)>> {
+    let kind = match parse_shift_kind(input) {
+        Some(kind) => kind,
+        None => return Ok(None)
+    };
+
+    if kind == ShiftKind::RRX {
+        return Ok(Some((kind, ShiftAmount::None)));
+    }
+
+    if input.peek(Token![#]) {
+        let _: Token![#] = input.parse()?;
+    } else if let Some(reg) = parse_reg(ctx, input)? {
+        return Ok(Some((kind, ShiftAmount::Reg(reg))));
+    }
+
+    let amount: syn::Expr = input.parse()?;
+    Ok(Some((kind, ShiftAmount::Imm(amount))))
+}
+
+/// parses a shift by an immediate, as used in references
+fn parse_shift(input: parse::ParseStream) -> parse::Result<Option<(ShiftKind, Option<syn::Expr>)>> {
+    let kind = match parse_shift_kind(input) {
+        Some(kind) => kind,
+        None => return Ok(None)
+    };
+
+    if kind == ShiftKind::RRX {
+        return Ok(Some((kind, None)));
+    }
+
+    if input.peek(Token![#]) {
+        let _: Token![#] = input.parse()?;
+    }
+    let amount: syn::Expr = input.parse()?;
+    Ok(Some((kind, Some(amount))))
+}
+
+fn parse_shift_kind(input: parse::ParseStream) -> Option<ShiftKind> {
+    input.step(|cursor| {
+        if let Some((ident, rest)) = cursor.ident() {
+            let kind = match &*ident.to_string() {
+                "lsl" => ShiftKind::LSL,
+                "lsr" => ShiftKind::LSR,
+                "asr" => ShiftKind::ASR,
+                "ror" => ShiftKind::ROR,
+                "rrx" => ShiftKind::RRX,
+                _ => return Err(cursor.error("expected shift"))
+            };
+
+            // a shift is always followed by its amount, unless it's rrx
+            if kind != ShiftKind::RRX && (rest.eof() || rest.punct().map_or(false, |(p, _)| p.as_char() == ',')) {
+                return Err(cursor.error("expected shift"));
+            }
+            return Ok((kind, rest));
+        }
+        Err(cursor.error("expected shift"))
+    }).ok()
+}
+
+/// parses the contents of a register list: register ("-" register)? ("," register ("-" register)?)*
+fn parse_reg_list(ctx: &mut Context, input: parse::ParseStream) -> parse::Result<Vec<RegId>> {
+    let mut regs: Vec<RegId> = Vec::new();
+
+    while !input.is_empty() {
+        let span = input.cursor().span();
+        let first = parse_static_reg(ctx, input)?;
+
+        let last = if input.peek(Token![-]) {
+            let _: Token![-] = input.parse()?;
+            let last = parse_static_reg(ctx, input)?;
+            if last.family() != first.family() || last.code() < first.code() {
+                return Err(parse::Error::new(span, "Invalid register range"));
+            }
+            last
+        } else {
+            first
+        };
+
+        for code in first.code() ..= last.code() {
+            let reg = RegId::from_parts(first.family(), code);
+            if regs.contains(&reg) {
+                return Err(parse::Error::new(span, "Duplicate register in register list"));
+            }
+            regs.push(reg);
+        }
+
+        if input.is_empty() {
+            break;
+        }
+        let _: Token![,] = input.parse()?;
+    }
+
+    if regs.is_empty() {
+        return Err(input.error("Expected at least one register"));
+    }
+
+    if regs.iter().any(|r| r.family() != regs[0].family()) {
+        return Err(input.error("Registers in a register list have to be of the same type"));
+    }
+
+    Ok(regs)
+}
+
+fn parse_static_reg(ctx: &mut Context, input: parse::ParseStream) -> parse::Result<RegId> {
+    match parse_reg(ctx, input)? {
+        Some(RegKind::Static(id)) => Ok(id),
+        Some(RegKind::Dynamic(_, _)) => Err(input.error("Register lists cannot contain dynamic registers")),
+        None => Err(input.error("Expected a register"))
+    }
+}
+
+fn parse_reg(ctx: &mut Context, input: parse::ParseStream) -> parse::Result<Option<RegKind>> {
+    let name = match input.step(|cursor| {
+        if let Some((ident, rest)) = cursor.ident() {
+            let mut ident = ident.to_string();
+
+            // dynamic registers are only recognized when followed by their parenthesized expression
+            if ARM_FAMILIES.contains_key(&*ident) {
+                if rest.group(proc_macro2::Delimiter::Parenthesis).is_some() {
+                    return Ok((ident, rest));
+                }
+                return Err(cursor.error("expected register"));
+            }
+
+            if let Some(repl) = ctx.state.file_data.aliases.get(&ident) {
+                ident = repl.clone();
+            }
+
+            if ARM_REGISTERS.contains_key(&*ident) {
+                return Ok((ident, rest));
+            }
+        }
+        Err(cursor.error("expected identifier"))
+    }) {
+        Ok(name) => name,
+        Err(_) => return Ok(None)
+    };
+
+    if let Some(&id) = ARM_REGISTERS.get(&*name) {
+        Ok(Some(RegKind::Static(id)))
+
+    } else if let Some(&family) = ARM_FAMILIES.get(&*name) {
+
+        // parse the dynamic register expression
+        let inner;
+        let _ = syn::parenthesized!(inner in input);
+        let inner = &inner;
+
+        let expr: syn::Expr = inner.parse()?;
+
+        Ok(Some(RegKind::Dynamic(family, expr)))
+    } else {
+        unreachable!();
+    }
+}
+
+impl RegId {
+    /// Finds the register with the given family and code
+    fn from_parts(family: RegFamily, code: u8) -> RegId {
+        let prefix = match family {
+            RegFamily::CORE => "r",
+            RegFamily::SINGLE => "s",
+            RegFamily::DOUBLE => "d",
+        };
+        ARM_REGISTERS[&*format!("{}{}", prefix, code)]
+    }
+}
+
+lazy_static!{
+    static ref ARM_REGISTERS: HashMap<&'static str, RegId> = {
+        use self::RegId::*;
+
+        static MAP: &[(&str, RegId)] = &[
+            ("r0" , R0 ),
+            ("r1" , R1 ),
+            ("r2" , R2 ),
+            ("r3" , R3 ),
+            ("r4" , R4 ),
+            ("r5" , R5 ),
+            ("r6" , R6 ),
+            ("r7" , R7 ),
+            ("r8" , R8 ),
+            ("r9" , R9 ),
+            ("r10", R10),
+            ("r11", R11),
+            ("r12", R12),
+            ("r13", R13),
+            ("r14", R14),
+            ("r15", R15),
+
+            ("sb" , R9 ),
+            ("sl" , R10),
+            ("fp" , R11),
+            ("ip" , R12),
+            ("sp" , R13),
+            ("lr" , R14),
+            ("pc" , R15),
+
+            ("s0" , S0 ),
+            ("s1" , S1 ),
+            ("s2" , S2 ),
+            ("s3" , S3 ),
+            ("s4" , S4 ),
+            ("s5" , S5 ),
+            ("s6" , S6 ),
+            ("s7" , S7 ),
+            ("s8" , S8 ),
+            ("s9" , S9 ),
+            ("s10", S10),
+            ("s11", S11),
+            ("s12", S12),
+            ("s13", S13),
+            ("s14", S14),
+            ("s15", S15),
+            ("s16", S16),
+            ("s17", S17),
+            ("s18", S18),
+            ("s19", S19),
+            ("s20", S20),
+            ("s21", S21),
+            ("s22", S22),
+            ("s23", S23),
+            ("s24", S24),
+            ("s25", S25),
+            ("s26", S26),
+            ("s27", S27),
+            ("s28", S28),
+            ("s29", S29),
+            ("s30", S30),
+            ("s31", S31),
+
+            ("d0" , D0 ),
+            ("d1" , D1 ),
+            ("d2" , D2 ),
+            ("d3" , D3 ),
+            ("d4" , D4 ),
+            ("d5" , D5 ),
+            ("d6" , D6 ),
+            ("d7" , D7 ),
+            ("d8" , D8 ),
+            ("d9" , D9 ),
+            ("d10", D10),
+            ("d11", D11),
+            ("d12", D12),
+            ("d13", D13),
+            ("d14", D14),
+            ("d15", D15),
+            ("d16", D16),
+            ("d17", D17),
+            ("d18", D18),
+            ("d19", D19),
+            ("d20", D20),
+            ("d21", D21),
+            ("d22", D22),
+            ("d23", D23),
+            ("d24", D24),
+            ("d25", D25),
+            ("d26", D26),
+            ("d27", D27),
+            ("d28", D28),
+            ("d29", D29),
+            ("d30", D30),
+            ("d31", D31),
+        ];
+        MAP.iter().cloned().collect()
+    };
+
+    static ref ARM_FAMILIES: HashMap<&'static str, RegFamily> = {
+        static MAP: &[(&str, RegFamily)] = &[
+            ("R", RegFamily::CORE),
+            ("S", RegFamily::SINGLE),
+            ("D", RegFamily::DOUBLE),
+        ];
+        MAP.iter().cloned().collect()
+    };
+}
@@ -0,0 +1,133 @@
+use syn;
+use proc_macro2::Span;
+
+use crate::common::Jump;
+
+
+// Register id without indication of its usage. Either a static Regid or a family identifier + expression to choose the register
+#[derive(Debug, Clone)]
+pub enum RegKind {
+    Static(RegId),
+    Dynamic(RegFamily, syn::Expr)
+}
+
+// a register identifier. This identifies an architecturally completely separate register.
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+pub enum RegId {
+    // general purpose registers. r0 is hardwired to zero.
+    R0 = 0x00, R1 = 0x01, R2 = 0x02, R3 = 0x03,
+    R4 = 0x04, R5 = 0x05, R6 = 0x06, R7 = 0x07,
+    R8 = 0x08, R9 = 0x09, R10= 0x0A, R11= 0x0B,
+    R12= 0x0C, R13= 0x0D, R14= 0x0E, R15= 0x0F,
+    R16= 0x10, R17= 0x11, R18= 0x12, R19= 0x13,
+    R20= 0x14, R21= 0x15, R22= 0x16, R23= 0x17,
+    R24= 0x18, R25= 0x19, R26= 0x1A, R27= 0x1B,
+    R28= 0x1C, R29= 0x1D, R30= 0x1E, R31= 0x1F,
+
+    // floating point registers. Used as single or double precision depending on the instruction.
+    F0 = 0x20, F1 = 0x21, F2 = 0x22, F3 = 0x23,
+    F4 = 0x24, F5 = 0x25, F6 = 0x26, F7 = 0x27,
+    F8 = 0x28, F9 = 0x29, F10= 0x2A, F11= 0x2B,
+    F12= 0x2C, F13= 0x2D, F14= 0x2E, F15= 0x2F,
+    F16= 0x30, F17= 0x31, F18= 0x32, F19= 0x33,
+    F20= 0x34, F21= 0x35, F22= 0x36, F23= 0x37,
+    F24= 0x38, F25= 0x39, F26= 0x3A, F27= 0x3B,
+    F28= 0x3C, F29= 0x3D, F30= 0x3E, F31= 0x3F,
+
+    // floating point condition flags
+    FCC0 = 0x40, FCC1 = 0x41, FCC2 = 0x42, FCC3 = 0x43,
+    FCC4 = 0x44, FCC5 = 0x45, FCC6 = 0x46, FCC7 = 0x47,
+
+    // floating point control and status registers
+    FCSR0 = 0x60, FCSR1 = 0x61, FCSR2 = 0x62, FCSR3 = 0x63,
+}
+
+// register family. INTEGER = rN, FP = fN, FCC = fccN, FCSR = fcsrN
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+pub enum RegFamily {
+    INTEGER = 0,
+    FP      = 1,
+    FCC     = 2,
+    FCSR    = 3,
+}
+
+impl RegId {
+    /// Encode this RegId in a 5-bit value
+    pub fn code(self) -> u8 {
+        self as u8 & 0x1F
+    }
+
+    /// Returns what family this Regid is from
+    pub fn family(self) -> RegFamily {
+        match self as u8 >> 5 {
+            0 => RegFamily::INTEGER,
+            1 => RegFamily::FP,
+            2 => RegFamily::FCC,
+            3 => RegFamily::FCSR,
+            _ => unreachable!()
+        }
+    }
+}
+
+impl RegKind {
+    /// Returns the family that this regkind is of
+    pub fn family(&self) -> RegFamily {
+        match *self {
+            RegKind::Static(code) => code.family(),
+            RegKind::Dynamic(family, _) => family
+        }
+    }
+}
+
+impl RegFamily {
+    /// The bitmask of a register number of this family
+    pub fn mask(self) -> u32 {
+        match self {
+            RegFamily::INTEGER
+            | RegFamily::FP => 0x1F,
+            RegFamily::FCC => 0x7,
+            RegFamily::FCSR => 0x3,
+        }
+    }
+}
+
+// basic parse results, before we start doing any kind of checking
+#[derive(Debug)]
+pub enum RawArg {
+    // direct register reference
+    Direct {
+        span: Span,
+        reg: RegKind
+    },
+    // jump target. Also used by PC-relative address calculations
+    JumpTarget {
+        jump: Jump
+    },
+    // just an arbitrary expression
+    Immediate {
+        value: syn::Expr
+    }
+}
+
+// Contains the actual instruction mnemnonic.
+#[derive(Debug)]
+pub struct Instruction {
+    pub span: Span,
+    pub name: String
+}
+
+// flat arg list after matching, for encoding
+#[derive(Debug)]
+pub enum FlatArg {
+    Direct {
+        span: Span,
+        reg: RegKind
+    },
+    Immediate {
+        value: syn::Expr,
+    },
+    JumpTarget {
+        jump: Jump
+    },
+    Default
+}
@@ -0,0 +1,282 @@
+use super::matching::MatchData;
+use super::loongarchdata::{Command, Relocation};
+use super::Context;
+use super::ast::{FlatArg, RegKind};
+
+use crate::common::{Stmt, Size, delimited, emit_error_at, bitmask};
+use crate::parse_helpers::{as_number, as_signed_number};
+
+use syn::spanned::Spanned;
+use quote::{quote, quote_spanned};
+
+pub(super) fn compile_instruction(ctx: &mut Context, data: MatchData) -> Result<(), Option<String>> {
+    // determine if the features required for this op are fulfilled
+    if !ctx.features.contains(data.data.features) {
+        return Err(Some(format!(
+            "This instruction uses features that are not indicated to be available: {}",
+            data.data.features - ctx.features
+        )));
+    }
+
+    let mut cursor = 0usize;
+
+    // All static bitfields (compile-time constant) will be encoded into this map of (offset, bitfield)
+    let mut statics = Vec::new();
+    // All dynamic bitfields (run-time determined) will be encoded into this map of (offset, TokenStream)
+    let mut dynamics = Vec::new();
+    // Any relocations will be encoded into this list
+    let mut relocations = Vec::new();
+
+    for command in data.data.commands.iter() {
+        match *command {
+            // special commands that don't check the current arg
+            Command::A => {
+                cursor += 1;
+                continue
+            },
+            Command::C => {
+                cursor -= 1;
+                continue
+            },
+            _ => ()
+        }
+
+        let arg = data.args.get(cursor).expect("Invalid encoding data, tried to process more arguments than given");
+
+        match *arg {
+            FlatArg::Direct { reg: RegKind::Static(id), .. } => match *command {
+                Command::R(offset) => {
+                    statics.push((offset, u32::from(id.code())));
+                },
+                _ => panic!("Invalid argument processor")
+            },
+            FlatArg::Direct { span, reg: RegKind::Dynamic(family, ref expr) } => match *command {
+                Command::R(offset) => {
+                    let mask = family.mask();
+                    dynamics.push((offset, quote_spanned!{ span=>
+                        #expr & #mask
+                    }));
+                },
+                _ => panic!("Invalid argument processor")
+            },
+            FlatArg::Immediate { ref value } => match *command {
+
+                // unsigned integer encodings
+                Command::Ubits(offset, bitlen) => {
+                    let mask = bitmask(bitlen);
+                    if let Some(value) = unsigned_rangecheck(value, 0, mask, 0) {
+                        statics.push((offset, value?));
+                    } else {
+                        dynamics.push((offset, quote_spanned!{ value.span()=>
+                            #value & #mask
+                        }));
+                    }
+                },
+                Command::Urange(offset, min, max) => {
+                    let min = u32::from(min);
+                    let max = u32::from(max);
+                    if let Some(value) = unsigned_rangecheck(value, min, max, 0) {
+                        statics.push((offset, value? - min));
+                    } else {
+                        let mask = bitmask(32 - (max - min).leading_zeros() as u8);
+                        dynamics.push((offset, quote_spanned!{ value.span()=>
+                            (#value - #min) & #mask
+                        }));
+                    }
+                },
+
+                // signed integer encoding
+                Command::Sbits(offset, bitlen) => {
+                    let mask = bitmask(bitlen);
+                    let half = -1i32 << (bitlen - 1);
+                    if let Some(value) = signed_rangecheck(value, half, mask as i32 + half, 0) {
+                        statics.push((offset, (value? as u32) & mask));
+                    } else {
+                        dynamics.push((offset, quote_spanned!{ value.span()=>
+                            (#value as u32) & #mask
+                        }));
+                    }
+                },
+
+                // nonconsuming integer checks
+                Command::BSscaled(bitlen, shift) => {
+                    let mask = bitmask(bitlen);
+                    let half = -1i32 << (bitlen - 1);
+                    if let Some(value) = signed_rangecheck(value, half, mask as i32 + half, shift) {
+                        value?;
+                    }
+                },
+                Command::Slice(offset, bitlen, shift) => {
+                    let mask = bitmask(bitlen);
+                    if let Some(value) = as_signed_number(value) {
+                        statics.push((offset, ((value >> shift) as u32) & mask));
+                    } else {
+                        dynamics.push((offset, quote_spanned!{ value.span()=>
+                            ((#value >> #shift) as u32) & #mask
+                        }));
+                    }
+                },
+
+                // jump targets also accept immediates
+                Command::Offset(relocation) => match relocation {
+                    Relocation::B16
+                    | Relocation::B21
+                    | Relocation::B26
+                    | Relocation::PCADDI => {
+                        let (slices, bits, shift) = relocation.slices();
+                        let mask = bitmask(bits - shift);
+                        let half = -1i32 << (bits - shift - 1);
+                        if let Some(value) = signed_rangecheck(value, half, mask as i32 + half, shift) {
+                            let value = value? << shift;
+                            for &(offset, bitlen, shift) in slices {
+                                statics.push((offset, ((value >> shift) as u32) & bitmask(bitlen)));
+                            }
+                        } else {
+                            for &(offset, bitlen, shift) in slices {
+                                let mask = bitmask(bitlen);
+                                dynamics.push((offset, quote_spanned!{ value.span()=>
+                                    ((#value >> #shift) as u32) & #mask
+                                }));
+                            }
+                        }
+                    },
+                    // the offset is split in a high part for the pcaddu12i or pcaddu18i, and a low part for the
+                    // instruction after it, which is sign extended.
+                    Relocation::PCADDU12I
+                    | Relocation::PCADDU18I => {
+                        let (low_bits, low_shift) = if relocation == Relocation::PCADDU12I { (12, 0) } else { (18, 2) };
+                        let round = 1i64 << (low_bits - 1);
+                        let low_mask = bitmask(low_bits - low_shift);
+
+                        if let Some(number) = as_signed_number(value) {
+                            let high = (number + round) >> low_bits;
+                            if number & ((1 << low_shift) - 1) != 0 {
+                                emit_error_at(value.span(), "Unrepresentable value".into());
+                                return Err(None);
+                            }
+                            if !(-(1 << 19) .. (1 << 19)).contains(&high) {
+                                emit_error_at(value.span(), "Value out of range".into());
+                                return Err(None);
+                            }
+                            statics.push((5, (high as u32) & 0xF_FFFF));
+                            statics.push((42, ((number >> low_shift) as u32) & low_mask));
+                        } else {
+                            dynamics.push((5, quote_spanned!{ value.span()=>
+                                (((#value + #round) >> #low_bits) as u32) & 0xF_FFFF
+                            }));
+                            dynamics.push((42, quote_spanned!{ value.span()=>
+                                ((#value >> #low_shift) as u32) & #low_mask
+                            }));
+                        }
+                    },
+                    Relocation::LITERAL8
+                    | Relocation::LITERAL16
+                    | Relocation::LITERAL32
+                    | Relocation::LITERAL64 => ()
+                },
+
+                _ => panic!("Invalid argument processor")
+            },
+            FlatArg::Default => match *command {
+                // omitted offsets default to 0
+                Command::Sbits(_, _) |
+                Command::Slice(_, _, _) => (),
+
+                // integer checks don't have anything to check
+                Command::BSscaled(_, _) => (),
+
+                _ => panic!("Invalid argument processor")
+            },
+            FlatArg::JumpTarget { ref jump } => match *command {
+                Command::Offset(relocation) => {
+                    // what kind of relocation is it
+                    let data = [relocation.to_id()];
+
+                    // encode the complete relocation
+                    let stmt = jump.clone().encode(&data);
+
+                    relocations.push(stmt);
+                },
+                _ => panic!("Invalid argument processor")
+            },
+        }
+
+        // figure out how far the cursor has to be advanced.
+        match *command {
+            Command::BSscaled(_, _) |
+            Command::Slice(_, _, _) => (),
+            _ => cursor += 1
+        }
+    }
+
+    // sanity
+    if cursor != data.args.len() {
+        panic!("Not enough command processors");
+    }
+
+    // apply all statics to bits
+    let mut bits = data.data.base;
+    for (offset, value) in statics {
+        bits |= u64::from(value) << offset;
+    }
+
+    let size = data.data.size();
+
+    // generate code to be emitted for dynamics
+    if !dynamics.is_empty() {
+        let (mut res, ty) = match size {
+            Size::DWORD => (quote!{ #bits as u32 }, quote!(u32)),
+            Size::QWORD => (quote!{ #bits }, quote!(u64)),
+            _ => unreachable!()
+        };
+        for (offset, expr) in dynamics {
+            res = quote!{
+                #res | (((#expr) as #ty) << #offset)
+            };
+        }
+        ctx.state.stmts.push(Stmt::ExprUnsigned(delimited(res), size));
+    } else {
+        ctx.state.stmts.push(Stmt::Const(bits, size));
+    }
+
+    // generate code to be emitted for relocations
+    ctx.state.stmts.extend(relocations);
+
+    Ok(())
+}
+
+fn unsigned_rangecheck(expr: &syn::Expr, min: u32, max: u32, scale: u8) -> Option<Result<u32, Option<String>>> {
+    let value = as_number(expr)?;
+    let scaled = value >> scale;
+
+    Some(if (scaled << scale) != value {
+        emit_error_at(expr.span(), "Unrepresentable value".into());
+        Err(None)
+    } else if scaled > u64::from(max) {
+        emit_error_at(expr.span(), "Value too large".into());
+        Err(None)
+    } else if scaled < u64::from(min) {
+        emit_error_at(expr.span(), "Value too small".into());
+        Err(None)
+    } else {
+        Ok(scaled as u32)
+    })
+}
+
+fn signed_rangecheck(expr: &syn::Expr, min: i32, max: i32, scale: u8) -> Option<Result<i32, Option<String>>> {
+    let value = as_signed_number(expr)?;
+    let scaled = value >> scale;
+
+    Some(if (scaled << scale) != value {
+        emit_error_at(expr.span(), "Unrepresentable value".into());
+        Err(None)
+    } else if scaled > i64::from(max) {
+        emit_error_at(expr.span(), "Value too large".into());
+        Err(None)
+    } else if scaled < i64::from(min) {
+        emit_error_at(expr.span(), "Value too small".into());
+        Err(None)
+    } else {
+        Ok(scaled as i32)
+    })
+}
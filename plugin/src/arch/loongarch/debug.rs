@@ -0,0 +1,168 @@
+use super::loongarchdata::{Opdata, Matcher, Command};
+
+use std::fmt::Write;
+
+
+#[cfg(feature = "dynasm_opmap")]
+pub fn create_opmap() -> String {
+    let mut s = String::new();
+
+    let mut mnemnonics: Vec<_> = super::loongarchdata::mnemnonics().cloned().collect();
+    mnemnonics.sort();
+
+    for mnemnonic in mnemnonics {
+        // get the data for this mnemnonic
+        let data = super::loongarchdata::get_mnemonic_data(mnemnonic).unwrap();
+        // format the data for the opmap docs
+        let formats = data.iter()
+            .map(|x| format_opdata(mnemnonic, x).replace(">>> ", ""))
+            .collect::<Vec<_>>();
+
+        // push mnemnonic name as title
+        write!(s, "### {}\n```insref\n{}\n```\n", mnemnonic, formats.join("\n")).unwrap();
+    }
+    s
+}
+
+
+pub fn format_opdata_list(name: &str, data: &[Opdata]) -> String {
+    data.iter().map(|data| format_opdata(name, data)).collect::<Vec<_>>().join("\n")
+}
+
+pub fn format_opdata(name: &str, data: &Opdata) -> String {
+    let mut buf = format!(">>> {}", name);
+
+    let args = group_commands(data);
+    let arg_names = name_args(&args);
+    let mut names = &arg_names[..];
+
+    let mut first = true;
+    let mut end_count = 0;
+
+    for matcher in data.matchers {
+        if let Matcher::End = matcher {
+            end_count += 1;
+            buf.push_str(" {");
+            continue;
+        }
+
+        if first {
+            buf.push(' ');
+            first = false;
+        } else {
+            buf.push_str(", ");
+        }
+
+        let (arg_names, rest) = names.split_at(matcher.flatarg_count());
+        names = rest;
+
+        match matcher {
+            Matcher::Imm
+            | Matcher::Offset => buf.push_str(&arg_names[0]),
+            Matcher::R => write!(buf, "R{}", arg_names[0]).unwrap(),
+            Matcher::F => write!(buf, "F{}", arg_names[0]).unwrap(),
+            Matcher::FCC => write!(buf, "FCC{}", arg_names[0]).unwrap(),
+            Matcher::FCSR => write!(buf, "FCSR{}", arg_names[0]).unwrap(),
+            Matcher::End => ()
+        }
+    }
+
+    for _ in 0 .. end_count {
+        buf.push_str(" }");
+    }
+
+    let constraints = format_constraints(&args, &arg_names);
+    if !constraints.is_empty() {
+        let mut len = constraints.len() + buf.len();
+        while len < 100 {
+            buf.push(' ');
+            len += 1;
+        }
+        buf.push_str(&constraints);
+    }
+
+    if !data.features.is_empty() {
+        write!(buf, " ({})", data.features).unwrap();
+    }
+
+    buf
+}
+
+/// Groups the commands of an op by the flat argument they operate on.
+fn group_commands(data: &Opdata) -> Vec<Vec<Command>> {
+    let count = data.matchers.iter().map(|m| m.flatarg_count()).sum();
+    let mut args = vec![Vec::new(); count];
+    let mut cursor = 0usize;
+
+    for &command in data.commands {
+        match command {
+            Command::A => cursor += 1,
+            Command::C => cursor -= 1,
+            Command::BSscaled(_, _)
+            | Command::Slice(_, _, _) => args[cursor].push(command),
+            _ => {
+                args[cursor].push(command);
+                cursor += 1;
+            }
+        }
+    }
+
+    args
+}
+
+/// Names arguments after what they encode. Registers get a letter, other arguments a descriptive name.
+fn name_args(args: &[Vec<Command>]) -> Vec<String> {
+    let mut letters = "abcdefgh".chars();
+    let mut names: Vec<String> = Vec::new();
+
+    for commands in args {
+        let name = match commands.first() {
+            Some(Command::R(_)) => letters.next().unwrap().to_string(),
+            Some(Command::Offset(_)) => "offset".into(),
+            _ => "imm".into()
+        };
+
+        // disambiguate arguments with the same name
+        let name = if names.contains(&name) {
+            let mut i = 2;
+            while names.contains(&format!("{}{}", name, i)) {
+                i += 1;
+            }
+            format!("{}{}", name, i)
+        } else {
+            name
+        };
+        names.push(name);
+    }
+
+    names
+}
+
+fn format_constraints(args: &[Vec<Command>], names: &[String]) -> String {
+    let mut constraints = Vec::new();
+
+    for (name, commands) in names.iter().zip(args) {
+        // bitslices are encoded as a whole, so only the checks matter
+        let mut range = None;
+
+        for &command in commands {
+            match command {
+                Command::Ubits(_, bits) => range = Some((0, (1i64 << bits) - 1, 0)),
+                Command::Sbits(_, bits) => range = Some((-(1i64 << (bits - 1)), (1i64 << (bits - 1)) - 1, 0)),
+                Command::Urange(_, min, max) => range = Some((i64::from(min), i64::from(max), 0)),
+                Command::BSscaled(bits, shift) => range = Some((-(1i64 << (bits - 1)) << shift, ((1i64 << (bits - 1)) - 1) << shift, shift)),
+                _ => ()
+            }
+        }
+
+        if let Some((min, max, shift)) = range {
+            let mut c = format!("{} = [{}, {}]", name, min, max);
+            if shift != 0 {
+                write!(c, " #{}", 1 << shift).unwrap();
+            }
+            constraints.push(c);
+        }
+    }
+
+    constraints.join(", ")
+}
@@ -0,0 +1,219 @@
+use crate::common::Size;
+
+use lazy_static::lazy_static;
+use bitflags::bitflags;
+use std::collections::{HashMap, hash_map};
+use std::fmt::{self, Display};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Matcher {
+    // immediate
+    Imm,
+
+    // general purpose registers
+    R,
+
+    // floating point registers
+    F,
+
+    // floating point condition flags
+    FCC,
+
+    // floating point control and status registers
+    FCSR,
+
+    // jump offsets and pc-relative addresses. Matches labels as well as immediates
+    Offset,
+
+    // possible op mnemnonic end (everything after this point uses the default encoding)
+    End,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Command {
+    // commands that advance the argument pointer
+    R(u8), // encode a register into a 5-bit bitfield, or a condition flag into a 3-bit bitfield.
+
+    // immediate encodings
+    Ubits(u8, u8), // encodes an unsigned immediate starting at bit .0, .1 bits long
+    Sbits(u8, u8), // encodes a signed immediate starting at bit .0, .1 bits long
+    Urange(u8, u8, u8), // encodes an unsigned immediate in the range [.1, .2] at bit .0, minus .1
+
+    // bit slice encodings. These don't advance the current argument. Only the slice commands actually encode anything
+    BSscaled(u8, u8), // checks that the pointed value fits in .0 bits as a signed value after being shifted .1 bits to the right, without losing bits
+    Slice(u8, u8, u8), // encodes at .0, .1 bits long, the bitslice starting at .2 from the current arg
+
+    // Offsets
+    Offset(Relocation),
+
+    // special commands
+    A, // advances the argument pointer, only needed to skip over an argument.
+    C, // moves the argument pointer back.
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[allow(non_camel_case_types)]
+pub enum Relocation {
+    // beq, bne, blt, bge, bltu, bgeu: 16 bits, word aligned
+    B16 = 0,
+    // beqz, bnez, bceqz, bcnez: split 21 bits, word aligned
+    B21 = 1,
+    // b, bl: split 26 bits, word aligned
+    B26 = 2,
+    // pcaddi: 20 bits, word aligned
+    PCADDI = 3,
+    // pcaddu12i followed by an instruction with a 12-bit immediate (addi.d, loads, stores): 32 bits, split over both instructions
+    PCADDU12I = 4,
+    // pcaddu18i followed by jirl: 38 bits, word aligned, split over both instructions
+    PCADDU18I = 5,
+    // 8-bit literal
+    LITERAL8 = 7,
+    // 16-bit literal
+    LITERAL16 = 8,
+    // 32-bit literal
+    LITERAL32 = 10,
+    // 64-bit literal
+    LITERAL64 = 14,
+}
+
+impl Relocation {
+    pub fn to_id(self) -> u8 {
+        self as u8
+    }
+
+    /// The bitslices of the relocated value that make up this relocation, as (offset, bitlen, shift) triples,
+    /// the amount of bits of the value and the amount of low bits that have to be zero.
+    /// The pair relocations are handled separately.
+    pub fn slices(self) -> (&'static [(u8, u8, u8)], u8, u8) {
+        match self {
+            Relocation::B16 => (&[(10, 16, 2)], 18, 2),
+            Relocation::B21 => (&[(10, 16, 2), (0, 5, 18)], 23, 2),
+            Relocation::B26 => (&[(10, 16, 2), (0, 10, 18)], 28, 2),
+            Relocation::PCADDI => (&[(5, 20, 2)], 22, 2),
+            _ => panic!("Relocation without bitslices")
+        }
+    }
+}
+
+
+bitflags! {
+    pub struct Features: u64 {
+        const BASELINE   = 0x0000_0000;
+        const F          = 0x0000_0001;
+        const D          = 0x0000_0002;
+    }
+}
+
+impl Features {
+    const fn make(bits: u64) -> Features {
+        Features { bits }
+    }
+
+    pub fn from_str(name: &str) -> Option<Features> {
+        match name {
+            "f" => Some(Features::F),
+            "d" => Some(Features::D | Features::F),
+            _ => None
+        }
+    }
+}
+
+impl Display for Features {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut keys = Vec::new();
+        if self.contains(Features::F) { keys.push("f"); }
+        if self.contains(Features::D) { keys.push("d"); }
+        for (i, k) in keys.into_iter().enumerate() {
+            if i != 0 {
+                f.write_str(", ")?;
+            }
+            f.write_str(k)?;
+        }
+        Ok(())
+    }
+}
+
+// workaround until bitflags can be used in const
+const F          : u64 = Features::F.bits;
+const D          : u64 = Features::D.bits;
+
+#[derive(Debug, Clone, Copy)]
+pub struct Opdata {
+    /// The base template for the encoding. Instruction pairs have the second instruction in the upper 32 bits.
+    pub base: u64,
+    /// A set of matchers capable of matching the instruction encoding that this instruction represents.
+    pub matchers: &'static [Matcher],
+    /// A sequence of encoder commands that check the matched instruction on validity and whose output gets orred together with the original template at runtime.
+    pub commands: &'static [Command],
+    /// The architecture extensions that have to be available for this instruction to be used.
+    pub features: Features
+}
+
+impl Opdata {
+    /// The size of the encoded instruction(s). Instructions are 4 bytes long, instruction pairs 8.
+    pub fn size(&self) -> Size {
+        if self.base > 0xFFFF_FFFF {
+            Size::QWORD
+        } else {
+            Size::DWORD
+        }
+    }
+}
+
+macro_rules! SingleOp {
+    ( $base:expr, [ $( $matcher:expr ),* ], [ $( $command:expr ),* ] $( , $features:expr )? ) => {
+        {
+            const MATCHERS: &'static [Matcher] = {
+                #[allow(unused_imports)]
+                use self::Matcher::*;
+                &[ $(
+                    $matcher
+                ),* ]
+            };
+            const COMMANDS: &'static [Command] = {
+                #[allow(unused_imports)]
+                use self::Command::*;
+                &[ $(
+                    $command
+                ),* ]
+            };
+            Opdata {
+                base: $base,
+                matchers: MATCHERS,
+                commands: COMMANDS,
+                features: Features::make(0 $( | $features )?),
+            }
+        }
+    }
+}
+
+macro_rules! Ops {
+    ( $( $name:tt = [ $( $base:tt = [ $( $matcher:expr ),* ] => [ $( $command:expr ),* ] $( , $features:expr )? ; )+ ] )* ) => {
+        [ $(
+            (
+                $name,
+                &[ $(
+                    SingleOp!( $base, [ $( $matcher ),* ], [ $( $command ),* ] $( , $features )? )
+                ),+ ] as &[_]
+            )
+        ),* ]
+    }
+}
+
+pub fn get_mnemonic_data(name: &str) -> Option<&'static [Opdata]> {
+    OPMAP.get(&name).cloned()
+}
+
+#[allow(dead_code)]
+pub fn mnemnonics() -> hash_map::Keys<'static, &'static str, &'static [Opdata]> {
+    OPMAP.keys()
+}
+
+lazy_static! {
+    static ref OPMAP: HashMap<&'static str, &'static [Opdata]> = {
+        use self::Relocation::*;
+
+        static MAP: &[(&str, &[Opdata])] = &include!("opmap.rs");
+        MAP.iter().cloned().collect()
+    };
+}
@@ -0,0 +1,1105 @@
+// LA64 base integer and floating point instructions, including the common pseudo instructions
+Ops!(
+"add.d" = [
+    0x0010_8000 = [R, R, R] => [R(0), R(5), R(10)];
+]
+"add.w" = [
+    0x0010_0000 = [R, R, R] => [R(0), R(5), R(10)];
+]
+"addi.d" = [
+    0x02C0_0000 = [R, R, Imm] => [R(0), R(5), Sbits(10, 12)];
+]
+"addi.w" = [
+    0x0280_0000 = [R, R, Imm] => [R(0), R(5), Sbits(10, 12)];
+]
+"addu16i.d" = [
+    0x1000_0000 = [R, R, Imm] => [R(0), R(5), Sbits(10, 16)];
+]
+"alsl.d" = [
+    0x002C_0000 = [R, R, R, Imm] => [R(0), R(5), R(10), Urange(15, 1, 4)];
+]
+"alsl.w" = [
+    0x0004_0000 = [R, R, R, Imm] => [R(0), R(5), R(10), Urange(15, 1, 4)];
+]
+"alsl.wu" = [
+    0x0006_0000 = [R, R, R, Imm] => [R(0), R(5), R(10), Urange(15, 1, 4)];
+]
+"amadd.d" = [
+    0x3861_8000 = [R, R, R] => [R(0), R(10), R(5)];
+]
+"amadd.w" = [
+    0x3861_0000 = [R, R, R] => [R(0), R(10), R(5)];
+]
+"amadd_db.d" = [
+    0x386A_8000 = [R, R, R] => [R(0), R(10), R(5)];
+]
+"amadd_db.w" = [
+    0x386A_0000 = [R, R, R] => [R(0), R(10), R(5)];
+]
+"amand.d" = [
+    0x3862_8000 = [R, R, R] => [R(0), R(10), R(5)];
+]
+"amand.w" = [
+    0x3862_0000 = [R, R, R] => [R(0), R(10), R(5)];
+]
+"amand_db.d" = [
+    0x386B_8000 = [R, R, R] => [R(0), R(10), R(5)];
+]
+"amand_db.w" = [
+    0x386B_0000 = [R, R, R] => [R(0), R(10), R(5)];
+]
+"ammax.d" = [
+    0x3865_8000 = [R, R, R] => [R(0), R(10), R(5)];
+]
+"ammax.du" = [
+    0x3867_8000 = [R, R, R] => [R(0), R(10), R(5)];
+]
+"ammax.w" = [
+    0x3865_0000 = [R, R, R] => [R(0), R(10), R(5)];
+]
+"ammax.wu" = [
+    0x3867_0000 = [R, R, R] => [R(0), R(10), R(5)];
+]
+"ammax_db.d" = [
+    0x386E_8000 = [R, R, R] => [R(0), R(10), R(5)];
+]
+"ammax_db.du" = [
+    0x3870_8000 = [R, R, R] => [R(0), R(10), R(5)];
+]
+"ammax_db.w" = [
+    0x386E_0000 = [R, R, R] => [R(0), R(10), R(5)];
+]
+"ammax_db.wu" = [
+    0x3870_0000 = [R, R, R] => [R(0), R(10), R(5)];
+]
+"ammin.d" = [
+    0x3866_8000 = [R, R, R] => [R(0), R(10), R(5)];
+]
+"ammin.du" = [
+    0x3868_8000 = [R, R, R] => [R(0), R(10), R(5)];
+]
+"ammin.w" = [
+    0x3866_0000 = [R, R, R] => [R(0), R(10), R(5)];
+]
+"ammin.wu" = [
+    0x3868_0000 = [R, R, R] => [R(0), R(10), R(5)];
+]
+"ammin_db.d" = [
+    0x386F_8000 = [R, R, R] => [R(0), R(10), R(5)];
+]
+"ammin_db.du" = [
+    0x3871_8000 = [R, R, R] => [R(0), R(10), R(5)];
+]
+"ammin_db.w" = [
+    0x386F_0000 = [R, R, R] => [R(0), R(10), R(5)];
+]
+"ammin_db.wu" = [
+    0x3871_0000 = [R, R, R] => [R(0), R(10), R(5)];
+]
+"amor.d" = [
+    0x3863_8000 = [R, R, R] => [R(0), R(10), R(5)];
+]
+"amor.w" = [
+    0x3863_0000 = [R, R, R] => [R(0), R(10), R(5)];
+]
+"amor_db.d" = [
+    0x386C_8000 = [R, R, R] => [R(0), R(10), R(5)];
+]
+"amor_db.w" = [
+    0x386C_0000 = [R, R, R] => [R(0), R(10), R(5)];
+]
+"amswap.d" = [
+    0x3860_8000 = [R, R, R] => [R(0), R(10), R(5)];
+]
+"amswap.w" = [
+    0x3860_0000 = [R, R, R] => [R(0), R(10), R(5)];
+]
+"amswap_db.d" = [
+    0x3869_8000 = [R, R, R] => [R(0), R(10), R(5)];
+]
+"amswap_db.w" = [
+    0x3869_0000 = [R, R, R] => [R(0), R(10), R(5)];
+]
+"amxor.d" = [
+    0x3864_8000 = [R, R, R] => [R(0), R(10), R(5)];
+]
+"amxor.w" = [
+    0x3864_0000 = [R, R, R] => [R(0), R(10), R(5)];
+]
+"amxor_db.d" = [
+    0x386D_8000 = [R, R, R] => [R(0), R(10), R(5)];
+]
+"amxor_db.w" = [
+    0x386D_0000 = [R, R, R] => [R(0), R(10), R(5)];
+]
+"and" = [
+    0x0014_8000 = [R, R, R] => [R(0), R(5), R(10)];
+]
+"andi" = [
+    0x0340_0000 = [R, R, Imm] => [R(0), R(5), Ubits(10, 12)];
+]
+"andn" = [
+    0x0016_8000 = [R, R, R] => [R(0), R(5), R(10)];
+]
+"asrtgt.d" = [
+    0x0001_8000 = [R, R] => [R(5), R(10)];
+]
+"asrtle.d" = [
+    0x0001_0000 = [R, R] => [R(5), R(10)];
+]
+"b" = [
+    0x5000_0000 = [Offset] => [Offset(B26)];
+]
+"bceqz" = [
+    0x4800_0000 = [FCC, Offset] => [R(5), Offset(B21)], F;
+]
+"bcnez" = [
+    0x4800_0100 = [FCC, Offset] => [R(5), Offset(B21)], F;
+]
+"beq" = [
+    0x5800_0000 = [R, R, Offset] => [R(5), R(0), Offset(B16)];
+]
+"beqz" = [
+    0x4000_0000 = [R, Offset] => [R(5), Offset(B21)];
+]
+"bge" = [
+    0x6400_0000 = [R, R, Offset] => [R(5), R(0), Offset(B16)];
+]
+"bgeu" = [
+    0x6C00_0000 = [R, R, Offset] => [R(5), R(0), Offset(B16)];
+]
+"bgt" = [
+    0x6000_0000 = [R, R, Offset] => [R(0), R(5), Offset(B16)];
+]
+"bgtu" = [
+    0x6800_0000 = [R, R, Offset] => [R(0), R(5), Offset(B16)];
+]
+"bitrev.4b" = [
+    0x0000_4800 = [R, R] => [R(0), R(5)];
+]
+"bitrev.8b" = [
+    0x0000_4C00 = [R, R] => [R(0), R(5)];
+]
+"bitrev.d" = [
+    0x0000_5400 = [R, R] => [R(0), R(5)];
+]
+"bitrev.w" = [
+    0x0000_5000 = [R, R] => [R(0), R(5)];
+]
+"bl" = [
+    0x5400_0000 = [Offset] => [Offset(B26)];
+]
+"ble" = [
+    0x6400_0000 = [R, R, Offset] => [R(0), R(5), Offset(B16)];
+]
+"bleu" = [
+    0x6C00_0000 = [R, R, Offset] => [R(0), R(5), Offset(B16)];
+]
+"blt" = [
+    0x6000_0000 = [R, R, Offset] => [R(5), R(0), Offset(B16)];
+]
+"bltu" = [
+    0x6800_0000 = [R, R, Offset] => [R(5), R(0), Offset(B16)];
+]
+"bne" = [
+    0x5C00_0000 = [R, R, Offset] => [R(5), R(0), Offset(B16)];
+]
+"bnez" = [
+    0x4400_0000 = [R, Offset] => [R(5), Offset(B21)];
+]
+"break" = [
+    0x002A_0000 = [Imm] => [Ubits(0, 15)];
+]
+"bstrins.d" = [
+    0x0080_0000 = [R, R, Imm, Imm] => [R(0), R(5), Ubits(16, 6), Ubits(10, 6)];
+]
+"bstrins.w" = [
+    0x0060_0000 = [R, R, Imm, Imm] => [R(0), R(5), Ubits(16, 5), Ubits(10, 5)];
+]
+"bstrpick.d" = [
+    0x00C0_0000 = [R, R, Imm, Imm] => [R(0), R(5), Ubits(16, 6), Ubits(10, 6)];
+]
+"bstrpick.w" = [
+    0x0060_8000 = [R, R, Imm, Imm] => [R(0), R(5), Ubits(16, 5), Ubits(10, 5)];
+]
+"bytepick.d" = [
+    0x000C_0000 = [R, R, R, Imm] => [R(0), R(5), R(10), Ubits(15, 3)];
+]
+"bytepick.w" = [
+    0x0008_0000 = [R, R, R, Imm] => [R(0), R(5), R(10), Ubits(15, 2)];
+]
+"call36" = [
+    0x4C00_0021_1E00_0001 = [Offset] => [Offset(PCADDU18I)];
+]
+"clo.d" = [
+    0x0000_2000 = [R, R] => [R(0), R(5)];
+]
+"clo.w" = [
+    0x0000_1000 = [R, R] => [R(0), R(5)];
+]
+"clz.d" = [
+    0x0000_2400 = [R, R] => [R(0), R(5)];
+]
+"clz.w" = [
+    0x0000_1400 = [R, R] => [R(0), R(5)];
+]
+"cpucfg" = [
+    0x0000_6C00 = [R, R] => [R(0), R(5)];
+]
+"crc.w.b.w" = [
+    0x0024_0000 = [R, R, R] => [R(0), R(5), R(10)];
+]
+"crc.w.d.w" = [
+    0x0025_8000 = [R, R, R] => [R(0), R(5), R(10)];
+]
+"crc.w.h.w" = [
+    0x0024_8000 = [R, R, R] => [R(0), R(5), R(10)];
+]
+"crc.w.w.w" = [
+    0x0025_0000 = [R, R, R] => [R(0), R(5), R(10)];
+]
+"crcc.w.b.w" = [
+    0x0026_0000 = [R, R, R] => [R(0), R(5), R(10)];
+]
+"crcc.w.d.w" = [
+    0x0027_8000 = [R, R, R] => [R(0), R(5), R(10)];
+]
+"crcc.w.h.w" = [
+    0x0026_8000 = [R, R, R] => [R(0), R(5), R(10)];
+]
+"crcc.w.w.w" = [
+    0x0027_0000 = [R, R, R] => [R(0), R(5), R(10)];
+]
+"cto.d" = [
+    0x0000_2800 = [R, R] => [R(0), R(5)];
+]
+"cto.w" = [
+    0x0000_1800 = [R, R] => [R(0), R(5)];
+]
+"ctz.d" = [
+    0x0000_2C00 = [R, R] => [R(0), R(5)];
+]
+"ctz.w" = [
+    0x0000_1C00 = [R, R] => [R(0), R(5)];
+]
+"dbar" = [
+    0x3872_0000 = [Imm] => [Ubits(0, 15)];
+]
+"dbcl" = [
+    0x002A_8000 = [Imm] => [Ubits(0, 15)];
+]
+"div.d" = [
+    0x0022_0000 = [R, R, R] => [R(0), R(5), R(10)];
+]
+"div.du" = [
+    0x0023_0000 = [R, R, R] => [R(0), R(5), R(10)];
+]
+"div.w" = [
+    0x0020_0000 = [R, R, R] => [R(0), R(5), R(10)];
+]
+"div.wu" = [
+    0x0021_0000 = [R, R, R] => [R(0), R(5), R(10)];
+]
+"ext.w.b" = [
+    0x0000_5C00 = [R, R] => [R(0), R(5)];
+]
+"ext.w.h" = [
+    0x0000_5800 = [R, R] => [R(0), R(5)];
+]
+"fabs.d" = [
+    0x0114_0800 = [F, F] => [R(0), R(5)], D;
+]
+"fabs.s" = [
+    0x0114_0400 = [F, F] => [R(0), R(5)], F;
+]
+"fadd.d" = [
+    0x0101_0000 = [F, F, F] => [R(0), R(5), R(10)], D;
+]
+"fadd.s" = [
+    0x0100_8000 = [F, F, F] => [R(0), R(5), R(10)], F;
+]
+"fclass.d" = [
+    0x0114_3800 = [F, F] => [R(0), R(5)], D;
+]
+"fclass.s" = [
+    0x0114_3400 = [F, F] => [R(0), R(5)], F;
+]
+"fcmp.caf.d" = [
+    0x0C20_0000 = [FCC, F, F] => [R(0), R(5), R(10)], D;
+]
+"fcmp.caf.s" = [
+    0x0C10_0000 = [FCC, F, F] => [R(0), R(5), R(10)], F;
+]
+"fcmp.ceq.d" = [
+    0x0C22_0000 = [FCC, F, F] => [R(0), R(5), R(10)], D;
+]
+"fcmp.ceq.s" = [
+    0x0C12_0000 = [FCC, F, F] => [R(0), R(5), R(10)], F;
+]
+"fcmp.cle.d" = [
+    0x0C23_0000 = [FCC, F, F] => [R(0), R(5), R(10)], D;
+]
+"fcmp.cle.s" = [
+    0x0C13_0000 = [FCC, F, F] => [R(0), R(5), R(10)], F;
+]
+"fcmp.clt.d" = [
+    0x0C21_0000 = [FCC, F, F] => [R(0), R(5), R(10)], D;
+]
+"fcmp.clt.s" = [
+    0x0C11_0000 = [FCC, F, F] => [R(0), R(5), R(10)], F;
+]
+"fcmp.cne.d" = [
+    0x0C28_0000 = [FCC, F, F] => [R(0), R(5), R(10)], D;
+]
+"fcmp.cne.s" = [
+    0x0C18_0000 = [FCC, F, F] => [R(0), R(5), R(10)], F;
+]
+"fcmp.cor.d" = [
+    0x0C2A_0000 = [FCC, F, F] => [R(0), R(5), R(10)], D;
+]
+"fcmp.cor.s" = [
+    0x0C1A_0000 = [FCC, F, F] => [R(0), R(5), R(10)], F;
+]
+"fcmp.cueq.d" = [
+    0x0C26_0000 = [FCC, F, F] => [R(0), R(5), R(10)], D;
+]
+"fcmp.cueq.s" = [
+    0x0C16_0000 = [FCC, F, F] => [R(0), R(5), R(10)], F;
+]
+"fcmp.cule.d" = [
+    0x0C27_0000 = [FCC, F, F] => [R(0), R(5), R(10)], D;
+]
+"fcmp.cule.s" = [
+    0x0C17_0000 = [FCC, F, F] => [R(0), R(5), R(10)], F;
+]
+"fcmp.cult.d" = [
+    0x0C25_0000 = [FCC, F, F] => [R(0), R(5), R(10)], D;
+]
+"fcmp.cult.s" = [
+    0x0C15_0000 = [FCC, F, F] => [R(0), R(5), R(10)], F;
+]
+"fcmp.cun.d" = [
+    0x0C24_0000 = [FCC, F, F] => [R(0), R(5), R(10)], D;
+]
+"fcmp.cun.s" = [
+    0x0C14_0000 = [FCC, F, F] => [R(0), R(5), R(10)], F;
+]
+"fcmp.cune.d" = [
+    0x0C2C_0000 = [FCC, F, F] => [R(0), R(5), R(10)], D;
+]
+"fcmp.cune.s" = [
+    0x0C1C_0000 = [FCC, F, F] => [R(0), R(5), R(10)], F;
+]
+"fcmp.saf.d" = [
+    0x0C20_8000 = [FCC, F, F] => [R(0), R(5), R(10)], D;
+]
+"fcmp.saf.s" = [
+    0x0C10_8000 = [FCC, F, F] => [R(0), R(5), R(10)], F;
+]
+"fcmp.seq.d" = [
+    0x0C22_8000 = [FCC, F, F] => [R(0), R(5), R(10)], D;
+]
+"fcmp.seq.s" = [
+    0x0C12_8000 = [FCC, F, F] => [R(0), R(5), R(10)], F;
+]
+"fcmp.sle.d" = [
+    0x0C23_8000 = [FCC, F, F] => [R(0), R(5), R(10)], D;
+]
+"fcmp.sle.s" = [
+    0x0C13_8000 = [FCC, F, F] => [R(0), R(5), R(10)], F;
+]
+"fcmp.slt.d" = [
+    0x0C21_8000 = [FCC, F, F] => [R(0), R(5), R(10)], D;
+]
+"fcmp.slt.s" = [
+    0x0C11_8000 = [FCC, F, F] => [R(0), R(5), R(10)], F;
+]
+"fcmp.sne.d" = [
+    0x0C28_8000 = [FCC, F, F] => [R(0), R(5), R(10)], D;
+]
+"fcmp.sne.s" = [
+    0x0C18_8000 = [FCC, F, F] => [R(0), R(5), R(10)], F;
+]
+"fcmp.sor.d" = [
+    0x0C2A_8000 = [FCC, F, F] => [R(0), R(5), R(10)], D;
+]
+"fcmp.sor.s" = [
+    0x0C1A_8000 = [FCC, F, F] => [R(0), R(5), R(10)], F;
+]
+"fcmp.sueq.d" = [
+    0x0C26_8000 = [FCC, F, F] => [R(0), R(5), R(10)], D;
+]
+"fcmp.sueq.s" = [
+    0x0C16_8000 = [FCC, F, F] => [R(0), R(5), R(10)], F;
+]
+"fcmp.sule.d" = [
+    0x0C27_8000 = [FCC, F, F] => [R(0), R(5), R(10)], D;
+]
+"fcmp.sule.s" = [
+    0x0C17_8000 = [FCC, F, F] => [R(0), R(5), R(10)], F;
+]
+"fcmp.sult.d" = [
+    0x0C25_8000 = [FCC, F, F] => [R(0), R(5), R(10)], D;
+]
+"fcmp.sult.s" = [
+    0x0C15_8000 = [FCC, F, F] => [R(0), R(5), R(10)], F;
+]
+"fcmp.sun.d" = [
+    0x0C24_8000 = [FCC, F, F] => [R(0), R(5), R(10)], D;
+]
+"fcmp.sun.s" = [
+    0x0C14_8000 = [FCC, F, F] => [R(0), R(5), R(10)], F;
+]
+"fcmp.sune.d" = [
+    0x0C2C_8000 = [FCC, F, F] => [R(0), R(5), R(10)], D;
+]
+"fcmp.sune.s" = [
+    0x0C1C_8000 = [FCC, F, F] => [R(0), R(5), R(10)], F;
+]
+"fcopysign.d" = [
+    0x0113_0000 = [F, F, F] => [R(0), R(5), R(10)], D;
+]
+"fcopysign.s" = [
+    0x0112_8000 = [F, F, F] => [R(0), R(5), R(10)], F;
+]
+"fcvt.d.s" = [
+    0x0119_2400 = [F, F] => [R(0), R(5)], D;
+]
+"fcvt.s.d" = [
+    0x0119_1800 = [F, F] => [R(0), R(5)], D;
+]
+"fdiv.d" = [
+    0x0107_0000 = [F, F, F] => [R(0), R(5), R(10)], D;
+]
+"fdiv.s" = [
+    0x0106_8000 = [F, F, F] => [R(0), R(5), R(10)], F;
+]
+"ffint.d.l" = [
+    0x011D_2800 = [F, F] => [R(0), R(5)], D;
+]
+"ffint.d.w" = [
+    0x011D_2000 = [F, F] => [R(0), R(5)], D;
+]
+"ffint.s.l" = [
+    0x011D_1800 = [F, F] => [R(0), R(5)], F;
+]
+"ffint.s.w" = [
+    0x011D_1000 = [F, F] => [R(0), R(5)], F;
+]
+"fld.d" = [
+    0x2B80_0000 = [F, R, End, Imm] => [R(0), R(5), Sbits(10, 12)], D;
+    0x2B80_0000_1C00_0000 = [F, Offset, R] => [R(32), Offset(PCADDU12I), R(0), C, R(37)], D;
+]
+"fld.s" = [
+    0x2B00_0000 = [F, R, End, Imm] => [R(0), R(5), Sbits(10, 12)], F;
+    0x2B00_0000_1C00_0000 = [F, Offset, R] => [R(32), Offset(PCADDU12I), R(0), C, R(37)], F;
+]
+"fldgt.d" = [
+    0x3874_8000 = [F, R, R] => [R(0), R(5), R(10)], D;
+]
+"fldgt.s" = [
+    0x3874_0000 = [F, R, R] => [R(0), R(5), R(10)], F;
+]
+"fldle.d" = [
+    0x3875_8000 = [F, R, R] => [R(0), R(5), R(10)], D;
+]
+"fldle.s" = [
+    0x3875_0000 = [F, R, R] => [R(0), R(5), R(10)], F;
+]
+"fldx.d" = [
+    0x3834_0000 = [F, R, R] => [R(0), R(5), R(10)], D;
+]
+"fldx.s" = [
+    0x3830_0000 = [F, R, R] => [R(0), R(5), R(10)], F;
+]
+"flogb.d" = [
+    0x0114_2800 = [F, F] => [R(0), R(5)], D;
+]
+"flogb.s" = [
+    0x0114_2400 = [F, F] => [R(0), R(5)], F;
+]
+"fmadd.d" = [
+    0x0820_0000 = [F, F, F, F] => [R(0), R(5), R(10), R(15)], D;
+]
+"fmadd.s" = [
+    0x0810_0000 = [F, F, F, F] => [R(0), R(5), R(10), R(15)], F;
+]
+"fmax.d" = [
+    0x0109_0000 = [F, F, F] => [R(0), R(5), R(10)], D;
+]
+"fmax.s" = [
+    0x0108_8000 = [F, F, F] => [R(0), R(5), R(10)], F;
+]
+"fmaxa.d" = [
+    0x010D_0000 = [F, F, F] => [R(0), R(5), R(10)], D;
+]
+"fmaxa.s" = [
+    0x010C_8000 = [F, F, F] => [R(0), R(5), R(10)], F;
+]
+"fmin.d" = [
+    0x010B_0000 = [F, F, F] => [R(0), R(5), R(10)], D;
+]
+"fmin.s" = [
+    0x010A_8000 = [F, F, F] => [R(0), R(5), R(10)], F;
+]
+"fmina.d" = [
+    0x010F_0000 = [F, F, F] => [R(0), R(5), R(10)], D;
+]
+"fmina.s" = [
+    0x010E_8000 = [F, F, F] => [R(0), R(5), R(10)], F;
+]
+"fmov.d" = [
+    0x0114_9800 = [F, F] => [R(0), R(5)], D;
+]
+"fmov.s" = [
+    0x0114_9400 = [F, F] => [R(0), R(5)], F;
+]
+"fmsub.d" = [
+    0x0860_0000 = [F, F, F, F] => [R(0), R(5), R(10), R(15)], D;
+]
+"fmsub.s" = [
+    0x0850_0000 = [F, F, F, F] => [R(0), R(5), R(10), R(15)], F;
+]
+"fmul.d" = [
+    0x0105_0000 = [F, F, F] => [R(0), R(5), R(10)], D;
+]
+"fmul.s" = [
+    0x0104_8000 = [F, F, F] => [R(0), R(5), R(10)], F;
+]
+"fneg.d" = [
+    0x0114_1800 = [F, F] => [R(0), R(5)], D;
+]
+"fneg.s" = [
+    0x0114_1400 = [F, F] => [R(0), R(5)], F;
+]
+"fnmadd.d" = [
+    0x08A0_0000 = [F, F, F, F] => [R(0), R(5), R(10), R(15)], D;
+]
+"fnmadd.s" = [
+    0x0890_0000 = [F, F, F, F] => [R(0), R(5), R(10), R(15)], F;
+]
+"fnmsub.d" = [
+    0x08E0_0000 = [F, F, F, F] => [R(0), R(5), R(10), R(15)], D;
+]
+"fnmsub.s" = [
+    0x08D0_0000 = [F, F, F, F] => [R(0), R(5), R(10), R(15)], F;
+]
+"frecip.d" = [
+    0x0114_5800 = [F, F] => [R(0), R(5)], D;
+]
+"frecip.s" = [
+    0x0114_5400 = [F, F] => [R(0), R(5)], F;
+]
+"frint.d" = [
+    0x011E_4800 = [F, F] => [R(0), R(5)], D;
+]
+"frint.s" = [
+    0x011E_4400 = [F, F] => [R(0), R(5)], F;
+]
+"frsqrt.d" = [
+    0x0114_6800 = [F, F] => [R(0), R(5)], D;
+]
+"frsqrt.s" = [
+    0x0114_6400 = [F, F] => [R(0), R(5)], F;
+]
+"fscaleb.d" = [
+    0x0111_0000 = [F, F, F] => [R(0), R(5), R(10)], D;
+]
+"fscaleb.s" = [
+    0x0110_8000 = [F, F, F] => [R(0), R(5), R(10)], F;
+]
+"fsel" = [
+    0x0D00_0000 = [F, F, F, FCC] => [R(0), R(5), R(10), R(15)], F;
+]
+"fsqrt.d" = [
+    0x0114_4800 = [F, F] => [R(0), R(5)], D;
+]
+"fsqrt.s" = [
+    0x0114_4400 = [F, F] => [R(0), R(5)], F;
+]
+"fst.d" = [
+    0x2BC0_0000 = [F, R, End, Imm] => [R(0), R(5), Sbits(10, 12)], D;
+    0x2BC0_0000_1C00_0000 = [F, Offset, R] => [R(32), Offset(PCADDU12I), R(0), C, R(37)], D;
+]
+"fst.s" = [
+    0x2B40_0000 = [F, R, End, Imm] => [R(0), R(5), Sbits(10, 12)], F;
+    0x2B40_0000_1C00_0000 = [F, Offset, R] => [R(32), Offset(PCADDU12I), R(0), C, R(37)], F;
+]
+"fstgt.d" = [
+    0x3876_8000 = [F, R, R] => [R(0), R(5), R(10)], D;
+]
+"fstgt.s" = [
+    0x3876_0000 = [F, R, R] => [R(0), R(5), R(10)], F;
+]
+"fstle.d" = [
+    0x3877_8000 = [F, R, R] => [R(0), R(5), R(10)], D;
+]
+"fstle.s" = [
+    0x3877_0000 = [F, R, R] => [R(0), R(5), R(10)], F;
+]
+"fstx.d" = [
+    0x383C_0000 = [F, R, R] => [R(0), R(5), R(10)], D;
+]
+"fstx.s" = [
+    0x3838_0000 = [F, R, R] => [R(0), R(5), R(10)], F;
+]
+"fsub.d" = [
+    0x0103_0000 = [F, F, F] => [R(0), R(5), R(10)], D;
+]
+"fsub.s" = [
+    0x0102_8000 = [F, F, F] => [R(0), R(5), R(10)], F;
+]
+"ftint.l.d" = [
+    0x011B_2800 = [F, F] => [R(0), R(5)], D;
+]
+"ftint.l.s" = [
+    0x011B_2400 = [F, F] => [R(0), R(5)], F;
+]
+"ftint.w.d" = [
+    0x011B_0800 = [F, F] => [R(0), R(5)], D;
+]
+"ftint.w.s" = [
+    0x011B_0400 = [F, F] => [R(0), R(5)], F;
+]
+"ftintrm.l.d" = [
+    0x011A_2800 = [F, F] => [R(0), R(5)], D;
+]
+"ftintrm.l.s" = [
+    0x011A_2400 = [F, F] => [R(0), R(5)], F;
+]
+"ftintrm.w.d" = [
+    0x011A_0800 = [F, F] => [R(0), R(5)], D;
+]
+"ftintrm.w.s" = [
+    0x011A_0400 = [F, F] => [R(0), R(5)], F;
+]
+"ftintrne.l.d" = [
+    0x011A_E800 = [F, F] => [R(0), R(5)], D;
+]
+"ftintrne.l.s" = [
+    0x011A_E400 = [F, F] => [R(0), R(5)], F;
+]
+"ftintrne.w.d" = [
+    0x011A_C800 = [F, F] => [R(0), R(5)], D;
+]
+"ftintrne.w.s" = [
+    0x011A_C400 = [F, F] => [R(0), R(5)], F;
+]
+"ftintrp.l.d" = [
+    0x011A_6800 = [F, F] => [R(0), R(5)], D;
+]
+"ftintrp.l.s" = [
+    0x011A_6400 = [F, F] => [R(0), R(5)], F;
+]
+"ftintrp.w.d" = [
+    0x011A_4800 = [F, F] => [R(0), R(5)], D;
+]
+"ftintrp.w.s" = [
+    0x011A_4400 = [F, F] => [R(0), R(5)], F;
+]
+"ftintrz.l.d" = [
+    0x011A_A800 = [F, F] => [R(0), R(5)], D;
+]
+"ftintrz.l.s" = [
+    0x011A_A400 = [F, F] => [R(0), R(5)], F;
+]
+"ftintrz.w.d" = [
+    0x011A_8800 = [F, F] => [R(0), R(5)], D;
+]
+"ftintrz.w.s" = [
+    0x011A_8400 = [F, F] => [R(0), R(5)], F;
+]
+"ibar" = [
+    0x3872_8000 = [Imm] => [Ubits(0, 15)];
+]
+"jirl" = [
+    0x4C00_0000 = [R, R, End, Imm] => [R(0), R(5), BSscaled(16, 2), Slice(10, 16, 2), A];
+]
+"jr" = [
+    0x4C00_0000 = [R] => [R(5)];
+]
+"la.pcrel" = [
+    0x02C0_0000_1C00_0000 = [R, Offset] => [R(0), C, R(32), C, R(37), Offset(PCADDU12I)];
+]
+"ld.b" = [
+    0x2800_0000 = [R, R, End, Imm] => [R(0), R(5), Sbits(10, 12)];
+    0x2800_0000_1C00_0000 = [R, Offset] => [R(0), C, R(32), C, R(37), Offset(PCADDU12I)];
+]
+"ld.bu" = [
+    0x2A00_0000 = [R, R, End, Imm] => [R(0), R(5), Sbits(10, 12)];
+    0x2A00_0000_1C00_0000 = [R, Offset] => [R(0), C, R(32), C, R(37), Offset(PCADDU12I)];
+]
+"ld.d" = [
+    0x28C0_0000 = [R, R, End, Imm] => [R(0), R(5), Sbits(10, 12)];
+    0x28C0_0000_1C00_0000 = [R, Offset] => [R(0), C, R(32), C, R(37), Offset(PCADDU12I)];
+]
+"ld.h" = [
+    0x2840_0000 = [R, R, End, Imm] => [R(0), R(5), Sbits(10, 12)];
+    0x2840_0000_1C00_0000 = [R, Offset] => [R(0), C, R(32), C, R(37), Offset(PCADDU12I)];
+]
+"ld.hu" = [
+    0x2A40_0000 = [R, R, End, Imm] => [R(0), R(5), Sbits(10, 12)];
+    0x2A40_0000_1C00_0000 = [R, Offset] => [R(0), C, R(32), C, R(37), Offset(PCADDU12I)];
+]
+"ld.w" = [
+    0x2880_0000 = [R, R, End, Imm] => [R(0), R(5), Sbits(10, 12)];
+    0x2880_0000_1C00_0000 = [R, Offset] => [R(0), C, R(32), C, R(37), Offset(PCADDU12I)];
+]
+"ld.wu" = [
+    0x2A80_0000 = [R, R, End, Imm] => [R(0), R(5), Sbits(10, 12)];
+    0x2A80_0000_1C00_0000 = [R, Offset] => [R(0), C, R(32), C, R(37), Offset(PCADDU12I)];
+]
+"ldgt.b" = [
+    0x3878_0000 = [R, R, R] => [R(0), R(5), R(10)];
+]
+"ldgt.d" = [
+    0x3879_8000 = [R, R, R] => [R(0), R(5), R(10)];
+]
+"ldgt.h" = [
+    0x3878_8000 = [R, R, R] => [R(0), R(5), R(10)];
+]
+"ldgt.w" = [
+    0x3879_0000 = [R, R, R] => [R(0), R(5), R(10)];
+]
+"ldle.b" = [
+    0x387A_0000 = [R, R, R] => [R(0), R(5), R(10)];
+]
+"ldle.d" = [
+    0x387B_8000 = [R, R, R] => [R(0), R(5), R(10)];
+]
+"ldle.h" = [
+    0x387A_8000 = [R, R, R] => [R(0), R(5), R(10)];
+]
+"ldle.w" = [
+    0x387B_0000 = [R, R, R] => [R(0), R(5), R(10)];
+]
+"ldptr.d" = [
+    0x2600_0000 = [R, R, End, Imm] => [R(0), R(5), BSscaled(14, 2), Slice(10, 14, 2), A];
+]
+"ldptr.w" = [
+    0x2400_0000 = [R, R, End, Imm] => [R(0), R(5), BSscaled(14, 2), Slice(10, 14, 2), A];
+]
+"ldx.b" = [
+    0x3800_0000 = [R, R, R] => [R(0), R(5), R(10)];
+]
+"ldx.bu" = [
+    0x3820_0000 = [R, R, R] => [R(0), R(5), R(10)];
+]
+"ldx.d" = [
+    0x380C_0000 = [R, R, R] => [R(0), R(5), R(10)];
+]
+"ldx.h" = [
+    0x3804_0000 = [R, R, R] => [R(0), R(5), R(10)];
+]
+"ldx.hu" = [
+    0x3824_0000 = [R, R, R] => [R(0), R(5), R(10)];
+]
+"ldx.w" = [
+    0x3808_0000 = [R, R, R] => [R(0), R(5), R(10)];
+]
+"ldx.wu" = [
+    0x3828_0000 = [R, R, R] => [R(0), R(5), R(10)];
+]
+"ll.d" = [
+    0x2200_0000 = [R, R, End, Imm] => [R(0), R(5), BSscaled(14, 2), Slice(10, 14, 2), A];
+]
+"ll.w" = [
+    0x2000_0000 = [R, R, End, Imm] => [R(0), R(5), BSscaled(14, 2), Slice(10, 14, 2), A];
+]
+"lu12i.w" = [
+    0x1400_0000 = [R, Imm] => [R(0), Sbits(5, 20)];
+]
+"lu32i.d" = [
+    0x1600_0000 = [R, Imm] => [R(0), Sbits(5, 20)];
+]
+"lu52i.d" = [
+    0x0300_0000 = [R, R, Imm] => [R(0), R(5), Sbits(10, 12)];
+]
+"maskeqz" = [
+    0x0013_0000 = [R, R, R] => [R(0), R(5), R(10)];
+]
+"masknez" = [
+    0x0013_8000 = [R, R, R] => [R(0), R(5), R(10)];
+]
+"mod.d" = [
+    0x0022_8000 = [R, R, R] => [R(0), R(5), R(10)];
+]
+"mod.du" = [
+    0x0023_8000 = [R, R, R] => [R(0), R(5), R(10)];
+]
+"mod.w" = [
+    0x0020_8000 = [R, R, R] => [R(0), R(5), R(10)];
+]
+"mod.wu" = [
+    0x0021_8000 = [R, R, R] => [R(0), R(5), R(10)];
+]
+"movcf2fr" = [
+    0x0114_D400 = [F, FCC] => [R(0), R(5)], F;
+]
+"movcf2gr" = [
+    0x0114_DC00 = [R, FCC] => [R(0), R(5)], F;
+]
+"move" = [
+    0x0015_0000 = [R, R] => [R(0), R(5)];
+]
+"movfcsr2gr" = [
+    0x0114_C800 = [R, FCSR] => [R(0), R(5)], F;
+]
+"movfr2cf" = [
+    0x0114_D000 = [FCC, F] => [R(0), R(5)], F;
+]
+"movfr2gr.d" = [
+    0x0114_B800 = [R, F] => [R(0), R(5)], D;
+]
+"movfr2gr.s" = [
+    0x0114_B400 = [R, F] => [R(0), R(5)], F;
+]
+"movfrh2gr.s" = [
+    0x0114_BC00 = [R, F] => [R(0), R(5)], D;
+]
+"movgr2cf" = [
+    0x0114_D800 = [FCC, R] => [R(0), R(5)], F;
+]
+"movgr2fcsr" = [
+    0x0114_C000 = [FCSR, R] => [R(0), R(5)], F;
+]
+"movgr2fr.d" = [
+    0x0114_A800 = [F, R] => [R(0), R(5)], D;
+]
+"movgr2fr.w" = [
+    0x0114_A400 = [F, R] => [R(0), R(5)], F;
+]
+"movgr2frh.w" = [
+    0x0114_AC00 = [F, R] => [R(0), R(5)], D;
+]
+"mul.d" = [
+    0x001D_8000 = [R, R, R] => [R(0), R(5), R(10)];
+]
+"mul.w" = [
+    0x001C_0000 = [R, R, R] => [R(0), R(5), R(10)];
+]
+"mulh.d" = [
+    0x001E_0000 = [R, R, R] => [R(0), R(5), R(10)];
+]
+"mulh.du" = [
+    0x001E_8000 = [R, R, R] => [R(0), R(5), R(10)];
+]
+"mulh.w" = [
+    0x001C_8000 = [R, R, R] => [R(0), R(5), R(10)];
+]
+"mulh.wu" = [
+    0x001D_0000 = [R, R, R] => [R(0), R(5), R(10)];
+]
+"mulw.d.w" = [
+    0x001F_0000 = [R, R, R] => [R(0), R(5), R(10)];
+]
+"mulw.d.wu" = [
+    0x001F_8000 = [R, R, R] => [R(0), R(5), R(10)];
+]
+"nop" = [
+    0x0340_0000 = [] => [];
+]
+"nor" = [
+    0x0014_0000 = [R, R, R] => [R(0), R(5), R(10)];
+]
+"or" = [
+    0x0015_0000 = [R, R, R] => [R(0), R(5), R(10)];
+]
+"ori" = [
+    0x0380_0000 = [R, R, Imm] => [R(0), R(5), Ubits(10, 12)];
+]
+"orn" = [
+    0x0016_0000 = [R, R, R] => [R(0), R(5), R(10)];
+]
+"pcaddi" = [
+    0x1800_0000 = [R, Imm] => [R(0), Sbits(5, 20)];
+    0x1800_0000 = [R, Offset] => [R(0), Offset(PCADDI)];
+]
+"pcaddu12i" = [
+    0x1C00_0000 = [R, Imm] => [R(0), Sbits(5, 20)];
+]
+"pcaddu18i" = [
+    0x1E00_0000 = [R, Imm] => [R(0), Sbits(5, 20)];
+]
+"pcalau12i" = [
+    0x1A00_0000 = [R, Imm] => [R(0), Sbits(5, 20)];
+]
+"preld" = [
+    0x2AC0_0000 = [Imm, R, End, Imm] => [Ubits(0, 5), R(5), Sbits(10, 12)];
+]
+"preldx" = [
+    0x382C_0000 = [Imm, R, R] => [Ubits(0, 5), R(5), R(10)];
+]
+"rdtime.d" = [
+    0x0000_6800 = [R, R] => [R(0), R(5)];
+]
+"rdtimeh.w" = [
+    0x0000_6400 = [R, R] => [R(0), R(5)];
+]
+"rdtimel.w" = [
+    0x0000_6000 = [R, R] => [R(0), R(5)];
+]
+"ret" = [
+    0x4C00_0020 = [] => [];
+]
+"revb.2h" = [
+    0x0000_3000 = [R, R] => [R(0), R(5)];
+]
+"revb.2w" = [
+    0x0000_3800 = [R, R] => [R(0), R(5)];
+]
+"revb.4h" = [
+    0x0000_3400 = [R, R] => [R(0), R(5)];
+]
+"revb.d" = [
+    0x0000_3C00 = [R, R] => [R(0), R(5)];
+]
+"revh.2w" = [
+    0x0000_4000 = [R, R] => [R(0), R(5)];
+]
+"revh.d" = [
+    0x0000_4400 = [R, R] => [R(0), R(5)];
+]
+"rotr.d" = [
+    0x001B_8000 = [R, R, R] => [R(0), R(5), R(10)];
+]
+"rotr.w" = [
+    0x001B_0000 = [R, R, R] => [R(0), R(5), R(10)];
+]
+"rotri.d" = [
+    0x004D_0000 = [R, R, Imm] => [R(0), R(5), Ubits(10, 6)];
+]
+"rotri.w" = [
+    0x004C_8000 = [R, R, Imm] => [R(0), R(5), Ubits(10, 5)];
+]
+"sc.d" = [
+    0x2300_0000 = [R, R, End, Imm] => [R(0), R(5), BSscaled(14, 2), Slice(10, 14, 2), A];
+]
+"sc.w" = [
+    0x2100_0000 = [R, R, End, Imm] => [R(0), R(5), BSscaled(14, 2), Slice(10, 14, 2), A];
+]
+"sll.d" = [
+    0x0018_8000 = [R, R, R] => [R(0), R(5), R(10)];
+]
+"sll.w" = [
+    0x0017_0000 = [R, R, R] => [R(0), R(5), R(10)];
+]
+"slli.d" = [
+    0x0041_0000 = [R, R, Imm] => [R(0), R(5), Ubits(10, 6)];
+]
+"slli.w" = [
+    0x0040_8000 = [R, R, Imm] => [R(0), R(5), Ubits(10, 5)];
+]
+"slt" = [
+    0x0012_0000 = [R, R, R] => [R(0), R(5), R(10)];
+]
+"slti" = [
+    0x0200_0000 = [R, R, Imm] => [R(0), R(5), Sbits(10, 12)];
+]
+"sltu" = [
+    0x0012_8000 = [R, R, R] => [R(0), R(5), R(10)];
+]
+"sltui" = [
+    0x0240_0000 = [R, R, Imm] => [R(0), R(5), Sbits(10, 12)];
+]
+"sra.d" = [
+    0x0019_8000 = [R, R, R] => [R(0), R(5), R(10)];
+]
+"sra.w" = [
+    0x0018_0000 = [R, R, R] => [R(0), R(5), R(10)];
+]
+"srai.d" = [
+    0x0049_0000 = [R, R, Imm] => [R(0), R(5), Ubits(10, 6)];
+]
+"srai.w" = [
+    0x0048_8000 = [R, R, Imm] => [R(0), R(5), Ubits(10, 5)];
+]
+"srl.d" = [
+    0x0019_0000 = [R, R, R] => [R(0), R(5), R(10)];
+]
+"srl.w" = [
+    0x0017_8000 = [R, R, R] => [R(0), R(5), R(10)];
+]
+"srli.d" = [
+    0x0045_0000 = [R, R, Imm] => [R(0), R(5), Ubits(10, 6)];
+]
+"srli.w" = [
+    0x0044_8000 = [R, R, Imm] => [R(0), R(5), Ubits(10, 5)];
+]
+"st.b" = [
+    0x2900_0000 = [R, R, End, Imm] => [R(0), R(5), Sbits(10, 12)];
+    0x2900_0000_1C00_0000 = [R, Offset, R] => [R(32), Offset(PCADDU12I), R(0), C, R(37)];
+]
+"st.d" = [
+    0x29C0_0000 = [R, R, End, Imm] => [R(0), R(5), Sbits(10, 12)];
+    0x29C0_0000_1C00_0000 = [R, Offset, R] => [R(32), Offset(PCADDU12I), R(0), C, R(37)];
+]
+"st.h" = [
+    0x2940_0000 = [R, R, End, Imm] => [R(0), R(5), Sbits(10, 12)];
+    0x2940_0000_1C00_0000 = [R, Offset, R] => [R(32), Offset(PCADDU12I), R(0), C, R(37)];
+]
+"st.w" = [
+    0x2980_0000 = [R, R, End, Imm] => [R(0), R(5), Sbits(10, 12)];
+    0x2980_0000_1C00_0000 = [R, Offset, R] => [R(32), Offset(PCADDU12I), R(0), C, R(37)];
+]
+"stgt.b" = [
+    0x387C_0000 = [R, R, R] => [R(0), R(5), R(10)];
+]
+"stgt.d" = [
+    0x387D_8000 = [R, R, R] => [R(0), R(5), R(10)];
+]
+"stgt.h" = [
+    0x387C_8000 = [R, R, R] => [R(0), R(5), R(10)];
+]
+"stgt.w" = [
+    0x387D_0000 = [R, R, R] => [R(0), R(5), R(10)];
+]
+"stle.b" = [
+    0x387E_0000 = [R, R, R] => [R(0), R(5), R(10)];
+]
+"stle.d" = [
+    0x387F_8000 = [R, R, R] => [R(0), R(5), R(10)];
+]
+"stle.h" = [
+    0x387E_8000 = [R, R, R] => [R(0), R(5), R(10)];
+]
+"stle.w" = [
+    0x387F_0000 = [R, R, R] => [R(0), R(5), R(10)];
+]
+"stptr.d" = [
+    0x2700_0000 = [R, R, End, Imm] => [R(0), R(5), BSscaled(14, 2), Slice(10, 14, 2), A];
+]
+"stptr.w" = [
+    0x2500_0000 = [R, R, End, Imm] => [R(0), R(5), BSscaled(14, 2), Slice(10, 14, 2), A];
+]
+"stx.b" = [
+    0x3810_0000 = [R, R, R] => [R(0), R(5), R(10)];
+]
+"stx.d" = [
+    0x381C_0000 = [R, R, R] => [R(0), R(5), R(10)];
+]
+"stx.h" = [
+    0x3814_0000 = [R, R, R] => [R(0), R(5), R(10)];
+]
+"stx.w" = [
+    0x3818_0000 = [R, R, R] => [R(0), R(5), R(10)];
+]
+"sub.d" = [
+    0x0011_8000 = [R, R, R] => [R(0), R(5), R(10)];
+]
+"sub.w" = [
+    0x0011_0000 = [R, R, R] => [R(0), R(5), R(10)];
+]
+"syscall" = [
+    0x002B_0000 = [Imm] => [Ubits(0, 15)];
+]
+"tail36" = [
+    0x4C00_0000_1E00_0000 = [R, Offset] => [R(0), C, R(37), Offset(PCADDU18I)];
+]
+"xor" = [
+    0x0015_8000 = [R, R, R] => [R(0), R(5), R(10)];
+]
+"xori" = [
+    0x03C0_0000 = [R, R, Imm] => [R(0), R(5), Ubits(10, 12)];
+]
+)
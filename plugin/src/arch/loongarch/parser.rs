@@ -0,0 +1,283 @@
+use syn::{parse, Token};
+
+use lazy_static::lazy_static;
+
+use crate::parse_helpers::{parse_ident_or_rust_keyword, ParseOptExt};
+
+use super::Context;
+use super::ast::{Instruction, RawArg, RegId, RegKind, RegFamily};
+
+use std::collections::HashMap;
+
+// parses a full instruction
+// syntax for a single op: ident ("." ident)* (arg ("," arg)*)? ";"
+pub(super) fn parse_instruction(ctx: &mut Context, input: parse::ParseStream) -> parse::Result<(Instruction, Vec<RawArg>)> {
+    let span = input.cursor().span();
+
+    // read the full dot-separated op. Like on rv64, the dotted parts are all part of the mnemnonic.
+    let mut name = parse_ident_or_rust_keyword(input)?.to_string();
+
+    while input.peek(Token![.]) {
+        let _: Token![.] = input.parse()?;
+        let part = parse_ident_or_rust_keyword(input)?;
+        name.push('.');
+        name.push_str(&part.to_string());
+    }
+
+    let mut args = Vec::new();
+
+    // parse 0 or more comma-separated args
+    if !(input.is_empty() || input.peek(Token![;])) {
+        args.push(parse_arg(ctx, input)?);
+
+        while input.peek(Token![,]) {
+            let _: Token![,] = input.parse()?;
+
+            args.push(parse_arg(ctx, input)?);
+        }
+    }
+
+    Ok((
+        Instruction {
+            span,
+            name
+        },
+        args
+    ))
+}
+
+/// tries to parse a full arg definition
+fn parse_arg(ctx: &mut Context, input: parse::ParseStream) -> parse::Result<RawArg> {
+    let _start = input.cursor().span(); // FIXME can't join spans yet
+
+    // a label
+    if let Some(jump) = input.parse_opt()? {
+        return Ok(RawArg::JumpTarget {
+            jump
+        });
+    }
+
+    // register
+    if let Some(reg) = parse_reg(ctx, input)? {
+        return Ok(RawArg::Direct {
+            reg,
+            span: _start
+        })
+    }
+
+    // immediate
+    let arg: syn::Expr = input.parse()?;
+    Ok(RawArg::Immediate {
+        value: arg
+    })
+}
+
+fn parse_reg(ctx: &mut Context, input: parse::ParseStream) -> parse::Result<Option<RegKind>> {
+    let name = match input.step(|cursor| {
+        if let Some((ident, rest)) = cursor.ident() {
+            let mut ident = ident.to_string();
+
+            // dynamic registers are only recognized when followed by their parenthesized expression
+            if LOONGARCH_FAMILIES.contains_key(&*ident) {
+                if rest.group(proc_macro2::Delimiter::Parenthesis).is_some() {
+                    return Ok((ident, rest));
+                }
+                return Err(cursor.error("expected register"));
+            }
+
+            if let Some(repl) = ctx.state.file_data.aliases.get(&ident) {
+                ident = repl.clone();
+            }
+
+            if LOONGARCH_REGISTERS.contains_key(&*ident) {
+                return Ok((ident, rest));
+            }
+        }
+        Err(cursor.error("expected identifier"))
+    }) {
+        Ok(name) => name,
+        Err(_) => return Ok(None)
+    };
+
+    if let Some(&id) = LOONGARCH_REGISTERS.get(&*name) {
+        Ok(Some(RegKind::Static(id)))
+
+    } else if let Some(&family) = LOONGARCH_FAMILIES.get(&*name) {
+
+        // parse the dynamic register expression
+        let inner;
+        let _ = syn::parenthesized!(inner in input);
+        let inner = &inner;
+
+        let expr: syn::Expr = inner.parse()?;
+
+        Ok(Some(RegKind::Dynamic(family, expr)))
+    } else {
+        unreachable!();
+    }
+}
+
+lazy_static!{
+    static ref LOONGARCH_REGISTERS: HashMap<&'static str, RegId> = {
+        use self::RegId::*;
+
+        static MAP: &[(&str, RegId)] = &[
+            ("r0" , R0 ),
+            ("r1" , R1 ),
+            ("r2" , R2 ),
+            ("r3" , R3 ),
+            ("r4" , R4 ),
+            ("r5" , R5 ),
+            ("r6" , R6 ),
+            ("r7" , R7 ),
+            ("r8" , R8 ),
+            ("r9" , R9 ),
+            ("r10", R10),
+            ("r11", R11),
+            ("r12", R12),
+            ("r13", R13),
+            ("r14", R14),
+            ("r15", R15),
+            ("r16", R16),
+            ("r17", R17),
+            ("r18", R18),
+            ("r19", R19),
+            ("r20", R20),
+            ("r21", R21),
+            ("r22", R22),
+            ("r23", R23),
+            ("r24", R24),
+            ("r25", R25),
+            ("r26", R26),
+            ("r27", R27),
+            ("r28", R28),
+            ("r29", R29),
+            ("r30", R30),
+            ("r31", R31),
+
+            ("zero", R0 ),
+            ("ra"  , R1 ),
+            ("tp"  , R2 ),
+            ("sp"  , R3 ),
+            ("a0"  , R4 ),
+            ("a1"  , R5 ),
+            ("a2"  , R6 ),
+            ("a3"  , R7 ),
+            ("a4"  , R8 ),
+            ("a5"  , R9 ),
+            ("a6"  , R10),
+            ("a7"  , R11),
+            ("t0"  , R12),
+            ("t1"  , R13),
+            ("t2"  , R14),
+            ("t3"  , R15),
+            ("t4"  , R16),
+            ("t5"  , R17),
+            ("t6"  , R18),
+            ("t7"  , R19),
+            ("t8"  , R20),
+            ("fp"  , R22),
+            ("s9"  , R22),
+            ("s0"  , R23),
+            ("s1"  , R24),
+            ("s2"  , R25),
+            ("s3"  , R26),
+            ("s4"  , R27),
+            ("s5"  , R28),
+            ("s6"  , R29),
+            ("s7"  , R30),
+            ("s8"  , R31),
+
+            ("f0" , F0 ),
+            ("f1" , F1 ),
+            ("f2" , F2 ),
+            ("f3" , F3 ),
+            ("f4" , F4 ),
+            ("f5" , F5 ),
+            ("f6" , F6 ),
+            ("f7" , F7 ),
+            ("f8" , F8 ),
+            ("f9" , F9 ),
+            ("f10", F10),
+            ("f11", F11),
+            ("f12", F12),
+            ("f13", F13),
+            ("f14", F14),
+            ("f15", F15),
+            ("f16", F16),
+            ("f17", F17),
+            ("f18", F18),
+            ("f19", F19),
+            ("f20", F20),
+            ("f21", F21),
+            ("f22", F22),
+            ("f23", F23),
+            ("f24", F24),
+            ("f25", F25),
+            ("f26", F26),
+            ("f27", F27),
+            ("f28", F28),
+            ("f29", F29),
+            ("f30", F30),
+            ("f31", F31),
+
+            ("fa0" , F0 ),
+            ("fa1" , F1 ),
+            ("fa2" , F2 ),
+            ("fa3" , F3 ),
+            ("fa4" , F4 ),
+            ("fa5" , F5 ),
+            ("fa6" , F6 ),
+            ("fa7" , F7 ),
+            ("ft0" , F8 ),
+            ("ft1" , F9 ),
+            ("ft2" , F10),
+            ("ft3" , F11),
+            ("ft4" , F12),
+            ("ft5" , F13),
+            ("ft6" , F14),
+            ("ft7" , F15),
+            ("ft8" , F16),
+            ("ft9" , F17),
+            ("ft10", F18),
+            ("ft11", F19),
+            ("ft12", F20),
+            ("ft13", F21),
+            ("ft14", F22),
+            ("ft15", F23),
+            ("fs0" , F24),
+            ("fs1" , F25),
+            ("fs2" , F26),
+            ("fs3" , F27),
+            ("fs4" , F28),
+            ("fs5" , F29),
+            ("fs6" , F30),
+            ("fs7" , F31),
+
+            ("fcc0", FCC0),
+            ("fcc1", FCC1),
+            ("fcc2", FCC2),
+            ("fcc3", FCC3),
+            ("fcc4", FCC4),
+            ("fcc5", FCC5),
+            ("fcc6", FCC6),
+            ("fcc7", FCC7),
+
+            ("fcsr0", FCSR0),
+            ("fcsr1", FCSR1),
+            ("fcsr2", FCSR2),
+            ("fcsr3", FCSR3),
+        ];
+        MAP.iter().cloned().collect()
+    };
+
+    static ref LOONGARCH_FAMILIES: HashMap<&'static str, RegFamily> = {
+        static MAP: &[(&str, RegFamily)] = &[
+            ("R", RegFamily::INTEGER),
+            ("F", RegFamily::FP),
+            ("FCC", RegFamily::FCC),
+            ("FCSR", RegFamily::FCSR),
+        ];
+        MAP.iter().cloned().collect()
+    };
+}
@@ -7,12 +7,20 @@ use std::fmt::Debug;
 
 pub mod x64;
 pub mod aarch64;
+pub mod riscv;
+pub mod arm;
+pub mod loongarch;
 
 pub(crate) trait Arch : Debug + Send {
     fn name(&self) -> &str;
     fn set_features(&mut self, features: &[syn::Ident]);
     fn handle_static_reloc(&self, stmts: &mut Vec<Stmt>, reloc: Jump, size: Size);
-    fn default_align(&self) -> u8;
+    /// The filler `.align` pads with when none is given. Fillers longer than a byte are repeated as a whole.
+    fn default_align(&self) -> &'static [u8];
+    /// Whether data directives on this architecture are stored in big-endian byte order.
+    fn big_endian(&self) -> bool {
+        false
+    }
     fn compile_instruction(&self, state: &mut State, input: parse::ParseStream) -> parse::Result<()>;
 }
 
@@ -43,8 +51,8 @@ impl Arch for DummyArch {
         emit_error_at(span, "Current assembling architecture is undefined. Define it using a .arch directive".into());
     }
 
-    fn default_align(&self) -> u8 {
-        0
+    fn default_align(&self) -> &'static [u8] {
+        &[0]
     }
 
     fn compile_instruction(&self, _state: &mut State, input: parse::ParseStream) -> parse::Result<()> {
@@ -57,7 +65,13 @@ pub(crate) fn from_str(s: &str) -> Option<Box<dyn Arch>> {
     match s {
         "x64" => Some(Box::new(x64::Archx64::default())),
         "x86" => Some(Box::new(x64::Archx86::default())),
+        "x86_16" => Some(Box::new(x64::Archx86_16::default())),
         "aarch64" => Some(Box::new(aarch64::ArchAarch64::default())),
+        "aarch64_be" => Some(Box::new(aarch64::ArchAarch64::big_endian())),
+        "rv64" => Some(Box::new(riscv::ArchRv64::default())),
+        "arm" => Some(Box::new(arm::ArchArm::default())),
+        "thumb" => Some(Box::new(arm::ArchArm::thumb())),
+        "loongarch64" => Some(Box::new(loongarch::ArchLoongArch64::default())),
         "unknown" => Some(Box::new(DummyArch::new("unknown"))),
         _ => None
     }
@@ -67,7 +81,15 @@ pub(crate) fn from_str(s: &str) -> Option<Box<dyn Arch>> {
 pub const CURRENT_ARCH: &str = "x64";
 #[cfg(target_arch="x86")]
 pub const CURRENT_ARCH: &str = "x86";
-#[cfg(target_arch="aarch64")]
+#[cfg(all(target_arch="aarch64", target_endian="little"))]
 pub const CURRENT_ARCH: &str = "aarch64";
-#[cfg(not(any(target_arch="x86", target_arch="x86_64", target_arch="aarch64")))]
+#[cfg(all(target_arch="aarch64", target_endian="big"))]
+pub const CURRENT_ARCH: &str = "aarch64_be";
+#[cfg(target_arch="riscv64")]
+pub const CURRENT_ARCH: &str = "rv64";
+#[cfg(target_arch="arm")]
+pub const CURRENT_ARCH: &str = "arm";
+#[cfg(target_arch="loongarch64")]
+pub const CURRENT_ARCH: &str = "loongarch64";
+#[cfg(not(any(target_arch="x86", target_arch="x86_64", target_arch="aarch64", target_arch="riscv64", target_arch="arm", target_arch="loongarch64")))]
 pub const CURRENT_ARCH: &str = "unknown";
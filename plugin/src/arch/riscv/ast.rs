@@ -0,0 +1,116 @@
+use syn;
+use proc_macro2::Span;
+
+use crate::common::Jump;
+
+
+// Register id without indication of its usage. Either a static Regid or a family identifier + expression to choose the register
+#[derive(Debug, Clone)]
+pub enum RegKind {
+    Static(RegId),
+    Dynamic(RegFamily, syn::Expr)
+}
+
+// a register identifier. This identifies an architecturally completely separate register.
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+pub enum RegId {
+    // integer registers. x0 is hardwired to zero.
+    X0 = 0x00, X1 = 0x01, X2 = 0x02, X3 = 0x03,
+    X4 = 0x04, X5 = 0x05, X6 = 0x06, X7 = 0x07,
+    X8 = 0x08, X9 = 0x09, X10= 0x0A, X11= 0x0B,
+    X12= 0x0C, X13= 0x0D, X14= 0x0E, X15= 0x0F,
+    X16= 0x10, X17= 0x11, X18= 0x12, X19= 0x13,
+    X20= 0x14, X21= 0x15, X22= 0x16, X23= 0x17,
+    X24= 0x18, X25= 0x19, X26= 0x1A, X27= 0x1B,
+    X28= 0x1C, X29= 0x1D, X30= 0x1E, X31= 0x1F,
+
+    // floating point registers. Used as single or double precision depending on the instruction.
+    F0 = 0x20, F1 = 0x21, F2 = 0x22, F3 = 0x23,
+    F4 = 0x24, F5 = 0x25, F6 = 0x26, F7 = 0x27,
+    F8 = 0x28, F9 = 0x29, F10= 0x2A, F11= 0x2B,
+    F12= 0x2C, F13= 0x2D, F14= 0x2E, F15= 0x2F,
+    F16= 0x30, F17= 0x31, F18= 0x32, F19= 0x33,
+    F20= 0x34, F21= 0x35, F22= 0x36, F23= 0x37,
+    F24= 0x38, F25= 0x39, F26= 0x3A, F27= 0x3B,
+    F28= 0x3C, F29= 0x3D, F30= 0x3E, F31= 0x3F,
+}
+
+// register family. INTEGER = xN, FP = fN
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+pub enum RegFamily {
+    INTEGER = 0,
+    FP      = 1,
+}
+
+impl RegId {
+    /// Encode this RegId in a 5-bit value
+    pub fn code(self) -> u8 {
+        self as u8 & 0x1F
+    }
+
+    /// Returns what family this Regid is from
+    pub fn family(self) -> RegFamily {
+        match self as u8 >> 5 {
+            0 => RegFamily::INTEGER,
+            1 => RegFamily::FP,
+            _ => unreachable!()
+        }
+    }
+}
+
+impl RegKind {
+    /// Returns the family that this regkind is of
+    pub fn family(&self) -> RegFamily {
+        match *self {
+            RegKind::Static(code) => code.family(),
+            RegKind::Dynamic(family, _) => family
+        }
+    }
+}
+
+// basic parse results, before we start doing any kind of checking
+#[derive(Debug)]
+pub enum RawArg {
+    // A memory reference, [base] or [base, offset]
+    Reference {
+        span: Span,
+        base: RegKind,
+        offset: Option<syn::Expr>
+    },
+    // direct register reference
+    Direct {
+        span: Span,
+        reg: RegKind
+    },
+    // jump target. Also used by PC-relative address calculations
+    JumpTarget {
+        jump: Jump
+    },
+    // just an arbitrary expression
+    Immediate {
+        value: syn::Expr
+    }
+}
+
+// Contains the actual instruction mnemnonic.
+#[derive(Debug)]
+pub struct Instruction {
+    pub span: Span,
+    pub name: String
+}
+
+// flat arg list after matching, for encoding
+#[derive(Debug)]
+pub enum FlatArg {
+    Direct {
+        span: Span,
+        reg: RegKind
+    },
+    Immediate {
+        value: syn::Expr,
+    },
+    JumpTarget {
+        jump: Jump
+    },
+    Default
+}
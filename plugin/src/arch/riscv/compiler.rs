@@ -0,0 +1,380 @@
+use super::matching::MatchData;
+use super::riscvdata::{Command, Relocation, ROUNDING_MODES, CSR_MAP};
+use super::Context;
+use super::ast::{FlatArg, RegKind, RegFamily};
+
+use crate::common::{Stmt, Size, delimited, emit_error_at, bitmask};
+use crate::parse_helpers::{as_ident, as_number, as_signed_number};
+
+use syn::spanned::Spanned;
+use quote::{quote, quote_spanned};
+
+pub(super) fn compile_instruction(ctx: &mut Context, data: MatchData) -> Result<(), Option<String>> {
+    // determine if the features required for this op are fulfilled
+    if !ctx.features.contains(data.data.features) {
+        return Err(Some(format!(
+            "This instruction uses features that are not indicated to be available: {}",
+            data.data.features - ctx.features
+        )));
+    }
+
+    let mut cursor = 0usize;
+
+    // All static bitfields (compile-time constant) will be encoded into this map of (offset, bitfield)
+    let mut statics = Vec::new();
+    // All dynamic bitfields (run-time determined) will be encoded into this map of (offset, TokenStream)
+    let mut dynamics = Vec::new();
+    // Any relocations will be encoded into this list
+    let mut relocations = Vec::new();
+
+    for command in data.data.commands.iter() {
+        match *command {
+            // special commands that don't check the current arg
+            Command::A => {
+                cursor += 1;
+                continue
+            },
+            Command::C => {
+                cursor -= 1;
+                continue
+            },
+            _ => ()
+        }
+
+        let arg = data.args.get(cursor).expect("Invalid encoding data, tried to process more arguments than given");
+
+        match *arg {
+            FlatArg::Direct { span, reg: RegKind::Static(id) } => match *command {
+                Command::R(offset) => {
+                    statics.push((offset, u32::from(id.code())));
+                },
+                Command::RNz(offset) => {
+                    if id.code() == 0 {
+                        emit_error_at(span, "Field does not support register x0".into());
+                        return Err(None);
+                    }
+                    statics.push((offset, u32::from(id.code())));
+                },
+                Command::RNzSp(offset) => {
+                    if id.code() == 0 || id.code() == 2 {
+                        emit_error_at(span, "Field does not support registers x0 and x2".into());
+                        return Err(None);
+                    }
+                    statics.push((offset, u32::from(id.code())));
+                },
+                Command::RC(offset) => {
+                    if id.code() < 8 || id.code() >= 16 {
+                        let family = if id.family() == RegFamily::FP { "f" } else { "x" };
+                        emit_error_at(span, format!("Field only supports registers {0}8-{0}15", family));
+                        return Err(None);
+                    }
+                    statics.push((offset, u32::from(id.code() - 8)));
+                },
+                Command::RIs(code) => {
+                    if id.code() != code {
+                        emit_error_at(span, format!("Field only supports register x{}", code));
+                        return Err(None);
+                    }
+                },
+                _ => panic!("Invalid argument processor")
+            },
+            FlatArg::Direct { span, reg: RegKind::Dynamic(_, ref expr) } => match *command {
+                Command::R(offset)
+                | Command::RNz(offset)
+                | Command::RNzSp(offset) => {
+                    dynamics.push((offset, quote_spanned!{ span=>
+                        #expr & 0x1F
+                    }));
+                },
+                Command::RC(offset) => {
+                    dynamics.push((offset, quote_spanned!{ span=>
+                        #expr & 0x7
+                    }));
+                },
+                Command::RIs(code) => {
+                    emit_error_at(span, format!("Field only supports register x{}, which cannot be chosen dynamically", code));
+                    return Err(None);
+                },
+                _ => panic!("Invalid argument processor")
+            },
+            FlatArg::Immediate { ref value } => match *command {
+
+                // unsigned integer encodings
+                Command::Ubits(offset, bitlen) => {
+                    let mask = bitmask(bitlen);
+                    if let Some(value) = unsigned_rangecheck(value, 0, mask, 0) {
+                        statics.push((offset, value?));
+                    } else {
+                        dynamics.push((offset, quote_spanned!{ value.span()=>
+                            #value & #mask
+                        }));
+                    }
+                },
+
+                // signed integer encoding
+                Command::Sbits(offset, bitlen) => {
+                    let mask = bitmask(bitlen);
+                    let half = -1i32 << (bitlen - 1);
+                    if let Some(value) = signed_rangecheck(value, half, mask as i32 + half, 0) {
+                        statics.push((offset, (value? as u32) & mask));
+                    } else {
+                        dynamics.push((offset, quote_spanned!{ value.span()=>
+                            (#value as u32) & #mask
+                        }));
+                    }
+                },
+
+                // nonconsuming integer checks
+                Command::BUscaled(bitlen, shift) => {
+                    let mask = bitmask(bitlen);
+                    if let Some(value) = unsigned_rangecheck(value, 0, mask, shift) {
+                        value?;
+                    }
+                },
+                Command::BSscaled(bitlen, shift) => {
+                    let mask = bitmask(bitlen);
+                    let half = -1i32 << (bitlen - 1);
+                    if let Some(value) = signed_rangecheck(value, half, mask as i32 + half, shift) {
+                        value?;
+                    }
+                },
+                Command::BNz => {
+                    if let Some(0) = as_signed_number(value) {
+                        emit_error_at(value.span(), "Value cannot be zero".into());
+                        return Err(None);
+                    }
+                },
+                Command::Slice(offset, bitlen, shift) => {
+                    let mask = bitmask(bitlen);
+                    if let Some(value) = as_signed_number(value) {
+                        statics.push((offset, ((value >> shift) as u32) & mask));
+                    } else {
+                        dynamics.push((offset, quote_spanned!{ value.span()=>
+                            ((#value >> #shift) as u32) & #mask
+                        }));
+                    }
+                },
+
+                // named operands
+                Command::Rm(offset) => {
+                    let name = as_ident(value).expect("bad command data").to_string();
+                    if let Some(&bits) = ROUNDING_MODES.get(&&*name) {
+                        statics.push((offset, bits));
+                    } else {
+                        emit_error_at(value.span(), format!("Unknown rounding mode '{}'", name));
+                        return Err(None);
+                    }
+                },
+                Command::Fence(offset) => {
+                    let name = as_ident(value).expect("bad command data").to_string();
+                    if let Some(bits) = encode_fence_set(&name) {
+                        statics.push((offset, bits));
+                    } else {
+                        emit_error_at(value.span(), format!("Invalid fence set '{}'. Expected a combination of i, o, r and w in that order", name));
+                        return Err(None);
+                    }
+                },
+                Command::Csr(offset) => {
+                    let name = as_ident(value).expect("bad command data").to_string();
+                    if let Some(&bits) = CSR_MAP.get(&&*name) {
+                        statics.push((offset, bits));
+                    } else {
+                        emit_error_at(value.span(), format!("Unknown control and status register '{}'", name));
+                        return Err(None);
+                    }
+                },
+
+                // jump targets also accept immediates
+                Command::Offset(relocation) => match relocation {
+                    Relocation::B
+                    | Relocation::J
+                    | Relocation::CB
+                    | Relocation::CJ => {
+                        let (slices, bits, shift) = relocation.slices();
+                        let mask = bitmask(bits - shift);
+                        let half = -1i32 << (bits - shift - 1);
+                        if let Some(value) = signed_rangecheck(value, half, mask as i32 + half, shift) {
+                            let value = value? << shift;
+                            for &(offset, bitlen, shift) in slices {
+                                statics.push((offset, ((value >> shift) as u32) & bitmask(bitlen)));
+                            }
+                        } else {
+                            for &(offset, bitlen, shift) in slices {
+                                let mask = bitmask(bitlen);
+                                dynamics.push((offset, quote_spanned!{ value.span()=>
+                                    ((#value >> #shift) as u32) & #mask
+                                }));
+                            }
+                        }
+                    },
+                    // the offset is split in a high part for the auipc, and a low part for the instruction after it,
+                    // which is sign extended.
+                    Relocation::AUIPC_I
+                    | Relocation::AUIPC_S => {
+                        let low: &[(u8, u8, u8)] = if relocation == Relocation::AUIPC_I {
+                            &[(52, 12, 0)]
+                        } else {
+                            &[(39, 5, 0), (57, 7, 5)]
+                        };
+
+                        if let Some(number) = as_signed_number(value) {
+                            let high = number + 0x800;
+                            if high < i64::from(i32::MIN) || high > i64::from(i32::MAX) {
+                                emit_error_at(value.span(), "Value out of range".into());
+                                return Err(None);
+                            }
+                            statics.push((12, ((high >> 12) as u32) & 0xF_FFFF));
+                            for &(offset, bitlen, shift) in low {
+                                statics.push((offset, ((number >> shift) as u32) & bitmask(bitlen)));
+                            }
+                        } else {
+                            dynamics.push((12, quote_spanned!{ value.span()=>
+                                (((#value + 0x800) >> 12) as u32) & 0xF_FFFF
+                            }));
+                            for &(offset, bitlen, shift) in low {
+                                let mask = bitmask(bitlen);
+                                dynamics.push((offset, quote_spanned!{ value.span()=>
+                                    ((#value >> #shift) as u32) & #mask
+                                }));
+                            }
+                        }
+                    },
+                    Relocation::LITERAL8
+                    | Relocation::LITERAL16
+                    | Relocation::LITERAL32
+                    | Relocation::LITERAL64 => ()
+                },
+
+                _ => panic!("Invalid argument processor")
+            },
+            FlatArg::Default => match *command {
+                // the rounding mode defaults to the dynamic rounding mode
+                Command::Rm(offset) => {
+                    statics.push((offset, 0b111));
+                },
+
+                // omitted offsets default to 0
+                Command::Ubits(_, _) |
+                Command::Sbits(_, _) |
+                Command::Slice(_, _, _) => (),
+
+                // integer checks don't have anything to check
+                Command::BUscaled(_, _) |
+                Command::BSscaled(_, _) => (),
+
+                _ => panic!("Invalid argument processor")
+            },
+            FlatArg::JumpTarget { ref jump } => match *command {
+                Command::Offset(relocation) => {
+                    // what kind of relocation is it
+                    let data = [relocation.to_id()];
+
+                    // encode the complete relocation
+                    let stmt = jump.clone().encode(&data);
+
+                    relocations.push(stmt);
+                },
+                _ => panic!("Invalid argument processor")
+            },
+        }
+
+        // figure out how far the cursor has to be advanced.
+        match *command {
+            Command::BUscaled(_, _) |
+            Command::BSscaled(_, _) |
+            Command::BNz |
+            Command::Slice(_, _, _) => (),
+            _ => cursor += 1
+        }
+    }
+
+    // sanity
+    if cursor != data.args.len() {
+        panic!("Not enough command processors");
+    }
+
+    // apply all statics to bits
+    let mut bits = data.data.base;
+    for (offset, value) in statics {
+        bits |= u64::from(value) << offset;
+    }
+
+    let size = data.data.size();
+
+    // generate code to be emitted for dynamics
+    if !dynamics.is_empty() {
+        let (mut res, ty) = match size {
+            Size::WORD => (quote!{ #bits as u16 }, quote!(u16)),
+            Size::DWORD => (quote!{ #bits as u32 }, quote!(u32)),
+            Size::QWORD => (quote!{ #bits }, quote!(u64)),
+            _ => unreachable!()
+        };
+        for (offset, expr) in dynamics {
+            res = quote!{
+                #res | (((#expr) as #ty) << #offset)
+            };
+        }
+        ctx.state.stmts.push(Stmt::ExprUnsigned(delimited(res), size));
+    } else {
+        ctx.state.stmts.push(Stmt::Const(bits, size));
+    }
+
+    // generate code to be emitted for relocations
+    ctx.state.stmts.extend(relocations);
+
+    Ok(())
+}
+
+/// Encodes a fence predecessor or successor set, a combination of the letters i, o, r and w in that order.
+fn encode_fence_set(name: &str) -> Option<u32> {
+    let mut bits = 0;
+    let mut rest = name;
+    for (letter, bit) in [('i', 8), ('o', 4), ('r', 2), ('w', 1)].iter() {
+        if let Some(stripped) = rest.strip_prefix(*letter) {
+            bits |= bit;
+            rest = stripped;
+        }
+    }
+
+    if bits == 0 || !rest.is_empty() {
+        None
+    } else {
+        Some(bits)
+    }
+}
+
+fn unsigned_rangecheck(expr: &syn::Expr, min: u32, max: u32, scale: u8) -> Option<Result<u32, Option<String>>> {
+    let value = as_number(expr)?;
+    let scaled = value >> scale;
+
+    Some(if (scaled << scale) != value {
+        emit_error_at(expr.span(), "Unrepresentable value".into());
+        Err(None)
+    } else if scaled > u64::from(max) {
+        emit_error_at(expr.span(), "Value too large".into());
+        Err(None)
+    } else if scaled < u64::from(min) {
+        emit_error_at(expr.span(), "Value too small".into());
+        Err(None)
+    } else {
+        Ok(scaled as u32)
+    })
+}
+
+fn signed_rangecheck(expr: &syn::Expr, min: i32, max: i32, scale: u8) -> Option<Result<i32, Option<String>>> {
+    let value = as_signed_number(expr)?;
+    let scaled = value >> scale;
+
+    Some(if (scaled << scale) != value {
+        emit_error_at(expr.span(), "Unrepresentable value".into());
+        Err(None)
+    } else if scaled > i64::from(max) {
+        emit_error_at(expr.span(), "Value too large".into());
+        Err(None)
+    } else if scaled < i64::from(min) {
+        emit_error_at(expr.span(), "Value too small".into());
+        Err(None)
+    } else {
+        Ok(scaled as i32)
+    })
+}
@@ -0,0 +1,168 @@
+use super::Context;
+use super::ast::{Instruction, RawArg, FlatArg, RegFamily};
+use super::riscvdata::{Opdata, Matcher, get_mnemonic_data};
+use super::debug::format_opdata_list;
+
+use crate::common::{JumpKind, emit_error_at};
+use crate::parse_helpers::as_ident;
+
+/// Try finding an appropriate definition that matches the given instruction / arguments.
+pub(super) fn match_instruction(_ctx: &mut Context, instruction: &Instruction, args: Vec<RawArg>) -> Result<MatchData, Option<String>> {
+    // sanitize our arg list to remove any structures that cannot be matched on
+    let args = sanitize_args(args)?;
+
+    // get the possible matchers
+    let name = &instruction.name;
+    let opdata = if let Some(o) = get_mnemonic_data(name) {
+        o
+    } else {
+        return Err(Some(format!("Unknown instruction mnemonic '{}'", name)));
+    };
+
+    // matching loop
+    for data in opdata {
+        if let Some(mut ctx) = match_args(&args, data) {
+
+            // flatten the arg list for the encoding vm
+            flatten_args(args, data, &mut ctx);
+
+            return Ok(ctx);
+        }
+    }
+
+    Err(Some(
+        format!("'{}': instruction format mismatch, expected one of the following forms:\n{}", &name, format_opdata_list(name, opdata))
+    ))
+}
+
+/// Sanitizes arguments, ensuring that only relative jumps are used.
+fn sanitize_args(args: Vec<RawArg>) -> Result<Vec<RawArg>, Option<String>> {
+    for arg in &args {
+        if let RawArg::JumpTarget { jump } = arg {
+            if let JumpKind::Bare(_) = jump.kind {
+                emit_error_at(jump.span(), "Extern relocations are not allowed in rv64".into());
+                return Err(None);
+            }
+        }
+    }
+
+    Ok(args)
+}
+
+#[derive(Debug)]
+pub struct MatchData {
+    pub data: &'static Opdata,
+    pub args: Vec<FlatArg>
+}
+
+impl MatchData {
+    fn new(data: &'static Opdata) -> MatchData {
+        MatchData {
+            data,
+            args: Vec::new()
+        }
+    }
+}
+
+impl Matcher {
+    /// Returns if this matcher matches the given argument
+    pub fn matches(&self, arg: &RawArg) -> bool {
+        match arg {
+            RawArg::Direct { reg, .. } => match self {
+                Matcher::X => reg.family() == RegFamily::INTEGER,
+                Matcher::F => reg.family() == RegFamily::FP,
+                _ => false
+            },
+            RawArg::Reference { base, offset, .. } => base.family() == RegFamily::INTEGER && match self {
+                Matcher::RefBase => offset.is_none(),
+                Matcher::RefOffset => true,
+                _ => false
+            },
+            RawArg::JumpTarget { .. } => *self == Matcher::Offset,
+            RawArg::Immediate { value } => match self {
+                Matcher::Ident => as_ident(value).is_some(),
+                Matcher::Imm
+                | Matcher::Offset => true,
+                _ => false
+            },
+        }
+    }
+
+    /// Returns the amount of arguments this matcher flattens to
+    pub fn flatarg_count(&self) -> usize {
+        match self {
+            Matcher::RefOffset => 2,
+            Matcher::End => 0,
+            _ => 1
+        }
+    }
+}
+
+pub fn match_args(args: &[RawArg], data: &'static Opdata) -> Option<MatchData> {
+    let mut args = args.iter();
+    let mut optional = false;
+
+    for matcher in data.matchers {
+        if let Matcher::End = matcher {
+            // the remaining arguments can be omitted
+            optional = true;
+            continue;
+        }
+
+        if let Some(arg) = args.next() {
+            if !matcher.matches(arg) {
+                return None;
+            }
+        } else if optional {
+            break;
+        } else {
+            return None;
+        }
+    }
+
+    // too many arguments
+    if args.next().is_some() {
+        return None;
+    }
+
+    Some(MatchData::new(data))
+}
+
+fn flatten_args(args: Vec<RawArg>, data: &Opdata, ctx: &mut MatchData) {
+    let mut source_args = args.into_iter();
+    let mut new_args = Vec::new();
+
+    for matcher in data.matchers {
+        if let Matcher::End = matcher {
+            continue;
+        }
+
+        let arg = match source_args.next() {
+            Some(arg) => arg,
+            None => {
+                // omitted optional arguments use the default encoding
+                for _ in 0 .. matcher.flatarg_count() {
+                    new_args.push(FlatArg::Default);
+                }
+                continue;
+            }
+        };
+
+        match arg {
+            RawArg::Direct { span, reg } => new_args.push(FlatArg::Direct { span, reg }),
+            RawArg::Reference { span, base, offset } => {
+                new_args.push(FlatArg::Direct { span, reg: base });
+                if *matcher == Matcher::RefOffset {
+                    new_args.push(match offset {
+                        Some(value) => FlatArg::Immediate { value },
+                        None => FlatArg::Default
+                    });
+                }
+            },
+            RawArg::JumpTarget { jump } => new_args.push(FlatArg::JumpTarget { jump }),
+            RawArg::Immediate { value } => new_args.push(FlatArg::Immediate { value }),
+        }
+    }
+
+    ctx.args = new_args;
+}
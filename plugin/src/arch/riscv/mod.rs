@@ -0,0 +1,105 @@
+use syn::parse;
+
+mod ast;
+mod parser;
+mod matching;
+mod compiler;
+mod riscvdata;
+mod debug;
+
+use crate::State;
+use crate::common::{Size, Stmt, Jump, emit_error_at};
+use crate::arch::Arch;
+use self::riscvdata::{Relocation, Features};
+
+#[cfg(feature = "dynasm_opmap")]
+pub use debug::create_opmap;
+
+struct Context<'a, 'b: 'a> {
+    pub state: &'a mut State<'b>,
+    pub features: Features
+}
+
+#[derive(Clone, Debug)]
+pub struct ArchRv64 {
+    features: Features
+}
+
+impl Default for ArchRv64 {
+    fn default() -> ArchRv64 {
+        ArchRv64 { features: Features::all() }
+    }
+}
+
+impl Arch for ArchRv64 {
+    fn name(&self) -> &str {
+        "rv64"
+    }
+
+    fn set_features(&mut self, features: &[syn::Ident]) {
+        let mut new_features = Features::empty();
+        for ident in features {
+            new_features |= match Features::from_str(&ident.to_string()) {
+                Some(feature) => feature,
+                None => {
+                    emit_error_at(ident.span(), format!("Architecture {} does not support feature '{}'", self.name(), ident.to_string()));
+                    continue;
+                }
+            }
+        }
+        self.features = new_features;
+    }
+
+    fn handle_static_reloc(&self, stmts: &mut Vec<Stmt>, reloc: Jump, size: Size) {
+        let span = reloc.span();
+
+        let relocation = match size {
+            Size::BYTE => Relocation::LITERAL8,
+            Size::WORD => Relocation::LITERAL16,
+            Size::DWORD => Relocation::LITERAL32,
+            Size::QWORD => Relocation::LITERAL64,
+            _ => {
+                emit_error_at(span, "Relocation of unsupported size for the current target architecture".into());
+                return;
+            }
+        };
+        let data = [relocation.to_id()];
+
+        stmts.push(Stmt::Const(0, size));
+        stmts.push(reloc.encode(&data));
+    }
+
+    fn default_align(&self) -> &'static [u8] {
+        &[0]
+    }
+
+    fn compile_instruction(&self, state: &mut State, input: parse::ParseStream) -> parse::Result<()> {
+        let mut ctx = Context {
+            state,
+            features: self.features
+        };
+
+        let (instruction, args) = parser::parse_instruction(&mut ctx, input)?;
+        let span = instruction.span;
+
+        let match_data = match matching::match_instruction(&mut ctx, &instruction, args) {
+            Err(None) => return Ok(()),
+            Err(Some(e)) => {
+                emit_error_at(span, e);
+                return Ok(())
+            }
+            Ok(m) => m
+        };
+
+        match compiler::compile_instruction(&mut ctx, match_data) {
+            Err(None) => return Ok(()),
+            Err(Some(e)) => {
+                emit_error_at(span, e);
+                return Ok(())
+            }
+            Ok(()) => ()
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,1078 @@
+// RV64GC, Zba and Zbb instructions, including the common pseudo instructions
+Ops!(
+"add" = [
+    0x0000_0033 = [X, X, X] => [R(7), R(15), R(20)];
+]
+"add.uw" = [
+    0x0800_003B = [X, X, X] => [R(7), R(15), R(20)], ZBA;
+]
+"addi" = [
+    0x0000_0013 = [X, X, Imm] => [R(7), R(15), Sbits(20, 12)];
+]
+"addiw" = [
+    0x0000_001B = [X, X, Imm] => [R(7), R(15), Sbits(20, 12)];
+]
+"addw" = [
+    0x0000_003B = [X, X, X] => [R(7), R(15), R(20)];
+]
+"amoadd.d" = [
+    0x0000_302F = [X, X, RefBase] => [R(7), R(20), R(15)], A;
+]
+"amoadd.d.aq" = [
+    0x0400_302F = [X, X, RefBase] => [R(7), R(20), R(15)], A;
+]
+"amoadd.d.aqrl" = [
+    0x0600_302F = [X, X, RefBase] => [R(7), R(20), R(15)], A;
+]
+"amoadd.d.rl" = [
+    0x0200_302F = [X, X, RefBase] => [R(7), R(20), R(15)], A;
+]
+"amoadd.w" = [
+    0x0000_202F = [X, X, RefBase] => [R(7), R(20), R(15)], A;
+]
+"amoadd.w.aq" = [
+    0x0400_202F = [X, X, RefBase] => [R(7), R(20), R(15)], A;
+]
+"amoadd.w.aqrl" = [
+    0x0600_202F = [X, X, RefBase] => [R(7), R(20), R(15)], A;
+]
+"amoadd.w.rl" = [
+    0x0200_202F = [X, X, RefBase] => [R(7), R(20), R(15)], A;
+]
+"amoand.d" = [
+    0x6000_302F = [X, X, RefBase] => [R(7), R(20), R(15)], A;
+]
+"amoand.d.aq" = [
+    0x6400_302F = [X, X, RefBase] => [R(7), R(20), R(15)], A;
+]
+"amoand.d.aqrl" = [
+    0x6600_302F = [X, X, RefBase] => [R(7), R(20), R(15)], A;
+]
+"amoand.d.rl" = [
+    0x6200_302F = [X, X, RefBase] => [R(7), R(20), R(15)], A;
+]
+"amoand.w" = [
+    0x6000_202F = [X, X, RefBase] => [R(7), R(20), R(15)], A;
+]
+"amoand.w.aq" = [
+    0x6400_202F = [X, X, RefBase] => [R(7), R(20), R(15)], A;
+]
+"amoand.w.aqrl" = [
+    0x6600_202F = [X, X, RefBase] => [R(7), R(20), R(15)], A;
+]
+"amoand.w.rl" = [
+    0x6200_202F = [X, X, RefBase] => [R(7), R(20), R(15)], A;
+]
+"amomax.d" = [
+    0xA000_302F = [X, X, RefBase] => [R(7), R(20), R(15)], A;
+]
+"amomax.d.aq" = [
+    0xA400_302F = [X, X, RefBase] => [R(7), R(20), R(15)], A;
+]
+"amomax.d.aqrl" = [
+    0xA600_302F = [X, X, RefBase] => [R(7), R(20), R(15)], A;
+]
+"amomax.d.rl" = [
+    0xA200_302F = [X, X, RefBase] => [R(7), R(20), R(15)], A;
+]
+"amomax.w" = [
+    0xA000_202F = [X, X, RefBase] => [R(7), R(20), R(15)], A;
+]
+"amomax.w.aq" = [
+    0xA400_202F = [X, X, RefBase] => [R(7), R(20), R(15)], A;
+]
+"amomax.w.aqrl" = [
+    0xA600_202F = [X, X, RefBase] => [R(7), R(20), R(15)], A;
+]
+"amomax.w.rl" = [
+    0xA200_202F = [X, X, RefBase] => [R(7), R(20), R(15)], A;
+]
+"amomaxu.d" = [
+    0xE000_302F = [X, X, RefBase] => [R(7), R(20), R(15)], A;
+]
+"amomaxu.d.aq" = [
+    0xE400_302F = [X, X, RefBase] => [R(7), R(20), R(15)], A;
+]
+"amomaxu.d.aqrl" = [
+    0xE600_302F = [X, X, RefBase] => [R(7), R(20), R(15)], A;
+]
+"amomaxu.d.rl" = [
+    0xE200_302F = [X, X, RefBase] => [R(7), R(20), R(15)], A;
+]
+"amomaxu.w" = [
+    0xE000_202F = [X, X, RefBase] => [R(7), R(20), R(15)], A;
+]
+"amomaxu.w.aq" = [
+    0xE400_202F = [X, X, RefBase] => [R(7), R(20), R(15)], A;
+]
+"amomaxu.w.aqrl" = [
+    0xE600_202F = [X, X, RefBase] => [R(7), R(20), R(15)], A;
+]
+"amomaxu.w.rl" = [
+    0xE200_202F = [X, X, RefBase] => [R(7), R(20), R(15)], A;
+]
+"amomin.d" = [
+    0x8000_302F = [X, X, RefBase] => [R(7), R(20), R(15)], A;
+]
+"amomin.d.aq" = [
+    0x8400_302F = [X, X, RefBase] => [R(7), R(20), R(15)], A;
+]
+"amomin.d.aqrl" = [
+    0x8600_302F = [X, X, RefBase] => [R(7), R(20), R(15)], A;
+]
+"amomin.d.rl" = [
+    0x8200_302F = [X, X, RefBase] => [R(7), R(20), R(15)], A;
+]
+"amomin.w" = [
+    0x8000_202F = [X, X, RefBase] => [R(7), R(20), R(15)], A;
+]
+"amomin.w.aq" = [
+    0x8400_202F = [X, X, RefBase] => [R(7), R(20), R(15)], A;
+]
+"amomin.w.aqrl" = [
+    0x8600_202F = [X, X, RefBase] => [R(7), R(20), R(15)], A;
+]
+"amomin.w.rl" = [
+    0x8200_202F = [X, X, RefBase] => [R(7), R(20), R(15)], A;
+]
+"amominu.d" = [
+    0xC000_302F = [X, X, RefBase] => [R(7), R(20), R(15)], A;
+]
+"amominu.d.aq" = [
+    0xC400_302F = [X, X, RefBase] => [R(7), R(20), R(15)], A;
+]
+"amominu.d.aqrl" = [
+    0xC600_302F = [X, X, RefBase] => [R(7), R(20), R(15)], A;
+]
+"amominu.d.rl" = [
+    0xC200_302F = [X, X, RefBase] => [R(7), R(20), R(15)], A;
+]
+"amominu.w" = [
+    0xC000_202F = [X, X, RefBase] => [R(7), R(20), R(15)], A;
+]
+"amominu.w.aq" = [
+    0xC400_202F = [X, X, RefBase] => [R(7), R(20), R(15)], A;
+]
+"amominu.w.aqrl" = [
+    0xC600_202F = [X, X, RefBase] => [R(7), R(20), R(15)], A;
+]
+"amominu.w.rl" = [
+    0xC200_202F = [X, X, RefBase] => [R(7), R(20), R(15)], A;
+]
+"amoor.d" = [
+    0x4000_302F = [X, X, RefBase] => [R(7), R(20), R(15)], A;
+]
+"amoor.d.aq" = [
+    0x4400_302F = [X, X, RefBase] => [R(7), R(20), R(15)], A;
+]
+"amoor.d.aqrl" = [
+    0x4600_302F = [X, X, RefBase] => [R(7), R(20), R(15)], A;
+]
+"amoor.d.rl" = [
+    0x4200_302F = [X, X, RefBase] => [R(7), R(20), R(15)], A;
+]
+"amoor.w" = [
+    0x4000_202F = [X, X, RefBase] => [R(7), R(20), R(15)], A;
+]
+"amoor.w.aq" = [
+    0x4400_202F = [X, X, RefBase] => [R(7), R(20), R(15)], A;
+]
+"amoor.w.aqrl" = [
+    0x4600_202F = [X, X, RefBase] => [R(7), R(20), R(15)], A;
+]
+"amoor.w.rl" = [
+    0x4200_202F = [X, X, RefBase] => [R(7), R(20), R(15)], A;
+]
+"amoswap.d" = [
+    0x0800_302F = [X, X, RefBase] => [R(7), R(20), R(15)], A;
+]
+"amoswap.d.aq" = [
+    0x0C00_302F = [X, X, RefBase] => [R(7), R(20), R(15)], A;
+]
+"amoswap.d.aqrl" = [
+    0x0E00_302F = [X, X, RefBase] => [R(7), R(20), R(15)], A;
+]
+"amoswap.d.rl" = [
+    0x0A00_302F = [X, X, RefBase] => [R(7), R(20), R(15)], A;
+]
+"amoswap.w" = [
+    0x0800_202F = [X, X, RefBase] => [R(7), R(20), R(15)], A;
+]
+"amoswap.w.aq" = [
+    0x0C00_202F = [X, X, RefBase] => [R(7), R(20), R(15)], A;
+]
+"amoswap.w.aqrl" = [
+    0x0E00_202F = [X, X, RefBase] => [R(7), R(20), R(15)], A;
+]
+"amoswap.w.rl" = [
+    0x0A00_202F = [X, X, RefBase] => [R(7), R(20), R(15)], A;
+]
+"amoxor.d" = [
+    0x2000_302F = [X, X, RefBase] => [R(7), R(20), R(15)], A;
+]
+"amoxor.d.aq" = [
+    0x2400_302F = [X, X, RefBase] => [R(7), R(20), R(15)], A;
+]
+"amoxor.d.aqrl" = [
+    0x2600_302F = [X, X, RefBase] => [R(7), R(20), R(15)], A;
+]
+"amoxor.d.rl" = [
+    0x2200_302F = [X, X, RefBase] => [R(7), R(20), R(15)], A;
+]
+"amoxor.w" = [
+    0x2000_202F = [X, X, RefBase] => [R(7), R(20), R(15)], A;
+]
+"amoxor.w.aq" = [
+    0x2400_202F = [X, X, RefBase] => [R(7), R(20), R(15)], A;
+]
+"amoxor.w.aqrl" = [
+    0x2600_202F = [X, X, RefBase] => [R(7), R(20), R(15)], A;
+]
+"amoxor.w.rl" = [
+    0x2200_202F = [X, X, RefBase] => [R(7), R(20), R(15)], A;
+]
+"and" = [
+    0x0000_7033 = [X, X, X] => [R(7), R(15), R(20)];
+]
+"andi" = [
+    0x0000_7013 = [X, X, Imm] => [R(7), R(15), Sbits(20, 12)];
+]
+"andn" = [
+    0x4000_7033 = [X, X, X] => [R(7), R(15), R(20)], ZBB;
+]
+"auipc" = [
+    0x0000_0017 = [X, Imm] => [R(7), Ubits(12, 20)];
+]
+"beq" = [
+    0x0000_0063 = [X, X, Offset] => [R(15), R(20), Offset(B)];
+]
+"beqz" = [
+    0x0000_0063 = [X, Offset] => [R(15), Offset(B)];
+]
+"bge" = [
+    0x0000_5063 = [X, X, Offset] => [R(15), R(20), Offset(B)];
+]
+"bgeu" = [
+    0x0000_7063 = [X, X, Offset] => [R(15), R(20), Offset(B)];
+]
+"bgez" = [
+    0x0000_5063 = [X, Offset] => [R(15), Offset(B)];
+]
+"bgt" = [
+    0x0000_4063 = [X, X, Offset] => [R(20), R(15), Offset(B)];
+]
+"bgtu" = [
+    0x0000_6063 = [X, X, Offset] => [R(20), R(15), Offset(B)];
+]
+"bgtz" = [
+    0x0000_4063 = [X, Offset] => [R(20), Offset(B)];
+]
+"ble" = [
+    0x0000_5063 = [X, X, Offset] => [R(20), R(15), Offset(B)];
+]
+"bleu" = [
+    0x0000_7063 = [X, X, Offset] => [R(20), R(15), Offset(B)];
+]
+"blez" = [
+    0x0000_5063 = [X, Offset] => [R(20), Offset(B)];
+]
+"blt" = [
+    0x0000_4063 = [X, X, Offset] => [R(15), R(20), Offset(B)];
+]
+"bltu" = [
+    0x0000_6063 = [X, X, Offset] => [R(15), R(20), Offset(B)];
+]
+"bltz" = [
+    0x0000_4063 = [X, Offset] => [R(15), Offset(B)];
+]
+"bne" = [
+    0x0000_1063 = [X, X, Offset] => [R(15), R(20), Offset(B)];
+]
+"bnez" = [
+    0x0000_1063 = [X, Offset] => [R(15), Offset(B)];
+]
+"c.add" = [
+    0x9002 = [X, X] => [RNz(7), RNz(2)], C;
+]
+"c.addi" = [
+    0x0001 = [X, Imm] => [RNz(7), BSscaled(6, 0), BNz, Slice(12, 1, 5), Slice(2, 5, 0), A], C;
+]
+"c.addi16sp" = [
+    0x6101 = [X, Imm] => [RIs(2), BSscaled(6, 4), BNz, Slice(12, 1, 9), Slice(6, 1, 4), Slice(5, 1, 6), Slice(3, 2, 7), Slice(2, 1, 5), A], C;
+]
+"c.addi4spn" = [
+    0x0000 = [X, X, Imm] => [RC(2), RIs(2), BUscaled(8, 2), BNz, Slice(11, 2, 4), Slice(7, 4, 6), Slice(6, 1, 2), Slice(5, 1, 3), A], C;
+]
+"c.addiw" = [
+    0x2001 = [X, Imm] => [RNz(7), BSscaled(6, 0), Slice(12, 1, 5), Slice(2, 5, 0), A], C;
+]
+"c.addw" = [
+    0x9C21 = [X, X] => [RC(7), RC(2)], C;
+]
+"c.and" = [
+    0x8C61 = [X, X] => [RC(7), RC(2)], C;
+]
+"c.andi" = [
+    0x8801 = [X, Imm] => [RC(7), BSscaled(6, 0), Slice(12, 1, 5), Slice(2, 5, 0), A], C;
+]
+"c.beqz" = [
+    0xC001 = [X, Offset] => [RC(7), Offset(CB)], C;
+]
+"c.bnez" = [
+    0xE001 = [X, Offset] => [RC(7), Offset(CB)], C;
+]
+"c.ebreak" = [
+    0x9002 = [] => [], C;
+]
+"c.fld" = [
+    0x2000 = [F, RefOffset] => [RC(2), RC(7), BUscaled(5, 3), Slice(10, 3, 3), Slice(5, 2, 6), A], C | D;
+]
+"c.fldsp" = [
+    0x2002 = [F, RefOffset] => [R(7), RIs(2), BUscaled(6, 3), Slice(12, 1, 5), Slice(5, 2, 3), Slice(2, 3, 6), A], C | D;
+]
+"c.fsd" = [
+    0xA000 = [F, RefOffset] => [RC(2), RC(7), BUscaled(5, 3), Slice(10, 3, 3), Slice(5, 2, 6), A], C | D;
+]
+"c.fsdsp" = [
+    0xA002 = [F, RefOffset] => [R(2), RIs(2), BUscaled(6, 3), Slice(10, 3, 3), Slice(7, 3, 6), A], C | D;
+]
+"c.j" = [
+    0xA001 = [Offset] => [Offset(CJ)], C;
+]
+"c.jalr" = [
+    0x9002 = [X] => [RNz(7)], C;
+]
+"c.jr" = [
+    0x8002 = [X] => [RNz(7)], C;
+]
+"c.ld" = [
+    0x6000 = [X, RefOffset] => [RC(2), RC(7), BUscaled(5, 3), Slice(10, 3, 3), Slice(5, 2, 6), A], C;
+]
+"c.ldsp" = [
+    0x6002 = [X, RefOffset] => [RNz(7), RIs(2), BUscaled(6, 3), Slice(12, 1, 5), Slice(5, 2, 3), Slice(2, 3, 6), A], C;
+]
+"c.li" = [
+    0x4001 = [X, Imm] => [RNz(7), BSscaled(6, 0), Slice(12, 1, 5), Slice(2, 5, 0), A], C;
+]
+"c.lui" = [
+    0x6001 = [X, Imm] => [RNzSp(7), BSscaled(6, 0), BNz, Slice(12, 1, 5), Slice(2, 5, 0), A], C;
+]
+"c.lw" = [
+    0x4000 = [X, RefOffset] => [RC(2), RC(7), BUscaled(5, 2), Slice(10, 3, 3), Slice(6, 1, 2), Slice(5, 1, 6), A], C;
+]
+"c.lwsp" = [
+    0x4002 = [X, RefOffset] => [RNz(7), RIs(2), BUscaled(6, 2), Slice(12, 1, 5), Slice(4, 3, 2), Slice(2, 2, 6), A], C;
+]
+"c.mv" = [
+    0x8002 = [X, X] => [RNz(7), RNz(2)], C;
+]
+"c.nop" = [
+    0x0001 = [] => [], C;
+]
+"c.or" = [
+    0x8C41 = [X, X] => [RC(7), RC(2)], C;
+]
+"c.sd" = [
+    0xE000 = [X, RefOffset] => [RC(2), RC(7), BUscaled(5, 3), Slice(10, 3, 3), Slice(5, 2, 6), A], C;
+]
+"c.sdsp" = [
+    0xE002 = [X, RefOffset] => [R(2), RIs(2), BUscaled(6, 3), Slice(10, 3, 3), Slice(7, 3, 6), A], C;
+]
+"c.slli" = [
+    0x0002 = [X, Imm] => [RNz(7), BUscaled(6, 0), BNz, Slice(12, 1, 5), Slice(2, 5, 0), A], C;
+]
+"c.srai" = [
+    0x8401 = [X, Imm] => [RC(7), BUscaled(6, 0), BNz, Slice(12, 1, 5), Slice(2, 5, 0), A], C;
+]
+"c.srli" = [
+    0x8001 = [X, Imm] => [RC(7), BUscaled(6, 0), BNz, Slice(12, 1, 5), Slice(2, 5, 0), A], C;
+]
+"c.sub" = [
+    0x8C01 = [X, X] => [RC(7), RC(2)], C;
+]
+"c.subw" = [
+    0x9C01 = [X, X] => [RC(7), RC(2)], C;
+]
+"c.sw" = [
+    0xC000 = [X, RefOffset] => [RC(2), RC(7), BUscaled(5, 2), Slice(10, 3, 3), Slice(6, 1, 2), Slice(5, 1, 6), A], C;
+]
+"c.swsp" = [
+    0xC002 = [X, RefOffset] => [R(2), RIs(2), BUscaled(6, 2), Slice(9, 4, 2), Slice(7, 2, 6), A], C;
+]
+"c.xor" = [
+    0x8C21 = [X, X] => [RC(7), RC(2)], C;
+]
+"call" = [
+    0x0000_80E7_0000_0097 = [Offset] => [Offset(AUIPC_I)];
+]
+"clz" = [
+    0x6000_1013 = [X, X] => [R(7), R(15)], ZBB;
+]
+"clzw" = [
+    0x6000_101B = [X, X] => [R(7), R(15)], ZBB;
+]
+"cpop" = [
+    0x6020_1013 = [X, X] => [R(7), R(15)], ZBB;
+]
+"cpopw" = [
+    0x6020_101B = [X, X] => [R(7), R(15)], ZBB;
+]
+"csrc" = [
+    0x0000_3073 = [Ident, X] => [Csr(20), R(15)], ZICSR;
+    0x0000_3073 = [Imm, X] => [Ubits(20, 12), R(15)], ZICSR;
+]
+"csrci" = [
+    0x0000_7073 = [Ident, Imm] => [Csr(20), Ubits(15, 5)], ZICSR;
+    0x0000_7073 = [Imm, Imm] => [Ubits(20, 12), Ubits(15, 5)], ZICSR;
+]
+"csrr" = [
+    0x0000_2073 = [X, Ident] => [R(7), Csr(20)], ZICSR;
+    0x0000_2073 = [X, Imm] => [R(7), Ubits(20, 12)], ZICSR;
+]
+"csrrc" = [
+    0x0000_3073 = [X, Ident, X] => [R(7), Csr(20), R(15)], ZICSR;
+    0x0000_3073 = [X, Imm, X] => [R(7), Ubits(20, 12), R(15)], ZICSR;
+]
+"csrrci" = [
+    0x0000_7073 = [X, Ident, Imm] => [R(7), Csr(20), Ubits(15, 5)], ZICSR;
+    0x0000_7073 = [X, Imm, Imm] => [R(7), Ubits(20, 12), Ubits(15, 5)], ZICSR;
+]
+"csrrs" = [
+    0x0000_2073 = [X, Ident, X] => [R(7), Csr(20), R(15)], ZICSR;
+    0x0000_2073 = [X, Imm, X] => [R(7), Ubits(20, 12), R(15)], ZICSR;
+]
+"csrrsi" = [
+    0x0000_6073 = [X, Ident, Imm] => [R(7), Csr(20), Ubits(15, 5)], ZICSR;
+    0x0000_6073 = [X, Imm, Imm] => [R(7), Ubits(20, 12), Ubits(15, 5)], ZICSR;
+]
+"csrrw" = [
+    0x0000_1073 = [X, Ident, X] => [R(7), Csr(20), R(15)], ZICSR;
+    0x0000_1073 = [X, Imm, X] => [R(7), Ubits(20, 12), R(15)], ZICSR;
+]
+"csrrwi" = [
+    0x0000_5073 = [X, Ident, Imm] => [R(7), Csr(20), Ubits(15, 5)], ZICSR;
+    0x0000_5073 = [X, Imm, Imm] => [R(7), Ubits(20, 12), Ubits(15, 5)], ZICSR;
+]
+"csrs" = [
+    0x0000_2073 = [Ident, X] => [Csr(20), R(15)], ZICSR;
+    0x0000_2073 = [Imm, X] => [Ubits(20, 12), R(15)], ZICSR;
+]
+"csrsi" = [
+    0x0000_6073 = [Ident, Imm] => [Csr(20), Ubits(15, 5)], ZICSR;
+    0x0000_6073 = [Imm, Imm] => [Ubits(20, 12), Ubits(15, 5)], ZICSR;
+]
+"csrw" = [
+    0x0000_1073 = [Ident, X] => [Csr(20), R(15)], ZICSR;
+    0x0000_1073 = [Imm, X] => [Ubits(20, 12), R(15)], ZICSR;
+]
+"csrwi" = [
+    0x0000_5073 = [Ident, Imm] => [Csr(20), Ubits(15, 5)], ZICSR;
+    0x0000_5073 = [Imm, Imm] => [Ubits(20, 12), Ubits(15, 5)], ZICSR;
+]
+"ctz" = [
+    0x6010_1013 = [X, X] => [R(7), R(15)], ZBB;
+]
+"ctzw" = [
+    0x6010_101B = [X, X] => [R(7), R(15)], ZBB;
+]
+"div" = [
+    0x0200_4033 = [X, X, X] => [R(7), R(15), R(20)], M;
+]
+"divu" = [
+    0x0200_5033 = [X, X, X] => [R(7), R(15), R(20)], M;
+]
+"divuw" = [
+    0x0200_503B = [X, X, X] => [R(7), R(15), R(20)], M;
+]
+"divw" = [
+    0x0200_403B = [X, X, X] => [R(7), R(15), R(20)], M;
+]
+"ebreak" = [
+    0x0010_0073 = [] => [];
+]
+"ecall" = [
+    0x0000_0073 = [] => [];
+]
+"fabs.d" = [
+    0x2200_2053 = [F, F] => [R(7), R(15), C, R(20)], D;
+]
+"fabs.s" = [
+    0x2000_2053 = [F, F] => [R(7), R(15), C, R(20)], F;
+]
+"fadd.d" = [
+    0x0200_0053 = [F, F, F, End, Ident] => [R(7), R(15), R(20), Rm(12)], D;
+]
+"fadd.s" = [
+    0x0000_0053 = [F, F, F, End, Ident] => [R(7), R(15), R(20), Rm(12)], F;
+]
+"fclass.d" = [
+    0xE200_1053 = [X, F] => [R(7), R(15)], D;
+]
+"fclass.s" = [
+    0xE000_1053 = [X, F] => [R(7), R(15)], F;
+]
+"fcvt.d.l" = [
+    0xD220_0053 = [F, X, End, Ident] => [R(7), R(15), Rm(12)], D;
+]
+"fcvt.d.lu" = [
+    0xD230_0053 = [F, X, End, Ident] => [R(7), R(15), Rm(12)], D;
+]
+"fcvt.d.s" = [
+    0x4200_0053 = [F, F] => [R(7), R(15)], D;
+]
+"fcvt.d.w" = [
+    0xD200_0053 = [F, X] => [R(7), R(15)], D;
+]
+"fcvt.d.wu" = [
+    0xD210_0053 = [F, X] => [R(7), R(15)], D;
+]
+"fcvt.l.d" = [
+    0xC220_0053 = [X, F, End, Ident] => [R(7), R(15), Rm(12)], D;
+]
+"fcvt.l.s" = [
+    0xC020_0053 = [X, F, End, Ident] => [R(7), R(15), Rm(12)], F;
+]
+"fcvt.lu.d" = [
+    0xC230_0053 = [X, F, End, Ident] => [R(7), R(15), Rm(12)], D;
+]
+"fcvt.lu.s" = [
+    0xC030_0053 = [X, F, End, Ident] => [R(7), R(15), Rm(12)], F;
+]
+"fcvt.s.d" = [
+    0x4010_0053 = [F, F, End, Ident] => [R(7), R(15), Rm(12)], D;
+]
+"fcvt.s.l" = [
+    0xD020_0053 = [F, X, End, Ident] => [R(7), R(15), Rm(12)], F;
+]
+"fcvt.s.lu" = [
+    0xD030_0053 = [F, X, End, Ident] => [R(7), R(15), Rm(12)], F;
+]
+"fcvt.s.w" = [
+    0xD000_0053 = [F, X, End, Ident] => [R(7), R(15), Rm(12)], F;
+]
+"fcvt.s.wu" = [
+    0xD010_0053 = [F, X, End, Ident] => [R(7), R(15), Rm(12)], F;
+]
+"fcvt.w.d" = [
+    0xC200_0053 = [X, F, End, Ident] => [R(7), R(15), Rm(12)], D;
+]
+"fcvt.w.s" = [
+    0xC000_0053 = [X, F, End, Ident] => [R(7), R(15), Rm(12)], F;
+]
+"fcvt.wu.d" = [
+    0xC210_0053 = [X, F, End, Ident] => [R(7), R(15), Rm(12)], D;
+]
+"fcvt.wu.s" = [
+    0xC010_0053 = [X, F, End, Ident] => [R(7), R(15), Rm(12)], F;
+]
+"fdiv.d" = [
+    0x1A00_0053 = [F, F, F, End, Ident] => [R(7), R(15), R(20), Rm(12)], D;
+]
+"fdiv.s" = [
+    0x1800_0053 = [F, F, F, End, Ident] => [R(7), R(15), R(20), Rm(12)], F;
+]
+"fence" = [
+    0x0FF0_000F = [] => [];
+    0x0000_000F = [Ident, Ident] => [Fence(24), Fence(20)];
+]
+"fence.i" = [
+    0x0000_100F = [] => [], ZIFENCEI;
+]
+"fence.tso" = [
+    0x8330_000F = [] => [];
+]
+"feq.d" = [
+    0xA200_2053 = [X, F, F] => [R(7), R(15), R(20)], D;
+]
+"feq.s" = [
+    0xA000_2053 = [X, F, F] => [R(7), R(15), R(20)], F;
+]
+"fld" = [
+    0x0000_3007 = [F, RefOffset] => [R(7), R(15), Sbits(20, 12)], D;
+    0x0000_3007_0000_0017 = [F, Offset, X] => [R(39), Offset(AUIPC_I), R(7), C, R(47)], D;
+]
+"fle.d" = [
+    0xA200_0053 = [X, F, F] => [R(7), R(15), R(20)], D;
+]
+"fle.s" = [
+    0xA000_0053 = [X, F, F] => [R(7), R(15), R(20)], F;
+]
+"flt.d" = [
+    0xA200_1053 = [X, F, F] => [R(7), R(15), R(20)], D;
+]
+"flt.s" = [
+    0xA000_1053 = [X, F, F] => [R(7), R(15), R(20)], F;
+]
+"flw" = [
+    0x0000_2007 = [F, RefOffset] => [R(7), R(15), Sbits(20, 12)], F;
+    0x0000_2007_0000_0017 = [F, Offset, X] => [R(39), Offset(AUIPC_I), R(7), C, R(47)], F;
+]
+"fmadd.d" = [
+    0x0200_0043 = [F, F, F, F, End, Ident] => [R(7), R(15), R(20), R(27), Rm(12)], D;
+]
+"fmadd.s" = [
+    0x0000_0043 = [F, F, F, F, End, Ident] => [R(7), R(15), R(20), R(27), Rm(12)], F;
+]
+"fmax.d" = [
+    0x2A00_1053 = [F, F, F] => [R(7), R(15), R(20)], D;
+]
+"fmax.s" = [
+    0x2800_1053 = [F, F, F] => [R(7), R(15), R(20)], F;
+]
+"fmin.d" = [
+    0x2A00_0053 = [F, F, F] => [R(7), R(15), R(20)], D;
+]
+"fmin.s" = [
+    0x2800_0053 = [F, F, F] => [R(7), R(15), R(20)], F;
+]
+"fmsub.d" = [
+    0x0200_0047 = [F, F, F, F, End, Ident] => [R(7), R(15), R(20), R(27), Rm(12)], D;
+]
+"fmsub.s" = [
+    0x0000_0047 = [F, F, F, F, End, Ident] => [R(7), R(15), R(20), R(27), Rm(12)], F;
+]
+"fmul.d" = [
+    0x1200_0053 = [F, F, F, End, Ident] => [R(7), R(15), R(20), Rm(12)], D;
+]
+"fmul.s" = [
+    0x1000_0053 = [F, F, F, End, Ident] => [R(7), R(15), R(20), Rm(12)], F;
+]
+"fmv.d" = [
+    0x2200_0053 = [F, F] => [R(7), R(15), C, R(20)], D;
+]
+"fmv.d.x" = [
+    0xF200_0053 = [F, X] => [R(7), R(15)], D;
+]
+"fmv.s" = [
+    0x2000_0053 = [F, F] => [R(7), R(15), C, R(20)], F;
+]
+"fmv.w.x" = [
+    0xF000_0053 = [F, X] => [R(7), R(15)], F;
+]
+"fmv.x.d" = [
+    0xE200_0053 = [X, F] => [R(7), R(15)], D;
+]
+"fmv.x.w" = [
+    0xE000_0053 = [X, F] => [R(7), R(15)], F;
+]
+"fneg.d" = [
+    0x2200_1053 = [F, F] => [R(7), R(15), C, R(20)], D;
+]
+"fneg.s" = [
+    0x2000_1053 = [F, F] => [R(7), R(15), C, R(20)], F;
+]
+"fnmadd.d" = [
+    0x0200_004F = [F, F, F, F, End, Ident] => [R(7), R(15), R(20), R(27), Rm(12)], D;
+]
+"fnmadd.s" = [
+    0x0000_004F = [F, F, F, F, End, Ident] => [R(7), R(15), R(20), R(27), Rm(12)], F;
+]
+"fnmsub.d" = [
+    0x0200_004B = [F, F, F, F, End, Ident] => [R(7), R(15), R(20), R(27), Rm(12)], D;
+]
+"fnmsub.s" = [
+    0x0000_004B = [F, F, F, F, End, Ident] => [R(7), R(15), R(20), R(27), Rm(12)], F;
+]
+"frcsr" = [
+    0x0030_2073 = [X] => [R(7)], ZICSR;
+]
+"frflags" = [
+    0x0010_2073 = [X] => [R(7)], ZICSR;
+]
+"frrm" = [
+    0x0020_2073 = [X] => [R(7)], ZICSR;
+]
+"fscsr" = [
+    0x0030_1073 = [X] => [R(15)], ZICSR;
+    0x0030_1073 = [X, X] => [R(7), R(15)], ZICSR;
+]
+"fsd" = [
+    0x0000_3027 = [F, RefOffset] => [R(20), R(15), BSscaled(12, 0), Slice(7, 5, 0), Slice(25, 7, 5), A], D;
+    0x0000_3027_0000_0017 = [F, Offset, X] => [R(52), Offset(AUIPC_S), R(7), C, R(47)], D;
+]
+"fsflags" = [
+    0x0010_1073 = [X] => [R(15)], ZICSR;
+    0x0010_1073 = [X, X] => [R(7), R(15)], ZICSR;
+]
+"fsflagsi" = [
+    0x0010_5073 = [Imm] => [Ubits(15, 5)], ZICSR;
+    0x0010_5073 = [X, Imm] => [R(7), Ubits(15, 5)], ZICSR;
+]
+"fsgnj.d" = [
+    0x2200_0053 = [F, F, F] => [R(7), R(15), R(20)], D;
+]
+"fsgnj.s" = [
+    0x2000_0053 = [F, F, F] => [R(7), R(15), R(20)], F;
+]
+"fsgnjn.d" = [
+    0x2200_1053 = [F, F, F] => [R(7), R(15), R(20)], D;
+]
+"fsgnjn.s" = [
+    0x2000_1053 = [F, F, F] => [R(7), R(15), R(20)], F;
+]
+"fsgnjx.d" = [
+    0x2200_2053 = [F, F, F] => [R(7), R(15), R(20)], D;
+]
+"fsgnjx.s" = [
+    0x2000_2053 = [F, F, F] => [R(7), R(15), R(20)], F;
+]
+"fsqrt.d" = [
+    0x5A00_0053 = [F, F, End, Ident] => [R(7), R(15), Rm(12)], D;
+]
+"fsqrt.s" = [
+    0x5800_0053 = [F, F, End, Ident] => [R(7), R(15), Rm(12)], F;
+]
+"fsrm" = [
+    0x0020_1073 = [X] => [R(15)], ZICSR;
+    0x0020_1073 = [X, X] => [R(7), R(15)], ZICSR;
+]
+"fsrmi" = [
+    0x0020_5073 = [Imm] => [Ubits(15, 5)], ZICSR;
+    0x0020_5073 = [X, Imm] => [R(7), Ubits(15, 5)], ZICSR;
+]
+"fsub.d" = [
+    0x0A00_0053 = [F, F, F, End, Ident] => [R(7), R(15), R(20), Rm(12)], D;
+]
+"fsub.s" = [
+    0x0800_0053 = [F, F, F, End, Ident] => [R(7), R(15), R(20), Rm(12)], F;
+]
+"fsw" = [
+    0x0000_2027 = [F, RefOffset] => [R(20), R(15), BSscaled(12, 0), Slice(7, 5, 0), Slice(25, 7, 5), A], F;
+    0x0000_2027_0000_0017 = [F, Offset, X] => [R(52), Offset(AUIPC_S), R(7), C, R(47)], F;
+]
+"j" = [
+    0x0000_006F = [Offset] => [Offset(J)];
+]
+"jal" = [
+    0x0000_00EF = [Offset] => [Offset(J)];
+    0x0000_006F = [X, Offset] => [R(7), Offset(J)];
+]
+"jalr" = [
+    0x0000_00E7 = [X] => [R(15)];
+    0x0000_0067 = [X, RefOffset] => [R(7), R(15), Sbits(20, 12)];
+    0x0000_0067 = [X, X, Imm] => [R(7), R(15), Sbits(20, 12)];
+]
+"jr" = [
+    0x0000_0067 = [X] => [R(15)];
+]
+"la" = [
+    0x0000_0013_0000_0017 = [X, Offset] => [R(7), C, R(39), C, R(47), Offset(AUIPC_I)];
+]
+"lb" = [
+    0x0000_0003 = [X, RefOffset] => [R(7), R(15), Sbits(20, 12)];
+    0x0000_0003_0000_0017 = [X, Offset] => [R(7), C, R(39), C, R(47), Offset(AUIPC_I)];
+]
+"lbu" = [
+    0x0000_4003 = [X, RefOffset] => [R(7), R(15), Sbits(20, 12)];
+    0x0000_4003_0000_0017 = [X, Offset] => [R(7), C, R(39), C, R(47), Offset(AUIPC_I)];
+]
+"ld" = [
+    0x0000_3003 = [X, RefOffset] => [R(7), R(15), Sbits(20, 12)];
+    0x0000_3003_0000_0017 = [X, Offset] => [R(7), C, R(39), C, R(47), Offset(AUIPC_I)];
+]
+"lh" = [
+    0x0000_1003 = [X, RefOffset] => [R(7), R(15), Sbits(20, 12)];
+    0x0000_1003_0000_0017 = [X, Offset] => [R(7), C, R(39), C, R(47), Offset(AUIPC_I)];
+]
+"lhu" = [
+    0x0000_5003 = [X, RefOffset] => [R(7), R(15), Sbits(20, 12)];
+    0x0000_5003_0000_0017 = [X, Offset] => [R(7), C, R(39), C, R(47), Offset(AUIPC_I)];
+]
+"li" = [
+    0x0000_0013 = [X, Imm] => [R(7), Sbits(20, 12)];
+]
+"lla" = [
+    0x0000_0013_0000_0017 = [X, Offset] => [R(7), C, R(39), C, R(47), Offset(AUIPC_I)];
+]
+"lr.d" = [
+    0x1000_302F = [X, RefBase] => [R(7), R(15)], A;
+]
+"lr.d.aq" = [
+    0x1400_302F = [X, RefBase] => [R(7), R(15)], A;
+]
+"lr.d.aqrl" = [
+    0x1600_302F = [X, RefBase] => [R(7), R(15)], A;
+]
+"lr.d.rl" = [
+    0x1200_302F = [X, RefBase] => [R(7), R(15)], A;
+]
+"lr.w" = [
+    0x1000_202F = [X, RefBase] => [R(7), R(15)], A;
+]
+"lr.w.aq" = [
+    0x1400_202F = [X, RefBase] => [R(7), R(15)], A;
+]
+"lr.w.aqrl" = [
+    0x1600_202F = [X, RefBase] => [R(7), R(15)], A;
+]
+"lr.w.rl" = [
+    0x1200_202F = [X, RefBase] => [R(7), R(15)], A;
+]
+"lui" = [
+    0x0000_0037 = [X, Imm] => [R(7), Ubits(12, 20)];
+]
+"lw" = [
+    0x0000_2003 = [X, RefOffset] => [R(7), R(15), Sbits(20, 12)];
+    0x0000_2003_0000_0017 = [X, Offset] => [R(7), C, R(39), C, R(47), Offset(AUIPC_I)];
+]
+"lwu" = [
+    0x0000_6003 = [X, RefOffset] => [R(7), R(15), Sbits(20, 12)];
+    0x0000_6003_0000_0017 = [X, Offset] => [R(7), C, R(39), C, R(47), Offset(AUIPC_I)];
+]
+"max" = [
+    0x0A00_6033 = [X, X, X] => [R(7), R(15), R(20)], ZBB;
+]
+"maxu" = [
+    0x0A00_7033 = [X, X, X] => [R(7), R(15), R(20)], ZBB;
+]
+"min" = [
+    0x0A00_4033 = [X, X, X] => [R(7), R(15), R(20)], ZBB;
+]
+"minu" = [
+    0x0A00_5033 = [X, X, X] => [R(7), R(15), R(20)], ZBB;
+]
+"mul" = [
+    0x0200_0033 = [X, X, X] => [R(7), R(15), R(20)], M;
+]
+"mulh" = [
+    0x0200_1033 = [X, X, X] => [R(7), R(15), R(20)], M;
+]
+"mulhsu" = [
+    0x0200_2033 = [X, X, X] => [R(7), R(15), R(20)], M;
+]
+"mulhu" = [
+    0x0200_3033 = [X, X, X] => [R(7), R(15), R(20)], M;
+]
+"mulw" = [
+    0x0200_003B = [X, X, X] => [R(7), R(15), R(20)], M;
+]
+"mv" = [
+    0x0000_0013 = [X, X] => [R(7), R(15)];
+]
+"neg" = [
+    0x4000_0033 = [X, X] => [R(7), R(20)];
+]
+"negw" = [
+    0x4000_003B = [X, X] => [R(7), R(20)];
+]
+"nop" = [
+    0x0000_0013 = [] => [];
+]
+"not" = [
+    0xFFF0_4013 = [X, X] => [R(7), R(15)];
+]
+"or" = [
+    0x0000_6033 = [X, X, X] => [R(7), R(15), R(20)];
+]
+"orc.b" = [
+    0x2870_5013 = [X, X] => [R(7), R(15)], ZBB;
+]
+"ori" = [
+    0x0000_6013 = [X, X, Imm] => [R(7), R(15), Sbits(20, 12)];
+]
+"orn" = [
+    0x4000_6033 = [X, X, X] => [R(7), R(15), R(20)], ZBB;
+]
+"rdcycle" = [
+    0xC000_2073 = [X] => [R(7)], ZICSR;
+]
+"rdinstret" = [
+    0xC020_2073 = [X] => [R(7)], ZICSR;
+]
+"rdtime" = [
+    0xC010_2073 = [X] => [R(7)], ZICSR;
+]
+"rem" = [
+    0x0200_6033 = [X, X, X] => [R(7), R(15), R(20)], M;
+]
+"remu" = [
+    0x0200_7033 = [X, X, X] => [R(7), R(15), R(20)], M;
+]
+"remuw" = [
+    0x0200_703B = [X, X, X] => [R(7), R(15), R(20)], M;
+]
+"remw" = [
+    0x0200_603B = [X, X, X] => [R(7), R(15), R(20)], M;
+]
+"ret" = [
+    0x0000_8067 = [] => [];
+]
+"rev8" = [
+    0x6B80_5013 = [X, X] => [R(7), R(15)], ZBB;
+]
+"rol" = [
+    0x6000_1033 = [X, X, X] => [R(7), R(15), R(20)], ZBB;
+]
+"rolw" = [
+    0x6000_103B = [X, X, X] => [R(7), R(15), R(20)], ZBB;
+]
+"ror" = [
+    0x6000_5033 = [X, X, X] => [R(7), R(15), R(20)], ZBB;
+]
+"rori" = [
+    0x6000_5013 = [X, X, Imm] => [R(7), R(15), Ubits(20, 6)], ZBB;
+]
+"roriw" = [
+    0x6000_501B = [X, X, Imm] => [R(7), R(15), Ubits(20, 5)], ZBB;
+]
+"rorw" = [
+    0x6000_503B = [X, X, X] => [R(7), R(15), R(20)], ZBB;
+]
+"sb" = [
+    0x0000_0023 = [X, RefOffset] => [R(20), R(15), BSscaled(12, 0), Slice(7, 5, 0), Slice(25, 7, 5), A];
+    0x0000_0023_0000_0017 = [X, Offset, X] => [R(52), Offset(AUIPC_S), R(7), C, R(47)];
+]
+"sc.d" = [
+    0x1800_302F = [X, X, RefBase] => [R(7), R(20), R(15)], A;
+]
+"sc.d.aq" = [
+    0x1C00_302F = [X, X, RefBase] => [R(7), R(20), R(15)], A;
+]
+"sc.d.aqrl" = [
+    0x1E00_302F = [X, X, RefBase] => [R(7), R(20), R(15)], A;
+]
+"sc.d.rl" = [
+    0x1A00_302F = [X, X, RefBase] => [R(7), R(20), R(15)], A;
+]
+"sc.w" = [
+    0x1800_202F = [X, X, RefBase] => [R(7), R(20), R(15)], A;
+]
+"sc.w.aq" = [
+    0x1C00_202F = [X, X, RefBase] => [R(7), R(20), R(15)], A;
+]
+"sc.w.aqrl" = [
+    0x1E00_202F = [X, X, RefBase] => [R(7), R(20), R(15)], A;
+]
+"sc.w.rl" = [
+    0x1A00_202F = [X, X, RefBase] => [R(7), R(20), R(15)], A;
+]
+"sd" = [
+    0x0000_3023 = [X, RefOffset] => [R(20), R(15), BSscaled(12, 0), Slice(7, 5, 0), Slice(25, 7, 5), A];
+    0x0000_3023_0000_0017 = [X, Offset, X] => [R(52), Offset(AUIPC_S), R(7), C, R(47)];
+]
+"seqz" = [
+    0x0010_3013 = [X, X] => [R(7), R(15)];
+]
+"sext.b" = [
+    0x6040_1013 = [X, X] => [R(7), R(15)], ZBB;
+]
+"sext.h" = [
+    0x6050_1013 = [X, X] => [R(7), R(15)], ZBB;
+]
+"sext.w" = [
+    0x0000_001B = [X, X] => [R(7), R(15)];
+]
+"sgtz" = [
+    0x0000_2033 = [X, X] => [R(7), R(20)];
+]
+"sh" = [
+    0x0000_1023 = [X, RefOffset] => [R(20), R(15), BSscaled(12, 0), Slice(7, 5, 0), Slice(25, 7, 5), A];
+    0x0000_1023_0000_0017 = [X, Offset, X] => [R(52), Offset(AUIPC_S), R(7), C, R(47)];
+]
+"sh1add" = [
+    0x2000_2033 = [X, X, X] => [R(7), R(15), R(20)], ZBA;
+]
+"sh1add.uw" = [
+    0x2000_203B = [X, X, X] => [R(7), R(15), R(20)], ZBA;
+]
+"sh2add" = [
+    0x2000_4033 = [X, X, X] => [R(7), R(15), R(20)], ZBA;
+]
+"sh2add.uw" = [
+    0x2000_403B = [X, X, X] => [R(7), R(15), R(20)], ZBA;
+]
+"sh3add" = [
+    0x2000_6033 = [X, X, X] => [R(7), R(15), R(20)], ZBA;
+]
+"sh3add.uw" = [
+    0x2000_603B = [X, X, X] => [R(7), R(15), R(20)], ZBA;
+]
+"sll" = [
+    0x0000_1033 = [X, X, X] => [R(7), R(15), R(20)];
+]
+"slli" = [
+    0x0000_1013 = [X, X, Imm] => [R(7), R(15), Ubits(20, 6)];
+]
+"slli.uw" = [
+    0x0800_101B = [X, X, Imm] => [R(7), R(15), Ubits(20, 6)], ZBA;
+]
+"slliw" = [
+    0x0000_101B = [X, X, Imm] => [R(7), R(15), Ubits(20, 5)];
+]
+"sllw" = [
+    0x0000_103B = [X, X, X] => [R(7), R(15), R(20)];
+]
+"slt" = [
+    0x0000_2033 = [X, X, X] => [R(7), R(15), R(20)];
+]
+"slti" = [
+    0x0000_2013 = [X, X, Imm] => [R(7), R(15), Sbits(20, 12)];
+]
+"sltiu" = [
+    0x0000_3013 = [X, X, Imm] => [R(7), R(15), Sbits(20, 12)];
+]
+"sltu" = [
+    0x0000_3033 = [X, X, X] => [R(7), R(15), R(20)];
+]
+"sltz" = [
+    0x0000_2033 = [X, X] => [R(7), R(15)];
+]
+"snez" = [
+    0x0000_3033 = [X, X] => [R(7), R(20)];
+]
+"sra" = [
+    0x4000_5033 = [X, X, X] => [R(7), R(15), R(20)];
+]
+"srai" = [
+    0x4000_5013 = [X, X, Imm] => [R(7), R(15), Ubits(20, 6)];
+]
+"sraiw" = [
+    0x4000_501B = [X, X, Imm] => [R(7), R(15), Ubits(20, 5)];
+]
+"sraw" = [
+    0x4000_503B = [X, X, X] => [R(7), R(15), R(20)];
+]
+"srl" = [
+    0x0000_5033 = [X, X, X] => [R(7), R(15), R(20)];
+]
+"srli" = [
+    0x0000_5013 = [X, X, Imm] => [R(7), R(15), Ubits(20, 6)];
+]
+"srliw" = [
+    0x0000_501B = [X, X, Imm] => [R(7), R(15), Ubits(20, 5)];
+]
+"srlw" = [
+    0x0000_503B = [X, X, X] => [R(7), R(15), R(20)];
+]
+"sub" = [
+    0x4000_0033 = [X, X, X] => [R(7), R(15), R(20)];
+]
+"subw" = [
+    0x4000_003B = [X, X, X] => [R(7), R(15), R(20)];
+]
+"sw" = [
+    0x0000_2023 = [X, RefOffset] => [R(20), R(15), BSscaled(12, 0), Slice(7, 5, 0), Slice(25, 7, 5), A];
+    0x0000_2023_0000_0017 = [X, Offset, X] => [R(52), Offset(AUIPC_S), R(7), C, R(47)];
+]
+"tail" = [
+    0x0003_0067_0000_0317 = [Offset] => [Offset(AUIPC_I)];
+]
+"xnor" = [
+    0x4000_4033 = [X, X, X] => [R(7), R(15), R(20)], ZBB;
+]
+"xor" = [
+    0x0000_4033 = [X, X, X] => [R(7), R(15), R(20)];
+]
+"xori" = [
+    0x0000_4013 = [X, X, Imm] => [R(7), R(15), Sbits(20, 12)];
+]
+"zext.b" = [
+    0x0FF0_7013 = [X, X] => [R(7), R(15)];
+]
+"zext.h" = [
+    0x0800_403B = [X, X] => [R(7), R(15)], ZBB;
+]
+"zext.w" = [
+    0x0800_003B = [X, X] => [R(7), R(15)], ZBA;
+]
+)
@@ -0,0 +1,296 @@
+use syn::{parse, Token};
+
+use lazy_static::lazy_static;
+
+use crate::parse_helpers::{parse_ident_or_rust_keyword, ParseOptExt};
+
+use super::Context;
+use super::ast::{Instruction, RawArg, RegId, RegKind, RegFamily};
+
+use std::collections::HashMap;
+
+// parses a full instruction
+// syntax for a single op: ident ("." ident)* (arg ("," arg)*)? ";"
+pub(super) fn parse_instruction(ctx: &mut Context, input: parse::ParseStream) -> parse::Result<(Instruction, Vec<RawArg>)> {
+    let span = input.cursor().span();
+
+    // read the full dot-separated op. Unlike on aarch64, the dotted parts are all part of the mnemnonic.
+    let mut name = parse_ident_or_rust_keyword(input)?.to_string();
+
+    while input.peek(Token![.]) {
+        let _: Token![.] = input.parse()?;
+        let part = parse_ident_or_rust_keyword(input)?;
+        name.push('.');
+        name.push_str(&part.to_string());
+    }
+
+    let mut args = Vec::new();
+
+    // parse 0 or more comma-separated args
+    if !(input.is_empty() || input.peek(Token![;])) {
+        args.push(parse_arg(ctx, input)?);
+
+        while input.peek(Token![,]) {
+            let _: Token![,] = input.parse()?;
+
+            args.push(parse_arg(ctx, input)?);
+        }
+    }
+
+    Ok((
+        Instruction {
+            span,
+            name
+        },
+        args
+    ))
+}
+
+/// tries to parse a full arg definition
+fn parse_arg(ctx: &mut Context, input: parse::ParseStream) -> parse::Result<RawArg> {
+    let _start = input.cursor().span(); // FIXME can't join spans yet
+
+    // a label
+    if let Some(jump) = input.parse_opt()? {
+        return Ok(RawArg::JumpTarget {
+            jump
+        });
+    }
+
+    // reference
+    if input.peek(syn::token::Bracket) {
+        let span = input.cursor().span();
+        let inner;
+        let _ = syn::bracketed!(inner in input);
+        let inner = &inner;
+
+        let base = parse_reg(ctx, inner)?.ok_or_else(|| inner.error("Expected a base register"))?;
+
+        let offset = if inner.peek(Token![,]) {
+            let _: Token![,] = inner.parse()?;
+            let offset: syn::Expr = inner.parse()?;
+            Some(offset)
+        } else {
+            None
+        };
+
+        if !inner.is_empty() {
+            return Err(inner.error("Expected the end of the reference"));
+        }
+
+        return Ok(RawArg::Reference {
+            span,
+            base,
+            offset
+        });
+    }
+
+    // register
+    if let Some(reg) = parse_reg(ctx, input)? {
+        return Ok(RawArg::Direct {
+            reg,
+            span: _start
+        })
+    }
+
+    // immediate
+    let arg: syn::Expr = input.parse()?;
+    Ok(RawArg::Immediate {
+        value: arg
+    })
+}
+
+fn parse_reg(ctx: &mut Context, input: parse::ParseStream) -> parse::Result<Option<RegKind>> {
+    let name = match input.step(|cursor| {
+        if let Some((ident, rest)) = cursor.ident() {
+            let mut ident = ident.to_string();
+
+            // dynamic registers are only recognized when followed by their parenthesized expression
+            if RISCV_FAMILIES.contains_key(&*ident) {
+                if rest.group(proc_macro2::Delimiter::Parenthesis).is_some() {
+                    return Ok((ident, rest));
+                }
+                return Err(cursor.error("expected register"));
+            }
+
+            if let Some(repl) = ctx.state.file_data.aliases.get(&ident) {
+                ident = repl.clone();
+            }
+
+            if RISCV_REGISTERS.contains_key(&*ident) {
+                return Ok((ident, rest));
+            }
+        }
+        Err(cursor.error("expected identifier"))
+    }) {
+        Ok(name) => name,
+        Err(_) => return Ok(None)
+    };
+
+    if let Some(&id) = RISCV_REGISTERS.get(&*name) {
+        Ok(Some(RegKind::Static(id)))
+
+    } else if let Some(&family) = RISCV_FAMILIES.get(&*name) {
+
+        // parse the dynamic register expression
+        let inner;
+        let _ = syn::parenthesized!(inner in input);
+        let inner = &inner;
+
+        let expr: syn::Expr = inner.parse()?;
+
+        Ok(Some(RegKind::Dynamic(family, expr)))
+    } else {
+        unreachable!();
+    }
+}
+
+lazy_static!{
+    static ref RISCV_REGISTERS: HashMap<&'static str, RegId> = {
+        use self::RegId::*;
+
+        static MAP: &[(&str, RegId)] = &[
+            ("x0" , X0 ),
+            ("x1" , X1 ),
+            ("x2" , X2 ),
+            ("x3" , X3 ),
+            ("x4" , X4 ),
+            ("x5" , X5 ),
+            ("x6" , X6 ),
+            ("x7" , X7 ),
+            ("x8" , X8 ),
+            ("x9" , X9 ),
+            ("x10", X10),
+            ("x11", X11),
+            ("x12", X12),
+            ("x13", X13),
+            ("x14", X14),
+            ("x15", X15),
+            ("x16", X16),
+            ("x17", X17),
+            ("x18", X18),
+            ("x19", X19),
+            ("x20", X20),
+            ("x21", X21),
+            ("x22", X22),
+            ("x23", X23),
+            ("x24", X24),
+            ("x25", X25),
+            ("x26", X26),
+            ("x27", X27),
+            ("x28", X28),
+            ("x29", X29),
+            ("x30", X30),
+            ("x31", X31),
+
+            ("zero", X0 ),
+            ("ra"  , X1 ),
+            ("sp"  , X2 ),
+            ("gp"  , X3 ),
+            ("tp"  , X4 ),
+            ("t0"  , X5 ),
+            ("t1"  , X6 ),
+            ("t2"  , X7 ),
+            ("s0"  , X8 ),
+            ("fp"  , X8 ),
+            ("s1"  , X9 ),
+            ("a0"  , X10),
+            ("a1"  , X11),
+            ("a2"  , X12),
+            ("a3"  , X13),
+            ("a4"  , X14),
+            ("a5"  , X15),
+            ("a6"  , X16),
+            ("a7"  , X17),
+            ("s2"  , X18),
+            ("s3"  , X19),
+            ("s4"  , X20),
+            ("s5"  , X21),
+            ("s6"  , X22),
+            ("s7"  , X23),
+            ("s8"  , X24),
+            ("s9"  , X25),
+            ("s10" , X26),
+            ("s11" , X27),
+            ("t3"  , X28),
+            ("t4"  , X29),
+            ("t5"  , X30),
+            ("t6"  , X31),
+
+            ("f0" , F0 ),
+            ("f1" , F1 ),
+            ("f2" , F2 ),
+            ("f3" , F3 ),
+            ("f4" , F4 ),
+            ("f5" , F5 ),
+            ("f6" , F6 ),
+            ("f7" , F7 ),
+            ("f8" , F8 ),
+            ("f9" , F9 ),
+            ("f10", F10),
+            ("f11", F11),
+            ("f12", F12),
+            ("f13", F13),
+            ("f14", F14),
+            ("f15", F15),
+            ("f16", F16),
+            ("f17", F17),
+            ("f18", F18),
+            ("f19", F19),
+            ("f20", F20),
+            ("f21", F21),
+            ("f22", F22),
+            ("f23", F23),
+            ("f24", F24),
+            ("f25", F25),
+            ("f26", F26),
+            ("f27", F27),
+            ("f28", F28),
+            ("f29", F29),
+            ("f30", F30),
+            ("f31", F31),
+
+            ("ft0" , F0 ),
+            ("ft1" , F1 ),
+            ("ft2" , F2 ),
+            ("ft3" , F3 ),
+            ("ft4" , F4 ),
+            ("ft5" , F5 ),
+            ("ft6" , F6 ),
+            ("ft7" , F7 ),
+            ("fs0" , F8 ),
+            ("fs1" , F9 ),
+            ("fa0" , F10),
+            ("fa1" , F11),
+            ("fa2" , F12),
+            ("fa3" , F13),
+            ("fa4" , F14),
+            ("fa5" , F15),
+            ("fa6" , F16),
+            ("fa7" , F17),
+            ("fs2" , F18),
+            ("fs3" , F19),
+            ("fs4" , F20),
+            ("fs5" , F21),
+            ("fs6" , F22),
+            ("fs7" , F23),
+            ("fs8" , F24),
+            ("fs9" , F25),
+            ("fs10", F26),
+            ("fs11", F27),
+            ("ft8" , F28),
+            ("ft9" , F29),
+            ("ft10", F30),
+            ("ft11", F31),
+        ];
+        MAP.iter().cloned().collect()
+    };
+
+    static ref RISCV_FAMILIES: HashMap<&'static str, RegFamily> = {
+        static MAP: &[(&str, RegFamily)] = &[
+            ("X", RegFamily::INTEGER),
+            ("F", RegFamily::FP),
+        ];
+        MAP.iter().cloned().collect()
+    };
+}
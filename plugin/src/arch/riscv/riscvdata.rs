@@ -0,0 +1,358 @@
+use crate::common::Size;
+
+use lazy_static::lazy_static;
+use bitflags::bitflags;
+use std::collections::{HashMap, hash_map};
+use std::fmt::{self, Display};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Matcher {
+    // a random ident, like a rounding mode, fence set or csr name
+    Ident,
+
+    // immediate
+    Imm,
+
+    // integer registers
+    X,
+
+    // floating point registers
+    F,
+
+    // jump offsets and pc-relative addresses. Matches labels as well as immediates
+    Offset,
+
+    // references
+    /// [Xn]
+    RefBase,
+    /// [Xn] or [Xn, offset]
+    RefOffset,
+
+    // possible op mnemnonic end (everything after this point uses the default encoding)
+    End,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Command {
+    // commands that advance the argument pointer
+    R(u8), // encode a register, or reference base, into a 5-bit bitfield.
+    RNz(u8), // same as R, but does not allow x0.
+    RNzSp(u8), // same as R, but does not allow x0 or x2.
+    RC(u8), // encode a register in the range x8-x15 or f8-f15 into a 3-bit bitfield, as used by compressed instructions.
+    RIs(u8), // checks that the register is the given register. Nothing is encoded
+
+    // immediate encodings
+    Ubits(u8, u8), // encodes an unsigned immediate starting at bit .0, .1 bits long
+    Sbits(u8, u8), // encodes a signed immediate starting at bit .0, .1 bits long
+
+    // bit slice encodings. These don't advance the current argument. Only the slice commands actually encode anything
+    BUscaled(u8, u8), // checks that the pointed value fits in .0 bits after being shifted .1 bits to the right, without losing bits
+    BSscaled(u8, u8), // checks that the pointed value fits in .0 bits as a signed value after being shifted .1 bits to the right, without losing bits
+    BNz, // checks that the pointed value is not zero
+    Slice(u8, u8, u8), // encodes at .0, .1 bits long, the bitslice starting at .2 from the current arg
+
+    // named operands
+    Rm(u8), // 3-bit floating point rounding mode. Defaults to dyn.
+    Fence(u8), // 4-bit fence set, a combination of the letters i, o, r and w
+    Csr(u8), // 12-bit control and status register, either a name from CSR_MAP or a number
+
+    // Offsets
+    Offset(Relocation),
+
+    // special commands
+    A, // advances the argument pointer, only needed to skip over an argument.
+    C, // moves the argument pointer back.
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[allow(non_camel_case_types)]
+pub enum Relocation {
+    // beq, bne, blt, bge, bltu, bgeu: split 13 bits, word aligned
+    B = 0,
+    // jal: split 21 bits, word aligned
+    J = 1,
+    // c.beqz, c.bnez: split 9 bits, word aligned
+    CB = 2,
+    // c.j: split 12 bits, word aligned
+    CJ = 3,
+    // auipc followed by an I-type instruction (addi, jalr, loads): 32 bits, split over both instructions
+    AUIPC_I = 4,
+    // auipc followed by an S-type instruction (stores): 32 bits, split over both instructions
+    AUIPC_S = 5,
+    // 8-bit literal
+    LITERAL8 = 7,
+    // 16-bit literal
+    LITERAL16 = 8,
+    // 32-bit literal
+    LITERAL32 = 10,
+    // 64-bit literal
+    LITERAL64 = 14,
+}
+
+impl Relocation {
+    pub fn to_id(self) -> u8 {
+        self as u8
+    }
+
+    /// The bitslices of the relocated value that make up this relocation, as (offset, bitlen, shift) triples,
+    /// the amount of bits of the value and the amount of low bits that have to be zero.
+    /// The pair relocations are handled separately.
+    pub fn slices(self) -> (&'static [(u8, u8, u8)], u8, u8) {
+        match self {
+            Relocation::B => (&[(31, 1, 12), (25, 6, 5), (8, 4, 1), (7, 1, 11)], 13, 1),
+            Relocation::J => (&[(31, 1, 20), (21, 10, 1), (20, 1, 11), (12, 8, 12)], 21, 1),
+            Relocation::CB => (&[(12, 1, 8), (10, 2, 3), (5, 2, 6), (3, 2, 1), (2, 1, 5)], 9, 1),
+            Relocation::CJ => (&[(12, 1, 11), (11, 1, 4), (9, 2, 8), (8, 1, 10), (7, 1, 6), (6, 1, 7), (3, 3, 1), (2, 1, 5)], 12, 1),
+            _ => panic!("Relocation without bitslices")
+        }
+    }
+}
+
+
+bitflags! {
+    pub struct Features: u64 {
+        const BASELINE   = 0x0000_0000;
+        const M          = 0x0000_0001;
+        const A          = 0x0000_0002;
+        const F          = 0x0000_0004;
+        const D          = 0x0000_0008;
+        const C          = 0x0000_0010;
+        const ZICSR      = 0x0000_0020;
+        const ZIFENCEI   = 0x0000_0040;
+        const ZBA        = 0x0000_0080;
+        const ZBB        = 0x0000_0100;
+    }
+}
+
+impl Features {
+    const fn make(bits: u64) -> Features {
+        Features { bits }
+    }
+
+    pub fn from_str(name: &str) -> Option<Features> {
+        match name {
+            "m"        => Some(Features::M),
+            "a"        => Some(Features::A),
+            "f"        => Some(Features::F | Features::ZICSR),
+            "d"        => Some(Features::D | Features::F | Features::ZICSR),
+            "c"        => Some(Features::C),
+            "zicsr"    => Some(Features::ZICSR),
+            "zifencei" => Some(Features::ZIFENCEI),
+            "zba"      => Some(Features::ZBA),
+            "zbb"      => Some(Features::ZBB),
+            "g"        => Some(Features::M | Features::A | Features::F | Features::D | Features::ZICSR | Features::ZIFENCEI),
+            _ => None
+        }
+    }
+}
+
+impl Display for Features {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut keys = Vec::new();
+        if self.contains(Features::M)        { keys.push("m"); }
+        if self.contains(Features::A)        { keys.push("a"); }
+        if self.contains(Features::F)        { keys.push("f"); }
+        if self.contains(Features::D)        { keys.push("d"); }
+        if self.contains(Features::C)        { keys.push("c"); }
+        if self.contains(Features::ZICSR)    { keys.push("zicsr"); }
+        if self.contains(Features::ZIFENCEI) { keys.push("zifencei"); }
+        if self.contains(Features::ZBA)      { keys.push("zba"); }
+        if self.contains(Features::ZBB)      { keys.push("zbb"); }
+        for (i, k) in keys.into_iter().enumerate() {
+            if i != 0 {
+                f.write_str(", ")?;
+            }
+            f.write_str(k)?;
+        }
+        Ok(())
+    }
+}
+
+// workaround until bitflags can be used in const
+const M          : u64 = Features::M.bits;
+const A          : u64 = Features::A.bits;
+const F          : u64 = Features::F.bits;
+const D          : u64 = Features::D.bits;
+const C          : u64 = Features::C.bits;
+const ZICSR      : u64 = Features::ZICSR.bits;
+const ZIFENCEI   : u64 = Features::ZIFENCEI.bits;
+const ZBA        : u64 = Features::ZBA.bits;
+const ZBB        : u64 = Features::ZBB.bits;
+
+#[derive(Debug, Clone, Copy)]
+pub struct Opdata {
+    /// The base template for the encoding. Instruction pairs have the second instruction in the upper 32 bits.
+    pub base: u64,
+    /// A set of matchers capable of matching the instruction encoding that this instruction represents.
+    pub matchers: &'static [Matcher],
+    /// A sequence of encoder commands that check the matched instruction on validity and whose output gets orred together with the original template at runtime.
+    pub commands: &'static [Command],
+    /// The architecture extensions that have to be available for this instruction to be used.
+    pub features: Features
+}
+
+impl Opdata {
+    /// The size of the encoded instruction(s). Compressed instructions are 2 bytes long, instruction pairs 8.
+    pub fn size(&self) -> Size {
+        if self.base > 0xFFFF_FFFF {
+            Size::QWORD
+        } else if self.base & 3 == 3 {
+            Size::DWORD
+        } else {
+            Size::WORD
+        }
+    }
+}
+
+macro_rules! SingleOp {
+    ( $base:expr, [ $( $matcher:expr ),* ], [ $( $command:expr ),* ] $( , $features:expr )? ) => {
+        {
+            const MATCHERS: &'static [Matcher] = {
+                #[allow(unused_imports)]
+                use self::Matcher::*;
+                &[ $(
+                    $matcher
+                ),* ]
+            };
+            const COMMANDS: &'static [Command] = {
+                #[allow(unused_imports)]
+                use self::Command::*;
+                &[ $(
+                    $command
+                ),* ]
+            };
+            Opdata {
+                base: $base,
+                matchers: MATCHERS,
+                commands: COMMANDS,
+                features: Features::make(0 $( | $features )?),
+            }
+        }
+    }
+}
+
+macro_rules! Ops {
+    ( $( $name:tt = [ $( $base:tt = [ $( $matcher:expr ),* ] => [ $( $command:expr ),* ] $( , $features:expr )? ; )+ ] )* ) => {
+        [ $(
+            (
+                $name,
+                &[ $(
+                    SingleOp!( $base, [ $( $matcher ),* ], [ $( $command ),* ] $( , $features )? )
+                ),+ ] as &[_]
+            )
+        ),* ]
+    }
+}
+
+pub fn get_mnemonic_data(name: &str) -> Option<&'static [Opdata]> {
+    OPMAP.get(&name).cloned()
+}
+
+#[allow(dead_code)]
+pub fn mnemnonics() -> hash_map::Keys<'static, &'static str, &'static [Opdata]> {
+    OPMAP.keys()
+}
+
+lazy_static! {
+    static ref OPMAP: HashMap<&'static str, &'static [Opdata]> = {
+        use self::Relocation::*;
+
+        static MAP: &[(&str, &[Opdata])] = &include!("opmap.rs");
+        MAP.iter().cloned().collect()
+    };
+
+    /// A map of floating point rounding modes and their encoding
+    pub static ref ROUNDING_MODES: HashMap<&'static str, u32> = {
+        static MAP: &[(&str, u32)] = &[
+            ("rne", 0b000),
+            ("rtz", 0b001),
+            ("rdn", 0b010),
+            ("rup", 0b011),
+            ("rmm", 0b100),
+            ("dyn", 0b111),
+        ];
+        MAP.iter().cloned().collect()
+    };
+
+    /// A map of named control and status registers to their 12-bit number
+    pub static ref CSR_MAP: HashMap<&'static str, u32> = {
+        static MAP: &[(&str, u32)] = &[
+            // unprivileged floating point
+            ("fflags", 0x001),
+            ("frm", 0x002),
+            ("fcsr", 0x003),
+
+            // unprivileged counters and timers
+            ("cycle", 0xC00),
+            ("time", 0xC01),
+            ("instret", 0xC02),
+            ("hpmcounter3", 0xC03),
+            ("hpmcounter4", 0xC04),
+            ("hpmcounter5", 0xC05),
+            ("hpmcounter6", 0xC06),
+            ("hpmcounter7", 0xC07),
+            ("hpmcounter8", 0xC08),
+            ("hpmcounter9", 0xC09),
+            ("hpmcounter10", 0xC0A),
+            ("hpmcounter11", 0xC0B),
+            ("hpmcounter12", 0xC0C),
+            ("hpmcounter13", 0xC0D),
+            ("hpmcounter14", 0xC0E),
+            ("hpmcounter15", 0xC0F),
+            ("hpmcounter16", 0xC10),
+            ("hpmcounter17", 0xC11),
+            ("hpmcounter18", 0xC12),
+            ("hpmcounter19", 0xC13),
+            ("hpmcounter20", 0xC14),
+            ("hpmcounter21", 0xC15),
+            ("hpmcounter22", 0xC16),
+            ("hpmcounter23", 0xC17),
+            ("hpmcounter24", 0xC18),
+            ("hpmcounter25", 0xC19),
+            ("hpmcounter26", 0xC1A),
+            ("hpmcounter27", 0xC1B),
+            ("hpmcounter28", 0xC1C),
+            ("hpmcounter29", 0xC1D),
+            ("hpmcounter30", 0xC1E),
+            ("hpmcounter31", 0xC1F),
+
+            // supervisor
+            ("sstatus", 0x100),
+            ("sie", 0x104),
+            ("stvec", 0x105),
+            ("scounteren", 0x106),
+            ("sscratch", 0x140),
+            ("sepc", 0x141),
+            ("scause", 0x142),
+            ("stval", 0x143),
+            ("sip", 0x144),
+            ("satp", 0x180),
+
+            // machine information
+            ("mvendorid", 0xF11),
+            ("marchid", 0xF12),
+            ("mimpid", 0xF13),
+            ("mhartid", 0xF14),
+
+            // machine trap setup and handling
+            ("mstatus", 0x300),
+            ("misa", 0x301),
+            ("medeleg", 0x302),
+            ("mideleg", 0x303),
+            ("mie", 0x304),
+            ("mtvec", 0x305),
+            ("mcounteren", 0x306),
+            ("mscratch", 0x340),
+            ("mepc", 0x341),
+            ("mcause", 0x342),
+            ("mtval", 0x343),
+            ("mip", 0x344),
+
+            // machine counters
+            ("mcycle", 0xB00),
+            ("minstret", 0xB02),
+            ("mcountinhibit", 0x320),
+        ];
+        MAP.iter().cloned().collect()
+    };
+}
@@ -50,7 +50,7 @@ pub enum RegId {
     MMX0 = 0x40, MMX1 = 0x41, MMX2 = 0x42, MMX3 = 0x43,
     MMX4 = 0x44, MMX5 = 0x45, MMX6 = 0x46, MMX7 = 0x47,
 
-    // size: 16 bytes or 32 bytes
+    // size: 16 bytes, 32 bytes or 64 bytes
     XMM0  = 0x50, XMM1  = 0x51, XMM2  = 0x52, XMM3  = 0x53,
     XMM4  = 0x54, XMM5  = 0x55, XMM6  = 0x56, XMM7  = 0x57,
     XMM8  = 0x58, XMM9  = 0x59, XMM10 = 0x5A, XMM11 = 0x5B,
@@ -73,7 +73,27 @@ pub enum RegId {
     DR12 = 0x8C, DR13 = 0x8D, DR14 = 0x8E, DR15 = 0x8F,
 
     // size: 16 bytes
-    BND0 = 0x90, BND1 = 0x91, BND2 = 0x92, BND3 = 0x93
+    BND0 = 0x90, BND1 = 0x91, BND2 = 0x92, BND3 = 0x93,
+
+    // size: 8 bytes
+    K0 = 0xA0, K1 = 0xA1, K2 = 0xA2, K3 = 0xA3,
+    K4 = 0xA4, K5 = 0xA5, K6 = 0xA6, K7 = 0xA7,
+
+    // size: 16 bytes, 32 bytes or 64 bytes. Can only be encoded using EVEX
+    XMM16 = 0xB0, XMM17 = 0xB1, XMM18 = 0xB2, XMM19 = 0xB3,
+    XMM20 = 0xB4, XMM21 = 0xB5, XMM22 = 0xB6, XMM23 = 0xB7,
+    XMM24 = 0xB8, XMM25 = 0xB9, XMM26 = 0xBA, XMM27 = 0xBB,
+    XMM28 = 0xBC, XMM29 = 0xBD, XMM30 = 0xBE, XMM31 = 0xBF,
+
+    // size: 1, 2, 4 or 8 bytes. Can only be encoded using REX2 or EVEX (APX)
+    R16 = 0xC0, R17 = 0xC1, R18 = 0xC2, R19 = 0xC3,
+    R20 = 0xC4, R21 = 0xC5, R22 = 0xC6, R23 = 0xC7,
+    R24 = 0xC8, R25 = 0xC9, R26 = 0xCA, R27 = 0xCB,
+    R28 = 0xCC, R29 = 0xCD, R30 = 0xCE, R31 = 0xCF,
+
+    // size: 1024 bytes (16 rows of 64 bytes)
+    TMM0 = 0xD0, TMM1 = 0xD1, TMM2 = 0xD2, TMM3 = 0xD3,
+    TMM4 = 0xD4, TMM5 = 0xD5, TMM6 = 0xD6, TMM7 = 0xD7
 }
 
 #[derive(Debug, PartialOrd, PartialEq, Ord, Eq, Hash, Clone, Copy)]
@@ -87,7 +107,9 @@ pub enum RegFamily {
     SEGMENT = 6,
     CONTROL = 7,
     DEBUG = 8,
-    BOUND = 9
+    BOUND = 9,
+    OPMASK = 10,
+    TILE = 11
 }
 
 impl Register {
@@ -136,6 +158,16 @@ impl RegKind {
         }
     }
 
+    // xmm16-xmm31 can only be encoded using EVEX
+    pub fn is_evex_only(&self) -> bool {
+        self.family() == RegFamily::XMM && self.code().unwrap_or(0) > 15
+    }
+
+    // r16-r31 can only be encoded using REX2 or EVEX
+    pub fn is_apx_extended(&self) -> bool {
+        self.family() == RegFamily::LEGACY && self.code().unwrap_or(0) > 15
+    }
+
     pub fn encode(&self) -> u8 {
         self.code().unwrap_or(0)
     }
@@ -194,7 +226,10 @@ impl PartialEq<RegId> for Option<RegKind> {
 
 impl RegId {
     pub fn code(self) -> u8 {
-        self as u8 & 0xF
+        match self as u8 >> 4 {
+            11 | 12 => self as u8 & 0xF | 0x10,
+            _ => self as u8 & 0xF
+        }
     }
 
     pub fn family(self) -> RegFamily {
@@ -209,6 +244,10 @@ impl RegId {
             7 => RegFamily::CONTROL,
             8 => RegFamily::DEBUG,
             9 => RegFamily::BOUND,
+            10 => RegFamily::OPMASK,
+            11 => RegFamily::XMM,
+            12 => RegFamily::LEGACY,
+            13 => RegFamily::TILE,
             _ => unreachable!()
         }
     }
@@ -292,11 +331,16 @@ pub enum RawArg {
         value: syn::Expr,
         size: Option<Size>
     },
+    // a dynamically chosen condition code, i.e. Cond(expr)
+    Cond {
+        span: Span,
+        value: syn::Expr
+    },
     // used to not block the parser on a parsing error in a single arg
     Invalid
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub enum CleanArg {
     // memory reference
     Indirect {
@@ -332,10 +376,11 @@ pub enum CleanArg {
 
 #[derive(Debug)]
 pub enum SizedArg {
-    // memory reference. size info is lost here as
-    // it is never actually encoded
+    // memory reference. size is only used to determine the scale of
+    // compressed displacements, it is never actually encoded
     Indirect {
         span: Span,
+        size: Size,
         disp_size: Option<Size>,
         base: Option<Register>,
         index: Option<(Register, isize, Option<syn::Expr>)>,
@@ -362,11 +407,44 @@ pub enum SizedArg {
     }
 }
 
+/**
+ * AVX-512 operand decorations
+ */
+
+// embedded rounding control. All of these also imply suppress-all-exceptions
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Rounding {
+    RN = 0, // round to nearest (even)
+    RD = 1, // round down (toward -inf)
+    RU = 2, // round up (toward +inf)
+    RZ = 3, // round toward zero
+    SAE = 4 // suppress all exceptions, without a rounding override
+}
+
+#[derive(Debug, Default)]
+pub struct Decorations {
+    // opmask applied to the destination operand, i.e. {k1}
+    pub mask: Option<Register>,
+    // zeroing-masking instead of merging-masking, i.e. {z}
+    pub zeroing: bool,
+    // the memory operand is a broadcasted element, i.e. {1to16}. contains the element count
+    pub broadcast: Option<u8>,
+    // embedded rounding / sae operand, i.e. {rn-sae}
+    pub rounding: Option<Rounding>
+}
+
+impl Decorations {
+    pub fn is_empty(&self) -> bool {
+        self.mask.is_none() && !self.zeroing && self.broadcast.is_none() && self.rounding.is_none()
+    }
+}
+
 /**
  * Parsed instruction
  */
 
 pub struct Instruction {
     pub span: Span,
-    pub idents: Vec<syn::Ident>
+    pub idents: Vec<syn::Ident>,
+    pub decorations: Decorations
 }
@@ -3,10 +3,11 @@ use proc_macro2::{Span, TokenTree};
 use quote::{quote_spanned};
 
 use crate::common::{Stmt, Size, Jump, JumpKind, delimited, emit_error_at};
+use crate::parse_helpers::as_signed_number;
 use crate::serialize;
 
 use super::{Context, X86Mode};
-use super::ast::{RawArg, CleanArg, SizedArg, Instruction, MemoryRefItem, Register, RegKind, RegFamily, RegId};
+use super::ast::{RawArg, CleanArg, SizedArg, Instruction, MemoryRefItem, Register, RegKind, RegFamily, RegId, Decorations, Rounding};
 use super::x64data::get_mnemnonic_data;
 use super::x64data::Flags;
 use super::x64data::Features;
@@ -63,6 +64,17 @@ const MOD_NOBASE: u8 = 0b00; // VSIB addressing
 const MOD_DISP8:  u8 = 0b01;
 const MOD_DISP32: u8 = 0b10;
 
+// the maximum amount of immediates and displacements of an instruction that are sized at runtime, as every one of
+// them doubles the amount of encodings generated. Any further ones use their normal encoding.
+const MAX_RUNTIME_SIZED_ARGS: usize = 2;
+
+// generic conditional instructions, and the instruction with condition code 0 that they are encoded as
+const CONDITIONAL_INSTRUCTIONS: [(&str, &str); 3] = [
+    ("jcc",    "jo"),
+    ("setcc",  "seto"),
+    ("cmovcc", "cmovo"),
+];
+
 
 #[derive(Debug, Clone, Copy)]
 enum RelocationKind {
@@ -92,6 +104,22 @@ pub(super) fn compile_instruction(ctx: &mut Context, instruction: Instruction, a
     let mut ops = instruction.idents;
     let op = ops.pop().unwrap();
     let prefixes = ops;
+    let decorations = instruction.decorations;
+    let mut args = args;
+
+    // the condition code of generic conditional instructions is determined at runtime by their first operand
+    let (op, cond) = if let Some(&(_, base)) = CONDITIONAL_INSTRUCTIONS.iter().find(|&&(name, _)| op == name) {
+        if args.is_empty() {
+            return Err(Some(format!("'{}': Expected a condition code as first operand", op)));
+        }
+        let cond = match args.remove(0) {
+            RawArg::Cond {value, ..} => value,
+            _ => return Err(Some(format!("'{}': Expected a condition code as first operand", op)))
+        };
+        (syn::Ident::new(base, op.span()), Some(cond))
+    } else {
+        (op, None)
+    };
 
     // Fold RawArgs into CleanArgs
     let mut args = args.into_iter().map(clean_memoryref).collect::<Result<Vec<CleanArg>, _>>()?;
@@ -100,20 +128,142 @@ pub(super) fn compile_instruction(ctx: &mut Context, instruction: Instruction, a
     let addr_size = sanitize_indirects_and_sizes(&ctx, &mut args)?;
     let addr_size = addr_size.unwrap_or(match ctx.mode {
         X86Mode::Long => Size::QWORD,
-        X86Mode::Protected => Size::DWORD
+        X86Mode::Protected => Size::DWORD,
+        X86Mode::Real => Size::WORD
     });
 
+    // with runtime sizing, dynamic immediates and displacements are also encoded in a short form if possible.
+    // Their values are bound to a variable first, as they're used both to select a form and in the form itself.
+    let candidates = if ctx.state.file_data.runtime_sizes {
+        let mut candidates = runtime_sized_args(&args);
+        candidates.truncate(MAX_RUNTIME_SIZED_ARGS);
+        for (i, &index) in candidates.iter().enumerate() {
+            let value = match args[index] {
+                CleanArg::Immediate {ref mut value, ..} => value,
+                CleanArg::Indirect {disp: Some(ref mut disp), ..} => disp,
+                _ => panic!("bad runtime sized argument")
+            };
+            let (binding, name) = serialize::expr_bind(&delimited(&*value), i);
+            *value = serialize::reparse(&name).expect("Invalid expression generated internally");
+            ctx.state.stmts.push(Stmt::Stmt(binding));
+        }
+        candidates
+    } else {
+        Vec::new()
+    };
+
+    compile_runtime_sized(ctx, &op, &prefixes, &decorations, cond.as_ref(), args, addr_size, &candidates, None, false)?;
+    Ok(())
+}
+
+// Finds the immediates and displacements whose size can only be determined at runtime
+fn runtime_sized_args(args: &[CleanArg]) -> Vec<usize> {
+    args.iter().enumerate().filter(|&(_, arg)| match *arg {
+        CleanArg::Immediate {size: None, ..} => true,
+        // displacements without a base or relative to rip are always 32 bits wide
+        CleanArg::Indirect {disp: Some(ref disp), disp_size: None, base: Some(ref base), ..} =>
+            base.kind.family() != RegFamily::RIP && derive_size(disp).is_none(),
+        _ => false
+    }).map(|(i, _)| i).collect()
+}
+
+// Compiles an instruction, selecting at runtime between an encoding using a single byte and the normal encoding
+// for every dynamic immediate or displacement in candidates. As the shorter encodings need to know the disp8 scale
+// of the instruction, this returns the scale that was used.
+fn compile_runtime_sized(ctx: &mut Context, op: &syn::Ident, prefixes: &[syn::Ident], decorations: &Decorations, cond: Option<&syn::Expr>,
+                         args: Vec<CleanArg>, addr_size: Size, candidates: &[usize], format: Option<&'static Opdata>, disp8: bool) -> Result<Option<u8>, Option<String>> {
+    let (&index, candidates) = if let Some(split) = candidates.split_first() {
+        split
+    } else {
+        return compile_operation(ctx, op, prefixes.to_vec(), decorations, cond, args, addr_size, format, disp8);
+    };
+
+    // create the args for the short form
+    let mut short_args = args.clone();
+    let (expr, is_disp) = match short_args[index] {
+        CleanArg::Immediate {ref mut value, ref mut size} => {
+            let expr = delimited(&*value);
+            *value = serialize::reparse(&serialize::expr_cast(&expr, "i8")).expect("Invalid expression generated internally");
+            *size = Some(Size::BYTE);
+            (expr, false)
+        },
+        CleanArg::Indirect {ref mut disp_size, disp: Some(ref disp), ..} => {
+            *disp_size = Some(Size::BYTE);
+            (delimited(disp), true)
+        },
+        _ => panic!("bad runtime sized argument")
+    };
+
+    // immediates are only worth selecting if a different format encodes them as a byte
+    let short_format = if is_disp {
+        format
+    } else if let Some(byte_form) = find_byte_form(ctx, op, &args, &short_args, decorations, index, format) {
+        Some(byte_form)
+    } else {
+        return compile_runtime_sized(ctx, op, prefixes, decorations, cond, args, addr_size, candidates, format, disp8);
+    };
+
+    // compile both forms into their own buffers
+    let outer = std::mem::take(ctx.state.stmts);
+    let long = compile_runtime_sized(ctx, op, prefixes, decorations, cond, args, addr_size, candidates, format, disp8);
+    let long_stmts = std::mem::take(ctx.state.stmts);
+    let short = match long {
+        Ok(_) => compile_runtime_sized(ctx, op, prefixes, decorations, cond, short_args, addr_size, candidates, short_format, disp8 || is_disp),
+        Err(_) => Ok(None)
+    };
+    let short_stmts = std::mem::replace(ctx.state.stmts, outer);
+
+    let scale = long?;
+    short?;
+
+    // compressed displacements also have to be a multiple of the scale
+    let check = serialize::expr_fits_i8(&expr, if is_disp {scale} else {None});
+    ctx.state.stmts.push(Stmt::Select(check, short_stmts, long_stmts));
+    Ok(scale)
+}
+
+// Finds a format that encodes the immediate at index as a single byte, if the format used otherwise needs more.
+// Formats taking a byte immediate aren't always preferred when matching, so this does not use match_op_format.
+fn find_byte_form(ctx: &Context, op: &syn::Ident, args: &[CleanArg], short_args: &[CleanArg], decorations: &Decorations,
+                  index: usize, format: Option<&'static Opdata>) -> Option<&'static Opdata> {
+    // the format was already picked by another runtime sized immediate
+    if format.is_some() {
+        return None;
+    }
+
+    // invalid instructions are reported when compiling them
+    let data = get_mnemnonic_data(&op.to_string())?;
+    let long = match_op_format(ctx, op, args, decorations).ok()?;
+
+    let is_byte = |format: &Opdata| FormatStringIterator::new(format.args).nth(index) == Some((b'i', b'b'));
+    if is_byte(long) {
+        return None;
+    }
+
+    data.iter().find(|&format| is_byte(format) && match_format_string(ctx, format, short_args, decorations).is_ok())
+}
+
+// Compiles a single instruction form to the statement buffer, using format if it was already determined. If disp8 is set,
+// the displacement of the memory reference is only known to fit in a single byte at runtime. If cond is given, it is
+// encoded in the opcode as condition code. Returns the disp8 scale used.
+fn compile_operation(ctx: &mut Context, op: &syn::Ident, prefixes: Vec<syn::Ident>, decorations: &Decorations, cond: Option<&syn::Expr>,
+                     mut args: Vec<CleanArg>, addr_size: Size, format: Option<&'static Opdata>, disp8: bool) -> Result<Option<u8>, Option<String>> {
     // determine if we need an address size override prefix
     let pref_addr = match (ctx.mode, addr_size) {
         (X86Mode::Long, Size::QWORD) => false,
         (X86Mode::Long, Size::DWORD) => true,
         (X86Mode::Protected, Size::DWORD) => false,
         (X86Mode::Protected, Size::WORD) => true,
+        (X86Mode::Real, Size::WORD) => false,
+        (X86Mode::Real, Size::DWORD) => true,
         _ => return Err(Some("Impossible address size".into()))
     };
 
     // find a matching op
-    let data = match_op_format(ctx, &op, &args)?;
+    let data = match format {
+        Some(data) => data,
+        None => match_op_format(ctx, op, &args, decorations)?
+    };
 
     // determine if the features required for this op are fulfilled
     if !ctx.features.contains(data.features) {
@@ -126,12 +276,35 @@ pub(super) fn compile_instruction(ctx: &mut Context, instruction: Instruction, a
     // determine legacy prefixes
     let (mut pref_mod, pref_seg) = get_legacy_prefixes(data, prefixes)?;
 
+    // broadcasted memory references only reference a single element, so any specified
+    // size is the element size. Strip it so it doesn't participate in operand sizing.
+    let mut element_size = None;
+    if decorations.broadcast.is_some() {
+        for arg in args.iter_mut() {
+            if let CleanArg::Indirect {ref mut size, ..} = *arg {
+                element_size = size.take();
+            }
+        }
+    }
+
     // fill in size info from the format string to create the final SizedArg vec
-    let (op_size, args) = size_operands(data, args)?;
+    let (op_size, mut args) = size_operands(data, args)?;
+
+    // near jump targets are only 16 bits wide in 16-bit mode
+    if ctx.mode == X86Mode::Real {
+        for arg in args.iter_mut() {
+            if let SizedArg::JumpTarget {ref mut size, ..} = *arg {
+                if *size == Size::DWORD {
+                    *size = Size::WORD;
+                }
+            }
+        }
+    }
 
     let mut pref_size = false;
     let mut rex_w = false;
     let mut vex_l = false;
+    let mut evex_l = false; // EVEX.L', the upper bit of the vector length
 
     // determine if size prefixes are necessary
     if data.flags.intersects(Flags::AUTO_SIZE | Flags::AUTO_NO32 | Flags::AUTO_REXW | Flags::AUTO_VEXL) {
@@ -142,6 +315,9 @@ pub(super) fn compile_instruction(ctx: &mut Context, instruction: Instruction, a
             X86Mode::Protected => if op_size == Size::QWORD {
                 return Err(Some(format!("'{}': Does not support 64 bit operands in 32-bit mode", op.to_string())));
             },
+            X86Mode::Real => if op_size == Size::QWORD {
+                return Err(Some(format!("'{}': Does not support 64 bit operands in 16-bit mode", op.to_string())));
+            },
             X86Mode::Long => ()
         }
 
@@ -150,6 +326,7 @@ pub(super) fn compile_instruction(ctx: &mut Context, instruction: Instruction, a
                 (Size::WORD, _) => pref_size = true,
                 (Size::QWORD, X86Mode::Long) => (),
                 (Size::DWORD, X86Mode::Protected) => (),
+                (Size::DWORD, X86Mode::Real) => (),
                 (Size::DWORD, X86Mode::Long) => return Err(Some(format!("'{}': Does not support 32 bit operands in 64-bit mode", op.to_string()))),
                 (_, _) => panic!("bad formatting data"),
             }
@@ -160,11 +337,21 @@ pub(super) fn compile_instruction(ctx: &mut Context, instruction: Instruction, a
                 return Err(Some(format!("'{}': Does not support 16-bit operands", op.to_string())));
             }
         } else if data.flags.contains(Flags::AUTO_VEXL) {
-            if op_size == Size::HWORD {
+            if op_size == Size::ZWORD {
+                evex_l = true;
+            } else if op_size == Size::HWORD {
                 vex_l = true;
             } else if op_size != Size::OWORD {
                 panic!("bad formatting data");
             }
+
+            // EVEX encoded 128-bit and 256-bit vector operations are part of AVX512VL
+            if data.flags.contains(Flags::EVEX_OP) && op_size != Size::ZWORD && !ctx.features.contains(Features::AVX512VL) {
+                return Err(Some(format!(
+                    "This instruction uses features that are not indicated to be available: {}",
+                    Features::AVX512VL
+                )));
+            }
         } else if op_size == Size::WORD {
             pref_size = true;
         } else if op_size == Size::QWORD {
@@ -174,10 +361,21 @@ pub(super) fn compile_instruction(ctx: &mut Context, instruction: Instruction, a
         }
     }
 
-    // mandatory prefixes
     let pref_size = pref_size || data.flags.contains(Flags::WORD_SIZE);
+
+    // 16-bit mode defaults to 16-bit operands, so the operand size prefix selects 32-bit operands instead
+    let pref_size = if ctx.mode == X86Mode::Real && !data.flags.intersects(Flags::VEX_OP | Flags::XOP_OP | Flags::EVEX_OP) &&
+        data.flags.intersects(Flags::AUTO_SIZE | Flags::AUTO_NO32 | Flags::AUTO_REXW | Flags::WORD_SIZE | Flags::DWORD_SIZE) {
+        !pref_size
+    } else {
+        pref_size
+    };
+
+    // mandatory prefixes
+    let pref_size = pref_size || data.flags.contains(Flags::PREF_66);
     let rex_w     = rex_w     || data.flags.contains(Flags::WITH_REXW);
     let vex_l     = vex_l     || data.flags.contains(Flags::WITH_VEXL);
+    let evex_l    = evex_l    || data.flags.contains(Flags::WITH_EVEXL);
     let pref_addr = pref_addr || data.flags.contains(Flags::PREF_67);
 
     if        data.flags.contains(Flags::PREF_F0) { pref_mod = Some(0xF0);
@@ -188,9 +386,40 @@ pub(super) fn compile_instruction(ctx: &mut Context, instruction: Instruction, a
     // check if this combination of args can actually be encoded and whether a rex prefix is necessary
     let need_rex = check_rex(ctx, data, &args, rex_w)?;
 
+    // check if the APX extended registers are used and whether a rex2 prefix is necessary
+    let need_rex2 = check_rex2(ctx, data, &args)?;
+
+    // dynamic vector registers 16-31 can only be encoded in an EVEX prefix, so this has to be checked at runtime
+    if !data.flags.contains(Flags::EVEX_OP) {
+        compile_vector_register_checks(&mut ctx.state.stmts, &args);
+    }
+
     // split args
     let (mut rm, reg, vvvv, ireg, mut args) = extract_args(data, args);
 
+    // EVEX.L'L
+    let vector_length = (evex_l as u8) << 1 | (vex_l as u8);
+
+    // validate AVX-512 decorations and determine how 8-bit displacements are scaled
+    let disp8_scale = if data.flags.contains(Flags::EVEX_OP) {
+        Some(check_decorations(data, decorations, &rm, element_size, rex_w, vector_length)?)
+    } else {
+        None
+    };
+
+    // determine the size of displacements in memory references if it was not specified explicitly
+    if let Some(SizedArg::Indirect {ref mut disp_size, ref mut disp, ..}) = rm {
+        derive_disp_size(addr_size, disp8_scale, disp_size, disp)?;
+
+        // the displacement is only known to fit at runtime, so it has to be truncated explicitly
+        if disp8 {
+            if let Some(ref mut disp) = *disp {
+                let expr = serialize::expr_cast(&delimited(&*disp), "i8");
+                *disp = serialize::reparse(&expr).expect("Invalid expression generated internally");
+            }
+        }
+    }
+
     // we'll need this to keep track of where relocations need to be made
     // (target, offset, size, kind)
     let mut relocations = Vec::new();
@@ -208,6 +437,7 @@ pub(super) fn compile_instruction(ctx: &mut Context, instruction: Instruction, a
 
     // shorthand
     let buffer = &mut ctx.state.stmts;
+    let start = buffer.len();
 
     // legacy-only prefixes
     if let Some(pref) = pref_seg {
@@ -217,8 +447,8 @@ pub(super) fn compile_instruction(ctx: &mut Context, instruction: Instruction, a
         buffer.push(Stmt::u8(0x67));
     }
 
-    // VEX/XOP prefixes embed the operand size prefix / modification prefixes in them.
-    if data.flags.intersects(Flags::VEX_OP | Flags::XOP_OP) {
+    // VEX/XOP/EVEX prefixes embed the operand size prefix / modification prefixes in them.
+    if data.flags.intersects(Flags::VEX_OP | Flags::XOP_OP | Flags::EVEX_OP) {
         let prefix = if pref_size        { 0b01
         } else if pref_mod == Some(0xF3) { 0b10
         } else if pref_mod == Some(0xF2) { 0b11
@@ -227,7 +457,11 @@ pub(super) fn compile_instruction(ctx: &mut Context, instruction: Instruction, a
         // map_sel is stored in the first byte of the opcode
         let (&map_sel, tail) = ops.split_first().expect("bad formatting data");
         ops = tail;
-        compile_vex_xop(ctx.mode, buffer, data, &reg, &rm, map_sel, rex_w, &vvvv, vex_l, prefix);
+        if data.flags.contains(Flags::EVEX_OP) {
+            compile_evex(ctx.mode, buffer, data, &reg, &rm, map_sel, rex_w, &vvvv, vector_length, prefix, decorations);
+        } else {
+            compile_vex_xop(ctx.mode, buffer, data, &reg, &rm, map_sel, rex_w, &vvvv, vex_l, prefix);
+        }
     // otherwise, the size/mod prefixes have to be pushed and check if a rex prefix has to be generated.
     } else {
         if let Some(pref) = pref_mod {
@@ -236,17 +470,25 @@ pub(super) fn compile_instruction(ctx: &mut Context, instruction: Instruction, a
         if pref_size {
             buffer.push(Stmt::u8(0x66));
         }
-        if need_rex {
+        if need_rex2 {
+            // REX2 can imply the 0x0F opcode escape byte
+            let map1 = ops[0] == 0x0F;
+            if map1 {
+                ops = &ops[1..];
+            }
+            compile_rex2(buffer, rex_w, map1, &reg, &rm);
+        } else if need_rex {
             // Certain SSE/AVX legacy encoded operations are not available in 32-bit mode
             // as they require a REX.W prefix to be encoded, which is impossible. We catch those cases here
-            if ctx.mode == X86Mode::Protected {
-                return Err(Some(format!("'{}': Does not support 64 bit operand size in 32-bit mode", op.to_string())))
+            if ctx.mode != X86Mode::Long {
+                return Err(Some(format!("'{}': Does not support 64 bit operand size in {}-bit mode", op.to_string(), ctx.mode.bits())))
             }
             compile_rex(buffer, rex_w, &reg, &rm);
         }
     }
 
     // if rm is embedded in the last opcode byte, push it here
+    let opcode_start = stmts_len(&buffer[start ..]);
     if data.flags.contains(Flags::SHORT_ARG) {
         let (last, head) = ops.split_last().expect("bad formatting data");
         ops = head;
@@ -264,10 +506,18 @@ pub(super) fn compile_instruction(ctx: &mut Context, instruction: Instruction, a
         } else {
             buffer.push(Stmt::u8(last + (rm_k.encode() & 7)));
         }
+    // the condition code is encoded in the last opcode byte
+    } else if let Some(cond) = cond {
+        let (last, head) = ops.split_last().expect("bad formatting data");
+        buffer.push(Stmt::Extend(Vec::from(head)));
+
+        let last: TokenTree = proc_macro2::Literal::u8_suffixed(*last).into();
+        buffer.push(Stmt::ExprUnsigned(serialize::expr_mask_shift_or(&last, &delimited(cond), 0xF, 0), Size::BYTE));
     // just push the opcode
     } else {
         buffer.push(Stmt::Extend(Vec::from(ops)));
     }
+    let opcode_len = stmts_len(&buffer[start ..]) - opcode_start;
 
     // Direct ModRM addressing
     if let Some(SizedArg::Direct {reg: rm, ..}) = rm {
@@ -290,7 +540,7 @@ pub(super) fn compile_instruction(ctx: &mut Context, instruction: Instruction, a
         let mode_vsib = index.as_ref().map_or(false, |&(ref i, _, _)| i.kind.family() == RegFamily::XMM);
         let mode_16bit = addr_size == Size::WORD;
         let mode_rip_relative = base.as_ref().map_or(false, |b| b.kind.family() == RegFamily::RIP);
-        let mode_rbp_base = base.as_ref().map_or(false, |b| b == &RegId::RBP || b == &RegId::R13 || b.kind.is_dynamic());
+        let mode_rbp_base = base.as_ref().map_or(false, |b| b == &RegId::RBP || b == &RegId::R13 || b == &RegId::R21 || b == &RegId::R29 || b.kind.is_dynamic());
 
         if mode_vsib {
             let (index, scale, scale_expr) = index.unwrap();
@@ -328,12 +578,15 @@ pub(super) fn compile_instruction(ctx: &mut Context, instruction: Instruction, a
 
         } else if mode_16bit {
             // 16-bit mode: the index/base combination has been encoded in the base register.
-            // this register is guaranteed to be present.
-            let base_k = base.unwrap().kind;
+            // if no base is present, only a displacement is encoded, which uses the encoding of [bp].
+            let no_base = base.is_none();
+            let mode_bp_base = base.as_ref().map_or(false, |b| b == &RegId::RSI);
+            let base_k = base.map_or(RegKind::Static(RegId::RSI), |b| b.kind);
             let mode = match (&disp, disp_size) {
+                _ if no_base => MOD_NODISP,
                 (&Some(_), Some(Size::BYTE)) => MOD_DISP8,
                 (&Some(_), _) => MOD_DISP32, // well, technically 16-bit.
-                (&None, _) => if mode_rbp_base {MOD_DISP8} else {MOD_NODISP}
+                (&None, _) => if mode_bp_base {MOD_DISP8} else {MOD_NODISP}
             };
 
             // only need a mod.r/m byte for 16-bit addressing
@@ -341,6 +594,8 @@ pub(super) fn compile_instruction(ctx: &mut Context, instruction: Instruction, a
 
             if let Some(disp) = disp {
                 buffer.push(Stmt::ExprSigned(delimited(disp), if mode == MOD_DISP8 {Size::BYTE} else {Size::WORD}));
+            } else if no_base {
+                buffer.push(Stmt::u16(0));
             } else if mode == MOD_DISP8 {
                 buffer.push(Stmt::u8(0));
             }
@@ -355,7 +610,7 @@ pub(super) fn compile_instruction(ctx: &mut Context, instruction: Instruction, a
                 } else {
                     buffer.push(Stmt::u32(0))
                 },
-                X86Mode::Protected => {
+                X86Mode::Protected | X86Mode::Real => {
                     // x86 doesn't actually allow RIP-relative addressing
                     // but we can work around it with relocations
                     buffer.push(Stmt::u32(0));
@@ -380,6 +635,13 @@ pub(super) fn compile_instruction(ctx: &mut Context, instruction: Instruction, a
                 MOD_DISP32
             };
 
+            // sibmem operands always require a SIB byte. RSP as index encodes the lack of an index
+            let index = if index.is_none() && data.flags.contains(Flags::SIBMEM) {
+                Some((Register::new_static(addr_size, RegId::RSP), 1, None))
+            } else {
+                index
+            };
+
             // if there's an index we need to escape into the SIB byte
             if let Some((index, scale, scale_expr)) = index {
                 // to encode the lack of a base we encode RBP
@@ -405,7 +667,7 @@ pub(super) fn compile_instruction(ctx: &mut Context, instruction: Instruction, a
             // no base, no index. only disp. Easy in x86, but in x64 escape, use RBP as base and RSP as index
             } else {
                 match ctx.mode {
-                    X86Mode::Protected => {
+                    X86Mode::Protected | X86Mode::Real => {
                         compile_modrm_sib(buffer, mode, reg_k, RegKind::Static(RegId::RBP));
                     },
                     X86Mode::Long => {
@@ -432,13 +694,28 @@ pub(super) fn compile_instruction(ctx: &mut Context, instruction: Instruction, a
         } else {
             RegKind::from_number(data.reg)
         };
-        compile_modrm_sib(buffer, MOD_NODISP, reg_k, RegKind::Static(RegId::RBP));
-
-        buffer.push(Stmt::u32(0));
         match ctx.mode {
-            X86Mode::Long      => relocations.push((jump, 0, Size::DWORD, RelocationKind::Relative)),
-            X86Mode::Protected => relocations.push((jump, 0, Size::DWORD, RelocationKind::Absolute))
+            X86Mode::Long => {
+                compile_modrm_sib(buffer, MOD_NODISP, reg_k, RegKind::Static(RegId::RBP));
+                buffer.push(Stmt::u32(0));
+                relocations.push((jump, 0, Size::DWORD, RelocationKind::Relative));
+            },
+            X86Mode::Protected => {
+                compile_modrm_sib(buffer, MOD_NODISP, reg_k, RegKind::Static(RegId::RBP));
+                buffer.push(Stmt::u32(0));
+                relocations.push((jump, 0, Size::DWORD, RelocationKind::Absolute));
+            },
+            X86Mode::Real => {
+                // the 16-bit displacement-only form is encoded in the place of [bp]
+                compile_modrm_sib(buffer, MOD_NODISP, reg_k, RegKind::Static(RegId::RSI));
+                buffer.push(Stmt::u16(0));
+                relocations.push((jump, 0, Size::WORD, RelocationKind::Absolute));
+            }
         }
+
+    // only a register in the reg field, the r/m field is unused
+    } else if let Some(SizedArg::Direct {reg, ..}) = reg {
+        compile_modrm_sib(buffer, MOD_DIRECT, reg.kind, RegKind::from_number(0));
     }
 
     // opcode encoded after the displacement
@@ -492,7 +769,7 @@ pub(super) fn compile_instruction(ctx: &mut Context, instruction: Instruction, a
                 // add the new relocation
                 if let JumpKind::Bare(_) = &jump.kind {
                     match ctx.mode {
-                        X86Mode::Protected => relocations.push((jump, 0, size, RelocationKind::Extern)),
+                        X86Mode::Protected | X86Mode::Real => relocations.push((jump, 0, size, RelocationKind::Extern)),
                         X86Mode::Long => return Err(Some("Extern relocations are not supported in x64 mode".to_string()))
                     }
                 } else {
@@ -503,18 +780,31 @@ pub(super) fn compile_instruction(ctx: &mut Context, instruction: Instruction, a
         };
     }
 
-    // push relocations
+    // push relocations. x64 relocations also describe the instruction they are part of, so branches can be relaxed
+    let len = stmts_len(&buffer[start ..]);
     for (target, offset, size, kind) in relocations {
-        let data = [offset, size.in_bytes(), kind.to_id()];
         let data = match ctx.mode {
-            X86Mode::Protected => &data,
-            X86Mode::Long      => &data[..2],
+            X86Mode::Protected |
+            X86Mode::Real      => vec![offset, size.in_bytes(), kind.to_id()],
+            X86Mode::Long      => vec![offset, size.in_bytes(), len, opcode_len],
         };
 
-        buffer.push(target.encode(data));
+        buffer.push(target.encode(&data));
     }
 
-    Ok(())
+    Ok(disp8_scale)
+}
+
+// the amount of bytes emitted by the statements of a single instruction
+fn stmts_len(stmts: &[Stmt]) -> u8 {
+    stmts.iter().map(|stmt| match *stmt {
+        Stmt::Const(_, size) |
+        Stmt::ExprUnsigned(_, size) |
+        Stmt::ExprSigned(_, size) => size.in_bytes(),
+        Stmt::Extend(ref bytes) => bytes.len() as u8,
+        Stmt::Stmt(_) => 0,
+        _ => panic!("unexpected statement in instruction")
+    }).sum()
 }
 
 // Folds RawArgs into CleanArgs by analyzing the different raw memoryref variants
@@ -529,6 +819,10 @@ fn clean_memoryref(arg: RawArg) -> Result<CleanArg, Option<String>> {
             CleanArg::IndirectJumpTarget {jump, size}
         },
         RawArg::Immediate {value, size} => CleanArg::Immediate {value, size},
+        RawArg::Cond {span, ..} => {
+            emit_error_at(span, "Condition codes can only be used as first operand of jcc, setcc and cmovcc".into());
+            return Err(None);
+        },
         RawArg::Invalid => return Err(None),
         RawArg::IndirectRaw {span, value_size, nosplit, disp_size, items} => {
             // split the ast on the memoryrefitem types
@@ -691,7 +985,7 @@ fn clean_memoryref(arg: RawArg) -> Result<CleanArg, Option<String>> {
     })
 }
 
-// Go through the CleanArgs, check for impossible to encode indirect arguments, fill in immediate/displacement size information
+// Go through the CleanArgs, check for impossible to encode indirect arguments, fill in immediate size information
 // and return the effective address size
 fn sanitize_indirects_and_sizes(ctx: &Context, args: &mut [CleanArg]) -> Result<Option<Size>, Option<String>> {
     // determine if an address size prefix is necessary, and sanitize the register choice for memoryrefs
@@ -700,7 +994,7 @@ fn sanitize_indirects_and_sizes(ctx: &Context, args: &mut [CleanArg]) -> Result<
 
     for arg in args.iter_mut() {
         match *arg {
-            CleanArg::Indirect {span, nosplit, ref mut disp_size, ref mut base, ref mut index, ref disp, ..} => {
+            CleanArg::Indirect {span, nosplit, ref disp_size, ref mut base, ref mut index, ref disp, ..} => {
 
                 if encountered_indirect {
                     emit_error_at(span, "Multiple memory references in a single instruction".into())
@@ -730,13 +1024,6 @@ fn sanitize_indirects_and_sizes(ctx: &Context, args: &mut [CleanArg]) -> Result<
                     } else if size != Size::BYTE && size != Size::DWORD {
                         emit_error_at(span, "Invalid displacement size, only BYTE or DWORD are possible".into());
                     }
-                } else if let Some(ref disp) = *disp {
-                    match derive_size(disp) {
-                        Some(Size::BYTE)                         => *disp_size = Some(Size::BYTE),
-                        Some(_) if addr_size == Some(Size::WORD) => *disp_size = Some(Size::WORD),
-                        Some(_)                                  => *disp_size = Some(Size::DWORD),
-                        None => ()
-                    }
                 }
             },
             CleanArg::Immediate {ref value, size: ref mut size @ None} => {
@@ -749,6 +1036,67 @@ fn sanitize_indirects_and_sizes(ctx: &Context, args: &mut [CleanArg]) -> Result<
     Ok(addr_size)
 }
 
+// Determines the size of the displacement of a memory reference if it wasn't specified.
+// EVEX encoded instructions implicitly scale 8-bit displacements by disp8_scale, so for them
+// the displacement is replaced by the compressed displacement if an 8-bit displacement is used.
+fn derive_disp_size(addr_size: Size, disp8_scale: Option<u8>, disp_size: &mut Option<Size>, disp: &mut Option<syn::Expr>) -> Result<(), Option<String>> {
+    let expr = if let Some(ref expr) = *disp {
+        expr
+    } else {
+        return Ok(());
+    };
+
+    let scale = match disp8_scale {
+        Some(scale) => i64::from(scale),
+        None => {
+            if disp_size.is_none() {
+                *disp_size = match derive_size(expr) {
+                    Some(Size::BYTE)                 => Some(Size::BYTE),
+                    Some(_) if addr_size == Size::WORD => Some(Size::WORD),
+                    Some(_)                          => Some(Size::DWORD),
+                    None => None
+                };
+            }
+            return Ok(());
+        }
+    };
+
+    let compressed = if let Some(value) = as_signed_number(expr) {
+        if value % scale == 0 && value / scale >= -0x80 && value / scale < 0x80 {
+            Some(value / scale)
+        } else {
+            None
+        }
+    } else {
+        None
+    };
+
+    match (*disp_size, compressed) {
+        (Some(Size::BYTE), Some(value)) |
+        (None, Some(value)) => {
+            *disp_size = Some(Size::BYTE);
+            let value: TokenTree = proc_macro2::Literal::i64_unsuffixed(value).into();
+            *disp = Some(serialize::reparse(&value).expect("Invalid expression generated internally"));
+        },
+        (Some(Size::BYTE), None) => if as_signed_number(expr).is_some() {
+            emit_error_at(expr.span(), format!("Displacement cannot be encoded as a compressed 8-bit displacement with scale {}", scale));
+            return Err(None);
+        } else {
+            // runtime determined displacement. Scale it down at runtime.
+            let span = expr.span();
+            let expr = delimited(expr.clone());
+            let scale: TokenTree = proc_macro2::Literal::i64_unsuffixed(scale).into();
+            let value = delimited(quote_spanned!{ span=>
+                #expr / #scale
+            });
+            *disp = Some(serialize::reparse(&value).expect("Invalid expression generated internally"));
+        },
+        _ => ()
+    }
+
+    Ok(())
+}
+
 // Tries to find the maximum size necessary to hold the result of an expression.
 fn derive_size(expr: &syn::Expr) -> Option<Size> {
     // strip any wrapping Group nodes due to delimiting
@@ -842,7 +1190,7 @@ fn sanitize_indirect(ctx: &Context, span: Span, nosplit: bool, base: &mut Option
         RegFamily::LEGACY => match size {
             Size::DWORD => (),
             Size::QWORD => (), // only valid in long mode, but should only be possible in long mode
-            Size::WORD  => if ctx.mode == X86Mode::Protected || vsib_mode {
+            Size::WORD  => if ctx.mode == X86Mode::Long || vsib_mode {
                 emit_error_at(span, "16-bit addressing is not supported in this mode".into());
                 return Err(None);
             },
@@ -971,16 +1319,16 @@ fn sanitize_indirect(ctx: &Context, span: Span, nosplit: bool, base: &mut Option
         }
     }
 
-    // RSP, R12 or a dynamic register as base without index (add an index so we escape into SIB)
-    if index.is_none() && (*base == RegId::RSP || *base == RegId::R12 || base.as_ref().map_or(false, |r| r.kind.is_dynamic())) {
+    // RSP, R12, R20, R28 or a dynamic register as base without index (add an index so we escape into SIB)
+    if index.is_none() && (*base == RegId::RSP || *base == RegId::R12 || *base == RegId::R20 || *base == RegId::R28 || base.as_ref().map_or(false, |r| r.kind.is_dynamic())) {
         *index = Some((Register::new_static(size, RegId::RSP), 1, None));
     }
 
-    // RBP as base field just requires a mandatory MOD_DISP8, so we only process that at encoding time
+    // RBP, R13, R21 or R29 as base field just requires a mandatory MOD_DISP8, so we only process that at encoding time
     Ok(Some(size))
 }
 
-fn match_op_format(ctx: &Context, ident: &syn::Ident, args: &[CleanArg]) -> Result<&'static Opdata, Option<String>> {
+fn match_op_format(ctx: &Context, ident: &syn::Ident, args: &[CleanArg], decorations: &Decorations) -> Result<&'static Opdata, Option<String>> {
     let name = ident.to_string();
     let name = name.as_str();
 
@@ -992,7 +1340,7 @@ fn match_op_format(ctx: &Context, ident: &syn::Ident, args: &[CleanArg]) -> Resu
     };
 
     for format in data {
-        if let Ok(()) = match_format_string(ctx, format, args) {
+        if let Ok(()) = match_format_string(ctx, format, args, decorations) {
             return Ok(format);
         }
     }
@@ -1002,11 +1350,55 @@ fn match_op_format(ctx: &Context, ident: &syn::Ident, args: &[CleanArg]) -> Resu
     ))
 }
 
-fn match_format_string(ctx: &Context, fmt: &Opdata, args: &[CleanArg]) -> Result<(), &'static str> {
+fn match_format_string(ctx: &Context, fmt: &Opdata, args: &[CleanArg], decorations: &Decorations) -> Result<(), &'static str> {
     let fmtstr = &fmt.args;
 
-    if ctx.mode != X86Mode::Protected && fmt.flags.intersects(Flags::X86_ONLY) {
-        return Err("Not available in 32-bit mode");
+    if ctx.mode == X86Mode::Long && fmt.flags.intersects(Flags::X86_ONLY) {
+        return Err("Not available in 64-bit mode");
+    }
+
+    if ctx.mode != X86Mode::Long && fmt.flags.intersects(Flags::NDD) {
+        return Err("Only available in 64-bit mode");
+    }
+
+    // AVX-512 decorations can only be encoded in an EVEX prefix, and only if the instruction supports them
+    if fmt.flags.contains(Flags::EVEX_OP) {
+        if decorations.mask.is_some() && !fmt.flags.contains(Flags::OPMASK) {
+            return Err("opmask not supported");
+        }
+        if decorations.zeroing && !fmt.flags.contains(Flags::ZEROING) {
+            return Err("zeroing-masking not supported");
+        }
+        if decorations.broadcast.is_some() && !fmt.flags.contains(Flags::BCST) {
+            return Err("broadcast not supported");
+        }
+        match decorations.rounding {
+            Some(Rounding::SAE) if !fmt.flags.contains(Flags::SAE) =>
+                return Err("suppress-all-exceptions not supported"),
+            Some(Rounding::SAE) => (),
+            Some(_) if !fmt.flags.contains(Flags::ROUNDING) =>
+                return Err("embedded rounding not supported"),
+            _ => ()
+        }
+    } else if !decorations.is_empty() {
+        return Err("decorations require an EVEX encoded instruction");
+    } else if args.iter().any(|arg| match *arg {
+        CleanArg::Direct {ref reg, ..} => reg.kind.is_evex_only(),
+        CleanArg::Indirect {index: Some((ref reg, _, _)), ..} => reg.kind.is_evex_only(),
+        _ => false
+    }) {
+        return Err("xmm16-xmm31 require an EVEX encoded instruction");
+    }
+
+    // r16-r31 can only be encoded using REX2 or EVEX
+    if fmt.flags.intersects(Flags::VEX_OP | Flags::XOP_OP) && args.iter().any(|arg| match *arg {
+        CleanArg::Direct {ref reg, ..} => reg.kind.is_apx_extended(),
+        CleanArg::Indirect {ref base, ref index, ..} =>
+            base.as_ref().map_or(false, |r| r.kind.is_apx_extended()) ||
+            index.as_ref().map_or(false, |&(ref r, _, _)| r.kind.is_apx_extended()),
+        _ => false
+    }) {
+        return Err("r16-r31 cannot be encoded in a VEX or XOP prefix");
     }
 
     if fmtstr.len() != args.len() * 2 {
@@ -1022,7 +1414,9 @@ fn match_format_string(ctx: &Context, fmt: &Opdata, args: &[CleanArg]) -> Result
     // r : legacy reg
     // f : fp reg
     // x : mmx reg
-    // y : xmm/ymm reg
+    // y : xmm/ymm/zmm reg
+    // j : opmask reg
+    // t : tile reg
     // s : segment reg
     // c : control reg
     // d : debug reg
@@ -1037,10 +1431,10 @@ fn match_format_string(ctx: &Context, fmt: &Opdata, args: &[CleanArg]) -> Result
     // W: matches CR8
     // X: matches st0
 
-    // b, w, d, q, o, h match a byte, word, doubleword, quadword, octword and hexadecword
+    // b, w, d, q, o, h, z match a byte, word, doubleword, quadword, octword, hexadecword and zmmword
     // p matches a PWORD (10 bytes)
     // f matches an FWORD (6 bytes)
-    // * matches all possible sizes for this operand (w/d for i, w/d/q for r/v, o/h for y/w and everything for m)
+    // * matches all possible sizes for this operand (w/d for i, w/d/q for r/v, o/h for y/w (o/h/z if EVEX) and everything for m)
     // ! matches a lack of size, only useful in combination with m
     // ? matches any size and doesn't participate in the operand size calculation
     let mut args = args.iter();
@@ -1096,6 +1490,17 @@ fn match_format_string(ctx: &Context, fmt: &Opdata, args: &[CleanArg]) -> Result
                 reg.kind.family() == RegFamily::DEBUG => Some(reg.size()),
             (b'b', &CleanArg::Direct{ref reg, ..}) if
                 reg.kind.family() == RegFamily::BOUND => Some(reg.size()),
+            (b'j', &CleanArg::Direct{ref reg, ..}) if
+                reg.kind.family() == RegFamily::OPMASK => Some(reg.size()),
+            (b't', &CleanArg::Direct{ref reg, ..}) if
+                reg.kind.family() == RegFamily::TILE => Some(reg.size()),
+
+            // broadcasted memory references only reference a single element. Their size is
+            // checked when the element size is known.
+            (b'm',          &CleanArg::Indirect {ref index, ..}) |
+            (b'u' ..= b'w', &CleanArg::Indirect {ref index, ..}) if
+                decorations.broadcast.is_some() &&
+                (index.is_none() || index.as_ref().unwrap().0.kind.family() != RegFamily::XMM) => None,
 
             // memory offsets
             (b'm',          &CleanArg::Indirect {size, ref index, ..}) |
@@ -1132,11 +1537,13 @@ fn match_format_string(ctx: &Context, fmt: &Opdata, args: &[CleanArg]) -> Result
                 (b'p', _)    => size == Size::PWORD,
                 (b'o', _)    => size == Size::OWORD,
                 (b'h', _)    => size == Size::HWORD,
+                (b'z', _)    => size == Size::ZWORD,
                 // what is allowed for wildcards
                 (b'*', b'k') |
                 (b'*', b'l') |
                 (b'*', b'y') |
-                (b'*', b'w') => size == Size::OWORD || size == Size::HWORD,
+                (b'*', b'w') => size == Size::OWORD || size == Size::HWORD ||
+                    (size == Size::ZWORD && fmt.flags.contains(Flags::EVEX_OP)),
                 (b'*', b'r') |
                 (b'*', b'A' ..= b'P') |
                 (b'*', b'v') => size == Size::WORD || size == Size::DWORD || size == Size::QWORD,
@@ -1247,6 +1654,7 @@ fn size_operands(fmt: &Opdata, args: Vec<CleanArg>) -> Result<(Option<Size>, Vec
             (b'p', _) => Size::PWORD,
             (b'o', _) => Size::OWORD,
             (b'h', _) => Size::HWORD,
+            (b'z', _) => Size::ZWORD,
             (b'*', b'i') => im_size.unwrap(),
             (b'*', _) => op_size.unwrap(),
             (b'!', _) => Size::BYTE, // will never be used, placeholder
@@ -1263,7 +1671,7 @@ fn size_operands(fmt: &Opdata, args: Vec<CleanArg>) -> Result<(Option<Size>, Vec
             CleanArg::Immediate {value, ..} =>
                 SizedArg::Immediate {value, size},
             CleanArg::Indirect {span, disp_size, base, index, disp, ..} => 
-                SizedArg::Indirect {span, disp_size, base, index, disp},
+                SizedArg::Indirect {span, size, disp_size, base, index, disp},
         });
     }
 
@@ -1324,9 +1732,9 @@ fn get_legacy_prefixes(fmt: &'static Opdata, idents: Vec<syn::Ident>) -> Result<
 fn check_rex(ctx: &Context, fmt: &'static Opdata, args: &[SizedArg], rex_w: bool) -> Result<bool, Option<String>> {
     // performs checks for not encodable arg combinations
     // output arg indicates if a rex prefix can be encoded
-    if ctx.mode == X86Mode::Protected {
+    if ctx.mode != X86Mode::Long {
         if rex_w {
-            return Err(Some("Does not support 64 bit operand size in 32-bit mode".to_string()));
+            return Err(Some(format!("Does not support 64 bit operand size in {}-bit mode", ctx.mode.bits())));
         } else {
             return Ok(false);
         }
@@ -1361,13 +1769,87 @@ fn check_rex(ctx: &Context, fmt: &'static Opdata, args: &[SizedArg], rex_w: bool
         }
     }
 
-    if requires_rex && requires_no_rex {
+    if requires_no_rex && fmt.flags.contains(Flags::EVEX_OP) {
+        Err(Some("High byte registers cannot be encoded in an EVEX prefix".to_string()))
+    } else if requires_rex && requires_no_rex {
         Err(Some("High byte register combined with extended registers or 64-bit operand size".to_string()))
     } else {
         Ok(requires_rex)
     }
 }
 
+fn check_rex2(ctx: &Context, fmt: &'static Opdata, args: &[SizedArg]) -> Result<bool, Option<String>> {
+    // checks if the APX extended registers are used, and if a REX2 prefix is required to encode them.
+    // when the apx feature is enabled, dynamic registers are assumed to possibly be one of these registers.
+    let mut requires_rex2 = false;
+    let mut dynamic = false;
+
+    for (arg, (c, _)) in args.iter().zip(FormatStringIterator::new(fmt.args)) {
+        // only scan args that are actually encoded
+        if let b'a' ..= b'z' = c {
+            let mut check = |reg: &Register| if reg.kind.is_apx_extended() {
+                requires_rex2 = true;
+            } else if reg.kind.is_dynamic() && reg.kind.family() == RegFamily::LEGACY {
+                dynamic = true;
+            };
+
+            match *arg {
+                SizedArg::Direct {ref reg, ..} => check(reg),
+                SizedArg::Indirect {ref base, ref index, ..} => {
+                    if let Some(ref reg) = *base {
+                        check(reg);
+                    }
+                    if let Some((ref reg, _, _)) = *index {
+                        check(reg);
+                    }
+                },
+                _ => (),
+            }
+        }
+    }
+
+    if requires_rex2 && !ctx.features.contains(Features::APX) {
+        return Err(Some(format!(
+            "This instruction uses features that are not indicated to be available: {}",
+            Features::APX
+        )));
+    }
+
+    // VEX/XOP/EVEX encoded instructions do not use a REX2 prefix
+    if ctx.mode != X86Mode::Long || fmt.flags.intersects(Flags::VEX_OP | Flags::XOP_OP | Flags::EVEX_OP) {
+        return Ok(false);
+    }
+
+    if requires_rex2 {
+        if !rex2_compatible(fmt) {
+            return Err(Some("r16-r31 cannot be used with this instruction".to_string()));
+        }
+        Ok(true)
+    } else {
+        Ok(dynamic && ctx.features.contains(Features::APX) && rex2_compatible(fmt))
+    }
+}
+
+fn rex2_compatible(fmt: &'static Opdata) -> bool {
+    // REX2 cannot be used with the 0F38 and 0F3A opcode maps, nor with several rows of the
+    // one-byte and 0F opcode maps (REX/INC/DEC, Jcc, MOV moffs/string ops, LOOP/JCXZ/IN/OUT/CALL/JMP).
+    if fmt.flags.intersects(Flags::IMM_OP) {
+        return false;
+    }
+
+    let ops = fmt.ops;
+    if ops.len() >= 2 && ops[0] == 0x0F {
+        match ops[1] {
+            0x38 | 0x3A => false,
+            op => !(op >> 4 == 0x3 || op >> 4 == 0x8)
+        }
+    } else if let Some(&op) = ops.first() {
+        !(op >> 4 == 0x4 || op >> 4 == 0x7 || op >> 4 == 0xA || op >> 4 == 0xE)
+    } else {
+        false
+    }
+}
+
 fn extract_args(fmt: &'static Opdata, args: Vec<SizedArg>) -> (Option<SizedArg>, Option<SizedArg>, Option<SizedArg>, Option<SizedArg>, Vec<SizedArg>) {
     // way operand order works:
 
@@ -1376,9 +1858,10 @@ fn extract_args(fmt: &'static Opdata, args: Vec<SizedArg>) -> (Option<SizedArg>,
 
     // default argument encoding order is as follows:
     // no encoding flag: m, rm, rvm, rvim
-    // ENC_MR:              mr, rmv, rvmi
+    // ENC_MR:              r, mr, rmv, rvmi
     // ENC_VM:              vm, mvr
     // these can also be chosen based on the location of a memory argument (except for vm)
+    // NDD:                 the first argument goes into vvvv, the rest is encoded as above
 
     let mut memarg = None;
     let mut regarg = None;
@@ -1393,7 +1876,7 @@ fn extract_args(fmt: &'static Opdata, args: Vec<SizedArg>) -> (Option<SizedArg>,
                 memarg = Some(regs.len());
                 regs.push(arg)
             },
-            b'f' | b'x' | b'r' | b'y' | b'b' => regs.push(arg),
+            b'f' | b'x' | b'r' | b'y' | b'b' | b'j' | b't' => regs.push(arg),
             b'c' | b'd' | b's'        => if regarg.is_some() {
                 panic!("multiple segment, debug or control registers in format string");
             } else {
@@ -1405,7 +1888,7 @@ fn extract_args(fmt: &'static Opdata, args: Vec<SizedArg>) -> (Option<SizedArg>,
         }
     }
 
-    let len = regs.len();
+    let mut len = regs.len();
     if len > 4 {
         panic!("too many arguments");
     }
@@ -1416,6 +1899,12 @@ fn extract_args(fmt: &'static Opdata, args: Vec<SizedArg>) -> (Option<SizedArg>,
     let mut v = None;
     let mut i = None;
 
+    if fmt.flags.contains(Flags::NDD) {
+        v = regs.next();
+        len -= 1;
+        memarg = memarg.map(|pos| pos - 1);
+    }
+
     if let Some(i) = regarg {
         if i == 0 {
             r = regs.next();
@@ -1425,7 +1914,11 @@ fn extract_args(fmt: &'static Opdata, args: Vec<SizedArg>) -> (Option<SizedArg>,
             r = regs.next();
         }
     } else if len == 1 {
-        m = regs.next();
+        if fmt.flags.contains(Flags::ENC_MR) {
+            r = regs.next();
+        } else {
+            m = regs.next();
+        }
     } else if len == 2 {
         if fmt.flags.contains(Flags::ENC_MR) || memarg == Some(0) {
             m = regs.next();
@@ -1468,6 +1961,50 @@ fn extract_args(fmt: &'static Opdata, args: Vec<SizedArg>) -> (Option<SizedArg>,
     (m, r, v, i, immediates)
 }
 
+// Validates the AVX-512 decorations used on an EVEX encoded instruction and
+// returns the scale of compressed 8-bit displacements
+fn check_decorations(fmt: &'static Opdata, decorations: &Decorations, rm: &Option<SizedArg>, element_size: Option<Size>, rex_w: bool, vector_length: u8) -> Result<u8, Option<String>> {
+    if decorations.zeroing && decorations.mask.is_none() {
+        return Err(Some("Zeroing-masking requires an opmask".to_string()));
+    }
+
+    // EVEX promoted legacy instructions don't use compressed displacements
+    if fmt.flags.contains(Flags::NDD) {
+        return Ok(1);
+    }
+
+    let memory_size = if let Some(SizedArg::Indirect {size, ..}) = *rm {
+        Some(size)
+    } else {
+        None
+    };
+
+    if decorations.rounding.is_some() {
+        if memory_size.is_some() {
+            return Err(Some("Embedded rounding cannot be used with memory operands".to_string()));
+        }
+        if fmt.flags.contains(Flags::AUTO_VEXL) && vector_length != 0b10 {
+            return Err(Some("Embedded rounding is only supported for 512-bit vectors".to_string()));
+        }
+    }
+
+    if let Some(count) = decorations.broadcast {
+        // the element size of broadcasts is determined by EVEX.W
+        let element = if rex_w {Size::QWORD} else {Size::DWORD};
+        if element_size.map_or(false, |s| s != element) {
+            return Err(Some(format!("Broadcast element size mismatch, expected {:?}", element)));
+        }
+
+        let memory_size = memory_size.expect("broadcast without memory reference");
+        if u32::from(count) * u32::from(element.in_bytes()) != u32::from(memory_size.in_bytes()) {
+            return Err(Some(format!("Invalid broadcast, expected {{1to{}}}", memory_size.in_bytes() / element.in_bytes())));
+        }
+        return Ok(element.in_bytes());
+    }
+
+    Ok(memory_size.map_or(1, Size::in_bytes))
+}
+
 fn encode_scale(scale: isize) -> Option<u8> {
     match scale {
         1 => Some(0),
@@ -1521,6 +2058,58 @@ fn compile_rex(buffer: &mut Vec<Stmt>, rex_w: bool, reg: &Option<SizedArg>, rm:
     buffer.push(Stmt::ExprUnsigned(rex, Size::BYTE));
 }
 
+fn compile_rex2(buffer: &mut Vec<Stmt>, rex_w: bool, map1: bool, reg: &Option<SizedArg>, rm: &Option<SizedArg>) {
+    let mut reg_k   = RegKind::from_number(0);
+    let mut index_k = RegKind::from_number(0);
+    let mut base_k  = RegKind::from_number(0);
+
+    if let Some(SizedArg::Direct {ref reg, ..}) = *reg {
+        reg_k = reg.kind.clone();
+    }
+    if let Some(SizedArg::Direct {ref reg, ..}) = *rm {
+        base_k = reg.kind.clone();
+    }
+    if let Some(SizedArg::Indirect {ref base, ref index, ..} ) = *rm {
+        if let Some(ref base) = *base {
+            base_k = base.kind.clone();
+        }
+        if let Some((ref index, _, _)) = *index {
+            index_k = index.kind.clone();
+        }
+    }
+
+    let rex2 = (map1              as u8) << 7 |
+               (reg_k.encode()   & 0x10) << 2 |
+               (index_k.encode() & 0x10) << 1 |
+               (base_k.encode()  & 0x10)      |
+               (rex_w             as u8) << 3 |
+               (reg_k.encode()      & 8) >> 1 |
+               (index_k.encode()    & 8) >> 2 |
+               (base_k.encode()     & 8) >> 3 ;
+
+    buffer.push(Stmt::u8(0xD5));
+    if !reg_k.is_dynamic() && !index_k.is_dynamic() && !base_k.is_dynamic() {
+        buffer.push(Stmt::u8(rex2));
+        return;
+    }
+
+    let mut rex2: TokenTree = proc_macro2::Literal::u8_suffixed(rex2).into();
+
+    if let RegKind::Dynamic(_, ref expr) = reg_k {
+        rex2 = serialize::expr_mask_shift_or(&rex2, &delimited(expr), 0x10, 2);
+        rex2 = serialize::expr_mask_shift_or(&rex2, &delimited(expr), 8, -1);
+    }
+    if let RegKind::Dynamic(_, ref expr) = index_k {
+        rex2 = serialize::expr_mask_shift_or(&rex2, &delimited(expr), 0x10, 1);
+        rex2 = serialize::expr_mask_shift_or(&rex2, &delimited(expr), 8, -2);
+    }
+    if let RegKind::Dynamic(_, ref expr) = base_k {
+        rex2 = serialize::expr_mask_shift_or(&rex2, &delimited(expr), 0x10, 0);
+        rex2 = serialize::expr_mask_shift_or(&rex2, &delimited(expr), 8, -3);
+    }
+    buffer.push(Stmt::ExprUnsigned(rex2, Size::BYTE));
+}
+
 fn compile_vex_xop(mode: X86Mode, buffer: &mut Vec<Stmt>, data: &'static Opdata, reg: &Option<SizedArg>,
 rm: &Option<SizedArg>, map_sel: u8, rex_w: bool, vvvv: &Option<SizedArg>, vex_l: bool, prefix: u8) {
     let mut reg_k   = RegKind::from_number(0);
@@ -1550,7 +2139,7 @@ rm: &Option<SizedArg>, map_sel: u8, rex_w: bool, vvvv: &Option<SizedArg>, vex_l:
             (!index_k.encode() & 8) << 3 |
             (!base_k.encode()  & 8) << 2
         },
-        X86Mode::Protected => {
+        X86Mode::Protected | X86Mode::Real => {
             (map_sel & 0x1f) | 0xE0
         }
     };
@@ -1565,7 +2154,7 @@ rm: &Option<SizedArg>, map_sel: u8, rex_w: bool, vvvv: &Option<SizedArg>, vex_l:
                 (vex_l            as u8) << 2 ;
 
     if data.flags.contains(Flags::VEX_OP) && (byte1 & 0x7F) == 0x61 && (byte2 & 0x80) == 0 &&
-    ((!index_k.is_dynamic() && !base_k.is_dynamic()) || mode == X86Mode::Protected) {
+    ((!index_k.is_dynamic() && !base_k.is_dynamic()) || mode != X86Mode::Long) {
         // 2-byte vex
         buffer.push(Stmt::u8(0xC5));
 
@@ -1617,6 +2206,178 @@ rm: &Option<SizedArg>, map_sel: u8, rex_w: bool, vvvv: &Option<SizedArg>, vex_l:
     }
 }
 
+fn compile_evex(mode: X86Mode, buffer: &mut Vec<Stmt>, data: &'static Opdata, reg: &Option<SizedArg>, rm: &Option<SizedArg>, map_sel: u8,
+rex_w: bool, vvvv: &Option<SizedArg>, vector_length: u8, prefix: u8, decorations: &Decorations) {
+    let mut reg_k   = RegKind::from_number(0);
+    let mut index_k = RegKind::from_number(0);
+    let mut base_k  = RegKind::from_number(0);
+    let mut vvvv_k  = RegKind::from_number(0);
+    let mut mask_k  = RegKind::from_number(0);
+
+    // if rm is a vector register, EVEX.X is used to extend it to 32 registers
+    let mut rm_direct = false;
+    // with VSIB addressing, EVEX.V' is used to extend the index register to 32 registers
+    let mut vsib = false;
+
+    if let Some(SizedArg::Direct {ref reg, ..}) = *reg {
+        reg_k = reg.kind.clone();
+    }
+    if let Some(SizedArg::Direct {ref reg, ..}) = *rm {
+        base_k = reg.kind.clone();
+        rm_direct = true;
+    }
+    if let Some(SizedArg::Indirect {ref base, ref index, ..}) = *rm {
+        if let Some(ref base) = *base {
+            base_k = base.kind.clone();
+        }
+        if let Some((ref index, _, _)) = *index {
+            index_k = index.kind.clone();
+            vsib = index_k.family() == RegFamily::XMM;
+        }
+    }
+    if let Some(SizedArg::Direct {ref reg, ..}) = *vvvv {
+        vvvv_k = reg.kind.clone();
+    }
+    if let Some(ref mask) = decorations.mask {
+        mask_k = mask.kind.clone();
+    }
+
+    // general purpose registers r16-r31 are encoded using EVEX.B4 and EVEX.X4 instead (APX)
+    let gpr_base = base_k.family() == RegFamily::LEGACY;
+    let gpr_index = !vsib && index_k.family() == RegFamily::LEGACY;
+    rm_direct = rm_direct && !gpr_base;
+
+    let byte1 = match mode {
+        X86Mode::Long => {
+            let x_bit = if rm_direct {
+                (!base_k.encode() & 0x10) << 2
+            } else {
+                (!index_k.encode() & 8) << 3
+            };
+            let b4_bit = if gpr_base {
+                (base_k.encode() & 0x10) >> 1
+            } else {
+                0
+            };
+
+            (map_sel        & 0x07)      |
+            (!reg_k.encode()   & 8) << 4 |
+            x_bit                        |
+            (!base_k.encode()  & 8) << 2 |
+            (!reg_k.encode() & 0x10)     | // EVEX.R'
+            b4_bit                         // EVEX.B4
+        },
+        X86Mode::Protected | X86Mode::Real => {
+            (map_sel & 0x07) | 0xF0
+        }
+    };
+
+    let x4_bit = if gpr_index {
+        (!index_k.encode() & 0x10) >> 2
+    } else {
+        0x04
+    };
+
+    let byte2 = (prefix           & 0x3)      |
+                (rex_w            as u8) << 7 |
+                (!vvvv_k.encode() & 0xF) << 3 |
+                x4_bit; // EVEX.X4
+
+    // embedded rounding control is encoded in the vector length field
+    let (vector_length, bcst) = match decorations.rounding {
+        Some(Rounding::SAE) => (0, true),
+        Some(rounding) => (rounding as u8, true),
+        None => (vector_length, decorations.broadcast.is_some())
+    };
+
+    // EVEX.V' extends either vvvv or the VSIB index register
+    let v_k = if vsib {&index_k} else {&vvvv_k};
+
+    // APX new data destination forms reuse EVEX.b as EVEX.ND
+    let nd = data.flags.contains(Flags::NDD);
+
+    let byte3 = (mask_k.encode()     & 0x07)      |
+                (!v_k.encode()       & 0x10) >> 1 | // EVEX.V'
+                (bcst                as u8)  << 4 |
+                (nd                  as u8)  << 4 |
+                (vector_length       & 0x03) << 5 |
+                (decorations.zeroing as u8)  << 7;
+
+    buffer.push(Stmt::u8(0x62));
+
+    if mode == X86Mode::Long && (reg_k.is_dynamic() || index_k.is_dynamic() || base_k.is_dynamic()) {
+        let mut byte1: TokenTree = proc_macro2::Literal::u8_suffixed(byte1).into();
+
+        if let RegKind::Dynamic(_, ref expr) = reg_k {
+            byte1 = serialize::expr_mask_shift_inverted_and(&byte1, &delimited(expr), 8, 4);
+            byte1 = serialize::expr_mask_shift_inverted_and(&byte1, &delimited(expr), 0x10, 0);
+        }
+        if let RegKind::Dynamic(_, ref expr) = index_k {
+            byte1 = serialize::expr_mask_shift_inverted_and(&byte1, &delimited(expr), 8, 3);
+        }
+        if let RegKind::Dynamic(_, ref expr) = base_k {
+            byte1 = serialize::expr_mask_shift_inverted_and(&byte1, &delimited(expr), 8, 2);
+            if rm_direct {
+                byte1 = serialize::expr_mask_shift_inverted_and(&byte1, &delimited(expr), 0x10, 2);
+            } else if gpr_base {
+                byte1 = serialize::expr_mask_shift_or(&byte1, &delimited(expr), 0x10, -1);
+            }
+        }
+        buffer.push(Stmt::ExprUnsigned(byte1, Size::BYTE));
+    } else {
+        buffer.push(Stmt::u8(byte1));
+    }
+
+    if vvvv_k.is_dynamic() || (gpr_index && index_k.is_dynamic()) {
+        let mut byte2: TokenTree = proc_macro2::Literal::u8_suffixed(byte2).into();
+
+        if let RegKind::Dynamic(_, ref expr) = vvvv_k {
+            byte2 = serialize::expr_mask_shift_inverted_and(&byte2, &delimited(expr), 0xF, 3);
+        }
+        if let (true, &RegKind::Dynamic(_, ref expr)) = (gpr_index, &index_k) {
+            byte2 = serialize::expr_mask_shift_inverted_and(&byte2, &delimited(expr), 0x10, -2);
+        }
+        buffer.push(Stmt::ExprUnsigned(byte2, Size::BYTE));
+    } else {
+        buffer.push(Stmt::u8(byte2));
+    }
+
+    if v_k.is_dynamic() || mask_k.is_dynamic() {
+        let mut byte3: TokenTree = proc_macro2::Literal::u8_suffixed(byte3).into();
+
+        if let RegKind::Dynamic(_, ref expr) = *v_k {
+            byte3 = serialize::expr_mask_shift_inverted_and(&byte3, &delimited(expr), 0x10, -1);
+        }
+        if let RegKind::Dynamic(_, ref expr) = mask_k {
+            byte3 = serialize::expr_mask_shift_or(&byte3, &delimited(expr), 7, 0);
+        }
+        buffer.push(Stmt::ExprUnsigned(byte3, Size::BYTE));
+    } else {
+        buffer.push(Stmt::u8(byte3));
+    }
+}
+
+// emits a runtime check that the dynamic vector registers in args are not any of xmm16-xmm31, as only
+// an EVEX prefix can encode those and otherwise a different register would be used silently.
+fn compile_vector_register_checks(buffer: &mut Vec<Stmt>, args: &[SizedArg]) {
+    for arg in args {
+        let reg = match *arg {
+            SizedArg::Direct {ref reg, ..} => reg,
+            SizedArg::Indirect {index: Some((ref reg, _, _)), ..} => reg,
+            _ => continue
+        };
+        if let RegKind::Dynamic(RegFamily::XMM, ref expr) = reg.kind {
+            let span = expr.span();
+            let expr = delimited(expr);
+            buffer.push(Stmt::Stmt(delimited(quote_spanned!{ span=>
+                if #expr >= 16 {
+                    dynasmrt::x64::runtime_error("xmm16-xmm31 require an EVEX encoded instruction")
+                }
+            })));
+        }
+    }
+}
+
 fn compile_modrm_sib(buffer: &mut Vec<Stmt>, mode: u8, reg1: RegKind, reg2: RegKind) {
     let byte = mode                << 6 |
               (reg1.encode()  & 7) << 3 |
@@ -15,6 +15,7 @@ pub fn format_opdata(name: &str, data: &Opdata) -> Vec<String> {
     let opsizes = if data.flags.contains(Flags::AUTO_SIZE) {&b"qwd"[..]}
              else if data.flags.contains(Flags::AUTO_NO32) {&b"qw"[..]}
              else if data.flags.contains(Flags::AUTO_REXW) {&b"qd"[..]}
+             else if data.flags.contains(Flags::AUTO_VEXL) && data.flags.contains(Flags::EVEX_OP) {&b"zho"[..]}
              else if data.flags.contains(Flags::AUTO_VEXL) {&b"ho"[..]}
              else if name == "monitorx"                    {&b"qwd"[..]}
              else                                          {&b"!"[..]};
@@ -25,6 +26,7 @@ pub fn format_opdata(name: &str, data: &Opdata) -> Vec<String> {
         buf.push_str(">>> ");
         buf.push_str(name);
         let mut first = true;
+        let mut first_arg = true;
         for (ty, size) in FormatStringIterator::new(data.args) {
             if first {
                 buf.push_str(" ");
@@ -32,7 +34,26 @@ pub fn format_opdata(name: &str, data: &Opdata) -> Vec<String> {
             } else {
                 buf.push_str(", ");
             }
-            buf.push_str(&format_arg(ty, size, opsize))
+            buf.push_str(&format_arg(ty, size, opsize));
+
+            // AVX-512 decorations
+            if first_arg {
+                if data.flags.contains(Flags::OPMASK) {
+                    buf.push_str("{k}");
+                }
+                if data.flags.contains(Flags::ZEROING) {
+                    buf.push_str("{z}");
+                }
+                first_arg = false;
+            }
+            if data.flags.contains(Flags::BCST) && (ty == b'm' || ty == b'w') {
+                buf.push_str("/bcst");
+            }
+        }
+        if data.flags.contains(Flags::ROUNDING) {
+            buf.push_str(", {er}");
+        } else if data.flags.contains(Flags::SAE) {
+            buf.push_str(", {sae}");
         }
         if data.flags.contains(Flags::X86_ONLY) {
             for _ in buf.len() .. 45 {
@@ -74,6 +95,7 @@ fn format_arg(ty: u8, mut size: u8, opsize: u8) -> Cow<'static, str> {
             b'p' => "80",
             b'o' => "128",
             b'h' => "256",
+            b'z' => "512",
             _ => ""
         }
     }
@@ -87,14 +109,16 @@ fn format_arg(ty: u8, mut size: u8, opsize: u8) -> Cow<'static, str> {
         b'r' => format!("reg{}",      format_size(size)).into(),
         b'f' => "st".into(),
         b'x' => "mm".into(),
-        b'y' => (if size == b'h' {"ymm"} else {"xmm"}).into(),
+        b'y' => (if size == b'z' {"zmm"} else if size == b'h' {"ymm"} else {"xmm"}).into(),
         b's' => "segreg".into(),
         b'c' => "creg".into(),
         b'd' => "dreg".into(),
         b'b' => "bndreg".into(),
+        b'j' => "kreg".into(),
+        b't' => "tmm".into(),
         b'v' => format!("reg/mem{}", format_size(size)).into(),
         b'u' => format!("mm/mem{}", format_size(size)).into(),
-        b'w' => format!("{}mm/mem{}", if size == b'h' {"y"} else {"x"}, format_size(size)).into(),
+        b'w' => format!("{}mm/mem{}", if size == b'z' {"z"} else if size == b'h' {"y"} else {"x"}, format_size(size)).into(),
         b'A'..=b'P' => {
             let i = ty as usize - 'A' as usize;
             match size {
@@ -16,7 +16,19 @@ pub use debug::create_opmap;
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum X86Mode {
     Long,
-    Protected
+    Protected,
+    Real
+}
+
+impl X86Mode {
+    /// The default operand size of this mode, in bits
+    pub fn bits(self) -> u8 {
+        match self {
+            X86Mode::Long => 64,
+            X86Mode::Protected => 32,
+            X86Mode::Real => 16
+        }
+    }
 }
 
 struct Context<'a, 'b: 'a> {
@@ -32,7 +44,8 @@ pub struct Archx64 {
 
 impl Default for Archx64 {
     fn default() -> Archx64 {
-        Archx64 { features: x64data::Features::all() }
+        // APX changes how dynamic registers are encoded, so it has to be enabled explicitly
+        Archx64 { features: x64data::Features::all() - x64data::Features::APX }
     }
 }
 
@@ -56,14 +69,14 @@ impl Arch for Archx64 {
     }
 
     fn handle_static_reloc(&self, stmts: &mut Vec<Stmt>, reloc: Jump, size: Size) {
-        let data = [0, size.in_bytes()]; // no offset, specified size, relative implicit
+        let data = [0, size.in_bytes(), 0, 0]; // no offset, specified size, relative implicit, not part of an instruction
 
         stmts.push(Stmt::Const(0, size));
         stmts.push(reloc.encode(&data));
     }
 
-    fn default_align(&self) -> u8 {
-        0x90
+    fn default_align(&self) -> &'static [u8] {
+        &[0x90]
     }
 
     fn compile_instruction(&self, state: &mut State, input: parse::ParseStream) -> parse::Result<()> {
@@ -119,8 +132,8 @@ impl Arch for Archx86 {
         stmts.push(reloc.encode(&data));
     }
 
-    fn default_align(&self) -> u8 {
-        0x90
+    fn default_align(&self) -> &'static [u8] {
+        &[0x90]
     }
 
     fn compile_instruction(&self, state: &mut State, input: parse::ParseStream) -> parse::Result<()> {
@@ -138,3 +151,60 @@ impl Arch for Archx86 {
         Ok(())
     }
 }
+
+#[derive(Clone, Debug)]
+pub struct Archx86_16 {
+    features: x64data::Features
+}
+
+impl Default for Archx86_16 {
+    fn default() -> Archx86_16 {
+        Archx86_16 { features: x64data::Features::all() }
+    }
+}
+
+impl Arch for Archx86_16 {
+    fn name(&self) -> &str {
+        "x86_16"
+    }
+
+    fn set_features(&mut self, features: &[syn::Ident]) {
+        let mut new_features = x64data::Features::empty();
+        for ident in features {
+            new_features |= match x64data::Features::from_str(&ident.to_string()) {
+                Some(feature) => feature,
+                None => {
+                    emit_error_at(ident.span(), format!("Architecture x86_16 does not support feature '{}'", ident.to_string()));
+                    continue;
+                }
+            }
+        }
+        self.features = new_features;
+    }
+
+    fn handle_static_reloc(&self, stmts: &mut Vec<Stmt>, reloc: Jump, size: Size) {
+        let data = [0, size.in_bytes(), 0]; // no offset, specified size, relative
+
+        stmts.push(Stmt::Const(0, size));
+        stmts.push(reloc.encode(&data));
+    }
+
+    fn default_align(&self) -> &'static [u8] {
+        &[0x90]
+    }
+
+    fn compile_instruction(&self, state: &mut State, input: parse::ParseStream) -> parse::Result<()> {
+        let mut ctx = Context {
+            state,
+            mode: X86Mode::Real,
+            features: self.features
+        };
+        let (instruction, args) = parser::parse_instruction(&mut ctx, input)?;
+        let span = instruction.span;
+
+        if let Err(Some(e)) = compiler::compile_instruction(&mut ctx, instruction, args) {
+            emit_error_at(span, e);
+        }
+        Ok(())
+    }
+}
@@ -1,14 +1,15 @@
 use syn::{parse, Token};
+use syn::parse::discouraged::Speculative;
 use syn::spanned::Spanned;
 use proc_macro2::Span;
 
 use lazy_static::lazy_static;
 
 use crate::common::{Size, emit_error_at};
-use crate::parse_helpers::{eat_pseudo_keyword, parse_ident_or_rust_keyword, as_ident, ParseOptExt};
+use crate::parse_helpers::{eat_pseudo_keyword, parse_ident_or_rust_keyword, parse_dynamic_cond, as_ident, ParseOptExt};
 
 use super::{Context, X86Mode};
-use super::ast::{Instruction, RawArg, Register, RegId, RegFamily, MemoryRefItem};
+use super::ast::{Instruction, RawArg, Register, RegId, RegFamily, MemoryRefItem, Decorations, Rounding};
 
 use std::collections::HashMap;
 
@@ -17,7 +18,7 @@ use std::collections::HashMap;
  */
 
 // parses a full instruction
-// syntax for a single op: PREFIX* ident (SIZE? expr ("," SIZE? expr)*)? ";"
+// syntax for a single op: PREFIX* ident (SIZE? expr DECORATION* ("," (SIZE? expr DECORATION* | ROUNDING))*)? ";"
 pub(super) fn parse_instruction(ctx: &mut Context, input: parse::ParseStream) -> parse::Result<(Instruction, Vec<RawArg>)> {
     let span = input.cursor().span();
 
@@ -33,14 +34,25 @@ pub(super) fn parse_instruction(ctx: &mut Context, input: parse::ParseStream) ->
 
     // parse (sizehint? expr),*
     let mut args = Vec::new();
+    let mut decorations = Decorations::default();
 
     if !(input.is_empty() || input.peek(Token![;])) {
-        args.push(parse_arg(ctx, input)?);
+        args.push(parse_arg(ctx, input, &mut decorations, true)?);
 
         while input.peek(Token![,]) {
             let _: Token![,] = input.parse()?;
 
-            args.push(parse_arg(ctx, input)?);
+            // embedded rounding specifiers are parsed as separate operands, but they're not encoded as such
+            let span = input.cursor().span();
+            if let Some(rounding) = eat_rounding(input)? {
+                if decorations.rounding.is_some() {
+                    emit_error_at(span, "Duplicate rounding specifier".into());
+                }
+                decorations.rounding = Some(rounding);
+                continue;
+            }
+
+            args.push(parse_arg(ctx, input, &mut decorations, false)?);
         }
     }
 
@@ -49,7 +61,8 @@ pub(super) fn parse_instruction(ctx: &mut Context, input: parse::ParseStream) ->
     Ok((
         Instruction {
             idents: ops,
-            span
+            span,
+            decorations
         },
         args
     ))
@@ -69,7 +82,7 @@ fn is_prefix(ident: &syn::Ident) -> bool {
 
 /// if a size hint is present in the parse stream, returning the indicated size
 fn eat_size_hint(ctx: &Context, input: parse::ParseStream) -> Option<Size> {
-    const X86_SIZES: [(&str, Size); 9] = [
+    const X86_SIZES: [(&str, Size); 10] = [
         ("BYTE", Size::BYTE),
         ("WORD", Size::WORD),
         ("DWORD", Size::DWORD),
@@ -78,9 +91,22 @@ fn eat_size_hint(ctx: &Context, input: parse::ParseStream) -> Option<Size> {
         ("QWORD", Size::QWORD),
         ("TWORD", Size::PWORD),
         ("OWORD", Size::OWORD),
-        ("YWORD", Size::HWORD)
+        ("YWORD", Size::HWORD),
+        ("ZWORD", Size::ZWORD)
+    ];
+    const X86_16_SIZES: [(&str, Size); 10] = [
+        ("BYTE", Size::BYTE),
+        ("WORD", Size::WORD),
+        ("AWORD", Size::WORD),
+        ("DWORD", Size::DWORD),
+        ("FWORD", Size::FWORD),
+        ("QWORD", Size::QWORD),
+        ("TWORD", Size::PWORD),
+        ("OWORD", Size::OWORD),
+        ("YWORD", Size::HWORD),
+        ("ZWORD", Size::ZWORD)
     ];
-    const X64_SIZES: [(&str, Size); 9] = [
+    const X64_SIZES: [(&str, Size); 10] = [
         ("BYTE", Size::BYTE),
         ("WORD", Size::WORD),
         ("DWORD", Size::DWORD),
@@ -89,10 +115,12 @@ fn eat_size_hint(ctx: &Context, input: parse::ParseStream) -> Option<Size> {
         ("QWORD", Size::QWORD),
         ("TWORD", Size::PWORD),
         ("OWORD", Size::OWORD),
-        ("YWORD", Size::HWORD)
+        ("YWORD", Size::HWORD),
+        ("ZWORD", Size::ZWORD)
     ];
 
     let sizes = match ctx.mode {
+        X86Mode::Real      => &X86_16_SIZES,
         X86Mode::Protected => &X86_SIZES,
         X86Mode::Long      => &X64_SIZES
     };
@@ -104,8 +132,116 @@ fn eat_size_hint(ctx: &Context, input: parse::ParseStream) -> Option<Size> {
     None
 }
 
+/// if an embedded rounding / sae specifier ({rn-sae}, {rd-sae}, {ru-sae}, {rz-sae} or {sae}) is present
+/// in the parse stream, returns it.
+fn eat_rounding(input: parse::ParseStream) -> parse::Result<Option<Rounding>> {
+    if !input.peek(syn::token::Brace) {
+        return Ok(None);
+    }
+
+    let fork = input.fork();
+    let inner;
+    let _ = syn::braced!(inner in fork);
+    let inner = &inner;
+
+    let rounding = if eat_pseudo_keyword(inner, "sae") {
+        Rounding::SAE
+    } else {
+        let mode = if eat_pseudo_keyword(inner, "rn") {
+            Rounding::RN
+        } else if eat_pseudo_keyword(inner, "rd") {
+            Rounding::RD
+        } else if eat_pseudo_keyword(inner, "ru") {
+            Rounding::RU
+        } else if eat_pseudo_keyword(inner, "rz") {
+            Rounding::RZ
+        } else {
+            return Ok(None);
+        };
+
+        if !inner.peek(Token![-]) {
+            return Ok(None);
+        }
+        let _: Token![-] = inner.parse()?;
+
+        if !eat_pseudo_keyword(inner, "sae") {
+            return Ok(None);
+        }
+        mode
+    };
+
+    if !inner.is_empty() {
+        return Ok(None);
+    }
+
+    input.advance_to(&fork);
+    Ok(Some(rounding))
+}
+
+/// parses any AVX-512 decorations ({k}, {z} or {1toN}) following an operand
+fn parse_decorations(ctx: &Context, input: parse::ParseStream, decorations: &mut Decorations, first: bool, memory: bool) -> parse::Result<()> {
+    while input.peek(syn::token::Brace) {
+        let span = input.cursor().span();
+        let inner;
+        let _ = syn::braced!(inner in input);
+        let inner = &inner;
+
+        // broadcast: {1toN}
+        if inner.peek(syn::LitInt) {
+            let lit: syn::LitInt = inner.parse()?;
+            let count = if lit.base10_digits() == "1" && lit.suffix().starts_with("to") {
+                lit.suffix()[2..].parse::<u8>().ok()
+            } else {
+                None
+            };
+
+            if let Some(count) = count {
+                if !memory {
+                    emit_error_at(span, "Broadcasts can only be applied to memory references".into());
+                } else if decorations.broadcast.is_some() {
+                    emit_error_at(span, "Duplicate broadcast".into());
+                } else {
+                    decorations.broadcast = Some(count);
+                }
+            } else {
+                emit_error_at(span, "Invalid broadcast, expected {1toN}".into());
+            }
+            continue;
+        }
+
+        // zeroing-masking: {z}
+        if eat_pseudo_keyword(inner, "z") {
+            if !first {
+                emit_error_at(span, "Zeroing can only be applied to the destination operand".into());
+            } else if memory {
+                emit_error_at(span, "Zeroing cannot be applied to memory references".into());
+            } else {
+                decorations.zeroing = true;
+            }
+            continue;
+        }
+
+        // opmask: {k}
+        let expr: syn::Expr = inner.parse()?;
+        match parse_reg(ctx, &expr) {
+            Some((span, reg)) if reg.kind.family() == RegFamily::OPMASK => if !first {
+                emit_error_at(span, "An opmask can only be applied to the destination operand".into());
+            } else if decorations.mask.is_some() {
+                emit_error_at(span, "Duplicate opmask".into());
+            } else if reg.kind == RegId::K0 {
+                emit_error_at(span, "k0 cannot be used as an opmask".into());
+            } else {
+                decorations.mask = Some(reg);
+            },
+            _ => emit_error_at(expr.span(), "Unknown decoration, expected an opmask, {z} or {1toN}".into())
+        }
+    }
+
+    Ok(())
+}
+
 /// tries to parse a full arg definition
-fn parse_arg(ctx: &mut Context, input: parse::ParseStream) -> parse::Result<RawArg> {
+fn parse_arg(ctx: &mut Context, input: parse::ParseStream, decorations: &mut Decorations, first: bool) -> parse::Result<RawArg> {
     // sizehint
     let size = eat_size_hint(ctx, input);
 
@@ -142,6 +278,8 @@ fn parse_arg(ctx: &mut Context, input: parse::ParseStream) -> parse::Result<RawA
         // split the expression into the different (displacement, register, scaled register) components
         let items = parse_adds(ctx, expr);
 
+        parse_decorations(ctx, input, decorations, first, true)?;
+
         return Ok(RawArg::IndirectRaw {
             span,
             nosplit,
@@ -152,6 +290,37 @@ fn parse_arg(ctx: &mut Context, input: parse::ParseStream) -> parse::Result<RawA
 
     }
 
+    // dynamic condition code
+    let span = input.cursor().span();
+    if let Some(value) = parse_dynamic_cond(input)? {
+        if size.is_some() {
+            emit_error_at(span, "size hint with condition code".into());
+        }
+        return Ok(RawArg::Cond {
+            span,
+            value
+        })
+    }
+
+    // decorated register. These have to be checked for first as the decorations would otherwise be
+    // parsed as struct literal syntax.
+    let fork = input.fork();
+    if let Ok(arg) = fork.call(syn::Expr::parse_without_eager_brace) {
+        if fork.peek(syn::token::Brace) {
+            if let Some((span, reg)) = parse_reg(ctx, &arg) {
+                input.advance_to(&fork);
+                if size.is_some() {
+                    emit_error_at(span, "size hint with direct register".into());
+                }
+                parse_decorations(ctx, input, decorations, first, false)?;
+                return Ok(RawArg::Direct {
+                    reg,
+                    span
+                })
+            }
+        }
+    }
+
     // it's a normal (register/immediate/typemapped) operand
     let arg: syn::Expr = input.parse()?;
 
@@ -236,7 +405,8 @@ fn parse_reg(ctx: &Context, expr: &syn::Expr) -> Option<(Span, Register)> {
 
         let (reg, size) = match ctx.mode {
             X86Mode::Long      => X64_REGISTERS.get(&name).cloned(),
-            X86Mode::Protected => X86_REGISTERS.get(&name).cloned()
+            X86Mode::Protected |
+            X86Mode::Real      => X86_REGISTERS.get(&name).cloned()
         }?;
 
         Some((
@@ -260,7 +430,8 @@ fn parse_reg(ctx: &Context, expr: &syn::Expr) -> Option<(Span, Register)> {
         let name = name.as_str();
         let (size, family) = match ctx.mode {
             X86Mode::Long      => X64_FAMILIES.get(&name).cloned(),
-            X86Mode::Protected => X86_FAMILIES.get(&name).cloned()
+            X86Mode::Protected |
+            X86Mode::Real      => X86_FAMILIES.get(&name).cloned()
         }?;
 
         Some((
@@ -359,6 +530,22 @@ lazy_static!{
             ("r13", (R13, QWORD)),
             ("r14", (R14, QWORD)),
             ("r15", (R15, QWORD)),
+            ("r16", (R16, QWORD)),
+            ("r17", (R17, QWORD)),
+            ("r18", (R18, QWORD)),
+            ("r19", (R19, QWORD)),
+            ("r20", (R20, QWORD)),
+            ("r21", (R21, QWORD)),
+            ("r22", (R22, QWORD)),
+            ("r23", (R23, QWORD)),
+            ("r24", (R24, QWORD)),
+            ("r25", (R25, QWORD)),
+            ("r26", (R26, QWORD)),
+            ("r27", (R27, QWORD)),
+            ("r28", (R28, QWORD)),
+            ("r29", (R29, QWORD)),
+            ("r30", (R30, QWORD)),
+            ("r31", (R31, QWORD)),
 
             ("eax" , (RAX, DWORD)),
             ("r0d" , (RAX, DWORD)),
@@ -384,6 +571,22 @@ lazy_static!{
             ("r13d", (R13, DWORD)),
             ("r14d", (R14, DWORD)),
             ("r15d", (R15, DWORD)),
+            ("r16d", (R16, DWORD)),
+            ("r17d", (R17, DWORD)),
+            ("r18d", (R18, DWORD)),
+            ("r19d", (R19, DWORD)),
+            ("r20d", (R20, DWORD)),
+            ("r21d", (R21, DWORD)),
+            ("r22d", (R22, DWORD)),
+            ("r23d", (R23, DWORD)),
+            ("r24d", (R24, DWORD)),
+            ("r25d", (R25, DWORD)),
+            ("r26d", (R26, DWORD)),
+            ("r27d", (R27, DWORD)),
+            ("r28d", (R28, DWORD)),
+            ("r29d", (R29, DWORD)),
+            ("r30d", (R30, DWORD)),
+            ("r31d", (R31, DWORD)),
 
             ("ax"  , (RAX, WORD)),
             ("r0w" , (RAX, WORD)),
@@ -409,6 +612,22 @@ lazy_static!{
             ("r13w", (R13, WORD)),
             ("r14w", (R14, WORD)),
             ("r15w", (R15, WORD)),
+            ("r16w", (R16, WORD)),
+            ("r17w", (R17, WORD)),
+            ("r18w", (R18, WORD)),
+            ("r19w", (R19, WORD)),
+            ("r20w", (R20, WORD)),
+            ("r21w", (R21, WORD)),
+            ("r22w", (R22, WORD)),
+            ("r23w", (R23, WORD)),
+            ("r24w", (R24, WORD)),
+            ("r25w", (R25, WORD)),
+            ("r26w", (R26, WORD)),
+            ("r27w", (R27, WORD)),
+            ("r28w", (R28, WORD)),
+            ("r29w", (R29, WORD)),
+            ("r30w", (R30, WORD)),
+            ("r31w", (R31, WORD)),
 
             ("al"  , (RAX, BYTE)),
             ("r0b" , (RAX, BYTE)),
@@ -434,6 +653,22 @@ lazy_static!{
             ("r13b", (R13, BYTE)),
             ("r14b", (R14, BYTE)),
             ("r15b", (R15, BYTE)),
+            ("r16b", (R16, BYTE)),
+            ("r17b", (R17, BYTE)),
+            ("r18b", (R18, BYTE)),
+            ("r19b", (R19, BYTE)),
+            ("r20b", (R20, BYTE)),
+            ("r21b", (R21, BYTE)),
+            ("r22b", (R22, BYTE)),
+            ("r23b", (R23, BYTE)),
+            ("r24b", (R24, BYTE)),
+            ("r25b", (R25, BYTE)),
+            ("r26b", (R26, BYTE)),
+            ("r27b", (R27, BYTE)),
+            ("r28b", (R28, BYTE)),
+            ("r29b", (R29, BYTE)),
+            ("r30b", (R30, BYTE)),
+            ("r31b", (R31, BYTE)),
 
             ("rip", (RIP, QWORD)),
             ("eip", (RIP, DWORD)),
@@ -477,6 +712,22 @@ lazy_static!{
             ("xmm13", (XMM13, OWORD)),
             ("xmm14", (XMM14, OWORD)),
             ("xmm15", (XMM15, OWORD)),
+            ("xmm16", (XMM16, OWORD)),
+            ("xmm17", (XMM17, OWORD)),
+            ("xmm18", (XMM18, OWORD)),
+            ("xmm19", (XMM19, OWORD)),
+            ("xmm20", (XMM20, OWORD)),
+            ("xmm21", (XMM21, OWORD)),
+            ("xmm22", (XMM22, OWORD)),
+            ("xmm23", (XMM23, OWORD)),
+            ("xmm24", (XMM24, OWORD)),
+            ("xmm25", (XMM25, OWORD)),
+            ("xmm26", (XMM26, OWORD)),
+            ("xmm27", (XMM27, OWORD)),
+            ("xmm28", (XMM28, OWORD)),
+            ("xmm29", (XMM29, OWORD)),
+            ("xmm30", (XMM30, OWORD)),
+            ("xmm31", (XMM31, OWORD)),
 
             ("ymm0" , (XMM0 , HWORD)),
             ("ymm1" , (XMM1 , HWORD)),
@@ -494,6 +745,55 @@ lazy_static!{
             ("ymm13", (XMM13, HWORD)),
             ("ymm14", (XMM14, HWORD)),
             ("ymm15", (XMM15, HWORD)),
+            ("ymm16", (XMM16, HWORD)),
+            ("ymm17", (XMM17, HWORD)),
+            ("ymm18", (XMM18, HWORD)),
+            ("ymm19", (XMM19, HWORD)),
+            ("ymm20", (XMM20, HWORD)),
+            ("ymm21", (XMM21, HWORD)),
+            ("ymm22", (XMM22, HWORD)),
+            ("ymm23", (XMM23, HWORD)),
+            ("ymm24", (XMM24, HWORD)),
+            ("ymm25", (XMM25, HWORD)),
+            ("ymm26", (XMM26, HWORD)),
+            ("ymm27", (XMM27, HWORD)),
+            ("ymm28", (XMM28, HWORD)),
+            ("ymm29", (XMM29, HWORD)),
+            ("ymm30", (XMM30, HWORD)),
+            ("ymm31", (XMM31, HWORD)),
+
+            ("zmm0" , (XMM0 , ZWORD)),
+            ("zmm1" , (XMM1 , ZWORD)),
+            ("zmm2" , (XMM2 , ZWORD)),
+            ("zmm3" , (XMM3 , ZWORD)),
+            ("zmm4" , (XMM4 , ZWORD)),
+            ("zmm5" , (XMM5 , ZWORD)),
+            ("zmm6" , (XMM6 , ZWORD)),
+            ("zmm7" , (XMM7 , ZWORD)),
+            ("zmm8" , (XMM8 , ZWORD)),
+            ("zmm9" , (XMM9 , ZWORD)),
+            ("zmm10", (XMM10, ZWORD)),
+            ("zmm11", (XMM11, ZWORD)),
+            ("zmm12", (XMM12, ZWORD)),
+            ("zmm13", (XMM13, ZWORD)),
+            ("zmm14", (XMM14, ZWORD)),
+            ("zmm15", (XMM15, ZWORD)),
+            ("zmm16", (XMM16, ZWORD)),
+            ("zmm17", (XMM17, ZWORD)),
+            ("zmm18", (XMM18, ZWORD)),
+            ("zmm19", (XMM19, ZWORD)),
+            ("zmm20", (XMM20, ZWORD)),
+            ("zmm21", (XMM21, ZWORD)),
+            ("zmm22", (XMM22, ZWORD)),
+            ("zmm23", (XMM23, ZWORD)),
+            ("zmm24", (XMM24, ZWORD)),
+            ("zmm25", (XMM25, ZWORD)),
+            ("zmm26", (XMM26, ZWORD)),
+            ("zmm27", (XMM27, ZWORD)),
+            ("zmm28", (XMM28, ZWORD)),
+            ("zmm29", (XMM29, ZWORD)),
+            ("zmm30", (XMM30, ZWORD)),
+            ("zmm31", (XMM31, ZWORD)),
 
             ("es", (ES, WORD)),
             ("cs", (CS, WORD)),
@@ -540,6 +840,24 @@ lazy_static!{
             ("bnd1", (BND1, OWORD)),
             ("bnd2", (BND2, OWORD)),
             ("bnd3", (BND3, OWORD)),
+
+            ("k0", (K0, QWORD)),
+            ("k1", (K1, QWORD)),
+            ("k2", (K2, QWORD)),
+            ("k3", (K3, QWORD)),
+            ("k4", (K4, QWORD)),
+            ("k5", (K5, QWORD)),
+            ("k6", (K6, QWORD)),
+            ("k7", (K7, QWORD)),
+
+            ("tmm0", (TMM0, ZWORD)),
+            ("tmm1", (TMM1, ZWORD)),
+            ("tmm2", (TMM2, ZWORD)),
+            ("tmm3", (TMM3, ZWORD)),
+            ("tmm4", (TMM4, ZWORD)),
+            ("tmm5", (TMM5, ZWORD)),
+            ("tmm6", (TMM6, ZWORD)),
+            ("tmm7", (TMM7, ZWORD)),
         ];
         MAP.iter().cloned().collect()
     };
@@ -614,6 +932,15 @@ lazy_static!{
             ("ymm6", (XMM6, HWORD)),
             ("ymm7", (XMM7, HWORD)),
 
+            ("zmm0", (XMM0, ZWORD)),
+            ("zmm1", (XMM1, ZWORD)),
+            ("zmm2", (XMM2, ZWORD)),
+            ("zmm3", (XMM3, ZWORD)),
+            ("zmm4", (XMM4, ZWORD)),
+            ("zmm5", (XMM5, ZWORD)),
+            ("zmm6", (XMM6, ZWORD)),
+            ("zmm7", (XMM7, ZWORD)),
+
             ("es", (ES, WORD)),
             ("cs", (CS, WORD)),
             ("ss", (SS, WORD)),
@@ -643,6 +970,15 @@ lazy_static!{
             ("bnd1", (BND1, OWORD)),
             ("bnd2", (BND2, OWORD)),
             ("bnd3", (BND3, OWORD)),
+
+            ("k0", (K0, QWORD)),
+            ("k1", (K1, QWORD)),
+            ("k2", (K2, QWORD)),
+            ("k3", (K3, QWORD)),
+            ("k4", (K4, QWORD)),
+            ("k5", (K5, QWORD)),
+            ("k6", (K6, QWORD)),
+            ("k7", (K7, QWORD)),
         ];
         MAP.iter().cloned().collect()
     };
@@ -658,6 +994,9 @@ lazy_static!{
             ("Rm", (Size::QWORD, RegFamily::MMX)),
             ("Rx", (Size::OWORD, RegFamily::XMM)),
             ("Ry", (Size::HWORD, RegFamily::XMM)),
+            ("Rz", (Size::ZWORD, RegFamily::XMM)),
+            ("Rk", (Size::QWORD, RegFamily::OPMASK)),
+            ("Rt", (Size::ZWORD, RegFamily::TILE)),
             ("Rs", (Size::WORD,  RegFamily::SEGMENT)),
             ("RC", (Size::QWORD, RegFamily::CONTROL)),
             ("RD", (Size::QWORD, RegFamily::DEBUG)),
@@ -676,6 +1015,8 @@ lazy_static!{
             ("Rm",(Size::QWORD, RegFamily::MMX)),
             ("Rx",(Size::OWORD, RegFamily::XMM)),
             ("Ry",(Size::HWORD, RegFamily::XMM)),
+            ("Rz",(Size::ZWORD, RegFamily::XMM)),
+            ("Rk",(Size::QWORD, RegFamily::OPMASK)),
             ("Rs",(Size::WORD,  RegFamily::SEGMENT)),
             ("RC",(Size::DWORD, RegFamily::CONTROL)),
             ("RD",(Size::DWORD, RegFamily::DEBUG)),
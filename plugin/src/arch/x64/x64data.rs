@@ -5,6 +5,7 @@ use std::fmt::{self, Display};
 
 use lazy_static::lazy_static;
 use bitflags::bitflags;
+use dynasm_data::x64::{flags, features};
 
 macro_rules! constify {
     ($t:ty, $e:expr) => { {const C: &$t = &$e; C} }
@@ -36,79 +37,119 @@ pub fn get_mnemnonic_data(name: &str) -> Option<&'static [Opdata]> {
 }
 
 bitflags! {
-    pub struct Flags: u32 {
-        const DEFAULT   = 0x0000_0000; // this instruction has default encoding
-        const VEX_OP    = 0x0000_0001; // this instruction requires a VEX prefix to be encoded
-        const XOP_OP    = 0x0000_0002; // this instruction requires a XOP prefix to be encoded
-        const IMM_OP    = 0x0000_0004; // this instruction encodes the final opcode byte in the immediate position, like 3DNow! ops.
-
-        // note: the first 4 in this block are mutually exclusive
-        const AUTO_SIZE = 0x0000_0008; // 16 bit -> OPSIZE , 32-bit -> None     , 64-bit -> REX.W/VEX.W/XOP.W
-        const AUTO_NO32 = 0x0000_0010; // 16 bit -> OPSIZE , 32-bit -> None(x86), 64-bit -> None(x64)
-        const AUTO_REXW = 0x0000_0020; // 16 bit -> illegal, 32-bit -> None     , 64-bit -> REX.W/VEX.W/XOP.W
-        const AUTO_VEXL = 0x0000_0040; // 128bit -> None   , 256bit -> VEX.L
-        const WORD_SIZE = 0x0000_0080; // implies opsize prefix
-        const WITH_REXW = 0x0000_0100; // implies REX.W/VEX.W/XOP.W
-        const WITH_VEXL = 0x0000_0200; // implies VEX.L/XOP.L
-        const EXACT_SIZE= 0x0000_0400; // operands with unknown sizes cannot be assumed to match
-
-        const PREF_66   = WORD_SIZE;   // mandatory prefix (same as WORD_SIZE)
-        const PREF_67   = 0x0000_0800; // mandatory prefix (same as SMALL_ADDRESS)
-        const PREF_F0   = 0x0000_1000; // mandatory prefix (same as LOCK)
-        const PREF_F2   = 0x0000_2000; // mandatory prefix (REPNE)
-        const PREF_F3   = 0x0000_4000; // mandatory prefix (REP)
-
-        const LOCK      = 0x0000_8000; // user lock prefix is valid with this instruction
-        const REP       = 0x0001_0000; // user rep prefix is valid with this instruction
-        const REPE      = 0x0002_0000;
-
-        const SHORT_ARG = 0x0004_0000; // a register argument is encoded in the last byte of the opcode
-        const ENC_MR    = 0x0008_0000; // select alternate arg encoding
-        const ENC_VM    = 0x0010_0000; // select alternate arg encoding
-        const ENC_MIB   = 0x0020_0000; // A special encoding using the SIB to specify an immediate and two registers
-        const X86_ONLY  = 0x0040_0000; // instructions available in protected mode, but not long mode
+    pub struct Flags: u64 {
+        const DEFAULT     = flags::DEFAULT;
+        const VEX_OP      = flags::VEX_OP;
+        const XOP_OP      = flags::XOP_OP;
+        const IMM_OP      = flags::IMM_OP;
+        const AUTO_SIZE   = flags::AUTO_SIZE;
+        const AUTO_NO32   = flags::AUTO_NO32;
+        const AUTO_REXW   = flags::AUTO_REXW;
+        const AUTO_VEXL   = flags::AUTO_VEXL;
+        const WORD_SIZE   = flags::WORD_SIZE;
+        const WITH_REXW   = flags::WITH_REXW;
+        const WITH_VEXL   = flags::WITH_VEXL;
+        const EXACT_SIZE  = flags::EXACT_SIZE;
+        const PREF_66     = flags::PREF_66;
+        const PREF_67     = flags::PREF_67;
+        const PREF_F0     = flags::PREF_F0;
+        const PREF_F2     = flags::PREF_F2;
+        const PREF_F3     = flags::PREF_F3;
+        const LOCK        = flags::LOCK;
+        const REP         = flags::REP;
+        const REPE        = flags::REPE;
+        const SHORT_ARG   = flags::SHORT_ARG;
+        const ENC_MR      = flags::ENC_MR;
+        const ENC_VM      = flags::ENC_VM;
+        const ENC_MIB     = flags::ENC_MIB;
+        const X86_ONLY    = flags::X86_ONLY;
+        const EVEX_OP     = flags::EVEX_OP;
+        const OPMASK      = flags::OPMASK;
+        const ZEROING     = flags::ZEROING;
+        const BCST        = flags::BCST;
+        const ROUNDING    = flags::ROUNDING;
+        const SAE         = flags::SAE;
+        const WITH_EVEXL  = flags::WITH_EVEXL;
+        const NDD         = flags::NDD;
+        const SIBMEM      = flags::SIBMEM;
+        const DWORD_SIZE  = flags::DWORD_SIZE;
     }
 }
 
 impl Flags {
-    const fn make(bits: u32) -> Flags {
+    const fn make(bits: u64) -> Flags {
         Flags { bits }
     }
 }
 
 bitflags! {
-    pub struct Features: u32 {
-        const X64_IMPLICIT = 0x0000_0000;
-        const FPU          = 0x0000_0001;
-          const MMX          = 0x0000_0002;
-        const TDNOW        = 0x0000_0004;
-        const SSE          = 0x0000_0008;
-        const SSE2         = 0x0000_0010;
-        const SSE3         = 0x0000_0020;
-        const VMX          = 0x0000_0040;
-        const SSSE3        = 0x0000_0080;
-        const SSE4A        = 0x0000_0100;
-        const SSE41        = 0x0000_0200;
-        const SSE42        = 0x0000_0400;
-        const SSE5         = 0x0000_0800;
-        const AVX          = 0x0000_1000;
-        const AVX2         = 0x0000_2000;
-        const FMA          = 0x0000_4000;
-        const BMI1         = 0x0000_8000;
-        const BMI2         = 0x0001_0000;
-        const TBM          = 0x0002_0000;
-        const RTM          = 0x0004_0000;
-        const INVPCID      = 0x0008_0000;
-        const MPX          = 0x0010_0000;
-        const SHA          = 0x0020_0000;
-        const PREFETCHWT1  = 0x0040_0000;
-        const CYRIX        = 0x0080_0000;
-        const AMD          = 0x0100_0000;
+    pub struct Features: u64 {
+        const X64_IMPLICIT= features::X64_IMPLICIT;
+        const FPU         = features::FPU;
+        const MMX         = features::MMX;
+        const TDNOW       = features::TDNOW;
+        const SSE         = features::SSE;
+        const SSE2        = features::SSE2;
+        const SSE3        = features::SSE3;
+        const VMX         = features::VMX;
+        const SSSE3       = features::SSSE3;
+        const SSE4A       = features::SSE4A;
+        const SSE41       = features::SSE41;
+        const SSE42       = features::SSE42;
+        const SSE5        = features::SSE5;
+        const AVX         = features::AVX;
+        const AVX2        = features::AVX2;
+        const FMA         = features::FMA;
+        const BMI1        = features::BMI1;
+        const BMI2        = features::BMI2;
+        const TBM         = features::TBM;
+        const RTM         = features::RTM;
+        const INVPCID     = features::INVPCID;
+        const MPX         = features::MPX;
+        const SHA         = features::SHA;
+        const PREFETCHWT1 = features::PREFETCHWT1;
+        const CYRIX       = features::CYRIX;
+        const AMD         = features::AMD;
+        const AVX512F     = features::AVX512F;
+        const AVX512CD    = features::AVX512CD;
+        const AVX512ER    = features::AVX512ER;
+        const AVX512PF    = features::AVX512PF;
+        const AVX512BW    = features::AVX512BW;
+        const AVX512DQ    = features::AVX512DQ;
+        const AVX512VL    = features::AVX512VL;
+        const AVX512IFMA  = features::AVX512IFMA;
+        const AVX512VBMI  = features::AVX512VBMI;
+        const APX         = features::APX;
+        const AMX_TILE    = features::AMX_TILE;
+        const AMX_INT8    = features::AMX_INT8;
+        const AMX_BF16    = features::AMX_BF16;
+        const AES         = features::AES;
+        const PCLMULQDQ   = features::PCLMULQDQ;
+        const ADX         = features::ADX;
+        const POPCNT      = features::POPCNT;
+        const LZCNT       = features::LZCNT;
+        const RDRAND      = features::RDRAND;
+        const RDSEED      = features::RDSEED;
+        const MOVBE       = features::MOVBE;
+        const XSAVE       = features::XSAVE;
+        const XSAVEOPT    = features::XSAVEOPT;
+        const XSAVEC      = features::XSAVEC;
+        const XSAVES      = features::XSAVES;
+        const F16C        = features::F16C;
+        const CLFLUSHOPT  = features::CLFLUSHOPT;
+        const CLWB        = features::CLWB;
+        const GFNI        = features::GFNI;
+        const VAES        = features::VAES;
+        const VPCLMULQDQ  = features::VPCLMULQDQ;
+        const AVXVNNI     = features::AVXVNNI;
+        const SERIALIZE   = features::SERIALIZE;
+        const FSGSBASE    = features::FSGSBASE;
+        const PRFCHW      = features::PRFCHW;
     }
 }
 
 impl Features {
-    const fn make(bits: u32) -> Features {
+    const fn make(bits: u64) -> Features {
         Features { bits }
     }
 
@@ -139,6 +180,41 @@ impl Features {
             "prefetchwt1" => Some(Features::PREFETCHWT1),
             "cyrix" => Some(Features::CYRIX),
             "amd"   => Some(Features::AMD),
+            "avx512f"    => Some(Features::AVX512F),
+            "avx512cd"   => Some(Features::AVX512CD),
+            "avx512er"   => Some(Features::AVX512ER),
+            "avx512pf"   => Some(Features::AVX512PF),
+            "avx512bw"   => Some(Features::AVX512BW),
+            "avx512dq"   => Some(Features::AVX512DQ),
+            "avx512vl"   => Some(Features::AVX512VL),
+            "avx512ifma" => Some(Features::AVX512IFMA),
+            "avx512vbmi" => Some(Features::AVX512VBMI),
+            "apx"        => Some(Features::APX),
+            "amxtile"    => Some(Features::AMX_TILE),
+            "amxint8"    => Some(Features::AMX_INT8),
+            "amxbf16"    => Some(Features::AMX_BF16),
+            "aes"        => Some(Features::AES),
+            "pclmulqdq"  => Some(Features::PCLMULQDQ),
+            "adx"        => Some(Features::ADX),
+            "popcnt"     => Some(Features::POPCNT),
+            "lzcnt"      => Some(Features::LZCNT),
+            "rdrand"     => Some(Features::RDRAND),
+            "rdseed"     => Some(Features::RDSEED),
+            "movbe"      => Some(Features::MOVBE),
+            "xsave"      => Some(Features::XSAVE),
+            "xsaveopt"   => Some(Features::XSAVEOPT),
+            "xsavec"     => Some(Features::XSAVEC),
+            "xsaves"     => Some(Features::XSAVES),
+            "f16c"       => Some(Features::F16C),
+            "clflushopt" => Some(Features::CLFLUSHOPT),
+            "clwb"       => Some(Features::CLWB),
+            "gfni"       => Some(Features::GFNI),
+            "vaes"       => Some(Features::VAES),
+            "vpclmulqdq" => Some(Features::VPCLMULQDQ),
+            "avxvnni"    => Some(Features::AVXVNNI),
+            "serialize"  => Some(Features::SERIALIZE),
+            "fsgsbase"   => Some(Features::FSGSBASE),
+            "prfchw"     => Some(Features::PRFCHW),
             _ => None
         }
     }
@@ -172,6 +248,41 @@ impl Display for Features {
         if self.contains(Features::PREFETCHWT1) { keys.push("prefetchwt1"); }
         if self.contains(Features::CYRIX) { keys.push("cyrix"); }
         if self.contains(Features::AMD)   { keys.push("amd"); }
+        if self.contains(Features::AVX512F)    { keys.push("avx512f"); }
+        if self.contains(Features::AVX512CD)   { keys.push("avx512cd"); }
+        if self.contains(Features::AVX512ER)   { keys.push("avx512er"); }
+        if self.contains(Features::AVX512PF)   { keys.push("avx512pf"); }
+        if self.contains(Features::AVX512BW)   { keys.push("avx512bw"); }
+        if self.contains(Features::AVX512DQ)   { keys.push("avx512dq"); }
+        if self.contains(Features::AVX512VL)   { keys.push("avx512vl"); }
+        if self.contains(Features::AVX512IFMA) { keys.push("avx512ifma"); }
+        if self.contains(Features::AVX512VBMI) { keys.push("avx512vbmi"); }
+        if self.contains(Features::APX)        { keys.push("apx"); }
+        if self.contains(Features::AMX_TILE)   { keys.push("amxtile"); }
+        if self.contains(Features::AMX_INT8)   { keys.push("amxint8"); }
+        if self.contains(Features::AMX_BF16)   { keys.push("amxbf16"); }
+        if self.contains(Features::AES)        { keys.push("aes"); }
+        if self.contains(Features::PCLMULQDQ)  { keys.push("pclmulqdq"); }
+        if self.contains(Features::ADX)        { keys.push("adx"); }
+        if self.contains(Features::POPCNT)     { keys.push("popcnt"); }
+        if self.contains(Features::LZCNT)      { keys.push("lzcnt"); }
+        if self.contains(Features::RDRAND)     { keys.push("rdrand"); }
+        if self.contains(Features::RDSEED)     { keys.push("rdseed"); }
+        if self.contains(Features::MOVBE)      { keys.push("movbe"); }
+        if self.contains(Features::XSAVE)      { keys.push("xsave"); }
+        if self.contains(Features::XSAVEOPT)   { keys.push("xsaveopt"); }
+        if self.contains(Features::XSAVEC)     { keys.push("xsavec"); }
+        if self.contains(Features::XSAVES)     { keys.push("xsaves"); }
+        if self.contains(Features::F16C)       { keys.push("f16c"); }
+        if self.contains(Features::CLFLUSHOPT) { keys.push("clflushopt"); }
+        if self.contains(Features::CLWB)       { keys.push("clwb"); }
+        if self.contains(Features::GFNI)       { keys.push("gfni"); }
+        if self.contains(Features::VAES)       { keys.push("vaes"); }
+        if self.contains(Features::VPCLMULQDQ) { keys.push("vpclmulqdq"); }
+        if self.contains(Features::AVXVNNI)    { keys.push("avxvnni"); }
+        if self.contains(Features::SERIALIZE)  { keys.push("serialize"); }
+        if self.contains(Features::FSGSBASE)   { keys.push("fsgsbase"); }
+        if self.contains(Features::PRFCHW)     { keys.push("prfchw"); }
         for (i, k) in keys.into_iter().enumerate() {
             if i != 0 {
                 f.write_str(", ")?;
@@ -187,66 +298,13 @@ pub fn mnemnonics() -> hash_map::Keys<'static, &'static str, &'static [Opdata]>
     OPMAP.keys()
 }
 
-// workaround until bitflags can be used in const
-const DEFAULT    : u32 = Flags::DEFAULT.bits;
-const VEX_OP     : u32 = Flags::VEX_OP.bits;
-const XOP_OP     : u32 = Flags::XOP_OP.bits;
-const IMM_OP     : u32 = Flags::IMM_OP.bits;
-const SHORT_ARG  : u32 = Flags::SHORT_ARG.bits;
-const AUTO_SIZE  : u32 = Flags::AUTO_SIZE.bits;
-const AUTO_NO32  : u32 = Flags::AUTO_NO32.bits;
-const AUTO_REXW  : u32 = Flags::AUTO_REXW.bits;
-const AUTO_VEXL  : u32 = Flags::AUTO_VEXL.bits;
-const WORD_SIZE  : u32 = Flags::WORD_SIZE.bits;
-const WITH_REXW  : u32 = Flags::WITH_REXW.bits;
-const WITH_VEXL  : u32 = Flags::WITH_VEXL.bits;
-const EXACT_SIZE : u32 = Flags::EXACT_SIZE.bits;
-const PREF_66    : u32 = Flags::PREF_66.bits;
-const PREF_67    : u32 = Flags::PREF_67.bits;
-const PREF_F0    : u32 = Flags::PREF_F0.bits;
-const PREF_F2    : u32 = Flags::PREF_F2.bits;
-const PREF_F3    : u32 = Flags::PREF_F3.bits;
-const LOCK       : u32 = Flags::LOCK.bits;
-const REP        : u32 = Flags::REP.bits;
-const REPE       : u32 = Flags::REPE.bits;
-const ENC_MR     : u32 = Flags::ENC_MR.bits;
-const ENC_VM     : u32 = Flags::ENC_VM.bits;
-const ENC_MIB    : u32 = Flags::ENC_MIB.bits;
-const X86_ONLY   : u32 = Flags::X86_ONLY.bits;
-
-#[allow(dead_code)]
-const X64_IMPLICIT : u32 = Features::X64_IMPLICIT.bits;
-const FPU          : u32 = Features::FPU.bits;
-const MMX          : u32 = Features::MMX.bits;
-const TDNOW        : u32 = Features::TDNOW.bits;
-const SSE          : u32 = Features::SSE.bits;
-const SSE2         : u32 = Features::SSE2.bits;
-const SSE3         : u32 = Features::SSE3.bits;
-const VMX          : u32 = Features::VMX.bits;
-const SSSE3        : u32 = Features::SSSE3.bits;
-const SSE4A        : u32 = Features::SSE4A.bits;
-const SSE41        : u32 = Features::SSE41.bits;
-const SSE42        : u32 = Features::SSE42.bits;
-const SSE5         : u32 = Features::SSE5.bits;
-const AVX          : u32 = Features::AVX.bits;
-const AVX2         : u32 = Features::AVX2.bits;
-const FMA          : u32 = Features::FMA.bits;
-const BMI1         : u32 = Features::BMI1.bits;
-const BMI2         : u32 = Features::BMI2.bits;
-const TBM          : u32 = Features::TBM.bits;
-const RTM          : u32 = Features::RTM.bits;
-const INVPCID      : u32 = Features::INVPCID.bits;
-const MPX          : u32 = Features::MPX.bits;
-const SHA          : u32 = Features::SHA.bits;
-const PREFETCHWT1  : u32 = Features::PREFETCHWT1.bits;
-const CYRIX        : u32 = Features::CYRIX.bits;
-const AMD          : u32 = Features::AMD.bits;
-
-
 lazy_static! {
     static ref OPMAP: HashMap<&'static str, &'static [Opdata]> = {
+        use dynasm_data::x64::flags::*;
+        use dynasm_data::x64::features::*;
+
         const X: u8 = 0xFF;
-        static MAP: &[(&str, &[Opdata])] = &include!("gen_opmap.rs");
+        static MAP: &[(&str, &[Opdata])] = &dynasm_data::x64_opmap!(Ops);
         MAP.iter().cloned().collect()
     };
 }
@@ -21,6 +21,7 @@ pub enum Size {
     PWORD = 10,
     OWORD = 16,
     HWORD = 32,
+    ZWORD = 64,
 }
 
 impl Size {
@@ -37,7 +38,8 @@ impl Size {
             Size::QWORD => "i64",
             Size::PWORD => "i80",
             Size::OWORD => "i128",
-            Size::HWORD => "i256"
+            Size::HWORD => "i256",
+            Size::ZWORD => "i512"
         }, Span::call_site())
     }
 }
@@ -180,6 +182,8 @@ pub enum Stmt {
     ExprUnsigned(TokenTree, Size),
     // push signed data into the instruction stream. signed
     ExprSigned(TokenTree, Size),
+    // push signed data into the instruction stream in big-endian byte order. signed
+    ExprSignedBe(TokenTree, Size),
 
     // extend the instruction stream with unsigned bytes
     Extend(Vec<u8>),
@@ -187,6 +191,8 @@ pub enum Stmt {
     ExprExtend(TokenTree),
     // align the instruction stream to some alignment
     Align(TokenTree, TokenTree),
+    // align the instruction stream to some alignment, padding with copies of an instruction
+    AlignFiller(TokenTree, Vec<u8>),
 
     // label declarations
     GlobalLabel(syn::Ident),
@@ -201,7 +207,16 @@ pub enum Stmt {
     BareJumpTarget(   TokenTree, TokenTree),
 
     // a random statement that has to be inserted between assembly hunks
-    Stmt(TokenTree)
+    Stmt(TokenTree),
+
+    // record the source location of the instruction that follows
+    SourceLocation(Span),
+
+    // end the source location of the preceding instructions
+    EndSourceLocation,
+
+    // select between two encodings at runtime, depending on the condition
+    Select(TokenTree, Vec<Stmt>, Vec<Stmt>)
 }
 
 // convenience methods
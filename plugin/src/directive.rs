@@ -57,16 +57,16 @@ pub(crate) fn evaluate_directive(file_data: &mut DynasmData, stmts: &mut Vec<Stm
             // this might need to be architecture dependent
             let value: syn::Expr = input.parse()?;
 
-            let with = if input.peek(Token![,]) {
+            if input.peek(Token![,]) {
                 let _: Token![,] = input.parse()?;
                 let with: syn::Expr = input.parse()?;
-                delimited(with)
+                stmts.push(Stmt::Align(delimited(value), delimited(with)));
             } else {
-                let with = file_data.current_arch.default_align();
-                delimited(quote!(#with))
-            };
-
-            stmts.push(Stmt::Align(delimited(value), with));
+                match *file_data.current_arch.default_align() {
+                    [with] => stmts.push(Stmt::Align(delimited(value), delimited(quote!(#with)))),
+                    ref filler => stmts.push(Stmt::AlignFiller(delimited(value), filler.to_vec()))
+                }
+            }
         },
         "alias" => {
             // ; .alias ident, ident
@@ -86,6 +86,29 @@ pub(crate) fn evaluate_directive(file_data: &mut DynasmData, stmts: &mut Vec<Stm
                 }
             }
         },
+        "runtime_sizes" => {
+            // ; .runtime_sizes (on | off)
+            let setting: syn::Ident = input.parse()?;
+            let arch = file_data.current_arch.name();
+            if !matches!(arch, "x64" | "x86" | "x86_16") {
+                emit_error_at(directive.span(), format!("Runtime sizes are not supported on the '{}' architecture", arch));
+            } else {
+                match setting.to_string().as_str() {
+                    "on" => file_data.runtime_sizes = true,
+                    "off" => file_data.runtime_sizes = false,
+                    _ => emit_error_at(setting.span(), "Expected 'on' or 'off'".into())
+                }
+            }
+        },
+        "source_locations" => {
+            // ; .source_locations (on | off)
+            let setting: syn::Ident = input.parse()?;
+            match setting.to_string().as_str() {
+                "on" => file_data.source_locations = true,
+                "off" => file_data.source_locations = false,
+                _ => emit_error_at(setting.span(), "Expected 'on' or 'off'".into())
+            }
+        },
         d => {
             // unknown directive. skip ahead until we hit a ; so the parser can recover
             emit_error_at(directive.span(), format!("unknown directive '{}'", d));
@@ -108,7 +131,7 @@ fn directive_const(file_data: &mut DynasmData, stmts: &mut Vec<Stmt>, input: par
         file_data.current_arch.handle_static_reloc(stmts, jump, size);
     } else {
         let expr: syn::Expr = input.parse()?;
-        stmts.push(Stmt::ExprSigned(delimited(expr), size));
+        stmts.push(data_stmt(file_data, expr, size));
     }
 
 
@@ -119,13 +142,22 @@ fn directive_const(file_data: &mut DynasmData, stmts: &mut Vec<Stmt>, input: par
             file_data.current_arch.handle_static_reloc(stmts, jump, size);
         } else {
             let expr: syn::Expr = input.parse()?;
-            stmts.push(Stmt::ExprSigned(delimited(expr), size));
+            stmts.push(data_stmt(file_data, expr, size));
         }
     }
 
     Ok(())
 }
 
+/// Data is stored in the byte order of the current architecture
+fn data_stmt(file_data: &DynasmData, expr: syn::Expr, size: Size) -> Stmt {
+    if file_data.current_arch.big_endian() {
+        Stmt::ExprSignedBe(delimited(expr), size)
+    } else {
+        Stmt::ExprSigned(delimited(expr), size)
+    }
+}
+
 /// In case a directive is unknown, try to skip up to the next ; and resume parsing. 
 fn skip_until_semicolon(input: parse::ParseStream) {
     let _ = input.step(|cursor| {
@@ -1,5 +1,4 @@
 #![feature(proc_macro_diagnostic)]
-#![feature(proc_macro_span)]
 
 // token/ast manipulation
 extern crate proc_macro;
@@ -12,6 +11,7 @@ extern crate lazy_static;
 extern crate bitflags;
 extern crate owning_ref;
 extern crate byteorder;
+extern crate dynasm_data;
 
 use syn::parse;
 use syn::{Token, parse_macro_input};
@@ -66,7 +66,7 @@ impl parse::Parse for Dynasm {
         // and just convert it back to a tokentree since that's how we'll always be using it.
         let target = common::delimited(target);
 
-        // get file-local data (alias definitions, current architecture)
+        // get file-local data (alias definitions, current architecture, runtime sizing)
         let file_data = file_local_data();
         let mut file_data = file_data.lock().unwrap();
 
@@ -137,12 +137,26 @@ impl parse::Parse for Dynasm {
             } else {
                 // anything else is an assembly instruction which should be in current_arch
 
+                // record where it came from if requested. The previous instruction's location is only ended
+                // if something else was emitted in between
+                let source_locations = file_data.source_locations;
+                if source_locations {
+                    if let Some(common::Stmt::EndSourceLocation) = stmts.last() {
+                        stmts.pop();
+                    }
+                    stmts.push(common::Stmt::SourceLocation(input.span()));
+                }
+
                 let mut state = State {
                     stmts: &mut stmts,
                     target: &target,
                     file_data: &*file_data,
                 };
                 file_data.current_arch.compile_instruction(&mut state, input)?;
+
+                if source_locations {
+                    stmts.push(common::Stmt::EndSourceLocation);
+                }
             }
 
         }
@@ -167,8 +181,12 @@ pub fn dynasm_opmap(tokens: proc_macro::TokenStream) -> proc_macro::TokenStream
     s.push_str("% Instruction Reference\n\n");
 
     s.push_str(&match opmap.arch.as_str() {
-        "x64" | "x86" => arch::x64::create_opmap(),
+        "x64" | "x86" | "x86_16" => arch::x64::create_opmap(),
         "aarch64" => arch::aarch64::create_opmap(),
+        "rv64" => arch::riscv::create_opmap(),
+        "arm" => arch::arm::create_opmap(false),
+        "thumb" => arch::arm::create_opmap(true),
+        "loongarch64" => arch::loongarch::create_opmap(),
         x => panic!("Unknown architecture {}", x)
     });
 
@@ -188,8 +206,11 @@ pub fn dynasm_extract(tokens: proc_macro::TokenStream) -> proc_macro::TokenStrea
     let opmap = parse_macro_input!(tokens as DynasmOpmap);
 
     let s = match opmap.arch.as_str() {
-        "x64" | "x86" => "UNIMPLEMENTED".into(),
+        "x64" | "x86" | "x86_16" => "UNIMPLEMENTED".into(),
         "aarch64" => arch::aarch64::extract_opmap(),
+        "rv64" => "UNIMPLEMENTED".into(),
+        "arm" | "thumb" => "UNIMPLEMENTED".into(),
+        "loongarch64" => "UNIMPLEMENTED".into(),
         x => panic!("Unknown architecture {}", x)
     };
 
@@ -230,6 +251,8 @@ type DynasmStorage = HashMap<PathBuf, Mutex<DynasmData>>;
 struct DynasmData {
     pub current_arch: Box<dyn arch::Arch>,
     pub aliases: HashMap<String, String>,
+    pub runtime_sizes: bool,
+    pub source_locations: bool,
 }
 
 impl DynasmData {
@@ -238,6 +261,8 @@ impl DynasmData {
             current_arch:
                 arch::from_str(arch::CURRENT_ARCH).expect("Default architecture is invalid"),
             aliases: HashMap::new(),
+            runtime_sizes: false,
+            source_locations: false,
         }
     }
 }
@@ -249,7 +274,7 @@ fn file_local_data() -> FileLocalData {
     let span = Span::call_site().unstable();
 
     // and use the file that that was at as scope for resolving dynasm data
-    let id = span.source_file().path();
+    let id = PathBuf::from(span.file());
 
     {
         let data = RwLockReadGuardRef::new(DYNASM_STORAGE.read().unwrap());
@@ -34,6 +34,19 @@ pub fn eat_pseudo_keyword(input: parse::ParseStream, kw: &str) -> bool {
     }).is_ok()
 }
 
+/// Tries to parse a dynamic condition code ( Cond(expr) ). Returns the expression if it worked.
+pub fn parse_dynamic_cond(input: parse::ParseStream) -> parse::Result<Option<syn::Expr>> {
+    if !(input.peek(syn::Ident) && input.peek2(syn::token::Paren)) || input.fork().parse::<syn::Ident>()? != "Cond" {
+        return Ok(None);
+    }
+
+    let _: syn::Ident = input.parse()?;
+    let inner;
+    let _ = syn::parenthesized!(inner in input);
+    let expr: syn::Expr = inner.parse()?;
+    Ok(Some(expr))
+}
+
 /// parses an ident, but instead of syn's Parse impl it does also parse keywords as idents
 pub fn parse_ident_or_rust_keyword(input: parse::ParseStream) -> parse::Result<syn::Ident> {
     input.step(|cursor| {
@@ -11,7 +11,8 @@ use crate::common::{Size, Stmt, delimited};
 
 /// Converts a sequence of abstract Statements to actual tokens
 pub fn serialize(name: &TokenTree, stmts: Vec<Stmt>) -> TokenStream {
-    // first, try to fold constants into a byte stream
+    // first, try to fold constants into a byte stream. These are always little-endian, as constants are only
+    // used for instructions, which are little-endian on all supported architectures (even aarch64_be)
     let mut folded_stmts = Vec::new();
     let mut const_buffer = Vec::new();
     for stmt in stmts {
@@ -75,9 +76,15 @@ pub fn serialize(name: &TokenTree, stmts: Vec<Stmt>) -> TokenStream {
             Stmt::ExprSigned(  expr, Size::DWORD) => ("push_i32", vec![expr]),
             Stmt::ExprSigned(  expr, Size::QWORD) => ("push_i64", vec![expr]),
             Stmt::ExprSigned(_, _) => unimplemented!(),
+            Stmt::ExprSignedBe(expr, Size::BYTE)  => ("push_i8",  vec![expr]),
+            Stmt::ExprSignedBe(expr, Size::WORD)  => ("extend",   vec![delimited(quote!(&i16::to_be_bytes(#expr)))]),
+            Stmt::ExprSignedBe(expr, Size::DWORD) => ("extend",   vec![delimited(quote!(&i32::to_be_bytes(#expr)))]),
+            Stmt::ExprSignedBe(expr, Size::QWORD) => ("extend",   vec![delimited(quote!(&i64::to_be_bytes(#expr)))]),
+            Stmt::ExprSignedBe(_, _) => unimplemented!(),
             Stmt::Extend(data)     => ("extend", vec![proc_macro2::Literal::byte_string(&data).into()]),
             Stmt::ExprExtend(expr) => ("extend", vec![expr]),
             Stmt::Align(expr, with)      => ("align", vec![expr, with]),
+            Stmt::AlignFiller(expr, filler) => ("align_with_filler", vec![expr, proc_macro2::Literal::byte_string(&filler).into()]),
             Stmt::GlobalLabel(n) => ("global_label", vec![expr_string_from_ident(&n)]),
             Stmt::LocalLabel(n)  => ("local_label", vec![expr_string_from_ident(&n)]),
             Stmt::DynamicLabel(expr) => ("dynamic_label", vec![expr]),
@@ -86,11 +93,26 @@ pub fn serialize(name: &TokenTree, stmts: Vec<Stmt>) -> TokenStream {
             Stmt::BackwardJumpTarget(n,   offset, reloc) => ("backward_reloc", vec![expr_string_from_ident(&n), offset, reloc]),
             Stmt::DynamicJumpTarget(expr, offset, reloc) => ("dynamic_reloc" , vec![expr, offset, reloc]),
             Stmt::BareJumpTarget(expr, reloc)    => ("bare_reloc"    , vec![expr, reloc]),
+            Stmt::SourceLocation(span) => {
+                let span = span.unstable();
+                let line = proc_macro2::Literal::u32_unsuffixed(span.line() as u32);
+                let column = proc_macro2::Literal::u32_unsuffixed(span.column() as u32);
+                ("set_source_location", vec![delimited(quote!(file!())), line.into(), column.into()])
+            },
+            Stmt::EndSourceLocation => ("end_source_location", Vec::new()),
             Stmt::Stmt(s) => {
                 output.extend(quote! {
                     #s ;
                 });
                 continue;
+            },
+            Stmt::Select(cond, then, otherwise) => {
+                let then = serialize(name, then);
+                let otherwise = serialize(name, otherwise);
+                output.extend(quote! {
+                    if #cond { #then } else { #otherwise }
+                });
+                continue;
             }
         };
 
@@ -174,7 +196,7 @@ pub fn expr_size_of_scale(ty: &syn::Path, value: &TokenTree, size: Size) -> Toke
     })
 }
 
-/// returns orig | ((expr & mask) << shift)
+/// returns orig | (((expr) & mask) << shift)
 pub fn expr_mask_shift_or(orig: &TokenTree, expr: &TokenTree, mask: u64, shift: i8) -> TokenTree {
     let span = expr.span();
 
@@ -183,18 +205,18 @@ pub fn expr_mask_shift_or(orig: &TokenTree, expr: &TokenTree, mask: u64, shift:
     delimited(if shift >= 0 {
         let shift: TokenTree = proc_macro2::Literal::i8_unsuffixed(shift).into();
         quote_spanned! { span=>
-            #orig | ((#expr & #mask) << #shift)
+            #orig | (((#expr) & #mask) << #shift)
         }
     } else {
         let shift: TokenTree = proc_macro2::Literal::i8_unsuffixed(-shift).into();
         quote_spanned! { span=>
-            #orig | ((#expr & #mask) >> #shift)
+            #orig | (((#expr) & #mask) >> #shift)
         }
     })
 }
 
 
-/// returns orig & !((expr & mask) << shift)
+/// returns orig & !(((expr) & mask) << shift)
 pub fn expr_mask_shift_inverted_and(orig: &TokenTree, expr: &TokenTree, mask: u64, shift: i8) -> TokenTree {
     let span = expr.span();
 
@@ -203,12 +225,12 @@ pub fn expr_mask_shift_inverted_and(orig: &TokenTree, expr: &TokenTree, mask: u6
     delimited(if shift >= 0 {
         let shift: TokenTree = proc_macro2::Literal::i8_unsuffixed(shift).into();
         quote_spanned! { span=>
-            #orig & !((#expr & #mask) << #shift)
+            #orig & !(((#expr) & #mask) << #shift)
         }
     } else {
         let shift: TokenTree = proc_macro2::Literal::i8_unsuffixed(-shift).into();
         quote_spanned! { span=>
-            #orig & !((#expr & #mask) >> #shift)
+            #orig & !(((#expr) & #mask) >> #shift)
         }
     })
 }
@@ -263,6 +285,40 @@ pub fn expr_encode_x64_sib_scale(size: &TokenTree) -> TokenTree {
     })
 }
 
+// expr as ty
+pub fn expr_cast(expr: &TokenTree, ty: &str) -> TokenTree {
+    let span = expr.span();
+    let ty = syn::Ident::new(ty, span);
+    delimited(quote_spanned! { span=>
+        (#expr) as #ty
+    })
+}
+
+// let name = expr, where name is only visible to the code generated by this macro. Returns the statement and name
+pub fn expr_bind(expr: &TokenTree, index: usize) -> (TokenTree, TokenTree) {
+    let span = expr.span();
+    let name = syn::Ident::new(&format!("__dynasm_value_{}", index), Span::mixed_site());
+    (delimited(quote_spanned! { span=>
+        let #name = #expr
+    }), name.into())
+}
+
+// checks if the value of expr fits in a sign-extended byte, after being divided by scale
+pub fn expr_fits_i8(expr: &TokenTree, scale: Option<u8>) -> TokenTree {
+    let span = expr.span();
+    match scale {
+        Some(scale) if scale > 1 => {
+            let scale: TokenTree = proc_macro2::Literal::i64_unsuffixed(i64::from(scale)).into();
+            delimited(quote_spanned! { span=>
+                (#expr) as i64 % #scale == 0 && (-0x80 ..= 0x7F).contains(&((#expr) as i64 / #scale))
+            })
+        },
+        _ => delimited(quote_spanned! { span=>
+            (-0x80 ..= 0x7F).contains(&((#expr) as i64))
+        })
+    }
+}
+
 // Reparses a tokentree into an expression
 pub fn reparse(tt: &TokenTree) -> parse::Result<syn::Expr> {
     syn::parse2(tt.into_token_stream())
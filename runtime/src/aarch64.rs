@@ -2,6 +2,11 @@ use crate::relocations::{Relocation, RelocationSize, RelocationKind, ImpossibleR
 use byteorder::{ByteOrder, LittleEndian};
 use std::convert::TryFrom;
 
+#[cfg(feature = "encoder")]
+pub use crate::encoder::EncodeError;
+#[cfg(feature = "encoder")]
+pub use crate::encoder::aarch64::{encode, Mnemonic, Operand, Register, Vector, MemoryRef, Modifier, Size};
+
 /// Relocation implementation for the aarch64 architecture.
 #[derive(Debug, Clone)]
 pub enum Aarch64Relocation {
@@ -159,6 +164,34 @@ impl Relocation for Aarch64Relocation {
     fn page_size() -> usize {
         4096
     }
+    fn flush_icache(code: &[u8]) {
+        flush_icache(code)
+    }
+    fn instruction_len(_code: &[u8]) -> Option<usize> {
+        Some(4)
+    }
+    fn widen(&self, instruction: &[u8]) -> Option<(usize, Vec<u8>, Self)> {
+        let start = instruction.len().checked_sub(4)?;
+        let template = LittleEndian::read_u32(&instruction[start ..]);
+
+        // invert the condition of the branch, so it skips over an unconditional branch to the target
+        let inverted = match self {
+            // b.cond, except for the conditions that cannot be inverted
+            Self::BCOND if template & 0xFF00_0010 == 0x5400_0000 && template & 0xE != 0xE => (template & 0xFF00_000F) ^ 1,
+            // cbz, cbnz
+            Self::BCOND if template & 0x7E00_0000 == 0x3400_0000 => (template & 0xFF00_001F) ^ 0x0100_0000,
+            // tbz, tbnz
+            Self::TBZ => (template & 0xFFF8_001F) ^ 0x0100_0000,
+            // ldr, ldrsw and prfm literals, and anything else
+            _ => return None
+        };
+
+        // the inverted branch skips over the unconditional branch that follows it
+        let mut bytes = vec![0; 8];
+        LittleEndian::write_u32(&mut bytes[.. 4], inverted | (2 << 5));
+        LittleEndian::write_u32(&mut bytes[4 ..], 0x1400_0000);
+        Some((4, bytes, Self::B))
+    }
 }
 
 
@@ -167,6 +200,64 @@ pub type AssemblyModifier<'a> = crate::Modifier<'a, Aarch64Relocation>;
 pub type UncommittedModifier<'a> = crate::UncommittedModifier<'a>;
 
 
+/// Makes code that was just written to `code` visible to instruction fetches. This cleans the data
+/// cache and invalidates the instruction cache for the affected cache lines (`dc cvau` and `ic ivau`),
+/// followed by the barriers that make this complete (`dsb ish` and `isb`). Threads other than the
+/// calling thread that execute the new code still need to execute an `isb` themselves, or synchronize
+/// with the calling thread in some other way that implies a context synchronization event.
+/// This does nothing when not running on aarch64.
+pub fn flush_icache(code: &[u8]) {
+    #[cfg(target_arch = "aarch64")]
+    flush_icache_native(code);
+
+    #[cfg(not(target_arch = "aarch64"))]
+    let _ = code;
+}
+
+#[cfg(target_arch = "aarch64")]
+fn flush_icache_native(code: &[u8]) {
+    use std::arch::asm;
+
+    if code.is_empty() {
+        return;
+    }
+
+    let start = code.as_ptr() as usize;
+    let end = start + code.len();
+
+    // the cache type register tells us the cache line sizes, and if any of the maintenance is unnecessary
+    let ctr_el0: u64;
+    unsafe {
+        asm!("mrs {}, ctr_el0", out(reg) ctr_el0, options(nomem, nostack, preserves_flags));
+    }
+    let dcache_line = 4usize << ((ctr_el0 >> 16) & 0xF);
+    let icache_line = 4usize << (ctr_el0 & 0xF);
+    let idc = ctr_el0 & (1 << 28) != 0;
+    let dic = ctr_el0 & (1 << 29) != 0;
+
+    unsafe {
+        if !idc {
+            let mut addr = start & !(dcache_line - 1);
+            while addr < end {
+                asm!("dc cvau, {}", in(reg) addr, options(nostack, preserves_flags));
+                addr += dcache_line;
+            }
+        }
+        asm!("dsb ish", options(nostack, preserves_flags));
+
+        if !dic {
+            let mut addr = start & !(icache_line - 1);
+            while addr < end {
+                asm!("ic ivau, {}", in(reg) addr, options(nostack, preserves_flags));
+                addr += icache_line;
+            }
+            asm!("dsb ish", options(nostack, preserves_flags));
+        }
+        asm!("isb", options(nostack, preserves_flags));
+    }
+}
+
+
 /// Helper function for validating that a given value can be encoded as a 32-bit logical immediate
 pub fn encode_logical_immediate_32bit(value: u32) -> Option<u16> {
     let transitions = value ^ value.rotate_right(1);
@@ -177,7 +268,7 @@ pub fn encode_logical_immediate_32bit(value: u32) -> Option<u16> {
         return None;
     }
 
-    let element = value & (1u32 << element_size).wrapping_sub(1);
+    let element = value & (u32::MAX >> (32 - element_size));
     let ones = element.count_ones();
     let imms = (!((element_size << 1) - 1) & 0x3F) | (ones - 1);
 
@@ -200,7 +291,7 @@ pub fn encode_logical_immediate_64bit(value: u64) -> Option<u16> {
         return None;
     }
 
-    let element = value & (1u64 << element_size).wrapping_sub(1);
+    let element = value & (u64::MAX >> (64 - element_size));
     let ones = element.count_ones();
     let imms = (!((element_size << 1) - 1) & 0x7F) | (ones - 1);
 
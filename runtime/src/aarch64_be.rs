@@ -0,0 +1,65 @@
+use crate::relocations::{Relocation, RelocationSize, RelocationKind, ImpossibleRelocation};
+use crate::aarch64::Aarch64Relocation;
+
+
+/// Relocation implementation for the big-endian aarch64 architecture. Instructions are always stored
+/// little-endian, so this only differs from `Aarch64Relocation` in the byte order of relocations in data.
+#[derive(Debug, Clone)]
+pub struct Aarch64BeRelocation(pub Aarch64Relocation);
+
+impl Relocation for Aarch64BeRelocation {
+    type Encoding = (u8,);
+    fn from_encoding(encoding: Self::Encoding) -> Self {
+        Self(Aarch64Relocation::from_encoding(encoding))
+    }
+    fn from_size(size: RelocationSize) -> Self {
+        Self(Aarch64Relocation::Plain(size))
+    }
+    fn start_offset(&self) -> usize {
+        self.0.start_offset()
+    }
+    fn relative_to(&self, location: usize) -> usize {
+        self.0.relative_to(location)
+    }
+    fn field_offset(&self) -> usize {
+        self.0.field_offset()
+    }
+    fn size(&self) -> usize {
+        self.0.size()
+    }
+    fn write_value(&self, buf: &mut [u8], value: isize) -> Result<(), ImpossibleRelocation> {
+        match self.0 {
+            Aarch64Relocation::Plain(s) => s.write_value_be(buf, value),
+            ref r => r.write_value(buf, value)
+        }
+    }
+    fn read_value(&self, buf: &[u8]) -> isize {
+        match self.0 {
+            Aarch64Relocation::Plain(s) => s.read_value_be(buf),
+            ref r => r.read_value(buf)
+        }
+    }
+    fn kind(&self) -> RelocationKind {
+        self.0.kind()
+    }
+    fn page_size() -> usize {
+        Aarch64Relocation::page_size()
+    }
+    fn big_endian() -> bool {
+        true
+    }
+    fn flush_icache(code: &[u8]) {
+        Aarch64Relocation::flush_icache(code)
+    }
+    fn instruction_len(code: &[u8]) -> Option<usize> {
+        Aarch64Relocation::instruction_len(code)
+    }
+    fn widen(&self, instruction: &[u8]) -> Option<(usize, Vec<u8>, Self)> {
+        self.0.widen(instruction).map(|(len, bytes, reloc)| (len, bytes, Self(reloc)))
+    }
+}
+
+
+pub type Assembler = crate::Assembler<Aarch64BeRelocation>;
+pub type AssemblyModifier<'a> = crate::Modifier<'a, Aarch64BeRelocation>;
+pub type UncommittedModifier<'a> = crate::UncommittedModifier<'a>;
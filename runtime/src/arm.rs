@@ -0,0 +1,380 @@
+use crate::relocations::{Relocation, RelocationSize, RelocationKind, ImpossibleRelocation, fits_signed_bitfield};
+use byteorder::{ByteOrder, LittleEndian};
+use std::convert::TryFrom;
+
+/// Relocation implementation for the 32-bit ARM architecture. This covers both A32 and thumb code,
+/// so both can be assembled into the same buffer.
+#[derive(Debug, Clone)]
+#[allow(non_camel_case_types)]
+pub enum ArmRelocation {
+    // a32 b, bl: 24 bits, word aligned
+    B,
+    // a32 blx to thumb code: 24 bits and the H bit, halfword aligned
+    BLX,
+    // a32 ldr, ldrb: 12 bits and the U bit, byte aligned
+    LDR,
+    // a32 ldrh, ldrsb, ldrsh, ldrd: split 8 bits and the U bit, byte aligned
+    LDRH,
+    // a32 vldr: 8 bits and the U bit, word aligned
+    VLDR,
+    // a32 adr: modified immediate, added or subtracted
+    ADR,
+    // t32 b.w, bl: split 24 bits, halfword aligned
+    T_B,
+    // t32 blx to a32 code: split 24 bits, word aligned, relative to the aligned PC
+    T_BLX,
+    // t32 conditional b.w: split 20 bits, halfword aligned
+    T_BCOND,
+    // 16-bit thumb b: 11 bits, halfword aligned
+    T_B16,
+    // 16-bit thumb conditional b: 8 bits, halfword aligned
+    T_BCOND16,
+    // cbz, cbnz: split 6 bits, halfword aligned, forward only
+    T_CBZ,
+    // t32 ldr, ldrb, ldrh, ldrsb, ldrsh: 12 bits and the U bit, relative to the aligned PC
+    T_LDR,
+    // 16-bit thumb ldr: 8 bits, word aligned, forward only, relative to the aligned PC
+    T_LDR16,
+    // t32 vldr, ldrd: 8 bits and the U bit, word aligned, relative to the aligned PC
+    T_VLDR,
+    // t32 adr: split 12 bits, added or subtracted, relative to the aligned PC
+    T_ADR,
+    // 16-bit thumb adr: 8 bits, word aligned, forward only, relative to the aligned PC
+    T_ADR16,
+    // Anything in directives
+    Plain(RelocationSize),
+}
+
+impl ArmRelocation {
+    // whether this relocation is part of a 32-bit thumb instruction, which is stored as two halfwords
+    fn is_thumb32(&self) -> bool {
+        matches!(self, Self::T_B | Self::T_BLX | Self::T_BCOND | Self::T_LDR | Self::T_VLDR | Self::T_ADR)
+    }
+
+    // returns the (mask, bits) of the fields of an instruction that encode `value`
+    fn encode(&self, value: i64) -> Result<(u32, u32), ImpossibleRelocation> {
+        let magnitude = value.unsigned_abs();
+        let add = u32::from(value >= 0);
+
+        Ok(match self {
+            Self::B => {
+                check(value & 3 == 0 && fits_signed_bitfield(value, 26))?;
+                (0x00FF_FFFF, ((value >> 2) as u32) & 0x00FF_FFFF)
+            },
+            Self::BLX => {
+                check(value & 1 == 0 && fits_signed_bitfield(value, 26))?;
+                (0x01FF_FFFF, ((value as u32 & 2) << 23) | (((value >> 2) as u32) & 0x00FF_FFFF))
+            },
+            Self::LDR
+            | Self::T_LDR => {
+                check(magnitude <= 0xFFF)?;
+                (0x0080_0FFF, (add << 23) | magnitude as u32)
+            },
+            Self::LDRH => {
+                check(magnitude <= 0xFF)?;
+                let magnitude = magnitude as u32;
+                (0x0080_0F0F, (add << 23) | ((magnitude & 0xF0) << 4) | (magnitude & 0xF))
+            },
+            Self::VLDR
+            | Self::T_VLDR => {
+                check(magnitude & 3 == 0 && magnitude <= 0x3FC)?;
+                (0x0080_00FF, (add << 23) | (magnitude as u32 >> 2))
+            },
+            Self::ADR => {
+                let imm = u32::try_from(magnitude).ok().and_then(encode_modified_immediate).ok_or(ImpossibleRelocation { })?;
+                // adr is add rd, pc, #imm or sub rd, pc, #imm
+                let opcode = if add != 0 { 0x0080_0000 } else { 0x0040_0000 };
+                (0x00C0_0FFF, opcode | u32::from(imm))
+            },
+            Self::T_B
+            | Self::T_BLX => {
+                let aligned = if let Self::T_BLX = self { value & 3 == 0 } else { value & 1 == 0 };
+                check(aligned && fits_signed_bitfield(value, 25))?;
+                let value = value as u32;
+                let s = (value >> 24) & 1;
+                let j1 = !((value >> 23) ^ s) & 1;
+                let j2 = !((value >> 22) ^ s) & 1;
+                (0x07FF_2FFF, (s << 26) | (((value >> 12) & 0x3FF) << 16) | (j1 << 13) | (j2 << 11) | ((value >> 1) & 0x7FF))
+            },
+            Self::T_BCOND => {
+                check(value & 1 == 0 && fits_signed_bitfield(value, 21))?;
+                let value = value as u32;
+                let s = (value >> 20) & 1;
+                let j1 = (value >> 18) & 1;
+                let j2 = (value >> 19) & 1;
+                (0x043F_2FFF, (s << 26) | (((value >> 12) & 0x3F) << 16) | (j1 << 13) | (j2 << 11) | ((value >> 1) & 0x7FF))
+            },
+            Self::T_B16 => {
+                check(value & 1 == 0 && fits_signed_bitfield(value, 12))?;
+                (0x07FF, ((value >> 1) as u32) & 0x7FF)
+            },
+            Self::T_BCOND16 => {
+                check(value & 1 == 0 && fits_signed_bitfield(value, 9))?;
+                (0x00FF, ((value >> 1) as u32) & 0xFF)
+            },
+            Self::T_CBZ => {
+                check(value & 1 == 0 && (0 ..= 126).contains(&value))?;
+                let value = value as u32;
+                (0x02F8, ((value & 0x40) << 3) | ((value & 0x3E) << 2))
+            },
+            Self::T_LDR16
+            | Self::T_ADR16 => {
+                check(value & 3 == 0 && (0 ..= 0x3FC).contains(&value))?;
+                (0x00FF, (value as u32) >> 2)
+            },
+            Self::T_ADR => {
+                check(magnitude <= 0xFFF)?;
+                let magnitude = magnitude as u32;
+                // adr.w is addw rd, pc, #imm or subw rd, pc, #imm
+                let opcode = if add != 0 { 0 } else { 0x00A0_0000 };
+                (0x04A0_70FF, opcode | ((magnitude & 0x800) << 15) | ((magnitude & 0x700) << 4) | (magnitude & 0xFF))
+            },
+            Self::Plain(_) => unreachable!()
+        })
+    }
+
+    // reads the value encoded in the fields of an instruction
+    fn decode(&self, ins: u32) -> i64 {
+        let sign = |magnitude: u32| if ins & 0x0080_0000 != 0 { i64::from(magnitude) } else { -i64::from(magnitude) };
+
+        match self {
+            Self::B => i64::from(((ins << 8) as i32) >> 6),
+            Self::BLX => i64::from(((ins << 8) as i32) >> 6) | i64::from((ins >> 23) & 2),
+            Self::LDR
+            | Self::T_LDR => sign(ins & 0xFFF),
+            Self::LDRH => sign(((ins >> 4) & 0xF0) | (ins & 0xF)),
+            Self::VLDR
+            | Self::T_VLDR => sign((ins & 0xFF) << 2),
+            Self::ADR => {
+                let rot = (ins >> 8) & 0xF;
+                let magnitude = i64::from((ins & 0xFF).rotate_right(rot * 2));
+                if ins & 0x0080_0000 != 0 { magnitude } else { -magnitude }
+            },
+            Self::T_B
+            | Self::T_BLX => {
+                let s = (ins >> 26) & 1;
+                let i1 = !((ins >> 13) ^ s) & 1;
+                let i2 = !((ins >> 11) ^ s) & 1;
+                let value = (s << 24) | (i1 << 23) | (i2 << 22) | (((ins >> 16) & 0x3FF) << 12) | ((ins & 0x7FF) << 1);
+                i64::from(((value << 7) as i32) >> 7)
+            },
+            Self::T_BCOND => {
+                let value = (((ins >> 26) & 1) << 20) | (((ins >> 11) & 1) << 19) | (((ins >> 13) & 1) << 18)
+                    | (((ins >> 16) & 0x3F) << 12) | ((ins & 0x7FF) << 1);
+                i64::from(((value << 11) as i32) >> 11)
+            },
+            Self::T_B16 => i64::from(((ins << 21) as i32) >> 20),
+            Self::T_BCOND16 => i64::from(((ins << 24) as i32) >> 23),
+            Self::T_CBZ => i64::from(((ins >> 3) & 0x40) | ((ins >> 2) & 0x3E)),
+            Self::T_LDR16
+            | Self::T_ADR16 => i64::from((ins & 0xFF) << 2),
+            Self::T_ADR => {
+                let magnitude = i64::from(((ins >> 15) & 0x800) | ((ins >> 4) & 0x700) | (ins & 0xFF));
+                if ins & 0x00A0_0000 == 0 { magnitude } else { -magnitude }
+            },
+            Self::Plain(_) => unreachable!()
+        }
+    }
+
+    // 32-bit thumb instructions are stored as their most significant halfword, followed by their least significant halfword
+    fn read_instruction(&self, buf: &[u8]) -> u32 {
+        match self.size() {
+            2 => u32::from(LittleEndian::read_u16(buf)),
+            _ if self.is_thumb32() => (u32::from(LittleEndian::read_u16(&buf[.. 2])) << 16) | u32::from(LittleEndian::read_u16(&buf[2 ..])),
+            _ => LittleEndian::read_u32(buf)
+        }
+    }
+
+    fn write_instruction(&self, buf: &mut [u8], ins: u32) {
+        match self.size() {
+            2 => LittleEndian::write_u16(buf, ins as u16),
+            _ if self.is_thumb32() => {
+                LittleEndian::write_u16(&mut buf[.. 2], (ins >> 16) as u16);
+                LittleEndian::write_u16(&mut buf[2 ..], ins as u16);
+            },
+            _ => LittleEndian::write_u32(buf, ins)
+        }
+    }
+}
+
+fn check(valid: bool) -> Result<(), ImpossibleRelocation> {
+    if valid {
+        Ok(())
+    } else {
+        Err(ImpossibleRelocation { })
+    }
+}
+
+impl Relocation for ArmRelocation {
+    type Encoding = (u8,);
+    fn from_encoding(encoding: Self::Encoding) -> Self {
+        match encoding.0 {
+            0 => Self::B,
+            1 => Self::BLX,
+            2 => Self::LDR,
+            3 => Self::LDRH,
+            4 => Self::VLDR,
+            5 => Self::ADR,
+            6 => Self::T_B,
+            7 => Self::T_BLX,
+            8 => Self::T_BCOND,
+            9 => Self::T_B16,
+            10 => Self::T_BCOND16,
+            11 => Self::T_CBZ,
+            12 => Self::T_LDR,
+            13 => Self::T_LDR16,
+            14 => Self::T_VLDR,
+            15 => Self::T_ADR,
+            16 => Self::T_ADR16,
+            x  => Self::Plain(RelocationSize::from_encoding(x - 17))
+        }
+    }
+    fn from_size(size: RelocationSize) -> Self {
+        Self::Plain(size)
+    }
+    fn relative_to(&self, location: usize) -> usize {
+        match self {
+            // the PC reads as the address of the current instruction plus 8 in A32 code
+            Self::B
+            | Self::BLX
+            | Self::LDR
+            | Self::LDRH
+            | Self::VLDR
+            | Self::ADR => location + 4,
+            // and as the address of the current instruction plus 4 in thumb code, which some instructions align down
+            Self::T_BLX
+            | Self::T_LDR
+            | Self::T_LDR16
+            | Self::T_VLDR
+            | Self::T_ADR
+            | Self::T_ADR16 => (location - self.size() + 4) & !3,
+            Self::T_B
+            | Self::T_BCOND
+            | Self::T_B16
+            | Self::T_BCOND16
+            | Self::T_CBZ => location - self.size() + 4,
+            Self::Plain(s) => location - s.size(),
+        }
+    }
+    fn size(&self) -> usize {
+        match self {
+            Self::T_B16
+            | Self::T_BCOND16
+            | Self::T_CBZ
+            | Self::T_LDR16
+            | Self::T_ADR16 => RelocationSize::Word.size(),
+            Self::Plain(s) => s.size(),
+            _ => RelocationSize::DWord.size(),
+        }
+    }
+    fn write_value(&self, buf: &mut [u8], value: isize) -> Result<(), ImpossibleRelocation> {
+        if let Self::Plain(s) = self {
+            return s.write_value(buf, value);
+        };
+
+        let value = i64::try_from(value).map_err(|_| ImpossibleRelocation { } )?;
+        let (mask, bits) = self.encode(value)?;
+
+        let template = self.read_instruction(buf) & !mask;
+        self.write_instruction(buf, template | bits);
+        Ok(())
+    }
+    fn read_value(&self, buf: &[u8]) -> isize {
+        if let Self::Plain(s) = self {
+            return s.read_value(buf);
+        };
+
+        self.decode(self.read_instruction(buf)) as isize
+    }
+    fn kind(&self) -> RelocationKind {
+        RelocationKind::Relative
+    }
+    fn page_size() -> usize {
+        4096
+    }
+    fn flush_icache(code: &[u8]) {
+        flush_icache(code)
+    }
+}
+
+
+pub type Assembler = crate::Assembler<ArmRelocation>;
+pub type AssemblyModifier<'a> = crate::Modifier<'a, ArmRelocation>;
+pub type UncommittedModifier<'a> = crate::UncommittedModifier<'a>;
+
+
+/// Returns the address to branch to in order to execute the thumb code at `ptr` through an
+/// interworking branch such as `blx` or a function pointer call. This sets the lowest bit of the address.
+pub fn thumb_entry(ptr: *const u8) -> *const u8 {
+    (ptr as usize | 1) as *const u8
+}
+
+
+/// Makes code that was just written to `code` visible to instruction fetches, using the
+/// `__clear_cache` routine of the compiler runtime.
+/// This does nothing when not running on arm.
+pub fn flush_icache(code: &[u8]) {
+    #[cfg(target_arch = "arm")]
+    flush_icache_native(code);
+
+    #[cfg(not(target_arch = "arm"))]
+    let _ = code;
+}
+
+#[cfg(target_arch = "arm")]
+fn flush_icache_native(code: &[u8]) {
+    extern "C" {
+        fn __clear_cache(start: *mut u8, end: *mut u8);
+    }
+
+    if code.is_empty() {
+        return;
+    }
+
+    let range = code.as_ptr_range();
+    unsafe {
+        __clear_cache(range.start as *mut u8, range.end as *mut u8);
+    }
+}
+
+
+/// Helper function for validating that a given value can be encoded as an A32 modified immediate.
+/// Returns the 12-bit rotation and immediate field.
+pub fn encode_modified_immediate(value: u32) -> Option<u16> {
+    for rot in 0 .. 16 {
+        let imm8 = value.rotate_left(rot * 2);
+        if imm8 <= 0xFF {
+            return Some(((rot as u16) << 8) | (imm8 as u16));
+        }
+    }
+    None
+}
+
+/// Helper function for validating that a given value can be encoded as a thumb modified immediate.
+/// Returns the 12-bit i:imm3:imm8 field.
+pub fn encode_thumb_modified_immediate(value: u32) -> Option<u16> {
+    let byte = value & 0xFF;
+    if value <= 0xFF {
+        return Some(value as u16);
+    }
+    if value == byte * 0x0001_0001 {
+        return Some(0x100 | byte as u16);
+    }
+    let byte = (value >> 8) & 0xFF;
+    if value == byte * 0x0100_0100 {
+        return Some(0x200 | byte as u16);
+    }
+    let byte = value & 0xFF;
+    if value == byte * 0x0101_0101 {
+        return Some(0x300 | byte as u16);
+    }
+
+    // an 8-bit value with its top bit set, rotated right by 8-31 bits
+    for rot in 8 .. 32 {
+        let imm8 = value.rotate_left(rot);
+        if (0x80 ..= 0xFF).contains(&imm8) {
+            return Some(((rot as u16) << 7) | (imm8 as u16 & 0x7F));
+        }
+    }
+    None
+}
@@ -2,12 +2,13 @@
 // like primitives for assembling buffers, etc
 
 use std::io;
+use std::fmt;
 use std::collections::hash_map::{HashMap, Entry};
 use std::collections::BTreeMap;
 use std::sync::{Arc, RwLock, RwLockWriteGuard};
 use std::mem;
 
-use crate::{DynamicLabel, AssemblyOffset, DynasmError, LabelKind, DynasmLabelApi};
+use crate::{DynamicLabel, AssemblyOffset, DynasmError, LabelKind, TargetKind, DynasmLabelApi};
 use crate::mmap::{ExecutableBuffer, MutableBuffer};
 use crate::relocations::{Relocation, RelocationKind, RelocationSize, ImpossibleRelocation};
 
@@ -52,8 +53,9 @@ impl MemoryManager {
     }
 
     /// Commits the data from `new` into the managed memory, calling `f` when the buffer is moved to fix anything
-    /// that relies on the address of the buffer
-    pub fn commit<F>(&mut self, new: &mut Vec<u8>, f: F) where F: FnOnce(&mut [u8], usize, usize) {
+    /// that relies on the address of the buffer. Afterwards, the cache maintenance required by the architecture
+    /// of `R` is performed on the code that changed.
+    pub fn commit<R, F>(&mut self, new: &mut Vec<u8>, f: F) where R: Relocation, F: FnOnce(&mut [u8], usize, usize) {
         let old_asmoffset = self.asmoffset;
         let new_asmoffset = self.asmoffset + new.len();
 
@@ -80,8 +82,10 @@ impl MemoryManager {
             f(&mut new_buffer, self.execbuffer_addr, new_buffer_addr);
 
             // swap the buffers
+            let new_buffer = new_buffer.make_exec().expect("Could not swap buffer protection modes");
+            R::flush_icache(&new_buffer);
             self.execbuffer_addr = new_buffer_addr;
-            *self.execbuffer.write().unwrap() = new_buffer.make_exec().expect("Could not swap buffer protection modes")
+            *self.execbuffer.write().unwrap() = new_buffer;
 
         } else {
 
@@ -96,6 +100,7 @@ impl MemoryManager {
 
             // repack the buffer
             let buffer = buffer.make_exec().expect("Could not swap buffer protection modes");
+            R::flush_icache(&buffer[old_asmoffset ..]);
             mem::replace(&mut *lock, buffer);
         }
 
@@ -197,6 +202,17 @@ impl LabelRegistry {
     pub fn resolve_local(&self, name: &'static str) -> Result<AssemblyOffset, DynasmError> {
         self.local_labels.get(&name).cloned().ok_or_else(|| DynasmError::UnknownLabel(LabelKind::Local(name)))
     }
+
+    /// Moves every defined label to the offset returned by `f`. This is used to keep labels
+    /// consistent when the code they point into gets rewritten.
+    pub fn remap<F>(&mut self, f: F) where F: Fn(AssemblyOffset) -> AssemblyOffset {
+        for offset in self.global_labels.values_mut().chain(self.local_labels.values_mut()) {
+            *offset = f(*offset);
+        }
+        for offset in self.dynamic_labels.iter_mut().flatten() {
+            *offset = f(*offset);
+        }
+    }
 }
 
 
@@ -224,10 +240,10 @@ impl<R: Relocation> PatchLoc<R> {
         &mut buffer[field_offset .. field_offset + self.relocation.size()]
     }
 
-    fn value(&self, target: usize, buf_addr: usize) -> isize {
+    fn value(&self, location: usize, target: usize, buf_addr: usize) -> isize {
         (match self.relocation.kind() {
-            RelocationKind::Relative => target.wrapping_sub(self.location.0 - self.relocation.start_offset()),
-            RelocationKind::RelToAbs => target.wrapping_sub(self.location.0 - self.relocation.start_offset() + buf_addr),
+            RelocationKind::Relative => target.wrapping_sub(self.relocation.relative_to(location)),
+            RelocationKind::RelToAbs => target.wrapping_sub(self.relocation.relative_to(location) + buf_addr),
             RelocationKind::AbsToRel => target + buf_addr
         }) as isize + self.offset
     }
@@ -237,7 +253,7 @@ impl<R: Relocation> PatchLoc<R> {
     /// `buf_addr` is the address that this larger buffer will come to reside at when it is assembled.
     pub fn patch(&self, buf_offset: usize, buf_addr: usize, buffer: &mut [u8], target: usize) -> Result<(), ImpossibleRelocation> {
         let buf = self.slice(buf_offset, buffer);
-        let value = self.value(target, buf_addr);
+        let value = self.value(self.location.0, target, buf_addr);
         self.relocation.write_value(buf, value)
     }
 
@@ -255,6 +271,13 @@ impl<R: Relocation> PatchLoc<R> {
         self.relocation.write_value(buf, value)
     }
 
+    // Check if this patch would be able to point to `target` if it was located at `location`.
+    fn fits(&self, location: usize, buf_addr: usize, target: usize) -> bool {
+        let mut scratch = vec![0; self.relocation.size()];
+        let value = self.value(location, target, buf_addr);
+        self.relocation.write_value(&mut scratch, value).is_ok()
+    }
+
     /// Returns if this patch requires adjustment when the address of the buffer it resides in is altered.
     pub fn needs_adjustment(&self) -> bool {
         match self.relocation.kind() {
@@ -378,6 +401,416 @@ impl<R: Relocation> ManagedRelocs<R> {
 }
 
 
+/// A registry of relocations in uncommitted code, used by assemblers that perform branch relaxation.
+/// Instead of being encoded immediately, relocations are recorded here together with their targets.
+/// When the code is committed, branches that cannot reach their target are widened, after which the
+/// code, the labels pointing into it and the relocations inside it are updated to match.
+#[derive(Debug, Default)]
+pub struct RelaxationRegistry<R: Relocation> {
+    // relocations waiting to be encoded, with their target and a description of the target
+    pending: Vec<(PatchLoc<R>, usize, TargetKind)>,
+    // alignment directives in the relaxed code: the offset at which their padding starts, the alignment and the filler
+    alignments: Vec<(usize, usize, Vec<u8>)>,
+    // for the stretch of code relaxed most recently, its start and end before relaxation and how it was relaxed
+    history: Option<(usize, usize, ShiftTable)>,
+}
+
+// offsets in code before relaxation, together with how far code at or after them moved
+type ShiftTable = Vec<(usize, isize)>;
+
+// parts of relaxed code that can change in size
+#[derive(Debug, Clone, Copy)]
+enum Resize {
+    // the pending relocation with this index
+    Branch(usize),
+    // the alignment directive with this index
+    Align(usize),
+}
+
+impl<R: Relocation> RelaxationRegistry<R> {
+    /// Create a new, empty relaxation registry.
+    pub fn new() -> Self {
+        Self {
+            pending: Vec::new(),
+            alignments: Vec::new(),
+            history: None,
+        }
+    }
+
+    /// Record a relocation that should be pointed at `target` when the code it is part of is relaxed.
+    /// `kind` is used for error reporting. Targets described as `TargetKind::Extern` are not moved by relaxation.
+    pub fn add(&mut self, patchloc: PatchLoc<R>, target: usize, kind: TargetKind) {
+        self.pending.push((patchloc, target, kind));
+    }
+
+    /// Record that the code was padded with `filler` up to `alignment` starting at `offset`, so the padding can
+    /// be recalculated when the code before it moves. Padding that is not a multiple of the length of `filler`
+    /// starts with zeroes, like `DynasmApi::align_with_filler` pads it.
+    pub fn add_alignment(&mut self, offset: AssemblyOffset, alignment: usize, filler: &[u8]) {
+        self.alignments.push((offset.0, alignment, filler.to_vec()));
+    }
+
+    /// Translate an offset into code from before that code was relaxed to the offset it ended up at.
+    /// Only the most recent relaxation is remembered: offsets into code that was not relaxed by it are returned unchanged.
+    pub fn translate(&self, offset: AssemblyOffset) -> AssemblyOffset {
+        match self.history {
+            Some((start, end, ref table)) if offset.0 >= start && offset.0 <= end => AssemblyOffset(shifted(table, offset.0)),
+            _ => offset
+        }
+    }
+
+    /// Relax and encode all pending relocations. `buffer` is a subsection of a larger buffer, located at offset
+    /// `buf_offset` in this larger buffer, which will come to reside at `buf_addr`. Any branch that cannot reach
+    /// its target is widened, after which `buffer` is rewritten and the labels in `labels` are moved to match.
+    /// Encoded relocations that need adjustment when the buffer is moved are added to `managed`.
+    pub fn relax(&mut self, buf_offset: usize, buf_addr: usize, buffer: &mut Vec<u8>, labels: &mut LabelRegistry, managed: &mut ManagedRelocs<R>) -> Result<(), DynasmError> {
+        // offsets into code relaxed earlier can no longer be translated once more code is committed
+        self.history = None;
+
+        let mut pending = mem::take(&mut self.pending);
+        pending.sort_by_key(|(loc, _, _)| loc.location.0);
+        let alignments = mem::take(&mut self.alignments);
+
+        let widened: Vec<_> = pending.iter()
+            .map(|(loc, _, _)| loc.relocation.widen(&buffer[.. loc.location.0 - buf_offset]))
+            .collect();
+
+        // everything that can change size, in order. Branches are keyed by the end of their instruction,
+        // alignment padding by its start.
+        let mut resizes: Vec<_> = widened.iter().enumerate()
+            .filter(|(_, widened)| widened.is_some())
+            .map(|(i, _)| (pending[i].0.location.0, Resize::Branch(i)))
+            .chain(alignments.iter().enumerate().map(|(i, &(offset, _, _))| (offset, Resize::Align(i))))
+            .collect();
+        resizes.sort_by_key(|&(offset, resize)| (offset, match resize { Resize::Branch(_) => 0, Resize::Align(..) => 1 }));
+
+        // widen branches until every branch can reach its target. As branches only grow, this terminates.
+        let mut growth = vec![0; pending.len()];
+        let mut table = Vec::new();
+        loop {
+            let mut changed = false;
+            for (i, (loc, target, kind)) in pending.iter().enumerate() {
+                let (replaced, bytes) = match &widened[i] {
+                    Some((replaced, bytes, _)) if growth[i] == 0 => (replaced, bytes),
+                    _ => continue
+                };
+
+                if !loc.fits(shifted(&table, loc.location.0), buf_addr, relaxed_target(&table, *target, kind)) {
+                    growth[i] = bytes.len() - replaced;
+                    changed = true;
+                }
+            }
+
+            if !changed {
+                break;
+            }
+
+            table.clear();
+            let mut total = 0;
+            for &(offset, resize) in &resizes {
+                let key = match resize {
+                    Resize::Branch(i) => {
+                        total += growth[i] as isize;
+                        offset
+                    },
+                    Resize::Align(i) => {
+                        let alignment = alignments[i].1;
+                        let old = padding(offset, alignment);
+                        total += padding((offset as isize + total) as usize, alignment) as isize - old as isize;
+                        offset + old
+                    }
+                };
+                match table.last_mut() {
+                    Some((last, shift)) if *last == key => *shift = total,
+                    _ => table.push((key, total))
+                }
+            }
+        }
+
+        // rewrite the code with the widened branches and the new alignment padding
+        if !table.is_empty() {
+            let mut relaxed = Vec::with_capacity(buffer.len() + 16 * table.len());
+            let mut copied = 0;
+            for &(offset, resize) in &resizes {
+                let start = offset - buf_offset;
+                match resize {
+                    Resize::Branch(i) => if growth[i] != 0 {
+                        let (replaced, bytes, _) = widened[i].as_ref().unwrap();
+                        relaxed.extend_from_slice(&buffer[copied .. start - replaced]);
+                        relaxed.extend_from_slice(bytes);
+                        copied = start;
+                    },
+                    Resize::Align(i) => {
+                        let (_, alignment, ref filler) = alignments[i];
+                        relaxed.extend_from_slice(&buffer[copied .. start]);
+                        let fill = padding(buf_offset + relaxed.len(), alignment);
+                        relaxed.resize(relaxed.len() + fill % filler.len(), 0);
+                        for _ in 0 .. fill / filler.len() {
+                            relaxed.extend_from_slice(filler);
+                        }
+                        copied = start + padding(offset, alignment);
+                    }
+                }
+            }
+            relaxed.extend_from_slice(&buffer[copied ..]);
+
+            labels.remap(|offset| AssemblyOffset(shifted(&table, offset.0)));
+            self.history = Some((buf_offset, buf_offset + buffer.len(), table.clone()));
+            *buffer = relaxed;
+        }
+
+        // and encode the relocations in their final locations
+        for (((mut loc, target, kind), widened), grown) in pending.into_iter().zip(widened).zip(growth) {
+            if grown != 0 {
+                loc.relocation = widened.unwrap().2;
+            }
+            loc.location = AssemblyOffset(shifted(&table, loc.location.0));
+
+            if let Err(_) = loc.patch(buf_offset, buf_addr, buffer, relaxed_target(&table, target, &kind)) {
+                return Err(DynasmError::ImpossibleRelocation(kind));
+            }
+            if loc.needs_adjustment() {
+                managed.add(loc);
+            }
+        }
+
+        Ok(())
+    }
+}
+
+// the offset that code at `offset` moved to according to `table`
+fn shifted(table: &[(usize, isize)], offset: usize) -> usize {
+    let shift = match table.binary_search_by_key(&offset, |&(key, _)| key) {
+        Ok(i) => table[i].1,
+        Err(0) => 0,
+        Err(i) => table[i - 1].1,
+    };
+    (offset as isize + shift) as usize
+}
+
+// the location of a relocation target after relaxation
+fn relaxed_target(table: &[(usize, isize)], target: usize, kind: &TargetKind) -> usize {
+    match kind {
+        TargetKind::Extern(_) => target,
+        _ => shifted(table, target)
+    }
+}
+
+// the amount of padding needed at `offset` to reach `alignment`
+fn padding(offset: usize, alignment: usize) -> usize {
+    (alignment - offset % alignment) % alignment
+}
+
+
+/// A registry of the labels and relocations in the code of an assembler. Unlike the `LabelRegistry`
+/// and `RelocRegistry`, it keeps them after they have been resolved, so the code can be annotated with them
+/// afterwards. Used by assemblers in debug mode.
+#[derive(Debug, Clone, Default)]
+pub struct DebugRegistry {
+    // labels, by the offset they were defined at
+    labels: BTreeMap<usize, Vec<LabelKind>>,
+    // the targets of relocations, by the definition point of the relocation
+    relocs: BTreeMap<usize, TargetKind>,
+}
+
+impl DebugRegistry {
+    /// Create a new, empty debug registry
+    pub fn new() -> Self {
+        Self {
+            labels: BTreeMap::new(),
+            relocs: BTreeMap::new(),
+        }
+    }
+
+    /// Record that `label` was defined at `offset`.
+    pub fn add_label(&mut self, offset: AssemblyOffset, label: LabelKind) {
+        let labels = self.labels.entry(offset.0).or_default();
+        if !labels.contains(&label) {
+            labels.push(label);
+        }
+    }
+
+    /// Record a relocation to `target` defined at `location`. This replaces any relocation previously
+    /// recorded at the same location, as happens when code is overwritten.
+    pub fn add_reloc(&mut self, location: AssemblyOffset, target: TargetKind) {
+        self.relocs.insert(location.0, target);
+    }
+
+    /// Move everything recorded at or after `start` to the offset returned by `f`. This is used when
+    /// code gets relaxed.
+    pub fn remap<F>(&mut self, start: usize, f: F) where F: Fn(AssemblyOffset) -> AssemblyOffset {
+        let labels = self.labels.split_off(&start);
+        for (offset, labels) in labels {
+            self.labels.entry(f(AssemblyOffset(offset)).0).or_default().extend(labels);
+        }
+        let relocs = self.relocs.split_off(&start);
+        for (location, target) in relocs {
+            self.relocs.insert(f(AssemblyOffset(location)).0, target);
+        }
+    }
+
+    /// Split `code`, the code of an assembler for architecture `R`, into the lines of a listing annotated with
+    /// the recorded labels and relocations. Code is split into instructions using `Relocation::instruction_len`,
+    /// and lines always end at the definition point of a relocation or the offset of a label. If the length
+    /// of instructions is unknown, each line contains all code up to the next of these.
+    pub fn listing<R: Relocation>(&self, code: &[u8]) -> Vec<ListingLine> {
+        let mut lines = Vec::new();
+        let mut offset = 0;
+
+        while offset < code.len() {
+            let boundary = self.labels.range(offset + 1 ..).map(|(&o, _)| o).next().unwrap_or(code.len())
+                .min(self.relocs.range(offset + 1 ..).map(|(&o, _)| o).next().unwrap_or(code.len()))
+                .min(code.len());
+            let end = match R::instruction_len(&code[offset ..]) {
+                Some(len) if len != 0 => (offset + len).min(boundary),
+                _ => boundary
+            };
+
+            lines.push(ListingLine {
+                offset: AssemblyOffset(offset),
+                bytes: code[offset .. end].to_vec(),
+                labels: self.labels.get(&offset).cloned().unwrap_or_default(),
+                targets: self.relocs.range(offset + 1 ..= end).map(|(_, &target)| target).collect(),
+            });
+            offset = end;
+        }
+
+        // labels at the end of the code get a line of their own
+        if let Some(labels) = self.labels.get(&code.len()) {
+            lines.push(ListingLine {
+                offset: AssemblyOffset(code.len()),
+                bytes: Vec::new(),
+                labels: labels.clone(),
+                targets: Vec::new(),
+            });
+        }
+
+        lines
+    }
+}
+
+/// A line of an assembly listing as produced by `Assembler::listing`. It contains a single instruction,
+/// unless instructions cannot be told apart on the target architecture.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ListingLine {
+    /// The offset of the instruction in the assembling buffer
+    pub offset: AssemblyOffset,
+    /// The bytes of the instruction
+    pub bytes: Vec<u8>,
+    /// The labels defined at the start of the instruction
+    pub labels: Vec<LabelKind>,
+    /// The targets of the relocations in the instruction
+    pub targets: Vec<TargetKind>,
+}
+
+impl fmt::Display for ListingLine {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let bytes: Vec<String> = self.bytes.iter().map(|b| format!("{:02X}", b)).collect();
+        let annotations: Vec<String> = self.labels.iter().map(ToString::to_string)
+            .chain(self.targets.iter().map(ToString::to_string))
+            .collect();
+
+        write!(f, "{:08X}  ", self.offset.0)?;
+        if annotations.is_empty() {
+            write!(f, "{}", bytes.join(" "))
+        } else {
+            write!(f, "{:<30} ; {}", bytes.join(" "), annotations.join(", "))
+        }
+    }
+}
+
+
+/// A location in the Rust source code that some code was assembled from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SourceLocation {
+    /// The path of the source file
+    pub file: &'static str,
+    /// The line in the source file, starting at 1
+    pub line: u32,
+    /// The column in the source file, starting at 1
+    pub column: u32,
+}
+
+impl fmt::Display for SourceLocation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}:{}:{}", self.file, self.line, self.column)
+    }
+}
+
+/// A registry of the source locations that instructions were assembled from, by the offset of the instruction.
+/// Every recorded location covers the code up to the next recorded location or the end of the sequence of
+/// instructions it belongs to.
+#[derive(Debug, Clone, Default)]
+pub struct SourceMap {
+    // `None` marks the end of the code covered by the previous location
+    locations: BTreeMap<usize, Option<SourceLocation>>,
+}
+
+impl SourceMap {
+    /// Create a new, empty source map
+    pub fn new() -> Self {
+        Self {
+            locations: BTreeMap::new(),
+        }
+    }
+
+    /// Record that the instruction at `offset` was assembled from `location`.
+    pub fn add(&mut self, offset: AssemblyOffset, location: SourceLocation) {
+        self.locations.insert(offset.0, Some(location));
+    }
+
+    /// Record that the code at `offset` was not assembled from a known location.
+    pub fn end(&mut self, offset: AssemblyOffset) {
+        self.locations.insert(offset.0, None);
+    }
+
+    /// Look up the source location of the instruction containing `offset`. Returns `None` if `offset` lies
+    /// outside of the code covered by any recorded location.
+    pub fn lookup(&self, offset: AssemblyOffset) -> Option<SourceLocation> {
+        self.locations.range(..= offset.0).next_back().and_then(|(_, &location)| location)
+    }
+
+    /// Remove all locations recorded between `start` and `end`. This is used when code is overwritten.
+    /// The overwritten code is no longer covered by any location, while the code after it keeps its own.
+    pub fn remove_between(&mut self, start: usize, end: usize) {
+        if start >= end {
+            return;
+        }
+
+        let following = self.lookup(AssemblyOffset(end));
+        let mut removed = self.locations.split_off(&start);
+        let mut rest = removed.split_off(&end);
+        self.locations.append(&mut rest);
+
+        self.locations.insert(start, None);
+        if let Some(location) = following {
+            self.locations.entry(end).or_insert(Some(location));
+        }
+    }
+
+    /// Add all locations recorded in `other` to this source map. The end of a sequence of instructions in
+    /// `other` does not replace a location recorded at the same offset in this source map.
+    pub fn append(&mut self, other: &mut SourceMap) {
+        for (offset, location) in mem::take(&mut other.locations) {
+            if location.is_some() {
+                self.locations.insert(offset, location);
+            } else {
+                self.locations.entry(offset).or_insert(None);
+            }
+        }
+    }
+
+    /// Move all locations recorded at or after `start` to the offset returned by `f`. This is used when
+    /// code gets relaxed.
+    pub fn remap<F>(&mut self, start: usize, f: F) where F: Fn(AssemblyOffset) -> AssemblyOffset {
+        let locations = self.locations.split_off(&start);
+        for (offset, location) in locations {
+            self.locations.insert(f(AssemblyOffset(offset)).0, location);
+        }
+    }
+}
+
+
 #[derive(Clone, Debug)]
 enum LitPoolEntry {
     U8(u8),
@@ -494,14 +927,23 @@ impl LitPool {
         }
     }
 
+    // values follow the byte order of the target architecture
+    fn emit_bytes<D: DynasmLabelApi>(le_bytes: &[u8], be_bytes: &[u8], assembler: &mut D) {
+        if D::Relocation::big_endian() {
+            assembler.extend(be_bytes);
+        } else {
+            assembler.extend(le_bytes);
+        }
+    }
+
     /// Emit this literal pool into the specified assembler
     pub fn emit<D: DynasmLabelApi>(self, assembler: &mut D) {
         for entry in self.entries {
             match entry {
                 LitPoolEntry::U8(value) => assembler.push(value),
-                LitPoolEntry::U16(value) => assembler.push_u16(value),
-                LitPoolEntry::U32(value) => assembler.push_u32(value),
-                LitPoolEntry::U64(value) => assembler.push_u64(value),
+                LitPoolEntry::U16(value) => Self::emit_bytes(&value.to_le_bytes(), &value.to_be_bytes(), assembler),
+                LitPoolEntry::U32(value) => Self::emit_bytes(&value.to_le_bytes(), &value.to_be_bytes(), assembler),
+                LitPoolEntry::U64(value) => Self::emit_bytes(&value.to_le_bytes(), &value.to_be_bytes(), assembler),
                 LitPoolEntry::Dynamic(size, id) => {
                     Self::pad_sized(size, assembler);
                     assembler.dynamic_relocation(id, 0, D::Relocation::from_size(size));
@@ -532,25 +974,53 @@ mod tests {
 
     #[test]
     fn test_litpool_size() {
-        test_litpool::<RelocationSize>();
+        test_litpool::<RelocationSize>(LITPOOL_LE);
     }
 
     #[test]
     fn test_litpool_x64() {
-        test_litpool::<x64::X64Relocation>();
+        test_litpool::<x64::X64Relocation>(LITPOOL_LE);
     }
 
     #[test]
     fn test_litpool_x86() {
-        test_litpool::<x86::X86Relocation>();
+        test_litpool::<x86::X86Relocation>(LITPOOL_LE);
+    }
+
+    #[test]
+    fn test_litpool_x86_16() {
+        test_litpool::<x86_16::X86_16Relocation>(LITPOOL_LE);
     }
 
     #[test]
     fn test_litpool_aarch64() {
-        test_litpool::<aarch64::Aarch64Relocation>();
+        test_litpool::<aarch64::Aarch64Relocation>(LITPOOL_LE);
     }
 
-    fn test_litpool<R: Relocation + Debug>() {
+    #[test]
+    fn test_litpool_aarch64_be() {
+        test_litpool::<aarch64_be::Aarch64BeRelocation>(LITPOOL_BE);
+    }
+
+    const LITPOOL_LE: &[u8] = &[
+        0x12, 0x34, 0x56, 0x00, 0x9A, 0x78, 0x00, 0x00,
+        0x12, 0xF0, 0xDE, 0xBC, 0x00, 0x00, 0x00, 0x00,
+        0x12, 0xF0, 0xDE, 0xBC, 0x9A, 0x78, 0x56, 0x34,
+        24  , 0xCC, 0xCC, 0xCC, 20  , 0   , 0x00, 0x00,
+        16  , 0   , 0   , 0   , 0x00, 0x00, 0x00, 0x00,
+        0xD8, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF,
+    ];
+
+    const LITPOOL_BE: &[u8] = &[
+        0x12, 0x34, 0x56, 0x00, 0x78, 0x9A, 0x00, 0x00,
+        0xBC, 0xDE, 0xF0, 0x12, 0x00, 0x00, 0x00, 0x00,
+        0x34, 0x56, 0x78, 0x9A, 0xBC, 0xDE, 0xF0, 0x12,
+        24  , 0xCC, 0xCC, 0xCC, 0   , 20  , 0x00, 0x00,
+        0   , 0   , 0   , 16  , 0x00, 0x00, 0x00, 0x00,
+        0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xD8,
+    ];
+
+    fn test_litpool<R: Relocation + Debug>(expected: &[u8]) {
         let mut ops = Assembler::<R>::new().unwrap();
         let dynamic1 = ops.new_dynamic_label();
 
@@ -589,13 +1059,35 @@ mod tests {
         assert_eq!(ops.commit(), Ok(()));
         let buf = ops.finalize().unwrap();
 
-        assert_eq!(&*buf, &[
-            0x12, 0x34, 0x56, 0x00, 0x9A, 0x78, 0x00, 0x00,
-            0x12, 0xF0, 0xDE, 0xBC, 0x00, 0x00, 0x00, 0x00,
-            0x12, 0xF0, 0xDE, 0xBC, 0x9A, 0x78, 0x56, 0x34,
-            24  , 0xCC, 0xCC, 0xCC, 20  , 0   , 0x00, 0x00,
-            16  , 0   , 0   , 0   , 0x00, 0x00, 0x00, 0x00,
-            0xD8, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFFu8, 
-        ] as &[u8]);
+        assert_eq!(&*buf, expected);
+    }
+
+    #[test]
+    fn test_litpool_wide_values() {
+        test_litpool_wide::<x64::X64Relocation>(&[
+            0xFF, 0xFF, 0xFF, 0xFF, 0x00, 0x00, 0x00, 0x00,
+            0x10, 0x32, 0x54, 0x76, 0x98, 0xBA, 0xDC, 0xFE,
+            0x00, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00,
+        ]);
+        test_litpool_wide::<aarch64_be::Aarch64BeRelocation>(&[
+            0xFF, 0xFF, 0xFF, 0xFF, 0x00, 0x00, 0x00, 0x00,
+            0xFE, 0xDC, 0xBA, 0x98, 0x76, 0x54, 0x32, 0x10,
+            0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00,
+        ]);
+    }
+
+    // values above u32::MAX and with the top bit set are emitted unchanged
+    fn test_litpool_wide<R: Relocation + Debug>(expected: &[u8]) {
+        let mut ops = Assembler::<R>::new().unwrap();
+        let mut pool = components::LitPool::new();
+
+        assert_eq!(pool.push_u32(0xFFFF_FFFF), 0);
+        assert_eq!(pool.push_u64(0xFEDC_BA98_7654_3210), 8);
+        assert_eq!(pool.push_u64(0x1_0000_0000), 16);
+
+        pool.emit(&mut ops);
+
+        let buf = ops.finalize().unwrap();
+        assert_eq!(&*buf, expected);
     }
 }
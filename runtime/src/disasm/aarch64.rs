@@ -0,0 +1,716 @@
+//! Decoding of aarch64 instructions. An instruction word is matched against the fixed bits of every
+//! instruction form, after which the commands of the form are inverted to recover its operands.
+
+use std::slice;
+use std::sync::OnceLock;
+
+use crate::encoder::aarch64::{encode_form, instructions, sysregs, special_idents, Opdata, Matcher, Command, SpecialComm,
+                              Relocation, SysRegAccess};
+use crate::encoder::aarch64::{Operand, Register, Vector, MemoryRef, Modifier, Size};
+
+use super::format_int;
+
+
+const CONDITIONS: [&str; 16] = ["eq", "ne", "hs", "lo", "mi", "pl", "vs", "vc", "hi", "ls", "ge", "lt", "gt", "le", "al", "nv"];
+const ROTATES: [Modifier; 4] = [Modifier::LSL, Modifier::LSR, Modifier::ASR, Modifier::ROR];
+const EXTENDS: [Modifier; 8] = [Modifier::UXTB, Modifier::UXTH, Modifier::UXTW, Modifier::UXTX,
+                                Modifier::SXTB, Modifier::SXTH, Modifier::SXTW, Modifier::SXTX];
+
+// the name of an instruction, all of its forms, the form itself and the mask of the bits its operands are encoded in
+type Form = (&'static str, &'static [Opdata], &'static Opdata, u32);
+
+fn bitmask(bits: u32) -> u32 {
+    1u32.checked_shl(bits).unwrap_or(0).wrapping_sub(1)
+}
+
+// the amount of bits needed to represent value
+fn bits(value: u32) -> u32 {
+    32 - value.leading_zeros()
+}
+
+fn field(word: u32, offset: u8, bits: u32) -> u32 {
+    (word >> offset) & bitmask(bits)
+}
+
+fn sign_extend(value: u32, bits: u32) -> i64 {
+    let shift = 64 - bits;
+    (i64::from(value) << shift) >> shift
+}
+
+/// the bits of an instruction word a command encodes into
+fn command_mask(command: &Command) -> u32 {
+    match *command {
+        Command::R(offset)
+        | Command::REven(offset)
+        | Command::RNoZr(offset) => bitmask(5) << offset,
+        Command::R4(offset) => bitmask(4) << offset,
+        Command::R3(offset) => bitmask(3) << offset,
+        Command::Ubits(offset, len)
+        | Command::Uscaled(offset, len, _)
+        | Command::Usub(offset, len, _)
+        | Command::Unegmod(offset, len)
+        | Command::Usumdec(offset, len)
+        | Command::Sbits(offset, len)
+        | Command::Sscaled(offset, len, _)
+        | Command::Smultiple(offset, len, _)
+        | Command::Uslice(offset, len, _)
+        | Command::Sslice(offset, len, _)
+        | Command::Unegslice(offset, len, _) => bitmask(u32::from(len)) << offset,
+        Command::Ulist(offset, options) => bitmask(bits(options.len() as u32 - 1)) << offset,
+        Command::Urange(offset, min, max) => bitmask(bits(u32::from(max - min))) << offset,
+        Command::Ufields(fields) => fields.iter().fold(0, |mask, &f| mask | 1 << f),
+        Command::Special(offset, special) => match special {
+            SpecialComm::WIDE_IMMEDIATE_W
+            | SpecialComm::WIDE_IMMEDIATE_X
+            | SpecialComm::INVERTED_WIDE_IMMEDIATE_W
+            | SpecialComm::INVERTED_WIDE_IMMEDIATE_X => bitmask(18) << offset,
+            SpecialComm::STRETCHED_IMMEDIATE
+            | SpecialComm::SPLIT_FLOAT_IMMEDIATE => (0x1F << offset) | (0xE0 << (offset + 6)),
+            SpecialComm::FLOAT_IMMEDIATE => 0xFF << offset,
+            SpecialComm::LOGICAL_IMMEDIATE_B
+            | SpecialComm::LOGICAL_IMMEDIATE_H
+            | SpecialComm::LOGICAL_IMMEDIATE_W
+            | SpecialComm::LOGICAL_IMMEDIATE_X => bitmask(13) << offset,
+        },
+        Command::Rwidth(offset) => 1 << offset,
+        Command::Rotates(offset) => bitmask(2) << offset,
+        Command::ExtendsW(offset)
+        | Command::ExtendsX(offset)
+        | Command::ExtendsZ(offset) => bitmask(3) << offset,
+        Command::Cond(offset)
+        | Command::CondInv(offset) => bitmask(4) << offset,
+        Command::LitList(offset, name) => {
+            let max = special_ident_list(name).iter().map(|&(_, value)| value).max().unwrap_or(0);
+            bitmask(bits(max)) << offset
+        },
+        Command::SysRegRead(offset)
+        | Command::SysRegWrite(offset) => bitmask(15) << offset,
+        Command::Offset(relocation) => match relocation {
+            Relocation::B => bitmask(26),
+            Relocation::BCOND => bitmask(19) << 5,
+            Relocation::ADR
+            | Relocation::ADRP => (bitmask(19) << 5) | (bitmask(2) << 29),
+            Relocation::TBZ => bitmask(14) << 5,
+            _ => 0
+        },
+        _ => 0
+    }
+}
+
+// SVE and SME operands cannot be encoded, so forms using them are not decoded either
+fn is_supported(matcher: &Matcher) -> bool {
+    !matches!(*matcher,
+        Matcher::Z(_) | Matcher::ZBare | Matcher::ZElement(_) | Matcher::ZRegList(_, _)
+        | Matcher::P(_) | Matcher::PBare | Matcher::PMerge | Matcher::PZero
+        | Matcher::ZaTile(_) | Matcher::ZaH(_) | Matcher::ZaV(_) | Matcher::ZaHList(_) | Matcher::ZaVList(_)
+        | Matcher::ZaArray | Matcher::ZaTileList | Matcher::RefMulVl | Matcher::RefIndexLsl(_)
+        | Matcher::RefZIndexLsl(_, _) | Matcher::RefZIndexExtend(_, _) | Matcher::RefZBase(_)
+        | Matcher::RefZZ(_) | Matcher::RefZZExtend(_)
+    )
+}
+
+/// All forms that can be decoded, in the order of the instruction data
+fn forms() -> &'static [Form] {
+    static FORMS: OnceLock<Vec<Form>> = OnceLock::new();
+    FORMS.get_or_init(|| {
+        let mut forms = Vec::new();
+        for &(name, all) in instructions() {
+            for data in all {
+                if data.matchers.iter().all(is_supported) {
+                    let mask = data.commands.iter().fold(0, |mask, command| mask | command_mask(command));
+                    forms.push((name, all, data, mask));
+                }
+            }
+        }
+        forms
+    })
+}
+
+fn special_ident_list(name: &str) -> &'static [(&'static str, u32)] {
+    special_idents().iter().find(|&&(list, _)| list == name).map_or(&[], |&(_, idents)| idents)
+}
+
+/// Decodes a single instruction word, returning its text and jump target.
+pub(super) fn decode(word: u32, address: usize) -> Option<(String, Option<usize>)> {
+    // Forms that encode to the same word as the encoder would pick for their text are preferred, and of
+    // those the one with the least operands, as that is the most specific alias. If there are none, a
+    // form that still encodes to the same word when it is picked explicitly is used.
+    let mut best: Option<(usize, String, Option<usize>)> = None;
+    let mut fallback = None;
+
+    for &(name, all, data, mask) in forms() {
+        if word & !mask != data.base {
+            continue;
+        }
+        let decoded = match decode_args(word, data) {
+            Some(decoded) => decoded,
+            None => continue
+        };
+
+        for (suffix, operands) in decoded.candidates(data) {
+            let verifies = |forms| encode_form(forms, suffix.as_deref(), &operands) == Some(word);
+
+            if verifies(all) {
+                if best.as_ref().is_none_or(|&(count, _, _)| operands.len() < count) {
+                    let (text, target) = finish(name, suffix.as_deref(), &operands, &decoded, address);
+                    best = Some((operands.len(), text, target));
+                }
+                break;
+            } else if fallback.is_none() && verifies(slice::from_ref(data)) {
+                fallback = Some(finish(name, suffix.as_deref(), &operands, &decoded, address));
+            }
+        }
+    }
+
+    best.map(|(_, text, target)| (text, target)).or(fallback)
+}
+
+fn finish(name: &str, suffix: Option<&str>, operands: &[Operand], decoded: &Decoded, address: usize) -> (String, Option<usize>) {
+    let mut text = name.to_string();
+    if let Some(suffix) = suffix {
+        text.push('.');
+        text.push_str(suffix);
+    }
+    for (i, operand) in operands.iter().enumerate() {
+        text.push_str(if i == 0 { " " } else { ", " });
+        text.push_str(&format_operand(operand));
+    }
+
+    let target = decoded.offset.map(|(relocation, offset)| {
+        let base = if relocation == Relocation::ADRP { address & !0xFFF } else { address };
+        base.wrapping_add(offset as usize)
+    });
+    (text, target)
+}
+
+/// A value recovered from an instruction word, in the flattened argument order of the encoder.
+#[derive(Debug, Clone)]
+enum Flat {
+    Unset,
+    Reg(u8),
+    Int(i64),
+    Float(f64),
+    Modifier(Modifier),
+    Ident(String),
+}
+
+impl Flat {
+    fn reg(&self) -> Option<u8> {
+        match *self {
+            Flat::Reg(reg) => Some(reg),
+            _ => None
+        }
+    }
+
+    fn int(&self) -> Option<i64> {
+        match *self {
+            Flat::Int(value) => Some(value),
+            Flat::Unset => Some(0),
+            _ => None
+        }
+    }
+}
+
+struct Decoded {
+    args: Vec<Flat>,
+    full_width: Option<bool>,
+    offset: Option<(Relocation, i64)>,
+}
+
+/// Inverts the commands of a form to recover the flattened arguments that were encoded.
+fn decode_args(word: u32, data: &Opdata) -> Option<Decoded> {
+    let count = data.matchers.iter().filter(|&&m| m != Matcher::End).map(Matcher::flatarg_count).sum();
+    let mut args = vec![Flat::Unset; count];
+    let mut full_width = None;
+    let mut offset = None;
+    // the arguments built from signed slices, and their total width
+    let mut signed_slices: Vec<(usize, u32)> = Vec::new();
+
+    let mut cursor = 0usize;
+    for command in data.commands {
+        let value = match *command {
+            Command::A => {
+                cursor += 1;
+                continue;
+            },
+            Command::C => {
+                cursor = cursor.checked_sub(1)?;
+                continue;
+            },
+            Command::Rwidth(offset) => {
+                full_width = Some(field(word, offset, 1) != 0);
+                continue;
+            },
+
+            Command::R(offset)
+            | Command::REven(offset)
+            | Command::RNoZr(offset) => Flat::Reg(field(word, offset, 5) as u8),
+            Command::R4(offset) => Flat::Reg(field(word, offset, 4) as u8),
+            Command::R3(offset) => Flat::Reg(field(word, offset, 3) as u8),
+            Command::RTied => Flat::Reg(args.first()?.reg()?),
+            Command::RNext => Flat::Reg((args.get(cursor.checked_sub(1)?)?.reg()? + 1) % 32),
+
+            Command::Ubits(offset, len) => Flat::Int(i64::from(field(word, offset, u32::from(len)))),
+            Command::Uscaled(offset, len, shift) => Flat::Int(i64::from(field(word, offset, u32::from(len))) << shift),
+            Command::Ulist(offset, options) => {
+                let index = field(word, offset, bits(options.len() as u32 - 1));
+                Flat::Int(i64::from(*options.get(index as usize)?))
+            },
+            Command::Urange(offset, min, max) => {
+                let value = field(word, offset, bits(u32::from(max - min)));
+                Flat::Int(i64::from(value + u32::from(min)))
+            },
+            Command::Usub(offset, len, addval) => {
+                Flat::Int(i64::from(addval) - i64::from(field(word, offset, u32::from(len))))
+            },
+            Command::Unegmod(offset, len) => {
+                let len = u32::from(len);
+                Flat::Int(i64::from(((1 << len) - field(word, offset, len)) & bitmask(len)))
+            },
+            Command::Usumdec(offset, len) => {
+                let left = args.get(cursor.checked_sub(1)?)?.int()?;
+                let value = i64::from(field(word, offset, u32::from(len))) + 1 - left;
+                Flat::Int(value.rem_euclid(1 << len))
+            },
+            Command::Usame(back) => args.get(cursor.checked_sub(usize::from(back))?)?.clone(),
+            Command::Ufields(fields) => {
+                Flat::Int(fields.iter().fold(0, |value, &f| (value << 1) | i64::from(field(word, f, 1))))
+            },
+
+            Command::Sbits(offset, len) => Flat::Int(sign_extend(field(word, offset, u32::from(len)), u32::from(len))),
+            Command::Sscaled(offset, len, shift) => {
+                Flat::Int(sign_extend(field(word, offset, u32::from(len)), u32::from(len)) << shift)
+            },
+            Command::Smultiple(offset, len, factor) => {
+                Flat::Int(sign_extend(field(word, offset, u32::from(len)), u32::from(len)) * i64::from(factor))
+            },
+
+            // slices build an argument in parts, without advancing to the next argument
+            Command::Uslice(offset, len, shift)
+            | Command::Sslice(offset, len, shift) => {
+                let part = i64::from(field(word, offset, u32::from(len))) << shift;
+                let arg = args.get_mut(cursor)?;
+                *arg = Flat::Int(arg.int()? | part);
+                if let Command::Sslice(..) = *command {
+                    signed_slices.push((cursor, u32::from(len + shift)));
+                }
+                continue;
+            },
+            Command::Unegslice(..) => return None,
+
+            // checks don't encode anything
+            Command::BUbits(_)
+            | Command::BUsum(_)
+            | Command::BSscaled(_, _)
+            | Command::BUrange(_, _) => continue,
+
+            Command::Special(offset, special) => decode_special(word, offset, special)?,
+
+            Command::Rotates(offset) => Flat::Modifier(ROTATES[field(word, offset, 2) as usize]),
+            Command::ExtendsW(offset)
+            | Command::ExtendsX(offset) => Flat::Modifier(EXTENDS[field(word, offset, 3) as usize]),
+
+            Command::Cond(offset) => Flat::Ident(CONDITIONS[field(word, offset, 4) as usize].to_string()),
+            Command::CondInv(offset) => Flat::Ident(CONDITIONS[field(word, offset, 4) as usize ^ 1].to_string()),
+            Command::LitList(offset, name) => {
+                let list = special_ident_list(name);
+                let max = list.iter().map(|&(_, value)| value).max().unwrap_or(0);
+                let value = field(word, offset, bits(max));
+                Flat::Ident(list.iter().find(|&&(_, v)| v == value)?.0.to_string())
+            },
+            Command::SysRegRead(offset) => Flat::Ident(sysreg_name(field(word, offset, 15), SysRegAccess::Write)),
+            Command::SysRegWrite(offset) => Flat::Ident(sysreg_name(field(word, offset, 15), SysRegAccess::Read)),
+
+            Command::Offset(relocation) => {
+                let value = match relocation {
+                    Relocation::B => sign_extend(field(word, 0, 26), 26) << 2,
+                    Relocation::BCOND => sign_extend(field(word, 5, 19), 19) << 2,
+                    Relocation::TBZ => sign_extend(field(word, 5, 14), 14) << 2,
+                    Relocation::ADR
+                    | Relocation::ADRP => {
+                        let value = sign_extend((field(word, 5, 19) << 2) | field(word, 29, 2), 21);
+                        if relocation == Relocation::ADRP { value << 12 } else { value }
+                    },
+                    _ => return None
+                };
+                offset = Some((relocation, value));
+                Flat::Int(value)
+            },
+
+            Command::RTile(_, _)
+            | Command::RSelect(_)
+            | Command::ExtendsZ(_) => return None,
+        };
+
+        *args.get_mut(cursor)? = value;
+        cursor += 1;
+    }
+
+    for (index, len) in signed_slices {
+        if let Flat::Int(value) = args[index] {
+            args[index] = Flat::Int(sign_extend(value as u32, len));
+        }
+    }
+
+    Some(Decoded { args, full_width, offset })
+}
+
+fn decode_special(word: u32, offset: u8, special: SpecialComm) -> Option<Flat> {
+    let wide = || {
+        let value = field(word, offset, 18);
+        (u64::from(value & 0xFFFF), (value >> 16) * 16)
+    };
+    let split = || field(word, offset, 5) | (field(word, offset + 6, 8) & 0xE0);
+
+    Some(match special {
+        SpecialComm::WIDE_IMMEDIATE_X => {
+            let (value, shift) = wide();
+            Flat::Int((value << shift) as i64)
+        },
+        SpecialComm::INVERTED_WIDE_IMMEDIATE_X => {
+            let (value, shift) = wide();
+            Flat::Int(!(value << shift) as i64)
+        },
+        SpecialComm::WIDE_IMMEDIATE_W => {
+            let (value, shift) = wide();
+            Flat::Int(i64::from((value as u32).checked_shl(shift)?))
+        },
+        SpecialComm::INVERTED_WIDE_IMMEDIATE_W => {
+            let (value, shift) = wide();
+            Flat::Int(i64::from(!(value as u32).checked_shl(shift)?))
+        },
+        SpecialComm::STRETCHED_IMMEDIATE => {
+            let bits = split();
+            let value = (0 .. 8).filter(|i| bits & (1 << i) != 0).fold(0u64, |value, i| value | (0xFF << (i * 8)));
+            Flat::Int(value as i64)
+        },
+        SpecialComm::LOGICAL_IMMEDIATE_B => Flat::Int((decode_logical_immediate(field(word, offset, 13), 32)? & 0xFF) as i64),
+        SpecialComm::LOGICAL_IMMEDIATE_H => Flat::Int((decode_logical_immediate(field(word, offset, 13), 32)? & 0xFFFF) as i64),
+        SpecialComm::LOGICAL_IMMEDIATE_W => Flat::Int(decode_logical_immediate(field(word, offset, 13), 32)? as i64),
+        SpecialComm::LOGICAL_IMMEDIATE_X => Flat::Int(decode_logical_immediate(field(word, offset, 13), 64)? as i64),
+        SpecialComm::FLOAT_IMMEDIATE => Flat::Float(decode_float_immediate(field(word, offset, 8))),
+        SpecialComm::SPLIT_FLOAT_IMMEDIATE => Flat::Float(decode_float_immediate(split())),
+    })
+}
+
+// decodes an N:immr:imms bitmask immediate
+fn decode_logical_immediate(encoded: u32, datasize: u32) -> Option<u64> {
+    let n = (encoded >> 12) & 1;
+    let immr = (encoded >> 6) & 0x3F;
+    let imms = encoded & 0x3F;
+    if datasize == 32 && n != 0 {
+        return None;
+    }
+
+    let len = bits((n << 6) | (!imms & 0x3F)).checked_sub(1)?;
+    if len < 1 {
+        return None;
+    }
+    let esize = 1u32 << len;
+    let levels = esize - 1;
+    let s = imms & levels;
+    let r = immr & levels;
+    if s == levels {
+        return None;
+    }
+
+    let emask = if esize == 64 { u64::MAX } else { (1u64 << esize) - 1 };
+    let welem = (1u64 << (s + 1)) - 1;
+    let elem = if r == 0 { welem } else { ((welem >> r) | (welem << (esize - r))) & emask };
+
+    let mut value = 0;
+    let mut i = 0;
+    while i < datasize {
+        value |= elem << i;
+        i += esize;
+    }
+    Some(value)
+}
+
+// decodes an 8-bit floating point immediate, as per VFPExpandImm
+fn decode_float_immediate(imm8: u32) -> f64 {
+    let fraction = 1.0 + f64::from(imm8 & 0xF) / 16.0;
+    let exponent = (imm8 >> 4) & 3;
+    let exponent = if imm8 & 0x40 != 0 { exponent as i32 - 3 } else { exponent as i32 + 1 };
+    let value = fraction * 2f64.powi(exponent);
+    if imm8 & 0x80 != 0 { -value } else { value }
+}
+
+// the name of a system register, or its generic s<op0>_<op1>_c<n>_c<m>_<op2> notation
+fn sysreg_name(bits: u32, excluded: SysRegAccess) -> String {
+    if let Some(&(name, _, _)) = sysregs().iter().find(|&&(_, b, access)| b == bits && access != excluded) {
+        return name.to_string();
+    }
+    format!("s{}_{}_c{}_c{}_{}", (bits >> 14) + 2, (bits >> 11) & 7, (bits >> 7) & 0xF, (bits >> 3) & 0xF, bits & 7)
+}
+
+impl Decoded {
+    /// The dotted suffixes and operand lists these arguments could have been written as, in order of preference.
+    fn candidates(&self, data: &Opdata) -> Vec<(Option<String>, Vec<Operand<'_>>)> {
+        let widths = match self.full_width {
+            Some(full) => vec![full],
+            None => vec![true, false],
+        };
+
+        let mut candidates = Vec::new();
+        for full_width in widths {
+            let (suffix, alternatives, optional) = match self.operands(data, full_width) {
+                Some(operands) => operands,
+                None => continue
+            };
+
+            // operands after an End matcher can be left out
+            let mut lengths = vec![alternatives.len()];
+            if let Some(optional) = optional {
+                lengths.insert(0, optional);
+            }
+
+            for len in lengths {
+                let alternatives = &alternatives[.. len];
+                let combinations: usize = alternatives.iter().map(Vec::len).product();
+                for mut i in 0 .. combinations.min(64) {
+                    let mut operands = Vec::new();
+                    for alternative in alternatives {
+                        operands.push(alternative[i % alternative.len()]);
+                        i /= alternative.len();
+                    }
+                    candidates.push((suffix.clone(), operands));
+                }
+            }
+        }
+        candidates
+    }
+
+    /// Rebuilds the operands from the matchers of a form, with their alternative notations. Also returns the
+    /// suffix, and the amount of operands in front of an End matcher.
+    #[allow(clippy::type_complexity)]
+    fn operands(&self, data: &Opdata, full_width: bool) -> Option<(Option<String>, Vec<Vec<Operand<'_>>>, Option<usize>)> {
+        let mut suffix = None;
+        let mut dot = false;
+        let mut optional = None;
+        let mut operands = Vec::new();
+        let mut cursor = 0;
+
+        let lanes = |size: Size| Some((if full_width { 16 } else { 8 }) / size.in_bytes());
+
+        for matcher in data.matchers {
+            match *matcher {
+                Matcher::End => {
+                    optional = Some(operands.len());
+                    continue;
+                },
+                Matcher::Dot => {
+                    dot = true;
+                    continue;
+                },
+                _ => ()
+            }
+
+            let count = matcher.flatarg_count();
+            let args = self.args.get(cursor .. cursor + count)?;
+            cursor += count;
+            let reg = |i: usize| args[i].reg();
+            let int = |i: usize| args[i].int();
+            let ident = |i: usize| match args[i] {
+                Flat::Ident(ref ident) => Some(ident.as_str()),
+                _ => None
+            };
+            let base = || reg(0).map(|n| if n == 31 { Register::XSP(31) } else { Register::X(n) });
+
+            if dot {
+                dot = false;
+                suffix = Some(match *matcher {
+                    Matcher::Lit(s) => s.to_string(),
+                    Matcher::Cond => ident(0)?.to_string(),
+                    _ => return None
+                });
+                continue;
+            }
+
+            let alternatives = match *matcher {
+                Matcher::Lit(s) => vec![Operand::Ident(s)],
+                Matcher::LitInt(v) => vec![Operand::Imm(i64::from(v))],
+                Matcher::LitFloat(v) => vec![Operand::Float(f64::from(v))],
+                Matcher::Ident
+                | Matcher::Cond => vec![Operand::Ident(ident(0)?)],
+                Matcher::Imm => vec![match args[0] {
+                    Flat::Float(value) => Operand::Float(value),
+                    ref arg => Operand::Imm(arg.int()?)
+                }],
+                Matcher::Offset => vec![Operand::Imm(int(0)?)],
+
+                Matcher::W => vec![Operand::Reg(Register::W(reg(0)?))],
+                Matcher::X => vec![Operand::Reg(Register::X(reg(0)?))],
+                Matcher::WSP => vec![Operand::Reg(Register::WSP(reg(0)?))],
+                Matcher::XSP => vec![Operand::Reg(Register::XSP(reg(0)?))],
+                Matcher::B => vec![Operand::Reg(Register::B(reg(0)?))],
+                Matcher::H => vec![Operand::Reg(Register::H(reg(0)?))],
+                Matcher::S => vec![Operand::Reg(Register::S(reg(0)?))],
+                Matcher::D => vec![Operand::Reg(Register::D(reg(0)?))],
+                Matcher::Q => vec![Operand::Reg(Register::Q(reg(0)?))],
+
+                Matcher::V(size) => vec![Operand::Vector(vector(reg(0)?, size, lanes(size), None))],
+                Matcher::VStatic(size, l) => vec![Operand::Vector(vector(reg(0)?, size, Some(l), None))],
+                Matcher::VElement(size) => vec![Operand::Vector(vector(reg(0)?, size, None, Some(int(1)? as u8)))],
+                Matcher::VElementStatic(size, element) => vec![Operand::Vector(vector(reg(0)?, size, None, Some(element)))],
+                Matcher::VStaticElement(size, l) => vec![Operand::Vector(vector(reg(0)?, size, Some(l), Some(int(1)? as u8)))],
+                Matcher::RegList(amount, size) => vec![Operand::List(vector(reg(0)?, size, lanes(size), None), amount)],
+                Matcher::RegListStatic(amount, size, l) => vec![Operand::List(vector(reg(0)?, size, Some(l), None), amount)],
+                Matcher::RegListElement(amount, size) => {
+                    vec![Operand::List(vector(reg(0)?, size, None, Some(int(1)? as u8)), amount)]
+                },
+
+                Matcher::RefBase => vec![Operand::Mem(MemoryRef::Base(base()?))],
+                Matcher::RefOffset => match int(1)? {
+                    0 => vec![Operand::Mem(MemoryRef::Base(base()?)), Operand::Mem(MemoryRef::Offset(base()?, 0))],
+                    offset => vec![Operand::Mem(MemoryRef::Offset(base()?, offset))],
+                },
+                Matcher::RefPre => vec![Operand::Mem(MemoryRef::PreIndexed(base()?, int(1)?))],
+                Matcher::RefIndex => {
+                    let modifier = match args[2] {
+                        Flat::Modifier(Modifier::UXTX) => Modifier::LSL,
+                        Flat::Modifier(modifier) => modifier,
+                        _ => Modifier::LSL
+                    };
+                    let index = match modifier {
+                        Modifier::UXTW | Modifier::SXTW => Register::W(reg(1)?),
+                        _ => Register::X(reg(1)?)
+                    };
+                    let amount = int(3)? as u32;
+                    let mut alternatives = Vec::new();
+                    if modifier == Modifier::LSL && amount == 0 {
+                        alternatives.push(MemoryRef::Indexed(base()?, index, None));
+                    }
+                    if amount == 0 {
+                        alternatives.push(MemoryRef::Indexed(base()?, index, Some((modifier, None))));
+                    }
+                    alternatives.push(MemoryRef::Indexed(base()?, index, Some((modifier, Some(amount)))));
+                    alternatives.into_iter().map(Operand::Mem).collect()
+                },
+
+                Matcher::Mod(allowed) => {
+                    let modifier = match args[0] {
+                        Flat::Modifier(modifier) => modifier,
+                        _ => Modifier::LSL
+                    };
+                    let amount = int(1)? as u32;
+                    let mut alternatives = Vec::new();
+                    for modifier in [modifier, Modifier::LSL] {
+                        if !allowed.contains(&modifier) {
+                            continue;
+                        }
+                        if amount == 0 {
+                            alternatives.push(Operand::Modifier(modifier, None));
+                        }
+                        alternatives.push(Operand::Modifier(modifier, Some(amount)));
+                    }
+                    alternatives
+                },
+                Matcher::LitMod(modifier) => match args[0] {
+                    Flat::Unset => vec![Operand::Modifier(modifier, None), Operand::Modifier(modifier, Some(0))],
+                    ref arg => vec![Operand::Modifier(modifier, Some(arg.int()? as u32))],
+                },
+
+                _ => return None
+            };
+            if alternatives.is_empty() {
+                return None;
+            }
+            operands.push(alternatives);
+        }
+
+        Some((suffix, operands, optional))
+    }
+}
+
+fn vector(reg: u8, element_size: Size, lanes: Option<u8>, element: Option<u8>) -> Vector {
+    Vector { reg, element_size, lanes, element }
+}
+
+fn register_name(reg: Register) -> String {
+    match reg {
+        Register::W(31) => "wzr".to_string(),
+        Register::X(31) => "xzr".to_string(),
+        Register::WSP(31) => "wsp".to_string(),
+        Register::XSP(31) => "sp".to_string(),
+        Register::W(n) | Register::WSP(n) => format!("w{}", n),
+        Register::X(n) | Register::XSP(n) => format!("x{}", n),
+        Register::B(n) => format!("b{}", n),
+        Register::H(n) => format!("h{}", n),
+        Register::S(n) => format!("s{}", n),
+        Register::D(n) => format!("d{}", n),
+        Register::Q(n) => format!("q{}", n),
+    }
+}
+
+fn vector_name(reg: u8, v: &Vector) -> String {
+    let size = match v.element_size {
+        Size::Byte => "b",
+        Size::Word => "h",
+        Size::DWord => "s",
+        Size::QWord => "d",
+        Size::OWord => "q",
+    };
+    match v.lanes {
+        Some(lanes) => format!("v{}.{}{}", reg, size, lanes),
+        None => format!("v{}.{}", reg, size),
+    }
+}
+
+fn modifier_name(modifier: Modifier) -> &'static str {
+    match modifier {
+        Modifier::LSL => "lsl",
+        Modifier::LSR => "lsr",
+        Modifier::ASR => "asr",
+        Modifier::ROR => "ror",
+        Modifier::SXTX => "sxtx",
+        Modifier::SXTW => "sxtw",
+        Modifier::SXTH => "sxth",
+        Modifier::SXTB => "sxtb",
+        Modifier::UXTX => "uxtx",
+        Modifier::UXTW => "uxtw",
+        Modifier::UXTH => "uxth",
+        Modifier::UXTB => "uxtb",
+        Modifier::MSL => "msl",
+        Modifier::MUL => "mul",
+    }
+}
+
+fn format_modifier(modifier: Modifier, amount: Option<u32>) -> String {
+    match amount {
+        Some(amount) => format!("{} {}", modifier_name(modifier), amount),
+        None => modifier_name(modifier).to_string(),
+    }
+}
+
+fn format_operand(operand: &Operand) -> String {
+    match *operand {
+        Operand::Reg(reg) => register_name(reg),
+        Operand::Vector(ref v) => match v.element {
+            Some(element) => format!("{}[{}]", vector_name(v.reg, v), element),
+            None => vector_name(v.reg, v),
+        },
+        Operand::List(ref first, count) => {
+            let regs: Vec<String> = (0 .. count).map(|i| vector_name((first.reg + i) % 32, first)).collect();
+            match first.element {
+                Some(element) => format!("{{{}}}[{}]", regs.join(", "), element),
+                None => format!("{{{}}}", regs.join(", ")),
+            }
+        },
+        Operand::Mem(mem) => match mem {
+            MemoryRef::Base(base) => format!("[{}]", register_name(base)),
+            MemoryRef::Offset(base, offset) => format!("[{}, {}]", register_name(base), format_int(offset)),
+            MemoryRef::PreIndexed(base, offset) => format!("[{}, {}]!", register_name(base), format_int(offset)),
+            MemoryRef::Indexed(base, index, None) => format!("[{}, {}]", register_name(base), register_name(index)),
+            MemoryRef::Indexed(base, index, Some((modifier, amount))) => {
+                format!("[{}, {}, {}]", register_name(base), register_name(index), format_modifier(modifier, amount))
+            },
+        },
+        Operand::Imm(value) => format_int(value),
+        Operand::Float(value) => format!("{:?}", value),
+        Operand::Modifier(modifier, amount) => format_modifier(modifier, amount),
+        Operand::Ident(ident) => ident.to_string(),
+        // the disassembler does not produce label operands
+        Operand::Label(_) => unreachable!(),
+    }
+}
@@ -0,0 +1,116 @@
+//! A disassembler for x64 and aarch64 machine code. It decodes instructions back to text in the same dialect
+//! as the `dynasm!` macro, using the same instruction data as the runtime encoders in the `encoder` module.
+//!
+//! Every decoded instruction is checked by encoding it again. Instructions are preferably shown in the form
+//! the encoder picks for their text, so that assembling the text with the `text` module reproduces the
+//! decoded bytes. Relative jumps and references are shown with the displacement that was encoded, like
+//! `jmp 0x10`, while the address they point to is available as `Instruction::target`. Bytes that cannot be
+//! decoded are shown as `.byte` (x64) or `.dword` (aarch64) directives.
+
+mod x64;
+mod aarch64;
+
+use std::fmt;
+
+
+/// A single decoded instruction.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Instruction {
+    /// The address of the instruction: the address the code was disassembled at plus its offset in the code
+    pub address: usize,
+    /// The bytes the instruction was decoded from
+    pub bytes: Vec<u8>,
+    /// The instruction, in the syntax used by `dynasm!`
+    pub text: String,
+    /// The address a relative jump or reference in this instruction points to
+    pub target: Option<usize>,
+}
+
+impl fmt::Display for Instruction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&self.text)
+    }
+}
+
+/// Disassemble x64 machine code. `address` is the address of the first byte of `code`, which is used to
+/// calculate the addresses of instructions and jump targets.
+pub fn disassemble_x64(code: &[u8], address: usize) -> Vec<Instruction> {
+    let mut instructions = Vec::new();
+    let mut offset = 0;
+
+    while offset < code.len() {
+        let address = address.wrapping_add(offset);
+        let (len, text, target) = match x64::decode(&code[offset ..], address) {
+            Some(decoded) => decoded,
+            None => (1, format!(".byte 0x{:02X}", code[offset]), None)
+        };
+        instructions.push(Instruction {
+            address,
+            bytes: code[offset .. offset + len].to_vec(),
+            text,
+            target
+        });
+        offset += len;
+    }
+
+    instructions
+}
+
+/// Disassemble little-endian aarch64 machine code. `address` is the address of the first byte of `code`,
+/// which is used to calculate the addresses of instructions and jump targets.
+pub fn disassemble_aarch64(code: &[u8], address: usize) -> Vec<Instruction> {
+    let mut instructions = Vec::new();
+
+    for (i, chunk) in code.chunks(4).enumerate() {
+        let address = address.wrapping_add(i * 4);
+
+        if chunk.len() < 4 {
+            for (j, &byte) in chunk.iter().enumerate() {
+                instructions.push(Instruction {
+                    address: address.wrapping_add(j),
+                    bytes: vec![byte],
+                    text: format!(".byte 0x{:02X}", byte),
+                    target: None
+                });
+            }
+            break;
+        }
+
+        let word = u32::from_le_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]);
+        let (text, target) = match aarch64::decode(word, address) {
+            Some(decoded) => decoded,
+            None => (format!(".dword 0x{:08X}", word), None)
+        };
+        instructions.push(Instruction {
+            address,
+            bytes: chunk.to_vec(),
+            text,
+            target
+        });
+    }
+
+    instructions
+}
+
+/// Disassemble machine code for the architecture this crate was compiled for.
+#[cfg(target_arch = "x86_64")]
+pub fn disassemble(code: &[u8], address: usize) -> Vec<Instruction> {
+    disassemble_x64(code, address)
+}
+
+/// Disassemble machine code for the architecture this crate was compiled for.
+#[cfg(target_arch = "aarch64")]
+pub fn disassemble(code: &[u8], address: usize) -> Vec<Instruction> {
+    disassemble_aarch64(code, address)
+}
+
+// small values are shown in decimal, everything else in hexadecimal
+fn format_int(value: i64) -> String {
+    if (-9 ..= 9).contains(&value) {
+        value.to_string()
+    } else if value < 0 {
+        format!("-0x{:X}", value.unsigned_abs())
+    } else {
+        format!("0x{:X}", value)
+    }
+}
@@ -0,0 +1,629 @@
+//! Decoding of x64 instructions. Candidate instruction forms are looked up by their opcode, after which
+//! their operands are decoded as the format string of the form describes them.
+
+use std::collections::HashMap;
+use std::convert::TryFrom;
+use std::slice;
+use std::sync::OnceLock;
+
+use crate::encoder::x64::{encode_form, extract_args, instructions, FormatStringIterator, Opdata};
+use crate::encoder::x64::{Operand, MemoryRef, Register, Size, Prefix};
+use dynasm_data::x64::flags::{VEX_OP, XOP_OP, IMM_OP, AUTO_SIZE, AUTO_NO32, AUTO_REXW, AUTO_VEXL, PREF_F0, PREF_F2, PREF_F3,
+                                REPE, SHORT_ARG, X86_ONLY, EVEX_OP};
+
+use super::format_int;
+
+
+// the opcode maps an instruction can be encoded in
+const LEGACY: u8 = 0;
+const VEX: u8 = 1;
+const XOP: u8 = 2;
+
+// (opcode map kind, VEX/XOP map, first opcode byte, second opcode byte) of an opcode. The second byte is only
+// used after a 0x0F escape
+type OpcodeKey = (u8, u8, u8, u8);
+
+// the name of an instruction, all of its forms and the form itself
+type Form = (&'static str, &'static [Opdata], &'static Opdata);
+
+// the opcode bytes of a form, without VEX/XOP map select or an opcode byte that follows the displacement
+fn opcode(data: &'static Opdata) -> &'static [u8] {
+    let mut ops = data.ops;
+    if data.flags & (VEX_OP | XOP_OP) != 0 {
+        ops = &ops[1 ..];
+    }
+    if data.flags & IMM_OP != 0 {
+        ops = &ops[.. ops.len() - 1];
+    }
+    ops
+}
+
+fn key(kind: u8, map: u8, ops: &[u8]) -> OpcodeKey {
+    if kind == LEGACY && ops[0] == 0x0F && ops.len() > 1 {
+        (kind, map, ops[0], ops[1])
+    } else {
+        (kind, map, ops[0], 0)
+    }
+}
+
+/// All forms that can be encoded in 64-bit mode, by their opcode
+fn forms() -> &'static HashMap<OpcodeKey, Vec<Form>> {
+    static FORMS: OnceLock<HashMap<OpcodeKey, Vec<Form>>> = OnceLock::new();
+    FORMS.get_or_init(|| {
+        let mut forms: HashMap<OpcodeKey, Vec<Form>> = HashMap::new();
+
+        for &(name, all) in instructions() {
+            for data in all {
+                let ops = opcode(data);
+                if data.flags & (X86_ONLY | EVEX_OP) != 0 || ops.is_empty() {
+                    continue;
+                }
+
+                let (kind, map) = if data.flags & VEX_OP != 0 {
+                    (VEX, data.ops[0])
+                } else if data.flags & XOP_OP != 0 {
+                    (XOP, data.ops[0])
+                } else {
+                    (LEGACY, 0)
+                };
+
+                // forms with a register in the last opcode byte are registered under every possible opcode
+                let mut keys = vec![key(kind, map, ops)];
+                if data.flags & SHORT_ARG != 0 {
+                    let mut ops = ops.to_vec();
+                    for _ in 1 .. 8 {
+                        *ops.last_mut().unwrap() += 1;
+                        keys.push(key(kind, map, &ops));
+                    }
+                    keys.dedup();
+                }
+                for key in keys {
+                    forms.entry(key).or_default().push((name, all, data));
+                }
+            }
+        }
+
+        forms
+    })
+}
+
+/// The prefixes in front of the opcode.
+struct Prefixes {
+    kind: u8,
+    map: u8,
+    // the group 1 prefix (lock, rep and repne), if it was a legacy prefix
+    group1: Option<u8>,
+    segment: Option<Prefix>,
+    // 0x66, or the equivalent VEX/XOP pp field
+    size: bool,
+    address: bool,
+    rex: bool,
+    r: u8,
+    x: u8,
+    b: u8,
+    w: bool,
+    vvvv: u8,
+    l: bool,
+}
+
+fn decode_prefixes(code: &[u8], pos: &mut usize) -> Option<Prefixes> {
+    let mut prefixes = Prefixes {
+        kind: LEGACY,
+        map: 0,
+        group1: None,
+        segment: None,
+        size: false,
+        address: false,
+        rex: false,
+        r: 0,
+        x: 0,
+        b: 0,
+        w: false,
+        vvvv: 0,
+        l: false,
+    };
+
+    // legacy prefixes
+    loop {
+        match *code.get(*pos)? {
+            byte @ (0xF0 | 0xF2 | 0xF3) => prefixes.group1 = Some(byte),
+            0x26 => prefixes.segment = Some(Prefix::Es),
+            0x2E => prefixes.segment = Some(Prefix::Cs),
+            0x36 => prefixes.segment = Some(Prefix::Ss),
+            0x3E => prefixes.segment = Some(Prefix::Ds),
+            0x64 => prefixes.segment = Some(Prefix::Fs),
+            0x65 => prefixes.segment = Some(Prefix::Gs),
+            0x66 => prefixes.size = true,
+            0x67 => prefixes.address = true,
+            _ => break
+        }
+        *pos += 1;
+    }
+
+    let byte = *code.get(*pos)?;
+
+    // rex prefix
+    if byte & 0xF0 == 0x40 {
+        prefixes.rex = true;
+        prefixes.w = byte & 8 != 0;
+        prefixes.r = (byte >> 2) & 1;
+        prefixes.x = (byte >> 1) & 1;
+        prefixes.b = byte & 1;
+        *pos += 1;
+
+    // VEX/XOP prefixes. 0x8F is only a XOP prefix if it selects a XOP map
+    } else if byte == 0xC4 || byte == 0xC5 || (byte == 0x8F && code.get(*pos + 1)? & 0x1F >= 8) {
+        let (byte1, byte2) = if byte == 0xC5 {
+            let byte1 = *code.get(*pos + 1)?;
+            *pos += 2;
+            (0x61 | (byte1 & 0x80), byte1 & 0x7F)
+        } else {
+            let bytes = (*code.get(*pos + 1)?, *code.get(*pos + 2)?);
+            *pos += 3;
+            bytes
+        };
+
+        prefixes.kind = if byte == 0x8F { XOP } else { VEX };
+        prefixes.map = byte1 & 0x1F;
+        prefixes.r = (!byte1 >> 7) & 1;
+        prefixes.x = (!byte1 >> 6) & 1;
+        prefixes.b = (!byte1 >> 5) & 1;
+        prefixes.w = byte2 & 0x80 != 0;
+        prefixes.vvvv = (!byte2 >> 3) & 0xF;
+        prefixes.l = byte2 & 4 != 0;
+
+        // the operand size prefix is part of the VEX/XOP prefix, the other prefixes are verified by encoding
+        prefixes.size = byte2 & 3 == 1;
+        prefixes.group1 = None;
+    }
+
+    Some(prefixes)
+}
+
+/// Decodes a single instruction, returning its length, text and jump target.
+pub(super) fn decode(code: &[u8], address: usize) -> Option<(usize, String, Option<usize>)> {
+    let mut pos = 0;
+    let prefixes = decode_prefixes(code, &mut pos)?;
+
+    let first = *code.get(pos)?;
+    let second = if prefixes.kind == LEGACY && first == 0x0F { *code.get(pos + 1)? } else { 0 };
+    let candidates = forms().get(&(prefixes.kind, prefixes.map, first, second))?;
+
+    // Forms that encode to the same bytes as the encoder would pick for their text are preferred. If
+    // there are none, a form that still encodes to the same bytes when it is picked explicitly is used.
+    let mut fallback = None;
+
+    for &(name, all, data) in candidates {
+        let ops = opcode(data);
+        let matches = code.get(pos .. pos + ops.len()).is_some_and(|bytes| {
+            let (last, head) = ops.split_last().unwrap();
+            let mask = if data.flags & SHORT_ARG != 0 { 0xF8 } else { 0xFF };
+            bytes.starts_with(head) && bytes[ops.len() - 1] & mask == *last
+        });
+        if !matches {
+            continue;
+        }
+
+        let instruction_prefixes = instruction_prefixes(data, &prefixes);
+
+        for op_size in operand_sizes(data, &prefixes) {
+            let (mut operands, displacement) = match decode_operands(code, pos + ops.len(), data, &prefixes, op_size) {
+                Some(decoded) => decoded,
+                None => continue
+            };
+
+            // memory operands are shown without their size if a register operand has the same size
+            let sized = operands.clone();
+            let register_sizes: Vec<Size> = operands.iter().filter_map(|operand| match *operand {
+                Operand::Reg(reg) => Some(reg.size()),
+                _ => None
+            }).collect();
+            for operand in operands.iter_mut() {
+                if let Operand::Mem(ref mut mem) = *operand {
+                    if mem.size.is_some_and(|size| register_sizes.contains(&size)) {
+                        mem.size = None;
+                    }
+                }
+            }
+
+            for operands in [operands, sized] {
+                let verifies = |forms| encode_form(forms, &instruction_prefixes, &operands)
+                    .filter(|bytes| code.starts_with(bytes));
+
+                if let Some(bytes) = verifies(all) {
+                    return Some(finish(name, &instruction_prefixes, &operands, displacement, bytes.len(), address));
+                } else if fallback.is_none() {
+                    if let Some(bytes) = verifies(slice::from_ref(data)) {
+                        fallback = Some(finish(name, &instruction_prefixes, &operands, displacement, bytes.len(), address));
+                    }
+                }
+            }
+        }
+    }
+
+    fallback
+}
+
+fn finish(name: &str, prefixes: &[Prefix], operands: &[Operand], displacement: Option<i64>, len: usize, address: usize)
+-> (usize, String, Option<usize>) {
+    let mut text = String::new();
+    for &prefix in prefixes {
+        text.push_str(prefix_name(prefix));
+        text.push(' ');
+    }
+    text.push_str(name);
+    for (i, operand) in operands.iter().enumerate() {
+        text.push_str(if i == 0 { " " } else { ", " });
+        text.push_str(&format_operand(operand));
+    }
+
+    let target = displacement.map(|disp| address.wrapping_add(len).wrapping_add(disp as usize));
+    (len, text, target)
+}
+
+// the prefixes that have to be given to the encoder, i.e. those that are not implied by the form
+fn instruction_prefixes(data: &Opdata, prefixes: &Prefixes) -> Vec<Prefix> {
+    let mut result = Vec::new();
+
+    if let Some(byte) = prefixes.group1 {
+        let (flag, prefix) = match byte {
+            0xF0 => (PREF_F0, Prefix::Lock),
+            0xF2 => (PREF_F2, Prefix::Repne),
+            _ => (PREF_F3, if data.flags & REPE != 0 { Prefix::Repe } else { Prefix::Rep }),
+        };
+        if data.flags & flag == 0 {
+            result.push(prefix);
+        }
+    }
+    if let Some(prefix) = prefixes.segment {
+        result.push(prefix);
+    }
+
+    result
+}
+
+// the operand sizes a form could have been encoded with
+fn operand_sizes(data: &Opdata, prefixes: &Prefixes) -> Vec<Option<Size>> {
+    let size = if data.flags & AUTO_VEXL != 0 {
+        if prefixes.l { Size::HWord } else { Size::OWord }
+    } else if data.flags & AUTO_REXW != 0 {
+        if prefixes.w { Size::QWord } else { Size::DWord }
+    } else if data.flags & AUTO_NO32 != 0 {
+        if prefixes.size { Size::Word } else { Size::QWord }
+    } else if data.flags & AUTO_SIZE != 0 {
+        if prefixes.w { Size::QWord } else if prefixes.size { Size::Word } else { Size::DWord }
+    } else if data.args.chunks(2).any(|arg| arg[1] == b'*') {
+        return [Size::Word, Size::DWord, Size::QWord, Size::OWord, Size::HWord].iter().map(|&s| Some(s)).collect();
+    } else {
+        return vec![None];
+    };
+    vec![Some(size)]
+}
+
+fn arg_size(fsize: u8, op_size: Option<Size>) -> Option<Size> {
+    Some(match fsize {
+        b'b' => Size::Byte,
+        b'w' => Size::Word,
+        b'd' => Size::DWord,
+        b'q' => Size::QWord,
+        b'f' => Size::FWord,
+        b'p' => Size::PWord,
+        b'o' => Size::OWord,
+        b'h' => Size::HWord,
+        b'z' => Size::ZWord,
+        b'*' => return op_size,
+        _ => return None
+    })
+}
+
+// the register of the family described by a format string code
+fn register(code: u8, size: Option<Size>, num: u8, rex: bool) -> Option<Register> {
+    Some(match code {
+        b'r' | b'v' => match size? {
+            Size::Byte if !rex && (4 .. 8).contains(&num) => Register::Rh(num),
+            Size::Byte => Register::Rb(num),
+            Size::Word => Register::Rw(num),
+            Size::DWord => Register::Rd(num),
+            Size::QWord => Register::Rq(num),
+            _ => return None
+        },
+        b'x' | b'u' => Register::Rm(num),
+        b'y' | b'w' | b'k' | b'l' => match size? {
+            Size::OWord => Register::Rx(num),
+            Size::HWord => Register::Ry(num),
+            _ => return None
+        },
+        b'f' => Register::Rf(num),
+        b's' => Register::Rs(num),
+        b'c' => Register::RC(num),
+        b'd' => Register::RD(num),
+        b'b' => Register::RB(num),
+        b'j' => Register::Rk(num),
+        b't' => Register::Rt(num),
+        _ => return None
+    })
+}
+
+// registers that are not encoded, but implied by the format string
+fn fixed_register(code: u8, size: Option<Size>) -> Option<Register> {
+    Some(match code {
+        b'A' ..= b'P' => {
+            let num = code - b'A';
+            match size? {
+                Size::Byte => Register::Rb(num),
+                Size::Word => Register::Rw(num),
+                Size::DWord => Register::Rd(num),
+                Size::QWord => Register::Rq(num),
+                _ => return None
+            }
+        },
+        b'Q' ..= b'V' => Register::Rs(code - b'Q'),
+        b'W' => Register::RC(8),
+        b'X' => Register::Rf(0),
+        _ => return None
+    })
+}
+
+fn read_signed(code: &[u8], pos: &mut usize, size: Size) -> Option<i64> {
+    let len = usize::from(size.in_bytes());
+    let bytes = code.get(*pos .. *pos + len)?;
+    *pos += len;
+
+    let mut buf = [0u8; 8];
+    buf[.. len].copy_from_slice(bytes);
+    let shift = 64 - 8 * len as u32;
+    Some(i64::from_le_bytes(buf) << shift >> shift)
+}
+
+/// Decodes the operands of the form `data` from the bytes following its opcode. Returns them together with
+/// the displacement of a relative jump, if any.
+fn decode_operands(code: &[u8], mut pos: usize, data: &Opdata, prefixes: &Prefixes, op_size: Option<Size>)
+-> Option<(Vec<Operand>, Option<i64>)> {
+    let fmt: Vec<(u8, u8)> = FormatStringIterator::new(data.args).collect();
+    let mut operands: Vec<Option<Operand>> = vec![None; fmt.len()];
+    let mut displacement = None;
+
+    // figure out where every operand is encoded
+    let (m, r, v, i, immediates) = extract_args(data, (0 .. fmt.len()).collect()).ok()?;
+    let short = data.flags & SHORT_ARG != 0;
+    let size = |index: usize| arg_size(fmt[index].1, op_size);
+
+    for (index, &(code, _)) in fmt.iter().enumerate() {
+        if code.is_ascii_uppercase() {
+            operands[index] = Some(Operand::Reg(fixed_register(code, size(index))?));
+        }
+    }
+
+    // a register in the last opcode byte
+    if let Some(m) = m.filter(|_| short) {
+        let num = (code[pos - 1] & 7) | prefixes.b << 3;
+        operands[m] = Some(Operand::Reg(register(fmt[m].0, size(m), num, prefixes.rex)?));
+    }
+
+    // the ModRM byte
+    if (m.is_some() && !short) || r.is_some() {
+        let modrm = *code.get(pos)?;
+        pos += 1;
+        let mode = modrm >> 6;
+        let reg = (modrm >> 3) & 7;
+        let rm = modrm & 7;
+
+        if let Some(r) = r {
+            let num = reg | prefixes.r << 3;
+            operands[r] = Some(Operand::Reg(register(fmt[r].0, size(r), num, prefixes.rex)?));
+        }
+
+        if let Some(m) = m.filter(|_| !short) {
+            let (code_char, fsize) = fmt[m];
+            operands[m] = Some(if mode == 0b11 {
+                if let b'm' | b'k' | b'l' = code_char {
+                    return None;
+                }
+                Operand::Reg(register(code_char, size(m), rm | prefixes.b << 3, prefixes.rex)?)
+            } else {
+                if let b'r' | b'x' | b'y' = code_char {
+                    return None;
+                }
+                let vsib = if let b'k' | b'l' = code_char { size(m) } else { None };
+                let mut mem = decode_memory(code, &mut pos, mode, rm, prefixes, vsib)?;
+                if vsib.is_none() {
+                    mem.size = arg_size(fsize, op_size);
+                }
+                Operand::Mem(mem)
+            });
+        }
+    }
+
+    // an opcode byte following the displacement
+    if data.flags & IMM_OP != 0 {
+        pos += 1;
+    }
+
+    // the register in the VEX/XOP vvvv field
+    if let Some(v) = v {
+        if prefixes.kind == LEGACY {
+            return None;
+        }
+        operands[v] = Some(Operand::Reg(register(fmt[v].0, size(v), prefixes.vvvv, false)?));
+    }
+
+    // a register in the upper half of an immediate byte, which can share its byte with the first immediate
+    let mut immediates = immediates.into_iter().peekable();
+    if let Some(i) = i {
+        let byte = *code.get(pos)?;
+        pos += 1;
+        operands[i] = Some(Operand::Reg(register(fmt[i].0, size(i), byte >> 4, false)?));
+        if let Some(imm) = immediates.next() {
+            operands[imm] = Some(Operand::Imm(i64::from(byte & 0xF)));
+        }
+    }
+
+    for imm in immediates {
+        let (code_char, fsize) = fmt[imm];
+        let imm_size = match fsize {
+            b'*' => op_size.map_or(Size::DWord, |size| size.min(Size::DWord)),
+            fsize => arg_size(fsize, op_size)?
+        };
+        let value = read_signed(code, &mut pos, imm_size)?;
+        if code_char == b'o' {
+            displacement = Some(value);
+        }
+        operands[imm] = Some(Operand::Imm(value));
+    }
+
+    let operands = operands.into_iter().collect::<Option<Vec<_>>>()?;
+    Some((operands, displacement))
+}
+
+// decodes a memory reference from its ModRM, SIB and displacement. For VSIB addressing, vsib is the
+// size of the vector index register.
+fn decode_memory(code: &[u8], pos: &mut usize, mode: u8, rm: u8, prefixes: &Prefixes, vsib: Option<Size>) -> Option<MemoryRef> {
+    let address_register = |num: u8| if prefixes.address { Register::Rd(num) } else { Register::Rq(num) };
+
+    let mut base = None;
+    let mut index = None;
+
+    if rm == 4 {
+        let sib = *code.get(*pos)?;
+        *pos += 1;
+        let scale = 1 << (sib >> 6);
+        let index_num = ((sib >> 3) & 7) | prefixes.x << 3;
+
+        index = match vsib {
+            Some(Size::OWord) => Some((Register::Rx(index_num), scale)),
+            Some(_) => Some((Register::Ry(index_num), scale)),
+            // rsp as index encodes the lack of an index
+            None if index_num == 4 => None,
+            None => Some((address_register(index_num), scale))
+        };
+        if sib & 7 != 5 || mode != 0 {
+            base = Some(address_register((sib & 7) | prefixes.b << 3));
+        }
+    } else if rm == 5 && mode == 0 {
+        base = Some(Register::Rip);
+    } else if vsib.is_none() {
+        base = Some(address_register(rm | prefixes.b << 3));
+    } else {
+        return None;
+    }
+
+    let disp = match mode {
+        0b01 => read_signed(code, pos, Size::Byte)?,
+        0b10 => read_signed(code, pos, Size::DWord)?,
+        _ if base.is_none() || base == Some(Register::Rip) => read_signed(code, pos, Size::DWord)?,
+        _ => 0
+    };
+
+    Some(MemoryRef {
+        size: None,
+        base,
+        index,
+        disp: i32::try_from(disp).ok()?
+    })
+}
+
+fn prefix_name(prefix: Prefix) -> &'static str {
+    match prefix {
+        Prefix::Lock => "lock",
+        Prefix::Rep => "rep",
+        Prefix::Repe => "repe",
+        Prefix::Repne => "repne",
+        Prefix::Cs => "cs",
+        Prefix::Ds => "ds",
+        Prefix::Es => "es",
+        Prefix::Fs => "fs",
+        Prefix::Gs => "gs",
+        Prefix::Ss => "ss",
+    }
+}
+
+fn register_name(reg: Register) -> String {
+    const LEGACY: [&str; 8] = ["ax", "cx", "dx", "bx", "sp", "bp", "si", "di"];
+    const BYTES: [&str; 8] = ["al", "cl", "dl", "bl", "spl", "bpl", "sil", "dil"];
+    const HIGH_BYTES: [&str; 4] = ["ah", "ch", "dh", "bh"];
+    const SEGMENTS: [&str; 6] = ["es", "cs", "ss", "ds", "fs", "gs"];
+
+    let legacy = |n: u8, suffix: &str, low: &dyn Fn(&str) -> String| if n < 8 {
+        low(LEGACY[usize::from(n)])
+    } else {
+        format!("r{}{}", n, suffix)
+    };
+
+    match reg {
+        Register::Rb(n) if n < 8 => BYTES[usize::from(n)].to_string(),
+        Register::Rb(n) => format!("r{}b", n),
+        Register::Rh(n) => HIGH_BYTES[usize::from(n - 4)].to_string(),
+        Register::Rw(n) => legacy(n, "w", &|name| name.to_string()),
+        Register::Rd(n) => legacy(n, "d", &|name| format!("e{}", name)),
+        Register::Rq(n) => legacy(n, "", &|name| format!("r{}", name)),
+        Register::Rf(n) => format!("st{}", n),
+        Register::Rm(n) => format!("mm{}", n),
+        Register::Rx(n) => format!("xmm{}", n),
+        Register::Ry(n) => format!("ymm{}", n),
+        Register::Rz(n) => format!("zmm{}", n),
+        Register::Rs(n) => SEGMENTS[usize::from(n)].to_string(),
+        Register::RC(n) => format!("cr{}", n),
+        Register::RD(n) => format!("dr{}", n),
+        Register::RB(n) => format!("bnd{}", n),
+        Register::Rk(n) => format!("k{}", n),
+        Register::Rt(n) => format!("tmm{}", n),
+        Register::Rip => "rip".to_string(),
+    }
+}
+
+fn size_name(size: Size) -> &'static str {
+    match size {
+        Size::Byte => "BYTE",
+        Size::Word => "WORD",
+        Size::DWord => "DWORD",
+        Size::FWord => "FWORD",
+        Size::QWord => "QWORD",
+        Size::PWord => "TWORD",
+        Size::OWord => "OWORD",
+        Size::HWord => "YWORD",
+        Size::ZWord => "ZWORD",
+    }
+}
+
+fn format_operand(operand: &Operand) -> String {
+    match *operand {
+        Operand::Reg(reg) => register_name(reg),
+        Operand::Imm(value) => format_int(value),
+        Operand::Mem(ref mem) => {
+            let mut text = String::new();
+            if let Some(size) = mem.size {
+                text.push_str(size_name(size));
+                text.push(' ');
+            }
+
+            let mut parts = Vec::new();
+            if let Some(base) = mem.base {
+                parts.push(register_name(base));
+            }
+            if let Some((index, scale)) = mem.index {
+                parts.push(if scale == 1 {
+                    register_name(index)
+                } else {
+                    format!("{} * {}", register_name(index), scale)
+                });
+            }
+
+            text.push('[');
+            text.push_str(&parts.join(" + "));
+            let disp = i64::from(mem.disp);
+            if parts.is_empty() {
+                text.push_str(&format_int(disp));
+            } else if disp < 0 {
+                text.push_str(" - ");
+                text.push_str(&format_int(-disp));
+            } else if disp > 0 {
+                text.push_str(" + ");
+                text.push_str(&format_int(disp));
+            }
+            text.push(']');
+            text
+        },
+        // the disassembler does not produce label operands
+        Operand::Label(_) | Operand::LabelMem(_, _) => unreachable!(),
+    }
+}
@@ -0,0 +1,1546 @@
+//! A runtime encoder for aarch64 instructions, driven by the same instruction data as the `dynasm!` macro.
+//!
+//! This encoder supports the base instruction set, as well as the Advanced SIMD and floating point
+//! instructions. SVE and SME instructions cannot be encoded at runtime yet, and are reported as
+//! `EncodeError::Unsupported`. Required architecture extensions are not checked.
+
+use std::collections::HashMap;
+use std::fmt;
+use std::str::FromStr;
+use std::sync::OnceLock;
+
+use crate::{DynasmLabelApi, TargetKind};
+use crate::aarch64::{Aarch64Relocation, encode_logical_immediate_32bit, encode_logical_immediate_64bit, encode_floating_point_immediate};
+use super::{EncodeError, check_target, emit_relocation};
+
+
+/// The size of a register or vector element.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Size {
+    Byte = 1,
+    Word = 2,
+    DWord = 4,
+    QWord = 8,
+    OWord = 16,
+}
+
+impl Size {
+    /// The size in bytes
+    pub fn in_bytes(self) -> u8 {
+        self as u8
+    }
+}
+
+/// A scalar register operand.
+#[allow(clippy::upper_case_acronyms)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Register {
+    /// 32-bit general purpose registers. Register 31 is `wzr`
+    W(u8),
+    /// 64-bit general purpose registers. Register 31 is `xzr`
+    X(u8),
+    /// 32-bit general purpose registers. Register 31 is `wsp`
+    WSP(u8),
+    /// 64-bit general purpose registers. Register 31 is `sp`
+    XSP(u8),
+    /// 8-bit scalar SIMD registers
+    B(u8),
+    /// 16-bit scalar SIMD registers
+    H(u8),
+    /// 32-bit scalar SIMD registers
+    S(u8),
+    /// 64-bit scalar SIMD registers
+    D(u8),
+    /// 128-bit scalar SIMD registers
+    Q(u8),
+}
+
+impl Register {
+    fn code(self) -> u8 {
+        match self {
+            Register::W(n)
+            | Register::X(n)
+            | Register::WSP(n)
+            | Register::XSP(n)
+            | Register::B(n)
+            | Register::H(n)
+            | Register::S(n)
+            | Register::D(n)
+            | Register::Q(n) => n
+        }
+    }
+
+    // general purpose registers other than register 31 are the same register, whether they're named as
+    // potential stack pointers or not
+    fn canonicalize(self) -> Register {
+        match self {
+            Register::WSP(n) if n != 31 => Register::W(n),
+            Register::XSP(n) if n != 31 => Register::X(n),
+            reg => reg
+        }
+    }
+}
+
+/// A vector register operand, like `v1.s[2]` or `v3.16b`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Vector {
+    /// The register number
+    pub reg: u8,
+    /// The size of the vector elements
+    pub element_size: Size,
+    /// The amount of lanes, if specified
+    pub lanes: Option<u8>,
+    /// The element index, if specified
+    pub element: Option<u8>,
+}
+
+/// A memory reference.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MemoryRef {
+    /// `[base]`
+    Base(Register),
+    /// `[base, #offset]`
+    Offset(Register, i64),
+    /// `[base, #offset]!`
+    PreIndexed(Register, i64),
+    /// `[base, index {, modifier {#amount}}]`
+    Indexed(Register, Register, Option<(Modifier, Option<u32>)>),
+}
+
+/// Shift and extend modifiers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Modifier {
+    LSL,
+    LSR,
+    ASR,
+    ROR,
+    SXTX,
+    SXTW,
+    SXTH,
+    SXTB,
+    UXTX,
+    UXTW,
+    UXTH,
+    UXTB,
+    MSL,
+    MUL,
+}
+
+impl Modifier {
+    fn expr_required(self) -> bool {
+        matches!(self, Modifier::LSL | Modifier::LSR | Modifier::ASR | Modifier::ROR | Modifier::MSL | Modifier::MUL)
+    }
+}
+
+/// An operand to an instruction. Post-indexed addressing is written as a `Mem(MemoryRef::Base(..))` operand
+/// followed by an `Imm` operand, just like in `dynasm!`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Operand<'a> {
+    /// A scalar register
+    Reg(Register),
+    /// A vector register
+    Vector(Vector),
+    /// A list of `count` consecutive vector registers, starting at `first`. The element index of `first`
+    /// applies to the whole list.
+    List(Vector, u8),
+    /// A memory reference
+    Mem(MemoryRef),
+    /// An integer immediate
+    Imm(i64),
+    /// A floating point immediate
+    Float(f64),
+    /// A jump target (`=>label`, `->label`, `>label`, `<label` or `extern address`)
+    Label(TargetKind),
+    /// A modifier, with its amount if specified
+    Modifier(Modifier, Option<u32>),
+    /// A bare identifier, such as a condition code, barrier option or system register
+    Ident(&'a str),
+}
+
+/// An instruction mnemonic, resolved by name from the aarch64 instruction data. Names with a dotted
+/// suffix like `b.eq` are supported.
+#[derive(Clone, Copy)]
+pub struct Mnemonic {
+    name: &'static str,
+    suffix: Option<&'static str>,
+    data: &'static [Opdata],
+}
+
+impl Mnemonic {
+    /// Look up an instruction mnemonic by its name, like `add` or `b.ne`
+    pub fn from_name(name: &str) -> Option<Mnemonic> {
+        let (base, suffix) = match name.find('.') {
+            Some(i) => (&name[.. i], Some(&name[i + 1 ..])),
+            None => (name, None)
+        };
+        let (&name, &data) = opmap().get_key_value(base)?;
+
+        // the suffix is matched against the instruction data later, so find its static version here.
+        let suffix = match suffix {
+            None => None,
+            Some(suffix) => Some(if let Some((&cond, _)) = cond_map().get_key_value(suffix) {
+                cond
+            } else {
+                data.iter()
+                    .flat_map(|d| d.matchers.iter())
+                    .filter_map(|m| if let Matcher::Lit(s) = *m { Some(s) } else { None })
+                    .find(|&s| s == suffix)?
+            })
+        };
+
+        Some(Mnemonic { name, suffix, data })
+    }
+
+    /// The name of this mnemonic, without any dotted suffix
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+}
+
+impl FromStr for Mnemonic {
+    type Err = EncodeError;
+
+    fn from_str(name: &str) -> Result<Mnemonic, EncodeError> {
+        Mnemonic::from_name(name).ok_or_else(|| EncodeError::UnknownMnemonic(name.to_string()))
+    }
+}
+
+impl fmt::Debug for Mnemonic {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.suffix {
+            Some(suffix) => write!(f, "Mnemonic({}.{})", self.name, suffix),
+            None => write!(f, "Mnemonic({})", self.name)
+        }
+    }
+}
+
+impl PartialEq for Mnemonic {
+    fn eq(&self, other: &Mnemonic) -> bool {
+        self.name == other.name && self.suffix == other.suffix
+    }
+}
+
+impl Eq for Mnemonic {}
+
+
+/// Encode a single instruction into `ops`. Any labels used are referenced through relocations, which will be
+/// resolved when the assembler commits.
+pub fn encode<O>(ops: &mut O, mnemonic: Mnemonic, operands: &[Operand]) -> Result<(), EncodeError>
+where O: DynasmLabelApi<Relocation = Aarch64Relocation> {
+    let mut args = Vec::new();
+    if let Some(suffix) = mnemonic.suffix {
+        args.push(CleanArg::Dot);
+        args.push(CleanArg::Op(Operand::Ident(suffix)));
+    }
+    for operand in operands {
+        if let Operand::Ident(ident) = *operand {
+            if is_scalable_register(ident) {
+                return Err(unsupported_scalable(mnemonic.name));
+            }
+        }
+        args.push(CleanArg::Op(clean_operand(operand)?));
+    }
+
+    // matching loop
+    for data in mnemonic.data {
+        if let Some(mut ctx) = match_args(&args, data) {
+            flatten_args(&args, data, &mut ctx);
+
+            let (bits, relocations) = compile_instruction(ctx)?;
+            ops.extend(bits.to_le_bytes().iter().cloned());
+            for (label, relocation) in relocations {
+                emit_relocation(ops, label, (relocation.to_id(),));
+            }
+            return Ok(());
+        }
+    }
+
+    if mnemonic.data.iter().all(|data| data.matchers.iter().any(Matcher::is_scalable)) {
+        return Err(unsupported_scalable(mnemonic.name));
+    }
+    Err(EncodeError::OperandMismatch(mnemonic.name))
+}
+
+
+/// Encodes an instruction with the first of `forms` that matches the operands, without emitting it. Labels
+/// are not supported. Used by the disassembler to verify its output.
+#[cfg(feature = "disasm")]
+pub(crate) fn encode_form(forms: &'static [Opdata], suffix: Option<&str>, operands: &[Operand]) -> Option<u32> {
+    let mut args = Vec::new();
+    if let Some(suffix) = suffix {
+        args.push(CleanArg::Dot);
+        args.push(CleanArg::Op(Operand::Ident(suffix)));
+    }
+    for operand in operands {
+        args.push(CleanArg::Op(clean_operand(operand).ok()?));
+    }
+
+    let (data, mut ctx) = forms.iter().find_map(|data| match_args(&args, data).map(|ctx| (data, ctx)))?;
+    flatten_args(&args, data, &mut ctx);
+    match compile_instruction(ctx) {
+        Ok((bits, relocations)) if relocations.is_empty() => Some(bits),
+        _ => None
+    }
+}
+
+
+/*
+ * Operand preprocessing
+ */
+
+#[derive(Debug, Clone, Copy)]
+enum CleanArg<'a> {
+    Dot,
+    Op(Operand<'a>),
+}
+
+#[derive(Debug, Clone, Copy)]
+enum FlatArg<'a> {
+    Reg(u8),
+    Modifier(Modifier),
+    Int(i64),
+    Float(f64),
+    Ident(&'a str),
+    Label(TargetKind),
+    Default,
+}
+
+fn invalid(msg: &str) -> EncodeError {
+    EncodeError::InvalidOperand(msg.to_string())
+}
+
+fn check_register(reg: Register) -> Result<Register, EncodeError> {
+    if reg.code() > 31 {
+        return Err(invalid("Register numbers have to be below 32"));
+    }
+    Ok(reg.canonicalize())
+}
+
+fn check_vector(v: &Vector) -> Result<(), EncodeError> {
+    if v.reg > 31 {
+        return Err(invalid("Register numbers have to be below 32"));
+    }
+    if let Some(lanes) = v.lanes {
+        if lanes == 0 || u32::from(lanes) * u32::from(v.element_size.in_bytes()) > 16 {
+            return Err(invalid("Overly wide vector register."));
+        }
+    }
+    Ok(())
+}
+
+fn clean_operand<'a>(operand: &Operand<'a>) -> Result<Operand<'a>, EncodeError> {
+    Ok(match *operand {
+        Operand::Reg(reg) => Operand::Reg(check_register(reg)?),
+        Operand::Vector(ref v) => {
+            check_vector(v)?;
+            *operand
+        },
+        Operand::List(ref v, count) => {
+            check_vector(v)?;
+            if count == 0 || count > 32 {
+                return Err(invalid("Invalid amount of registers in register list."));
+            }
+            *operand
+        },
+        Operand::Modifier(modifier, amount) => {
+            if amount.is_none() && modifier.expr_required() {
+                return Err(invalid("LSL, LSR, ASR, ROR and MSL modifiers require a shift immediate."));
+            }
+            *operand
+        },
+        Operand::Mem(mem) => {
+            let base = match mem {
+                MemoryRef::Base(base)
+                | MemoryRef::Offset(base, _)
+                | MemoryRef::PreIndexed(base, _)
+                | MemoryRef::Indexed(base, _, _) => check_register(base)?,
+            };
+
+            // base can only be a Xn|SP reg
+            if !(matches!(base, Register::X(n) if n != 31) || base == Register::XSP(31)) {
+                return Err(invalid("Base register can only be a Xn|SP register"));
+            }
+
+            Operand::Mem(match mem {
+                MemoryRef::Base(_) => MemoryRef::Base(base),
+                MemoryRef::Offset(_, offset) => MemoryRef::Offset(base, offset),
+                MemoryRef::PreIndexed(_, offset) => MemoryRef::PreIndexed(base, offset),
+                MemoryRef::Indexed(_, index, modifier) => {
+                    // index can only be a Xn or Wn reg, with a limited set of allowed modifiers.
+                    let index = check_register(index)?;
+                    let allowed: &[Modifier] = match index {
+                        Register::X(_) => &[Modifier::LSL, Modifier::SXTX],
+                        Register::W(_) => &[Modifier::SXTW, Modifier::UXTW],
+                        _ => return Err(invalid("Index register can only be a Xn or Wn register"))
+                    };
+                    if let Some((m, amount)) = modifier {
+                        if !allowed.contains(&m) {
+                            return Err(invalid("Invalid modifier for the selected index register type"));
+                        }
+                        if m.expr_required() && amount.is_none() {
+                            return Err(invalid("LSL reference modifier requires an immediate"));
+                        }
+                    }
+                    MemoryRef::Indexed(base, index, modifier)
+                }
+            })
+        },
+        Operand::Label(label) => Operand::Label(check_target(label)?),
+        Operand::Imm(_)
+        | Operand::Float(_)
+        | Operand::Ident(_) => *operand,
+    })
+}
+
+
+/*
+ * Matching
+ */
+
+struct MatchData<'a> {
+    simd_full_width: Option<bool>,
+    data: &'static Opdata,
+    args: Vec<FlatArg<'a>>,
+}
+
+// checks that all full width vector operands of an instruction are either 8 or 16 bytes wide
+fn match_full_width(v: &Vector, ctx: &mut MatchData) -> bool {
+    let full_width = match v.lanes.map(|lanes| lanes * v.element_size.in_bytes()) {
+        Some(8) => false,
+        Some(16) => true,
+        _ => return false
+    };
+    match ctx.simd_full_width {
+        None => {
+            ctx.simd_full_width = Some(full_width);
+            true
+        }
+        Some(f) => f == full_width
+    }
+}
+
+impl Matcher {
+    /// Returns if this matcher matches the given argument
+    fn matches(&self, arg: &CleanArg, ctx: &mut MatchData) -> bool {
+        let arg = match *arg {
+            CleanArg::Dot => return *self == Matcher::Dot,
+            CleanArg::Op(ref arg) => arg,
+        };
+
+        match *arg {
+            Operand::Reg(reg) => match (*self, reg) {
+                (Matcher::W, Register::W(_))
+                | (Matcher::X, Register::X(_))
+                | (Matcher::WSP, Register::WSP(_))
+                | (Matcher::XSP, Register::XSP(_))
+                | (Matcher::B, Register::B(_))
+                | (Matcher::H, Register::H(_))
+                | (Matcher::S, Register::S(_))
+                | (Matcher::D, Register::D(_))
+                | (Matcher::Q, Register::Q(_)) => true,
+                (Matcher::WSP, Register::W(n))
+                | (Matcher::XSP, Register::X(n)) => n != 31,
+                _ => false
+            },
+            Operand::Vector(ref v) => match *self {
+                Matcher::V(size) => size == v.element_size && v.element.is_none() && match_full_width(v, ctx),
+                Matcher::VStatic(size, lanes) =>
+                    size == v.element_size && v.element.is_none() && v.lanes == Some(lanes),
+                Matcher::VElement(size) =>
+                    size == v.element_size && v.element.is_some(),
+                Matcher::VElementStatic(size, element) =>
+                    size == v.element_size && v.element == Some(element),
+                Matcher::VStaticElement(size, lanes) =>
+                    size == v.element_size && v.element.is_some() && v.lanes == Some(lanes),
+                _ => false
+            },
+            Operand::List(ref first, amount) => match *self {
+                Matcher::RegList(m_amount, size) =>
+                    m_amount == amount && size == first.element_size && first.element.is_none() && match_full_width(first, ctx),
+                Matcher::RegListStatic(m_amount, size, lanes) =>
+                    m_amount == amount && size == first.element_size && first.element.is_none() && first.lanes == Some(lanes),
+                Matcher::RegListElement(m_amount, size) =>
+                    m_amount == amount && size == first.element_size && first.element.is_some(),
+                _ => false
+            },
+            Operand::Mem(mem) => matches!((*self, mem),
+                (Matcher::RefBase, MemoryRef::Base(_))
+                | (Matcher::RefOffset, MemoryRef::Base(_))
+                | (Matcher::RefOffset, MemoryRef::Offset(_, _))
+                | (Matcher::RefPre, MemoryRef::PreIndexed(_, _))
+                | (Matcher::RefIndex, MemoryRef::Indexed(_, _, _))
+            ),
+            Operand::Label(_) => *self == Matcher::Offset,
+            Operand::Imm(value) => match *self {
+                Matcher::Imm
+                | Matcher::Offset => true,
+                Matcher::LitInt(v) => value == i64::from(v),
+                Matcher::LitFloat(v) => value as f64 == f64::from(v),
+                _ => false
+            },
+            Operand::Float(value) => match *self {
+                Matcher::Imm => true,
+                Matcher::LitFloat(v) => value == f64::from(v),
+                _ => false
+            },
+            Operand::Modifier(modifier, _) => match *self {
+                Matcher::Mod(list) => list.contains(&modifier),
+                Matcher::LitMod(m) => m == modifier,
+                _ => false
+            },
+            Operand::Ident(ident) => match *self {
+                Matcher::Ident => true,
+                Matcher::Cond => cond_map().contains_key(ident),
+                Matcher::Lit(s) => ident == s,
+                _ => false
+            },
+        }
+    }
+
+    pub(crate) fn flatarg_count(&self) -> usize {
+        match *self {
+            Matcher::Dot
+            | Matcher::Lit(_)
+            | Matcher::LitInt(_)
+            | Matcher::LitFloat(_) => 0,
+            Matcher::VElement(_)
+            | Matcher::VStaticElement(_, _)
+            | Matcher::RegListElement(_, _)
+            | Matcher::RefOffset
+            | Matcher::RefPre
+            | Matcher::Mod(_) => 2,
+            Matcher::RefIndex => 4,
+            Matcher::End => 0,
+            // SVE and SME operands are never matched
+            _ => 1
+        }
+    }
+
+    // SVE and SME operands, which cannot be expressed as an `Operand`
+    fn is_scalable(&self) -> bool {
+        matches!(*self,
+            Matcher::Z(_)
+            | Matcher::ZBare
+            | Matcher::ZElement(_)
+            | Matcher::ZRegList(_, _)
+            | Matcher::P(_)
+            | Matcher::PBare
+            | Matcher::PMerge
+            | Matcher::PZero
+            | Matcher::ZaTile(_)
+            | Matcher::ZaH(_)
+            | Matcher::ZaV(_)
+            | Matcher::ZaHList(_)
+            | Matcher::ZaVList(_)
+            | Matcher::ZaArray
+            | Matcher::ZaTileList
+            | Matcher::RefMulVl
+            | Matcher::RefZIndexLsl(_, _)
+            | Matcher::RefZIndexExtend(_, _)
+            | Matcher::RefZBase(_)
+            | Matcher::RefZZ(_)
+            | Matcher::RefZZExtend(_)
+        )
+    }
+}
+
+/// Returns whether `name` is the name of an SVE or SME register (`z0`-`z31`, `p0`-`p15`, `za` and its tiles
+/// and slices). These registers cannot be encoded at runtime.
+pub(crate) fn is_scalable_register(name: &str) -> bool {
+    let name = name.to_ascii_lowercase();
+    let number = |digits: &str, limit: u8| digits.parse::<u8>().is_ok_and(|n| n < limit);
+
+    if let Some(tile) = name.strip_prefix("za") {
+        tile.is_empty() || tile.starts_with(|c: char| c.is_ascii_digit())
+    } else if let Some(digits) = name.strip_prefix('z') {
+        number(digits, 32)
+    } else if let Some(digits) = name.strip_prefix('p') {
+        number(digits, 16)
+    } else {
+        false
+    }
+}
+
+pub(crate) fn unsupported_scalable(name: &str) -> EncodeError {
+    EncodeError::Unsupported(format!("SVE and SME forms of '{}' cannot be encoded at runtime", name))
+}
+
+/// Check if the args string matches the data matching template
+fn match_args<'a>(args: &[CleanArg<'a>], data: &'static Opdata) -> Option<MatchData<'a>> {
+    let mut ctx = MatchData {
+        simd_full_width: None,
+        data,
+        args: Vec::new()
+    };
+
+    let mut args = args.iter().peekable();
+
+    for matcher in data.matchers {
+        match matcher {
+            Matcher::End => if args.peek().is_some() {
+                continue;
+            } else {
+                return Some(ctx);
+            },
+            matcher => if let Some(arg) = args.next() {
+                if !matcher.matches(arg, &mut ctx) {
+                    return None;
+                }
+            } else {
+                return None;
+            },
+        }
+    }
+
+    if args.next().is_some() {
+        None
+    } else {
+        Some(ctx)
+    }
+}
+
+/// flatten the arg list into a linear sequence of encodable elements
+fn flatten_args<'a>(args: &[CleanArg<'a>], data: &Opdata, ctx: &mut MatchData<'a>) {
+    let mut source_args = args.iter();
+    let mut new_args = Vec::new();
+
+    for matcher in data.matchers {
+        let arg_count = match matcher {
+            Matcher::End => continue,
+            matcher => matcher.flatarg_count()
+        };
+
+        if let Some(&CleanArg::Op(arg)) = source_args.next() {
+            match arg {
+                Operand::Reg(reg) => new_args.push(FlatArg::Reg(reg.code())),
+                Operand::Vector(v)
+                | Operand::List(v, _) => {
+                    new_args.push(FlatArg::Reg(v.reg));
+                    if let Some(element) = v.element {
+                        new_args.push(FlatArg::Int(i64::from(element)));
+                    }
+                },
+                Operand::Mem(mem) => match mem {
+                    MemoryRef::Base(base) => new_args.push(FlatArg::Reg(base.code())),
+                    MemoryRef::Offset(base, value)
+                    | MemoryRef::PreIndexed(base, value) => {
+                        new_args.push(FlatArg::Reg(base.code()));
+                        new_args.push(FlatArg::Int(value));
+                    },
+                    MemoryRef::Indexed(base, index, modifier) => {
+                        new_args.push(FlatArg::Reg(base.code()));
+                        new_args.push(FlatArg::Reg(index.code()));
+                        if let Some((modifier, amount)) = modifier {
+                            new_args.push(FlatArg::Modifier(modifier));
+                            if let Some(amount) = amount {
+                                new_args.push(FlatArg::Int(i64::from(amount)));
+                            }
+                        }
+                    }
+                },
+                Operand::Imm(value) => new_args.push(FlatArg::Int(value)),
+                Operand::Float(value) => new_args.push(FlatArg::Float(value)),
+                Operand::Label(label) => new_args.push(FlatArg::Label(label)),
+                Operand::Modifier(modifier, amount) => {
+                    if arg_count >= 2 {
+                        new_args.push(FlatArg::Modifier(modifier));
+                    }
+                    if let Some(amount) = amount {
+                        new_args.push(FlatArg::Int(i64::from(amount)));
+                    }
+                },
+                Operand::Ident(ident) => if arg_count != 0 {
+                    new_args.push(FlatArg::Ident(ident));
+                },
+            }
+        }
+
+        new_args.resize(arg_count, FlatArg::Default);
+
+        ctx.args.append(&mut new_args);
+    }
+}
+
+
+/*
+ * Encoding
+ */
+
+fn bitmask(bits: u8) -> u32 {
+    1u32.checked_shl(u32::from(bits)).unwrap_or(0).wrapping_sub(1)
+}
+
+fn compile_instruction(data: MatchData) -> Result<(u32, Vec<(TargetKind, Relocation)>), EncodeError> {
+    let mut cursor = 0usize;
+
+    // All bitfields will be encoded into this map of (offset, bitfield)
+    let mut statics = Vec::new();
+    // Any relocations will be encoded into this list
+    let mut relocations = Vec::new();
+
+    for command in data.data.commands.iter() {
+        match *command {
+            // special commands that don't check the current arg
+            Command::A => {
+                cursor += 1;
+                continue
+            },
+            Command::C => {
+                cursor -= 1;
+                continue
+            },
+            Command::Rwidth(offset) => {
+                statics.push((offset, data.simd_full_width.unwrap_or(true) as u32));
+                continue
+            },
+
+            _ => ()
+        }
+
+        let arg = *data.args.get(cursor).expect("Invalid encoding data, tried to process more arguments than given");
+
+        match arg {
+            FlatArg::Reg(code) => match *command {
+                Command::R(offset) => {
+                    statics.push((offset, u32::from(code)));
+                },
+                Command::REven(offset) => {
+                    if code & 1 != 0 {
+                        return Err(invalid("Field only supports even registers"));
+                    }
+                    statics.push((offset, u32::from(code)));
+                },
+                Command::RNoZr(offset) => {
+                    if code == 31 {
+                        return Err(invalid("Field does not support register the zr/sp register"));
+                    }
+                    statics.push((offset, u32::from(code)));
+                },
+                Command::R4(offset) => {
+                    if code >= 16 {
+                        return Err(invalid("Field only supports register numbers 0-15"));
+                    }
+                    statics.push((offset, u32::from(code)));
+                },
+                Command::R3(offset) => {
+                    if code >= 8 {
+                        return Err(invalid("Field only supports register numbers 0-7"));
+                    }
+                    statics.push((offset, u32::from(code)));
+                },
+                Command::RTied => {
+                    if let Some(&FlatArg::Reg(first)) = data.args.first() {
+                        if code != first {
+                            return Err(invalid("Invalid register. This register has to be the same register as the first argument."));
+                        }
+                    } else {
+                        panic!("RTied command without the first argument being a register");
+                    }
+                },
+                Command::RNext => {
+                    if let Some(&FlatArg::Reg(prev)) = data.args.get(cursor - 1) {
+                        if code != (prev + 1) % 32 {
+                            return Err(invalid("Invalid register. This register has to be the register after the previous argument."));
+                        }
+                    } else {
+                        panic!("RNext command without the previous command being a register encoder");
+                    }
+                },
+                _ => panic!("Invalid argument processor")
+            },
+            FlatArg::Modifier(modifier) => match *command {
+                Command::Rotates(offset) => statics.push((offset, match modifier {
+                    Modifier::LSL => 0b00,
+                    Modifier::LSR => 0b01,
+                    Modifier::ASR => 0b10,
+                    Modifier::ROR => 0b11,
+                    _ => panic!("Unexpected modifier for argument processor")
+                })),
+                Command::ExtendsW(offset)
+                | Command::ExtendsX(offset) => statics.push((offset, match modifier {
+                    Modifier::UXTB => 0b000,
+                    Modifier::UXTH => 0b001,
+                    Modifier::UXTW => 0b010,
+                    Modifier::UXTX => 0b011,
+                    Modifier::SXTB => 0b100,
+                    Modifier::SXTH => 0b101,
+                    Modifier::SXTW => 0b110,
+                    Modifier::SXTX => 0b111,
+                    Modifier::LSL => if let Command::ExtendsW(_) = *command { 0b010 } else { 0b011 },
+                    _ => panic!("Unexpected modifier for argument processor")
+                })),
+                _ => panic!("Invalid argument processor")
+            },
+            FlatArg::Ident(ident) => match *command {
+                // Condition codes, literals
+                Command::Cond(offset) => {
+                    let bits = *cond_map().get(ident).expect("bad command data");
+                    statics.push((offset, u32::from(bits)))
+                },
+                Command::CondInv(offset) => {
+                    let bits = *cond_map().get(ident).expect("bad command data");
+                    statics.push((offset, u32::from(bits) ^ 1))
+                },
+                Command::LitList(offset, listname) => {
+                    let list = special_ident_map().get(listname).expect("bad command data");
+                    if let Some(&bits) = list.get(ident) {
+                        statics.push((offset, bits));
+                    } else {
+                        return Err(EncodeError::InvalidOperand(format!("Unknown literal '{}'", ident)));
+                    }
+                },
+                Command::SysRegRead(offset) => statics.push((offset, encode_sysreg(ident, false)?)),
+                Command::SysRegWrite(offset) => statics.push((offset, encode_sysreg(ident, true)?)),
+                _ => panic!("Invalid argument processor")
+            },
+            FlatArg::Float(value) => match *command {
+                Command::Special(offset, special) => handle_special_immediates(offset, special, Immediate::Float(value), &mut statics)?,
+                _ => return Err(invalid("Expected an integer immediate"))
+            },
+            FlatArg::Int(value) => match *command {
+                // unsigned integer encodings
+                Command::Ubits(offset, bitlen) => {
+                    let mask = bitmask(bitlen);
+                    statics.push((offset, unsigned_rangecheck(value, 0, mask, 0)?));
+                },
+                Command::Uscaled(offset, bitlen, shift) => {
+                    let mask = bitmask(bitlen);
+                    statics.push((offset, unsigned_rangecheck(value, 0, mask, shift)?));
+                },
+                Command::Uslice(offset, bitlen, shift) => {
+                    let mask = bitmask(bitlen);
+                    statics.push((offset, ((value as u32) >> shift) & mask));
+                },
+                Command::Unegslice(offset, bitlen, shift) => {
+                    let mask = bitmask(bitlen);
+                    statics.push((offset, ((value as u32).wrapping_neg() >> shift) & mask));
+                },
+                Command::Ulist(offset, options) => {
+                    if let Some(i) = options.iter().rposition(|&n| i64::from(n) == value) {
+                        statics.push((offset, i as u32));
+                    } else {
+                        return Err(invalid("Impossible value"));
+                    }
+                },
+                Command::Urange(offset, min, max) => {
+                    let min = u32::from(min);
+                    let max = u32::from(max);
+                    statics.push((offset, unsigned_rangecheck(value, min, max, 0)? - min));
+                },
+                Command::Usub(offset, bitlen, addval) => {
+                    let mask = bitmask(bitlen);
+                    let addval = u32::from(addval);
+                    statics.push((offset, addval - unsigned_rangecheck(value, addval.saturating_sub(mask), addval, 0)?));
+                },
+                Command::Unegmod(offset, bitlen) => {
+                    let mask = bitmask(bitlen);
+                    let addval = 1u32 << bitlen;
+                    statics.push((offset, (addval - unsigned_rangecheck(value, 0, mask, 0)?) & mask));
+                },
+                Command::Usumdec(offset, bitlen) => {
+                    let mask = bitmask(bitlen);
+                    if let Some(&FlatArg::Int(leftvalue)) = data.args.get(cursor - 1) {
+                        statics.push((offset, ((leftvalue + value - 1) as u32) & mask));
+                    } else {
+                        panic!("Bad encoding data, previous argument was not an immediate");
+                    }
+                },
+                Command::Usame(back) => {
+                    if let Some(&FlatArg::Int(other)) = data.args.get(cursor - usize::from(back)) {
+                        if value != other {
+                            return Err(invalid("This value has to be the same as the earlier offset"));
+                        }
+                    } else {
+                        panic!("Bad encoding data, the tied argument was not an immediate");
+                    }
+                },
+                Command::Ufields(bitfields) => {
+                    let mask = bitmask(bitfields.len() as u8);
+                    let value = unsigned_rangecheck(value, 0, mask, 0)?;
+                    for (i, &field) in bitfields.iter().rev().enumerate() {
+                        statics.push((field, (value >> i) & 1));
+                    }
+                },
+
+                // signed integer encoding
+                Command::Sbits(offset, bitlen) => {
+                    let mask = bitmask(bitlen);
+                    let half = -1i32 << (bitlen - 1);
+                    statics.push((offset, (signed_rangecheck(value, half, mask as i32 + half, 0)? as u32) & mask));
+                },
+                Command::Sscaled(offset, bitlen, shift) => {
+                    let mask = bitmask(bitlen);
+                    let half = -1i32 << (bitlen - 1);
+                    statics.push((offset, (signed_rangecheck(value, half, mask as i32 + half, shift)? as u32) & mask));
+                },
+                Command::Smultiple(offset, bitlen, factor) => {
+                    let mask = bitmask(bitlen);
+                    let half = -1i32 << (bitlen - 1);
+                    let factor = i64::from(factor);
+                    if value % factor != 0 {
+                        return Err(invalid("Unrepresentable value"));
+                    } else if value / factor > i64::from(mask as i32 + half) {
+                        return Err(invalid("Value too large"));
+                    } else if value / factor < i64::from(half) {
+                        return Err(invalid("Value too small"));
+                    }
+                    statics.push((offset, ((value / factor) as u32) & mask));
+                },
+                Command::Sslice(offset, bitlen, shift) => {
+                    let mask = bitmask(bitlen);
+                    statics.push((offset, ((value >> shift) as u32) & mask));
+                },
+
+                // nonconsuming integer checks
+                Command::BUbits(bitlen) => {
+                    unsigned_rangecheck(value, 0, bitmask(bitlen), 0)?;
+                },
+                Command::BUsum(bitlen) => {
+                    let prev = if let Some(&FlatArg::Int(leftvalue)) = data.args.get(cursor - 1) {
+                        leftvalue
+                    } else {
+                        panic!("Bad encoding data, previous argument was not an immediate");
+                    };
+                    let max = (1i64 << bitlen) - prev;
+                    if value < 1 {
+                        return Err(invalid("Value too small"));
+                    } else if value > max {
+                        return Err(invalid("Value too large"));
+                    }
+                },
+                Command::BSscaled(bitlen, shift) => {
+                    let mask = bitmask(bitlen);
+                    let half = -1i32 << (bitlen - 1);
+                    signed_rangecheck(value, half, mask as i32 + half, shift)?;
+                },
+                Command::BUrange(min, max) => {
+                    unsigned_rangecheck(value, u32::from(min), u32::from(max), 0)?;
+                },
+
+                // specials. These have some more involved code.
+                Command::Special(offset, special) => handle_special_immediates(offset, special, Immediate::Int(value), &mut statics)?,
+
+                // jump targets also accept immediates
+                Command::Offset(relocation) => match relocation {
+                    // b, bl 26 bits, dword aligned
+                    Relocation::B => {
+                        let mask = bitmask(26);
+                        let half = -1i32 << 25;
+                        statics.push((0, (signed_rangecheck(value, half, mask as i32 + half, 2)? as u32) & mask));
+                    },
+                    // b.cond, cbnz, cbz, ldr, ldrsw, prfm: 19 bits, dword aligned
+                    Relocation::BCOND => {
+                        let mask = bitmask(19);
+                        let half = -1i32 << 18;
+                        statics.push((5, (signed_rangecheck(value, half, mask as i32 + half, 2)? as u32) & mask));
+                    },
+                    // adr split 21 bit, byte aligned
+                    // adrp split 21 bit, 4096-byte aligned
+                    Relocation::ADR
+                    | Relocation::ADRP => {
+                        let mask = bitmask(21);
+                        let half = -1i32 << 20;
+                        let shift = if relocation == Relocation::ADRP { 12 } else { 0 };
+                        let value = signed_rangecheck(value, half, mask as i32 + half, shift)?;
+                        statics.push((5, ((value >> 2) as u32) & 0x7FFFF));
+                        statics.push((29, (value as u32) & 3));
+                    },
+                    // tbnz, tbz: 14 bits, dword aligned
+                    Relocation::TBZ => {
+                        let mask = bitmask(14);
+                        let half = -1i32 << 13;
+                        statics.push((5, (signed_rangecheck(value, half, mask as i32 + half, 2)? as u32) & mask));
+                    },
+                    Relocation::LITERAL8
+                    | Relocation::LITERAL16
+                    | Relocation::LITERAL32
+                    | Relocation::LITERAL64 => ()
+                },
+
+                _ => panic!("Invalid argument processor")
+            },
+            FlatArg::Default => match *command {
+                // Registers default to R31
+                Command::R(offset) => {
+                    statics.push((offset, 0b11111u32));
+                },
+
+                // modifiers to LSL
+                Command::Rotates(offset) => {
+                    statics.push((offset, 0b00));
+                },
+                Command::ExtendsW(offset) => {
+                    statics.push((offset, 0b010));
+                },
+                Command::ExtendsX(offset) => {
+                    statics.push((offset, 0b011));
+                },
+
+                // normal integer encodings default to 0 (i.e. not doing anything)
+                Command::Ubits(_, _) |
+                Command::Uscaled(_, _, _) |
+                Command::Uslice(_, _, _) |
+                Command::Unegslice(_, _, _) |
+                Command::Urange(_, _, _) |
+                Command::Ulist(_, _) |
+                Command::Ufields(_) |
+                Command::Sbits(_, _) |
+                Command::Sscaled(_, _, _) |
+                Command::Smultiple(_, _, _) |
+                Command::Sslice(_, _, _) => (),
+
+                // integer checks don't have anything to check
+                Command::BUbits(_) |
+                Command::BSscaled(_, _) |
+                Command::Usame(_) => (),
+
+                _ => panic!("Invalid argument processor")
+            },
+            FlatArg::Label(label) => match *command {
+                Command::Offset(relocation) => relocations.push((label, relocation)),
+                _ => panic!("Invalid argument processor")
+            },
+        }
+
+        // figure out how far the cursor has to be advanced.
+        match *command {
+            Command::Uslice(_, _, _) |
+            Command::Sslice(_, _, _) |
+            Command::Unegslice(_, _, _) => (),
+            Command::BUbits(_) |
+            Command::BUsum(_) |
+            Command::BSscaled(_, _) |
+            Command::BUrange(_, _) => (),
+            _ => cursor += 1
+        }
+    }
+
+    // sanity
+    if cursor != data.args.len() {
+        panic!("Not enough command processors");
+    }
+
+    // apply all statics to bits
+    let mut bits = data.data.base;
+    for (offset, value) in statics {
+        bits |= value << offset;
+    }
+
+    Ok((bits, relocations))
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Immediate {
+    Int(i64),
+    Float(f64),
+}
+
+impl Immediate {
+    fn as_int(self) -> Result<i64, EncodeError> {
+        match self {
+            Immediate::Int(value) => Ok(value),
+            Immediate::Float(_) => Err(invalid("Expected an integer immediate"))
+        }
+    }
+
+    fn as_float(self) -> f32 {
+        match self {
+            Immediate::Int(value) => value as f32,
+            Immediate::Float(value) => value as f32
+        }
+    }
+
+    // 32-bit immediates can be given both as signed and as unsigned values
+    fn as_u32(self) -> Result<Option<u32>, EncodeError> {
+        let value = self.as_int()?;
+        Ok(if (i64::from(i32::MIN) ..= i64::from(u32::MAX)).contains(&value) {
+            Some(value as u32)
+        } else {
+            None
+        })
+    }
+}
+
+fn handle_special_immediates(offset: u8, special: SpecialComm, imm: Immediate, statics: &mut Vec<(u8, u32)>) -> Result<(), EncodeError> {
+    match special {
+        SpecialComm::INVERTED_WIDE_IMMEDIATE_X => {
+            if let Some(encoded) = encode_wide_immediate_64bit(!(imm.as_int()? as u64)) {
+                statics.push((offset, encoded));
+                return Ok(());
+            }
+        },
+        SpecialComm::INVERTED_WIDE_IMMEDIATE_W => if let Some(number) = imm.as_u32()? {
+            if let Some(encoded) = encode_wide_immediate_32bit(!number) {
+                statics.push((offset, encoded));
+                return Ok(());
+            }
+        },
+        SpecialComm::WIDE_IMMEDIATE_X => {
+            if let Some(encoded) = encode_wide_immediate_64bit(imm.as_int()? as u64) {
+                statics.push((offset, encoded));
+                return Ok(());
+            }
+        },
+        SpecialComm::WIDE_IMMEDIATE_W => if let Some(number) = imm.as_u32()? {
+            if let Some(encoded) = encode_wide_immediate_32bit(number) {
+                statics.push((offset, encoded));
+                return Ok(());
+            }
+        },
+        SpecialComm::STRETCHED_IMMEDIATE => {
+            if let Some(encoded) = encode_stretched_immediate(imm.as_int()? as u64) {
+                statics.push((offset, encoded & 0x1F));
+                statics.push((offset + 6, encoded & 0xE0));
+                return Ok(());
+            }
+        },
+        SpecialComm::LOGICAL_IMMEDIATE_B => {
+            let number = imm.as_int()?;
+            if (i64::from(i8::MIN) ..= i64::from(u8::MAX)).contains(&number) {
+                if let Some(encoded) = encode_logical_immediate_32bit(u32::from(number as u8) * 0x0101_0101) {
+                    statics.push((offset, u32::from(encoded)));
+                    return Ok(());
+                }
+            }
+        },
+        SpecialComm::LOGICAL_IMMEDIATE_H => {
+            let number = imm.as_int()?;
+            if (i64::from(i16::MIN) ..= i64::from(u16::MAX)).contains(&number) {
+                if let Some(encoded) = encode_logical_immediate_32bit(u32::from(number as u16) * 0x0001_0001) {
+                    statics.push((offset, u32::from(encoded)));
+                    return Ok(());
+                }
+            }
+        },
+        SpecialComm::LOGICAL_IMMEDIATE_W => if let Some(number) = imm.as_u32()? {
+            if let Some(encoded) = encode_logical_immediate_32bit(number) {
+                statics.push((offset, u32::from(encoded)));
+                return Ok(());
+            }
+        },
+        SpecialComm::LOGICAL_IMMEDIATE_X => {
+            if let Some(encoded) = encode_logical_immediate_64bit(imm.as_int()? as u64) {
+                statics.push((offset, u32::from(encoded)));
+                return Ok(());
+            }
+        },
+        SpecialComm::FLOAT_IMMEDIATE => {
+            if let Some(encoded) = encode_floating_point_immediate(imm.as_float()) {
+                statics.push((offset, u32::from(encoded)));
+                return Ok(());
+            }
+        },
+        SpecialComm::SPLIT_FLOAT_IMMEDIATE => {
+            if let Some(encoded) = encode_floating_point_immediate(imm.as_float()) {
+                statics.push((offset, u32::from(encoded & 0x1F)));
+                statics.push((offset + 6, u32::from(encoded & 0xE0)));
+                return Ok(());
+            }
+        },
+    }
+
+    Err(invalid("Impossible to encode immediate"))
+}
+
+fn encode_stretched_immediate(value: u64) -> Option<u32> {
+    // ensure the number is formatted correctly
+    let mut test = value & 0x0101_0101_0101_0101;
+    test |= test << 1;
+    test |= test << 2;
+    test |= test << 4;
+    if test != value {
+        return None;
+    }
+
+    // do bitwise magic
+    let mut masked = value & 0x8040_2010_0804_0201;
+    masked |= masked >> 32;
+    masked |= masked >> 16;
+    masked |= masked >> 8;
+    let masked = masked as u32;
+    Some(masked & 0xFF)
+}
+
+fn encode_wide_immediate_64bit(value: u64) -> Option<u32> {
+    let offset = value.trailing_zeros() & 0b11_0000;
+    let masked = 0xFFFF & (value >> offset);
+    if (masked << offset) == value {
+        Some((masked as u32) | (offset << 12))
+    } else {
+        None
+    }
+}
+
+fn encode_wide_immediate_32bit(value: u32) -> Option<u32> {
+    let offset = value.trailing_zeros() & 0b1_0000;
+    let masked = 0xFFFF & (value >> offset);
+    if (masked << offset) == value {
+        Some(masked | (offset << 12))
+    } else {
+        None
+    }
+}
+
+/// Looks up the o0:op1:CRn:CRm:op2 encoding of a named system register, or of a system register
+/// written as s<op0>_<op1>_c<n>_c<m>_<op2>, and checks that it can be read or written.
+fn encode_sysreg(name: &str, write: bool) -> Result<u32, EncodeError> {
+    if let Some(&(bits, access)) = sysreg_map().get(name) {
+        if write && access == SysRegAccess::Read {
+            return Err(EncodeError::InvalidOperand(format!("System register '{}' is read-only", name)));
+        } else if !write && access == SysRegAccess::Write {
+            return Err(EncodeError::InvalidOperand(format!("System register '{}' is write-only", name)));
+        }
+        return Ok(bits);
+    }
+
+    if let Some(bits) = parse_generic_sysreg(name) {
+        return Ok(bits);
+    }
+
+    if write && special_ident_map()["MSR_IMM_OPS"].contains_key(name) {
+        Err(EncodeError::InvalidOperand(format!("PSTATE field '{}' can only be written with an immediate", name)))
+    } else {
+        Err(EncodeError::InvalidOperand(format!("Unknown system register '{}'", name)))
+    }
+}
+
+/// Parses the s<op0>_<op1>_c<n>_c<m>_<op2> notation for system registers.
+fn parse_generic_sysreg(name: &str) -> Option<u32> {
+    let mut fields = name.strip_prefix('s')?.split('_');
+    let mut field = |prefix: &str, max: u32| -> Option<u32> {
+        let digits = fields.next()?.strip_prefix(prefix)?;
+        if digits.is_empty() || !digits.bytes().all(|b| b.is_ascii_digit()) {
+            return None;
+        }
+        digits.parse().ok().filter(|&v| v <= max)
+    };
+
+    let op0 = field("", 3)?;
+    let op1 = field("", 7)?;
+    let crn = field("c", 15)?;
+    let crm = field("c", 15)?;
+    let op2 = field("", 7)?;
+    if op0 < 2 || fields.next().is_some() {
+        return None;
+    }
+
+    Some(((op0 - 2) << 14) | (op1 << 11) | (crn << 7) | (crm << 3) | op2)
+}
+
+fn unsigned_rangecheck(value: i64, min: u32, max: u32, scale: u8) -> Result<u32, EncodeError> {
+    if value < 0 {
+        return Err(invalid("Value too small"));
+    }
+    let scaled = value >> scale;
+
+    if (scaled << scale) != value {
+        Err(invalid("Unrepresentable value"))
+    } else if scaled > i64::from(max) {
+        Err(invalid("Value too large"))
+    } else if scaled < i64::from(min) {
+        Err(invalid("Value too small"))
+    } else {
+        Ok(scaled as u32)
+    }
+}
+
+fn signed_rangecheck(value: i64, min: i32, max: i32, scale: u8) -> Result<i32, EncodeError> {
+    let scaled = value >> scale;
+
+    if (scaled << scale) != value {
+        Err(invalid("Unrepresentable value"))
+    } else if scaled > i64::from(max) {
+        Err(invalid("Value too large"))
+    } else if scaled < i64::from(min) {
+        Err(invalid("Value too small"))
+    } else {
+        Ok(scaled as i32)
+    }
+}
+
+
+/*
+ * Instruction data
+ */
+
+// The instruction data also describes SVE and SME operands. These never match any operand given to this encoder,
+// see `Matcher::is_scalable`.
+#[allow(dead_code, clippy::upper_case_acronyms)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum Matcher {
+    Dot,
+    Lit(&'static str),
+    LitInt(u32),
+    LitFloat(f32),
+    Ident,
+    Cond,
+    Imm,
+    W,
+    X,
+    WSP,
+    XSP,
+    B,
+    H,
+    S,
+    D,
+    Q,
+    V(Size),
+    VStatic(Size, u8),
+    VElement(Size),
+    VElementStatic(Size, u8),
+    VStaticElement(Size, u8),
+    RegList(u8, Size),
+    RegListStatic(u8, Size, u8),
+    RegListElement(u8, Size),
+    Z(Size),
+    ZBare,
+    ZElement(Size),
+    ZRegList(u8, Size),
+    P(Size),
+    PBare,
+    PMerge,
+    PZero,
+    ZaTile(Size),
+    ZaH(Size),
+    ZaV(Size),
+    ZaHList(Size),
+    ZaVList(Size),
+    ZaArray,
+    ZaTileList,
+    Offset,
+    RefBase,
+    RefOffset,
+    RefPre,
+    RefIndex,
+    RefMulVl,
+    RefIndexLsl(u8),
+    RefZIndexLsl(Size, u8),
+    RefZIndexExtend(Size, u8),
+    RefZBase(Size),
+    RefZZ(Size),
+    RefZZExtend(Modifier),
+    LitMod(Modifier),
+    Mod(&'static [Modifier]),
+    End,
+}
+
+// see the command documentation in the aarch64 instruction data of the plugin
+#[allow(dead_code)]
+#[derive(Debug, Clone, Copy)]
+pub(crate) enum Command {
+    R(u8),
+    REven(u8),
+    RNoZr(u8),
+    R4(u8),
+    R3(u8),
+    RNext,
+    RTied,
+    RTile(u8, u8),
+    RSelect(u8),
+    Ubits(u8, u8),
+    Uscaled(u8, u8, u8),
+    Ulist(u8, &'static [u16]),
+    Urange(u8, u8, u8),
+    Usub(u8, u8, u8),
+    Unegmod(u8, u8),
+    Usumdec(u8, u8),
+    Usame(u8),
+    Ufields(&'static [u8]),
+    Sbits(u8, u8),
+    Sscaled(u8, u8, u8),
+    Smultiple(u8, u8, u8),
+    BUbits(u8),
+    BUsum(u8),
+    BSscaled(u8, u8),
+    BUrange(u8, u8),
+    Uslice(u8, u8, u8),
+    Sslice(u8, u8, u8),
+    Unegslice(u8, u8, u8),
+    Special(u8, SpecialComm),
+    Rwidth(u8),
+    Rotates(u8),
+    ExtendsW(u8),
+    ExtendsX(u8),
+    ExtendsZ(u8),
+    Cond(u8),
+    CondInv(u8),
+    LitList(u8, &'static str),
+    SysRegRead(u8),
+    SysRegWrite(u8),
+    Offset(Relocation),
+    A,
+    C,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[allow(non_camel_case_types)]
+pub(crate) enum SpecialComm {
+    INVERTED_WIDE_IMMEDIATE_W,
+    INVERTED_WIDE_IMMEDIATE_X,
+    WIDE_IMMEDIATE_W,
+    WIDE_IMMEDIATE_X,
+    STRETCHED_IMMEDIATE,
+    LOGICAL_IMMEDIATE_B,
+    LOGICAL_IMMEDIATE_H,
+    LOGICAL_IMMEDIATE_W,
+    LOGICAL_IMMEDIATE_X,
+    FLOAT_IMMEDIATE,
+    SPLIT_FLOAT_IMMEDIATE,
+}
+
+// literal relocations are only used by directives
+#[allow(dead_code, clippy::upper_case_acronyms)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Relocation {
+    B = 0,
+    BCOND = 1,
+    ADR = 2,
+    ADRP = 3,
+    TBZ = 4,
+    LITERAL8 = 5,
+    LITERAL16 = 6,
+    LITERAL32 = 8,
+    LITERAL64 = 12,
+}
+
+impl Relocation {
+    fn to_id(self) -> u8 {
+        self as u8
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum SysRegAccess {
+    Read,
+    Write,
+    ReadWrite,
+}
+
+pub(crate) struct Opdata {
+    pub(crate) base: u32,
+    pub(crate) matchers: &'static [Matcher],
+    pub(crate) commands: &'static [Command],
+}
+
+// the features an instruction requires are not checked at runtime, so they're ignored here
+macro_rules! SingleOp {
+    ( $base:expr, [ $( $matcher:expr ),* ], [ $( $command:expr ),* ] $( , $features:expr )? ) => {
+        {
+            const MATCHERS: &'static [Matcher] = {
+                #[allow(unused_imports)]
+                use self::Matcher::*;
+                &[ $(
+                    $matcher
+                ),* ]
+            };
+            const COMMANDS: &'static [Command] = {
+                #[allow(unused_imports)]
+                use self::Command::*;
+                &[ $(
+                    $command
+                ),* ]
+            };
+            Opdata {
+                base: $base,
+                matchers: MATCHERS,
+                commands: COMMANDS,
+            }
+        }
+    }
+}
+
+macro_rules! Ops {
+    ( $( $name:tt = [ $( $base:tt = [ $( $matcher:expr ),* ] => [ $( $command:expr ),* ] $( , $features:expr )? ; )+ ] )* ) => {
+        [ $(
+            (
+                $name,
+                &[ $(
+                    SingleOp!( $base, [ $( $matcher ),* ], [ $( $command ),* ] $( , $features )? )
+                ),+ ] as &[_]
+            )
+        ),* ]
+    }
+}
+
+/// All instructions and their forms, in the order of the instruction data
+pub(crate) fn instructions() -> &'static [(&'static str, &'static [Opdata])] {
+    use self::Modifier::*;
+    use self::SpecialComm::*;
+    use self::Relocation::*;
+
+    const BYTE: Size = Size::Byte;
+    const WORD: Size = Size::Word;
+    const DWORD: Size = Size::DWord;
+    const QWORD: Size = Size::QWord;
+    const OWORD: Size = Size::OWord;
+
+    const EXTENDS: &[Modifier] = &[UXTB, UXTH, UXTW, UXTX, SXTB, SXTH, SXTW, SXTX, LSL];
+    const EXTENDS_W: &[Modifier] = &[UXTB, UXTH, UXTW, SXTB, SXTH, SXTW];
+    const EXTENDS_X: &[Modifier] = &[UXTX, SXTX, LSL];
+    const SHIFTS: &[Modifier] = &[LSL, LSR, ASR];
+    const ROTATES: &[Modifier] = &[LSL, LSR, ASR, ROR];
+
+    static MAP: &[(&str, &[Opdata])] = &dynasm_data::aarch64_opmap!(Ops);
+    MAP
+}
+
+fn opmap() -> &'static HashMap<&'static str, &'static [Opdata]> {
+    static OPMAP: OnceLock<HashMap<&'static str, &'static [Opdata]>> = OnceLock::new();
+    OPMAP.get_or_init(|| instructions().iter().cloned().collect())
+}
+
+/// A map of existing condition codes and their normal encoding
+fn cond_map() -> &'static HashMap<&'static str, u8> {
+    static COND_MAP: OnceLock<HashMap<&'static str, u8>> = OnceLock::new();
+    COND_MAP.get_or_init(|| {
+        static MAP: &[(&str, u8)] = &[
+            ("eq", 0),
+            ("ne", 1),
+            ("cs", 2),
+            ("hs", 2),
+            ("cc", 3),
+            ("lo", 3),
+            ("mi", 4),
+            ("pl", 5),
+            ("vs", 6),
+            ("vc", 7),
+            ("hi", 8),
+            ("ls", 9),
+            ("ge", 10),
+            ("lt", 11),
+            ("gt", 12),
+            ("le", 13),
+            ("al", 14),
+            ("nv", 15),
+        ];
+        MAP.iter().cloned().collect()
+    })
+}
+
+/// Named system registers, their o0:op1:CRn:CRm:op2 encoding and how they can be accessed
+pub(crate) fn sysregs() -> &'static [(&'static str, u32, SysRegAccess)] {
+    use self::SysRegAccess::*;
+
+    static MAP: &[(&str, u32, SysRegAccess)] = &dynasm_data::aarch64_sysregs!();
+    MAP
+}
+
+/// A map of named system registers to their o0:op1:CRn:CRm:op2 encoding and how they can be accessed
+fn sysreg_map() -> &'static HashMap<&'static str, (u32, SysRegAccess)> {
+    static SYSREG_MAP: OnceLock<HashMap<&'static str, (u32, SysRegAccess)>> = OnceLock::new();
+    SYSREG_MAP.get_or_init(|| sysregs().iter().map(|&(name, bits, access)| (name, (bits, access))).collect())
+}
+
+/// Lists of special identifiers and their encodings, by list name
+pub(crate) fn special_idents() -> &'static [(&'static str, &'static [(&'static str, u32)])] {
+    static MAP: &[(&str, &[(&str, u32)])] = &dynasm_data::aarch64_special_idents!();
+    MAP
+}
+
+/// Lists of special identifiers and their encodings, keyed by list name
+fn special_ident_map() -> &'static HashMap<&'static str, HashMap<&'static str, u32>> {
+    static SPECIAL_IDENT_MAP: OnceLock<HashMap<&'static str, HashMap<&'static str, u32>>> = OnceLock::new();
+    SPECIAL_IDENT_MAP.get_or_init(|| {
+        special_idents().iter().map(|&(name, idents)| (name, idents.iter().cloned().collect())).collect()
+    })
+}
@@ -0,0 +1,70 @@
+//! Runtime instruction encoders. These encode single instructions from mnemonics and operands that are
+//! only known at runtime, using the same instruction data as the `dynasm!` macro. They are used through
+//! the `encode` functions in the `x64` and `aarch64` modules.
+
+pub mod x64;
+pub mod aarch64;
+
+use std::error;
+use std::fmt;
+
+use crate::{DynasmLabelApi, TargetKind};
+use crate::relocations::Relocation;
+
+
+/// The errors that can occur while encoding an instruction at runtime.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EncodeError {
+    /// No instruction with this mnemonic exists
+    UnknownMnemonic(String),
+    /// None of the forms of the instruction accept the given operands
+    OperandMismatch(&'static str),
+    /// An operand matched the instruction, but its value cannot be encoded
+    InvalidOperand(String),
+    /// The instruction form exists, but cannot be encoded by the runtime encoder
+    Unsupported(String),
+}
+
+impl fmt::Display for EncodeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            EncodeError::UnknownMnemonic(name) => write!(f, "Unknown instruction mnemonic '{}'", name),
+            EncodeError::OperandMismatch(name) => write!(f, "'{}': argument type/size mismatch", name),
+            EncodeError::InvalidOperand(msg) => write!(f, "Invalid operand: {}", msg),
+            EncodeError::Unsupported(msg) => write!(f, "Unsupported: {}", msg),
+        }
+    }
+}
+
+impl error::Error for EncodeError {
+    fn description(&self) -> &str {
+        match self {
+            EncodeError::UnknownMnemonic(_) => "Unknown instruction mnemonic",
+            EncodeError::OperandMismatch(_) => "Argument type/size mismatch",
+            EncodeError::InvalidOperand(_) => "Invalid operand",
+            EncodeError::Unsupported(_) => "Unsupported instruction form",
+        }
+    }
+}
+
+
+/// Checks that a label operand refers to something a relocation can target.
+fn check_target(target: TargetKind) -> Result<TargetKind, EncodeError> {
+    match target {
+        TargetKind::Managed => Err(EncodeError::InvalidOperand("a managed relocation is not a jump target".into())),
+        target => Ok(target)
+    }
+}
+
+/// Emits a relocation to `target` for the instruction that was just pushed into `ops`.
+pub(crate) fn emit_relocation<O>(ops: &mut O, target: TargetKind, kind: <O::Relocation as Relocation>::Encoding)
+where O: DynasmLabelApi {
+    match target {
+        TargetKind::Forward(name) => ops.forward_reloc(name, 0, kind),
+        TargetKind::Backward(name) => ops.backward_reloc(name, 0, kind),
+        TargetKind::Global(name) => ops.global_reloc(name, 0, kind),
+        TargetKind::Dynamic(id) => ops.dynamic_reloc(id, 0, kind),
+        TargetKind::Extern(target) => ops.bare_reloc(target, kind),
+        TargetKind::Managed => unreachable!("managed targets are rejected when cleaning operands"),
+    }
+}
@@ -0,0 +1,1637 @@
+//! A runtime encoder for x64 instructions, driven by the same instruction data as the `dynasm!` macro.
+//!
+//! This encoder supports all instructions available in 64-bit mode, encoded with legacy, REX, REX2, VEX,
+//! XOP or EVEX prefixes. This includes the AVX-512 registers and decorations, as well as the APX extended
+//! general purpose registers and new data destination forms.
+
+use std::collections::HashMap;
+use std::convert::TryFrom;
+use std::fmt;
+use std::str::FromStr;
+use std::sync::OnceLock;
+
+use crate::{DynasmLabelApi, TargetKind};
+use crate::x64::X64Relocation;
+use dynasm_data::x64::flags::*;
+use super::{EncodeError, check_target, emit_relocation};
+
+
+/// The size of an operand.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Size {
+    Byte = 1,
+    Word = 2,
+    DWord = 4,
+    FWord = 6,
+    QWord = 8,
+    PWord = 10,
+    OWord = 16,
+    HWord = 32,
+    ZWord = 64,
+}
+
+impl Size {
+    /// The size in bytes
+    pub fn in_bytes(self) -> u8 {
+        self as u8
+    }
+}
+
+/// A register operand. Registers are named by the same families as dynamic registers in `dynasm!`,
+/// with the register number as argument, so `Rq(0)` is `rax` and `Rx(1)` is `xmm1`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Register {
+    /// 8-bit general purpose registers (al - r31b)
+    Rb(u8),
+    /// high byte registers, numbered 4 - 7 (ah, ch, dh, bh)
+    Rh(u8),
+    /// 16-bit general purpose registers (ax - r31w)
+    Rw(u8),
+    /// 32-bit general purpose registers (eax - r31d)
+    Rd(u8),
+    /// 64-bit general purpose registers (rax - r31)
+    Rq(u8),
+    /// x87 floating point stack registers (st0 - st7)
+    Rf(u8),
+    /// mmx registers (mm0 - mm7)
+    Rm(u8),
+    /// 128-bit vector registers (xmm0 - xmm31)
+    Rx(u8),
+    /// 256-bit vector registers (ymm0 - ymm31)
+    Ry(u8),
+    /// 512-bit vector registers (zmm0 - zmm31)
+    Rz(u8),
+    /// segment registers (es, cs, ss, ds, fs, gs)
+    Rs(u8),
+    /// control registers (cr0 - cr15)
+    RC(u8),
+    /// debug registers (dr0 - dr15)
+    RD(u8),
+    /// bound registers (bnd0 - bnd3)
+    RB(u8),
+    /// opmask registers (k0 - k7)
+    Rk(u8),
+    /// tile registers (tmm0 - tmm7)
+    Rt(u8),
+    /// the instruction pointer, only usable as base of a memory reference
+    Rip,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum RegFamily {
+    Legacy,
+    Rip,
+    HighByte,
+    Fp,
+    Mmx,
+    Xmm,
+    Segment,
+    Control,
+    Debug,
+    Bound,
+    Opmask,
+    Tile,
+}
+
+impl Register {
+    // returns the family, size and number of this register, as well as the amount of registers in its family
+    fn info(self) -> (RegFamily, Size, u8, u8) {
+        match self {
+            Register::Rb(n) => (RegFamily::Legacy, Size::Byte, n, 32),
+            Register::Rh(n) => (RegFamily::HighByte, Size::Byte, n, 8),
+            Register::Rw(n) => (RegFamily::Legacy, Size::Word, n, 32),
+            Register::Rd(n) => (RegFamily::Legacy, Size::DWord, n, 32),
+            Register::Rq(n) => (RegFamily::Legacy, Size::QWord, n, 32),
+            Register::Rf(n) => (RegFamily::Fp, Size::PWord, n, 8),
+            Register::Rm(n) => (RegFamily::Mmx, Size::QWord, n, 8),
+            Register::Rx(n) => (RegFamily::Xmm, Size::OWord, n, 32),
+            Register::Ry(n) => (RegFamily::Xmm, Size::HWord, n, 32),
+            Register::Rz(n) => (RegFamily::Xmm, Size::ZWord, n, 32),
+            Register::Rs(n) => (RegFamily::Segment, Size::Word, n, 6),
+            Register::RC(n) => (RegFamily::Control, Size::QWord, n, 16),
+            Register::RD(n) => (RegFamily::Debug, Size::QWord, n, 16),
+            Register::RB(n) => (RegFamily::Bound, Size::OWord, n, 4),
+            Register::Rk(n) => (RegFamily::Opmask, Size::QWord, n, 8),
+            Register::Rt(n) => (RegFamily::Tile, Size::ZWord, n, 8),
+            Register::Rip => (RegFamily::Rip, Size::QWord, 5, 8),
+        }
+    }
+
+    fn family(self) -> RegFamily {
+        self.info().0
+    }
+
+    pub(crate) fn size(self) -> Size {
+        self.info().1
+    }
+
+    fn code(self) -> u8 {
+        self.info().2
+    }
+
+    // registers that need a REX prefix to be encoded
+    fn is_extended(self) -> bool {
+        match self.family() {
+            RegFamily::Legacy
+            | RegFamily::Xmm
+            | RegFamily::Control
+            | RegFamily::Debug => self.code() > 7,
+            _ => false
+        }
+    }
+
+    // xmm16-xmm31 can only be encoded using EVEX
+    fn is_evex_only(self) -> bool {
+        self.family() == RegFamily::Xmm && self.code() > 15
+    }
+
+    // r16-r31 can only be encoded using REX2 or EVEX
+    fn is_apx_extended(self) -> bool {
+        self.family() == RegFamily::Legacy && self.code() > 15
+    }
+
+    fn validate(self) -> Result<(), EncodeError> {
+        let (family, _, code, count) = self.info();
+        let valid = if family == RegFamily::HighByte {
+            (4 .. count).contains(&code)
+        } else {
+            code < count
+        };
+        if valid {
+            Ok(())
+        } else {
+            Err(EncodeError::InvalidOperand(format!("{:?} is not a valid register", self)))
+        }
+    }
+}
+
+/// A memory reference of the form `size [base + index * scale + disp]`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct MemoryRef {
+    /// The size of the referenced memory, if it should be specified
+    pub size: Option<Size>,
+    /// The base register
+    pub base: Option<Register>,
+    /// The index register and its scale
+    pub index: Option<(Register, u8)>,
+    /// The displacement
+    pub disp: i32,
+}
+
+/// An operand to an instruction.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Operand {
+    /// A register
+    Reg(Register),
+    /// A memory reference
+    Mem(MemoryRef),
+    /// An immediate. Its size is derived from its value
+    Imm(i64),
+    /// A jump target (`=>label`, `->label`, `>label`, `<label` or `extern address`)
+    Label(TargetKind),
+    /// A rip-relative memory reference to a label (`[=>label]`), with an optional size
+    LabelMem(TargetKind, Option<Size>),
+}
+
+/// Legacy prefixes that can be put in front of an instruction.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Prefix {
+    Lock,
+    Rep,
+    Repe,
+    Repne,
+    Cs,
+    Ds,
+    Es,
+    Fs,
+    Gs,
+    Ss,
+}
+
+/// Embedded rounding control of an AVX-512 instruction. All of these also suppress floating point exceptions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Rounding {
+    /// `{rn-sae}`: round to nearest (even)
+    Nearest = 0,
+    /// `{rd-sae}`: round down (toward -inf)
+    Down = 1,
+    /// `{ru-sae}`: round up (toward +inf)
+    Up = 2,
+    /// `{rz-sae}`: round toward zero
+    Zero = 3,
+    /// `{sae}`: only suppress all exceptions, without a rounding override
+    Sae = 4,
+}
+
+/// The AVX-512 decorations of an EVEX encoded instruction.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Decorations {
+    /// The opmask applied to the destination operand (`{k1}`)
+    pub mask: Option<Register>,
+    /// Use zeroing-masking instead of merging-masking (`{z}`)
+    pub zeroing: bool,
+    /// The element count of a broadcasted memory operand (`{1to16}`)
+    pub broadcast: Option<u8>,
+    /// Embedded rounding control (`{rn-sae}`, `{sae}`)
+    pub rounding: Option<Rounding>,
+}
+
+impl Decorations {
+    fn is_empty(&self) -> bool {
+        self.mask.is_none() && !self.zeroing && self.broadcast.is_none() && self.rounding.is_none()
+    }
+}
+
+/// An instruction mnemonic, resolved by name from the x64 instruction data.
+#[derive(Clone, Copy)]
+pub struct Mnemonic {
+    name: &'static str,
+    data: &'static [Opdata],
+}
+
+impl Mnemonic {
+    /// Look up an instruction mnemonic by its name, like `add` or `vpaddd`
+    pub fn from_name(name: &str) -> Option<Mnemonic> {
+        opmap().get_key_value(name).map(|(&name, &data)| Mnemonic { name, data })
+    }
+
+    /// The name of this mnemonic
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+}
+
+impl FromStr for Mnemonic {
+    type Err = EncodeError;
+
+    fn from_str(name: &str) -> Result<Mnemonic, EncodeError> {
+        Mnemonic::from_name(name).ok_or_else(|| EncodeError::UnknownMnemonic(name.to_string()))
+    }
+}
+
+impl fmt::Debug for Mnemonic {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Mnemonic({})", self.name)
+    }
+}
+
+impl PartialEq for Mnemonic {
+    fn eq(&self, other: &Mnemonic) -> bool {
+        self.name == other.name
+    }
+}
+
+impl Eq for Mnemonic {}
+
+
+/// Encode a single instruction into `ops`. Any labels used are referenced through relocations, which will be
+/// resolved when the assembler commits.
+pub fn encode<O>(ops: &mut O, mnemonic: Mnemonic, operands: &[Operand]) -> Result<(), EncodeError>
+where O: DynasmLabelApi<Relocation = X64Relocation> {
+    encode_with_prefixes(ops, &[], mnemonic, operands)
+}
+
+/// Encode a single instruction into `ops`, with the given legacy prefixes in front of it.
+pub fn encode_with_prefixes<O>(ops: &mut O, prefixes: &[Prefix], mnemonic: Mnemonic, operands: &[Operand]) -> Result<(), EncodeError>
+where O: DynasmLabelApi<Relocation = X64Relocation> {
+    encode_with_decorations(ops, prefixes, &Decorations::default(), mnemonic, operands)
+}
+
+/// Encode a single instruction into `ops`, with the given legacy prefixes in front of it and the given AVX-512
+/// decorations applied to it. Decorations are only accepted by EVEX encoded instruction forms.
+pub fn encode_with_decorations<O>(ops: &mut O, prefixes: &[Prefix], decorations: &Decorations, mnemonic: Mnemonic, operands: &[Operand])
+-> Result<(), EncodeError>
+where O: DynasmLabelApi<Relocation = X64Relocation> {
+    let args = operands.iter().map(clean_operand).collect::<Result<Vec<_>, _>>()?;
+    check_masking(decorations, &args)?;
+
+    // find a matching op
+    let data = match mnemonic.data.iter().find(|data| match_format_string(data, &args, decorations)) {
+        Some(data) => data,
+        None => return Err(EncodeError::OperandMismatch(mnemonic.name))
+    };
+
+    let (buffer, relocations) = compile_operation(data, prefixes, decorations, args)?;
+
+    let len = buffer.len() as u8;
+    ops.extend(buffer);
+    for (label, end, size, opcode_len) in relocations {
+        emit_relocation(ops, label, (len - end, size, len, opcode_len));
+    }
+    Ok(())
+}
+
+
+/// Encodes an instruction with the first of `forms` that matches the operands, without emitting it. Labels
+/// are not supported. Used by the disassembler to verify its output.
+#[cfg(feature = "disasm")]
+pub(crate) fn encode_form(forms: &'static [Opdata], prefixes: &[Prefix], operands: &[Operand]) -> Option<Vec<u8>> {
+    let args = operands.iter().map(clean_operand).collect::<Result<Vec<_>, _>>().ok()?;
+    let decorations = Decorations::default();
+    let data = forms.iter().find(|data| match_format_string(data, &args, &decorations))?;
+    match compile_operation(data, prefixes, &decorations, args) {
+        Ok((buffer, relocations)) if relocations.is_empty() => Some(buffer),
+        _ => None
+    }
+}
+
+
+/*
+ * Operand preprocessing
+ */
+
+#[derive(Debug, Clone, Copy)]
+enum CleanArg {
+    Direct(Register),
+    Indirect {
+        size: Option<Size>,
+        base: Option<Register>,
+        index: Option<(Register, u8)>,
+        disp: i32,
+    },
+    IndirectJumpTarget(TargetKind, Option<Size>),
+    Immediate(i64),
+    JumpTarget(TargetKind),
+}
+
+#[derive(Debug, Clone, Copy)]
+enum SizedArg {
+    Direct(Register),
+    Indirect {
+        size: Size,
+        base: Option<Register>,
+        index: Option<(Register, u8)>,
+        disp: i32,
+    },
+    IndirectJumpTarget(TargetKind),
+    Immediate(i64, Size),
+    JumpTarget(TargetKind, Size),
+}
+
+fn clean_operand(operand: &Operand) -> Result<CleanArg, EncodeError> {
+    Ok(match *operand {
+        Operand::Reg(reg) => {
+            reg.validate()?;
+            if reg == Register::Rip {
+                return Err(EncodeError::InvalidOperand("rip can only be used in memory references".into()));
+            }
+            CleanArg::Direct(reg)
+        },
+        Operand::Mem(ref mem) => {
+            let mut base = mem.base;
+            let mut index = mem.index;
+            if let Some(reg) = base {
+                reg.validate()?;
+            }
+            if let Some((reg, _)) = index {
+                reg.validate()?;
+            }
+            sanitize_indirect(&mut base, &mut index)?;
+            CleanArg::Indirect { size: mem.size, base, index, disp: mem.disp }
+        },
+        Operand::Imm(value) => CleanArg::Immediate(value),
+        Operand::Label(label) => CleanArg::JumpTarget(check_target(label)?),
+        Operand::LabelMem(label, size) => CleanArg::IndirectJumpTarget(check_target(label)?, size),
+    })
+}
+
+/// Validates that the opmask decoration is an opmask register that can be used for masking, and that
+/// zeroing-masking is not applied to a memory destination.
+fn check_masking(decorations: &Decorations, args: &[CleanArg]) -> Result<(), EncodeError> {
+    if decorations.zeroing && matches!(args.first(), Some(CleanArg::Indirect {..})) {
+        return Err(EncodeError::InvalidOperand("Zeroing cannot be applied to memory references".into()));
+    }
+
+    match decorations.mask {
+        Some(reg) if reg.family() != RegFamily::Opmask =>
+            Err(EncodeError::InvalidOperand(format!("{:?} is not an opmask register", reg))),
+        Some(reg) if reg.code() == 0 => Err(EncodeError::InvalidOperand("k0 cannot be used as an opmask".into())),
+        Some(reg) => reg.validate(),
+        None => Ok(())
+    }
+}
+
+/// Validates that the base/index combination can actually be encoded in 64-bit mode, and canonicalizes it.
+fn sanitize_indirect(base: &mut Option<Register>, index: &mut Option<(Register, u8)>) -> Result<(), EncodeError> {
+    let b = base.map(|b| (b.family(), b.size()));
+    let i = index.map(|i| (i.0.family(), i.0.size()));
+
+    let size;
+    let family;
+    let mut vsib_mode = false;
+
+    // figure out the addressing mode and size
+    match (b, i) {
+        (None, None) => return Ok(()),
+        (Some((f, s)), None) |
+        (None, Some((f, s))) => {
+            size = s;
+            family = f;
+        },
+        (Some((f1, s1)), Some((f2, s2))) => if f1 == f2 {
+            if s1 != s2 {
+                return Err(EncodeError::InvalidOperand("Registers of differing sizes".into()));
+            }
+            size = s1;
+            family = f1;
+
+        // allow only vsib addressing
+        } else if f1 == RegFamily::Xmm {
+            vsib_mode = true;
+            size = s2;
+            family = f2;
+        } else if f2 == RegFamily::Xmm {
+            vsib_mode = true;
+            size = s1;
+            family = f1;
+        } else {
+            return Err(EncodeError::InvalidOperand("Register type combination not supported".into()));
+        }
+    }
+
+    // filter out combinations that are impossible to encode
+    match family {
+        RegFamily::Rip | RegFamily::Xmm => if b.is_some() && i.is_some() {
+            return Err(EncodeError::InvalidOperand("Register type combination not supported".into()));
+        },
+        RegFamily::Legacy => match size {
+            Size::DWord | Size::QWord => (),
+            Size::Word => return Err(EncodeError::InvalidOperand("16-bit addressing is not supported in this mode".into())),
+            _ => return Err(EncodeError::InvalidOperand("Register type not supported".into()))
+        },
+        _ => return Err(EncodeError::InvalidOperand("Register type not supported".into()))
+    }
+
+    if let Some((_, scale)) = *index {
+        if encode_scale(scale).is_none() && !(base.is_none() && [3, 5, 9].contains(&scale)) {
+            return Err(EncodeError::InvalidOperand("Impossible scale".into()));
+        }
+    }
+
+    // RIP-relative encoding
+    if family == RegFamily::Rip {
+        match index.take() {
+            Some((index, 1)) => *base = Some(index),
+            Some(_) => return Err(EncodeError::InvalidOperand("RIP cannot be scaled".into())),
+            None => ()
+        }
+        return Ok(());
+    }
+
+    // VSIB without base
+    if family == RegFamily::Xmm {
+        if let Some(reg) = base.take() {
+            *index = Some((reg, 1));
+        }
+        return Ok(());
+    }
+
+    // VSIB with base
+    if vsib_mode {
+        // the other register is a legacy register, so we just have to check if an index/base swap is necessary
+        if let Some(b) = base.filter(|b| b.family() == RegFamily::Xmm) {
+            match *index {
+                Some((i, 1)) => {
+                    *base = Some(i);
+                    *index = Some((b, 1));
+                },
+                _ => return Err(EncodeError::InvalidOperand("vsib addressing requires a general purpose register as base".into()))
+            }
+        }
+        return Ok(());
+    }
+
+    // optimize indexes if a base is not present
+    if base.is_none() {
+        if let Some((reg, ref mut scale)) = *index {
+            if let 2 | 3 | 5 | 9 = *scale {
+                *base = Some(reg);
+                *scale -= 1;
+            }
+        }
+    }
+
+    // RSP as index field can not be represented. Check if we can swap it with base
+    if let Some((i, scale)) = index.take() {
+        if i.code() == 4 {
+            if base.is_none_or(|b| b.code() != 4) && scale == 1 {
+                *index = base.take().map(|reg| (reg, 1));
+                *base = Some(i);
+            } else {
+                return Err(EncodeError::InvalidOperand("'rsp' cannot be used as index field".into()));
+            }
+        } else {
+            *index = Some((i, scale));
+        }
+    }
+
+    // RSP or R12 as base without index (add an index so we escape into SIB)
+    if index.is_none() && base.is_some_and(|b| b.code() & 7 == 4) {
+        *index = Some((Register::Rq(4), 1));
+    }
+
+    // RBP or R13 as base field just requires a mandatory MOD_DISP8, so we only process that at encoding time
+    Ok(())
+}
+
+// The smallest size that can hold a signed value
+fn derive_size(value: i64) -> Size {
+    if i64::from(value as i8) == value {
+        Size::Byte
+    } else if i64::from(value as i16) == value {
+        Size::Word
+    } else if i64::from(value as i32) == value {
+        Size::DWord
+    } else {
+        Size::QWord
+    }
+}
+
+fn encode_scale(scale: u8) -> Option<u8> {
+    match scale {
+        1 => Some(0),
+        2 => Some(1),
+        4 => Some(2),
+        8 => Some(3),
+        _ => None
+    }
+}
+
+
+/*
+ * Matching
+ */
+
+fn match_format_string(fmt: &Opdata, args: &[CleanArg], decorations: &Decorations) -> bool {
+    // these forms cannot be encoded in 64-bit mode
+    if fmt.flags & X86_ONLY != 0 {
+        return false;
+    }
+
+    // AVX-512 decorations can only be encoded in an EVEX prefix, and only if the instruction supports them
+    if fmt.flags & EVEX_OP != 0 {
+        if (decorations.mask.is_some() && fmt.flags & OPMASK == 0) ||
+           (decorations.zeroing && fmt.flags & ZEROING == 0) ||
+           (decorations.broadcast.is_some() && fmt.flags & BCST == 0) {
+            return false;
+        }
+        let rounding_flag = match decorations.rounding {
+            Some(Rounding::Sae) => SAE,
+            Some(_) => ROUNDING,
+            None => DEFAULT
+        };
+        if fmt.flags & rounding_flag != rounding_flag {
+            return false;
+        }
+    } else if !decorations.is_empty() {
+        return false;
+    } else if args.iter().any(|arg| match *arg {
+        CleanArg::Direct(reg) => reg.is_evex_only(),
+        CleanArg::Indirect {index: Some((reg, _)), ..} => reg.is_evex_only(),
+        _ => false
+    }) {
+        // xmm16-xmm31 require an EVEX encoded instruction
+        return false;
+    }
+
+    // r16-r31 can only be encoded using REX2 or EVEX
+    if fmt.flags & (VEX_OP | XOP_OP) != 0 && args.iter().any(|arg| match *arg {
+        CleanArg::Direct(reg) => reg.is_apx_extended(),
+        CleanArg::Indirect {base, index, ..} =>
+            base.is_some_and(Register::is_apx_extended) ||
+            index.is_some_and(|(reg, _)| reg.is_apx_extended()),
+        _ => false
+    }) {
+        return false;
+    }
+
+    if fmt.args.len() != args.len() * 2 {
+        return false;
+    }
+
+    // see the format string documentation in the x64 compiler of the plugin
+    for (&arg, (code, fsize)) in args.iter().zip(FormatStringIterator::new(fmt.args)) {
+        let size = match (code, arg) {
+            // immediates
+            (b'i', CleanArg::Immediate(value)) |
+            (b'o', CleanArg::Immediate(value)) => Some(derive_size(value)),
+            (b'o', CleanArg::JumpTarget(_)) => None,
+
+            // specific legacy regs
+            (x @ b'A' ..= b'P', CleanArg::Direct(reg)) if
+                reg.family() == RegFamily::Legacy &&
+                reg.code() == x - b'A' => Some(reg.size()),
+
+            // specific segment regs
+            (x @ b'Q' ..= b'V', CleanArg::Direct(reg)) if
+                reg.family() == RegFamily::Segment &&
+                reg.code() == x - b'Q' => Some(reg.size()),
+
+            // CR8 can be specially referenced
+            (b'W', CleanArg::Direct(reg)) if
+                reg == Register::RC(8) => Some(reg.size()),
+
+            // top of the fp stack is also often used
+            (b'X', CleanArg::Direct(reg)) if
+                reg == Register::Rf(0) => Some(reg.size()),
+
+            // generic legacy regs
+            (b'r', CleanArg::Direct(reg)) |
+            (b'v', CleanArg::Direct(reg)) if
+                reg.family() == RegFamily::Legacy ||
+                reg.family() == RegFamily::HighByte => Some(reg.size()),
+
+            // other reg types often mixed with memory refs
+            (b'x', CleanArg::Direct(reg)) |
+            (b'u', CleanArg::Direct(reg)) if
+                reg.family() == RegFamily::Mmx => Some(reg.size()),
+            (b'y', CleanArg::Direct(reg)) |
+            (b'w', CleanArg::Direct(reg)) if
+                reg.family() == RegFamily::Xmm => Some(reg.size()),
+
+            // other reg types
+            (b'f', CleanArg::Direct(reg)) if
+                reg.family() == RegFamily::Fp => Some(reg.size()),
+            (b's', CleanArg::Direct(reg)) if
+                reg.family() == RegFamily::Segment => Some(reg.size()),
+            (b'c', CleanArg::Direct(reg)) if
+                reg.family() == RegFamily::Control => Some(reg.size()),
+            (b'd', CleanArg::Direct(reg)) if
+                reg.family() == RegFamily::Debug => Some(reg.size()),
+            (b'b', CleanArg::Direct(reg)) if
+                reg.family() == RegFamily::Bound => Some(reg.size()),
+            (b'j', CleanArg::Direct(reg)) if
+                reg.family() == RegFamily::Opmask => Some(reg.size()),
+            (b't', CleanArg::Direct(reg)) if
+                reg.family() == RegFamily::Tile => Some(reg.size()),
+
+            // broadcasted memory references only reference a single element. Their size is
+            // checked when the element size is known.
+            (b'm',          CleanArg::Indirect {index, ..}) |
+            (b'u' ..= b'w', CleanArg::Indirect {index, ..}) if
+                decorations.broadcast.is_some() &&
+                index.is_none_or(|(i, _)| i.family() != RegFamily::Xmm) => None,
+
+            // memory offsets
+            (b'm',          CleanArg::Indirect {size, index, ..}) |
+            (b'u' ..= b'w', CleanArg::Indirect {size, index, ..}) if
+                index.is_none_or(|(i, _)| i.family() != RegFamily::Xmm) => size,
+
+            (b'm',          CleanArg::IndirectJumpTarget(_, size)) |
+            (b'u' ..= b'w', CleanArg::IndirectJumpTarget(_, size)) => size,
+
+            // vsib addressing. as they have two sizes that must be checked they check one of the sizes here
+            (b'k', CleanArg::Indirect {size, index: Some((index, _)), ..}) if
+                (size.is_none() || size == Some(Size::DWord)) &&
+                index.family() == RegFamily::Xmm => Some(index.size()),
+            (b'l', CleanArg::Indirect {size, index: Some((index, _)), ..}) if
+                (size.is_none() || size == Some(Size::QWord)) &&
+                index.family() == RegFamily::Xmm => Some(index.size()),
+            _ => return false
+        };
+
+        // if size is none it always matches (and will later be coerced to a more specific type if the match is successful)
+        if let Some(size) = size {
+            if !match (fsize, code) {
+                // immediates can always fit in larger slots. Offsets too, as their size is derived from their value
+                (b'w', b'i') => size <= Size::Word,
+                (b'd', b'i') |
+                (b'd', b'o') => size <= Size::DWord,
+                (b'q', b'i') => size <= Size::QWord,
+                (b'*', b'i') => size <= Size::DWord,
+                // normal size matches
+                (b'b', _)    => size == Size::Byte,
+                (b'w', _)    => size == Size::Word,
+                (b'd', _)    => size == Size::DWord,
+                (b'q', _)    => size == Size::QWord,
+                (b'f', _)    => size == Size::FWord,
+                (b'p', _)    => size == Size::PWord,
+                (b'o', _)    => size == Size::OWord,
+                (b'h', _)    => size == Size::HWord,
+                (b'z', _)    => size == Size::ZWord,
+                // what is allowed for wildcards
+                (b'*', b'k') |
+                (b'*', b'l') |
+                (b'*', b'y') |
+                (b'*', b'w') => size == Size::OWord || size == Size::HWord ||
+                    (size == Size::ZWord && fmt.flags & EVEX_OP != 0),
+                (b'*', b'r') |
+                (b'*', b'A' ..= b'P') |
+                (b'*', b'v') => size == Size::Word || size == Size::DWord || size == Size::QWord,
+                (b'*', b'm') => true,
+                (b'?', _)    => true,
+                _ => false
+            } {
+                return false;
+            }
+        } else if fsize != b'*' && fmt.flags & EXACT_SIZE != 0 {
+            // this format is a more specific version of an instruction that also has more general versions.
+            // It should only be picked if the size constraints are met, not if the size is unspecified
+            return false;
+        }
+    }
+
+    true
+}
+
+fn size_operands(fmt: &Opdata, args: Vec<CleanArg>) -> Result<(Option<Size>, Vec<SizedArg>), EncodeError> {
+    // sizing operands requires two passes.
+    // In the first one, we determine the effective operand size if necessary (if *'s are present)
+    // In the second one, we create the final sized args
+
+    let mut has_arg = false;
+    let mut op_size = None;
+    let mut im_size = None;
+
+    for (&arg, (_, fsize)) in args.iter().zip(FormatStringIterator::new(fmt.args)) {
+        if fsize != b'*' {
+            continue;
+        }
+
+        let size = match arg {
+            CleanArg::Direct(reg) => Some(reg.size()),
+            CleanArg::IndirectJumpTarget(_, size) => size,
+            // VSIB addressing
+            CleanArg::Indirect {index: Some((reg, _)), ..} if reg.family() == RegFamily::Xmm => Some(reg.size()),
+            CleanArg::Indirect {size, ..} => size,
+            CleanArg::Immediate(value) => {
+                im_size = Some(derive_size(value));
+                continue;
+            },
+            CleanArg::JumpTarget(_) => continue,
+        };
+
+        has_arg = true;
+        if let Some(size) = size {
+            if op_size.is_some_and(|s| s != size) {
+                return Err(EncodeError::InvalidOperand("Conflicting operand sizes".into()));
+            }
+            op_size = Some(size);
+        }
+    }
+
+    if let Some(o) = op_size {
+        let ref_im_size = if o > Size::DWord {Size::DWord} else {o};
+        if let Some(i) = im_size {
+            if i > ref_im_size {
+                return Err(EncodeError::InvalidOperand("Immediate size mismatch".into()));
+            }
+        }
+        im_size = Some(ref_im_size);
+    } else if has_arg {
+        return Err(EncodeError::InvalidOperand("Unknown operand size".into()));
+    }
+
+    let mut new_args = Vec::new();
+    for (arg, (code, fsize)) in args.into_iter().zip(FormatStringIterator::new(fmt.args)) {
+        // get the specified operand size from the format string
+        let size = match (fsize, code) {
+            (b'b', _) => Size::Byte,
+            (b'w', _) => Size::Word,
+            (_, b'k') |
+            (b'd', _) => Size::DWord,
+            (_, b'l') |
+            (b'q', _) => Size::QWord,
+            (b'f', _) => Size::FWord,
+            (b'p', _) => Size::PWord,
+            (b'o', _) => Size::OWord,
+            (b'h', _) => Size::HWord,
+            (b'z', _) => Size::ZWord,
+            (b'*', b'i') => im_size.unwrap_or(Size::DWord),
+            (b'*', _) => op_size.unwrap_or(Size::Byte),
+            _ => Size::Byte, // placeholder, never used
+        };
+
+        new_args.push(match arg {
+            CleanArg::Direct(reg) => SizedArg::Direct(reg),
+            CleanArg::JumpTarget(label) => SizedArg::JumpTarget(label, size),
+            CleanArg::IndirectJumpTarget(label, _) => SizedArg::IndirectJumpTarget(label),
+            CleanArg::Immediate(value) => SizedArg::Immediate(value, size),
+            CleanArg::Indirect {base, index, disp, ..} => SizedArg::Indirect {size, base, index, disp},
+        });
+    }
+
+    Ok((op_size, new_args))
+}
+
+
+/*
+ * Encoding
+ */
+
+const MOD_DIRECT: u8 = 0b11;
+const MOD_NODISP: u8 = 0b00; // normal addressing
+const MOD_NOBASE: u8 = 0b00; // VSIB addressing
+const MOD_DISP8:  u8 = 0b01;
+const MOD_DISP32: u8 = 0b10;
+
+// (label, offset of the end of the field from the start of the instruction, size of the field, length of the opcode)
+type Relocations = Vec<(TargetKind, u8, u8, u8)>;
+
+// the instruction data is expected to be consistent, but a runtime encoder should report an error instead of panicking
+fn bad_data() -> EncodeError {
+    EncodeError::Unsupported("this instruction form has formatting data the runtime encoder cannot handle".into())
+}
+
+fn compile_operation(data: &'static Opdata, prefixes: &[Prefix], decorations: &Decorations, mut args: Vec<CleanArg>)
+-> Result<(Vec<u8>, Relocations), EncodeError> {
+    // determine legacy prefixes
+    let (mut pref_mod, pref_seg) = get_legacy_prefixes(data, prefixes)?;
+
+    // 32-bit addressing needs an address size override prefix
+    let pref_addr = args.iter().any(|arg| match *arg {
+        CleanArg::Indirect {base: Some(reg), ..} |
+        CleanArg::Indirect {index: Some((reg, _)), ..} => reg.family() != RegFamily::Xmm && reg.size() == Size::DWord,
+        _ => false
+    });
+
+    // broadcasted memory references only reference a single element, so any specified
+    // size is the element size. Strip it so it doesn't participate in operand sizing.
+    let mut element_size = None;
+    if decorations.broadcast.is_some() {
+        for arg in args.iter_mut() {
+            if let CleanArg::Indirect {ref mut size, ..} = *arg {
+                element_size = size.take();
+            }
+        }
+    }
+
+    // fill in size info from the format string to create the final SizedArg vec
+    let (op_size, args) = size_operands(data, args)?;
+
+    let mut pref_size = false;
+    let mut rex_w = false;
+    let mut vex_l = false;
+    let mut evex_l = false; // EVEX.L', the upper bit of the vector length
+
+    // determine if size prefixes are necessary
+    if data.flags & (AUTO_SIZE | AUTO_NO32 | AUTO_REXW | AUTO_VEXL) != 0 {
+        // if any of these flags are true an operand size should've been calculated
+        let op_size = op_size.ok_or_else(bad_data)?;
+
+        if data.flags & AUTO_NO32 != 0 {
+            match op_size {
+                Size::Word => pref_size = true,
+                Size::QWord => (),
+                _ => return Err(EncodeError::InvalidOperand("Does not support 32 bit operands in 64-bit mode".into())),
+            }
+        } else if data.flags & AUTO_REXW != 0 {
+            if op_size == Size::QWord {
+                rex_w = true;
+            } else if op_size != Size::DWord {
+                return Err(EncodeError::InvalidOperand("Does not support 16-bit operands".into()));
+            }
+        } else if data.flags & AUTO_VEXL != 0 {
+            if op_size == Size::ZWord {
+                evex_l = true;
+            } else if op_size == Size::HWord {
+                vex_l = true;
+            } else if op_size != Size::OWord {
+                return Err(bad_data());
+            }
+        } else if op_size == Size::Word {
+            pref_size = true;
+        } else if op_size == Size::QWord {
+            rex_w = true;
+        } else if op_size != Size::DWord {
+            return Err(bad_data());
+        }
+    }
+
+    // mandatory prefixes
+    let pref_size = pref_size || data.flags & (WORD_SIZE | PREF_66) != 0;
+    let rex_w     = rex_w     || data.flags & WITH_REXW != 0;
+    let vex_l     = vex_l     || data.flags & WITH_VEXL != 0;
+    let evex_l    = evex_l    || data.flags & WITH_EVEXL != 0;
+    let pref_addr = pref_addr || data.flags & PREF_67 != 0;
+
+    if        data.flags & PREF_F0 != 0 { pref_mod = Some(0xF0);
+    } else if data.flags & PREF_F2 != 0 { pref_mod = Some(0xF2);
+    } else if data.flags & PREF_F3 != 0 { pref_mod = Some(0xF3);
+    }
+
+    // check if this combination of args can actually be encoded and whether a rex prefix is necessary
+    let need_rex = check_rex(data, &args, rex_w)?;
+
+    // check if the APX extended registers are used and whether a rex2 prefix is necessary
+    let need_rex2 = check_rex2(data, &args)?;
+
+    // split args
+    let (mut rm, reg, vvvv, ireg, mut args) = extract_args(data, args)?;
+
+    // EVEX.L'L
+    let vector_length = (evex_l as u8) << 1 | (vex_l as u8);
+
+    // validate AVX-512 decorations and determine how 8-bit displacements are scaled
+    let disp8_scale = if data.flags & EVEX_OP != 0 {
+        check_decorations(data, decorations, &rm, element_size, rex_w, vector_length)?
+    } else {
+        1
+    };
+
+    let mut buffer = Vec::new();
+    let mut relocations = Relocations::new();
+
+    let mut ops = data.ops;
+
+    // deal with ops that encode the final byte in an immediate
+    let immediate_opcode = if data.flags & IMM_OP != 0 {
+        let (&imm, rest) = ops.split_last().ok_or_else(bad_data)?;
+        ops = rest;
+        Some(imm)
+    } else {
+        None
+    };
+
+    // legacy-only prefixes
+    if let Some(pref) = pref_seg {
+        buffer.push(pref);
+    }
+    if pref_addr {
+        buffer.push(0x67);
+    }
+
+    // VEX/XOP/EVEX prefixes embed the operand size prefix / modification prefixes in them.
+    if data.flags & (VEX_OP | XOP_OP | EVEX_OP) != 0 {
+        let prefix = if pref_size        { 0b01
+        } else if pref_mod == Some(0xF3) { 0b10
+        } else if pref_mod == Some(0xF2) { 0b11
+        } else                           { 0
+        };
+        // map_sel is stored in the first byte of the opcode
+        let (&map_sel, tail) = ops.split_first().ok_or_else(bad_data)?;
+        ops = tail;
+        if data.flags & EVEX_OP != 0 {
+            compile_evex(&mut buffer, data, &reg, &rm, map_sel, rex_w, &vvvv, vector_length, prefix, decorations);
+        } else {
+            compile_vex_xop(&mut buffer, data, &reg, &rm, map_sel, rex_w, &vvvv, vex_l, prefix);
+        }
+    // otherwise, the size/mod prefixes have to be pushed and check if a rex prefix has to be generated.
+    } else {
+        if let Some(pref) = pref_mod {
+            buffer.push(pref);
+        }
+        if pref_size {
+            buffer.push(0x66);
+        }
+        if need_rex2 {
+            // REX2 can imply the 0x0F opcode escape byte
+            let map1 = ops.first() == Some(&0x0F);
+            if map1 {
+                ops = &ops[1 ..];
+            }
+            compile_rex2(&mut buffer, rex_w, map1, &reg, &rm);
+        } else if need_rex {
+            compile_rex(&mut buffer, rex_w, &reg, &rm);
+        }
+    }
+
+    // if rm is embedded in the last opcode byte, push it here
+    let opcode_start = buffer.len();
+    if data.flags & SHORT_ARG != 0 {
+        let (last, head) = ops.split_last().ok_or_else(bad_data)?;
+        buffer.extend_from_slice(head);
+
+        if let Some(SizedArg::Direct(reg)) = rm.take() {
+            buffer.push(last + (reg.code() & 7));
+        } else {
+            return Err(bad_data());
+        }
+    } else {
+        buffer.extend_from_slice(ops);
+    }
+    let opcode_len = (buffer.len() - opcode_start) as u8;
+
+    let reg_code = if let Some(SizedArg::Direct(reg)) = reg {
+        reg.code()
+    } else {
+        data.reg
+    };
+
+    match rm {
+        // Direct ModRM addressing
+        Some(SizedArg::Direct(rm)) => {
+            buffer.push(modrm_sib(MOD_DIRECT, reg_code, rm.code()));
+        },
+        // Indirect ModRM (+SIB) addressing
+        Some(SizedArg::Indirect {base, index, disp, ..}) => {
+            compile_indirect(&mut buffer, data, reg_code, base, index, disp, disp8_scale)?;
+        },
+        // jump-target relative addressing
+        Some(SizedArg::IndirectJumpTarget(label)) => {
+            buffer.push(modrm_sib(MOD_NODISP, reg_code, 5));
+            buffer.extend_from_slice(&[0; 4]);
+            relocations.push((label, buffer.len() as u8, 4, opcode_len));
+        },
+        // only a register in the reg field, the r/m field is unused
+        _ => if let Some(SizedArg::Direct(reg)) = reg {
+            buffer.push(modrm_sib(MOD_DIRECT, reg.code(), 0));
+        }
+    }
+
+    // opcode encoded after the displacement
+    if let Some(code) = immediate_opcode {
+        buffer.push(code);
+    }
+
+    // register in immediate argument
+    if let Some(SizedArg::Direct(ireg)) = ireg {
+        let mut byte = ireg.code() << 4;
+
+        // if immediates are present, the register argument will be merged into the first immediate byte.
+        if !args.is_empty() {
+            if let SizedArg::Immediate(value, Size::Byte) = args.remove(0) {
+                byte |= value as u8 & 0xF;
+            } else {
+                return Err(bad_data());
+            }
+        }
+        buffer.push(byte);
+    }
+
+    // immediates
+    for arg in args {
+        match arg {
+            SizedArg::Immediate(value, size) => {
+                let bytes = value.to_le_bytes();
+                buffer.extend_from_slice(bytes.get(.. size.in_bytes() as usize).ok_or_else(bad_data)?);
+            },
+            SizedArg::JumpTarget(label, size) => {
+                // placeholder
+                buffer.extend((0 .. size.in_bytes()).map(|_| 0));
+                relocations.push((label, buffer.len() as u8, size.in_bytes(), opcode_len));
+            },
+            _ => return Err(bad_data())
+        }
+    }
+
+    Ok((buffer, relocations))
+}
+
+// EVEX encoded instructions scale 8-bit displacements by disp8_scale. If the displacement can be compressed
+// like that, this returns the compressed displacement.
+fn compress_disp(disp: i32, disp8_scale: u8) -> Option<i8> {
+    let scale = i32::from(disp8_scale);
+    if disp % scale == 0 {
+        i8::try_from(disp / scale).ok()
+    } else {
+        None
+    }
+}
+
+fn compile_indirect(buffer: &mut Vec<u8>, data: &Opdata, reg_code: u8, base: Option<Register>, index: Option<(Register, u8)>,
+                    disp: i32, disp8_scale: u8) -> Result<(), EncodeError> {
+    let disp8 = compress_disp(disp, disp8_scale);
+    let scale_code = |scale| encode_scale(scale).ok_or_else(|| EncodeError::InvalidOperand("Impossible scale".into()));
+
+    // VSIB addressing has simplified rules.
+    if let Some((index, scale)) = index.filter(|(i, _)| i.family() == RegFamily::Xmm) {
+        let (base_code, mode) = if let Some(base) = base {
+            (base.code(), if disp8.is_some() { MOD_DISP8 } else { MOD_DISP32 })
+        } else {
+            (5, MOD_NOBASE)
+        };
+
+        // always need a SIB byte for VSIB addressing
+        buffer.push(modrm_sib(mode, reg_code, 4));
+        buffer.push(modrm_sib(scale_code(scale)?, index.code(), base_code));
+
+        match disp8 {
+            Some(disp8) if mode == MOD_DISP8 => buffer.push(disp8 as u8),
+            _ => buffer.extend_from_slice(&disp.to_le_bytes())
+        }
+
+    // encode the RIP + disp32 form
+    } else if base == Some(Register::Rip) {
+        buffer.push(modrm_sib(MOD_NODISP, reg_code, 5));
+        buffer.extend_from_slice(&disp.to_le_bytes());
+
+    // normal addressing
+    } else {
+        let no_base = base.is_none();
+
+        // RBP can only be encoded as base if a displacement is present.
+        let mode = if base.is_some_and(|b| b.code() & 7 == 5) && disp == 0 {
+            MOD_DISP8
+        // mode_nodisp if no base is to be encoded. note that in these scenarions a 32-bit disp has to be emitted
+        } else if disp == 0 || no_base {
+            MOD_NODISP
+        } else if disp8.is_some() {
+            MOD_DISP8
+        } else {
+            MOD_DISP32
+        };
+
+        // sibmem operands always require a SIB byte. RSP as index encodes the lack of an index
+        let index = if index.is_none() && data.flags & SIBMEM != 0 {
+            Some((Register::Rq(4), 1))
+        } else {
+            index
+        };
+
+        // if there's an index we need to escape into the SIB byte
+        if let Some((index, scale)) = index {
+            // to encode the lack of a base we encode RBP
+            let base_code = base.map_or(5, Register::code);
+            buffer.push(modrm_sib(mode, reg_code, 4));
+            buffer.push(modrm_sib(scale_code(scale)?, index.code(), base_code));
+
+        // no index, only a base. RBP at MOD_NODISP is used to encode RIP, but this is already handled
+        } else if let Some(base) = base {
+            buffer.push(modrm_sib(mode, reg_code, base.code()));
+
+        // no base, no index. only disp. escape, use RBP as base and RSP as index
+        } else {
+            buffer.push(modrm_sib(mode, reg_code, 4));
+            buffer.push(modrm_sib(0, 4, 5));
+        }
+
+        // Disp
+        match disp8 {
+            Some(disp8) if mode == MOD_DISP8 => buffer.push(disp8 as u8),
+            _ => if mode == MOD_DISP32 || no_base {
+                buffer.extend_from_slice(&disp.to_le_bytes());
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn get_legacy_prefixes(fmt: &Opdata, prefixes: &[Prefix]) -> Result<(Option<u8>, Option<u8>), EncodeError> {
+    let mut group1 = None;
+    let mut group2 = None;
+
+    for &prefix in prefixes {
+        let (group, value) = match prefix {
+            Prefix::Rep   => (&mut group1, if fmt.flags & REP != 0 {Some(0xF3)} else {None}),
+            Prefix::Repe  => (&mut group1, if fmt.flags & REPE != 0 {Some(0xF3)} else {None}),
+            Prefix::Repne => (&mut group1, if fmt.flags & REP != 0 {Some(0xF2)} else {None}),
+            Prefix::Lock  => (&mut group1, if fmt.flags & LOCK != 0 {Some(0xF0)} else {None}),
+            Prefix::Ss    => (&mut group2, Some(0x36)),
+            Prefix::Cs    => (&mut group2, Some(0x2E)),
+            Prefix::Ds    => (&mut group2, Some(0x3E)),
+            Prefix::Es    => (&mut group2, Some(0x26)),
+            Prefix::Fs    => (&mut group2, Some(0x64)),
+            Prefix::Gs    => (&mut group2, Some(0x65)),
+        };
+        let value = value.ok_or_else(|| EncodeError::InvalidOperand(format!("Cannot use prefix {:?} on this instruction", prefix)))?;
+        if group.is_some() {
+            return Err(EncodeError::InvalidOperand("Duplicate prefix group".into()));
+        }
+        *group = Some(value);
+    }
+
+    Ok((group1, group2))
+}
+
+// the registers of an argument that are encoded in the instruction
+fn encoded_registers(arg: &SizedArg) -> impl Iterator<Item = Register> {
+    let (first, second) = match *arg {
+        SizedArg::Direct(reg) => (Some(reg), None),
+        SizedArg::Indirect {base, index, ..} => (base, index.map(|(reg, _)| reg)),
+        _ => (None, None)
+    };
+    first.into_iter().chain(second)
+}
+
+fn check_rex(fmt: &Opdata, args: &[SizedArg], rex_w: bool) -> Result<bool, EncodeError> {
+    // performs checks for not encodable arg combinations
+    // output arg indicates if a rex prefix can be encoded
+    let mut requires_rex    = rex_w;
+    let mut requires_no_rex = false;
+
+    for (arg, (c, _)) in args.iter().zip(FormatStringIterator::new(fmt.args)) {
+        // only scan args that are actually encoded
+        if let b'a' ..= b'z' = c {
+            match *arg {
+                SizedArg::Direct(reg) => {
+                    if reg.family() == RegFamily::HighByte {
+                        requires_no_rex = true;
+                    } else if reg.is_extended() || (reg.size() == Size::Byte && (4 .. 8).contains(&reg.code())) {
+                        requires_rex = true;
+                    }
+                },
+                SizedArg::Indirect {base, index, ..} => {
+                    if let Some(reg) = base {
+                        requires_rex = requires_rex || reg.is_extended();
+                    }
+                    if let Some((reg, _)) = index {
+                        requires_rex = requires_rex || reg.is_extended();
+                    }
+                },
+                _ => (),
+            }
+        }
+    }
+
+    if requires_no_rex && fmt.flags & EVEX_OP != 0 {
+        Err(EncodeError::InvalidOperand("High byte registers cannot be encoded in an EVEX prefix".into()))
+    } else if requires_rex && requires_no_rex {
+        Err(EncodeError::InvalidOperand("High byte register combined with extended registers or 64-bit operand size".into()))
+    } else {
+        Ok(requires_rex)
+    }
+}
+
+fn check_rex2(fmt: &Opdata, args: &[SizedArg]) -> Result<bool, EncodeError> {
+    // checks if the APX extended registers are used, and if a REX2 prefix is required to encode them.
+    let requires_rex2 = args.iter().zip(FormatStringIterator::new(fmt.args))
+        .filter(|&(_, (c, _))| c.is_ascii_lowercase())
+        .any(|(arg, _)| encoded_registers(arg).any(Register::is_apx_extended));
+
+    // VEX/XOP/EVEX encoded instructions do not use a REX2 prefix
+    if !requires_rex2 || fmt.flags & (VEX_OP | XOP_OP | EVEX_OP) != 0 {
+        Ok(false)
+    } else if rex2_compatible(fmt) {
+        Ok(true)
+    } else {
+        Err(EncodeError::InvalidOperand("r16-r31 cannot be used with this instruction".into()))
+    }
+}
+
+fn rex2_compatible(fmt: &Opdata) -> bool {
+    // REX2 cannot be used with the 0F38 and 0F3A opcode maps, nor with several rows of the
+    // one-byte and 0F opcode maps (REX/INC/DEC, Jcc, MOV moffs/string ops, LOOP/JCXZ/IN/OUT/CALL/JMP).
+    if fmt.flags & IMM_OP != 0 {
+        return false;
+    }
+
+    match *fmt.ops {
+        [0x0F, 0x38, ..] | [0x0F, 0x3A, ..] => false,
+        [0x0F, op, ..] => !(op >> 4 == 0x3 || op >> 4 == 0x8),
+        [op, ..] => !(op >> 4 == 0x4 || op >> 4 == 0x7 || op >> 4 == 0xA || op >> 4 == 0xE),
+        [] => false
+    }
+}
+
+pub(crate) type ExtractedArgs<T> = (Option<T>, Option<T>, Option<T>, Option<T>, Vec<T>);
+
+// splits the args into the ones encoded in the r/m field, the reg field, the vvvv field, the immediate register
+// and the actual immediates. Generic so the disassembler can use it to figure out where each arg is encoded.
+pub(crate) fn extract_args<T>(fmt: &Opdata, args: Vec<T>) -> Result<ExtractedArgs<T>, EncodeError> {
+    // see the x64 compiler of the plugin for how the operand order works
+
+    let mut memarg = None;
+    let mut regarg = None;
+    let mut regs = Vec::new();
+    let mut immediates = Vec::new();
+
+    for (arg, (c, _)) in args.into_iter().zip(FormatStringIterator::new(fmt.args)) {
+        match c {
+            b'm' | b'u' | b'v' | b'w' | b'k' | b'l'  => if memarg.is_some() {
+                return Err(bad_data());
+            } else {
+                memarg = Some(regs.len());
+                regs.push(arg)
+            },
+            b'f' | b'x' | b'r' | b'y' | b'b' | b'j' | b't' => regs.push(arg),
+            b'c' | b'd' | b's'        => if regarg.is_some() {
+                return Err(bad_data());
+            } else {
+                regarg = Some(regs.len());
+                regs.push(arg)
+            },
+            b'i' | b'o' => immediates.push(arg),
+            _ => () // hardcoded regs don't have to be encoded
+        }
+    }
+
+    let mut len = regs.len();
+    if len > 4 {
+        return Err(bad_data());
+    }
+    let mut regs = regs.drain(..).fuse();
+
+    let mut m = None;
+    let mut r = None;
+    let mut v = None;
+    let mut i = None;
+
+    // APX new data destination forms encode the first argument in vvvv
+    if fmt.flags & NDD != 0 {
+        if len == 0 || memarg == Some(0) {
+            return Err(bad_data());
+        }
+        v = regs.next();
+        len -= 1;
+        memarg = memarg.map(|pos| pos - 1);
+    }
+
+    if let Some(i) = regarg {
+        if i == 0 {
+            r = regs.next();
+            m = regs.next();
+        } else {
+            m = regs.next();
+            r = regs.next();
+        }
+    } else if len == 1 {
+        if fmt.flags & ENC_MR != 0 {
+            r = regs.next();
+        } else {
+            m = regs.next();
+        }
+    } else if len == 2 {
+        if fmt.flags & ENC_MR != 0 || memarg == Some(0) {
+            m = regs.next();
+            r = regs.next();
+        } else if fmt.flags & ENC_VM != 0 {
+            v = regs.next();
+            m = regs.next();
+        } else {
+            r = regs.next();
+            m = regs.next();
+        }
+    } else if len == 3 {
+        if fmt.flags & ENC_MR != 0 || memarg == Some(1) {
+            r = regs.next();
+            m = regs.next();
+            v = regs.next();
+        } else if fmt.flags & ENC_VM != 0 || memarg == Some(0) {
+            m = regs.next();
+            v = regs.next();
+            r = regs.next();
+        } else {
+            r = regs.next();
+            v = regs.next();
+            m = regs.next();
+        }
+    } else if len == 4 {
+        if fmt.flags & ENC_MR != 0 || memarg == Some(2) {
+            r = regs.next();
+            v = regs.next();
+            m = regs.next();
+            i = regs.next();
+        } else {
+            r = regs.next();
+            v = regs.next();
+            i = regs.next();
+            m = regs.next();
+        }
+    }
+
+    Ok((m, r, v, i, immediates))
+}
+
+// Validates the AVX-512 decorations used on an EVEX encoded instruction and
+// returns the scale of compressed 8-bit displacements
+fn check_decorations(fmt: &Opdata, decorations: &Decorations, rm: &Option<SizedArg>, element_size: Option<Size>, rex_w: bool,
+                     vector_length: u8) -> Result<u8, EncodeError> {
+    if decorations.zeroing && decorations.mask.is_none() {
+        return Err(EncodeError::InvalidOperand("Zeroing-masking requires an opmask".into()));
+    }
+
+    // EVEX promoted legacy instructions don't use compressed displacements
+    if fmt.flags & NDD != 0 {
+        return Ok(1);
+    }
+
+    let memory_size = if let Some(SizedArg::Indirect {size, ..}) = *rm {
+        Some(size)
+    } else {
+        None
+    };
+
+    if decorations.rounding.is_some() {
+        if memory_size.is_some() {
+            return Err(EncodeError::InvalidOperand("Embedded rounding cannot be used with memory operands".into()));
+        }
+        if fmt.flags & AUTO_VEXL != 0 && vector_length != 0b10 {
+            return Err(EncodeError::InvalidOperand("Embedded rounding is only supported for 512-bit vectors".into()));
+        }
+    }
+
+    if let Some(count) = decorations.broadcast {
+        // the element size of broadcasts is determined by EVEX.W
+        let element = if rex_w {Size::QWord} else {Size::DWord};
+        if element_size.is_some_and(|s| s != element) {
+            return Err(EncodeError::InvalidOperand(format!("Broadcast element size mismatch, expected {:?}", element)));
+        }
+        let memory_size = memory_size.ok_or_else(|| EncodeError::InvalidOperand("Broadcasts can only be applied to memory references".into()))?;
+        if u32::from(count) * u32::from(element.in_bytes()) != u32::from(memory_size.in_bytes()) {
+            return Err(EncodeError::InvalidOperand(format!("Invalid broadcast, expected {{1to{}}}", memory_size.in_bytes() / element.in_bytes())));
+        }
+        return Ok(element.in_bytes());
+    }
+
+    Ok(memory_size.map_or(1, Size::in_bytes))
+}
+
+// the register numbers encoded in the reg field, and in the index and base of the rm field
+fn rex_codes(reg: &Option<SizedArg>, rm: &Option<SizedArg>) -> (u8, u8, u8) {
+    let reg = match *reg {
+        Some(SizedArg::Direct(reg)) => reg.code(),
+        _ => 0
+    };
+    let (index, base) = match *rm {
+        Some(SizedArg::Direct(reg)) => (0, reg.code()),
+        Some(SizedArg::Indirect {base, index, ..}) => (
+            index.map_or(0, |(i, _)| i.code()),
+            base.map_or(0, Register::code)
+        ),
+        _ => (0, 0)
+    };
+    (reg, index, base)
+}
+
+fn compile_rex(buffer: &mut Vec<u8>, rex_w: bool, reg: &Option<SizedArg>, rm: &Option<SizedArg>) {
+    let (reg, index, base) = rex_codes(reg, rm);
+
+    buffer.push(0x40 | (rex_w as u8) << 3 |
+                       (reg   & 8) >> 1 |
+                       (index & 8) >> 2 |
+                       (base  & 8) >> 3 );
+}
+
+fn compile_rex2(buffer: &mut Vec<u8>, rex_w: bool, map1: bool, reg: &Option<SizedArg>, rm: &Option<SizedArg>) {
+    let (reg, index, base) = rex_codes(reg, rm);
+
+    buffer.push(0xD5);
+    buffer.push((map1   as u8) << 7 |
+                (reg  & 0x10) << 2 |
+                (index & 0x10) << 1 |
+                (base & 0x10)      |
+                (rex_w  as u8) << 3 |
+                (reg     & 8) >> 1 |
+                (index   & 8) >> 2 |
+                (base    & 8) >> 3 );
+}
+
+#[allow(clippy::too_many_arguments)]
+fn compile_vex_xop(buffer: &mut Vec<u8>, data: &Opdata, reg: &Option<SizedArg>, rm: &Option<SizedArg>,
+                   map_sel: u8, rex_w: bool, vvvv: &Option<SizedArg>, vex_l: bool, prefix: u8) {
+    let (reg, index, base) = rex_codes(reg, rm);
+    let vvvv = match *vvvv {
+        Some(SizedArg::Direct(reg)) => reg.code(),
+        _ => 0
+    };
+
+    let byte1 = (map_sel & 0x1F)      |
+                (!reg    & 8) << 4 |
+                (!index  & 8) << 3 |
+                (!base   & 8) << 2 ;
+
+    let byte2 = (prefix        & 0x3) |
+                (rex_w        as u8) << 7 |
+                (!vvvv       & 0xF) << 3 |
+                (vex_l        as u8) << 2 ;
+
+    if data.flags & VEX_OP != 0 && (byte1 & 0x7F) == 0x61 && (byte2 & 0x80) == 0 {
+        // 2-byte vex
+        buffer.push(0xC5);
+        buffer.push((byte1 & 0x80) | (byte2 & 0x7F));
+    } else {
+        buffer.push(if data.flags & VEX_OP != 0 {0xC4} else {0x8F});
+        buffer.push(byte1);
+        buffer.push(byte2);
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+fn compile_evex(buffer: &mut Vec<u8>, data: &Opdata, reg: &Option<SizedArg>, rm: &Option<SizedArg>, map_sel: u8,
+                rex_w: bool, vvvv: &Option<SizedArg>, vector_length: u8, prefix: u8, decorations: &Decorations) {
+    let (reg, index, base) = rex_codes(reg, rm);
+    let vvvv = match *vvvv {
+        Some(SizedArg::Direct(reg)) => reg.code(),
+        _ => 0
+    };
+    let mask = decorations.mask.map_or(0, Register::code);
+
+    // if rm is a vector register, EVEX.X is used to extend it to 32 registers. General purpose
+    // registers r16-r31 are encoded using EVEX.B4 and EVEX.X4 instead (APX)
+    let (rm_direct, gpr_base) = match *rm {
+        Some(SizedArg::Direct(reg)) => (reg.family() != RegFamily::Legacy, reg.family() == RegFamily::Legacy),
+        Some(SizedArg::Indirect {base, ..}) => (false, base.is_some_and(|b| b.family() == RegFamily::Legacy)),
+        _ => (false, false)
+    };
+    // with VSIB addressing, EVEX.V' is used to extend the index register to 32 registers
+    let (vsib, gpr_index) = match *rm {
+        Some(SizedArg::Indirect {index: Some((index, _)), ..}) =>
+            (index.family() == RegFamily::Xmm, index.family() == RegFamily::Legacy),
+        _ => (false, false)
+    };
+
+    let x_bit = if rm_direct {
+        (!base & 0x10) << 2
+    } else {
+        (!index & 8) << 3
+    };
+    let b4_bit = if gpr_base {
+        (base & 0x10) >> 1
+    } else {
+        0
+    };
+
+    let byte1 = (map_sel & 0x07)      |
+                (!reg       & 8) << 4 |
+                x_bit                 |
+                (!base      & 8) << 2 |
+                (!reg    & 0x10)      | // EVEX.R'
+                b4_bit;                 // EVEX.B4
+
+    let x4_bit = if gpr_index {
+        (!index & 0x10) >> 2
+    } else {
+        0x04
+    };
+
+    let byte2 = (prefix     & 0x3)      |
+                (rex_w      as u8) << 7 |
+                (!vvvv      & 0xF) << 3 |
+                x4_bit; // EVEX.X4
+
+    // embedded rounding control is encoded in the vector length field
+    let (vector_length, bcst) = match decorations.rounding {
+        Some(Rounding::Sae) => (0, true),
+        Some(rounding) => (rounding as u8, true),
+        None => (vector_length, decorations.broadcast.is_some())
+    };
+
+    // EVEX.V' extends either vvvv or the VSIB index register
+    let v = if vsib {index} else {vvvv};
+
+    // APX new data destination forms reuse EVEX.b as EVEX.ND
+    let nd = data.flags & NDD != 0;
+
+    let byte3 = (mask                & 0x07)      |
+                (!v                  & 0x10) >> 1 | // EVEX.V'
+                (bcst                as u8)  << 4 |
+                (nd                  as u8)  << 4 |
+                (vector_length       & 0x03) << 5 |
+                (decorations.zeroing as u8)  << 7;
+
+    buffer.push(0x62);
+    buffer.push(byte1);
+    buffer.push(byte2);
+    buffer.push(byte3);
+}
+
+fn modrm_sib(mode: u8, reg1: u8, reg2: u8) -> u8 {
+    mode << 6 | (reg1 & 7) << 3 | (reg2 & 7)
+}
+
+
+/*
+ * Instruction data
+ */
+
+pub(crate) struct Opdata {
+    pub(crate) args:  &'static [u8],  // format string of arg format
+    pub(crate) ops:   &'static [u8],
+    pub(crate) reg:   u8,
+    pub(crate) flags: u64,
+}
+
+pub(crate) struct FormatStringIterator<'a> {
+    inner: std::slice::Iter<'a, u8>
+}
+
+impl<'a> FormatStringIterator<'a> {
+    pub(crate) fn new(buf: &'a [u8]) -> FormatStringIterator<'a> {
+        FormatStringIterator { inner: buf.iter() }
+    }
+}
+
+impl<'a> Iterator for FormatStringIterator<'a> {
+    type Item = (u8, u8);
+
+    fn next(&mut self) -> Option<(u8, u8)> {
+        let ty = *self.inner.next()?;
+        let size = *self.inner.next()?;
+        Some((ty, size))
+    }
+}
+
+// the features an instruction requires are not checked at runtime, so they're ignored here
+macro_rules! OpInner {
+    ($fmt:expr, $ops:expr, $reg:expr)          => { Opdata {args: $fmt, ops: &$ops, reg: $reg, flags: DEFAULT} };
+    ($fmt:expr, $ops:expr, $reg:expr, $f:expr) => { Opdata {args: $fmt, ops: &$ops, reg: $reg, flags: $f} };
+    ($fmt:expr, $ops:expr, $reg:expr, $f:expr, $ft:expr) => { Opdata {args: $fmt, ops: &$ops, reg: $reg, flags: $f} };
+}
+
+macro_rules! Ops {
+    ( $( $name:tt = [ $( $( $e:expr ),+ ; )+ ] )* ) => {
+        [ $(
+            (
+                $name,
+                {
+                    const OPDATA: &[Opdata] = &[$( OpInner!($( $e ),*) ,)+];
+                    OPDATA
+                }
+            )
+        ),* ]
+    };
+}
+
+/// All instructions and their forms, in the order of the instruction data
+pub(crate) fn instructions() -> &'static [(&'static str, &'static [Opdata])] {
+    const X: u8 = 0xFF;
+    static MAP: &[(&str, &[Opdata])] = &dynasm_data::x64_opmap!(Ops);
+    MAP
+}
+
+fn opmap() -> &'static HashMap<&'static str, &'static [Opdata]> {
+    static OPMAP: OnceLock<HashMap<&'static str, &'static [Opdata]>> = OnceLock::new();
+    OPMAP.get_or_init(|| instructions().iter().cloned().collect())
+}
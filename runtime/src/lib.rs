@@ -1,15 +1,34 @@
 extern crate memmap;
 extern crate byteorder;
+#[cfg(all(feature = "perf", target_os = "linux"))]
+extern crate libc;
+#[cfg(feature = "encoder")]
+extern crate dynasm_data;
 
 pub mod mmap;
 pub mod components;
 pub mod relocations;
 pub mod x64;
 pub mod x86;
+pub mod x86_16;
 pub mod aarch64;
+pub mod aarch64_be;
+pub mod riscv;
+pub mod arm;
+pub mod loongarch;
+mod x86_length;
+#[cfg(feature = "encoder")]
+pub mod encoder;
+#[cfg(feature = "text")]
+pub mod text;
+#[cfg(feature = "disasm")]
+pub mod disasm;
+#[cfg(all(feature = "perf", target_os = "linux"))]
+pub mod perf;
 
 pub use crate::mmap::ExecutableBuffer;
-use crate::components::{MemoryManager, LabelRegistry, RelocRegistry, ManagedRelocs, PatchLoc};
+use crate::components::{MemoryManager, LabelRegistry, RelocRegistry, ManagedRelocs, RelaxationRegistry, DebugRegistry, SourceMap, PatchLoc};
+use crate::components::{ListingLine, SourceLocation};
 use crate::relocations::Relocation;
 
 use std::iter::Extend;
@@ -161,6 +180,17 @@ pub trait DynasmApi: Extend<u8> + for<'a> Extend<&'a u8> {
     fn push(&mut self, byte: u8);
     /// Push filler until the assembling target end is aligned to the given alignment.
     fn align(&mut self, alignment: usize, with: u8);
+    /// Push copies of the instruction `filler` until the assembling target end is aligned to the given alignment.
+    /// Any padding too small to hold a whole `filler` is pushed as zero bytes first.
+    fn align_with_filler(&mut self, alignment: usize, filler: &[u8]) {
+        let padding = (alignment - self.offset().0 % alignment) % alignment;
+        for _ in 0 .. padding % filler.len() {
+            self.push(0);
+        }
+        for _ in 0 .. padding / filler.len() {
+            self.extend(filler);
+        }
+    }
 
     #[inline]
     /// Push a signed byte into the assembling target
@@ -202,6 +232,16 @@ pub trait DynasmApi: Extend<u8> + for<'a> Extend<&'a u8> {
     fn runtime_error(&self, msg: &'static str) -> ! {
         panic!(msg);
     }
+    /// Record that the following instruction was assembled from `line` and `column` of the Rust source file `file`.
+    /// This is called by `dynasm!` for every instruction when the `.source_locations` directive is enabled.
+    /// By default this does nothing.
+    #[inline]
+    fn set_source_location(&mut self, _file: &'static str, _line: u32, _column: u32) {}
+    /// Record that the code following this point was not assembled from a known source location. This is
+    /// called by `dynasm!` after the last of a sequence of instructions when the `.source_locations` directive
+    /// is enabled. By default this does nothing.
+    #[inline]
+    fn end_source_location(&mut self) {}
 }
 
 /// This trait extends DynasmApi to not only allow assembling, but also labels and various directives
@@ -288,6 +328,12 @@ pub struct Assembler<R: Relocation> {
     labels: LabelRegistry,
     relocs: RelocRegistry<R>,
     managed: ManagedRelocs<R>,
+    relaxation: RelaxationRegistry<R>,
+    relax: bool,
+    debug: Option<DebugRegistry>,
+    locations: SourceMap,
+    #[cfg(all(feature = "perf", target_os = "linux"))]
+    profiler: Option<perf::Profiler>,
     error: Option<DynasmError>,
 }
 
@@ -300,10 +346,82 @@ impl<R: Relocation> Assembler<R> {
             labels: LabelRegistry::new(),
             relocs: RelocRegistry::new(),
             managed: ManagedRelocs::new(),
+            relaxation: RelaxationRegistry::new(),
+            relax: false,
+            debug: None,
+            locations: SourceMap::new(),
+            #[cfg(all(feature = "perf", target_os = "linux"))]
+            profiler: None,
             error: None
         })
     }
 
+    /// Enable or disable branch relaxation for code assembled from now on. This commits any code assembled so far.
+    /// While relaxation is enabled, branches to labels are assembled in their shortest form, and are only widened
+    /// at `commit()` time if their target turns out to be out of reach. Labels and relocations are moved accordingly.
+    /// As this moves code around, any `AssemblyOffset` obtained before committing the code it points into
+    /// should be passed through `relaxed_offset` afterwards, before more code is committed.
+    pub fn set_relaxation(&mut self, relax: bool) -> Result<(), DynasmError> {
+        self.commit()?;
+        self.relax = relax;
+        Ok(())
+    }
+
+    /// Translate an `AssemblyOffset` obtained before the code it points into was committed to where
+    /// that location ended up after relaxation. Offsets that were not affected by relaxation, including
+    /// any offsets into code that has not been committed yet, are returned unchanged. Only offsets into the code
+    /// committed most recently can be translated, so this has to happen before committing again.
+    pub fn relaxed_offset(&self, offset: AssemblyOffset) -> AssemblyOffset {
+        self.relaxation.translate(offset)
+    }
+
+    /// Enable or disable debug mode. While debug mode is enabled, the labels defined and the targets of the
+    /// relocations emitted are recorded after they have been resolved, so they can be shown in `listing()`.
+    /// Disabling debug mode discards everything that was recorded.
+    pub fn set_debug(&mut self, debug: bool) {
+        if !debug {
+            self.debug = None;
+        } else if self.debug.is_none() {
+            self.debug = Some(DebugRegistry::new());
+        }
+    }
+
+    /// Produce a listing of the committed and uncommitted code, with a line for every instruction. In debug
+    /// mode, lines are annotated with the labels defined at their offset and the targets of any relocations
+    /// in them.
+    pub fn listing(&self) -> Vec<ListingLine> {
+        let mut code = self.memory.reader().read().unwrap().to_vec();
+        code.extend_from_slice(&self.ops);
+
+        match self.debug {
+            Some(ref debug) => debug.listing::<R>(&code),
+            None => DebugRegistry::new().listing::<R>(&code)
+        }
+    }
+
+    /// Set the profiler that named regions of code are written to as they are committed, returning the
+    /// previous one. Regions start at global labels and at dynamic labels named with `name_dynamic_label`.
+    #[cfg(all(feature = "perf", target_os = "linux"))]
+    pub fn set_profiler(&mut self, profiler: Option<perf::Profiler>) -> Option<perf::Profiler> {
+        mem::replace(&mut self.profiler, profiler)
+    }
+
+    /// Name the dynamic label `id` for profiling, making it start a region of code. This does nothing if
+    /// no profiler has been set.
+    #[cfg(all(feature = "perf", target_os = "linux"))]
+    pub fn name_dynamic_label(&mut self, id: DynamicLabel, name: &str) {
+        if let Some(ref mut profiler) = self.profiler {
+            profiler.name_dynamic_label(id, name);
+        }
+    }
+
+    /// Look up the location in the Rust source of the instruction containing `offset`. Locations are only
+    /// known for instructions assembled while the `.source_locations` directive was enabled, for any other
+    /// offset this returns `None`.
+    pub fn source_location(&self, offset: AssemblyOffset) -> Option<SourceLocation> {
+        self.locations.lookup(offset)
+    }
+
     /// Create a new dynamic label ID
     pub fn new_dynamic_label(&mut self) -> DynamicLabel {
         self.labels.new_dynamic_label()
@@ -319,6 +437,8 @@ impl<R: Relocation> Assembler<R> {
     /// Use a `Modifier` to alter committed code directly. While this is happening
     /// no code can be executed as the relevant pages are remapped as writable.
     /// This API supports defining new labels/relocations, and overwriting previously defined relocations.
+    /// Afterwards, the instruction cache maintenance required by the target architecture is performed over
+    /// all committed code.
     pub fn alter<F, O>(&mut self, f: F) -> Result<O, DynasmError>
     where F: FnOnce(&mut Modifier<R>) -> O {
         self.commit()?;
@@ -338,6 +458,9 @@ impl<R: Relocation> Assembler<R> {
             relocs: &mut self.relocs,
             old_managed: &mut self.managed,
             new_managed: ManagedRelocs::new(),
+            debug: self.debug.as_mut(),
+            old_locations: &mut self.locations,
+            new_locations: SourceMap::new(),
 
             error: None
         };
@@ -348,8 +471,9 @@ impl<R: Relocation> Assembler<R> {
         // flush any changes made by the user code to the buffer
         modifier.encode_relocs()?;
 
-        // repack the buffer
+        // repack the buffer, and make sure the altered code will be executed
         let buffer = buffer.make_exec().expect("Could not swap buffer protection modes");
+        R::flush_icache(&buffer);
         mem::replace(&mut *lock, buffer);
 
         // call it a day
@@ -357,14 +481,18 @@ impl<R: Relocation> Assembler<R> {
     }
 
     /// Commit code, flushing the temporary internal assembling buffer to the mapped executable memory.
-    /// This makes assembled code available for execution.
+    /// This makes assembled code available for execution, performing any instruction cache maintenance the
+    /// target architecture requires on the newly committed code.
     pub fn commit(&mut self) -> Result<(), DynasmError> {
         self.encode_relocs()?;
 
+        #[cfg(all(feature = "perf", target_os = "linux"))]
+        let old_addr = self.memory.execbuffer_addr();
+
         let managed = &self.managed;
         let error = &mut self.error;
 
-        self.memory.commit(&mut self.ops, |buffer, old_addr, new_addr| {
+        self.memory.commit::<R, _>(&mut self.ops, |buffer, old_addr, new_addr| {
             let change = new_addr.wrapping_sub(old_addr) as isize;
 
             for reloc in managed.iter() {
@@ -374,6 +502,16 @@ impl<R: Relocation> Assembler<R> {
             }
         });
 
+        // write the regions of the new code, and move the old ones if the buffer moved
+        #[cfg(all(feature = "perf", target_os = "linux"))]
+        {
+            if let Some(ref mut profiler) = self.profiler {
+                let buffer = self.memory.reader();
+                let buffer = buffer.read().unwrap();
+                profiler.commit(&self.labels, &buffer, old_addr);
+            }
+        }
+
         if let Some(e) = self.error.take() {
             return Err(e);
         }
@@ -424,6 +562,10 @@ impl<R: Relocation> Assembler<R> {
         // Resolve globals
         for (loc, name) in self.relocs.take_globals() {
             let target = self.labels.resolve_global(name)?;
+            if self.relax {
+                self.relaxation.add(loc, target.0, TargetKind::Global(name));
+                continue;
+            }
             if let Err(_) = loc.patch(buf_offset, buf_addr, buf, target.0) {
                 return Err(DynasmError::ImpossibleRelocation(TargetKind::Global(name)));
             }
@@ -435,6 +577,10 @@ impl<R: Relocation> Assembler<R> {
         // Resolve dynamics
         for (loc, id) in self.relocs.take_dynamics() {
             let target = self.labels.resolve_dynamic(id)?;
+            if self.relax {
+                self.relaxation.add(loc, target.0, TargetKind::Dynamic(id));
+                continue;
+            }
             if let Err(_) = loc.patch(buf_offset, buf_addr, buf, target.0) {
                 return Err(DynasmError::ImpossibleRelocation(TargetKind::Dynamic(id)));
             }
@@ -448,8 +594,53 @@ impl<R: Relocation> Assembler<R> {
             return Err(DynasmError::UnknownLabel(LabelKind::Local(name)));
         }
 
+        // Widen any branches that cannot reach their target and encode the relaxed relocations
+        self.relaxation.relax(buf_offset, buf_addr, buf, &mut self.labels, &mut self.managed)?;
+
+        // and move anything recorded for debugging along with the code
+        let relaxation = &self.relaxation;
+        if let Some(ref mut debug) = self.debug {
+            debug.remap(buf_offset, |offset| relaxation.translate(offset));
+        }
+        self.locations.remap(buf_offset, |offset| relaxation.translate(offset));
         Ok(())
     }
+
+    // Create a patch location for a relocation to `target` that was just emitted. If relaxation is enabled
+    // branches are shrunk to their shortest form here.
+    fn patchloc(&mut self, offset: isize, mut kind: R, target: TargetKind) -> PatchLoc<R> {
+        if self.relax {
+            if let Some((replaced, bytes, shrunk)) = kind.shrink(&self.ops) {
+                let len = self.ops.len() - replaced;
+                self.ops.truncate(len);
+                self.ops.extend(bytes);
+                kind = shrunk;
+            }
+        }
+        let location = self.offset();
+        self.record_reloc(location, target);
+        PatchLoc::new(location, offset, kind)
+    }
+
+    // record a label for debugging and profiling, if enabled
+    fn record_label(&mut self, offset: AssemblyOffset, label: LabelKind) {
+        #[cfg(all(feature = "perf", target_os = "linux"))]
+        {
+            if let Some(ref mut profiler) = self.profiler {
+                profiler.add_label(label);
+            }
+        }
+        if let Some(ref mut debug) = self.debug {
+            debug.add_label(offset, label);
+        }
+    }
+
+    // record the target of a relocation for debugging, if debug mode is enabled
+    fn record_reloc(&mut self, location: AssemblyOffset, target: TargetKind) {
+        if let Some(ref mut debug) = self.debug {
+            debug.add_reloc(location, target);
+        }
+    }
 }
 
 impl<R: Relocation> Extend<u8> for Assembler<R> {
@@ -474,6 +665,10 @@ impl<R: Relocation> DynasmApi for Assembler<R> {
     }
 
     fn align(&mut self, alignment: usize, with: u8) {
+        if self.relax {
+            self.relaxation.add_alignment(self.offset(), alignment, &[with]);
+        }
+
         let misalign = self.offset().0 % alignment;
         if misalign != 0 {
             for _ in misalign .. alignment {
@@ -481,6 +676,30 @@ impl<R: Relocation> DynasmApi for Assembler<R> {
             }
         }
     }
+
+    fn align_with_filler(&mut self, alignment: usize, filler: &[u8]) {
+        if self.relax {
+            self.relaxation.add_alignment(self.offset(), alignment, filler);
+        }
+
+        let padding = (alignment - self.offset().0 % alignment) % alignment;
+        for _ in 0 .. padding % filler.len() {
+            self.push(0);
+        }
+        for _ in 0 .. padding / filler.len() {
+            self.extend(filler);
+        }
+    }
+
+    fn set_source_location(&mut self, file: &'static str, line: u32, column: u32) {
+        let offset = self.offset();
+        self.locations.add(offset, SourceLocation { file, line, column });
+    }
+
+    fn end_source_location(&mut self) {
+        let offset = self.offset();
+        self.locations.end(offset);
+    }
 }
 
 impl<R: Relocation> DynasmLabelApi for Assembler<R> {
@@ -489,37 +708,44 @@ impl<R: Relocation> DynasmLabelApi for Assembler<R> {
     fn local_label(&mut self, name: &'static str) {
         let offset = self.offset();
         for loc in self.relocs.take_locals_named(name) {
-            if let Err(_) = loc.patch(self.memory.committed(), self.memory.execbuffer_addr(), &mut self.ops, offset.0) {
+            if self.relax {
+                self.relaxation.add(loc, offset.0, TargetKind::Forward(name));
+            } else if let Err(_) = loc.patch(self.memory.committed(), self.memory.execbuffer_addr(), &mut self.ops, offset.0) {
                 self.error = Some(DynasmError::ImpossibleRelocation(TargetKind::Forward(name)))
             } else if loc.needs_adjustment() {
                 self.managed.add(loc)
             }
         }
         self.labels.define_local(name, offset);
+        self.record_label(offset, LabelKind::Local(name));
     }
     fn global_label( &mut self, name: &'static str) {
         let offset = self.offset();
         if let Err(e) = self.labels.define_global(name, offset) {
             self.error = Some(e)
+        } else {
+            self.record_label(offset, LabelKind::Global(name));
         }
     }
     fn dynamic_label(&mut self, id: DynamicLabel) {
         let offset = self.offset();
         if let Err(e) = self.labels.define_dynamic(id, offset) {
             self.error = Some(e)
+        } else {
+            self.record_label(offset, LabelKind::Dynamic(id));
         }
     }
     fn global_relocation(&mut self, name: &'static str, offset: isize, kind: R) {
-        let location = self.offset();
-        self.relocs.add_global(name, PatchLoc::new(location, offset, kind));
+        let loc = self.patchloc(offset, kind, TargetKind::Global(name));
+        self.relocs.add_global(name, loc);
     }
     fn dynamic_relocation(&mut self, id: DynamicLabel, offset: isize, kind: R) {
-        let location = self.offset();
-        self.relocs.add_dynamic(id, PatchLoc::new(location, offset, kind));
+        let loc = self.patchloc(offset, kind, TargetKind::Dynamic(id));
+        self.relocs.add_dynamic(id, loc);
     }
     fn forward_relocation(&mut self, name: &'static str, offset: isize, kind: R) {
-        let location = self.offset();
-        self.relocs.add_local(name, PatchLoc::new(location, offset, kind));
+        let loc = self.patchloc(offset, kind, TargetKind::Forward(name));
+        self.relocs.add_local(name, loc);
     }
     fn backward_relocation(&mut self, name: &'static str, offset: isize, kind: R) {
         let target = match self.labels.resolve_local(name) {
@@ -529,9 +755,10 @@ impl<R: Relocation> DynasmLabelApi for Assembler<R> {
                 return;
             }
         };
-        let location = self.offset();
-        let loc = PatchLoc::new(location, offset, kind);
-        if let Err(_) = loc.patch(self.memory.committed(), self.memory.execbuffer_addr(), &mut self.ops, target) {
+        let loc = self.patchloc(offset, kind, TargetKind::Backward(name));
+        if self.relax {
+            self.relaxation.add(loc, target, TargetKind::Backward(name));
+        } else if let Err(_) = loc.patch(self.memory.committed(), self.memory.execbuffer_addr(), &mut self.ops, target) {
             self.error = Some(DynasmError::ImpossibleRelocation(TargetKind::Backward(name)))
         } else if loc.needs_adjustment() {
             self.managed.add(loc)
@@ -539,8 +766,11 @@ impl<R: Relocation> DynasmLabelApi for Assembler<R> {
     }
     fn bare_relocation(&mut self, target: usize, kind: R) {
         let location = self.offset();
+        self.record_reloc(location, TargetKind::Extern(target));
         let loc = PatchLoc::new(location, 0, kind);
-        if let Err(_) = loc.patch(self.memory.committed(), self.memory.execbuffer_addr(), &mut self.ops, target) {
+        if self.relax {
+            self.relaxation.add(loc, target, TargetKind::Extern(target));
+        } else if let Err(_) = loc.patch(self.memory.committed(), self.memory.execbuffer_addr(), &mut self.ops, target) {
             self.error = Some(DynasmError::ImpossibleRelocation(TargetKind::Extern(target)))
         } else if loc.needs_adjustment() {
             self.managed.add(loc)
@@ -562,6 +792,9 @@ pub struct Modifier<'a, R: Relocation> {
     relocs: &'a mut RelocRegistry<R>,
     old_managed: &'a mut ManagedRelocs<R>,
     new_managed: ManagedRelocs<R>,
+    debug: Option<&'a mut DebugRegistry>,
+    old_locations: &'a mut SourceMap,
+    new_locations: SourceMap,
 
     error: Option<DynasmError>
 }
@@ -570,6 +803,7 @@ impl<'a, R: Relocation> Modifier<'a, R> {
     /// Move the modifier cursor to the selected location.
     pub fn goto(&mut self, offset: AssemblyOffset) {
         self.old_managed.remove_between(self.previous_asmoffset, self.asmoffset);
+        self.old_locations.remove_between(self.previous_asmoffset, self.asmoffset);
         self.asmoffset = offset.0;
         self.previous_asmoffset = offset.0;
     }
@@ -629,26 +863,42 @@ impl<'a, R: Relocation> Modifier<'a, R> {
         }
 
         self.old_managed.remove_between(self.previous_asmoffset, self.asmoffset);
+        self.old_locations.remove_between(self.previous_asmoffset, self.asmoffset);
         self.previous_asmoffset = self.asmoffset;
 
         self.old_managed.append(&mut self.new_managed);
+        self.old_locations.append(&mut self.new_locations);
 
         Ok(())
     }
+
+    // record a label for debugging, if the assembler is in debug mode
+    fn record_label(&mut self, offset: AssemblyOffset, label: LabelKind) {
+        if let Some(ref mut debug) = self.debug {
+            debug.add_label(offset, label);
+        }
+    }
+
+    // record the target of a relocation for debugging, if the assembler is in debug mode
+    fn record_reloc(&mut self, location: AssemblyOffset, target: TargetKind) {
+        if let Some(ref mut debug) = self.debug {
+            debug.add_reloc(location, target);
+        }
+    }
 }
 
 impl<'a, R: Relocation> Extend<u8> for Modifier<'a,R> {
     fn extend<T>(&mut self, iter: T) where T: IntoIterator<Item=u8> {
-        for (src, dst) in iter.into_iter().zip(self.buffer[self.asmoffset ..].iter_mut()) {
-            *dst = src;
+        for byte in iter {
+            self.push(byte);
         }
     }
 }
 
 impl<'a, 'b, R: Relocation> Extend<&'b u8> for Modifier<'a, R> {
     fn extend<T>(&mut self, iter: T) where T: IntoIterator<Item=&'b u8> {
-        for (src, dst) in iter.into_iter().zip(self.buffer[self.asmoffset ..].iter_mut()) {
-            *dst = *src;
+        for &byte in iter {
+            self.push(byte);
         }
     }
 }
@@ -671,6 +921,16 @@ impl<'a, R: Relocation> DynasmApi for Modifier<'a, R> {
             }
         }
     }
+
+    fn set_source_location(&mut self, file: &'static str, line: u32, column: u32) {
+        let offset = self.offset();
+        self.new_locations.add(offset, SourceLocation { file, line, column });
+    }
+
+    fn end_source_location(&mut self) {
+        let offset = self.offset();
+        self.new_locations.end(offset);
+    }
 }
 
 impl<'a, R: Relocation> DynasmLabelApi for Modifier<'a, R> {
@@ -686,29 +946,37 @@ impl<'a, R: Relocation> DynasmLabelApi for Modifier<'a, R> {
             }
         }
         self.labels.define_local(name, offset);
+        self.record_label(offset, LabelKind::Local(name));
     }
     fn global_label( &mut self, name: &'static str) {
         let offset = self.offset();
         if let Err(e) = self.labels.define_global(name, offset) {
             self.error = Some(e);
+        } else {
+            self.record_label(offset, LabelKind::Global(name));
         }
     }
     fn dynamic_label(&mut self, id: DynamicLabel) {
         let offset = self.offset();
         if let Err(e) = self.labels.define_dynamic(id, offset) {
             self.error = Some(e);
+        } else {
+            self.record_label(offset, LabelKind::Dynamic(id));
         }
     }
     fn global_relocation(&mut self, name: &'static str, offset: isize, kind: R) {
         let location = self.offset();
+        self.record_reloc(location, TargetKind::Global(name));
         self.relocs.add_global(name, PatchLoc::new(location, offset, kind));
     }
     fn dynamic_relocation(&mut self, id: DynamicLabel, offset: isize, kind: R) {
         let location = self.offset();
+        self.record_reloc(location, TargetKind::Dynamic(id));
         self.relocs.add_dynamic(id, PatchLoc::new(location, offset, kind));
     }
     fn forward_relocation(&mut self, name: &'static str, offset: isize, kind: R) {
         let location = self.offset();
+        self.record_reloc(location, TargetKind::Forward(name));
         self.relocs.add_local(name, PatchLoc::new(location, offset, kind));
     }
     fn backward_relocation(&mut self, name: &'static str, offset: isize, kind: R) {
@@ -720,6 +988,7 @@ impl<'a, R: Relocation> DynasmLabelApi for Modifier<'a, R> {
             }
         };
         let location = self.offset();
+        self.record_reloc(location, TargetKind::Backward(name));
         let loc = PatchLoc::new(location, offset, kind);
         if let Err(_) = loc.patch(0, self.buffer.as_ptr() as usize, self.buffer, target) {
             self.error = Some(DynasmError::ImpossibleRelocation(TargetKind::Backward(name)));
@@ -729,6 +998,7 @@ impl<'a, R: Relocation> DynasmLabelApi for Modifier<'a, R> {
     }
     fn bare_relocation(&mut self, target: usize, kind: R) {
         let location = self.offset();
+        self.record_reloc(location, TargetKind::Extern(target));
         let loc = PatchLoc::new(location, 0, kind);
         if let Err(_) = loc.patch(0, self.buffer.as_ptr() as usize, self.buffer, target) {
             self.error = Some(DynasmError::ImpossibleRelocation(TargetKind::Extern(target)));
@@ -0,0 +1,212 @@
+use crate::relocations::{Relocation, RelocationSize, RelocationKind, ImpossibleRelocation, fits_signed_bitfield};
+use byteorder::{ByteOrder, LittleEndian};
+use std::convert::TryFrom;
+
+/// Relocation implementation for the LoongArch64 architecture.
+#[derive(Debug, Clone)]
+#[allow(non_camel_case_types)]
+pub enum LoongArchRelocation {
+    // beq, bne, blt, bge, bltu, bgeu: 16 bits, word aligned
+    B16,
+    // beqz, bnez, bceqz, bcnez: split 21 bits, word aligned
+    B21,
+    // b, bl: split 26 bits, word aligned
+    B26,
+    // pcaddi: 20 bits, word aligned
+    PCADDI,
+    // pcaddu12i followed by an instruction with a 12-bit immediate (addi.d, loads, stores): 32 bits, byte aligned
+    PCADDU12I,
+    // pcaddu18i followed by jirl: 38 bits, word aligned
+    PCADDU18I,
+    // Anything in directives
+    Plain(RelocationSize),
+}
+
+impl LoongArchRelocation {
+    // the (offset, bit length, shift) slices of the immediate in the instruction, and the size of the immediate
+    fn slices(&self) -> (&'static [(u32, u32, u32)], u8) {
+        match self {
+            Self::B16 => (&[(10, 16, 2)], 18),
+            Self::B21 => (&[(10, 16, 2), (0, 5, 18)], 23),
+            Self::B26 => (&[(10, 16, 2), (0, 10, 18)], 28),
+            Self::PCADDI => (&[(5, 20, 2)], 22),
+            _ => unreachable!()
+        }
+    }
+
+    // scatters a value over the slices of a split immediate
+    fn scatter(&self, value: i64) -> Result<(u32, u32), ImpossibleRelocation> {
+        let (slices, bits) = self.slices();
+        if value & 3 != 0 || !fits_signed_bitfield(value, bits) {
+            return Err(ImpossibleRelocation { } );
+        }
+
+        let mut mask = 0;
+        let mut packed = 0;
+        for &(offset, len, shift) in slices {
+            let field = (1u32 << len) - 1;
+            mask |= field << offset;
+            packed |= (((value >> shift) as u32) & field) << offset;
+        }
+        Ok((mask, packed))
+    }
+
+    // gathers a value back from the slices of a split immediate, and sign extends it
+    fn gather(&self, instruction: u32) -> i64 {
+        let (slices, bits) = self.slices();
+
+        let mut value = 0u64;
+        for &(offset, len, shift) in slices {
+            let field = (1u32 << len) - 1;
+            value |= u64::from((instruction >> offset) & field) << shift;
+        }
+
+        let sign = 1u64 << (bits - 1);
+        ((value ^ sign).wrapping_sub(sign)) as i64
+    }
+
+    // splits a value over the 20 bits of a pcaddu12i or pcaddu18i, and the sign extended immediate of the
+    // instruction that follows it. Returns the high part, the low part and the mask of the low part.
+    fn split(&self, value: i64) -> Result<(u32, u32, u32), ImpossibleRelocation> {
+        let (bits, shift) = match self {
+            Self::PCADDU12I => (12, 0),
+            Self::PCADDU18I => (18, 2),
+            _ => unreachable!()
+        };
+
+        let high = (value + (1 << (bits - 1))) >> bits;
+        if value & ((1 << shift) - 1) != 0 || !fits_signed_bitfield(high, 20) {
+            return Err(ImpossibleRelocation { } );
+        }
+        let low = (value - (high << bits)) >> shift;
+        let mask = (1u32 << (bits - shift)) - 1;
+        Ok(((high as u32) & 0xF_FFFF, (low as u32) & mask, mask))
+    }
+}
+
+impl Relocation for LoongArchRelocation {
+    type Encoding = (u8,);
+    fn from_encoding(encoding: Self::Encoding) -> Self {
+        match encoding.0 {
+            0 => Self::B16,
+            1 => Self::B21,
+            2 => Self::B26,
+            3 => Self::PCADDI,
+            4 => Self::PCADDU12I,
+            5 => Self::PCADDU18I,
+            x  => Self::Plain(RelocationSize::from_encoding(x - 6))
+        }
+    }
+    fn from_size(size: RelocationSize) -> Self {
+        Self::Plain(size)
+    }
+    fn size(&self) -> usize {
+        match self {
+            Self::B16 | Self::B21 | Self::B26 | Self::PCADDI => RelocationSize::DWord.size(),
+            Self::PCADDU12I | Self::PCADDU18I => RelocationSize::QWord.size(),
+            Self::Plain(s) => s.size(),
+        }
+    }
+    fn write_value(&self, buf: &mut [u8], value: isize) -> Result<(), ImpossibleRelocation> {
+        if let Self::Plain(s) = self {
+            return s.write_value(buf, value);
+        };
+
+        let value = i64::try_from(value).map_err(|_| ImpossibleRelocation { } )?;
+
+        match self {
+            Self::B16 | Self::B21 | Self::B26 | Self::PCADDI => {
+                let (mask, packed) = self.scatter(value)?;
+                let template = LittleEndian::read_u32(buf) & !mask;
+                LittleEndian::write_u32(buf, template | packed);
+            },
+            Self::PCADDU12I | Self::PCADDU18I => {
+                let (high, low, mask) = self.split(value)?;
+                let first = LittleEndian::read_u32(&buf[.. 4]) & !(0xF_FFFF << 5);
+                LittleEndian::write_u32(&mut buf[.. 4], first | (high << 5));
+
+                let second = LittleEndian::read_u32(&buf[4 ..]) & !(mask << 10);
+                LittleEndian::write_u32(&mut buf[4 ..], second | (low << 10));
+            },
+            Self::Plain(_) => unreachable!()
+        }
+        Ok(())
+    }
+    fn read_value(&self, buf: &[u8]) -> isize {
+        let value = match self {
+            Self::B16 | Self::B21 | Self::B26 | Self::PCADDI => self.gather(LittleEndian::read_u32(buf)),
+            Self::PCADDU12I | Self::PCADDU18I => {
+                let high = i64::from((LittleEndian::read_u32(&buf[.. 4]) as i32) << 7 >> 12);
+                let second = LittleEndian::read_u32(&buf[4 ..]) as i32;
+                if let Self::PCADDU12I = self {
+                    (high << 12) + i64::from(second << 10 >> 20)
+                } else {
+                    (high << 18) + (i64::from(second << 6 >> 16) << 2)
+                }
+            },
+            Self::Plain(s) => return s.read_value(buf)
+        };
+        value as isize
+    }
+    fn kind(&self) -> RelocationKind {
+        RelocationKind::Relative
+    }
+    fn page_size() -> usize {
+        4096
+    }
+    fn flush_icache(code: &[u8]) {
+        flush_icache(code)
+    }
+    fn instruction_len(_code: &[u8]) -> Option<usize> {
+        Some(4)
+    }
+    fn widen(&self, instruction: &[u8]) -> Option<(usize, Vec<u8>, Self)> {
+        // only the conditional branches can be rewritten here
+        if !matches!(self, Self::B16 | Self::B21) {
+            return None;
+        }
+
+        let start = instruction.len().checked_sub(4)?;
+        let template = LittleEndian::read_u32(&instruction[start ..]);
+
+        // invert the condition of the branch, so it skips over a b to the target. bceqz and bcnez
+        // differ in bit 8, the other branches in bit 26.
+        let flip = if template >> 26 == 0x12 { 0x100 } else { 0x0400_0000 };
+        let (mask, packed) = self.scatter(8).ok()?;
+        let inverted = ((template & !mask) ^ flip) | packed;
+
+        let mut bytes = vec![0; 8];
+        LittleEndian::write_u32(&mut bytes[.. 4], inverted);
+        LittleEndian::write_u32(&mut bytes[4 ..], 0x5000_0000);
+        Some((4, bytes, Self::B26))
+    }
+}
+
+
+pub type Assembler = crate::Assembler<LoongArchRelocation>;
+pub type AssemblyModifier<'a> = crate::Modifier<'a, LoongArchRelocation>;
+pub type UncommittedModifier<'a> = crate::UncommittedModifier<'a>;
+
+
+/// Makes code that was just written to `code` visible to instruction fetches of the calling core,
+/// using `ibar 0`. This does nothing when not running on loongarch64.
+pub fn flush_icache(code: &[u8]) {
+    #[cfg(target_arch = "loongarch64")]
+    flush_icache_native(code);
+
+    #[cfg(not(target_arch = "loongarch64"))]
+    let _ = code;
+}
+
+#[cfg(target_arch = "loongarch64")]
+fn flush_icache_native(code: &[u8]) {
+    use std::arch::asm;
+
+    if code.is_empty() {
+        return;
+    }
+
+    unsafe {
+        asm!("ibar 0", options(nostack, preserves_flags));
+    }
+}
@@ -74,6 +74,13 @@ impl ExecutableBuffer {
             buffer
         })
     }
+
+    /// Disassemble the code in `range` of this buffer, using the addresses it is mapped at.
+    #[cfg(all(feature = "disasm", any(target_arch = "x86_64", target_arch = "aarch64")))]
+    pub fn disassemble(&self, range: std::ops::Range<usize>) -> Vec<crate::disasm::Instruction> {
+        let code = &self[range];
+        crate::disasm::disassemble(code, code.as_ptr() as usize)
+    }
 }
 
 impl MutableBuffer {
@@ -0,0 +1,331 @@
+//! Support for profiling generated code with Linux `perf`.
+//!
+//! `perf` can only attribute samples in generated code to a name when it is told what code lives where.
+//! This module can write that information both as a perf map (`/tmp/perf-<pid>.map`), which `perf report`
+//! reads directly, and in the jitdump format, which `perf inject --jit` uses to make the generated code
+//! itself available for annotation.
+//!
+//! A `Profiler` bundles these writers. It can be given to an `Assembler` with `Assembler::set_profiler`,
+//! after which the code following every global label and every dynamic label named with
+//! `Assembler::name_dynamic_label` is written as a region when it is committed. Whole buffers can be
+//! written with `Profiler::write_buffer`.
+
+use std::collections::HashMap;
+use std::fs::{File, OpenOptions};
+use std::io::{self, Write};
+use std::path::Path;
+
+use byteorder::{ByteOrder, NativeEndian};
+use memmap::{Mmap, MmapOptions};
+
+use crate::{DynamicLabel, LabelKind};
+use crate::components::LabelRegistry;
+
+
+/// A writer for perf map files. These contain a line with the address, size and name of every region of code.
+#[derive(Debug)]
+pub struct PerfMap {
+    file: File,
+}
+
+impl PerfMap {
+    /// Open the perf map of the current process, `/tmp/perf-<pid>.map`, appending to it if it already exists.
+    pub fn new() -> io::Result<PerfMap> {
+        PerfMap::open(format!("/tmp/perf-{}.map", std::process::id()))
+    }
+
+    /// Open a perf map at `path`, appending to it if it already exists.
+    pub fn open<P: AsRef<Path>>(path: P) -> io::Result<PerfMap> {
+        let file = OpenOptions::new().create(true).append(true).open(path)?;
+        Ok(PerfMap {
+            file
+        })
+    }
+
+    /// Write an entry for the `size` bytes of code named `name` at `addr`.
+    pub fn write_entry(&mut self, addr: usize, size: usize, name: &str) -> io::Result<()> {
+        // each entry is written in one go, so perf never sees a partial line
+        let line = format!("{:x} {:x} {}\n", addr, size, name);
+        self.file.write_all(line.as_bytes())
+    }
+}
+
+
+// jitdump constants, see tools/perf/Documentation/jitdump-specification.txt in the linux tree
+const JITDUMP_MAGIC: u32 = 0x4A69_5444;
+const JITDUMP_VERSION: u32 = 1;
+const JITDUMP_HEADER_SIZE: usize = 40;
+const JIT_CODE_LOAD: u32 = 0;
+const JIT_CODE_MOVE: u32 = 1;
+const JIT_CODE_LOAD_SIZE: usize = 56;
+const JIT_CODE_MOVE_SIZE: usize = 64;
+
+// the ELF machine of the code that is being profiled
+#[cfg(target_arch = "x86_64")]
+const ELF_MACHINE: u32 = 62;
+#[cfg(target_arch = "x86")]
+const ELF_MACHINE: u32 = 3;
+#[cfg(target_arch = "aarch64")]
+const ELF_MACHINE: u32 = 183;
+#[cfg(target_arch = "arm")]
+const ELF_MACHINE: u32 = 40;
+#[cfg(any(target_arch = "riscv32", target_arch = "riscv64"))]
+const ELF_MACHINE: u32 = 243;
+#[cfg(target_arch = "loongarch64")]
+const ELF_MACHINE: u32 = 258;
+#[cfg(not(any(target_arch = "x86_64", target_arch = "x86", target_arch = "aarch64", target_arch = "arm",
+              target_arch = "riscv32", target_arch = "riscv64", target_arch = "loongarch64")))]
+const ELF_MACHINE: u32 = 0;
+
+/// A writer for jitdump files. These contain a code load record with the address, name and contents of every
+/// region of code, and code move records for code that got moved afterwards.
+#[derive(Debug)]
+pub struct JitDump {
+    file: File,
+    // perf finds jitdump files through an executable mapping of them, which has to stay alive while profiling
+    _marker: Mmap,
+    // the index of the next code load record
+    code_index: u64,
+}
+
+impl JitDump {
+    /// Create the jitdump file of the current process, `/tmp/jit-<pid>.dump`.
+    pub fn new() -> io::Result<JitDump> {
+        JitDump::create(format!("/tmp/jit-{}.dump", std::process::id()))
+    }
+
+    /// Create a jitdump file at `path`. `perf inject` only picks up files named `jit-<pid>.dump`.
+    pub fn create<P: AsRef<Path>>(path: P) -> io::Result<JitDump> {
+        let mut file = OpenOptions::new().create(true).truncate(true).read(true).write(true).open(path)?;
+
+        let mut header = [0u8; JITDUMP_HEADER_SIZE];
+        NativeEndian::write_u32(&mut header[0 ..], JITDUMP_MAGIC);
+        NativeEndian::write_u32(&mut header[4 ..], JITDUMP_VERSION);
+        NativeEndian::write_u32(&mut header[8 ..], JITDUMP_HEADER_SIZE as u32);
+        NativeEndian::write_u32(&mut header[12 ..], ELF_MACHINE);
+        NativeEndian::write_u32(&mut header[20 ..], std::process::id());
+        NativeEndian::write_u64(&mut header[24 ..], timestamp());
+        file.write_all(&header)?;
+
+        // the mapping is never accessed, so it does not matter that the file gets written to
+        let marker = unsafe { MmapOptions::new().map_exec(&file)? };
+
+        Ok(JitDump {
+            file,
+            _marker: marker,
+            code_index: 0,
+        })
+    }
+
+    /// Write a code load record for `code`, named `name` and located at `addr`. Returns the index of the
+    /// record, which identifies the code in later code move records.
+    pub fn write_load(&mut self, addr: usize, code: &[u8], name: &str) -> io::Result<u64> {
+        let index = self.code_index;
+        let size = JIT_CODE_LOAD_SIZE + name.len() + 1 + code.len();
+
+        let mut record = vec![0u8; JIT_CODE_LOAD_SIZE];
+        write_record_header(&mut record, JIT_CODE_LOAD, size);
+        NativeEndian::write_u64(&mut record[24 ..], addr as u64);
+        NativeEndian::write_u64(&mut record[32 ..], addr as u64);
+        NativeEndian::write_u64(&mut record[40 ..], code.len() as u64);
+        NativeEndian::write_u64(&mut record[48 ..], index);
+        record.extend_from_slice(name.as_bytes());
+        record.push(0);
+        record.extend_from_slice(code);
+        self.file.write_all(&record)?;
+
+        self.code_index += 1;
+        Ok(index)
+    }
+
+    /// Write a code move record for the `size` bytes of code loaded with index `index`, which moved from
+    /// `old_addr` to `new_addr`.
+    pub fn write_move(&mut self, index: u64, old_addr: usize, new_addr: usize, size: usize) -> io::Result<()> {
+        let mut record = [0u8; JIT_CODE_MOVE_SIZE];
+        write_record_header(&mut record, JIT_CODE_MOVE, JIT_CODE_MOVE_SIZE);
+        NativeEndian::write_u64(&mut record[24 ..], new_addr as u64);
+        NativeEndian::write_u64(&mut record[32 ..], old_addr as u64);
+        NativeEndian::write_u64(&mut record[40 ..], new_addr as u64);
+        NativeEndian::write_u64(&mut record[48 ..], size as u64);
+        NativeEndian::write_u64(&mut record[56 ..], index);
+        self.file.write_all(&record)
+    }
+}
+
+// write the record header and the pid and tid fields that all code records start with
+fn write_record_header(record: &mut [u8], id: u32, size: usize) {
+    NativeEndian::write_u32(&mut record[0 ..], id);
+    NativeEndian::write_u32(&mut record[4 ..], size as u32);
+    NativeEndian::write_u64(&mut record[8 ..], timestamp());
+    NativeEndian::write_u32(&mut record[16 ..], std::process::id());
+    NativeEndian::write_u32(&mut record[20 ..], thread_id());
+}
+
+// perf orders records by CLOCK_MONOTONIC
+fn timestamp() -> u64 {
+    let mut time = libc::timespec { tv_sec: 0, tv_nsec: 0 };
+    unsafe {
+        libc::clock_gettime(libc::CLOCK_MONOTONIC, &mut time);
+    }
+    time.tv_sec as u64 * 1_000_000_000 + time.tv_nsec as u64
+}
+
+fn thread_id() -> u32 {
+    unsafe {
+        libc::syscall(libc::SYS_gettid) as u32
+    }
+}
+
+
+// a region of code that has been written
+#[derive(Debug)]
+struct Region {
+    offset: usize,
+    size: usize,
+    name: String,
+    // the index of its jitdump code load record
+    index: Option<u64>,
+}
+
+/// Writes the named regions of code of an `Assembler` to a perf map and/or a jitdump file as they are committed.
+/// Writing is best-effort: errors do not interrupt assembling, but the first error is kept and can be
+/// retrieved with `take_error`.
+#[derive(Debug)]
+pub struct Profiler {
+    map: Option<PerfMap>,
+    jitdump: Option<JitDump>,
+    // names of dynamic labels
+    names: HashMap<DynamicLabel, String>,
+    // labels that name regions which have not been written yet
+    pending: Vec<LabelKind>,
+    // the regions that have been written
+    regions: Vec<Region>,
+    error: Option<io::Error>,
+}
+
+impl Profiler {
+    /// Create a profiler that writes to `map` and `jitdump`, if present.
+    pub fn new(map: Option<PerfMap>, jitdump: Option<JitDump>) -> Profiler {
+        Profiler {
+            map,
+            jitdump,
+            names: HashMap::new(),
+            pending: Vec::new(),
+            regions: Vec::new(),
+            error: None,
+        }
+    }
+
+    /// Create a profiler that writes to the perf map of the current process, and to its jitdump file
+    /// if `jitdump` is true.
+    pub fn for_process(jitdump: bool) -> io::Result<Profiler> {
+        let map = PerfMap::new()?;
+        let jitdump = if jitdump { Some(JitDump::new()?) } else { None };
+        Ok(Profiler::new(Some(map), jitdump))
+    }
+
+    /// Take the first error that occurred while writing, if any.
+    pub fn take_error(&mut self) -> Option<io::Error> {
+        self.error.take()
+    }
+
+    /// Write all code in `buffer` as a single region named `name`.
+    pub fn write_buffer(&mut self, name: &str, buffer: &[u8]) -> io::Result<()> {
+        self.write_region(buffer.as_ptr() as usize, buffer, name).map(|_| ())
+    }
+
+    // give the dynamic label `id` a name. Its region is written once it has been defined and committed.
+    pub(crate) fn name_dynamic_label(&mut self, id: DynamicLabel, name: &str) {
+        if self.names.insert(id, name.to_string()).is_none() {
+            self.pending.push(LabelKind::Dynamic(id));
+        }
+    }
+
+    // note that `label` was defined. Global labels start a region, named dynamic labels are already
+    // pending from the moment they were named.
+    pub(crate) fn add_label(&mut self, label: LabelKind) {
+        if let LabelKind::Global(_) = label {
+            self.pending.push(label);
+        }
+    }
+
+    // write the regions of the newly committed code in `code`, which used to be located at `old_addr`.
+    pub(crate) fn commit(&mut self, labels: &LabelRegistry, code: &[u8], old_addr: usize) {
+        let addr = code.as_ptr() as usize;
+
+        // code that was already written got moved along with the buffer
+        if addr != old_addr {
+            for i in 0 .. self.regions.len() {
+                let result = self.write_move(i, old_addr, addr);
+                self.keep_error(result);
+            }
+        }
+
+        // find the regions that start in committed code
+        let mut starts = Vec::new();
+        let mut pending = Vec::new();
+        for label in self.pending.drain(..) {
+            let (offset, name) = match label {
+                LabelKind::Global(name) => (labels.resolve_global(name), name.to_string()),
+                LabelKind::Dynamic(id) => (labels.resolve_dynamic(id), self.names[&id].clone()),
+                LabelKind::Local(_) => continue
+            };
+            match offset {
+                Ok(offset) if offset.0 < code.len() => starts.push((offset.0, name)),
+                _ => pending.push(label)
+            }
+        }
+        self.pending = pending;
+
+        // regions end where the next one starts, or at the end of the committed code
+        let mut boundaries: Vec<usize> = self.regions.iter().map(|r| r.offset).chain(starts.iter().map(|s| s.0)).collect();
+        boundaries.sort();
+        starts.sort();
+
+        for (offset, name) in starts {
+            let end = boundaries.iter().cloned().find(|&b| b > offset).unwrap_or(code.len()).min(code.len());
+            let result = self.write_region(addr + offset, &code[offset .. end], &name);
+            match result {
+                Ok(index) => self.regions.push(Region {
+                    offset,
+                    size: end - offset,
+                    name,
+                    index
+                }),
+                Err(e) => self.keep_error(Err(e))
+            }
+        }
+    }
+
+    // write a region to all outputs, returning the index of its jitdump code load record
+    fn write_region(&mut self, addr: usize, code: &[u8], name: &str) -> io::Result<Option<u64>> {
+        if let Some(ref mut map) = self.map {
+            map.write_entry(addr, code.len(), name)?;
+        }
+        match self.jitdump {
+            Some(ref mut jitdump) => jitdump.write_load(addr, code, name).map(Some),
+            None => Ok(None)
+        }
+    }
+
+    // write that the region at `index` moved along with its buffer from `old_addr` to `new_addr`
+    fn write_move(&mut self, index: usize, old_addr: usize, new_addr: usize) -> io::Result<()> {
+        let region = &self.regions[index];
+        // perf maps cannot express moves, the latest entry for an address wins
+        if let Some(ref mut map) = self.map {
+            map.write_entry(new_addr + region.offset, region.size, &region.name)?;
+        }
+        if let (Some(jitdump), Some(code_index)) = (self.jitdump.as_mut(), region.index) {
+            jitdump.write_move(code_index, old_addr + region.offset, new_addr + region.offset, region.size)?;
+        }
+        Ok(())
+    }
+
+    fn keep_error(&mut self, result: io::Result<()>) {
+        if let Err(e) = result {
+            if self.error.is_none() {
+                self.error = Some(e);
+            }
+        }
+    }
+}
@@ -1,4 +1,4 @@
-use byteorder::{ByteOrder, LittleEndian};
+use byteorder::{ByteOrder, LittleEndian, BigEndian};
 
 use std::convert::TryFrom;
 
@@ -23,6 +23,13 @@ pub trait Relocation {
     fn start_offset(&self) -> usize {
         self.size()
     }
+    /// Returns the address that this relocation is relative to, given `location`, the definition point
+    /// of this relocation. This only needs to be overridden when that address is not at a fixed offset
+    /// before the definition point, like the word-aligned program counter of thumb.
+    /// Defaults to `location - self.start_offset()`.
+    fn relative_to(&self, location: usize) -> usize {
+        location - self.start_offset()
+    }
     /// Returns the offset of the start of the bytes containing this relocation, backwards with respect to the definition point
     /// of this relocation.
     /// Defaults to the size of this relocation.
@@ -40,6 +47,37 @@ pub trait Relocation {
     fn kind(&self) -> RelocationKind;
     /// Specifies the default page size on this platform.
     fn page_size() -> usize;
+    /// Returns true if data on this architecture is stored in big-endian byte order, like the values
+    /// in a literal pool. Defaults to `false`.
+    fn big_endian() -> bool {
+        false
+    }
+    /// Performs the cache maintenance this architecture requires before the code in `code`, which was
+    /// just written to, can be executed. This is called by the assemblers whenever they commit or alter
+    /// code, and should be called by anything else that writes code into memory for this architecture.
+    /// Defaults to doing nothing, which is correct for architectures with coherent instruction caches.
+    fn flush_icache(_code: &[u8]) { }
+    /// Used by relaxing assemblers. If `instruction`, the bytes leading up to the definition point of this
+    /// relocation, ends with a branch that has a shorter encoding, returns the amount of bytes at the end of
+    /// `instruction` to replace, the bytes to replace them with, and the relocation to use for the shorter form.
+    /// Defaults to not supporting any shorter forms.
+    fn shrink(&self, _instruction: &[u8]) -> Option<(usize, Vec<u8>, Self)> where Self: Sized {
+        None
+    }
+    /// Used by relaxing assemblers. If `instruction`, the bytes leading up to the definition point of this
+    /// relocation, ends with a branch that can be rewritten to reach further, returns the amount of bytes at the
+    /// end of `instruction` to replace, the bytes to replace them with, and the relocation to use for the
+    /// rewritten form. The new relocation is defined at the end of the replacement bytes.
+    /// Defaults to not supporting any relaxation.
+    fn widen(&self, _instruction: &[u8]) -> Option<(usize, Vec<u8>, Self)> where Self: Sized {
+        None
+    }
+    /// Returns the length of the instruction at the start of `code`. This is used to split code into
+    /// instructions for listings. Defaults to `None`, which is used for architectures where the length of an
+    /// instruction cannot be determined from its bytes alone.
+    fn instruction_len(_code: &[u8]) -> Option<usize> {
+        None
+    }
 }
 
 
@@ -137,6 +175,36 @@ impl Relocation for RelocationSize {
     }
 }
 
+impl RelocationSize {
+    /// Write a value into a buffer of size `self.size()` in big-endian byte order.
+    pub fn write_value_be(&self, buf: &mut [u8], value: isize) -> Result<(), ImpossibleRelocation> {
+        match self {
+            RelocationSize::Byte => buf[0] =
+                i8::try_from(value).map_err(|_| ImpossibleRelocation { } )?
+            as u8,
+            RelocationSize::Word => BigEndian::write_i16(buf,
+                i16::try_from(value).map_err(|_| ImpossibleRelocation { } )?
+            ),
+            RelocationSize::DWord => BigEndian::write_i32(buf,
+                i32::try_from(value).map_err(|_| ImpossibleRelocation { } )?
+            ),
+            RelocationSize::QWord => BigEndian::write_i64(buf,
+                i64::try_from(value).map_err(|_| ImpossibleRelocation { } )?
+            ),
+        }
+        Ok(())
+    }
+    /// Read a value from a buffer of size `self.size()` in big-endian byte order.
+    pub fn read_value_be(&self, buf: &[u8]) -> isize {
+        match self {
+            RelocationSize::Byte => buf[0] as i8 as isize,
+            RelocationSize::Word => BigEndian::read_i16(buf) as isize,
+            RelocationSize::DWord => BigEndian::read_i32(buf) as isize,
+            RelocationSize::QWord => BigEndian::read_i64(buf) as isize,
+        }
+    }
+}
+
 pub(crate) fn fits_signed_bitfield(value: i64, bits: u8) -> bool {
     if bits >= 64 {
         return true;
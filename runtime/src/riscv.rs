@@ -0,0 +1,225 @@
+use crate::relocations::{Relocation, RelocationSize, RelocationKind, ImpossibleRelocation, fits_signed_bitfield};
+use byteorder::{ByteOrder, LittleEndian};
+use std::convert::TryFrom;
+
+/// Relocation implementation for the RV64 architecture.
+#[derive(Debug, Clone)]
+#[allow(non_camel_case_types)]
+pub enum RiscvRelocation {
+    // beq, bne, blt, bge, bltu, bgeu: split 13 bits, word aligned
+    B,
+    // jal: split 21 bits, word aligned
+    J,
+    // c.beqz, c.bnez: split 9 bits, word aligned
+    CB,
+    // c.j: split 12 bits, word aligned
+    CJ,
+    // auipc followed by an I-type instruction (addi, jalr, loads): 32 bits, byte aligned
+    AUIPC_I,
+    // auipc followed by an S-type instruction (stores): 32 bits, byte aligned
+    AUIPC_S,
+    // Anything in directives
+    Plain(RelocationSize),
+}
+
+impl RiscvRelocation {
+    // the (offset, bit length, shift) slices of the immediate in the instruction, and the size of the immediate
+    fn slices(&self) -> (&'static [(u32, u32, u32)], u8) {
+        match self {
+            Self::B => (&[(31, 1, 12), (25, 6, 5), (8, 4, 1), (7, 1, 11)], 13),
+            Self::J => (&[(31, 1, 20), (21, 10, 1), (20, 1, 11), (12, 8, 12)], 21),
+            Self::CB => (&[(12, 1, 8), (10, 2, 3), (5, 2, 6), (3, 2, 1), (2, 1, 5)], 9),
+            Self::CJ => (&[(12, 1, 11), (11, 1, 4), (9, 2, 8), (8, 1, 10), (7, 1, 6), (6, 1, 7), (3, 3, 1), (2, 1, 5)], 12),
+            _ => unreachable!()
+        }
+    }
+
+    // scatters a value over the slices of a split immediate
+    fn scatter(&self, value: i64) -> Result<(u32, u32), ImpossibleRelocation> {
+        let (slices, bits) = self.slices();
+        if value & 1 != 0 || !fits_signed_bitfield(value, bits) {
+            return Err(ImpossibleRelocation { } );
+        }
+
+        let mut mask = 0;
+        let mut packed = 0;
+        for &(offset, len, shift) in slices {
+            let field = (1u32 << len) - 1;
+            mask |= field << offset;
+            packed |= (((value >> shift) as u32) & field) << offset;
+        }
+        Ok((mask, packed))
+    }
+
+    // gathers a value back from the slices of a split immediate, and sign extends it
+    fn gather(&self, instruction: u32) -> i64 {
+        let (slices, bits) = self.slices();
+
+        let mut value = 0u64;
+        for &(offset, len, shift) in slices {
+            let field = (1u32 << len) - 1;
+            value |= u64::from((instruction >> offset) & field) << shift;
+        }
+
+        let sign = 1u64 << (bits - 1);
+        ((value ^ sign).wrapping_sub(sign)) as i64
+    }
+
+    // splits a value over the upper 20 bits of an auipc, and the 12 bits of the instruction that follows it
+    fn split(value: i64) -> Result<(u32, u32), ImpossibleRelocation> {
+        let high = (value + 0x800) >> 12;
+        if !fits_signed_bitfield(high, 20) {
+            return Err(ImpossibleRelocation { } );
+        }
+        let low = value - (high << 12);
+        Ok(((high as u32) & 0xF_FFFF, (low as u32) & 0xFFF))
+    }
+}
+
+impl Relocation for RiscvRelocation {
+    type Encoding = (u8,);
+    fn from_encoding(encoding: Self::Encoding) -> Self {
+        match encoding.0 {
+            0 => Self::B,
+            1 => Self::J,
+            2 => Self::CB,
+            3 => Self::CJ,
+            4 => Self::AUIPC_I,
+            5 => Self::AUIPC_S,
+            x  => Self::Plain(RelocationSize::from_encoding(x - 6))
+        }
+    }
+    fn from_size(size: RelocationSize) -> Self {
+        Self::Plain(size)
+    }
+    fn size(&self) -> usize {
+        match self {
+            Self::B | Self::J => RelocationSize::DWord.size(),
+            Self::CB | Self::CJ => RelocationSize::Word.size(),
+            Self::AUIPC_I | Self::AUIPC_S => RelocationSize::QWord.size(),
+            Self::Plain(s) => s.size(),
+        }
+    }
+    fn write_value(&self, buf: &mut [u8], value: isize) -> Result<(), ImpossibleRelocation> {
+        if let Self::Plain(s) = self {
+            return s.write_value(buf, value);
+        };
+
+        let value = i64::try_from(value).map_err(|_| ImpossibleRelocation { } )?;
+
+        match self {
+            Self::B | Self::J => {
+                let (mask, packed) = self.scatter(value)?;
+                let template = LittleEndian::read_u32(buf) & !mask;
+                LittleEndian::write_u32(buf, template | packed);
+            },
+            Self::CB | Self::CJ => {
+                let (mask, packed) = self.scatter(value)?;
+                let template = u32::from(LittleEndian::read_u16(buf)) & !mask;
+                LittleEndian::write_u16(buf, (template | packed) as u16);
+            },
+            Self::AUIPC_I | Self::AUIPC_S => {
+                let (high, low) = Self::split(value)?;
+                let auipc = LittleEndian::read_u32(&buf[.. 4]) & 0xFFF;
+                LittleEndian::write_u32(&mut buf[.. 4], auipc | (high << 12));
+
+                let second = LittleEndian::read_u32(&buf[4 ..]);
+                let second = if let Self::AUIPC_I = self {
+                    (second & 0x000F_FFFF) | (low << 20)
+                } else {
+                    (second & 0x01FF_F07F) | ((low >> 5) << 25) | ((low & 0x1F) << 7)
+                };
+                LittleEndian::write_u32(&mut buf[4 ..], second);
+            },
+            Self::Plain(_) => unreachable!()
+        }
+        Ok(())
+    }
+    fn read_value(&self, buf: &[u8]) -> isize {
+        let value = match self {
+            Self::B | Self::J => self.gather(LittleEndian::read_u32(buf)),
+            Self::CB | Self::CJ => self.gather(u32::from(LittleEndian::read_u16(buf))),
+            Self::AUIPC_I | Self::AUIPC_S => {
+                let high = i64::from(LittleEndian::read_u32(&buf[.. 4]) as i32 >> 12);
+                let second = LittleEndian::read_u32(&buf[4 ..]) as i32;
+                let low = if let Self::AUIPC_I = self {
+                    second >> 20
+                } else {
+                    ((second >> 25) << 5) | ((second >> 7) & 0x1F)
+                };
+                (high << 12) + i64::from(low)
+            },
+            Self::Plain(s) => return s.read_value(buf)
+        };
+        value as isize
+    }
+    fn kind(&self) -> RelocationKind {
+        RelocationKind::Relative
+    }
+    fn page_size() -> usize {
+        4096
+    }
+    fn flush_icache(code: &[u8]) {
+        flush_icache(code)
+    }
+    fn instruction_len(code: &[u8]) -> Option<usize> {
+        // compressed instructions don't have both of their lowest bits set
+        match code.first()? & 3 {
+            3 => Some(4),
+            _ => Some(2)
+        }
+    }
+    fn widen(&self, instruction: &[u8]) -> Option<(usize, Vec<u8>, Self)> {
+        // only the conditional branches can be rewritten here
+        if !matches!(self, Self::B) {
+            return None;
+        }
+
+        let start = instruction.len().checked_sub(4)?;
+        let template = LittleEndian::read_u32(&instruction[start ..]);
+
+        // invert the condition of the branch (funct3 bit 0), so it skips over a jal x0 to the target
+        let (mask, packed) = self.scatter(8).ok()?;
+        let inverted = ((template & !mask) ^ 0x1000) | packed;
+
+        let mut bytes = vec![0; 8];
+        LittleEndian::write_u32(&mut bytes[.. 4], inverted);
+        LittleEndian::write_u32(&mut bytes[4 ..], 0x0000_006F);
+        Some((4, bytes, Self::J))
+    }
+}
+
+
+pub type Assembler = crate::Assembler<RiscvRelocation>;
+pub type AssemblyModifier<'a> = crate::Modifier<'a, RiscvRelocation>;
+pub type UncommittedModifier<'a> = crate::UncommittedModifier<'a>;
+
+
+/// Makes code that was just written to `code` visible to instruction fetches, using the
+/// `__clear_cache` routine of the compiler runtime. Unlike a bare `fence.i`, which only affects the
+/// hart executing it, this makes the code visible to all harts (through the `riscv_flush_icache`
+/// system call on Linux), so it can be executed from any thread.
+/// This does nothing when not running on riscv64.
+pub fn flush_icache(code: &[u8]) {
+    #[cfg(target_arch = "riscv64")]
+    flush_icache_native(code);
+
+    #[cfg(not(target_arch = "riscv64"))]
+    let _ = code;
+}
+
+#[cfg(target_arch = "riscv64")]
+fn flush_icache_native(code: &[u8]) {
+    extern "C" {
+        fn __clear_cache(start: *mut u8, end: *mut u8);
+    }
+
+    if code.is_empty() {
+        return;
+    }
+
+    let range = code.as_ptr_range();
+    unsafe {
+        __clear_cache(range.start as *mut u8, range.end as *mut u8);
+    }
+}
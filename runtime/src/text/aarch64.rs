@@ -0,0 +1,414 @@
+//! Parsing of aarch64 instructions. This follows the aarch64 parser of the `dynasm!` macro.
+
+use std::convert::TryFrom;
+
+use crate::DynasmLabelApi;
+use crate::aarch64::Aarch64Relocation;
+use crate::encoder::aarch64::{encode, Mnemonic, Operand, Register, Vector, MemoryRef, Modifier, Size};
+use crate::encoder::aarch64::{is_scalable_register, unsupported_scalable};
+
+use super::{Arch, TextAssembler, TextError, TextErrorKind, encode_error};
+use super::parser::{Cursor, Token};
+
+
+pub(super) struct Aarch64;
+
+impl Arch for Aarch64 {
+    type Relocation = Aarch64Relocation;
+
+    const NAME: &'static str = "aarch64";
+    const DEFAULT_ALIGN: u8 = 0;
+
+    fn data_relocation(size: u8) -> Option<(u8,)> {
+        // literal relocations are numbered after the instruction relocations, by their size in bytes
+        match size {
+            1 | 2 | 4 | 8 => Some((4 + size,)),
+            _ => None
+        }
+    }
+
+    // syntax for a single op: ident ("." ident)* (arg ("," arg)*)?
+    fn compile_instruction<O>(asm: &TextAssembler, ops: &mut O, cursor: &mut Cursor) -> Result<(), TextError>
+    where O: DynasmLabelApi<Relocation = Aarch64Relocation> {
+        let start = cursor.column();
+
+        // read the full dot-separated op
+        let mut name = cursor.ident()?.to_string();
+        while cursor.eat(".") {
+            name.push('.');
+            name.push_str(cursor.ident()?);
+        }
+        let mnemonic = encode_error(cursor, start, name.parse::<Mnemonic>())?;
+
+        // parse 0 or more comma-separated args
+        let mut args = Vec::new();
+        if !cursor.is_empty() {
+            loop {
+                // SVE and SME registers have a syntax of their own, so reject them before it fails to parse
+                if let Some(Token::Ident(ident)) = cursor.peek() {
+                    if is_scalable_register(ident) {
+                        return encode_error(cursor, start, Err(unsupported_scalable(&name)));
+                    }
+                }
+                args.push(parse_arg(asm, cursor)?);
+
+                if !cursor.eat(",") {
+                    break;
+                }
+            }
+        }
+        cursor.finish()?;
+
+        encode_error(cursor, start, encode(ops, mnemonic, &args))
+    }
+}
+
+/// A register, which can be either a scalar or a vector register.
+enum Reg {
+    Scalar(Register),
+    Vector(Vector),
+}
+
+/// tries to parse a full arg definition
+fn parse_arg<'a>(asm: &TextAssembler, cursor: &mut Cursor<'a, '_>) -> Result<Operand<'a>, TextError> {
+    let column = cursor.column();
+
+    // a label
+    if let Some(target) = asm.parse_jump(cursor)? {
+        return Ok(Operand::Label(target));
+    }
+
+    // reference
+    if cursor.eat("[") {
+        let mem = parse_memoryref(asm, cursor, column)?;
+        return Ok(Operand::Mem(mem));
+    }
+
+    // registerlist
+    if cursor.eat("{") {
+        return parse_list(asm, cursor, column);
+    }
+
+    // modifier
+    if let Some(modifier) = parse_modifier(cursor)? {
+        return Ok(modifier);
+    }
+
+    // immediate (arm notation)
+    if cursor.eat("#") {
+        return parse_immediate(cursor);
+    }
+
+    // register
+    match parse_reg(asm, cursor)? {
+        Some(Reg::Scalar(reg)) => return Ok(Operand::Reg(reg)),
+        Some(Reg::Vector(vector)) => return Ok(Operand::Vector(vector)),
+        None => ()
+    }
+
+    // named operands like condition codes, barrier options or system registers
+    if let Some(Token::Ident(ident)) = cursor.peek() {
+        cursor.next();
+        return Ok(Operand::Ident(ident));
+    }
+
+    // immediate (relaxed notation)
+    parse_immediate(cursor)
+}
+
+/// Parses an integer or floating point immediate
+fn parse_immediate<'a>(cursor: &mut Cursor<'a, '_>) -> Result<Operand<'a>, TextError> {
+    match (cursor.peek(), cursor.peek2()) {
+        (Some(Token::Float(value)), _) => {
+            cursor.next();
+            Ok(Operand::Float(value))
+        },
+        (Some(Token::Punct("-")), Some(Token::Float(value))) => {
+            cursor.next();
+            cursor.next();
+            Ok(Operand::Float(-value))
+        },
+        _ => Ok(Operand::Imm(cursor.expr()?))
+    }
+}
+
+/// Parses a memory reference after its opening bracket, including a trailing `!`
+fn parse_memoryref(asm: &TextAssembler, cursor: &mut Cursor, column: usize) -> Result<MemoryRef, TextError> {
+    let invalid = |cursor: &Cursor, msg: &str| cursor.error_at(column, TextErrorKind::Parse(msg.into()));
+
+    let base = match parse_reg(asm, cursor)? {
+        Some(Reg::Scalar(base)) => base,
+        _ => return Err(cursor.error("Expected a base register"))
+    };
+
+    let mut offset = None;
+    let mut index = None;
+    let mut modifier = None;
+
+    if cursor.eat(",") {
+        match parse_reg(asm, cursor)? {
+            Some(Reg::Scalar(reg)) => index = Some(reg),
+            Some(Reg::Vector(_)) => return Err(invalid(cursor, "Vector registers cannot be used in memory references")),
+            None => {
+                cursor.eat("#");
+                offset = Some(cursor.expr()?);
+            }
+        }
+
+        if index.is_some() && cursor.eat(",") {
+            match parse_modifier(cursor)? {
+                Some(Operand::Modifier(m, amount)) => modifier = Some((m, amount)),
+                _ => return Err(cursor.error("Expected a modifier"))
+            }
+        }
+    }
+    cursor.expect("]")?;
+    let bang = cursor.eat("!");
+
+    Ok(match (offset, index, bang) {
+        (None, None, false) => MemoryRef::Base(base),
+        (Some(offset), None, false) => MemoryRef::Offset(base, offset),
+        (Some(offset), None, true) => MemoryRef::PreIndexed(base, offset),
+        (None, Some(index), false) => MemoryRef::Indexed(base, index, modifier),
+        _ => return Err(invalid(cursor, "Pre-indexing requires an immediate offset"))
+    })
+}
+
+/// Parses a register list after its opening brace, including a trailing element index
+fn parse_list<'a>(asm: &TextAssembler, cursor: &mut Cursor<'a, '_>, column: usize) -> Result<Operand<'a>, TextError> {
+    let parse_vector = |cursor: &mut Cursor| match parse_reg(asm, cursor)? {
+        Some(Reg::Vector(vector)) => Ok(vector),
+        _ => Err(cursor.error("Expected a vector register"))
+    };
+    let same_layout = |a: &Vector, b: &Vector| a.element_size == b.element_size && a.lanes == b.lanes && b.element.is_none();
+
+    let mut first = parse_vector(cursor)?;
+    let count;
+
+    // parses {reg - reg}
+    if cursor.eat("-") {
+        let last = parse_vector(cursor)?;
+        if !same_layout(&first, &last) {
+            return Err(cursor.error_at(column, TextErrorKind::Parse("Registers in a list must be of the same type".into())));
+        }
+        count = (last.reg.wrapping_sub(first.reg) % 32) + 1;
+
+    // parses {reg * amount}
+    } else if cursor.eat("*") {
+        let amount_column = cursor.column();
+        let amount = cursor.expr()?;
+        count = u8::try_from(amount).map_err(|_| cursor.error_at(amount_column, TextErrorKind::Parse("Invalid register list length".into())))?;
+
+    // parses {reg, reg .. , reg}
+    } else {
+        let mut last = first;
+        let mut amount = 1u8;
+        while cursor.eat(",") {
+            let next = parse_vector(cursor)?;
+            if !same_layout(&first, &next) || next.reg != (last.reg + 1) % 32 {
+                return Err(cursor.error_at(column, TextErrorKind::Parse("Registers in a list must be consecutive and of the same type".into())));
+            }
+            last = next;
+            amount += 1;
+        }
+        count = amount;
+    }
+    cursor.expect("}")?;
+
+    // parse a trailing [element] declaration
+    if cursor.eat("[") {
+        first.element = Some(parse_element(cursor)?);
+        cursor.expect("]")?;
+    }
+
+    Ok(Operand::List(first, count))
+}
+
+/// Parses a modifier (`lsl #2`, `sxtw`), if one is next
+fn parse_modifier<'a>(cursor: &mut Cursor<'a, '_>) -> Result<Option<Operand<'a>>, TextError> {
+    let modifier = match cursor.peek() {
+        Some(Token::Ident(ident)) => match as_modifier(ident) {
+            Some(modifier) => modifier,
+            None => return Ok(None)
+        },
+        _ => return Ok(None)
+    };
+    cursor.next();
+
+    // valid terminating symbols
+    if cursor.is_empty() || cursor.peek_punct(",") || cursor.peek_punct("]") {
+        return Ok(Some(Operand::Modifier(modifier, None)));
+    }
+
+    // parse an expr with possible leading # sign
+    cursor.eat("#");
+    let column = cursor.column();
+    let amount = cursor.expr()?;
+    let amount = u32::try_from(amount).map_err(|_| cursor.error_at(column, TextErrorKind::Parse("Invalid modifier amount".into())))?;
+
+    Ok(Some(Operand::Modifier(modifier, Some(amount))))
+}
+
+fn as_modifier(name: &str) -> Option<Modifier> {
+    Some(match name {
+        "LSL"  | "lsl"  => Modifier::LSL,
+        "LSR"  | "lsr"  => Modifier::LSR,
+        "ASR"  | "asr"  => Modifier::ASR,
+        "ROR"  | "ror"  => Modifier::ROR,
+        "SXTX" | "sxtx" => Modifier::SXTX,
+        "SXTW" | "sxtw" => Modifier::SXTW,
+        "SXTH" | "sxth" => Modifier::SXTH,
+        "SXTB" | "sxtb" => Modifier::SXTB,
+        "UXTX" | "uxtx" => Modifier::UXTX,
+        "UXTW" | "uxtw" => Modifier::UXTW,
+        "UXTH" | "uxth" => Modifier::UXTH,
+        "UXTB" | "uxtb" => Modifier::UXTB,
+        "MSL"  | "msl"  => Modifier::MSL,
+        "MUL"  | "mul"  => Modifier::MUL,
+        _ => return None
+    })
+}
+
+/// Parses a register if one is next. Registers can be named statically (`x0`, `v1.b16`), by an alias, or
+/// chosen by number with a register family (`X(0)`, `V(1).b16`).
+fn parse_reg(asm: &TextAssembler, cursor: &mut Cursor) -> Result<Option<Reg>, TextError> {
+    let name = match cursor.peek() {
+        Some(Token::Ident(name)) => name,
+        _ => return Ok(None)
+    };
+
+    let (family, number) = if cursor.peek2() == Some(Token::Punct("(")) {
+        // dynamically chosen registers ( ident(expr) )
+        let family = match as_family(name) {
+            Some(family) => family,
+            None => return Ok(None)
+        };
+        cursor.next();
+        cursor.next();
+        let column = cursor.column();
+        let number = cursor.expr()?;
+        cursor.expect(")")?;
+
+        match u8::try_from(number) {
+            Ok(number) if number < 32 => (family, number),
+            _ => return Err(cursor.error_at(column, TextErrorKind::Parse("Invalid register number".into())))
+        }
+    } else {
+        match as_register(asm.resolve_alias(name)) {
+            Some(reg) => {
+                cursor.next();
+                reg
+            },
+            None => return Ok(None)
+        }
+    };
+
+    Ok(Some(match family {
+        Family::Scalar(family) => Reg::Scalar(family(number)),
+        Family::Vector => Reg::Vector(parse_vector_trailer(cursor, number)?)
+    }))
+}
+
+/// Parses the elementsize/lanes specifier ("." [BHSDQ][124816]) and optional element index of a vector register.
+/// Like in `dynasm!`, the lane count follows the element size.
+fn parse_vector_trailer(cursor: &mut Cursor, reg: u8) -> Result<Vector, TextError> {
+    cursor.expect(".")?;
+    let column = cursor.column();
+    let invalid = |cursor: &Cursor| cursor.error_at(column, TextErrorKind::Parse("Invalid width specifier".into()));
+
+    let specifier = cursor.ident()?;
+    let (first, trailer) = specifier.split_at(1);
+    let element_size = match first {
+        "B" | "b" => Size::Byte,
+        "H" | "h" => Size::Word,
+        "S" | "s" => Size::DWord,
+        "D" | "d" => Size::QWord,
+        "Q" | "q" => Size::OWord,
+        _ => return Err(invalid(cursor))
+    };
+    let lanes = match trailer {
+        "" => None,
+        "1" | "2" | "4" | "8" | "16" => trailer.parse().ok(),
+        _ => return Err(invalid(cursor))
+    };
+
+    // parse the element specifier
+    let element = if cursor.eat("[") {
+        let element = parse_element(cursor)?;
+        cursor.expect("]")?;
+        Some(element)
+    } else {
+        None
+    };
+
+    Ok(Vector {
+        reg,
+        element_size,
+        lanes,
+        element
+    })
+}
+
+fn parse_element(cursor: &mut Cursor) -> Result<u8, TextError> {
+    let column = cursor.column();
+    let element = cursor.expr()?;
+    u8::try_from(element).map_err(|_| cursor.error_at(column, TextErrorKind::Parse("Invalid element index".into())))
+}
+
+/// The kind of register a register name refers to. Vector registers can only be constructed once their
+/// trailer has been parsed.
+#[derive(Clone, Copy)]
+enum Family {
+    Scalar(fn(u8) -> Register),
+    Vector,
+}
+
+fn as_family(name: &str) -> Option<Family> {
+    Some(match name {
+        "X" => Family::Scalar(Register::X),
+        "W" => Family::Scalar(Register::W),
+        "XSP" => Family::Scalar(Register::XSP),
+        "WSP" => Family::Scalar(Register::WSP),
+        "B" => Family::Scalar(Register::B),
+        "H" => Family::Scalar(Register::H),
+        "S" => Family::Scalar(Register::S),
+        "D" => Family::Scalar(Register::D),
+        "Q" => Family::Scalar(Register::Q),
+        "V" => Family::Vector,
+        _ => return None
+    })
+}
+
+/// Looks up a static register name
+fn as_register(name: &str) -> Option<(Family, u8)> {
+    match name {
+        "sp" => return Some((Family::Scalar(Register::XSP), 31)),
+        "wsp" => return Some((Family::Scalar(Register::WSP), 31)),
+        "xzr" => return Some((Family::Scalar(Register::X), 31)),
+        "wzr" => return Some((Family::Scalar(Register::W), 31)),
+        _ => ()
+    }
+
+    if !name.is_char_boundary(1) {
+        return None;
+    }
+    let (family, count) = match name.split_at(1).0 {
+        "x" => (Family::Scalar(Register::X), 31),
+        "w" => (Family::Scalar(Register::W), 31),
+        "b" => (Family::Scalar(Register::B), 32),
+        "h" => (Family::Scalar(Register::H), 32),
+        "s" => (Family::Scalar(Register::S), 32),
+        "d" => (Family::Scalar(Register::D), 32),
+        "q" => (Family::Scalar(Register::Q), 32),
+        "v" => (Family::Vector, 32),
+        _ => return None
+    };
+
+    // register numbers without leading zeroes
+    let digits = &name[1 ..];
+    if digits.is_empty() || (digits.len() > 1 && digits.starts_with('0')) || !digits.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    let number = digits.parse().ok().filter(|&n| n < count)?;
+    Some((family, number))
+}
@@ -0,0 +1,348 @@
+//! A runtime text assembler. It assembles strings written in the same dialect as the `dynasm!` macro,
+//! for when the assembly is only known at runtime, like snippets from configuration files or scripts.
+//!
+//! ```text
+//! // comments start with two slashes
+//! ->entry:
+//!     mov rax, 0x1234       ; add rax, 8
+//!     test rdi, rdi
+//!     jz >skip
+//!     mov rax, [rdi + rsi * 8 - 0x10]
+//! skip:
+//!     ret
+//! ```
+//!
+//! Statements are separated by newlines or `;`. Instructions are parsed like `dynasm!` parses them, and
+//! encoded by the runtime encoders in the `x64` and `aarch64` modules. Local (`label:`), global
+//! (`->label:`) and dynamic (`=>label`) labels are supported, as are the `.arch`, `.feature`, `.alias`,
+//! `.align`, `.byte`, `.word`, `.dword`, `.qword` and `.runtime_sizes` directives.
+//!
+//! As there is no rust code to evaluate at runtime, immediates have to be constant expressions, registers
+//! can only be chosen dynamically with constant expressions (`Rq(3)`), and dynamic labels are referred to
+//! by names that have to be bound with `TextAssembler::bind_dynamic_label`. `.bytes` and `;;` statements
+//! are not supported.
+
+mod parser;
+mod x64;
+mod aarch64;
+
+use std::collections::{HashMap, HashSet};
+use std::error;
+use std::fmt;
+use std::sync::{Mutex, OnceLock};
+
+use crate::{DynasmLabelApi, DynamicLabel, TargetKind};
+use crate::relocations::Relocation;
+use crate::encoder::{EncodeError, emit_relocation};
+use crate::x64::X64Relocation;
+use crate::aarch64::Aarch64Relocation;
+
+use self::parser::{Cursor, Token, tokenize};
+
+
+/// An error that occurred while assembling text, and where it occurred.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TextError {
+    /// The line the error occurred on, starting at 1
+    pub line: usize,
+    /// The column the error occurred at, starting at 1
+    pub column: usize,
+    /// What went wrong
+    pub kind: TextErrorKind,
+}
+
+/// The kinds of errors that can occur while assembling text.
+#[derive(Debug, Clone, PartialEq)]
+pub enum TextErrorKind {
+    /// The text does not follow the assembly syntax
+    Parse(String),
+    /// A directive is unknown or has invalid arguments
+    Directive(String),
+    /// An instruction could not be encoded
+    Encode(EncodeError),
+}
+
+impl fmt::Display for TextError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}:{}: ", self.line, self.column)?;
+        match self.kind {
+            TextErrorKind::Parse(ref msg) => write!(f, "{}", msg),
+            TextErrorKind::Directive(ref msg) => write!(f, "{}", msg),
+            TextErrorKind::Encode(ref e) => write!(f, "{}", e),
+        }
+    }
+}
+
+impl error::Error for TextError {
+    fn description(&self) -> &str {
+        match self.kind {
+            TextErrorKind::Parse(_) => "Syntax error",
+            TextErrorKind::Directive(_) => "Invalid directive",
+            TextErrorKind::Encode(_) => "Invalid instruction",
+        }
+    }
+}
+
+
+/// Assembles text into a `DynasmLabelApi` implementor. Aliases defined with `.alias` and the bound
+/// dynamic label names are kept between calls, just like aliases are kept between `dynasm!` invocations
+/// in the same file.
+///
+/// Assembly stops at the first error. Anything that was assembled before it is left in the assembler.
+#[derive(Debug, Clone, Default)]
+pub struct TextAssembler {
+    aliases: HashMap<String, String>,
+    dynamic_labels: HashMap<String, DynamicLabel>,
+}
+
+impl TextAssembler {
+    /// Create a new text assembler without any aliases or dynamic labels.
+    pub fn new() -> TextAssembler {
+        TextAssembler::default()
+    }
+
+    /// Make `=>name` in assembled text refer to the dynamic label `label`.
+    pub fn bind_dynamic_label(&mut self, name: &str, label: DynamicLabel) {
+        self.dynamic_labels.insert(name.to_string(), label);
+    }
+
+    /// Assemble x64 text into `ops`.
+    pub fn assemble_x64<O>(&mut self, ops: &mut O, source: &str) -> Result<(), TextError>
+    where O: DynasmLabelApi<Relocation = X64Relocation> {
+        self.assemble::<x64::X64, O>(ops, source)
+    }
+
+    /// Assemble aarch64 text into `ops`.
+    pub fn assemble_aarch64<O>(&mut self, ops: &mut O, source: &str) -> Result<(), TextError>
+    where O: DynasmLabelApi<Relocation = Aarch64Relocation> {
+        self.assemble::<aarch64::Aarch64, O>(ops, source)
+    }
+
+    fn assemble<A, O>(&mut self, ops: &mut O, source: &str) -> Result<(), TextError>
+    where A: Arch, O: DynasmLabelApi<Relocation = A::Relocation> {
+        for (i, text) in source.lines().enumerate() {
+            let line = i + 1;
+            let tokens = tokenize(text, line)?;
+            let end = text.chars().count() + 1;
+
+            for stmt in tokens.split(|t| t.token == Token::Punct(";")) {
+                if stmt.is_empty() {
+                    continue;
+                }
+                let mut cursor = Cursor::new(stmt, line, end);
+                self.statement::<A, O>(ops, &mut cursor)?;
+                cursor.finish()?;
+            }
+        }
+        Ok(())
+    }
+
+    // the same statements that dynasm! parses, minus rust statements
+    fn statement<A, O>(&mut self, ops: &mut O, cursor: &mut Cursor) -> Result<(), TextError>
+    where A: Arch, O: DynasmLabelApi<Relocation = A::Relocation> {
+        // -> label :
+        if cursor.eat("->") {
+            let name = cursor.ident()?;
+            cursor.expect(":")?;
+            ops.global_label(intern(name));
+            return Ok(());
+        }
+
+        // => label
+        if cursor.peek_punct("=>") {
+            let column = cursor.column();
+            cursor.next();
+            let name = cursor.ident()?;
+            let label = self.dynamic_label(cursor, column, name)?;
+            ops.dynamic_label(label);
+            return Ok(());
+        }
+
+        // label :
+        if let (Some(Token::Ident(name)), Some(Token::Punct(":"))) = (cursor.peek(), cursor.peek2()) {
+            cursor.next();
+            cursor.next();
+            ops.local_label(intern(name));
+            return Ok(());
+        }
+
+        // . directive
+        if cursor.eat(".") {
+            return self.directive::<A, O>(ops, cursor);
+        }
+
+        A::compile_instruction(self, ops, cursor)
+    }
+
+    fn directive<A, O>(&mut self, ops: &mut O, cursor: &mut Cursor) -> Result<(), TextError>
+    where A: Arch, O: DynasmLabelApi<Relocation = A::Relocation> {
+        let column = cursor.column();
+        let directive = cursor.ident()?;
+
+        match directive {
+            "arch" => {
+                // ; .arch ident
+                let column = cursor.column();
+                let arch = cursor.ident()?;
+                if arch != A::NAME {
+                    return Err(cursor.error_at(column, TextErrorKind::Directive(
+                        format!("Cannot switch to architecture '{}', this assembler targets '{}'", arch, A::NAME)
+                    )));
+                }
+            },
+            "feature" => {
+                // ; .feature ident ("," ident) *
+                // the runtime encoders do not check features, so these are only parsed
+                cursor.ident()?;
+                while cursor.eat(",") {
+                    cursor.ident()?;
+                }
+            },
+            // ; .byte (expr ("," expr)*)?
+            "byte"  => self.directive_const::<A, O>(ops, cursor, 1)?,
+            "word"  => self.directive_const::<A, O>(ops, cursor, 2)?,
+            "dword" => self.directive_const::<A, O>(ops, cursor, 4)?,
+            "qword" => self.directive_const::<A, O>(ops, cursor, 8)?,
+            "align" => {
+                // ; .align expr ("," expr)
+                let value = cursor.expr()?;
+                let with = if cursor.eat(",") {
+                    cursor.expr()? as u8
+                } else {
+                    A::DEFAULT_ALIGN
+                };
+                if value <= 0 {
+                    return Err(cursor.error_at(column, TextErrorKind::Directive("Alignment has to be positive".into())));
+                }
+                ops.align(value as usize, with);
+            },
+            "alias" => {
+                // ; .alias ident, ident
+                let column = cursor.column();
+                let alias = cursor.ident()?;
+                cursor.expect(",")?;
+                let reg = cursor.ident()?;
+
+                if self.aliases.contains_key(alias) {
+                    return Err(cursor.error_at(column, TextErrorKind::Directive(
+                        format!("Duplicate alias definition, alias '{}' was already defined", alias)
+                    )));
+                }
+                self.aliases.insert(alias.to_string(), reg.to_string());
+            },
+            "runtime_sizes" => {
+                // ; .runtime_sizes (on | off)
+                // immediates are always known when assembling text, so their size never has to be guessed
+                let column = cursor.column();
+                match cursor.ident()? {
+                    "on" | "off" => (),
+                    _ => return Err(cursor.error_at(column, TextErrorKind::Directive("Expected 'on' or 'off'".into())))
+                }
+            },
+            d => return Err(cursor.error_at(column, TextErrorKind::Directive(format!("unknown directive '{}'", d))))
+        }
+
+        Ok(())
+    }
+
+    fn directive_const<A, O>(&self, ops: &mut O, cursor: &mut Cursor, size: u8) -> Result<(), TextError>
+    where A: Arch, O: DynasmLabelApi<Relocation = A::Relocation> {
+        if cursor.is_empty() {
+            return Ok(());
+        }
+
+        loop {
+            let column = cursor.column();
+            if let Some(target) = self.parse_jump(cursor)? {
+                let kind = A::data_relocation(size).ok_or_else(|| cursor.error_at(column, TextErrorKind::Directive(
+                    "Relocation of unsupported size for the current target architecture".into()
+                )))?;
+                ops.extend(std::iter::repeat_n(0, size as usize));
+                emit_relocation(ops, target, kind);
+            } else {
+                // data is stored in little endian order on all supported architectures
+                let value = cursor.expr()?;
+                ops.extend(value.to_le_bytes()[.. size as usize].iter().cloned());
+            }
+
+            if !cursor.eat(",") {
+                return Ok(());
+            }
+        }
+    }
+
+    /// Parses a jump target (`->label`, `>label`, `<label`, `=>label` or `extern address`), if one is next.
+    fn parse_jump(&self, cursor: &mut Cursor) -> Result<Option<TargetKind>, TextError> {
+        let column = cursor.column();
+
+        let target = if cursor.eat_keyword("extern") {
+            TargetKind::Extern(cursor.expr()? as usize)
+        } else if cursor.eat("->") {
+            TargetKind::Global(intern(cursor.ident()?))
+        } else if cursor.eat(">") {
+            TargetKind::Forward(intern(cursor.ident()?))
+        } else if cursor.eat("<") {
+            TargetKind::Backward(intern(cursor.ident()?))
+        } else if cursor.eat("=>") {
+            let name = cursor.ident()?;
+            TargetKind::Dynamic(self.dynamic_label(cursor, column, name)?)
+        } else {
+            return Ok(None);
+        };
+
+        if cursor.peek_punct("+") || cursor.peek_punct("-") {
+            return Err(cursor.error("Label offsets are not supported when assembling text"));
+        }
+
+        Ok(Some(target))
+    }
+
+    fn dynamic_label(&self, cursor: &Cursor, column: usize, name: &str) -> Result<DynamicLabel, TextError> {
+        self.dynamic_labels.get(name).cloned().ok_or_else(|| cursor.error_at(
+            column, TextErrorKind::Parse(format!("Unknown dynamic label '{}'", name))
+        ))
+    }
+
+    /// Resolves a register alias defined with `.alias`
+    fn resolve_alias<'a>(&'a self, name: &'a str) -> &'a str {
+        self.aliases.get(name).map_or(name, |s| s.as_str())
+    }
+}
+
+
+/// Turns an `EncodeError` into an error at the given column
+fn encode_error<T>(cursor: &Cursor, column: usize, result: Result<T, EncodeError>) -> Result<T, TextError> {
+    result.map_err(|e| cursor.error_at(column, TextErrorKind::Encode(e)))
+}
+
+/// Label names have to be `'static` for the label API. Names from assembled text are therefore leaked,
+/// but only once for every distinct name.
+fn intern(name: &str) -> &'static str {
+    static NAMES: OnceLock<Mutex<HashSet<&'static str>>> = OnceLock::new();
+
+    let mut names = NAMES.get_or_init(|| Mutex::new(HashSet::new())).lock().unwrap();
+    if let Some(&name) = names.get(name) {
+        return name;
+    }
+    let name: &'static str = Box::leak(name.to_string().into_boxed_str());
+    names.insert(name);
+    name
+}
+
+
+/// The architecture specific parts of the text assembler.
+trait Arch {
+    type Relocation: Relocation;
+
+    /// The name of this architecture in `.arch`
+    const NAME: &'static str;
+    /// The default filler byte of `.align`
+    const DEFAULT_ALIGN: u8;
+
+    /// The relocation used by data directives of the given size in bytes that refer to a label
+    fn data_relocation(size: u8) -> Option<<Self::Relocation as Relocation>::Encoding>;
+
+    /// Parses and encodes a single instruction
+    fn compile_instruction<O>(asm: &TextAssembler, ops: &mut O, cursor: &mut Cursor) -> Result<(), TextError>
+    where O: DynasmLabelApi<Relocation = Self::Relocation>;
+}
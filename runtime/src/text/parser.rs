@@ -0,0 +1,327 @@
+//! Tokenizing and constant expression evaluation, shared by the text assemblers of all architectures.
+
+use std::convert::TryFrom;
+
+use super::{TextError, TextErrorKind};
+
+
+/// A single token of assembly text.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(super) enum Token<'a> {
+    Ident(&'a str),
+    Int(u64),
+    Float(f64),
+    Punct(&'static str),
+}
+
+/// A token, and the column it starts at.
+#[derive(Debug, Clone, Copy)]
+pub(super) struct Spanned<'a> {
+    pub token: Token<'a>,
+    pub column: usize,
+}
+
+// multi-character punctuation has to be listed before any of its prefixes
+const PUNCTS: &[&str] = &[
+    "->", "=>", "<<", ">>",
+    ",", ":", ";", "[", "]", "{", "}", "(", ")", ".", "#", "!",
+    "+", "-", "*", "/", "%", "&", "|", "^", "<", ">",
+];
+
+/// Splits a line into tokens. Anything after `//` is a comment.
+pub(super) fn tokenize(text: &str, line: usize) -> Result<Vec<Spanned<'_>>, TextError> {
+    let mut tokens = Vec::new();
+    let mut rest = text;
+    let mut column = 1;
+
+    loop {
+        // skip whitespace
+        let trimmed = rest.trim_start();
+        column += rest[.. rest.len() - trimmed.len()].chars().count();
+        rest = trimmed;
+
+        if rest.is_empty() || rest.starts_with("//") {
+            break;
+        }
+
+        let first = rest.chars().next().unwrap();
+        let len;
+        let token;
+
+        if first.is_ascii_alphabetic() || first == '_' {
+            len = rest.find(|c: char| !(c.is_ascii_alphanumeric() || c == '_')).unwrap_or(rest.len());
+            token = Token::Ident(&rest[.. len]);
+
+        // AVX-512 broadcast decorations ({1to8}) are kept together as a single identifier
+        } else if first.is_ascii_digit() && rest.get(1 .. 3) == Some("to") {
+            len = rest.find(|c: char| !c.is_ascii_alphanumeric()).unwrap_or(rest.len());
+            token = Token::Ident(&rest[.. len]);
+
+        } else if first.is_ascii_digit() {
+            let (number, l) = parse_number(rest).ok_or_else(|| TextError {
+                line,
+                column,
+                kind: TextErrorKind::Parse("Invalid number literal".into())
+            })?;
+            len = l;
+            token = number;
+
+        } else if let Some(&punct) = PUNCTS.iter().find(|&&p| rest.starts_with(p)) {
+            len = punct.len();
+            token = Token::Punct(punct);
+
+        } else {
+            return Err(TextError {
+                line,
+                column,
+                kind: TextErrorKind::Parse(format!("Unexpected character '{}'", first))
+            });
+        }
+
+        tokens.push(Spanned { token, column });
+        column += rest[.. len].chars().count();
+        rest = &rest[len ..];
+    }
+
+    Ok(tokens)
+}
+
+/// Parses an integer or float literal in rust syntax from the start of `text`, returning it and its length.
+fn parse_number(text: &str) -> Option<(Token<'_>, usize)> {
+    let bytes = text.as_bytes();
+    let word = |start: usize| text[start ..].find(|c: char| !(c.is_ascii_alphanumeric() || c == '_')).map_or(text.len(), |i| i + start);
+
+    // prefixed integers
+    let radix = match (bytes[0], bytes.get(1)) {
+        (b'0', Some(b'x')) => Some(16),
+        (b'0', Some(b'o')) => Some(8),
+        (b'0', Some(b'b')) => Some(2),
+        _ => None
+    };
+    if let Some(radix) = radix {
+        let end = word(2);
+        let digits: String = text[2 .. end].chars().filter(|&c| c != '_').collect();
+        return u64::from_str_radix(&digits, radix).ok().map(|value| (Token::Int(value), end));
+    }
+
+    // decimal integers, optionally followed by a fraction and/or exponent
+    let mut end = text.find(|c: char| !(c.is_ascii_digit() || c == '_')).unwrap_or(text.len());
+    let mut is_float = false;
+    if bytes.get(end) == Some(&b'.') && bytes.get(end + 1).is_some_and(u8::is_ascii_digit) {
+        is_float = true;
+        end = text[end + 1 ..].find(|c: char| !(c.is_ascii_digit() || c == '_')).map_or(text.len(), |i| i + end + 1);
+    }
+    if let Some(b'e') | Some(b'E') = bytes.get(end) {
+        let mut exp = end + 1;
+        if let Some(b'+') | Some(b'-') = bytes.get(exp) {
+            exp += 1;
+        }
+        if bytes.get(exp).is_some_and(u8::is_ascii_digit) {
+            is_float = true;
+            end = text[exp ..].find(|c: char| !(c.is_ascii_digit() || c == '_')).map_or(text.len(), |i| i + exp);
+        }
+    }
+
+    // reject trailing garbage like type suffixes
+    if word(end) != end {
+        return None;
+    }
+
+    let digits: String = text[.. end].chars().filter(|&c| c != '_').collect();
+    if is_float {
+        digits.parse().ok().map(|value| (Token::Float(value), end))
+    } else {
+        digits.parse().ok().map(|value| (Token::Int(value), end))
+    }
+}
+
+
+/// A cursor over the tokens of a single statement.
+#[derive(Clone)]
+pub(super) struct Cursor<'a, 'b> {
+    tokens: &'b [Spanned<'a>],
+    pos: usize,
+    line: usize,
+    end: usize,
+}
+
+impl<'a, 'b> Cursor<'a, 'b> {
+    pub fn new(tokens: &'b [Spanned<'a>], line: usize, end: usize) -> Cursor<'a, 'b> {
+        Cursor { tokens, pos: 0, line, end }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.pos == self.tokens.len()
+    }
+
+    pub fn peek(&self) -> Option<Token<'a>> {
+        self.tokens.get(self.pos).map(|t| t.token)
+    }
+
+    pub fn peek2(&self) -> Option<Token<'a>> {
+        self.tokens.get(self.pos + 1).map(|t| t.token)
+    }
+
+    pub fn next(&mut self) -> Option<Token<'a>> {
+        let token = self.peek();
+        if token.is_some() {
+            self.pos += 1;
+        }
+        token
+    }
+
+    /// The column of the next token, or of the end of the statement
+    pub fn column(&self) -> usize {
+        self.tokens.get(self.pos).map_or(self.end, |t| t.column)
+    }
+
+    /// Creates a parse error at the next token
+    pub fn error(&self, msg: &str) -> TextError {
+        self.error_at(self.column(), TextErrorKind::Parse(msg.into()))
+    }
+
+    pub fn error_at(&self, column: usize, kind: TextErrorKind) -> TextError {
+        TextError {
+            line: self.line,
+            column,
+            kind
+        }
+    }
+
+    pub fn peek_punct(&self, punct: &str) -> bool {
+        matches!(self.peek(), Some(Token::Punct(p)) if p == punct)
+    }
+
+    /// Consumes the given punctuation if it is next
+    pub fn eat(&mut self, punct: &str) -> bool {
+        if self.peek_punct(punct) {
+            self.pos += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    pub fn expect(&mut self, punct: &str) -> Result<(), TextError> {
+        if self.eat(punct) {
+            Ok(())
+        } else {
+            Err(self.error(&format!("Expected '{}'", punct)))
+        }
+    }
+
+    /// Consumes an identifier with the given name if it is next
+    pub fn eat_keyword(&mut self, keyword: &str) -> bool {
+        if self.peek() == Some(Token::Ident(keyword)) {
+            self.pos += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    pub fn ident(&mut self) -> Result<&'a str, TextError> {
+        match self.peek() {
+            Some(Token::Ident(ident)) => {
+                self.pos += 1;
+                Ok(ident)
+            },
+            _ => Err(self.error("Expected identifier"))
+        }
+    }
+
+    /// Checks that the whole statement was consumed
+    pub fn finish(&self) -> Result<(), TextError> {
+        if self.is_empty() {
+            Ok(())
+        } else {
+            Err(self.error("Unexpected token"))
+        }
+    }
+
+    /// Parses and evaluates a constant integer expression. Supports the rust integer operators, evaluated
+    /// with wrapping 64-bit arithmetic.
+    pub fn expr(&mut self) -> Result<i64, TextError> {
+        self.binary_expr(0)
+    }
+
+    // precedence climbing over the binary operators
+    fn binary_expr(&mut self, min_precedence: u8) -> Result<i64, TextError> {
+        let mut lhs = self.unary_expr()?;
+
+        while let Some((op, precedence)) = self.peek_binary_op(min_precedence) {
+            let column = self.column();
+            self.pos += 1;
+
+            let rhs = self.binary_expr(precedence + 1)?;
+            lhs = apply_binary(op, lhs, rhs).ok_or_else(|| self.error_at(
+                column, TextErrorKind::Parse("Division by zero or shift overflow".into())
+            ))?;
+        }
+
+        Ok(lhs)
+    }
+
+    // the next binary operator and its precedence, if it binds at least as strong as min_precedence
+    fn peek_binary_op(&self, min_precedence: u8) -> Option<(&'static str, u8)> {
+        match self.peek() {
+            Some(Token::Punct(op)) => binary_precedence(op)
+                .filter(|&precedence| precedence >= min_precedence)
+                .map(|precedence| (op, precedence)),
+            _ => None
+        }
+    }
+
+    /// Parses a single operand of a constant expression: a literal, a parenthesized expression or a negation
+    pub fn unary_expr(&mut self) -> Result<i64, TextError> {
+        match self.peek() {
+            Some(Token::Int(value)) => {
+                self.pos += 1;
+                Ok(value as i64)
+            },
+            Some(Token::Punct("-")) => {
+                self.pos += 1;
+                Ok(self.unary_expr()?.wrapping_neg())
+            },
+            Some(Token::Punct("!")) => {
+                self.pos += 1;
+                Ok(!self.unary_expr()?)
+            },
+            Some(Token::Punct("(")) => {
+                self.pos += 1;
+                let value = self.expr()?;
+                self.expect(")")?;
+                Ok(value)
+            },
+            _ => Err(self.error("Expected a constant expression"))
+        }
+    }
+}
+
+fn binary_precedence(op: &str) -> Option<u8> {
+    Some(match op {
+        "|" => 1,
+        "^" => 2,
+        "&" => 3,
+        "<<" | ">>" => 4,
+        "+" | "-" => 5,
+        "*" | "/" | "%" => 6,
+        _ => return None
+    })
+}
+
+fn apply_binary(op: &str, lhs: i64, rhs: i64) -> Option<i64> {
+    Some(match op {
+        "|" => lhs | rhs,
+        "^" => lhs ^ rhs,
+        "&" => lhs & rhs,
+        "<<" => lhs.checked_shl(u32::try_from(rhs).ok()?)?,
+        ">>" => lhs.checked_shr(u32::try_from(rhs).ok()?)?,
+        "+" => lhs.wrapping_add(rhs),
+        "-" => lhs.wrapping_sub(rhs),
+        "*" => lhs.wrapping_mul(rhs),
+        "/" => lhs.checked_div(rhs)?,
+        "%" => lhs.checked_rem(rhs)?,
+        _ => unreachable!()
+    })
+}
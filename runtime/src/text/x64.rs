@@ -0,0 +1,466 @@
+//! Parsing of x64 instructions. This follows the x64 parser of the `dynasm!` macro.
+
+use std::convert::TryFrom;
+
+use crate::DynasmLabelApi;
+use crate::x64::X64Relocation;
+use crate::encoder::x64::{encode_with_decorations, Mnemonic, Operand, Register, MemoryRef, Size, Prefix, Decorations, Rounding};
+
+use super::{Arch, TextAssembler, TextError, TextErrorKind, encode_error};
+use super::parser::{Cursor, Token};
+
+
+pub(super) struct X64;
+
+impl Arch for X64 {
+    type Relocation = X64Relocation;
+
+    const NAME: &'static str = "x64";
+    const DEFAULT_ALIGN: u8 = 0x90;
+
+    fn data_relocation(size: u8) -> Option<(u8, u8, u8, u8)> {
+        // no offset, specified size, relative implicit, not part of an instruction
+        Some((0, size, 0, 0))
+    }
+
+    // syntax for a single op: PREFIX* ident (SIZE? expr DECORATION* ("," (SIZE? expr DECORATION* | ROUNDING))*)?
+    fn compile_instruction<O>(asm: &TextAssembler, ops: &mut O, cursor: &mut Cursor) -> Result<(), TextError>
+    where O: DynasmLabelApi<Relocation = X64Relocation> {
+        let start = cursor.column();
+
+        // read prefixes + op
+        let mut prefixes = Vec::new();
+        let mut column = cursor.column();
+        let mut op = cursor.ident()?;
+        while let Some(prefix) = as_prefix(op) {
+            prefixes.push(prefix);
+            column = cursor.column();
+            op = cursor.ident()?;
+        }
+        let mnemonic = encode_error(cursor, column, op.parse::<Mnemonic>())?;
+
+        // parse (sizehint? expr),*
+        let mut args = Vec::new();
+        let mut decorations = Decorations::default();
+        if !cursor.is_empty() {
+            args.push(parse_arg(asm, cursor, &mut decorations, true)?);
+
+            while cursor.eat(",") {
+                // embedded rounding specifiers are parsed as separate operands, but they're not encoded as such
+                let column = cursor.column();
+                if let Some(rounding) = eat_rounding(cursor) {
+                    if decorations.rounding.is_some() {
+                        return Err(cursor.error_at(column, TextErrorKind::Parse("Duplicate rounding specifier".into())));
+                    }
+                    decorations.rounding = Some(rounding);
+                    continue;
+                }
+
+                args.push(parse_arg(asm, cursor, &mut decorations, false)?);
+            }
+        }
+        cursor.finish()?;
+
+        encode_error(cursor, start, encode_with_decorations(ops, &prefixes, &decorations, mnemonic, &args))
+    }
+}
+
+/// checks if the given ident is a valid x86 prefix
+fn as_prefix(ident: &str) -> Option<Prefix> {
+    Some(match ident {
+        "lock" => Prefix::Lock,
+        "rep" => Prefix::Rep,
+        "repe" | "repz" => Prefix::Repe,
+        "repne" | "repnz" => Prefix::Repne,
+        "ss" => Prefix::Ss,
+        "cs" => Prefix::Cs,
+        "ds" => Prefix::Ds,
+        "es" => Prefix::Es,
+        "fs" => Prefix::Fs,
+        "gs" => Prefix::Gs,
+        _ => return None
+    })
+}
+
+/// if a size hint is present in the token stream, returning the indicated size
+fn eat_size_hint(cursor: &mut Cursor) -> Option<Size> {
+    const X64_SIZES: [(&str, Size); 10] = [
+        ("BYTE", Size::Byte),
+        ("WORD", Size::Word),
+        ("DWORD", Size::DWord),
+        ("FWORD", Size::FWord),
+        ("AWORD", Size::QWord),
+        ("QWORD", Size::QWord),
+        ("TWORD", Size::PWord),
+        ("OWORD", Size::OWord),
+        ("YWORD", Size::HWord),
+        ("ZWORD", Size::ZWord)
+    ];
+
+    for &(kw, size) in &X64_SIZES {
+        if cursor.eat_keyword(kw) {
+            return Some(size);
+        }
+    }
+    None
+}
+
+/// if an embedded rounding / sae specifier ({rn-sae}, {rd-sae}, {ru-sae}, {rz-sae} or {sae}) is next,
+/// consumes and returns it.
+fn eat_rounding(cursor: &mut Cursor) -> Option<Rounding> {
+    let mut fork = cursor.clone();
+    if !fork.eat("{") {
+        return None;
+    }
+
+    let rounding = if fork.eat_keyword("sae") {
+        Rounding::Sae
+    } else {
+        let mode = match fork.next() {
+            Some(Token::Ident("rn")) => Rounding::Nearest,
+            Some(Token::Ident("rd")) => Rounding::Down,
+            Some(Token::Ident("ru")) => Rounding::Up,
+            Some(Token::Ident("rz")) => Rounding::Zero,
+            _ => return None
+        };
+        if !(fork.eat("-") && fork.eat_keyword("sae")) {
+            return None;
+        }
+        mode
+    };
+
+    if !fork.eat("}") {
+        return None;
+    }
+
+    *cursor = fork;
+    Some(rounding)
+}
+
+/// parses any AVX-512 decorations ({k}, {z} or {1toN}) following an operand
+fn parse_decorations(asm: &TextAssembler, cursor: &mut Cursor, decorations: &mut Decorations, first: bool, memory: bool)
+-> Result<(), TextError> {
+    while cursor.peek_punct("{") {
+        let column = cursor.column();
+        let error = |cursor: &Cursor, msg: &str| Err(cursor.error_at(column, TextErrorKind::Parse(msg.into())));
+        cursor.next();
+
+        match cursor.peek() {
+            // broadcast: {1toN}
+            Some(Token::Ident(word)) if word.starts_with(|c: char| c.is_ascii_digit()) => {
+                cursor.next();
+                match word.strip_prefix("1to").and_then(|count| count.parse::<u8>().ok()) {
+                    Some(_) if !memory => return error(cursor, "Broadcasts can only be applied to memory references"),
+                    Some(_) if decorations.broadcast.is_some() => return error(cursor, "Duplicate broadcast"),
+                    Some(count) => decorations.broadcast = Some(count),
+                    None => return error(cursor, "Invalid broadcast, expected {1toN}")
+                }
+            },
+            Some(Token::Int(_)) => return error(cursor, "Invalid broadcast, expected {1toN}"),
+
+            // zeroing-masking: {z}
+            Some(Token::Ident("z")) => {
+                cursor.next();
+                if !first {
+                    return error(cursor, "Zeroing can only be applied to the destination operand");
+                } else if memory {
+                    return error(cursor, "Zeroing cannot be applied to memory references");
+                }
+                decorations.zeroing = true;
+            },
+
+            // opmask: {k}
+            _ => match parse_reg(asm, cursor)? {
+                Some(Register::Rk(n)) => if !first {
+                    return error(cursor, "An opmask can only be applied to the destination operand");
+                } else if decorations.mask.is_some() {
+                    return error(cursor, "Duplicate opmask");
+                } else if n == 0 {
+                    return error(cursor, "k0 cannot be used as an opmask");
+                } else {
+                    decorations.mask = Some(Register::Rk(n));
+                },
+                _ => return error(cursor, "Unknown decoration, expected an opmask, {z} or {1toN}")
+            }
+        }
+
+        cursor.expect("}")?;
+    }
+
+    Ok(())
+}
+
+/// tries to parse a full arg definition
+fn parse_arg(asm: &TextAssembler, cursor: &mut Cursor, decorations: &mut Decorations, first: bool) -> Result<Operand, TextError> {
+    // sizehint
+    let size = eat_size_hint(cursor);
+    let column = cursor.column();
+
+    // bare label
+    if let Some(target) = asm.parse_jump(cursor)? {
+        if size.is_some() {
+            return Err(cursor.error_at(column, TextErrorKind::Parse("Size hints on jump targets are not supported when assembling text".into())));
+        }
+        return Ok(Operand::Label(target));
+    }
+
+    // indirect
+    if cursor.eat("[") {
+        // label
+        if let Some(target) = asm.parse_jump(cursor)? {
+            cursor.expect("]")?;
+            return Ok(Operand::LabelMem(target, size));
+        }
+
+        if cursor.eat_keyword("NOSPLIT") || eat_size_hint(cursor).is_some() {
+            return Err(cursor.error_at(column, TextErrorKind::Parse("NOSPLIT and displacement size hints are not supported when assembling text".into())));
+        }
+
+        let mem = parse_memoryref(asm, cursor, size)?;
+        cursor.expect("]")?;
+
+        parse_decorations(asm, cursor, decorations, first, true)?;
+        return Ok(Operand::Mem(mem));
+    }
+
+    // direct register
+    if let Some(reg) = parse_reg(asm, cursor)? {
+        if size.is_some() {
+            return Err(cursor.error_at(column, TextErrorKind::Parse("size hint with direct register".into())));
+        }
+        parse_decorations(asm, cursor, decorations, first, false)?;
+        return Ok(Operand::Reg(reg));
+    }
+
+    // immediate
+    let value = cursor.expr()?;
+    if size.is_some() {
+        return Err(cursor.error_at(column, TextErrorKind::Parse("Size hints on immediates are not supported when assembling text".into())));
+    }
+    Ok(Operand::Imm(value))
+}
+
+/// Parses the contents of a memory reference. Registers can be added together and scaled by constants, all
+/// other terms are added into the displacement.
+fn parse_memoryref(asm: &TextAssembler, cursor: &mut Cursor, size: Option<Size>) -> Result<MemoryRef, TextError> {
+    let column = cursor.column();
+    let impossible = |cursor: &Cursor, msg: &str| cursor.error_at(column, TextErrorKind::Parse(msg.into()));
+
+    // split the reference into registers, scaled registers and displacements
+    let mut regs = Vec::new();
+    let mut scaled = Vec::new();
+    let mut disp = 0i64;
+
+    let mut negative = cursor.eat("-");
+    loop {
+        let mut reg = None;
+        let mut multiplied = false;
+        let mut value = 1i64;
+
+        loop {
+            let factor_column = cursor.column();
+            if let Some(r) = parse_reg(asm, cursor)? {
+                if reg.is_some() {
+                    return Err(cursor.error_at(factor_column, TextErrorKind::Parse("Registers cannot be multiplied".into())));
+                }
+                reg = Some(r);
+            } else {
+                value = value.wrapping_mul(cursor.unary_expr()?);
+            }
+
+            if !cursor.eat("*") {
+                break;
+            }
+            multiplied = true;
+        }
+
+        match reg {
+            Some(_) if negative => return Err(impossible(cursor, "Registers cannot be subtracted")),
+            Some(reg) if !multiplied => regs.push(reg),
+            Some(reg) => scaled.push((reg, value)),
+            None if negative => disp = disp.wrapping_sub(value),
+            None => disp = disp.wrapping_add(value),
+        }
+
+        if cursor.eat("+") {
+            negative = false;
+        } else if cursor.eat("-") {
+            negative = true;
+        } else {
+            break;
+        }
+    }
+
+    // figure out the base register if possible
+    let base_index = (0 .. regs.len()).find(|&i| !(
+        regs.iter().enumerate().any(|(j, other)| i != j && regs[i] == *other) ||
+        scaled.iter().any(|&(other, _)| regs[i] == other)
+    ));
+    let mut base = base_index.map(|i| regs.remove(i));
+
+    // join all registers
+    scaled.extend(regs.into_iter().map(|r| (r, 1)));
+    let mut joined_regs: Vec<(Register, i64)> = Vec::new();
+    for (reg, s) in scaled {
+        // does this register already have a spot?
+        if let Some(entry) = joined_regs.iter_mut().find(|(other, _)| *other == reg) {
+            entry.1 += s;
+        } else {
+            joined_regs.push((reg, s));
+        }
+    }
+
+    // identify an index candidate (and a base if one wasn't found yet)
+    if base.is_none() {
+        if let Some(i) = joined_regs.iter().position(|&(_, s)| s == 1) {
+            base = Some(joined_regs.remove(i).0);
+        }
+    }
+    let index = joined_regs.pop();
+
+    if !joined_regs.is_empty() {
+        return Err(impossible(cursor, "Impossible memory argument"));
+    }
+
+    let index = match index {
+        Some((reg, scale)) => Some((reg, u8::try_from(scale).map_err(|_| impossible(cursor, "Impossible scale"))?)),
+        None => None
+    };
+    let disp = i32::try_from(disp).map_err(|_| impossible(cursor, "Displacement out of range"))?;
+
+    Ok(MemoryRef {
+        size,
+        base,
+        index,
+        disp
+    })
+}
+
+/// Parses a register if one is next. Registers can be named statically (`rax`), by an alias, or chosen by
+/// number with a register family (`Rq(0)`).
+fn parse_reg(asm: &TextAssembler, cursor: &mut Cursor) -> Result<Option<Register>, TextError> {
+    let name = match cursor.peek() {
+        Some(Token::Ident(name)) => name,
+        _ => return Ok(None)
+    };
+
+    // dynamically chosen registers ( ident(expr) )
+    if cursor.peek2() == Some(Token::Punct("(")) {
+        let family = match as_family(name) {
+            Some(family) => family,
+            None => return Ok(None)
+        };
+        cursor.next();
+        cursor.next();
+        let column = cursor.column();
+        let number = cursor.expr()?;
+        cursor.expect(")")?;
+
+        return match u8::try_from(number) {
+            Ok(number) => Ok(Some(family(number))),
+            Err(_) => Err(cursor.error_at(column, TextErrorKind::Parse("Invalid register number".into())))
+        };
+    }
+
+    let reg = as_register(asm.resolve_alias(name));
+    if reg.is_some() {
+        cursor.next();
+    }
+    Ok(reg)
+}
+
+/// A constructor of a register by its number
+type Family = fn(u8) -> Register;
+
+fn as_family(name: &str) -> Option<Family> {
+    Some(match name {
+        "Rb" => Register::Rb,
+        "Rh" => Register::Rh,
+        "Rw" => Register::Rw,
+        "Rd" => Register::Rd,
+        "Ra" | "Rq" => Register::Rq,
+        "Rf" => Register::Rf,
+        "Rm" => Register::Rm,
+        "Rx" => Register::Rx,
+        "Ry" => Register::Ry,
+        "Rz" => Register::Rz,
+        "Rk" => Register::Rk,
+        "Rt" => Register::Rt,
+        "Rs" => Register::Rs,
+        "RC" => Register::RC,
+        "RD" => Register::RD,
+        "RB" => Register::RB,
+        _ => return None
+    })
+}
+
+/// Looks up a static register name
+fn as_register(name: &str) -> Option<Register> {
+    const LEGACY: [&str; 8] = ["ax", "cx", "dx", "bx", "sp", "bp", "si", "di"];
+    const BYTES: [&str; 8] = ["al", "cl", "dl", "bl", "spl", "bpl", "sil", "dil"];
+    const HIGH_BYTES: [&str; 4] = ["ah", "ch", "dh", "bh"];
+    const SEGMENTS: [&str; 6] = ["es", "cs", "ss", "ds", "fs", "gs"];
+    const NUMBERED: [(&str, Family, u8); 10] = [
+        ("st", Register::Rf, 8),
+        ("mm", Register::Rm, 8),
+        ("xmm", Register::Rx, 32),
+        ("ymm", Register::Ry, 32),
+        ("zmm", Register::Rz, 32),
+        ("cr", Register::RC, 16),
+        ("dr", Register::RD, 16),
+        ("bnd", Register::RB, 4),
+        ("k", Register::Rk, 8),
+        ("tmm", Register::Rt, 8),
+    ];
+
+    let position = |list: &[&str], name: &str| list.iter().position(|&r| r == name).map(|i| i as u8);
+
+    if name == "rip" {
+        return Some(Register::Rip);
+    }
+    if let Some(i) = position(&LEGACY, name) {
+        return Some(Register::Rw(i));
+    }
+    if let Some(i) = name.strip_prefix('e').and_then(|n| position(&LEGACY, n)) {
+        return Some(Register::Rd(i));
+    }
+    if let Some(rest) = name.strip_prefix('r') {
+        if let Some(i) = position(&LEGACY, rest) {
+            return Some(Register::Rq(i));
+        }
+
+        // r0 - r31, with an optional d, w or b size suffix
+        let (digits, family): (&str, Family) = match rest.as_bytes().last() {
+            Some(b'd') => (&rest[.. rest.len() - 1], Register::Rd),
+            Some(b'w') => (&rest[.. rest.len() - 1], Register::Rw),
+            Some(b'b') => (&rest[.. rest.len() - 1], Register::Rb),
+            _ => (rest, Register::Rq)
+        };
+        if let Some(i) = register_number(digits, 32) {
+            return Some(family(i));
+        }
+    }
+    if let Some(i) = position(&BYTES, name) {
+        return Some(Register::Rb(i));
+    }
+    if let Some(i) = position(&HIGH_BYTES, name) {
+        return Some(Register::Rh(i + 4));
+    }
+    if let Some(i) = position(&SEGMENTS, name) {
+        return Some(Register::Rs(i));
+    }
+    for &(prefix, family, count) in &NUMBERED {
+        if let Some(i) = name.strip_prefix(prefix).and_then(|n| register_number(n, count)) {
+            return Some(family(i));
+        }
+    }
+    None
+}
+
+/// Parses the number of a numbered register, without leading zeroes
+fn register_number(digits: &str, count: u8) -> Option<u8> {
+    if digits.is_empty() || (digits.len() > 1 && digits.starts_with('0')) || !digits.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    digits.parse().ok().filter(|&n| n < count)
+}
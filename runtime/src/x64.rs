@@ -1,21 +1,47 @@
 use crate::relocations::{Relocation, RelocationSize, RelocationKind, ImpossibleRelocation};
 
+#[cfg(feature = "encoder")]
+pub use crate::encoder::EncodeError;
+#[cfg(feature = "encoder")]
+pub use crate::encoder::x64::{encode, encode_with_prefixes, encode_with_decorations, Mnemonic, Operand, Register, MemoryRef, Size, Prefix,
+                              Decorations, Rounding};
+
 
 /// Relocation implementation for the x64 architecture.
 #[derive(Debug, Clone)]
 pub struct X64Relocation {
     size: RelocationSize,
     offset: u8,
-    start_offset: u8
+    start_offset: u8,
+    // the length of the instruction this relocation is part of and the length of its opcode,
+    // or zero if it isn't part of an instruction. Used to recognise branches when relaxing.
+    instruction_len: u8,
+    opcode_len: u8,
+}
+
+impl X64Relocation {
+    // if this relocation is the target of a branch that consists of only its opcode and the relocation,
+    // returns that opcode. `instruction` holds the bytes leading up to the definition point of this relocation.
+    fn branch_opcode<'a>(&self, instruction: &'a [u8]) -> Option<&'a [u8]> {
+        let len = usize::from(self.instruction_len);
+        if self.offset != 0 || self.start_offset != 0 || len != usize::from(self.opcode_len) + self.size.size() || len > instruction.len() {
+            return None;
+        }
+
+        let start = instruction.len() - len;
+        Some(&instruction[start .. start + usize::from(self.opcode_len)])
+    }
 }
 
 impl Relocation for X64Relocation {
-    type Encoding = (u8, u8);
+    type Encoding = (u8, u8, u8, u8);
     fn from_encoding(encoding: Self::Encoding) -> Self {
         Self {
             offset: encoding.0,
             size: RelocationSize::from_encoding(encoding.1),
             start_offset: 0,
+            instruction_len: encoding.2,
+            opcode_len: encoding.3,
         }
     }
     fn from_size(size: RelocationSize) -> Self {
@@ -23,6 +49,8 @@ impl Relocation for X64Relocation {
             size,
             offset: 0,
             start_offset: size as u8,
+            instruction_len: 0,
+            opcode_len: 0,
         }
     }
     fn start_offset(&self) -> usize {
@@ -46,9 +74,45 @@ impl Relocation for X64Relocation {
     fn page_size() -> usize {
         4096
     }
+    fn instruction_len(code: &[u8]) -> Option<usize> {
+        crate::x86_length::instruction_len(code, 64)
+    }
+    fn shrink(&self, instruction: &[u8]) -> Option<(usize, Vec<u8>, Self)> {
+        if self.size != RelocationSize::DWord {
+            return None;
+        }
+
+        let short = Self::from_encoding((0, 1, 2, 1));
+        match *self.branch_opcode(instruction)? {
+            [0xE9] => Some((5, vec![0xEB, 0], short)),
+            [0x0F, cc @ 0x80 ..= 0x8F] => Some((6, vec![cc - 0x10, 0], short)),
+            _ => None
+        }
+    }
+    fn widen(&self, instruction: &[u8]) -> Option<(usize, Vec<u8>, Self)> {
+        // jecxz and loop do not have a rel32 form
+        if self.size != RelocationSize::Byte {
+            return None;
+        }
+
+        match *self.branch_opcode(instruction)? {
+            [0xEB] => Some((2, vec![0xE9, 0, 0, 0, 0], Self::from_encoding((0, 4, 5, 1)))),
+            [cc @ 0x70 ..= 0x7F] => Some((2, vec![0x0F, cc + 0x10, 0, 0, 0, 0], Self::from_encoding((0, 4, 6, 2)))),
+            _ => None
+        }
+    }
 }
 
 
 pub type Assembler = crate::Assembler<X64Relocation>;
 pub type AssemblyModifier<'a> = crate::Modifier<'a, X64Relocation>;
 pub type UncommittedModifier<'a> = crate::UncommittedModifier<'a>;
+
+/// Reports an operand that turned out to be impossible to encode while assembling, such as a dynamic register
+/// that the encoding chosen at compile time cannot address. Called by code generated by the `dynasm!` macro.
+#[doc(hidden)]
+#[cold]
+#[track_caller]
+pub fn runtime_error(message: &str) -> ! {
+    panic!("{}", message)
+}
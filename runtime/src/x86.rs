@@ -49,6 +49,9 @@ impl Relocation for X86Relocation {
     fn page_size() -> usize {
         4096
     }
+    fn instruction_len(code: &[u8]) -> Option<usize> {
+        crate::x86_length::instruction_len(code, 32)
+    }
 }
 
 
@@ -0,0 +1,67 @@
+use crate::relocations::{Relocation, RelocationSize, RelocationKind, ImpossibleRelocation};
+use byteorder::{ByteOrder, LittleEndian};
+
+
+/// Relocation implementation for the 16-bit x86 architecture.
+#[derive(Debug, Clone)]
+pub struct X86_16Relocation {
+    size: RelocationSize,
+    kind: RelocationKind,
+    offset: u8,
+    start_offset: u8,
+}
+
+impl Relocation for X86_16Relocation {
+    type Encoding = (u8, u8, u8);
+    fn from_encoding(encoding: Self::Encoding) -> Self {
+        Self {
+            offset: encoding.0,
+            size: RelocationSize::from_encoding(encoding.1),
+            kind: RelocationKind::from_encoding(encoding.2),
+            start_offset: 0,
+        }
+    }
+    fn from_size(size: RelocationSize) -> Self {
+        Self {
+            size,
+            kind: RelocationKind::Relative,
+            offset: 0,
+            start_offset: size as u8,
+        }
+    }
+    fn start_offset(&self) -> usize {
+        self.start_offset as usize
+    }
+    fn field_offset(&self) -> usize{
+        self.size.size() + self.offset as usize
+    }
+    fn size(&self) -> usize {
+        self.size.size()
+    }
+    fn write_value(&self, buf: &mut [u8], value: isize) -> Result<(), ImpossibleRelocation> {
+        // the instruction pointer wraps around within its 64KiB segment, so a 16-bit
+        // relative relocation can reach anything within that segment.
+        if self.size == RelocationSize::Word && self.kind == RelocationKind::Relative && value > -0x1_0000 && value < 0x1_0000 {
+            LittleEndian::write_u16(buf, value as u16);
+            return Ok(());
+        }
+        self.size.write_value(buf, value)
+    }
+    fn read_value(&self, buf: &[u8]) -> isize {
+        self.size.read_value(buf)
+    }
+    fn kind(&self) -> RelocationKind {
+        self.kind
+    }
+    fn page_size() -> usize {
+        4096
+    }
+    fn instruction_len(code: &[u8]) -> Option<usize> {
+        crate::x86_length::instruction_len(code, 16)
+    }
+}
+
+
+pub type Assembler = crate::Assembler<X86_16Relocation>;
+pub type AssemblyModifier<'a> = crate::Modifier<'a, X86_16Relocation>;
+pub type UncommittedModifier<'a> = crate::UncommittedModifier<'a>;
@@ -0,0 +1,195 @@
+//! Instruction length decoding for the x86 family, used to split code into instructions for listings.
+//! It only looks at the prefixes, the opcode and the ModRM/SIB bytes, so it also works for instructions
+//! the assembler does not know.
+
+// the kinds of opcode maps an instruction can be encoded in
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Encoding {
+    Legacy,
+    Vex,
+    Evex,
+    Xop,
+}
+
+/// Returns the length of the x86 instruction at the start of `code`, decoded in `bits`-bit mode (16, 32 or 64).
+/// Returns `None` if the instruction does not fit in `code` or is not valid.
+pub(crate) fn instruction_len(code: &[u8], bits: u8) -> Option<usize> {
+    let long = bits == 64;
+    let mut pos = 0;
+    let mut operand_size = false;
+    let mut address_size = false;
+    let mut repne = false;
+
+    // legacy prefixes
+    loop {
+        match *code.get(pos)? {
+            0x66 => operand_size = true,
+            0x67 => address_size = true,
+            0xF2 => repne = true,
+            0xF0 | 0xF3 | 0x26 | 0x2E | 0x36 | 0x3E | 0x64 | 0x65 => (),
+            _ => break
+        }
+        pos += 1;
+    }
+
+    // REX, REX2, VEX, EVEX and XOP prefixes. Outside of 64-bit mode, the VEX and EVEX prefixes overlap with
+    // instructions that take a memory operand, so they can only be followed by a ModRM byte in register form.
+    let mut encoding = Encoding::Legacy;
+    let mut map = 0;
+    let mut rex_w = false;
+    let byte = *code.get(pos)?;
+    let next = code.get(pos + 1).copied();
+    let vex = long || next.is_some_and(|next| next & 0xC0 == 0xC0);
+
+    if long && byte & 0xF0 == 0x40 {
+        rex_w = byte & 0x08 != 0;
+        pos += 1;
+    } else if long && byte == 0xD5 {
+        let payload = next?;
+        rex_w = payload & 0x08 != 0;
+        map = payload >> 7;
+        pos += 2;
+    } else if byte == 0xC5 && vex {
+        encoding = Encoding::Vex;
+        map = 1;
+        operand_size = next? & 3 == 1;
+        pos += 2;
+    } else if byte == 0xC4 && vex {
+        encoding = Encoding::Vex;
+        map = next? & 0x1F;
+        pos += 3;
+    } else if byte == 0x62 && vex {
+        encoding = Encoding::Evex;
+        map = next? & 0x07;
+        let payload = *code.get(pos + 2)?;
+        rex_w = payload & 0x80 != 0;
+        operand_size = payload & 3 == 1;
+        pos += 4;
+    } else if byte == 0x8F && next? & 0x1F >= 8 {
+        encoding = Encoding::Xop;
+        map = next? & 0x1F;
+        pos += 3;
+    }
+
+    let mut opcode = *code.get(pos)?;
+    pos += 1;
+
+    // escape bytes select the legacy opcode maps
+    if encoding == Encoding::Legacy && map == 0 && opcode == 0x0F {
+        opcode = *code.get(pos)?;
+        pos += 1;
+        map = match opcode {
+            0x38 => 2,
+            0x3A => 3,
+            _ => 1
+        };
+        if map != 1 {
+            opcode = *code.get(pos)?;
+            pos += 1;
+        }
+    }
+
+    // the sizes of immediates that depend on the operand size
+    let word = if bits == 16 { !operand_size } else { operand_size };
+    let imm_z = if word { 2 } else { 4 };
+    let imm_v = if rex_w { 8 } else { imm_z };
+    let rel_z = if long { 4 } else { imm_z };
+    let address16 = if bits == 16 { !address_size } else { bits == 32 && address_size };
+    let moffs = if long { if address_size { 4 } else { 8 } } else if address16 { 2 } else { 4 };
+
+    // the reg field of the ModRM byte, which extends the opcode of some instructions
+    let reg = code.get(pos).map_or(0, |modrm| (modrm >> 3) & 7);
+
+    let (modrm, imm) = match (encoding, map) {
+        (Encoding::Legacy, 0) => match opcode {
+            0x00 ..= 0x3F if opcode & 7 < 4 => (true, 0),
+            0x00 ..= 0x3F if opcode & 7 == 4 => (false, 1),
+            0x00 ..= 0x3F if opcode & 7 == 5 => (false, imm_z),
+            0x62 | 0x63 | 0x84 ..= 0x8F | 0xC4 | 0xC5 | 0xD0 ..= 0xD3 | 0xD8 ..= 0xDF | 0xFE | 0xFF => (true, 0),
+            0x69 | 0x81 | 0xC7 => (true, imm_z),
+            0x6B | 0x80 | 0x82 | 0x83 | 0xC0 | 0xC1 | 0xC6 => (true, 1),
+            0xF6 => (true, if reg < 2 { 1 } else { 0 }),
+            0xF7 => (true, if reg < 2 { imm_z } else { 0 }),
+            0x68 | 0xA9 => (false, imm_z),
+            0x6A | 0x70 ..= 0x7F | 0xA8 | 0xB0 ..= 0xB7 | 0xCD | 0xD4 | 0xD5 | 0xE0 ..= 0xE7 | 0xEB => (false, 1),
+            0x9A | 0xEA => (false, 2 + imm_z),
+            0xA0 ..= 0xA3 => (false, moffs),
+            0xB8 ..= 0xBF => (false, imm_v),
+            0xC2 | 0xCA => (false, 2),
+            0xC8 => (false, 3),
+            0xE8 | 0xE9 => (false, rel_z),
+            _ => (false, 0)
+        },
+        (Encoding::Legacy, 1) => match opcode {
+            0x05 ..= 0x09 | 0x0B | 0x0E | 0x30 ..= 0x37 | 0x77 | 0xA0 ..= 0xA2 | 0xA8 ..= 0xAA | 0xC8 ..= 0xCF => (false, 0),
+            0x80 ..= 0x8F => (false, rel_z),
+            0x0F | 0x70 ..= 0x73 | 0xA4 | 0xAC | 0xBA | 0xC2 | 0xC4 ..= 0xC6 => (true, 1),
+            // the SSE4a extrq and insertq forms with immediates
+            0x78 if operand_size || repne => (true, 2),
+            _ => (true, 0)
+        },
+        (Encoding::Legacy, 2) => (true, 0),
+        (Encoding::Legacy, 3) => (true, 1),
+
+        (Encoding::Vex, 1) | (Encoding::Evex, 1) => match opcode {
+            0x77 => (false, 0),
+            0x70 ..= 0x73 | 0xC2 | 0xC4 ..= 0xC6 => (true, 1),
+            _ => (true, 0)
+        },
+        (Encoding::Vex, 3) | (Encoding::Evex, 3) => (true, 1),
+        // the promoted legacy instructions of APX
+        (Encoding::Evex, 4) => match opcode {
+            0x24 | 0x2C | 0x6B | 0x80 | 0x83 | 0xC0 | 0xC1 => (true, 1),
+            0x69 | 0x81 => (true, imm_z),
+            0xF6 => (true, if reg < 2 { 1 } else { 0 }),
+            0xF7 => (true, if reg < 2 { imm_z } else { 0 }),
+            _ => (true, 0)
+        },
+        (Encoding::Vex, _) | (Encoding::Evex, _) => (true, 0),
+
+        (Encoding::Xop, 8) => (true, 1),
+        (Encoding::Xop, 0xA) => (true, 4),
+        (Encoding::Xop, _) => (true, 0),
+
+        _ => return None
+    };
+
+    if modrm {
+        pos += modrm_len(code.get(pos ..)?, address16)?;
+    }
+    pos += imm;
+
+    if pos <= code.len() {
+        Some(pos)
+    } else {
+        None
+    }
+}
+
+// the length of a ModRM byte with the SIB byte and displacement that follow it
+fn modrm_len(code: &[u8], address16: bool) -> Option<usize> {
+    let modrm = *code.first()?;
+    let mode = modrm >> 6;
+    let rm = modrm & 7;
+
+    Some(match (mode, rm) {
+        (3, _) => 1,
+        (0, 6) if address16 => 3,
+        (0, _) if address16 => 1,
+        (1, _) if address16 => 2,
+        (2, _) if address16 => 3,
+        (_, 4) => {
+            let sib = *code.get(1)?;
+            match mode {
+                0 if sib & 7 == 5 => 6,
+                0 => 2,
+                1 => 3,
+                _ => 6
+            }
+        },
+        (0, 5) => 5,
+        (0, _) => 1,
+        (1, _) => 2,
+        _ => 5
+    })
+}
@@ -0,0 +1,123 @@
+//! This crate only contains tests. Code that `dynasm!` has to reject cannot be tested with `#[test]`, so
+//! those tests are written as `compile_fail` doctests here. Each of them has a counterpart in `tests/` that
+//! does compile.
+//!
+//! `crc32` requires `sse42`:
+//!
+//! ```compile_fail
+//! # #![feature(proc_macro_hygiene)]
+//! # extern crate dynasmrt;
+//! # extern crate dynasm;
+//! # use dynasm::dynasm;
+//! # use dynasmrt::DynasmApi;
+//! let mut ops = dynasmrt::x64::Assembler::new().unwrap();
+//! dynasm!(ops
+//!     ; .arch x64
+//!     ; .feature sse41
+//!     ; crc32 eax, cl
+//! );
+//! ```
+//!
+//! ```compile_fail
+//! # #![feature(proc_macro_hygiene)]
+//! # extern crate dynasmrt;
+//! # extern crate dynasm;
+//! # use dynasm::dynasm;
+//! # use dynasmrt::DynasmApi;
+//! let mut ops = dynasmrt::x64::Assembler::new().unwrap();
+//! dynasm!(ops
+//!     ; .arch x64
+//!     ; .feature sse41
+//!     ; crc32 rax, QWORD [rcx]
+//! );
+//! ```
+//!
+//! `rdfsbase`, `rdgsbase`, `wrfsbase` and `wrgsbase` require `fsgsbase`:
+//!
+//! ```compile_fail
+//! # #![feature(proc_macro_hygiene)]
+//! # extern crate dynasmrt;
+//! # extern crate dynasm;
+//! # use dynasm::dynasm;
+//! # use dynasmrt::DynasmApi;
+//! let mut ops = dynasmrt::x64::Assembler::new().unwrap();
+//! dynasm!(ops
+//!     ; .arch x64
+//!     ; .feature sse42
+//!     ; rdfsbase eax
+//! );
+//! ```
+//!
+//! ```compile_fail
+//! # #![feature(proc_macro_hygiene)]
+//! # extern crate dynasmrt;
+//! # extern crate dynasm;
+//! # use dynasm::dynasm;
+//! # use dynasmrt::DynasmApi;
+//! let mut ops = dynasmrt::x64::Assembler::new().unwrap();
+//! dynasm!(ops
+//!     ; .arch x64
+//!     ; .feature sse42
+//!     ; rdgsbase rcx
+//! );
+//! ```
+//!
+//! ```compile_fail
+//! # #![feature(proc_macro_hygiene)]
+//! # extern crate dynasmrt;
+//! # extern crate dynasm;
+//! # use dynasm::dynasm;
+//! # use dynasmrt::DynasmApi;
+//! let mut ops = dynasmrt::x64::Assembler::new().unwrap();
+//! dynasm!(ops
+//!     ; .arch x64
+//!     ; .feature sse42
+//!     ; wrfsbase rdx
+//! );
+//! ```
+//!
+//! ```compile_fail
+//! # #![feature(proc_macro_hygiene)]
+//! # extern crate dynasmrt;
+//! # extern crate dynasm;
+//! # use dynasm::dynasm;
+//! # use dynasmrt::DynasmApi;
+//! let mut ops = dynasmrt::x64::Assembler::new().unwrap();
+//! dynasm!(ops
+//!     ; .arch x64
+//!     ; .feature sse42
+//!     ; wrgsbase ebx
+//! );
+//! ```
+//!
+//! `prefetchw` requires `prfchw` instead of `tdnow`:
+//!
+//! ```compile_fail
+//! # #![feature(proc_macro_hygiene)]
+//! # extern crate dynasmrt;
+//! # extern crate dynasm;
+//! # use dynasm::dynasm;
+//! # use dynasmrt::DynasmApi;
+//! let mut ops = dynasmrt::x64::Assembler::new().unwrap();
+//! dynasm!(ops
+//!     ; .arch x64
+//!     ; .feature tdnow
+//!     ; prefetchw [rax]
+//! );
+//! ```
+//!
+//! `.runtime_sizes` is only supported on `x64`, `x86` and `x86_16`:
+//!
+//! ```compile_fail
+//! # #![feature(proc_macro_hygiene)]
+//! # extern crate dynasmrt;
+//! # extern crate dynasm;
+//! # use dynasm::dynasm;
+//! # use dynasmrt::DynasmApi;
+//! let mut ops = dynasmrt::aarch64::Assembler::new().unwrap();
+//! dynasm!(ops
+//!     ; .arch aarch64
+//!     ; .runtime_sizes on
+//!     ; nop
+//! );
+//! ```
@@ -0,0 +1,63 @@
+#![feature(proc_macro_hygiene)]
+#![allow(unused_imports)]
+
+#[macro_use]
+extern crate dynasmrt;
+extern crate dynasm;
+
+use dynasm::dynasm;
+use dynasmrt::{DynasmApi, DynasmLabelApi};
+
+// instructions are little-endian on aarch64_be as well
+#[test]
+fn be_instructions() {
+    let mut ops = dynasmrt::aarch64_be::Assembler::new().unwrap();
+    dynasm!(ops
+        ; .arch aarch64_be
+        ; add x0, x1, x2
+        ; b >next
+        ; next:
+        ; ret
+    );
+    let buf = ops.finalize().unwrap();
+    let hex: Vec<String> = buf.iter().map(|x| format!("{:02X}", *x)).collect();
+    let hex = hex.join(", ");
+    assert_eq!(hex, "20, 00, 02, 8B, 01, 00, 00, 14, C0, 03, 5F, D6", "be_instructions");
+}
+
+// data directives store their values big-endian
+#[test]
+fn be_data() {
+    let value = 0x1234_5678;
+    let mut ops = dynasmrt::aarch64_be::Assembler::new().unwrap();
+    dynasm!(ops
+        ; .arch aarch64_be
+        ; .byte 0x12
+        ; .byte -2
+        ; .word 0x1234
+        ; .dword value
+        ; .qword -2
+    );
+    let buf = ops.finalize().unwrap();
+    let hex: Vec<String> = buf.iter().map(|x| format!("{:02X}", *x)).collect();
+    let hex = hex.join(", ");
+    assert_eq!(hex, "12, FE, 12, 34, 12, 34, 56, 78, FF, FF, FF, FF, FF, FF, FF, FE", "be_data");
+}
+
+// relocations in data are big-endian, while relocations in instructions are not
+#[test]
+fn be_relocations() {
+    let mut ops = dynasmrt::aarch64_be::Assembler::new().unwrap();
+    dynasm!(ops
+        ; .arch aarch64_be
+        ; start:
+        ; ldr w0, >data
+        ; data:
+        ; .dword <start
+        ; .word <start
+    );
+    let buf = ops.finalize().unwrap();
+    let hex: Vec<String> = buf.iter().map(|x| format!("{:02X}", *x)).collect();
+    let hex = hex.join(", ");
+    assert_eq!(hex, "20, 00, 00, 18, FF, FF, FF, FC, FF, F8", "be_relocations");
+}
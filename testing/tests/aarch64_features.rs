@@ -0,0 +1,62 @@
+#![feature(proc_macro_hygiene)]
+#![allow(unused_imports)]
+
+#[macro_use]
+extern crate dynasmrt;
+extern crate dynasm;
+
+use dynasm::dynasm;
+use dynasmrt::DynasmApi;
+
+// instructions from the listed extensions can be used after they have been declared with .feature
+#[test]
+fn features_listed() {
+    let mut ops = dynasmrt::aarch64::Assembler::new().unwrap();
+    dynasm!(ops
+        ; .arch aarch64
+        ; .feature lse, crc, pauth, bti
+        ; ldadd w0, w1, [x2]
+        ; crc32cx w3, w4, x5
+        ; paciasp
+        ; bti c
+    );
+    let buf = ops.finalize().unwrap();
+    let hex: Vec<String> = buf.iter().map(|x| format!("{:02X}", *x)).collect();
+    let hex = hex.join(", ");
+    assert_eq!(hex, "41, 00, 20, B8, 83, 5C, C5, 9A, 3F, 23, 03, D5, 5F, 24, 03, D5", "features_listed");
+}
+
+// crypto enables all of the cryptographic extensions
+#[test]
+fn features_crypto() {
+    let mut ops = dynasmrt::aarch64::Assembler::new().unwrap();
+    dynasm!(ops
+        ; .arch aarch64
+        ; .feature crypto
+        ; aese v0.b16, v1.b16
+        ; sha256h q2, q3, v4.s4
+        ; eor3 v5.b16, v6.b16, v7.b16, v8.b16
+        ; sm4e v9.s4, v10.s4
+    );
+    let buf = ops.finalize().unwrap();
+    let hex: Vec<String> = buf.iter().map(|x| format!("{:02X}", *x)).collect();
+    let hex = hex.join(", ");
+    assert_eq!(hex, "20, 48, 28, 4E, 62, 40, 04, 5E, C5, 20, 07, CE, 49, 85, C0, CE", "features_crypto");
+}
+
+// the baseline instruction set is always available
+#[test]
+fn features_none() {
+    let mut ops = dynasmrt::aarch64::Assembler::new().unwrap();
+    dynasm!(ops
+        ; .arch aarch64
+        ; .feature none
+        ; add x0, x1, x2
+        ; ldr x3, [x4, 8]
+        ; ret
+    );
+    let buf = ops.finalize().unwrap();
+    let hex: Vec<String> = buf.iter().map(|x| format!("{:02X}", *x)).collect();
+    let hex = hex.join(", ");
+    assert_eq!(hex, "20, 00, 02, 8B, 83, 04, 40, F9, C0, 03, 5F, D6", "features_none");
+}
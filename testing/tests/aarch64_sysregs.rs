@@ -0,0 +1,61 @@
+#![feature(proc_macro_hygiene)]
+#![allow(unused_imports)]
+
+#[macro_use]
+extern crate dynasmrt;
+extern crate dynasm;
+
+use dynasm::dynasm;
+use dynasmrt::DynasmApi;
+
+// system registers can be read and written by name
+#[test]
+fn sysregs_named() {
+    let mut ops = dynasmrt::aarch64::Assembler::new().unwrap();
+    dynasm!(ops
+        ; .arch aarch64
+        ; mrs x0, tpidr_el0
+        ; mrs x1, cntvct_el0
+        ; mrs x2, fpcr
+        ; msr nzcv, x3
+        ; msr dbgdtrtx_el0, x8
+        ; mrs x9, dbgdtrrx_el0
+    );
+    let buf = ops.finalize().unwrap();
+    let hex: Vec<String> = buf.iter().map(|x| format!("{:02X}", *x)).collect();
+    let hex = hex.join(", ");
+    assert_eq!(hex, "40, D0, 3B, D5, 41, E0, 3B, D5, 02, 44, 3B, D5, 03, 42, 1B, D5, 08, 05, 13, D5, 09, 05, 33, D5", "sysregs_named");
+}
+
+// system registers can also be given by their encoded fields, or as an immediate
+#[test]
+fn sysregs_encoded() {
+    let sysreg = 0x5E82;
+    let mut ops = dynasmrt::aarch64::Assembler::new().unwrap();
+    dynasm!(ops
+        ; .arch aarch64
+        ; msr s3_3_c13_c0_2, x4
+        ; mrs x5, s2_0_c0_c0_0
+        ; mrs x6, (sysreg)
+        ; mrs x7, 0x5E82
+    );
+    let buf = ops.finalize().unwrap();
+    let hex: Vec<String> = buf.iter().map(|x| format!("{:02X}", *x)).collect();
+    let hex = hex.join(", ");
+    assert_eq!(hex, "44, D0, 1B, D5, 05, 00, 30, D5, 46, D0, 3B, D5, 47, D0, 3B, D5", "sysregs_encoded");
+}
+
+// the PSTATE fields are still written with an immediate
+#[test]
+fn sysregs_pstate() {
+    let mut ops = dynasmrt::aarch64::Assembler::new().unwrap();
+    dynasm!(ops
+        ; .arch aarch64
+        ; msr daifset, 3
+        ; msr daif, x0
+    );
+    let buf = ops.finalize().unwrap();
+    let hex: Vec<String> = buf.iter().map(|x| format!("{:02X}", *x)).collect();
+    let hex = hex.join(", ");
+    assert_eq!(hex, "DF, 43, 03, D5, 20, 42, 1B, D5", "sysregs_pstate");
+}
@@ -0,0 +1,30 @@
+#![feature(proc_macro_hygiene)]
+#![allow(unused_imports)]
+
+#[macro_use]
+extern crate dynasmrt;
+extern crate dynasm;
+
+use dynasm::dynasm;
+use dynasmrt::DynasmApi;
+
+include!("gen_x64/apx.rs.gen");
+
+// dynamic registers can refer to the APX extended registers when the apx feature is enabled
+#[test]
+fn dynamic_extended_registers() {
+    let mut ops = dynasmrt::x64::Assembler::new().unwrap();
+    dynasm!(ops
+       ; .arch x64
+       ; .feature apx
+       ; mov Rq(16), Rq(17)
+       ; mov Rq(8), rdi
+       ; mov rax, [Rq(21)]
+       ; add Rq(16), Rq(17), Rq(18)
+       ; not rax, [Rq(16) + Rq(17) * 8]
+    );
+    let buf = ops.finalize().unwrap();
+    let hex: Vec<String> = buf.iter().map(|x| format!("0x{:02X}", *x)).collect();
+    let hex: String = hex.join(", ");
+    assert_eq!(hex, "0xD5, 0x58, 0x89, 0xC8, 0xD5, 0x09, 0x89, 0xF8, 0xD5, 0x18, 0x8B, 0x44, 0x25, 0x00, 0x62, 0xEC, 0xFC, 0x10, 0x01, 0xD1, 0x62, 0xFC, 0xF8, 0x18, 0xF7, 0x54, 0xC8, 0x00", "dynamic_extended_registers");
+}
@@ -0,0 +1,500 @@
+#![feature(proc_macro_hygiene)]
+#![allow(unused_imports)]
+
+#[macro_use]
+extern crate dynasmrt;
+extern crate dynasm;
+
+use dynasm::dynasm;
+use dynasmrt::{DynasmApi, DynasmLabelApi};
+
+// a32 data processing instructions with immediate, shifted register and register shifted register operands
+#[test]
+fn a32_data_processing() {
+    let mut ops = dynasmrt::arm::Assembler::new().unwrap();
+    dynasm!(ops
+        ; .arch arm
+        ; add r0, r1, r2
+        ; adds r3, r4, #0xFF000000
+        ; addeq r5, r6, r7, lsl #3
+        ; sub r8, r9, r10, lsr r11
+        ; rsb r0, r0, #0
+        ; and r1, r2, r3, asr #32
+        ; orrs r4, r5, r6, ror #7
+        ; eor r7, r8, r9, rrx
+        ; bicne r10, r11, #0x3F0
+        ; mov r0, #255
+        ; mov r1, r2
+        ; movs r3, r4, lsl #1
+        ; mvn r5, #0
+        ; movw r6, #0xBEEF
+        ; movt r6, #0xDEAD
+        ; cmp r0, #1
+        ; cmn r1, r2
+        ; tst r3, #0x80000000
+        ; teq r4, r5, lsl r6
+        ; lsl r0, r1, #31
+        ; asr r2, r3, r4
+        ; rrx r5, r6
+    );
+    let buf = ops.finalize().unwrap();
+    let hex: Vec<String> = buf.iter().map(|x| format!("{:02X}", *x)).collect();
+    let hex = hex.join(", ");
+    assert_eq!(hex, "02, 00, 81, E0, FF, 34, 94, E2, 87, 51, 86, 00, 3A, 8B, 49, E0, 00, 00, 60, E2, 43, 10, 02, E0, E6, 43, 95, E1, 69, 70, 28, E0, 3F, AE, CB, 13, FF, 00, A0, E3, 02, 10, A0, E1, 84, 30, B0, E1, 00, 50, E0, E3, EF, 6E, 0B, E3, AD, 6E, 4D, E3, 01, 00, 50, E3, 02, 00, 71, E1, 02, 01, 13, E3, 15, 06, 34, E1, 81, 0F, A0, E1, 53, 24, A0, E1, 66, 50, A0, E1", "a32_data_processing");
+}
+
+// a32 loads and stores in all addressing modes, and load/store multiple
+#[test]
+fn a32_memory() {
+    let mut ops = dynasmrt::arm::Assembler::new().unwrap();
+    dynasm!(ops
+        ; .arch arm
+        ; ldr r0, [r1]
+        ; ldr r2, [r3, #4095]
+        ; ldr r4, [r5, #-4]!
+        ; ldr r6, [r7], #8
+        ; ldr r8, [r9, r10]
+        ; ldr r11, [r12, -r0, lsl #2]
+        ; ldrb r1, [r2, #1]
+        ; strb r3, [r4, r5]!
+        ; ldrh r6, [r7, #-255]
+        ; strh r8, [r9], #2
+        ; ldrsb r10, [r11, r12]
+        ; ldrsh r0, [r1, #-2]!
+        ; ldrd r2, r3, [r4, #8]
+        ; strd r4, r5, [sp, #-16]!
+        ; str r0, [sp, #-4]!
+        ; ldm r0, {r1, r2, r3}
+        ; ldmia r0!, {r4-r11}
+        ; stmdb sp!, {r4-r11, lr}
+        ; ldmib r1, {r0, pc}
+        ; push {r4, lr}
+        ; pop {r4, pc}
+        ; ldrex r0, [r1]
+        ; strex r2, r3, [r4]
+        ; ldrexd r0, r1, [r2]
+    );
+    let buf = ops.finalize().unwrap();
+    let hex: Vec<String> = buf.iter().map(|x| format!("{:02X}", *x)).collect();
+    let hex = hex.join(", ");
+    assert_eq!(hex, "00, 00, 91, E5, FF, 2F, 93, E5, 04, 40, 35, E5, 08, 60, 97, E4, 0A, 80, 99, E7, 00, B1, 1C, E7, 01, 10, D2, E5, 05, 30, E4, E7, BF, 6F, 57, E1, B2, 80, C9, E0, DC, A0, 9B, E1, F2, 00, 71, E1, D8, 20, C4, E1, F0, 41, 6D, E1, 04, 00, 2D, E5, 0E, 00, 90, E8, F0, 0F, B0, E8, F0, 4F, 2D, E9, 01, 80, 91, E9, 10, 40, 2D, E9, 10, 80, BD, E8, 9F, 0F, 91, E1, 93, 2F, 84, E1, 9F, 0F, B2, E1", "a32_memory");
+}
+
+// a32 multiplies, divides, bitfield operations, branches to registers and system instructions
+#[test]
+fn a32_misc() {
+    let mut ops = dynasmrt::arm::Assembler::new().unwrap();
+    dynasm!(ops
+        ; .arch arm
+        ; mul r0, r1, r2
+        ; mla r3, r4, r5, r6
+        ; mls r7, r8, r9, r10
+        ; umull r0, r1, r2, r3
+        ; smlal r4, r5, r6, r7
+        ; sdiv r0, r1, r2
+        ; udiv r3, r4, r5
+        ; clz r0, r1
+        ; rev r2, r3
+        ; rbit r4, r5
+        ; ubfx r0, r1, #4, #8
+        ; sbfx r2, r3, #0, #32
+        ; bfi r4, r5, #8, #16
+        ; bfc r6, #31, #1
+        ; uxtb r0, r1
+        ; sxth r2, r3, ror #16
+        ; bx lr
+        ; blx r12
+        ; svc #0x123456
+        ; bkpt #0xBEEF
+        ; udf #0xFFFF
+        ; dmb ish
+        ; dsb sy
+        ; isb sy
+        ; mrs r0, apsr
+        ; msr apsr_nzcvq, r1
+        ; nop
+        ; wfi
+    );
+    let buf = ops.finalize().unwrap();
+    let hex: Vec<String> = buf.iter().map(|x| format!("{:02X}", *x)).collect();
+    let hex = hex.join(", ");
+    assert_eq!(hex, "91, 02, 00, E0, 94, 65, 23, E0, 98, A9, 67, E0, 92, 03, 81, E0, 96, 47, E5, E0, 11, F2, 10, E7, 14, F5, 33, E7, 11, 0F, 6F, E1, 33, 2F, BF, E6, 35, 4F, FF, E6, 51, 02, E7, E7, 53, 20, BF, E7, 15, 44, D7, E7, 9F, 6F, DF, E7, 71, 00, EF, E6, 73, 28, BF, E6, 1E, FF, 2F, E1, 3C, FF, 2F, E1, 56, 34, 12, EF, 7F, EE, 2B, E1, FF, FF, FF, E7, 5B, F0, 7F, F5, 4F, F0, 7F, F5, 6F, F0, 7F, F5, 00, 00, 0F, E1, 01, F0, 28, E1, 00, F0, 20, E3, 03, F0, 20, E3", "a32_misc");
+}
+
+// a32 VFP instructions on single and double precision registers
+#[test]
+fn a32_vfp() {
+    let mut ops = dynasmrt::arm::Assembler::new().unwrap();
+    dynasm!(ops
+        ; .arch arm
+        ; vadd.f32 s0, s1, s2
+        ; vsub.f64 d0, d1, d2
+        ; vmul.f64 d16, d17, d31
+        ; vdiv.f32 s31, s30, s29
+        ; vmla.f64 d3, d4, d5
+        ; vnmul.f32 s4, s5, s6
+        ; vabs.f64 d7, d8
+        ; vsqrt.f32 s9, s10
+        ; vneg.f64 d20, d21
+        ; vcmp.f32 s0, s1
+        ; vcmpe.f64 d0, #0
+        ; vmrs APSR_nzcv, fpscr
+        ; vmov.f32 s0, #1.0
+        ; vmov.f64 d1, #-0.5
+        ; vmov.f64 d2, d3
+        ; vmov s0, r1
+        ; vmov r2, s3
+        ; vmov d4, r5, r6
+        ; vmov r7, r8, d9
+        ; vcvt.f64.f32 d0, s1
+        ; vcvt.s32.f64 s2, d3
+        ; vcvt.f32.u32 s4, s5
+        ; vldr s0, [r0, #-1020]
+        ; vstr d1, [sp, #8]
+        ; vldmia r0!, {d0-d3}
+        ; vstmdb sp!, {s16-s31}
+        ; vpush {d8-d15}
+        ; vpop {d8-d15}
+    );
+    let buf = ops.finalize().unwrap();
+    let hex: Vec<String> = buf.iter().map(|x| format!("{:02X}", *x)).collect();
+    let hex = hex.join(", ");
+    assert_eq!(hex, "81, 0A, 30, EE, 42, 0B, 31, EE, AF, 0B, 61, EE, 2E, FA, CF, EE, 05, 3B, 04, EE, C3, 2A, 22, EE, C8, 7B, B0, EE, C5, 4A, F1, EE, 65, 4B, F1, EE, 60, 0A, B4, EE, C0, 0B, B5, EE, 10, FA, F1, EE, 00, 0A, B7, EE, 00, 1B, BE, EE, 43, 2B, B0, EE, 10, 1A, 00, EE, 90, 2A, 11, EE, 14, 5B, 46, EC, 19, 7B, 58, EC, E0, 0A, B7, EE, C3, 1B, BD, EE, 62, 2A, B8, EE, FF, 0A, 10, ED, 02, 1B, 8D, ED, 08, 0B, B0, EC, 10, 8A, 2D, ED, 10, 8B, 2D, ED, 10, 8B, BD, EC", "a32_vfp");
+}
+
+// thumb data processing instructions, selecting the 16-bit or 32-bit encoding with .n and .w
+#[test]
+fn thumb_data_processing() {
+    let mut ops = dynasmrt::arm::Assembler::new().unwrap();
+    dynasm!(ops
+        ; .arch thumb
+        ; adds.n r0, r1, r2
+        ; adds.n r3, r4, #7
+        ; adds.n r5, #255
+        ; add.n r8, r9
+        ; add.n r0, sp, #1020
+        ; add.n sp, sp, #508
+        ; sub.n sp, sp, #4
+        ; add.w r0, r1, #0xAB00AB00
+        ; addw r2, r3, #4095
+        ; subw r4, r5, #1
+        ; add.w r6, r7, r8, lsl #3
+        ; subs.w r9, r10, r11, asr #32
+        ; orn r0, r1, #0xFF
+        ; and.w r2, r3, r4, rrx
+        ; mov.n r0, r8
+        ; movs.n r1, #200
+        ; mov.w r2, #0x00120012
+        ; movw r3, #0xBEEF
+        ; movt r3, #0xDEAD
+        ; mvn.w r4, r5
+        ; cmp.n r0, #255
+        ; cmp.w r1, #0x2A80
+        ; tst.w r2, r3
+        ; lsls.n r0, r1, #31
+        ; lsl.w r2, r3, r4
+        ; rrx r5, r6
+    );
+    let buf = ops.finalize().unwrap();
+    let hex: Vec<String> = buf.iter().map(|x| format!("{:02X}", *x)).collect();
+    let hex = hex.join(", ");
+    assert_eq!(hex, "88, 18, E3, 1D, FF, 35, C8, 44, FF, A8, 7F, B0, 81, B0, 01, F1, AB, 20, 03, F6, FF, 72, A5, F2, 01, 04, 07, EB, C8, 06, BA, EB, 2B, 09, 61, F0, FF, 00, 03, EA, 34, 02, 40, 46, C8, 21, 4F, F0, 12, 12, 4B, F6, EF, 63, CD, F6, AD, 63, 6F, EA, 05, 04, FF, 28, B1, F5, 2A, 5F, 12, EA, 03, 0F, C8, 07, 03, FA, 04, F2, 4F, EA, 36, 05", "thumb_data_processing");
+}
+
+// thumb loads and stores in all addressing modes, and load/store multiple
+#[test]
+fn thumb_memory() {
+    let mut ops = dynasmrt::arm::Assembler::new().unwrap();
+    dynasm!(ops
+        ; .arch thumb
+        ; ldr.n r0, [r1, #124]
+        ; ldr.n r2, [sp, #1020]
+        ; str.n r3, [sp]
+        ; ldrb.n r4, [r5, r6]
+        ; strh.n r7, [r0, #62]
+        ; ldr.w r0, [r1, #4095]
+        ; ldr r2, [r3, #-255]
+        ; ldr r4, [r5, #4]!
+        ; ldr r6, [r7], #-8
+        ; ldr.w r8, [r9, r10, lsl #3]
+        ; ldrsb.w r11, [r12, #1]
+        ; ldrd r0, r1, [r2, #-1020]
+        ; strd r4, r5, [sp, #8]!
+        ; ldm.n r0!, {r1-r3}
+        ; ldmia.w r4, {r0, r5-r7, lr}
+        ; stmdb sp!, {r4-r11, lr}
+        ; push.n {r4-r7, lr}
+        ; pop.n {r4-r7, pc}
+        ; push.w {r8, lr}
+        ; pop.w {r4-r11, pc}
+        ; ldrex r0, [r1, #4]
+        ; strex r2, r3, [r4]
+    );
+    let buf = ops.finalize().unwrap();
+    let hex: Vec<String> = buf.iter().map(|x| format!("{:02X}", *x)).collect();
+    let hex = hex.join(", ");
+    assert_eq!(hex, "C8, 6F, FF, 9A, 00, 93, AC, 5D, C7, 87, D1, F8, FF, 0F, 53, F8, FF, 2C, 55, F8, 04, 4F, 57, F8, 08, 69, 59, F8, 3A, 80, 9C, F9, 01, B0, 52, E9, FF, 01, ED, E9, 02, 45, 0E, C8, 94, E8, E1, 40, 2D, E9, F0, 4F, F0, B5, F0, BD, 2D, E9, 00, 41, BD, E8, F0, 8F, 51, E8, 01, 0F, 44, E8, 00, 32", "thumb_memory");
+}
+
+// thumb multiplies, divides, bitfield operations, branches to registers, system instructions and VFP
+#[test]
+fn thumb_misc() {
+    let mut ops = dynasmrt::arm::Assembler::new().unwrap();
+    dynasm!(ops
+        ; .arch thumb
+        ; muls.n r0, r1
+        ; mul r2, r3, r4
+        ; mla r5, r6, r7, r8
+        ; umull r0, r1, r2, r3
+        ; sdiv r4, r5, r6
+        ; udiv r7, r8, r9
+        ; clz r0, r1
+        ; rev.n r2, r3
+        ; rev.w r4, r8
+        ; ubfx r0, r1, #4, #8
+        ; bfi r2, r3, #0, #32
+        ; uxtb.n r4, r5
+        ; sxth.w r6, r7, ror #8
+        ; bx lr
+        ; blx r3
+        ; svc #255
+        ; bkpt #7
+        ; udf.n #255
+        ; udf.w #0xFFFF
+        ; dmb ish
+        ; isb sy
+        ; mrs r0, apsr
+        ; msr apsr_nzcvq, r1
+        ; nop.n
+        ; nop.w
+        ; vadd.f64 d0, d1, d2
+        ; vmov.f32 s0, #31.0
+        ; vldr d16, [r0, #1020]
+        ; vpush {s0-s3}
+    );
+    let buf = ops.finalize().unwrap();
+    let hex: Vec<String> = buf.iter().map(|x| format!("{:02X}", *x)).collect();
+    let hex = hex.join(", ");
+    assert_eq!(hex, "48, 43, 03, FB, 04, F2, 06, FB, 07, 85, A2, FB, 03, 01, 95, FB, F6, F4, B8, FB, F9, F7, B1, FA, 81, F0, 1A, BA, 98, FA, 88, F4, C1, F3, 07, 10, 63, F3, 1F, 02, EC, B2, 0F, FA, 97, F6, 70, 47, 98, 47, FF, DF, 07, BE, FF, DE, FF, F7, FF, AF, BF, F3, 5B, 8F, BF, F3, 6F, 8F, EF, F3, 00, 80, 81, F3, 00, 88, 00, BF, AF, F3, 00, 80, 31, EE, 02, 0B, B3, EE, 0F, 0A, D0, ED, FF, 0B, 2D, ED, 04, 0A", "thumb_misc");
+}
+
+// instructions in thumb it blocks take the condition of their slot
+#[test]
+fn thumb_it() {
+    let mut ops = dynasmrt::arm::Assembler::new().unwrap();
+    dynasm!(ops
+        ; .arch thumb
+        ; it eq
+        ; addeq.w r0, r0, r1
+        ; ite ne
+        ; movne.w r0, #1
+        ; moveq.w r0, #0
+        ; ittet gt
+        ; addgt.w r1, r1, #1
+        ; subgt.w r2, r2, #1
+        ; movle.w r3, r4
+        ; strgt.w r5, [r6]
+        ; it al
+        ; add.w r0, r0, r0
+    );
+    let buf = ops.finalize().unwrap();
+    let hex: Vec<String> = buf.iter().map(|x| format!("{:02X}", *x)).collect();
+    let hex = hex.join(", ");
+    assert_eq!(hex, "08, BF, 00, EB, 01, 00, 14, BF, 4F, F0, 01, 00, 4F, F0, 00, 00, C5, BF, 01, F1, 01, 01, A2, F1, 01, 02, 4F, EA, 04, 03, C6, F8, 00, 50, E8, BF, 00, EB, 00, 00", "thumb_it");
+}
+
+// a32 branches, literal loads and adr to labels
+#[test]
+fn a32_labels() {
+    let mut ops = dynasmrt::arm::Assembler::new().unwrap();
+    dynasm!(ops
+        ; .arch arm
+        ; back:
+        ; nop
+        ; b <back
+        ; bl >fwd
+        ; bne >fwd
+        ; ldr r0, <back
+        ; ldrh r1, >fwd
+        ; ldrd r2, r3, <back
+        ; vldr d0, >fwd
+        ; adr r4, <back
+        ; adrlt r5, >fwd
+        ; fwd:
+        ; nop
+    );
+    let buf = ops.finalize().unwrap();
+    let hex: Vec<String> = buf.iter().map(|x| format!("{:02X}", *x)).collect();
+    let hex = hex.join(", ");
+    assert_eq!(hex, "00, F0, 20, E3, FD, FF, FF, EA, 06, 00, 00, EB, 05, 00, 00, 1A, 18, 00, 1F, E5, BC, 10, DF, E1, D0, 22, 4F, E1, 01, 0B, 9F, ED, 28, 40, 4F, E2, 04, 50, 4F, B2, 00, F0, 20, E3", "a32_labels");
+}
+
+// thumb branches, literal loads and adr to labels. Literal loads and adr are relative to the word aligned pc
+#[test]
+fn thumb_labels() {
+    let mut ops = dynasmrt::arm::Assembler::new().unwrap();
+    dynasm!(ops
+        ; .arch thumb
+        ; back:
+        ; nop.n
+        ; b.n <back
+        ; b.w >fwd
+        ; beq.n <back
+        ; bne.w >fwd
+        ; bl <back
+        ; cbz r0, >fwd
+        ; cbnz r7, >fwd
+        ; ldr.n r1, >fwd
+        ; ldr.w r2, <back
+        ; ldrd r4, r5, >fwd
+        ; vldr s0, <back
+        ; adr.n r3, >fwd
+        ; adr.w r4, <back
+        ; .align 4
+        ; fwd:
+        ; nop.w
+    );
+    let buf = ops.finalize().unwrap();
+    let hex: Vec<String> = buf.iter().map(|x| format!("{:02X}", *x)).collect();
+    let hex = hex.join(", ");
+    assert_eq!(hex, "00, BF, FD, E7, 00, F0, 12, B8, FA, D0, 40, F0, 0F, 80, FF, F7, F7, FF, 58, B1, 57, B9, 05, 49, 5F, F8, 1C, 20, DF, E9, 03, 45, 1F, ED, 09, 0A, 01, A3, AF, F2, 28, 04, 00, BF, AF, F3, 00, 80", "thumb_labels");
+}
+
+// alignment padding consists of nops in both instruction sets, unless a filler byte is given
+#[test]
+fn arm_align() {
+    let mut ops = dynasmrt::arm::Assembler::new().unwrap();
+    dynasm!(ops
+        ; .arch arm
+        ; bx lr
+        ; .align 16
+        ; .arch thumb
+        ; bx lr
+        ; .align 8
+        ; .byte 0x11
+        ; .align 4
+        ; .align 8, 0xFF
+    );
+    let buf = ops.finalize().unwrap();
+    let hex: Vec<String> = buf.iter().map(|x| format!("{:02X}", *x)).collect();
+    let hex = hex.join(", ");
+    assert_eq!(hex, "1E, FF, 2F, E1, 00, F0, 20, E3, 00, F0, 20, E3, 00, F0, 20, E3, 70, 47, 00, BF, 00, BF, 00, BF, 11, 00, 00, BF, FF, FF, FF, FF", "arm_align");
+}
+
+// blx switches between a32 and thumb code assembled into the same buffer. A32 blx can target any
+// halfword, thumb blx is relative to the word aligned pc
+#[test]
+fn interworking() {
+    let mut ops = dynasmrt::arm::Assembler::new().unwrap();
+    let thumb_fn = ops.new_dynamic_label();
+    dynasm!(ops
+        ; .arch arm
+        ; arm_fn:
+        ; push {r4, lr}
+        ; blx =>thumb_fn
+        ; blx >thumb_fn2
+        ; pop {r4, pc}
+        ; .arch thumb
+        ; =>thumb_fn
+        ; nop.n
+        ; thumb_fn2:
+        ; blx <arm_fn
+        ; bx lr
+    );
+    let offset = ops.labels().resolve_dynamic(thumb_fn).unwrap();
+    let buf = ops.finalize().unwrap();
+    let hex: Vec<String> = buf.iter().map(|x| format!("{:02X}", *x)).collect();
+    let hex = hex.join(", ");
+    assert_eq!(hex, "10, 40, 2D, E9, 01, 00, 00, FA, 00, 00, 00, FB, 10, 80, BD, E8, 00, BF, FF, F7, F6, EF, 70, 47", "interworking");
+
+    let entry = dynasmrt::arm::thumb_entry(buf.ptr(offset));
+    assert_eq!(entry as usize, buf.ptr(offset) as usize + 1);
+}
+
+// relocations in data are relative to the start of the data
+#[test]
+fn data_relocations() {
+    let mut ops = dynasmrt::arm::Assembler::new().unwrap();
+    dynasm!(ops
+        ; .arch arm
+        ; start:
+        ; nop
+        ; .dword <start
+        ; .word <start
+    );
+    let buf = ops.finalize().unwrap();
+    let hex: Vec<String> = buf.iter().map(|x| format!("{:02X}", *x)).collect();
+    let hex = hex.join(", ");
+    assert_eq!(hex, "00, F0, 20, E3, FC, FF, FF, FF, F8, FF", "data_relocations");
+}
+
+// registers and immediates can be chosen at runtime, modified immediates are encoded at runtime
+#[test]
+fn dynamic() {
+    let r = 4;
+    let d = 16;
+    let imm = 0x3F0;
+    let big = 0xAB00_0000u32;
+    let off = -12;
+    let half = -0.5f32;
+    let lsb = 3;
+    let width = 12;
+    let mut ops = dynasmrt::arm::Assembler::new().unwrap();
+    dynasm!(ops
+        ; .arch arm
+        ; add R(r), R(r + 1), r6
+        ; add R(r), R(r), #imm
+        ; ldr R(r), [sp, #off]
+        ; mov r0, #big
+        ; vadd.f64 D(d), D(d + 1), D(d)
+        ; vmov.f32 S(1), #half
+        ; ubfx R(r), r5, #lsb, #width
+    );
+    let buf = ops.finalize().unwrap();
+    let hex: Vec<String> = buf.iter().map(|x| format!("{:02X}", *x)).collect();
+    let hex = hex.join(", ");
+    assert_eq!(hex, "06, 40, 85, E0, 3F, 4E, 84, E2, 0C, 40, 1D, E5, AB, 04, A0, E3, A0, 0B, 71, EE, 00, 0A, FE, EE, D5, 41, EB, E7", "dynamic");
+}
+
+// registers and immediates can be chosen at runtime in thumb code as well
+#[test]
+fn thumb_dynamic() {
+    let r = 4;
+    let imm = 0x0012_0012;
+    let off = -12;
+    let half = -0.5f32;
+    let shift = 3;
+    let mut ops = dynasmrt::arm::Assembler::new().unwrap();
+    dynasm!(ops
+        ; .arch thumb
+        ; add.w R(r), R(r + 1), r6
+        ; add.w R(r), R(r), #imm
+        ; ldr R(r), [sp, #off]
+        ; ldr.n R(r), [sp, #-off]
+        ; vmov.f32 S(1), #half
+        ; lsl.w R(r), r5, #shift
+    );
+    let buf = ops.finalize().unwrap();
+    let hex: Vec<String> = buf.iter().map(|x| format!("{:02X}", *x)).collect();
+    let hex = hex.join(", ");
+    assert_eq!(hex, "05, EB, 06, 04, 04, F1, 12, 14, 5D, F8, 0C, 4C, 03, 9C, FE, EE, 00, 0A, 4F, EA, C5, 04", "thumb_dynamic");
+}
+
+// instructions from the listed extensions can be used after they have been declared with .feature
+#[test]
+fn features() {
+    let mut ops = dynasmrt::arm::Assembler::new().unwrap();
+    dynasm!(ops
+        ; .arch arm
+        ; .feature none
+        ; add r0, r1, r2
+        ; .feature idiv
+        ; sdiv r0, r1, r2
+        ; .feature idiv, vfp
+        ; vadd.f32 s0, s1, s2
+    );
+    let buf = ops.finalize().unwrap();
+    let hex: Vec<String> = buf.iter().map(|x| format!("{:02X}", *x)).collect();
+    let hex = hex.join(", ");
+    assert_eq!(hex, "02, 00, 81, E0, 11, F2, 10, E7, 81, 0A, 30, EE", "features");
+}
@@ -9,3 +9,33 @@ use dynasm::dynasm;
 use dynasmrt::DynasmApi;
 
 include!("gen_x64/avx512.rs.gen");
+
+// dynamic registers work with EVEX ops, including the upper 16 vector registers
+#[test]
+fn dynamic_upper_registers() {
+    let mut ops = dynasmrt::x64::Assembler::new().unwrap();
+    dynasm!(ops
+       ; .arch x64
+       ; vaddps Rz(16), Rz(17), Rz(31)
+       ; vaddps Rz(1){Rk(2)}{z}, Rz(20), Rz(9)
+       ; vpandq Rx(24), Rx(8), Rx(19)
+       ; vgatherdps Rz(17){Rk(1)}, [rax + Rz(28) * 4 + 64]
+       ; vmovdqa32 [r13 + 128]{Rk(3)}, Rz(19)
+    );
+    let buf = ops.finalize().unwrap();
+    let hex: Vec<String> = buf.iter().map(|x| format!("0x{:02X}", *x)).collect();
+    let hex: String = hex.join(", ");
+    assert_eq!(hex, "0x62, 0x81, 0x74, 0x40, 0x58, 0xC7, 0x62, 0xD1, 0x5C, 0xC2, 0x58, 0xC9, 0x62, 0x21, 0xBD, 0x08, 0xDB, 0xC3, 0x62, 0xA2, 0x7D, 0x41, 0x92, 0x4C, 0xA0, 0x10, 0x62, 0xC1, 0x7D, 0x4B, 0x7F, 0x5D, 0x02", "dynamic_upper_registers");
+}
+
+// dynamic upper vector registers can't be encoded in the VEX form of an instruction
+#[test]
+#[should_panic(expected = "xmm16-xmm31 require an EVEX encoded instruction")]
+fn dynamic_upper_registers_vex() {
+    let reg = 16;
+    let mut ops = dynasmrt::x64::Assembler::new().unwrap();
+    dynasm!(ops
+       ; .arch x64
+       ; vaddps Rx(1), Rx(2), Rx(reg)
+    );
+}
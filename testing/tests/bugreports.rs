@@ -72,3 +72,73 @@ fn bugreport_3() {
     let hex: String = hex.join(", ");
     assert_eq!(hex, "0xC4, 0xE1, 0x6B, 0x58, 0xCB, 0xC4, 0x41, 0x33, 0x58, 0xD3", "bugreport_3");
 }
+
+// ensure tile registers can be used dynamically
+#[test]
+fn bugreport_4() {
+    let mut ops = dynasmrt::x64::Assembler::new().unwrap();
+    dynasm!(ops
+       ; .arch x64
+       ; tdpbssd Rt(1), Rt(2), Rt(3)
+       ; tileloadd Rt(4), [rax + rbx]
+       ; tilezero Rt(5)
+    );
+    let buf = ops.finalize().unwrap();
+    let hex: Vec<String> = buf.iter().map(|x| format!("0x{:02X}", *x)).collect();
+    let hex: String = hex.join(", ");
+    assert_eq!(hex, "0xC4, 0xE2, 0x63, 0x5E, 0xCA, 0xC4, 0xE2, 0x7B, 0x4B, 0x24, 0x18, 0xC4, 0xE2, 0x7B, 0x49, 0xE8", "bugreport_4");
+}
+
+// ensure code emitted while altering is written sequentially, also for instructions longer than a byte
+#[test]
+fn bugreport_5() {
+    let mut ops = dynasmrt::x64::Assembler::new().unwrap();
+    let start = ops.offset();
+    dynasm!(ops
+       ; .arch x64
+       ; nop
+       ; nop
+       ; nop
+       ; nop
+       ; nop
+       ; nop
+       ; nop
+       ; nop
+       ; nop
+       ; nop
+       ; ret
+    );
+    ops.alter(|ops| {
+        ops.goto(start);
+        dynasm!(ops
+           ; .arch x64
+           ; mov eax, 0x11223344
+           ; mov ecx, 0x55667788
+        );
+        assert_eq!(ops.offset().0, 10);
+    }).unwrap();
+    let buf = ops.finalize().unwrap();
+    let hex: Vec<String> = buf.iter().map(|x| format!("0x{:02X}", *x)).collect();
+    let hex: String = hex.join(", ");
+    assert_eq!(hex, "0xB8, 0x44, 0x33, 0x22, 0x11, 0xB9, 0x88, 0x77, 0x66, 0x55, 0xC3", "bugreport_5");
+}
+
+// ensure emitting past the end of the altered buffer panics, instead of silently dropping code
+#[test]
+#[should_panic]
+fn bugreport_6() {
+    let mut ops = dynasmrt::x64::Assembler::new().unwrap();
+    let start = ops.offset();
+    dynasm!(ops
+       ; .arch x64
+       ; nop
+       ; nop
+    );
+    ops.alter(|ops| {
+        ops.goto(start);
+        dynasm!(ops
+           ; .arch x64
+           ; mov eax, 0x11223344
+        );
+    }).unwrap();
+}
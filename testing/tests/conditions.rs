@@ -0,0 +1,153 @@
+#![feature(proc_macro_hygiene)]
+#![allow(unused_imports)]
+
+extern crate dynasmrt;
+extern crate dynasm;
+
+use dynasm::dynasm;
+use dynasmrt::{DynasmApi, DynasmLabelApi};
+
+// runtime condition codes encode the same as the static mnemonics
+#[test]
+fn conditions_x64() {
+    let mut ops = dynasmrt::x64::Assembler::new().unwrap();
+    for cc in 0 .. 16u8 {
+        dynasm!(ops
+            ; .arch x64
+            ; jcc Cond(cc), >done
+            ; jcc Cond(cc), BYTE >done
+            ; setcc Cond(cc), al
+            ; cmovcc Cond(cc), eax, ebx
+            ; cmovcc Cond(cc), r8, [rax]
+            ; done:
+        );
+    }
+    let dynamic = ops.finalize().unwrap();
+
+    let mut ops = dynasmrt::x64::Assembler::new().unwrap();
+    macro_rules! conditions {
+        ($($j:ident $set:ident $cmov:ident),*) => {$(
+            dynasm!(ops
+                ; .arch x64
+                ; $j >done
+                ; $j BYTE >done
+                ; $set al
+                ; $cmov eax, ebx
+                ; $cmov r8, [rax]
+                ; done:
+            );
+        )*}
+    }
+    conditions!(
+        jo  seto  cmovo,  jno  setno  cmovno,  jb  setb  cmovb,  jae setae cmovae,
+        je  sete  cmove,  jne  setne  cmovne,  jbe setbe cmovbe, ja  seta  cmova,
+        js  sets  cmovs,  jns  setns  cmovns,  jp  setp  cmovp,  jnp setnp cmovnp,
+        jl  setl  cmovl,  jge  setge  cmovge,  jle setle cmovle, jg  setg  cmovg
+    );
+    let fixed = ops.finalize().unwrap();
+
+    assert_eq!(&dynamic[.. 18], &[
+        0x0F, 0x80, 0x0C, 0x00, 0x00, 0x00,
+        0x70, 0x0A,
+        0x0F, 0x90, 0xC0,
+        0x0F, 0x40, 0xC3,
+        0x4C, 0x0F, 0x40, 0x00
+    ]);
+    assert_eq!(&*dynamic, &*fixed);
+}
+
+// aarch64 condition codes can be given at runtime as well, including the inverted encodings of aliases like cset
+#[test]
+fn conditions_aarch64() {
+    let mut ops = dynasmrt::aarch64::Assembler::new().unwrap();
+    for cond in 0 .. 14u32 {
+        dynasm!(ops
+            ; .arch aarch64
+            ; b.Cond(cond) >done
+            ; csel w0, w1, w2, Cond(cond)
+            ; cset x3, Cond(cond)
+            ; ccmp x4, #5, #2, Cond(cond)
+            ; done:
+        );
+    }
+    let dynamic = ops.finalize().unwrap();
+
+    let mut ops = dynasmrt::aarch64::Assembler::new().unwrap();
+    macro_rules! conditions {
+        ($($cond:ident),*) => {$(
+            dynasm!(ops
+                ; .arch aarch64
+                ; b.$cond >done
+                ; csel w0, w1, w2, $cond
+                ; cset x3, $cond
+                ; ccmp x4, #5, #2, $cond
+                ; done:
+            );
+        )*}
+    }
+    conditions!(eq, ne, hs, lo, mi, pl, vs, vc, hi, ls, ge, lt, gt, le);
+    let fixed = ops.finalize().unwrap();
+
+    assert_eq!(&*dynamic, &*fixed);
+}
+
+// compound expressions are evaluated as a whole before the condition field is masked out
+#[test]
+fn conditions_x64_compound() {
+    let mut ops = dynasmrt::x64::Assembler::new().unwrap();
+    for cc in 0 .. 16u8 {
+        dynasm!(ops
+            ; .arch x64
+            ; jcc Cond(0x10 | cc), >done
+            ; setcc Cond(0x30 ^ cc), al
+            ; cmovcc Cond(0x10 | cc), eax, ebx
+            ; done:
+        );
+    }
+    let compound = ops.finalize().unwrap();
+
+    let mut ops = dynasmrt::x64::Assembler::new().unwrap();
+    for cc in 0 .. 16u8 {
+        dynasm!(ops
+            ; .arch x64
+            ; jcc Cond(cc), >done
+            ; setcc Cond(cc), al
+            ; cmovcc Cond(cc), eax, ebx
+            ; done:
+        );
+    }
+    let plain = ops.finalize().unwrap();
+
+    assert_eq!(&*compound, &*plain);
+}
+
+// compound expressions are evaluated as a whole before the condition field is masked out
+#[test]
+fn conditions_aarch64_compound() {
+    let mut ops = dynasmrt::aarch64::Assembler::new().unwrap();
+    for cond in 0 .. 14u32 {
+        let base = cond / 2;
+        dynasm!(ops
+            ; .arch aarch64
+            ; b.Cond(0x10 | cond) >done
+            ; csel w0, w1, w2, Cond(0x30 ^ cond)
+            ; cset x3, Cond(0x10 | base + cond - base)
+            ; done:
+        );
+    }
+    let compound = ops.finalize().unwrap();
+
+    let mut ops = dynasmrt::aarch64::Assembler::new().unwrap();
+    for cond in 0 .. 14u32 {
+        dynasm!(ops
+            ; .arch aarch64
+            ; b.Cond(cond) >done
+            ; csel w0, w1, w2, Cond(cond)
+            ; cset x3, Cond(cond)
+            ; done:
+        );
+    }
+    let plain = ops.finalize().unwrap();
+
+    assert_eq!(&*compound, &*plain);
+}
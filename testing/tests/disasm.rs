@@ -0,0 +1,182 @@
+#![feature(proc_macro_hygiene)]
+#![allow(unused_imports)]
+
+#[macro_use]
+extern crate dynasmrt;
+extern crate dynasm;
+
+use dynasm::dynasm;
+use dynasmrt::{DynasmApi, DynasmLabelApi};
+use dynasmrt::disasm::{disassemble_x64, disassemble_aarch64, Instruction};
+use dynasmrt::text::TextAssembler;
+
+fn hex(buf: &[u8]) -> String {
+    let hex: Vec<String> = buf.iter().map(|x| format!("{:02X}", *x)).collect();
+    hex.join(", ")
+}
+
+fn texts(instructions: &[Instruction]) -> Vec<&str> {
+    instructions.iter().map(|i| i.text.as_str()).collect()
+}
+
+// x64 code disassembles to text that assembles back to the same code
+#[test]
+fn x64_disasm() {
+    let mut ops = dynasmrt::x64::Assembler::new().unwrap();
+    dynasm!(ops
+        ; .arch x64
+        ; mov rax, 0x1234
+        ; add rcx, 8
+        ; mov QWORD [rbx + rcx * 4 + 0x100], 0x7F
+        ; lea r12, [r13 + rax * 2 - 16]
+        ; mov rdx, [rip + 0x10]
+        ; movzx eax, BYTE [rsi]
+        ; movsd xmm0, QWORD [rax]
+        ; vpaddd xmm0, xmm9, xmm15
+        ; lock add [rdi], eax
+        ; rep movsb
+        ; fs mov [rax], eax
+        ; lea eax, [ebx + 4]
+        ; movabs rax, 0x1122334455667788
+        ; jl BYTE >skip
+        ; push rbp
+        ; call rax
+        ; skip:
+        ; ret
+    );
+    let code = ops.finalize().unwrap();
+
+    let instructions = disassemble_x64(&code, 0x1000);
+    assert_eq!(texts(&instructions), [
+        "mov rax, 0x1234",
+        "add rcx, 8",
+        "mov QWORD [rbx + rcx * 4 + 0x100], 0x7F",
+        "lea r12, [r13 + rax * 2 - 0x10]",
+        "mov rdx, [rip + 0x10]",
+        "movzx eax, BYTE [rsi]",
+        "movsd xmm0, QWORD [rax]",
+        "vpaddd xmm0, xmm9, xmm15",
+        "lock add [rdi], eax",
+        "rep movsb",
+        "fs mov [rax], eax",
+        "lea eax, [ebx + 4]",
+        "movabs rax, 0x1122334455667788",
+        "jl 3",
+        "push rbp",
+        "call rax",
+        "ret",
+    ]);
+
+    let jump = &instructions[13];
+    assert_eq!((jump.address, jump.target), (0x1000 + code.len() - 6, Some(0x1000 + code.len() - 1)));
+    assert_eq!(instructions.iter().map(|i| i.bytes.len()).sum::<usize>(), code.len());
+
+    let mut ops = dynasmrt::x64::Assembler::new().unwrap();
+    TextAssembler::new().assemble_x64(&mut ops, &texts(&instructions).join("\n")).unwrap();
+    let assembled = ops.finalize().unwrap();
+    assert_eq!(hex(&assembled), hex(&code), "x64_disasm");
+}
+
+// aarch64 code disassembles to text that assembles back to the same code
+#[test]
+fn aarch64_disasm() {
+    let mut ops = dynasmrt::aarch64::Assembler::new().unwrap();
+    dynasm!(ops
+        ; .arch aarch64
+        ; add x0, x1, x2
+        ; add w0, w1, 0xFFF
+        ; sub x3, x19, x4, lsl 3
+        ; ldr x0, [x1, 8]
+        ; ldr x0, [x1, 16]!
+        ; ldr x0, [x1], 8
+        ; ldr w2, [x3, x4, lsl 2]
+        ; stp x29, x30, [sp, -16]!
+        ; movz x0, 0x1234, lsl 16
+        ; and x0, x1, 0xFF00
+        ; fmov d0, 1.5
+        ; add v0.s4, v1.s4, v2.s4
+        ; ld1 {v0.b16, v1.b16}, [x0]
+        ; mrs x0, nzcv
+        ; dmb ish
+        ; csel x0, x1, x2, ne
+        ; mov x0, x1
+        ; cmp x0, 3
+        ; b.ne >skip
+        ; adr x1, >skip
+        ; skip:
+        ; ret
+    );
+    let code = ops.finalize().unwrap();
+
+    let instructions = disassemble_aarch64(&code, 0x1000);
+    assert_eq!(texts(&instructions), [
+        "add x0, x1, x2",
+        "add w0, w1, 0xFFF",
+        "sub x3, x19, x4, lsl 3",
+        "ldr x0, [x1, 8]",
+        "ldr x0, [x1, 0x10]!",
+        "ldr x0, [x1], 8",
+        "ldr w2, [x3, x4, lsl 2]",
+        "stp x29, x30, [sp, -0x10]!",
+        "mov x0, 0x12340000",
+        "and x0, x1, 0xFF00",
+        "fmov d0, 1.5",
+        "add v0.s4, v1.s4, v2.s4",
+        "ld1 {v0.b16, v1.b16}, [x0]",
+        "mrs x0, nzcv",
+        "dmb ish",
+        "csel x0, x1, x2, ne",
+        "mov x0, x1",
+        "cmp x0, 3",
+        "b.ne 8",
+        "adr x1, 4",
+        "ret",
+    ]);
+
+    let jump = &instructions[18];
+    assert_eq!((jump.address, jump.target), (0x1048, Some(0x1050)));
+
+    let mut ops = dynasmrt::aarch64::Assembler::new().unwrap();
+    TextAssembler::new().assemble_aarch64(&mut ops, &texts(&instructions).join("\n")).unwrap();
+    let assembled = ops.finalize().unwrap();
+    assert_eq!(hex(&assembled), hex(&code), "aarch64_disasm");
+}
+
+// bytes that cannot be decoded are shown as data
+#[test]
+fn disasm_undecodable() {
+    let instructions = disassemble_x64(&[0x06, 0x90, 0x0F], 0);
+    assert_eq!(texts(&instructions), [".byte 0x06", "nop", ".byte 0x0F"]);
+
+    let instructions = disassemble_aarch64(&[0xFF, 0xFF, 0xFF, 0xFF, 0xC0, 0x03, 0x5F, 0xD6, 0x01], 0);
+    assert_eq!(texts(&instructions), [".dword 0xFFFFFFFF", "ret", ".byte 0x01"]);
+    assert_eq!(instructions[2].address, 8);
+}
+
+// executable buffers disassemble at the address they are mapped at
+#[cfg(target_arch = "x86_64")]
+#[test]
+fn executable_buffer_disasm() {
+    let mut ops = dynasmrt::x64::Assembler::new().unwrap();
+    let start = ops.offset();
+    dynasm!(ops
+        ; .arch x64
+        ; mov eax, 1
+        ; jmp BYTE >done
+        ; nop
+        ; done:
+        ; ret
+    );
+    let buf = ops.finalize().unwrap();
+
+    let base = buf.ptr(start) as usize;
+    let instructions = buf.disassemble(0 .. buf.len());
+    assert_eq!(texts(&instructions), ["mov eax, 1", "jmp 1", "nop", "ret"]);
+    assert_eq!(instructions[0].address, base);
+    assert_eq!(instructions[1].target, Some(base + 8));
+    assert_eq!(instructions[1].to_string(), "jmp 1");
+
+    let tail = buf.disassemble(8 .. buf.len());
+    assert_eq!(texts(&tail), ["ret"]);
+    assert_eq!(tail[0].address, base + 8);
+}
@@ -0,0 +1,480 @@
+#![feature(proc_macro_hygiene)]
+#![allow(unused_imports)]
+
+#[macro_use]
+extern crate dynasmrt;
+extern crate dynasm;
+extern crate dynasm_data;
+
+use dynasm::dynasm;
+use dynasmrt::{DynasmApi, DynasmLabelApi, TargetKind};
+
+fn hex(buf: &[u8]) -> String {
+    let hex: Vec<String> = buf.iter().map(|x| format!("{:02X}", *x)).collect();
+    hex.join(", ")
+}
+
+// the x64 runtime encoder produces the same code as dynasm! for the same instructions
+#[test]
+fn x64_encoder() {
+    use dynasmrt::x64::{encode, encode_with_prefixes, encode_with_decorations, Mnemonic, Operand, Register, MemoryRef, Size, Prefix,
+                        Decorations, Rounding};
+    use dynasmrt::x64::Register::*;
+    use dynasmrt::x64::Operand::{Reg, Imm, Mem};
+
+    fn mnem(name: &str) -> Mnemonic {
+        name.parse().unwrap()
+    }
+
+    let mut ops = dynasmrt::x64::Assembler::new().unwrap();
+    let label = ops.new_dynamic_label();
+    dynasm!(ops
+        ; .arch x64
+        ; =>label
+        ; add rax, rbx
+        ; add eax, 5
+        ; add r8w, 0x1234
+        ; add rcx, [rsp + 8]
+        ; mov QWORD [rbx + rcx * 4 + 0x100], 0x7F
+        ; lea r12, [r13 + rax * 2]
+        ; mov al, [rip + 0x10]
+        ; mov rax, QWORD 0x1122334455667788
+        ; movzx eax, ah
+        ; mov sil, 1
+        ; push r15
+        ; imul rax, rcx, 100
+        ; shl rdx, 3
+        ; mov eax, [0x1000]
+        ; mov ecx, [rax * 3]
+        ; vaddps ymm1, ymm2, [rax]
+        ; vpaddd xmm0, xmm9, xmm15
+        ; vpgatherdd xmm0, [rax + xmm1 * 4], xmm2
+        ; vaddps zmm1{k1}{z}, zmm2, [rax + 64]{1to16}
+        ; vaddpd zmm30, zmm29, zmm28, {rz-sae}
+        ; vmovups [rax - 0x2000]{k2}, zmm17
+        ; vmovups zmm3, [rax + 0x1FC0]
+        ; vmovups zmm3, [rax + 0x2000]
+        ; vpgatherdd zmm0{k1}, [rax + zmm20 * 4]
+        ; .feature apx
+        ; add r16, r17
+        ; add r18, r19, [r20 + r21 * 2 + 8]
+        ; lock add [rdi], eax
+        ; rep movsb
+        ; fs mov rax, [0]
+        ; jmp =>label
+        ; call =>label
+        ; lea rax, [=>label]
+        ; ret
+    );
+    let expected = ops.finalize().unwrap();
+
+    let mut ops = dynasmrt::x64::Assembler::new().unwrap();
+    let label = ops.new_dynamic_label();
+    ops.dynamic_label(label);
+    encode(&mut ops, mnem("add"), &[Reg(Rq(0)), Reg(Rq(3))]).unwrap();
+    encode(&mut ops, mnem("add"), &[Reg(Rd(0)), Imm(5)]).unwrap();
+    encode(&mut ops, mnem("add"), &[Reg(Rw(8)), Imm(0x1234)]).unwrap();
+    encode(&mut ops, mnem("add"), &[Reg(Rq(1)), Mem(MemoryRef { base: Some(Rq(4)), disp: 8, ..Default::default() })]).unwrap();
+    encode(&mut ops, mnem("mov"), &[Mem(MemoryRef { size: Some(Size::QWord), base: Some(Rq(3)), index: Some((Rq(1), 4)), disp: 0x100 }), Imm(0x7F)]).unwrap();
+    encode(&mut ops, mnem("lea"), &[Reg(Rq(12)), Mem(MemoryRef { base: Some(Rq(13)), index: Some((Rq(0), 2)), ..Default::default() })]).unwrap();
+    encode(&mut ops, mnem("mov"), &[Reg(Rb(0)), Mem(MemoryRef { base: Some(Rip), disp: 0x10, ..Default::default() })]).unwrap();
+    encode(&mut ops, mnem("mov"), &[Reg(Rq(0)), Imm(0x1122334455667788)]).unwrap();
+    encode(&mut ops, mnem("movzx"), &[Reg(Rd(0)), Reg(Rh(4))]).unwrap();
+    encode(&mut ops, mnem("mov"), &[Reg(Rb(6)), Imm(1)]).unwrap();
+    encode(&mut ops, mnem("push"), &[Reg(Rq(15))]).unwrap();
+    encode(&mut ops, mnem("imul"), &[Reg(Rq(0)), Reg(Rq(1)), Imm(100)]).unwrap();
+    encode(&mut ops, mnem("shl"), &[Reg(Rq(2)), Imm(3)]).unwrap();
+    encode(&mut ops, mnem("mov"), &[Reg(Rd(0)), Mem(MemoryRef { disp: 0x1000, ..Default::default() })]).unwrap();
+    encode(&mut ops, mnem("mov"), &[Reg(Rd(1)), Mem(MemoryRef { index: Some((Rq(0), 3)), ..Default::default() })]).unwrap();
+    encode(&mut ops, mnem("vaddps"), &[Reg(Ry(1)), Reg(Ry(2)), Mem(MemoryRef { base: Some(Rq(0)), ..Default::default() })]).unwrap();
+    encode(&mut ops, mnem("vpaddd"), &[Reg(Rx(0)), Reg(Rx(9)), Reg(Rx(15))]).unwrap();
+    encode(&mut ops, mnem("vpgatherdd"), &[Reg(Rx(0)), Mem(MemoryRef { base: Some(Rq(0)), index: Some((Rx(1), 4)), ..Default::default() }), Reg(Rx(2))]).unwrap();
+    encode_with_decorations(&mut ops, &[], &Decorations { mask: Some(Rk(1)), zeroing: true, broadcast: Some(16), rounding: None }, mnem("vaddps"),
+        &[Reg(Rz(1)), Reg(Rz(2)), Mem(MemoryRef { base: Some(Rq(0)), disp: 64, ..Default::default() })]).unwrap();
+    encode_with_decorations(&mut ops, &[], &Decorations { rounding: Some(Rounding::Zero), ..Default::default() }, mnem("vaddpd"),
+        &[Reg(Rz(30)), Reg(Rz(29)), Reg(Rz(28))]).unwrap();
+    encode_with_decorations(&mut ops, &[], &Decorations { mask: Some(Rk(2)), ..Default::default() }, mnem("vmovups"),
+        &[Mem(MemoryRef { base: Some(Rq(0)), disp: -0x2000, ..Default::default() }), Reg(Rz(17))]).unwrap();
+    encode(&mut ops, mnem("vmovups"), &[Reg(Rz(3)), Mem(MemoryRef { base: Some(Rq(0)), disp: 0x1FC0, ..Default::default() })]).unwrap();
+    encode(&mut ops, mnem("vmovups"), &[Reg(Rz(3)), Mem(MemoryRef { base: Some(Rq(0)), disp: 0x2000, ..Default::default() })]).unwrap();
+    encode_with_decorations(&mut ops, &[], &Decorations { mask: Some(Rk(1)), ..Default::default() }, mnem("vpgatherdd"),
+        &[Reg(Rz(0)), Mem(MemoryRef { base: Some(Rq(0)), index: Some((Rz(20), 4)), ..Default::default() })]).unwrap();
+    encode(&mut ops, mnem("add"), &[Reg(Rq(16)), Reg(Rq(17))]).unwrap();
+    encode(&mut ops, mnem("add"), &[Reg(Rq(18)), Reg(Rq(19)), Mem(MemoryRef { base: Some(Rq(20)), index: Some((Rq(21), 2)), disp: 8, ..Default::default() })]).unwrap();
+    encode_with_prefixes(&mut ops, &[Prefix::Lock], mnem("add"), &[Mem(MemoryRef { base: Some(Rq(7)), ..Default::default() }), Reg(Rd(0))]).unwrap();
+    encode_with_prefixes(&mut ops, &[Prefix::Rep], mnem("movsb"), &[]).unwrap();
+    encode_with_prefixes(&mut ops, &[Prefix::Fs], mnem("mov"), &[Reg(Rq(0)), Mem(MemoryRef::default())]).unwrap();
+    encode(&mut ops, mnem("jmp"), &[Operand::Label(TargetKind::Dynamic(label))]).unwrap();
+    encode(&mut ops, mnem("call"), &[Operand::Label(TargetKind::Dynamic(label))]).unwrap();
+    encode(&mut ops, mnem("lea"), &[Reg(Rq(0)), Operand::LabelMem(TargetKind::Dynamic(label), None)]).unwrap();
+    encode(&mut ops, mnem("ret"), &[]).unwrap();
+    let encoded = ops.finalize().unwrap();
+
+    assert_eq!(hex(&encoded), hex(&expected), "x64_encoder");
+}
+
+// encoding errors are reported instead of panicking
+#[test]
+fn x64_encoder_errors() {
+    use dynasmrt::x64::{encode, encode_with_prefixes, encode_with_decorations, Mnemonic, Operand, MemoryRef, Size, Prefix, Decorations,
+                        Rounding, EncodeError};
+    use dynasmrt::x64::Register::*;
+    use dynasmrt::x64::Operand::{Reg, Imm, Mem};
+
+    let mut ops = dynasmrt::x64::Assembler::new().unwrap();
+    let add = Mnemonic::from_name("add").unwrap();
+
+    assert_eq!("frobnicate".parse::<Mnemonic>().unwrap_err(), EncodeError::UnknownMnemonic("frobnicate".into()));
+    assert_eq!(encode(&mut ops, add, &[Reg(Rb(0)), Reg(Rq(3))]), Err(EncodeError::OperandMismatch("add")));
+    assert_eq!(encode(&mut ops, add, &[Imm(1), Reg(Rq(3))]), Err(EncodeError::OperandMismatch("add")));
+    assert_eq!(encode(&mut ops, add, &[Reg(Rh(4)), Reg(Rb(8))]),
+        Err(EncodeError::InvalidOperand("High byte register combined with extended registers or 64-bit operand size".into())));
+    assert_eq!(encode(&mut ops, add, &[Reg(Rq(0)), Mem(MemoryRef { base: Some(Rw(0)), ..Default::default() })]),
+        Err(EncodeError::InvalidOperand("16-bit addressing is not supported in this mode".into())));
+    assert_eq!(encode(&mut ops, add, &[Reg(Rq(0)), Mem(MemoryRef { base: Some(Rq(4)), index: Some((Rq(4), 2)), ..Default::default() })]),
+        Err(EncodeError::InvalidOperand("'rsp' cannot be used as index field".into())));
+    assert_eq!(encode(&mut ops, add, &[Reg(Rq(32)), Reg(Rq(0))]),
+        Err(EncodeError::InvalidOperand("Rq(32) is not a valid register".into())));
+    assert_eq!(encode(&mut ops, add, &[Reg(Rq(0)), Reg(Rd(1))]),
+        Err(EncodeError::InvalidOperand("Conflicting operand sizes".into())));
+    assert_eq!(encode_with_prefixes(&mut ops, &[Prefix::Rep], add, &[Reg(Rq(0)), Reg(Rq(1))]),
+        Err(EncodeError::InvalidOperand("Cannot use prefix Rep on this instruction".into())));
+
+    // AVX-512 and APX
+    let vaddps = Mnemonic::from_name("vaddps").unwrap();
+    let zmm = [Reg(Rz(1)), Reg(Rz(2)), Reg(Rz(3))];
+    let zmm_mem = |size| [Reg(Rz(1)), Reg(Rz(2)), Mem(MemoryRef { size, base: Some(Rq(0)), ..Default::default() })];
+    assert_eq!(encode(&mut ops, Mnemonic::from_name("addps").unwrap(), &[Reg(Rx(16)), Reg(Rx(1))]),
+        Err(EncodeError::OperandMismatch("addps")));
+    assert_eq!(encode_with_decorations(&mut ops, &[], &Decorations { mask: Some(Rk(0)), ..Default::default() }, vaddps, &zmm),
+        Err(EncodeError::InvalidOperand("k0 cannot be used as an opmask".into())));
+    assert_eq!(encode_with_decorations(&mut ops, &[], &Decorations { mask: Some(Rq(1)), ..Default::default() }, vaddps, &zmm),
+        Err(EncodeError::InvalidOperand("Rq(1) is not an opmask register".into())));
+    assert_eq!(encode_with_decorations(&mut ops, &[], &Decorations { zeroing: true, ..Default::default() }, vaddps, &zmm),
+        Err(EncodeError::InvalidOperand("Zeroing-masking requires an opmask".into())));
+    assert_eq!(encode_with_decorations(&mut ops, &[], &Decorations { broadcast: Some(16), ..Default::default() }, vaddps, &zmm),
+        Err(EncodeError::InvalidOperand("Broadcasts can only be applied to memory references".into())));
+    assert_eq!(encode_with_decorations(&mut ops, &[], &Decorations { broadcast: Some(8), ..Default::default() }, vaddps, &zmm_mem(None)),
+        Err(EncodeError::InvalidOperand("Invalid broadcast, expected {1to16}".into())));
+    assert_eq!(encode_with_decorations(&mut ops, &[], &Decorations { broadcast: Some(16), ..Default::default() }, vaddps, &zmm_mem(Some(Size::QWord))),
+        Err(EncodeError::InvalidOperand("Broadcast element size mismatch, expected DWord".into())));
+    assert_eq!(encode_with_decorations(&mut ops, &[], &Decorations { rounding: Some(Rounding::Nearest), ..Default::default() }, vaddps, &zmm_mem(None)),
+        Err(EncodeError::InvalidOperand("Embedded rounding cannot be used with memory operands".into())));
+    assert_eq!(encode_with_decorations(&mut ops, &[], &Decorations { rounding: Some(Rounding::Nearest), ..Default::default() }, vaddps,
+        &[Reg(Ry(1)), Reg(Ry(2)), Reg(Ry(3))]),
+        Err(EncodeError::InvalidOperand("Embedded rounding is only supported for 512-bit vectors".into())));
+    assert_eq!(encode_with_decorations(&mut ops, &[], &Decorations { rounding: Some(Rounding::Sae), ..Default::default() }, add,
+        &[Reg(Rq(0)), Reg(Rq(1))]),
+        Err(EncodeError::OperandMismatch("add")));
+    assert_eq!(encode_with_decorations(&mut ops, &[], &Decorations { mask: Some(Rk(1)), zeroing: true, ..Default::default() },
+        Mnemonic::from_name("vmovups").unwrap(), &[Mem(MemoryRef { base: Some(Rq(0)), ..Default::default() }), Reg(Rz(1))]),
+        Err(EncodeError::InvalidOperand("Zeroing cannot be applied to memory references".into())));
+    assert_eq!(encode(&mut ops, Mnemonic::from_name("vpgatherdd").unwrap(),
+        &[Reg(Ry(0)), Mem(MemoryRef { base: Some(Rq(16)), index: Some((Ry(1), 4)), ..Default::default() }), Reg(Ry(2))]),
+        Err(EncodeError::OperandMismatch("vpgatherdd")));
+    assert_eq!(encode(&mut ops, Mnemonic::from_name("crc32").unwrap(), &[Reg(Rd(16)), Reg(Rd(1))]),
+        Err(EncodeError::InvalidOperand("r16-r31 cannot be used with this instruction".into())));
+    assert_eq!(encode(&mut ops, add, &[Reg(Rh(4)), Reg(Rb(1)), Reg(Rb(2))]),
+        Err(EncodeError::InvalidOperand("High byte registers cannot be encoded in an EVEX prefix".into())));
+    assert_eq!(ops.offset().0, 0);
+}
+
+// every instruction form covered by the generated dynasm! tests is encoded identically at runtime
+#[test]
+fn x64_encoder_forms() {
+    use dynasmrt::text::TextAssembler;
+
+    let dir = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/gen_x64");
+    let mut paths: Vec<_> = std::fs::read_dir(dir).unwrap().map(|entry| entry.unwrap().path()).collect();
+    paths.sort();
+
+    let mut count = 0;
+    let mut failures = Vec::new();
+    for path in paths {
+        let source = std::fs::read_to_string(&path).unwrap();
+        let mut asm = None;
+
+        for line in source.lines() {
+            let line = line.trim();
+            if line.starts_with("; ") && line != "; .arch x64" {
+                asm = Some(&line[2 ..]);
+            } else if let Some(rest) = line.strip_prefix("assert_eq!(hex, \"") {
+                let asm = asm.take().unwrap();
+                let expected = rest[.. rest.find('"').unwrap()].replace("0x", "");
+
+                let mut ops = dynasmrt::x64::Assembler::new().unwrap();
+                let result = TextAssembler::new().assemble_x64(&mut ops, asm);
+                let encoded = result.map(|_| hex(&ops.finalize().unwrap()));
+                if encoded.as_ref() != Ok(&expected) {
+                    failures.push(format!("{}: {} => {:?}, expected {}", path.display(), asm, encoded, expected));
+                }
+                count += 1;
+            }
+        }
+    }
+
+    assert!(count > 6000, "only found {} test cases", count);
+    assert!(failures.is_empty(), "{} of {} forms failed:\n{}", failures.len(), count, failures.join("\n"));
+}
+
+// every x64 instruction form in the instruction data can be encoded at runtime, with the decorations it supports
+#[test]
+fn x64_encoder_opmap() {
+    use dynasmrt::x64::{encode_with_decorations, Mnemonic, Operand, Register, MemoryRef, Size, Decorations, Rounding};
+    use dynasmrt::x64::Register::*;
+    use dynasm_data::x64::flags::*;
+
+    macro_rules! Form {
+        ($fmt:expr, $ops:expr, $reg:expr) => { ($fmt as &[u8], DEFAULT) };
+        ($fmt:expr, $ops:expr, $reg:expr, $f:expr $(, $ft:expr)?) => { ($fmt as &[u8], $f) };
+    }
+
+    macro_rules! Forms {
+        ( $( $name:tt = [ $( $( $e:expr ),+ ; )+ ] )* ) => {
+            [ $( ($name, &[ $( Form!($( $e ),*) ),+ ] as &[(&[u8], u64)]) ),* ]
+        };
+    }
+
+    // a size for every size code. wildcards pick the largest size the form allows, and
+    // wildcard memory operands pick the size of the other wildcard operands
+    fn size(code: u8, fsize: u8, flags: u64) -> Option<Size> {
+        Some(match (fsize, code) {
+            (b'b', _) => Size::Byte,
+            (b'w', _) => Size::Word,
+            (b'd', _) => Size::DWord,
+            (b'q', _) => Size::QWord,
+            (b'f', _) => Size::FWord,
+            (b'p', _) => Size::PWord,
+            (b'o', _) => Size::OWord,
+            (b'h', _) => Size::HWord,
+            (b'z', _) => Size::ZWord,
+            (b'*', b'y') | (b'*', b'w') | (b'*', b'k') | (b'*', b'l') if flags & EVEX_OP != 0 => Size::ZWord,
+            (b'*', b'y') | (b'*', b'w') | (b'*', b'k') | (b'*', b'l') => Size::HWord,
+            (b'*', b'r') | (b'*', b'v') | (b'*', b'A' ..= b'P') => Size::QWord,
+            (b'*', b'm') if flags & AUTO_VEXL != 0 && flags & EVEX_OP != 0 => Size::ZWord,
+            (b'*', b'm') if flags & AUTO_VEXL != 0 => Size::HWord,
+            (b'*', b'm') => Size::QWord,
+            _ => return None
+        })
+    }
+
+    fn gpr(n: u8, size: Option<Size>) -> Register {
+        match size {
+            Some(Size::Byte) => Rb(n),
+            Some(Size::Word) => Rw(n),
+            Some(Size::DWord) => Rd(n),
+            _ => Rq(n),
+        }
+    }
+
+    fn vector(n: u8, size: Option<Size>) -> Register {
+        match size {
+            Some(Size::HWord) => Ry(n),
+            Some(Size::ZWord) => Rz(n),
+            _ => Rx(n),
+        }
+    }
+
+    // synthesizes an operand matching the given format string entry. EVEX forms use registers
+    // that can only be encoded with an EVEX prefix, so they cannot match a VEX or legacy form.
+    fn operand(code: u8, fsize: u8, flags: u64, memory: bool) -> Operand {
+        let size = size(code, fsize, flags);
+        let n = if flags & EVEX_OP != 0 { 17 } else { 1 };
+        let mem = |index: Option<(Register, u8)>| Operand::Mem(MemoryRef { size: if index.is_some() { None } else { size }, base: Some(Rq(n)), index, disp: 0x40 });
+        Operand::Reg(match code {
+            b'i' | b'o' => return Operand::Imm(1),
+            b'm' => return mem(None),
+            b'k' | b'l' => return mem(Some((vector(n, size), 4))),
+            b'u' | b'v' | b'w' if memory => return mem(None),
+            b'r' | b'v' => gpr(n, size),
+            b'y' | b'w' => vector(n, size),
+            b'x' | b'u' => Rm(1),
+            b'f' => Rf(1),
+            b'j' => Rk(3),
+            b't' => Rt(1),
+            b's' => Rs(1),
+            b'c' => RC(0),
+            b'd' => RD(0),
+            b'b' => RB(1),
+            x @ b'A' ..= b'P' => gpr(x - b'A', size),
+            x @ b'Q' ..= b'V' => Rs(x - b'Q'),
+            b'W' => RC(8),
+            b'X' => Rf(0),
+            _ => panic!("unknown format string code {}", code)
+        })
+    }
+
+    const FORMS: &[(&str, &[(&[u8], u64)])] = &dynasm_data::x64_opmap!(Forms);
+
+    let mut count = 0;
+    let mut failures = Vec::new();
+    for &(name, forms) in FORMS {
+        let mnemonic: Mnemonic = name.parse().unwrap();
+        for &(fmt, flags) in forms.iter().filter(|&&(_, flags)| flags & X86_ONLY == 0) {
+            let args: Vec<(u8, u8)> = fmt.chunks(2).map(|c| (c[0], c[1])).collect();
+            let has_memory = args.iter().any(|&(code, _)| code == b'm' || code == b'k' || code == b'l');
+            let toggles = args.iter().any(|&(code, _)| code == b'u' || code == b'v' || code == b'w');
+
+            for memory in [false, true] {
+                if memory && !toggles {
+                    continue;
+                }
+
+                let mut operands: Vec<Operand> = args.iter().map(|&(code, fsize)| operand(code, fsize, flags, memory)).collect();
+                let mut decorations = Decorations::default();
+                if flags & OPMASK != 0 {
+                    decorations.mask = Some(Rk(1));
+                    decorations.zeroing = flags & ZEROING != 0 && matches!(operands.first(), Some(Operand::Reg(_)));
+                }
+                if memory && flags & BCST != 0 {
+                    let (index, &(code, fsize)) = args.iter().enumerate().find(|(_, &(code, _))| b"uvw".contains(&code)).unwrap();
+                    let element = if flags & WITH_REXW != 0 { 8 } else { 4 };
+                    decorations.broadcast = Some(size(code, fsize, flags).unwrap().in_bytes() / element);
+                    if let Operand::Mem(ref mut mem) = operands[index] {
+                        mem.size = None;
+                    }
+                } else if !memory && !has_memory {
+                    if flags & ROUNDING != 0 {
+                        decorations.rounding = Some(Rounding::Down);
+                    } else if flags & SAE != 0 {
+                        decorations.rounding = Some(Rounding::Sae);
+                    }
+                }
+
+                let mut ops = dynasmrt::x64::Assembler::new().unwrap();
+                if let Err(e) = encode_with_decorations(&mut ops, &[], &decorations, mnemonic, &operands) {
+                    failures.push(format!("{} {:?} {:?}: {:?}", name, operands, decorations, e));
+                }
+                count += 1;
+            }
+        }
+    }
+
+    assert!(count > 4000, "only found {} forms", count);
+    assert!(failures.is_empty(), "{} of {} forms failed:\n{}", failures.len(), count, failures.join("\n"));
+}
+
+// the aarch64 runtime encoder produces the same code as dynasm! for the same instructions
+#[test]
+fn aarch64_encoder() {
+    use dynasmrt::aarch64::{encode, Mnemonic, Operand, Register, Vector, MemoryRef, Modifier, Size};
+    use dynasmrt::aarch64::Register::*;
+    use dynasmrt::aarch64::Operand::{Reg, Imm, Mem, Ident};
+
+    fn mnem(name: &str) -> Mnemonic {
+        name.parse().unwrap()
+    }
+
+    fn v(reg: u8, element_size: Size, lanes: Option<u8>, element: Option<u8>) -> Operand<'static> {
+        Operand::Vector(Vector { reg, element_size, lanes, element })
+    }
+
+    let mut ops = dynasmrt::aarch64::Assembler::new().unwrap();
+    let label = ops.new_dynamic_label();
+    dynasm!(ops
+        ; .arch aarch64
+        ; =>label
+        ; add x0, x1, x2
+        ; add w0, w1, 12
+        ; add x3, sp, 1, lsl 12
+        ; sub x4, x5, x6, lsr 7
+        ; add x7, x8, w9, sxtw 2
+        ; ldr x3, [x4, 8]
+        ; ldr x3, [x4, 8]!
+        ; ldr x3, [x4], 8
+        ; ldr w5, [x6, w7, uxtw 2]
+        ; stp x29, x30, [sp, -16]!
+        ; movz x0, 0x1234, lsl 16
+        ; mov x1, 0x12340000
+        ; movn w2, 0
+        ; and x0, x1, 0xFF00
+        ; fmov d0, 1.5
+        ; add v0.s4, v1.s4, v2.s4
+        ; addp d1, v2.d2
+        ; ld1 {v0.b16, v1.b16}, [x0]
+        ; mov v0.s[1], w2
+        ; csel x0, x1, x2, eq
+        ; mrs x0, nzcv
+        ; msr tpidr_el0, x1
+        ; dmb ish
+        ; b.ne =>label
+        ; cbz x0, =>label
+        ; b =>label
+        ; adr x1, =>label
+        ; ret
+    );
+    let expected = ops.finalize().unwrap();
+
+    let mut ops = dynasmrt::aarch64::Assembler::new().unwrap();
+    let label = ops.new_dynamic_label();
+    ops.dynamic_label(label);
+    encode(&mut ops, mnem("add"), &[Reg(X(0)), Reg(X(1)), Reg(X(2))]).unwrap();
+    encode(&mut ops, mnem("add"), &[Reg(W(0)), Reg(W(1)), Imm(12)]).unwrap();
+    encode(&mut ops, mnem("add"), &[Reg(X(3)), Reg(XSP(31)), Imm(1), Operand::Modifier(Modifier::LSL, Some(12))]).unwrap();
+    encode(&mut ops, mnem("sub"), &[Reg(X(4)), Reg(X(5)), Reg(X(6)), Operand::Modifier(Modifier::LSR, Some(7))]).unwrap();
+    encode(&mut ops, mnem("add"), &[Reg(X(7)), Reg(X(8)), Reg(W(9)), Operand::Modifier(Modifier::SXTW, Some(2))]).unwrap();
+    encode(&mut ops, mnem("ldr"), &[Reg(X(3)), Mem(MemoryRef::Offset(X(4), 8))]).unwrap();
+    encode(&mut ops, mnem("ldr"), &[Reg(X(3)), Mem(MemoryRef::PreIndexed(X(4), 8))]).unwrap();
+    encode(&mut ops, mnem("ldr"), &[Reg(X(3)), Mem(MemoryRef::Base(X(4))), Imm(8)]).unwrap();
+    encode(&mut ops, mnem("ldr"), &[Reg(W(5)), Mem(MemoryRef::Indexed(X(6), W(7), Some((Modifier::UXTW, Some(2)))))]).unwrap();
+    encode(&mut ops, mnem("stp"), &[Reg(X(29)), Reg(X(30)), Mem(MemoryRef::PreIndexed(XSP(31), -16))]).unwrap();
+    encode(&mut ops, mnem("movz"), &[Reg(X(0)), Imm(0x1234), Operand::Modifier(Modifier::LSL, Some(16))]).unwrap();
+    encode(&mut ops, mnem("mov"), &[Reg(X(1)), Imm(0x12340000)]).unwrap();
+    encode(&mut ops, mnem("mov.inverted"), &[Reg(W(2)), Imm(-1)]).unwrap();
+    encode(&mut ops, mnem("and"), &[Reg(X(0)), Reg(X(1)), Imm(0xFF00)]).unwrap();
+    encode(&mut ops, mnem("fmov"), &[Reg(D(0)), Operand::Float(1.5)]).unwrap();
+    encode(&mut ops, mnem("add"), &[v(0, Size::DWord, Some(4), None), v(1, Size::DWord, Some(4), None), v(2, Size::DWord, Some(4), None)]).unwrap();
+    encode(&mut ops, mnem("addp"), &[Reg(D(1)), v(2, Size::QWord, Some(2), None)]).unwrap();
+    encode(&mut ops, mnem("ld1"), &[Operand::List(Vector { reg: 0, element_size: Size::Byte, lanes: Some(16), element: None }, 2), Mem(MemoryRef::Base(X(0)))]).unwrap();
+    encode(&mut ops, mnem("mov"), &[v(0, Size::DWord, None, Some(1)), Reg(W(2))]).unwrap();
+    encode(&mut ops, mnem("csel"), &[Reg(X(0)), Reg(X(1)), Reg(X(2)), Ident("eq")]).unwrap();
+    encode(&mut ops, mnem("mrs"), &[Reg(X(0)), Ident("nzcv")]).unwrap();
+    encode(&mut ops, mnem("msr"), &[Ident("tpidr_el0"), Reg(X(1))]).unwrap();
+    encode(&mut ops, mnem("dmb"), &[Ident("ish")]).unwrap();
+    encode(&mut ops, mnem("b.ne"), &[Operand::Label(TargetKind::Dynamic(label))]).unwrap();
+    encode(&mut ops, mnem("cbz"), &[Reg(X(0)), Operand::Label(TargetKind::Dynamic(label))]).unwrap();
+    encode(&mut ops, mnem("b"), &[Operand::Label(TargetKind::Dynamic(label))]).unwrap();
+    encode(&mut ops, mnem("adr"), &[Reg(X(1)), Operand::Label(TargetKind::Dynamic(label))]).unwrap();
+    encode(&mut ops, mnem("ret"), &[]).unwrap();
+    let encoded = ops.finalize().unwrap();
+
+    assert_eq!(hex(&encoded), hex(&expected), "aarch64_encoder");
+}
+
+// encoding errors are reported instead of panicking
+#[test]
+fn aarch64_encoder_errors() {
+    use dynasmrt::aarch64::{encode, Mnemonic, Operand, MemoryRef, Modifier, EncodeError};
+    use dynasmrt::aarch64::Register::*;
+    use dynasmrt::aarch64::Operand::{Reg, Imm, Mem, Ident};
+
+    let mut ops = dynasmrt::aarch64::Assembler::new().unwrap();
+    let add = Mnemonic::from_name("add").unwrap();
+
+    assert_eq!("b.xx".parse::<Mnemonic>().unwrap_err(), EncodeError::UnknownMnemonic("b.xx".into()));
+    assert_eq!(encode(&mut ops, add, &[Reg(X(0)), Reg(W(1)), Reg(X(2))]), Err(EncodeError::OperandMismatch("add")));
+    assert_eq!(encode(&mut ops, add, &[Reg(X(0)), Reg(X(1)), Imm(0x1000)]),
+        Err(EncodeError::InvalidOperand("Value too large".into())));
+    assert_eq!(encode(&mut ops, add, &[Reg(X(0)), Reg(X(1)), Imm(-1)]),
+        Err(EncodeError::InvalidOperand("Value too small".into())));
+    assert_eq!(encode(&mut ops, Mnemonic::from_name("ldr").unwrap(), &[Reg(X(0)), Mem(MemoryRef::Offset(X(31), 0))]),
+        Err(EncodeError::InvalidOperand("Base register can only be a Xn|SP register".into())));
+    assert_eq!(encode(&mut ops, Mnemonic::from_name("and").unwrap(), &[Reg(X(0)), Reg(X(1)), Imm(0x1234)]),
+        Err(EncodeError::InvalidOperand("Impossible to encode immediate".into())));
+    assert_eq!(encode(&mut ops, Mnemonic::from_name("msr").unwrap(), &[Ident("midr_el1"), Reg(X(0))]),
+        Err(EncodeError::InvalidOperand("System register 'midr_el1' is read-only".into())));
+    assert_eq!(encode(&mut ops, Mnemonic::from_name("dmb").unwrap(), &[Ident("everything")]),
+        Err(EncodeError::InvalidOperand("Unknown literal 'everything'".into())));
+    assert_eq!(encode(&mut ops, add, &[Reg(X(0)), Reg(X(1)), Reg(X(2)), Operand::Modifier(Modifier::LSL, None)]),
+        Err(EncodeError::InvalidOperand("LSL, LSR, ASR, ROR and MSL modifiers require a shift immediate.".into())));
+
+    // SVE and SME forms are not supported, but other forms of the same instruction are
+    assert_eq!(encode(&mut ops, Mnemonic::from_name("ptrue").unwrap(), &[Ident("p0"), Ident("all")]),
+        Err(EncodeError::Unsupported("SVE and SME forms of 'ptrue' cannot be encoded at runtime".into())));
+    assert_eq!(encode(&mut ops, Mnemonic::from_name("whilelo").unwrap(), &[Reg(X(0)), Reg(X(1))]),
+        Err(EncodeError::Unsupported("SVE and SME forms of 'whilelo' cannot be encoded at runtime".into())));
+    assert_eq!(encode(&mut ops, add, &[Ident("z0"), Ident("z1"), Ident("z2")]),
+        Err(EncodeError::Unsupported("SVE and SME forms of 'add' cannot be encoded at runtime".into())));
+    assert_eq!(encode(&mut ops, add, &[Reg(X(0)), Reg(X(1))]), Err(EncodeError::OperandMismatch("add")));
+    assert_eq!(ops.offset().0, 0);
+}
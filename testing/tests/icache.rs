@@ -0,0 +1,71 @@
+extern crate dynasmrt;
+
+use dynasmrt::{DynasmApi, Assembler};
+use dynasmrt::relocations::{Relocation, RelocationSize, RelocationKind, ImpossibleRelocation};
+
+use std::sync::Mutex;
+
+static FLUSHED: Mutex<Vec<(usize, usize)>> = Mutex::new(Vec::new());
+
+// a relocation type for an imaginary architecture that records the cache maintenance it is asked to perform
+#[derive(Debug, Clone)]
+struct Recording(RelocationSize);
+
+impl Relocation for Recording {
+    type Encoding = u8;
+    fn from_encoding(encoding: Self::Encoding) -> Self {
+        Recording(RelocationSize::from_encoding(encoding))
+    }
+    fn from_size(size: RelocationSize) -> Self {
+        Recording(size)
+    }
+    fn size(&self) -> usize {
+        self.0.size()
+    }
+    fn write_value(&self, buf: &mut [u8], value: isize) -> Result<(), ImpossibleRelocation> {
+        self.0.write_value(buf, value)
+    }
+    fn read_value(&self, buf: &[u8]) -> isize {
+        self.0.read_value(buf)
+    }
+    fn kind(&self) -> RelocationKind {
+        RelocationKind::Relative
+    }
+    fn page_size() -> usize {
+        4096
+    }
+    fn flush_icache(code: &[u8]) {
+        FLUSHED.lock().unwrap().push((code.as_ptr() as usize, code.len()));
+    }
+}
+
+// committing and altering code flushes the code that changed
+#[test]
+fn flush_on_commit_and_alter() {
+    let mut ops: Assembler<Recording> = Assembler::new().unwrap();
+    let start = ops.reader().lock().as_ptr() as usize;
+
+    ops.extend(&[1, 2, 3]);
+    ops.commit().unwrap();
+    assert_eq!(*FLUSHED.lock().unwrap(), [(start, 3)]);
+
+    ops.extend(&[4, 5]);
+    ops.commit().unwrap();
+    assert_eq!(*FLUSHED.lock().unwrap(), [(start, 3), (start + 3, 2)]);
+
+    ops.alter(|modifier| modifier.push(6)).unwrap();
+    assert_eq!(*FLUSHED.lock().unwrap(), [(start, 3), (start + 3, 2), (start, 5)]);
+
+    let buf = ops.finalize().unwrap();
+    assert_eq!(&*buf, &[6, 2, 3, 4, 5]);
+}
+
+// flushing is always possible on the host, even when it is a no-op
+#[test]
+fn flush_aarch64() {
+    let mut ops = dynasmrt::aarch64::Assembler::new().unwrap();
+    ops.extend(&[0xC0, 0x03, 0x5F, 0xD6]);
+    let buf = ops.finalize().unwrap();
+    dynasmrt::aarch64::flush_icache(&buf);
+    <dynasmrt::aarch64::Aarch64Relocation as Relocation>::flush_icache(&buf[.. 0]);
+}
@@ -0,0 +1,173 @@
+#![feature(proc_macro_hygiene)]
+#![allow(unused_imports)]
+
+extern crate dynasmrt;
+extern crate dynasm;
+
+use dynasm::dynasm;
+use dynasmrt::{DynasmApi, DynasmLabelApi, AssemblyOffset, LabelKind, TargetKind};
+use dynasmrt::components::ListingLine;
+
+fn lines(listing: &[ListingLine]) -> Vec<String> {
+    listing.iter().map(ToString::to_string).collect()
+}
+
+// listings are split into instructions and annotated with labels and relocation targets
+#[test]
+fn x64_listing() {
+    let mut ops = dynasmrt::x64::Assembler::new().unwrap();
+    ops.set_debug(true);
+    let func = ops.new_dynamic_label();
+    dynasm!(ops
+        ; .arch x64
+        ; ->entry:
+        ; mov eax, 1
+        ; repeat:
+        ; dec eax
+        ; jnz <repeat
+        ; call =>func
+        ; ret
+        ; =>func
+        ; ret
+        ; end:
+    );
+
+    // globals and dynamics are resolved when committing
+    let listing = ops.listing();
+    assert_eq!(listing[3].bytes, [0xE8, 0x00, 0x00, 0x00, 0x00]);
+
+    ops.commit().unwrap();
+    let listing = ops.listing();
+    assert_eq!(lines(&listing), [
+        "00000000  B8 01 00 00 00                 ; label ->entry",
+        "00000005  FF C8                          ; label repeat",
+        "00000007  0F 85 F8 FF FF FF              ; target <repeat",
+        "0000000D  E8 01 00 00 00                 ; target =>0",
+        "00000012  C3",
+        "00000013  C3                             ; label =>0",
+        "00000014                                 ; label end",
+    ]);
+    assert_eq!(listing[3].offset, AssemblyOffset(13));
+    assert_eq!(listing[3].targets, [TargetKind::Dynamic(func)]);
+    assert_eq!(listing[5].labels, [LabelKind::Dynamic(func)]);
+    assert!(listing[6].bytes.is_empty());
+
+    // the listing covers uncommitted code following committed code
+    dynasm!(ops
+        ; .arch x64
+        ; jmp <end
+    );
+    let listing = ops.listing();
+    assert_eq!(listing.len(), 7);
+    assert_eq!(lines(&listing[6 ..]), ["00000014  E9 FB FF FF FF                 ; label end, target <end"]);
+}
+
+// without debug mode, listings are only split into instructions
+#[test]
+fn listing_without_debug() {
+    let mut ops = dynasmrt::x64::Assembler::new().unwrap();
+    dynasm!(ops
+        ; .arch x64
+        ; ->entry:
+        ; movabs rax, 0x1122334455667788
+        ; vpaddd xmm0, xmm9, xmm15
+        ; mov QWORD [rbx + rcx * 4 + 0x100], 0x7F
+        ; jmp ->entry
+    );
+
+    let listing = ops.listing();
+    let lengths: Vec<usize> = listing.iter().map(|l| l.bytes.len()).collect();
+    assert_eq!(lengths, [10, 5, 12, 5]);
+    assert!(listing.iter().all(|l| l.labels.is_empty() && l.targets.is_empty()));
+}
+
+// relaxation moves the recorded labels and relocations along with the code
+#[test]
+fn listing_relaxed() {
+    let mut ops = dynasmrt::x64::Assembler::new().unwrap();
+    ops.set_debug(true);
+    ops.set_relaxation(true).unwrap();
+    dynasm!(ops
+        ; .arch x64
+        ; jmp >done
+    );
+    for _ in 0 .. 200 {
+        dynasm!(ops
+            ; .arch x64
+            ; nop
+        );
+    }
+    dynasm!(ops
+        ; .arch x64
+        ; done:
+        ; ret
+    );
+    ops.commit().unwrap();
+
+    let listing = ops.listing();
+    assert_eq!(listing.len(), 202);
+    assert_eq!(listing[0].bytes, [0xE9, 0xC8, 0x00, 0x00, 0x00]);
+    assert_eq!(listing[0].targets, [TargetKind::Forward("done")]);
+    assert_eq!(listing[201].offset, AssemblyOffset(205));
+    assert_eq!(listing[201].labels, [LabelKind::Local("done")]);
+}
+
+// code emitted while altering is recorded as well
+#[test]
+fn listing_alter() {
+    let mut ops = dynasmrt::x64::Assembler::new().unwrap();
+    ops.set_debug(true);
+    dynasm!(ops
+        ; .arch x64
+        ; ->entry:
+        ; nop
+        ; nop
+        ; nop
+        ; nop
+        ; nop
+        ; nop
+        ; ret
+    );
+    ops.commit().unwrap();
+
+    ops.alter(|modifier| {
+        modifier.goto(AssemblyOffset(1));
+        dynasm!(modifier
+            ; .arch x64
+            ; jmp ->entry
+        );
+    }).unwrap();
+
+    assert_eq!(lines(&ops.listing()), [
+        "00000000  90                             ; label ->entry",
+        "00000001  E9 FA FF FF FF                 ; target ->entry",
+        "00000006  C3",
+    ]);
+
+    ops.set_debug(false);
+    assert!(ops.listing().iter().all(|l| l.labels.is_empty() && l.targets.is_empty()));
+}
+
+// aarch64 instructions are always four bytes
+#[test]
+fn aarch64_listing() {
+    let mut ops = dynasmrt::aarch64::Assembler::new().unwrap();
+    ops.set_debug(true);
+    dynasm!(ops
+        ; .arch aarch64
+        ; ->entry:
+        ; add x0, x1, x2
+        ; b.ne >skip
+        ; adr x1, ->entry
+        ; skip:
+        ; ret
+    );
+    ops.commit().unwrap();
+
+    assert_eq!(lines(&ops.listing()), [
+        "00000000  20 00 02 8B                    ; label ->entry",
+        "00000004  41 00 00 54                    ; target >skip",
+        "00000008  C1 FF FF 10                    ; target ->entry",
+        "0000000C  C0 03 5F D6                    ; label skip",
+    ]);
+}
@@ -0,0 +1,278 @@
+#![feature(proc_macro_hygiene)]
+#![allow(unused_imports)]
+
+#[macro_use]
+extern crate dynasmrt;
+extern crate dynasm;
+
+use dynasm::dynasm;
+use dynasmrt::{DynasmApi, DynasmLabelApi};
+
+// integer arithmetic, logic and shifts
+#[test]
+fn base() {
+    let mut ops = dynasmrt::loongarch::Assembler::new().unwrap();
+    dynasm!(ops
+        ; .arch loongarch64
+        ; add.d a0, a1, a2
+        ; sub.w t0, t1, t2
+        ; addi.d sp, sp, -16
+        ; addi.w a0, zero, 2047
+        ; addu16i.d a0, a1, -1
+        ; lu12i.w t0, 0x12345
+        ; lu32i.d t0, -1
+        ; lu52i.d t0, t0, 0x7FF
+        ; slt a0, a1, a2
+        ; sltui a0, a1, 1
+        ; and s0, s1, s2
+        ; orn a0, a1, a2
+        ; andi a0, a0, 0xFFF
+        ; xori a0, a0, 1
+        ; slli.d a0, a0, 3
+        ; srai.w a1, a2, 31
+        ; rotri.d a0, a1, 63
+        ; sll.w a0, a1, a2
+        ; alsl.d a0, a1, a2, 3
+        ; bstrpick.d a0, a0, 31, 0
+        ; bstrins.w a0, a1, 15, 8
+        ; mul.d a0, a1, a2
+        ; mulh.du a0, a1, a2
+        ; div.w a0, a1, a2
+        ; mod.du a0, a1, a2
+        ; maskeqz a0, a1, a2
+        ; clz.d a0, a1
+        ; revb.d a0, a1
+        ; ext.w.b a0, a1
+        ; bytepick.d a0, a1, a2, 7
+        ; syscall 0
+        ; break 5
+    );
+    let buf = ops.finalize().unwrap();
+    let hex: Vec<String> = buf.iter().map(|x| format!("{:02X}", *x)).collect();
+    let hex = hex.join(", ");
+    assert_eq!(hex, "A4, 98, 10, 00, AC, 39, 11, 00, 63, C0, FF, 02, 04, FC, 9F, 02, A4, FC, FF, 13, AC, 68, 24, 14, EC, FF, FF, 17, 8C, FD, 1F, 03, A4, 18, 12, 00, A4, 04, 40, 02, 17, E7, 14, 00, A4, 18, 16, 00, 84, FC, 7F, 03, 84, 04, C0, 03, 84, 0C, 41, 00, C5, FC, 48, 00, A4, FC, 4D, 00, A4, 18, 17, 00, A4, 18, 2D, 00, 84, 00, DF, 00, A4, 20, 6F, 00, A4, 98, 1D, 00, A4, 98, 1E, 00, A4, 18, 20, 00, A4, 98, 23, 00, A4, 18, 13, 00, A4, 24, 00, 00, A4, 3C, 00, 00, A4, 5C, 00, 00, A4, 98, 0F, 00, 00, 00, 2B, 00, 05, 00, 2A, 00", "base");
+}
+
+// loads and stores, with and without offsets
+#[test]
+fn memory() {
+    let mut ops = dynasmrt::loongarch::Assembler::new().unwrap();
+    dynasm!(ops
+        ; .arch loongarch64
+        ; ld.d a0, sp, 8
+        ; ld.w a1, a0
+        ; ld.bu a2, a3, -2048
+        ; ld.hu a4, a5, 2047
+        ; st.d ra, sp, 8
+        ; st.b a0, a1, -1
+        ; ldx.d a0, a1, a2
+        ; stx.w a0, a1, a2
+        ; ldptr.d a0, a1, 0x7FFC
+        ; stptr.w a0, sp, -32768
+        ; ldgt.d a0, a1, a2
+        ; preld 0, a0, 64
+    );
+    let buf = ops.finalize().unwrap();
+    let hex: Vec<String> = buf.iter().map(|x| format!("{:02X}", *x)).collect();
+    let hex = hex.join(", ");
+    assert_eq!(hex, "64, 20, C0, 28, 85, 00, 80, 28, E6, 00, 20, 2A, 28, FD, 5F, 2A, 61, 20, C0, 29, A4, FC, 3F, 29, A4, 18, 0C, 38, A4, 18, 18, 38, A4, FC, 7F, 26, 64, 00, 80, 25, A4, 98, 79, 38, 80, 00, C1, 2A", "memory");
+}
+
+// load linked / store conditional, atomic memory operations and barriers
+#[test]
+fn atomics() {
+    let mut ops = dynasmrt::loongarch::Assembler::new().unwrap();
+    dynasm!(ops
+        ; .arch loongarch64
+        ; ll.d a0, a1, 8
+        ; sc.w a2, a1
+        ; amswap.d a0, a1, a2
+        ; amadd_db.w a0, a1, a2
+        ; ammax.du t0, t1, t2
+        ; dbar 0
+        ; ibar 0
+    );
+    let buf = ops.finalize().unwrap();
+    let hex: Vec<String> = buf.iter().map(|x| format!("{:02X}", *x)).collect();
+    let hex = hex.join(", ");
+    assert_eq!(hex, "A4, 08, 00, 22, A6, 00, 00, 21, C4, 94, 60, 38, C4, 14, 6A, 38, CC, B5, 67, 38, 00, 00, 72, 38, 00, 80, 72, 38", "atomics");
+}
+
+// floating point arithmetic, conversions, comparisons and moves
+#[test]
+fn fp() {
+    let mut ops = dynasmrt::loongarch::Assembler::new().unwrap();
+    dynasm!(ops
+        ; .arch loongarch64
+        ; fadd.d fa0, fa0, fa1
+        ; fmul.s ft0, ft1, ft2
+        ; fmadd.d fa0, fa1, fa2, fa3
+        ; fsqrt.s f0, f1
+        ; fneg.d fa0, fa1
+        ; fmov.d fs0, fa0
+        ; fcvt.d.s fa0, fa1
+        ; ffint.d.l fa0, fa1
+        ; ftintrz.w.d fa0, fa1
+        ; fcmp.clt.d fcc0, fa0, fa1
+        ; fcmp.sueq.s fcc7, f2, f3
+        ; fsel fa0, fa1, fa2, fcc1
+        ; movgr2fr.d fa0, a0
+        ; movfr2gr.s a0, fa0
+        ; movgr2fcsr fcsr0, a0
+        ; movfcsr2gr a0, fcsr3
+        ; movfr2cf fcc2, fa0
+        ; movcf2gr a0, fcc3
+        ; fld.d fa0, sp, 8
+        ; fst.s fa1, a0, -4
+        ; fldx.d fa0, a0, a1
+    );
+    let buf = ops.finalize().unwrap();
+    let hex: Vec<String> = buf.iter().map(|x| format!("{:02X}", *x)).collect();
+    let hex = hex.join(", ");
+    assert_eq!(hex, "00, 04, 01, 01, 28, A9, 04, 01, 20, 88, 21, 08, 20, 44, 14, 01, 20, 18, 14, 01, 18, 98, 14, 01, 20, 24, 19, 01, 20, 28, 1D, 01, 20, 88, 1A, 01, 00, 04, 21, 0C, 47, 8C, 16, 0C, 20, 88, 00, 0D, 80, A8, 14, 01, 04, B4, 14, 01, 80, C0, 14, 01, 64, C8, 14, 01, 02, D0, 14, 01, 64, DC, 14, 01, 60, 20, 80, 2B, 81, F0, 7F, 2B, 80, 14, 34, 38", "fp");
+}
+
+// pseudo instructions assemble to the instructions they stand for
+#[test]
+fn pseudos() {
+    let mut ops = dynasmrt::loongarch::Assembler::new().unwrap();
+    dynasm!(ops
+        ; .arch loongarch64
+        ; nop
+        ; move a0, a1
+        ; ret
+        ; jr t0
+        ; jirl ra, t0, 16
+        ; jirl zero, ra
+    );
+    let buf = ops.finalize().unwrap();
+    let hex: Vec<String> = buf.iter().map(|x| format!("{:02X}", *x)).collect();
+    let hex = hex.join(", ");
+    assert_eq!(hex, "00, 00, 40, 03, A4, 00, 15, 00, 20, 00, 00, 4C, 80, 01, 00, 4C, 81, 11, 00, 4C, 20, 00, 00, 4C", "pseudos");
+}
+
+// branches to labels
+#[test]
+fn labels() {
+    let mut ops = dynasmrt::loongarch::Assembler::new().unwrap();
+    dynasm!(ops
+        ; .arch loongarch64
+        ; start:
+        ; beq a0, a1, >fwd
+        ; bl <start
+        ; bnez a0, >fwd
+        ; bceqz fcc1, <start
+        ; bgt a0, a1, <start
+        ; pcaddi a0, >fwd
+        ; b >fwd
+        ; fwd:
+        ; bltu t0, t1, <start
+    );
+    let buf = ops.finalize().unwrap();
+    let hex: Vec<String> = buf.iter().map(|x| format!("{:02X}", *x)).collect();
+    let hex = hex.join(", ");
+    assert_eq!(hex, "85, 1C, 00, 58, FF, FF, FF, 57, 80, 14, 00, 44, 3F, F4, FF, 4B, A4, F0, FF, 63, 44, 00, 00, 18, 00, 04, 00, 50, 8D, E5, FF, 6B", "labels");
+}
+
+// pcaddu12i and pcaddu18i pairs for label addresses, loads, stores and far calls
+#[test]
+fn label_pairs() {
+    let mut ops = dynasmrt::loongarch::Assembler::new().unwrap();
+    dynasm!(ops
+        ; .arch loongarch64
+        ; start:
+        ; call36 >func
+        ; la.pcrel a0, >data
+        ; ld.d a1, >data
+        ; st.w a2, >data, t0
+        ; func:
+        ; ret
+        ; data:
+        ; tail36 t0, <data
+    );
+    let buf = ops.finalize().unwrap();
+    let hex: Vec<String> = buf.iter().map(|x| format!("{:02X}", *x)).collect();
+    let hex = hex.join(", ");
+    assert_eq!(hex, "01, 00, 00, 1E, 21, 20, 00, 4C, 04, 00, 00, 1C, 84, 70, C0, 02, 05, 00, 00, 1C, A5, 50, C0, 28, 0C, 00, 00, 1C, 86, 31, 80, 29, 20, 00, 00, 4C, 0C, 00, 00, 1E, 80, 01, 00, 4C", "label_pairs");
+}
+
+// the upper part of a pcaddu12i pair is rounded so the sign extended lower part adds up to the offset
+#[test]
+fn label_pairs_far() {
+    let mut ops = dynasmrt::loongarch::Assembler::new().unwrap();
+    let far = ops.new_dynamic_label();
+    dynasm!(ops
+        ; .arch loongarch64
+        ; la.pcrel a0, =>far
+        ; .bytes vec![0u8; 0x1804]
+        ; =>far
+    );
+    let buf = ops.finalize().unwrap();
+    assert_eq!(&buf[.. 8], &[0x44, 0x00, 0x00, 0x1C, 0x84, 0x30, 0xE0, 0x02]);
+}
+
+// relocations in data are relative to the start of the data
+#[test]
+fn data_relocations() {
+    let mut ops = dynasmrt::loongarch::Assembler::new().unwrap();
+    dynasm!(ops
+        ; .arch loongarch64
+        ; start:
+        ; nop
+        ; .dword <start
+        ; .qword <start
+    );
+    let buf = ops.finalize().unwrap();
+    let hex: Vec<String> = buf.iter().map(|x| format!("{:02X}", *x)).collect();
+    let hex = hex.join(", ");
+    assert_eq!(hex, "00, 00, 40, 03, FC, FF, FF, FF, F8, FF, FF, FF, FF, FF, FF, FF", "data_relocations");
+}
+
+// registers and immediates can be chosen at runtime
+#[test]
+fn dynamic() {
+    let r = 4;
+    let f = 1;
+    let cc = 2;
+    let imm = -5;
+    let shamt = 3;
+    let offset = 0x1234;
+    let mut ops = dynasmrt::loongarch::Assembler::new().unwrap();
+    dynasm!(ops
+        ; .arch loongarch64
+        ; add.d R(r), R(r + 1), a2
+        ; addi.d R(r), R(3), imm
+        ; fadd.d F(f), F(f), fa2
+        ; fcmp.ceq.d FCC(cc), F(f), F(0)
+        ; ld.d R(r), sp, 8 * shamt
+        ; slli.d a0, a0, shamt
+        ; alsl.w a0, a1, a2, shamt
+        ; ll.w a0, a1, 4 * shamt
+        ; beq a0, a1, offset
+        ; b offset
+        ; la.pcrel a0, offset
+    );
+    let buf = ops.finalize().unwrap();
+    let hex: Vec<String> = buf.iter().map(|x| format!("{:02X}", *x)).collect();
+    let hex = hex.join(", ");
+    assert_eq!(hex, "A4, 98, 10, 00, 64, EC, FF, 02, 21, 08, 01, 01, 22, 00, 22, 0C, 64, 60, C0, 28, 84, 0C, 41, 00, A4, 18, 05, 00, A4, 0C, 00, 20, 85, 34, 12, 58, 00, 34, 12, 50, 24, 00, 00, 1C, 84, D0, C8, 02", "dynamic");
+}
+
+// floating point instructions can only be used after they have been declared with .feature
+#[test]
+fn features() {
+    let mut ops = dynasmrt::loongarch::Assembler::new().unwrap();
+    dynasm!(ops
+        ; .arch loongarch64
+        ; .feature none
+        ; add.d a0, a1, a2
+        ; .feature f
+        ; fadd.s fa0, fa0, fa1
+        ; .feature d
+        ; fadd.d fa0, fa0, fa1
+    );
+    let buf = ops.finalize().unwrap();
+    let hex: Vec<String> = buf.iter().map(|x| format!("{:02X}", *x)).collect();
+    let hex = hex.join(", ");
+    assert_eq!(hex, "A4, 98, 10, 00, 00, 84, 00, 01, 00, 04, 01, 01", "features");
+}
@@ -0,0 +1,208 @@
+#![feature(proc_macro_hygiene)]
+#![allow(unused_imports)]
+
+extern crate dynasmrt;
+extern crate dynasm;
+
+use dynasm::dynasm;
+use dynasmrt::{DynasmApi, DynasmLabelApi};
+
+use std::path::PathBuf;
+
+#[cfg(target_os = "linux")]
+use dynasmrt::perf::{Profiler, PerfMap, JitDump};
+
+fn read_u32(buf: &[u8]) -> u32 {
+    let mut bytes = [0u8; 4];
+    bytes.copy_from_slice(&buf[.. 4]);
+    u32::from_ne_bytes(bytes)
+}
+
+fn read_u64(buf: &[u8]) -> u64 {
+    let mut bytes = [0u8; 8];
+    bytes.copy_from_slice(&buf[.. 8]);
+    u64::from_ne_bytes(bytes)
+}
+
+fn temp_path(name: &str) -> PathBuf {
+    std::env::temp_dir().join(format!("dynasm-{}-{}", std::process::id(), name))
+}
+
+// parse the entries of a perf map
+fn parse_map(path: &PathBuf) -> Vec<(usize, usize, String)> {
+    let map = std::fs::read_to_string(path).unwrap();
+    map.lines().map(|line| {
+        let mut parts = line.splitn(3, ' ');
+        let addr = usize::from_str_radix(parts.next().unwrap(), 16).unwrap();
+        let size = usize::from_str_radix(parts.next().unwrap(), 16).unwrap();
+        (addr, size, parts.next().unwrap().to_string())
+    }).collect()
+}
+
+#[derive(Debug, PartialEq)]
+enum Record {
+    Load { addr: usize, index: u64, name: String, code: Vec<u8> },
+    Move { old_addr: usize, new_addr: usize, size: usize, index: u64 },
+}
+
+// parse the records of a jitdump file, checking the header
+fn parse_jitdump(path: &PathBuf) -> Vec<Record> {
+    let dump = std::fs::read(path).unwrap();
+    assert_eq!(read_u32(&dump[0 ..]), 0x4A69_5444);
+    assert_eq!(read_u32(&dump[4 ..]), 1);
+    assert_eq!(read_u32(&dump[20 ..]), std::process::id());
+
+    let mut records = Vec::new();
+    let mut pos = read_u32(&dump[8 ..]) as usize;
+    while pos < dump.len() {
+        let record = &dump[pos .. pos + read_u32(&dump[pos + 4 ..]) as usize];
+        assert_eq!(read_u32(&record[16 ..]), std::process::id());
+        match read_u32(record) {
+            0 => {
+                let size = read_u64(&record[40 ..]) as usize;
+                let name_len = record[56 ..].iter().position(|&b| b == 0).unwrap();
+                assert_eq!(record.len(), 56 + name_len + 1 + size);
+                records.push(Record::Load {
+                    addr: read_u64(&record[32 ..]) as usize,
+                    index: read_u64(&record[48 ..]),
+                    name: String::from_utf8(record[56 .. 56 + name_len].to_vec()).unwrap(),
+                    code: record[56 + name_len + 1 ..].to_vec(),
+                });
+            },
+            1 => {
+                assert_eq!(record.len(), 64);
+                records.push(Record::Move {
+                    old_addr: read_u64(&record[32 ..]) as usize,
+                    new_addr: read_u64(&record[40 ..]) as usize,
+                    size: read_u64(&record[48 ..]) as usize,
+                    index: read_u64(&record[56 ..]),
+                });
+            },
+            id => panic!("unexpected record {}", id)
+        }
+        pos += record.len();
+    }
+    records
+}
+
+// global labels and named dynamic labels start regions when they are committed
+#[cfg(all(target_os = "linux", target_arch = "x86_64"))]
+#[test]
+fn perf_regions() {
+    let map_path = temp_path("regions.map");
+    let dump_path = temp_path("regions.dump");
+
+    let mut ops = dynasmrt::x64::Assembler::new().unwrap();
+    ops.set_profiler(Some(Profiler::new(Some(PerfMap::open(&map_path).unwrap()), Some(JitDump::create(&dump_path).unwrap()))));
+
+    let helper = ops.new_dynamic_label();
+    let unnamed = ops.new_dynamic_label();
+    ops.name_dynamic_label(helper, "helper");
+    dynasm!(ops
+        ; .arch x64
+        ; ->entry:
+        ; mov eax, 1
+        ; call =>helper
+        ; ret
+        ; =>unnamed
+        ; =>helper
+        ; mov eax, 2
+        ; ret
+    );
+    ops.commit().unwrap();
+
+    let addr = ops.reader().lock().as_ptr() as usize;
+    assert_eq!(parse_map(&map_path), [
+        (addr, 11, "entry".to_string()),
+        (addr + 11, 6, "helper".to_string()),
+    ]);
+
+    let code = ops.reader().lock().to_vec();
+    assert_eq!(parse_jitdump(&dump_path), [
+        Record::Load { addr, index: 0, name: "entry".to_string(), code: code[.. 11].to_vec() },
+        Record::Load { addr: addr + 11, index: 1, name: "helper".to_string(), code: code[11 ..].to_vec() },
+    ]);
+
+    let mut profiler = ops.set_profiler(None).unwrap();
+    assert!(profiler.take_error().is_none());
+
+    std::fs::remove_file(&map_path).unwrap();
+    std::fs::remove_file(&dump_path).unwrap();
+}
+
+// code that moves when the buffer grows gets code move records
+#[cfg(all(target_os = "linux", target_arch = "x86_64"))]
+#[test]
+fn perf_code_move() {
+    let map_path = temp_path("move.map");
+    let dump_path = temp_path("move.dump");
+
+    let mut ops = dynasmrt::x64::Assembler::new().unwrap();
+    ops.set_profiler(Some(Profiler::new(Some(PerfMap::open(&map_path).unwrap()), Some(JitDump::create(&dump_path).unwrap()))));
+
+    // a named dynamic label that only gets defined in a later commit
+    let later = ops.new_dynamic_label();
+    ops.name_dynamic_label(later, "later");
+    dynasm!(ops
+        ; .arch x64
+        ; ->first:
+        ; ret
+    );
+    ops.commit().unwrap();
+    let old_addr = ops.reader().lock().as_ptr() as usize;
+
+    dynasm!(ops
+        ; .arch x64
+        ; =>later
+    );
+    for _ in 0 .. 5000 {
+        dynasm!(ops
+            ; .arch x64
+            ; nop
+        );
+    }
+    ops.commit().unwrap();
+    let new_addr = ops.reader().lock().as_ptr() as usize;
+    assert_ne!(old_addr, new_addr);
+
+    assert_eq!(parse_map(&map_path), [
+        (old_addr, 1, "first".to_string()),
+        (new_addr, 1, "first".to_string()),
+        (new_addr + 1, 5000, "later".to_string()),
+    ]);
+
+    let records = parse_jitdump(&dump_path);
+    assert_eq!(records.len(), 3);
+    assert_eq!(records[0], Record::Load { addr: old_addr, index: 0, name: "first".to_string(), code: vec![0xC3] });
+    assert_eq!(records[1], Record::Move { old_addr, new_addr, size: 1, index: 0 });
+    match records[2] {
+        Record::Load { addr, index, ref name, ref code } => {
+            assert_eq!((addr, index, name.as_str(), code.len()), (new_addr + 1, 1, "later", 5000));
+        },
+        ref r => panic!("unexpected record {:?}", r)
+    }
+
+    std::fs::remove_file(&map_path).unwrap();
+    std::fs::remove_file(&dump_path).unwrap();
+}
+
+// whole buffers can be written as a single region
+#[cfg(all(target_os = "linux", target_arch = "x86_64"))]
+#[test]
+fn perf_buffer() {
+    let map_path = temp_path("buffer.map");
+
+    let mut ops = dynasmrt::x64::Assembler::new().unwrap();
+    dynasm!(ops
+        ; .arch x64
+        ; mov eax, 1
+        ; ret
+    );
+    let buf = ops.finalize().unwrap();
+
+    let mut profiler = Profiler::new(Some(PerfMap::open(&map_path).unwrap()), None);
+    profiler.write_buffer("jit code", &buf).unwrap();
+    assert_eq!(parse_map(&map_path), [(buf.as_ptr() as usize, 6, "jit code".to_string())]);
+
+    std::fs::remove_file(&map_path).unwrap();
+}
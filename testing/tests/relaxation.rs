@@ -0,0 +1,320 @@
+#![feature(proc_macro_hygiene)]
+#![allow(unused_imports)]
+
+extern crate dynasmrt;
+extern crate dynasm;
+
+use dynasm::dynasm;
+use dynasmrt::{DynasmApi, DynasmLabelApi, AssemblyOffset};
+
+fn nops(ops: &mut dynasmrt::x64::Assembler, count: usize) {
+    for _ in 0 .. count {
+        dynasm!(ops
+            ; .arch x64
+            ; nop
+        );
+    }
+}
+
+// jumps that can reach their target stay short
+#[test]
+fn relax_short() {
+    let mut ops = dynasmrt::x64::Assembler::new().unwrap();
+    ops.set_relaxation(true).unwrap();
+    dynasm!(ops
+        ; .arch x64
+        ; jmp >done
+        ; jz >done
+        ; nop
+        ; done:
+        ; jnz <done
+        ; ret
+    );
+    let buf = ops.finalize().unwrap();
+    assert_eq!(&*buf, &[0xEB, 0x03, 0x74, 0x01, 0x90, 0x75, 0xFE, 0xC3]);
+}
+
+// jumps that cannot reach their target are widened
+#[test]
+fn relax_widen() {
+    let mut ops = dynasmrt::x64::Assembler::new().unwrap();
+    ops.set_relaxation(true).unwrap();
+    dynasm!(ops
+        ; .arch x64
+        ; back:
+        ; jmp >done
+        ; jnz >done
+    );
+    nops(&mut ops, 200);
+    dynasm!(ops
+        ; .arch x64
+        ; done:
+        ; jl <back
+    );
+    let buf = ops.finalize().unwrap();
+    assert_eq!(&buf[.. 11], &[0xE9, 0xCE, 0x00, 0x00, 0x00, 0x0F, 0x85, 0xC8, 0x00, 0x00, 0x00]);
+    assert_eq!(&buf[211 ..], &[0x0F, 0x8C, 0x27, 0xFF, 0xFF, 0xFF]);
+}
+
+// widening a jump can push other jumps out of range
+#[test]
+fn relax_cascade() {
+    let mut ops = dynasmrt::x64::Assembler::new().unwrap();
+    ops.set_relaxation(true).unwrap();
+    dynasm!(ops
+        ; .arch x64
+        ; jmp >first
+        ; jmp >second
+    );
+    nops(&mut ops, 125);
+    dynasm!(ops
+        ; .arch x64
+        ; first:
+    );
+    nops(&mut ops, 200);
+    dynasm!(ops
+        ; .arch x64
+        ; second:
+    );
+    let buf = ops.finalize().unwrap();
+    assert_eq!(&buf[.. 10], &[0xE9, 0x82, 0x00, 0x00, 0x00, 0xE9, 0x45, 0x01, 0x00, 0x00]);
+    assert_eq!(buf.len(), 335);
+}
+
+// labels, offsets and other relocations move along with relaxed code
+#[test]
+fn relax_labels() {
+    let mut ops = dynasmrt::x64::Assembler::new().unwrap();
+    ops.set_relaxation(true).unwrap();
+    let dynamic = ops.new_dynamic_label();
+    dynasm!(ops
+        ; .arch x64
+        ; lea rax, [->data]
+        ; jmp =>dynamic
+    );
+    nops(&mut ops, 200);
+    let before = ops.offset();
+    dynasm!(ops
+        ; .arch x64
+        ; =>dynamic
+        ; ->data:
+        ; .qword 0
+    );
+    assert_eq!(before, AssemblyOffset(209));
+
+    ops.commit().unwrap();
+    assert_eq!(ops.relaxed_offset(before), AssemblyOffset(212));
+    assert_eq!(ops.labels().resolve_global("data"), Ok(AssemblyOffset(212)));
+    assert_eq!(ops.labels().resolve_dynamic(dynamic), Ok(AssemblyOffset(212)));
+
+    // code committed later is not affected by earlier relaxation, which is forgotten
+    let after = ops.offset();
+    dynasm!(ops
+        ; .arch x64
+        ; jmp ->data
+    );
+    ops.commit().unwrap();
+    assert_eq!(ops.relaxed_offset(after), after);
+    assert_eq!(ops.relaxed_offset(before), before);
+
+    let buf = ops.finalize().unwrap();
+    assert_eq!(&buf[.. 12], &[0x48, 0x8D, 0x05, 0xCD, 0x00, 0x00, 0x00, 0xE9, 0xC8, 0x00, 0x00, 0x00]);
+    assert_eq!(&buf[220 ..], &[0xEB, 0xF6]);
+}
+
+// only branch instructions are shrunk, not data that looks like one
+#[test]
+fn relax_data() {
+    let mut ops = dynasmrt::x64::Assembler::new().unwrap();
+    ops.set_relaxation(true).unwrap();
+    dynasm!(ops
+        ; .arch x64
+        ; .bytes [0xE9]
+        ; .dword >done
+        ; .bytes [0x0F, 0x84]
+        ; .dword >done
+        ; done:
+    );
+    let buf = ops.finalize().unwrap();
+    assert_eq!(&buf[..], &[0xE9, 0x06, 0x00, 0x00, 0x00, 0x0F, 0x84, 0x00, 0x00, 0x00, 0x00]);
+}
+
+// alignment is preserved when code before it grows
+#[test]
+fn relax_align() {
+    let mut ops = dynasmrt::x64::Assembler::new().unwrap();
+    ops.set_relaxation(true).unwrap();
+    dynasm!(ops
+        ; .arch x64
+        ; jmp >done
+        ; .align 8
+        ; data:
+        ; .qword 0
+    );
+    nops(&mut ops, 200);
+    dynasm!(ops
+        ; .arch x64
+        ; done:
+        ; lea rax, [<data]
+    );
+    let buf = ops.finalize().unwrap();
+    assert_eq!(&buf[.. 16], &[0xE9, 0xD3, 0x00, 0x00, 0x00, 0x90, 0x90, 0x90, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]);
+    assert_eq!(&buf[216 ..], &[0x48, 0x8D, 0x05, 0x29, 0xFF, 0xFF, 0xFF]);
+}
+
+// alignment with a multi-byte filler is recalculated the same way
+#[test]
+fn relax_align_filler() {
+    let mut ops = dynasmrt::x64::Assembler::new().unwrap();
+    ops.set_relaxation(true).unwrap();
+    dynasm!(ops
+        ; .arch x64
+        ; jmp >done
+    );
+    ops.align_with_filler(8, &[0x66, 0x90]);
+    nops(&mut ops, 200);
+    dynasm!(ops
+        ; .arch x64
+        ; done:
+        ; ret
+    );
+    let buf = ops.finalize().unwrap();
+    assert_eq!(&buf[.. 8], &[0xE9, 0xCB, 0x00, 0x00, 0x00, 0x00, 0x66, 0x90]);
+    assert_eq!(buf.len(), 209);
+}
+
+fn aarch64_nops(ops: &mut dynasmrt::aarch64::Assembler, count: usize) {
+    for _ in 0 .. count {
+        dynasm!(ops
+            ; .arch aarch64
+            ; nop
+        );
+    }
+}
+
+fn words(buf: &[u8]) -> Vec<u32> {
+    buf.chunks(4).map(|c| u32::from_le_bytes([c[0], c[1], c[2], c[3]])).collect()
+}
+
+// aarch64 branches that can reach their target are left alone
+#[test]
+fn relax_aarch64_short() {
+    let mut ops = dynasmrt::aarch64::Assembler::new().unwrap();
+    ops.set_relaxation(true).unwrap();
+    dynasm!(ops
+        ; .arch aarch64
+        ; cbz w0, >done
+        ; tbnz x1, #3, >done
+        ; b.eq >done
+        ; nop
+        ; done:
+        ; ret
+    );
+    let buf = ops.finalize().unwrap();
+    assert_eq!(words(&buf), [0x3400_0080, 0x3718_0061, 0x5400_0040, 0xD503_201F, 0xD65F_03C0]);
+}
+
+// out of range test branches are rewritten to an inverted test branch over an unconditional branch
+#[test]
+fn relax_aarch64_tbz() {
+    let mut ops = dynasmrt::aarch64::Assembler::new().unwrap();
+    ops.set_relaxation(true).unwrap();
+    dynasm!(ops
+        ; .arch aarch64
+        ; back:
+        ; tbz x1, #3, ->done
+        ; cbnz x2, ->done
+    );
+    aarch64_nops(&mut ops, 8192);
+    dynasm!(ops
+        ; .arch aarch64
+        ; ->done:
+        ; tbnz w4, #0, <back
+    );
+    ops.commit().unwrap();
+    assert_eq!(ops.labels().resolve_global("done"), Ok(dynasmrt::AssemblyOffset(32780)));
+
+    let buf = ops.finalize().unwrap();
+    let words = words(&buf);
+    assert_eq!(&words[.. 3], [0x3718_0041, 0x1400_2002, 0xB500_0000 | 0x2001 << 5 | 2]);
+    assert_eq!(&words[8195 ..], [0x3600_0044, 0x17FF_DFFC]);
+}
+
+// out of range conditional branches are rewritten to an inverted conditional branch over an unconditional branch
+#[test]
+fn relax_aarch64_bcond() {
+    let mut ops = dynasmrt::aarch64::Assembler::new().unwrap();
+    ops.set_relaxation(true).unwrap();
+    dynasm!(ops
+        ; .arch aarch64
+        ; b.eq >done
+    );
+    aarch64_nops(&mut ops, 262_144);
+    dynasm!(ops
+        ; .arch aarch64
+        ; done:
+    );
+    let buf = ops.finalize().unwrap();
+    assert_eq!(&words(&buf[.. 8]), &[0x5400_0041, 0x1404_0001]);
+    assert_eq!(buf.len(), 1_048_584);
+}
+
+fn riscv_nops(ops: &mut dynasmrt::riscv::Assembler, count: usize) {
+    for _ in 0 .. count {
+        dynasm!(ops
+            ; .arch rv64
+            ; nop
+        );
+    }
+}
+
+// out of range rv64 branches are rewritten to an inverted branch over a jal, branches in range are left alone
+#[test]
+fn relax_riscv() {
+    let mut ops = dynasmrt::riscv::Assembler::new().unwrap();
+    ops.set_relaxation(true).unwrap();
+    dynasm!(ops
+        ; .arch rv64
+        ; beq a0, a1, >done
+        ; bltu t0, t1, >next
+        ; next:
+    );
+    riscv_nops(&mut ops, 1024);
+    dynasm!(ops
+        ; .arch rv64
+        ; done:
+    );
+    let buf = ops.finalize().unwrap();
+    assert_eq!(&words(&buf[.. 12]), &[0x00B5_1463, 0x0080_106F, 0x0062_E263]);
+    assert_eq!(buf.len(), 4108);
+}
+
+fn loongarch_nops(ops: &mut dynasmrt::loongarch::Assembler, count: usize) {
+    for _ in 0 .. count {
+        dynasm!(ops
+            ; .arch loongarch64
+            ; nop
+        );
+    }
+}
+
+// out of range loongarch64 branches are rewritten to an inverted branch over a b, branches in range are left alone
+#[test]
+fn relax_loongarch() {
+    let mut ops = dynasmrt::loongarch::Assembler::new().unwrap();
+    ops.set_relaxation(true).unwrap();
+    dynasm!(ops
+        ; .arch loongarch64
+        ; beq a0, a1, >done
+        ; bnez t0, >next
+        ; next:
+    );
+    loongarch_nops(&mut ops, 32768);
+    dynasm!(ops
+        ; .arch loongarch64
+        ; done:
+    );
+    let buf = ops.finalize().unwrap();
+    assert_eq!(&words(&buf[.. 12]), &[0x5C00_0885, 0x5200_0800, 0x4400_0580]);
+    assert_eq!(buf.len(), 131_084);
+}
@@ -0,0 +1,478 @@
+#![feature(proc_macro_hygiene)]
+#![allow(unused_imports)]
+
+#[macro_use]
+extern crate dynasmrt;
+extern crate dynasm;
+
+use dynasm::dynasm;
+use dynasmrt::{DynasmApi, DynasmLabelApi};
+
+// the RV64I base instruction set
+#[test]
+fn base() {
+    let mut ops = dynasmrt::riscv::Assembler::new().unwrap();
+    dynasm!(ops
+        ; .arch rv64
+        ; lui a0, 0x12345
+        ; auipc t0, 1
+        ; addi a0, a1, -2048
+        ; slti t1, t2, 100
+        ; sltiu t1, t2, 1
+        ; xori a0, a0, -1
+        ; andi s0, s1, 0xFF
+        ; slli a2, a3, 63
+        ; srai a4, a5, 7
+        ; addiw a6, a7, -1
+        ; sraiw s2, s3, 31
+        ; add x1, x2, x3
+        ; sub t3, t4, t5
+        ; sra s4, s5, s6
+        ; sltu s7, s8, s9
+        ; subw ra, sp, gp
+        ; srlw tp, fp, zero
+        ; lb a0, [sp]
+        ; lhu a1, [s0, -4]
+        ; ld a2, [a3, 2047]
+        ; lwu a4, [a5, 12]
+        ; sw a4, [a5, -2048]
+        ; sd ra, [sp, 8]
+        ; jalr ra, [a0, 16]
+        ; jalr t0, t1, -4
+        ; fence
+        ; fence rw, w
+        ; fence.tso
+        ; ecall
+        ; ebreak
+    );
+    let buf = ops.finalize().unwrap();
+    let hex: Vec<String> = buf.iter().map(|x| format!("{:02X}", *x)).collect();
+    let hex = hex.join(", ");
+    assert_eq!(hex, "37, 55, 34, 12, 97, 12, 00, 00, 13, 85, 05, 80, 13, A3, 43, 06, 13, B3, 13, 00, 13, 45, F5, FF, 13, F4, F4, 0F, 13, 96, F6, 03, 13, D7, 77, 40, 1B, 88, F8, FF, 1B, D9, F9, 41, B3, 00, 31, 00, 33, 8E, EE, 41, 33, DA, 6A, 41, B3, 3B, 9C, 01, BB, 00, 31, 40, 3B, 52, 04, 00, 03, 05, 01, 00, 83, 55, C4, FF, 03, B6, F6, 7F, 03, E7, C7, 00, 23, A0, E7, 80, 23, 34, 11, 00, E7, 00, 05, 01, E7, 02, C3, FF, 0F, 00, F0, 0F, 0F, 00, 10, 03, 0F, 00, 30, 83, 73, 00, 00, 00, 73, 00, 10, 00", "base");
+}
+
+// instruction fences and control and status register access, by name or by number
+#[test]
+fn zifencei_zicsr() {
+    let mut ops = dynasmrt::riscv::Assembler::new().unwrap();
+    dynasm!(ops
+        ; .arch rv64
+        ; fence.i
+        ; csrrw a0, fcsr, a1
+        ; csrrs t0, mstatus, zero
+        ; csrrc a2, 0x7C0, a3
+        ; csrrwi a4, sscratch, 31
+        ; csrrsi zero, mie, 8
+        ; csrrci a5, 0x300, 1
+    );
+    let buf = ops.finalize().unwrap();
+    let hex: Vec<String> = buf.iter().map(|x| format!("{:02X}", *x)).collect();
+    let hex = hex.join(", ");
+    assert_eq!(hex, "0F, 10, 00, 00, 73, 95, 35, 00, F3, 22, 00, 30, 73, B6, 06, 7C, 73, D7, 0F, 14, 73, 60, 44, 30, F3, F7, 00, 30", "zifencei_zicsr");
+}
+
+// the M extension
+#[test]
+fn m() {
+    let mut ops = dynasmrt::riscv::Assembler::new().unwrap();
+    dynasm!(ops
+        ; .arch rv64
+        ; mul a0, a1, a2
+        ; mulh a3, a4, a5
+        ; mulhsu t0, t1, t2
+        ; mulhu s0, s1, s2
+        ; div a0, a0, a1
+        ; divu a2, a3, a4
+        ; rem t3, t4, t5
+        ; remu s3, s4, s5
+        ; mulw a6, a7, s6
+        ; divw s7, s8, s9
+        ; divuw s10, s11, t6
+        ; remw ra, sp, gp
+        ; remuw tp, a0, a1
+    );
+    let buf = ops.finalize().unwrap();
+    let hex: Vec<String> = buf.iter().map(|x| format!("{:02X}", *x)).collect();
+    let hex = hex.join(", ");
+    assert_eq!(hex, "33, 85, C5, 02, B3, 16, F7, 02, B3, 22, 73, 02, 33, B4, 24, 03, 33, 45, B5, 02, 33, D6, E6, 02, 33, EE, EE, 03, B3, 79, 5A, 03, 3B, 88, 68, 03, BB, 4B, 9C, 03, 3B, DD, FD, 03, BB, 60, 31, 02, 3B, 72, B5, 02", "m");
+}
+
+// the A extension, with all memory ordering variants
+#[test]
+fn a() {
+    let mut ops = dynasmrt::riscv::Assembler::new().unwrap();
+    dynasm!(ops
+        ; .arch rv64
+        ; lr.w a0, [a1]
+        ; lr.d.aq a2, [a3]
+        ; sc.w.rl a4, a5, [a6]
+        ; sc.d.aqrl t0, t1, [t2]
+        ; amoswap.w a0, a1, [sp]
+        ; amoadd.d.aq s0, s1, [s2]
+        ; amoxor.w.rl s3, s4, [s5]
+        ; amoand.d.aqrl s6, s7, [s8]
+        ; amoor.w a0, a1, [a2]
+        ; amomin.d a3, a4, [a5]
+        ; amomax.w.aq t3, t4, [t5]
+        ; amominu.d.rl a0, a0, [a1]
+        ; amomaxu.w.aqrl zero, ra, [gp]
+    );
+    let buf = ops.finalize().unwrap();
+    let hex: Vec<String> = buf.iter().map(|x| format!("{:02X}", *x)).collect();
+    let hex = hex.join(", ");
+    assert_eq!(hex, "2F, A5, 05, 10, 2F, B6, 06, 14, 2F, 27, F8, 1A, AF, B2, 63, 1E, 2F, 25, B1, 08, 2F, 34, 99, 04, AF, A9, 4A, 23, 2F, 3B, 7C, 67, 2F, 25, B6, 40, AF, B6, E7, 80, 2F, 2E, DF, A5, 2F, B5, A5, C2, 2F, A0, 11, E6", "a");
+}
+
+// the F extension, with and without an explicit rounding mode
+#[test]
+fn f() {
+    let mut ops = dynasmrt::riscv::Assembler::new().unwrap();
+    dynasm!(ops
+        ; .arch rv64
+        ; flw fa0, [sp, 4]
+        ; fsw fs0, [a0, -8]
+        ; fmadd.s fa0, fa1, fa2, fa3
+        ; fmsub.s ft0, ft1, ft2, ft3, rne
+        ; fnmsub.s fs0, fs1, fs2, fs3, rtz
+        ; fnmadd.s ft8, ft9, ft10, ft11, rdn
+        ; fadd.s fa0, fa1, fa2
+        ; fsub.s fa3, fa4, fa5, rup
+        ; fmul.s f0, f1, f2, rmm
+        ; fdiv.s f29, f30, f31, dyn
+        ; fsqrt.s fa0, fa1
+        ; fsgnj.s fa0, fa1, fa2
+        ; fsgnjn.s fa3, fa4, fa5
+        ; fsgnjx.s fa6, fa7, ft0
+        ; fmin.s ft1, ft2, ft3
+        ; fmax.s ft4, ft5, ft6
+        ; feq.s a0, fa0, fa1
+        ; flt.s a1, fa2, fa3
+        ; fle.s a2, fa4, fa5
+        ; fclass.s a3, fa6
+        ; fcvt.w.s a0, fa0, rtz
+        ; fcvt.wu.s a1, fa1
+        ; fcvt.l.s a2, fa2, rtz
+        ; fcvt.lu.s a3, fa3
+        ; fcvt.s.w fa0, a0
+        ; fcvt.s.wu fa1, a1, rne
+        ; fcvt.s.l fa2, a2
+        ; fcvt.s.lu fa3, a3, rdn
+        ; fmv.x.w a0, fa0
+        ; fmv.w.x fa0, a0
+    );
+    let buf = ops.finalize().unwrap();
+    let hex: Vec<String> = buf.iter().map(|x| format!("{:02X}", *x)).collect();
+    let hex = hex.join(", ");
+    assert_eq!(hex, "07, 25, 41, 00, 27, 2C, 85, FE, 43, F5, C5, 68, 47, 80, 20, 18, 4B, 94, 24, 99, 4F, AE, EE, F9, 53, F5, C5, 00, D3, 36, F7, 08, 53, C0, 20, 10, D3, 7E, FF, 19, 53, F5, 05, 58, 53, 85, C5, 20, D3, 16, F7, 20, 53, A8, 08, 20, D3, 00, 31, 28, 53, 92, 62, 28, 53, 25, B5, A0, D3, 15, D6, A0, 53, 06, F7, A0, D3, 16, 08, E0, 53, 15, 05, C0, D3, F5, 15, C0, 53, 16, 26, C0, D3, F6, 36, C0, 53, 75, 05, D0, D3, 85, 15, D0, 53, 76, 26, D0, D3, A6, 36, D0, 53, 05, 05, E0, 53, 05, 05, F0", "f");
+}
+
+// the D extension
+#[test]
+fn d() {
+    let mut ops = dynasmrt::riscv::Assembler::new().unwrap();
+    dynasm!(ops
+        ; .arch rv64
+        ; fld fa0, [sp, 8]
+        ; fsd fs0, [a0, -16]
+        ; fmadd.d fa0, fa1, fa2, fa3
+        ; fnmadd.d ft0, ft1, ft2, ft3, rtz
+        ; fadd.d fa0, fa1, fa2
+        ; fsub.d fa3, fa4, fa5, rup
+        ; fmul.d f0, f1, f2
+        ; fdiv.d f29, f30, f31, rmm
+        ; fsqrt.d fa0, fa1, rne
+        ; fsgnj.d fa0, fa1, fa2
+        ; fmin.d ft1, ft2, ft3
+        ; fmax.d ft4, ft5, ft6
+        ; feq.d a0, fa0, fa1
+        ; flt.d a1, fa2, fa3
+        ; fle.d a2, fa4, fa5
+        ; fclass.d a3, fa6
+        ; fcvt.w.d a0, fa0, rtz
+        ; fcvt.lu.d a3, fa3
+        ; fcvt.d.w fa0, a0
+        ; fcvt.d.wu fa1, a1
+        ; fcvt.d.l fa2, a2, rdn
+        ; fcvt.d.lu fa3, a3
+        ; fcvt.s.d fa0, fa1
+        ; fcvt.d.s fa2, fa3
+        ; fmv.x.d a0, fa0
+        ; fmv.d.x fa0, a0
+    );
+    let buf = ops.finalize().unwrap();
+    let hex: Vec<String> = buf.iter().map(|x| format!("{:02X}", *x)).collect();
+    let hex = hex.join(", ");
+    assert_eq!(hex, "07, 35, 81, 00, 27, 38, 85, FE, 43, F5, C5, 6A, 4F, 90, 20, 1A, 53, F5, C5, 02, D3, 36, F7, 0A, 53, F0, 20, 12, D3, 4E, FF, 1B, 53, 85, 05, 5A, 53, 85, C5, 22, D3, 00, 31, 2A, 53, 92, 62, 2A, 53, 25, B5, A2, D3, 15, D6, A2, 53, 06, F7, A2, D3, 16, 08, E2, 53, 15, 05, C2, D3, F6, 36, C2, 53, 05, 05, D2, D3, 85, 15, D2, 53, 26, 26, D2, D3, F6, 36, D2, 53, F5, 15, 40, 53, 86, 06, 42, 53, 05, 05, E2, 53, 05, 05, F2", "d");
+}
+
+// the compressed instructions of the C extension
+#[test]
+fn c() {
+    let mut ops = dynasmrt::riscv::Assembler::new().unwrap();
+    dynasm!(ops
+        ; .arch rv64
+        ; c.addi4spn a0, sp, 1020
+        ; c.fld fa0, [a1, 248]
+        ; c.lw a2, [a3, 124]
+        ; c.ld a4, [a5, 0]
+        ; c.fsd fs0, [s1, 8]
+        ; c.sw s0, [s1, 4]
+        ; c.sd a0, [a1, 16]
+        ; c.nop
+        ; c.addi a0, -32
+        ; c.addiw a1, 31
+        ; c.li t0, -1
+        ; c.addi16sp sp, -512
+        ; c.addi16sp sp, 496
+        ; c.lui t1, 31
+        ; c.srli s0, 63
+        ; c.srai s1, 1
+        ; c.andi a2, -32
+        ; c.sub a3, a4
+        ; c.xor a5, s0
+        ; c.or s1, a0
+        ; c.and a1, a2
+        ; c.subw a3, a4
+        ; c.addw a5, s0
+        ; c.slli t2, 63
+        ; c.fldsp fa0, [sp, 504]
+        ; c.lwsp ra, [sp, 252]
+        ; c.ldsp s11, [sp, 0]
+        ; c.jr ra
+        ; c.mv a0, t6
+        ; c.ebreak
+        ; c.jalr t0
+        ; c.add s2, s3
+        ; c.fsdsp fs0, [sp, 8]
+        ; c.swsp zero, [sp, 4]
+        ; c.sdsp ra, [sp, 504]
+    );
+    let buf = ops.finalize().unwrap();
+    let hex: Vec<String> = buf.iter().map(|x| format!("{:02X}", *x)).collect();
+    let hex = hex.join(", ");
+    assert_eq!(hex, "E8, 1F, E8, 3D, F0, 5E, 98, 63, 80, A4, C0, C0, 88, E9, 01, 00, 01, 15, FD, 25, FD, 52, 01, 71, 7D, 61, 7D, 63, 7D, 90, 85, 84, 01, 9A, 99, 8E, A1, 8F, C9, 8C, F1, 8D, 99, 9E, A1, 9F, FE, 13, 7E, 35, FE, 50, 82, 6D, 82, 80, 7E, 85, 02, 90, 82, 92, 4E, 99, 22, A4, 02, C2, 86, FF", "c");
+}
+
+// the Zba and Zbb bit manipulation extensions
+#[test]
+fn zba_zbb() {
+    let mut ops = dynasmrt::riscv::Assembler::new().unwrap();
+    dynasm!(ops
+        ; .arch rv64
+        ; sh1add a0, a1, a2
+        ; sh2add a3, a4, a5
+        ; sh3add t0, t1, t2
+        ; sh1add.uw s0, s1, s2
+        ; sh2add.uw s3, s4, s5
+        ; sh3add.uw s6, s7, s8
+        ; add.uw a0, a1, a2
+        ; slli.uw a3, a4, 63
+        ; zext.w a5, a6
+        ; andn a0, a1, a2
+        ; orn a3, a4, a5
+        ; xnor t0, t1, t2
+        ; max s0, s1, s2
+        ; maxu s3, s4, s5
+        ; min s6, s7, s8
+        ; minu s9, s10, s11
+        ; rol a0, a1, a2
+        ; ror a3, a4, a5
+        ; rolw t3, t4, t5
+        ; rorw a6, a7, t6
+        ; clz a0, a1
+        ; ctz a2, a3
+        ; cpop a4, a5
+        ; sext.b t0, t1
+        ; sext.h t2, s0
+        ; clzw s1, s2
+        ; ctzw s3, s4
+        ; cpopw s5, s6
+        ; zext.h s7, s8
+        ; rori a0, a1, 63
+        ; roriw a2, a3, 31
+        ; orc.b a4, a5
+        ; rev8 a6, a7
+    );
+    let buf = ops.finalize().unwrap();
+    let hex: Vec<String> = buf.iter().map(|x| format!("{:02X}", *x)).collect();
+    let hex = hex.join(", ");
+    assert_eq!(hex, "33, A5, C5, 20, B3, 46, F7, 20, B3, 62, 73, 20, 3B, A4, 24, 21, BB, 49, 5A, 21, 3B, EB, 8B, 21, 3B, 85, C5, 08, 9B, 16, F7, 0B, BB, 07, 08, 08, 33, F5, C5, 40, B3, 66, F7, 40, B3, 42, 73, 40, 33, E4, 24, 0B, B3, 79, 5A, 0B, 33, CB, 8B, 0B, B3, 5C, BD, 0B, 33, 95, C5, 60, B3, 56, F7, 60, 3B, 9E, EE, 61, 3B, D8, F8, 61, 13, 95, 05, 60, 13, 96, 16, 60, 13, 97, 27, 60, 93, 12, 43, 60, 93, 13, 54, 60, 9B, 14, 09, 60, 9B, 19, 1A, 60, 9B, 1A, 2B, 60, BB, 4B, 0C, 08, 13, D5, F5, 63, 1B, D6, F6, 61, 13, D7, 77, 28, 13, D8, 88, 6B", "zba_zbb");
+}
+
+// pseudo instructions assemble to the instructions they stand for
+#[test]
+fn pseudos() {
+    let mut ops = dynasmrt::riscv::Assembler::new().unwrap();
+    dynasm!(ops
+        ; .arch rv64
+        ; nop
+        ; li a0, -2048
+        ; mv a1, a2
+        ; not a3, a4
+        ; neg a5, a6
+        ; negw t0, t1
+        ; sext.w t2, s0
+        ; zext.b s1, s2
+        ; seqz s3, s4
+        ; snez s5, s6
+        ; sltz s7, s8
+        ; sgtz s9, s10
+        ; jr t0
+        ; ret
+        ; fmv.s fa0, fa1
+        ; fneg.s fa2, fa3
+        ; fabs.d fa4, fa5
+        ; csrr a0, fcsr
+        ; csrw mscratch, a1
+        ; csrs 0x300, a2
+        ; csrc mie, a3
+        ; csrwi fcsr, 31
+        ; csrsi mstatus, 8
+        ; csrci 0x344, 1
+        ; rdcycle a0
+        ; rdtime a1
+        ; rdinstret a2
+        ; frcsr a3
+        ; frrm a4
+        ; frflags a5
+        ; fscsr t0
+        ; fscsr t1, t2
+        ; fsrm s0
+        ; fsflags s1, s2
+        ; fsrmi 1
+        ; fsflagsi a0, 31
+        ; jalr a0
+    );
+    let buf = ops.finalize().unwrap();
+    let hex: Vec<String> = buf.iter().map(|x| format!("{:02X}", *x)).collect();
+    let hex = hex.join(", ");
+    assert_eq!(hex, "13, 00, 00, 00, 13, 05, 00, 80, 93, 05, 06, 00, 93, 46, F7, FF, B3, 07, 00, 41, BB, 02, 60, 40, 9B, 03, 04, 00, 93, 74, F9, 0F, 93, 39, 1A, 00, B3, 3A, 60, 01, B3, 2B, 0C, 00, B3, 2C, A0, 01, 67, 80, 02, 00, 67, 80, 00, 00, 53, 85, B5, 20, 53, 96, D6, 20, 53, A7, F7, 22, 73, 25, 30, 00, 73, 90, 05, 34, 73, 20, 06, 30, 73, B0, 46, 30, 73, D0, 3F, 00, 73, 60, 04, 30, 73, F0, 40, 34, 73, 25, 00, C0, F3, 25, 10, C0, 73, 26, 20, C0, F3, 26, 30, 00, 73, 27, 20, 00, F3, 27, 10, 00, 73, 90, 32, 00, 73, 93, 33, 00, 73, 10, 24, 00, F3, 14, 19, 00, 73, D0, 20, 00, 73, D5, 1F, 00, E7, 00, 05, 00", "pseudos");
+}
+// branches and jumps to labels, including the compressed forms
+#[test]
+fn labels() {
+    let mut ops = dynasmrt::riscv::Assembler::new().unwrap();
+    dynasm!(ops
+        ; .arch rv64
+        ; start:
+        ; beq a0, a1, >fwd
+        ; jal ra, <start
+        ; c.beqz s0, >fwd
+        ; c.j <start
+        ; bnez a0, <start
+        ; j >fwd
+        ; fwd:
+        ; c.bnez a5, <start
+        ; c.nop
+    );
+    let buf = ops.finalize().unwrap();
+    let hex: Vec<String> = buf.iter().map(|x| format!("{:02X}", *x)).collect();
+    let hex = hex.join(", ");
+    assert_eq!(hex, "63, 0A, B5, 00, EF, F0, DF, FF, 11, C4, DD, BF, E3, 1A, 05, FE, 6F, 00, 40, 00, F5, F7, 01, 00", "labels");
+}
+
+// auipc pairs for calls and label addresses, loads and stores
+#[test]
+fn label_pairs() {
+    let mut ops = dynasmrt::riscv::Assembler::new().unwrap();
+    dynasm!(ops
+        ; .arch rv64
+        ; start:
+        ; call >func
+        ; la a0, >data
+        ; ld a1, >data
+        ; sd a2, >data, t0
+        ; func:
+        ; ret
+        ; data:
+        ; tail <data
+    );
+    let buf = ops.finalize().unwrap();
+    let hex: Vec<String> = buf.iter().map(|x| format!("{:02X}", *x)).collect();
+    let hex = hex.join(", ");
+    assert_eq!(hex, "97, 00, 00, 00, E7, 80, 00, 02, 17, 05, 00, 00, 13, 05, C5, 01, 97, 05, 00, 00, 83, B5, 45, 01, 97, 02, 00, 00, 23, B6, C2, 00, 67, 80, 00, 00, 17, 03, 00, 00, 67, 00, 03, 00", "label_pairs");
+}
+
+// the upper part of an auipc pair is rounded so the sign extended lower part adds up to the offset
+#[test]
+fn label_pairs_far() {
+    let mut ops = dynasmrt::riscv::Assembler::new().unwrap();
+    let far = ops.new_dynamic_label();
+    dynasm!(ops
+        ; .arch rv64
+        ; lla a0, =>far
+        ; .bytes vec![0u8; 0x1804]
+        ; =>far
+    );
+    let buf = ops.finalize().unwrap();
+    assert_eq!(&buf[.. 8], &[0x17, 0x25, 0x00, 0x00, 0x13, 0x05, 0xC5, 0x80]);
+}
+
+// relocations in data are relative to the start of the data
+#[test]
+fn data_relocations() {
+    let mut ops = dynasmrt::riscv::Assembler::new().unwrap();
+    dynasm!(ops
+        ; .arch rv64
+        ; start:
+        ; nop
+        ; .dword <start
+        ; .qword <start
+    );
+    let buf = ops.finalize().unwrap();
+    let hex: Vec<String> = buf.iter().map(|x| format!("{:02X}", *x)).collect();
+    let hex = hex.join(", ");
+    assert_eq!(hex, "13, 00, 00, 00, FC, FF, FF, FF, F8, FF, FF, FF, FF, FF, FF, FF", "data_relocations");
+}
+
+// registers and immediates can be chosen at runtime
+#[test]
+fn dynamic() {
+    let r = 10;
+    let f = 11;
+    let imm = -5;
+    let shamt = 3;
+    let upper = 0xF_FFFB;
+    let mut ops = dynasmrt::riscv::Assembler::new().unwrap();
+    dynasm!(ops
+        ; .arch rv64
+        ; add X(r), X(r + 1), a2
+        ; addi X(r), X(5), imm
+        ; fadd.d F(f), F(f), fa2
+        ; ld X(r), [X(2), 8 * shamt]
+        ; sd X(r), [sp, imm * 8]
+        ; slli a0, a0, shamt
+        ; c.addi X(r), imm
+        ; c.lw X(r), [X(9), 4 * shamt]
+        ; lui a0, upper
+        ; csrwi fcsr, shamt
+    );
+    let buf = ops.finalize().unwrap();
+    let hex: Vec<String> = buf.iter().map(|x| format!("{:02X}", *x)).collect();
+    let hex = hex.join(", ");
+    assert_eq!(hex, "33, 85, C5, 00, 13, 85, B2, FF, D3, F5, C5, 02, 03, 35, 81, 01, 23, 3C, A1, FC, 13, 15, 35, 00, 6D, 15, C8, 44, 37, B5, FF, FF, 73, D0, 31, 00", "dynamic");
+}
+
+// instructions from the listed extensions can be used after they have been declared with .feature
+#[test]
+fn features() {
+    let mut ops = dynasmrt::riscv::Assembler::new().unwrap();
+    dynasm!(ops
+        ; .arch rv64
+        ; .feature none
+        ; add a0, a1, a2
+        ; .feature m, c
+        ; mul a0, a1, a2
+        ; c.nop
+        ; .feature g
+        ; fadd.d fa1, fa1, fa2
+        ; csrwi fcsr, 3
+        ; .feature zba, zbb
+        ; sh1add a0, a1, a2
+        ; clz a0, a1
+    );
+    let buf = ops.finalize().unwrap();
+    let hex: Vec<String> = buf.iter().map(|x| format!("{:02X}", *x)).collect();
+    let hex = hex.join(", ");
+    assert_eq!(hex, "33, 85, C5, 00, 33, 85, C5, 02, 01, 00, D3, F5, C5, 02, 73, D0, 31, 00, 33, A5, C5, 20, 13, 95, 05, 60", "features");
+}
@@ -0,0 +1,152 @@
+#![feature(proc_macro_hygiene)]
+#![allow(unused_imports)]
+
+extern crate dynasmrt;
+extern crate dynasm;
+
+use dynasm::dynasm;
+use dynasmrt::{DynasmApi, DynasmLabelApi};
+
+fn add_imm(value: i32) -> Vec<u8> {
+    let mut ops = dynasmrt::x64::Assembler::new().unwrap();
+    dynasm!(ops
+        ; .arch x64
+        ; .runtime_sizes on
+        ; add rax, value
+        ; push value
+        ; imul eax, ecx, value
+    );
+    ops.finalize().unwrap().to_vec()
+}
+
+// immediates that fit in a byte use the sign-extended imm8 forms
+#[test]
+fn runtime_sizes_immediate() {
+    assert_eq!(add_imm(5), [0x48, 0x83, 0xC0, 0x05, 0x6A, 0x05, 0x6B, 0xC1, 0x05]);
+    assert_eq!(add_imm(-0x80), [0x48, 0x83, 0xC0, 0x80, 0x6A, 0x80, 0x6B, 0xC1, 0x80]);
+    assert_eq!(add_imm(0x80), [
+        0x48, 0x05, 0x80, 0x00, 0x00, 0x00,
+        0x68, 0x80, 0x00, 0x00, 0x00,
+        0x69, 0xC1, 0x80, 0x00, 0x00, 0x00
+    ]);
+}
+
+fn load_disp(disp: i32, value: i32) -> Vec<u8> {
+    let mut ops = dynasmrt::x64::Assembler::new().unwrap();
+    dynasm!(ops
+        ; .arch x64
+        ; .runtime_sizes on
+        ; mov eax, [rbx + disp]
+        ; add DWORD [rbp + disp], value
+    );
+    ops.finalize().unwrap().to_vec()
+}
+
+// displacements that fit in a byte use the disp8 forms
+#[test]
+fn runtime_sizes_displacement() {
+    assert_eq!(load_disp(8, 1), [0x8B, 0x43, 0x08, 0x83, 0x45, 0x08, 0x01]);
+    assert_eq!(load_disp(-8, 0x300), [0x8B, 0x43, 0xF8, 0x81, 0x45, 0xF8, 0x00, 0x03, 0x00, 0x00]);
+    assert_eq!(load_disp(0x100, 1), [0x8B, 0x83, 0x00, 0x01, 0x00, 0x00, 0x83, 0x85, 0x00, 0x01, 0x00, 0x00, 0x01]);
+}
+
+fn load_zmm(disp: i32) -> Vec<u8> {
+    let mut ops = dynasmrt::x64::Assembler::new().unwrap();
+    dynasm!(ops
+        ; .arch x64
+        ; .runtime_sizes on
+        ; vmovaps zmm0, [rax + disp]
+    );
+    ops.finalize().unwrap().to_vec()
+}
+
+// compressed displacements are used if the displacement is a multiple of the scale
+#[test]
+fn runtime_sizes_compressed() {
+    assert_eq!(load_zmm(128), [0x62, 0xF1, 0x7C, 0x48, 0x28, 0x40, 0x02]);
+    assert_eq!(load_zmm(100), [0x62, 0xF1, 0x7C, 0x48, 0x28, 0x80, 0x64, 0x00, 0x00, 0x00]);
+    assert_eq!(load_zmm(64 * 0x80), [0x62, 0xF1, 0x7C, 0x48, 0x28, 0x80, 0x00, 0x20, 0x00, 0x00]);
+}
+
+// the expressions of runtime sized operands are evaluated only once
+#[test]
+fn runtime_sizes_evaluated_once() {
+    use std::cell::Cell;
+
+    for &(disp, value) in &[(8, 1), (0x100, 0x300), (128, 1), (100, 0x300)] {
+        let evaluations = Cell::new(0);
+        let count = |value: i32| {
+            evaluations.set(evaluations.get() + 1);
+            value
+        };
+
+        let mut ops = dynasmrt::x64::Assembler::new().unwrap();
+        dynasm!(ops
+            ; .arch x64
+            ; .runtime_sizes on
+            ; add DWORD [rbp + count(disp)], count(value)
+            ; vmovaps zmm0, [rax + count(disp)]
+        );
+        assert_eq!(evaluations.get(), 3);
+
+        let mut expected = dynasmrt::x64::Assembler::new().unwrap();
+        dynasm!(expected
+            ; .arch x64
+            ; .runtime_sizes on
+            ; add DWORD [rbp + disp], value
+            ; vmovaps zmm0, [rax + disp]
+        );
+        assert_eq!(&*ops.finalize().unwrap(), &*expected.finalize().unwrap());
+    }
+}
+
+// relocations are emitted by whichever form was picked
+#[test]
+fn runtime_sizes_labels() {
+    for &(value, len) in &[(1, 4), (0x1000, 6)] {
+        let mut ops = dynasmrt::x64::Assembler::new().unwrap();
+        dynasm!(ops
+            ; .arch x64
+            ; .runtime_sizes on
+            ; jmp >done
+            ; add rax, value
+            ; done:
+            ; jmp <done
+        );
+        let buf = ops.finalize().unwrap();
+        assert_eq!(&buf[.. 5], &[0xE9, len, 0x00, 0x00, 0x00]);
+        assert_eq!(&buf[5 + len as usize ..], &[0xE9, 0xFB, 0xFF, 0xFF, 0xFF]);
+    }
+}
+
+// without the directive the long forms are always used
+#[test]
+fn runtime_sizes_off() {
+    let value = 1;
+    let mut ops = dynasmrt::x64::Assembler::new().unwrap();
+    dynasm!(ops
+        ; .arch x64
+        ; .runtime_sizes off
+        ; add rax, value
+    );
+    let buf = ops.finalize().unwrap();
+    assert_eq!(&*buf, &[0x48, 0x05, 0x01, 0x00, 0x00, 0x00]);
+}
+
+fn x86_16(value: i16) -> Vec<u8> {
+    let mut ops = dynasmrt::x86_16::Assembler::new().unwrap();
+    dynasm!(ops
+        ; .arch x86_16
+        ; .runtime_sizes on
+        ; add ax, value
+        ; mov ax, [bx + value]
+    );
+    ops.finalize().unwrap().to_vec()
+}
+
+// in 16-bit mode the long forms use 16-bit immediates and displacements
+#[test]
+fn runtime_sizes_x86_16() {
+    assert_eq!(x86_16(-2), [0x83, 0xC0, 0xFE, 0x8B, 0x47, 0xFE]);
+    assert_eq!(x86_16(0x400), [0x05, 0x00, 0x04, 0x8B, 0x87, 0x00, 0x04]);
+}
@@ -0,0 +1,163 @@
+#![feature(proc_macro_hygiene)]
+#![allow(unused_imports)]
+
+extern crate dynasmrt;
+extern crate dynasm;
+
+use dynasm::dynasm;
+use dynasmrt::{DynasmApi, DynasmLabelApi, AssemblyOffset};
+use dynasmrt::components::SourceLocation;
+
+fn location(line: u32, column: u32) -> Option<SourceLocation> {
+    Some(SourceLocation { file: file!(), line, column })
+}
+
+// instructions are mapped back to the line and column they were written at
+#[test]
+fn source_locations() {
+    let mut ops = dynasmrt::x64::Assembler::new().unwrap();
+    let first = line!();
+    dynasm!(ops
+        ; .arch x64
+        ; nop
+        ; .source_locations on
+        ; mov eax, 1
+        ;   ret
+        ; .source_locations off
+    );
+
+    assert_eq!(ops.source_location(AssemblyOffset(0)), None);
+    assert_eq!(ops.source_location(AssemblyOffset(1)), location(first + 5, 11));
+    assert_eq!(ops.source_location(AssemblyOffset(5)), location(first + 5, 11));
+    assert_eq!(ops.source_location(AssemblyOffset(6)), location(first + 6, 13));
+    assert_eq!(location(first + 6, 13).unwrap().to_string(), format!("{}:{}:13", file!(), first + 6));
+
+    // the locations survive committing
+    ops.commit().unwrap();
+    let line = line!();
+    dynasm!(ops
+        ; .arch x64
+        ; .source_locations on
+        ; push rbx
+        ; .source_locations off
+    );
+    ops.commit().unwrap();
+    assert_eq!(ops.source_location(AssemblyOffset(6)), location(first + 6, 13));
+    assert_eq!(ops.source_location(AssemblyOffset(7)), location(line + 4, 11));
+
+    // and are replaced when code is altered
+    let line = line!();
+    ops.alter(|modifier| {
+        modifier.goto(AssemblyOffset(1));
+        dynasm!(modifier
+            ; .arch x64
+            ; .source_locations on
+            ; inc rax
+            ; nop
+            ; nop
+            ; .source_locations off
+        );
+    }).unwrap();
+    assert_eq!(ops.source_location(AssemblyOffset(1)), location(line + 6, 15));
+    assert_eq!(ops.source_location(AssemblyOffset(4)), location(line + 7, 15));
+    assert_eq!(ops.source_location(AssemblyOffset(5)), location(line + 8, 15));
+    assert_eq!(ops.source_location(AssemblyOffset(6)), location(first + 6, 13));
+}
+
+// relaxation moves the locations along with the code
+#[test]
+fn source_locations_relaxed() {
+    let mut ops = dynasmrt::x64::Assembler::new().unwrap();
+    ops.set_relaxation(true).unwrap();
+    let line = line!();
+    dynasm!(ops
+        ; .arch x64
+        ; .source_locations on
+        ; jmp >done
+        ; .source_locations off
+    );
+    for _ in 0 .. 200 {
+        dynasm!(ops
+            ; .arch x64
+            ; nop
+        );
+    }
+    dynasm!(ops
+        ; .arch x64
+        ; .source_locations on
+        ; done:
+        ; ret
+        ; .source_locations off
+    );
+    ops.commit().unwrap();
+
+    assert_eq!(ops.source_location(AssemblyOffset(0)), location(line + 4, 11));
+    assert_eq!(ops.source_location(AssemblyOffset(4)), location(line + 4, 11));
+    assert_eq!(ops.source_location(AssemblyOffset(5)), None);
+    assert_eq!(ops.source_location(AssemblyOffset(204)), None);
+    assert_eq!(ops.source_location(AssemblyOffset(205)), location(line + 17, 11));
+    assert_eq!(ops.source_location(AssemblyOffset(206)), None);
+}
+
+// code that was not assembled with the directive enabled has no location
+#[test]
+fn source_locations_uninstrumented() {
+    let mut ops = dynasmrt::x64::Assembler::new().unwrap();
+    let line = line!();
+    dynasm!(ops
+        ; .arch x64
+        ; .source_locations on
+        ; mov eax, 1
+        ; .byte 0x10
+        ; inc eax
+        ; .source_locations off
+        ; nop
+        ; .source_locations on
+        ; inc eax
+    );
+    dynasm!(ops
+        ; .arch x64
+        ; .source_locations off
+        ; nop
+    );
+
+    assert_eq!(ops.source_location(AssemblyOffset(4)), location(line + 4, 11));
+    assert_eq!(ops.source_location(AssemblyOffset(5)), None);
+    assert_eq!(ops.source_location(AssemblyOffset(6)), location(line + 6, 11));
+    assert_eq!(ops.source_location(AssemblyOffset(7)), location(line + 6, 11));
+    assert_eq!(ops.source_location(AssemblyOffset(8)), None);
+    assert_eq!(ops.source_location(AssemblyOffset(9)), location(line + 10, 11));
+    assert_eq!(ops.source_location(AssemblyOffset(10)), location(line + 10, 11));
+    assert_eq!(ops.source_location(AssemblyOffset(11)), None);
+    assert_eq!(ops.source_location(AssemblyOffset(12)), None);
+    ops.commit().unwrap();
+
+    // overwriting instrumented code with uninstrumented code removes its location, but not the location of
+    // the code after it
+    ops.alter(|modifier| {
+        modifier.goto(AssemblyOffset(2));
+        dynasm!(modifier
+            ; .arch x64
+            ; nop
+            ; nop
+        );
+    }).unwrap();
+    assert_eq!(ops.source_location(AssemblyOffset(1)), location(line + 4, 11));
+    assert_eq!(ops.source_location(AssemblyOffset(2)), None);
+    assert_eq!(ops.source_location(AssemblyOffset(3)), None);
+    assert_eq!(ops.source_location(AssemblyOffset(4)), location(line + 4, 11));
+    assert_eq!(ops.source_location(AssemblyOffset(6)), location(line + 6, 11));
+}
+
+// without the directive, nothing is recorded
+#[test]
+fn source_locations_off() {
+    let mut ops = dynasmrt::x64::Assembler::new().unwrap();
+    dynasm!(ops
+        ; .arch x64
+        ; mov eax, 1
+        ; ret
+    );
+    ops.commit().unwrap();
+    assert_eq!(ops.source_location(AssemblyOffset(0)), None);
+}
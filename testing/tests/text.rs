@@ -0,0 +1,242 @@
+#![feature(proc_macro_hygiene)]
+#![allow(unused_imports)]
+
+#[macro_use]
+extern crate dynasmrt;
+extern crate dynasm;
+
+use dynasm::dynasm;
+use dynasmrt::{DynasmApi, DynasmLabelApi};
+use dynasmrt::text::{TextAssembler, TextError, TextErrorKind};
+
+fn hex(buf: &[u8]) -> String {
+    let hex: Vec<String> = buf.iter().map(|x| format!("{:02X}", *x)).collect();
+    hex.join(", ")
+}
+
+// x64 text assembles to the same code as dynasm!
+#[test]
+fn x64_text() {
+    let mut ops = dynasmrt::x64::Assembler::new().unwrap();
+    let label = ops.new_dynamic_label();
+    dynasm!(ops
+        ; .arch x64
+        ; .alias text_counter, rcx
+        ; ->text_start:
+        ; mov rax, 0x1234
+        ; add text_counter, 8
+        ; mov QWORD [rbx + rcx * 4 + 0x100], 0x7F
+        ; lea r12, [r13 + rax * 2 - 16]
+        ; mov ecx, [rax * 3]
+        ; mov rdx, [rip + 0x10]
+        ; vpaddd xmm0, xmm9, xmm15
+        ; vaddps zmm1{k1}{z}, zmm2, [rax + 64]{1to16}
+        ; vaddpd zmm30, zmm29, zmm28, {rz-sae}
+        ; vmovups [rax - 0x2000]{k2}, zmm17
+        ; lock add [rdi], eax
+        ; rep movsb
+        ; test rdi, Rq(3)
+        ; jz >skip
+        ; backward:
+        ; jmp <backward
+        ; skip:
+        ; call ->text_start
+        ; =>label
+        ; lea rax, [=>label]
+        ; jmp =>label
+        ; .byte 1, -1, 0x7F
+        ; .align 8
+        ; .dword ->text_start
+        ; .qword 0x1122334455667788
+        ; ret
+    );
+    let expected = ops.finalize().unwrap();
+
+    let mut ops = dynasmrt::x64::Assembler::new().unwrap();
+    let label = ops.new_dynamic_label();
+    let mut text = TextAssembler::new();
+    text.bind_dynamic_label("label", label);
+    text.assemble_x64(&mut ops, "
+        .arch x64
+        .alias counter, rcx
+        ->text_start:
+            mov rax, 0x1234
+            add counter, 8           // aliases work like in dynasm!
+            mov QWORD [rbx + rcx * 4 + 0x100], 0x7F
+            lea r12, [r13 + 2 * rax - 0x10]
+            mov ecx, [rax * 3]
+            mov rdx, [rip + (1 << 4)]
+            vpaddd xmm0, xmm9, xmm15
+            vaddps zmm1{k1}{z}, zmm2, [rax + 64]{1to16}
+            vaddpd zmm30, zmm29, zmm28, {rz-sae}
+            vmovups [rax - 0x2000] {k2}, Rz(17)
+            lock add [rdi], eax ; rep movsb
+            test rdi, Rq(1 + 2)
+            jz >skip
+        backward:
+            jmp <backward
+        skip:
+            call ->text_start
+        =>label
+            lea rax, [=>label]
+            jmp =>label
+            .byte 1, -1, 0x7F
+            .align 8
+            .dword ->text_start
+            .qword 0x1122_3344_5566_7788
+            ret
+    ").unwrap();
+    let assembled = ops.finalize().unwrap();
+
+    assert_eq!(hex(&assembled), hex(&expected), "x64_text");
+}
+
+// aarch64 text assembles to the same code as dynasm!
+#[test]
+fn aarch64_text() {
+    let mut ops = dynasmrt::aarch64::Assembler::new().unwrap();
+    let label = ops.new_dynamic_label();
+    dynasm!(ops
+        ; .arch aarch64
+        ; .alias text_base, x19
+        ; ->text_entry:
+        ; add x0, x1, x2
+        ; add w0, w1, 0xFFF
+        ; sub x3, text_base, x4, lsl 3
+        ; ldr x0, [x1, 8]
+        ; ldr x0, [x1, 16]!
+        ; ldr x0, [x1], 8
+        ; ldr w2, [x3, x4, lsl 2]
+        ; str x5, [sp, -16]!
+        ; movz x0, 0x1234, lsl 16
+        ; and x0, x1, 0xFF00
+        ; fmov d0, 1.5
+        ; add v0.s4, v1.s4, v2.s4
+        ; mov v3.s[1], w4
+        ; ld1 {v0.b16, v1.b16}, [x0]
+        ; st1 {v4.s4 * 3}, [x1]
+        ; mrs x0, nzcv
+        ; dmb ish
+        ; csel x0, x1, X(2), ne
+        ; b.ne >skip
+        ; cbz x0, >done
+        ; done:
+        ; b ->text_entry
+        ; skip:
+        ; =>label
+        ; adr x1, =>label
+        ; bl =>label
+        ; .align 8
+        ; .qword ->text_entry
+        ; ret
+    );
+    let expected = ops.finalize().unwrap();
+
+    let mut ops = dynasmrt::aarch64::Assembler::new().unwrap();
+    let label = ops.new_dynamic_label();
+    let mut text = TextAssembler::new();
+    text.bind_dynamic_label("label", label);
+    text.assemble_aarch64(&mut ops, "
+        .arch aarch64
+        .feature fp
+        .alias base, x19
+        ->text_entry:
+            add x0, x1, x2
+            add w0, w1, #0xFFF
+            sub x3, base, x4, lsl #3
+            ldr x0, [x1, #8]
+            ldr x0, [x1, #16]!
+            ldr x0, [x1], #8
+            ldr w2, [x3, x4, lsl #2]
+            str x5, [sp, #-16]!
+            movz x0, 0x1234, lsl 16
+            and x0, x1, 0xFF << 8
+            fmov d0, 1.5
+            add v0.s4, v1.s4, v2.s4
+            mov v3.s[1], w4
+            ld1 {v0.b16, v1.b16}, [x0]
+            st1 {v4.s4 - v6.s4}, [x1]
+            mrs x0, nzcv
+            dmb ish
+            csel x0, x1, X(2), ne
+            b.ne >skip
+            cbz x0, >done
+        done:
+            b ->text_entry
+        skip:
+        =>label
+            adr x1, =>label
+            bl =>label
+            .align 8
+            .qword ->text_entry
+            ret
+    ").unwrap();
+    let assembled = ops.finalize().unwrap();
+
+    assert_eq!(hex(&assembled), hex(&expected), "aarch64_text");
+}
+
+// errors report the line and column they occurred at
+#[test]
+fn text_errors() {
+    fn x64_error(source: &str) -> TextError {
+        let mut ops = dynasmrt::x64::Assembler::new().unwrap();
+        TextAssembler::new().assemble_x64(&mut ops, source).unwrap_err()
+    }
+
+    let error = x64_error("mov rax, 1\n  frobnicate rax");
+    assert_eq!((error.line, error.column), (2, 3));
+    assert_eq!(error.kind, TextErrorKind::Encode(dynasmrt::x64::EncodeError::UnknownMnemonic("frobnicate".into())));
+
+    let error = x64_error("nop\nnop\n    add rax, ebx");
+    assert_eq!((error.line, error.column), (3, 5));
+    assert!(matches!(error.kind, TextErrorKind::Encode(_)));
+
+    let error = x64_error("mov rax, [rbx + ]");
+    assert_eq!((error.line, error.column), (1, 17));
+    assert!(matches!(error.kind, TextErrorKind::Parse(_)));
+
+    let error = x64_error("ret\n.frobnicate 1");
+    assert_eq!((error.line, error.column), (2, 2));
+    assert_eq!(error.kind, TextErrorKind::Directive("unknown directive 'frobnicate'".into()));
+
+    let error = x64_error(".arch aarch64");
+    assert_eq!((error.line, error.column), (1, 7));
+
+    let error = x64_error("jmp =>missing");
+    assert_eq!((error.line, error.column), (1, 5));
+    assert_eq!(error.kind, TextErrorKind::Parse("Unknown dynamic label 'missing'".into()));
+
+    let error = x64_error("mov rax, 1 2");
+    assert_eq!((error.line, error.column), (1, 12));
+    assert_eq!(error.to_string(), "1:12: Unexpected token");
+
+    let error = x64_error(".alias a, rax\n.alias a, rbx");
+    assert_eq!((error.line, error.column), (2, 8));
+
+    let error = x64_error("vaddps zmm1, zmm2{k1}, zmm3");
+    assert_eq!((error.line, error.column), (1, 18));
+    assert_eq!(error.kind, TextErrorKind::Parse("An opmask can only be applied to the destination operand".into()));
+
+    let error = x64_error("vaddps zmm1, zmm2, [rax]{1to3x}");
+    assert_eq!((error.line, error.column), (1, 25));
+    assert_eq!(error.kind, TextErrorKind::Parse("Invalid broadcast, expected {1toN}".into()));
+
+    let error = x64_error("vaddps zmm1, zmm2, zmm3, {rn-sae}, {sae}");
+    assert_eq!((error.line, error.column), (1, 36));
+    assert_eq!(error.kind, TextErrorKind::Parse("Duplicate rounding specifier".into()));
+
+    let error = x64_error("vaddps zmm1, zmm2, [rax]{1to8}");
+    assert_eq!((error.line, error.column), (1, 1));
+    assert_eq!(error.kind, TextErrorKind::Encode(dynasmrt::x64::EncodeError::InvalidOperand("Invalid broadcast, expected {1to16}".into())));
+
+    let mut ops = dynasmrt::aarch64::Assembler::new().unwrap();
+    let error = TextAssembler::new().assemble_aarch64(&mut ops, "ldr x0, [x1, #8]\nadd x0, x1, #0x1001001").unwrap_err();
+    assert_eq!((error.line, error.column), (2, 1));
+    assert!(matches!(error.kind, TextErrorKind::Encode(dynasmrt::aarch64::EncodeError::InvalidOperand(_))));
+
+    let error = TextAssembler::new().assemble_aarch64(&mut ops, "nop\n  add z0.s, z1.s, z2.s").unwrap_err();
+    assert_eq!((error.line, error.column), (2, 3));
+    assert_eq!(error.kind, TextErrorKind::Encode(dynasmrt::aarch64::EncodeError::Unsupported(
+        "SVE and SME forms of 'add' cannot be encoded at runtime".into())));
+}
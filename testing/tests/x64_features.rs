@@ -0,0 +1,31 @@
+#![feature(proc_macro_hygiene)]
+#![allow(unused_imports)]
+
+#[macro_use]
+extern crate dynasmrt;
+extern crate dynasm;
+
+use dynasm::dynasm;
+use dynasmrt::DynasmApi;
+
+// instructions from the listed extensions can be used after they have been declared with .feature.
+// Using them without is tested by the compile_fail doctests in src/lib.rs
+#[test]
+fn features_listed() {
+    let mut ops = dynasmrt::x64::Assembler::new().unwrap();
+    dynasm!(ops
+        ; .arch x64
+        ; .feature sse42, fsgsbase, prfchw
+        ; crc32 eax, cl
+        ; crc32 rax, QWORD [rcx]
+        ; rdfsbase eax
+        ; rdgsbase rcx
+        ; wrfsbase rdx
+        ; wrgsbase ebx
+        ; prefetchw [rax]
+    );
+    let buf = ops.finalize().unwrap();
+    let hex: Vec<String> = buf.iter().map(|x| format!("{:02X}", *x)).collect();
+    let hex = hex.join(", ");
+    assert_eq!(hex, "F2, 0F, 38, F0, C1, F2, 48, 0F, 38, F1, 01, F3, 0F, AE, C0, F3, 48, 0F, AE, C9, F3, 48, 0F, AE, D2, F3, 0F, AE, DB, 0F, 0D, 08", "features_listed");
+}
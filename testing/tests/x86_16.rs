@@ -0,0 +1,157 @@
+#![feature(proc_macro_hygiene)]
+#![allow(unused_imports)]
+
+extern crate dynasmrt;
+extern crate dynasm;
+
+use dynasm::dynasm;
+use dynasmrt::{DynasmApi, DynasmLabelApi};
+
+// 16-bit operands are the default, 32-bit operands need an operand size prefix
+#[test]
+fn x86_16_operand_size() {
+    let mut ops = dynasmrt::x86_16::Assembler::new().unwrap();
+    dynasm!(ops
+        ; .arch x86_16
+        ; mov ax, bx
+        ; mov eax, ebx
+        ; add ax, 5
+        ; add eax, 0x12345
+        ; push ax
+        ; push eax
+        ; cbw
+        ; cwde
+        ; lodsw
+        ; lodsd
+        ; pushf
+        ; pushfd
+        ; movzx eax, BYTE [di]
+        ; movzx ax, BYTE [di]
+        ; bswap eax
+        ; in eax, dx
+        ; out 0x60, ax
+        ; mov cr0, eax
+        ; movd mm0, eax
+        ; paddd xmm0, xmm1
+    );
+    let buf = ops.finalize().unwrap();
+    assert_eq!(&*buf, &[
+        0x89, 0xD8,
+        0x66, 0x89, 0xD8,
+        0x83, 0xC0, 0x05,
+        0x66, 0x05, 0x45, 0x23, 0x01, 0x00,
+        0x50,
+        0x66, 0x50,
+        0x98,
+        0x66, 0x98,
+        0xAD,
+        0x66, 0xAD,
+        0x9C,
+        0x66, 0x9C,
+        0x66, 0x0F, 0xB6, 0x05,
+        0x0F, 0xB6, 0x05,
+        0x66, 0x0F, 0xC8,
+        0x66, 0xED,
+        0xE7, 0x60,
+        0x0F, 0x22, 0xC0,
+        0x0F, 0x6E, 0xC0,
+        0x66, 0x0F, 0xFE, 0xC1,
+    ][..]);
+}
+
+// 16-bit addressing forms, and 32-bit addressing with an address size prefix
+#[test]
+fn x86_16_addressing() {
+    let mut ops = dynasmrt::x86_16::Assembler::new().unwrap();
+    dynasm!(ops
+        ; .arch x86_16
+        ; mov ax, [bx + si + 4]
+        ; mov ax, [bp]
+        ; mov cx, [bp + di + 0x1234]
+        ; mov dx, [0x1234]
+        ; mov ax, [si]
+        ; mov ax, [di + bx]
+        ; mov eax, [ebx + ecx * 4]
+        ; jecxz >start
+        ; start:
+    );
+    let buf = ops.finalize().unwrap();
+    assert_eq!(&*buf, &[
+        0x8B, 0x40, 0x04,
+        0x8B, 0x46, 0x00,
+        0x8B, 0x8B, 0x34, 0x12,
+        0x8B, 0x16, 0x34, 0x12,
+        0x8B, 0x04,
+        0x8B, 0x01,
+        0x67, 0x66, 0x8B, 0x04, 0x8B,
+        0x67, 0xE3, 0x00,
+    ][..]);
+}
+
+// far jumps and calls take an offset followed by a segment
+#[test]
+fn x86_16_far() {
+    let mut ops = dynasmrt::x86_16::Assembler::new().unwrap();
+    dynasm!(ops
+        ; .arch x86_16
+        ; jmp WORD 0x5678, WORD 0x1234
+        ; call WORD 0x5678, WORD 0x1234
+        ; jmp DWORD 0x12345678, WORD 0x1234
+        ; callf DWORD [bx]
+        ; callf FWORD [bx]
+        ; jmp WORD [si]
+        ; retf
+    );
+    let buf = ops.finalize().unwrap();
+    assert_eq!(&*buf, &[
+        0xEA, 0x78, 0x56, 0x34, 0x12,
+        0x9A, 0x78, 0x56, 0x34, 0x12,
+        0x66, 0xEA, 0x78, 0x56, 0x34, 0x12, 0x34, 0x12,
+        0xFF, 0x1F,
+        0x66, 0xFF, 0x1F,
+        0xFF, 0x24,
+        0xCB,
+    ][..]);
+}
+
+// near jumps use 16-bit displacements, which wrap around within the segment
+#[test]
+fn x86_16_jumps() {
+    let mut ops = dynasmrt::x86_16::Assembler::new().unwrap();
+    dynasm!(ops
+        ; .arch x86_16
+        ; back:
+        ; jmp >done
+        ; jz >done
+        ; call <back
+        ; jnz BYTE <back
+        ; done:
+        ; ret
+    );
+    let buf = ops.finalize().unwrap();
+    assert_eq!(&*buf, &[
+        0xE9, 0x09, 0x00,
+        0x0F, 0x84, 0x05, 0x00,
+        0xE8, 0xF6, 0xFF,
+        0x75, 0xF4,
+        0xC3,
+    ][..]);
+
+    let mut ops = dynasmrt::x86_16::Assembler::new().unwrap();
+    dynasm!(ops
+        ; .arch x86_16
+        ; jmp >done
+    );
+    for _ in 0 .. 0xC000 {
+        dynasm!(ops
+            ; .arch x86_16
+            ; nop
+        );
+    }
+    dynasm!(ops
+        ; .arch x86_16
+        ; done:
+    );
+    let buf = ops.finalize().unwrap();
+    assert_eq!(&buf[.. 3], &[0xE9, 0x00, 0xC0]);
+}
@@ -0,0 +1,307 @@
+# The architecture extensions required by instructions in the opmap, as an expression of the
+# Features constants from plugin/src/arch/aarch64/aarch64data.rs. Entries from tl_sve.py, tl_sve2.py
+# and tl_sme.py default to SVE, SVE2 and SME respectively, everything else defaults to the ARMv8.0
+# baseline. Each entry overrides that for the listed mnemonics, either for all of their forms, or
+# only for the forms using the listed matchers.
+
+tlfeature('LSE', [
+    'CAS', 'CASA', 'CASAB', 'CASAH', 'CASAL', 'CASALB', 'CASALH', 'CASB', 'CASH', 'CASL', 'CASLB',
+    'CASLH', 'CASP', 'CASPA', 'CASPAL', 'CASPL', 'LDADD', 'LDADDA', 'LDADDAB', 'LDADDAH', 'LDADDAL',
+    'LDADDALB', 'LDADDALH', 'LDADDB', 'LDADDH', 'LDADDL', 'LDADDLB', 'LDADDLH', 'LDCLR', 'LDCLRA',
+    'LDCLRAB', 'LDCLRAH', 'LDCLRAL', 'LDCLRALB', 'LDCLRALH', 'LDCLRB', 'LDCLRH', 'LDCLRL',
+    'LDCLRLB', 'LDCLRLH', 'LDEOR', 'LDEORA', 'LDEORAB', 'LDEORAH', 'LDEORAL', 'LDEORALB',
+    'LDEORALH', 'LDEORB', 'LDEORH', 'LDEORL', 'LDEORLB', 'LDEORLH', 'LDSET', 'LDSETA', 'LDSETAB',
+    'LDSETAH', 'LDSETAL', 'LDSETALB', 'LDSETALH', 'LDSETB', 'LDSETH', 'LDSETL', 'LDSETLB',
+    'LDSETLH', 'LDSMAX', 'LDSMAXA', 'LDSMAXAB', 'LDSMAXAH', 'LDSMAXAL', 'LDSMAXALB', 'LDSMAXALH',
+    'LDSMAXB', 'LDSMAXH', 'LDSMAXL', 'LDSMAXLB', 'LDSMAXLH', 'LDSMIN', 'LDSMINA', 'LDSMINAB',
+    'LDSMINAH', 'LDSMINAL', 'LDSMINALB', 'LDSMINALH', 'LDSMINB', 'LDSMINH', 'LDSMINL', 'LDSMINLB',
+    'LDSMINLH', 'LDUMAX', 'LDUMAXA', 'LDUMAXAB', 'LDUMAXAH', 'LDUMAXAL', 'LDUMAXALB', 'LDUMAXALH',
+    'LDUMAXB', 'LDUMAXH', 'LDUMAXL', 'LDUMAXLB', 'LDUMAXLH', 'LDUMIN', 'LDUMINA', 'LDUMINAB',
+    'LDUMINAH', 'LDUMINAL', 'LDUMINALB', 'LDUMINALH', 'LDUMINB', 'LDUMINH', 'LDUMINL', 'LDUMINLB',
+    'LDUMINLH', 'STADD', 'STADDB', 'STADDH', 'STADDL', 'STADDLB', 'STADDLH', 'STCLR', 'STCLRB',
+    'STCLRH', 'STCLRL', 'STCLRLB', 'STCLRLH', 'STEOR', 'STEORB', 'STEORH', 'STEORL', 'STEORLB',
+    'STEORLH', 'STSET', 'STSETB', 'STSETH', 'STSETL', 'STSETLB', 'STSETLH', 'STSMAX', 'STSMAXB',
+    'STSMAXH', 'STSMAXL', 'STSMAXLB', 'STSMAXLH', 'STSMIN', 'STSMINB', 'STSMINH', 'STSMINL',
+    'STSMINLB', 'STSMINLH', 'STUMAX', 'STUMAXB', 'STUMAXH', 'STUMAXL', 'STUMAXLB', 'STUMAXLH',
+    'STUMIN', 'STUMINB', 'STUMINH', 'STUMINL', 'STUMINLB', 'STUMINLH', 'SWP', 'SWPA', 'SWPAB',
+    'SWPAH', 'SWPAL', 'SWPALB', 'SWPALH', 'SWPB', 'SWPH', 'SWPL', 'SWPLB', 'SWPLH',
+])
+
+tlfeature('CRC', [
+    'CRC32B', 'CRC32CB', 'CRC32CH', 'CRC32CW', 'CRC32CX', 'CRC32H', 'CRC32W', 'CRC32X',
+])
+
+tlfeature('AES', ['AESD', 'AESE', 'AESIMC', 'AESMC'], matchers = [
+    'VStatic(BYTE, 16), VStatic(BYTE, 16)',
+])
+
+tlfeature('AES', ['PMULL'], matchers = [
+    'VStatic(OWORD, 1), VStatic(QWORD, 1), VStatic(QWORD, 1)',
+])
+
+tlfeature('AES', ['PMULL2'], matchers = [
+    'VStatic(OWORD, 1), VStatic(QWORD, 2), VStatic(QWORD, 2)',
+])
+
+tlfeature('SVE2 | AES', ['AESIMC', 'AESMC'], matchers = [
+    'Z(BYTE), Z(BYTE)',
+])
+
+tlfeature('SVE2 | AES', ['AESD', 'AESE'], matchers = [
+    'Z(BYTE), Z(BYTE), Z(BYTE)',
+])
+
+tlfeature('SHA2', [
+    'SHA1C', 'SHA1H', 'SHA1M', 'SHA1P', 'SHA1SU0', 'SHA1SU1', 'SHA256H', 'SHA256H2', 'SHA256SU0',
+    'SHA256SU1',
+])
+
+tlfeature('SHA3', ['SHA512H', 'SHA512H2', 'SHA512SU0', 'SHA512SU1'])
+
+tlfeature('SHA3', ['BCAX', 'EOR3'], matchers = [
+    'VStatic(BYTE, 16), VStatic(BYTE, 16), VStatic(BYTE, 16), VStatic(BYTE, 16)',
+])
+
+tlfeature('SHA3', ['RAX1'], matchers = [
+    'VStatic(QWORD, 2), VStatic(QWORD, 2), VStatic(QWORD, 2)',
+])
+
+tlfeature('SHA3', ['XAR'], matchers = [
+    'VStatic(QWORD, 2), VStatic(QWORD, 2), VStatic(QWORD, 2), Imm',
+])
+
+tlfeature('SVE2 | SHA3', ['RAX1'], matchers = [
+    'Z(QWORD), Z(QWORD), Z(QWORD)',
+])
+
+tlfeature('SM4', [
+    'SM3PARTW1', 'SM3PARTW2', 'SM3SS1', 'SM3TT1A', 'SM3TT1B', 'SM3TT2A', 'SM3TT2B',
+])
+
+tlfeature('SM4', ['SM4E'], matchers = [
+    'VStatic(DWORD, 4), VStatic(DWORD, 4)',
+])
+
+tlfeature('SM4', ['SM4EKEY'], matchers = [
+    'VStatic(DWORD, 4), VStatic(DWORD, 4), VStatic(DWORD, 4)',
+])
+
+tlfeature('SVE2 | SM4', ['SM4E', 'SM4EKEY'], matchers = [
+    'Z(DWORD), Z(DWORD), Z(DWORD)',
+])
+
+tlfeature('FP16', ['FRECPX'], matchers = [
+    'H, H',
+])
+
+tlfeature('FP16', ['FCMP', 'FCMPE'], matchers = [
+    'H, H',
+    'H, LitFloat(0.0)',
+])
+
+tlfeature('FP16', ['FRECPE', 'FRSQRTE'], matchers = [
+    'H, H',
+    'V(WORD), V(WORD)',
+])
+
+tlfeature('FP16', [
+    'FCVTAS', 'FCVTAU', 'FCVTMS', 'FCVTMU', 'FCVTNS', 'FCVTNU', 'FCVTPS', 'FCVTPU',
+], matchers = [
+    'H, H',
+    'V(WORD), V(WORD)',
+    'W, H',
+    'X, H',
+])
+
+tlfeature('FP16', ['FNMUL'], matchers = [
+    'H, H, H',
+])
+
+tlfeature('FP16', ['FABD', 'FACGE', 'FACGT', 'FRECPS', 'FRSQRTS'], matchers = [
+    'H, H, H',
+    'V(WORD), V(WORD), V(WORD)',
+])
+
+tlfeature('FP16', ['FCMEQ', 'FCMGE', 'FCMGT'], matchers = [
+    'H, H, H',
+    'V(WORD), V(WORD), V(WORD)',
+    'H, H, LitFloat(0.0)',
+    'V(WORD), V(WORD), LitFloat(0.0)',
+])
+
+tlfeature('FP16', ['FCSEL'], matchers = [
+    'H, H, H, Cond',
+])
+
+tlfeature('FP16', ['FMADD', 'FMSUB', 'FNMADD', 'FNMSUB'], matchers = [
+    'H, H, H, H',
+])
+
+tlfeature('FP16', ['SCVTF', 'UCVTF'], matchers = [
+    'H, H, Imm',
+    'V(WORD), V(WORD), Imm',
+    'H, H',
+    'V(WORD), V(WORD)',
+    'H, W, Imm',
+    'H, X, Imm',
+    'H, W',
+    'H, X',
+])
+
+tlfeature('FP16', ['FCVTZS', 'FCVTZU'], matchers = [
+    'H, H, Imm',
+    'V(WORD), V(WORD), Imm',
+    'H, H',
+    'V(WORD), V(WORD)',
+    'W, H, Imm',
+    'X, H, Imm',
+    'W, H',
+    'X, H',
+])
+
+tlfeature('FP16', ['FCCMP', 'FCCMPE'], matchers = [
+    'H, H, Imm, Cond',
+])
+
+tlfeature('FP16', ['FCMLE', 'FCMLT'], matchers = [
+    'H, H, LitFloat(0.0)',
+    'V(WORD), V(WORD), LitFloat(0.0)',
+])
+
+tlfeature('FP16', ['FMULX'], matchers = [
+    'H, H, VElement(WORD)',
+    'V(WORD), V(WORD), VElement(WORD)',
+    'H, H, H',
+    'V(WORD), V(WORD), V(WORD)',
+])
+
+tlfeature('FP16', ['FMLA', 'FMLS'], matchers = [
+    'H, H, VElement(WORD)',
+    'V(WORD), V(WORD), VElement(WORD)',
+    'V(WORD), V(WORD), V(WORD)',
+])
+
+tlfeature('FP16', ['FMUL'], matchers = [
+    'H, H, VElement(WORD)',
+    'V(WORD), V(WORD), VElement(WORD)',
+    'V(WORD), V(WORD), V(WORD)',
+    'H, H, H',
+])
+
+tlfeature('FP16', ['FMAXNMV', 'FMAXV', 'FMINNMV', 'FMINV'], matchers = [
+    'H, V(WORD)',
+])
+
+tlfeature('FP16', ['FADDP', 'FMAXNMP', 'FMAXP', 'FMINNMP', 'FMINP'], matchers = [
+    'H, VStatic(WORD, 2)',
+    'V(WORD), V(WORD), V(WORD)',
+])
+
+tlfeature('FP16', ['FMOV'], matchers = [
+    'V(WORD), Imm',
+    'H, H',
+    'W, H',
+    'X, H',
+    'H, W',
+    'H, X',
+    'H, Imm',
+])
+
+tlfeature('FP16', [
+    'FABS', 'FNEG', 'FRINTA', 'FRINTI', 'FRINTM', 'FRINTN', 'FRINTP', 'FRINTX', 'FRINTZ', 'FSQRT',
+], matchers = [
+    'V(WORD), V(WORD)',
+    'H, H',
+])
+
+tlfeature('FP16', ['FADD', 'FDIV', 'FMAX', 'FMAXNM', 'FMIN', 'FMINNM', 'FSUB'], matchers = [
+    'V(WORD), V(WORD), V(WORD)',
+    'H, H, H',
+])
+
+tlfeature('FCMA | FP16', ['FCADD'], matchers = [
+    'V(WORD), V(WORD), V(WORD), Imm',
+])
+
+tlfeature('FCMA | FP16', ['FCMLA'], matchers = [
+    'VStatic(WORD, 4), VStatic(WORD, 4), VElement(WORD), Imm',
+    'VStatic(WORD, 8), VStatic(WORD, 8), VElement(WORD), Imm',
+    'V(WORD), V(WORD), V(WORD), Imm',
+])
+
+tlfeature('FHM', ['FMLAL', 'FMLAL2', 'FMLSL', 'FMLSL2'])
+
+tlfeature('RDM', ['SQRDMLAH', 'SQRDMLSH'])
+
+tlfeature('DOTPROD', ['SDOT', 'UDOT'], matchers = [
+    'VStatic(DWORD, 2), VStatic(BYTE, 8), VStaticElement(BYTE, 4)',
+    'VStatic(DWORD, 4), VStatic(BYTE, 16), VStaticElement(BYTE, 4)',
+    'VStatic(DWORD, 2), VStatic(BYTE, 8), VStatic(BYTE, 8)',
+    'VStatic(DWORD, 4), VStatic(BYTE, 16), VStatic(BYTE, 16)',
+])
+
+tlfeature('RCPC', ['LDAPR', 'LDAPRB', 'LDAPRH'])
+
+tlfeature('RCPC2', [
+    'LDAPUR', 'LDAPURB', 'LDAPURH', 'LDAPURSB', 'LDAPURSH', 'LDAPURSW', 'STLUR', 'STLURB', 'STLURH',
+])
+
+tlfeature('FCMA', ['FCADD'], matchers = [
+    'V(DWORD), V(DWORD), V(DWORD), Imm',
+    'VStatic(QWORD, 2), VStatic(QWORD, 2), VStatic(QWORD, 2), Imm',
+])
+
+tlfeature('FCMA', ['FCMLA'], matchers = [
+    'VStatic(DWORD, 4), VStatic(DWORD, 4), VElement(DWORD), Imm',
+    'V(DWORD), V(DWORD), V(DWORD), Imm',
+    'VStatic(QWORD, 2), VStatic(QWORD, 2), VStatic(QWORD, 2), Imm',
+])
+
+tlfeature('JSCONV', ['FJCVTZS'])
+
+tlfeature('FLAGM', ['CFINV', 'RMIF', 'SETF16', 'SETF8'])
+
+tlfeature('SB', ['SB'])
+
+tlfeature('LOR', ['LDLAR', 'LDLARB', 'LDLARH', 'STLLR', 'STLLRB', 'STLLRH'])
+
+tlfeature('RAS', ['ESB'])
+
+tlfeature('SPE', ['PSB'])
+
+tlfeature('TRF', ['TSB'])
+
+tlfeature('PREDRES', ['CFP', 'CPP', 'DVP'])
+
+tlfeature('BF16', ['BFCVT', 'BFCVTN', 'BFCVTN2', 'BFDOT', 'BFMLALB', 'BFMLALT', 'BFMMLA'])
+
+tlfeature('I8MM', ['SMMLA', 'SUDOT', 'UMMLA', 'USDOT', 'USMMLA'])
+
+tlfeature('MTE', [
+    'ADDG', 'CMPP', 'GMI', 'IRG', 'LDG', 'LDGM', 'ST2G', 'STG', 'STGM', 'STGP', 'STZ2G', 'STZG',
+    'STZGM', 'SUBG', 'SUBP', 'SUBPS',
+])
+
+tlfeature('PAUTH', [
+    'AUTDA', 'AUTDB', 'AUTDZA', 'AUTDZB', 'AUTIA', 'AUTIA1716', 'AUTIASP', 'AUTIAZ', 'AUTIB',
+    'AUTIB1716', 'AUTIBSP', 'AUTIBZ', 'AUTIZA', 'AUTIZB', 'BLRAA', 'BLRAAZ', 'BLRAB', 'BLRABZ',
+    'BRAA', 'BRAAZ', 'BRAB', 'BRABZ', 'ERETAA', 'ERETAB', 'LDRAA', 'LDRAB', 'PACDA', 'PACDB',
+    'PACDZA', 'PACDZB', 'PACGA', 'PACIA', 'PACIA1716', 'PACIASP', 'PACIAZ', 'PACIB', 'PACIB1716',
+    'PACIBSP', 'PACIBZ', 'PACIZA', 'PACIZB', 'RETAA', 'RETAB', 'XPACD', 'XPACI', 'XPACLRI',
+])
+
+tlfeature('BTI', ['BTI'])
+
+tlfeature('SME | SME_I16I64', ['ADDHA', 'ADDVA'], matchers = [
+    'ZaTile(QWORD), PMerge, PMerge, Z(QWORD)',
+])
+
+tlfeature('SME | SME_I16I64', [
+    'SMOPA', 'SMOPS', 'SUMOPA', 'SUMOPS', 'UMOPA', 'UMOPS', 'USMOPA', 'USMOPS',
+], matchers = [
+    'ZaTile(QWORD), PMerge, PMerge, Z(WORD), Z(WORD)',
+])
+
+tlfeature('SME | SME_F64F64', ['FMOPA', 'FMOPS'], matchers = [
+    'ZaTile(QWORD), PMerge, PMerge, Z(QWORD), Z(QWORD)',
+])
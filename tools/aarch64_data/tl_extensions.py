@@ -0,0 +1,275 @@
+# Instructions from the ARMv8.5 and ARMv8.6 extensions (BTI, MTE, BF16 and I8MM) are not part of
+# the instruction set versions read from the machine readable specification by
+# aarch64_gen_opmap.py, so like the entries in tl_sve2.py these carry the base encoding of each of
+# their mnemonics (and optionally the heading they are listed under) themselves.
+
+# BTI
+
+tlentry(['BTI'],
+    '{<targets>}', (),
+    encodings = {
+        'BTI': '11010101000000110010010000011111',
+    },
+    matchers = [
+        '',
+        'Lit("c")',
+        'Lit("j")',
+        'Lit("jc")',
+    ],
+    processors = [
+        '',
+        'Static(6, 0b01)',
+        'Static(6, 0b10)',
+        'Static(6, 0b11)',
+    ],
+)
+
+# MTE
+
+tlentry(['ADDG', 'SUBG'],
+    '<Xd|SP>,<Xn|SP>,#<uimm6>,#<uimm4>', (),
+    encodings = {
+        'ADDG': '10010001100000000000000000000000',
+        'SUBG': '11010001100000000000000000000000',
+    },
+    matcher   = 'XSP, XSP, Imm, Imm',
+    processor = 'R(0), R(5), Uscaled(16, 6, 4), Ubits(10, 4)',
+)
+
+tlentry(['CMPP'],
+    '<Xn|SP>,<Xm|SP>', (),
+    encodings = {
+        'CMPP': '10111010110000000000000000011111',
+    },
+    matcher   = 'XSP, XSP',
+    processor = 'R(5), R(16)',
+)
+
+tlentry(['GMI'],
+    '<Xd>,<Xn|SP>,<Xm>', (),
+    encodings = {
+        'GMI': '10011010110000000001010000000000',
+    },
+    matcher   = 'X, XSP, X',
+    processor = 'R(0), R(5), R(16)',
+)
+
+tlentry(['IRG'],
+    '<Xd|SP>,<Xn|SP>{,<Xm>}', (),
+    encodings = {
+        'IRG': '10011010110000000001000000000000',
+    },
+    matchers = [
+        'XSP, XSP, X',
+        'XSP, XSP',
+    ],
+    processors = [
+        'R(0), R(5), R(16)',
+        'R(0), R(5), Static(16, 0b11111)',
+    ],
+)
+
+tlentry(['LDG'],
+    '<Xt>,[<Xn|SP>{,#<simm>}]', (),
+    encodings = {
+        'LDG': '11011001011000000000000000000000',
+    },
+    matcher   = 'X, RefOffset',
+    processor = 'R(0), R(5), Sscaled(12, 9, 4)',
+)
+
+tlentry(['LDGM', 'STGM', 'STZGM'],
+    '<Xt>,[<Xn|SP>]', (),
+    encodings = {
+        'LDGM': '11011001111000000000000000000000',
+        'STGM': '11011001101000000000000000000000',
+        'STZGM': '11011001001000000000000000000000',
+    },
+    matcher   = 'X, RefBase',
+    processor = 'R(0), R(5)',
+)
+
+tlentry(['ST2G', 'STG', 'STZ2G', 'STZG'],
+    '<Xt|SP>,[<Xn|SP>{,#<simm>}]', (),
+    encodings = {
+        'ST2G': '11011001101000000000000000000000',
+        'STG': '11011001001000000000000000000000',
+        'STZ2G': '11011001111000000000000000000000',
+        'STZG': '11011001011000000000000000000000',
+    },
+    matchers = [
+        'XSP, RefOffset',
+        'XSP, RefPre',
+        'XSP, RefBase, Imm',
+    ],
+    processors = [
+        'R(0), R(5), Sscaled(12, 9, 4), Static(10, 0b10)',
+        'R(0), R(5), Sscaled(12, 9, 4), Static(10, 0b11)',
+        'R(0), R(5), Sscaled(12, 9, 4), Static(10, 0b01)',
+    ],
+)
+
+tlentry(['STGP'],
+    '<Xt1>,<Xt2>,[<Xn|SP>{,#<imm>}]', (),
+    encodings = {
+        'STGP': '01101000000000000000000000000000',
+    },
+    matchers = [
+        'X, X, RefOffset',
+        'X, X, RefPre',
+        'X, X, RefBase, Imm',
+    ],
+    processors = [
+        'R(0), R(10), R(5), Sscaled(15, 7, 4), Static(23, 0b10)',
+        'R(0), R(10), R(5), Sscaled(15, 7, 4), Static(23, 0b11)',
+        'R(0), R(10), R(5), Sscaled(15, 7, 4), Static(23, 0b01)',
+    ],
+)
+
+tlentry(['SUBP', 'SUBPS'],
+    '<Xd>,<Xn|SP>,<Xm|SP>', (),
+    encodings = {
+        'SUBP': '10011010110000000000000000000000',
+        'SUBPS': '10111010110000000000000000000000',
+    },
+    matcher   = 'X, XSP, XSP',
+    processor = 'R(0), R(5), R(16)',
+)
+
+# BF16
+
+tlentry(['BFCVT'],
+    '<Hd>,<Sn>', (),
+    encodings = {
+        'BFCVT': '00011110011000110100000000000000',
+    },
+    matcher   = 'H, S',
+    processor = 'R(0), R(5)',
+)
+
+tlentry(['BFCVTN'],
+    '<Vd>.4H,<Vn>.4S', (),
+    encodings = {
+        'BFCVTN': '00001110101000010110100000000000',
+    },
+    matcher   = 'VStatic(WORD, 4), VStatic(DWORD, 4)',
+    processor = 'R(0), R(5)',
+)
+
+tlentry(['BFCVTN2'],
+    '<Vd>.8H,<Vn>.4S', (),
+    encodings = {
+        'BFCVTN2': '01001110101000010110100000000000',
+    },
+    matcher   = 'VStatic(WORD, 8), VStatic(DWORD, 4)',
+    processor = 'R(0), R(5)',
+)
+
+tlentry(['BFDOT'],
+    '<Vd>.<Ta>,<Vn>.<Tb>,<Vm>.2H[<index>]', (),
+    encodings = {
+        'BFDOT': '00001111010000001111000000000000',
+    },
+    heading = 'by element',
+    matchers = [
+        'VStatic(DWORD, 2), VStatic(WORD, 4), VStaticElement(WORD, 2)',
+        'VStatic(DWORD, 4), VStatic(WORD, 8), VStaticElement(WORD, 2)',
+    ],
+    processors = [
+        'R(0), R(5), R(16), Ufields(&[11, 21])',
+        'R(0), R(5), R(16), Ufields(&[11, 21]), Static(30, 0b1)',
+    ],
+)
+
+tlentry(['BFDOT'],
+    '<Vd>.<Ta>,<Vn>.<Tb>,<Vm>.<Tb>', (),
+    encodings = {
+        'BFDOT': '00101110010000001111110000000000',
+    },
+    heading = 'vector',
+    matchers = [
+        'VStatic(DWORD, 2), VStatic(WORD, 4), VStatic(WORD, 4)',
+        'VStatic(DWORD, 4), VStatic(WORD, 8), VStatic(WORD, 8)',
+    ],
+    processors = [
+        'R(0), R(5), R(16)',
+        'R(0), R(5), R(16), Static(30, 0b1)',
+    ],
+)
+
+tlentry(['BFMLALB', 'BFMLALT'],
+    '<Vd>.4S,<Vn>.8H,<Vm>.H[<index>]', (),
+    encodings = {
+        'BFMLALB': '00001111110000001111000000000000',
+        'BFMLALT': '01001111110000001111000000000000',
+    },
+    heading = 'by element',
+    matcher   = 'VStatic(DWORD, 4), VStatic(WORD, 8), VElement(WORD)',
+    processor = 'R(0), R(5), R4(16), Ufields(&[11, 21, 20])',
+)
+
+tlentry(['BFMLALB', 'BFMLALT'],
+    '<Vd>.4S,<Vn>.8H,<Vm>.8H', (),
+    encodings = {
+        'BFMLALB': '00101110110000001111110000000000',
+        'BFMLALT': '01101110110000001111110000000000',
+    },
+    heading = 'vector',
+    matcher   = 'VStatic(DWORD, 4), VStatic(WORD, 8), VStatic(WORD, 8)',
+    processor = 'R(0), R(5), R(16)',
+)
+
+tlentry(['BFMMLA'],
+    '<Vd>.4S,<Vn>.8H,<Vm>.8H', (),
+    encodings = {
+        'BFMMLA': '01101110010000001110110000000000',
+    },
+    matcher   = 'VStatic(DWORD, 4), VStatic(WORD, 8), VStatic(WORD, 8)',
+    processor = 'R(0), R(5), R(16)',
+)
+
+# I8MM
+
+tlentry(['SMMLA', 'UMMLA', 'USMMLA'],
+    '<Vd>.4S,<Vn>.16B,<Vm>.16B', (),
+    encodings = {
+        'SMMLA': '01001110100000001010010000000000',
+        'UMMLA': '01101110100000001010010000000000',
+        'USMMLA': '01001110100000001010110000000000',
+    },
+    matcher   = 'VStatic(DWORD, 4), VStatic(BYTE, 16), VStatic(BYTE, 16)',
+    processor = 'R(0), R(5), R(16)',
+)
+
+tlentry(['SUDOT', 'USDOT'],
+    '<Vd>.<Ta>,<Vn>.<Tb>,<Vm>.4B[<index>]', (),
+    encodings = {
+        'SUDOT': '00001111000000001111000000000000',
+        'USDOT': '00001111100000001111000000000000',
+    },
+    heading = 'by element',
+    matchers = [
+        'VStatic(DWORD, 2), VStatic(BYTE, 8), VStaticElement(BYTE, 4)',
+        'VStatic(DWORD, 4), VStatic(BYTE, 16), VStaticElement(BYTE, 4)',
+    ],
+    processors = [
+        'R(0), R(5), R(16), Ufields(&[11, 21])',
+        'R(0), R(5), R(16), Ufields(&[11, 21]), Static(30, 0b1)',
+    ],
+)
+
+tlentry(['USDOT'],
+    '<Vd>.<Ta>,<Vn>.<Tb>,<Vm>.<Tb>', (),
+    encodings = {
+        'USDOT': '00001110100000001001110000000000',
+    },
+    heading = 'vector',
+    matchers = [
+        'VStatic(DWORD, 2), VStatic(BYTE, 8), VStatic(BYTE, 8)',
+        'VStatic(DWORD, 4), VStatic(BYTE, 16), VStatic(BYTE, 16)',
+    ],
+    processors = [
+        'R(0), R(5), R(16)',
+        'R(0), R(5), R(16), Static(30, 0b1)',
+    ],
+)
@@ -0,0 +1,421 @@
+# SME postdates the v8.5 machine readable specification read by aarch64_gen_opmap.py as well, so
+# like the entries in tl_sve2.py these carry the base encoding of each of their mnemonics (and
+# optionally the heading they are listed under) themselves.
+
+tlentry(['SMSTART', 'SMSTOP'],
+    '{<option>}', (),
+    encodings = {
+        'SMSTART': '11010101000000110100000101111111',
+        'SMSTOP': '11010101000000110100000001111111',
+    },
+    matchers = [
+        '',
+        'Lit("sm")',
+        'Lit("za")',
+    ],
+    processors = [
+        'Static(9, 0b11)',
+        'Static(9, 0b01)',
+        'Static(9, 0b10)',
+    ],
+)
+
+tlentry(['RDSVL'],
+    '<Xd>,#<imm>', (),
+    encodings = {
+        'RDSVL': '00000100101111110101100000000000',
+    },
+    matcher   = 'X, Imm',
+    processor = 'R(0), Sbits(5, 6)',
+)
+
+tlentry(['ADDSPL', 'ADDSVL'],
+    '<Xd|SP>,<Xn|SP>,#<imm>', (),
+    encodings = {
+        'ADDSPL': '00000100011000000101100000000000',
+        'ADDSVL': '00000100001000000101100000000000',
+    },
+    matcher   = 'XSP, XSP, Imm',
+    processor = 'R(0), R(16), Sbits(5, 6)',
+)
+
+tlentry(['ADDHA', 'ADDVA'],
+    '<ZAda>.<T>,<Pn>/M,<Pm>/M,<Zn>.<T>', (),
+    encodings = {
+        'ADDHA': '11000000100100000000000000000000',
+        'ADDVA': '11000000100100010000000000000000',
+    },
+    matchers = [
+        'ZaTile(DWORD), PMerge, PMerge, Z(DWORD)',
+        'ZaTile(QWORD), PMerge, PMerge, Z(QWORD)',
+    ],
+    processors = [
+        'RTile(0, 2), R3(10), R3(13), R(5)',
+        'RTile(0, 3), R3(10), R3(13), R(5), Static(22, 0b1)',
+    ],
+)
+
+tlentry(['BFMOPA', 'BFMOPS'],
+    '<ZAda>.S,<Pn>/M,<Pm>/M,<Zn>.H,<Zm>.H', (),
+    encodings = {
+        'BFMOPA': '10000001100000000000000000000000',
+        'BFMOPS': '10000001100000000000000000010000',
+    },
+    matcher   = 'ZaTile(DWORD), PMerge, PMerge, Z(WORD), Z(WORD)',
+    processor = 'RTile(0, 2), R3(10), R3(13), R(5), R(16)',
+)
+
+tlentry(['FMOPA', 'FMOPS'],
+    '<ZAda>.S,<Pn>/M,<Pm>/M,<Zn>.H,<Zm>.H', (),
+    heading = 'widening',
+    encodings = {
+        'FMOPA': '10000001101000000000000000000000',
+        'FMOPS': '10000001101000000000000000010000',
+    },
+    matcher   = 'ZaTile(DWORD), PMerge, PMerge, Z(WORD), Z(WORD)',
+    processor = 'RTile(0, 2), R3(10), R3(13), R(5), R(16)',
+)
+
+tlentry(['FMOPA', 'FMOPS'],
+    '<ZAda>.<T>,<Pn>/M,<Pm>/M,<Zn>.<T>,<Zm>.<T>', (),
+    heading = 'non-widening',
+    encodings = {
+        'FMOPA': '10000000100000000000000000000000',
+        'FMOPS': '10000000100000000000000000010000',
+    },
+    matchers = [
+        'ZaTile(DWORD), PMerge, PMerge, Z(DWORD), Z(DWORD)',
+        'ZaTile(QWORD), PMerge, PMerge, Z(QWORD), Z(QWORD)',
+    ],
+    processors = [
+        'RTile(0, 2), R3(10), R3(13), R(5), R(16)',
+        'RTile(0, 3), R3(10), R3(13), R(5), R(16), Static(22, 0b1)',
+    ],
+)
+
+tlentry(['SMOPA', 'SMOPS', 'SUMOPA', 'SUMOPS', 'UMOPA', 'UMOPS', 'USMOPA', 'USMOPS'],
+    '<ZAda>.<T>,<Pn>/M,<Pm>/M,<Zn>.<Tb>,<Zm>.<Tb>', (),
+    encodings = {
+        'SMOPA': '10100000100000000000000000000000',
+        'SMOPS': '10100000100000000000000000010000',
+        'SUMOPA': '10100000101000000000000000000000',
+        'SUMOPS': '10100000101000000000000000010000',
+        'UMOPA': '10100001101000000000000000000000',
+        'UMOPS': '10100001101000000000000000010000',
+        'USMOPA': '10100001100000000000000000000000',
+        'USMOPS': '10100001100000000000000000010000',
+    },
+    matchers = [
+        'ZaTile(DWORD), PMerge, PMerge, Z(BYTE), Z(BYTE)',
+        'ZaTile(QWORD), PMerge, PMerge, Z(WORD), Z(WORD)',
+    ],
+    processors = [
+        'RTile(0, 2), R3(10), R3(13), R(5), R(16)',
+        'RTile(0, 3), R3(10), R3(13), R(5), R(16), Static(22, 0b1)',
+    ],
+)
+
+tlentry(['LD1B'],
+    '{<ZAt><HV>.B[<Ws>,<offs>]},<Pg>/Z,[<Xn|SP>{,<Xm>}]', (),
+    encodings = {
+        'LD1B': '11100000000000000000000000000000',
+    },
+    matchers = [
+        'ZaHList(BYTE), PZero, RefBase',
+        'ZaHList(BYTE), PZero, RefIndexLsl(0)',
+        'ZaVList(BYTE), PZero, RefBase',
+        'ZaVList(BYTE), PZero, RefIndexLsl(0)',
+    ],
+    processors = [
+        'RTile(4, 0), RSelect(13), Ubits(0, 4), R3(10), R(5), Static(16, 0b11111)',
+        'RTile(4, 0), RSelect(13), Ubits(0, 4), R3(10), R(5), R(16)',
+        'RTile(4, 0), RSelect(13), Ubits(0, 4), R3(10), R(5), Static(15, 0b1), Static(16, 0b11111)',
+        'RTile(4, 0), RSelect(13), Ubits(0, 4), R3(10), R(5), R(16), Static(15, 0b1)',
+    ],
+)
+
+tlentry(['LD1H'],
+    '{<ZAt><HV>.H[<Ws>,<offs>]},<Pg>/Z,[<Xn|SP>{,<Xm>,LSL #1}]', (),
+    encodings = {
+        'LD1H': '11100000010000000000000000000000',
+    },
+    matchers = [
+        'ZaHList(WORD), PZero, RefBase',
+        'ZaHList(WORD), PZero, RefIndexLsl(1)',
+        'ZaVList(WORD), PZero, RefBase',
+        'ZaVList(WORD), PZero, RefIndexLsl(1)',
+    ],
+    processors = [
+        'RTile(3, 1), RSelect(13), Ubits(0, 3), R3(10), R(5), Static(16, 0b11111)',
+        'RTile(3, 1), RSelect(13), Ubits(0, 3), R3(10), R(5), R(16)',
+        'RTile(3, 1), RSelect(13), Ubits(0, 3), R3(10), R(5), Static(15, 0b1), Static(16, 0b11111)',
+        'RTile(3, 1), RSelect(13), Ubits(0, 3), R3(10), R(5), R(16), Static(15, 0b1)',
+    ],
+)
+
+tlentry(['LD1W'],
+    '{<ZAt><HV>.S[<Ws>,<offs>]},<Pg>/Z,[<Xn|SP>{,<Xm>,LSL #2}]', (),
+    encodings = {
+        'LD1W': '11100000100000000000000000000000',
+    },
+    matchers = [
+        'ZaHList(DWORD), PZero, RefBase',
+        'ZaHList(DWORD), PZero, RefIndexLsl(2)',
+        'ZaVList(DWORD), PZero, RefBase',
+        'ZaVList(DWORD), PZero, RefIndexLsl(2)',
+    ],
+    processors = [
+        'RTile(2, 2), RSelect(13), Ubits(0, 2), R3(10), R(5), Static(16, 0b11111)',
+        'RTile(2, 2), RSelect(13), Ubits(0, 2), R3(10), R(5), R(16)',
+        'RTile(2, 2), RSelect(13), Ubits(0, 2), R3(10), R(5), Static(15, 0b1), Static(16, 0b11111)',
+        'RTile(2, 2), RSelect(13), Ubits(0, 2), R3(10), R(5), R(16), Static(15, 0b1)',
+    ],
+)
+
+tlentry(['LD1D'],
+    '{<ZAt><HV>.D[<Ws>,<offs>]},<Pg>/Z,[<Xn|SP>{,<Xm>,LSL #3}]', (),
+    encodings = {
+        'LD1D': '11100000110000000000000000000000',
+    },
+    matchers = [
+        'ZaHList(QWORD), PZero, RefBase',
+        'ZaHList(QWORD), PZero, RefIndexLsl(3)',
+        'ZaVList(QWORD), PZero, RefBase',
+        'ZaVList(QWORD), PZero, RefIndexLsl(3)',
+    ],
+    processors = [
+        'RTile(1, 3), RSelect(13), Ubits(0, 1), R3(10), R(5), Static(16, 0b11111)',
+        'RTile(1, 3), RSelect(13), Ubits(0, 1), R3(10), R(5), R(16)',
+        'RTile(1, 3), RSelect(13), Ubits(0, 1), R3(10), R(5), Static(15, 0b1), Static(16, 0b11111)',
+        'RTile(1, 3), RSelect(13), Ubits(0, 1), R3(10), R(5), R(16), Static(15, 0b1)',
+    ],
+)
+
+tlentry(['LD1Q'],
+    '{<ZAt><HV>.Q[<Ws>,<offs>]},<Pg>/Z,[<Xn|SP>{,<Xm>,LSL #4}]', (),
+    encodings = {
+        'LD1Q': '11100001110000000000000000000000',
+    },
+    matchers = [
+        'ZaHList(OWORD), PZero, RefBase',
+        'ZaHList(OWORD), PZero, RefIndexLsl(4)',
+        'ZaVList(OWORD), PZero, RefBase',
+        'ZaVList(OWORD), PZero, RefIndexLsl(4)',
+    ],
+    processors = [
+        'RTile(0, 4), RSelect(13), Urange(0, 0, 0), R3(10), R(5), Static(16, 0b11111)',
+        'RTile(0, 4), RSelect(13), Urange(0, 0, 0), R3(10), R(5), R(16)',
+        'RTile(0, 4), RSelect(13), Urange(0, 0, 0), R3(10), R(5), Static(15, 0b1), Static(16, 0b11111)',
+        'RTile(0, 4), RSelect(13), Urange(0, 0, 0), R3(10), R(5), R(16), Static(15, 0b1)',
+    ],
+)
+
+tlentry(['ST1B'],
+    '{<ZAt><HV>.B[<Ws>,<offs>]},<Pg>,[<Xn|SP>{,<Xm>}]', (),
+    encodings = {
+        'ST1B': '11100000001000000000000000000000',
+    },
+    matchers = [
+        'ZaHList(BYTE), PBare, RefBase',
+        'ZaHList(BYTE), PBare, RefIndexLsl(0)',
+        'ZaVList(BYTE), PBare, RefBase',
+        'ZaVList(BYTE), PBare, RefIndexLsl(0)',
+    ],
+    processors = [
+        'RTile(4, 0), RSelect(13), Ubits(0, 4), R3(10), R(5), Static(16, 0b11111)',
+        'RTile(4, 0), RSelect(13), Ubits(0, 4), R3(10), R(5), R(16)',
+        'RTile(4, 0), RSelect(13), Ubits(0, 4), R3(10), R(5), Static(15, 0b1), Static(16, 0b11111)',
+        'RTile(4, 0), RSelect(13), Ubits(0, 4), R3(10), R(5), R(16), Static(15, 0b1)',
+    ],
+)
+
+tlentry(['ST1H'],
+    '{<ZAt><HV>.H[<Ws>,<offs>]},<Pg>,[<Xn|SP>{,<Xm>,LSL #1}]', (),
+    encodings = {
+        'ST1H': '11100000011000000000000000000000',
+    },
+    matchers = [
+        'ZaHList(WORD), PBare, RefBase',
+        'ZaHList(WORD), PBare, RefIndexLsl(1)',
+        'ZaVList(WORD), PBare, RefBase',
+        'ZaVList(WORD), PBare, RefIndexLsl(1)',
+    ],
+    processors = [
+        'RTile(3, 1), RSelect(13), Ubits(0, 3), R3(10), R(5), Static(16, 0b11111)',
+        'RTile(3, 1), RSelect(13), Ubits(0, 3), R3(10), R(5), R(16)',
+        'RTile(3, 1), RSelect(13), Ubits(0, 3), R3(10), R(5), Static(15, 0b1), Static(16, 0b11111)',
+        'RTile(3, 1), RSelect(13), Ubits(0, 3), R3(10), R(5), R(16), Static(15, 0b1)',
+    ],
+)
+
+tlentry(['ST1W'],
+    '{<ZAt><HV>.S[<Ws>,<offs>]},<Pg>,[<Xn|SP>{,<Xm>,LSL #2}]', (),
+    encodings = {
+        'ST1W': '11100000101000000000000000000000',
+    },
+    matchers = [
+        'ZaHList(DWORD), PBare, RefBase',
+        'ZaHList(DWORD), PBare, RefIndexLsl(2)',
+        'ZaVList(DWORD), PBare, RefBase',
+        'ZaVList(DWORD), PBare, RefIndexLsl(2)',
+    ],
+    processors = [
+        'RTile(2, 2), RSelect(13), Ubits(0, 2), R3(10), R(5), Static(16, 0b11111)',
+        'RTile(2, 2), RSelect(13), Ubits(0, 2), R3(10), R(5), R(16)',
+        'RTile(2, 2), RSelect(13), Ubits(0, 2), R3(10), R(5), Static(15, 0b1), Static(16, 0b11111)',
+        'RTile(2, 2), RSelect(13), Ubits(0, 2), R3(10), R(5), R(16), Static(15, 0b1)',
+    ],
+)
+
+tlentry(['ST1D'],
+    '{<ZAt><HV>.D[<Ws>,<offs>]},<Pg>,[<Xn|SP>{,<Xm>,LSL #3}]', (),
+    encodings = {
+        'ST1D': '11100000111000000000000000000000',
+    },
+    matchers = [
+        'ZaHList(QWORD), PBare, RefBase',
+        'ZaHList(QWORD), PBare, RefIndexLsl(3)',
+        'ZaVList(QWORD), PBare, RefBase',
+        'ZaVList(QWORD), PBare, RefIndexLsl(3)',
+    ],
+    processors = [
+        'RTile(1, 3), RSelect(13), Ubits(0, 1), R3(10), R(5), Static(16, 0b11111)',
+        'RTile(1, 3), RSelect(13), Ubits(0, 1), R3(10), R(5), R(16)',
+        'RTile(1, 3), RSelect(13), Ubits(0, 1), R3(10), R(5), Static(15, 0b1), Static(16, 0b11111)',
+        'RTile(1, 3), RSelect(13), Ubits(0, 1), R3(10), R(5), R(16), Static(15, 0b1)',
+    ],
+)
+
+tlentry(['ST1Q'],
+    '{<ZAt><HV>.Q[<Ws>,<offs>]},<Pg>,[<Xn|SP>{,<Xm>,LSL #4}]', (),
+    encodings = {
+        'ST1Q': '11100001111000000000000000000000',
+    },
+    matchers = [
+        'ZaHList(OWORD), PBare, RefBase',
+        'ZaHList(OWORD), PBare, RefIndexLsl(4)',
+        'ZaVList(OWORD), PBare, RefBase',
+        'ZaVList(OWORD), PBare, RefIndexLsl(4)',
+    ],
+    processors = [
+        'RTile(0, 4), RSelect(13), Urange(0, 0, 0), R3(10), R(5), Static(16, 0b11111)',
+        'RTile(0, 4), RSelect(13), Urange(0, 0, 0), R3(10), R(5), R(16)',
+        'RTile(0, 4), RSelect(13), Urange(0, 0, 0), R3(10), R(5), Static(15, 0b1), Static(16, 0b11111)',
+        'RTile(0, 4), RSelect(13), Urange(0, 0, 0), R3(10), R(5), R(16), Static(15, 0b1)',
+    ],
+)
+
+tlentry(['LDR', 'STR'],
+    'ZA[<Wv>,<offs>],[<Xn|SP>{,#<offs>,MULVL}]', (),
+    heading = 'array vector',
+    encodings = {
+        'LDR': '11100001000000000000000000000000',
+        'STR': '11100001001000000000000000000000',
+    },
+    matcher   = 'ZaArray, RefMulVl',
+    processor = 'RSelect(13), Ubits(0, 4), R(5), Usame(2)',
+)
+
+tlentry(['MOV', 'MOVA'],
+    '<Zd>.<T>,<Pg>/M,<ZAn><HV>.<T>[<Ws>,<offs>]', (),
+    heading = 'tile to vector',
+    encodings = {
+        'MOV': '11000000000000100000000000000000',
+        'MOVA': '11000000000000100000000000000000',
+    },
+    matchers = [
+        'Z(BYTE), PMerge, ZaH(BYTE)',
+        'Z(WORD), PMerge, ZaH(WORD)',
+        'Z(DWORD), PMerge, ZaH(DWORD)',
+        'Z(QWORD), PMerge, ZaH(QWORD)',
+        'Z(OWORD), PMerge, ZaH(OWORD)',
+        'Z(BYTE), PMerge, ZaV(BYTE)',
+        'Z(WORD), PMerge, ZaV(WORD)',
+        'Z(DWORD), PMerge, ZaV(DWORD)',
+        'Z(QWORD), PMerge, ZaV(QWORD)',
+        'Z(OWORD), PMerge, ZaV(OWORD)',
+    ],
+    processors = [
+        'R(0), R3(10), RTile(9, 0), RSelect(13), Ubits(5, 4)',
+        'R(0), R3(10), RTile(8, 1), RSelect(13), Ubits(5, 3), Static(22, 0b01)',
+        'R(0), R3(10), RTile(7, 2), RSelect(13), Ubits(5, 2), Static(22, 0b10)',
+        'R(0), R3(10), RTile(6, 3), RSelect(13), Ubits(5, 1), Static(22, 0b11)',
+        'R(0), R3(10), RTile(5, 4), RSelect(13), Urange(5, 0, 0), Static(16, 0b1), Static(22, 0b11)',
+        'R(0), R3(10), RTile(9, 0), RSelect(13), Ubits(5, 4), Static(15, 0b1)',
+        'R(0), R3(10), RTile(8, 1), RSelect(13), Ubits(5, 3), Static(15, 0b1), Static(22, 0b01)',
+        'R(0), R3(10), RTile(7, 2), RSelect(13), Ubits(5, 2), Static(15, 0b1), Static(22, 0b10)',
+        'R(0), R3(10), RTile(6, 3), RSelect(13), Ubits(5, 1), Static(15, 0b1), Static(22, 0b11)',
+        'R(0), R3(10), RTile(5, 4), RSelect(13), Urange(5, 0, 0), Static(15, 0b1), Static(16, 0b1), Static(22, 0b11)',
+    ],
+)
+
+tlentry(['MOV', 'MOVA'],
+    '<ZAd><HV>.<T>[<Ws>,<offs>],<Pg>/M,<Zn>.<T>', (),
+    heading = 'vector to tile',
+    encodings = {
+        'MOV': '11000000000000000000000000000000',
+        'MOVA': '11000000000000000000000000000000',
+    },
+    matchers = [
+        'ZaH(BYTE), PMerge, Z(BYTE)',
+        'ZaH(WORD), PMerge, Z(WORD)',
+        'ZaH(DWORD), PMerge, Z(DWORD)',
+        'ZaH(QWORD), PMerge, Z(QWORD)',
+        'ZaH(OWORD), PMerge, Z(OWORD)',
+        'ZaV(BYTE), PMerge, Z(BYTE)',
+        'ZaV(WORD), PMerge, Z(WORD)',
+        'ZaV(DWORD), PMerge, Z(DWORD)',
+        'ZaV(QWORD), PMerge, Z(QWORD)',
+        'ZaV(OWORD), PMerge, Z(OWORD)',
+    ],
+    processors = [
+        'RTile(4, 0), RSelect(13), Ubits(0, 4), R3(10), R(5)',
+        'RTile(3, 1), RSelect(13), Ubits(0, 3), R3(10), R(5), Static(22, 0b01)',
+        'RTile(2, 2), RSelect(13), Ubits(0, 2), R3(10), R(5), Static(22, 0b10)',
+        'RTile(1, 3), RSelect(13), Ubits(0, 1), R3(10), R(5), Static(22, 0b11)',
+        'RTile(0, 4), RSelect(13), Urange(0, 0, 0), R3(10), R(5), Static(16, 0b1), Static(22, 0b11)',
+        'RTile(4, 0), RSelect(13), Ubits(0, 4), R3(10), R(5), Static(15, 0b1)',
+        'RTile(3, 1), RSelect(13), Ubits(0, 3), R3(10), R(5), Static(15, 0b1), Static(22, 0b01)',
+        'RTile(2, 2), RSelect(13), Ubits(0, 2), R3(10), R(5), Static(15, 0b1), Static(22, 0b10)',
+        'RTile(1, 3), RSelect(13), Ubits(0, 1), R3(10), R(5), Static(15, 0b1), Static(22, 0b11)',
+        'RTile(0, 4), RSelect(13), Urange(0, 0, 0), R3(10), R(5), Static(15, 0b1), Static(16, 0b1), Static(22, 0b11)',
+    ],
+)
+
+tlentry(['ZERO'],
+    '{<mask>}', (),
+    encodings = {
+        'ZERO': '11000000000010000000000000000000',
+    },
+    matcher   = 'ZaTileList',
+    processor = 'Ubits(0, 8)',
+)
+
+tlentry(['REVD'],
+    '<Zd>.Q,<Pg>/M,<Zn>.Q', (),
+    encodings = {
+        'REVD': '00000101001011101000000000000000',
+    },
+    matcher   = 'Z(OWORD), PMerge, Z(OWORD)',
+    processor = 'R(0), R3(10), R(5)',
+)
+
+tlentry(['SCLAMP', 'UCLAMP'],
+    '<Zd>.<T>,<Zn>.<T>,<Zm>.<T>', (),
+    encodings = {
+        'SCLAMP': '01000100000000001100000000000000',
+        'UCLAMP': '01000100000000001100010000000000',
+    },
+    matchers = [
+        'Z(BYTE), Z(BYTE), Z(BYTE)',
+        'Z(WORD), Z(WORD), Z(WORD)',
+        'Z(DWORD), Z(DWORD), Z(DWORD)',
+        'Z(QWORD), Z(QWORD), Z(QWORD)',
+    ],
+    processors = [
+        'R(0), R(5), R(16)',
+        'R(0), R(5), R(16), Static(22, 0b01)',
+        'R(0), R(5), R(16), Static(22, 0b10)',
+        'R(0), R(5), R(16), Static(22, 0b11)',
+    ],
+)
@@ -1,1542 +1,2960 @@
 
 tlentry(['SETFFR'],
     '', (),
-    matcher   = 'Unimp',
-    processor = 'Unimp',
+    matcher   = '',
+    processor = '',
 )
 
-tlentry(['SADDV', 'UADDV'],
+tlentry(['SADDV'],
     '<Dd>,<Pg>,<Zn>.<T>', (('size', 2, 22), ('Pg', 3, 10), ('Zn', 5, 5), ('Vd', 5, 0)),
-    matcher   = 'Unimp',
-    processor = 'Unimp',
-)
-
-tlentry(['CMPLE', 'CMPLO', 'CMPLS', 'CMPLT', 'FACLE', 'FACLT', 'FCMLE', 'FCMLT'],
+    matchers = [
+        'D, PBare, Z(BYTE)',
+        'D, PBare, Z(WORD)',
+        'D, PBare, Z(DWORD)',
+    ],
+    processors = [
+        'R(0), R3(10), R(5), Static(22, 0b00)',
+        'R(0), R3(10), R(5), Static(22, 0b01)',
+        'R(0), R3(10), R(5), Static(22, 0b10)',
+    ],
+)
+
+tlentry(['UADDV'],
+    '<Dd>,<Pg>,<Zn>.<T>', (('size', 2, 22), ('Pg', 3, 10), ('Zn', 5, 5), ('Vd', 5, 0)),
+    matchers = [
+        'D, PBare, Z(BYTE)',
+        'D, PBare, Z(WORD)',
+        'D, PBare, Z(DWORD)',
+        'D, PBare, Z(QWORD)',
+    ],
+    processors = [
+        'R(0), R3(10), R(5), Static(22, 0b00)',
+        'R(0), R3(10), R(5), Static(22, 0b01)',
+        'R(0), R3(10), R(5), Static(22, 0b10)',
+        'R(0), R3(10), R(5), Static(22, 0b11)',
+    ],
+)
+
+tlentry(['CMPLE', 'CMPLO', 'CMPLS', 'CMPLT'],
+    '<Pd>.<T>,<Pg>/Z,<Zm>.<T>,<Zn>.<T>', (('size', 2, 22), ('Zm', 5, 16), ('Pg', 3, 10), ('Zn', 5, 5), ('Pd', 4, 0)),
+    matchers = [
+        'P(BYTE), PZero, Z(BYTE), Z(BYTE)',
+        'P(WORD), PZero, Z(WORD), Z(WORD)',
+        'P(DWORD), PZero, Z(DWORD), Z(DWORD)',
+        'P(QWORD), PZero, Z(QWORD), Z(QWORD)',
+    ],
+    processors = [
+        'R4(0), R3(10), R(16), R(5), Static(22, 0b00)',
+        'R4(0), R3(10), R(16), R(5), Static(22, 0b01)',
+        'R4(0), R3(10), R(16), R(5), Static(22, 0b10)',
+        'R4(0), R3(10), R(16), R(5), Static(22, 0b11)',
+    ],
+)
+
+tlentry(['FACLE', 'FACLT', 'FCMLE', 'FCMLT'],
     '<Pd>.<T>,<Pg>/Z,<Zm>.<T>,<Zn>.<T>', (('size', 2, 22), ('Zm', 5, 16), ('Pg', 3, 10), ('Zn', 5, 5), ('Pd', 4, 0)),
-    matcher   = 'Unimp',
-    processor = 'Unimp',
+    matchers = [
+        'P(WORD), PZero, Z(WORD), Z(WORD)',
+        'P(DWORD), PZero, Z(DWORD), Z(DWORD)',
+        'P(QWORD), PZero, Z(QWORD), Z(QWORD)',
+    ],
+    processors = [
+        'R4(0), R3(10), R(16), R(5), Static(22, 0b01)',
+        'R4(0), R3(10), R(16), R(5), Static(22, 0b10)',
+        'R4(0), R3(10), R(16), R(5), Static(22, 0b11)',
+    ],
 )
 
 tlentry(['FCMEQ', 'FCMGE', 'FCMGT', 'FCMLE', 'FCMLT', 'FCMNE'],
     '<Pd>.<T>,<Pg>/Z,<Zn>.<T>,#0.0', (('size', 2, 22), ('Pg', 3, 10), ('Zn', 5, 5), ('Pd', 4, 0)),
-    matcher   = 'Unimp',
-    processor = 'Unimp',
+    matchers = [
+        'P(WORD), PZero, Z(WORD), LitFloat(0.0)',
+        'P(DWORD), PZero, Z(DWORD), LitFloat(0.0)',
+        'P(QWORD), PZero, Z(QWORD), LitFloat(0.0)',
+    ],
+    processors = [
+        'R4(0), R3(10), R(5), Static(22, 0b01)',
+        'R4(0), R3(10), R(5), Static(22, 0b10)',
+        'R4(0), R3(10), R(5), Static(22, 0b11)',
+    ],
 )
 
 tlentry(['CMPEQ', 'CMPGE', 'CMPGT', 'CMPLE', 'CMPLT', 'CMPNE'],
     '<Pd>.<T>,<Pg>/Z,<Zn>.<T>,#<imm>', (('size', 2, 22), ('imm5', 5, 16), ('Pg', 3, 10), ('Zn', 5, 5), ('Pd', 4, 0)),
-    matcher   = 'Unimp',
-    processor = 'Unimp',
+    matchers = [
+        'P(BYTE), PZero, Z(BYTE), Imm',
+        'P(WORD), PZero, Z(WORD), Imm',
+        'P(DWORD), PZero, Z(DWORD), Imm',
+        'P(QWORD), PZero, Z(QWORD), Imm',
+    ],
+    processors = [
+        'R4(0), R3(10), R(5), Sbits(16, 5), Static(22, 0b00)',
+        'R4(0), R3(10), R(5), Sbits(16, 5), Static(22, 0b01)',
+        'R4(0), R3(10), R(5), Sbits(16, 5), Static(22, 0b10)',
+        'R4(0), R3(10), R(5), Sbits(16, 5), Static(22, 0b11)',
+    ],
 )
 
 tlentry(['CMPHI', 'CMPHS', 'CMPLO', 'CMPLS'],
     '<Pd>.<T>,<Pg>/Z,<Zn>.<T>,#<imm>', (('size', 2, 22), ('imm7', 7, 14), ('Pg', 3, 10), ('Zn', 5, 5), ('Pd', 4, 0)),
-    matcher   = 'Unimp',
-    processor = 'Unimp',
-)
-
-tlentry(['CMPEQ', 'CMPGE', 'CMPGT', 'CMPHI', 'CMPHS', 'CMPNE', 'FACGE', 'FACGT', 'FCMEQ', 'FCMGE', 'FCMGT', 'FCMNE', 'FCMUO'],
+    matchers = [
+        'P(BYTE), PZero, Z(BYTE), Imm',
+        'P(WORD), PZero, Z(WORD), Imm',
+        'P(DWORD), PZero, Z(DWORD), Imm',
+        'P(QWORD), PZero, Z(QWORD), Imm',
+    ],
+    processors = [
+        'R4(0), R3(10), R(5), Ubits(14, 7), Static(22, 0b00)',
+        'R4(0), R3(10), R(5), Ubits(14, 7), Static(22, 0b01)',
+        'R4(0), R3(10), R(5), Ubits(14, 7), Static(22, 0b10)',
+        'R4(0), R3(10), R(5), Ubits(14, 7), Static(22, 0b11)',
+    ],
+)
+
+tlentry(['CMPEQ', 'CMPGE', 'CMPGT', 'CMPHI', 'CMPHS', 'CMPNE'],
     '<Pd>.<T>,<Pg>/Z,<Zn>.<T>,<Zm>.<T>', (('size', 2, 22), ('Zm', 5, 16), ('Pg', 3, 10), ('Zn', 5, 5), ('Pd', 4, 0)),
-    matcher   = 'Unimp',
-    processor = 'Unimp',
+    matchers = [
+        'P(BYTE), PZero, Z(BYTE), Z(BYTE)',
+        'P(WORD), PZero, Z(WORD), Z(WORD)',
+        'P(DWORD), PZero, Z(DWORD), Z(DWORD)',
+        'P(QWORD), PZero, Z(QWORD), Z(QWORD)',
+    ],
+    processors = [
+        'R4(0), R3(10), R(5), R(16), Static(22, 0b00)',
+        'R4(0), R3(10), R(5), R(16), Static(22, 0b01)',
+        'R4(0), R3(10), R(5), R(16), Static(22, 0b10)',
+        'R4(0), R3(10), R(5), R(16), Static(22, 0b11)',
+    ],
+)
+
+tlentry(['FACGE', 'FACGT', 'FCMEQ', 'FCMGE', 'FCMGT', 'FCMNE', 'FCMUO'],
+    '<Pd>.<T>,<Pg>/Z,<Zn>.<T>,<Zm>.<T>', (('size', 2, 22), ('Zm', 5, 16), ('Pg', 3, 10), ('Zn', 5, 5), ('Pd', 4, 0)),
+    matchers = [
+        'P(WORD), PZero, Z(WORD), Z(WORD)',
+        'P(DWORD), PZero, Z(DWORD), Z(DWORD)',
+        'P(QWORD), PZero, Z(QWORD), Z(QWORD)',
+    ],
+    processors = [
+        'R4(0), R3(10), R(5), R(16), Static(22, 0b01)',
+        'R4(0), R3(10), R(5), R(16), Static(22, 0b10)',
+        'R4(0), R3(10), R(5), R(16), Static(22, 0b11)',
+    ],
 )
 
 tlentry(['CMPEQ', 'CMPGE', 'CMPGT', 'CMPHI', 'CMPHS', 'CMPLE', 'CMPLO', 'CMPLS', 'CMPLT', 'CMPNE'],
     '<Pd>.<T>,<Pg>/Z,<Zn>.<T>,<Zm>.D', (('size', 2, 22), ('Zm', 5, 16), ('Pg', 3, 10), ('Zn', 5, 5), ('Pd', 4, 0)),
-    matcher   = 'Unimp',
-    processor = 'Unimp',
+    matchers = [
+        'P(BYTE), PZero, Z(BYTE), Z(QWORD)',
+        'P(WORD), PZero, Z(WORD), Z(QWORD)',
+        'P(DWORD), PZero, Z(DWORD), Z(QWORD)',
+    ],
+    processors = [
+        'R4(0), R3(10), R(5), R(16), Static(22, 0b00)',
+        'R4(0), R3(10), R(5), R(16), Static(22, 0b01)',
+        'R4(0), R3(10), R(5), R(16), Static(22, 0b10)',
+    ],
 )
 
 tlentry(['REV'],
     '<Pd>.<T>,<Pn>.<T>', (('size', 2, 22), ('Pn', 4, 5), ('Pd', 4, 0)),
-    matcher   = 'Unimp',
-    processor = 'Unimp',
+    matchers = [
+        'P(BYTE), P(BYTE)',
+        'P(WORD), P(WORD)',
+        'P(DWORD), P(DWORD)',
+        'P(QWORD), P(QWORD)',
+    ],
+    processors = [
+        'R4(0), R4(5), Static(22, 0b00)',
+        'R4(0), R4(5), Static(22, 0b01)',
+        'R4(0), R4(5), Static(22, 0b10)',
+        'R4(0), R4(5), Static(22, 0b11)',
+    ],
 )
 
 tlentry(['TRN1', 'TRN2', 'UZP1', 'UZP2', 'ZIP1', 'ZIP2'],
     '<Pd>.<T>,<Pn>.<T>,<Pm>.<T>', (('size', 2, 22), ('Pm', 4, 16), ('Pn', 4, 5), ('Pd', 4, 0)),
-    matcher   = 'Unimp',
-    processor = 'Unimp',
+    matchers = [
+        'P(BYTE), P(BYTE), P(BYTE)',
+        'P(WORD), P(WORD), P(WORD)',
+        'P(DWORD), P(DWORD), P(DWORD)',
+        'P(QWORD), P(QWORD), P(QWORD)',
+    ],
+    processors = [
+        'R4(0), R4(5), R4(16), Static(22, 0b00)',
+        'R4(0), R4(5), R4(16), Static(22, 0b01)',
+        'R4(0), R4(5), R4(16), Static(22, 0b10)',
+        'R4(0), R4(5), R4(16), Static(22, 0b11)',
+    ],
 )
 
 tlentry(['WHILELE', 'WHILELO', 'WHILELS', 'WHILELT'],
     '<Pd>.<T>,<R><n>,<R><m>', (('size', 2, 22), ('Rm', 5, 16), ('sf', 1, 12), ('Rn', 5, 5), ('Pd', 4, 0)),
-    matcher   = 'Unimp',
-    processor = 'Unimp',
+    matchers = [
+        'P(BYTE), W, W',
+        'P(WORD), W, W',
+        'P(DWORD), W, W',
+        'P(QWORD), W, W',
+        'P(BYTE), X, X',
+        'P(WORD), X, X',
+        'P(DWORD), X, X',
+        'P(QWORD), X, X',
+    ],
+    processors = [
+        'R4(0), R(5), R(16), Static(22, 0b00)',
+        'R4(0), R(5), R(16), Static(22, 0b01)',
+        'R4(0), R(5), R(16), Static(22, 0b10)',
+        'R4(0), R(5), R(16), Static(22, 0b11)',
+        'R4(0), R(5), R(16), Static(22, 0b00), Static(12, 0b1)',
+        'R4(0), R(5), R(16), Static(22, 0b01), Static(12, 0b1)',
+        'R4(0), R(5), R(16), Static(22, 0b10), Static(12, 0b1)',
+        'R4(0), R(5), R(16), Static(22, 0b11), Static(12, 0b1)',
+    ],
 )
 
 tlentry(['PTRUE', 'PTRUES'],
     '<Pd>.<T>{,<pattern>}', (('size', 2, 22), ('pattern', 5, 5), ('Pd', 4, 0)),
-    matcher   = 'Unimp',
-    processor = 'Unimp',
+    matchers = [
+        'P(BYTE)',
+        'P(WORD)',
+        'P(DWORD)',
+        'P(QWORD)',
+        'P(BYTE), Ident',
+        'P(WORD), Ident',
+        'P(DWORD), Ident',
+        'P(QWORD), Ident',
+    ],
+    processors = [
+        'R4(0), Static(5, 0b11111), Static(22, 0b00)',
+        'R4(0), Static(5, 0b11111), Static(22, 0b01)',
+        'R4(0), Static(5, 0b11111), Static(22, 0b10)',
+        'R4(0), Static(5, 0b11111), Static(22, 0b11)',
+        'R4(0), LitList(5, "SVE_PATTERNS"), Static(22, 0b00)',
+        'R4(0), LitList(5, "SVE_PATTERNS"), Static(22, 0b01)',
+        'R4(0), LitList(5, "SVE_PATTERNS"), Static(22, 0b10)',
+        'R4(0), LitList(5, "SVE_PATTERNS"), Static(22, 0b11)',
+    ],
 )
 
 tlentry(['PFALSE', 'RDFFR'],
     '<Pd>.B', (('Pd', 4, 0),),
-    matcher   = 'Unimp',
-    processor = 'Unimp',
+    matcher   = 'P(BYTE)',
+    processor = 'R4(0)',
 )
 
 tlentry(['SEL'],
     '<Pd>.B,<Pg>,<Pn>.B,<Pm>.B', (('Pm', 4, 16), ('Pg', 4, 10), ('Pn', 4, 5), ('Pd', 4, 0)),
-    matcher   = 'Unimp',
-    processor = 'Unimp',
+    matcher   = 'P(BYTE), PBare, P(BYTE), P(BYTE)',
+    processor = 'R4(0), R4(10), R4(5), R4(16)',
 )
 
 tlentry(['BRKA', 'BRKB'],
     '<Pd>.B,<Pg>/<ZM>,<Pn>.B', (('Pg', 4, 10), ('Pn', 4, 5), ('M', 1, 4), ('Pd', 4, 0)),
-    matcher   = 'Unimp',
-    processor = 'Unimp',
+    matchers = [
+        'P(BYTE), PZero, P(BYTE)',
+        'P(BYTE), PMerge, P(BYTE)',
+    ],
+    processors = [
+        'R4(0), R4(10), R4(5)',
+        'R4(0), R4(10), R4(5), Static(4, 0b1)',
+    ],
 )
 
 tlentry(['MOV'],
     '<Pd>.B,<Pg>/M,<Pn>.B', (('Pm', 4, 16), ('Pg', 4, 10), ('Pn', 4, 5), ('Pd', 4, 0)),
-    matcher   = 'Unimp',
-    processor = 'Unimp',
+    matcher   = 'P(BYTE), PMerge, P(BYTE)',
+    processor = 'R4(0), C, R4(16), R4(10), R4(5)',
 )
 
 tlentry(['RDFFR', 'RDFFRS'],
     '<Pd>.B,<Pg>/Z', (('Pg', 4, 5), ('Pd', 4, 0)),
-    matcher   = 'Unimp',
-    processor = 'Unimp',
+    matcher   = 'P(BYTE), PZero',
+    processor = 'R4(0), R4(5)',
 )
 
 tlentry(['BRKAS', 'BRKBS'],
     '<Pd>.B,<Pg>/Z,<Pn>.B', (('Pg', 4, 10), ('Pn', 4, 5), ('Pd', 4, 0)),
-    matcher   = 'Unimp',
-    processor = 'Unimp',
+    matcher   = 'P(BYTE), PZero, P(BYTE)',
+    processor = 'R4(0), R4(10), R4(5)',
 )
 
-tlentry(['MOV', 'MOVS', 'NOT', 'NOTS'],
+tlentry(['MOV', 'MOVS'],
     '<Pd>.B,<Pg>/Z,<Pn>.B', (('Pm', 4, 16), ('Pg', 4, 10), ('Pn', 4, 5), ('Pd', 4, 0)),
-    matcher   = 'Unimp',
-    processor = 'Unimp',
+    matcher   = 'P(BYTE), PZero, P(BYTE)',
+    processor = 'R4(0), R4(10), R4(5), C, R4(16)',
+)
+
+tlentry(['NOT', 'NOTS'],
+    '<Pd>.B,<Pg>/Z,<Pn>.B', (('Pm', 4, 16), ('Pg', 4, 10), ('Pn', 4, 5), ('Pd', 4, 0)),
+    matcher   = 'P(BYTE), PZero, P(BYTE)',
+    processor = 'R4(0), R4(10), C, R4(16), R4(5)',
 )
 
 tlentry(['AND', 'ANDS', 'BIC', 'BICS', 'BRKPA', 'BRKPAS', 'BRKPB', 'BRKPBS', 'EOR', 'EORS', 'NAND', 'NANDS', 'NOR', 'NORS', 'ORN', 'ORNS', 'ORR', 'ORRS'],
     '<Pd>.B,<Pg>/Z,<Pn>.B,<Pm>.B', (('Pm', 4, 16), ('Pg', 4, 10), ('Pn', 4, 5), ('Pd', 4, 0)),
-    matcher   = 'Unimp',
-    processor = 'Unimp',
+    matcher   = 'P(BYTE), PZero, P(BYTE), P(BYTE)',
+    processor = 'R4(0), R4(10), R4(5), R4(16)',
 )
 
 tlentry(['MOV', 'MOVS'],
     '<Pd>.B,<Pn>.B', (('Pm', 4, 16), ('Pg', 4, 10), ('Pn', 4, 5), ('Pd', 4, 0)),
-    matcher   = 'Unimp',
-    processor = 'Unimp',
+    matcher   = 'P(BYTE), P(BYTE)',
+    processor = 'R4(0), R4(5), C, R4(10), C, R4(16)',
 )
 
 tlentry(['PUNPKHI', 'PUNPKLO'],
     '<Pd>.H,<Pn>.B', (('Pn', 4, 5), ('Pd', 4, 0)),
-    matcher   = 'Unimp',
-    processor = 'Unimp',
+    matcher   = 'P(WORD), P(BYTE)',
+    processor = 'R4(0), R4(5)',
 )
 
 tlentry(['BRKN', 'BRKNS'],
     '<Pdm>.B,<Pg>/Z,<Pn>.B,<Pdm>.B', (('Pg', 4, 10), ('Pn', 4, 5), ('Pdm', 4, 0)),
-    matcher   = 'Unimp',
-    processor = 'Unimp',
+    matcher   = 'P(BYTE), PZero, P(BYTE), P(BYTE)',
+    processor = 'R4(0), R4(10), R4(5), RTied',
 )
 
 tlentry(['PNEXT'],
     '<Pdn>.<T>,<Pg>,<Pdn>.<T>', (('size', 2, 22), ('Pg', 4, 5), ('Pdn', 4, 0)),
-    matcher   = 'Unimp',
-    processor = 'Unimp',
+    matchers = [
+        'P(BYTE), PBare, P(BYTE)',
+        'P(WORD), PBare, P(WORD)',
+        'P(DWORD), PBare, P(DWORD)',
+        'P(QWORD), PBare, P(QWORD)',
+    ],
+    processors = [
+        'R4(0), R4(5), RTied, Static(22, 0b00)',
+        'R4(0), R4(5), RTied, Static(22, 0b01)',
+        'R4(0), R4(5), RTied, Static(22, 0b10)',
+        'R4(0), R4(5), RTied, Static(22, 0b11)',
+    ],
 )
 
 tlentry(['PFIRST'],
     '<Pdn>.B,<Pg>,<Pdn>.B', (('Pg', 4, 5), ('Pdn', 4, 0)),
-    matcher   = 'Unimp',
-    processor = 'Unimp',
+    matcher   = 'P(BYTE), PBare, P(BYTE)',
+    processor = 'R4(0), R4(5), RTied',
 )
 
 tlentry(['PTEST'],
     '<Pg>,<Pn>.B', (('Pg', 4, 10), ('Pn', 4, 5)),
-    matcher   = 'Unimp',
-    processor = 'Unimp',
+    matcher   = 'PBare, P(BYTE)',
+    processor = 'R4(10), R4(5)',
 )
 
 tlentry(['WRFFR'],
     '<Pn>.B', (('Pn', 4, 5),),
-    matcher   = 'Unimp',
-    processor = 'Unimp',
+    matcher   = 'P(BYTE)',
+    processor = 'R4(5)',
 )
 
 tlentry(['LDR', 'STR'],
     '<Pt>,[<Xn|SP>{,#<imm>,MULVL}]', (('imm9h', 6, 16), ('imm9l', 3, 10), ('Rn', 5, 5), ('Pt', 4, 0)),
-    matcher   = 'Unimp',
-    processor = 'Unimp',
+    matcher   = 'PBare, RefMulVl',
+    processor = 'R4(0), R(5), BSscaled(9, 0), Sslice(10, 3, 0), Sslice(16, 6, 3), A',
 )
 
 tlentry(['LASTA', 'LASTB'],
     '<R><d>,<Pg>,<Zn>.<T>', (('size', 2, 22), ('Pg', 3, 10), ('Zn', 5, 5), ('Rd', 5, 0)),
-    matcher   = 'Unimp',
-    processor = 'Unimp',
+    matchers = [
+        'W, PBare, Z(BYTE)',
+        'W, PBare, Z(WORD)',
+        'W, PBare, Z(DWORD)',
+        'X, PBare, Z(QWORD)',
+    ],
+    processors = [
+        'R(0), R3(10), R(5), Static(22, 0b00)',
+        'R(0), R3(10), R(5), Static(22, 0b01)',
+        'R(0), R3(10), R(5), Static(22, 0b10)',
+        'R(0), R3(10), R(5), Static(22, 0b11)',
+    ],
 )
 
 tlentry(['CLASTA', 'CLASTB'],
     '<R><dn>,<Pg>,<R><dn>,<Zm>.<T>', (('size', 2, 22), ('Pg', 3, 10), ('Zm', 5, 5), ('Rdn', 5, 0)),
-    matcher   = 'Unimp',
-    processor = 'Unimp',
+    matchers = [
+        'W, PBare, W, Z(BYTE)',
+        'W, PBare, W, Z(WORD)',
+        'W, PBare, W, Z(DWORD)',
+        'X, PBare, X, Z(QWORD)',
+    ],
+    processors = [
+        'R(0), R3(10), RTied, R(5), Static(22, 0b00)',
+        'R(0), R3(10), RTied, R(5), Static(22, 0b01)',
+        'R(0), R3(10), RTied, R(5), Static(22, 0b10)',
+        'R(0), R3(10), RTied, R(5), Static(22, 0b11)',
+    ],
 )
 
 tlentry(['CTERMEQ', 'CTERMNE'],
     '<R><n>,<R><m>', (('sz', 1, 22), ('Rm', 5, 16), ('Rn', 5, 5)),
-    matcher   = 'Unimp',
-    processor = 'Unimp',
+    matchers = [
+        'W, W',
+        'X, X',
+    ],
+    processors = [
+        'R(5), R(16)',
+        'R(5), R(16), Static(22, 0b1)',
+    ],
 )
 
-tlentry(['ANDV', 'EORV', 'FADDV', 'FMAXNMV', 'FMAXV', 'FMINNMV', 'FMINV', 'LASTA', 'LASTB', 'ORV', 'SMAXV', 'SMINV', 'UMAXV', 'UMINV'],
+tlentry(['ANDV', 'EORV', 'ORV', 'SMAXV', 'SMINV', 'UMAXV', 'UMINV'],
     '<V><d>,<Pg>,<Zn>.<T>', (('size', 2, 22), ('Pg', 3, 10), ('Zn', 5, 5), ('Vd', 5, 0)),
-    matcher   = 'Unimp',
-    processor = 'Unimp',
+    matchers = [
+        'B, PBare, Z(BYTE)',
+        'H, PBare, Z(WORD)',
+        'S, PBare, Z(DWORD)',
+        'D, PBare, Z(QWORD)',
+    ],
+    processors = [
+        'R(0), R3(10), R(5), Static(22, 0b00)',
+        'R(0), R3(10), R(5), Static(22, 0b01)',
+        'R(0), R3(10), R(5), Static(22, 0b10)',
+        'R(0), R3(10), R(5), Static(22, 0b11)',
+    ],
 )
 
-tlentry(['CLASTA', 'CLASTB', 'FADDA'],
+tlentry(['LASTA', 'LASTB'],
+    '<V><d>,<Pg>,<Zn>.<T>', (('size', 2, 22), ('Pg', 3, 10), ('Zn', 5, 5), ('Vd', 5, 0)),
+    matchers = [
+        'B, PBare, Z(BYTE)',
+        'H, PBare, Z(WORD)',
+        'S, PBare, Z(DWORD)',
+        'D, PBare, Z(QWORD)',
+    ],
+    processors = [
+        'R(0), R3(10), R(5), Static(22, 0b00)',
+        'R(0), R3(10), R(5), Static(22, 0b01)',
+        'R(0), R3(10), R(5), Static(22, 0b10)',
+        'R(0), R3(10), R(5), Static(22, 0b11)',
+    ],
+)
+
+tlentry(['FADDV', 'FMAXNMV', 'FMAXV', 'FMINNMV', 'FMINV'],
+    '<V><d>,<Pg>,<Zn>.<T>', (('size', 2, 22), ('Pg', 3, 10), ('Zn', 5, 5), ('Vd', 5, 0)),
+    matchers = [
+        'H, PBare, Z(WORD)',
+        'S, PBare, Z(DWORD)',
+        'D, PBare, Z(QWORD)',
+    ],
+    processors = [
+        'R(0), R3(10), R(5), Static(22, 0b01)',
+        'R(0), R3(10), R(5), Static(22, 0b10)',
+        'R(0), R3(10), R(5), Static(22, 0b11)',
+    ],
+)
+
+tlentry(['CLASTA', 'CLASTB'],
     '<V><dn>,<Pg>,<V><dn>,<Zm>.<T>', (('size', 2, 22), ('Pg', 3, 10), ('Zm', 5, 5), ('Vdn', 5, 0)),
-    matcher   = 'Unimp',
-    processor = 'Unimp',
+    matchers = [
+        'B, PBare, B, Z(BYTE)',
+        'H, PBare, H, Z(WORD)',
+        'S, PBare, S, Z(DWORD)',
+        'D, PBare, D, Z(QWORD)',
+    ],
+    processors = [
+        'R(0), R3(10), RTied, R(5), Static(22, 0b00)',
+        'R(0), R3(10), RTied, R(5), Static(22, 0b01)',
+        'R(0), R3(10), RTied, R(5), Static(22, 0b10)',
+        'R(0), R3(10), RTied, R(5), Static(22, 0b11)',
+    ],
+)
+
+tlentry(['FADDA'],
+    '<V><dn>,<Pg>,<V><dn>,<Zm>.<T>', (('size', 2, 22), ('Pg', 3, 10), ('Zm', 5, 5), ('Vdn', 5, 0)),
+    matchers = [
+        'H, PBare, H, Z(WORD)',
+        'S, PBare, S, Z(DWORD)',
+        'D, PBare, D, Z(QWORD)',
+    ],
+    processors = [
+        'R(0), R3(10), RTied, R(5), Static(22, 0b01)',
+        'R(0), R3(10), RTied, R(5), Static(22, 0b10)',
+        'R(0), R3(10), RTied, R(5), Static(22, 0b11)',
+    ],
 )
 
 tlentry(['UQDECP', 'UQINCP'],
     '<Wdn>,<Pg>.<T>', (('size', 2, 22), ('Pg', 4, 5), ('Rdn', 5, 0)),
-    matcher   = 'Unimp',
-    processor = 'Unimp',
+    matchers = [
+        'W, P(BYTE)',
+        'W, P(WORD)',
+        'W, P(DWORD)',
+        'W, P(QWORD)',
+    ],
+    processors = [
+        'R(0), R4(5), Static(22, 0b00)',
+        'R(0), R4(5), Static(22, 0b01)',
+        'R(0), R4(5), Static(22, 0b10)',
+        'R(0), R4(5), Static(22, 0b11)',
+    ],
 )
 
 tlentry(['UQDECB', 'UQDECD', 'UQDECH', 'UQDECW', 'UQINCB', 'UQINCD', 'UQINCH', 'UQINCW'],
     '<Wdn>{,<pattern>{,MUL#<imm>}}', (('imm4', 4, 16), ('pattern', 5, 5), ('Rdn', 5, 0)),
-    matcher   = 'Unimp',
-    processor = 'Unimp',
+    matchers = [
+        'W',
+        'W, Ident, End, LitMod(MUL)',
+    ],
+    processors = [
+        'R(0), Static(5, 0b11111)',
+        'R(0), LitList(5, "SVE_PATTERNS"), Urange(16, 1, 16)',
+    ],
 )
 
 tlentry(['RDVL'],
     '<Xd>,#<imm>', (('imm6', 6, 5), ('Rd', 5, 0)),
-    matcher   = 'Unimp',
-    processor = 'Unimp',
+    matcher   = 'X, Imm',
+    processor = 'R(0), Sbits(5, 6)',
 )
 
 tlentry(['CNTP'],
     '<Xd>,<Pg>,<Pn>.<T>', (('size', 2, 22), ('Pg', 4, 10), ('Pn', 4, 5), ('Rd', 5, 0)),
-    matcher   = 'Unimp',
-    processor = 'Unimp',
+    matchers = [
+        'X, PBare, P(BYTE)',
+        'X, PBare, P(WORD)',
+        'X, PBare, P(DWORD)',
+        'X, PBare, P(QWORD)',
+    ],
+    processors = [
+        'R(0), R4(10), R4(5), Static(22, 0b00)',
+        'R(0), R4(10), R4(5), Static(22, 0b01)',
+        'R(0), R4(10), R4(5), Static(22, 0b10)',
+        'R(0), R4(10), R4(5), Static(22, 0b11)',
+    ],
 )
 
 tlentry(['CNTB', 'CNTD', 'CNTH', 'CNTW'],
     '<Xd>{,<pattern>{,MUL#<imm>}}', (('imm4', 4, 16), ('pattern', 5, 5), ('Rd', 5, 0)),
-    matcher   = 'Unimp',
-    processor = 'Unimp',
+    matchers = [
+        'X',
+        'X, Ident, End, LitMod(MUL)',
+    ],
+    processors = [
+        'R(0), Static(5, 0b11111)',
+        'R(0), LitList(5, "SVE_PATTERNS"), Urange(16, 1, 16)',
+    ],
 )
 
 tlentry(['DECP', 'INCP', 'SQDECP', 'SQINCP', 'UQDECP', 'UQINCP'],
     '<Xdn>,<Pg>.<T>', (('size', 2, 22), ('Pg', 4, 5), ('Rdn', 5, 0)),
-    matcher   = 'Unimp',
-    processor = 'Unimp',
+    matchers = [
+        'X, P(BYTE)',
+        'X, P(WORD)',
+        'X, P(DWORD)',
+        'X, P(QWORD)',
+    ],
+    processors = [
+        'R(0), R4(5), Static(22, 0b00)',
+        'R(0), R4(5), Static(22, 0b01)',
+        'R(0), R4(5), Static(22, 0b10)',
+        'R(0), R4(5), Static(22, 0b11)',
+    ],
 )
 
 tlentry(['SQDECP', 'SQINCP'],
     '<Xdn>,<Pg>.<T>,<Wdn>', (('size', 2, 22), ('Pg', 4, 5), ('Rdn', 5, 0)),
-    matcher   = 'Unimp',
-    processor = 'Unimp',
+    matchers = [
+        'X, P(BYTE), W',
+        'X, P(WORD), W',
+        'X, P(DWORD), W',
+        'X, P(QWORD), W',
+    ],
+    processors = [
+        'R(0), R4(5), RTied, Static(22, 0b00)',
+        'R(0), R4(5), RTied, Static(22, 0b01)',
+        'R(0), R4(5), RTied, Static(22, 0b10)',
+        'R(0), R4(5), RTied, Static(22, 0b11)',
+    ],
 )
 
 tlentry(['SQDECB', 'SQDECD', 'SQDECH', 'SQDECW', 'SQINCB', 'SQINCD', 'SQINCH', 'SQINCW'],
     '<Xdn>,<Wdn>{,<pattern>{,MUL#<imm>}}', (('imm4', 4, 16), ('pattern', 5, 5), ('Rdn', 5, 0)),
-    matcher   = 'Unimp',
-    processor = 'Unimp',
+    matchers = [
+        'X, W',
+        'X, W, Ident, End, LitMod(MUL)',
+    ],
+    processors = [
+        'R(0), RTied, Static(5, 0b11111)',
+        'R(0), RTied, LitList(5, "SVE_PATTERNS"), Urange(16, 1, 16)',
+    ],
 )
 
 tlentry(['DECB', 'DECD', 'DECH', 'DECW', 'INCB', 'INCD', 'INCH', 'INCW', 'SQDECB', 'SQDECD', 'SQDECH', 'SQDECW', 'SQINCB', 'SQINCD', 'SQINCH', 'SQINCW', 'UQDECB', 'UQDECD', 'UQDECH', 'UQDECW', 'UQINCB', 'UQINCD', 'UQINCH', 'UQINCW'],
     '<Xdn>{,<pattern>{,MUL#<imm>}}', (('imm4', 4, 16), ('pattern', 5, 5), ('Rdn', 5, 0)),
-    matcher   = 'Unimp',
-    processor = 'Unimp',
+    matchers = [
+        'X',
+        'X, Ident, End, LitMod(MUL)',
+    ],
+    processors = [
+        'R(0), Static(5, 0b11111)',
+        'R(0), LitList(5, "SVE_PATTERNS"), Urange(16, 1, 16)',
+    ],
 )
 
 tlentry(['ADDPL', 'ADDVL'],
     '<Xd|SP>,<Xn|SP>,#<imm>', (('Rn', 5, 16), ('imm6', 6, 5), ('Rd', 5, 0)),
-    matcher   = 'Unimp',
-    processor = 'Unimp',
+    matcher   = 'XSP, XSP, Imm',
+    processor = 'R(0), R(16), Sbits(5, 6)',
 )
 
 tlentry(['MOVPRFX'],
     '<Zd>,<Zn>', (('Zn', 5, 5), ('Zd', 5, 0)),
-    matcher   = 'Unimp',
-    processor = 'Unimp',
+    matcher   = 'ZBare, ZBare',
+    processor = 'R(0), R(5)',
 )
 
 tlentry(['FMOV'],
     '<Zd>.<T>,#0.0', (('size', 2, 22), ('Zd', 5, 0)),
-    matcher   = 'Unimp',
-    processor = 'Unimp',
+    matchers = [
+        'Z(WORD), LitFloat(0.0)',
+        'Z(DWORD), LitFloat(0.0)',
+        'Z(QWORD), LitFloat(0.0)',
+    ],
+    processors = [
+        'R(0), Static(22, 0b01)',
+        'R(0), Static(22, 0b10)',
+        'R(0), Static(22, 0b11)',
+    ],
+)
+
+tlentry(['DUPM'],
+    '<Zd>.<T>,#<const>', (('imm13', 13, 5), ('Zd', 5, 0)),
+    matchers = [
+        'Z(BYTE), Imm',
+        'Z(WORD), Imm',
+        'Z(DWORD), Imm',
+        'Z(QWORD), Imm',
+    ],
+    processors = [
+        'R(0), Special(5, LOGICAL_IMMEDIATE_B)',
+        'R(0), Special(5, LOGICAL_IMMEDIATE_H)',
+        'R(0), Special(5, LOGICAL_IMMEDIATE_W)',
+        'R(0), Special(5, LOGICAL_IMMEDIATE_X)',
+    ],
 )
 
-tlentry(['DUPM', 'MOV'],
+tlentry(['MOV'],
     '<Zd>.<T>,#<const>', (('imm13', 13, 5), ('Zd', 5, 0)),
-    matcher   = 'Unimp',
-    processor = 'Unimp',
+    forget = True
 )
 
 tlentry(['FDUP', 'FMOV'],
     '<Zd>.<T>,#<const>', (('size', 2, 22), ('imm8', 8, 5), ('Zd', 5, 0)),
-    matcher   = 'Unimp',
-    processor = 'Unimp',
+    matchers = [
+        'Z(WORD), Imm',
+        'Z(DWORD), Imm',
+        'Z(QWORD), Imm',
+    ],
+    processors = [
+        'R(0), Special(5, FLOAT_IMMEDIATE), Static(22, 0b01)',
+        'R(0), Special(5, FLOAT_IMMEDIATE), Static(22, 0b10)',
+        'R(0), Special(5, FLOAT_IMMEDIATE), Static(22, 0b11)',
+    ],
 )
 
 tlentry(['INDEX'],
     '<Zd>.<T>,#<imm1>,#<imm2>', (('size', 2, 22), ('imm5b', 5, 16), ('imm5', 5, 5), ('Zd', 5, 0)),
-    matcher   = 'Unimp',
-    processor = 'Unimp',
+    matchers = [
+        'Z(BYTE), Imm, Imm',
+        'Z(WORD), Imm, Imm',
+        'Z(DWORD), Imm, Imm',
+        'Z(QWORD), Imm, Imm',
+    ],
+    processors = [
+        'R(0), Sbits(5, 5), Sbits(16, 5), Static(22, 0b00)',
+        'R(0), Sbits(5, 5), Sbits(16, 5), Static(22, 0b01)',
+        'R(0), Sbits(5, 5), Sbits(16, 5), Static(22, 0b10)',
+        'R(0), Sbits(5, 5), Sbits(16, 5), Static(22, 0b11)',
+    ],
 )
 
 tlentry(['INDEX'],
     '<Zd>.<T>,#<imm>,<R><m>', (('size', 2, 22), ('Rm', 5, 16), ('imm5', 5, 5), ('Zd', 5, 0)),
-    matcher   = 'Unimp',
-    processor = 'Unimp',
+    matchers = [
+        'Z(BYTE), Imm, W',
+        'Z(WORD), Imm, W',
+        'Z(DWORD), Imm, W',
+        'Z(QWORD), Imm, X',
+    ],
+    processors = [
+        'R(0), Sbits(5, 5), R(16), Static(22, 0b00)',
+        'R(0), Sbits(5, 5), R(16), Static(22, 0b01)',
+        'R(0), Sbits(5, 5), R(16), Static(22, 0b10)',
+        'R(0), Sbits(5, 5), R(16), Static(22, 0b11)',
+    ],
 )
 
 tlentry(['DUP', 'MOV'],
     '<Zd>.<T>,#<imm>{,<shift>}', (('size', 2, 22), ('sh', 1, 13), ('imm8', 8, 5), ('Zd', 5, 0)),
-    matcher   = 'Unimp',
-    processor = 'Unimp',
+    matchers = [
+        'Z(BYTE), Imm',
+        'Z(WORD), Imm, End, LitMod(LSL)',
+        'Z(DWORD), Imm, End, LitMod(LSL)',
+        'Z(QWORD), Imm, End, LitMod(LSL)',
+    ],
+    processors = [
+        'R(0), Sbits(5, 8), Static(22, 0b00)',
+        'R(0), Sbits(5, 8), Ulist(13, &[0, 8]), Static(22, 0b01)',
+        'R(0), Sbits(5, 8), Ulist(13, &[0, 8]), Static(22, 0b10)',
+        'R(0), Sbits(5, 8), Ulist(13, &[0, 8]), Static(22, 0b11)',
+    ],
 )
 
 tlentry(['COMPACT'],
     '<Zd>.<T>,<Pg>,<Zn>.<T>', (('sz', 1, 22), ('Pg', 3, 10), ('Zn', 5, 5), ('Zd', 5, 0)),
-    matcher   = 'Unimp',
-    processor = 'Unimp',
+    matchers = [
+        'Z(DWORD), PBare, Z(DWORD)',
+        'Z(QWORD), PBare, Z(QWORD)',
+    ],
+    processors = [
+        'R(0), R3(10), R(5)',
+        'R(0), R3(10), R(5), Static(22, 0b1)',
+    ],
 )
 
 tlentry(['SEL'],
     '<Zd>.<T>,<Pg>,<Zn>.<T>,<Zm>.<T>', (('size', 2, 22), ('Zm', 5, 16), ('Pg', 4, 10), ('Zn', 5, 5), ('Zd', 5, 0)),
-    matcher   = 'Unimp',
-    processor = 'Unimp',
+    matchers = [
+        'Z(BYTE), PBare, Z(BYTE), Z(BYTE)',
+        'Z(WORD), PBare, Z(WORD), Z(WORD)',
+        'Z(DWORD), PBare, Z(DWORD), Z(DWORD)',
+        'Z(QWORD), PBare, Z(QWORD), Z(QWORD)',
+    ],
+    processors = [
+        'R(0), R4(10), R(5), R(16), Static(22, 0b00)',
+        'R(0), R4(10), R(5), R(16), Static(22, 0b01)',
+        'R(0), R4(10), R(5), R(16), Static(22, 0b10)',
+        'R(0), R4(10), R(5), R(16), Static(22, 0b11)',
+    ],
 )
 
 tlentry(['CPY', 'MOV'],
     '<Zd>.<T>,<Pg>/<ZM>,#<imm>{,<shift>}', (('size', 2, 22), ('Pg', 4, 16), ('M', 1, 14), ('sh', 1, 13), ('imm8', 8, 5), ('Zd', 5, 0)),
-    matcher   = 'Unimp',
-    processor = 'Unimp',
+    matchers = [
+        'Z(BYTE), PZero, Imm',
+        'Z(WORD), PZero, Imm, End, LitMod(LSL)',
+        'Z(DWORD), PZero, Imm, End, LitMod(LSL)',
+        'Z(QWORD), PZero, Imm, End, LitMod(LSL)',
+        'Z(BYTE), PMerge, Imm',
+        'Z(WORD), PMerge, Imm, End, LitMod(LSL)',
+        'Z(DWORD), PMerge, Imm, End, LitMod(LSL)',
+        'Z(QWORD), PMerge, Imm, End, LitMod(LSL)',
+    ],
+    processors = [
+        'R(0), R4(16), Sbits(5, 8), Static(22, 0b00)',
+        'R(0), R4(16), Sbits(5, 8), Ulist(13, &[0, 8]), Static(22, 0b01)',
+        'R(0), R4(16), Sbits(5, 8), Ulist(13, &[0, 8]), Static(22, 0b10)',
+        'R(0), R4(16), Sbits(5, 8), Ulist(13, &[0, 8]), Static(22, 0b11)',
+        'R(0), R4(16), Sbits(5, 8), Static(14, 0b1), Static(22, 0b00)',
+        'R(0), R4(16), Sbits(5, 8), Ulist(13, &[0, 8]), Static(14, 0b1), Static(22, 0b01)',
+        'R(0), R4(16), Sbits(5, 8), Ulist(13, &[0, 8]), Static(14, 0b1), Static(22, 0b10)',
+        'R(0), R4(16), Sbits(5, 8), Ulist(13, &[0, 8]), Static(14, 0b1), Static(22, 0b11)',
+    ],
 )
 
 tlentry(['MOVPRFX'],
     '<Zd>.<T>,<Pg>/<ZM>,<Zn>.<T>', (('size', 2, 22), ('M', 1, 16), ('Pg', 3, 10), ('Zn', 5, 5), ('Zd', 5, 0)),
-    matcher   = 'Unimp',
-    processor = 'Unimp',
+    matchers = [
+        'Z(BYTE), PZero, Z(BYTE)',
+        'Z(WORD), PZero, Z(WORD)',
+        'Z(DWORD), PZero, Z(DWORD)',
+        'Z(QWORD), PZero, Z(QWORD)',
+        'Z(BYTE), PMerge, Z(BYTE)',
+        'Z(WORD), PMerge, Z(WORD)',
+        'Z(DWORD), PMerge, Z(DWORD)',
+        'Z(QWORD), PMerge, Z(QWORD)',
+    ],
+    processors = [
+        'R(0), R3(10), R(5), Static(22, 0b00)',
+        'R(0), R3(10), R(5), Static(22, 0b01)',
+        'R(0), R3(10), R(5), Static(22, 0b10)',
+        'R(0), R3(10), R(5), Static(22, 0b11)',
+        'R(0), R3(10), R(5), Static(16, 0b1), Static(22, 0b00)',
+        'R(0), R3(10), R(5), Static(16, 0b1), Static(22, 0b01)',
+        'R(0), R3(10), R(5), Static(16, 0b1), Static(22, 0b10)',
+        'R(0), R3(10), R(5), Static(16, 0b1), Static(22, 0b11)',
+    ],
 )
 
 tlentry(['FMOV'],
     '<Zd>.<T>,<Pg>/M,#0.0', (('size', 2, 22), ('Pg', 4, 16), ('Zd', 5, 0)),
-    matcher   = 'Unimp',
-    processor = 'Unimp',
+    matchers = [
+        'Z(WORD), PMerge, LitFloat(0.0)',
+        'Z(DWORD), PMerge, LitFloat(0.0)',
+        'Z(QWORD), PMerge, LitFloat(0.0)',
+    ],
+    processors = [
+        'R(0), R4(16), Static(22, 0b01)',
+        'R(0), R4(16), Static(22, 0b10)',
+        'R(0), R4(16), Static(22, 0b11)',
+    ],
 )
 
 tlentry(['FCPY', 'FMOV'],
     '<Zd>.<T>,<Pg>/M,#<const>', (('size', 2, 22), ('Pg', 4, 16), ('imm8', 8, 5), ('Zd', 5, 0)),
-    matcher   = 'Unimp',
-    processor = 'Unimp',
+    matchers = [
+        'Z(WORD), PMerge, Imm',
+        'Z(DWORD), PMerge, Imm',
+        'Z(QWORD), PMerge, Imm',
+    ],
+    processors = [
+        'R(0), R4(16), Special(5, FLOAT_IMMEDIATE), Static(22, 0b01)',
+        'R(0), R4(16), Special(5, FLOAT_IMMEDIATE), Static(22, 0b10)',
+        'R(0), R4(16), Special(5, FLOAT_IMMEDIATE), Static(22, 0b11)',
+    ],
 )
 
 tlentry(['CPY', 'MOV'],
     '<Zd>.<T>,<Pg>/M,<R><n|SP>', (('size', 2, 22), ('Pg', 3, 10), ('Rn', 5, 5), ('Zd', 5, 0)),
-    matcher   = 'Unimp',
-    processor = 'Unimp',
+    matchers = [
+        'Z(BYTE), PMerge, WSP',
+        'Z(WORD), PMerge, WSP',
+        'Z(DWORD), PMerge, WSP',
+        'Z(QWORD), PMerge, XSP',
+    ],
+    processors = [
+        'R(0), R3(10), R(5), Static(22, 0b00)',
+        'R(0), R3(10), R(5), Static(22, 0b01)',
+        'R(0), R3(10), R(5), Static(22, 0b10)',
+        'R(0), R3(10), R(5), Static(22, 0b11)',
+    ],
 )
 
 tlentry(['CPY', 'MOV'],
     '<Zd>.<T>,<Pg>/M,<V><n>', (('size', 2, 22), ('Pg', 3, 10), ('Vn', 5, 5), ('Zd', 5, 0)),
-    matcher   = 'Unimp',
-    processor = 'Unimp',
-)
-
-tlentry(['ABS', 'CLS', 'CLZ', 'CNOT', 'CNT', 'FABS', 'FNEG', 'FRECPX', 'FRINTA', 'FRINTI', 'FRINTM', 'FRINTN', 'FRINTP', 'FRINTX', 'FRINTZ', 'FSQRT', 'NEG', 'NOT', 'RBIT', 'REVB', 'REVH', 'SXTB', 'SXTH', 'UXTB', 'UXTH'],
+    matchers = [
+        'Z(BYTE), PMerge, B',
+        'Z(WORD), PMerge, H',
+        'Z(DWORD), PMerge, S',
+        'Z(QWORD), PMerge, D',
+    ],
+    processors = [
+        'R(0), R3(10), R(5), Static(22, 0b00)',
+        'R(0), R3(10), R(5), Static(22, 0b01)',
+        'R(0), R3(10), R(5), Static(22, 0b10)',
+        'R(0), R3(10), R(5), Static(22, 0b11)',
+    ],
+)
+
+tlentry(['ABS', 'CLS', 'CLZ', 'CNOT', 'CNT', 'NEG', 'NOT', 'RBIT'],
     '<Zd>.<T>,<Pg>/M,<Zn>.<T>', (('size', 2, 22), ('Pg', 3, 10), ('Zn', 5, 5), ('Zd', 5, 0)),
-    matcher   = 'Unimp',
-    processor = 'Unimp',
+    matchers = [
+        'Z(BYTE), PMerge, Z(BYTE)',
+        'Z(WORD), PMerge, Z(WORD)',
+        'Z(DWORD), PMerge, Z(DWORD)',
+        'Z(QWORD), PMerge, Z(QWORD)',
+    ],
+    processors = [
+        'R(0), R3(10), R(5), Static(22, 0b00)',
+        'R(0), R3(10), R(5), Static(22, 0b01)',
+        'R(0), R3(10), R(5), Static(22, 0b10)',
+        'R(0), R3(10), R(5), Static(22, 0b11)',
+    ],
+)
+
+tlentry(['FABS', 'FNEG', 'FRECPX', 'FRINTA', 'FRINTI', 'FRINTM', 'FRINTN', 'FRINTP', 'FRINTX', 'FRINTZ', 'FSQRT'],
+    '<Zd>.<T>,<Pg>/M,<Zn>.<T>', (('size', 2, 22), ('Pg', 3, 10), ('Zn', 5, 5), ('Zd', 5, 0)),
+    matchers = [
+        'Z(WORD), PMerge, Z(WORD)',
+        'Z(DWORD), PMerge, Z(DWORD)',
+        'Z(QWORD), PMerge, Z(QWORD)',
+    ],
+    processors = [
+        'R(0), R3(10), R(5), Static(22, 0b01)',
+        'R(0), R3(10), R(5), Static(22, 0b10)',
+        'R(0), R3(10), R(5), Static(22, 0b11)',
+    ],
+)
+
+tlentry(['REVB', 'SXTB', 'UXTB'],
+    '<Zd>.<T>,<Pg>/M,<Zn>.<T>', (('size', 2, 22), ('Pg', 3, 10), ('Zn', 5, 5), ('Zd', 5, 0)),
+    matchers = [
+        'Z(WORD), PMerge, Z(WORD)',
+        'Z(DWORD), PMerge, Z(DWORD)',
+        'Z(QWORD), PMerge, Z(QWORD)',
+    ],
+    processors = [
+        'R(0), R3(10), R(5), Static(22, 0b01)',
+        'R(0), R3(10), R(5), Static(22, 0b10)',
+        'R(0), R3(10), R(5), Static(22, 0b11)',
+    ],
+)
+
+tlentry(['REVH', 'SXTH', 'UXTH'],
+    '<Zd>.<T>,<Pg>/M,<Zn>.<T>', (('size', 2, 22), ('Pg', 3, 10), ('Zn', 5, 5), ('Zd', 5, 0)),
+    matchers = [
+        'Z(DWORD), PMerge, Z(DWORD)',
+        'Z(QWORD), PMerge, Z(QWORD)',
+    ],
+    processors = [
+        'R(0), R3(10), R(5), Static(22, 0b10)',
+        'R(0), R3(10), R(5), Static(22, 0b11)',
+    ],
 )
 
 tlentry(['MOV'],
     '<Zd>.<T>,<Pg>/M,<Zn>.<T>', (('size', 2, 22), ('Zm', 5, 16), ('Pg', 4, 10), ('Zn', 5, 5), ('Zd', 5, 0)),
-    matcher   = 'Unimp',
-    processor = 'Unimp',
+    matchers = [
+        'Z(BYTE), PMerge, Z(BYTE)',
+        'Z(WORD), PMerge, Z(WORD)',
+        'Z(DWORD), PMerge, Z(DWORD)',
+        'Z(QWORD), PMerge, Z(QWORD)',
+    ],
+    processors = [
+        'R(0), C, R(16), R4(10), R(5), Static(22, 0b00)',
+        'R(0), C, R(16), R4(10), R(5), Static(22, 0b01)',
+        'R(0), C, R(16), R4(10), R(5), Static(22, 0b10)',
+        'R(0), C, R(16), R4(10), R(5), Static(22, 0b11)',
+    ],
 )
 
 tlentry(['INDEX'],
     '<Zd>.<T>,<R><n>,#<imm>', (('size', 2, 22), ('imm5', 5, 16), ('Rn', 5, 5), ('Zd', 5, 0)),
-    matcher   = 'Unimp',
-    processor = 'Unimp',
+    matchers = [
+        'Z(BYTE), W, Imm',
+        'Z(WORD), W, Imm',
+        'Z(DWORD), W, Imm',
+        'Z(QWORD), X, Imm',
+    ],
+    processors = [
+        'R(0), R(5), Sbits(16, 5), Static(22, 0b00)',
+        'R(0), R(5), Sbits(16, 5), Static(22, 0b01)',
+        'R(0), R(5), Sbits(16, 5), Static(22, 0b10)',
+        'R(0), R(5), Sbits(16, 5), Static(22, 0b11)',
+    ],
 )
 
 tlentry(['INDEX'],
     '<Zd>.<T>,<R><n>,<R><m>', (('size', 2, 22), ('Rm', 5, 16), ('Rn', 5, 5), ('Zd', 5, 0)),
-    matcher   = 'Unimp',
-    processor = 'Unimp',
+    matchers = [
+        'Z(BYTE), W, W',
+        'Z(WORD), W, W',
+        'Z(DWORD), W, W',
+        'Z(QWORD), X, X',
+    ],
+    processors = [
+        'R(0), R(5), R(16), Static(22, 0b00)',
+        'R(0), R(5), R(16), Static(22, 0b01)',
+        'R(0), R(5), R(16), Static(22, 0b10)',
+        'R(0), R(5), R(16), Static(22, 0b11)',
+    ],
 )
 
 tlentry(['DUP', 'MOV'],
     '<Zd>.<T>,<R><n|SP>', (('size', 2, 22), ('Rn', 5, 5), ('Zd', 5, 0)),
-    matcher   = 'Unimp',
-    processor = 'Unimp',
+    matchers = [
+        'Z(BYTE), WSP',
+        'Z(WORD), WSP',
+        'Z(DWORD), WSP',
+        'Z(QWORD), XSP',
+    ],
+    processors = [
+        'R(0), R(5), Static(22, 0b00)',
+        'R(0), R(5), Static(22, 0b01)',
+        'R(0), R(5), Static(22, 0b10)',
+        'R(0), R(5), Static(22, 0b11)',
+    ],
 )
 
 tlentry(['MOV'],
     '<Zd>.<T>,<V><n>', (('imm2', 2, 22), ('tsz', 5, 16), ('Zn', 5, 5), ('Zd', 5, 0)),
-    matcher   = 'Unimp',
-    processor = 'Unimp',
-)
-
-tlentry(['FEXPA', 'FRECPE', 'FRSQRTE', 'REV'],
+    matchers = [
+        'Z(BYTE), B',
+        'Z(WORD), H',
+        'Z(DWORD), S',
+        'Z(QWORD), D',
+        'Z(OWORD), Q',
+    ],
+    processors = [
+        'R(0), R(5), Static(16, 0b00001)',
+        'R(0), R(5), Static(16, 0b00010)',
+        'R(0), R(5), Static(16, 0b00100)',
+        'R(0), R(5), Static(16, 0b01000)',
+        'R(0), R(5), Static(16, 0b10000)',
+    ],
+)
+
+tlentry(['FEXPA', 'FRECPE', 'FRSQRTE'],
     '<Zd>.<T>,<Zn>.<T>', (('size', 2, 22), ('Zn', 5, 5), ('Zd', 5, 0)),
-    matcher   = 'Unimp',
-    processor = 'Unimp',
+    matchers = [
+        'Z(WORD), Z(WORD)',
+        'Z(DWORD), Z(DWORD)',
+        'Z(QWORD), Z(QWORD)',
+    ],
+    processors = [
+        'R(0), R(5), Static(22, 0b01)',
+        'R(0), R(5), Static(22, 0b10)',
+        'R(0), R(5), Static(22, 0b11)',
+    ],
 )
 
-tlentry(['ASR', 'LSL', 'LSR'],
+tlentry(['REV'],
+    '<Zd>.<T>,<Zn>.<T>', (('size', 2, 22), ('Zn', 5, 5), ('Zd', 5, 0)),
+    matchers = [
+        'Z(BYTE), Z(BYTE)',
+        'Z(WORD), Z(WORD)',
+        'Z(DWORD), Z(DWORD)',
+        'Z(QWORD), Z(QWORD)',
+    ],
+    processors = [
+        'R(0), R(5), Static(22, 0b00)',
+        'R(0), R(5), Static(22, 0b01)',
+        'R(0), R(5), Static(22, 0b10)',
+        'R(0), R(5), Static(22, 0b11)',
+    ],
+)
+
+tlentry(['ASR', 'LSR'],
     '<Zd>.<T>,<Zn>.<T>,#<const>', (('tszh', 2, 22), ('tszl', 2, 19), ('imm3', 3, 16), ('Zn', 5, 5), ('Zd', 5, 0)),
-    matcher   = 'Unimp',
-    processor = 'Unimp',
-)
-
-tlentry(['ADD', 'FADD', 'FMUL', 'FRECPS', 'FRSQRTS', 'FSUB', 'FTSMUL', 'FTSSEL', 'SQADD', 'SQSUB', 'SUB', 'TRN1', 'TRN2', 'UQADD', 'UQSUB', 'UZP1', 'UZP2', 'ZIP1', 'ZIP2'],
+    matchers = [
+        'Z(BYTE), Z(BYTE), Imm',
+        'Z(WORD), Z(WORD), Imm',
+        'Z(DWORD), Z(DWORD), Imm',
+        'Z(QWORD), Z(QWORD), Imm',
+    ],
+    processors = [
+        'R(0), R(5), Usub(16, 3, 8), Static(19, 0b1)',
+        'R(0), R(5), Usub(16, 4, 16), Static(20, 0b1)',
+        'R(0), R(5), Usub(16, 5, 32), Static(22, 0b1)',
+        'R(0), R(5), BUrange(1, 64), Unegslice(16, 5, 0), Unegslice(22, 1, 5), A, Static(23, 0b1)',
+    ],
+)
+
+tlentry(['LSL'],
+    '<Zd>.<T>,<Zn>.<T>,#<const>', (('tszh', 2, 22), ('tszl', 2, 19), ('imm3', 3, 16), ('Zn', 5, 5), ('Zd', 5, 0)),
+    matchers = [
+        'Z(BYTE), Z(BYTE), Imm',
+        'Z(WORD), Z(WORD), Imm',
+        'Z(DWORD), Z(DWORD), Imm',
+        'Z(QWORD), Z(QWORD), Imm',
+    ],
+    processors = [
+        'R(0), R(5), Ubits(16, 3), Static(19, 0b01)',
+        'R(0), R(5), Ubits(16, 4), Static(20, 0b1)',
+        'R(0), R(5), Ubits(16, 5), Static(22, 0b1)',
+        'R(0), R(5), BUbits(6), Uslice(16, 5, 0), Uslice(22, 1, 5), A, Static(23, 0b1)',
+    ],
+)
+
+tlentry(['ADD', 'SQADD', 'SQSUB', 'SUB', 'UQADD', 'UQSUB', 'TRN1', 'TRN2', 'UZP1', 'UZP2', 'ZIP1', 'ZIP2'],
     '<Zd>.<T>,<Zn>.<T>,<Zm>.<T>', (('size', 2, 22), ('Zm', 5, 16), ('Zn', 5, 5), ('Zd', 5, 0)),
-    matcher   = 'Unimp',
-    processor = 'Unimp',
+    matchers = [
+        'Z(BYTE), Z(BYTE), Z(BYTE)',
+        'Z(WORD), Z(WORD), Z(WORD)',
+        'Z(DWORD), Z(DWORD), Z(DWORD)',
+        'Z(QWORD), Z(QWORD), Z(QWORD)',
+    ],
+    processors = [
+        'R(0), R(5), R(16), Static(22, 0b00)',
+        'R(0), R(5), R(16), Static(22, 0b01)',
+        'R(0), R(5), R(16), Static(22, 0b10)',
+        'R(0), R(5), R(16), Static(22, 0b11)',
+    ],
+)
+
+tlentry(['FADD', 'FMUL', 'FRECPS', 'FRSQRTS', 'FSUB', 'FTSMUL', 'FTSSEL'],
+    '<Zd>.<T>,<Zn>.<T>,<Zm>.<T>', (('size', 2, 22), ('Zm', 5, 16), ('Zn', 5, 5), ('Zd', 5, 0)),
+    matchers = [
+        'Z(WORD), Z(WORD), Z(WORD)',
+        'Z(DWORD), Z(DWORD), Z(DWORD)',
+        'Z(QWORD), Z(QWORD), Z(QWORD)',
+    ],
+    processors = [
+        'R(0), R(5), R(16), Static(22, 0b01)',
+        'R(0), R(5), R(16), Static(22, 0b10)',
+        'R(0), R(5), R(16), Static(22, 0b11)',
+    ],
 )
 
 tlentry(['ASR', 'LSL', 'LSR'],
     '<Zd>.<T>,<Zn>.<T>,<Zm>.D', (('size', 2, 22), ('Zm', 5, 16), ('Zn', 5, 5), ('Zd', 5, 0)),
-    matcher   = 'Unimp',
-    processor = 'Unimp',
+    matchers = [
+        'Z(BYTE), Z(BYTE), Z(QWORD)',
+        'Z(WORD), Z(WORD), Z(QWORD)',
+        'Z(DWORD), Z(DWORD), Z(QWORD)',
+    ],
+    processors = [
+        'R(0), R(5), R(16), Static(22, 0b00)',
+        'R(0), R(5), R(16), Static(22, 0b01)',
+        'R(0), R(5), R(16), Static(22, 0b10)',
+    ],
 )
 
 tlentry(['DUP', 'MOV'],
     '<Zd>.<T>,<Zn>.<T>[<imm>]', (('imm2', 2, 22), ('tsz', 5, 16), ('Zn', 5, 5), ('Zd', 5, 0)),
-    matcher   = 'Unimp',
-    processor = 'Unimp',
+    matchers = [
+        'Z(BYTE), ZElement(BYTE)',
+        'Z(WORD), ZElement(WORD)',
+        'Z(DWORD), ZElement(DWORD)',
+        'Z(QWORD), ZElement(QWORD)',
+        'Z(OWORD), ZElement(OWORD)',
+    ],
+    processors = [
+        'R(0), R(5), BUbits(6), Uslice(17, 4, 0), Uslice(22, 2, 4), A, Static(16, 0b1)',
+        'R(0), R(5), BUbits(5), Uslice(18, 3, 0), Uslice(22, 2, 3), A, Static(16, 0b10)',
+        'R(0), R(5), BUbits(4), Uslice(19, 2, 0), Uslice(22, 2, 2), A, Static(16, 0b100)',
+        'R(0), R(5), BUbits(3), Uslice(20, 1, 0), Uslice(22, 2, 1), A, Static(16, 0b1000)',
+        'R(0), R(5), Ubits(22, 2), Static(16, 0b10000)',
+    ],
 )
 
 tlentry(['SUNPKHI', 'SUNPKLO', 'UUNPKHI', 'UUNPKLO'],
     '<Zd>.<T>,<Zn>.<Tb>', (('size', 2, 22), ('Zn', 5, 5), ('Zd', 5, 0)),
-    matcher   = 'Unimp',
-    processor = 'Unimp',
+    matchers = [
+        'Z(WORD), Z(BYTE)',
+        'Z(DWORD), Z(WORD)',
+        'Z(QWORD), Z(DWORD)',
+    ],
+    processors = [
+        'R(0), R(5), Static(22, 0b01)',
+        'R(0), R(5), Static(22, 0b10)',
+        'R(0), R(5), Static(22, 0b11)',
+    ],
 )
 
 tlentry(['ADR'],
     '<Zd>.<T>,[<Zn>.<T>,<Zm>.<T>{,<mod><amount>}]', (('sz', 1, 22), ('Zm', 5, 16), ('msz', 2, 10), ('Zn', 5, 5), ('Zd', 5, 0)),
-    matcher   = 'Unimp',
-    processor = 'Unimp',
+    matchers = [
+        'Z(DWORD), RefZZ(DWORD)',
+        'Z(QWORD), RefZZ(QWORD)',
+    ],
+    processors = [
+        'R(0), R(5), R(16), Ubits(10, 2)',
+        'R(0), R(5), R(16), Ubits(10, 2), Static(22, 0b1)',
+    ],
 )
 
 tlentry(['TBL'],
     '<Zd>.<T>,{<Zn>.<T>},<Zm>.<T>', (('size', 2, 22), ('Zm', 5, 16), ('Zn', 5, 5), ('Zd', 5, 0)),
-    matcher   = 'Unimp',
-    processor = 'Unimp',
+    matchers = [
+        'Z(BYTE), ZRegList(1, BYTE), Z(BYTE)',
+        'Z(WORD), ZRegList(1, WORD), Z(WORD)',
+        'Z(DWORD), ZRegList(1, DWORD), Z(DWORD)',
+        'Z(QWORD), ZRegList(1, QWORD), Z(QWORD)',
+    ],
+    processors = [
+        'R(0), R(5), R(16), Static(22, 0b00)',
+        'R(0), R(5), R(16), Static(22, 0b01)',
+        'R(0), R(5), R(16), Static(22, 0b10)',
+        'R(0), R(5), R(16), Static(22, 0b11)',
+    ],
 )
 
 tlentry(['FCVTZS', 'FCVTZU', 'SCVTF', 'UCVTF'],
     '<Zd>.D,<Pg>/M,<Zn>.D', (('Pg', 3, 10), ('Zn', 5, 5), ('Zd', 5, 0)),
-    matcher   = 'Unimp',
-    processor = 'Unimp',
+    matcher   = 'Z(QWORD), PMerge, Z(QWORD)',
+    processor = 'R(0), R3(10), R(5)',
 )
 
 tlentry(['REVW', 'SXTW', 'UXTW'],
     '<Zd>.D,<Pg>/M,<Zn>.D', (('size', 2, 22), ('Pg', 3, 10), ('Zn', 5, 5), ('Zd', 5, 0)),
-    matcher   = 'Unimp',
-    processor = 'Unimp',
+    matcher   = 'Z(QWORD), PMerge, Z(QWORD)',
+    processor = 'R(0), R3(10), R(5), Static(22, 0b11)',
 )
 
 tlentry(['FCVT', 'FCVTZS', 'FCVTZU'],
     '<Zd>.D,<Pg>/M,<Zn>.H', (('Pg', 3, 10), ('Zn', 5, 5), ('Zd', 5, 0)),
-    matcher   = 'Unimp',
-    processor = 'Unimp',
+    matcher   = 'Z(QWORD), PMerge, Z(WORD)',
+    processor = 'R(0), R3(10), R(5)',
 )
 
 tlentry(['FCVT', 'FCVTZS', 'FCVTZU', 'SCVTF', 'UCVTF'],
     '<Zd>.D,<Pg>/M,<Zn>.S', (('Pg', 3, 10), ('Zn', 5, 5), ('Zd', 5, 0)),
-    matcher   = 'Unimp',
-    processor = 'Unimp',
+    matcher   = 'Z(QWORD), PMerge, Z(DWORD)',
+    processor = 'R(0), R3(10), R(5)',
 )
 
 tlentry(['MOV'],
     '<Zd>.D,<Zn>.D', (('Zm', 5, 16), ('Zn', 5, 5), ('Zd', 5, 0)),
-    matcher   = 'Unimp',
-    processor = 'Unimp',
+    matcher   = 'Z(QWORD), Z(QWORD)',
+    processor = 'R(0), R(5), C, R(16)',
 )
 
 tlentry(['AND', 'BIC', 'EOR', 'ORR'],
     '<Zd>.D,<Zn>.D,<Zm>.D', (('Zm', 5, 16), ('Zn', 5, 5), ('Zd', 5, 0)),
-    matcher   = 'Unimp',
-    processor = 'Unimp',
+    matcher   = 'Z(QWORD), Z(QWORD), Z(QWORD)',
+    processor = 'R(0), R(5), R(16)',
 )
 
 tlentry(['FMUL'],
     '<Zd>.D,<Zn>.D,<Zm>.D[<imm>]', (('i1', 1, 20), ('Zm', 4, 16), ('Zn', 5, 5), ('Zd', 5, 0)),
-    matcher   = 'Unimp',
-    processor = 'Unimp',
+    matcher   = 'Z(QWORD), Z(QWORD), ZElement(QWORD)',
+    processor = 'R(0), R(5), R4(16), Ubits(20, 1)',
 )
 
 tlentry(['ADR'],
     '<Zd>.D,[<Zn>.D,<Zm>.D,SXTW{<amount>}]', (('Zm', 5, 16), ('msz', 2, 10), ('Zn', 5, 5), ('Zd', 5, 0)),
-    matcher   = 'Unimp',
-    processor = 'Unimp',
+    matcher   = 'Z(QWORD), RefZZExtend(SXTW)',
+    processor = 'R(0), R(5), R(16), Ubits(10, 2)',
 )
 
 tlentry(['ADR'],
     '<Zd>.D,[<Zn>.D,<Zm>.D,UXTW{<amount>}]', (('Zm', 5, 16), ('msz', 2, 10), ('Zn', 5, 5), ('Zd', 5, 0)),
-    matcher   = 'Unimp',
-    processor = 'Unimp',
+    matcher   = 'Z(QWORD), RefZZExtend(UXTW)',
+    processor = 'R(0), R(5), R(16), Ubits(10, 2)',
 )
 
 tlentry(['FCVT', 'SCVTF', 'UCVTF'],
     '<Zd>.H,<Pg>/M,<Zn>.D', (('Pg', 3, 10), ('Zn', 5, 5), ('Zd', 5, 0)),
-    matcher   = 'Unimp',
-    processor = 'Unimp',
+    matcher   = 'Z(WORD), PMerge, Z(QWORD)',
+    processor = 'R(0), R3(10), R(5)',
 )
 
 tlentry(['FCVTZS', 'FCVTZU', 'SCVTF', 'UCVTF'],
     '<Zd>.H,<Pg>/M,<Zn>.H', (('Pg', 3, 10), ('Zn', 5, 5), ('Zd', 5, 0)),
-    matcher   = 'Unimp',
-    processor = 'Unimp',
+    matcher   = 'Z(WORD), PMerge, Z(WORD)',
+    processor = 'R(0), R3(10), R(5)',
 )
 
 tlentry(['FCVT', 'SCVTF', 'UCVTF'],
     '<Zd>.H,<Pg>/M,<Zn>.S', (('Pg', 3, 10), ('Zn', 5, 5), ('Zd', 5, 0)),
-    matcher   = 'Unimp',
-    processor = 'Unimp',
+    matcher   = 'Z(WORD), PMerge, Z(DWORD)',
+    processor = 'R(0), R3(10), R(5)',
 )
 
 tlentry(['FMUL'],
     '<Zd>.H,<Zn>.H,<Zm>.H[<imm>]', (('i3h', 1, 22), ('i3l', 2, 19), ('Zm', 3, 16), ('Zn', 5, 5), ('Zd', 5, 0)),
-    matcher   = 'Unimp',
-    processor = 'Unimp',
+    matcher   = 'Z(WORD), Z(WORD), ZElement(WORD)',
+    processor = 'R(0), R(5), R3(16), Ufields(&[22, 20, 19])',
 )
 
 tlentry(['FCVT', 'FCVTZS', 'FCVTZU', 'SCVTF', 'UCVTF'],
     '<Zd>.S,<Pg>/M,<Zn>.D', (('Pg', 3, 10), ('Zn', 5, 5), ('Zd', 5, 0)),
-    matcher   = 'Unimp',
-    processor = 'Unimp',
+    matcher   = 'Z(DWORD), PMerge, Z(QWORD)',
+    processor = 'R(0), R3(10), R(5)',
 )
 
 tlentry(['FCVT', 'FCVTZS', 'FCVTZU'],
     '<Zd>.S,<Pg>/M,<Zn>.H', (('Pg', 3, 10), ('Zn', 5, 5), ('Zd', 5, 0)),
-    matcher   = 'Unimp',
-    processor = 'Unimp',
+    matcher   = 'Z(DWORD), PMerge, Z(WORD)',
+    processor = 'R(0), R3(10), R(5)',
 )
 
 tlentry(['FCVTZS', 'FCVTZU', 'SCVTF', 'UCVTF'],
     '<Zd>.S,<Pg>/M,<Zn>.S', (('Pg', 3, 10), ('Zn', 5, 5), ('Zd', 5, 0)),
-    matcher   = 'Unimp',
-    processor = 'Unimp',
+    matcher   = 'Z(DWORD), PMerge, Z(DWORD)',
+    processor = 'R(0), R3(10), R(5)',
 )
 
 tlentry(['FMUL'],
     '<Zd>.S,<Zn>.S,<Zm>.S[<imm>]', (('i2', 2, 19), ('Zm', 3, 16), ('Zn', 5, 5), ('Zd', 5, 0)),
-    matcher   = 'Unimp',
-    processor = 'Unimp',
+    matcher   = 'Z(DWORD), Z(DWORD), ZElement(DWORD)',
+    processor = 'R(0), R(5), R3(16), Ubits(19, 2)',
 )
 
-tlentry(['FMLA', 'FMLS', 'FNMLA', 'FNMLS', 'MLA', 'MLS'],
+tlentry(['FMLA', 'FMLS', 'FNMLA', 'FNMLS'],
+    '<Zda>.<T>,<Pg>/M,<Zn>.<T>,<Zm>.<T>', (('size', 2, 22), ('Zm', 5, 16), ('Pg', 3, 10), ('Zn', 5, 5), ('Zda', 5, 0)),
+    matchers = [
+        'Z(WORD), PMerge, Z(WORD), Z(WORD)',
+        'Z(DWORD), PMerge, Z(DWORD), Z(DWORD)',
+        'Z(QWORD), PMerge, Z(QWORD), Z(QWORD)',
+    ],
+    processors = [
+        'R(0), R3(10), R(5), R(16), Static(22, 0b01)',
+        'R(0), R3(10), R(5), R(16), Static(22, 0b10)',
+        'R(0), R3(10), R(5), R(16), Static(22, 0b11)',
+    ],
+)
+
+tlentry(['MLA', 'MLS'],
     '<Zda>.<T>,<Pg>/M,<Zn>.<T>,<Zm>.<T>', (('size', 2, 22), ('Zm', 5, 16), ('Pg', 3, 10), ('Zn', 5, 5), ('Zda', 5, 0)),
-    matcher   = 'Unimp',
-    processor = 'Unimp',
+    matchers = [
+        'Z(BYTE), PMerge, Z(BYTE), Z(BYTE)',
+        'Z(WORD), PMerge, Z(WORD), Z(WORD)',
+        'Z(DWORD), PMerge, Z(DWORD), Z(DWORD)',
+        'Z(QWORD), PMerge, Z(QWORD), Z(QWORD)',
+    ],
+    processors = [
+        'R(0), R3(10), R(5), R(16), Static(22, 0b00)',
+        'R(0), R3(10), R(5), R(16), Static(22, 0b01)',
+        'R(0), R3(10), R(5), R(16), Static(22, 0b10)',
+        'R(0), R3(10), R(5), R(16), Static(22, 0b11)',
+    ],
 )
 
 tlentry(['FCMLA'],
     '<Zda>.<T>,<Pg>/M,<Zn>.<T>,<Zm>.<T>,<const>', (('size', 2, 22), ('Zm', 5, 16), ('rot', 2, 13), ('Pg', 3, 10), ('Zn', 5, 5), ('Zda', 5, 0)),
-    matcher   = 'Unimp',
-    processor = 'Unimp',
+    matchers = [
+        'Z(WORD), PMerge, Z(WORD), Z(WORD), Imm',
+        'Z(DWORD), PMerge, Z(DWORD), Z(DWORD), Imm',
+        'Z(QWORD), PMerge, Z(QWORD), Z(QWORD), Imm',
+    ],
+    processors = [
+        'R(0), R3(10), R(5), R(16), Ulist(13, &[0, 90, 180, 270]), Static(22, 0b01)',
+        'R(0), R3(10), R(5), R(16), Ulist(13, &[0, 90, 180, 270]), Static(22, 0b10)',
+        'R(0), R3(10), R(5), R(16), Ulist(13, &[0, 90, 180, 270]), Static(22, 0b11)',
+    ],
 )
 
 tlentry(['SDOT', 'UDOT'],
     '<Zda>.<T>,<Zn>.<Tb>,<Zm>.<Tb>', (('size', 2, 22), ('Zm', 5, 16), ('Zn', 5, 5), ('Zda', 5, 0)),
-    matcher   = 'Unimp',
-    processor = 'Unimp',
+    matchers = [
+        'Z(DWORD), Z(BYTE), Z(BYTE)',
+        'Z(QWORD), Z(WORD), Z(WORD)',
+    ],
+    processors = [
+        'R(0), R(5), R(16), Static(22, 0b10)',
+        'R(0), R(5), R(16), Static(22, 0b11)',
+    ],
 )
 
 tlentry(['FMLA', 'FMLS'],
     '<Zda>.D,<Zn>.D,<Zm>.D[<imm>]', (('i1', 1, 20), ('Zm', 4, 16), ('Zn', 5, 5), ('Zda', 5, 0)),
-    matcher   = 'Unimp',
-    processor = 'Unimp',
+    matcher   = 'Z(QWORD), Z(QWORD), ZElement(QWORD)',
+    processor = 'R(0), R(5), R4(16), Ubits(20, 1)',
 )
 
 tlentry(['SDOT', 'UDOT'],
     '<Zda>.D,<Zn>.H,<Zm>.H[<imm>]', (('i1', 1, 20), ('Zm', 4, 16), ('Zn', 5, 5), ('Zda', 5, 0)),
-    matcher   = 'Unimp',
-    processor = 'Unimp',
+    matcher   = 'Z(QWORD), Z(WORD), ZElement(WORD)',
+    processor = 'R(0), R(5), R4(16), Ubits(20, 1)',
 )
 
 tlentry(['FMLA', 'FMLS'],
     '<Zda>.H,<Zn>.H,<Zm>.H[<imm>]', (('i3h', 1, 22), ('i3l', 2, 19), ('Zm', 3, 16), ('Zn', 5, 5), ('Zda', 5, 0)),
-    matcher   = 'Unimp',
-    processor = 'Unimp',
+    matcher   = 'Z(WORD), Z(WORD), ZElement(WORD)',
+    processor = 'R(0), R(5), R3(16), Ufields(&[22, 20, 19])',
 )
 
 tlentry(['FCMLA'],
     '<Zda>.H,<Zn>.H,<Zm>.H[<imm>],<const>', (('i2', 2, 19), ('Zm', 3, 16), ('rot', 2, 10), ('Zn', 5, 5), ('Zda', 5, 0)),
-    matcher   = 'Unimp',
-    processor = 'Unimp',
+    matcher   = 'Z(WORD), Z(WORD), ZElement(WORD), Imm',
+    processor = 'R(0), R(5), R3(16), Ubits(19, 2), Ulist(10, &[0, 90, 180, 270])',
 )
 
 tlentry(['SDOT', 'UDOT'],
     '<Zda>.S,<Zn>.B,<Zm>.B[<imm>]', (('i2', 2, 19), ('Zm', 3, 16), ('Zn', 5, 5), ('Zda', 5, 0)),
-    matcher   = 'Unimp',
-    processor = 'Unimp',
+    matcher   = 'Z(DWORD), Z(BYTE), ZElement(BYTE)',
+    processor = 'R(0), R(5), R3(16), Ubits(19, 2)',
 )
 
 tlentry(['FMLA', 'FMLS'],
     '<Zda>.S,<Zn>.S,<Zm>.S[<imm>]', (('i2', 2, 19), ('Zm', 3, 16), ('Zn', 5, 5), ('Zda', 5, 0)),
-    matcher   = 'Unimp',
-    processor = 'Unimp',
+    matcher   = 'Z(DWORD), Z(DWORD), ZElement(DWORD)',
+    processor = 'R(0), R(5), R3(16), Ubits(19, 2)',
 )
 
 tlentry(['FCMLA'],
     '<Zda>.S,<Zn>.S,<Zm>.S[<imm>],<const>', (('i1', 1, 20), ('Zm', 4, 16), ('rot', 2, 10), ('Zn', 5, 5), ('Zda', 5, 0)),
-    matcher   = 'Unimp',
-    processor = 'Unimp',
+    matcher   = 'Z(DWORD), Z(DWORD), ZElement(DWORD), Imm',
+    processor = 'R(0), R(5), R4(16), Ubits(20, 1), Ulist(10, &[0, 90, 180, 270])',
 )
 
 tlentry(['DECP', 'INCP', 'SQDECP', 'SQINCP', 'UQDECP', 'UQINCP'],
     '<Zdn>.<T>,<Pg>', (('size', 2, 22), ('Pg', 4, 5), ('Zdn', 5, 0)),
-    matcher   = 'Unimp',
-    processor = 'Unimp',
+    matchers = [
+        'Z(WORD), PBare',
+        'Z(DWORD), PBare',
+        'Z(QWORD), PBare',
+    ],
+    processors = [
+        'R(0), R4(5), Static(22, 0b01)',
+        'R(0), R4(5), Static(22, 0b10)',
+        'R(0), R4(5), Static(22, 0b11)',
+    ],
 )
 
-tlentry(['CLASTA', 'CLASTB', 'SPLICE'],
+tlentry(['CLASTA', 'CLASTB'],
     '<Zdn>.<T>,<Pg>,<Zdn>.<T>,<Zm>.<T>', (('size', 2, 22), ('Pg', 3, 10), ('Zm', 5, 5), ('Zdn', 5, 0)),
-    matcher   = 'Unimp',
-    processor = 'Unimp',
-)
-
-tlentry(['ASR', 'ASRD', 'LSL', 'LSR'],
+    matchers = [
+        'Z(BYTE), PBare, Z(BYTE), Z(BYTE)',
+        'Z(WORD), PBare, Z(WORD), Z(WORD)',
+        'Z(DWORD), PBare, Z(DWORD), Z(DWORD)',
+        'Z(QWORD), PBare, Z(QWORD), Z(QWORD)',
+    ],
+    processors = [
+        'R(0), R3(10), RTied, R(5), Static(22, 0b00)',
+        'R(0), R3(10), RTied, R(5), Static(22, 0b01)',
+        'R(0), R3(10), RTied, R(5), Static(22, 0b10)',
+        'R(0), R3(10), RTied, R(5), Static(22, 0b11)',
+    ],
+)
+
+tlentry(['SPLICE'],
+    '<Zdn>.<T>,<Pg>,<Zdn>.<T>,<Zm>.<T>', (('size', 2, 22), ('Pg', 3, 10), ('Zm', 5, 5), ('Zdn', 5, 0)),
+    matchers = [
+        'Z(BYTE), PBare, Z(BYTE), Z(BYTE)',
+        'Z(WORD), PBare, Z(WORD), Z(WORD)',
+        'Z(DWORD), PBare, Z(DWORD), Z(DWORD)',
+        'Z(QWORD), PBare, Z(QWORD), Z(QWORD)',
+    ],
+    processors = [
+        'R(0), R3(10), RTied, R(5), Static(22, 0b00)',
+        'R(0), R3(10), RTied, R(5), Static(22, 0b01)',
+        'R(0), R3(10), RTied, R(5), Static(22, 0b10)',
+        'R(0), R3(10), RTied, R(5), Static(22, 0b11)',
+    ],
+)
+
+tlentry(['ASR', 'ASRD', 'LSR'],
+    '<Zdn>.<T>,<Pg>/M,<Zdn>.<T>,#<const>', (('tszh', 2, 22), ('Pg', 3, 10), ('tszl', 2, 8), ('imm3', 3, 5), ('Zdn', 5, 0)),
+    matchers = [
+        'Z(BYTE), PMerge, Z(BYTE), Imm',
+        'Z(WORD), PMerge, Z(WORD), Imm',
+        'Z(DWORD), PMerge, Z(DWORD), Imm',
+        'Z(QWORD), PMerge, Z(QWORD), Imm',
+    ],
+    processors = [
+        'R(0), R3(10), RTied, Usub(5, 3, 8), Static(8, 0b1)',
+        'R(0), R3(10), RTied, Usub(5, 4, 16), Static(9, 0b1)',
+        'R(0), R3(10), RTied, Usub(5, 5, 32), Static(22, 0b1)',
+        'R(0), R3(10), RTied, BUrange(1, 64), Unegslice(5, 5, 0), Unegslice(22, 1, 5), A, Static(23, 0b1)',
+    ],
+)
+
+tlentry(['LSL'],
     '<Zdn>.<T>,<Pg>/M,<Zdn>.<T>,#<const>', (('tszh', 2, 22), ('Pg', 3, 10), ('tszl', 2, 8), ('imm3', 3, 5), ('Zdn', 5, 0)),
-    matcher   = 'Unimp',
-    processor = 'Unimp',
+    matchers = [
+        'Z(BYTE), PMerge, Z(BYTE), Imm',
+        'Z(WORD), PMerge, Z(WORD), Imm',
+        'Z(DWORD), PMerge, Z(DWORD), Imm',
+        'Z(QWORD), PMerge, Z(QWORD), Imm',
+    ],
+    processors = [
+        'R(0), R3(10), RTied, Ubits(5, 3), Static(8, 0b01)',
+        'R(0), R3(10), RTied, Ubits(5, 4), Static(9, 0b1)',
+        'R(0), R3(10), RTied, Ubits(5, 5), Static(22, 0b1)',
+        'R(0), R3(10), RTied, BUbits(6), Uslice(5, 5, 0), Uslice(22, 1, 5), A, Static(23, 0b1)',
+    ],
+)
+
+tlentry(['ADD', 'AND', 'ASR', 'ASRR', 'BIC', 'EOR', 'LSL', 'LSLR', 'LSR', 'LSRR', 'MUL', 'ORR', 'SABD', 'SMAX', 'SMIN', 'SMULH', 'SUB', 'SUBR', 'UABD', 'UMAX', 'UMIN', 'UMULH'],
+    '<Zdn>.<T>,<Pg>/M,<Zdn>.<T>,<Zm>.<T>', (('size', 2, 22), ('Pg', 3, 10), ('Zm', 5, 5), ('Zdn', 5, 0)),
+    matchers = [
+        'Z(BYTE), PMerge, Z(BYTE), Z(BYTE)',
+        'Z(WORD), PMerge, Z(WORD), Z(WORD)',
+        'Z(DWORD), PMerge, Z(DWORD), Z(DWORD)',
+        'Z(QWORD), PMerge, Z(QWORD), Z(QWORD)',
+    ],
+    processors = [
+        'R(0), R3(10), RTied, R(5), Static(22, 0b00)',
+        'R(0), R3(10), RTied, R(5), Static(22, 0b01)',
+        'R(0), R3(10), RTied, R(5), Static(22, 0b10)',
+        'R(0), R3(10), RTied, R(5), Static(22, 0b11)',
+    ],
+)
+
+tlentry(['SDIV', 'SDIVR', 'UDIV', 'UDIVR'],
+    '<Zdn>.<T>,<Pg>/M,<Zdn>.<T>,<Zm>.<T>', (('size', 2, 22), ('Pg', 3, 10), ('Zm', 5, 5), ('Zdn', 5, 0)),
+    matchers = [
+        'Z(DWORD), PMerge, Z(DWORD), Z(DWORD)',
+        'Z(QWORD), PMerge, Z(QWORD), Z(QWORD)',
+    ],
+    processors = [
+        'R(0), R3(10), RTied, R(5), Static(22, 0b10)',
+        'R(0), R3(10), RTied, R(5), Static(22, 0b11)',
+    ],
 )
 
-tlentry(['ADD', 'AND', 'ASR', 'ASRR', 'BIC', 'EOR', 'FABD', 'FADD', 'FDIV', 'FDIVR', 'FMAX', 'FMAXNM', 'FMIN', 'FMINNM', 'FMUL', 'FMULX', 'FSCALE', 'FSUB', 'FSUBR', 'LSL', 'LSLR', 'LSR', 'LSRR', 'MUL', 'ORR', 'SABD', 'SDIV', 'SDIVR', 'SMAX', 'SMIN', 'SMULH', 'SUB', 'SUBR', 'UABD', 'UDIV', 'UDIVR', 'UMAX', 'UMIN', 'UMULH'],
+tlentry(['FABD', 'FADD', 'FDIV', 'FDIVR', 'FMAX', 'FMAXNM', 'FMIN', 'FMINNM', 'FMUL', 'FMULX', 'FSCALE', 'FSUB', 'FSUBR'],
     '<Zdn>.<T>,<Pg>/M,<Zdn>.<T>,<Zm>.<T>', (('size', 2, 22), ('Pg', 3, 10), ('Zm', 5, 5), ('Zdn', 5, 0)),
-    matcher   = 'Unimp',
-    processor = 'Unimp',
+    matchers = [
+        'Z(WORD), PMerge, Z(WORD), Z(WORD)',
+        'Z(DWORD), PMerge, Z(DWORD), Z(DWORD)',
+        'Z(QWORD), PMerge, Z(QWORD), Z(QWORD)',
+    ],
+    processors = [
+        'R(0), R3(10), RTied, R(5), Static(22, 0b01)',
+        'R(0), R3(10), RTied, R(5), Static(22, 0b10)',
+        'R(0), R3(10), RTied, R(5), Static(22, 0b11)',
+    ],
 )
 
 tlentry(['FCADD'],
     '<Zdn>.<T>,<Pg>/M,<Zdn>.<T>,<Zm>.<T>,<const>', (('size', 2, 22), ('rot', 1, 16), ('Pg', 3, 10), ('Zm', 5, 5), ('Zdn', 5, 0)),
-    matcher   = 'Unimp',
-    processor = 'Unimp',
+    matchers = [
+        'Z(WORD), PMerge, Z(WORD), Z(WORD), Imm',
+        'Z(DWORD), PMerge, Z(DWORD), Z(DWORD), Imm',
+        'Z(QWORD), PMerge, Z(QWORD), Z(QWORD), Imm',
+    ],
+    processors = [
+        'R(0), R3(10), RTied, R(5), Ulist(16, &[90, 270]), Static(22, 0b01)',
+        'R(0), R3(10), RTied, R(5), Ulist(16, &[90, 270]), Static(22, 0b10)',
+        'R(0), R3(10), RTied, R(5), Ulist(16, &[90, 270]), Static(22, 0b11)',
+    ],
 )
 
 tlentry(['ASR', 'LSL', 'LSR'],
     '<Zdn>.<T>,<Pg>/M,<Zdn>.<T>,<Zm>.D', (('size', 2, 22), ('Pg', 3, 10), ('Zm', 5, 5), ('Zdn', 5, 0)),
-    matcher   = 'Unimp',
-    processor = 'Unimp',
+    matchers = [
+        'Z(BYTE), PMerge, Z(BYTE), Z(QWORD)',
+        'Z(WORD), PMerge, Z(WORD), Z(QWORD)',
+        'Z(DWORD), PMerge, Z(DWORD), Z(QWORD)',
+    ],
+    processors = [
+        'R(0), R3(10), RTied, R(5), Static(22, 0b00)',
+        'R(0), R3(10), RTied, R(5), Static(22, 0b01)',
+        'R(0), R3(10), RTied, R(5), Static(22, 0b10)',
+    ],
+)
+
+tlentry(['FADD', 'FSUB', 'FSUBR'],
+    '<Zdn>.<T>,<Pg>/M,<Zdn>.<T>,<const>', (('size', 2, 22), ('Pg', 3, 10), ('i1', 1, 5), ('Zdn', 5, 0)),
+    matchers = [
+        'Z(WORD), PMerge, Z(WORD), LitFloat(0.5)',
+        'Z(DWORD), PMerge, Z(DWORD), LitFloat(0.5)',
+        'Z(QWORD), PMerge, Z(QWORD), LitFloat(0.5)',
+        'Z(WORD), PMerge, Z(WORD), LitFloat(1.0)',
+        'Z(DWORD), PMerge, Z(DWORD), LitFloat(1.0)',
+        'Z(QWORD), PMerge, Z(QWORD), LitFloat(1.0)',
+    ],
+    processors = [
+        'R(0), R3(10), RTied, Static(22, 0b01)',
+        'R(0), R3(10), RTied, Static(22, 0b10)',
+        'R(0), R3(10), RTied, Static(22, 0b11)',
+        'R(0), R3(10), RTied, Static(5, 0b1), Static(22, 0b01)',
+        'R(0), R3(10), RTied, Static(5, 0b1), Static(22, 0b10)',
+        'R(0), R3(10), RTied, Static(5, 0b1), Static(22, 0b11)',
+    ],
 )
 
-tlentry(['FADD', 'FMAX', 'FMAXNM', 'FMIN', 'FMINNM', 'FMUL', 'FSUB', 'FSUBR'],
+tlentry(['FMUL'],
+    '<Zdn>.<T>,<Pg>/M,<Zdn>.<T>,<const>', (('size', 2, 22), ('Pg', 3, 10), ('i1', 1, 5), ('Zdn', 5, 0)),
+    matchers = [
+        'Z(WORD), PMerge, Z(WORD), LitFloat(0.5)',
+        'Z(DWORD), PMerge, Z(DWORD), LitFloat(0.5)',
+        'Z(QWORD), PMerge, Z(QWORD), LitFloat(0.5)',
+        'Z(WORD), PMerge, Z(WORD), LitFloat(2.0)',
+        'Z(DWORD), PMerge, Z(DWORD), LitFloat(2.0)',
+        'Z(QWORD), PMerge, Z(QWORD), LitFloat(2.0)',
+    ],
+    processors = [
+        'R(0), R3(10), RTied, Static(22, 0b01)',
+        'R(0), R3(10), RTied, Static(22, 0b10)',
+        'R(0), R3(10), RTied, Static(22, 0b11)',
+        'R(0), R3(10), RTied, Static(5, 0b1), Static(22, 0b01)',
+        'R(0), R3(10), RTied, Static(5, 0b1), Static(22, 0b10)',
+        'R(0), R3(10), RTied, Static(5, 0b1), Static(22, 0b11)',
+    ],
+)
+
+tlentry(['FMAX', 'FMAXNM', 'FMIN', 'FMINNM'],
     '<Zdn>.<T>,<Pg>/M,<Zdn>.<T>,<const>', (('size', 2, 22), ('Pg', 3, 10), ('i1', 1, 5), ('Zdn', 5, 0)),
-    matcher   = 'Unimp',
-    processor = 'Unimp',
+    matchers = [
+        'Z(WORD), PMerge, Z(WORD), LitFloat(0.0)',
+        'Z(DWORD), PMerge, Z(DWORD), LitFloat(0.0)',
+        'Z(QWORD), PMerge, Z(QWORD), LitFloat(0.0)',
+        'Z(WORD), PMerge, Z(WORD), LitFloat(1.0)',
+        'Z(DWORD), PMerge, Z(DWORD), LitFloat(1.0)',
+        'Z(QWORD), PMerge, Z(QWORD), LitFloat(1.0)',
+    ],
+    processors = [
+        'R(0), R3(10), RTied, Static(22, 0b01)',
+        'R(0), R3(10), RTied, Static(22, 0b10)',
+        'R(0), R3(10), RTied, Static(22, 0b11)',
+        'R(0), R3(10), RTied, Static(5, 0b1), Static(22, 0b01)',
+        'R(0), R3(10), RTied, Static(5, 0b1), Static(22, 0b10)',
+        'R(0), R3(10), RTied, Static(5, 0b1), Static(22, 0b11)',
+    ],
 )
 
 tlentry(['FMAD', 'FMSB', 'FNMAD', 'FNMSB'],
     '<Zdn>.<T>,<Pg>/M,<Zm>.<T>,<Za>.<T>', (('size', 2, 22), ('Za', 5, 16), ('Pg', 3, 10), ('Zm', 5, 5), ('Zdn', 5, 0)),
-    matcher   = 'Unimp',
-    processor = 'Unimp',
+    matchers = [
+        'Z(WORD), PMerge, Z(WORD), Z(WORD)',
+        'Z(DWORD), PMerge, Z(DWORD), Z(DWORD)',
+        'Z(QWORD), PMerge, Z(QWORD), Z(QWORD)',
+    ],
+    processors = [
+        'R(0), R3(10), R(5), R(16), Static(22, 0b01)',
+        'R(0), R3(10), R(5), R(16), Static(22, 0b10)',
+        'R(0), R3(10), R(5), R(16), Static(22, 0b11)',
+    ],
 )
 
 tlentry(['MAD', 'MSB'],
     '<Zdn>.<T>,<Pg>/M,<Zm>.<T>,<Za>.<T>', (('size', 2, 22), ('Zm', 5, 16), ('Pg', 3, 10), ('Za', 5, 5), ('Zdn', 5, 0)),
-    matcher   = 'Unimp',
-    processor = 'Unimp',
+    matchers = [
+        'Z(BYTE), PMerge, Z(BYTE), Z(BYTE)',
+        'Z(WORD), PMerge, Z(WORD), Z(WORD)',
+        'Z(DWORD), PMerge, Z(DWORD), Z(DWORD)',
+        'Z(QWORD), PMerge, Z(QWORD), Z(QWORD)',
+    ],
+    processors = [
+        'R(0), R3(10), R(16), R(5), Static(22, 0b00)',
+        'R(0), R3(10), R(16), R(5), Static(22, 0b01)',
+        'R(0), R3(10), R(16), R(5), Static(22, 0b10)',
+        'R(0), R3(10), R(16), R(5), Static(22, 0b11)',
+    ],
 )
 
 tlentry(['INSR'],
     '<Zdn>.<T>,<R><m>', (('size', 2, 22), ('Rm', 5, 5), ('Zdn', 5, 0)),
-    matcher   = 'Unimp',
-    processor = 'Unimp',
+    matchers = [
+        'Z(BYTE), W',
+        'Z(WORD), W',
+        'Z(DWORD), W',
+        'Z(QWORD), X',
+    ],
+    processors = [
+        'R(0), R(5), Static(22, 0b00)',
+        'R(0), R(5), Static(22, 0b01)',
+        'R(0), R(5), Static(22, 0b10)',
+        'R(0), R(5), Static(22, 0b11)',
+    ],
 )
 
 tlentry(['INSR'],
     '<Zdn>.<T>,<V><m>', (('size', 2, 22), ('Vm', 5, 5), ('Zdn', 5, 0)),
-    matcher   = 'Unimp',
-    processor = 'Unimp',
-)
-
-tlentry(['AND', 'BIC', 'EON', 'EOR', 'ORN', 'ORR'],
+    matchers = [
+        'Z(BYTE), B',
+        'Z(WORD), H',
+        'Z(DWORD), S',
+        'Z(QWORD), D',
+    ],
+    processors = [
+        'R(0), R(5), Static(22, 0b00)',
+        'R(0), R(5), Static(22, 0b01)',
+        'R(0), R(5), Static(22, 0b10)',
+        'R(0), R(5), Static(22, 0b11)',
+    ],
+)
+
+tlentry(['AND', 'EOR', 'ORR'],
     '<Zdn>.<T>,<Zdn>.<T>,#<const>', (('imm13', 13, 5), ('Zdn', 5, 0)),
-    matcher   = 'Unimp',
-    processor = 'Unimp',
+    matchers = [
+        'Z(BYTE), Z(BYTE), Imm',
+        'Z(WORD), Z(WORD), Imm',
+        'Z(DWORD), Z(DWORD), Imm',
+        'Z(QWORD), Z(QWORD), Imm',
+    ],
+    processors = [
+        'R(0), RTied, Special(5, LOGICAL_IMMEDIATE_B)',
+        'R(0), RTied, Special(5, LOGICAL_IMMEDIATE_H)',
+        'R(0), RTied, Special(5, LOGICAL_IMMEDIATE_W)',
+        'R(0), RTied, Special(5, LOGICAL_IMMEDIATE_X)',
+    ],
+)
+
+tlentry(['BIC', 'EON', 'ORN'],
+    '<Zdn>.<T>,<Zdn>.<T>,#<const>', (('imm13', 13, 5), ('Zdn', 5, 0)),
+    forget = True
 )
 
-tlentry(['MUL', 'SMAX', 'SMIN', 'UMAX', 'UMIN'],
+tlentry(['MUL', 'SMAX', 'SMIN'],
+    '<Zdn>.<T>,<Zdn>.<T>,#<imm>', (('size', 2, 22), ('imm8', 8, 5), ('Zdn', 5, 0)),
+    matchers = [
+        'Z(BYTE), Z(BYTE), Imm',
+        'Z(WORD), Z(WORD), Imm',
+        'Z(DWORD), Z(DWORD), Imm',
+        'Z(QWORD), Z(QWORD), Imm',
+    ],
+    processors = [
+        'R(0), RTied, Sbits(5, 8), Static(22, 0b00)',
+        'R(0), RTied, Sbits(5, 8), Static(22, 0b01)',
+        'R(0), RTied, Sbits(5, 8), Static(22, 0b10)',
+        'R(0), RTied, Sbits(5, 8), Static(22, 0b11)',
+    ],
+)
+
+tlentry(['UMAX', 'UMIN'],
     '<Zdn>.<T>,<Zdn>.<T>,#<imm>', (('size', 2, 22), ('imm8', 8, 5), ('Zdn', 5, 0)),
-    matcher   = 'Unimp',
-    processor = 'Unimp',
+    matchers = [
+        'Z(BYTE), Z(BYTE), Imm',
+        'Z(WORD), Z(WORD), Imm',
+        'Z(DWORD), Z(DWORD), Imm',
+        'Z(QWORD), Z(QWORD), Imm',
+    ],
+    processors = [
+        'R(0), RTied, Ubits(5, 8), Static(22, 0b00)',
+        'R(0), RTied, Ubits(5, 8), Static(22, 0b01)',
+        'R(0), RTied, Ubits(5, 8), Static(22, 0b10)',
+        'R(0), RTied, Ubits(5, 8), Static(22, 0b11)',
+    ],
 )
 
 tlentry(['ADD', 'SQADD', 'SQSUB', 'SUB', 'SUBR', 'UQADD', 'UQSUB'],
     '<Zdn>.<T>,<Zdn>.<T>,#<imm>{,<shift>}', (('size', 2, 22), ('sh', 1, 13), ('imm8', 8, 5), ('Zdn', 5, 0)),
-    matcher   = 'Unimp',
-    processor = 'Unimp',
+    matchers = [
+        'Z(BYTE), Z(BYTE), Imm',
+        'Z(WORD), Z(WORD), Imm, End, LitMod(LSL)',
+        'Z(DWORD), Z(DWORD), Imm, End, LitMod(LSL)',
+        'Z(QWORD), Z(QWORD), Imm, End, LitMod(LSL)',
+    ],
+    processors = [
+        'R(0), RTied, Ubits(5, 8), Static(22, 0b00)',
+        'R(0), RTied, Ubits(5, 8), Ulist(13, &[0, 8]), Static(22, 0b01)',
+        'R(0), RTied, Ubits(5, 8), Ulist(13, &[0, 8]), Static(22, 0b10)',
+        'R(0), RTied, Ubits(5, 8), Ulist(13, &[0, 8]), Static(22, 0b11)',
+    ],
 )
 
 tlentry(['FTMAD'],
     '<Zdn>.<T>,<Zdn>.<T>,<Zm>.<T>,#<imm>', (('size', 2, 22), ('imm3', 3, 16), ('Zm', 5, 5), ('Zdn', 5, 0)),
-    matcher   = 'Unimp',
-    processor = 'Unimp',
+    matchers = [
+        'Z(WORD), Z(WORD), Z(WORD), Imm',
+        'Z(DWORD), Z(DWORD), Z(DWORD), Imm',
+        'Z(QWORD), Z(QWORD), Z(QWORD), Imm',
+    ],
+    processors = [
+        'R(0), RTied, R(5), Ubits(16, 3), Static(22, 0b01)',
+        'R(0), RTied, R(5), Ubits(16, 3), Static(22, 0b10)',
+        'R(0), RTied, R(5), Ubits(16, 3), Static(22, 0b11)',
+    ],
 )
 
 tlentry(['EXT'],
     '<Zdn>.B,<Zdn>.B,<Zm>.B,#<imm>', (('imm8h', 5, 16), ('imm8l', 3, 10), ('Zm', 5, 5), ('Zdn', 5, 0)),
-    matcher   = 'Unimp',
-    processor = 'Unimp',
+    matcher   = 'Z(BYTE), Z(BYTE), Z(BYTE), Imm',
+    processor = 'R(0), RTied, R(5), BUbits(8), Uslice(10, 3, 0), Uslice(16, 5, 3), A',
 )
 
 tlentry(['DECD', 'INCD', 'SQDECD', 'SQINCD', 'UQDECD', 'UQINCD'],
     '<Zdn>.D{,<pattern>{,MUL#<imm>}}', (('imm4', 4, 16), ('pattern', 5, 5), ('Zdn', 5, 0)),
-    matcher   = 'Unimp',
-    processor = 'Unimp',
+    matchers = [
+        'Z(QWORD)',
+        'Z(QWORD), Ident, End, LitMod(MUL)',
+    ],
+    processors = [
+        'R(0), Static(5, 0b11111)',
+        'R(0), LitList(5, "SVE_PATTERNS"), Urange(16, 1, 16)',
+    ],
 )
 
 tlentry(['DECH', 'INCH', 'SQDECH', 'SQINCH', 'UQDECH', 'UQINCH'],
     '<Zdn>.H{,<pattern>{,MUL#<imm>}}', (('imm4', 4, 16), ('pattern', 5, 5), ('Zdn', 5, 0)),
-    matcher   = 'Unimp',
-    processor = 'Unimp',
+    matchers = [
+        'Z(WORD)',
+        'Z(WORD), Ident, End, LitMod(MUL)',
+    ],
+    processors = [
+        'R(0), Static(5, 0b11111)',
+        'R(0), LitList(5, "SVE_PATTERNS"), Urange(16, 1, 16)',
+    ],
 )
 
 tlentry(['DECW', 'INCW', 'SQDECW', 'SQINCW', 'UQDECW', 'UQINCW'],
     '<Zdn>.S{,<pattern>{,MUL#<imm>}}', (('imm4', 4, 16), ('pattern', 5, 5), ('Zdn', 5, 0)),
-    matcher   = 'Unimp',
-    processor = 'Unimp',
+    matchers = [
+        'Z(DWORD)',
+        'Z(DWORD), Ident, End, LitMod(MUL)',
+    ],
+    processors = [
+        'R(0), Static(5, 0b11111)',
+        'R(0), LitList(5, "SVE_PATTERNS"), Urange(16, 1, 16)',
+    ],
 )
 
 tlentry(['LDR', 'STR'],
     '<Zt>,[<Xn|SP>{,#<imm>,MULVL}]', (('imm9h', 6, 16), ('imm9l', 3, 10), ('Rn', 5, 5), ('Zt', 5, 0)),
-    matcher   = 'Unimp',
-    processor = 'Unimp',
+    matcher   = 'ZBare, RefMulVl',
+    processor = 'R(0), R(5), BSscaled(9, 0), Sslice(10, 3, 0), Sslice(16, 6, 3), A',
 )
 
 tlentry(['PRFH'],
     '<prfop>,<Pg>,[<Xn|SP>,<Xm>,LSL#1]', (('Rm', 5, 16), ('Pg', 3, 10), ('Rn', 5, 5), ('prfop', 4, 0)),
-    matcher   = 'Unimp',
-    processor = 'Unimp',
+    matcher   = 'Imm, PBare, RefIndexLsl(1)',
+    processor = 'Ubits(0, 4), R3(10), R(5), RNoZr(16)',
 )
 
 tlentry(['PRFW'],
     '<prfop>,<Pg>,[<Xn|SP>,<Xm>,LSL#2]', (('Rm', 5, 16), ('Pg', 3, 10), ('Rn', 5, 5), ('prfop', 4, 0)),
-    matcher   = 'Unimp',
-    processor = 'Unimp',
+    matcher   = 'Imm, PBare, RefIndexLsl(2)',
+    processor = 'Ubits(0, 4), R3(10), R(5), RNoZr(16)',
 )
 
 tlentry(['PRFD'],
     '<prfop>,<Pg>,[<Xn|SP>,<Xm>,LSL#3]', (('Rm', 5, 16), ('Pg', 3, 10), ('Rn', 5, 5), ('prfop', 4, 0)),
-    matcher   = 'Unimp',
-    processor = 'Unimp',
+    matcher   = 'Imm, PBare, RefIndexLsl(3)',
+    processor = 'Ubits(0, 4), R3(10), R(5), RNoZr(16)',
 )
 
 tlentry(['PRFB'],
     '<prfop>,<Pg>,[<Xn|SP>,<Xm>]', (('Rm', 5, 16), ('Pg', 3, 10), ('Rn', 5, 5), ('prfop', 4, 0)),
-    matcher   = 'Unimp',
-    processor = 'Unimp',
+    matcher   = 'Imm, PBare, RefIndexLsl(0)',
+    processor = 'Ubits(0, 4), R3(10), R(5), RNoZr(16)',
 )
 
 tlentry(['PRFH'],
     '<prfop>,<Pg>,[<Xn|SP>,<Zm>.D,<mod>#1]', (('xs', 1, 22), ('Zm', 5, 16), ('Pg', 3, 10), ('Rn', 5, 5), ('prfop', 4, 0)),
-    matcher   = 'Unimp',
-    processor = 'Unimp',
+    matcher   = 'Imm, PBare, RefZIndexExtend(QWORD, 1)',
+    processor = 'Ubits(0, 4), R3(10), R(5), R(16), ExtendsZ(22)',
 )
 
 tlentry(['PRFW'],
     '<prfop>,<Pg>,[<Xn|SP>,<Zm>.D,<mod>#2]', (('xs', 1, 22), ('Zm', 5, 16), ('Pg', 3, 10), ('Rn', 5, 5), ('prfop', 4, 0)),
-    matcher   = 'Unimp',
-    processor = 'Unimp',
+    matcher   = 'Imm, PBare, RefZIndexExtend(QWORD, 2)',
+    processor = 'Ubits(0, 4), R3(10), R(5), R(16), ExtendsZ(22)',
 )
 
 tlentry(['PRFD'],
     '<prfop>,<Pg>,[<Xn|SP>,<Zm>.D,<mod>#3]', (('xs', 1, 22), ('Zm', 5, 16), ('Pg', 3, 10), ('Rn', 5, 5), ('prfop', 4, 0)),
-    matcher   = 'Unimp',
-    processor = 'Unimp',
+    matcher   = 'Imm, PBare, RefZIndexExtend(QWORD, 3)',
+    processor = 'Ubits(0, 4), R3(10), R(5), R(16), ExtendsZ(22)',
 )
 
 tlentry(['PRFB'],
     '<prfop>,<Pg>,[<Xn|SP>,<Zm>.D,<mod>]', (('xs', 1, 22), ('Zm', 5, 16), ('Pg', 3, 10), ('Rn', 5, 5), ('prfop', 4, 0)),
-    matcher   = 'Unimp',
-    processor = 'Unimp',
+    matcher   = 'Imm, PBare, RefZIndexExtend(QWORD, 0)',
+    processor = 'Ubits(0, 4), R3(10), R(5), R(16), ExtendsZ(22)',
 )
 
 tlentry(['PRFH'],
     '<prfop>,<Pg>,[<Xn|SP>,<Zm>.D,LSL#1]', (('Zm', 5, 16), ('Pg', 3, 10), ('Rn', 5, 5), ('prfop', 4, 0)),
-    matcher   = 'Unimp',
-    processor = 'Unimp',
+    matcher   = 'Imm, PBare, RefZIndexLsl(QWORD, 1)',
+    processor = 'Ubits(0, 4), R3(10), R(5), R(16)',
 )
 
 tlentry(['PRFW'],
     '<prfop>,<Pg>,[<Xn|SP>,<Zm>.D,LSL#2]', (('Zm', 5, 16), ('Pg', 3, 10), ('Rn', 5, 5), ('prfop', 4, 0)),
-    matcher   = 'Unimp',
-    processor = 'Unimp',
+    matcher   = 'Imm, PBare, RefZIndexLsl(QWORD, 2)',
+    processor = 'Ubits(0, 4), R3(10), R(5), R(16)',
 )
 
 tlentry(['PRFD'],
     '<prfop>,<Pg>,[<Xn|SP>,<Zm>.D,LSL#3]', (('Zm', 5, 16), ('Pg', 3, 10), ('Rn', 5, 5), ('prfop', 4, 0)),
-    matcher   = 'Unimp',
-    processor = 'Unimp',
+    matcher   = 'Imm, PBare, RefZIndexLsl(QWORD, 3)',
+    processor = 'Ubits(0, 4), R3(10), R(5), R(16)',
 )
 
 tlentry(['PRFB'],
     '<prfop>,<Pg>,[<Xn|SP>,<Zm>.D]', (('Zm', 5, 16), ('Pg', 3, 10), ('Rn', 5, 5), ('prfop', 4, 0)),
-    matcher   = 'Unimp',
-    processor = 'Unimp',
+    matcher   = 'Imm, PBare, RefZIndexLsl(QWORD, 0)',
+    processor = 'Ubits(0, 4), R3(10), R(5), R(16)',
 )
 
 tlentry(['PRFH'],
     '<prfop>,<Pg>,[<Xn|SP>,<Zm>.S,<mod>#1]', (('xs', 1, 22), ('Zm', 5, 16), ('Pg', 3, 10), ('Rn', 5, 5), ('prfop', 4, 0)),
-    matcher   = 'Unimp',
-    processor = 'Unimp',
+    matcher   = 'Imm, PBare, RefZIndexExtend(DWORD, 1)',
+    processor = 'Ubits(0, 4), R3(10), R(5), R(16), ExtendsZ(22)',
 )
 
 tlentry(['PRFW'],
     '<prfop>,<Pg>,[<Xn|SP>,<Zm>.S,<mod>#2]', (('xs', 1, 22), ('Zm', 5, 16), ('Pg', 3, 10), ('Rn', 5, 5), ('prfop', 4, 0)),
-    matcher   = 'Unimp',
-    processor = 'Unimp',
+    matcher   = 'Imm, PBare, RefZIndexExtend(DWORD, 2)',
+    processor = 'Ubits(0, 4), R3(10), R(5), R(16), ExtendsZ(22)',
 )
 
 tlentry(['PRFD'],
     '<prfop>,<Pg>,[<Xn|SP>,<Zm>.S,<mod>#3]', (('xs', 1, 22), ('Zm', 5, 16), ('Pg', 3, 10), ('Rn', 5, 5), ('prfop', 4, 0)),
-    matcher   = 'Unimp',
-    processor = 'Unimp',
+    matcher   = 'Imm, PBare, RefZIndexExtend(DWORD, 3)',
+    processor = 'Ubits(0, 4), R3(10), R(5), R(16), ExtendsZ(22)',
 )
 
 tlentry(['PRFB'],
     '<prfop>,<Pg>,[<Xn|SP>,<Zm>.S,<mod>]', (('xs', 1, 22), ('Zm', 5, 16), ('Pg', 3, 10), ('Rn', 5, 5), ('prfop', 4, 0)),
-    matcher   = 'Unimp',
-    processor = 'Unimp',
+    matcher   = 'Imm, PBare, RefZIndexExtend(DWORD, 0)',
+    processor = 'Ubits(0, 4), R3(10), R(5), R(16), ExtendsZ(22)',
 )
 
 tlentry(['PRFB', 'PRFD', 'PRFH', 'PRFW'],
     '<prfop>,<Pg>,[<Xn|SP>{,#<imm>,MULVL}]', (('imm6', 6, 16), ('Pg', 3, 10), ('Rn', 5, 5), ('prfop', 4, 0)),
-    matcher   = 'Unimp',
-    processor = 'Unimp',
+    matcher   = 'Imm, PBare, RefMulVl',
+    processor = 'Ubits(0, 4), R3(10), R(5), Sbits(16, 6)',
 )
 
-tlentry(['PRFB', 'PRFD', 'PRFH', 'PRFW'],
+tlentry(['PRFB'],
     '<prfop>,<Pg>,[<Zn>.D{,#<imm>}]', (('imm5', 5, 16), ('Pg', 3, 10), ('Zn', 5, 5), ('prfop', 4, 0)),
-    matcher   = 'Unimp',
-    processor = 'Unimp',
+    matcher   = 'Imm, PBare, RefZBase(QWORD)',
+    processor = 'Ubits(0, 4), R3(10), R(5), Ubits(16, 5)',
 )
 
-tlentry(['PRFB', 'PRFD', 'PRFH', 'PRFW'],
+tlentry(['PRFH'],
+    '<prfop>,<Pg>,[<Zn>.D{,#<imm>}]', (('imm5', 5, 16), ('Pg', 3, 10), ('Zn', 5, 5), ('prfop', 4, 0)),
+    matcher   = 'Imm, PBare, RefZBase(QWORD)',
+    processor = 'Ubits(0, 4), R3(10), R(5), Uscaled(16, 5, 1)',
+)
+
+tlentry(['PRFW'],
+    '<prfop>,<Pg>,[<Zn>.D{,#<imm>}]', (('imm5', 5, 16), ('Pg', 3, 10), ('Zn', 5, 5), ('prfop', 4, 0)),
+    matcher   = 'Imm, PBare, RefZBase(QWORD)',
+    processor = 'Ubits(0, 4), R3(10), R(5), Uscaled(16, 5, 2)',
+)
+
+tlentry(['PRFD'],
+    '<prfop>,<Pg>,[<Zn>.D{,#<imm>}]', (('imm5', 5, 16), ('Pg', 3, 10), ('Zn', 5, 5), ('prfop', 4, 0)),
+    matcher   = 'Imm, PBare, RefZBase(QWORD)',
+    processor = 'Ubits(0, 4), R3(10), R(5), Uscaled(16, 5, 3)',
+)
+
+tlentry(['PRFB'],
+    '<prfop>,<Pg>,[<Zn>.S{,#<imm>}]', (('imm5', 5, 16), ('Pg', 3, 10), ('Zn', 5, 5), ('prfop', 4, 0)),
+    matcher   = 'Imm, PBare, RefZBase(DWORD)',
+    processor = 'Ubits(0, 4), R3(10), R(5), Ubits(16, 5)',
+)
+
+tlentry(['PRFH'],
+    '<prfop>,<Pg>,[<Zn>.S{,#<imm>}]', (('imm5', 5, 16), ('Pg', 3, 10), ('Zn', 5, 5), ('prfop', 4, 0)),
+    matcher   = 'Imm, PBare, RefZBase(DWORD)',
+    processor = 'Ubits(0, 4), R3(10), R(5), Uscaled(16, 5, 1)',
+)
+
+tlentry(['PRFW'],
     '<prfop>,<Pg>,[<Zn>.S{,#<imm>}]', (('imm5', 5, 16), ('Pg', 3, 10), ('Zn', 5, 5), ('prfop', 4, 0)),
-    matcher   = 'Unimp',
-    processor = 'Unimp',
+    matcher   = 'Imm, PBare, RefZBase(DWORD)',
+    processor = 'Ubits(0, 4), R3(10), R(5), Uscaled(16, 5, 2)',
+)
+
+tlentry(['PRFD'],
+    '<prfop>,<Pg>,[<Zn>.S{,#<imm>}]', (('imm5', 5, 16), ('Pg', 3, 10), ('Zn', 5, 5), ('prfop', 4, 0)),
+    matcher   = 'Imm, PBare, RefZBase(DWORD)',
+    processor = 'Ubits(0, 4), R3(10), R(5), Uscaled(16, 5, 3)',
 )
 
 tlentry(['ST4B'],
     '{<Zt1>.B,<Zt2>.B,<Zt3>.B,<Zt4>.B},<Pg>,[<Xn|SP>,<Xm>]', (('Rm', 5, 16), ('Pg', 3, 10), ('Rn', 5, 5), ('Zt', 5, 0)),
-    matcher   = 'Unimp',
-    processor = 'Unimp',
+    matcher   = 'ZRegList(4, BYTE), PBare, RefIndexLsl(0)',
+    processor = 'R(0), R3(10), R(5), RNoZr(16)',
 )
 
 tlentry(['ST4B'],
     '{<Zt1>.B,<Zt2>.B,<Zt3>.B,<Zt4>.B},<Pg>,[<Xn|SP>{,#<imm>,MULVL}]', (('imm4', 4, 16), ('Pg', 3, 10), ('Rn', 5, 5), ('Zt', 5, 0)),
-    matcher   = 'Unimp',
-    processor = 'Unimp',
+    matcher   = 'ZRegList(4, BYTE), PBare, RefMulVl',
+    processor = 'R(0), R3(10), R(5), Sscaled(16, 4, 2)',
 )
 
 tlentry(['LD4B'],
     '{<Zt1>.B,<Zt2>.B,<Zt3>.B,<Zt4>.B},<Pg>/Z,[<Xn|SP>,<Xm>]', (('Rm', 5, 16), ('Pg', 3, 10), ('Rn', 5, 5), ('Zt', 5, 0)),
-    matcher   = 'Unimp',
-    processor = 'Unimp',
+    matcher   = 'ZRegList(4, BYTE), PZero, RefIndexLsl(0)',
+    processor = 'R(0), R3(10), R(5), RNoZr(16)',
 )
 
 tlentry(['LD4B'],
     '{<Zt1>.B,<Zt2>.B,<Zt3>.B,<Zt4>.B},<Pg>/Z,[<Xn|SP>{,#<imm>,MULVL}]', (('imm4', 4, 16), ('Pg', 3, 10), ('Rn', 5, 5), ('Zt', 5, 0)),
-    matcher   = 'Unimp',
-    processor = 'Unimp',
+    matcher   = 'ZRegList(4, BYTE), PZero, RefMulVl',
+    processor = 'R(0), R3(10), R(5), Sscaled(16, 4, 2)',
 )
 
 tlentry(['ST3B'],
     '{<Zt1>.B,<Zt2>.B,<Zt3>.B},<Pg>,[<Xn|SP>,<Xm>]', (('Rm', 5, 16), ('Pg', 3, 10), ('Rn', 5, 5), ('Zt', 5, 0)),
-    matcher   = 'Unimp',
-    processor = 'Unimp',
+    matcher   = 'ZRegList(3, BYTE), PBare, RefIndexLsl(0)',
+    processor = 'R(0), R3(10), R(5), RNoZr(16)',
 )
 
 tlentry(['ST3B'],
     '{<Zt1>.B,<Zt2>.B,<Zt3>.B},<Pg>,[<Xn|SP>{,#<imm>,MULVL}]', (('imm4', 4, 16), ('Pg', 3, 10), ('Rn', 5, 5), ('Zt', 5, 0)),
-    matcher   = 'Unimp',
-    processor = 'Unimp',
+    matcher   = 'ZRegList(3, BYTE), PBare, RefMulVl',
+    processor = 'R(0), R3(10), R(5), Smultiple(16, 4, 3)',
 )
 
 tlentry(['LD3B'],
     '{<Zt1>.B,<Zt2>.B,<Zt3>.B},<Pg>/Z,[<Xn|SP>,<Xm>]', (('Rm', 5, 16), ('Pg', 3, 10), ('Rn', 5, 5), ('Zt', 5, 0)),
-    matcher   = 'Unimp',
-    processor = 'Unimp',
+    matcher   = 'ZRegList(3, BYTE), PZero, RefIndexLsl(0)',
+    processor = 'R(0), R3(10), R(5), RNoZr(16)',
 )
 
 tlentry(['LD3B'],
     '{<Zt1>.B,<Zt2>.B,<Zt3>.B},<Pg>/Z,[<Xn|SP>{,#<imm>,MULVL}]', (('imm4', 4, 16), ('Pg', 3, 10), ('Rn', 5, 5), ('Zt', 5, 0)),
-    matcher   = 'Unimp',
-    processor = 'Unimp',
+    matcher   = 'ZRegList(3, BYTE), PZero, RefMulVl',
+    processor = 'R(0), R3(10), R(5), Smultiple(16, 4, 3)',
 )
 
 tlentry(['ST2B'],
     '{<Zt1>.B,<Zt2>.B},<Pg>,[<Xn|SP>,<Xm>]', (('Rm', 5, 16), ('Pg', 3, 10), ('Rn', 5, 5), ('Zt', 5, 0)),
-    matcher   = 'Unimp',
-    processor = 'Unimp',
+    matcher   = 'ZRegList(2, BYTE), PBare, RefIndexLsl(0)',
+    processor = 'R(0), R3(10), R(5), RNoZr(16)',
 )
 
 tlentry(['ST2B'],
     '{<Zt1>.B,<Zt2>.B},<Pg>,[<Xn|SP>{,#<imm>,MULVL}]', (('imm4', 4, 16), ('Pg', 3, 10), ('Rn', 5, 5), ('Zt', 5, 0)),
-    matcher   = 'Unimp',
-    processor = 'Unimp',
+    matcher   = 'ZRegList(2, BYTE), PBare, RefMulVl',
+    processor = 'R(0), R3(10), R(5), Sscaled(16, 4, 1)',
 )
 
 tlentry(['LD2B'],
     '{<Zt1>.B,<Zt2>.B},<Pg>/Z,[<Xn|SP>,<Xm>]', (('Rm', 5, 16), ('Pg', 3, 10), ('Rn', 5, 5), ('Zt', 5, 0)),
-    matcher   = 'Unimp',
-    processor = 'Unimp',
+    matcher   = 'ZRegList(2, BYTE), PZero, RefIndexLsl(0)',
+    processor = 'R(0), R3(10), R(5), RNoZr(16)',
 )
 
 tlentry(['LD2B'],
     '{<Zt1>.B,<Zt2>.B},<Pg>/Z,[<Xn|SP>{,#<imm>,MULVL}]', (('imm4', 4, 16), ('Pg', 3, 10), ('Rn', 5, 5), ('Zt', 5, 0)),
-    matcher   = 'Unimp',
-    processor = 'Unimp',
+    matcher   = 'ZRegList(2, BYTE), PZero, RefMulVl',
+    processor = 'R(0), R3(10), R(5), Sscaled(16, 4, 1)',
 )
 
 tlentry(['ST4D'],
     '{<Zt1>.D,<Zt2>.D,<Zt3>.D,<Zt4>.D},<Pg>,[<Xn|SP>,<Xm>,LSL#3]', (('Rm', 5, 16), ('Pg', 3, 10), ('Rn', 5, 5), ('Zt', 5, 0)),
-    matcher   = 'Unimp',
-    processor = 'Unimp',
+    matcher   = 'ZRegList(4, QWORD), PBare, RefIndexLsl(3)',
+    processor = 'R(0), R3(10), R(5), RNoZr(16)',
 )
 
 tlentry(['ST4D'],
     '{<Zt1>.D,<Zt2>.D,<Zt3>.D,<Zt4>.D},<Pg>,[<Xn|SP>{,#<imm>,MULVL}]', (('imm4', 4, 16), ('Pg', 3, 10), ('Rn', 5, 5), ('Zt', 5, 0)),
-    matcher   = 'Unimp',
-    processor = 'Unimp',
+    matcher   = 'ZRegList(4, QWORD), PBare, RefMulVl',
+    processor = 'R(0), R3(10), R(5), Sscaled(16, 4, 2)',
 )
 
 tlentry(['LD4D'],
     '{<Zt1>.D,<Zt2>.D,<Zt3>.D,<Zt4>.D},<Pg>/Z,[<Xn|SP>,<Xm>,LSL#3]', (('Rm', 5, 16), ('Pg', 3, 10), ('Rn', 5, 5), ('Zt', 5, 0)),
-    matcher   = 'Unimp',
-    processor = 'Unimp',
+    matcher   = 'ZRegList(4, QWORD), PZero, RefIndexLsl(3)',
+    processor = 'R(0), R3(10), R(5), RNoZr(16)',
 )
 
 tlentry(['LD4D'],
     '{<Zt1>.D,<Zt2>.D,<Zt3>.D,<Zt4>.D},<Pg>/Z,[<Xn|SP>{,#<imm>,MULVL}]', (('imm4', 4, 16), ('Pg', 3, 10), ('Rn', 5, 5), ('Zt', 5, 0)),
-    matcher   = 'Unimp',
-    processor = 'Unimp',
+    matcher   = 'ZRegList(4, QWORD), PZero, RefMulVl',
+    processor = 'R(0), R3(10), R(5), Sscaled(16, 4, 2)',
 )
 
 tlentry(['ST3D'],
     '{<Zt1>.D,<Zt2>.D,<Zt3>.D},<Pg>,[<Xn|SP>,<Xm>,LSL#3]', (('Rm', 5, 16), ('Pg', 3, 10), ('Rn', 5, 5), ('Zt', 5, 0)),
-    matcher   = 'Unimp',
-    processor = 'Unimp',
+    matcher   = 'ZRegList(3, QWORD), PBare, RefIndexLsl(3)',
+    processor = 'R(0), R3(10), R(5), RNoZr(16)',
 )
 
 tlentry(['ST3D'],
     '{<Zt1>.D,<Zt2>.D,<Zt3>.D},<Pg>,[<Xn|SP>{,#<imm>,MULVL}]', (('imm4', 4, 16), ('Pg', 3, 10), ('Rn', 5, 5), ('Zt', 5, 0)),
-    matcher   = 'Unimp',
-    processor = 'Unimp',
+    matcher   = 'ZRegList(3, QWORD), PBare, RefMulVl',
+    processor = 'R(0), R3(10), R(5), Smultiple(16, 4, 3)',
 )
 
 tlentry(['LD3D'],
     '{<Zt1>.D,<Zt2>.D,<Zt3>.D},<Pg>/Z,[<Xn|SP>,<Xm>,LSL#3]', (('Rm', 5, 16), ('Pg', 3, 10), ('Rn', 5, 5), ('Zt', 5, 0)),
-    matcher   = 'Unimp',
-    processor = 'Unimp',
+    matcher   = 'ZRegList(3, QWORD), PZero, RefIndexLsl(3)',
+    processor = 'R(0), R3(10), R(5), RNoZr(16)',
 )
 
 tlentry(['LD3D'],
     '{<Zt1>.D,<Zt2>.D,<Zt3>.D},<Pg>/Z,[<Xn|SP>{,#<imm>,MULVL}]', (('imm4', 4, 16), ('Pg', 3, 10), ('Rn', 5, 5), ('Zt', 5, 0)),
-    matcher   = 'Unimp',
-    processor = 'Unimp',
+    matcher   = 'ZRegList(3, QWORD), PZero, RefMulVl',
+    processor = 'R(0), R3(10), R(5), Smultiple(16, 4, 3)',
 )
 
 tlentry(['ST2D'],
     '{<Zt1>.D,<Zt2>.D},<Pg>,[<Xn|SP>,<Xm>,LSL#3]', (('Rm', 5, 16), ('Pg', 3, 10), ('Rn', 5, 5), ('Zt', 5, 0)),
-    matcher   = 'Unimp',
-    processor = 'Unimp',
+    matcher   = 'ZRegList(2, QWORD), PBare, RefIndexLsl(3)',
+    processor = 'R(0), R3(10), R(5), RNoZr(16)',
 )
 
 tlentry(['ST2D'],
     '{<Zt1>.D,<Zt2>.D},<Pg>,[<Xn|SP>{,#<imm>,MULVL}]', (('imm4', 4, 16), ('Pg', 3, 10), ('Rn', 5, 5), ('Zt', 5, 0)),
-    matcher   = 'Unimp',
-    processor = 'Unimp',
+    matcher   = 'ZRegList(2, QWORD), PBare, RefMulVl',
+    processor = 'R(0), R3(10), R(5), Sscaled(16, 4, 1)',
 )
 
 tlentry(['LD2D'],
     '{<Zt1>.D,<Zt2>.D},<Pg>/Z,[<Xn|SP>,<Xm>,LSL#3]', (('Rm', 5, 16), ('Pg', 3, 10), ('Rn', 5, 5), ('Zt', 5, 0)),
-    matcher   = 'Unimp',
-    processor = 'Unimp',
+    matcher   = 'ZRegList(2, QWORD), PZero, RefIndexLsl(3)',
+    processor = 'R(0), R3(10), R(5), RNoZr(16)',
 )
 
 tlentry(['LD2D'],
     '{<Zt1>.D,<Zt2>.D},<Pg>/Z,[<Xn|SP>{,#<imm>,MULVL}]', (('imm4', 4, 16), ('Pg', 3, 10), ('Rn', 5, 5), ('Zt', 5, 0)),
-    matcher   = 'Unimp',
-    processor = 'Unimp',
+    matcher   = 'ZRegList(2, QWORD), PZero, RefMulVl',
+    processor = 'R(0), R3(10), R(5), Sscaled(16, 4, 1)',
 )
 
 tlentry(['ST4H'],
     '{<Zt1>.H,<Zt2>.H,<Zt3>.H,<Zt4>.H},<Pg>,[<Xn|SP>,<Xm>,LSL#1]', (('Rm', 5, 16), ('Pg', 3, 10), ('Rn', 5, 5), ('Zt', 5, 0)),
-    matcher   = 'Unimp',
-    processor = 'Unimp',
+    matcher   = 'ZRegList(4, WORD), PBare, RefIndexLsl(1)',
+    processor = 'R(0), R3(10), R(5), RNoZr(16)',
 )
 
 tlentry(['ST4H'],
     '{<Zt1>.H,<Zt2>.H,<Zt3>.H,<Zt4>.H},<Pg>,[<Xn|SP>{,#<imm>,MULVL}]', (('imm4', 4, 16), ('Pg', 3, 10), ('Rn', 5, 5), ('Zt', 5, 0)),
-    matcher   = 'Unimp',
-    processor = 'Unimp',
+    matcher   = 'ZRegList(4, WORD), PBare, RefMulVl',
+    processor = 'R(0), R3(10), R(5), Sscaled(16, 4, 2)',
 )
 
 tlentry(['LD4H'],
     '{<Zt1>.H,<Zt2>.H,<Zt3>.H,<Zt4>.H},<Pg>/Z,[<Xn|SP>,<Xm>,LSL#1]', (('Rm', 5, 16), ('Pg', 3, 10), ('Rn', 5, 5), ('Zt', 5, 0)),
-    matcher   = 'Unimp',
-    processor = 'Unimp',
+    matcher   = 'ZRegList(4, WORD), PZero, RefIndexLsl(1)',
+    processor = 'R(0), R3(10), R(5), RNoZr(16)',
 )
 
 tlentry(['LD4H'],
     '{<Zt1>.H,<Zt2>.H,<Zt3>.H,<Zt4>.H},<Pg>/Z,[<Xn|SP>{,#<imm>,MULVL}]', (('imm4', 4, 16), ('Pg', 3, 10), ('Rn', 5, 5), ('Zt', 5, 0)),
-    matcher   = 'Unimp',
-    processor = 'Unimp',
+    matcher   = 'ZRegList(4, WORD), PZero, RefMulVl',
+    processor = 'R(0), R3(10), R(5), Sscaled(16, 4, 2)',
 )
 
 tlentry(['ST3H'],
     '{<Zt1>.H,<Zt2>.H,<Zt3>.H},<Pg>,[<Xn|SP>,<Xm>,LSL#1]', (('Rm', 5, 16), ('Pg', 3, 10), ('Rn', 5, 5), ('Zt', 5, 0)),
-    matcher   = 'Unimp',
-    processor = 'Unimp',
+    matcher   = 'ZRegList(3, WORD), PBare, RefIndexLsl(1)',
+    processor = 'R(0), R3(10), R(5), RNoZr(16)',
 )
 
 tlentry(['ST3H'],
     '{<Zt1>.H,<Zt2>.H,<Zt3>.H},<Pg>,[<Xn|SP>{,#<imm>,MULVL}]', (('imm4', 4, 16), ('Pg', 3, 10), ('Rn', 5, 5), ('Zt', 5, 0)),
-    matcher   = 'Unimp',
-    processor = 'Unimp',
+    matcher   = 'ZRegList(3, WORD), PBare, RefMulVl',
+    processor = 'R(0), R3(10), R(5), Smultiple(16, 4, 3)',
 )
 
 tlentry(['LD3H'],
     '{<Zt1>.H,<Zt2>.H,<Zt3>.H},<Pg>/Z,[<Xn|SP>,<Xm>,LSL#1]', (('Rm', 5, 16), ('Pg', 3, 10), ('Rn', 5, 5), ('Zt', 5, 0)),
-    matcher   = 'Unimp',
-    processor = 'Unimp',
+    matcher   = 'ZRegList(3, WORD), PZero, RefIndexLsl(1)',
+    processor = 'R(0), R3(10), R(5), RNoZr(16)',
 )
 
 tlentry(['LD3H'],
     '{<Zt1>.H,<Zt2>.H,<Zt3>.H},<Pg>/Z,[<Xn|SP>{,#<imm>,MULVL}]', (('imm4', 4, 16), ('Pg', 3, 10), ('Rn', 5, 5), ('Zt', 5, 0)),
-    matcher   = 'Unimp',
-    processor = 'Unimp',
+    matcher   = 'ZRegList(3, WORD), PZero, RefMulVl',
+    processor = 'R(0), R3(10), R(5), Smultiple(16, 4, 3)',
 )
 
 tlentry(['ST2H'],
     '{<Zt1>.H,<Zt2>.H},<Pg>,[<Xn|SP>,<Xm>,LSL#1]', (('Rm', 5, 16), ('Pg', 3, 10), ('Rn', 5, 5), ('Zt', 5, 0)),
-    matcher   = 'Unimp',
-    processor = 'Unimp',
+    matcher   = 'ZRegList(2, WORD), PBare, RefIndexLsl(1)',
+    processor = 'R(0), R3(10), R(5), RNoZr(16)',
 )
 
 tlentry(['ST2H'],
     '{<Zt1>.H,<Zt2>.H},<Pg>,[<Xn|SP>{,#<imm>,MULVL}]', (('imm4', 4, 16), ('Pg', 3, 10), ('Rn', 5, 5), ('Zt', 5, 0)),
-    matcher   = 'Unimp',
-    processor = 'Unimp',
+    matcher   = 'ZRegList(2, WORD), PBare, RefMulVl',
+    processor = 'R(0), R3(10), R(5), Sscaled(16, 4, 1)',
 )
 
 tlentry(['LD2H'],
     '{<Zt1>.H,<Zt2>.H},<Pg>/Z,[<Xn|SP>,<Xm>,LSL#1]', (('Rm', 5, 16), ('Pg', 3, 10), ('Rn', 5, 5), ('Zt', 5, 0)),
-    matcher   = 'Unimp',
-    processor = 'Unimp',
+    matcher   = 'ZRegList(2, WORD), PZero, RefIndexLsl(1)',
+    processor = 'R(0), R3(10), R(5), RNoZr(16)',
 )
 
 tlentry(['LD2H'],
     '{<Zt1>.H,<Zt2>.H},<Pg>/Z,[<Xn|SP>{,#<imm>,MULVL}]', (('imm4', 4, 16), ('Pg', 3, 10), ('Rn', 5, 5), ('Zt', 5, 0)),
-    matcher   = 'Unimp',
-    processor = 'Unimp',
+    matcher   = 'ZRegList(2, WORD), PZero, RefMulVl',
+    processor = 'R(0), R3(10), R(5), Sscaled(16, 4, 1)',
 )
 
 tlentry(['ST4W'],
     '{<Zt1>.S,<Zt2>.S,<Zt3>.S,<Zt4>.S},<Pg>,[<Xn|SP>,<Xm>,LSL#2]', (('Rm', 5, 16), ('Pg', 3, 10), ('Rn', 5, 5), ('Zt', 5, 0)),
-    matcher   = 'Unimp',
-    processor = 'Unimp',
+    matcher   = 'ZRegList(4, DWORD), PBare, RefIndexLsl(2)',
+    processor = 'R(0), R3(10), R(5), RNoZr(16)',
 )
 
 tlentry(['ST4W'],
     '{<Zt1>.S,<Zt2>.S,<Zt3>.S,<Zt4>.S},<Pg>,[<Xn|SP>{,#<imm>,MULVL}]', (('imm4', 4, 16), ('Pg', 3, 10), ('Rn', 5, 5), ('Zt', 5, 0)),
-    matcher   = 'Unimp',
-    processor = 'Unimp',
+    matcher   = 'ZRegList(4, DWORD), PBare, RefMulVl',
+    processor = 'R(0), R3(10), R(5), Sscaled(16, 4, 2)',
 )
 
 tlentry(['LD4W'],
     '{<Zt1>.S,<Zt2>.S,<Zt3>.S,<Zt4>.S},<Pg>/Z,[<Xn|SP>,<Xm>,LSL#2]', (('Rm', 5, 16), ('Pg', 3, 10), ('Rn', 5, 5), ('Zt', 5, 0)),
-    matcher   = 'Unimp',
-    processor = 'Unimp',
+    matcher   = 'ZRegList(4, DWORD), PZero, RefIndexLsl(2)',
+    processor = 'R(0), R3(10), R(5), RNoZr(16)',
 )
 
 tlentry(['LD4W'],
     '{<Zt1>.S,<Zt2>.S,<Zt3>.S,<Zt4>.S},<Pg>/Z,[<Xn|SP>{,#<imm>,MULVL}]', (('imm4', 4, 16), ('Pg', 3, 10), ('Rn', 5, 5), ('Zt', 5, 0)),
-    matcher   = 'Unimp',
-    processor = 'Unimp',
+    matcher   = 'ZRegList(4, DWORD), PZero, RefMulVl',
+    processor = 'R(0), R3(10), R(5), Sscaled(16, 4, 2)',
 )
 
 tlentry(['ST3W'],
     '{<Zt1>.S,<Zt2>.S,<Zt3>.S},<Pg>,[<Xn|SP>,<Xm>,LSL#2]', (('Rm', 5, 16), ('Pg', 3, 10), ('Rn', 5, 5), ('Zt', 5, 0)),
-    matcher   = 'Unimp',
-    processor = 'Unimp',
+    matcher   = 'ZRegList(3, DWORD), PBare, RefIndexLsl(2)',
+    processor = 'R(0), R3(10), R(5), RNoZr(16)',
 )
 
 tlentry(['ST3W'],
     '{<Zt1>.S,<Zt2>.S,<Zt3>.S},<Pg>,[<Xn|SP>{,#<imm>,MULVL}]', (('imm4', 4, 16), ('Pg', 3, 10), ('Rn', 5, 5), ('Zt', 5, 0)),
-    matcher   = 'Unimp',
-    processor = 'Unimp',
+    matcher   = 'ZRegList(3, DWORD), PBare, RefMulVl',
+    processor = 'R(0), R3(10), R(5), Smultiple(16, 4, 3)',
 )
 
 tlentry(['LD3W'],
     '{<Zt1>.S,<Zt2>.S,<Zt3>.S},<Pg>/Z,[<Xn|SP>,<Xm>,LSL#2]', (('Rm', 5, 16), ('Pg', 3, 10), ('Rn', 5, 5), ('Zt', 5, 0)),
-    matcher   = 'Unimp',
-    processor = 'Unimp',
+    matcher   = 'ZRegList(3, DWORD), PZero, RefIndexLsl(2)',
+    processor = 'R(0), R3(10), R(5), RNoZr(16)',
 )
 
 tlentry(['LD3W'],
     '{<Zt1>.S,<Zt2>.S,<Zt3>.S},<Pg>/Z,[<Xn|SP>{,#<imm>,MULVL}]', (('imm4', 4, 16), ('Pg', 3, 10), ('Rn', 5, 5), ('Zt', 5, 0)),
-    matcher   = 'Unimp',
-    processor = 'Unimp',
+    matcher   = 'ZRegList(3, DWORD), PZero, RefMulVl',
+    processor = 'R(0), R3(10), R(5), Smultiple(16, 4, 3)',
 )
 
 tlentry(['ST2W'],
     '{<Zt1>.S,<Zt2>.S},<Pg>,[<Xn|SP>,<Xm>,LSL#2]', (('Rm', 5, 16), ('Pg', 3, 10), ('Rn', 5, 5), ('Zt', 5, 0)),
-    matcher   = 'Unimp',
-    processor = 'Unimp',
+    matcher   = 'ZRegList(2, DWORD), PBare, RefIndexLsl(2)',
+    processor = 'R(0), R3(10), R(5), RNoZr(16)',
 )
 
 tlentry(['ST2W'],
     '{<Zt1>.S,<Zt2>.S},<Pg>,[<Xn|SP>{,#<imm>,MULVL}]', (('imm4', 4, 16), ('Pg', 3, 10), ('Rn', 5, 5), ('Zt', 5, 0)),
-    matcher   = 'Unimp',
-    processor = 'Unimp',
+    matcher   = 'ZRegList(2, DWORD), PBare, RefMulVl',
+    processor = 'R(0), R3(10), R(5), Sscaled(16, 4, 1)',
 )
 
 tlentry(['LD2W'],
     '{<Zt1>.S,<Zt2>.S},<Pg>/Z,[<Xn|SP>,<Xm>,LSL#2]', (('Rm', 5, 16), ('Pg', 3, 10), ('Rn', 5, 5), ('Zt', 5, 0)),
-    matcher   = 'Unimp',
-    processor = 'Unimp',
+    matcher   = 'ZRegList(2, DWORD), PZero, RefIndexLsl(2)',
+    processor = 'R(0), R3(10), R(5), RNoZr(16)',
 )
 
 tlentry(['LD2W'],
     '{<Zt1>.S,<Zt2>.S},<Pg>/Z,[<Xn|SP>{,#<imm>,MULVL}]', (('imm4', 4, 16), ('Pg', 3, 10), ('Rn', 5, 5), ('Zt', 5, 0)),
-    matcher   = 'Unimp',
-    processor = 'Unimp',
+    matcher   = 'ZRegList(2, DWORD), PZero, RefMulVl',
+    processor = 'R(0), R3(10), R(5), Sscaled(16, 4, 1)',
 )
 
 tlentry(['ST1H'],
     '{<Zt>.<T>},<Pg>,[<Xn|SP>,<Xm>,LSL#1]', (('size', 2, 21), ('Rm', 5, 16), ('Pg', 3, 10), ('Rn', 5, 5), ('Zt', 5, 0)),
-    matcher   = 'Unimp',
-    processor = 'Unimp',
+    matchers = [
+        'ZRegList(1, WORD), PBare, RefIndexLsl(1)',
+        'ZRegList(1, DWORD), PBare, RefIndexLsl(1)',
+        'ZRegList(1, QWORD), PBare, RefIndexLsl(1)',
+    ],
+    processors = [
+        'R(0), R3(10), R(5), RNoZr(16), Static(21, 0b01)',
+        'R(0), R3(10), R(5), RNoZr(16), Static(21, 0b10)',
+        'R(0), R3(10), R(5), RNoZr(16), Static(21, 0b11)',
+    ],
 )
 
 tlentry(['ST1W'],
     '{<Zt>.<T>},<Pg>,[<Xn|SP>,<Xm>,LSL#2]', (('size', 2, 21), ('Rm', 5, 16), ('Pg', 3, 10), ('Rn', 5, 5), ('Zt', 5, 0)),
-    matcher   = 'Unimp',
-    processor = 'Unimp',
+    matchers = [
+        'ZRegList(1, DWORD), PBare, RefIndexLsl(2)',
+        'ZRegList(1, QWORD), PBare, RefIndexLsl(2)',
+    ],
+    processors = [
+        'R(0), R3(10), R(5), RNoZr(16), Static(21, 0b10)',
+        'R(0), R3(10), R(5), RNoZr(16), Static(21, 0b11)',
+    ],
 )
 
 tlentry(['ST1B'],
     '{<Zt>.<T>},<Pg>,[<Xn|SP>,<Xm>]', (('size', 2, 21), ('Rm', 5, 16), ('Pg', 3, 10), ('Rn', 5, 5), ('Zt', 5, 0)),
-    matcher   = 'Unimp',
-    processor = 'Unimp',
+    matchers = [
+        'ZRegList(1, BYTE), PBare, RefIndexLsl(0)',
+        'ZRegList(1, WORD), PBare, RefIndexLsl(0)',
+        'ZRegList(1, DWORD), PBare, RefIndexLsl(0)',
+        'ZRegList(1, QWORD), PBare, RefIndexLsl(0)',
+    ],
+    processors = [
+        'R(0), R3(10), R(5), RNoZr(16), Static(21, 0b00)',
+        'R(0), R3(10), R(5), RNoZr(16), Static(21, 0b01)',
+        'R(0), R3(10), R(5), RNoZr(16), Static(21, 0b10)',
+        'R(0), R3(10), R(5), RNoZr(16), Static(21, 0b11)',
+    ],
 )
 
-tlentry(['ST1B', 'ST1H', 'ST1W'],
+tlentry(['ST1B'],
+    '{<Zt>.<T>},<Pg>,[<Xn|SP>{,#<imm>,MULVL}]', (('size', 2, 21), ('imm4', 4, 16), ('Pg', 3, 10), ('Rn', 5, 5), ('Zt', 5, 0)),
+    matchers = [
+        'ZRegList(1, BYTE), PBare, RefMulVl',
+        'ZRegList(1, WORD), PBare, RefMulVl',
+        'ZRegList(1, DWORD), PBare, RefMulVl',
+        'ZRegList(1, QWORD), PBare, RefMulVl',
+    ],
+    processors = [
+        'R(0), R3(10), R(5), Sbits(16, 4), Static(21, 0b00)',
+        'R(0), R3(10), R(5), Sbits(16, 4), Static(21, 0b01)',
+        'R(0), R3(10), R(5), Sbits(16, 4), Static(21, 0b10)',
+        'R(0), R3(10), R(5), Sbits(16, 4), Static(21, 0b11)',
+    ],
+)
+
+tlentry(['ST1H'],
+    '{<Zt>.<T>},<Pg>,[<Xn|SP>{,#<imm>,MULVL}]', (('size', 2, 21), ('imm4', 4, 16), ('Pg', 3, 10), ('Rn', 5, 5), ('Zt', 5, 0)),
+    matchers = [
+        'ZRegList(1, WORD), PBare, RefMulVl',
+        'ZRegList(1, DWORD), PBare, RefMulVl',
+        'ZRegList(1, QWORD), PBare, RefMulVl',
+    ],
+    processors = [
+        'R(0), R3(10), R(5), Sbits(16, 4), Static(21, 0b01)',
+        'R(0), R3(10), R(5), Sbits(16, 4), Static(21, 0b10)',
+        'R(0), R3(10), R(5), Sbits(16, 4), Static(21, 0b11)',
+    ],
+)
+
+tlentry(['ST1W'],
     '{<Zt>.<T>},<Pg>,[<Xn|SP>{,#<imm>,MULVL}]', (('size', 2, 21), ('imm4', 4, 16), ('Pg', 3, 10), ('Rn', 5, 5), ('Zt', 5, 0)),
-    matcher   = 'Unimp',
-    processor = 'Unimp',
+    matchers = [
+        'ZRegList(1, DWORD), PBare, RefMulVl',
+        'ZRegList(1, QWORD), PBare, RefMulVl',
+    ],
+    processors = [
+        'R(0), R3(10), R(5), Sbits(16, 4), Static(21, 0b10)',
+        'R(0), R3(10), R(5), Sbits(16, 4), Static(21, 0b11)',
+    ],
 )
 
 tlentry(['STNT1B'],
     '{<Zt>.B},<Pg>,[<Xn|SP>,<Xm>]', (('Rm', 5, 16), ('Pg', 3, 10), ('Rn', 5, 5), ('Zt', 5, 0)),
-    matcher   = 'Unimp',
-    processor = 'Unimp',
+    matcher   = 'ZRegList(1, BYTE), PBare, RefIndexLsl(0)',
+    processor = 'R(0), R3(10), R(5), RNoZr(16)',
 )
 
 tlentry(['STNT1B'],
     '{<Zt>.B},<Pg>,[<Xn|SP>{,#<imm>,MULVL}]', (('imm4', 4, 16), ('Pg', 3, 10), ('Rn', 5, 5), ('Zt', 5, 0)),
-    matcher   = 'Unimp',
-    processor = 'Unimp',
+    matcher   = 'ZRegList(1, BYTE), PBare, RefMulVl',
+    processor = 'R(0), R3(10), R(5), Sbits(16, 4)',
 )
 
 tlentry(['LD1B', 'LD1RQB', 'LDNT1B'],
     '{<Zt>.B},<Pg>/Z,[<Xn|SP>,<Xm>]', (('Rm', 5, 16), ('Pg', 3, 10), ('Rn', 5, 5), ('Zt', 5, 0)),
-    matcher   = 'Unimp',
-    processor = 'Unimp',
+    matcher   = 'ZRegList(1, BYTE), PZero, RefIndexLsl(0)',
+    processor = 'R(0), R3(10), R(5), RNoZr(16)',
 )
 
 tlentry(['LD1B', 'LDNF1B', 'LDNT1B'],
     '{<Zt>.B},<Pg>/Z,[<Xn|SP>{,#<imm>,MULVL}]', (('imm4', 4, 16), ('Pg', 3, 10), ('Rn', 5, 5), ('Zt', 5, 0)),
-    matcher   = 'Unimp',
-    processor = 'Unimp',
+    matcher   = 'ZRegList(1, BYTE), PZero, RefMulVl',
+    processor = 'R(0), R3(10), R(5), Sbits(16, 4)',
 )
 
 tlentry(['LD1RQB'],
     '{<Zt>.B},<Pg>/Z,[<Xn|SP>{,#<imm>}]', (('imm4', 4, 16), ('Pg', 3, 10), ('Rn', 5, 5), ('Zt', 5, 0)),
-    matcher   = 'Unimp',
-    processor = 'Unimp',
+    matcher   = 'ZRegList(1, BYTE), PZero, RefOffset',
+    processor = 'R(0), R3(10), R(5), Sscaled(16, 4, 4)',
 )
 
 tlentry(['LD1RB'],
     '{<Zt>.B},<Pg>/Z,[<Xn|SP>{,#<imm>}]', (('imm6', 6, 16), ('Pg', 3, 10), ('Rn', 5, 5), ('Zt', 5, 0)),
-    matcher   = 'Unimp',
-    processor = 'Unimp',
+    matcher   = 'ZRegList(1, BYTE), PZero, RefOffset',
+    processor = 'R(0), R3(10), R(5), Ubits(16, 6)',
 )
 
 tlentry(['LDFF1B'],
     '{<Zt>.B},<Pg>/Z,[<Xn|SP>{,<Xm>}]', (('Rm', 5, 16), ('Pg', 3, 10), ('Rn', 5, 5), ('Zt', 5, 0)),
-    matcher   = 'Unimp',
-    processor = 'Unimp',
+    matchers = [
+        'ZRegList(1, BYTE), PZero, RefIndexLsl(0)',
+        'ZRegList(1, BYTE), PZero, RefBase',
+    ],
+    processors = [
+        'R(0), R3(10), R(5), R(16)',
+        'R(0), R3(10), R(5), Static(16, 0b11111)',
+    ],
 )
 
 tlentry(['ST1D', 'STNT1D'],
     '{<Zt>.D},<Pg>,[<Xn|SP>,<Xm>,LSL#3]', (('Rm', 5, 16), ('Pg', 3, 10), ('Rn', 5, 5), ('Zt', 5, 0)),
-    matcher   = 'Unimp',
-    processor = 'Unimp',
+    matcher   = 'ZRegList(1, QWORD), PBare, RefIndexLsl(3)',
+    processor = 'R(0), R3(10), R(5), RNoZr(16)',
 )
 
 tlentry(['ST1H'],
     '{<Zt>.D},<Pg>,[<Xn|SP>,<Zm>.D,<mod>#1]', (('Zm', 5, 16), ('xs', 1, 14), ('Pg', 3, 10), ('Rn', 5, 5), ('Zt', 5, 0)),
-    matcher   = 'Unimp',
-    processor = 'Unimp',
+    matcher   = 'ZRegList(1, QWORD), PBare, RefZIndexExtend(QWORD, 1)',
+    processor = 'R(0), R3(10), R(5), R(16), ExtendsZ(14)',
 )
 
 tlentry(['ST1W'],
     '{<Zt>.D},<Pg>,[<Xn|SP>,<Zm>.D,<mod>#2]', (('Zm', 5, 16), ('xs', 1, 14), ('Pg', 3, 10), ('Rn', 5, 5), ('Zt', 5, 0)),
-    matcher   = 'Unimp',
-    processor = 'Unimp',
+    matcher   = 'ZRegList(1, QWORD), PBare, RefZIndexExtend(QWORD, 2)',
+    processor = 'R(0), R3(10), R(5), R(16), ExtendsZ(14)',
 )
 
 tlentry(['ST1D'],
     '{<Zt>.D},<Pg>,[<Xn|SP>,<Zm>.D,<mod>#3]', (('Zm', 5, 16), ('xs', 1, 14), ('Pg', 3, 10), ('Rn', 5, 5), ('Zt', 5, 0)),
-    matcher   = 'Unimp',
-    processor = 'Unimp',
+    matcher   = 'ZRegList(1, QWORD), PBare, RefZIndexExtend(QWORD, 3)',
+    processor = 'R(0), R3(10), R(5), R(16), ExtendsZ(14)',
 )
 
 tlentry(['ST1B', 'ST1D', 'ST1H', 'ST1W'],
     '{<Zt>.D},<Pg>,[<Xn|SP>,<Zm>.D,<mod>]', (('Zm', 5, 16), ('xs', 1, 14), ('Pg', 3, 10), ('Rn', 5, 5), ('Zt', 5, 0)),
-    matcher   = 'Unimp',
-    processor = 'Unimp',
+    matcher   = 'ZRegList(1, QWORD), PBare, RefZIndexExtend(QWORD, 0)',
+    processor = 'R(0), R3(10), R(5), R(16), ExtendsZ(14)',
 )
 
 tlentry(['ST1H'],
     '{<Zt>.D},<Pg>,[<Xn|SP>,<Zm>.D,LSL#1]', (('Zm', 5, 16), ('Pg', 3, 10), ('Rn', 5, 5), ('Zt', 5, 0)),
-    matcher   = 'Unimp',
-    processor = 'Unimp',
+    matcher   = 'ZRegList(1, QWORD), PBare, RefZIndexLsl(QWORD, 1)',
+    processor = 'R(0), R3(10), R(5), R(16)',
 )
 
 tlentry(['ST1W'],
     '{<Zt>.D},<Pg>,[<Xn|SP>,<Zm>.D,LSL#2]', (('Zm', 5, 16), ('Pg', 3, 10), ('Rn', 5, 5), ('Zt', 5, 0)),
-    matcher   = 'Unimp',
-    processor = 'Unimp',
+    matcher   = 'ZRegList(1, QWORD), PBare, RefZIndexLsl(QWORD, 2)',
+    processor = 'R(0), R3(10), R(5), R(16)',
 )
 
 tlentry(['ST1D'],
     '{<Zt>.D},<Pg>,[<Xn|SP>,<Zm>.D,LSL#3]', (('Zm', 5, 16), ('Pg', 3, 10), ('Rn', 5, 5), ('Zt', 5, 0)),
-    matcher   = 'Unimp',
-    processor = 'Unimp',
+    matcher   = 'ZRegList(1, QWORD), PBare, RefZIndexLsl(QWORD, 3)',
+    processor = 'R(0), R3(10), R(5), R(16)',
 )
 
 tlentry(['ST1B', 'ST1D', 'ST1H', 'ST1W'],
     '{<Zt>.D},<Pg>,[<Xn|SP>,<Zm>.D]', (('Zm', 5, 16), ('Pg', 3, 10), ('Rn', 5, 5), ('Zt', 5, 0)),
-    matcher   = 'Unimp',
-    processor = 'Unimp',
+    matcher   = 'ZRegList(1, QWORD), PBare, RefZIndexLsl(QWORD, 0)',
+    processor = 'R(0), R3(10), R(5), R(16)',
 )
 
 tlentry(['STNT1D'],
     '{<Zt>.D},<Pg>,[<Xn|SP>{,#<imm>,MULVL}]', (('imm4', 4, 16), ('Pg', 3, 10), ('Rn', 5, 5), ('Zt', 5, 0)),
-    matcher   = 'Unimp',
-    processor = 'Unimp',
+    matcher   = 'ZRegList(1, QWORD), PBare, RefMulVl',
+    processor = 'R(0), R3(10), R(5), Sbits(16, 4)',
 )
 
 tlentry(['ST1D'],
     '{<Zt>.D},<Pg>,[<Xn|SP>{,#<imm>,MULVL}]', (('size', 2, 21), ('imm4', 4, 16), ('Pg', 3, 10), ('Rn', 5, 5), ('Zt', 5, 0)),
-    matcher   = 'Unimp',
-    processor = 'Unimp',
+    matcher   = 'ZRegList(1, QWORD), PBare, RefMulVl',
+    processor = 'R(0), R3(10), R(5), Sbits(16, 4), Static(21, 0b11)',
 )
 
-tlentry(['ST1B', 'ST1D', 'ST1H', 'ST1W'],
+tlentry(['ST1B'],
+    '{<Zt>.D},<Pg>,[<Zn>.D{,#<imm>}]', (('imm5', 5, 16), ('Pg', 3, 10), ('Zn', 5, 5), ('Zt', 5, 0)),
+    matcher   = 'ZRegList(1, QWORD), PBare, RefZBase(QWORD)',
+    processor = 'R(0), R3(10), R(5), Ubits(16, 5)',
+)
+
+tlentry(['ST1D'],
+    '{<Zt>.D},<Pg>,[<Zn>.D{,#<imm>}]', (('imm5', 5, 16), ('Pg', 3, 10), ('Zn', 5, 5), ('Zt', 5, 0)),
+    matcher   = 'ZRegList(1, QWORD), PBare, RefZBase(QWORD)',
+    processor = 'R(0), R3(10), R(5), Uscaled(16, 5, 3)',
+)
+
+tlentry(['ST1H'],
+    '{<Zt>.D},<Pg>,[<Zn>.D{,#<imm>}]', (('imm5', 5, 16), ('Pg', 3, 10), ('Zn', 5, 5), ('Zt', 5, 0)),
+    matcher   = 'ZRegList(1, QWORD), PBare, RefZBase(QWORD)',
+    processor = 'R(0), R3(10), R(5), Uscaled(16, 5, 1)',
+)
+
+tlentry(['ST1W'],
     '{<Zt>.D},<Pg>,[<Zn>.D{,#<imm>}]', (('imm5', 5, 16), ('Pg', 3, 10), ('Zn', 5, 5), ('Zt', 5, 0)),
-    matcher   = 'Unimp',
-    processor = 'Unimp',
+    matcher   = 'ZRegList(1, QWORD), PBare, RefZBase(QWORD)',
+    processor = 'R(0), R3(10), R(5), Uscaled(16, 5, 2)',
 )
 
 tlentry(['LD1H', 'LD1SH'],
     '{<Zt>.D},<Pg>/Z,[<Xn|SP>,<Xm>,LSL#1]', (('Rm', 5, 16), ('Pg', 3, 10), ('Rn', 5, 5), ('Zt', 5, 0)),
-    matcher   = 'Unimp',
-    processor = 'Unimp',
+    matcher   = 'ZRegList(1, QWORD), PZero, RefIndexLsl(1)',
+    processor = 'R(0), R3(10), R(5), RNoZr(16)',
 )
 
 tlentry(['LD1SW', 'LD1W'],
     '{<Zt>.D},<Pg>/Z,[<Xn|SP>,<Xm>,LSL#2]', (('Rm', 5, 16), ('Pg', 3, 10), ('Rn', 5, 5), ('Zt', 5, 0)),
-    matcher   = 'Unimp',
-    processor = 'Unimp',
+    matcher   = 'ZRegList(1, QWORD), PZero, RefIndexLsl(2)',
+    processor = 'R(0), R3(10), R(5), RNoZr(16)',
 )
 
 tlentry(['LD1D', 'LD1RQD', 'LDNT1D'],
     '{<Zt>.D},<Pg>/Z,[<Xn|SP>,<Xm>,LSL#3]', (('Rm', 5, 16), ('Pg', 3, 10), ('Rn', 5, 5), ('Zt', 5, 0)),
-    matcher   = 'Unimp',
-    processor = 'Unimp',
+    matcher   = 'ZRegList(1, QWORD), PZero, RefIndexLsl(3)',
+    processor = 'R(0), R3(10), R(5), RNoZr(16)',
 )
 
 tlentry(['LD1B', 'LD1SB'],
     '{<Zt>.D},<Pg>/Z,[<Xn|SP>,<Xm>]', (('Rm', 5, 16), ('Pg', 3, 10), ('Rn', 5, 5), ('Zt', 5, 0)),
-    matcher   = 'Unimp',
-    processor = 'Unimp',
+    matcher   = 'ZRegList(1, QWORD), PZero, RefIndexLsl(0)',
+    processor = 'R(0), R3(10), R(5), RNoZr(16)',
 )
 
 tlentry(['LD1H', 'LD1SH', 'LDFF1H', 'LDFF1SH'],
     '{<Zt>.D},<Pg>/Z,[<Xn|SP>,<Zm>.D,<mod>#1]', (('xs', 1, 22), ('Zm', 5, 16), ('Pg', 3, 10), ('Rn', 5, 5), ('Zt', 5, 0)),
-    matcher   = 'Unimp',
-    processor = 'Unimp',
+    matcher   = 'ZRegList(1, QWORD), PZero, RefZIndexExtend(QWORD, 1)',
+    processor = 'R(0), R3(10), R(5), R(16), ExtendsZ(22)',
 )
 
 tlentry(['LD1SW', 'LD1W', 'LDFF1SW', 'LDFF1W'],
     '{<Zt>.D},<Pg>/Z,[<Xn|SP>,<Zm>.D,<mod>#2]', (('xs', 1, 22), ('Zm', 5, 16), ('Pg', 3, 10), ('Rn', 5, 5), ('Zt', 5, 0)),
-    matcher   = 'Unimp',
-    processor = 'Unimp',
+    matcher   = 'ZRegList(1, QWORD), PZero, RefZIndexExtend(QWORD, 2)',
+    processor = 'R(0), R3(10), R(5), R(16), ExtendsZ(22)',
 )
 
 tlentry(['LD1D', 'LDFF1D'],
     '{<Zt>.D},<Pg>/Z,[<Xn|SP>,<Zm>.D,<mod>#3]', (('xs', 1, 22), ('Zm', 5, 16), ('Pg', 3, 10), ('Rn', 5, 5), ('Zt', 5, 0)),
-    matcher   = 'Unimp',
-    processor = 'Unimp',
+    matcher   = 'ZRegList(1, QWORD), PZero, RefZIndexExtend(QWORD, 3)',
+    processor = 'R(0), R3(10), R(5), R(16), ExtendsZ(22)',
 )
 
 tlentry(['LD1B', 'LD1D', 'LD1H', 'LD1SB', 'LD1SH', 'LD1SW', 'LD1W', 'LDFF1B', 'LDFF1D', 'LDFF1H', 'LDFF1SB', 'LDFF1SH', 'LDFF1SW', 'LDFF1W'],
     '{<Zt>.D},<Pg>/Z,[<Xn|SP>,<Zm>.D,<mod>]', (('xs', 1, 22), ('Zm', 5, 16), ('Pg', 3, 10), ('Rn', 5, 5), ('Zt', 5, 0)),
-    matcher   = 'Unimp',
-    processor = 'Unimp',
+    matcher   = 'ZRegList(1, QWORD), PZero, RefZIndexExtend(QWORD, 0)',
+    processor = 'R(0), R3(10), R(5), R(16), ExtendsZ(22)',
 )
 
 tlentry(['LD1H', 'LD1SH', 'LDFF1H', 'LDFF1SH'],
     '{<Zt>.D},<Pg>/Z,[<Xn|SP>,<Zm>.D,LSL#1]', (('Zm', 5, 16), ('Pg', 3, 10), ('Rn', 5, 5), ('Zt', 5, 0)),
-    matcher   = 'Unimp',
-    processor = 'Unimp',
+    matcher   = 'ZRegList(1, QWORD), PZero, RefZIndexLsl(QWORD, 1)',
+    processor = 'R(0), R3(10), R(5), R(16)',
 )
 
 tlentry(['LD1SW', 'LD1W', 'LDFF1SW', 'LDFF1W'],
     '{<Zt>.D},<Pg>/Z,[<Xn|SP>,<Zm>.D,LSL#2]', (('Zm', 5, 16), ('Pg', 3, 10), ('Rn', 5, 5), ('Zt', 5, 0)),
-    matcher   = 'Unimp',
-    processor = 'Unimp',
+    matcher   = 'ZRegList(1, QWORD), PZero, RefZIndexLsl(QWORD, 2)',
+    processor = 'R(0), R3(10), R(5), R(16)',
 )
 
 tlentry(['LD1D', 'LDFF1D'],
     '{<Zt>.D},<Pg>/Z,[<Xn|SP>,<Zm>.D,LSL#3]', (('Zm', 5, 16), ('Pg', 3, 10), ('Rn', 5, 5), ('Zt', 5, 0)),
-    matcher   = 'Unimp',
-    processor = 'Unimp',
+    matcher   = 'ZRegList(1, QWORD), PZero, RefZIndexLsl(QWORD, 3)',
+    processor = 'R(0), R3(10), R(5), R(16)',
 )
 
 tlentry(['LD1B', 'LD1D', 'LD1H', 'LD1SB', 'LD1SH', 'LD1SW', 'LD1W', 'LDFF1B', 'LDFF1D', 'LDFF1H', 'LDFF1SB', 'LDFF1SH', 'LDFF1SW', 'LDFF1W'],
     '{<Zt>.D},<Pg>/Z,[<Xn|SP>,<Zm>.D]', (('Zm', 5, 16), ('Pg', 3, 10), ('Rn', 5, 5), ('Zt', 5, 0)),
-    matcher   = 'Unimp',
-    processor = 'Unimp',
+    matcher   = 'ZRegList(1, QWORD), PZero, RefZIndexLsl(QWORD, 0)',
+    processor = 'R(0), R3(10), R(5), R(16)',
 )
 
 tlentry(['LD1B', 'LD1D', 'LD1H', 'LD1SB', 'LD1SH', 'LD1SW', 'LD1W', 'LDNF1B', 'LDNF1D', 'LDNF1H', 'LDNF1SB', 'LDNF1SH', 'LDNF1SW', 'LDNF1W', 'LDNT1D'],
     '{<Zt>.D},<Pg>/Z,[<Xn|SP>{,#<imm>,MULVL}]', (('imm4', 4, 16), ('Pg', 3, 10), ('Rn', 5, 5), ('Zt', 5, 0)),
-    matcher   = 'Unimp',
-    processor = 'Unimp',
+    matcher   = 'ZRegList(1, QWORD), PZero, RefMulVl',
+    processor = 'R(0), R3(10), R(5), Sbits(16, 4)',
 )
 
 tlentry(['LD1RQD'],
     '{<Zt>.D},<Pg>/Z,[<Xn|SP>{,#<imm>}]', (('imm4', 4, 16), ('Pg', 3, 10), ('Rn', 5, 5), ('Zt', 5, 0)),
-    matcher   = 'Unimp',
-    processor = 'Unimp',
+    matcher   = 'ZRegList(1, QWORD), PZero, RefOffset',
+    processor = 'R(0), R3(10), R(5), Sscaled(16, 4, 4)',
+)
+
+tlentry(['LD1RB'],
+    '{<Zt>.D},<Pg>/Z,[<Xn|SP>{,#<imm>}]', (('imm6', 6, 16), ('Pg', 3, 10), ('Rn', 5, 5), ('Zt', 5, 0)),
+    matcher   = 'ZRegList(1, QWORD), PZero, RefOffset',
+    processor = 'R(0), R3(10), R(5), Ubits(16, 6)',
+)
+
+tlentry(['LD1RD'],
+    '{<Zt>.D},<Pg>/Z,[<Xn|SP>{,#<imm>}]', (('imm6', 6, 16), ('Pg', 3, 10), ('Rn', 5, 5), ('Zt', 5, 0)),
+    matcher   = 'ZRegList(1, QWORD), PZero, RefOffset',
+    processor = 'R(0), R3(10), R(5), Uscaled(16, 6, 3)',
 )
 
-tlentry(['LD1RB', 'LD1RD', 'LD1RH', 'LD1RSB', 'LD1RSH', 'LD1RSW', 'LD1RW'],
+tlentry(['LD1RH'],
     '{<Zt>.D},<Pg>/Z,[<Xn|SP>{,#<imm>}]', (('imm6', 6, 16), ('Pg', 3, 10), ('Rn', 5, 5), ('Zt', 5, 0)),
-    matcher   = 'Unimp',
-    processor = 'Unimp',
+    matcher   = 'ZRegList(1, QWORD), PZero, RefOffset',
+    processor = 'R(0), R3(10), R(5), Uscaled(16, 6, 1)',
+)
+
+tlentry(['LD1RSB'],
+    '{<Zt>.D},<Pg>/Z,[<Xn|SP>{,#<imm>}]', (('imm6', 6, 16), ('Pg', 3, 10), ('Rn', 5, 5), ('Zt', 5, 0)),
+    matcher   = 'ZRegList(1, QWORD), PZero, RefOffset',
+    processor = 'R(0), R3(10), R(5), Ubits(16, 6)',
+)
+
+tlentry(['LD1RSH'],
+    '{<Zt>.D},<Pg>/Z,[<Xn|SP>{,#<imm>}]', (('imm6', 6, 16), ('Pg', 3, 10), ('Rn', 5, 5), ('Zt', 5, 0)),
+    matcher   = 'ZRegList(1, QWORD), PZero, RefOffset',
+    processor = 'R(0), R3(10), R(5), Uscaled(16, 6, 1)',
+)
+
+tlentry(['LD1RSW'],
+    '{<Zt>.D},<Pg>/Z,[<Xn|SP>{,#<imm>}]', (('imm6', 6, 16), ('Pg', 3, 10), ('Rn', 5, 5), ('Zt', 5, 0)),
+    matcher   = 'ZRegList(1, QWORD), PZero, RefOffset',
+    processor = 'R(0), R3(10), R(5), Uscaled(16, 6, 2)',
+)
+
+tlentry(['LD1RW'],
+    '{<Zt>.D},<Pg>/Z,[<Xn|SP>{,#<imm>}]', (('imm6', 6, 16), ('Pg', 3, 10), ('Rn', 5, 5), ('Zt', 5, 0)),
+    matcher   = 'ZRegList(1, QWORD), PZero, RefOffset',
+    processor = 'R(0), R3(10), R(5), Uscaled(16, 6, 2)',
 )
 
 tlentry(['LDFF1H', 'LDFF1SH'],
     '{<Zt>.D},<Pg>/Z,[<Xn|SP>{,<Xm>,LSL#1}]', (('Rm', 5, 16), ('Pg', 3, 10), ('Rn', 5, 5), ('Zt', 5, 0)),
-    matcher   = 'Unimp',
-    processor = 'Unimp',
+    matchers = [
+        'ZRegList(1, QWORD), PZero, RefIndexLsl(1)',
+        'ZRegList(1, QWORD), PZero, RefBase',
+    ],
+    processors = [
+        'R(0), R3(10), R(5), R(16)',
+        'R(0), R3(10), R(5), Static(16, 0b11111)',
+    ],
 )
 
 tlentry(['LDFF1SW', 'LDFF1W'],
     '{<Zt>.D},<Pg>/Z,[<Xn|SP>{,<Xm>,LSL#2}]', (('Rm', 5, 16), ('Pg', 3, 10), ('Rn', 5, 5), ('Zt', 5, 0)),
-    matcher   = 'Unimp',
-    processor = 'Unimp',
+    matchers = [
+        'ZRegList(1, QWORD), PZero, RefIndexLsl(2)',
+        'ZRegList(1, QWORD), PZero, RefBase',
+    ],
+    processors = [
+        'R(0), R3(10), R(5), R(16)',
+        'R(0), R3(10), R(5), Static(16, 0b11111)',
+    ],
 )
 
 tlentry(['LDFF1D'],
     '{<Zt>.D},<Pg>/Z,[<Xn|SP>{,<Xm>,LSL#3}]', (('Rm', 5, 16), ('Pg', 3, 10), ('Rn', 5, 5), ('Zt', 5, 0)),
-    matcher   = 'Unimp',
-    processor = 'Unimp',
+    matchers = [
+        'ZRegList(1, QWORD), PZero, RefIndexLsl(3)',
+        'ZRegList(1, QWORD), PZero, RefBase',
+    ],
+    processors = [
+        'R(0), R3(10), R(5), R(16)',
+        'R(0), R3(10), R(5), Static(16, 0b11111)',
+    ],
 )
 
 tlentry(['LDFF1B', 'LDFF1SB'],
     '{<Zt>.D},<Pg>/Z,[<Xn|SP>{,<Xm>}]', (('Rm', 5, 16), ('Pg', 3, 10), ('Rn', 5, 5), ('Zt', 5, 0)),
-    matcher   = 'Unimp',
-    processor = 'Unimp',
+    matchers = [
+        'ZRegList(1, QWORD), PZero, RefIndexLsl(0)',
+        'ZRegList(1, QWORD), PZero, RefBase',
+    ],
+    processors = [
+        'R(0), R3(10), R(5), R(16)',
+        'R(0), R3(10), R(5), Static(16, 0b11111)',
+    ],
 )
 
-tlentry(['LD1B', 'LD1D', 'LD1H', 'LD1SB', 'LD1SH', 'LD1SW', 'LD1W', 'LDFF1B', 'LDFF1D', 'LDFF1H', 'LDFF1SB', 'LDFF1SH', 'LDFF1SW', 'LDFF1W'],
+tlentry(['LD1B'],
+    '{<Zt>.D},<Pg>/Z,[<Zn>.D{,#<imm>}]', (('imm5', 5, 16), ('Pg', 3, 10), ('Zn', 5, 5), ('Zt', 5, 0)),
+    matcher   = 'ZRegList(1, QWORD), PZero, RefZBase(QWORD)',
+    processor = 'R(0), R3(10), R(5), Ubits(16, 5)',
+)
+
+tlentry(['LD1D'],
+    '{<Zt>.D},<Pg>/Z,[<Zn>.D{,#<imm>}]', (('imm5', 5, 16), ('Pg', 3, 10), ('Zn', 5, 5), ('Zt', 5, 0)),
+    matcher   = 'ZRegList(1, QWORD), PZero, RefZBase(QWORD)',
+    processor = 'R(0), R3(10), R(5), Uscaled(16, 5, 3)',
+)
+
+tlentry(['LD1H'],
+    '{<Zt>.D},<Pg>/Z,[<Zn>.D{,#<imm>}]', (('imm5', 5, 16), ('Pg', 3, 10), ('Zn', 5, 5), ('Zt', 5, 0)),
+    matcher   = 'ZRegList(1, QWORD), PZero, RefZBase(QWORD)',
+    processor = 'R(0), R3(10), R(5), Uscaled(16, 5, 1)',
+)
+
+tlentry(['LD1SB'],
+    '{<Zt>.D},<Pg>/Z,[<Zn>.D{,#<imm>}]', (('imm5', 5, 16), ('Pg', 3, 10), ('Zn', 5, 5), ('Zt', 5, 0)),
+    matcher   = 'ZRegList(1, QWORD), PZero, RefZBase(QWORD)',
+    processor = 'R(0), R3(10), R(5), Ubits(16, 5)',
+)
+
+tlentry(['LD1SH'],
+    '{<Zt>.D},<Pg>/Z,[<Zn>.D{,#<imm>}]', (('imm5', 5, 16), ('Pg', 3, 10), ('Zn', 5, 5), ('Zt', 5, 0)),
+    matcher   = 'ZRegList(1, QWORD), PZero, RefZBase(QWORD)',
+    processor = 'R(0), R3(10), R(5), Uscaled(16, 5, 1)',
+)
+
+tlentry(['LD1SW'],
+    '{<Zt>.D},<Pg>/Z,[<Zn>.D{,#<imm>}]', (('imm5', 5, 16), ('Pg', 3, 10), ('Zn', 5, 5), ('Zt', 5, 0)),
+    matcher   = 'ZRegList(1, QWORD), PZero, RefZBase(QWORD)',
+    processor = 'R(0), R3(10), R(5), Uscaled(16, 5, 2)',
+)
+
+tlentry(['LD1W'],
+    '{<Zt>.D},<Pg>/Z,[<Zn>.D{,#<imm>}]', (('imm5', 5, 16), ('Pg', 3, 10), ('Zn', 5, 5), ('Zt', 5, 0)),
+    matcher   = 'ZRegList(1, QWORD), PZero, RefZBase(QWORD)',
+    processor = 'R(0), R3(10), R(5), Uscaled(16, 5, 2)',
+)
+
+tlentry(['LDFF1B'],
+    '{<Zt>.D},<Pg>/Z,[<Zn>.D{,#<imm>}]', (('imm5', 5, 16), ('Pg', 3, 10), ('Zn', 5, 5), ('Zt', 5, 0)),
+    matcher   = 'ZRegList(1, QWORD), PZero, RefZBase(QWORD)',
+    processor = 'R(0), R3(10), R(5), Ubits(16, 5)',
+)
+
+tlentry(['LDFF1D'],
+    '{<Zt>.D},<Pg>/Z,[<Zn>.D{,#<imm>}]', (('imm5', 5, 16), ('Pg', 3, 10), ('Zn', 5, 5), ('Zt', 5, 0)),
+    matcher   = 'ZRegList(1, QWORD), PZero, RefZBase(QWORD)',
+    processor = 'R(0), R3(10), R(5), Uscaled(16, 5, 3)',
+)
+
+tlentry(['LDFF1H'],
+    '{<Zt>.D},<Pg>/Z,[<Zn>.D{,#<imm>}]', (('imm5', 5, 16), ('Pg', 3, 10), ('Zn', 5, 5), ('Zt', 5, 0)),
+    matcher   = 'ZRegList(1, QWORD), PZero, RefZBase(QWORD)',
+    processor = 'R(0), R3(10), R(5), Uscaled(16, 5, 1)',
+)
+
+tlentry(['LDFF1SB'],
+    '{<Zt>.D},<Pg>/Z,[<Zn>.D{,#<imm>}]', (('imm5', 5, 16), ('Pg', 3, 10), ('Zn', 5, 5), ('Zt', 5, 0)),
+    matcher   = 'ZRegList(1, QWORD), PZero, RefZBase(QWORD)',
+    processor = 'R(0), R3(10), R(5), Ubits(16, 5)',
+)
+
+tlentry(['LDFF1SH'],
+    '{<Zt>.D},<Pg>/Z,[<Zn>.D{,#<imm>}]', (('imm5', 5, 16), ('Pg', 3, 10), ('Zn', 5, 5), ('Zt', 5, 0)),
+    matcher   = 'ZRegList(1, QWORD), PZero, RefZBase(QWORD)',
+    processor = 'R(0), R3(10), R(5), Uscaled(16, 5, 1)',
+)
+
+tlentry(['LDFF1SW'],
+    '{<Zt>.D},<Pg>/Z,[<Zn>.D{,#<imm>}]', (('imm5', 5, 16), ('Pg', 3, 10), ('Zn', 5, 5), ('Zt', 5, 0)),
+    matcher   = 'ZRegList(1, QWORD), PZero, RefZBase(QWORD)',
+    processor = 'R(0), R3(10), R(5), Uscaled(16, 5, 2)',
+)
+
+tlentry(['LDFF1W'],
     '{<Zt>.D},<Pg>/Z,[<Zn>.D{,#<imm>}]', (('imm5', 5, 16), ('Pg', 3, 10), ('Zn', 5, 5), ('Zt', 5, 0)),
-    matcher   = 'Unimp',
-    processor = 'Unimp',
+    matcher   = 'ZRegList(1, QWORD), PZero, RefZBase(QWORD)',
+    processor = 'R(0), R3(10), R(5), Uscaled(16, 5, 2)',
 )
 
 tlentry(['STNT1H'],
     '{<Zt>.H},<Pg>,[<Xn|SP>,<Xm>,LSL#1]', (('Rm', 5, 16), ('Pg', 3, 10), ('Rn', 5, 5), ('Zt', 5, 0)),
-    matcher   = 'Unimp',
-    processor = 'Unimp',
+    matcher   = 'ZRegList(1, WORD), PBare, RefIndexLsl(1)',
+    processor = 'R(0), R3(10), R(5), RNoZr(16)',
 )
 
 tlentry(['STNT1H'],
     '{<Zt>.H},<Pg>,[<Xn|SP>{,#<imm>,MULVL}]', (('imm4', 4, 16), ('Pg', 3, 10), ('Rn', 5, 5), ('Zt', 5, 0)),
-    matcher   = 'Unimp',
-    processor = 'Unimp',
+    matcher   = 'ZRegList(1, WORD), PBare, RefMulVl',
+    processor = 'R(0), R3(10), R(5), Sbits(16, 4)',
 )
 
 tlentry(['LD1H', 'LD1RQH', 'LDNT1H'],
     '{<Zt>.H},<Pg>/Z,[<Xn|SP>,<Xm>,LSL#1]', (('Rm', 5, 16), ('Pg', 3, 10), ('Rn', 5, 5), ('Zt', 5, 0)),
-    matcher   = 'Unimp',
-    processor = 'Unimp',
+    matcher   = 'ZRegList(1, WORD), PZero, RefIndexLsl(1)',
+    processor = 'R(0), R3(10), R(5), RNoZr(16)',
 )
 
 tlentry(['LD1B', 'LD1SB'],
     '{<Zt>.H},<Pg>/Z,[<Xn|SP>,<Xm>]', (('Rm', 5, 16), ('Pg', 3, 10), ('Rn', 5, 5), ('Zt', 5, 0)),
-    matcher   = 'Unimp',
-    processor = 'Unimp',
+    matcher   = 'ZRegList(1, WORD), PZero, RefIndexLsl(0)',
+    processor = 'R(0), R3(10), R(5), RNoZr(16)',
 )
 
 tlentry(['LD1B', 'LD1H', 'LD1SB', 'LDNF1B', 'LDNF1H', 'LDNF1SB', 'LDNT1H'],
     '{<Zt>.H},<Pg>/Z,[<Xn|SP>{,#<imm>,MULVL}]', (('imm4', 4, 16), ('Pg', 3, 10), ('Rn', 5, 5), ('Zt', 5, 0)),
-    matcher   = 'Unimp',
-    processor = 'Unimp',
+    matcher   = 'ZRegList(1, WORD), PZero, RefMulVl',
+    processor = 'R(0), R3(10), R(5), Sbits(16, 4)',
 )
 
 tlentry(['LD1RQH'],
     '{<Zt>.H},<Pg>/Z,[<Xn|SP>{,#<imm>}]', (('imm4', 4, 16), ('Pg', 3, 10), ('Rn', 5, 5), ('Zt', 5, 0)),
-    matcher   = 'Unimp',
-    processor = 'Unimp',
+    matcher   = 'ZRegList(1, WORD), PZero, RefOffset',
+    processor = 'R(0), R3(10), R(5), Sscaled(16, 4, 4)',
+)
+
+tlentry(['LD1RB'],
+    '{<Zt>.H},<Pg>/Z,[<Xn|SP>{,#<imm>}]', (('imm6', 6, 16), ('Pg', 3, 10), ('Rn', 5, 5), ('Zt', 5, 0)),
+    matcher   = 'ZRegList(1, WORD), PZero, RefOffset',
+    processor = 'R(0), R3(10), R(5), Ubits(16, 6)',
+)
+
+tlentry(['LD1RH'],
+    '{<Zt>.H},<Pg>/Z,[<Xn|SP>{,#<imm>}]', (('imm6', 6, 16), ('Pg', 3, 10), ('Rn', 5, 5), ('Zt', 5, 0)),
+    matcher   = 'ZRegList(1, WORD), PZero, RefOffset',
+    processor = 'R(0), R3(10), R(5), Uscaled(16, 6, 1)',
 )
 
-tlentry(['LD1RB', 'LD1RH', 'LD1RSB'],
+tlentry(['LD1RSB'],
     '{<Zt>.H},<Pg>/Z,[<Xn|SP>{,#<imm>}]', (('imm6', 6, 16), ('Pg', 3, 10), ('Rn', 5, 5), ('Zt', 5, 0)),
-    matcher   = 'Unimp',
-    processor = 'Unimp',
+    matcher   = 'ZRegList(1, WORD), PZero, RefOffset',
+    processor = 'R(0), R3(10), R(5), Ubits(16, 6)',
 )
 
 tlentry(['LDFF1H'],
     '{<Zt>.H},<Pg>/Z,[<Xn|SP>{,<Xm>,LSL#1}]', (('Rm', 5, 16), ('Pg', 3, 10), ('Rn', 5, 5), ('Zt', 5, 0)),
-    matcher   = 'Unimp',
-    processor = 'Unimp',
+    matchers = [
+        'ZRegList(1, WORD), PZero, RefIndexLsl(1)',
+        'ZRegList(1, WORD), PZero, RefBase',
+    ],
+    processors = [
+        'R(0), R3(10), R(5), R(16)',
+        'R(0), R3(10), R(5), Static(16, 0b11111)',
+    ],
 )
 
 tlentry(['LDFF1B', 'LDFF1SB'],
     '{<Zt>.H},<Pg>/Z,[<Xn|SP>{,<Xm>}]', (('Rm', 5, 16), ('Pg', 3, 10), ('Rn', 5, 5), ('Zt', 5, 0)),
-    matcher   = 'Unimp',
-    processor = 'Unimp',
+    matchers = [
+        'ZRegList(1, WORD), PZero, RefIndexLsl(0)',
+        'ZRegList(1, WORD), PZero, RefBase',
+    ],
+    processors = [
+        'R(0), R3(10), R(5), R(16)',
+        'R(0), R3(10), R(5), Static(16, 0b11111)',
+    ],
 )
 
 tlentry(['STNT1W'],
     '{<Zt>.S},<Pg>,[<Xn|SP>,<Xm>,LSL#2]', (('Rm', 5, 16), ('Pg', 3, 10), ('Rn', 5, 5), ('Zt', 5, 0)),
-    matcher   = 'Unimp',
-    processor = 'Unimp',
+    matcher   = 'ZRegList(1, DWORD), PBare, RefIndexLsl(2)',
+    processor = 'R(0), R3(10), R(5), RNoZr(16)',
 )
 
 tlentry(['ST1H'],
     '{<Zt>.S},<Pg>,[<Xn|SP>,<Zm>.S,<mod>#1]', (('Zm', 5, 16), ('xs', 1, 14), ('Pg', 3, 10), ('Rn', 5, 5), ('Zt', 5, 0)),
-    matcher   = 'Unimp',
-    processor = 'Unimp',
+    matcher   = 'ZRegList(1, DWORD), PBare, RefZIndexExtend(DWORD, 1)',
+    processor = 'R(0), R3(10), R(5), R(16), ExtendsZ(14)',
 )
 
 tlentry(['ST1W'],
     '{<Zt>.S},<Pg>,[<Xn|SP>,<Zm>.S,<mod>#2]', (('Zm', 5, 16), ('xs', 1, 14), ('Pg', 3, 10), ('Rn', 5, 5), ('Zt', 5, 0)),
-    matcher   = 'Unimp',
-    processor = 'Unimp',
+    matcher   = 'ZRegList(1, DWORD), PBare, RefZIndexExtend(DWORD, 2)',
+    processor = 'R(0), R3(10), R(5), R(16), ExtendsZ(14)',
 )
 
 tlentry(['ST1B', 'ST1H', 'ST1W'],
     '{<Zt>.S},<Pg>,[<Xn|SP>,<Zm>.S,<mod>]', (('Zm', 5, 16), ('xs', 1, 14), ('Pg', 3, 10), ('Rn', 5, 5), ('Zt', 5, 0)),
-    matcher   = 'Unimp',
-    processor = 'Unimp',
+    matcher   = 'ZRegList(1, DWORD), PBare, RefZIndexExtend(DWORD, 0)',
+    processor = 'R(0), R3(10), R(5), R(16), ExtendsZ(14)',
 )
 
 tlentry(['STNT1W'],
     '{<Zt>.S},<Pg>,[<Xn|SP>{,#<imm>,MULVL}]', (('imm4', 4, 16), ('Pg', 3, 10), ('Rn', 5, 5), ('Zt', 5, 0)),
-    matcher   = 'Unimp',
-    processor = 'Unimp',
+    matcher   = 'ZRegList(1, DWORD), PBare, RefMulVl',
+    processor = 'R(0), R3(10), R(5), Sbits(16, 4)',
 )
 
-tlentry(['ST1B', 'ST1H', 'ST1W'],
+tlentry(['ST1B'],
+    '{<Zt>.S},<Pg>,[<Zn>.S{,#<imm>}]', (('imm5', 5, 16), ('Pg', 3, 10), ('Zn', 5, 5), ('Zt', 5, 0)),
+    matcher   = 'ZRegList(1, DWORD), PBare, RefZBase(DWORD)',
+    processor = 'R(0), R3(10), R(5), Ubits(16, 5)',
+)
+
+tlentry(['ST1H'],
     '{<Zt>.S},<Pg>,[<Zn>.S{,#<imm>}]', (('imm5', 5, 16), ('Pg', 3, 10), ('Zn', 5, 5), ('Zt', 5, 0)),
-    matcher   = 'Unimp',
-    processor = 'Unimp',
+    matcher   = 'ZRegList(1, DWORD), PBare, RefZBase(DWORD)',
+    processor = 'R(0), R3(10), R(5), Uscaled(16, 5, 1)',
+)
+
+tlentry(['ST1W'],
+    '{<Zt>.S},<Pg>,[<Zn>.S{,#<imm>}]', (('imm5', 5, 16), ('Pg', 3, 10), ('Zn', 5, 5), ('Zt', 5, 0)),
+    matcher   = 'ZRegList(1, DWORD), PBare, RefZBase(DWORD)',
+    processor = 'R(0), R3(10), R(5), Uscaled(16, 5, 2)',
 )
 
 tlentry(['LD1H', 'LD1SH'],
     '{<Zt>.S},<Pg>/Z,[<Xn|SP>,<Xm>,LSL#1]', (('Rm', 5, 16), ('Pg', 3, 10), ('Rn', 5, 5), ('Zt', 5, 0)),
-    matcher   = 'Unimp',
-    processor = 'Unimp',
+    matcher   = 'ZRegList(1, DWORD), PZero, RefIndexLsl(1)',
+    processor = 'R(0), R3(10), R(5), RNoZr(16)',
 )
 
 tlentry(['LD1RQW', 'LD1W', 'LDNT1W'],
     '{<Zt>.S},<Pg>/Z,[<Xn|SP>,<Xm>,LSL#2]', (('Rm', 5, 16), ('Pg', 3, 10), ('Rn', 5, 5), ('Zt', 5, 0)),
-    matcher   = 'Unimp',
-    processor = 'Unimp',
+    matcher   = 'ZRegList(1, DWORD), PZero, RefIndexLsl(2)',
+    processor = 'R(0), R3(10), R(5), RNoZr(16)',
 )
 
 tlentry(['LD1B', 'LD1SB'],
     '{<Zt>.S},<Pg>/Z,[<Xn|SP>,<Xm>]', (('Rm', 5, 16), ('Pg', 3, 10), ('Rn', 5, 5), ('Zt', 5, 0)),
-    matcher   = 'Unimp',
-    processor = 'Unimp',
+    matcher   = 'ZRegList(1, DWORD), PZero, RefIndexLsl(0)',
+    processor = 'R(0), R3(10), R(5), RNoZr(16)',
 )
 
 tlentry(['LD1H', 'LD1SH', 'LDFF1H', 'LDFF1SH'],
     '{<Zt>.S},<Pg>/Z,[<Xn|SP>,<Zm>.S,<mod>#1]', (('xs', 1, 22), ('Zm', 5, 16), ('Pg', 3, 10), ('Rn', 5, 5), ('Zt', 5, 0)),
-    matcher   = 'Unimp',
-    processor = 'Unimp',
+    matcher   = 'ZRegList(1, DWORD), PZero, RefZIndexExtend(DWORD, 1)',
+    processor = 'R(0), R3(10), R(5), R(16), ExtendsZ(22)',
 )
 
 tlentry(['LD1W', 'LDFF1W'],
     '{<Zt>.S},<Pg>/Z,[<Xn|SP>,<Zm>.S,<mod>#2]', (('xs', 1, 22), ('Zm', 5, 16), ('Pg', 3, 10), ('Rn', 5, 5), ('Zt', 5, 0)),
-    matcher   = 'Unimp',
-    processor = 'Unimp',
+    matcher   = 'ZRegList(1, DWORD), PZero, RefZIndexExtend(DWORD, 2)',
+    processor = 'R(0), R3(10), R(5), R(16), ExtendsZ(22)',
 )
 
 tlentry(['LD1B', 'LD1H', 'LD1SB', 'LD1SH', 'LD1W', 'LDFF1B', 'LDFF1H', 'LDFF1SB', 'LDFF1SH', 'LDFF1W'],
     '{<Zt>.S},<Pg>/Z,[<Xn|SP>,<Zm>.S,<mod>]', (('xs', 1, 22), ('Zm', 5, 16), ('Pg', 3, 10), ('Rn', 5, 5), ('Zt', 5, 0)),
-    matcher   = 'Unimp',
-    processor = 'Unimp',
+    matcher   = 'ZRegList(1, DWORD), PZero, RefZIndexExtend(DWORD, 0)',
+    processor = 'R(0), R3(10), R(5), R(16), ExtendsZ(22)',
 )
 
 tlentry(['LD1B', 'LD1H', 'LD1SB', 'LD1SH', 'LD1W', 'LDNF1B', 'LDNF1H', 'LDNF1SB', 'LDNF1SH', 'LDNF1W', 'LDNT1W'],
     '{<Zt>.S},<Pg>/Z,[<Xn|SP>{,#<imm>,MULVL}]', (('imm4', 4, 16), ('Pg', 3, 10), ('Rn', 5, 5), ('Zt', 5, 0)),
-    matcher   = 'Unimp',
-    processor = 'Unimp',
+    matcher   = 'ZRegList(1, DWORD), PZero, RefMulVl',
+    processor = 'R(0), R3(10), R(5), Sbits(16, 4)',
 )
 
 tlentry(['LD1RQW'],
     '{<Zt>.S},<Pg>/Z,[<Xn|SP>{,#<imm>}]', (('imm4', 4, 16), ('Pg', 3, 10), ('Rn', 5, 5), ('Zt', 5, 0)),
-    matcher   = 'Unimp',
-    processor = 'Unimp',
+    matcher   = 'ZRegList(1, DWORD), PZero, RefOffset',
+    processor = 'R(0), R3(10), R(5), Sscaled(16, 4, 4)',
+)
+
+tlentry(['LD1RB'],
+    '{<Zt>.S},<Pg>/Z,[<Xn|SP>{,#<imm>}]', (('imm6', 6, 16), ('Pg', 3, 10), ('Rn', 5, 5), ('Zt', 5, 0)),
+    matcher   = 'ZRegList(1, DWORD), PZero, RefOffset',
+    processor = 'R(0), R3(10), R(5), Ubits(16, 6)',
+)
+
+tlentry(['LD1RH'],
+    '{<Zt>.S},<Pg>/Z,[<Xn|SP>{,#<imm>}]', (('imm6', 6, 16), ('Pg', 3, 10), ('Rn', 5, 5), ('Zt', 5, 0)),
+    matcher   = 'ZRegList(1, DWORD), PZero, RefOffset',
+    processor = 'R(0), R3(10), R(5), Uscaled(16, 6, 1)',
+)
+
+tlentry(['LD1RSB'],
+    '{<Zt>.S},<Pg>/Z,[<Xn|SP>{,#<imm>}]', (('imm6', 6, 16), ('Pg', 3, 10), ('Rn', 5, 5), ('Zt', 5, 0)),
+    matcher   = 'ZRegList(1, DWORD), PZero, RefOffset',
+    processor = 'R(0), R3(10), R(5), Ubits(16, 6)',
+)
+
+tlentry(['LD1RSH'],
+    '{<Zt>.S},<Pg>/Z,[<Xn|SP>{,#<imm>}]', (('imm6', 6, 16), ('Pg', 3, 10), ('Rn', 5, 5), ('Zt', 5, 0)),
+    matcher   = 'ZRegList(1, DWORD), PZero, RefOffset',
+    processor = 'R(0), R3(10), R(5), Uscaled(16, 6, 1)',
 )
 
-tlentry(['LD1RB', 'LD1RH', 'LD1RSB', 'LD1RSH', 'LD1RW'],
+tlentry(['LD1RW'],
     '{<Zt>.S},<Pg>/Z,[<Xn|SP>{,#<imm>}]', (('imm6', 6, 16), ('Pg', 3, 10), ('Rn', 5, 5), ('Zt', 5, 0)),
-    matcher   = 'Unimp',
-    processor = 'Unimp',
+    matcher   = 'ZRegList(1, DWORD), PZero, RefOffset',
+    processor = 'R(0), R3(10), R(5), Uscaled(16, 6, 2)',
 )
 
 tlentry(['LDFF1H', 'LDFF1SH'],
     '{<Zt>.S},<Pg>/Z,[<Xn|SP>{,<Xm>,LSL#1}]', (('Rm', 5, 16), ('Pg', 3, 10), ('Rn', 5, 5), ('Zt', 5, 0)),
-    matcher   = 'Unimp',
-    processor = 'Unimp',
+    matchers = [
+        'ZRegList(1, DWORD), PZero, RefIndexLsl(1)',
+        'ZRegList(1, DWORD), PZero, RefBase',
+    ],
+    processors = [
+        'R(0), R3(10), R(5), R(16)',
+        'R(0), R3(10), R(5), Static(16, 0b11111)',
+    ],
 )
 
 tlentry(['LDFF1W'],
     '{<Zt>.S},<Pg>/Z,[<Xn|SP>{,<Xm>,LSL#2}]', (('Rm', 5, 16), ('Pg', 3, 10), ('Rn', 5, 5), ('Zt', 5, 0)),
-    matcher   = 'Unimp',
-    processor = 'Unimp',
+    matchers = [
+        'ZRegList(1, DWORD), PZero, RefIndexLsl(2)',
+        'ZRegList(1, DWORD), PZero, RefBase',
+    ],
+    processors = [
+        'R(0), R3(10), R(5), R(16)',
+        'R(0), R3(10), R(5), Static(16, 0b11111)',
+    ],
 )
 
 tlentry(['LDFF1B', 'LDFF1SB'],
     '{<Zt>.S},<Pg>/Z,[<Xn|SP>{,<Xm>}]', (('Rm', 5, 16), ('Pg', 3, 10), ('Rn', 5, 5), ('Zt', 5, 0)),
-    matcher   = 'Unimp',
-    processor = 'Unimp',
+    matchers = [
+        'ZRegList(1, DWORD), PZero, RefIndexLsl(0)',
+        'ZRegList(1, DWORD), PZero, RefBase',
+    ],
+    processors = [
+        'R(0), R3(10), R(5), R(16)',
+        'R(0), R3(10), R(5), Static(16, 0b11111)',
+    ],
 )
 
-tlentry(['LD1B', 'LD1H', 'LD1SB', 'LD1SH', 'LD1W', 'LDFF1B', 'LDFF1H', 'LDFF1SB', 'LDFF1SH', 'LDFF1W'],
+tlentry(['LD1B'],
+    '{<Zt>.S},<Pg>/Z,[<Zn>.S{,#<imm>}]', (('imm5', 5, 16), ('Pg', 3, 10), ('Zn', 5, 5), ('Zt', 5, 0)),
+    matcher   = 'ZRegList(1, DWORD), PZero, RefZBase(DWORD)',
+    processor = 'R(0), R3(10), R(5), Ubits(16, 5)',
+)
+
+tlentry(['LD1H'],
+    '{<Zt>.S},<Pg>/Z,[<Zn>.S{,#<imm>}]', (('imm5', 5, 16), ('Pg', 3, 10), ('Zn', 5, 5), ('Zt', 5, 0)),
+    matcher   = 'ZRegList(1, DWORD), PZero, RefZBase(DWORD)',
+    processor = 'R(0), R3(10), R(5), Uscaled(16, 5, 1)',
+)
+
+tlentry(['LD1SB'],
+    '{<Zt>.S},<Pg>/Z,[<Zn>.S{,#<imm>}]', (('imm5', 5, 16), ('Pg', 3, 10), ('Zn', 5, 5), ('Zt', 5, 0)),
+    matcher   = 'ZRegList(1, DWORD), PZero, RefZBase(DWORD)',
+    processor = 'R(0), R3(10), R(5), Ubits(16, 5)',
+)
+
+tlentry(['LD1SH'],
+    '{<Zt>.S},<Pg>/Z,[<Zn>.S{,#<imm>}]', (('imm5', 5, 16), ('Pg', 3, 10), ('Zn', 5, 5), ('Zt', 5, 0)),
+    matcher   = 'ZRegList(1, DWORD), PZero, RefZBase(DWORD)',
+    processor = 'R(0), R3(10), R(5), Uscaled(16, 5, 1)',
+)
+
+tlentry(['LD1W'],
+    '{<Zt>.S},<Pg>/Z,[<Zn>.S{,#<imm>}]', (('imm5', 5, 16), ('Pg', 3, 10), ('Zn', 5, 5), ('Zt', 5, 0)),
+    matcher   = 'ZRegList(1, DWORD), PZero, RefZBase(DWORD)',
+    processor = 'R(0), R3(10), R(5), Uscaled(16, 5, 2)',
+)
+
+tlentry(['LDFF1B'],
+    '{<Zt>.S},<Pg>/Z,[<Zn>.S{,#<imm>}]', (('imm5', 5, 16), ('Pg', 3, 10), ('Zn', 5, 5), ('Zt', 5, 0)),
+    matcher   = 'ZRegList(1, DWORD), PZero, RefZBase(DWORD)',
+    processor = 'R(0), R3(10), R(5), Ubits(16, 5)',
+)
+
+tlentry(['LDFF1H'],
+    '{<Zt>.S},<Pg>/Z,[<Zn>.S{,#<imm>}]', (('imm5', 5, 16), ('Pg', 3, 10), ('Zn', 5, 5), ('Zt', 5, 0)),
+    matcher   = 'ZRegList(1, DWORD), PZero, RefZBase(DWORD)',
+    processor = 'R(0), R3(10), R(5), Uscaled(16, 5, 1)',
+)
+
+tlentry(['LDFF1SB'],
+    '{<Zt>.S},<Pg>/Z,[<Zn>.S{,#<imm>}]', (('imm5', 5, 16), ('Pg', 3, 10), ('Zn', 5, 5), ('Zt', 5, 0)),
+    matcher   = 'ZRegList(1, DWORD), PZero, RefZBase(DWORD)',
+    processor = 'R(0), R3(10), R(5), Ubits(16, 5)',
+)
+
+tlentry(['LDFF1SH'],
+    '{<Zt>.S},<Pg>/Z,[<Zn>.S{,#<imm>}]', (('imm5', 5, 16), ('Pg', 3, 10), ('Zn', 5, 5), ('Zt', 5, 0)),
+    matcher   = 'ZRegList(1, DWORD), PZero, RefZBase(DWORD)',
+    processor = 'R(0), R3(10), R(5), Uscaled(16, 5, 1)',
+)
+
+tlentry(['LDFF1W'],
     '{<Zt>.S},<Pg>/Z,[<Zn>.S{,#<imm>}]', (('imm5', 5, 16), ('Pg', 3, 10), ('Zn', 5, 5), ('Zt', 5, 0)),
-    matcher   = 'Unimp',
-    processor = 'Unimp',
+    matcher   = 'ZRegList(1, DWORD), PZero, RefZBase(DWORD)',
+    processor = 'R(0), R3(10), R(5), Uscaled(16, 5, 2)',
 )
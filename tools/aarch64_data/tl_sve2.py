@@ -0,0 +1,704 @@
+# The v8.5 machine readable specification read by aarch64_gen_opmap.py predates SVE2, so the
+# entries in this file carry the base encoding of each of their mnemonics (and optionally the
+# heading they are listed under) themselves.
+
+tlentry(['WHILEGE', 'WHILEGT', 'WHILEHI', 'WHILEHS'],
+    '<Pd>.<T>,<R><n>,<R><m>', (),
+    encodings = {
+        'WHILEGE': '00100101001000000000000000000000',
+        'WHILEGT': '00100101001000000000000000010000',
+        'WHILEHI': '00100101001000000000100000010000',
+        'WHILEHS': '00100101001000000000100000000000',
+    },
+    matchers = [
+        'P(BYTE), W, W',
+        'P(WORD), W, W',
+        'P(DWORD), W, W',
+        'P(QWORD), W, W',
+        'P(BYTE), X, X',
+        'P(WORD), X, X',
+        'P(DWORD), X, X',
+        'P(QWORD), X, X',
+    ],
+    processors = [
+        'R4(0), R(5), R(16), Static(22, 0b00)',
+        'R4(0), R(5), R(16), Static(22, 0b01)',
+        'R4(0), R(5), R(16), Static(22, 0b10)',
+        'R4(0), R(5), R(16), Static(22, 0b11)',
+        'R4(0), R(5), R(16), Static(22, 0b00), Static(12, 0b1)',
+        'R4(0), R(5), R(16), Static(22, 0b01), Static(12, 0b1)',
+        'R4(0), R(5), R(16), Static(22, 0b10), Static(12, 0b1)',
+        'R4(0), R(5), R(16), Static(22, 0b11), Static(12, 0b1)',
+    ],
+)
+
+tlentry(['WHILERW', 'WHILEWR'],
+    '<Pd>.<T>,<Xn>,<Xm>', (),
+    encodings = {
+        'WHILERW': '00100101001000000011000000010000',
+        'WHILEWR': '00100101001000000011000000000000',
+    },
+    matchers = [
+        'P(BYTE), X, X',
+        'P(WORD), X, X',
+        'P(DWORD), X, X',
+        'P(QWORD), X, X',
+    ],
+    processors = [
+        'R4(0), R(5), R(16), Static(22, 0b00)',
+        'R4(0), R(5), R(16), Static(22, 0b01)',
+        'R4(0), R(5), R(16), Static(22, 0b10)',
+        'R4(0), R(5), R(16), Static(22, 0b11)',
+    ],
+)
+
+tlentry(['MUL', 'SMULH', 'SQDMULH', 'SQRDMULH', 'UMULH'],
+    '<Zd>.<T>,<Zn>.<T>,<Zm>.<T>', (),
+    heading = 'vectors, unpredicated',
+    encodings = {
+        'MUL': '00000100001000000110000000000000',
+        'SMULH': '00000100001000000110100000000000',
+        'SQDMULH': '00000100001000000111000000000000',
+        'SQRDMULH': '00000100001000000111010000000000',
+        'UMULH': '00000100001000000110110000000000',
+    },
+    matchers = [
+        'Z(BYTE), Z(BYTE), Z(BYTE)',
+        'Z(WORD), Z(WORD), Z(WORD)',
+        'Z(DWORD), Z(DWORD), Z(DWORD)',
+        'Z(QWORD), Z(QWORD), Z(QWORD)',
+    ],
+    processors = [
+        'R(0), R(5), R(16), Static(22, 0b00)',
+        'R(0), R(5), R(16), Static(22, 0b01)',
+        'R(0), R(5), R(16), Static(22, 0b10)',
+        'R(0), R(5), R(16), Static(22, 0b11)',
+    ],
+)
+
+tlentry(['PMUL'],
+    '<Zd>.B,<Zn>.B,<Zm>.B', (),
+    encodings = {
+        'PMUL': '00000100001000000110010000000000',
+    },
+    matcher   = 'Z(BYTE), Z(BYTE), Z(BYTE)',
+    processor = 'R(0), R(5), R(16)',
+)
+
+tlentry(['BCAX', 'BSL', 'BSL1N', 'BSL2N', 'EOR3', 'NBSL'],
+    '<Zdn>.D,<Zdn>.D,<Zm>.D,<Zk>.D', (),
+    encodings = {
+        'BCAX': '00000100011000000011100000000000',
+        'BSL': '00000100001000000011110000000000',
+        'BSL1N': '00000100011000000011110000000000',
+        'BSL2N': '00000100101000000011110000000000',
+        'EOR3': '00000100001000000011100000000000',
+        'NBSL': '00000100111000000011110000000000',
+    },
+    matcher   = 'Z(QWORD), Z(QWORD), Z(QWORD), Z(QWORD)',
+    processor = 'R(0), RTied, R(16), R(5)',
+)
+
+tlentry(['XAR'],
+    '<Zdn>.<T>,<Zdn>.<T>,<Zm>.<T>,#<const>', (),
+    encodings = {
+        'XAR': '00000100001000000011010000000000',
+    },
+    matchers = [
+        'Z(BYTE), Z(BYTE), Z(BYTE), Imm',
+        'Z(WORD), Z(WORD), Z(WORD), Imm',
+        'Z(DWORD), Z(DWORD), Z(DWORD), Imm',
+        'Z(QWORD), Z(QWORD), Z(QWORD), Imm',
+    ],
+    processors = [
+        'R(0), RTied, R(5), Usub(16, 3, 8), Static(19, 0b1)',
+        'R(0), RTied, R(5), Usub(16, 4, 16), Static(20, 0b1)',
+        'R(0), RTied, R(5), Usub(16, 5, 32), Static(22, 0b1)',
+        'R(0), RTied, R(5), BUrange(1, 64), Unegslice(16, 5, 0), Unegslice(22, 1, 5), A, Static(23, 0b1)',
+    ],
+)
+
+tlentry(['CADD', 'SQCADD'],
+    '<Zdn>.<T>,<Zdn>.<T>,<Zm>.<T>,<const>', (),
+    encodings = {
+        'CADD': '01000101000000001101100000000000',
+        'SQCADD': '01000101000000011101100000000000',
+    },
+    matchers = [
+        'Z(BYTE), Z(BYTE), Z(BYTE), Imm',
+        'Z(WORD), Z(WORD), Z(WORD), Imm',
+        'Z(DWORD), Z(DWORD), Z(DWORD), Imm',
+        'Z(QWORD), Z(QWORD), Z(QWORD), Imm',
+    ],
+    processors = [
+        'R(0), RTied, R(5), Ulist(10, &[90, 270]), Static(22, 0b00)',
+        'R(0), RTied, R(5), Ulist(10, &[90, 270]), Static(22, 0b01)',
+        'R(0), RTied, R(5), Ulist(10, &[90, 270]), Static(22, 0b10)',
+        'R(0), RTied, R(5), Ulist(10, &[90, 270]), Static(22, 0b11)',
+    ],
+)
+
+tlentry(['ADDP', 'SHADD', 'SHSUB', 'SHSUBR', 'SMAXP', 'SMINP', 'SQADD', 'SQRSHL', 'SQRSHLR', 'SQSHL', 'SQSHLR', 'SQSUB', 'SQSUBR', 'SRHADD', 'SRSHL', 'SRSHLR', 'SUQADD', 'UHADD', 'UHSUB', 'UHSUBR', 'UMAXP', 'UMINP', 'UQADD', 'UQRSHL', 'UQRSHLR', 'UQSHL', 'UQSHLR', 'UQSUB', 'UQSUBR', 'URHADD', 'URSHL', 'URSHLR', 'USQADD'],
+    '<Zdn>.<T>,<Pg>/M,<Zdn>.<T>,<Zm>.<T>', (),
+    heading = 'vectors, predicated',
+    encodings = {
+        'ADDP': '01000100000100011010000000000000',
+        'SHADD': '01000100000100001000000000000000',
+        'SHSUB': '01000100000100101000000000000000',
+        'SHSUBR': '01000100000101101000000000000000',
+        'SMAXP': '01000100000101001010000000000000',
+        'SMINP': '01000100000101101010000000000000',
+        'SQADD': '01000100000110001000000000000000',
+        'SQRSHL': '01000100000010101000000000000000',
+        'SQRSHLR': '01000100000011101000000000000000',
+        'SQSHL': '01000100000010001000000000000000',
+        'SQSHLR': '01000100000011001000000000000000',
+        'SQSUB': '01000100000110101000000000000000',
+        'SQSUBR': '01000100000111101000000000000000',
+        'SRHADD': '01000100000101001000000000000000',
+        'SRSHL': '01000100000000101000000000000000',
+        'SRSHLR': '01000100000001101000000000000000',
+        'SUQADD': '01000100000111001000000000000000',
+        'UHADD': '01000100000100011000000000000000',
+        'UHSUB': '01000100000100111000000000000000',
+        'UHSUBR': '01000100000101111000000000000000',
+        'UMAXP': '01000100000101011010000000000000',
+        'UMINP': '01000100000101111010000000000000',
+        'UQADD': '01000100000110011000000000000000',
+        'UQRSHL': '01000100000010111000000000000000',
+        'UQRSHLR': '01000100000011111000000000000000',
+        'UQSHL': '01000100000010011000000000000000',
+        'UQSHLR': '01000100000011011000000000000000',
+        'UQSUB': '01000100000110111000000000000000',
+        'UQSUBR': '01000100000111111000000000000000',
+        'URHADD': '01000100000101011000000000000000',
+        'URSHL': '01000100000000111000000000000000',
+        'URSHLR': '01000100000001111000000000000000',
+        'USQADD': '01000100000111011000000000000000',
+    },
+    matchers = [
+        'Z(BYTE), PMerge, Z(BYTE), Z(BYTE)',
+        'Z(WORD), PMerge, Z(WORD), Z(WORD)',
+        'Z(DWORD), PMerge, Z(DWORD), Z(DWORD)',
+        'Z(QWORD), PMerge, Z(QWORD), Z(QWORD)',
+    ],
+    processors = [
+        'R(0), R3(10), RTied, R(5), Static(22, 0b00)',
+        'R(0), R3(10), RTied, R(5), Static(22, 0b01)',
+        'R(0), R3(10), RTied, R(5), Static(22, 0b10)',
+        'R(0), R3(10), RTied, R(5), Static(22, 0b11)',
+    ],
+)
+
+tlentry(['FADDP', 'FMAXNMP', 'FMAXP', 'FMINNMP', 'FMINP'],
+    '<Zdn>.<T>,<Pg>/M,<Zdn>.<T>,<Zm>.<T>', (),
+    heading = 'vectors, predicated',
+    encodings = {
+        'FADDP': '01100100000100001000000000000000',
+        'FMAXNMP': '01100100000101001000000000000000',
+        'FMAXP': '01100100000101101000000000000000',
+        'FMINNMP': '01100100000101011000000000000000',
+        'FMINP': '01100100000101111000000000000000',
+    },
+    matchers = [
+        'Z(WORD), PMerge, Z(WORD), Z(WORD)',
+        'Z(DWORD), PMerge, Z(DWORD), Z(DWORD)',
+        'Z(QWORD), PMerge, Z(QWORD), Z(QWORD)',
+    ],
+    processors = [
+        'R(0), R3(10), RTied, R(5), Static(22, 0b01)',
+        'R(0), R3(10), RTied, R(5), Static(22, 0b10)',
+        'R(0), R3(10), RTied, R(5), Static(22, 0b11)',
+    ],
+)
+
+tlentry(['SQABS', 'SQNEG'],
+    '<Zd>.<T>,<Pg>/M,<Zn>.<T>', (),
+    encodings = {
+        'SQABS': '01000100000010001010000000000000',
+        'SQNEG': '01000100000010011010000000000000',
+    },
+    matchers = [
+        'Z(BYTE), PMerge, Z(BYTE)',
+        'Z(WORD), PMerge, Z(WORD)',
+        'Z(DWORD), PMerge, Z(DWORD)',
+        'Z(QWORD), PMerge, Z(QWORD)',
+    ],
+    processors = [
+        'R(0), R3(10), R(5), Static(22, 0b00)',
+        'R(0), R3(10), R(5), Static(22, 0b01)',
+        'R(0), R3(10), R(5), Static(22, 0b10)',
+        'R(0), R3(10), R(5), Static(22, 0b11)',
+    ],
+)
+
+tlentry(['URECPE', 'URSQRTE'],
+    '<Zd>.S,<Pg>/M,<Zn>.S', (),
+    encodings = {
+        'URECPE': '01000100100000001010000000000000',
+        'URSQRTE': '01000100100000011010000000000000',
+    },
+    matcher   = 'Z(DWORD), PMerge, Z(DWORD)',
+    processor = 'R(0), R3(10), R(5)',
+)
+
+tlentry(['FLOGB'],
+    '<Zd>.<T>,<Pg>/M,<Zn>.<T>', (),
+    encodings = {
+        'FLOGB': '01100101000110001010000000000000',
+    },
+    matchers = [
+        'Z(WORD), PMerge, Z(WORD)',
+        'Z(DWORD), PMerge, Z(DWORD)',
+        'Z(QWORD), PMerge, Z(QWORD)',
+    ],
+    processors = [
+        'R(0), R3(10), R(5), Static(17, 0b01)',
+        'R(0), R3(10), R(5), Static(17, 0b10)',
+        'R(0), R3(10), R(5), Static(17, 0b11)',
+    ],
+)
+
+tlentry(['SADALP', 'UADALP'],
+    '<Zda>.<T>,<Pg>/M,<Zn>.<Tb>', (),
+    encodings = {
+        'SADALP': '01000100000001001010000000000000',
+        'UADALP': '01000100000001011010000000000000',
+    },
+    matchers = [
+        'Z(WORD), PMerge, Z(BYTE)',
+        'Z(DWORD), PMerge, Z(WORD)',
+        'Z(QWORD), PMerge, Z(DWORD)',
+    ],
+    processors = [
+        'R(0), R3(10), R(5), Static(22, 0b01)',
+        'R(0), R3(10), R(5), Static(22, 0b10)',
+        'R(0), R3(10), R(5), Static(22, 0b11)',
+    ],
+)
+
+tlentry(['SABDLB', 'SABDLT', 'SADDLB', 'SADDLT', 'SMULLB', 'SMULLT', 'SQDMULLB', 'SQDMULLT', 'SSUBLB', 'SSUBLT', 'UABDLB', 'UABDLT', 'UADDLB', 'UADDLT', 'UMULLB', 'UMULLT', 'USUBLB', 'USUBLT'],
+    '<Zd>.<T>,<Zn>.<Tb>,<Zm>.<Tb>', (),
+    heading = 'vectors',
+    encodings = {
+        'SABDLB': '01000101000000000011000000000000',
+        'SABDLT': '01000101000000000011010000000000',
+        'SADDLB': '01000101000000000000000000000000',
+        'SADDLT': '01000101000000000000010000000000',
+        'SMULLB': '01000101000000000111000000000000',
+        'SMULLT': '01000101000000000111010000000000',
+        'SQDMULLB': '01000101000000000110000000000000',
+        'SQDMULLT': '01000101000000000110010000000000',
+        'SSUBLB': '01000101000000000001000000000000',
+        'SSUBLT': '01000101000000000001010000000000',
+        'UABDLB': '01000101000000000011100000000000',
+        'UABDLT': '01000101000000000011110000000000',
+        'UADDLB': '01000101000000000000100000000000',
+        'UADDLT': '01000101000000000000110000000000',
+        'UMULLB': '01000101000000000111100000000000',
+        'UMULLT': '01000101000000000111110000000000',
+        'USUBLB': '01000101000000000001100000000000',
+        'USUBLT': '01000101000000000001110000000000',
+    },
+    matchers = [
+        'Z(WORD), Z(BYTE), Z(BYTE)',
+        'Z(DWORD), Z(WORD), Z(WORD)',
+        'Z(QWORD), Z(DWORD), Z(DWORD)',
+    ],
+    processors = [
+        'R(0), R(5), R(16), Static(22, 0b01)',
+        'R(0), R(5), R(16), Static(22, 0b10)',
+        'R(0), R(5), R(16), Static(22, 0b11)',
+    ],
+)
+
+tlentry(['SADDWB', 'SADDWT', 'SSUBWB', 'SSUBWT', 'UADDWB', 'UADDWT', 'USUBWB', 'USUBWT'],
+    '<Zd>.<T>,<Zn>.<T>,<Zm>.<Tb>', (),
+    encodings = {
+        'SADDWB': '01000101000000000100000000000000',
+        'SADDWT': '01000101000000000100010000000000',
+        'SSUBWB': '01000101000000000101000000000000',
+        'SSUBWT': '01000101000000000101010000000000',
+        'UADDWB': '01000101000000000100100000000000',
+        'UADDWT': '01000101000000000100110000000000',
+        'USUBWB': '01000101000000000101100000000000',
+        'USUBWT': '01000101000000000101110000000000',
+    },
+    matchers = [
+        'Z(WORD), Z(WORD), Z(BYTE)',
+        'Z(DWORD), Z(DWORD), Z(WORD)',
+        'Z(QWORD), Z(QWORD), Z(DWORD)',
+    ],
+    processors = [
+        'R(0), R(5), R(16), Static(22, 0b01)',
+        'R(0), R(5), R(16), Static(22, 0b10)',
+        'R(0), R(5), R(16), Static(22, 0b11)',
+    ],
+)
+
+tlentry(['SABALB', 'SABALT', 'SMLALB', 'SMLALT', 'SMLSLB', 'SMLSLT', 'SQDMLALB', 'SQDMLALT', 'SQDMLSLB', 'SQDMLSLT', 'UABALB', 'UABALT', 'UMLALB', 'UMLALT', 'UMLSLB', 'UMLSLT'],
+    '<Zda>.<T>,<Zn>.<Tb>,<Zm>.<Tb>', (),
+    heading = 'vectors',
+    encodings = {
+        'SABALB': '01000101000000001100000000000000',
+        'SABALT': '01000101000000001100010000000000',
+        'SMLALB': '01000100000000000100000000000000',
+        'SMLALT': '01000100000000000100010000000000',
+        'SMLSLB': '01000100000000000101000000000000',
+        'SMLSLT': '01000100000000000101010000000000',
+        'SQDMLALB': '01000100000000000110000000000000',
+        'SQDMLALT': '01000100000000000110010000000000',
+        'SQDMLSLB': '01000100000000000110100000000000',
+        'SQDMLSLT': '01000100000000000110110000000000',
+        'UABALB': '01000101000000001100100000000000',
+        'UABALT': '01000101000000001100110000000000',
+        'UMLALB': '01000100000000000100100000000000',
+        'UMLALT': '01000100000000000100110000000000',
+        'UMLSLB': '01000100000000000101100000000000',
+        'UMLSLT': '01000100000000000101110000000000',
+    },
+    matchers = [
+        'Z(WORD), Z(BYTE), Z(BYTE)',
+        'Z(DWORD), Z(WORD), Z(WORD)',
+        'Z(QWORD), Z(DWORD), Z(DWORD)',
+    ],
+    processors = [
+        'R(0), R(5), R(16), Static(22, 0b01)',
+        'R(0), R(5), R(16), Static(22, 0b10)',
+        'R(0), R(5), R(16), Static(22, 0b11)',
+    ],
+)
+
+tlentry(['SABA', 'UABA'],
+    '<Zda>.<T>,<Zn>.<T>,<Zm>.<T>', (),
+    encodings = {
+        'SABA': '01000101000000001111100000000000',
+        'UABA': '01000101000000001111110000000000',
+    },
+    matchers = [
+        'Z(BYTE), Z(BYTE), Z(BYTE)',
+        'Z(WORD), Z(WORD), Z(WORD)',
+        'Z(DWORD), Z(DWORD), Z(DWORD)',
+        'Z(QWORD), Z(QWORD), Z(QWORD)',
+    ],
+    processors = [
+        'R(0), R(5), R(16), Static(22, 0b00)',
+        'R(0), R(5), R(16), Static(22, 0b01)',
+        'R(0), R(5), R(16), Static(22, 0b10)',
+        'R(0), R(5), R(16), Static(22, 0b11)',
+    ],
+)
+
+tlentry(['ADCLB', 'ADCLT', 'SBCLB', 'SBCLT'],
+    '<Zda>.<T>,<Zn>.<T>,<Zm>.<T>', (),
+    encodings = {
+        'ADCLB': '01000101000000001101000000000000',
+        'ADCLT': '01000101000000001101010000000000',
+        'SBCLB': '01000101100000001101000000000000',
+        'SBCLT': '01000101100000001101010000000000',
+    },
+    matchers = [
+        'Z(DWORD), Z(DWORD), Z(DWORD)',
+        'Z(QWORD), Z(QWORD), Z(QWORD)',
+    ],
+    processors = [
+        'R(0), R(5), R(16)',
+        'R(0), R(5), R(16), Static(22, 0b1)',
+    ],
+)
+
+tlentry(['ADDHNB', 'ADDHNT', 'RADDHNB', 'RADDHNT', 'RSUBHNB', 'RSUBHNT', 'SUBHNB', 'SUBHNT'],
+    '<Zd>.<T>,<Zn>.<Tb>,<Zm>.<Tb>', (),
+    encodings = {
+        'ADDHNB': '01000101001000000110000000000000',
+        'ADDHNT': '01000101001000000110010000000000',
+        'RADDHNB': '01000101001000000110100000000000',
+        'RADDHNT': '01000101001000000110110000000000',
+        'RSUBHNB': '01000101001000000111100000000000',
+        'RSUBHNT': '01000101001000000111110000000000',
+        'SUBHNB': '01000101001000000111000000000000',
+        'SUBHNT': '01000101001000000111010000000000',
+    },
+    matchers = [
+        'Z(BYTE), Z(WORD), Z(WORD)',
+        'Z(WORD), Z(DWORD), Z(DWORD)',
+        'Z(DWORD), Z(QWORD), Z(QWORD)',
+    ],
+    processors = [
+        'R(0), R(5), R(16), Static(22, 0b01)',
+        'R(0), R(5), R(16), Static(22, 0b10)',
+        'R(0), R(5), R(16), Static(22, 0b11)',
+    ],
+)
+
+tlentry(['SQXTNB', 'SQXTNT', 'SQXTUNB', 'SQXTUNT', 'UQXTNB', 'UQXTNT'],
+    '<Zd>.<T>,<Zn>.<Tb>', (),
+    encodings = {
+        'SQXTNB': '01000101001000000100000000000000',
+        'SQXTNT': '01000101001000000100010000000000',
+        'SQXTUNB': '01000101001000000101000000000000',
+        'SQXTUNT': '01000101001000000101010000000000',
+        'UQXTNB': '01000101001000000100100000000000',
+        'UQXTNT': '01000101001000000100110000000000',
+    },
+    matchers = [
+        'Z(BYTE), Z(WORD)',
+        'Z(WORD), Z(DWORD)',
+        'Z(DWORD), Z(QWORD)',
+    ],
+    processors = [
+        'R(0), R(5), Static(19, 0b01)',
+        'R(0), R(5), Static(20, 0b1)',
+        'R(0), R(5), Static(22, 0b1)',
+    ],
+)
+
+tlentry(['RSHRNB', 'RSHRNT', 'SHRNB', 'SHRNT', 'SQRSHRNB', 'SQRSHRNT', 'SQRSHRUNB', 'SQRSHRUNT', 'SQSHRNB', 'SQSHRNT', 'SQSHRUNB', 'SQSHRUNT', 'UQRSHRNB', 'UQRSHRNT', 'UQSHRNB', 'UQSHRNT'],
+    '<Zd>.<T>,<Zn>.<Tb>,#<const>', (),
+    encodings = {
+        'RSHRNB': '01000101001000000001100000000000',
+        'RSHRNT': '01000101001000000001110000000000',
+        'SHRNB': '01000101001000000001000000000000',
+        'SHRNT': '01000101001000000001010000000000',
+        'SQRSHRNB': '01000101001000000010100000000000',
+        'SQRSHRNT': '01000101001000000010110000000000',
+        'SQRSHRUNB': '01000101001000000000100000000000',
+        'SQRSHRUNT': '01000101001000000000110000000000',
+        'SQSHRNB': '01000101001000000010000000000000',
+        'SQSHRNT': '01000101001000000010010000000000',
+        'SQSHRUNB': '01000101001000000000000000000000',
+        'SQSHRUNT': '01000101001000000000010000000000',
+        'UQRSHRNB': '01000101001000000011100000000000',
+        'UQRSHRNT': '01000101001000000011110000000000',
+        'UQSHRNB': '01000101001000000011000000000000',
+        'UQSHRNT': '01000101001000000011010000000000',
+    },
+    matchers = [
+        'Z(BYTE), Z(WORD), Imm',
+        'Z(WORD), Z(DWORD), Imm',
+        'Z(DWORD), Z(QWORD), Imm',
+    ],
+    processors = [
+        'R(0), R(5), Usub(16, 3, 8), Static(19, 0b01)',
+        'R(0), R(5), Usub(16, 4, 16), Static(20, 0b1)',
+        'R(0), R(5), Usub(16, 5, 32), Static(22, 0b1)',
+    ],
+)
+
+tlentry(['SSHLLB', 'SSHLLT', 'USHLLB', 'USHLLT'],
+    '<Zd>.<T>,<Zn>.<Tb>,#<const>', (),
+    encodings = {
+        'SSHLLB': '01000101000000001010000000000000',
+        'SSHLLT': '01000101000000001010010000000000',
+        'USHLLB': '01000101000000001010100000000000',
+        'USHLLT': '01000101000000001010110000000000',
+    },
+    matchers = [
+        'Z(WORD), Z(BYTE), Imm',
+        'Z(DWORD), Z(WORD), Imm',
+        'Z(QWORD), Z(DWORD), Imm',
+    ],
+    processors = [
+        'R(0), R(5), Ubits(16, 3), Static(19, 0b01)',
+        'R(0), R(5), Ubits(16, 4), Static(20, 0b1)',
+        'R(0), R(5), Ubits(16, 5), Static(22, 0b1)',
+    ],
+)
+
+tlentry(['SLI'],
+    '<Zd>.<T>,<Zn>.<T>,#<const>', (),
+    encodings = {
+        'SLI': '01000101000000001111010000000000',
+    },
+    matchers = [
+        'Z(BYTE), Z(BYTE), Imm',
+        'Z(WORD), Z(WORD), Imm',
+        'Z(DWORD), Z(DWORD), Imm',
+        'Z(QWORD), Z(QWORD), Imm',
+    ],
+    processors = [
+        'R(0), R(5), Ubits(16, 3), Static(19, 0b01)',
+        'R(0), R(5), Ubits(16, 4), Static(20, 0b1)',
+        'R(0), R(5), Ubits(16, 5), Static(22, 0b1)',
+        'R(0), R(5), BUbits(6), Uslice(16, 5, 0), Uslice(22, 1, 5), A, Static(23, 0b1)',
+    ],
+)
+
+tlentry(['SRI', 'SRSRA', 'SSRA', 'URSRA', 'USRA'],
+    '<Zd>.<T>,<Zn>.<T>,#<const>', (),
+    encodings = {
+        'SRI': '01000101000000001111000000000000',
+        'SRSRA': '01000101000000001110100000000000',
+        'SSRA': '01000101000000001110000000000000',
+        'URSRA': '01000101000000001110110000000000',
+        'USRA': '01000101000000001110010000000000',
+    },
+    matchers = [
+        'Z(BYTE), Z(BYTE), Imm',
+        'Z(WORD), Z(WORD), Imm',
+        'Z(DWORD), Z(DWORD), Imm',
+        'Z(QWORD), Z(QWORD), Imm',
+    ],
+    processors = [
+        'R(0), R(5), Usub(16, 3, 8), Static(19, 0b1)',
+        'R(0), R(5), Usub(16, 4, 16), Static(20, 0b1)',
+        'R(0), R(5), Usub(16, 5, 32), Static(22, 0b1)',
+        'R(0), R(5), BUrange(1, 64), Unegslice(16, 5, 0), Unegslice(22, 1, 5), A, Static(23, 0b1)',
+    ],
+)
+
+tlentry(['TBL'],
+    '<Zd>.<T>,{<Zn1>.<T>,<Zn2>.<T>},<Zm>.<T>', (),
+    heading = 'two registers',
+    encodings = {
+        'TBL': '00000101001000000010100000000000',
+    },
+    matchers = [
+        'Z(BYTE), ZRegList(2, BYTE), Z(BYTE)',
+        'Z(WORD), ZRegList(2, WORD), Z(WORD)',
+        'Z(DWORD), ZRegList(2, DWORD), Z(DWORD)',
+        'Z(QWORD), ZRegList(2, QWORD), Z(QWORD)',
+    ],
+    processors = [
+        'R(0), R(5), R(16), Static(22, 0b00)',
+        'R(0), R(5), R(16), Static(22, 0b01)',
+        'R(0), R(5), R(16), Static(22, 0b10)',
+        'R(0), R(5), R(16), Static(22, 0b11)',
+    ],
+)
+
+tlentry(['TBX'],
+    '<Zd>.<T>,<Zn>.<T>,<Zm>.<T>', (),
+    encodings = {
+        'TBX': '00000101001000000010110000000000',
+    },
+    matchers = [
+        'Z(BYTE), Z(BYTE), Z(BYTE)',
+        'Z(WORD), Z(WORD), Z(WORD)',
+        'Z(DWORD), Z(DWORD), Z(DWORD)',
+        'Z(QWORD), Z(QWORD), Z(QWORD)',
+    ],
+    processors = [
+        'R(0), R(5), R(16), Static(22, 0b00)',
+        'R(0), R(5), R(16), Static(22, 0b01)',
+        'R(0), R(5), R(16), Static(22, 0b10)',
+        'R(0), R(5), R(16), Static(22, 0b11)',
+    ],
+)
+
+tlentry(['SPLICE'],
+    '<Zd>.<T>,<Pg>,{<Zn1>.<T>,<Zn2>.<T>}', (),
+    heading = 'constructive',
+    encodings = {
+        'SPLICE': '00000101001011011000000000000000',
+    },
+    matchers = [
+        'Z(BYTE), PBare, ZRegList(2, BYTE)',
+        'Z(WORD), PBare, ZRegList(2, WORD)',
+        'Z(DWORD), PBare, ZRegList(2, DWORD)',
+        'Z(QWORD), PBare, ZRegList(2, QWORD)',
+    ],
+    processors = [
+        'R(0), R3(10), R(5), Static(22, 0b00)',
+        'R(0), R3(10), R(5), Static(22, 0b01)',
+        'R(0), R3(10), R(5), Static(22, 0b10)',
+        'R(0), R3(10), R(5), Static(22, 0b11)',
+    ],
+)
+
+tlentry(['EXT'],
+    '<Zd>.B,{<Zn1>.B,<Zn2>.B},#<imm>', (),
+    heading = 'constructive',
+    encodings = {
+        'EXT': '00000101011000000000000000000000',
+    },
+    matcher   = 'Z(BYTE), ZRegList(2, BYTE), Imm',
+    processor = 'R(0), R(5), BUbits(8), Uslice(10, 3, 0), Uslice(16, 5, 3), A',
+)
+
+tlentry(['MATCH', 'NMATCH'],
+    '<Pd>.<T>,<Pg>/Z,<Zn>.<T>,<Zm>.<T>', (),
+    encodings = {
+        'MATCH': '01000101001000001000000000000000',
+        'NMATCH': '01000101001000001000000000010000',
+    },
+    matchers = [
+        'P(BYTE), PZero, Z(BYTE), Z(BYTE)',
+        'P(WORD), PZero, Z(WORD), Z(WORD)',
+    ],
+    processors = [
+        'R4(0), R3(10), R(5), R(16), Static(22, 0b00)',
+        'R4(0), R3(10), R(5), R(16), Static(22, 0b01)',
+    ],
+)
+
+tlentry(['HISTCNT'],
+    '<Zd>.<T>,<Pg>/Z,<Zn>.<T>,<Zm>.<T>', (),
+    encodings = {
+        'HISTCNT': '01000101101000001100000000000000',
+    },
+    matchers = [
+        'Z(DWORD), PZero, Z(DWORD), Z(DWORD)',
+        'Z(QWORD), PZero, Z(QWORD), Z(QWORD)',
+    ],
+    processors = [
+        'R(0), R3(10), R(5), R(16)',
+        'R(0), R3(10), R(5), R(16), Static(22, 0b1)',
+    ],
+)
+
+tlentry(['HISTSEG'],
+    '<Zd>.B,<Zn>.B,<Zm>.B', (),
+    encodings = {
+        'HISTSEG': '01000101001000001010000000000000',
+    },
+    matcher   = 'Z(BYTE), Z(BYTE), Z(BYTE)',
+    processor = 'R(0), R(5), R(16)',
+)
+
+tlentry(['AESD', 'AESE'],
+    '<Zdn>.B,<Zdn>.B,<Zm>.B', (),
+    encodings = {
+        'AESD': '01000101001000101110010000000000',
+        'AESE': '01000101001000101110000000000000',
+    },
+    matcher   = 'Z(BYTE), Z(BYTE), Z(BYTE)',
+    processor = 'R(0), RTied, R(5)',
+)
+
+tlentry(['AESIMC', 'AESMC'],
+    '<Zdn>.B,<Zdn>.B', (),
+    encodings = {
+        'AESIMC': '01000101001000001110010000000000',
+        'AESMC': '01000101001000001110000000000000',
+    },
+    matcher   = 'Z(BYTE), Z(BYTE)',
+    processor = 'R(0), RTied',
+)
+
+tlentry(['SM4E'],
+    '<Zdn>.S,<Zdn>.S,<Zm>.S', (),
+    encodings = {
+        'SM4E': '01000101001000111110000000000000',
+    },
+    matcher   = 'Z(DWORD), Z(DWORD), Z(DWORD)',
+    processor = 'R(0), RTied, R(5)',
+)
+
+tlentry(['SM4EKEY'],
+    '<Zd>.S,<Zn>.S,<Zm>.S', (),
+    encodings = {
+        'SM4EKEY': '01000101001000001111000000000000',
+    },
+    matcher   = 'Z(DWORD), Z(DWORD), Z(DWORD)',
+    processor = 'R(0), R(5), R(16)',
+)
+
+tlentry(['RAX1'],
+    '<Zd>.D,<Zn>.D,<Zm>.D', (),
+    encodings = {
+        'RAX1': '01000101001000001111010000000000',
+    },
+    matcher   = 'Z(QWORD), Z(QWORD), Z(QWORD)',
+    processor = 'R(0), R(5), R(16)',
+)
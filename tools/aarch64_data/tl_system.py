@@ -25,8 +25,14 @@ tlentry(['SYS'],
 
 tlentry(['MSR'],
     '(<systemreg>|S<op0>_<op1>_<Cn>_<Cm>_<op2>),<Xt>', (('o0', 1, 19), ('op1', 3, 16), ('CRn', 4, 12), ('CRm', 4, 8), ('op2', 3, 5), ('Rt', 5, 0)),
-    matcher   = 'Imm, X',
-    processor = 'Ubits(5, 15), R(0)',
+    matchers = [
+        'Ident, X',
+        'Imm, X',
+    ],
+    processors = [
+        'SysRegWrite(5), R(0)',
+        'Ubits(5, 15), R(0)',
+    ],
 )
 
 tlentry(['SYSL'],
@@ -37,8 +43,14 @@ tlentry(['SYSL'],
 
 tlentry(['MRS'],
     '<Xt>,(<systemreg>|S<op0>_<op1>_<Cn>_<Cm>_<op2>)', (('o0', 1, 19), ('op1', 3, 16), ('CRn', 4, 12), ('CRm', 4, 8), ('op2', 3, 5), ('Rt', 5, 0)),
-    matcher   = 'X, Imm',
-    processor = 'R(0), Ubits(5, 15)',
+    matchers = [
+        'X, Ident',
+        'X, Imm',
+    ],
+    processors = [
+        'R(0), SysRegRead(5)',
+        'R(0), Ubits(5, 15)',
+    ],
 )
 
 tlentry(['AT'],
@@ -21,10 +21,12 @@ def main():
     with open(sys.argv[1], "r", encoding="utf-8") as f:
         data = read_input_file(f)
 
+    prefix = sys.argv[3] if len(sys.argv) > 3 else "aarch64_tests"
+
     tests = [emit_test_case(i, dynasm, gas, bytes) for i, (dynasm, gas, bytes) in enumerate(data)]
 
     for i, chunk in enumerate(chunks(tests, 800)):
-        with open(os.path.join(sys.argv[2], "aarch64_tests_{}.rs.gen".format(i)), "w", encoding="utf-8") as f:
+        with open(os.path.join(sys.argv[2], "{}_{}.rs.gen".format(prefix, i)), "w", encoding="utf-8") as f:
             for test in chunk:
                 f.write(test)
 
@@ -13,6 +13,7 @@ class IClass:
         self.template = template
         self.instrclass = instrclass
         self.arch_variant = arch_variant
+        self.features = None
 
     def __repr__(self):
         s = "{} ({}) => 0b{}".format(self.mnemonic, self.instrclass, self.bits)
@@ -270,7 +271,9 @@ def emit_opmap(ops, f):
             bits = op.bits.replace("x", "0").replace("N", "0").replace("Z", "0").replace("z", "0")
             bits = bits[0:8] + "_" + bits[8:16] + "_" + bits[16:24] + "_" + bits[24:32]
 
-            f.write("    0b{} = [{}] => [{}];\n".format(bits, op.matcher, op.processor))
+            features = ", {}".format(op.features) if op.features else ""
+
+            f.write("    0b{} = [{}] => [{}]{};\n".format(bits, op.matcher, op.processor, features))
 
 
         f.write("]\n")
@@ -304,7 +307,7 @@ tlentry({},
         )
 
 class TranslationEntry:
-    def __init__(self, ops, template, fields, matcher_processors, forget, names, bits, priority):
+    def __init__(self, ops, template, fields, matcher_processors, forget, names, bits, priority, encodings, heading):
         self.ops = ops
         self.template = template
         self.fields = fields
@@ -313,9 +316,12 @@ class TranslationEntry:
         self.names = names
         self.bits = bits
         self.priority = priority
+        self.encodings = encodings
+        self.heading = heading
+        self.features = None
 
     @classmethod
-    def entry(cls, ops, template, fields, matcher=None, processor=None, matchers=None, processors=None, forget=False, names=None, bits=None, priority=0):
+    def entry(cls, ops, template, fields, matcher=None, processor=None, matchers=None, processors=None, forget=False, names=None, bits=None, priority=0, encodings=None, heading=None):
         m = []
         p = []
         if matcher is not None:
@@ -335,7 +341,16 @@ class TranslationEntry:
             assert len(m) == len(p), "Amount of matchers and processors does not match"
             if names:
                 assert len(names) == len(m), "wrong amount of names"
-        return cls(ops, template, fields, list(zip(m, p)), forget, names, bits, priority)
+        if encodings:
+            assert sorted(encodings) == sorted(ops), "encodings do not match the listed ops"
+        return cls(ops, template, fields, list(zip(m, p)), forget, names, bits, priority, encodings, heading)
+
+# the architecture extensions required by default by the entries of each translation file
+TL_FILE_FEATURES = {
+    "tl_sve.py": "SVE",
+    "tl_sve2.py": "SVE2",
+    "tl_sme.py": "SME",
+}
 
 class TranslationMap:
     def __init__(self):
@@ -344,7 +359,9 @@ class TranslationMap:
 
     def load_file(self, f, name):
         def entry(*args, **kwargs):
-            self.map.append(TranslationEntry.entry(*args, **kwargs))
+            entry = TranslationEntry.entry(*args, **kwargs)
+            entry.features = TL_FILE_FEATURES.get(name)
+            self.map.append(entry)
 
         g = {"tlentry": entry}
         code = f.read()
@@ -366,6 +383,15 @@ class TranslationMap:
     def lookup_iclass(self, op):
         return self.table[op.mnemonic][(op.template, op.fields)]
 
+    def synthesize_iclasses(self, instrclass):
+        # entries for instructions that aren't described by the specification carry their own encodings
+        for entry in self.map:
+            if not entry.encodings:
+                continue
+            for op in entry.ops:
+                name = "{} ({})".format(op, entry.heading) if entry.heading else op
+                yield IClass(op, entry.fields, entry.encodings[op], name, entry.template, instrclass, None)
+
 def tl_assign_matchers(ops, map):
     for m, group in ops:
         newgroup = []
@@ -378,6 +404,7 @@ def tl_assign_matchers(ops, map):
             elif len(tlentry.matcher_processors) == 1:
                 op.matcher = tlentry.matcher_processors[0][0]
                 op.processor = tlentry.matcher_processors[0][1]
+                op.features = tlentry.features
                 newgroup.append((op, tlentry.priority))
 
             elif tlentry.names:
@@ -387,6 +414,7 @@ def tl_assign_matchers(ops, map):
                         new = copy.deepcopy(op)
                         new.matcher = matcher
                         new.processor = processor
+                        new.features = tlentry.features
                         newgroup.append((new, tlentry.priority))
                         found = True
                 assert found, "Never encountered a specific title during title lookup"
@@ -398,6 +426,7 @@ def tl_assign_matchers(ops, map):
                         new = copy.deepcopy(op)
                         new.matcher = matcher
                         new.processor = processor
+                        new.features = tlentry.features
                         newgroup.append((new, tlentry.priority))
                         found = True
                 assert found, "Never encountered a specific bits during bits lookup: {}".format(bits)
@@ -407,12 +436,28 @@ def tl_assign_matchers(ops, map):
                     new = copy.deepcopy(op)
                     new.matcher = matcher
                     new.processor = processor
+                    new.features = tlentry.features
                     newgroup.append((new, tlentry.priority))
 
         newgroup.sort(key=lambda x: x[1], reverse=True) # sort by priority
 
         group[:] = [i for i, _ in newgroup]
 
+def read_features(f):
+    features = []
+    def entry(features_expr, ops, matchers=None):
+        features.append((features_expr, ops, matchers))
+
+    exec(f.read(), {"tlfeature": entry})
+    return features
+
+def tl_assign_features(ops, features):
+    for m, group in ops:
+        for op in group:
+            for features_expr, mnemonics, matchers in features:
+                if op.mnemonic in mnemonics and (matchers is None or op.matcher in matchers):
+                    op.features = features_expr
+
 def tl_merge_statics(ops):
     for m, group in ops:
         for op in group:
@@ -443,7 +488,18 @@ def main():
     ops = filter_arch_versions(ops, {'ARMv8.1', 'ARMv8.2', 'ARMv8.3', 'ARMv8.4'})
 
     # available classes: general, system, float, fpsimd, advsimd, sve
-    ops = filter_instr_classes(ops, {"general", "system", "float", "fpsimd", "advsimd"})
+    ops = filter_instr_classes(ops, {"general", "system", "float", "fpsimd", "advsimd", "sve"})
+
+    # load translation files
+    tlmap = TranslationMap()
+    for file in ("tl_general.py", "tl_system.py", "tl_float.py", "tl_fpsimd.py", "tl_advsimd.py", "tl_sve.py", "tl_sve2.py", "tl_sme.py", "tl_extensions.py"):
+        with open(os.path.join("aarch64_data", file), "r", encoding="utf-8") as f:
+            tlmap.load_file(f, file)
+
+    tlmap.build_lookup_table()
+
+    # add the instructions that postdate the specification
+    ops.extend(tlmap.synthesize_iclasses("sve"))
 
     ops = group_instr_aliases(ops)
 
@@ -464,29 +520,29 @@ def main():
         for op in o:
             print(op)
 
-    # load translation files
-    tlmap = TranslationMap()
-    for file in ("tl_general.py", "tl_system.py", "tl_float.py", "tl_fpsimd.py", "tl_advsimd.py"):
-        with open(os.path.join("aarch64_data", file), "r", encoding="utf-8") as f:
-            tlmap.load_file(f, file)
-
-    tlmap.build_lookup_table()
-
     # add the opmap data
 
     tl_assign_matchers(ops, tlmap)
     #assign_matchers(ops)
     #merge_matchers(ops)
 
+    # determine the architecture extensions each op requires
+    with open(os.path.join("aarch64_data", "features.py"), "r", encoding="utf-8") as f:
+        features = read_features(f)
+    tl_assign_features(ops, features)
+
     # compile static data into bits
     tl_merge_statics(ops)
 
     #emit the actual opmap
 
-    with open("../plugin/src/arch/aarch64/opmap.rs", "w", encoding="utf-8") as f:
-        f.write("// This file was generated bo tools/aarch64_gen_opmap.py\nOps!(\n\n")
+    with open("../data/src/aarch64_opmap.rs", "w", encoding="utf-8") as f:
+        f.write("// This file was generated by tools/aarch64_gen_opmap.py\n\n")
+        f.write("/// The aarch64 instruction data. Expands to `$ops!(...)`, with for every mnemonic a list of its forms as\n")
+        f.write("/// `base encoding = [matchers] => [commands] (, features)`.\n")
+        f.write("#[macro_export]\nmacro_rules! aarch64_opmap {\n    ($ops:ident) => { $ops!(\n\n")
         emit_opmap(ops, f)
-        f.write("\n);\n")
+        f.write("\n    ) }\n}\n")
 
 if __name__ == '__main__':
     main()
@@ -0,0 +1,161 @@
+
+import os
+import re
+import sys
+import xml.etree.ElementTree as ET
+
+# Generates data/src/aarch64_sysregs.rs, the table of system register names that can be used
+# with mrs and msr.
+#
+# usage: aarch64_gen_sysregs.py SYSREG_XML_FOLDER OUTFILE
+#    or: aarch64_gen_sysregs.py --llvm-mc MRS_LISTING MSR_LISTING OUTFILE
+#    or: aarch64_gen_sysregs.py --enumerate (mrs|msr)
+#
+# The first form reads the AArch64-*.xml files of the Machine-Readable system register
+# specification released by ARM next to the instruction set specification read by
+# aarch64_gen_opmap.py. The second form reads the output of disassembling every possible mrs and
+# msr encoding with `llvm-mc --disassemble -show-encoding` with all architecture extensions enabled
+# (as printed by the --enumerate form), for when the specification is not at hand.
+
+# registers that are incorrectly reported as writable by some versions of llvm-mc
+READ_ONLY_OVERRIDES = {"cntpctss_el0", "cntvctss_el0"}
+
+
+def main():
+    args = sys.argv[1:]
+    if args[0] == "--enumerate":
+        for line in enumerate_encodings(args[1] == "mrs"):
+            print(line)
+        return
+    elif args[0] == "--llvm-mc":
+        regs = read_llvm_listing(args[1], True)
+        merge(regs, read_llvm_listing(args[2], False))
+        for name in READ_ONLY_OVERRIDES:
+            if name in regs:
+                regs[name][2] = False
+        outfile = args[3]
+    else:
+        regs = read_sysreg_xml(args[0])
+        outfile = args[1]
+
+    with open(outfile, "w", encoding="utf-8") as f:
+        emit_sysregs(f, regs)
+
+
+def merge(regs, other):
+    for name, (encoding, readable, writable) in other.items():
+        entry = regs.setdefault(name, [encoding, False, False])
+        if entry[0] != encoding:
+            raise ValueError("conflicting encodings for {}".format(name))
+        entry[1] |= readable
+        entry[2] |= writable
+
+
+def encode(op0, op1, crn, crm, op2):
+    assert op0 in (2, 3)
+    return ((op0 - 2) << 14) | (op1 << 11) | (crn << 7) | (crm << 3) | op2
+
+
+# llvm-mc listings
+
+GENERIC_RE = re.compile(r"^s[23]_[0-7]_c[0-9]+_c[0-9]+_[0-7]$")
+LISTING_RE = re.compile(r"\b(mrs|msr)\s+(?:x0,\s*)?([A-Za-z0-9_]+)(?:,\s*x0)?\s+// encoding: \[([^\]]*)\]")
+
+def enumerate_encodings(read):
+    """Every possible mrs or msr (register) instruction, in the input format of llvm-mc --disassemble"""
+    base = 0xd5300000 if read else 0xd5100000
+    for encoding in range(1 << 15):
+        word = base | (encoding << 5)
+        yield " ".join("0x{:02x}".format((word >> (8 * i)) & 0xFF) for i in range(4))
+
+
+def read_llvm_listing(path, read):
+    regs = {}
+    with open(path, "r", encoding="utf-8") as f:
+        for line in f:
+            m = LISTING_RE.search(line)
+            if m is None:
+                continue
+            name = m.group(2).lower()
+            if GENERIC_RE.match(name):
+                continue
+            b = [int(x, 16) for x in m.group(3).split(",")]
+            word = b[0] | (b[1] << 8) | (b[2] << 16) | (b[3] << 24)
+            merge(regs, {name: ((word >> 5) & 0x7FFF, read, not read)})
+    return regs
+
+
+# system register specification
+
+def read_sysreg_xml(folder):
+    regs = {}
+    for fname in sorted(os.listdir(folder)):
+        if fname.startswith("AArch64-") and fname.endswith(".xml"):
+            root = ET.parse(os.path.join(folder, fname)).getroot()
+            for mechanism in root.iter("access_mechanism"):
+                merge(regs, analyse_access_mechanism(mechanism))
+    return regs
+
+
+def analyse_access_mechanism(mechanism):
+    accessor = mechanism.get("accessor", "").split()
+    if len(accessor) != 2 or accessor[0] not in ("MRS", "MSRregister"):
+        return {}
+
+    read = accessor[0] == "MRS"
+    name = accessor[1].lower()
+
+    fields = {}
+    for enc in mechanism.iter("enc"):
+        fields[enc.get("n")] = enc.get("v")
+
+    # indexed registers such as DBGBVR<n>_EL1 spread the index over the encoding fields
+    if "<n>" in name:
+        index_bits = 1 + max(int(m.group(1)) for value in fields.values() for m in map(INDEX_RE.match, value.split(":")) if m is not None)
+        regs = {}
+        for n in range(1 << index_bits):
+            values = [field_value(fields[f], n) for f in ("op0", "op1", "CRn", "CRm", "op2")]
+            regs[name.replace("<n>", str(n))] = (encode(*values), read, not read)
+        return regs
+
+    values = [field_value(fields[f], None) for f in ("op0", "op1", "CRn", "CRm", "op2")]
+    return {name: (encode(*values), read, not read)}
+
+
+INDEX_RE = re.compile(r"^n\[(\d+)(?::(\d+))?\]$")
+
+def field_value(value, n):
+    """Evaluates an encoding field, which is a concatenation of bit literals and bits of the index n"""
+    result = 0
+    for part in value.split(":"):
+        if part.startswith("0b"):
+            bits = part[2:]
+            result = (result << len(bits)) | int(bits, 2)
+            continue
+
+        m = INDEX_RE.match(part)
+        if m is None or n is None:
+            raise ValueError("unknown encoding field {}".format(value))
+        high = int(m.group(1))
+        low = int(m.group(2)) if m.group(2) is not None else high
+        result = (result << (high - low + 1)) | ((n >> low) & ((1 << (high - low + 1)) - 1))
+    return result
+
+
+# output
+
+def emit_sysregs(f, regs):
+    f.write("// This file was generated by tools/aarch64_gen_sysregs.py\n\n")
+    f.write("/// The named aarch64 system registers, as an array of `(name, o0:op1:CRn:CRm:op2 encoding, access)`.\n")
+    f.write("#[macro_export]\nmacro_rules! aarch64_sysregs {\n    () => {\n[\n")
+    for name in sorted(regs):
+        encoding, readable, writable = regs[name]
+        access = "ReadWrite" if readable and writable else "Read" if readable else "Write"
+        bits = "{:015b}".format(encoding)
+        bits = "_".join((bits[0:3], bits[3:7], bits[7:11], bits[11:15]))
+        f.write("    ({:<24} 0b{}, {}),\n".format('"{}",'.format(name), bits, access))
+    f.write("]\n    }\n}\n")
+
+
+if __name__ == '__main__':
+    main()
@@ -38,7 +38,13 @@ class OpTemplate:
             value = constraint.create_value(history)
             gas = arg.emit_gas(value)
             emitted = arg.emit_dynasm(value)
-            if isinstance(constraint, RNext):
+            if isinstance(constraint, RTied):
+                # the tied register has to be given in the same notation as the first one
+                if "(" in history.emitted[0]:
+                    emitted = "{}({})".format(arg.family, value)
+                else:
+                    emitted = gas
+            elif isinstance(constraint, RNext):
                 if "(" in history.emitted[-1]:
                     emitted = arg.emit_dynasm(31, False)
                 else:
@@ -70,15 +76,16 @@ class OpTemplate:
         return dynasm_string, gas_string
 
 
-REGLIST_RE = re.compile(r"\{v([0-9]+)(\.[^ ]+) *\* *([1234])\}")
+REGLIST_RE = re.compile(r"\{([vz])([0-9]+)(\.[^ ]+) *\* *([1234])\}")
 def reformat_reglist(m):
-    start = int(m.group(1))
-    format = m.group(2)
-    amount = int(m.group(3))
+    family = m.group(1)
+    start = int(m.group(2))
+    format = m.group(3)
+    amount = int(m.group(4))
 
     items = []
     for i in range(amount):
-        items.append("v{}{}".format((start + i) % 32, format))
+        items.append("{}{}{}".format(family, (start + i) % 32, format))
 
     return "{{{}}}".format(", ".join(items))
 
@@ -97,6 +104,8 @@ def read_opdata_file(f):
         Range2=Range2,
         R=R,
         RNext=RNext,
+        RTied=RTied,
+        Same=Same,
         Special=Special,
     )
 
@@ -120,7 +129,9 @@ def parse_template(template):
             arg = Modifier()
         elif argty == "Off":
             arg = Offset()
-        elif argty in "WXBHSDQV" or argty in ("WSP", "XSP", "WX"):
+        elif argty == "ZAList":
+            arg = TileList()
+        elif argty in "WXBHSDQVZP" or argty in ("WSP", "XSP", "WX", "ZA", "ZAH", "ZAV"):
             arg = Register(argty)
         else:
             raise NotImplementedError(argty)
@@ -182,7 +193,20 @@ class RNext(Constraint):
     """A constraint that only allows the register after the previous arg"""
     def create_value(self, history):
         return (history.values[-1] + 1) % 32
-        
+
+class RTied(Constraint):
+    """A constraint that only allows the same register as the first arg"""
+    def create_value(self, history):
+        return history.values[0]
+
+class Same(Constraint):
+    """A constraint that only allows the same value as an earlier arg"""
+    def __init__(self, back):
+        self.back = back
+
+    def create_value(self, history):
+        return history.values[-self.back]
+
 class Special(Constraint):
     """A constraint that can emit various special integer encoding formats"""
     def __init__(self, type):
@@ -197,6 +221,10 @@ class Special(Constraint):
             return make_wide_integer(False) ^ 0xFFFFFFFF
         elif self.type == "inverted_x":
             return make_wide_integer(True) ^ 0xFFFFFFFFFFFFFFFF
+        elif self.type == "logical_b":
+            return make_logical_imm(False, 8)
+        elif self.type == "logical_h":
+            return make_logical_imm(False, 16)
         elif self.type == "logical_w":
             return make_logical_imm(False)
         elif self.type == "logical_x":
@@ -244,8 +272,10 @@ class Register(Arg):
             if value == 31:
                 return "sp"
             return "x{}".format(value)
-        elif self.family in "BHSDQV":
+        elif self.family in "BHSDQVZP":
             return "{}{}".format(self.family.lower(), value)
+        elif self.family in ("ZA", "ZAH", "ZAV"):
+            return "za{}{}".format(value, self.family[2:].lower())
         else:
             raise NotImplementedError(self.family)
 
@@ -276,6 +306,21 @@ class Immediate(Arg):
 class Offset(Immediate):
     pass
 
+class TileList(Arg):
+    def emit_gas(self, value):
+        # gas does not accept lists mixing tiles of different sizes, so list every 64-bit tile
+        return ", ".join("za{}.d".format(tile) for tile in range(8) if value & (1 << tile))
+
+    def emit_dynasm(self, value):
+        # cover the mask of 64-bit tiles with the widest tiles possible
+        tiles = []
+        for size, count, pattern in (("b", 1, 0xFF), ("h", 2, 0x55), ("s", 4, 0x11), ("d", 8, 0x01)):
+            for tile in range(count):
+                if value & (pattern << tile) == pattern << tile:
+                    tiles.append("za{}.{}".format(tile, size))
+                    value &= ~(pattern << tile)
+        return ", ".join(tiles)
+
 class Ident(Arg):
     def emit_gas(self, value):
         return value.lower()
@@ -293,13 +338,14 @@ def make_float_imm():
     exponent = random.randrange(-3, 5)
     return ((-1.0) ** sign) * (2.0 ** exponent) * ((16.0 + mantissa) / 16.0)
 
-def make_logical_imm(bit64):
-    element_size = random.choice([2, 4, 8, 16, 32, 64] if bit64 else [2, 4, 8, 16, 32])
+def make_logical_imm(bit64, width=None):
+    width = width or (64 if bit64 else 32)
+    element_size = random.choice([i for i in (2, 4, 8, 16, 32, 64) if i <= width])
     ones = random.randrange(1, element_size)
     rotation = random.randrange(0, element_size)
 
     element = [0] * (element_size - ones) + [1] * ones
-    l = element * ((64 if bit64 else 32) // element_size)
+    l = element * (width // element_size)
     rotated = l[rotation:] + l[:rotation]
 
     return int("".join(map(str, rotated)), 2)